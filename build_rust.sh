@@ -24,8 +24,9 @@ cd ..
 echo ""
 echo "[2/3] Building Rust library (release mode without mimalloc)..."
 cd rust/syn_api
-# Build without default features to avoid TLS block errors from mimalloc
-cargo build --release --lib --no-default-features
+# Build without default features to avoid TLS block errors from mimalloc,
+# but keep the FFI surface that Flutter links against
+cargo build --release --lib --no-default-features --features ffi
 
 # Step 3: Copy to Flutter bundle
 echo ""
@@ -0,0 +1,122 @@
+//! # Headless Runner
+//!
+//! Drives [`GameEngine`](syn_api::GameEngine) without Flutter. Build it without the FFI surface:
+//!
+//! ```ignore
+//! cargo run -p syn_api --no-default-features --example headless -- determinism --seed 42 --ticks 5000
+//! ```
+//!
+//! ## Commands
+//!
+//! - `determinism [--seed N] [--ticks N] [--interval N] [--quantized]`: run two
+//!   engines from the same seed and scripted inputs in lockstep, comparing world
+//!   snapshots every `--interval` ticks. Exits with status 1 and prints the first
+//!   divergent field if they disagree. On success prints the final accumulator
+//!   digest; run it on two platforms and compare the digests to check
+//!   cross-platform determinism.
+//! - `example-pack [--seed N] [--days N]`: compile `content/example_pack`, run
+//!   its storylet fixtures, then play it over the fixture world one beat per
+//!   day and print the timeline. Exits with status 1 if a fixture fails.
+
+use std::process::ExitCode;
+use syn_api::determinism::{verify_determinism, DeterminismConfig};
+use syn_api::FloatMode;
+use syn_director::{example_pack_dir, load_example_pack, run_example_pack, run_storylet_fixtures};
+use syn_storylets::errors::StoryletCompileError;
+
+fn parse_flag(args: &[String], name: &str) -> Result<Option<u64>, String> {
+    match args.iter().position(|a| a == name) {
+        None => Ok(None),
+        Some(i) => args
+            .get(i + 1)
+            .ok_or_else(|| format!("{name} needs a value"))?
+            .parse()
+            .map(Some)
+            .map_err(|e| format!("invalid value for {name}: {e}")),
+    }
+}
+
+fn run_determinism(args: &[String]) -> Result<ExitCode, String> {
+    let mut config = DeterminismConfig::default();
+    if let Some(seed) = parse_flag(args, "--seed")? {
+        config.seed = seed;
+    }
+    if let Some(ticks) = parse_flag(args, "--ticks")? {
+        config.ticks = ticks;
+    }
+    if let Some(interval) = parse_flag(args, "--interval")? {
+        config.check_interval = interval;
+    }
+    if args.iter().any(|a| a == "--quantized") {
+        config.float_mode = FloatMode::Quantized;
+    }
+
+    println!(
+        "determinism: seed {} for {} ticks (checking every {}, {:?} floats)",
+        config.seed, config.ticks, config.check_interval, config.float_mode
+    );
+    match verify_determinism(&config) {
+        Ok(report) => {
+            println!(
+                "ok: {} ticks, {} snapshot comparisons, no divergence, digest {:016x}",
+                report.ticks, report.checks, report.digest
+            );
+            Ok(ExitCode::SUCCESS)
+        }
+        Err(divergence) => {
+            eprintln!("FAIL: {divergence}");
+            Ok(ExitCode::FAILURE)
+        }
+    }
+}
+
+fn run_example_pack_command(args: &[String]) -> Result<ExitCode, String> {
+    let seed = parse_flag(args, "--seed")?.unwrap_or(42);
+    let days = parse_flag(args, "--days")?.unwrap_or(60);
+    let compile_failed = |errors: Vec<StoryletCompileError>| {
+        errors
+            .iter()
+            .map(ToString::to_string)
+            .collect::<Vec<_>>()
+            .join("; ")
+    };
+
+    let library = load_example_pack().map_err(compile_failed)?;
+    let report = run_storylet_fixtures(example_pack_dir()).map_err(compile_failed)?;
+    println!(
+        "example-pack: {} storylets, {} fixtures run",
+        library.total_count, report.run
+    );
+    if !report.passed() {
+        for failure in &report.failures {
+            eprintln!("FAIL: {failure}");
+        }
+        return Ok(ExitCode::FAILURE);
+    }
+
+    let run = run_example_pack(&library, seed, days);
+    println!("seed {seed} for {days} days: {} beats", run.beats.len());
+    for beat in &run.beats {
+        println!("  day {:>3}  {}", beat.tick.0 / 24, beat.storylet_id);
+    }
+    let mut fired: Vec<&str> = run.beats.iter().map(|b| b.storylet_id.as_str()).collect();
+    fired.sort_unstable();
+    fired.dedup();
+    println!("{} of {} storylets fired", fired.len(), library.total_count);
+    Ok(ExitCode::SUCCESS)
+}
+
+fn main() -> ExitCode {
+    let args: Vec<String> = std::env::args().skip(1).collect();
+    let result = match args.first().map(String::as_str) {
+        Some("determinism") | None => run_determinism(&args),
+        Some("example-pack") => run_example_pack_command(&args),
+        Some(other) => Err(format!(
+            "unknown command '{other}' (expected: determinism, example-pack)"
+        )),
+    };
+    result.unwrap_or_else(|err| {
+        eprintln!("error: {err}");
+        ExitCode::from(2)
+    })
+}
@@ -6,6 +6,13 @@
 //!
 //! FRB v2 requires a dedicated module for code scanning; this module acts as
 //! the "public FFI surface" that the Dart codegen discovers.
+//!
+//! Every function runs inside [`ffi_guard`], so a panic reaches Dart as an
+//! [`ApiError`] instead of unwinding across the FFI boundary.
+//!
+//! Functions are `#[frb(sync)]` and run on the calling Dart isolate's thread.
+//! The `_async` variants are not: FRB runs them on its worker pool and hands
+//! Dart a `Future`, so long simulation work never blocks the UI thread.
 
 use crate::{
     // Core API functions from lib.rs
@@ -15,16 +22,119 @@ use crate::{
     get_game_state_snapshot as get_game_state_snapshot_impl,
     get_current_storylet as get_current_storylet_impl,
     get_available_choices as get_available_choices_impl,
+    api_preview_next_event as api_preview_next_event_impl,
+    api_submit_choice as api_submit_choice_impl,
     engine_tick as engine_tick_impl,
     engine_tick_many as engine_tick_many_impl,
+    engine_tick_many_chunked as engine_tick_many_chunked_impl,
+    engine_fast_forward_chunked as engine_fast_forward_chunked_impl,
+    api_get_current_event as api_get_current_event_impl,
+    engine_get_content_preferences as engine_get_content_preferences_impl,
+    engine_set_content_preferences as engine_set_content_preferences_impl,
+    engine_get_news_feed as engine_get_news_feed_impl,
+    engine_get_recent_dreams as engine_get_recent_dreams_impl,
+    engine_get_chapter_summaries as engine_get_chapter_summaries_impl,
+    engine_get_pending_proposals as engine_get_pending_proposals_impl,
+    engine_respond_to_proposal as engine_respond_to_proposal_impl,
+    engine_get_major_characters as engine_get_major_characters_impl,
+    engine_get_pending_check_ins as engine_get_pending_check_ins_impl,
+    engine_respond_to_check_in as engine_respond_to_check_in_impl,
+    engine_get_year_in_review as engine_get_year_in_review_impl,
+    engine_set_choice_undo as engine_set_choice_undo_impl,
+    engine_undo_last_choice as engine_undo_last_choice_impl,
+    engine_get_action_budget as engine_get_action_budget_impl,
+    engine_get_underworld_snapshot as engine_get_underworld_snapshot_impl,
+    engine_inject_event as engine_inject_event_impl,
+    engine_get_narrative_log as engine_get_narrative_log_impl,
+    engine_poll_events as engine_poll_events_impl,
+    engine_force_fire_storylet as engine_force_fire_storylet_impl,
+    engine_get_heat_forecast as engine_get_heat_forecast_impl,
+    engine_get_world_stats as engine_get_world_stats_impl,
+    engine_export_relationship_network as engine_export_relationship_network_impl,
+    engine_last_panic_info as engine_last_panic_info_impl,
+    engine_search_memories as engine_search_memories_impl,
+    engine_export_journal as engine_export_journal_impl,
+    engine_set_note as engine_set_note_impl,
+    engine_get_rival_actions as engine_get_rival_actions_impl,
+    engine_get_jealousy_events as engine_get_jealousy_events_impl,
+    engine_get_opinion_shifts as engine_get_opinion_shifts_impl,
+    engine_get_encounters as engine_get_encounters_impl,
+    engine_get_life_goals as engine_get_life_goals_impl,
+    engine_get_life_goal_options as engine_get_life_goal_options_impl,
+    engine_choose_life_goal as engine_choose_life_goal_impl,
+    engine_get_dependents as engine_get_dependents_impl,
+    engine_adopt_dependent as engine_adopt_dependent_impl,
+    engine_care_for_dependent as engine_care_for_dependent_impl,
+    engine_get_difficulty_status as engine_get_difficulty_status_impl,
+    engine_set_dynamic_difficulty as engine_set_dynamic_difficulty_impl,
+    engine_session_start as engine_session_start_impl,
+    engine_session_end as engine_session_end_impl,
+    engine_set_quantized_floats as engine_set_quantized_floats_impl,
+    engine_get_post_life as engine_get_post_life_impl,
+    engine_post_life_commune as engine_post_life_commune_impl,
+    engine_post_life_nudge as engine_post_life_nudge_impl,
+    engine_init_profiles as engine_init_profiles_impl,
+    engine_create_profile as engine_create_profile_impl,
+    engine_list_profiles as engine_list_profiles_impl,
+    engine_switch_profile as engine_switch_profile_impl,
+    engine_delete_profile as engine_delete_profile_impl,
+    engine_get_relationship_changes as engine_get_relationship_changes_impl,
+    engine_poll_relationship_events as engine_poll_relationship_events_impl,
+    ffi_guard,
     
     // API types used in function signatures
+    ApiActionBudget,
+    ApiCastOverride,
+    ApiChapterSummary,
+    ApiChoiceSubmission,
+    ApiCheckIn,
+    ApiContentPreferences,
+    ApiDependent,
+    ApiDifficultyStatus,
+    ApiDreamEvent,
+    ApiEncounter,
+    ApiForcedFire,
+    ApiHeatForecast,
+    ApiInjectedEvent,
+    ApiJealousyEvent,
+    ApiJournalExport,
+    ApiLifeGoal,
+    ApiLifeGoalOption,
+    ApiMajorCharacter,
+    ApiMemorySearchQuery,
+    ApiMontageFrame,
+    ApiMemorySearchResults,
+    ApiNarrativeLogEntry,
+    ApiNewsItem,
+    ApiOpinionShift,
     ApiPlayerConfig,
+    ApiPostLife,
+    ApiProfile,
+    ApiRelationshipChanges,
+    ApiRelationshipEvents,
+    ApiRelationshipProposal,
+    ApiRivalAction,
     ApiSimpleGameState,
+    ApiTickProgress,
+    ApiUnderworldSnapshot,
+    ApiWorldEvent,
+    ApiWorldStats,
+    ApiYearInReview,
     ApiDirectorEventView,
+    ApiDirectorPreview,
+    ApiError,
+    ApiPanicInfo,
     ApiDirectorChoiceView,
     ApiGameStateSnapshot,
 };
+#[cfg(feature = "dev-api")]
+use crate::{
+    engine_dev_apply_outcome_batch as engine_dev_apply_outcome_batch_impl,
+    engine_dev_import_journal as engine_dev_import_journal_impl,
+    ApiJournalImportResult,
+    ApiOutcomeBatchResult,
+};
+use crate::frb_generated::StreamSink;
 use flutter_rust_bridge::frb;
 
 // ==================== Game Initialization ====================
@@ -38,8 +148,11 @@ use flutter_rust_bridge::frb;
 /// * `seed` - World seed for deterministic generation
 /// * `config` - Player configuration (name, archetype, difficulty, etc.)
 #[frb(sync)]
-pub fn engine_new_game(seed: u64, config: ApiPlayerConfig) -> Option<ApiSimpleGameState> {
-    engine_new_game_impl(seed, config)
+pub fn engine_new_game(
+    seed: u64,
+    config: ApiPlayerConfig,
+) -> Result<Option<ApiSimpleGameState>, ApiError> {
+    ffi_guard("engine_new_game", || engine_new_game_impl(seed, config))
 }
 
 // ==================== Simulation Stepping ====================
@@ -51,14 +164,14 @@ pub fn engine_new_game(seed: u64, config: ApiPlayerConfig) -> Option<ApiSimpleGa
 /// # Arguments
 /// * `ticks` - Number of ticks to advance
 #[frb(sync)]
-pub fn engine_step(ticks: u32) -> Option<ApiSimpleGameState> {
-    engine_step_impl(ticks)
+pub fn engine_step(ticks: u32) -> Result<Option<ApiSimpleGameState>, ApiError> {
+    ffi_guard("engine_step", || engine_step_impl(ticks))
 }
 
 /// Tick the engine by 1 tick (convenience wrapper).
 #[frb(sync)]
-pub fn engine_tick() {
-    engine_tick_impl()
+pub fn engine_tick() -> Result<(), ApiError> {
+    ffi_guard("engine_tick", engine_tick_impl)
 }
 
 /// Advance the simulation by multiple ticks (convenience wrapper).
@@ -66,8 +179,46 @@ pub fn engine_tick() {
 /// # Arguments
 /// * `count` - Number of ticks to advance
 #[frb(sync)]
-pub fn engine_tick_many(count: u32) {
-    engine_tick_many_impl(count)
+pub fn engine_tick_many(count: u32) -> Result<(), ApiError> {
+    ffi_guard("engine_tick_many", || engine_tick_many_impl(count))
+}
+
+/// Advance the simulation by multiple ticks on a worker thread, streaming
+/// progress as it goes.
+///
+/// The engine is unlocked between chunks, so sync calls made meanwhile (a
+/// state snapshot for the progress screen, say) wait for one chunk at most.
+/// Closing the stream stops the batch early. Returns the ticks advanced.
+///
+/// # Arguments
+/// * `count` - Number of ticks to advance
+/// * `progress` - Receives an [`ApiTickProgress`] after every chunk
+pub fn engine_tick_many_async(
+    count: u32,
+    progress: StreamSink<ApiTickProgress>,
+) -> Result<u32, ApiError> {
+    ffi_guard("engine_tick_many_async", || {
+        engine_tick_many_chunked_impl(count, |p| progress.add(p).is_ok())
+    })
+}
+
+/// Skip ahead up to `max_ticks` ticks on a worker thread, streaming a
+/// montage frame at every day boundary, fired storylet and milestone.
+///
+/// Closing the stream stops the skip early. Returns the ticks advanced.
+///
+/// # Arguments
+/// * `max_ticks` - Longest skip, in ticks
+/// * `stop_on_event` - End the skip once an event is waiting for the player
+/// * `frames` - Receives an [`ApiMontageFrame`] per montage beat
+pub fn engine_fast_forward_async(
+    max_ticks: u32,
+    stop_on_event: bool,
+    frames: StreamSink<ApiMontageFrame>,
+) -> Result<u32, ApiError> {
+    ffi_guard("engine_fast_forward_async", || {
+        engine_fast_forward_chunked_impl(max_ticks, stop_on_event, |f| frames.add(f).is_ok())
+    })
 }
 
 // ==================== Event/Storylet Interaction ====================
@@ -85,24 +236,120 @@ pub fn engine_choose_option(
     storylet_id: String,
     choice_id: String,
     ticks: u32,
-) -> Option<ApiSimpleGameState> {
-    engine_choose_option_impl(storylet_id, choice_id, ticks)
+) -> Result<Option<ApiSimpleGameState>, ApiError> {
+    ffi_guard("engine_choose_option", || {
+        engine_choose_option_impl(storylet_id, choice_id, ticks)
+    })
+}
+
+/// Submit a choice for a presented event, rejecting stale or duplicate submissions.
+///
+/// Nothing is applied when `rejection` is set, so UI retries are safe.
+///
+/// # Arguments
+/// * `event_token` - Token of the event view the player chose from
+/// * `storylet_id` - ID of the current storylet/event
+/// * `choice_id` - ID of the selected choice
+/// * `ticks` - Number of ticks to advance after applying the choice
+#[frb(sync)]
+pub fn api_submit_choice(
+    event_token: u64,
+    storylet_id: String,
+    choice_id: String,
+    ticks: u32,
+) -> Result<ApiChoiceSubmission, ApiError> {
+    ffi_guard("api_submit_choice", || {
+        api_submit_choice_impl(event_token, storylet_id, choice_id, ticks)
+    })
+}
+
+/// Opt in to (or out of) single-step choice undo.
+///
+/// # Arguments
+/// * `enabled` - Whether choices should record an undo; disabling drops any pending one
+#[frb(sync)]
+pub fn engine_set_choice_undo(enabled: bool) -> Result<(), ApiError> {
+    ffi_guard("engine_set_choice_undo", || {
+        engine_set_choice_undo_impl(enabled)
+    })
+}
+
+/// Roll back the last choice, if still possible.
+///
+/// Valid only until time advances, so choose with `ticks = 0` to keep the
+/// undo window open. Returns whether a choice was undone.
+#[frb(sync)]
+pub fn engine_undo_last_choice() -> Result<bool, ApiError> {
+    ffi_guard("engine_undo_last_choice", engine_undo_last_choice_impl)
 }
 
 /// Get current storylet/event card for UI display.
 ///
 /// Returns the next eligible storylet, or None if no events are available.
 #[frb(sync)]
-pub fn get_current_storylet() -> Option<ApiDirectorEventView> {
-    get_current_storylet_impl()
+pub fn get_current_storylet() -> Result<Option<ApiDirectorEventView>, ApiError> {
+    ffi_guard("get_current_storylet", get_current_storylet_impl)
+}
+
+/// Select and present the current event on a worker thread.
+///
+/// Same result as [`get_current_storylet`], without holding up the UI thread
+/// while the director scores a large library.
+pub fn api_get_current_event_async() -> Result<Option<ApiDirectorEventView>, ApiError> {
+    ffi_guard("api_get_current_event_async", api_get_current_event_impl)
 }
 
 /// Get available choices for the current event.
 ///
 /// Returns empty vector if no event is active.
 #[frb(sync)]
-pub fn get_available_choices() -> Vec<ApiDirectorChoiceView> {
-    get_available_choices_impl()
+pub fn get_available_choices() -> Result<Vec<ApiDirectorChoiceView>, ApiError> {
+    ffi_guard("get_available_choices", get_available_choices_impl)
+}
+
+/// Preview the next event and the weight of every eligible storylet.
+///
+/// Read-only: safe to poll from the dev "what's next" panel.
+#[frb(sync)]
+pub fn api_preview_next_event() -> Result<ApiDirectorPreview, ApiError> {
+    ffi_guard("api_preview_next_event", api_preview_next_event_impl)
+}
+
+// ==================== Memory Journal ====================
+
+/// Search the player's memory journal for the journal search box.
+///
+/// # Arguments
+/// * `query` - Text, tag, participant and intensity filters plus pagination
+#[frb(sync)]
+pub fn engine_search_memories(
+    query: ApiMemorySearchQuery,
+) -> Result<ApiMemorySearchResults, ApiError> {
+    ffi_guard("engine_search_memories", || {
+        engine_search_memories_impl(query)
+    })
+}
+
+/// Export the player's journal for offline storage.
+///
+/// The export carries a `schema_version`; entries have resolved NPC names
+/// and readable timestamps.
+#[frb(sync)]
+pub fn engine_export_journal() -> Result<Option<ApiJournalExport>, ApiError> {
+    ffi_guard("engine_export_journal", engine_export_journal_impl)
+}
+
+/// Set the player's note on an NPC or journal entry.
+///
+/// Notes come back on `NpcDto`, `MemoryDto` and journal entries, are saved
+/// with the game, and never affect the simulation.
+///
+/// # Arguments
+/// * `target` - `npc:<id>` or `memory:<id>`
+/// * `text` - Note text; blank clears the note
+#[frb(sync)]
+pub fn engine_set_note(target: String, text: String) -> Result<bool, ApiError> {
+    ffi_guard("engine_set_note", || engine_set_note_impl(target, text))
 }
 
 // ==================== State Accessors ====================
@@ -112,6 +359,533 @@ pub fn get_available_choices() -> Vec<ApiDirectorChoiceView> {
 /// This is the primary comprehensive state accessor Flutter should use
 /// for full UI rendering (includes narrative heat, karma bands, etc.).
 #[frb(sync)]
-pub fn get_game_state_snapshot() -> Option<ApiGameStateSnapshot> {
-    get_game_state_snapshot_impl()
+pub fn get_game_state_snapshot() -> Result<Option<ApiGameStateSnapshot>, ApiError> {
+    ffi_guard("get_game_state_snapshot", get_game_state_snapshot_impl)
+}
+
+// ==================== Settings ====================
+
+/// Get the player's content preferences (blocked domains/tags).
+#[frb(sync)]
+pub fn engine_get_content_preferences() -> Result<ApiContentPreferences, ApiError> {
+    ffi_guard(
+        "engine_get_content_preferences",
+        engine_get_content_preferences_impl,
+    )
+}
+
+/// Set the player's content preferences.
+///
+/// Returns false if the preferences would block mandatory stage-entry content.
+///
+/// # Arguments
+/// * `prefs` - Domains and tags to exclude from storylet selection
+#[frb(sync)]
+pub fn engine_set_content_preferences(prefs: ApiContentPreferences) -> Result<bool, ApiError> {
+    ffi_guard("engine_set_content_preferences", || {
+        engine_set_content_preferences_impl(prefs)
+    })
+}
+
+// ==================== World ====================
+
+/// Get the ambient world news feed (newest first).
+///
+/// # Arguments
+/// * `limit` - Maximum number of headlines to return
+#[frb(sync)]
+pub fn engine_get_news_feed(limit: u32) -> Result<Vec<ApiNewsItem>, ApiError> {
+    ffi_guard("engine_get_news_feed", || engine_get_news_feed_impl(limit))
+}
+
+/// Get the player's most recent dreams and flashbacks (newest first).
+///
+/// # Arguments
+/// * `limit` - Maximum number of dreams to return
+#[frb(sync)]
+pub fn engine_get_recent_dreams(limit: u32) -> Result<Vec<ApiDreamEvent>, ApiError> {
+    ffi_guard("engine_get_recent_dreams", || {
+        engine_get_recent_dreams_impl(limit)
+    })
+}
+
+/// Get the player's closed life chapters (oldest first) for the timeline.
+///
+/// A chapter closes at every birthday and life stage transition.
+#[frb(sync)]
+pub fn engine_get_chapter_summaries() -> Result<Vec<ApiChapterSummary>, ApiError> {
+    ffi_guard(
+        "engine_get_chapter_summaries",
+        engine_get_chapter_summaries_impl,
+    )
+}
+
+// ==================== Profiles ====================
+
+/// Enable named worlds, each stored under `root_dir/<name>`.
+/// Returns false if already enabled.
+///
+/// # Arguments
+/// * `root_dir` - Directory holding every profile's storage directory
+#[frb(sync)]
+pub fn engine_init_profiles(root_dir: String) -> Result<bool, ApiError> {
+    ffi_guard("engine_init_profiles", || engine_init_profiles_impl(root_dir))
+}
+
+/// Create a profile with a fresh world. The first profile becomes active.
+///
+/// # Arguments
+/// * `name` - 1-64 ASCII letters, digits, `-` or `_`
+/// * `seed` - World seed
+#[frb(sync)]
+pub fn engine_create_profile(name: String, seed: u64) -> Result<Option<ApiProfile>, ApiError> {
+    ffi_guard("engine_create_profile", || {
+        engine_create_profile_impl(name, seed)
+    })
+}
+
+/// List every profile, ordered by name.
+#[frb(sync)]
+pub fn engine_list_profiles() -> Result<Vec<ApiProfile>, ApiError> {
+    ffi_guard("engine_list_profiles", engine_list_profiles_impl)
+}
+
+/// Switch the running world to another profile.
+///
+/// # Arguments
+/// * `name` - Profile to make active
+#[frb(sync)]
+pub fn engine_switch_profile(name: String) -> Result<bool, ApiError> {
+    ffi_guard("engine_switch_profile", || engine_switch_profile_impl(name))
+}
+
+/// Delete an inactive profile and its storage directory.
+///
+/// # Arguments
+/// * `name` - Profile to delete
+#[frb(sync)]
+pub fn engine_delete_profile(name: String) -> Result<bool, ApiError> {
+    ffi_guard("engine_delete_profile", || engine_delete_profile_impl(name))
+}
+
+// ==================== Relationships ====================
+
+/// Get only the player relationships that changed at or after a tick.
+///
+/// Cheaper than a full snapshot for polling: pass the `tick` of the previous
+/// result as `since_tick` (0 for everything that ever changed).
+///
+/// # Arguments
+/// * `since_tick` - Tick of the last result the UI applied
+#[frb(sync)]
+pub fn engine_get_relationship_changes(
+    since_tick: u64,
+) -> Result<Option<ApiRelationshipChanges>, ApiError> {
+    ffi_guard("engine_get_relationship_changes", || {
+        engine_get_relationship_changes_impl(since_tick)
+    })
+}
+
+/// Relationship band shifts and milestones the UI hasn't shown yet, for
+/// toasts like "Your friendship with Maya is under strain".
+///
+/// Each event is returned once. Band shifts stay queued for the director.
+#[frb(sync)]
+pub fn engine_poll_relationship_events() -> Result<Option<ApiRelationshipEvents>, ApiError> {
+    ffi_guard(
+        "engine_poll_relationship_events",
+        engine_poll_relationship_events_impl,
+    )
+}
+
+/// Get the proposals NPCs have made to the player (oldest first).
+///
+/// Each is also offered as a "proposal:<id>" event card with "accept" and
+/// "reject" choices.
+#[frb(sync)]
+pub fn engine_get_pending_proposals() -> Result<Vec<ApiRelationshipProposal>, ApiError> {
+    ffi_guard(
+        "engine_get_pending_proposals",
+        engine_get_pending_proposals_impl,
+    )
+}
+
+/// Accept or reject a pending proposal. Returns false if it is gone.
+///
+/// # Arguments
+/// * `proposal_id` - Id from `engine_get_pending_proposals`
+/// * `accept` - Whether the player says yes
+#[frb(sync)]
+pub fn engine_respond_to_proposal(proposal_id: u64, accept: bool) -> Result<bool, ApiError> {
+    ffi_guard("engine_respond_to_proposal", || {
+        engine_respond_to_proposal_impl(proposal_id, accept)
+    })
+}
+
+/// Get the major characters, authored or promoted by shared memories.
+///
+/// Major characters are cast first, drift more slowly and check in on the
+/// player periodically.
+#[frb(sync)]
+pub fn engine_get_major_characters() -> Result<Vec<ApiMajorCharacter>, ApiError> {
+    ffi_guard("engine_get_major_characters", engine_get_major_characters_impl)
+}
+
+/// Get the check-ins major characters are waiting on (oldest first).
+///
+/// Each is also offered as a "check_in:<id>" event card with "catch_up" and
+/// "brush_off" choices.
+#[frb(sync)]
+pub fn engine_get_pending_check_ins() -> Result<Vec<ApiCheckIn>, ApiError> {
+    ffi_guard(
+        "engine_get_pending_check_ins",
+        engine_get_pending_check_ins_impl,
+    )
+}
+
+/// Answer a pending check-in. Returns false if it is gone.
+///
+/// # Arguments
+/// * `check_in_id` - Id from `engine_get_pending_check_ins`
+/// * `catch_up` - Whether the player makes time for them
+#[frb(sync)]
+pub fn engine_respond_to_check_in(check_in_id: u64, catch_up: bool) -> Result<bool, ApiError> {
+    ffi_guard("engine_respond_to_check_in", || {
+        engine_respond_to_check_in_impl(check_in_id, catch_up)
+    })
+}
+
+/// Get the recap of a finished calendar year, or None if it hasn't ended.
+///
+/// Computed on the device at each January 1 from the journal, mood, karma
+/// and storylet history: most-seen NPC, dominant mood, biggest karma swing
+/// and rarest storylet fired.
+///
+/// # Arguments
+/// * `year` - Calendar year, 1 being the year the game started in
+#[frb(sync)]
+pub fn engine_get_year_in_review(year: u32) -> Result<Option<ApiYearInReview>, ApiError> {
+    ffi_guard("engine_get_year_in_review", || {
+        engine_get_year_in_review_impl(year)
+    })
+}
+
+/// Get recent hostile actions rivals took against the player (oldest first).
+///
+/// Each also leaves a "rival_action" memory in the player's journal.
+#[frb(sync)]
+pub fn engine_get_rival_actions() -> Result<Vec<ApiRivalAction>, ApiError> {
+    ffi_guard("engine_get_rival_actions", engine_get_rival_actions_impl)
+}
+
+/// Get recent jealous reactions to the player's romances (oldest first).
+///
+/// Confrontations also leave a "jealousy" memory in the player's journal.
+#[frb(sync)]
+pub fn engine_get_jealousy_events() -> Result<Vec<ApiJealousyEvent>, ApiError> {
+    ffi_guard("engine_get_jealousy_events", engine_get_jealousy_events_impl)
+}
+
+/// Get recent third-party opinion shifts (oldest first): NPCs who saw or
+/// heard how someone treated another and think differently of them now.
+#[frb(sync)]
+pub fn engine_get_opinion_shifts() -> Result<Vec<ApiOpinionShift>, ApiError> {
+    ffi_guard("engine_get_opinion_shifts", engine_get_opinion_shifts_impl)
+}
+
+/// Get recent first meetings with new NPCs (oldest first).
+///
+/// Each also leaves a "first_meeting" memory in the player's journal, and
+/// the NPC joins the player's known NPCs.
+#[frb(sync)]
+pub fn engine_get_encounters() -> Result<Vec<ApiEncounter>, ApiError> {
+    ffi_guard("engine_get_encounters", engine_get_encounters_impl)
+}
+
+// ==================== Player ====================
+
+/// Get the player's life goals with progress.
+///
+/// Achieved and failed goals stay listed; their payoff or failure storylet
+/// is delivered as the next event.
+#[frb(sync)]
+pub fn engine_get_life_goals() -> Result<Vec<ApiLifeGoal>, ApiError> {
+    ffi_guard("engine_get_life_goals", engine_get_life_goals_impl)
+}
+
+/// Get the goals offered at character creation.
+#[frb(sync)]
+pub fn engine_get_life_goal_options() -> Result<Vec<ApiLifeGoalOption>, ApiError> {
+    ffi_guard(
+        "engine_get_life_goal_options",
+        engine_get_life_goal_options_impl,
+    )
+}
+
+/// Take on a life goal. Returns false if it cannot be adopted.
+///
+/// # Arguments
+/// * `goal_id` - Id from `engine_get_life_goal_options`
+#[frb(sync)]
+pub fn engine_choose_life_goal(goal_id: String) -> Result<bool, ApiError> {
+    ffi_guard("engine_choose_life_goal", || {
+        engine_choose_life_goal_impl(goal_id)
+    })
+}
+
+/// Get the pets, plants and younger siblings the player has taken in.
+///
+/// Lost dependents stay in the list with `lost` set.
+#[frb(sync)]
+pub fn engine_get_dependents() -> Result<Vec<ApiDependent>, ApiError> {
+    ffi_guard("engine_get_dependents", engine_get_dependents_impl)
+}
+
+/// Take in a new dependent. Returns its id, or None for an unknown kind.
+///
+/// # Arguments
+/// * `kind` - "pet", "plant" or "younger_sibling"
+/// * `name` - Display name
+#[frb(sync)]
+pub fn engine_adopt_dependent(kind: String, name: String) -> Result<Option<u64>, ApiError> {
+    ffi_guard("engine_adopt_dependent", || {
+        engine_adopt_dependent_impl(kind, name)
+    })
+}
+
+/// Look after a dependent, spending action points. Returns false if it is
+/// gone or the player is out of energy.
+///
+/// # Arguments
+/// * `dependent_id` - Id from `engine_get_dependents`
+#[frb(sync)]
+pub fn engine_care_for_dependent(dependent_id: u64) -> Result<bool, ApiError> {
+    ffi_guard("engine_care_for_dependent", || {
+        engine_care_for_dependent_impl(dependent_id)
+    })
+}
+
+/// Get the base difficulty, the dynamic controller's knobs and its recent
+/// decisions.
+#[frb(sync)]
+pub fn engine_get_difficulty_status() -> Result<Option<ApiDifficultyStatus>, ApiError> {
+    ffi_guard("engine_get_difficulty_status", engine_get_difficulty_status_impl)
+}
+
+/// Turn the dynamic difficulty controller on or off. Returns false if no
+/// game is running.
+///
+/// # Arguments
+/// * `enabled` - Whether the controller may adjust difficulty
+#[frb(sync)]
+pub fn engine_set_dynamic_difficulty(enabled: bool) -> Result<bool, ApiError> {
+    ffi_guard("engine_set_dynamic_difficulty", || {
+        engine_set_dynamic_difficulty_impl(enabled)
+    })
+}
+
+/// Tell the director a play session started, so the best content lands
+/// early-to-mid session. Call when the app comes to the foreground.
+///
+/// # Arguments
+/// * `at_secs` - UI clock in seconds (any monotonic clock)
+#[frb(sync)]
+pub fn engine_session_start(at_secs: u64) -> Result<bool, ApiError> {
+    ffi_guard("engine_session_start", || engine_session_start_impl(at_secs))
+}
+
+/// Tell the director the play session ended. Call when the app is
+/// backgrounded; recorded lengths set when critical beats are held back.
+///
+/// # Arguments
+/// * `at_secs` - UI clock in seconds, same clock as `engine_session_start`
+#[frb(sync)]
+pub fn engine_session_end(at_secs: u64) -> Result<bool, ApiError> {
+    ffi_guard("engine_session_end", || engine_session_end_impl(at_secs))
+}
+
+/// Turn quantized float accumulation on or off. Best set right after
+/// creating a world, so every tick of the run is quantized.
+///
+/// # Arguments
+/// * `enabled` - true for quantized, false for native f32 accumulation
+#[frb(sync)]
+pub fn engine_set_quantized_floats(enabled: bool) -> Result<bool, ApiError> {
+    ffi_guard("engine_set_quantized_floats", || {
+        engine_set_quantized_floats_impl(enabled)
+    })
+}
+
+/// Get the PostLife chapter: signal, coherence, digital NPCs, nudges and
+/// the descendants who can be nudged.
+#[frb(sync)]
+pub fn engine_get_post_life() -> Result<Option<ApiPostLife>, ApiError> {
+    ffi_guard("engine_get_post_life", engine_get_post_life_impl)
+}
+
+/// Commune with a digital NPC, spending signal to restore coherence.
+/// Returns false outside PostLife or without enough signal.
+///
+/// # Arguments
+/// * `digital_npc_id` - Id from `engine_get_post_life`
+#[frb(sync)]
+pub fn engine_post_life_commune(digital_npc_id: u64) -> Result<bool, ApiError> {
+    ffi_guard("engine_post_life_commune", || {
+        engine_post_life_commune_impl(digital_npc_id)
+    })
+}
+
+/// Nudge a descendant toward storylets tagged `tag`. Returns false outside
+/// PostLife, for someone who is not a descendant, or without enough signal.
+///
+/// # Arguments
+/// * `descendant_id` - NPC id from `engine_get_post_life`'s descendants
+/// * `tag` - Storylet tag to favor, e.g. "romance" or "career"
+#[frb(sync)]
+pub fn engine_post_life_nudge(descendant_id: u64, tag: String) -> Result<bool, ApiError> {
+    ffi_guard("engine_post_life_nudge", || {
+        engine_post_life_nudge_impl(descendant_id, tag)
+    })
+}
+
+/// Get the player's remaining action points for today.
+#[frb(sync)]
+pub fn engine_get_action_budget() -> Result<Option<ApiActionBudget>, ApiError> {
+    ffi_guard("engine_get_action_budget", engine_get_action_budget_impl)
+}
+
+/// Get the player's underworld exposure and what it currently unlocks.
+#[frb(sync)]
+pub fn engine_get_underworld_snapshot() -> Result<Option<ApiUnderworldSnapshot>, ApiError> {
+    ffi_guard(
+        "engine_get_underworld_snapshot",
+        engine_get_underworld_snapshot_impl,
+    )
+}
+
+/// Get population statistics: age distribution, employment by district,
+/// average warmth toward the player, tier counts and dormant population.
+#[frb(sync)]
+pub fn engine_get_world_stats() -> Result<Option<ApiWorldStats>, ApiError> {
+    ffi_guard("engine_get_world_stats", engine_get_world_stats_impl)
+}
+
+/// Export the player's relationship network for the relationship map.
+///
+/// # Arguments
+/// * `format` - "json" (nodes/edges for the map screen) or "dot" (GraphViz)
+#[frb(sync)]
+pub fn engine_export_relationship_network(format: String) -> Result<Option<String>, ApiError> {
+    ffi_guard("engine_export_relationship_network", || {
+        engine_export_relationship_network_impl(format)
+    })
+}
+
+// ==================== External Events ====================
+
+/// Inject a custom external event into the running world.
+///
+/// # Arguments
+/// * `kind` - One of "world_flag", "heat", "memory", "trigger"
+/// * `payload` - JSON payload for that kind
+#[frb(sync)]
+pub fn engine_inject_event(kind: String, payload: String) -> Result<ApiInjectedEvent, ApiError> {
+    ffi_guard("engine_inject_event", || {
+        engine_inject_event_impl(kind, payload)
+    })
+}
+
+/// Get the narrative log of injected events (newest first).
+///
+/// # Arguments
+/// * `limit` - Maximum number of entries to return
+#[frb(sync)]
+pub fn engine_get_narrative_log(limit: u32) -> Result<Vec<ApiNarrativeLogEntry>, ApiError> {
+    ffi_guard("engine_get_narrative_log", || {
+        engine_get_narrative_log_impl(limit)
+    })
+}
+
+// ==================== Event Bus ====================
+
+/// Drain relationship milestones, heat band and life stage changes and fired
+/// storylets published since the last poll, oldest first.
+#[frb(sync)]
+pub fn engine_poll_events() -> Result<Vec<ApiWorldEvent>, ApiError> {
+    ffi_guard("engine_poll_events", engine_poll_events_impl)
+}
+
+// ==================== Narrative Heat ====================
+
+/// Project narrative heat over the next few ticks for pacing hints.
+///
+/// Deterministic: the same world always forecasts the same series.
+///
+/// # Arguments
+/// * `horizon_ticks` - Ticks to project (capped at 168)
+#[frb(sync)]
+pub fn engine_get_heat_forecast(horizon_ticks: u32) -> Result<ApiHeatForecast, ApiError> {
+    ffi_guard("engine_get_heat_forecast", || {
+        engine_get_heat_forecast_impl(horizon_ticks)
+    })
+}
+
+// ==================== Forced Storylets ====================
+
+/// Fire a specific storylet now, bypassing scoring.
+///
+/// Casting, outcomes, cooldowns and logging still run; prerequisites that
+/// aren't met are returned as warnings.
+///
+/// # Arguments
+/// * `id` - Storylet id
+/// * `cast_overrides` - Roles to pin to specific NPCs before casting
+#[frb(sync)]
+pub fn engine_force_fire_storylet(
+    id: String,
+    cast_overrides: Vec<ApiCastOverride>,
+) -> Result<ApiForcedFire, ApiError> {
+    ffi_guard("engine_force_fire_storylet", || {
+        engine_force_fire_storylet_impl(id, cast_overrides)
+    })
+}
+
+// ==================== Dev Tools ====================
+
+/// Apply a scripted batch of storylet outcomes as one transaction.
+///
+/// Only built with the `dev-api` feature.
+///
+/// # Arguments
+/// * `batch_json` - JSON array of `{"storylet_id": ..., "outcome": {...}}`
+#[cfg(feature = "dev-api")]
+#[frb(sync)]
+pub fn engine_dev_apply_outcome_batch(batch_json: String) -> Result<ApiOutcomeBatchResult, ApiError> {
+    ffi_guard("engine_dev_apply_outcome_batch", || {
+        engine_dev_apply_outcome_batch_impl(batch_json)
+    })
+}
+
+/// Import a journal export into its holder's journal.
+///
+/// Only built with the `dev-api` feature.
+///
+/// # Arguments
+/// * `export_json` - A journal export, as returned by `engine_export_journal`, in JSON
+#[cfg(feature = "dev-api")]
+#[frb(sync)]
+pub fn engine_dev_import_journal(export_json: String) -> Result<ApiJournalImportResult, ApiError> {
+    ffi_guard("engine_dev_import_journal", || {
+        engine_dev_import_journal_impl(export_json)
+    })
+}
+
+// ==================== Diagnostics ====================
+
+/// Get the most recent panic caught at the FFI boundary, if any.
+///
+/// Attach this to bug reports after any call fails with `ApiError::Panic`.
+#[frb(sync)]
+pub fn engine_last_panic_info() -> Result<Option<ApiPanicInfo>, ApiError> {
+    ffi_guard("engine_last_panic_info", engine_last_panic_info_impl)
 }
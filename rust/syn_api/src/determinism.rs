@@ -0,0 +1,299 @@
+//! Determinism verification for headless runs.
+//!
+//! [`verify_determinism`] builds two [`GameEngine`]s from the same seed, feeds
+//! them the same scripted inputs, and compares their
+//! [`WorldStateSnapshot`](syn_core::WorldStateSnapshot)s
+//! every `check_interval` ticks. The first mismatch is reported as a
+//! [`Divergence`] naming the tick and the first differing snapshot field,
+//! which narrows the search for HashMap iteration or float-ordering bugs to
+//! one subsystem.
+//!
+//! The report also carries the final
+//! [`accumulator_digest`](syn_core::det_math::accumulator_digest), which
+//! golden tests compare against a recorded value so the same seed is checked
+//! across platforms, not just within one process. Set
+//! [`DeterminismConfig::float_mode`] to check the quantized mode.
+//!
+//! The `headless` example runs the check from the command line:
+//!
+//! ```text
+//! cargo run -p syn_api --no-default-features --example headless -- determinism --seed 42 --ticks 5000
+//! ```
+
+use crate::{FloatMode, GameEngine, LifeStage};
+use std::fmt;
+
+/// A single input applied to both engines before a given tick.
+#[derive(Debug, Clone, PartialEq)]
+pub enum ScriptedInput {
+    /// Register an NPC (see [`GameEngine::register_npc`]).
+    RegisterNpc {
+        /// NPC identifier.
+        npc_id: u64,
+        /// Age in years.
+        age: u32,
+        /// Job title.
+        job: String,
+        /// Home district.
+        district: String,
+    },
+    /// Overwrite a relationship (see [`GameEngine::set_relationship`]).
+    SetRelationship {
+        /// Actor holding the relationship.
+        from: u64,
+        /// Relationship target.
+        to: u64,
+        /// Affection axis.
+        affection: f32,
+        /// Trust axis.
+        trust: f32,
+        /// Attraction axis.
+        attraction: f32,
+        /// Familiarity axis.
+        familiarity: f32,
+        /// Resentment axis.
+        resentment: f32,
+    },
+    /// Record a memory (see [`GameEngine::record_memory`]).
+    RecordMemory {
+        /// NPC the memory belongs to.
+        npc_id: u64,
+        /// Event that produced the memory.
+        event_id: String,
+        /// Emotional intensity (-1.0..=1.0).
+        emotional_intensity: f32,
+    },
+    /// Override the player's life stage (see [`GameEngine::set_player_life_stage`]).
+    SetLifeStage {
+        /// New life stage.
+        stage: LifeStage,
+        /// Age in years.
+        age_years: u32,
+    },
+}
+
+impl ScriptedInput {
+    fn apply(&self, engine: &mut GameEngine) {
+        match self {
+            ScriptedInput::RegisterNpc {
+                npc_id,
+                age,
+                job,
+                district,
+            } => engine.register_npc(*npc_id, *age, job.clone(), district.clone()),
+            ScriptedInput::SetRelationship {
+                from,
+                to,
+                affection,
+                trust,
+                attraction,
+                familiarity,
+                resentment,
+            } => engine.set_relationship(
+                *from,
+                *to,
+                *affection,
+                *trust,
+                *attraction,
+                *familiarity,
+                *resentment,
+            ),
+            ScriptedInput::RecordMemory {
+                npc_id,
+                event_id,
+                emotional_intensity,
+            } => {
+                engine.record_memory(*npc_id, event_id.clone(), *emotional_intensity);
+            }
+            ScriptedInput::SetLifeStage { stage, age_years } => {
+                engine.set_player_life_stage(*stage, *age_years)
+            }
+        }
+    }
+}
+
+/// An input scheduled for a tick.
+#[derive(Debug, Clone, PartialEq)]
+pub struct ScriptedStep {
+    /// Tick at which the input is applied (before that tick is simulated).
+    pub tick: u64,
+    /// The input to apply.
+    pub input: ScriptedInput,
+}
+
+/// Settings for a determinism run.
+#[derive(Debug, Clone)]
+pub struct DeterminismConfig {
+    /// World seed shared by both engines.
+    pub seed: u64,
+    /// Number of ticks to simulate.
+    pub ticks: u64,
+    /// Compare snapshots every this many ticks (the final tick is always compared).
+    pub check_interval: u64,
+    /// Inputs applied to both engines, in tick order.
+    pub script: Vec<ScriptedStep>,
+    /// Float accumulation mode for both engines.
+    pub float_mode: FloatMode,
+}
+
+impl Default for DeterminismConfig {
+    fn default() -> Self {
+        Self {
+            seed: 42,
+            ticks: 2_000,
+            check_interval: 100,
+            script: default_script(),
+            float_mode: FloatMode::Native,
+        }
+    }
+}
+
+/// Outcome of a run in which both engines stayed identical.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct DeterminismReport {
+    /// Ticks simulated.
+    pub ticks: u64,
+    /// Snapshot comparisons performed.
+    pub checks: u32,
+    /// Accumulator digest of the final world.
+    pub digest: u64,
+}
+
+/// The first point at which two engines disagreed.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Divergence {
+    /// Tick at which the mismatch was observed.
+    pub tick: u64,
+    /// First differing [`WorldStateSnapshot`](syn_core::WorldStateSnapshot) field.
+    pub field: &'static str,
+}
+
+impl fmt::Display for Divergence {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "engines diverged at tick {} (first differing field: {})",
+            self.tick, self.field
+        )
+    }
+}
+
+impl std::error::Error for Divergence {}
+
+/// A small cast with close bonds, a painful memory, and a stage change, so the
+/// run exercises tiering, relationships, memories, and aging.
+pub fn default_script() -> Vec<ScriptedStep> {
+    let mut script: Vec<ScriptedStep> = [
+        (2, "Barista", "Downtown"),
+        (3, "Nurse", "Riverside"),
+        (4, "Mechanic", "Industrial"),
+        (5, "Teacher", "Suburbs"),
+    ]
+    .into_iter()
+    .zip(0u32..)
+    .map(|((npc_id, job, district), offset)| ScriptedStep {
+        tick: 0,
+        input: ScriptedInput::RegisterNpc {
+            npc_id,
+            age: 20 + offset * 7,
+            job: job.to_string(),
+            district: district.to_string(),
+        },
+    })
+    .collect();
+
+    for (tick, from, to, affection) in [
+        (0, 1, 2, 7.0),
+        (0, 2, 3, 9.0),
+        (0, 3, 4, 6.0),
+        (50, 4, 5, 8.5),
+    ] {
+        script.push(ScriptedStep {
+            tick,
+            input: ScriptedInput::SetRelationship {
+                from,
+                to,
+                affection,
+                trust: 4.0,
+                attraction: 0.0,
+                familiarity: 5.0,
+                resentment: 0.0,
+            },
+        });
+    }
+
+    script.push(ScriptedStep {
+        tick: 120,
+        input: ScriptedInput::RecordMemory {
+            npc_id: 3,
+            event_id: "job_loss".to_string(),
+            emotional_intensity: -0.8,
+        },
+    });
+    script.push(ScriptedStep {
+        tick: 500,
+        input: ScriptedInput::SetLifeStage {
+            stage: LifeStage::YoungAdult,
+            age_years: 19,
+        },
+    });
+    script
+}
+
+/// Run two engines in lockstep and compare them periodically.
+///
+/// Returns the first [`Divergence`], or a [`DeterminismReport`] if the engines
+/// matched at every check.
+pub fn verify_determinism(config: &DeterminismConfig) -> Result<DeterminismReport, Divergence> {
+    verify_engines(
+        GameEngine::new(config.seed),
+        GameEngine::new(config.seed),
+        config,
+    )
+}
+
+/// Like [`verify_determinism`], but with caller-built engines (e.g. to
+/// preload storylets or deliberately perturb one side).
+pub fn verify_engines(
+    mut left: GameEngine,
+    mut right: GameEngine,
+    config: &DeterminismConfig,
+) -> Result<DeterminismReport, Divergence> {
+    let mut script = config.script.clone();
+    script.sort_by_key(|step| step.tick);
+    let mut pending = script.iter().peekable();
+    let interval = config.check_interval.max(1);
+    let mut checks = 0u32;
+    left.set_float_mode(config.float_mode);
+    right.set_float_mode(config.float_mode);
+
+    for tick in 0..config.ticks {
+        while let Some(step) = pending.next_if(|step| step.tick <= tick) {
+            step.input.apply(&mut left);
+            step.input.apply(&mut right);
+        }
+
+        left.tick();
+        right.tick();
+
+        let elapsed = tick + 1;
+        if elapsed.is_multiple_of(interval) || elapsed == config.ticks {
+            checks += 1;
+            if let Some(field) = left
+                .world_snapshot()
+                .first_divergence(&right.world_snapshot())
+            {
+                return Err(Divergence {
+                    tick: left.current_tick(),
+                    field,
+                });
+            }
+        }
+    }
+
+    Ok(DeterminismReport {
+        ticks: config.ticks,
+        checks,
+        digest: left.accumulator_digest(),
+    })
+}
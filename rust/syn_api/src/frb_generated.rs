@@ -70,12 +70,12 @@ fn wire__crate__api__engine_choose_option_impl(
             let api_choice_id = <String>::sse_decode(&mut deserializer);
             let api_ticks = <u32>::sse_decode(&mut deserializer);
             deserializer.end();
-            transform_result_sse::<_, ()>((move || {
-                let output_ok = Result::<_, ()>::Ok(crate::api::engine_choose_option(
+            transform_result_sse::<_, crate::ApiError>((move || {
+                let output_ok = crate::api::engine_choose_option(
                     api_storylet_id,
                     api_choice_id,
                     api_ticks,
-                ))?;
+                )?;
                 Ok(output_ok)
             })())
         },
@@ -105,9 +105,8 @@ fn wire__crate__api__engine_new_game_impl(
             let api_seed = <u64>::sse_decode(&mut deserializer);
             let api_config = <crate::ApiPlayerConfig>::sse_decode(&mut deserializer);
             deserializer.end();
-            transform_result_sse::<_, ()>((move || {
-                let output_ok =
-                    Result::<_, ()>::Ok(crate::api::engine_new_game(api_seed, api_config))?;
+            transform_result_sse::<_, crate::ApiError>((move || {
+                let output_ok = crate::api::engine_new_game(api_seed, api_config)?;
                 Ok(output_ok)
             })())
         },
@@ -136,8 +135,8 @@ fn wire__crate__api__engine_step_impl(
                 flutter_rust_bridge::for_generated::SseDeserializer::new(message);
             let api_ticks = <u32>::sse_decode(&mut deserializer);
             deserializer.end();
-            transform_result_sse::<_, ()>((move || {
-                let output_ok = Result::<_, ()>::Ok(crate::api::engine_step(api_ticks))?;
+            transform_result_sse::<_, crate::ApiError>((move || {
+                let output_ok = crate::api::engine_step(api_ticks)?;
                 Ok(output_ok)
             })())
         },
@@ -165,10 +164,8 @@ fn wire__crate__api__engine_tick_impl(
             let mut deserializer =
                 flutter_rust_bridge::for_generated::SseDeserializer::new(message);
             deserializer.end();
-            transform_result_sse::<_, ()>((move || {
-                let output_ok = Result::<_, ()>::Ok({
-                    crate::api::engine_tick();
-                })?;
+            transform_result_sse::<_, crate::ApiError>((move || {
+                let output_ok = crate::api::engine_tick()?;
                 Ok(output_ok)
             })())
         },
@@ -197,10 +194,8 @@ fn wire__crate__api__engine_tick_many_impl(
                 flutter_rust_bridge::for_generated::SseDeserializer::new(message);
             let api_count = <u32>::sse_decode(&mut deserializer);
             deserializer.end();
-            transform_result_sse::<_, ()>((move || {
-                let output_ok = Result::<_, ()>::Ok({
-                    crate::api::engine_tick_many(api_count);
-                })?;
+            transform_result_sse::<_, crate::ApiError>((move || {
+                let output_ok = crate::api::engine_tick_many(api_count)?;
                 Ok(output_ok)
             })())
         },
@@ -228,8 +223,8 @@ fn wire__crate__api__get_available_choices_impl(
             let mut deserializer =
                 flutter_rust_bridge::for_generated::SseDeserializer::new(message);
             deserializer.end();
-            transform_result_sse::<_, ()>((move || {
-                let output_ok = Result::<_, ()>::Ok(crate::api::get_available_choices())?;
+            transform_result_sse::<_, crate::ApiError>((move || {
+                let output_ok = crate::api::get_available_choices()?;
                 Ok(output_ok)
             })())
         },
@@ -257,8 +252,8 @@ fn wire__crate__api__get_current_storylet_impl(
             let mut deserializer =
                 flutter_rust_bridge::for_generated::SseDeserializer::new(message);
             deserializer.end();
-            transform_result_sse::<_, ()>((move || {
-                let output_ok = Result::<_, ()>::Ok(crate::api::get_current_storylet())?;
+            transform_result_sse::<_, crate::ApiError>((move || {
+                let output_ok = crate::api::get_current_storylet()?;
                 Ok(output_ok)
             })())
         },
@@ -286,8 +281,8 @@ fn wire__crate__api__get_game_state_snapshot_impl(
             let mut deserializer =
                 flutter_rust_bridge::for_generated::SseDeserializer::new(message);
             deserializer.end();
-            transform_result_sse::<_, ()>((move || {
-                let output_ok = Result::<_, ()>::Ok(crate::api::get_game_state_snapshot())?;
+            transform_result_sse::<_, crate::ApiError>((move || {
+                let output_ok = crate::api::get_game_state_snapshot()?;
                 Ok(output_ok)
             })())
         },
@@ -309,9 +304,11 @@ impl SseDecode for crate::ApiDirectorChoiceView {
     fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
         let mut var_id = <String>::sse_decode(deserializer);
         let mut var_label = <String>::sse_decode(deserializer);
+        let mut var_locked = <bool>::sse_decode(deserializer);
         return crate::ApiDirectorChoiceView {
             id: var_id,
             label: var_label,
+            locked: var_locked,
         };
     }
 }
@@ -322,14 +319,38 @@ impl SseDecode for crate::ApiDirectorEventView {
         let mut var_storyletId = <String>::sse_decode(deserializer);
         let mut var_title = <String>::sse_decode(deserializer);
         let mut var_choices = <Vec<crate::ApiDirectorChoiceView>>::sse_decode(deserializer);
+        let mut var_eventToken = <u64>::sse_decode(deserializer);
+        let mut var_tone = <Vec<crate::ApiToneContext>>::sse_decode(deserializer);
         return crate::ApiDirectorEventView {
             storylet_id: var_storyletId,
             title: var_title,
             choices: var_choices,
+            event_token: var_eventToken,
+            tone: var_tone,
         };
     }
 }
 
+impl SseDecode for crate::ApiError {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut tag_ = <i32>::sse_decode(deserializer);
+        match tag_ {
+            0 => {
+                let mut var_function = <String>::sse_decode(deserializer);
+                let mut var_message = <String>::sse_decode(deserializer);
+                return crate::ApiError::Panic {
+                    function: var_function,
+                    message: var_message,
+                };
+            }
+            _ => {
+                unimplemented!("");
+            }
+        }
+    }
+}
+
 impl SseDecode for crate::ApiGameStateSnapshot {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
@@ -417,6 +438,8 @@ impl SseDecode for crate::ApiRelationship {
         let mut var_attractionBand = <String>::sse_decode(deserializer);
         let mut var_resentmentBand = <String>::sse_decode(deserializer);
         let mut var_roleLabel = <String>::sse_decode(deserializer);
+        let mut var_heat = <f32>::sse_decode(deserializer);
+        let mut var_heatTrend = <f32>::sse_decode(deserializer);
         return crate::ApiRelationship {
             actor_id: var_actorId,
             target_id: var_targetId,
@@ -430,6 +453,8 @@ impl SseDecode for crate::ApiRelationship {
             attraction_band: var_attractionBand,
             resentment_band: var_resentmentBand,
             role_label: var_roleLabel,
+            heat: var_heat,
+            heat_trend: var_heatTrend,
         };
     }
 }
@@ -498,6 +523,30 @@ impl SseDecode for crate::ApiStat {
     }
 }
 
+impl SseDecode for crate::ApiMontageFrame {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_tick = <u64>::sse_decode(deserializer);
+        let mut var_day = <u64>::sse_decode(deserializer);
+        let mut var_dayBoundary = <bool>::sse_decode(deserializer);
+        let mut var_firedStorylets = <Vec<String>>::sse_decode(deserializer);
+        let mut var_milestones = <Vec<String>>::sse_decode(deserializer);
+        let mut var_playerAgeYears = <u32>::sse_decode(deserializer);
+        let mut var_lifeStage = <String>::sse_decode(deserializer);
+        let mut var_stats = <crate::ApiStatsSnapshot>::sse_decode(deserializer);
+        return crate::ApiMontageFrame {
+            tick: var_tick,
+            day: var_day,
+            day_boundary: var_dayBoundary,
+            fired_storylets: var_firedStorylets,
+            milestones: var_milestones,
+            player_age_years: var_playerAgeYears,
+            life_stage: var_lifeStage,
+            stats: var_stats,
+        };
+    }
+}
+
 impl SseDecode for crate::ApiStatsSnapshot {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
@@ -510,6 +559,38 @@ impl SseDecode for crate::ApiStatsSnapshot {
     }
 }
 
+impl SseDecode for crate::ApiTickProgress {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_ticksDone = <u32>::sse_decode(deserializer);
+        let mut var_ticksTotal = <u32>::sse_decode(deserializer);
+        let mut var_currentTick = <u64>::sse_decode(deserializer);
+        return crate::ApiTickProgress {
+            ticks_done: var_ticksDone,
+            ticks_total: var_ticksTotal,
+            current_tick: var_currentTick,
+        };
+    }
+}
+
+impl SseDecode for crate::ApiToneContext {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_npcId = <u64>::sse_decode(deserializer);
+        let mut var_tone = <String>::sse_decode(deserializer);
+        let mut var_grudge = <f32>::sse_decode(deserializer);
+        let mut var_favor = <f32>::sse_decode(deserializer);
+        let mut var_flags = <Vec<String>>::sse_decode(deserializer);
+        return crate::ApiToneContext {
+            npc_id: var_npcId,
+            tone: var_tone,
+            grudge: var_grudge,
+            favor: var_favor,
+            flags: var_flags,
+        };
+    }
+}
+
 impl SseDecode for bool {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
@@ -598,6 +679,18 @@ impl SseDecode for Vec<crate::ApiStat> {
     }
 }
 
+impl SseDecode for Vec<crate::ApiToneContext> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut len_ = <i32>::sse_decode(deserializer);
+        let mut ans_ = vec![];
+        for idx_ in 0..len_ {
+            ans_.push(<crate::ApiToneContext>::sse_decode(deserializer));
+        }
+        return ans_;
+    }
+}
+
 impl SseDecode for Vec<u8> {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
@@ -721,6 +814,7 @@ impl flutter_rust_bridge::IntoDart for crate::ApiDirectorChoiceView {
         [
             self.id.into_into_dart().into_dart(),
             self.label.into_into_dart().into_dart(),
+            self.locked.into_into_dart().into_dart(),
         ]
         .into_dart()
     }
@@ -740,6 +834,8 @@ impl flutter_rust_bridge::IntoDart for crate::ApiDirectorEventView {
             self.storylet_id.into_into_dart().into_dart(),
             self.title.into_into_dart().into_dart(),
             self.choices.into_into_dart().into_dart(),
+            self.event_token.into_into_dart().into_dart(),
+            self.tone.into_into_dart().into_dart(),
         ]
         .into_dart()
     }
@@ -836,6 +932,8 @@ impl flutter_rust_bridge::IntoDart for crate::ApiRelationship {
             self.attraction_band.into_into_dart().into_dart(),
             self.resentment_band.into_into_dart().into_dart(),
             self.role_label.into_into_dart().into_dart(),
+            self.heat.into_into_dart().into_dart(),
+            self.heat_trend.into_into_dart().into_dart(),
         ]
         .into_dart()
     }
@@ -923,6 +1021,28 @@ impl flutter_rust_bridge::IntoIntoDart<crate::ApiStat> for crate::ApiStat {
     }
 }
 // Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::ApiMontageFrame {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.tick.into_into_dart().into_dart(),
+            self.day.into_into_dart().into_dart(),
+            self.day_boundary.into_into_dart().into_dart(),
+            self.fired_storylets.into_into_dart().into_dart(),
+            self.milestones.into_into_dart().into_dart(),
+            self.player_age_years.into_into_dart().into_dart(),
+            self.life_stage.into_into_dart().into_dart(),
+            self.stats.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive for crate::ApiMontageFrame {}
+impl flutter_rust_bridge::IntoIntoDart<crate::ApiMontageFrame> for crate::ApiMontageFrame {
+    fn into_into_dart(self) -> crate::ApiMontageFrame {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
 impl flutter_rust_bridge::IntoDart for crate::ApiStatsSnapshot {
     fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
         [
@@ -938,6 +1058,42 @@ impl flutter_rust_bridge::IntoIntoDart<crate::ApiStatsSnapshot> for crate::ApiSt
         self
     }
 }
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::ApiTickProgress {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.ticks_done.into_into_dart().into_dart(),
+            self.ticks_total.into_into_dart().into_dart(),
+            self.current_tick.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive for crate::ApiTickProgress {}
+impl flutter_rust_bridge::IntoIntoDart<crate::ApiTickProgress> for crate::ApiTickProgress {
+    fn into_into_dart(self) -> crate::ApiTickProgress {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::ApiToneContext {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.npc_id.into_into_dart().into_dart(),
+            self.tone.into_into_dart().into_dart(),
+            self.grudge.into_into_dart().into_dart(),
+            self.favor.into_into_dart().into_dart(),
+            self.flags.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive for crate::ApiToneContext {}
+impl flutter_rust_bridge::IntoIntoDart<crate::ApiToneContext> for crate::ApiToneContext {
+    fn into_into_dart(self) -> crate::ApiToneContext {
+        self
+    }
+}
 
 impl SseEncode for String {
     // Codec=Sse (Serialization based), see doc to use other codecs
@@ -951,6 +1107,7 @@ impl SseEncode for crate::ApiDirectorChoiceView {
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
         <String>::sse_encode(self.id, serializer);
         <String>::sse_encode(self.label, serializer);
+        <bool>::sse_encode(self.locked, serializer);
     }
 }
 
@@ -960,6 +1117,24 @@ impl SseEncode for crate::ApiDirectorEventView {
         <String>::sse_encode(self.storylet_id, serializer);
         <String>::sse_encode(self.title, serializer);
         <Vec<crate::ApiDirectorChoiceView>>::sse_encode(self.choices, serializer);
+        <u64>::sse_encode(self.event_token, serializer);
+        <Vec<crate::ApiToneContext>>::sse_encode(self.tone, serializer);
+    }
+}
+
+impl SseEncode for crate::ApiError {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        match self {
+            crate::ApiError::Panic { function, message } => {
+                <i32>::sse_encode(0, serializer);
+                <String>::sse_encode(function, serializer);
+                <String>::sse_encode(message, serializer);
+            }
+            _ => {
+                unimplemented!("");
+            }
+        }
     }
 }
 
@@ -1020,6 +1195,8 @@ impl SseEncode for crate::ApiRelationship {
         <String>::sse_encode(self.attraction_band, serializer);
         <String>::sse_encode(self.resentment_band, serializer);
         <String>::sse_encode(self.role_label, serializer);
+        <f32>::sse_encode(self.heat, serializer);
+        <f32>::sse_encode(self.heat_trend, serializer);
     }
 }
 
@@ -1063,6 +1240,20 @@ impl SseEncode for crate::ApiStat {
     }
 }
 
+impl SseEncode for crate::ApiMontageFrame {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <u64>::sse_encode(self.tick, serializer);
+        <u64>::sse_encode(self.day, serializer);
+        <bool>::sse_encode(self.day_boundary, serializer);
+        <Vec<String>>::sse_encode(self.fired_storylets, serializer);
+        <Vec<String>>::sse_encode(self.milestones, serializer);
+        <u32>::sse_encode(self.player_age_years, serializer);
+        <String>::sse_encode(self.life_stage, serializer);
+        <crate::ApiStatsSnapshot>::sse_encode(self.stats, serializer);
+    }
+}
+
 impl SseEncode for crate::ApiStatsSnapshot {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
@@ -1071,6 +1262,26 @@ impl SseEncode for crate::ApiStatsSnapshot {
     }
 }
 
+impl SseEncode for crate::ApiTickProgress {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <u32>::sse_encode(self.ticks_done, serializer);
+        <u32>::sse_encode(self.ticks_total, serializer);
+        <u64>::sse_encode(self.current_tick, serializer);
+    }
+}
+
+impl SseEncode for crate::ApiToneContext {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <u64>::sse_encode(self.npc_id, serializer);
+        <String>::sse_encode(self.tone, serializer);
+        <f32>::sse_encode(self.grudge, serializer);
+        <f32>::sse_encode(self.favor, serializer);
+        <Vec<String>>::sse_encode(self.flags, serializer);
+    }
+}
+
 impl SseEncode for bool {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
@@ -1149,6 +1360,16 @@ impl SseEncode for Vec<crate::ApiStat> {
     }
 }
 
+impl SseEncode for Vec<crate::ApiToneContext> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <i32>::sse_encode(self.len() as _, serializer);
+        for item in self {
+            <crate::ApiToneContext>::sse_encode(item, serializer);
+        }
+    }
+}
+
 impl SseEncode for Vec<u8> {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
@@ -9,6 +9,16 @@
 //! - [`GameRuntime`]: Shared runtime state for the director loop
 //! - API functions prefixed with `engine_*` are `#[frb(sync)]` for Flutter Rust Bridge
 //!
+//! ## Features
+//!
+//! - `ffi` (default): the flutter_rust_bridge surface — the global `RUNTIME`/`ENGINE`
+//!   statics and every free `engine_*`/`get_*` function. Disable it with
+//!   `--no-default-features` to use [`GameEngine`] from pure Rust tools without
+//!   pulling in flutter_rust_bridge or once_cell.
+//! - `dev-api`: developer-only FFI functions (`engine_dev_*`) for test harnesses
+//!   and tooling, such as applying a scripted batch of storylet outcomes or
+//!   importing a journal export.
+//!
 //! ## Primary API Functions (Flutter Core)
 //!
 //! Flutter should primarily use these functions for FRB cohesion:
@@ -16,20 +26,37 @@
 //! ### World Management
 //! - [`init_world(seed)`]: Initialize new world
 //! - [`load_world(seed)`]: Load saved world
+//! - [`engine_save(path)`] / [`engine_load(path)`]: Save the whole engine and resume it mid-life
 //! - [`step_world(ticks)`]: Advance simulation
+//! - [`engine_fast_forward_chunked(max_ticks, stop_on_event, on_frame)`]: Time-skip yielding montage frames (streamed to Dart by `api::engine_fast_forward_async`)
 //! - [`get_game_state_snapshot()`]: Get unified game state
+//! - [`engine_init_profiles(root_dir)`]: Enable named worlds; then create/list/switch/delete profiles
+//! - [`engine_set_quantized_floats(enabled)`]: Quantized float accumulation for cross-platform determinism
 //!
 //! ### Storylets & Events
 //! - [`get_current_storylet()`]: Get current event card
 //! - [`get_available_choices()`]: Get choices for current event
 //! - [`api_choose_option(storylet_id, choice_id, ticks)`]: Make choice and advance
+//! - [`api_submit_choice(event_token, storylet_id, choice_id, ticks)`]: Same, rejecting stale or duplicate submissions
+//! - [`engine_session_start(at_secs)`] / [`engine_session_end(at_secs)`]: Session markers for director pacing
+//! - [`engine_reload_storylets(path)`]: Swap in edited storylets without restarting, keeping cooldowns
+//! - [`engine_current_event()`] / [`engine_choose(storylet_id, choice_id, ticks)`]: The choice flow on the engine's own director and world
 //!
 //! ### Player Data
 //! - [`get_player_stats()`]: Get stats snapshot
 //! - [`get_player_mood()`]: Get mood value
 //! - [`get_player_karma()`]: Get karma value
 //! - [`get_relationship_network()`]: Get relationships for network view
+//! - [`engine_get_jealousy_events()`]: Admirers reacting to the player's romances
+//! - [`engine_get_opinion_shifts()`]: Bystanders rethinking someone over how they treated another
+//! - [`engine_get_relationship_changes(since_tick)`]: Only the relationships changed since a tick
+//! - [`engine_poll_relationship_events()`]: Band shifts and milestones not yet shown, for toasts
+//! - [`engine_export_relationship_network(format)`]: Social graph as JSON or GraphViz DOT
 //! - [`get_memory_journal()`]: Get memory entries for journal view
+//! - [`engine_export_journal()`]: Versioned journal export for offline storage
+//! - [`engine_get_chronicle(since_tick)`]: Fired storylets for the life timeline
+//! - [`engine_poll_events()`]: Drain milestones, heat band and life stage changes and fired storylets since the last poll
+//! - [`engine_set_note(target, text)`]: Player's own note on an NPC or journal entry
 //! - [`get_life_stage_summary()`]: Get digital legacy for end-of-life view
 //!
 //! ## DTOs
@@ -38,39 +65,91 @@
 //! - [`ApiGameStateSnapshot`]: Unified game state
 //! - [`ApiStatsSnapshot`]: Player stats
 //! - [`ApiRelationshipSnapshot`]: Player relationships with bands and roles
+//! - [`ApiRelationshipChanges`]: Player relationships changed since a tick
+//! - [`ApiRelationshipEvents`] / [`ApiRelationshipPressureEvent`] / [`ApiRelationshipMilestoneEvent`]: Band shifts and milestones for relationship toasts
 //! - [`ApiMemoryJournalEntry`]: Memory journal entries
+//! - [`ApiMemorySearchQuery`] / [`ApiMemorySearchResults`]: Journal search box
+//! - [`ApiJournalExport`]: Versioned journal export for offline storage
 //! - [`ApiDigitalLegacySnapshot`]: PostLife digital imprint data
 //! - [`ApiDirectorEventView`]: Current storylet/event
 //! - [`ApiDirectorChoiceView`]: Available choices
+//! - [`ApiToneContext`]: How an NPC in the scene feels about the player
+//! - [`ApiChoiceSubmission`] / [`ApiChoiceRejection`]: Result of a validated choice submission
+//! - [`ApiDirectorPreview`]: Dry-run of the next pick, for dev tools
 //! - [`ApiDistrictSnapshot`]: District economic/crime data
 //! - [`ApiPlayerSkillsSnapshot`]: Player skill progression
-
+//! - [`ApiUnderworldSnapshot`]: Underworld exposure and what it unlocks
+//! - [`ApiInjectedEvent`]: Result of injecting an external event
+//! - [`ApiNarrativeLogEntry`]: Injected events, for the narrative log view
+//! - [`ApiWorldEvent`]: A milestone, heat band or life stage change or fired storylet from the event bus
+//! - [`ApiWorldStats`]: Population statistics for the city overview
+//! - [`ApiMajorCharacter`] / [`ApiCheckIn`]: Major characters and their check-ins
+//! - [`ApiJealousyEvent`]: An admirer's jealous reaction to the player's romance
+//! - [`ApiOpinionShift`]: A bystander's changed view of someone
+//! - [`ApiYearInReview`]: Recap of a finished calendar year
+//! - [`ApiDependent`]: Pets, plants and younger siblings in the player's care
+//! - [`ApiDifficultyStatus`] / [`ApiDifficultyDecision`]: Difficulty and the dynamic controller's decisions
+//! - [`ApiProfile`]: A named world and its storage directory
+//! - [`ApiPostLife`] / [`ApiDigitalNpc`] / [`ApiLegacyNudge`]: The playable PostLife chapter
+//! - [`ApiHeatForecast`]: Projected narrative heat for pacing hints
+//! - [`ApiOutcomeBatchResult`]: Result of applying a scripted outcome batch
+//! - [`ApiTickProgress`]: Progress of a tick batch running off the UI thread
+//! - [`ApiMontageFrame`]: One beat of a time-skip montage
+//! - [`ApiError`]: Typed error returned across the FFI boundary
+//! - [`ApiPanicInfo`]: Details of the last panic caught at the FFI boundary
+
+#[cfg(feature = "ffi")]
 mod frb_generated; /* AUTO INJECTED BY flutter_rust_bridge. This line may not be accurate, and you can change it according to your needs. */
 
 /// FRB v2 API entrypoint module - exposes functions for flutter_rust_bridge codegen
+#[cfg(feature = "ffi")]
 pub mod api;
 
-use flutter_rust_bridge::frb;
-use once_cell::sync::Lazy;
+/// Global-state functions exported to Flutter.
+#[cfg(feature = "ffi")]
+mod ffi;
+#[cfg(feature = "ffi")]
+pub use ffi::*;
+
 use serde::{Deserialize, Serialize};
-use std::sync::Mutex;
-use syn_content::load_storylets_from_db;
+use std::path::{Path, PathBuf};
+use syn_content::{load_storylet_packs, load_storylets_from_db};
+use syn_core::player_notes::{NoteTarget, PlayerNotesState};
+use syn_core::relationship_milestones::RelationshipMilestoneEvent;
 use syn_core::relationship_model::{derive_role_label, RelationshipVector};
-use syn_director::{apply_choice_and_advance, select_next_event_view, DirectorEventView};
+use syn_core::relationship_pressure::RelationshipPressureEvent;
+use syn_core::time::{DayPhase, GameTime};
+use syn_director::{DirectorEventView, DirectorPreview};
 use syn_sim::SimState;
 
 /// Storylet library loading utilities.
 pub mod library_loader;
 
+/// Two-engine determinism verification for headless runs.
+pub mod determinism;
+
+/// Several named worlds with isolated storage in one process.
+pub mod profiles;
+pub use profiles::{ProfileError, ProfileInfo, ProfileManager};
+
 // Re-export core types for Dart
 pub use syn_core::{
-    AbstractNpc, AttachmentStyle, Karma, KarmaBand, LifeStage, MoodBand, NpcId, Relationship,
-    SimTick, StatKind, Stats, Traits, WorldSeed, WorldState, ALL_STAT_KINDS,
+    AbstractNpc, ActionBudget, AttachmentStyle, ChapterSummary, CheckIn, CheckInResolution,
+    ContentPreferences, Dependent, DependentConfig, DependentEvent, DependentKind,
+    DifficultyConfig, DifficultyDecision, DifficultyState, DigitalNpc, DigitalNpcKind, DreamEvent, Encounter, EncounterSource, ExternalEvent, HeatForecast, JealousyEvent, MajorCharacter, OpinionShift, Karma, KarmaBand,
+    LegacyNudge, LifeStage, MoodBand, NarrativeLogEntry, NewsItem, NpcId, PostLifeConfig,
+    PostLifeEvent, PostLifeEventKind, PostLifeState, ProposalResolution, PublishedEvent, Relationship,
+    RelationshipProposal, RivalAction, SessionPhase, SessionRecord, SimTick, StatKind, Stats, SynError, ToneContext, Traits,
+    UnderworldExposure, WorldEvent, WorldSeed, WorldState, YearInReview, ALL_STAT_KINDS,
 };
 pub use syn_core::character_gen::{
     CharacterArchetype, CharacterGenConfig, Difficulty, EarlyLifeEvent, FamilyStructure,
     GeneratedCharacter, SocioeconomicTier, generate_character,
 };
+pub use syn_core::life_goals::{
+    life_goal_catalog, GoalRequirement, GoalStatus, LifeGoal, TrackedGoal,
+};
+pub use syn_core::det_math::FloatMode;
 pub use syn_core::district::{
     CrimeLevel, District, DistrictId, DistrictRegistry, DistrictType, EconomicTier,
 };
@@ -79,11 +158,15 @@ pub use syn_core::skills::{
     SkillState, SkillTier,
 };
 pub use syn_director::{
-    tags_to_bitset, EventDirector, Storylet, StoryletChoice, StoryletCooldown, StoryletLibrary,
+    tags_to_bitset, ChoiceUndo, Chronicle, ChronicleEntry, CooldownEntry, EventDirector, ForceFireError, ForcedFire, LibraryReload, OutcomeBatchError,
+    OutcomeBatchReport, Storylet, StoryletChoice, StoryletCooldown, StoryletLibrary,
     StoryletOutcome, StoryletOutcomeSet, StoryletRole,
 };
-pub use syn_memory::{Journal, MemoryEntry, MemorySystem};
-pub use syn_query::{ClusterQuery, NpcQuery, RelationshipQuery, StatQuery};
+pub use syn_memory::{
+    Journal, MemoryEntry, MemoryHit, MemoryIntensityContext, MemoryQuery, MemorySearchPage,
+    MemorySystem,
+};
+pub use syn_query::{ClusterQuery, NpcQuery, RelationshipNetwork, RelationshipQuery, StatQuery};
 // Note: LodTier and Simulator are deprecated - use NpcTier and tick_simulation instead
 #[allow(deprecated)]
 pub use syn_sim::{LodTier, Simulator};
@@ -114,6 +197,14 @@ pub struct GameEngine {
     director: EventDirector,
     /// The memory system tracking NPC memories.
     memory: MemorySystem,
+    /// The event last shown through [`GameEngine::current_event_view`].
+    presentation: EventPresentation,
+    /// Pressure events already handed to the UI; they stay queued for the
+    /// director.
+    surfaced_pressure: Vec<RelationshipPressureEvent>,
+    /// Directory holding this engine's simulation storage and relative save
+    /// paths; `None` uses the shared `data` directory.
+    storage_dir: Option<PathBuf>,
 }
 
 /// Shared runtime state for the director loop.
@@ -127,119 +218,334 @@ pub struct GameRuntime {
     pub sim: SimState,
     /// The loaded storylet library.
     pub storylets: StoryletLibrary,
+    /// Whether choices record a single-step undo (opt-in).
+    pub undo_enabled: bool,
+    /// Undo for the last choice, valid until the world's tick advances.
+    pub last_choice_undo: Option<ChoiceUndo>,
+    /// The event currently shown to the player, for validating submissions.
+    pub presentation: EventPresentation,
 }
 
-/// Default storylet database filename.
-const DEFAULT_STORYLET_DB: &str = "storylets.sqlite";
+/// An event view handed to the UI, awaiting a choice.
+#[derive(Debug, Clone, PartialEq)]
+struct PresentedEvent {
+    token: u64,
+    storylet_id: String,
+    choice_ids: Vec<String>,
+}
+
+/// Tracks the event last presented to the player.
+///
+/// Every presented view carries an idempotency token. A submission must name
+/// the presented storylet, one of its choices and (when given) its token;
+/// the token is consumed on success so UI retries can't apply a choice twice.
+#[derive(Debug, Clone, Default)]
+pub struct EventPresentation {
+    current: Option<PresentedEvent>,
+    last_token: u64,
+    last_consumed: Option<u64>,
+}
+
+impl EventPresentation {
+    /// Record `view` as presented and stamp its token.
+    ///
+    /// Re-presenting the same storylet with the same choices keeps the token,
+    /// so polling the current event doesn't invalidate an in-flight choice.
+    pub fn present(&mut self, mut view: ApiDirectorEventView) -> ApiDirectorEventView {
+        let choice_ids: Vec<String> = view
+            .choices
+            .iter()
+            .filter(|c| !c.locked)
+            .map(|c| c.id.clone())
+            .collect();
+        let token = match &self.current {
+            Some(current)
+                if current.storylet_id == view.storylet_id && current.choice_ids == choice_ids =>
+            {
+                current.token
+            }
+            _ => {
+                self.last_token += 1;
+                self.last_token
+            }
+        };
+        self.current = Some(PresentedEvent {
+            token,
+            storylet_id: view.storylet_id.clone(),
+            choice_ids,
+        });
+        view.event_token = token;
+        view
+    }
 
-/// Lazily-initialized global runtime for FRB director loop functions.
-static RUNTIME: Lazy<Mutex<GameRuntime>> = Lazy::new(|| {
-    let world = WorldState::new(WorldSeed::new(0), NpcId(1));
-    let sim = SimState::new();
-    let storylets = StoryletLibrary::load_default().unwrap_or_default();
+    /// Forget the presented event (nothing is eligible right now).
+    pub fn clear(&mut self) {
+        self.current = None;
+    }
 
-    Mutex::new(GameRuntime {
-        world,
-        sim,
-        storylets,
-    })
-});
+    /// Token of the presented event, if any.
+    pub fn current_token(&self) -> Option<u64> {
+        self.current.as_ref().map(|c| c.token)
+    }
 
-/// Loads storylets from database and registers them with the event director.
-fn register_storylets_from_db(director: &mut EventDirector) {
-    let db_path =
-        std::env::var("SYN_STORYLET_DB").unwrap_or_else(|_| DEFAULT_STORYLET_DB.to_string());
-    match load_storylets_from_db(&db_path) {
-        Ok(storylets) => {
-            for content_storylet in storylets {
-                let tag_list = content_storylet.prerequisites.tags.clone();
-                // Convert syn_content::Storylet to syn_director::Storylet
-                let mut prereqs = syn_director::StoryletPrerequisites::default();
-                prereqs.min_relationship_affection = content_storylet
-                    .prerequisites
-                    .min_relationship_affection;
-                prereqs.min_relationship_resentment = content_storylet
-                    .prerequisites
-                    .min_relationship_resentment;
-                prereqs.stat_ranges = content_storylet.prerequisites.stat_conditions;
-                prereqs.life_stages = content_storylet.prerequisites.life_stages;
-                prereqs.tags = tag_list.clone();
-                prereqs.digital_legacy_prereq = content_storylet
-                    .prerequisites
-                    .digital_legacy_prereq
-                    .as_ref()
-                    .map(|p| syn_director::DigitalLegacyPrereq {
-                        require_post_life: p.require_post_life,
-                        min_compassion_vs_cruelty: p.min_compassion_vs_cruelty,
-                        max_compassion_vs_cruelty: p.max_compassion_vs_cruelty,
-                        min_ambition_vs_comfort: p.min_ambition_vs_comfort,
-                        max_ambition_vs_comfort: p.max_ambition_vs_comfort,
-                        min_connection_vs_isolation: p.min_connection_vs_isolation,
-                        max_connection_vs_isolation: p.max_connection_vs_isolation,
-                        min_stability_vs_chaos: p.min_stability_vs_chaos,
-                        max_stability_vs_chaos: p.max_stability_vs_chaos,
-                        min_light_vs_shadow: p.min_light_vs_shadow,
-                        max_light_vs_shadow: p.max_light_vs_shadow,
-                    });
-                prereqs.relationship_states = content_storylet.prerequisites.relationship_states;
-                prereqs.memory_tags_required = content_storylet.prerequisites.memory_tags_required;
-                prereqs.memory_tags_forbidden =
-                    content_storylet.prerequisites.memory_tags_forbidden;
-                prereqs.memory_recency_ticks = content_storylet.prerequisites.memory_recency_ticks;
-                prereqs.relationship_prereqs = content_storylet
-                    .prerequisites
-                    .relationship_prereqs
-                    .into_iter()
-                    .map(|r| syn_director::RelationshipPrereq {
-                        actor_id: r.actor_id,
-                        target_id: r.target_id,
-                        axis: r.axis,
-                        min_value: r.min_value,
-                        max_value: r.max_value,
-                        min_band: r.min_band,
-                        max_band: r.max_band,
-                    })
-                    .collect();
-                prereqs.allowed_life_stages = content_storylet.prerequisites.allowed_life_stages;
-                prereqs.time_and_location = None;
-
-                let director_storylet = Storylet {
-                    id: content_storylet.id,
-                    name: content_storylet.name,
-                    prerequisites: prereqs,
-                    heat: content_storylet.heat as i32,
-                    weight: content_storylet.weight,
-                    cooldown: syn_director::StoryletCooldown {
-                        ticks: content_storylet.cooldown_ticks,
-                    },
-                    roles: content_storylet
-                        .roles
-                        .into_iter()
-                        .map(|r| syn_director::StoryletRole {
-                            name: r.name,
-                            npc_id: r.npc_id,
-                        })
-                        .collect::<syn_director::StoryletRoles>(),
-                    tags: syn_director::tags_to_bitset(&tag_list),
-                    outcomes: syn_director::StoryletOutcomeSet {
-                        max_uses: None,
-                        choices: vec![],
-                        heat_category: content_storylet.heat_category,
-                        actors: None,
-                        interaction_tone: None,
-                        ..Default::default()
-                    },
-                    ..Default::default()
-                };
-                director.register_storylet(director_storylet);
+    /// Check a submission against the presented event, returning its token.
+    ///
+    /// `token` is optional for legacy callers; without it only the storylet
+    /// and choice are checked.
+    pub fn validate(
+        &self,
+        token: Option<u64>,
+        storylet_id: &str,
+        choice_id: &str,
+    ) -> Result<u64, ApiChoiceRejection> {
+        if let Some(token) = token {
+            if self.last_consumed == Some(token) {
+                return Err(ApiChoiceRejection::DuplicateSubmission { token });
+            }
+        }
+        let Some(current) = &self.current else {
+            return Err(ApiChoiceRejection::NoEventPresented);
+        };
+        if let Some(token) = token {
+            if token != current.token {
+                return Err(ApiChoiceRejection::StaleEvent {
+                    token,
+                    current_token: current.token,
+                });
             }
         }
+        if storylet_id != current.storylet_id {
+            return Err(ApiChoiceRejection::StoryletMismatch {
+                presented: current.storylet_id.clone(),
+                submitted: storylet_id.to_string(),
+            });
+        }
+        if !current.choice_ids.iter().any(|id| id == choice_id) {
+            return Err(ApiChoiceRejection::UnknownChoice {
+                storylet_id: storylet_id.to_string(),
+                choice_id: choice_id.to_string(),
+            });
+        }
+        Ok(current.token)
+    }
+
+    /// Mark `token` as used; later submissions with it are duplicates.
+    pub fn consume(&mut self, token: u64) {
+        if self.current_token() == Some(token) {
+            self.current = None;
+        }
+        self.last_consumed = Some(token);
+    }
+}
+
+/// Default storylet database filename.
+const DEFAULT_STORYLET_DB: &str = "storylets.sqlite";
+
+/// Loads storylets from database and registers them with the event director.
+///
+/// When `SYN_STORYLET_DIR` is set, the JSON storylets in that directory are
+/// layered over the database (see [`syn_content::load_storylet_packs`]), so
+/// content tweaks don't require rebuilding the database.
+fn register_storylets_from_db(director: &mut EventDirector) {
+    for content_storylet in load_content_storylets() {
+        director.register_storylet(director_storylet_from_content(content_storylet));
+    }
+}
+
+/// The storylet database: `SYN_STORYLET_DB`, or [`DEFAULT_STORYLET_DB`].
+fn storylet_db_path() -> String {
+    std::env::var("SYN_STORYLET_DB").unwrap_or_else(|_| DEFAULT_STORYLET_DB.to_string())
+}
+
+/// Storylets in the database at `db_path`. A missing database holds none;
+/// it is not created, so a run without content leaves no file behind.
+fn load_storylet_db(db_path: &str) -> Result<Vec<syn_content::Storylet>, String> {
+    if !Path::new(db_path).exists() {
+        return Ok(Vec::new());
+    }
+    load_storylets_from_db(db_path).map_err(|err| err.to_string())
+}
+
+/// Load content storylets from the database, then any directory packs.
+fn load_content_storylets() -> Vec<syn_content::Storylet> {
+    let db_path = storylet_db_path();
+    let storylets = match load_storylet_db(&db_path) {
+        Ok(storylets) => storylets,
         Err(err) => {
             eprintln!(
                 "Warning: failed to load storylets from {}: {}",
                 db_path, err
             );
+            Vec::new()
         }
+    };
+
+    let Ok(pack_dir) = std::env::var("SYN_STORYLET_DIR") else {
+        return storylets;
+    };
+    let load = load_storylet_packs(storylets, std::path::Path::new(&pack_dir));
+    for error in &load.errors {
+        eprintln!(
+            "Warning: skipped storylet file {}: {}",
+            error.path.display(),
+            error.message
+        );
+    }
+    load.storylets
+}
+
+/// Like [`load_content_storylets`], but an unreadable database or pack file
+/// fails the whole load instead of being skipped. Packs come from `pack_dir`
+/// when given, otherwise from `SYN_STORYLET_DIR`.
+fn reload_content_storylets(pack_dir: Option<&Path>) -> Result<Vec<syn_content::Storylet>, SynError> {
+    let db_path = storylet_db_path();
+    let storylets = load_storylet_db(&db_path)
+        .map_err(|err| SynError::PersistenceError(format!("{}: {}", db_path, err)))?;
+
+    let pack_dir = match pack_dir {
+        Some(dir) => dir.to_path_buf(),
+        None => match std::env::var("SYN_STORYLET_DIR") {
+            Ok(dir) => PathBuf::from(dir),
+            Err(_) => return Ok(storylets),
+        },
+    };
+    let load = load_storylet_packs(storylets, &pack_dir);
+    if let Some(error) = load.errors.first() {
+        return Err(SynError::PersistenceError(format!(
+            "{}: {}",
+            error.path.display(),
+            error.message
+        )));
+    }
+    Ok(load.storylets)
+}
+
+/// Convert a content storylet into the director's runtime form.
+fn director_storylet_from_content(content_storylet: syn_content::Storylet) -> Storylet {
+    let tag_list = content_storylet.prerequisites.tags.clone();
+    // Convert syn_content::Storylet to syn_director::Storylet
+    let mut prereqs = syn_director::StoryletPrerequisites::default();
+    prereqs.min_relationship_affection = content_storylet
+        .prerequisites
+        .min_relationship_affection;
+    prereqs.min_relationship_resentment = content_storylet
+        .prerequisites
+        .min_relationship_resentment;
+    prereqs.stat_ranges = content_storylet.prerequisites.stat_conditions;
+    prereqs.life_stages = content_storylet.prerequisites.life_stages;
+    prereqs.tags = tag_list.clone();
+    prereqs.digital_legacy_prereq = content_storylet
+        .prerequisites
+        .digital_legacy_prereq
+        .as_ref()
+        .map(|p| syn_director::DigitalLegacyPrereq {
+            require_post_life: p.require_post_life,
+            min_compassion_vs_cruelty: p.min_compassion_vs_cruelty,
+            max_compassion_vs_cruelty: p.max_compassion_vs_cruelty,
+            min_ambition_vs_comfort: p.min_ambition_vs_comfort,
+            max_ambition_vs_comfort: p.max_ambition_vs_comfort,
+            min_connection_vs_isolation: p.min_connection_vs_isolation,
+            max_connection_vs_isolation: p.max_connection_vs_isolation,
+            min_stability_vs_chaos: p.min_stability_vs_chaos,
+            max_stability_vs_chaos: p.max_stability_vs_chaos,
+            min_light_vs_shadow: p.min_light_vs_shadow,
+            max_light_vs_shadow: p.max_light_vs_shadow,
+        });
+    prereqs.relationship_states = content_storylet.prerequisites.relationship_states;
+    prereqs.memory_tags_required = content_storylet.prerequisites.memory_tags_required;
+    prereqs.memory_tags_forbidden =
+        content_storylet.prerequisites.memory_tags_forbidden;
+    prereqs.memory_recency_ticks = content_storylet.prerequisites.memory_recency_ticks;
+    prereqs.relationship_prereqs = content_storylet
+        .prerequisites
+        .relationship_prereqs
+        .into_iter()
+        .map(|r| syn_director::RelationshipPrereq {
+            actor_id: r.actor_id,
+            target_id: r.target_id,
+            axis: r.axis,
+            min_value: r.min_value,
+            max_value: r.max_value,
+            min_band: r.min_band,
+            max_band: r.max_band,
+        })
+        .collect();
+    prereqs.allowed_life_stages = content_storylet.prerequisites.allowed_life_stages;
+    prereqs.depends_on = content_storylet.prerequisites.depends_on;
+    prereqs.prefers_after = content_storylet.prerequisites.prefers_after;
+    prereqs.time_and_location = None;
+
+    Storylet {
+        id: content_storylet.id,
+        name: content_storylet.name,
+        prerequisites: prereqs,
+        heat: content_storylet.heat as i32,
+        weight: content_storylet.weight,
+        cooldown: syn_director::StoryletCooldown {
+            ticks: content_storylet.cooldown_ticks,
+        },
+        roles: content_storylet
+            .roles
+            .into_iter()
+            .map(|r| syn_director::StoryletRole {
+                name: r.name,
+                npc_id: r.npc_id,
+            })
+            .collect::<syn_director::StoryletRoles>(),
+        tags: syn_director::tags_to_bitset(&tag_list),
+        outcomes: syn_director::StoryletOutcomeSet {
+            max_uses: None,
+            choices: vec![],
+            heat_category: content_storylet.heat_category,
+            actors: None,
+            interaction_tone: None,
+            ..Default::default()
+        },
+        ..Default::default()
+    }
+}
+
+/// Version of the engine state stored in save files.
+pub const ENGINE_SAVE_VERSION: u32 = 1;
+
+/// Runtime-state key for [`EngineRuntimeSave`] in a save file.
+const ENGINE_RUNTIME_KEY: &str = "engine";
+
+/// Engine state that lives outside [`WorldState`].
+#[derive(Serialize, Deserialize)]
+struct EngineRuntimeSave {
+    version: u32,
+    memory: MemorySystem,
+    #[serde(default)]
+    cooldowns: Vec<CooldownEntry>,
+    #[serde(default)]
+    chronicle: Chronicle,
+    #[serde(default)]
+    follow_ups: Vec<syn_director::PendingFollowUp>,
+    #[serde(default)]
+    pacing: syn_director::PacingBudget,
+}
+
+fn open_save(path: &Path) -> Result<syn_core::Persistence, SynError> {
+    let path_str = path.to_str().ok_or_else(|| {
+        SynError::PersistenceError(format!("save path {} is not UTF-8", path.display()))
+    })?;
+    syn_core::Persistence::new(path_str).map_err(|e| SynError::PersistenceError(e.to_string()))
+}
+
+fn remove_if_exists(path: &Path) -> Result<(), SynError> {
+    match std::fs::remove_file(path) {
+        Ok(()) => Ok(()),
+        Err(e) if e.kind() == std::io::ErrorKind::NotFound => Ok(()),
+        Err(e) => Err(SynError::PersistenceError(format!("{}: {}", path.display(), e))),
+    }
+}
+
+/// `path`, taken from `dir` when it is relative and there is a `dir`.
+fn resolve_in(dir: Option<&Path>, path: &Path) -> PathBuf {
+    match dir {
+        Some(dir) if path.is_relative() => dir.join(path),
+        _ => path.to_path_buf(),
     }
 }
 
@@ -248,8 +554,23 @@ impl GameEngine {
     ///
     /// This initializes the world state, simulator, event director, and memory system.
     /// Storylets are loaded from the database path in `SYN_STORYLET_DB` environment
-    /// variable, or from `storylets.sqlite` by default.
+    /// variable, or from `storylets.sqlite` by default, with any JSON packs in
+    /// `SYN_STORYLET_DIR` layered on top.
     pub fn new(seed: u64) -> Self {
+        Self::with_sim(seed, syn_sim::SimState::new(), None)
+    }
+
+    /// Create a game engine that keeps its simulation storage, and any save
+    /// given a relative path, under `storage_dir` (e.g. one profile's
+    /// directory).
+    pub fn new_in(seed: u64, storage_dir: impl Into<PathBuf>) -> Result<Self, SynError> {
+        let storage_dir = storage_dir.into();
+        let sim_state = syn_sim::SimState::new_in(&storage_dir)
+            .map_err(|e| SynError::PersistenceError(e.to_string()))?;
+        Ok(Self::with_sim(seed, sim_state, Some(storage_dir)))
+    }
+
+    fn with_sim(seed: u64, sim_state: syn_sim::SimState, storage_dir: Option<PathBuf>) -> Self {
         let world_seed = WorldSeed::new(seed);
         let player_id = NpcId(1);
         let world = WorldState::new(world_seed, player_id);
@@ -259,11 +580,142 @@ impl GameEngine {
 
         GameEngine {
             world,
-            sim_state: syn_sim::SimState::new(),
+            sim_state,
             world_sim: syn_sim::WorldSimState::new(),
             director,
             memory: MemorySystem::new(),
+            presentation: EventPresentation::default(),
+            surfaced_pressure: Vec::new(),
+            storage_dir,
+        }
+    }
+
+    /// Directory holding this engine's storage, if it was given one.
+    pub fn storage_dir(&self) -> Option<&Path> {
+        self.storage_dir.as_deref()
+    }
+
+    // ==================== Save / Load ====================
+
+    /// Save the whole engine to a save file at `path`, replacing any
+    /// previous save there.
+    ///
+    /// The file holds the world (storylet usage included), the live NPC
+    /// instances, every memory journal and the director's cooldowns,
+    /// chronicle, pending follow-ups and pacing budget, so
+    /// [`GameEngine::load_from`] resumes the life exactly where it was.
+    ///
+    /// A relative `path` is taken from the engine's storage directory, when
+    /// it has one.
+    pub fn save_to(&mut self, path: impl AsRef<Path>) -> Result<(), SynError> {
+        let path = &resolve_in(self.storage_dir.as_deref(), path.as_ref());
+        let runtime = serde_json::to_string(&EngineRuntimeSave {
+            version: ENGINE_SAVE_VERSION,
+            memory: self.memory.clone(),
+            cooldowns: self.director.cooldown_entries(),
+            chronicle: self.director.chronicle().clone(),
+            follow_ups: self.director.pending_follow_ups().to_vec(),
+            pacing: self.director.pacing_budget().clone(),
+        })
+        .map_err(|e| SynError::PersistenceError(e.to_string()))?;
+
+        // Write beside the old save and swap it in, so a failed save never
+        // clobbers the last good one.
+        let staging = path.with_extension("saving");
+        remove_if_exists(&staging)?;
+        {
+            let mut db = open_save(&staging)?;
+            self.sim_state.save_game(&mut db, &self.world)?;
+            db.save_runtime_state(self.world.seed, ENGINE_RUNTIME_KEY, &runtime)
+                .map_err(|e| SynError::PersistenceError(e.to_string()))?;
+        }
+        std::fs::rename(&staging, path).map_err(|e| {
+            SynError::PersistenceError(format!("{}: {}", path.display(), e))
+        })
+    }
+
+    /// Resume a game saved with [`GameEngine::save_to`].
+    ///
+    /// Storylets are registered afresh, as in [`GameEngine::new`]; NPC
+    /// fidelity tiers are rebuilt by the simulation as it ticks.
+    pub fn load_from(path: impl AsRef<Path>) -> Result<Self, SynError> {
+        Self::load_with(path.as_ref(), syn_sim::SimState::new(), None)
+    }
+
+    /// Resume a game saved with [`GameEngine::save_to`] into an engine
+    /// stored under `storage_dir`, as [`GameEngine::new_in`] creates.
+    ///
+    /// A relative `path` is taken from `storage_dir`.
+    pub fn load_from_in(
+        path: impl AsRef<Path>,
+        storage_dir: impl Into<PathBuf>,
+    ) -> Result<Self, SynError> {
+        let storage_dir = storage_dir.into();
+        let path = resolve_in(Some(&storage_dir), path.as_ref());
+        if !path.exists() {
+            return Err(SynError::NotFound(format!("save file {}", path.display())));
         }
+        let sim_state = syn_sim::SimState::new_in(&storage_dir)
+            .map_err(|e| SynError::PersistenceError(e.to_string()))?;
+        Self::load_with(&path, sim_state, Some(storage_dir))
+    }
+
+    fn load_with(
+        path: &Path,
+        mut sim_state: syn_sim::SimState,
+        storage_dir: Option<PathBuf>,
+    ) -> Result<Self, SynError> {
+        if !path.exists() {
+            return Err(SynError::NotFound(format!("save file {}", path.display())));
+        }
+        let mut db = open_save(path)?;
+        let seeds = db
+            .world_seeds()
+            .map_err(|e| SynError::PersistenceError(e.to_string()))?;
+        let seed = match seeds.as_slice() {
+            [seed] => *seed,
+            _ => {
+                return Err(SynError::InvalidState(format!(
+                    "{} holds {} worlds, expected one",
+                    path.display(),
+                    seeds.len()
+                )))
+            }
+        };
+
+        let world = sim_state.load_game(&mut db, seed)?;
+        let runtime = db
+            .load_runtime_state(seed, ENGINE_RUNTIME_KEY)
+            .map_err(|e| SynError::PersistenceError(e.to_string()))?
+            .ok_or_else(|| {
+                SynError::InvalidState(format!("{} has no engine state", path.display()))
+            })?;
+        let runtime: EngineRuntimeSave = serde_json::from_str(&runtime)
+            .map_err(|e| SynError::PersistenceError(e.to_string()))?;
+        if runtime.version > ENGINE_SAVE_VERSION {
+            return Err(SynError::InvalidState(format!(
+                "save format v{} is newer than this build (v{})",
+                runtime.version, ENGINE_SAVE_VERSION
+            )));
+        }
+
+        let mut director = EventDirector::new();
+        register_storylets_from_db(&mut director);
+        director.restore_cooldowns(&runtime.cooldowns);
+        director.restore_chronicle(runtime.chronicle);
+        director.restore_follow_ups(runtime.follow_ups);
+        director.restore_pacing_budget(runtime.pacing);
+
+        Ok(GameEngine {
+            world,
+            sim_state,
+            world_sim: syn_sim::WorldSimState::new(),
+            director,
+            memory: runtime.memory,
+            presentation: EventPresentation::default(),
+            surfaced_pressure: Vec::new(),
+            storage_dir,
+        })
     }
 
     // ==================== World Management ====================
@@ -278,6 +730,11 @@ impl GameEngine {
         self.world.current_tick.0
     }
 
+    /// Snapshot the world state (used for determinism and persistence checks).
+    pub fn world_snapshot(&self) -> syn_core::WorldStateSnapshot {
+        syn_core::WorldStateSnapshot::from_world(&self.world)
+    }
+
     /// Get player age.
     pub fn player_age(&self) -> u32 {
         self.world.player_age
@@ -341,6 +798,12 @@ impl GameEngine {
         self.world.heat_trend()
     }
 
+    /// Project narrative heat over the next `horizon_ticks` (at most a week)
+    /// from the current trend, queued pressure events and scheduled follow-ups.
+    pub fn heat_forecast(&self, horizon_ticks: u64) -> HeatForecast {
+        syn_core::forecast_heat(&self.world, horizon_ticks)
+    }
+
     /// Get player stats (serialized for Dart).
     pub fn player_stats(&self) -> ApiStatsSnapshot {
         ApiStatsSnapshot {
@@ -370,7 +833,7 @@ impl GameEngine {
         format!("{:?}", self.world.player_karma.band())
     }
 
-    /// Get player relationships snapshot (with bands and role labels).
+    /// Get player relationships snapshot (with bands, role labels and heat).
     pub fn player_relationships(&self) -> ApiRelationshipSnapshot {
         let player_id = self.world.player_id;
         let mut relationships = Vec::new();
@@ -380,35 +843,111 @@ impl GameEngine {
             if actor_id != player_id {
                 continue;
             }
+            relationships.push(self.api_relationship(actor_id, target_id, rel));
+        }
 
-            // Convert Relationship to RelationshipVector for band methods
-            let rel_vec = RelationshipVector {
-                affection: rel.affection,
-                trust: rel.trust,
-                attraction: rel.attraction,
-                familiarity: rel.familiarity,
-                resentment: rel.resentment,
-            };
+        ApiRelationshipSnapshot { relationships }
+    }
 
-            let api_rel = ApiRelationship {
-                actor_id: actor_id.0 as i64,
-                target_id: target_id.0 as i64,
-                affection: rel.affection,
-                trust: rel.trust,
-                attraction: rel.attraction,
-                familiarity: rel.familiarity,
-                resentment: rel.resentment,
-                affection_band: rel_vec.affection_band().to_string(),
-                trust_band: rel_vec.trust_band().to_string(),
-                attraction_band: rel_vec.attraction_band().to_string(),
-                resentment_band: rel_vec.resentment_band().to_string(),
-                role_label: derive_role_label(&rel_vec),
-            };
+    /// Player relationships that changed at or after `since_tick`.
+    ///
+    /// Pass the `tick` of the previous result to poll only what changed;
+    /// pairs changed during that tick may be sent again, none are missed.
+    pub fn relationship_changes(&self, since_tick: u64) -> ApiRelationshipChanges {
+        let player_id = self.world.player_id;
+        let mut changes = ApiRelationshipChanges {
+            tick: self.world.current_tick.0,
+            relationships: Vec::new(),
+            removed_target_ids: Vec::new(),
+        };
 
-            relationships.push(api_rel);
+        let changed = self.world.relationship_pressure.pairs_changed_since(since_tick);
+        for (actor_id, target_id) in changed {
+            if actor_id != player_id.0 {
+                continue;
+            }
+            let key = (NpcId(actor_id), NpcId(target_id));
+            match self.world.relationships.get(&key) {
+                Some(rel) => changes
+                    .relationships
+                    .push(self.api_relationship(key.0, key.1, rel)),
+                None => changes.removed_target_ids.push(target_id as i64),
+            }
         }
 
-        ApiRelationshipSnapshot { relationships }
+        changes
+    }
+
+    /// Relationship band shifts and milestones the UI hasn't been shown yet.
+    ///
+    /// Milestones are taken off their queue. Pressure events stay queued so
+    /// the director can still pick storylets for them; each is returned
+    /// once.
+    pub fn poll_relationship_events(&mut self) -> ApiRelationshipEvents {
+        let queue = &self.world.relationship_pressure.queue;
+        self.surfaced_pressure.retain(|seen| queue.contains(seen));
+        let fresh: Vec<RelationshipPressureEvent> = queue
+            .iter()
+            .filter(|event| !self.surfaced_pressure.contains(event))
+            .cloned()
+            .collect();
+        self.surfaced_pressure.extend(fresh.iter().cloned());
+
+        let milestones: Vec<RelationshipMilestoneEvent> =
+            std::iter::from_fn(|| syn_director::next_relationship_milestone(&mut self.world))
+                .collect();
+
+        ApiRelationshipEvents {
+            pressure: fresh
+                .iter()
+                .map(|event| ApiRelationshipPressureEvent::from_event(&self.world, event))
+                .collect(),
+            milestones: milestones
+                .iter()
+                .map(|event| ApiRelationshipMilestoneEvent::from_event(&self.world, event))
+                .collect(),
+        }
+    }
+
+    /// DTO for one relationship, with bands, role label and heat.
+    fn api_relationship(
+        &self,
+        actor_id: NpcId,
+        target_id: NpcId,
+        rel: &Relationship,
+    ) -> ApiRelationship {
+        let tick = self.world.current_tick.0;
+
+        // Convert Relationship to RelationshipVector for band methods
+        let rel_vec = RelationshipVector {
+            affection: rel.affection,
+            trust: rel.trust,
+            attraction: rel.attraction,
+            familiarity: rel.familiarity,
+            resentment: rel.resentment,
+        };
+
+        ApiRelationship {
+            actor_id: actor_id.0 as i64,
+            target_id: target_id.0 as i64,
+            affection: rel.affection,
+            trust: rel.trust,
+            attraction: rel.attraction,
+            familiarity: rel.familiarity,
+            resentment: rel.resentment,
+            affection_band: rel_vec.affection_band().to_string(),
+            trust_band: rel_vec.trust_band().to_string(),
+            attraction_band: rel_vec.attraction_band().to_string(),
+            resentment_band: rel_vec.resentment_band().to_string(),
+            role_label: derive_role_label(&rel_vec),
+            heat: self.world.relationship_heat.heat(actor_id, target_id, tick),
+            heat_trend: self.world.relationship_heat.trend(actor_id, target_id, tick),
+        }
+    }
+
+    /// The player's social graph (nodes with roles and bands, weighted edges).
+    pub fn relationship_network(&self) -> RelationshipNetwork {
+        RelationshipNetwork::for_player(&self.world)
     }
 
     // ==================== Simulation ====================
@@ -430,6 +969,15 @@ impl GameEngine {
 
         // Tick PostLife drift if in Digital stage
         syn_sim::post_life::tick_postlife_drift(&mut self.world);
+
+        self.emit_ambient_beat();
+        self.emit_dream();
+        self.emit_rival_memories();
+        self.emit_jealousy_memories();
+        self.emit_encounter_memories();
+        self.emit_dependent_memories();
+        self.emit_post_life_memories();
+        syn_core::det_math::quantize_accumulators(&mut self.world);
     }
 
     /// Advance the simulation by N ticks.
@@ -440,6 +988,244 @@ impl GameEngine {
             
             // Handle PostLife drift after each tick
             syn_sim::post_life::tick_postlife_drift(&mut self.world);
+
+            self.emit_ambient_beat();
+            self.emit_dream();
+            self.emit_rival_memories();
+            self.emit_jealousy_memories();
+            self.emit_encounter_memories();
+            self.emit_dependent_memories();
+            self.emit_post_life_memories();
+            syn_core::det_math::quantize_accumulators(&mut self.world);
+        }
+    }
+
+    /// Tick until `condition` holds, at most `max_ticks` ticks.
+    ///
+    /// The condition is checked before every tick, so one that already holds
+    /// advances nothing. Returns the ticks advanced.
+    pub fn tick_until(
+        &mut self,
+        max_ticks: u32,
+        mut condition: impl FnMut(&GameEngine) -> bool,
+    ) -> u32 {
+        let mut advanced = 0;
+        while advanced < max_ticks && !condition(self) {
+            self.tick();
+            advanced += 1;
+        }
+        advanced
+    }
+
+    /// [`GameEngine::tick_until`] for a long time-skip, handing `on_frame` a
+    /// montage frame whenever a tick begins a new day, fires a storylet or
+    /// reaches a milestone.
+    ///
+    /// Returning false from `on_frame` stops the skip after that tick.
+    /// Returns the ticks advanced.
+    pub fn fast_forward(
+        &mut self,
+        max_ticks: u32,
+        mut until: impl FnMut(&GameEngine) -> bool,
+        mut on_frame: impl FnMut(ApiMontageFrame) -> bool,
+    ) -> u32 {
+        let mut last_seq = self.director.chronicle().entries().last().map_or(0, |e| e.seq);
+        let mut advanced = 0;
+        while advanced < max_ticks && !until(self) {
+            let day = self.world.game_time.day();
+            let age = self.world.player_age_years;
+            let stage = self.world.player_life_stage;
+            self.tick();
+            advanced += 1;
+
+            let fired_storylets: Vec<String> = self
+                .director
+                .chronicle()
+                .entries()
+                .iter()
+                .filter(|entry| entry.seq > last_seq)
+                .map(|entry| entry.storylet_id.clone())
+                .collect();
+            last_seq = self.director.chronicle().entries().last().map_or(0, |e| e.seq);
+            let milestones = self.montage_milestones(age, stage);
+            let day_boundary = self.world.game_time.day() != day;
+            if !day_boundary && fired_storylets.is_empty() && milestones.is_empty() {
+                continue;
+            }
+
+            let frame = ApiMontageFrame {
+                tick: self.world.current_tick.0,
+                day: self.world.game_time.day(),
+                day_boundary,
+                fired_storylets,
+                milestones,
+                player_age_years: self.world.player_age_years,
+                life_stage: format!("{:?}", self.world.player_life_stage),
+                stats: self.player_stats(),
+            };
+            if !on_frame(frame) {
+                break;
+            }
+        }
+        advanced
+    }
+
+    /// Milestones reached on the current tick, given the player's age and
+    /// life stage before it.
+    fn montage_milestones(&self, age_before: u32, stage_before: LifeStage) -> Vec<String> {
+        let mut milestones = Vec::new();
+        if self.world.player_age_years > age_before {
+            milestones.push(format!("birthday:{}", self.world.player_age_years));
+        }
+        if self.world.player_life_stage != stage_before {
+            milestones.push(format!("life_stage:{:?}", self.world.player_life_stage));
+        }
+        let tick = self.world.current_tick.0;
+        let player = self.world.player_id.0;
+        milestones.extend(
+            self.world
+                .relationship_milestones
+                .history
+                .iter()
+                .filter(|record| record.tick == tick && record.actor_id == player)
+                .map(|record| format!("relationship:{:?}:{}", record.kind, record.target_id)),
+        );
+        milestones
+    }
+
+    /// Maybe produce an ambient micro-event and record it in the player's journal.
+    fn emit_ambient_beat(&mut self) {
+        let Some(beat) = syn_core::generate_micro_event(&mut self.world) else {
+            return;
+        };
+        let record = beat.memory;
+        let mut entry = MemoryEntry::new(
+            record.id,
+            record.event_id,
+            record.npc_id,
+            record.sim_tick,
+            record.emotional_intensity,
+        );
+        entry.tags = record.tags;
+        entry.participants = record.participants;
+        let ctx = MemoryIntensityContext::for_npc(&self.world, record.npc_id);
+        self.memory.record_memory(entry, ctx.as_ref());
+    }
+
+    /// Maybe produce a night-time dream and record it in the player's journal.
+    fn emit_dream(&mut self) {
+        let Some(beat) = syn_core::generate_dream(&mut self.world) else {
+            return;
+        };
+        let record = beat.memory;
+        let mut entry = MemoryEntry::new(
+            record.id,
+            record.event_id,
+            record.npc_id,
+            record.sim_tick,
+            record.emotional_intensity,
+        );
+        entry.tags = record.tags;
+        entry.participants = record.participants;
+        let ctx = MemoryIntensityContext::for_npc(&self.world, record.npc_id);
+        self.memory.record_memory(entry, ctx.as_ref());
+    }
+
+    /// Record this tick's rival actions in the player's journal.
+    fn emit_rival_memories(&mut self) {
+        let tick = self.world.current_tick.0;
+        let record_ids: Vec<String> = self
+            .world
+            .rival_actions
+            .recent
+            .iter()
+            .filter(|action| action.tick == tick)
+            .map(|action| format!("rival_action:{}", action.id))
+            .collect();
+        self.mirror_world_memories(record_ids);
+    }
+
+    /// Record this tick's jealous confrontations in the player's journal.
+    fn emit_jealousy_memories(&mut self) {
+        let tick = self.world.current_tick.0;
+        let record_ids: Vec<String> = self
+            .world
+            .jealousy
+            .recent
+            .iter()
+            .filter(|event| event.tick == tick && event.confronted)
+            .map(|event| format!("{}:{}", syn_core::JEALOUSY_TAG, event.id))
+            .collect();
+        self.mirror_world_memories(record_ids);
+    }
+
+    /// Record this tick's first meetings in the player's journal.
+    fn emit_encounter_memories(&mut self) {
+        let tick = self.world.current_tick.0;
+        let record_ids: Vec<String> = self
+            .world
+            .encounters
+            .recent
+            .iter()
+            .filter(|encounter| encounter.tick == tick)
+            .map(|encounter| format!("encounter:{}", encounter.id))
+            .collect();
+        self.mirror_world_memories(record_ids);
+    }
+
+    /// Record this tick's dependent neglect and losses in the player's journal.
+    fn emit_dependent_memories(&mut self) {
+        let tick = self.world.current_tick.0;
+        let record_ids: Vec<String> = self
+            .world
+            .dependents
+            .recent
+            .iter()
+            .filter(|event| event.tick == tick)
+            .map(|event| format!("dependent:{}", event.id))
+            .collect();
+        self.mirror_world_memories(record_ids);
+    }
+
+    /// Record this tick's PostLife events (the chapter opening, the fade) in
+    /// the player's journal.
+    fn emit_post_life_memories(&mut self) {
+        let tick = self.world.current_tick.0;
+        let record_ids: Vec<String> = self
+            .world
+            .post_life
+            .recent
+            .iter()
+            .filter(|event| event.tick == tick)
+            .map(|event| format!("post_life:{}", event.id))
+            .collect();
+        self.mirror_world_memories(record_ids);
+    }
+
+    /// Copy memory records the core simulation wrote into the memory system.
+    fn mirror_world_memories(&mut self, record_ids: Vec<String>) {
+        for record_id in record_ids {
+            let Some(record) = self
+                .world
+                .memory_entries
+                .iter()
+                .rev()
+                .find(|m| m.id == record_id)
+                .cloned()
+            else {
+                continue;
+            };
+            let mut entry = MemoryEntry::new(
+                record.id,
+                record.event_id,
+                record.npc_id,
+                record.sim_tick,
+                record.emotional_intensity,
+            );
+            entry.tags = record.tags;
+            entry.participants = record.participants;
+            let ctx = MemoryIntensityContext::for_npc(&self.world, record.npc_id);
+            self.memory.record_memory(entry, ctx.as_ref());
         }
     }
 
@@ -487,6 +1273,7 @@ impl GameEngine {
             age: npc.age,
             job: npc.job.clone(),
             district: npc.district.clone(),
+            note: self.world.player_notes.npc_note(npc.id),
         })
     }
 
@@ -554,7 +1341,8 @@ impl GameEngine {
             self.world.current_tick,
             emotional_intensity.clamp(-1.0, 1.0),
         );
-        self.memory.record_memory(entry);
+        let ctx = MemoryIntensityContext::for_npc(&self.world, NpcId(npc_id));
+        self.memory.record_memory(entry, ctx.as_ref());
         memory_id
     }
 
@@ -571,31 +1359,94 @@ impl GameEngine {
                         event_id: entry.event_id.clone(),
                         emotional_intensity: entry.emotional_intensity,
                         sim_tick: entry.sim_tick.0,
+                        note: self.world.player_notes.memory_note(&entry.id),
                     })
                     .collect()
             })
             .unwrap_or_default()
     }
 
-    // ==================== Digital Legacy ====================
-
-    /// Ensure digital imprint is created when entering PostLife/Digital stage.
-    pub fn ensure_digital_imprint(&mut self) {
-        // Collect all memory entries for the builder
-        let all_memories: Vec<MemoryEntry> = self
-            .memory
-            .journals
-            .values()
-            .flat_map(|journal| journal.entries.clone())
-            .collect();
-
-        syn_sim::post_life::ensure_digital_imprint_for_postlife(&mut self.world, &all_memories);
+    /// Search the player's memory journal, ranked by salience.
+    pub fn search_memories(&self, query: &MemoryQuery) -> MemorySearchPage<'_> {
+        self.memory.search(Some(self.world.player_id), query)
     }
 
-    /// Get digital legacy snapshot for UI.
-    pub fn get_digital_legacy_snapshot(&self) -> ApiDigitalLegacySnapshot {
-        if let Some(imprint) = &self.world.digital_legacy.primary_imprint {
-            let lv = &imprint.legacy_vector;
+    /// Export an NPC's journal in the stable, versioned format used by the
+    /// Flutter journal for offline storage (newest entry first).
+    pub fn export_journal(&self, npc_id: u64) -> ApiJournalExport {
+        let entries = self
+            .memory
+            .get_journal(NpcId(npc_id))
+            .map(|journal| {
+                journal
+                    .timeline()
+                    .into_iter()
+                    .map(|entry| ApiJournalExportEntry::from_entry(&self.world, entry))
+                    .collect()
+            })
+            .unwrap_or_default();
+        ApiJournalExport {
+            schema_version: JOURNAL_EXPORT_SCHEMA_VERSION,
+            npc_id,
+            npc_name: syn_core::npc_name(&self.world, NpcId(npc_id)),
+            exported_at_tick: self.world.current_tick.0,
+            exported_at: journal_timestamp(self.world.current_tick.0),
+            entries,
+        }
+    }
+
+    /// Import a journal export into the holder's journal (dev/testing).
+    ///
+    /// Entries whose id is already in the journal are skipped; intensities
+    /// are stored as exported. Returns how many entries were added, or an
+    /// error if the export's schema version is not supported.
+    pub fn import_journal(&mut self, export: &ApiJournalExport) -> Result<usize, String> {
+        if export.schema_version == 0 || export.schema_version > JOURNAL_EXPORT_SCHEMA_VERSION {
+            return Err(format!(
+                "unsupported journal schema version {} (expected 1..={})",
+                export.schema_version, JOURNAL_EXPORT_SCHEMA_VERSION
+            ));
+        }
+        let journal = self.memory.get_or_create_journal(NpcId(export.npc_id));
+        let mut imported = 0;
+        for entry in &export.entries {
+            if journal.entries.iter().any(|existing| existing.id == entry.id) {
+                continue;
+            }
+            let mut memory = MemoryEntry::new(
+                entry.id.clone(),
+                entry.event_id.clone(),
+                NpcId(export.npc_id),
+                SimTick(entry.sim_tick),
+                entry.emotional_intensity,
+            )
+            .with_tags(entry.tags.clone());
+            memory.participants = entry.participants.iter().map(|p| p.npc_id).collect();
+            journal.record(memory);
+            imported += 1;
+        }
+        Ok(imported)
+    }
+
+    // ==================== Digital Legacy ====================
+
+    /// Ensure digital imprint is created when entering PostLife/Digital stage.
+    pub fn ensure_digital_imprint(&mut self) {
+        // Collect all memory entries for the builder
+        let all_memories: Vec<MemoryEntry> = self
+            .memory
+            .journals
+            .values()
+            .flat_map(|journal| journal.entries.clone())
+            .collect();
+
+        syn_sim::post_life::ensure_digital_imprint_for_postlife(&mut self.world, &all_memories);
+    }
+
+    /// Get digital legacy snapshot for UI.
+    pub fn get_digital_legacy_snapshot(&self) -> ApiDigitalLegacySnapshot {
+        if let Some(imprint) = &self.world.digital_legacy.primary_imprint {
+            let lv = &imprint.legacy_vector;
             let api_lv = ApiLegacyVector {
                 compassion_vs_cruelty: lv.compassion_vs_cruelty,
                 ambition_vs_comfort: lv.ambition_vs_comfort,
@@ -662,6 +1513,546 @@ impl GameEngine {
                 heat: s.heat as f32,
             })
     }
+
+    /// The next eligible event with its choices, as the player sees it.
+    ///
+    /// The view is remembered as presented, so [`GameEngine::choose`] only
+    /// accepts one of its choices. Polling again without anything changing
+    /// returns the same view and token.
+    pub fn current_event_view(&mut self) -> Option<ApiDirectorEventView> {
+        let Some(storylet) =
+            self.director
+                .select_next_event(&self.world, &self.memory, self.world.current_tick)
+        else {
+            self.presentation.clear();
+            return None;
+        };
+        let view = syn_director::event_view_for(&self.world, &self.sim_state, storylet);
+        Some(self.presentation.present(ApiDirectorEventView::from(view)))
+    }
+
+    /// Take `choice_id` on the presented event, fire it through the engine's
+    /// director, advance `ticks` ticks and return the next event.
+    ///
+    /// Nothing is applied if the storylet isn't the one last returned by
+    /// [`GameEngine::current_event_view`] or the choice isn't one of its own.
+    pub fn choose(
+        &mut self,
+        storylet_id: &str,
+        choice_id: &str,
+        ticks: u32,
+    ) -> Result<Option<ApiDirectorEventView>, ApiChoiceRejection> {
+        let token = self.presentation.validate(None, storylet_id, choice_id)?;
+        let Some(storylet) = self
+            .director
+            .all_storylets()
+            .iter()
+            .find(|s| s.id == storylet_id)
+            .cloned()
+        else {
+            // Reloaded away since it was presented
+            self.presentation.clear();
+            return Err(ApiChoiceRejection::NoEventPresented);
+        };
+        self.presentation.consume(token);
+
+        let tick = self.world.current_tick;
+        self.director
+            .fire_storylet_choice(&storylet, &mut self.world, &mut self.memory, choice_id, tick);
+        for _ in 0..ticks {
+            self.tick();
+        }
+        Ok(self.current_event_view())
+    }
+
+    // ==================== Content Preferences ====================
+
+    /// Current content preferences (blocked domains/tags).
+    pub fn content_preferences(&self) -> &ContentPreferences {
+        &self.world.content_preferences
+    }
+
+    /// Replace the content preferences after validating them.
+    ///
+    /// Rejects preferences that would block mandatory stage-entry content;
+    /// the previous preferences are kept in that case.
+    pub fn set_content_preferences(&mut self, prefs: ContentPreferences) -> Result<(), SynError> {
+        prefs.validate()?;
+        self.world.content_preferences = prefs;
+        Ok(())
+    }
+
+    // ==================== News Feed ====================
+
+    /// Most recent news items first, at most `limit`.
+    pub fn news_feed(&self, limit: usize) -> Vec<&NewsItem> {
+        self.world.news_feed.latest(limit).collect()
+    }
+
+    // ==================== Dreams ====================
+
+    /// Most recent dreams and flashbacks first, at most `limit`.
+    pub fn recent_dreams(&self, limit: usize) -> Vec<&DreamEvent> {
+        self.world.dreams.latest(limit).collect()
+    }
+
+    // ==================== Life Chapters ====================
+
+    /// Closed life chapters, oldest first.
+    pub fn chapter_summaries(&self) -> &[ChapterSummary] {
+        &self.world.chapters.chapters
+    }
+
+    // ==================== Life Goals ====================
+
+    /// Goals the player has taken on, in the order they were adopted.
+    pub fn life_goals(&self) -> &[TrackedGoal] {
+        &self.world.life_goals.goals
+    }
+
+    /// Take on a goal from [`syn_core::life_goal_catalog`] by id.
+    ///
+    /// Returns false for unknown or already tracked goals, or when the
+    /// player is pursuing the maximum number of goals.
+    pub fn choose_life_goal(&mut self, goal_id: &str) -> bool {
+        let Some(goal) = syn_core::life_goal_catalog()
+            .into_iter()
+            .find(|g| g.id == goal_id)
+        else {
+            return false;
+        };
+        syn_core::adopt_life_goal(&mut self.world, goal)
+    }
+
+    /// Take on the goal the player's archetype gravitates toward.
+    pub fn adopt_archetype_goal(&mut self, archetype: CharacterArchetype) -> bool {
+        syn_core::adopt_life_goal(&mut self.world, syn_core::archetype_goal(archetype))
+    }
+
+    // ==================== Rival Actions ====================
+
+    /// Recent hostile actions rivals took against the player, oldest first.
+    pub fn rival_actions(&self) -> &[RivalAction] {
+        &self.world.rival_actions.recent
+    }
+
+    // ==================== Jealousy ====================
+
+    /// Recent jealous reactions to the player's romances, oldest first.
+    pub fn jealousy_events(&self) -> &[JealousyEvent] {
+        &self.world.jealousy.recent
+    }
+
+    // ==================== Opinions ====================
+
+    /// Recent third-party opinion shifts, oldest first.
+    pub fn opinion_shifts(&self) -> &[OpinionShift] {
+        &self.world.opinions.recent
+    }
+
+    // ==================== Encounters ====================
+
+    /// Recent first meetings with new NPCs, oldest first.
+    pub fn encounters(&self) -> &[Encounter] {
+        &self.world.encounters.recent
+    }
+
+    // ==================== Dependents ====================
+
+    /// Pets, plants and younger siblings the player has taken in, lost ones
+    /// included, in the order they arrived.
+    pub fn dependents(&self) -> &[Dependent] {
+        &self.world.dependents.dependents
+    }
+
+    /// Need rates and thresholds for dependents.
+    pub fn dependent_config(&self) -> &DependentConfig {
+        &self.world.dependents.config
+    }
+
+    /// Recent adoptions, care, neglect and losses, oldest first.
+    pub fn dependent_events(&self) -> &[DependentEvent] {
+        &self.world.dependents.recent
+    }
+
+    /// Take in a new dependent. Returns its id.
+    ///
+    /// The adoption is recorded in the player's journal.
+    pub fn adopt_dependent(&mut self, kind: DependentKind, name: &str) -> u64 {
+        let id = syn_core::adopt_dependent(&mut self.world, kind, name);
+        let record_ids: Vec<String> = self
+            .world
+            .dependents
+            .recent
+            .last()
+            .map(|event| format!("dependent:{}", event.id))
+            .into_iter()
+            .collect();
+        self.mirror_world_memories(record_ids);
+        id
+    }
+
+    /// Look after a dependent, spending action points.
+    ///
+    /// The care is recorded in the player's journal.
+    pub fn care_for_dependent(&mut self, dependent_id: u64) -> Result<DependentEvent, SynError> {
+        let event = syn_core::care_for_dependent(&mut self.world, dependent_id)?;
+        self.mirror_world_memories(vec![format!("dependent:{}", event.id)]);
+        Ok(event)
+    }
+
+    // ==================== Difficulty ====================
+
+    /// Chosen difficulty, dynamic controller state and its decision log.
+    pub fn difficulty(&self) -> &DifficultyState {
+        &self.world.difficulty
+    }
+
+    /// Set the difficulty chosen at character creation.
+    pub fn set_base_difficulty(&mut self, difficulty: Difficulty) {
+        syn_core::set_base_difficulty(&mut self.world, difficulty);
+    }
+
+    /// Replace the dynamic difficulty controller's tuning (bounds, window,
+    /// thresholds, or `enabled` to switch it off entirely).
+    ///
+    /// The current knobs are clamped into the new bounds.
+    pub fn set_difficulty_config(&mut self, config: DifficultyConfig) {
+        let state = &mut self.world.difficulty;
+        state.config = config;
+        state.negative_scale = state
+            .negative_scale
+            .clamp(config.min_negative_scale, config.max_negative_scale);
+        state.heavy_mix = state
+            .heavy_mix
+            .clamp(config.min_heavy_mix, config.max_heavy_mix);
+    }
+
+    // ==================== Session Pacing ====================
+
+    /// The UI came to the foreground. `at_secs` is the UI's clock; it only
+    /// measures session length, so any monotonic clock works.
+    pub fn session_start(&mut self, at_secs: u64) {
+        let tick = self.world.current_tick.0;
+        self.world.session_pacing.start(at_secs, tick);
+    }
+
+    /// The UI was backgrounded. Returns the recorded session, or `None` if
+    /// no session was open or the clock didn't move forward.
+    pub fn session_end(&mut self, at_secs: u64) -> Option<SessionRecord> {
+        let tick = self.world.current_tick.0;
+        self.world.session_pacing.end(at_secs, tick)
+    }
+
+    /// Where the player is in the open session.
+    pub fn session_phase(&self) -> SessionPhase {
+        self.world.session_pacing.phase(self.world.current_tick.0)
+    }
+
+    // ==================== Float Determinism ====================
+
+    /// Switch float accumulation mode. Quantized worlds snap their
+    /// accumulators to a fixed grid after every tick, starting now.
+    pub fn set_float_mode(&mut self, mode: FloatMode) {
+        self.world.float_mode = mode;
+        syn_core::det_math::quantize_accumulators(&mut self.world);
+    }
+
+    /// Current float accumulation mode.
+    pub fn float_mode(&self) -> FloatMode {
+        self.world.float_mode
+    }
+
+    /// Platform-independent hash of the world's float accumulators, for
+    /// comparing runs of the same seed across devices.
+    pub fn accumulator_digest(&self) -> u64 {
+        syn_core::det_math::accumulator_digest(&self.world)
+    }
+
+    // ==================== PostLife ====================
+
+    /// The PostLife chapter: signal, coherence, digital NPCs and nudges.
+    pub fn post_life(&self) -> &PostLifeState {
+        &self.world.post_life
+    }
+
+    /// Living family members the player's ghost can nudge.
+    pub fn descendants(&self) -> Vec<NpcId> {
+        syn_core::descendants(&self.world)
+    }
+
+    /// Commune with a digital NPC, spending signal to restore coherence.
+    ///
+    /// The communion is recorded in the player's journal.
+    pub fn commune_with_digital_npc(&mut self, id: u64) -> Result<PostLifeEvent, SynError> {
+        let event = syn_core::commune_with_digital_npc(&mut self.world, id)?;
+        self.mirror_world_memories(vec![format!("post_life:{}", event.id)]);
+        Ok(event)
+    }
+
+    /// Nudge a descendant toward storylets tagged `tag`.
+    ///
+    /// The nudge is recorded in the player's journal.
+    pub fn nudge_descendant(&mut self, descendant: NpcId, tag: &str) -> Result<LegacyNudge, SynError> {
+        let nudge = syn_core::nudge_descendant(&mut self.world, descendant, tag)?;
+        let record_ids: Vec<String> = self
+            .world
+            .post_life
+            .recent
+            .last()
+            .map(|event| format!("post_life:{}", event.id))
+            .into_iter()
+            .collect();
+        self.mirror_world_memories(record_ids);
+        Ok(nudge)
+    }
+
+    // ==================== Player Notes ====================
+
+    /// Set the player's note on `target` (`npc:<id>` or `memory:<id>`).
+    /// Blank text clears it.
+    ///
+    /// Returns the stored text, or `None` if the note was cleared. NPC
+    /// targets must exist; memory targets need not, since the entry may
+    /// have been pruned or archived.
+    pub fn set_note(&mut self, target: &str, text: &str) -> Result<Option<String>, SynError> {
+        let target = NoteTarget::parse(target)?;
+        if let NoteTarget::Npc(id) = target {
+            if !self.world.npcs.contains_key(&id) {
+                return Err(SynError::NotFound(format!("NPC {}", id.0)));
+            }
+        }
+        let tick = self.world.current_tick.0;
+        Ok(self
+            .world
+            .player_notes
+            .set(&target, text, tick)
+            .map(|note| note.text.clone()))
+    }
+
+    /// The player's notes.
+    pub fn player_notes(&self) -> &PlayerNotesState {
+        &self.world.player_notes
+    }
+
+    // ==================== Relationship Proposals ====================
+
+    /// Proposals NPCs have made to the player, oldest first.
+    pub fn pending_proposals(&self) -> &[RelationshipProposal] {
+        &self.world.proposals.pending
+    }
+
+    /// Accept or reject a pending proposal.
+    ///
+    /// Both directions of the relationship change and the answer is recorded
+    /// in the player's journal.
+    pub fn respond_to_proposal(
+        &mut self,
+        proposal_id: u64,
+        accept: bool,
+    ) -> Result<ProposalResolution, SynError> {
+        let resolution = syn_core::respond_to_proposal(&mut self.world, proposal_id, accept)?;
+        self.mirror_world_memories(vec![format!("proposal:{}", proposal_id)]);
+        Ok(resolution)
+    }
+
+    // ==================== Major Characters ====================
+
+    /// Major characters, authored or promoted, by NPC id.
+    ///
+    /// Authored majors are registered by the first daily update after their
+    /// prototype is loaded.
+    pub fn major_characters(&self) -> impl Iterator<Item = &MajorCharacter> {
+        self.world.importance.majors.values()
+    }
+
+    /// Check-ins from major characters waiting for an answer, oldest first.
+    pub fn pending_check_ins(&self) -> &[CheckIn] {
+        &self.world.importance.pending_check_ins
+    }
+
+    /// Make time for a pending check-in, or brush it off.
+    ///
+    /// The answer is recorded in the player's journal.
+    pub fn respond_to_check_in(
+        &mut self,
+        check_in_id: u64,
+        catch_up: bool,
+    ) -> Result<CheckInResolution, SynError> {
+        let resolution = syn_core::respond_to_check_in(&mut self.world, check_in_id, catch_up)?;
+        self.mirror_world_memories(vec![format!("check_in:{}", check_in_id)]);
+        Ok(resolution)
+    }
+
+    // ==================== Year in Review ====================
+
+    /// Recap of a finished calendar year (1 = the year the game started in).
+    pub fn year_in_review(&self, year: u32) -> Option<&YearInReview> {
+        syn_core::year_in_review(&self.world, year)
+    }
+
+    /// Recaps of every finished calendar year, oldest first.
+    pub fn years_in_review(&self) -> &[YearInReview] {
+        &self.world.year_review.reviews
+    }
+
+    // ==================== Action Budget ====================
+
+    /// The player's remaining energy for today.
+    pub fn action_budget(&self) -> &ActionBudget {
+        &self.world.action_budget
+    }
+
+    // ==================== Underworld ====================
+
+    /// The player's underworld exposure track.
+    pub fn underworld_exposure(&self) -> &UnderworldExposure {
+        &self.world.underworld
+    }
+
+    // ==================== External Events ====================
+
+    /// Validate and apply an external event described by `kind` and a JSON payload.
+    ///
+    /// Memory events are also recorded in the NPC's journal. Returns the
+    /// narrative log sequence number; rejected events change nothing.
+    pub fn inject_event(&mut self, kind: &str, payload: &str) -> Result<u64, SynError> {
+        let event = ExternalEvent::parse(kind, payload)?;
+        let injected = syn_core::inject_external_event(&mut self.world, event)?;
+
+        if let Some(record) = injected.memory {
+            let mut entry = MemoryEntry::new(
+                record.id,
+                record.event_id,
+                record.npc_id,
+                record.sim_tick,
+                record.emotional_intensity,
+            );
+            entry.tags = record.tags;
+            entry.participants = record.participants;
+            let ctx = MemoryIntensityContext::for_npc(&self.world, record.npc_id);
+            self.memory.record_memory(entry, ctx.as_ref());
+        }
+
+        Ok(injected.seq)
+    }
+
+    /// Most recent injected events first, at most `limit`.
+    pub fn narrative_log(&self, limit: usize) -> Vec<&NarrativeLogEntry> {
+        self.world.external_events.latest(limit).collect()
+    }
+
+    // ==================== Event Bus ====================
+
+    /// Take the world events published since the last poll, oldest first.
+    pub fn poll_events(&mut self) -> Vec<PublishedEvent> {
+        self.world.event_bus.drain()
+    }
+
+    // ==================== Scripted Outcomes ====================
+
+    /// Apply `(storylet_id, outcome)` pairs at the current tick as one
+    /// transaction, bypassing selection (see
+    /// [`EventDirector::apply_outcome_batch`]). Rejected batches change nothing.
+    pub fn apply_outcome_batch(
+        &mut self,
+        batch: Vec<(String, StoryletOutcome)>,
+    ) -> Result<OutcomeBatchReport, OutcomeBatchError> {
+        let tick = self.world.current_tick;
+        self.director
+            .apply_outcome_batch(&mut self.world, &mut self.memory, batch, tick)
+    }
+
+    /// Fire `storylet_id` now, bypassing scoring (dev tools, scripted demos).
+    ///
+    /// `cast_overrides` pins `(role, npc_id)` pairs before casting. Failed
+    /// prerequisites are reported as warnings rather than blocking the fire
+    /// (see [`EventDirector::force_fire_storylet`]).
+    pub fn force_fire_storylet(
+        &mut self,
+        storylet_id: &str,
+        cast_overrides: &[(String, u64)],
+    ) -> Result<ForcedFire, ForceFireError> {
+        let overrides: Vec<(String, NpcId)> = cast_overrides
+            .iter()
+            .map(|(role, npc_id)| (role.clone(), NpcId(*npc_id)))
+            .collect();
+        let tick = self.world.current_tick;
+        self.director.force_fire_storylet(
+            storylet_id,
+            &overrides,
+            &mut self.world,
+            &mut self.memory,
+            tick,
+        )
+    }
+
+    /// Storylets forced with [`Self::force_fire_storylet`], oldest first.
+    pub fn forced_fires(&self) -> &[ForcedFire] {
+        self.director.forced_fires()
+    }
+
+    /// Storylets fired at or after `since_tick`, oldest first, for the life
+    /// timeline.
+    pub fn chronicle(&self, since_tick: u64) -> Vec<ApiChronicleEntry> {
+        self.director
+            .chronicle()
+            .since(since_tick)
+            .into_iter()
+            .map(ApiChronicleEntry::from)
+            .collect()
+    }
+
+    /// Storylets that cast `npc_id` in any role, oldest first.
+    pub fn npc_chronicle(&self, npc_id: u64) -> Vec<ApiChronicleEntry> {
+        self.director
+            .chronicle()
+            .involving(NpcId(npc_id))
+            .into_iter()
+            .map(ApiChronicleEntry::from)
+            .collect()
+    }
+
+    /// Active storylet cooldowns, as they would be saved.
+    pub fn storylet_cooldowns(&self) -> Vec<CooldownEntry> {
+        self.director.cooldown_entries()
+    }
+
+    /// Re-read the storylets and swap them into the director.
+    ///
+    /// Re-reads `SYN_STORYLET_DB` and layers a directory of content-format
+    /// storylet JSON over it (see [`syn_content::load_storylet_packs`]): `path`
+    /// when given, otherwise `SYN_STORYLET_DIR`, as at startup. Cooldowns of
+    /// storylets that are still there and every usage count carry over. Any
+    /// unreadable or malformed file fails the reload and nothing is replaced.
+    pub fn reload_storylets(&mut self, path: Option<&Path>) -> Result<LibraryReload, SynError> {
+        if let Some(path) = path {
+            if !path.exists() {
+                return Err(SynError::NotFound(format!(
+                    "storylet directory {}",
+                    path.display()
+                )));
+            }
+        }
+        let storylets = reload_content_storylets(path)?
+            .into_iter()
+            .map(director_storylet_from_content)
+            .collect();
+        Ok(self.director.replace_storylets(storylets))
+    }
+
+    /// Swap in already-built director storylets for the registered ones,
+    /// keeping cooldowns as [`GameEngine::reload_storylets`] does.
+    pub fn replace_storylets(&mut self, storylets: Vec<Storylet>) -> LibraryReload {
+        self.director.replace_storylets(storylets)
+    }
+
+    // ==================== World Statistics ====================
+
+    /// Population statistics (age, employment, warmth, tiers).
+    pub fn world_stats(&mut self) -> syn_sim::WorldStats {
+        self.world_sim.world_stats(&self.world)
+    }
 }
 
 // ==================== Data Transfer Objects (DTOs) for Dart ====================
@@ -669,7 +2060,7 @@ impl GameEngine {
 /// Player stats snapshot for serialization to Dart.
 ///
 /// Contains all stat values and the current mood band label.
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
 pub struct ApiStatsSnapshot {
     /// All player stats with their current values.
     pub stats: Vec<ApiStat>,
@@ -719,6 +2110,8 @@ pub struct NpcDto {
     pub job: String,
     /// District where NPC resides.
     pub district: String,
+    /// The player's note on this NPC.
+    pub note: Option<String>,
 }
 
 /// Relationship axes DTO for serialization to Dart.
@@ -769,6 +2162,10 @@ pub struct ApiRelationship {
     pub resentment_band: String,
     /// High-level summary for UI tags: "Friend", "Rival", "Crush", "Stranger", etc.
     pub role_label: String,
+    /// Recent interaction heat of the pair (0.0 to 1.0); decays without contact.
+    pub heat: f32,
+    /// Heat change since the last daily sample (-1.0 cooling to +1.0 rising).
+    pub heat_trend: f32,
 }
 
 /// Snapshot of all player relationships for UI display.
@@ -778,6 +2175,103 @@ pub struct ApiRelationshipSnapshot {
     pub relationships: Vec<ApiRelationship>,
 }
 
+/// Player relationships changed since a tick, for incremental UI updates.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ApiRelationshipChanges {
+    /// Current tick; pass it as `since_tick` on the next poll.
+    pub tick: u64,
+    /// Changed relationships, in target order.
+    pub relationships: Vec<ApiRelationship>,
+    /// Targets whose relationship with the player no longer exists.
+    pub removed_target_ids: Vec<i64>,
+}
+
+/// A relationship axis crossing into another band.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ApiRelationshipPressureEvent {
+    /// NPC whose feelings changed.
+    pub actor_id: i64,
+    /// NPC the feelings are about.
+    pub target_id: i64,
+    /// Display name of the target.
+    pub target_name: String,
+    /// Which band changed (e.g. "TrustBandChanged", "ResentmentBandChanged").
+    pub kind: String,
+    /// Band before.
+    pub old_band: String,
+    /// Band after.
+    pub new_band: String,
+    /// What caused it (e.g. "storylet:<id>", "drift").
+    pub source: Option<String>,
+    /// Tick of the change, when known.
+    pub tick: Option<u64>,
+}
+
+impl ApiRelationshipPressureEvent {
+    /// DTO for `event`, naming its target from `world`.
+    pub fn from_event(world: &WorldState, event: &RelationshipPressureEvent) -> Self {
+        ApiRelationshipPressureEvent {
+            actor_id: event.actor_id as i64,
+            target_id: event.target_id as i64,
+            target_name: syn_core::npc_name(world, NpcId(event.target_id)),
+            kind: format!("{:?}", event.kind),
+            old_band: event.old_band.clone(),
+            new_band: event.new_band.clone(),
+            source: event.source.clone(),
+            tick: event.tick,
+        }
+    }
+}
+
+/// A significant relationship role transition (e.g. friends becoming family).
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ApiRelationshipMilestoneEvent {
+    /// NPC whose relationship changed.
+    pub actor_id: i64,
+    /// NPC the relationship is with.
+    pub target_id: i64,
+    /// Display name of the target.
+    pub target_name: String,
+    /// Milestone kind (e.g. "FriendToRival", "FriendToFamily").
+    pub kind: String,
+    /// Role before.
+    pub from_role: String,
+    /// Role after.
+    pub to_role: String,
+    /// Why it happened ("betrayal memory", "shared trauma", ...).
+    pub reason: String,
+    /// What caused it (e.g. "storylet:<id>", "drift").
+    pub source: Option<String>,
+    /// Tick of the change, when known.
+    pub tick: Option<u64>,
+}
+
+impl ApiRelationshipMilestoneEvent {
+    /// DTO for `event`, naming its target from `world`.
+    pub fn from_event(world: &WorldState, event: &RelationshipMilestoneEvent) -> Self {
+        ApiRelationshipMilestoneEvent {
+            actor_id: event.actor_id as i64,
+            target_id: event.target_id as i64,
+            target_name: syn_core::npc_name(world, NpcId(event.target_id)),
+            kind: format!("{:?}", event.kind),
+            from_role: event.from_role.clone(),
+            to_role: event.to_role.clone(),
+            reason: event.reason.clone(),
+            source: event.source.clone(),
+            tick: event.tick,
+        }
+    }
+}
+
+/// Relationship events not yet shown to the player, for toasts.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct ApiRelationshipEvents {
+    /// Band shifts, oldest first.
+    pub pressure: Vec<ApiRelationshipPressureEvent>,
+    /// Role milestones, oldest first.
+    pub milestones: Vec<ApiRelationshipMilestoneEvent>,
+}
+
 /// Memory entry DTO for serialization to Dart.
 #[derive(Debug, Clone)]
 pub struct MemoryDto {
@@ -789,6 +2283,8 @@ pub struct MemoryDto {
     pub emotional_intensity: f32,
     /// Simulation tick when memory was created.
     pub sim_tick: u64,
+    /// The player's note on this memory.
+    pub note: Option<String>,
 }
 
 /// Event/storylet DTO for serialization to Dart.
@@ -809,6 +2305,9 @@ pub struct ApiDirectorChoiceView {
     pub id: String,
     /// Display label for the choice.
     pub label: String,
+    /// Whether the player lacks the skills for this choice. Locked choices
+    /// are shown but rejected if submitted.
+    pub locked: bool,
 }
 
 /// Director event view DTO for UI display.
@@ -822,6 +2321,39 @@ pub struct ApiDirectorEventView {
     pub title: String,
     /// Available choices for the player.
     pub choices: Vec<ApiDirectorChoiceView>,
+    /// Idempotency token to submit with the choice (0 for previews, which
+    /// were never presented).
+    pub event_token: u64,
+    /// How each NPC in the scene feels about the player, for tinting.
+    pub tone: Vec<ApiToneContext>,
+}
+
+/// How an NPC's history with the player should color their lines.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ApiToneContext {
+    /// The NPC speaking.
+    pub npc_id: u64,
+    /// "warm", "neutral", "guarded" or "bitter".
+    pub tone: String,
+    /// Grudge against the player (0..1).
+    pub grudge: f32,
+    /// Favor toward the player (0..1).
+    pub favor: f32,
+    /// Presentation flags ("remembers_betrayal", "remembers_kindness",
+    /// "grudge", "fond").
+    pub flags: Vec<String>,
+}
+
+impl From<ToneContext> for ApiToneContext {
+    fn from(ctx: ToneContext) -> Self {
+        ApiToneContext {
+            npc_id: ctx.npc_id.0,
+            tone: ctx.tone.as_str().to_string(),
+            grudge: ctx.grudge,
+            favor: ctx.favor,
+            flags: ctx.flags,
+        }
+    }
 }
 
 impl From<DirectorEventView> for ApiDirectorEventView {
@@ -835,14 +2367,157 @@ impl From<DirectorEventView> for ApiDirectorEventView {
                 .map(|c| ApiDirectorChoiceView {
                     id: c.id,
                     label: c.label,
+                    locked: c.locked,
                 })
                 .collect(),
+            event_token: 0,
+            tone: view.tone.into_iter().map(ApiToneContext::from).collect(),
         }
     }
 }
 
-/// Digital legacy vector DTO for serialization to Dart.
-///
+/// Why a choice submission was rejected without applying anything.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub enum ApiChoiceRejection {
+    /// No event is currently presented.
+    NoEventPresented,
+    /// The token belongs to an event that has since been replaced.
+    StaleEvent {
+        /// Token submitted.
+        token: u64,
+        /// Token of the event now presented.
+        current_token: u64,
+    },
+    /// The token was already used; the choice has been applied once.
+    DuplicateSubmission {
+        /// Token submitted.
+        token: u64,
+    },
+    /// The storylet isn't the one presented.
+    StoryletMismatch {
+        /// Storylet presented.
+        presented: String,
+        /// Storylet submitted.
+        submitted: String,
+    },
+    /// The presented storylet has no such choice.
+    UnknownChoice {
+        /// Storylet presented.
+        storylet_id: String,
+        /// Choice submitted.
+        choice_id: String,
+    },
+}
+
+impl std::fmt::Display for ApiChoiceRejection {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            ApiChoiceRejection::NoEventPresented => write!(f, "no event is presented"),
+            ApiChoiceRejection::StaleEvent {
+                token,
+                current_token,
+            } => write!(
+                f,
+                "event token {} is stale (current is {})",
+                token, current_token
+            ),
+            ApiChoiceRejection::DuplicateSubmission { token } => {
+                write!(f, "event token {} was already submitted", token)
+            }
+            ApiChoiceRejection::StoryletMismatch {
+                presented,
+                submitted,
+            } => write!(
+                f,
+                "storylet '{}' is not the presented event '{}'",
+                submitted, presented
+            ),
+            ApiChoiceRejection::UnknownChoice {
+                storylet_id,
+                choice_id,
+            } => write!(
+                f,
+                "storylet '{}' has no choice '{}'",
+                storylet_id, choice_id
+            ),
+        }
+    }
+}
+
+impl std::error::Error for ApiChoiceRejection {}
+
+/// Result of submitting a choice for a presented event.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ApiChoiceSubmission {
+    /// Whether the choice was applied.
+    pub accepted: bool,
+    /// The next event, if one is eligible after applying the choice.
+    pub next_event: Option<ApiDirectorEventView>,
+    /// Why the submission was rejected, if it was.
+    pub rejection: Option<ApiChoiceRejection>,
+}
+
+impl From<Result<Option<ApiDirectorEventView>, ApiChoiceRejection>> for ApiChoiceSubmission {
+    fn from(result: Result<Option<ApiDirectorEventView>, ApiChoiceRejection>) -> Self {
+        match result {
+            Ok(next_event) => ApiChoiceSubmission {
+                accepted: true,
+                next_event,
+                rejection: None,
+            },
+            Err(rejection) => ApiChoiceSubmission {
+                accepted: false,
+                next_event: None,
+                rejection: Some(rejection),
+            },
+        }
+    }
+}
+
+/// One eligible storylet in an [`ApiDirectorPreview`].
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ApiDirectorPreviewCandidate {
+    /// The storylet's unique identifier.
+    pub storylet_id: String,
+    /// Display title for the event.
+    pub title: String,
+    /// Selection weight.
+    pub weight: f32,
+    /// Chance of being drawn (0.0-1.0).
+    pub chance: f32,
+}
+
+/// Dry run of director selection: what would fire next and why.
+///
+/// Computing it never changes the simulation, so dev tools can poll it.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ApiDirectorPreview {
+    /// The event the director would present next, if any.
+    pub selected: Option<ApiDirectorEventView>,
+    /// Every eligible storylet, highest weight first.
+    pub candidates: Vec<ApiDirectorPreviewCandidate>,
+}
+
+impl From<DirectorPreview> for ApiDirectorPreview {
+    fn from(preview: DirectorPreview) -> Self {
+        ApiDirectorPreview {
+            selected: preview.selected.map(ApiDirectorEventView::from),
+            candidates: preview
+                .candidates
+                .into_iter()
+                .map(|c| ApiDirectorPreviewCandidate {
+                    storylet_id: c.storylet_id,
+                    title: c.title,
+                    weight: c.weight,
+                    chance: c.chance,
+                })
+                .collect(),
+        }
+    }
+}
+
+/// Digital legacy vector DTO for serialization to Dart.
+///
 /// The five-axis legacy vector summarizes the player's life choices.
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct ApiLegacyVector {
@@ -1081,68 +2756,6 @@ pub struct ApiCityStats {
     pub wealthiest_district: Option<String>,
 }
 
-// ==================== Director Loop API ====================
-
-/// Test-only helper to replace the shared runtime state.
-///
-/// **NOT EXPOSED TO FLUTTER.** This function is hidden from documentation
-/// and is used to inject custom world/sim/storylet state for integration testing.
-///
-/// Replaces the global `RUNTIME` state with the provided components.
-#[doc(hidden)]
-pub fn api_reset_runtime(world: WorldState, sim: SimState, storylets: StoryletLibrary) {
-    let mut guard = RUNTIME.lock().expect("GameRuntime poisoned");
-    *guard = GameRuntime {
-        world,
-        sim,
-        storylets,
-    };
-}
-
-/// Get the current event from the director.
-///
-/// Returns the next eligible storylet event for the player, or None if
-/// no events are currently eligible.
-#[frb(sync)]
-pub fn api_get_current_event() -> Option<ApiDirectorEventView> {
-    let mut guard = RUNTIME.lock().expect("GameRuntime poisoned");
-    let runtime = &mut *guard;
-
-    let view = select_next_event_view(&mut runtime.world, &mut runtime.sim, &runtime.storylets)?;
-    Some(ApiDirectorEventView::from(view))
-}
-
-/// Process a player's choice and advance time.
-///
-/// Applies the selected choice's effects, advances the simulation by
-/// `ticks_to_advance` ticks, and returns the next available event.
-///
-/// # Arguments
-///
-/// * `storylet_id` - ID of the current storylet
-/// * `choice_id` - ID of the selected choice
-/// * `ticks_to_advance` - Number of ticks to advance after applying the choice
-#[frb(sync)]
-pub fn api_choose_option(
-    storylet_id: String,
-    choice_id: String,
-    ticks_to_advance: u32,
-) -> Option<ApiDirectorEventView> {
-    let mut guard = RUNTIME.lock().expect("GameRuntime poisoned");
-    let runtime = &mut *guard;
-
-    let view = apply_choice_and_advance(
-        &mut runtime.world,
-        &mut runtime.sim,
-        &runtime.storylets,
-        &storylet_id,
-        &choice_id,
-        ticks_to_advance,
-    )?;
-
-    Some(ApiDirectorEventView::from(view))
-}
-
 // ==================== Core World Management API ====================
 
 /// Unified game state snapshot for Flutter UI.
@@ -1195,376 +2808,209 @@ pub struct ApiMemoryJournalEntry {
     pub description: Option<String>,
     /// Memory tags for categorization.
     pub tags: Vec<String>,
+    /// The player's note on this entry.
+    pub note: Option<String>,
 }
 
-// ==================== Frb Wrapper (Async Support) ====================
-
-/// Global engine instance (protected by Mutex for thread safety).
-static ENGINE: Mutex<Option<GameEngine>> = Mutex::new(None);
-
-/// Initialize the game engine with a world seed.
-/// This is the primary initialization function Flutter should call.
-#[frb(sync)]
-pub fn init_world(seed: u64) {
-    let mut engine = ENGINE.lock().unwrap();
-    *engine = Some(GameEngine::new(seed));
-}
-
-/// Alias for backwards compatibility.
-#[frb(sync)]
-pub fn init_engine(seed: u64) {
-    init_world(seed);
-}
-
-/// Load a saved world state (placeholder for save/load system).
-/// Currently reinitializes with the same seed.
-#[frb(sync)]
-pub fn load_world(seed: u64) -> bool {
-    init_world(seed);
-    true
-}
-
-/// Advance the simulation by a specified number of ticks.
-/// This is the primary time-step function Flutter should call.
-#[frb(sync)]
-pub fn step_world(ticks: u32) {
-    let mut engine = ENGINE.lock().unwrap();
-    if let Some(ref mut e) = *engine {
-        for _ in 0..ticks {
-            e.tick();
+impl From<&MemoryEntry> for ApiMemoryJournalEntry {
+    fn from(entry: &MemoryEntry) -> Self {
+        ApiMemoryJournalEntry {
+            id: entry.id.clone(),
+            event_id: entry.event_id.clone(),
+            npc_id: entry.npc_id.0 as i64,
+            sim_tick: entry.sim_tick.0,
+            emotional_intensity: entry.emotional_intensity,
+            description: None,
+            tags: entry.tags.clone(),
+            note: None,
         }
     }
 }
 
-/// Tick the engine by 1 tick (thread-safe).
-#[frb(sync)]
-pub fn engine_tick() {
-    step_world(1);
-}
-
-/// Advance the simulation by multiple ticks.
-#[frb(sync)]
-pub fn engine_tick_many(count: u32) {
-    step_world(count);
+/// Journal search box query. Empty filters match everything.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct ApiMemorySearchQuery {
+    /// Free text matched against event ids and tags.
+    pub text: Option<String>,
+    /// Tags every result must carry.
+    pub tags: Vec<String>,
+    /// NPC ids every result must involve.
+    pub participants: Vec<u64>,
+    /// Lowest emotional intensity (-1.0 to +1.0).
+    pub min_intensity: Option<f32>,
+    /// Highest emotional intensity (-1.0 to +1.0).
+    pub max_intensity: Option<f32>,
+    /// Zero-based page index.
+    pub page: u32,
+    /// Results per page (0 uses the default of 20).
+    pub page_size: u32,
 }
 
-/// Get unified game state snapshot for UI.
-/// This is the primary state accessor Flutter should call.
-#[frb(sync)]
-pub fn get_game_state_snapshot() -> Option<ApiGameStateSnapshot> {
-    let engine = ENGINE.lock().unwrap();
-    engine.as_ref().map(|e| {
-        ApiGameStateSnapshot {
-            current_tick: e.current_tick(),
-            player_age_years: e.player_age(),
-            life_stage: e.player_life_stage(),
-            stats: e.player_stats(),
-            relationships: e.player_relationships(),
-            narrative_heat: e.narrative_heat(),
-            heat_level: e.narrative_heat_level(),
-            heat_trend: e.narrative_heat_trend(),
-            current_event: api_get_current_event(),
-            karma: e.player_karma(),
-            karma_band: e.player_karma_band(),
-            mood_band: e.get_mood_band(),
-            life_stage_info: e.life_stage_info(),
+impl From<ApiMemorySearchQuery> for MemoryQuery {
+    fn from(query: ApiMemorySearchQuery) -> Self {
+        let limit = if query.page_size == 0 {
+            MemoryQuery::default().limit
+        } else {
+            query.page_size as usize
+        };
+        MemoryQuery {
+            text: query.text,
+            tags: query.tags,
+            participants: query.participants,
+            min_intensity: query.min_intensity,
+            max_intensity: query.max_intensity,
+            offset: query.page as usize * limit,
+            limit,
         }
-    })
-}
-
-/// Get player age.
-#[frb(sync)]
-pub fn engine_player_age() -> u32 {
-    let engine = ENGINE.lock().unwrap();
-    engine.as_ref().map(|e| e.player_age()).unwrap_or(0)
-}
-
-/// Get player stats snapshot (primary accessor for UI).
-#[frb(sync)]
-pub fn get_player_stats() -> ApiStatsSnapshot {
-    let engine = ENGINE.lock().unwrap();
-    engine
-        .as_ref()
-        .map(|e| e.player_stats())
-        .unwrap_or(ApiStatsSnapshot {
-            stats: vec![],
-            mood_band: "Unknown".to_string(),
-        })
-}
-
-/// Get player mood value.
-#[frb(sync)]
-pub fn get_player_mood() -> f32 {
-    let engine = ENGINE.lock().unwrap();
-    engine
-        .as_ref()
-        .map(|e| e.world.player_stats.get(StatKind::Mood))
-        .unwrap_or(0.0)
-}
-
-/// Get player karma value.
-#[frb(sync)]
-pub fn get_player_karma() -> f32 {
-    let engine = ENGINE.lock().unwrap();
-    engine.as_ref().map(|e| e.player_karma()).unwrap_or(0.0)
-}
-
-/// Backwards compatibility alias.
-#[frb(sync)]
-pub fn engine_player_mood() -> f32 {
-    get_player_mood()
-}
-
-/// Get player relationships snapshot via the global engine.
-#[frb(sync)]
-pub fn engine_player_relationships() -> ApiRelationshipSnapshot {
-    let engine = ENGINE.lock().unwrap();
-    engine
-        .as_ref()
-        .map(|e| e.player_relationships())
-        .unwrap_or(ApiRelationshipSnapshot {
-            relationships: vec![],
-        })
+    }
 }
 
-/// Get current storylet/event card for UI display.
-/// Returns the next eligible storylet, or None if no events are available.
-#[frb(sync)]
-pub fn get_current_storylet() -> Option<ApiDirectorEventView> {
-    api_get_current_event()
+/// A journal entry matched by a search, with its rank score.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ApiMemorySearchHit {
+    /// The matching memory.
+    pub entry: ApiMemoryJournalEntry,
+    /// Salience-weighted relevance; higher ranks first.
+    pub score: f32,
 }
 
-/// Get available choices for the current event.
-/// Returns empty vector if no event is active.
-#[frb(sync)]
-pub fn get_available_choices() -> Vec<ApiDirectorChoiceView> {
-    api_get_current_event()
-        .map(|event| event.choices)
-        .unwrap_or_default()
+/// One page of journal search results.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct ApiMemorySearchResults {
+    /// Hits on this page, best first.
+    pub hits: Vec<ApiMemorySearchHit>,
+    /// Hits across all pages.
+    pub total: u32,
+    /// Page index returned.
+    pub page: u32,
+    /// Page size used.
+    pub page_size: u32,
 }
 
-/// Get player memory journal entries.
-/// Returns all memories for the player character.
-#[frb(sync)]
-pub fn get_memory_journal() -> Vec<ApiMemoryJournalEntry> {
-    let engine = ENGINE.lock().unwrap();
-    engine
-        .as_ref()
-        .map(|e| {
-            e.get_npc_memories(e.world.player_id.0)
-                .into_iter()
-                .map(|mem| ApiMemoryJournalEntry {
-                    id: mem.id,
-                    event_id: mem.event_id,
-                    npc_id: e.world.player_id.0 as i64,
-                    sim_tick: mem.sim_tick,
-                    emotional_intensity: mem.emotional_intensity,
-                    description: None, // MemoryDto doesn't have description
-                    tags: vec![], // MemoryDto doesn't have tags
+impl ApiMemorySearchResults {
+    /// Build a results page for `query` (already converted to `MemoryQuery`).
+    pub fn from_page(page: &MemorySearchPage<'_>, query: &MemoryQuery) -> Self {
+        ApiMemorySearchResults {
+            hits: page
+                .hits
+                .iter()
+                .map(|hit| ApiMemorySearchHit {
+                    entry: ApiMemoryJournalEntry::from(hit.entry),
+                    score: hit.score,
                 })
-                .collect()
-        })
-        .unwrap_or_default()
-}
-
-/// Get relationship network slice for visualization.
-/// Returns player relationships with extended metadata.
-#[frb(sync)]
-pub fn get_relationship_network() -> ApiRelationshipSnapshot {
-    engine_player_relationships()
-}
-
-/// Get life-stage summary for end-of-life/digital legacy screen.
-/// Returns digital legacy snapshot if in Digital stage.
-#[frb(sync)]
-pub fn get_life_stage_summary() -> ApiDigitalLegacySnapshot {
-    engine_get_digital_legacy()
-}
-
-/// Get current narrative heat value.
-#[frb(sync)]
-pub fn engine_narrative_heat() -> f32 {
-    let engine = ENGINE.lock().unwrap();
-    engine.as_ref().map(|e| e.narrative_heat()).unwrap_or(0.0)
-}
-
-/// Get current narrative heat level label.
-#[frb(sync)]
-pub fn engine_narrative_heat_level() -> String {
-    let engine = ENGINE.lock().unwrap();
-    engine
-        .as_ref()
-        .map(|e| e.narrative_heat_level())
-        .unwrap_or_else(|| "Low".to_string())
-}
-
-/// Get normalized heat trend (-1.0..1.0).
-#[frb(sync)]
-pub fn engine_narrative_heat_trend() -> f32 {
-    let engine = ENGINE.lock().unwrap();
-    engine
-        .as_ref()
-        .map(|e| e.narrative_heat_trend())
-        .unwrap_or(0.0)
-}
-
-/// Get life stage info (stage label, age, visibility flags).
-#[frb(sync)]
-pub fn engine_life_stage_info() -> ApiLifeStageInfo {
-    let engine = ENGINE.lock().unwrap();
-    engine
-        .as_ref()
-        .map(|e| e.life_stage_info())
-        .unwrap_or(ApiLifeStageInfo {
-            life_stage: "Unknown".to_string(),
-            player_age_years: 0,
-            show_wealth: false,
-            show_reputation: false,
-            show_wisdom: false,
-            show_karma: false,
-        })
-}
+                .collect(),
+            total: page.total as u32,
+            page: (query.offset / query.limit.max(1)) as u32,
+            page_size: query.limit as u32,
+        }
+    }
 
-/// Get all NPC IDs.
-#[frb(sync)]
-pub fn engine_list_npcs() -> Vec<u64> {
-    let engine = ENGINE.lock().unwrap();
-    engine.as_ref().map(|e| e.list_npcs()).unwrap_or_default()
+    /// Attach the player's notes to the hits.
+    pub fn with_notes(mut self, notes: &PlayerNotesState) -> Self {
+        for hit in &mut self.hits {
+            hit.entry.note = notes.memory_note(&hit.entry.id);
+        }
+        self
+    }
 }
 
-/// Register an NPC.
-#[frb(sync)]
-pub fn engine_register_npc(npc_id: u64, age: u32, job: String, district: String) {
-    let mut engine = ENGINE.lock().unwrap();
-    if let Some(ref mut e) = *engine {
-        e.register_npc(npc_id, age, job, district);
-    }
+/// Current version of the journal export format. Bump it when a field's
+/// meaning changes; adding optional fields does not need a bump.
+pub const JOURNAL_EXPORT_SCHEMA_VERSION: u32 = 1;
+
+/// Human-readable time of a tick, e.g. "Day 3, Evening".
+fn journal_timestamp(tick: u64) -> String {
+    let time = GameTime::from_tick(tick);
+    let phase = match time.phase {
+        DayPhase::Morning => "Morning",
+        DayPhase::Afternoon => "Afternoon",
+        DayPhase::Evening => "Evening",
+        DayPhase::Night => "Night",
+    };
+    format!("Day {}, {}", time.day() + 1, phase)
 }
 
-/// Ensure digital imprint is created for PostLife stage.
-#[frb(sync)]
-pub fn engine_ensure_digital_imprint() {
-    let mut engine = ENGINE.lock().unwrap();
-    if let Some(ref mut e) = *engine {
-        e.ensure_digital_imprint();
-    }
+/// A versioned journal export for offline storage in the Flutter journal.
+///
+/// This is a stable format: the engine's internal memory representation
+/// may change, but exports with a supported `schema_version` stay readable.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ApiJournalExport {
+    /// Format version ([`JOURNAL_EXPORT_SCHEMA_VERSION`] when exported).
+    pub schema_version: u32,
+    /// NPC who holds these memories.
+    pub npc_id: u64,
+    /// Holder's display name at export time.
+    pub npc_name: String,
+    /// Tick the export was taken.
+    pub exported_at_tick: u64,
+    /// Readable export time, e.g. "Day 3, Evening".
+    pub exported_at: String,
+    /// Entries, newest first.
+    pub entries: Vec<ApiJournalExportEntry>,
 }
 
-/// Get digital legacy snapshot (imprint).
-#[frb(sync)]
-pub fn engine_get_digital_legacy() -> ApiDigitalLegacySnapshot {
-    let engine = ENGINE.lock().unwrap();
-    engine
-        .as_ref()
-        .map(|e| e.get_digital_legacy_snapshot())
-        .unwrap_or(ApiDigitalLegacySnapshot {
-            has_imprint: false,
-            imprint: None,
-        })
+/// One exported memory.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ApiJournalExportEntry {
+    /// Memory identifier.
+    pub id: String,
+    /// Event identifier.
+    pub event_id: String,
+    /// Simulation tick when created.
+    pub sim_tick: u64,
+    /// Readable time of the memory, e.g. "Day 3, Evening".
+    pub timestamp: String,
+    /// Emotional intensity (-1.0 to +1.0).
+    pub emotional_intensity: f32,
+    /// Memory tags for categorization.
+    #[serde(default)]
+    pub tags: Vec<String>,
+    /// Other NPCs involved, with names resolved at export time.
+    #[serde(default)]
+    pub participants: Vec<ApiJournalParticipant>,
 }
 
-// ==================== Character Generation API ====================
-
-/// Generate a character from seed and config.
-/// Returns a fully procedurally generated character with stats, personality, and background.
-#[frb(sync)]
-pub fn engine_generate_character(
-    world_seed: u64,
-    name: String,
-    archetype: String,
-    difficulty: String,
-    sfw_mode: bool,
-) -> Option<ApiGeneratedCharacter> {
-    let archetype_enum = CharacterArchetype::from_str(&archetype)?;
-    let difficulty_enum = Difficulty::from_str(&difficulty)?;
-    
-    let config = CharacterGenConfig {
-        name,
-        archetype: archetype_enum,
-        difficulty: difficulty_enum,
-        sfw_mode,
-    };
-    
-    let gen = generate_character(world_seed, &config);
-    
-    Some(ApiGeneratedCharacter {
-        name: gen.name,
-        archetype: gen.archetype.as_str().to_string(),
-        difficulty: gen.difficulty.as_str().to_string(),
-        sfw_mode: gen.sfw_mode,
-        family_structure: format!("{:?}", gen.family_structure),
-        socioeconomic_tier: format!("{:?}", gen.socioeconomic_tier),
-        early_life_event: format!("{:?}", gen.early_life_event),
-        attachment_style: format!("{:?}", gen.attachment_style),
-        luck_seed: gen.luck_seed,
-        starting_district: gen.starting_district,
-        has_early_trauma: gen.has_early_trauma,
-        stats: ApiStatsSnapshot {
-            stats: ALL_STAT_KINDS
+impl ApiJournalExportEntry {
+    /// Export `entry`, resolving participant names against `world`.
+    pub fn from_entry(world: &WorldState, entry: &MemoryEntry) -> Self {
+        ApiJournalExportEntry {
+            id: entry.id.clone(),
+            event_id: entry.event_id.clone(),
+            sim_tick: entry.sim_tick.0,
+            timestamp: journal_timestamp(entry.sim_tick.0),
+            emotional_intensity: entry.emotional_intensity,
+            tags: entry.tags.clone(),
+            participants: entry
+                .participants
                 .iter()
-                .map(|kind| ApiStat {
-                    kind: format!("{:?}", kind),
-                    value: gen.stats.get(*kind),
+                .map(|&id| ApiJournalParticipant {
+                    npc_id: id,
+                    name: syn_core::npc_name(world, NpcId(id)),
                 })
                 .collect(),
-            mood_band: format!("{:?}", gen.stats.mood_band()),
-        },
-        personality: ApiPersonalityVector {
-            warmth: gen.personality.warmth,
-            dominance: gen.personality.dominance,
-            volatility: gen.personality.volatility,
-            conscientiousness: gen.personality.conscientiousness,
-            openness: gen.personality.openness,
-        },
-        starting_karma: gen.karma.0,
-    })
-}
-
-/// Initialize game engine with a generated character.
-/// Combines character generation and engine init into one call.
-#[frb(sync)]
-pub fn engine_init_with_character(
-    world_seed: u64,
-    name: String,
-    archetype: String,
-    difficulty: String,
-    sfw_mode: bool,
-) -> bool {
-    // Parse enums
-    let Some(archetype_enum) = CharacterArchetype::from_str(&archetype) else {
-        return false;
-    };
-    let Some(difficulty_enum) = Difficulty::from_str(&difficulty) else {
-        return false;
-    };
-    
-    let config = CharacterGenConfig {
-        name,
-        archetype: archetype_enum,
-        difficulty: difficulty_enum,
-        sfw_mode,
-    };
-    
-    let gen = generate_character(world_seed, &config);
-    
-    // Init the engine
-    let mut engine = ENGINE.lock().unwrap();
-    let mut game_engine = GameEngine::new(world_seed);
-    
-    // Store attachment style (stored on player NPC)
-    if let Some(player_npc) = game_engine.world.npcs.get_mut(&game_engine.world.player_id) {
-        player_npc.attachment_style = gen.attachment_style;
+        }
     }
-    
-    *engine = Some(game_engine);
-    true
 }
 
-/// Get difficulty modifiers for UI display.
-#[frb(sync)]
-pub fn get_difficulty_modifiers(difficulty: String) -> Option<(f32, f32)> {
-    let diff = Difficulty::from_str(&difficulty)?;
-    Some((diff.negative_modifier(), diff.positive_modifier()))
+/// An NPC involved in an exported memory.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ApiJournalParticipant {
+    /// NPC id.
+    pub npc_id: u64,
+    /// Display name at export time.
+    pub name: String,
+}
+
+/// Result of importing a journal export (dev tooling).
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct ApiJournalImportResult {
+    /// Entries added to the journal.
+    pub imported: u32,
+    /// Entries skipped because their id was already present.
+    pub skipped: u32,
+    /// Why the import was rejected, if it was.
+    pub error: Option<String>,
 }
 
 // ==================== Simplified Frontend API ====================
@@ -1626,266 +3072,6 @@ pub struct ApiSimpleRelationship {
     pub strength: f32,
 }
 
-/// Build initial game state from player config.
-///
-/// Uses existing character generation logic to create a new game state
-/// representing "day 1, just started" after character creation.
-fn build_initial_game_state_from_player(
-    seed: u64,
-    config: &ApiPlayerConfig,
-) -> Option<ApiSimpleGameState> {
-    // Parse archetype and difficulty
-    let archetype_enum = CharacterArchetype::from_str(&config.archetype)?;
-    let difficulty_enum = Difficulty::from_str(&config.difficulty)?;
-
-    // Generate character using existing logic
-    let char_config = CharacterGenConfig {
-        name: config.name.clone(),
-        archetype: archetype_enum,
-        difficulty: difficulty_enum,
-        sfw_mode: config.sfw_mode,
-    };
-
-    let gen = generate_character(seed, &char_config);
-
-    // Create initial game state
-    let stats_snapshot = ApiStatsSnapshot {
-        stats: ALL_STAT_KINDS
-            .iter()
-            .map(|kind| ApiStat {
-                kind: format!("{:?}", kind),
-                value: gen.stats.get(*kind),
-            })
-            .collect(),
-        mood_band: format!("{:?}", gen.stats.mood_band()),
-    };
-
-    Some(ApiSimpleGameState {
-        current_day: 1,
-        current_tick: 0,
-        player_age: 6, // All lives start at age 6 (childhood)
-        life_stage: "Child".to_string(),
-        stats: stats_snapshot,
-        mood: format!("{:?}", gen.stats.mood_band()),
-        karma: gen.karma.0,
-        current_event: None,
-        relationships: vec![], // TODO: Initial relationships
-        recent_memories: vec![], // TODO: Initial memories
-    })
-}
-
-/// Helper function to build ApiSimpleGameState from current ENGINE state.
-/// Used by engine_step and engine_choose_option to return consistent snapshots.
-/// Only uses existing public API functions, not direct GameEngine methods.
-fn build_simple_game_state_snapshot() -> Option<ApiSimpleGameState> {
-    let engine = ENGINE.lock().unwrap();
-    let e = engine.as_ref()?;
-
-    // Debug: verify player_age is initialized correctly
-    debug_assert!(e.world.player_age >= 6, "Player age should be at least 6, got {}", e.world.player_age);
-
-    // Convert full game state to simplified view
-    let stats_snapshot = ApiStatsSnapshot {
-        stats: ALL_STAT_KINDS
-            .iter()
-            .map(|kind| ApiStat {
-                kind: format!("{:?}", kind),
-                value: e.world.player_stats.get(*kind),
-            })
-            .collect(),
-        mood_band: format!("{:?}", e.world.player_stats.mood_band()),
-    };
-
-    // Build simplified relationships (top 5 by strength)
-    let relationships: Vec<ApiSimpleRelationship> = e
-        .world
-        .relationships
-        .iter()
-        .filter(|(&(actor, _), _)| actor == e.world.player_id)
-        .take(5)
-        .map(|(&(_, target), rel)| ApiSimpleRelationship {
-            npc_id: target.0 as i64,
-            name: format!("NPC_{}", target.0), // TODO: Get actual names
-            strength: (rel.affection + rel.trust) / 20.0, // Simplified -1 to 1
-        })
-        .collect();
-
-    // Build recent memories (last 5) - get directly from engine to avoid re-locking
-    let recent_memories: Vec<String> = e
-        .get_npc_memories(e.world.player_id.0)
-        .iter()
-        .rev()
-        .take(5)
-        .map(|m| format!("Event_{}", m.event_id))
-        .collect();
-
-    Some(ApiSimpleGameState {
-        current_day: (e.world.current_tick.0 / 24) as u32, // Assuming 24 ticks per day
-        current_tick: e.world.current_tick.0,
-        player_age: e.world.player_age,
-        life_stage: format!("{:?}", e.world.player_life_stage),
-        stats: stats_snapshot,
-        mood: format!("{:?}", e.world.player_stats.mood_band()),
-        karma: e.world.player_karma.0,
-        current_event: None, // Don't call get_current_storylet - it would lock RUNTIME
-        relationships,
-        recent_memories,
-    })
-}
-
-/// Initialize a new game with player config.
-///
-/// Combines character generation and world initialization into a single call.
-/// Returns the initial game state snapshot for UI rendering.
-#[frb(sync)]
-pub fn engine_new_game(seed: u64, config: ApiPlayerConfig) -> Option<ApiSimpleGameState> {
-    // Initialize engine with generated character
-    let success = engine_init_with_character(
-        seed,
-        config.name,
-        config.archetype,
-        config.difficulty,
-        config.sfw_mode,
-    );
-
-    if !success {
-        return None;
-    }
-
-    // Return initial state snapshot
-    build_simple_game_state_snapshot()
-}
-
-/// Advance simulation by specified ticks and return updated state.
-///
-/// This is the main game loop function Flutter should call to progress time.
-#[frb(sync)]
-pub fn engine_step(ticks: u32) -> Option<ApiSimpleGameState> {
-    // Advance simulation using existing API
-    step_world(ticks);
-
-    // Return updated state snapshot
-    build_simple_game_state_snapshot()
-}
-
-/// Make a choice in the current event and advance simulation.
-///
-/// Applies the choice outcome and progresses time by the specified ticks.
-/// 
-/// # Arguments
-/// * `storylet_id` - ID of the current storylet/event
-/// * `choice_id` - ID of the selected choice
-/// * `ticks` - Number of ticks to advance after applying the choice
-#[frb(sync)]
-pub fn engine_choose_option(
-    storylet_id: String,
-    choice_id: String,
-    ticks: u32,
-) -> Option<ApiSimpleGameState> {
-    // Apply choice using existing API
-    api_choose_option(storylet_id, choice_id, ticks);
-
-    // Return updated state snapshot
-    build_simple_game_state_snapshot()
-}
-
-// ==================== District API ====================
-
-/// Get all district summaries for list display.
-#[frb(sync)]
-pub fn engine_list_districts() -> Vec<ApiDistrictSummary> {
-    let engine = ENGINE.lock().unwrap();
-    engine
-        .as_ref()
-        .map(|e| {
-            e.world
-                .districts
-                .districts
-                .values()
-                .map(ApiDistrictSummary::from)
-                .collect()
-        })
-        .unwrap_or_default()
-}
-
-/// Get detailed snapshot of a specific district by name.
-#[frb(sync)]
-pub fn engine_get_district(name: String) -> Option<ApiDistrictSnapshot> {
-    let engine = ENGINE.lock().unwrap();
-    engine
-        .as_ref()
-        .and_then(|e| e.world.districts.get_by_name(&name))
-        .map(ApiDistrictSnapshot::from)
-}
-
-/// Get detailed snapshot of a district by ID.
-#[frb(sync)]
-pub fn engine_get_district_by_id(id: u32) -> Option<ApiDistrictSnapshot> {
-    let engine = ENGINE.lock().unwrap();
-    engine
-        .as_ref()
-        .and_then(|e| e.world.districts.get(DistrictId(id)))
-        .map(ApiDistrictSnapshot::from)
-}
-
-/// Get city-wide statistics.
-#[frb(sync)]
-pub fn engine_get_city_stats() -> ApiCityStats {
-    let engine = ENGINE.lock().unwrap();
-    engine
-        .as_ref()
-        .map(|e| {
-            let registry = &e.world.districts;
-            ApiCityStats {
-                district_count: registry.len() as u32,
-                average_economy: registry.average_economy(),
-                average_crime: registry.average_crime(),
-                safest_district: registry.safest().map(|d| d.name.clone()),
-                most_dangerous_district: registry.most_dangerous().map(|d| d.name.clone()),
-                wealthiest_district: registry.wealthiest().map(|d| d.name.clone()),
-            }
-        })
-        .unwrap_or(ApiCityStats {
-            district_count: 0,
-            average_economy: 0.0,
-            average_crime: 0.0,
-            safest_district: None,
-            most_dangerous_district: None,
-            wealthiest_district: None,
-        })
-}
-
-/// Get player's current district (from their NPC record).
-#[frb(sync)]
-pub fn engine_get_player_district() -> Option<ApiDistrictSnapshot> {
-    let engine = ENGINE.lock().unwrap();
-    let e = engine.as_ref()?;
-    let player_npc = e.world.npcs.get(&e.world.player_id)?;
-    e.world.districts.get_by_name(&player_npc.district).map(ApiDistrictSnapshot::from)
-}
-
-/// Apply an economic event to a district.
-#[frb(sync)]
-pub fn engine_apply_district_economic_event(district_name: String, delta: f32) {
-    let mut engine = ENGINE.lock().unwrap();
-    if let Some(ref mut e) = *engine {
-        if let Some(district) = e.world.districts.get_by_name_mut(&district_name) {
-            district.apply_economic_event(delta);
-        }
-    }
-}
-
-/// Apply a crime event to a district.
-#[frb(sync)]
-pub fn engine_apply_district_crime_event(district_name: String, delta: f32) {
-    let mut engine = ENGINE.lock().unwrap();
-    if let Some(ref mut e) = *engine {
-        if let Some(district) = e.world.districts.get_by_name_mut(&district_name) {
-            district.apply_crime_event(delta);
-        }
-    }
-}
-
 // ==================== Skills API ====================
 
 /// Skill progress snapshot DTO for Flutter.
@@ -1947,172 +3133,1420 @@ pub struct ApiPlayerSkillsSnapshot {
     pub unlocked_storylets: Vec<String>,
 }
 
-/// Get all skill definitions from the registry.
-#[frb(sync)]
-pub fn engine_get_skill_definitions() -> Vec<ApiSkillDefinition> {
-    use syn_core::skills::SkillRegistry;
-    let registry = SkillRegistry::with_defaults();
-    registry
-        .skills
-        .values()
-        .map(|def| ApiSkillDefinition {
-            id: def.id.0.clone(),
-            name: def.name.clone(),
-            description: def.description.clone(),
-            category: format!("{:?}", def.category),
-            xp_rate: def.xp_rate,
-            stat_affinities: def.stat_affinities.iter().map(|s| format!("{:?}", s)).collect(),
-            can_decay: def.can_decay,
-        })
-        .collect()
-}
-
-/// Get player's skill progress for a specific skill.
-#[frb(sync)]
-pub fn engine_get_skill(skill_id: String) -> Option<ApiSkillProgress> {
-    use syn_core::skills::SkillId;
-    let engine = ENGINE.lock().unwrap();
-    engine.as_ref().and_then(|e| {
-        let skill_id = SkillId::new(&skill_id);
-        e.world.player_skills.get(&skill_id).map(|p| ApiSkillProgress {
-            id: skill_id.0.clone(),
-            xp: p.xp,
-            level: p.level(),
-            tier_name: format!("{:?}", p.tier()),
-            progress_to_next: p.progress_to_next_tier(),
-            practice_count: p.practice_count,
-            failure_count: p.failure_count,
-            achieved_mastery: p.achieved_mastery,
-        })
-    })
-}
+// ==================== Content Preferences API ====================
 
-/// Get all player skill progress.
-#[frb(sync)]
-pub fn engine_get_player_skills() -> ApiPlayerSkillsSnapshot {
-    use syn_core::skills::SkillRegistry;
-    let engine = ENGINE.lock().unwrap();
-    engine
-        .as_ref()
-        .map(|e| {
-            let registry = SkillRegistry::with_defaults();
-            let skills: Vec<ApiSkillProgress> = e
-                .world
-                .player_skills
-                .skills
-                .iter()
-                .map(|(id, p)| ApiSkillProgress {
-                    id: id.0.clone(),
-                    xp: p.xp,
-                    level: p.level(),
-                    tier_name: format!("{:?}", p.tier()),
-                    progress_to_next: p.progress_to_next_tier(),
-                    practice_count: p.practice_count,
-                    failure_count: p.failure_count,
-                    achieved_mastery: p.achieved_mastery,
-                })
-                .collect();
+/// Player content toggles for Flutter settings screens.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct ApiContentPreferences {
+    /// Blocked narrative domains (e.g. "addiction", "trauma").
+    pub blocked_domains: Vec<String>,
+    /// Blocked storylet tags.
+    pub blocked_tags: Vec<String>,
+}
 
-            let learned = skills.iter().filter(|s| s.level >= 1).count() as u32;
-            let highest = skills
-                .iter()
-                .max_by_key(|s| s.level)
-                .filter(|s| s.level >= 1)
-                .map(|s| s.id.clone());
-            let unlocked = e.world.player_skills.get_unlocked_storylets(&registry);
-
-            ApiPlayerSkillsSnapshot {
-                skills,
-                total_skills_learned: learned,
-                highest_tier_skill: highest,
-                unlocked_storylets: unlocked,
-            }
-        })
-        .unwrap_or(ApiPlayerSkillsSnapshot {
-            skills: vec![],
-            total_skills_learned: 0,
-            highest_tier_skill: None,
-            unlocked_storylets: vec![],
-        })
+impl From<&ContentPreferences> for ApiContentPreferences {
+    fn from(prefs: &ContentPreferences) -> Self {
+        ApiContentPreferences {
+            blocked_domains: prefs.blocked_domains.iter().cloned().collect(),
+            blocked_tags: prefs.blocked_tags.iter().cloned().collect(),
+        }
+    }
 }
 
-/// Practice a skill, granting XP. Returns the new progress or None if skill not found.
-#[frb(sync)]
-pub fn engine_practice_skill(skill_id: String, base_xp: u32, succeeded: bool) -> Option<ApiSkillProgress> {
-    use syn_core::skills::{SkillId, SkillRegistry};
-    let mut engine = ENGINE.lock().unwrap();
-    let e = engine.as_mut()?;
-    
-    let registry = SkillRegistry::with_defaults();
-    let skill_id = SkillId::new(&skill_id);
-    let current_tick = e.world.current_tick.0;
-    
-    // Calculate XP modifier based on skill definition and player stats
-    let modifier = registry
-        .get(&skill_id)
-        .map(|def| def.calculate_xp_modifier(&e.world.player_stats))
-        .unwrap_or(1.0);
-    let modified_xp = (base_xp as f32 * modifier).round() as u32;
-    
-    let progress = e.world.player_skills.get_or_create_mut(&skill_id);
-    if succeeded {
-        progress.add_xp(modified_xp, current_tick);
-    } else {
-        progress.add_failure_xp(modified_xp, current_tick);
-    }
-    
-    Some(ApiSkillProgress {
-        id: skill_id.0.clone(),
-        xp: progress.xp,
-        level: progress.level(),
-        tier_name: format!("{:?}", progress.tier()),
-        progress_to_next: progress.progress_to_next_tier(),
-        practice_count: progress.practice_count,
-        failure_count: progress.failure_count,
-        achieved_mastery: progress.achieved_mastery,
-    })
-}
-
-/// Check if player meets skill requirements for a storylet.
-#[frb(sync)]
-pub fn engine_check_skill_requirements(skill_id: String, min_tier: Option<u8>, min_xp: Option<u32>) -> bool {
-    use syn_core::skills::SkillId;
-    let engine = ENGINE.lock().unwrap();
-    engine.as_ref().map(|e| {
-        let skill_id = SkillId::new(&skill_id);
-        let tier = e.world.player_skills.get_tier(&skill_id);
-        let xp = e.world.player_skills.get_xp(&skill_id);
-        
-        let tier_ok = min_tier.map(|min| tier.as_level() >= min).unwrap_or(true);
-        let xp_ok = min_xp.map(|min| xp >= min).unwrap_or(true);
-        
-        tier_ok && xp_ok
-    }).unwrap_or(false)
+impl From<ApiContentPreferences> for ContentPreferences {
+    fn from(dto: ApiContentPreferences) -> Self {
+        ContentPreferences::from_lists(dto.blocked_domains, dto.blocked_tags)
+    }
 }
 
-#[cfg(test)]
-mod tests {
-    use super::*;
+// ==================== News Feed API ====================
 
-    #[test]
-    fn test_engine_creation() {
-        let engine = GameEngine::new(42);
-        assert_eq!(engine.world_seed(), 42);
-    }
+/// A single ambient news headline for the UI feed.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ApiNewsItem {
+    /// Unique item id (monotonic within a run).
+    pub id: u64,
+    /// Tick the underlying event happened.
+    pub tick: u64,
+    /// Day the item was published.
+    pub day: u64,
+    /// Source label ("district", "black_swan", "storylet", "gossip", "ambient").
+    pub source: String,
+    /// Sentiment label ("positive", "neutral", "negative").
+    pub sentiment: String,
+    /// Short headline text.
+    pub headline: String,
+    /// Narrative tags attached to the story.
+    pub tags: Vec<String>,
+    /// District the story is about, if any.
+    pub district_id: Option<u32>,
+    /// NPC the story is about, if any.
+    pub subject_id: Option<u64>,
+}
 
-    #[test]
-    fn test_engine_tick() {
-        let mut engine = GameEngine::new(42);
-        let initial_tick = engine.current_tick();
-        engine.tick();
-        assert_eq!(engine.current_tick(), initial_tick + 1);
+impl From<&NewsItem> for ApiNewsItem {
+    fn from(item: &NewsItem) -> Self {
+        ApiNewsItem {
+            id: item.id,
+            tick: item.tick,
+            day: item.day,
+            source: item.source.as_str().to_string(),
+            sentiment: item.sentiment.as_str().to_string(),
+            headline: item.headline.clone(),
+            tags: item.tags.clone(),
+            district_id: item.district_id,
+            subject_id: item.subject_id.map(|id| id.0),
+        }
     }
+}
 
-    #[test]
-    fn test_register_npc() {
-        let mut engine = GameEngine::new(42);
-        engine.register_npc(2, 25, "Engineer".to_string(), "Downtown".to_string());
-        assert!(engine.get_npc(2).is_some());
+// ==================== Dreams API ====================
+
+/// A dream or flashback the player had during the night.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ApiDreamEvent {
+    /// Tick the dream happened.
+    pub tick: u64,
+    /// Kind label ("dream", "flashback").
+    pub kind: String,
+    /// Rendered text.
+    pub text: String,
+    /// Storylet/event the remembered moment came from.
+    pub source_event_id: String,
+    /// Tags (always including "dream").
+    pub tags: Vec<String>,
+    /// NPC ids from the remembered moment.
+    pub participants: Vec<u64>,
+    /// Change applied to the player's mood.
+    pub mood_delta: f32,
+    /// Trait that drifted.
+    pub trait_name: String,
+    /// Change applied to that trait.
+    pub trait_delta: f32,
+}
+
+impl From<&DreamEvent> for ApiDreamEvent {
+    fn from(dream: &DreamEvent) -> Self {
+        ApiDreamEvent {
+            tick: dream.tick,
+            kind: dream.kind.as_str().to_string(),
+            text: dream.text.clone(),
+            source_event_id: dream.source_event_id.clone(),
+            tags: dream.tags.clone(),
+            participants: dream.participants.clone(),
+            mood_delta: dream.mood_delta,
+            trait_name: dream.trait_name.clone(),
+            trait_delta: dream.trait_delta,
+        }
+    }
+}
+
+// ==================== Life Chapters API ====================
+
+/// A memory that defined a chapter.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ApiChapterMemory {
+    /// Memory id.
+    pub memory_id: String,
+    /// Storylet/event that created the memory.
+    pub event_id: String,
+    /// Tick the memory was formed.
+    pub tick: u64,
+    /// Emotional intensity (negative for painful memories).
+    pub emotional_intensity: f32,
+    /// Memory tags.
+    pub tags: Vec<String>,
+}
+
+/// How the player's relationship with one NPC moved over a chapter.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ApiChapterRelationshipChange {
+    /// The NPC.
+    pub npc_id: u64,
+    /// Affection change.
+    pub affection_delta: f32,
+    /// Trust change.
+    pub trust_delta: f32,
+    /// Attraction change.
+    pub attraction_delta: f32,
+    /// Familiarity change.
+    pub familiarity_delta: f32,
+    /// Resentment change.
+    pub resentment_delta: f32,
+    /// Relationship state when the chapter opened.
+    pub start_state: String,
+    /// Relationship state when it closed.
+    pub end_state: String,
+}
+
+/// One stat's start and end value over a chapter.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ApiStatTrajectory {
+    /// Stat name (e.g. "Mood").
+    pub kind: String,
+    /// Value when the chapter opened.
+    pub start: f32,
+    /// Value when it closed.
+    pub end: f32,
+}
+
+/// A closed chapter of the player's life, for the timeline.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ApiChapterSummary {
+    /// Chapter number, starting at 0.
+    pub index: u32,
+    /// Tick the chapter opened.
+    pub start_tick: u64,
+    /// Tick the chapter closed.
+    pub end_tick: u64,
+    /// Life stage the chapter was lived in.
+    pub life_stage: String,
+    /// Player age (years) when the chapter opened.
+    pub start_age_years: u32,
+    /// What closed the chapter ("life_stage" or "year").
+    pub boundary: String,
+    /// Most salient memories, strongest first.
+    pub top_memories: Vec<ApiChapterMemory>,
+    /// Relationships that moved the most, largest change first.
+    pub relationship_changes: Vec<ApiChapterRelationshipChange>,
+    /// Every stat's start and end value.
+    pub stat_trajectory: Vec<ApiStatTrajectory>,
+}
+
+impl From<&ChapterSummary> for ApiChapterSummary {
+    fn from(chapter: &ChapterSummary) -> Self {
+        ApiChapterSummary {
+            index: chapter.index,
+            start_tick: chapter.start_tick,
+            end_tick: chapter.end_tick,
+            life_stage: format!("{:?}", chapter.life_stage),
+            start_age_years: chapter.start_age_years,
+            boundary: chapter.boundary.as_str().to_string(),
+            top_memories: chapter
+                .top_memories
+                .iter()
+                .map(|m| ApiChapterMemory {
+                    memory_id: m.memory_id.clone(),
+                    event_id: m.event_id.clone(),
+                    tick: m.tick,
+                    emotional_intensity: m.emotional_intensity,
+                    tags: m.tags.clone(),
+                })
+                .collect(),
+            relationship_changes: chapter
+                .relationship_changes
+                .iter()
+                .map(|c| ApiChapterRelationshipChange {
+                    npc_id: c.npc_id.0,
+                    affection_delta: c.end.affection - c.start.affection,
+                    trust_delta: c.end.trust - c.start.trust,
+                    attraction_delta: c.end.attraction - c.start.attraction,
+                    familiarity_delta: c.end.familiarity - c.start.familiarity,
+                    resentment_delta: c.end.resentment - c.start.resentment,
+                    start_state: format!("{:?}", c.start.state),
+                    end_state: format!("{:?}", c.end.state),
+                })
+                .collect(),
+            stat_trajectory: chapter
+                .stat_trajectory
+                .iter()
+                .map(|t| ApiStatTrajectory {
+                    kind: format!("{:?}", t.kind),
+                    start: t.start,
+                    end: t.end,
+                })
+                .collect(),
+        }
+    }
+}
+
+// ==================== Relationship Proposals API ====================
+
+/// A proposal an NPC made to the player.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ApiRelationshipProposal {
+    /// Proposal id (pass to `engine_respond_to_proposal`).
+    pub id: u64,
+    /// Storylet id the proposal is presented under ("proposal:<id>").
+    pub storylet_id: String,
+    /// NPC making the proposal.
+    pub npc_id: u64,
+    /// Kind label ("be_partners", "move_in", "end_friendship").
+    pub kind: String,
+    /// Tick the proposal was made.
+    pub created_tick: u64,
+}
+
+impl From<&RelationshipProposal> for ApiRelationshipProposal {
+    fn from(proposal: &RelationshipProposal) -> Self {
+        ApiRelationshipProposal {
+            id: proposal.id,
+            storylet_id: proposal.storylet_id(),
+            npc_id: proposal.npc_id.0,
+            kind: proposal.kind.as_str().to_string(),
+            created_tick: proposal.created_tick,
+        }
+    }
+}
+
+// ==================== Major Characters API ====================
+
+/// An NPC the story treats as a major character.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ApiMajorCharacter {
+    /// The NPC.
+    pub npc_id: u64,
+    /// Display name.
+    pub name: String,
+    /// How they became major ("authored", "emergent").
+    pub source: String,
+    /// Tick they became major.
+    pub since_tick: u64,
+    /// Tick of their last check-in.
+    pub last_check_in_tick: u64,
+    /// Memories the player shares with them.
+    pub shared_memories: u32,
+    /// Summed |emotional intensity| of those memories.
+    pub salience: f32,
+}
+
+impl ApiMajorCharacter {
+    /// Build the DTO, measuring shared memories against `world`.
+    pub fn from_major(major: &MajorCharacter, world: &WorldState) -> Self {
+        let shared = syn_core::shared_salience(world, major.npc_id);
+        ApiMajorCharacter {
+            npc_id: major.npc_id.0,
+            name: syn_core::npc_name(world, major.npc_id),
+            source: major.source.as_str().to_string(),
+            since_tick: major.since_tick,
+            last_check_in_tick: major.last_check_in_tick,
+            shared_memories: shared.memories as u32,
+            salience: shared.salience,
+        }
+    }
+}
+
+/// A major character checking in on the player.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ApiCheckIn {
+    /// Check-in id (pass to `engine_respond_to_check_in`).
+    pub id: u64,
+    /// Storylet id the check-in is presented under ("check_in:<id>").
+    pub storylet_id: String,
+    /// NPC checking in.
+    pub npc_id: u64,
+    /// Tick the check-in was queued.
+    pub created_tick: u64,
+}
+
+impl From<&CheckIn> for ApiCheckIn {
+    fn from(check_in: &CheckIn) -> Self {
+        ApiCheckIn {
+            id: check_in.id,
+            storylet_id: check_in.storylet_id(),
+            npc_id: check_in.npc_id.0,
+            created_tick: check_in.created_tick,
+        }
+    }
+}
+
+// ==================== Year in Review API ====================
+
+/// Recap of one calendar year, for a shareable summary screen.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ApiYearInReview {
+    /// Calendar year (1 = the year the game started in).
+    pub year: u32,
+    /// First tick of the year.
+    pub start_tick: u64,
+    /// Tick the year closed at.
+    pub end_tick: u64,
+    /// Days played this year.
+    pub days: u32,
+    /// NPC the player shared the most new memories with.
+    pub most_seen_npc_id: Option<u64>,
+    /// Their display name (empty when there is none).
+    pub most_seen_npc_name: String,
+    /// Memories shared with them this year.
+    pub most_seen_memories: u32,
+    /// Mood band the player spent the most days in (e.g. "High").
+    pub dominant_mood: Option<String>,
+    /// Days spent in that mood.
+    pub dominant_mood_days: u32,
+    /// Largest day-over-day karma change (signed).
+    pub biggest_karma_swing: f32,
+    /// Tick of the day it happened.
+    pub biggest_karma_swing_tick: u64,
+    /// Storylet fired this year with the fewest fires overall.
+    pub rarest_storylet: Option<String>,
+    /// Lifetime fires of that storylet.
+    pub rarest_storylet_fires: u32,
+    /// Storylet fires this year.
+    pub storylets_fired: u32,
+    /// Narrative log entries added this year.
+    pub narrative_events: u32,
+}
+
+impl ApiYearInReview {
+    /// Build the DTO, naming the most-seen NPC from `world`.
+    pub fn from_review(review: &YearInReview, world: &WorldState) -> Self {
+        ApiYearInReview {
+            year: review.year,
+            start_tick: review.start_tick,
+            end_tick: review.end_tick,
+            days: review.days,
+            most_seen_npc_id: review.most_seen_npc.map(|id| id.0),
+            most_seen_npc_name: review
+                .most_seen_npc
+                .map(|id| syn_core::npc_name(world, id))
+                .unwrap_or_default(),
+            most_seen_memories: review.most_seen_memories,
+            dominant_mood: review.dominant_mood.map(|band| format!("{:?}", band)),
+            dominant_mood_days: review.dominant_mood_days,
+            biggest_karma_swing: review.biggest_karma_swing,
+            biggest_karma_swing_tick: review.biggest_karma_swing_tick,
+            rarest_storylet: review.rarest_storylet.clone(),
+            rarest_storylet_fires: review.rarest_storylet_fires,
+            storylets_fired: review.storylets_fired,
+            narrative_events: review.narrative_events,
+        }
+    }
+}
+
+// ==================== Life Goals API ====================
+
+/// One requirement of a life goal and how close the player is.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ApiGoalRequirement {
+    /// Short label (e.g. "Wealth 70+").
+    pub description: String,
+    /// Progress toward it (0.0..=1.0).
+    pub progress: f32,
+}
+
+/// A life goal the player is pursuing or has resolved.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ApiLifeGoal {
+    /// Goal id.
+    pub id: String,
+    /// Display title.
+    pub title: String,
+    /// Status label ("active", "achieved", "failed").
+    pub status: String,
+    /// Overall progress (0.0..=1.0).
+    pub progress: f32,
+    /// Requirement checklist.
+    pub requirements: Vec<ApiGoalRequirement>,
+    /// Player age the goal must be met by, if any.
+    pub deadline_age: Option<u32>,
+}
+
+impl ApiLifeGoal {
+    /// Build the DTO, measuring requirement progress against `world`.
+    pub fn from_tracked(tracked: &TrackedGoal, world: &WorldState) -> Self {
+        ApiLifeGoal {
+            id: tracked.goal.id.clone(),
+            title: tracked.goal.title.clone(),
+            status: tracked.status.as_str().to_string(),
+            progress: tracked.progress,
+            requirements: tracked
+                .goal
+                .requirements
+                .iter()
+                .map(|r| ApiGoalRequirement {
+                    description: r.describe(),
+                    progress: r.progress(world),
+                })
+                .collect(),
+            deadline_age: tracked.goal.deadline_age,
+        }
+    }
+}
+
+/// A goal offered at character creation.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ApiLifeGoalOption {
+    /// Goal id (pass to `engine_choose_life_goal`).
+    pub id: String,
+    /// Display title.
+    pub title: String,
+    /// Requirement labels.
+    pub requirements: Vec<String>,
+}
+
+impl From<&LifeGoal> for ApiLifeGoalOption {
+    fn from(goal: &LifeGoal) -> Self {
+        ApiLifeGoalOption {
+            id: goal.id.clone(),
+            title: goal.title.clone(),
+            requirements: goal.requirements.iter().map(|r| r.describe()).collect(),
+        }
+    }
+}
+
+// ==================== Rival Actions API ====================
+
+/// A hostile action a rival took against the player.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ApiRivalAction {
+    /// Action id.
+    pub id: u64,
+    /// The rival.
+    pub npc_id: u64,
+    /// Kind label ("spread_gossip", "sabotage_reputation", "confront").
+    pub kind: String,
+    /// Tick it happened.
+    pub tick: u64,
+    /// Rival's resentment toward the player when they acted.
+    pub resentment: f32,
+}
+
+impl From<&RivalAction> for ApiRivalAction {
+    fn from(action: &RivalAction) -> Self {
+        ApiRivalAction {
+            id: action.id,
+            npc_id: action.npc_id.0,
+            kind: action.kind.as_str().to_string(),
+            tick: action.tick,
+            resentment: action.resentment,
+        }
+    }
+}
+
+// ==================== Jealousy API ====================
+
+/// An admirer's jealous reaction to the player's romance with someone else.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ApiJealousyEvent {
+    /// Reaction id.
+    pub id: u64,
+    /// Tick it happened.
+    pub tick: u64,
+    /// The jealous NPC.
+    pub jealous_id: u64,
+    /// The player's partner they resent.
+    pub partner_id: u64,
+    /// What moved up in the romance ("attraction", "affection").
+    pub trigger: String,
+    /// Jealous NPC's resentment toward the partner afterwards.
+    pub resentment: f32,
+    /// Whether they confronted the player.
+    pub confronted: bool,
+}
+
+impl From<&JealousyEvent> for ApiJealousyEvent {
+    fn from(event: &JealousyEvent) -> Self {
+        ApiJealousyEvent {
+            id: event.id,
+            tick: event.tick,
+            jealous_id: event.jealous_id.0,
+            partner_id: event.partner_id.0,
+            trigger: event.trigger.as_str().to_string(),
+            resentment: event.resentment,
+            confronted: event.confronted,
+        }
+    }
+}
+
+// ==================== Opinions API ====================
+
+/// A bystander changing their view of someone over how they treated another.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ApiOpinionShift {
+    /// Tick it happened.
+    pub tick: u64,
+    /// NPC whose view changed.
+    pub observer_id: u64,
+    /// NPC they now see differently.
+    pub subject_id: u64,
+    /// NPC whose treatment they judged.
+    pub about_id: u64,
+    /// Signed shift: positive is respect, negative is resentment.
+    pub shift: f32,
+    /// Whether they saw it happen (otherwise they heard about it).
+    pub witnessed: bool,
+    /// What they observed (e.g. "storylet:<id>").
+    pub source: String,
+}
+
+impl From<&OpinionShift> for ApiOpinionShift {
+    fn from(shift: &OpinionShift) -> Self {
+        ApiOpinionShift {
+            tick: shift.tick,
+            observer_id: shift.observer_id.0,
+            subject_id: shift.subject_id.0,
+            about_id: shift.about_id.0,
+            shift: shift.shift,
+            witnessed: shift.witnessed,
+            source: shift.source.clone(),
+        }
+    }
+}
+
+/// A first meeting with a new NPC.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ApiEncounter {
+    /// Encounter id.
+    pub id: u64,
+    /// The NPC the player met.
+    pub npc_id: u64,
+    /// Where they met ("neighborhood", "work", "school", "nightlife",
+    /// "errands", "mutual_friend").
+    pub source: String,
+    /// Friend who made the introduction, for "mutual_friend" meetings.
+    pub introduced_by: Option<u64>,
+    /// Tick they met.
+    pub tick: u64,
+}
+
+impl From<&Encounter> for ApiEncounter {
+    fn from(encounter: &Encounter) -> Self {
+        ApiEncounter {
+            id: encounter.id,
+            npc_id: encounter.npc_id.0,
+            source: encounter.source.as_str().to_string(),
+            introduced_by: encounter.introduced_by.map(|id| id.0),
+            tick: encounter.tick,
+        }
+    }
+}
+
+/// A pet, plant or younger sibling in the player's care.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ApiDependent {
+    /// Dependent id.
+    pub id: u64,
+    /// "pet", "plant" or "younger_sibling".
+    pub kind: String,
+    /// Display name.
+    pub name: String,
+    /// How badly they need care (0..1).
+    pub need: f32,
+    /// How well they are doing overall (0..1).
+    pub wellbeing: f32,
+    /// Whether their need is high enough to prompt the player.
+    pub needs_care: bool,
+    /// Whether neglect has driven them away.
+    pub lost: bool,
+    /// Tick they were last cared for.
+    pub last_cared_tick: Option<u64>,
+}
+
+impl ApiDependent {
+    /// Build the view of `dependent` under `config`'s thresholds.
+    pub fn from_dependent(dependent: &Dependent, config: &DependentConfig) -> Self {
+        ApiDependent {
+            id: dependent.id,
+            kind: dependent.kind.as_str().to_string(),
+            name: dependent.name.clone(),
+            need: dependent.need,
+            wellbeing: dependent.wellbeing,
+            needs_care: dependent.needs_care(config),
+            lost: dependent.is_lost(),
+            last_cared_tick: dependent.last_cared_tick,
+        }
+    }
+}
+
+/// The player's difficulty and what the dynamic controller is doing.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ApiDifficultyStatus {
+    /// Difficulty chosen at character creation ("FORGIVING", "BALANCED", "HARSH").
+    pub base: String,
+    /// Whether the dynamic controller is running.
+    pub dynamic_enabled: bool,
+    /// Current multiplier on negative outcome deltas.
+    pub negative_modifier: f32,
+    /// Current multiplier on positive outcome deltas.
+    pub positive_modifier: f32,
+    /// Controller's extra scale on negative outcomes.
+    pub negative_scale: f32,
+    /// Controller's score multiplier for heavy storylets.
+    pub heavy_mix: f32,
+    /// Outcome samples in the controller's window.
+    pub samples: u32,
+    /// Share of those outcomes that were negative.
+    pub negative_share: f32,
+    /// Wellbeing change over the window (-1..1).
+    pub trajectory: f32,
+    /// Recent decisions, oldest first.
+    pub decisions: Vec<ApiDifficultyDecision>,
+}
+
+impl From<&DifficultyState> for ApiDifficultyStatus {
+    fn from(state: &DifficultyState) -> Self {
+        let (samples, negative_share, trajectory) = state.window_summary();
+        ApiDifficultyStatus {
+            base: state.base.as_str().to_string(),
+            dynamic_enabled: state.config.enabled,
+            negative_modifier: state.negative_modifier(),
+            positive_modifier: state.positive_modifier(),
+            negative_scale: state.negative_scale,
+            heavy_mix: state.heavy_mix,
+            samples: samples as u32,
+            negative_share,
+            trajectory,
+            decisions: state.decisions.iter().map(ApiDifficultyDecision::from).collect(),
+        }
+    }
+}
+
+/// One logged dynamic difficulty decision.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ApiDifficultyDecision {
+    /// Tick of the evaluation.
+    pub tick: u64,
+    /// "ease", "harden" or "settle".
+    pub action: String,
+    /// Share of negative outcomes the decision saw.
+    pub negative_share: f32,
+    /// Outcome samples the decision saw.
+    pub samples: u32,
+    /// Wellbeing change the decision saw.
+    pub trajectory: f32,
+    /// Negative outcome scale after the decision.
+    pub negative_scale: f32,
+    /// Heavy storylet mix after the decision.
+    pub heavy_mix: f32,
+}
+
+impl From<&DifficultyDecision> for ApiDifficultyDecision {
+    fn from(decision: &DifficultyDecision) -> Self {
+        ApiDifficultyDecision {
+            tick: decision.tick,
+            action: decision.action.as_str().to_string(),
+            negative_share: decision.negative_share,
+            samples: decision.samples as u32,
+            trajectory: decision.trajectory,
+            negative_scale: decision.negative_scale,
+            heavy_mix: decision.heavy_mix,
+        }
+    }
+}
+
+/// The PostLife chapter after the player's death.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ApiPostLife {
+    /// Whether the chapter has opened.
+    pub active: bool,
+    /// Whether the imprint has faded for good.
+    pub faded: bool,
+    /// Reach into the living world, spent on actions.
+    pub signal: f32,
+    /// Cap on signal.
+    pub max_signal: f32,
+    /// How intact the imprint is (0..100).
+    pub coherence: f32,
+    /// Signal cost of communing with a digital NPC.
+    pub commune_cost: f32,
+    /// Signal cost of nudging a descendant.
+    pub nudge_cost: f32,
+    /// Digital-only NPCs.
+    pub digital_npcs: Vec<ApiDigitalNpc>,
+    /// Nudges still in effect.
+    pub nudges: Vec<ApiLegacyNudge>,
+    /// Living family members who can be nudged.
+    pub descendants: Vec<u64>,
+}
+
+impl ApiPostLife {
+    /// Build the view of `world`'s PostLife chapter.
+    pub fn from_world(world: &WorldState) -> Self {
+        let state = &world.post_life;
+        ApiPostLife {
+            active: state.is_active(),
+            faded: state.is_faded(),
+            signal: state.signal,
+            max_signal: state.config.max_signal,
+            coherence: state.coherence,
+            commune_cost: state.config.commune_signal_cost,
+            nudge_cost: state.config.nudge_signal_cost,
+            digital_npcs: state.digital_npcs.iter().map(ApiDigitalNpc::from).collect(),
+            nudges: state.nudges.iter().map(ApiLegacyNudge::from).collect(),
+            descendants: syn_core::descendants(world).into_iter().map(|id| id.0).collect(),
+        }
+    }
+}
+
+/// A digital-only NPC met in PostLife.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ApiDigitalNpc {
+    /// Digital NPC id.
+    pub id: u64,
+    /// "echo" or "archivist".
+    pub kind: String,
+    /// Living NPC an echo was seeded from.
+    pub source_npc: Option<u64>,
+    /// Bond with the player's ghost (0..1).
+    pub bond: f32,
+    /// Tick the player last communed with them.
+    pub last_communed_tick: Option<u64>,
+}
+
+impl From<&DigitalNpc> for ApiDigitalNpc {
+    fn from(npc: &DigitalNpc) -> Self {
+        ApiDigitalNpc {
+            id: npc.id,
+            kind: npc.kind.as_str().to_string(),
+            source_npc: npc.source_npc.map(|id| id.0),
+            bond: npc.bond,
+            last_communed_tick: npc.last_communed_tick,
+        }
+    }
+}
+
+/// A nudge leaning a descendant's storylets toward a tag.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ApiLegacyNudge {
+    /// Descendant NPC id.
+    pub descendant: u64,
+    /// Storylet tag favored.
+    pub tag: String,
+    /// Tick the nudge expires.
+    pub expires_tick: u64,
+}
+
+impl From<&LegacyNudge> for ApiLegacyNudge {
+    fn from(nudge: &LegacyNudge) -> Self {
+        ApiLegacyNudge {
+            descendant: nudge.descendant.0,
+            tag: nudge.tag.clone(),
+            expires_tick: nudge.expires_tick,
+        }
+    }
+}
+
+// ==================== Action Budget API ====================
+
+/// The player's daily energy budget.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ApiActionBudget {
+    /// Action points left for today.
+    pub points: f32,
+    /// Action points after a full night's rest.
+    pub max_points: f32,
+    /// Points spent so far today.
+    pub spent_today: f32,
+    /// Share of the budget used up (0.0 = fully rested, 1.0 = exhausted).
+    pub fatigue: f32,
+}
+
+impl From<&ActionBudget> for ApiActionBudget {
+    fn from(budget: &ActionBudget) -> Self {
+        ApiActionBudget {
+            points: budget.points,
+            max_points: budget.max_points,
+            spent_today: budget.spent_today,
+            fatigue: budget.fatigue(),
+        }
+    }
+}
+
+// ==================== Underworld API ====================
+
+/// The player's underworld exposure, for the UI.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ApiUnderworldSnapshot {
+    /// Exposure level (0-100).
+    pub exposure: f32,
+    /// Standing band ("Clean", "Noticed", "Connected", "Embedded").
+    pub standing: String,
+    /// Whether underworld storylets can fire.
+    pub domain_unlocked: bool,
+    /// Crime-tagged memories recorded so far.
+    pub crime_memories: u32,
+    /// Role tags of NPCs currently drawn toward the player.
+    pub attracted_role_tags: Vec<String>,
+    /// Gang activity added to the player's home district each phase.
+    pub district_gang_pressure: f32,
+}
+
+impl From<&UnderworldExposure> for ApiUnderworldSnapshot {
+    fn from(exposure: &UnderworldExposure) -> Self {
+        let standing = exposure.standing();
+        ApiUnderworldSnapshot {
+            exposure: exposure.level,
+            standing: format!("{:?}", standing),
+            domain_unlocked: exposure.domain_unlocked(),
+            crime_memories: exposure.crime_memories,
+            attracted_role_tags: standing
+                .attracted_role_tags()
+                .iter()
+                .map(|tag| format!("{:?}", tag))
+                .collect(),
+            district_gang_pressure: exposure.district_gang_pressure(),
+        }
+    }
+}
+
+// ==================== External Events API ====================
+
+/// Outcome of an external event injection.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct ApiInjectedEvent {
+    /// Whether the event passed validation and was applied.
+    pub accepted: bool,
+    /// Narrative log sequence number (0 when rejected).
+    pub seq: u64,
+    /// Why the event was rejected, if it was.
+    pub error: Option<String>,
+}
+
+impl From<Result<u64, SynError>> for ApiInjectedEvent {
+    fn from(result: Result<u64, SynError>) -> Self {
+        match result {
+            Ok(seq) => ApiInjectedEvent {
+                accepted: true,
+                seq,
+                error: None,
+            },
+            Err(err) => ApiInjectedEvent {
+                accepted: false,
+                seq: 0,
+                error: Some(err.to_string()),
+            },
+        }
+    }
+}
+
+/// One injected event in the narrative log.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ApiNarrativeLogEntry {
+    /// Injection sequence number.
+    pub seq: u64,
+    /// Tick the event was applied at.
+    pub tick: u64,
+    /// Event kind ("world_flag", "heat", "memory", "trigger").
+    pub kind: String,
+    /// Human-readable description.
+    pub summary: String,
+}
+
+impl From<&NarrativeLogEntry> for ApiNarrativeLogEntry {
+    fn from(entry: &NarrativeLogEntry) -> Self {
+        ApiNarrativeLogEntry {
+            seq: entry.seq,
+            tick: entry.tick,
+            kind: entry.kind.as_str().to_string(),
+            summary: entry.summary.clone(),
+        }
+    }
+}
+
+// ==================== Event Bus API ====================
+
+/// A world event drained from the event bus.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct ApiWorldEvent {
+    /// Publication sequence number.
+    pub seq: u64,
+    /// Tick it was published at.
+    pub tick: u64,
+    /// Event kind ("milestone_reached", "heat_band_changed",
+    /// "life_stage_changed", "storylet_fired").
+    pub kind: String,
+    /// Milestone kind or storylet ID; empty for band and stage changes.
+    pub subject: String,
+    /// Role, heat band or life stage before the change.
+    pub from: Option<String>,
+    /// Role, heat band or life stage after the change.
+    pub to: Option<String>,
+    /// Milestone actor and target NPC IDs.
+    pub npc_ids: Vec<i64>,
+    /// Choice taken when a storylet fired from one.
+    pub choice_id: Option<String>,
+}
+
+impl From<&PublishedEvent> for ApiWorldEvent {
+    fn from(published: &PublishedEvent) -> Self {
+        let mut api = ApiWorldEvent {
+            seq: published.seq,
+            tick: published.tick,
+            kind: published.event.kind().to_string(),
+            ..Default::default()
+        };
+        match &published.event {
+            WorldEvent::MilestoneReached {
+                actor_id,
+                target_id,
+                kind,
+                from_role,
+                to_role,
+            } => {
+                api.subject = format!("{:?}", kind);
+                api.from = Some(from_role.clone());
+                api.to = Some(to_role.clone());
+                api.npc_ids = vec![*actor_id as i64, *target_id as i64];
+            }
+            WorldEvent::HeatBandChanged { from, to } => {
+                api.from = Some(format!("{:?}", from));
+                api.to = Some(format!("{:?}", to));
+            }
+            WorldEvent::LifeStageChanged { from, to } => {
+                api.from = Some(format!("{:?}", from));
+                api.to = Some(format!("{:?}", to));
+            }
+            WorldEvent::StoryletFired {
+                storylet_id,
+                choice_id,
+            } => {
+                api.subject = storylet_id.clone();
+                api.choice_id = choice_id.clone();
+            }
+        }
+        api
+    }
+}
+
+// ==================== Narrative Heat Forecast API ====================
+
+/// Projected heat at one future tick (a sparkline sample).
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct ApiHeatForecastPoint {
+    /// World tick this point projects.
+    pub tick: u64,
+    /// Projected heat (0-100).
+    pub heat: f32,
+    /// Band label (Low/Medium/High/Critical).
+    pub band: String,
+}
+
+/// Short-horizon heat forecast for UI pacing hints.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct ApiHeatForecast {
+    /// Heat now.
+    pub current_heat: f32,
+    /// Band label now.
+    pub current_band: String,
+    /// One point per future tick, nearest first.
+    pub points: Vec<ApiHeatForecastPoint>,
+    /// Hottest band label reached over the horizon.
+    pub peak_band: String,
+    /// Whether heat is projected to climb into a hotter band.
+    pub brewing: bool,
+}
+
+impl From<&HeatForecast> for ApiHeatForecast {
+    fn from(forecast: &HeatForecast) -> Self {
+        ApiHeatForecast {
+            current_heat: forecast.current_heat,
+            current_band: forecast.current_band.to_string(),
+            points: forecast
+                .points
+                .iter()
+                .map(|p| ApiHeatForecastPoint {
+                    tick: p.tick,
+                    heat: p.heat,
+                    band: p.band.to_string(),
+                })
+                .collect(),
+            peak_band: forecast.peak_band.to_string(),
+            brewing: forecast.brewing,
+        }
+    }
+}
+
+// ==================== Scripted Outcomes API ====================
+
+/// A role pinned to an NPC when forcing a storylet.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ApiCastOverride {
+    /// Role name.
+    pub role: String,
+    /// NPC cast into it.
+    pub npc_id: u64,
+}
+
+/// Result of forcing a storylet to fire (dev tooling, scripted demos).
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct ApiForcedFire {
+    /// Whether the storylet fired.
+    pub fired: bool,
+    /// Storylet requested.
+    pub storylet_id: String,
+    /// Roles as cast when it fired.
+    pub cast: Vec<ApiCastOverride>,
+    /// Failed prerequisites, unknown override roles and recast NPCs.
+    pub warnings: Vec<String>,
+    /// Why nothing fired, if it didn't.
+    pub error: Option<String>,
+}
+
+impl ApiForcedFire {
+    /// Build the DTO for a force-fire of `storylet_id`.
+    pub fn from_result(storylet_id: &str, result: Result<ForcedFire, ForceFireError>) -> Self {
+        match result {
+            Ok(fired) => ApiForcedFire {
+                fired: true,
+                storylet_id: fired.storylet_id,
+                cast: fired
+                    .cast
+                    .into_iter()
+                    .map(|(role, npc_id)| ApiCastOverride {
+                        role,
+                        npc_id: npc_id.0,
+                    })
+                    .collect(),
+                warnings: fired.warnings,
+                error: None,
+            },
+            Err(err) => ApiForcedFire {
+                storylet_id: storylet_id.to_string(),
+                error: Some(err.to_string()),
+                ..Default::default()
+            },
+        }
+    }
+}
+
+/// One fired storylet on the life timeline.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct ApiChronicleEntry {
+    /// Position on the timeline, starting at 1.
+    pub seq: u64,
+    /// Storylet that fired.
+    pub storylet_id: String,
+    /// Storylet display name.
+    pub storylet_name: String,
+    /// Tick it fired at.
+    pub tick: u64,
+    /// Roles as cast when it fired.
+    pub roles: Vec<ApiCastOverride>,
+    /// Choice taken, if the outcome came from one.
+    pub choice_id: Option<String>,
+    /// Event ID of the memory it left.
+    pub memory_event_id: String,
+    /// Player stat changes the outcome applied.
+    pub stat_changes: Vec<ApiStat>,
+    /// Karma change, if any.
+    pub karma_delta: Option<f32>,
+    /// Narrative heat when it fired.
+    pub heat: f32,
+}
+
+impl From<&ChronicleEntry> for ApiChronicleEntry {
+    fn from(entry: &ChronicleEntry) -> Self {
+        ApiChronicleEntry {
+            seq: entry.seq,
+            storylet_id: entry.storylet_id.clone(),
+            storylet_name: entry.storylet_name.clone(),
+            tick: entry.tick,
+            roles: entry
+                .roles
+                .iter()
+                .map(|(role, npc_id)| ApiCastOverride {
+                    role: role.clone(),
+                    npc_id: npc_id.0,
+                })
+                .collect(),
+            choice_id: entry.choice_id.clone(),
+            memory_event_id: entry.outcome.memory_event_id.clone(),
+            stat_changes: entry
+                .outcome
+                .stat_deltas
+                .iter()
+                .map(|delta| ApiStat {
+                    kind: format!("{:?}", delta.kind),
+                    value: delta.delta,
+                })
+                .collect(),
+            karma_delta: entry.outcome.karma_delta,
+            heat: entry.heat,
+        }
+    }
+}
+
+/// Result of applying a scripted outcome batch (dev tooling).
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct ApiOutcomeBatchResult {
+    /// Whether the batch passed validation and was applied.
+    pub accepted: bool,
+    /// Entries applied.
+    pub applied: u32,
+    /// Relationship pairs changed by the batch.
+    pub pairs_changed: u32,
+    /// Pressure events queued by the batch.
+    pub pressure_events: u32,
+    /// Milestone events queued by the batch.
+    pub milestone_events: u32,
+    /// Why the batch was rejected, if it was.
+    pub error: Option<String>,
+}
+
+impl From<Result<OutcomeBatchReport, OutcomeBatchError>> for ApiOutcomeBatchResult {
+    fn from(result: Result<OutcomeBatchReport, OutcomeBatchError>) -> Self {
+        match result {
+            Ok(report) => ApiOutcomeBatchResult {
+                accepted: true,
+                applied: report.applied as u32,
+                pairs_changed: report.pairs_changed.len() as u32,
+                pressure_events: report.pressure_events as u32,
+                milestone_events: report.milestone_events as u32,
+                error: None,
+            },
+            Err(err) => ApiOutcomeBatchResult {
+                error: Some(err.to_string()),
+                ..Default::default()
+            },
+        }
+    }
+}
+
+// ==================== World Statistics API ====================
+
+/// NPC count for one age cohort.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ApiAgeBucket {
+    /// Cohort name ("Infant", "Teen", "YoungAdult", ...).
+    pub cohort: String,
+    /// NPCs in the cohort.
+    pub count: u32,
+}
+
+/// Employment counts for one district.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ApiDistrictEmployment {
+    /// District name.
+    pub district: String,
+    /// NPCs living in the district.
+    pub population: u32,
+    /// Of those, NPCs with a job.
+    pub employed: u32,
+    /// Share of residents with a job (0.0-1.0).
+    pub employment_rate: f32,
+}
+
+/// Population-level statistics for the city overview and balancing dashboards.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ApiWorldStats {
+    /// Tick the statistics were read at.
+    pub tick: u64,
+    /// Instantiated NPCs, excluding the player.
+    pub population: u32,
+    /// NPC counts per age cohort, youngest first.
+    pub age_distribution: Vec<ApiAgeBucket>,
+    /// Employment per district, sorted by name.
+    pub employment_by_district: Vec<ApiDistrictEmployment>,
+    /// Mean NPC→player warmth (-10..10), if any NPC knows the player.
+    pub average_warmth_to_player: Option<f32>,
+    /// NPCs in Tier0 (high fidelity).
+    pub tier0_count: u32,
+    /// NPCs in Tier1 (batched).
+    pub tier1_count: u32,
+    /// NPCs in Tier2 (background).
+    pub tier2_count: u32,
+    /// Known NPCs not instantiated in the world.
+    pub dormant_population: u32,
+}
+
+impl From<&syn_sim::WorldStats> for ApiWorldStats {
+    fn from(stats: &syn_sim::WorldStats) -> Self {
+        ApiWorldStats {
+            tick: stats.tick.0,
+            population: stats.population,
+            age_distribution: stats
+                .age_distribution
+                .iter()
+                .map(|(cohort, count)| ApiAgeBucket {
+                    cohort: format!("{:?}", cohort),
+                    count: *count,
+                })
+                .collect(),
+            employment_by_district: stats
+                .employment_by_district
+                .iter()
+                .map(|(district, employment)| ApiDistrictEmployment {
+                    district: district.clone(),
+                    population: employment.population,
+                    employed: employment.employed,
+                    employment_rate: employment.employment_rate(),
+                })
+                .collect(),
+            average_warmth_to_player: stats.average_warmth_to_player,
+            tier0_count: stats.tier_counts[0],
+            tier1_count: stats.tier_counts[1],
+            tier2_count: stats.tier_counts[2],
+            dormant_population: stats.dormant_population,
+        }
+    }
+}
+
+/// A named world, for the profile picker.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ApiProfile {
+    /// Profile name.
+    pub name: String,
+    /// Seed the world was created from.
+    pub seed: u64,
+    /// Directory reserved for this profile's saves and exports.
+    pub storage_dir: String,
+    /// Whether this is the profile being played.
+    pub active: bool,
+}
+
+impl From<&ProfileInfo> for ApiProfile {
+    fn from(info: &ProfileInfo) -> Self {
+        ApiProfile {
+            name: info.name.clone(),
+            seed: info.seed,
+            storage_dir: info.storage_dir.to_string_lossy().into_owned(),
+            active: info.active,
+        }
+    }
+}
+
+// ==================== Save / Load API ====================
+
+/// Outcome of saving or loading the engine.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct ApiSaveResult {
+    /// Whether the save or load went through.
+    pub success: bool,
+    /// Why it failed, if it did.
+    pub error: Option<String>,
+}
+
+impl From<Result<(), SynError>> for ApiSaveResult {
+    fn from(result: Result<(), SynError>) -> Self {
+        match result {
+            Ok(()) => ApiSaveResult {
+                success: true,
+                error: None,
+            },
+            Err(err) => ApiSaveResult {
+                success: false,
+                error: Some(err.to_string()),
+            },
+        }
+    }
+}
+
+/// Outcome of reloading the storylets.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct ApiStoryletReload {
+    /// Whether the new storylets were swapped in.
+    pub success: bool,
+    /// Why the reload failed, if it did. The old storylets stay registered.
+    pub error: Option<String>,
+    /// IDs new to the library.
+    pub added: Vec<String>,
+    /// IDs no longer in the library.
+    pub removed: Vec<String>,
+    /// How many storylets were reloaded in place, keeping their cooldowns.
+    pub kept: u32,
+}
+
+impl From<Result<LibraryReload, SynError>> for ApiStoryletReload {
+    fn from(result: Result<LibraryReload, SynError>) -> Self {
+        match result {
+            Ok(reload) => ApiStoryletReload {
+                success: true,
+                error: None,
+                added: reload.added,
+                removed: reload.removed,
+                kept: u32::try_from(reload.kept.len()).unwrap_or(u32::MAX),
+            },
+            Err(err) => ApiStoryletReload {
+                error: Some(err.to_string()),
+                ..Default::default()
+            },
+        }
+    }
+}
+
+/// Error returned to Flutter instead of unwinding across the FFI boundary.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub enum ApiError {
+    /// The call panicked; see `engine_last_panic_info` for the location.
+    Panic {
+        /// Exported function that panicked.
+        function: String,
+        /// Panic message.
+        message: String,
+    },
+}
+
+impl std::fmt::Display for ApiError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            ApiError::Panic { function, message } => {
+                write!(f, "{} panicked: {}", function, message)
+            }
+        }
+    }
+}
+
+impl std::error::Error for ApiError {}
+
+/// Progress of a tick batch running off the UI thread, streamed after every
+/// chunk.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ApiTickProgress {
+    /// Ticks advanced so far.
+    pub ticks_done: u32,
+    /// Ticks requested.
+    pub ticks_total: u32,
+    /// World tick after the last chunk.
+    pub current_tick: u64,
+}
+
+/// One beat of a time-skip montage, yielded by [`GameEngine::fast_forward`]
+/// at day boundaries, fired storylets and milestones.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct ApiMontageFrame {
+    /// Tick the frame was taken at.
+    pub tick: u64,
+    /// In-game day at that tick.
+    pub day: u64,
+    /// Whether this tick began a new day.
+    pub day_boundary: bool,
+    /// IDs of storylets fired since the previous frame, oldest first.
+    pub fired_storylets: Vec<String>,
+    /// Milestones reached on this tick: `birthday:<age>`,
+    /// `life_stage:<stage>` or `relationship:<kind>:<npc_id>`.
+    pub milestones: Vec<String>,
+    /// Player age in years.
+    pub player_age_years: u32,
+    /// Player life stage.
+    pub life_stage: String,
+    /// Player stats at the frame.
+    pub stats: ApiStatsSnapshot,
+}
+
+/// Diagnostic details of the last panic caught at the FFI boundary.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct ApiPanicInfo {
+    /// Exported function that panicked.
+    pub function: String,
+    /// Panic message.
+    pub message: String,
+    /// Source location ("file:line:column"), if known.
+    pub location: Option<String>,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_engine_creation() {
+        let engine = GameEngine::new(42);
+        assert_eq!(engine.world_seed(), 42);
+    }
+
+    #[test]
+    fn test_engine_tick() {
+        let mut engine = GameEngine::new(42);
+        let initial_tick = engine.current_tick();
+        engine.tick();
+        assert_eq!(engine.current_tick(), initial_tick + 1);
+    }
+
+    #[test]
+    fn test_register_npc() {
+        let mut engine = GameEngine::new(42);
+        engine.register_npc(2, 25, "Engineer".to_string(), "Downtown".to_string());
+        assert!(engine.get_npc(2).is_some());
     }
 
     #[test]
@@ -2130,6 +4564,257 @@ mod tests {
         assert!(!mem_id.is_empty());
     }
 
+    #[test]
+    fn test_news_feed_publishes_daily() {
+        let mut engine = GameEngine::new(42);
+        assert!(engine.news_feed(10).is_empty());
+
+        engine.world.news_feed.report_storylet(
+            engine.world.current_tick.0 + 1,
+            "city_fair",
+            "The city fair opens",
+            &["newsworthy".to_string(), "festival".to_string()],
+        );
+        for _ in 0..24 {
+            engine.tick();
+        }
+
+        let items: Vec<ApiNewsItem> = engine
+            .news_feed(10)
+            .into_iter()
+            .map(ApiNewsItem::from)
+            .collect();
+        let fair = items
+            .iter()
+            .find(|i| i.headline == "The city fair opens")
+            .expect("fair should be in the feed");
+        assert_eq!(fair.source, "storylet");
+        assert_eq!(fair.sentiment, "neutral");
+        assert_eq!(fair.tags, vec!["festival".to_string()]);
+    }
+
+    #[test]
+    fn test_ambient_beats_reach_feed_and_journal() {
+        let mut engine = GameEngine::new(42);
+        engine.world.ambient.config.chance_per_tick = 1.0;
+        engine.world.ensure_npc_known(NpcId(2));
+        engine.set_relationship(1, 2, 6.0, 5.0, 0.0, 6.0, 0.0);
+        for _ in 0..12 {
+            engine.tick();
+        }
+
+        let ambient: Vec<ApiNewsItem> = engine
+            .news_feed(10)
+            .into_iter()
+            .map(ApiNewsItem::from)
+            .filter(|i| i.source == "ambient")
+            .collect();
+        assert!(!ambient.is_empty());
+        let journal = engine.get_npc_memories(1);
+        assert!(journal.iter().any(|m| m.event_id == "ambient:friend_meme"));
+    }
+
+    #[test]
+    fn test_dreams_replay_salient_memories_at_night() {
+        let mut engine = GameEngine::new(42);
+        engine.world.dreams.config.chance_per_night = 1.0;
+        engine.world.memory_entries.push(syn_core::MemoryEntryRecord {
+            id: "first_kiss_1".to_string(),
+            event_id: "first_kiss".to_string(),
+            npc_id: NpcId(1),
+            sim_tick: SimTick(0),
+            emotional_intensity: 0.9,
+            tags: vec!["romance".to_string()],
+            participants: vec![1, 2],
+            ..Default::default()
+        });
+        for _ in 0..24 {
+            engine.tick();
+        }
+
+        let dreams: Vec<ApiDreamEvent> = engine
+            .recent_dreams(5)
+            .into_iter()
+            .map(ApiDreamEvent::from)
+            .collect();
+        assert_eq!(dreams.len(), 1);
+        assert_eq!(dreams[0].kind, "dream");
+        assert_eq!(dreams[0].source_event_id, "first_kiss");
+        let journal = engine.get_npc_memories(1);
+        assert!(journal.iter().any(|m| m.event_id == "dream:first_kiss"));
+    }
+
+    #[test]
+    fn test_birthday_closes_a_life_chapter() {
+        let mut engine = GameEngine::new(42);
+        engine.world.player_days_since_birth = 20 * 365 - 2;
+        engine.tick_many(24);
+        assert!(engine.chapter_summaries().is_empty());
+
+        engine.tick_many(48);
+        let chapters: Vec<ApiChapterSummary> = engine
+            .chapter_summaries()
+            .iter()
+            .map(ApiChapterSummary::from)
+            .collect();
+        assert_eq!(chapters.len(), 1);
+        assert_eq!(chapters[0].boundary, "year");
+        assert_eq!(chapters[0].start_age_years, 19);
+        assert_eq!(chapters[0].stat_trajectory.len(), ALL_STAT_KINDS.len());
+        assert_eq!(
+            engine.world.chapters.open.as_ref().map(|b| b.age_years),
+            Some(20)
+        );
+    }
+
+    #[test]
+    fn test_npc_proposal_surfaces_and_resolves() {
+        let mut engine = GameEngine::new(42);
+        engine.world.player_days_since_birth = 30 * 365;
+        engine.world.set_relationship(
+            NpcId(2),
+            NpcId(1),
+            Relationship {
+                affection: 7.0,
+                trust: 5.0,
+                attraction: 7.0,
+                ..Default::default()
+            },
+        );
+        engine.tick_many(24);
+
+        let proposals: Vec<ApiRelationshipProposal> = engine
+            .pending_proposals()
+            .iter()
+            .map(ApiRelationshipProposal::from)
+            .collect();
+        assert_eq!(proposals.len(), 1);
+        assert_eq!(proposals[0].kind, "be_partners");
+        assert_eq!(proposals[0].storylet_id, format!("proposal:{}", proposals[0].id));
+
+        let resolution = engine.respond_to_proposal(proposals[0].id, false).unwrap();
+        assert!(!resolution.accepted);
+        assert!(engine.pending_proposals().is_empty());
+        assert!(engine.respond_to_proposal(proposals[0].id, true).is_err());
+        let journal = engine.get_npc_memories(1);
+        assert!(journal
+            .iter()
+            .any(|m| m.event_id == "proposal:be_partners:rejected"));
+
+        // Answering heats the pair.
+        let snapshot = engine.player_relationships();
+        let rel = snapshot
+            .relationships
+            .iter()
+            .find(|r| r.target_id == 2)
+            .unwrap();
+        assert!(rel.heat > 0.0);
+        assert!(rel.heat_trend > 0.0);
+    }
+
+    #[test]
+    fn test_action_budget_exposes_fatigue() {
+        let mut engine = GameEngine::new(42);
+        let rested = ApiActionBudget::from(engine.action_budget());
+        assert_eq!(rested.points, rested.max_points);
+        assert_eq!(rested.fatigue, 0.0);
+
+        engine.world.action_budget.spend(rested.max_points / 2.0);
+        let tired = ApiActionBudget::from(engine.action_budget());
+        assert_eq!(tired.spent_today, rested.max_points / 2.0);
+        assert!((tired.fatigue - 0.5).abs() < 1e-6);
+    }
+
+    #[test]
+    fn test_underworld_snapshot_tracks_negative_karma() {
+        let mut engine = GameEngine::new(42);
+        let clean = ApiUnderworldSnapshot::from(engine.underworld_exposure());
+        assert_eq!(clean.standing, "Clean");
+        assert!(clean.attracted_role_tags.is_empty());
+
+        syn_core::apply_karma(&mut engine.world, -70.0);
+        let connected = ApiUnderworldSnapshot::from(engine.underworld_exposure());
+        assert_eq!(connected.standing, "Connected");
+        assert!(connected.domain_unlocked);
+        assert_eq!(connected.attracted_role_tags, vec!["Fixer", "Antagonist"]);
+    }
+
+    #[test]
+    fn test_inject_event_records_memory_and_log() {
+        let mut engine = GameEngine::new(42);
+        let seq = engine
+            .inject_event(
+                "memory",
+                r#"{"tags": ["festival"], "intensity": 0.6, "participants": []}"#,
+            )
+            .expect("memory event should be accepted");
+        assert_eq!(seq, 1);
+
+        let memories = engine.get_npc_memories(1);
+        assert_eq!(memories.len(), 1);
+        assert_eq!(memories[0].event_id, "external:memory");
+
+        let rejected = ApiInjectedEvent::from(engine.inject_event("heat", r#"{"delta": 500.0}"#));
+        assert!(!rejected.accepted);
+        assert!(rejected.error.is_some());
+
+        let log: Vec<ApiNarrativeLogEntry> = engine
+            .narrative_log(10)
+            .into_iter()
+            .map(ApiNarrativeLogEntry::from)
+            .collect();
+        assert_eq!(log.len(), 1);
+        assert_eq!(log[0].kind, "memory");
+    }
+
+    #[test]
+    fn test_world_stats_counts_registered_npcs() {
+        let mut engine = GameEngine::new(42);
+        engine.register_npc(2, 25, "Engineer".to_string(), "Downtown".to_string());
+        engine.register_npc(3, 16, "Student".to_string(), "Downtown".to_string());
+
+        let stats = ApiWorldStats::from(&engine.world_stats());
+        assert_eq!(stats.population, 2);
+        assert_eq!(stats.tier0_count + stats.tier1_count + stats.tier2_count, 2);
+        let downtown = &stats.employment_by_district[0];
+        assert_eq!(downtown.district, "Downtown");
+        assert_eq!(downtown.employed, 1);
+        assert!((downtown.employment_rate - 0.5).abs() < f32::EPSILON);
+
+        engine.tick();
+        assert_eq!(ApiWorldStats::from(&engine.world_stats()).population, 2);
+    }
+
+    #[test]
+    fn test_relationship_network_export() {
+        let mut engine = GameEngine::new(42);
+        engine.set_relationship(1, 2, 6.0, 5.0, 0.0, 8.0, 0.0);
+
+        let network = engine.relationship_network();
+        assert_eq!(network.nodes.len(), 2);
+        assert_eq!(network.edges.len(), 1);
+        assert!(network.to_dot().contains("n1 -> n2"));
+    }
+
+    #[test]
+    fn test_content_preferences_validation() {
+        let mut engine = GameEngine::new(42);
+        let prefs = ContentPreferences::from(ApiContentPreferences {
+            blocked_domains: vec!["Addiction".to_string()],
+            blocked_tags: vec![],
+        });
+        assert!(engine.set_content_preferences(prefs).is_ok());
+        assert!(engine.content_preferences().is_domain_blocked("addiction"));
+
+        let invalid = ContentPreferences::from(ApiContentPreferences {
+            blocked_domains: vec![],
+            blocked_tags: vec![syn_core::STAGE_ENTRY_TAG.to_string()],
+        });
+        assert!(engine.set_content_preferences(invalid).is_err());
+        // Rejected update leaves previous preferences in place.
+        assert!(engine.content_preferences().is_domain_blocked("addiction"));
+    }
+
     #[test]
     fn test_narrative_heat_accessors() {
         let engine = GameEngine::new(42);
@@ -2177,162 +4862,141 @@ mod tests {
     }
 
     #[test]
-    fn test_character_generation_api() {
-        let result = engine_generate_character(
-            12345,
-            "TestPlayer".to_string(),
-            "STORYTELLER".to_string(),
-            "BALANCED".to_string(),
-            true,
-        );
-        
-        let char = result.expect("should generate character");
-        assert_eq!(char.name, "TestPlayer");
-        assert_eq!(char.archetype, "STORYTELLER");
-        assert_eq!(char.difficulty, "BALANCED");
-        assert!(char.sfw_mode);
-        
-        // Verify stats are present
-        assert!(!char.stats.stats.is_empty());
-        
-        // Verify personality is populated
-        assert!(char.personality.warmth >= -1.0 && char.personality.warmth <= 1.0);
-        assert!(char.personality.openness >= 0.0 && char.personality.openness <= 1.0);
+    fn test_post_life_chapter_opens_and_nudges_descendants() {
+        let mut engine = GameEngine::new(42);
+        engine.world.player_days_since_birth = 92 * 365;
+        engine.register_npc(1, 92, String::new(), "Downtown".to_string());
+        engine.register_npc(2, 40, String::new(), "Downtown".to_string());
+        engine.tick_many(24);
+
+        let view = ApiPostLife::from_world(&engine.world);
+        assert!(view.active);
+        assert!(!view.faded);
+        assert_eq!(view.descendants, vec![2]);
+        let archivist = view
+            .digital_npcs
+            .iter()
+            .find(|n| n.kind == "archivist")
+            .expect("archivist")
+            .id;
+
+        let event = engine.commune_with_digital_npc(archivist).unwrap();
+        assert_eq!(event.digital_npc, Some(archivist));
+        let nudge = engine.nudge_descendant(NpcId(2), "career").unwrap();
+        assert_eq!(nudge.tag, "career");
+        assert!(engine.nudge_descendant(NpcId(1), "career").is_err());
+
+        let view = ApiPostLife::from_world(&engine.world);
+        assert_eq!(view.nudges.len(), 1);
+        assert!(view.signal < view.max_signal / 2.0);
+        let journal = engine.get_npc_memories(1);
+        assert!(journal.iter().any(|m| m.event_id == "post_life:entered"));
+        assert!(journal.iter().any(|m| m.event_id == "post_life:nudged"));
     }
 
     #[test]
-    fn test_character_generation_deterministic() {
-        let char1 = engine_generate_character(
-            99999,
-            "Alice".to_string(),
-            "CHALLENGER".to_string(),
-            "HARSH".to_string(),
-            false,
-        ).unwrap();
-        
-        let char2 = engine_generate_character(
-            99999,
-            "Alice".to_string(),
-            "CHALLENGER".to_string(),
-            "HARSH".to_string(),
-            false,
-        ).unwrap();
-        
-        // Same seed + config = identical output
-        assert_eq!(char1.family_structure, char2.family_structure);
-        assert_eq!(char1.socioeconomic_tier, char2.socioeconomic_tier);
-        assert_eq!(char1.luck_seed, char2.luck_seed);
-        assert_eq!(char1.starting_karma, char2.starting_karma);
+    fn test_relationship_changes_only_returns_pairs_changed_since_tick() {
+        let mut engine = GameEngine::new(42);
+        engine.register_npc(2, 30, String::new(), "Downtown".to_string());
+        engine.register_npc(3, 30, String::new(), "Downtown".to_string());
+        engine.set_relationship(1, 2, 5.0, 4.0, 0.0, 2.0, 0.0);
+
+        engine.world.current_tick = SimTick(10);
+        engine.set_relationship(1, 3, 2.0, 1.0, 0.0, 1.0, 0.0);
+        // Writing back an unchanged relationship is not a change
+        engine.set_relationship(1, 2, 5.0, 4.0, 0.0, 2.0, 0.0);
+
+        let changes = engine.relationship_changes(5);
+        assert_eq!(changes.tick, 10);
+        let targets: Vec<i64> = changes.relationships.iter().map(|r| r.target_id).collect();
+        assert_eq!(targets, vec![3]);
+        assert!(!changes.relationships[0].affection_band.is_empty());
+        assert_eq!(engine.relationship_changes(0).relationships.len(), 2);
+        assert!(engine.relationship_changes(11).relationships.is_empty());
+
+        engine.world.relationships.remove(&(NpcId(1), NpcId(3)));
+        engine.world.relationship_pressure.stamp_change(1, 3, 11);
+        let changes = engine.relationship_changes(11);
+        assert!(changes.relationships.is_empty());
+        assert_eq!(changes.removed_target_ids, vec![3]);
     }
 
     #[test]
-    fn test_invalid_archetype_returns_none() {
-        let result = engine_generate_character(
-            42,
-            "Test".to_string(),
-            "INVALID".to_string(),
-            "BALANCED".to_string(),
-            true,
-        );
-        assert!(result.is_none());
-    }
+    fn test_player_notes_are_returned_with_npcs_and_memories() {
+        let mut engine = GameEngine::new(42);
+        engine.register_npc(2, 30, String::new(), "Downtown".to_string());
+        let memory_id = engine.record_memory(1, "prom_night".to_string(), 0.6);
 
-    #[test]
-    fn test_init_with_character() {
-        // Clear any existing engine state
-        let mut engine = ENGINE.lock().unwrap();
-        *engine = None;
-        drop(engine);
-        
-        let success = engine_init_with_character(
-            54321,
-            "GamePlayer".to_string(),
-            "ANALYST".to_string(),
-            "FORGIVING".to_string(),
-            true,
+        assert_eq!(
+            engine.set_note("npc:2", "owes me $20").unwrap().as_deref(),
+            Some("owes me $20")
+        );
+        engine
+            .set_note(&format!("memory:{}", memory_id), "this is when it went wrong")
+            .unwrap();
+        assert!(engine.set_note("npc:99", "stranger").is_err());
+        assert!(engine.set_note("district:3", "nice").is_err());
+
+        assert_eq!(engine.get_npc(2).unwrap().note.as_deref(), Some("owes me $20"));
+        let memories = engine.get_npc_memories(1);
+        assert_eq!(
+            memories[0].note.as_deref(),
+            Some("this is when it went wrong")
+        );
+        let query = MemoryQuery::default();
+        let results = ApiMemorySearchResults::from_page(&engine.search_memories(&query), &query)
+            .with_notes(engine.player_notes());
+        assert_eq!(
+            results.hits[0].entry.note.as_deref(),
+            Some("this is when it went wrong")
         );
-        
-        assert!(success);
-        
-        // Verify engine was initialized
-        let engine = ENGINE.lock().unwrap();
-        assert!(engine.is_some());
-        let e = engine.as_ref().unwrap();
-        assert_eq!(e.world_seed(), 54321);
-    }
-
-    #[test]
-    fn test_district_api_list() {
-        // Clear and init engine
-        let mut engine = ENGINE.lock().unwrap();
-        *engine = None;
-        drop(engine);
-        
-        init_engine(42);
-        
-        let districts = engine_list_districts();
-        assert!(!districts.is_empty());
-        assert_eq!(districts.len(), 10); // Default city has 10 districts
-        
-        // Check we have expected districts
-        let names: Vec<&str> = districts.iter().map(|d| d.name.as_str()).collect();
-        assert!(names.contains(&"Downtown"));
-        assert!(names.contains(&"Highland Heights"));
-    }
 
-    #[test]
-    fn test_district_api_get_by_name() {
-        // Clear and init engine
-        let mut engine = ENGINE.lock().unwrap();
-        *engine = None;
-        drop(engine);
-        
-        init_engine(42);
-        
-        let district = engine_get_district("Downtown".to_string());
-        assert!(district.is_some());
-        let d = district.unwrap();
-        assert_eq!(d.name, "Downtown");
-        assert_eq!(d.district_type, "Downtown");
-        // Downtown type gives +15 economy modifier, so baseline is 65
-        // With random variation ±15, range is 50-80
-        assert!(d.economy >= 40.0 && d.economy <= 90.0);
+        // Clearing a note removes it
+        assert_eq!(engine.set_note("npc:2", "  ").unwrap(), None);
+        assert_eq!(engine.get_npc(2).unwrap().note, None);
     }
 
     #[test]
-    fn test_district_api_city_stats() {
-        // Clear and init engine
-        let mut engine = ENGINE.lock().unwrap();
-        *engine = None;
-        drop(engine);
-        
-        init_engine(42);
-        
-        let stats = engine_get_city_stats();
-        assert_eq!(stats.district_count, 10);
-        assert!(stats.average_economy > 0.0);
-        assert!(stats.safest_district.is_some());
-        assert!(stats.wealthiest_district.is_some());
-    }
+    fn test_admirers_confront_the_player_over_a_new_romance() {
+        let mut engine = GameEngine::new(42);
+        let player = 1; // GameEngine::new always plays as NPC 1
+        let (partner, admirer) = (2, 3);
+        for npc in [partner, admirer] {
+            engine.register_npc(npc, 28, "barista".into(), "Downtown".into());
+        }
+        engine.set_relationship(player, partner, 3.0, 2.0, 2.0, 3.0, 0.0);
+        engine.set_relationship(admirer, player, 3.0, 2.0, 7.0, 4.0, 0.0);
+        engine.set_relationship(admirer, partner, 0.0, 0.0, 0.0, 2.0, 5.0);
+        engine.tick();
+        assert!(engine.jealousy_events().is_empty());
+
+        // The two move in the same circle, and the player falls for the partner
+        let mut circle = syn_core::SocialCluster::new("cafe");
+        circle.add_member(NpcId(partner));
+        circle.add_member(NpcId(admirer));
+        engine.world.gossip.clusters.insert("cafe".into(), circle);
+        engine.set_relationship(player, partner, 6.0, 4.0, 7.0, 5.0, 0.0);
+        engine.tick();
 
-    #[test]
-    fn test_district_api_economic_event() {
-        // Clear and init engine
-        let mut engine = ENGINE.lock().unwrap();
-        *engine = None;
-        drop(engine);
-        
-        init_engine(42);
-        
-        // Get initial economy
-        let before = engine_get_district("Downtown".to_string()).unwrap();
-        let initial_economy = before.economy;
-        
-        // Apply economic crash
-        engine_apply_district_economic_event("Downtown".to_string(), -20.0);
-        
-        // Verify economy dropped
-        let after = engine_get_district("Downtown".to_string()).unwrap();
-        assert!(after.economy < initial_economy);
+        let events: Vec<ApiJealousyEvent> = engine
+            .jealousy_events()
+            .iter()
+            .map(ApiJealousyEvent::from)
+            .collect();
+        assert_eq!(events.len(), 1);
+        let event = &events[0];
+        assert_eq!((event.jealous_id, event.partner_id), (admirer, partner));
+        assert_eq!(event.trigger, "attraction");
+        assert!(event.confronted);
+        assert!(engine.get_relationship(admirer, player).resentment > 0.0);
+
+        let query = MemoryQuery {
+            tags: vec!["jealousy".into()],
+            limit: 10,
+            ..Default::default()
+        };
+        let page = engine.search_memories(&query);
+        let id = format!("jealousy:{}", event.id);
+        assert!(page.hits.iter().any(|hit| hit.entry.id == id), "{}", id);
     }
 }
@@ -0,0 +1,256 @@
+//! Named worlds ("profiles") side by side in one process.
+//!
+//! A [`ProfileManager`] keeps several independent worlds under names, each
+//! with its own storage directory under a shared root, and tracks which one
+//! is active. Players can run several lives; dev builds can A/B two worlds
+//! without restarting the process.
+//!
+//! Pure Rust consumers keep every world inside the manager and drive the
+//! active one through [`ProfileManager::active_world_mut`]. The FFI surface,
+//! whose active world lives in the global statics, checks worlds out with
+//! [`ProfileManager::take_world`] and parks them again with
+//! [`ProfileManager::park_world`] when switching.
+
+use std::collections::BTreeMap;
+use std::fmt;
+use std::fs;
+use std::path::{Path, PathBuf};
+
+/// Longest profile name accepted.
+pub const MAX_PROFILE_NAME_LEN: usize = 64;
+
+/// Why a profile operation was refused.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ProfileError {
+    /// Names must be 1..=64 ASCII letters, digits, `-` or `_`.
+    InvalidName(String),
+    /// A profile with this name already exists.
+    AlreadyExists(String),
+    /// No profile with this name.
+    NotFound(String),
+    /// The active profile cannot be deleted; switch away first.
+    Active(String),
+    /// The profile's storage directory could not be created or removed.
+    Storage {
+        /// Profile name.
+        name: String,
+        /// Underlying I/O error.
+        message: String,
+    },
+}
+
+impl fmt::Display for ProfileError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ProfileError::InvalidName(name) => write!(f, "invalid profile name '{}'", name),
+            ProfileError::AlreadyExists(name) => write!(f, "profile '{}' already exists", name),
+            ProfileError::NotFound(name) => write!(f, "no profile named '{}'", name),
+            ProfileError::Active(name) => {
+                write!(f, "profile '{}' is active and cannot be deleted", name)
+            }
+            ProfileError::Storage { name, message } => {
+                write!(f, "storage for profile '{}': {}", name, message)
+            }
+        }
+    }
+}
+
+impl std::error::Error for ProfileError {}
+
+/// Public description of one profile.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ProfileInfo {
+    /// Profile name, also its storage directory name.
+    pub name: String,
+    /// Seed the world was created from.
+    pub seed: u64,
+    /// Directory holding this profile's simulation storage, saves and exports.
+    pub storage_dir: PathBuf,
+    /// Whether this is the active profile.
+    pub active: bool,
+}
+
+#[derive(Debug)]
+struct ProfileSlot<W> {
+    seed: u64,
+    storage_dir: PathBuf,
+    /// `None` while the world is checked out with [`ProfileManager::take_world`].
+    world: Option<W>,
+}
+
+/// Named worlds with isolated storage directories.
+#[derive(Debug)]
+pub struct ProfileManager<W> {
+    root: PathBuf,
+    profiles: BTreeMap<String, ProfileSlot<W>>,
+    active: Option<String>,
+}
+
+impl<W> ProfileManager<W> {
+    /// A manager storing each profile under `root/<name>`.
+    pub fn new(root: impl Into<PathBuf>) -> Self {
+        Self {
+            root: root.into(),
+            profiles: BTreeMap::new(),
+            active: None,
+        }
+    }
+
+    /// Directory holding every profile's storage directory.
+    pub fn root(&self) -> &Path {
+        &self.root
+    }
+
+    /// Add a profile for `world` and create its storage directory.
+    ///
+    /// The first profile created becomes active.
+    pub fn create(&mut self, name: &str, seed: u64, world: W) -> Result<ProfileInfo, ProfileError> {
+        self.create_with(name, seed, |_| Ok::<W, ProfileError>(world))
+    }
+
+    /// Create a profile's storage directory, then build its world there with
+    /// `build`, which receives the directory.
+    ///
+    /// If `build` fails the directory is removed again and no profile is
+    /// added. The first profile created becomes active.
+    pub fn create_with<E: fmt::Display>(
+        &mut self,
+        name: &str,
+        seed: u64,
+        build: impl FnOnce(&Path) -> Result<W, E>,
+    ) -> Result<ProfileInfo, ProfileError> {
+        if !is_valid_profile_name(name) {
+            return Err(ProfileError::InvalidName(name.to_string()));
+        }
+        if self.profiles.contains_key(name) {
+            return Err(ProfileError::AlreadyExists(name.to_string()));
+        }
+        let storage_dir = self.root.join(name);
+        fs::create_dir_all(&storage_dir).map_err(|e| ProfileError::Storage {
+            name: name.to_string(),
+            message: e.to_string(),
+        })?;
+        let world = match build(&storage_dir) {
+            Ok(world) => world,
+            Err(e) => {
+                let _ = fs::remove_dir_all(&storage_dir);
+                return Err(ProfileError::Storage {
+                    name: name.to_string(),
+                    message: e.to_string(),
+                });
+            }
+        };
+        self.profiles.insert(
+            name.to_string(),
+            ProfileSlot {
+                seed,
+                storage_dir,
+                world: Some(world),
+            },
+        );
+        if self.active.is_none() {
+            self.active = Some(name.to_string());
+        }
+        self.info(name)
+            .ok_or_else(|| ProfileError::NotFound(name.to_string()))
+    }
+
+    /// Every profile, ordered by name.
+    pub fn list(&self) -> Vec<ProfileInfo> {
+        self.profiles
+            .keys()
+            .filter_map(|name| self.info(name))
+            .collect()
+    }
+
+    /// One profile by name.
+    pub fn info(&self, name: &str) -> Option<ProfileInfo> {
+        self.profiles.get(name).map(|slot| ProfileInfo {
+            name: name.to_string(),
+            seed: slot.seed,
+            storage_dir: slot.storage_dir.clone(),
+            active: self.active.as_deref() == Some(name),
+        })
+    }
+
+    /// Name of the active profile, if any.
+    pub fn active_name(&self) -> Option<&str> {
+        self.active.as_deref()
+    }
+
+    /// Whether a profile exists.
+    pub fn contains(&self, name: &str) -> bool {
+        self.profiles.contains_key(name)
+    }
+
+    /// Make `name` the active profile.
+    pub fn switch(&mut self, name: &str) -> Result<(), ProfileError> {
+        if !self.profiles.contains_key(name) {
+            return Err(ProfileError::NotFound(name.to_string()));
+        }
+        self.active = Some(name.to_string());
+        Ok(())
+    }
+
+    /// Remove a profile, its world and its storage directory.
+    ///
+    /// The active profile cannot be deleted.
+    pub fn delete(&mut self, name: &str) -> Result<(), ProfileError> {
+        if self.active.as_deref() == Some(name) {
+            return Err(ProfileError::Active(name.to_string()));
+        }
+        let slot = self
+            .profiles
+            .remove(name)
+            .ok_or_else(|| ProfileError::NotFound(name.to_string()))?;
+        if slot.storage_dir.exists() {
+            fs::remove_dir_all(&slot.storage_dir).map_err(|e| ProfileError::Storage {
+                name: name.to_string(),
+                message: e.to_string(),
+            })?;
+        }
+        Ok(())
+    }
+
+    /// A profile's world, unless it is checked out.
+    pub fn world(&self, name: &str) -> Option<&W> {
+        self.profiles.get(name)?.world.as_ref()
+    }
+
+    /// A profile's world, mutably, unless it is checked out.
+    pub fn world_mut(&mut self, name: &str) -> Option<&mut W> {
+        self.profiles.get_mut(name)?.world.as_mut()
+    }
+
+    /// The active profile's world, unless it is checked out.
+    pub fn active_world_mut(&mut self) -> Option<&mut W> {
+        let name = self.active.clone()?;
+        self.world_mut(&name)
+    }
+
+    /// Check a profile's world out of the manager, leaving its slot empty.
+    pub fn take_world(&mut self, name: &str) -> Option<W> {
+        self.profiles.get_mut(name)?.world.take()
+    }
+
+    /// Put a checked-out world back, or hand it back if the profile no
+    /// longer exists.
+    pub fn park_world(&mut self, name: &str, world: W) -> Result<(), W> {
+        match self.profiles.get_mut(name) {
+            Some(slot) => {
+                slot.world = Some(world);
+                Ok(())
+            }
+            None => Err(world),
+        }
+    }
+}
+
+/// Whether `name` is usable as a profile (and directory) name.
+pub fn is_valid_profile_name(name: &str) -> bool {
+    !name.is_empty()
+        && name.len() <= MAX_PROFILE_NAME_LEN
+        && name
+            .chars()
+            .all(|c| c.is_ascii_alphanumeric() || c == '-' || c == '_')
+}
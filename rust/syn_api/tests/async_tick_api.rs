@@ -0,0 +1,51 @@
+//! Chunked tick batches that back `engine_tick_many_async`.
+#![cfg(feature = "ffi")]
+
+use std::sync::Mutex;
+
+use syn_api::{
+    engine_new_game, engine_tick_many_chunked, ApiPlayerConfig, ApiTickProgress, TICK_CHUNK_SIZE,
+};
+
+/// Serializes tests that replace the shared engine.
+static ENGINE_LOCK: Mutex<()> = Mutex::new(());
+
+fn new_game() {
+    let config = ApiPlayerConfig {
+        name: "TestPlayer".to_string(),
+        pronouns: None,
+        archetype: "STORYTELLER".to_string(),
+        difficulty: "BALANCED".to_string(),
+        sfw_mode: true,
+    };
+    assert!(engine_new_game(42, config).is_some());
+}
+
+#[test]
+fn batches_report_progress_after_every_chunk() {
+    let _guard = ENGINE_LOCK.lock().unwrap();
+    new_game();
+    let count = TICK_CHUNK_SIZE * 2 + 5;
+    let mut reports: Vec<ApiTickProgress> = Vec::new();
+
+    let done = engine_tick_many_chunked(count, |p| {
+        reports.push(p);
+        true
+    });
+
+    assert_eq!(done, count);
+    let ticks_done: Vec<u32> = reports.iter().map(|p| p.ticks_done).collect();
+    assert_eq!(ticks_done, vec![TICK_CHUNK_SIZE, TICK_CHUNK_SIZE * 2, count]);
+    assert!(reports.iter().all(|p| p.ticks_total == count));
+    assert!(reports.windows(2).all(|w| w[0].current_tick < w[1].current_tick));
+}
+
+#[test]
+fn closing_the_stream_stops_the_batch() {
+    let _guard = ENGINE_LOCK.lock().unwrap();
+    new_game();
+
+    let done = engine_tick_many_chunked(TICK_CHUNK_SIZE * 10, |_| false);
+
+    assert_eq!(done, TICK_CHUNK_SIZE);
+}
@@ -0,0 +1,25 @@
+use syn_api::GameEngine;
+
+#[test]
+fn the_timeline_lists_fired_storylets() {
+    let mut engine = GameEngine::new(42);
+    assert!(engine.chronicle(0).is_empty());
+
+    engine.register_storylet("demo_beat".into(), "Demo Beat".into(), 0.0, 0.0);
+    engine
+        .force_fire_storylet("demo_beat", &[])
+        .expect("storylet is registered");
+    engine.tick();
+    let later = engine.current_tick();
+    engine
+        .force_fire_storylet("demo_beat", &[])
+        .expect("storylet is registered");
+
+    let timeline = engine.chronicle(0);
+    assert_eq!(timeline.len(), 2);
+    assert_eq!(timeline[0].seq, 1);
+    assert_eq!(timeline[0].storylet_name, "Demo Beat");
+    assert!(timeline[0].choice_id.is_none());
+    assert_eq!(engine.chronicle(later).len(), 1);
+    assert!(engine.npc_chronicle(99).is_empty());
+}
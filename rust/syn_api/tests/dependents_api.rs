@@ -0,0 +1,44 @@
+use syn_api::{ApiDependent, DependentKind, GameEngine, MemoryQuery};
+
+#[test]
+fn pets_need_care_and_neglect_lands_in_the_journal() {
+    let mut engine = GameEngine::new(42);
+    let pet = engine.adopt_dependent(DependentKind::Pet, "Biscuit");
+    let sibling = engine.adopt_dependent(DependentKind::YoungerSibling, "Milo");
+
+    engine.tick_many(18);
+    let view = |engine: &GameEngine, id: u64| {
+        engine
+            .dependents()
+            .iter()
+            .find(|d| d.id == id)
+            .map(|d| ApiDependent::from_dependent(d, engine.dependent_config()))
+            .expect("dependent")
+    };
+    assert!(view(&engine, pet).needs_care);
+    assert!(!view(&engine, sibling).needs_care);
+
+    let event = engine.care_for_dependent(pet).expect("care");
+    assert!(!view(&engine, pet).needs_care);
+    assert_eq!(view(&engine, pet).last_cared_tick, Some(event.tick));
+
+    engine.tick_many(24 * 7);
+    assert!(view(&engine, pet).lost);
+    assert!(!view(&engine, sibling).lost);
+    assert!(engine.care_for_dependent(pet).is_err());
+
+    let query = MemoryQuery {
+        tags: vec!["dependent".into()],
+        limit: 100,
+        ..Default::default()
+    };
+    let page = engine.search_memories(&query);
+    for event in engine.dependent_events() {
+        let id = format!("dependent:{}", event.id);
+        assert!(page.hits.iter().any(|hit| hit.entry.id == id), "{}", id);
+    }
+    assert!(page
+        .hits
+        .iter()
+        .any(|hit| hit.entry.tags.iter().any(|t| t == "lost")));
+}
@@ -0,0 +1,95 @@
+//! Two engines from the same seed and inputs must produce identical worlds.
+
+use std::path::PathBuf;
+
+use syn_api::determinism::{verify_determinism, verify_engines, DeterminismConfig, Divergence};
+use syn_api::{FloatMode, GameEngine};
+
+/// Golden digest of the quantized default run, shared by every platform.
+///
+/// Written on the first run (or when `SYN_BLESS_GOLDEN` is set); commit it so
+/// CI on other platforms compares against the same value.
+fn golden_digest_path() -> PathBuf {
+    PathBuf::from(env!("CARGO_MANIFEST_DIR")).join("tests/golden/determinism_seed42_quantized.txt")
+}
+
+#[test]
+fn engines_from_same_seed_stay_in_lockstep() {
+    let config = DeterminismConfig::default();
+    let report = verify_determinism(&config).expect("engines diverged");
+
+    assert_eq!(report.ticks, 2_000);
+    assert_eq!(report.checks, 20);
+}
+
+#[test]
+fn perturbed_engine_reports_first_divergent_field() {
+    let config = DeterminismConfig {
+        ticks: 300,
+        ..Default::default()
+    };
+    let left = GameEngine::new(config.seed);
+    let mut right = GameEngine::new(config.seed);
+    right.set_relationship(1, 9, 3.0, 0.0, 0.0, 0.0, 0.0);
+
+    let divergence = verify_engines(left, right, &config).unwrap_err();
+    assert_eq!(
+        divergence,
+        Divergence {
+            tick: 100,
+            field: "relationships",
+        }
+    );
+}
+
+#[test]
+fn quantized_engines_stay_in_lockstep_and_on_the_grid() {
+    let config = DeterminismConfig {
+        ticks: 500,
+        float_mode: FloatMode::Quantized,
+        ..Default::default()
+    };
+    let first = verify_determinism(&config).expect("quantized engines diverged");
+    let second = verify_determinism(&config).expect("quantized engines diverged");
+    assert_eq!(first.digest, second.digest);
+
+    let mut engine = GameEngine::new(config.seed);
+    engine.set_float_mode(FloatMode::Quantized);
+    engine.set_relationship(1, 2, 3.000_01, 0.0, 0.0, 0.0, 0.0);
+    engine.tick();
+    let snapshot = engine.world_snapshot();
+    for rel in snapshot.relationships.values() {
+        let scaled = rel.affection * 4096.0;
+        assert!((scaled - scaled.round()).abs() < 1e-3, "{} is off the grid", rel.affection);
+    }
+}
+
+#[test]
+fn quantized_run_matches_golden_digest() {
+    let config = DeterminismConfig {
+        float_mode: FloatMode::Quantized,
+        ..Default::default()
+    };
+    let report = verify_determinism(&config).expect("quantized engines diverged");
+    let actual = format!("{:016x}", report.digest);
+
+    let path = golden_digest_path();
+    if std::env::var_os("SYN_BLESS_GOLDEN").is_some() {
+        std::fs::create_dir_all(path.parent().unwrap()).unwrap();
+        std::fs::write(&path, format!("{}\n", actual)).unwrap();
+        return;
+    }
+    let golden = std::fs::read_to_string(&path).unwrap_or_else(|err| {
+        panic!(
+            "no golden digest at {} ({}); run with SYN_BLESS_GOLDEN=1 to write it",
+            path.display(),
+            err
+        )
+    });
+    assert_eq!(
+        actual,
+        golden.trim(),
+        "quantized digest drifted from {}; rerun with SYN_BLESS_GOLDEN=1 if the change is intended",
+        path.display()
+    );
+}
@@ -1,7 +1,11 @@
+#![cfg(feature = "ffi")]
+
 use std::collections::HashMap;
+use std::sync::Mutex;
 
 use syn_api::{
-    api_choose_option, api_get_current_event, api_reset_runtime, tags_to_bitset, Storylet,
+    api_choose_option, api_get_current_event, api_preview_next_event, api_reset_runtime,
+    api_submit_choice, tags_to_bitset, ApiChoiceRejection, Storylet,
     StoryletChoice, StoryletCooldown, StoryletOutcome, StoryletOutcomeSet, WorldSeed, WorldState,
 };
 use syn_director::{StoryletLibrary, StoryletRoles};
@@ -9,6 +13,9 @@ use syn_core::relationship_model::{RelationshipAxis, RelationshipDelta};
 use syn_core::{NpcId, StatDelta, StatKind};
 use syn_sim::SimState;
 
+/// Serializes tests that replace the shared runtime.
+static RUNTIME_LOCK: Mutex<()> = Mutex::new(());
+
 fn basic_prereqs() -> syn_director::StoryletPrerequisites {
     syn_director::StoryletPrerequisites {
         stat_conditions: vec![],
@@ -32,6 +39,13 @@ fn basic_prereqs() -> syn_director::StoryletPrerequisites {
         digital_legacy_prereq: None,
         time_and_location: None,
         skill_requirements: vec![],
+        lives_with_target: None,
+        max_target_mood: None,
+        romance_reciprocity: None,
+        depends_on: vec![],
+        prefers_after: vec![],
+        declared_statuses: vec![],
+        expression: None,
     }
 }
 
@@ -48,6 +62,7 @@ fn sample_storylet() -> Storylet {
             choices: vec![StoryletChoice {
                 id: "choice-api".to_string(),
                 label: "Take it".to_string(),
+                skill_requirements: Vec::new(),
                 outcome: StoryletOutcome {
                     stat_deltas: vec![StatDelta {
                         kind: StatKind::Mood,
@@ -73,6 +88,7 @@ fn sample_storylet() -> Storylet {
 
 #[test]
 fn api_flow_returns_events() {
+    let _guard = RUNTIME_LOCK.lock().unwrap();
     let world = WorldState::new(WorldSeed(5), NpcId(1));
     let sim = SimState::new_for_test();
     let library = StoryletLibrary::from_storylets(vec![sample_storylet()]);
@@ -86,3 +102,73 @@ fn api_flow_returns_events() {
     let next = api_choose_option(event.storylet_id, event.choices[0].id.clone(), 2);
     assert!(next.is_some());
 }
+
+#[test]
+fn preview_matches_next_event_without_consuming_it() {
+    let _guard = RUNTIME_LOCK.lock().unwrap();
+    let world = WorldState::new(WorldSeed(5), NpcId(1));
+    let sim = SimState::new_for_test();
+    let mut once = sample_storylet();
+    once.id = "story-once".to_string();
+    once.weight = 3.0;
+    let library = StoryletLibrary::from_storylets(vec![sample_storylet(), once]);
+
+    api_reset_runtime(world, sim, library);
+
+    let preview = api_preview_next_event();
+    let ids: Vec<&str> = preview.candidates.iter().map(|c| c.storylet_id.as_str()).collect();
+    assert_eq!(ids, vec!["story-once", "story-api"]);
+    assert!((preview.candidates[0].chance - 0.75).abs() < 1e-6);
+
+    // Previewing is idempotent and agrees with the real selection.
+    let again = api_preview_next_event();
+    let selected = preview.selected.expect("expected a pick");
+    assert_eq!(again.selected.map(|e| e.storylet_id), Some(selected.storylet_id.clone()));
+    let event = api_get_current_event().expect("expected event");
+    assert_eq!(event.storylet_id, selected.storylet_id);
+}
+
+#[test]
+fn submitted_choices_reject_foreign_stale_and_duplicate_submissions() {
+    let _guard = RUNTIME_LOCK.lock().unwrap();
+    let world = WorldState::new(WorldSeed(5), NpcId(1));
+    let sim = SimState::new_for_test();
+    let library = StoryletLibrary::from_storylets(vec![sample_storylet()]);
+
+    api_reset_runtime(world, sim, library);
+
+    let event = api_get_current_event().expect("expected event");
+    assert_ne!(event.event_token, 0);
+    // Polling the same event keeps its token.
+    assert_eq!(
+        api_get_current_event().map(|e| e.event_token),
+        Some(event.event_token)
+    );
+
+    let token = event.event_token;
+    let id = event.storylet_id.clone();
+    let foreign = api_submit_choice(token, id.clone(), "nope".into(), 2);
+    assert!(!foreign.accepted);
+    assert_eq!(
+        foreign.rejection,
+        Some(ApiChoiceRejection::UnknownChoice {
+            storylet_id: "story-api".to_string(),
+            choice_id: "nope".to_string(),
+        })
+    );
+
+    let stale = api_submit_choice(token + 7, id.clone(), "choice-api".into(), 2);
+    assert!(matches!(stale.rejection, Some(ApiChoiceRejection::StaleEvent { .. })));
+
+    let first = api_submit_choice(token, id.clone(), "choice-api".into(), 2);
+    assert!(first.accepted);
+    let next = first.next_event.expect("storylet has no cooldown");
+    assert_ne!(next.event_token, token);
+
+    let retry = api_submit_choice(token, id, "choice-api".into(), 2);
+    assert!(!retry.accepted);
+    assert_eq!(
+        retry.rejection,
+        Some(ApiChoiceRejection::DuplicateSubmission { token })
+    );
+}
@@ -0,0 +1,30 @@
+use syn_api::{ApiDifficultyStatus, Difficulty, DifficultyConfig, GameEngine};
+
+#[test]
+fn base_difficulty_and_controller_config_surface_in_status() {
+    let mut engine = GameEngine::new(7);
+    let status = ApiDifficultyStatus::from(engine.difficulty());
+    assert_eq!(status.base, "BALANCED");
+    assert!(!status.dynamic_enabled);
+    assert!((status.negative_modifier - 1.0).abs() < 1e-6);
+    assert!(status.decisions.is_empty());
+
+    engine.set_base_difficulty(Difficulty::Harsh);
+    engine.set_difficulty_config(DifficultyConfig {
+        enabled: true,
+        min_negative_scale: 1.1,
+        max_negative_scale: 1.2,
+        ..Default::default()
+    });
+    let status = ApiDifficultyStatus::from(engine.difficulty());
+    assert_eq!(status.base, "HARSH");
+    assert!(status.dynamic_enabled);
+    // The knob is clamped into the new bounds and stacks on the base modifier.
+    assert!((status.negative_scale - 1.1).abs() < 1e-6);
+    assert!((status.negative_modifier - 1.4 * 1.1).abs() < 1e-5);
+    assert!((status.positive_modifier - 0.85).abs() < 1e-6);
+
+    engine.tick_many(24 * 3);
+    let status = ApiDifficultyStatus::from(engine.difficulty());
+    assert!(status.negative_scale >= 1.1 - 1e-6 && status.negative_scale <= 1.2 + 1e-6);
+}
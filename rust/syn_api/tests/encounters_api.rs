@@ -0,0 +1,29 @@
+use syn_api::{ApiEncounter, GameEngine, MemoryQuery};
+
+#[test]
+fn neighbors_become_acquaintances_with_journal_entries() {
+    let mut engine = GameEngine::new(42);
+    engine.register_npc(1, 25, "barista".into(), "Harbor".into());
+    for neighbor in 2..=6 {
+        engine.register_npc(neighbor, 30, "clerk".into(), "Harbor".into());
+    }
+
+    engine.tick_many(24 * 40);
+
+    let encounters: Vec<ApiEncounter> =
+        engine.encounters().iter().map(ApiEncounter::from).collect();
+    assert!(!encounters.is_empty());
+    assert!(encounters.iter().all(|e| (2..=6).contains(&e.npc_id)));
+    assert!(encounters.iter().all(|e| e.source == "neighborhood"));
+
+    let query = MemoryQuery {
+        tags: vec!["first_meeting".into()],
+        limit: 100,
+        ..Default::default()
+    };
+    let page = engine.search_memories(&query);
+    for encounter in &encounters {
+        let id = format!("encounter:{}", encounter.id);
+        assert!(page.hits.iter().any(|hit| hit.entry.id == id), "{}", id);
+    }
+}
@@ -0,0 +1,65 @@
+use syn_api::{ApiChoiceRejection, GameEngine};
+use syn_director::storylet_loader::parse_storylet_str;
+
+/// An engine whose only storylet offers two choices.
+fn engine_with_choice_storylet() -> GameEngine {
+    let mut engine = GameEngine::new(42);
+    let storylet = parse_storylet_str(
+        r#"{
+            "id": "engine_crossroads",
+            "name": "Crossroads",
+            "heat": 5,
+            "weight": 1.0,
+            "outcomes": {
+                "choices": [
+                    { "id": "help", "label": "Help out", "outcome": { "karma_delta": 3.0 } },
+                    { "id": "walk_on", "label": "Walk on", "outcome": { "karma_delta": -3.0 } }
+                ]
+            }
+        }"#,
+    )
+    .expect("storylet parses");
+    engine.replace_storylets(vec![storylet]);
+    engine
+}
+
+#[test]
+fn choices_fire_through_the_engines_own_director() {
+    let mut engine = engine_with_choice_storylet();
+    let event = engine.current_event_view().expect("storylet is eligible");
+    assert_eq!(event.storylet_id, "engine_crossroads");
+    let choices: Vec<&str> = event.choices.iter().map(|c| c.id.as_str()).collect();
+    assert_eq!(choices, vec!["help", "walk_on"]);
+    assert_eq!(
+        engine.current_event_view().map(|e| e.event_token),
+        Some(event.event_token)
+    );
+
+    let karma = engine.player_karma();
+    let start = engine.current_tick();
+    engine
+        .choose("engine_crossroads", "help", 2)
+        .expect("presented choice is accepted");
+
+    assert!(engine.player_karma() > karma);
+    assert_eq!(engine.current_tick(), start + 2);
+    let timeline = engine.chronicle(0);
+    assert_eq!(timeline.len(), 1);
+    assert_eq!(timeline[0].choice_id.as_deref(), Some("help"));
+}
+
+#[test]
+fn choices_not_presented_are_rejected() {
+    let mut engine = engine_with_choice_storylet();
+    assert!(matches!(
+        engine.choose("engine_crossroads", "help", 0),
+        Err(ApiChoiceRejection::NoEventPresented)
+    ));
+
+    engine.current_event_view().expect("storylet is eligible");
+    assert!(matches!(
+        engine.choose("engine_crossroads", "shrug", 0),
+        Err(ApiChoiceRejection::UnknownChoice { .. })
+    ));
+    assert!(engine.chronicle(0).is_empty());
+}
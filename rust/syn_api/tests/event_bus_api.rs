@@ -0,0 +1,23 @@
+//! World events drained through `GameEngine::poll_events`.
+
+use syn_api::{ApiWorldEvent, GameEngine};
+
+#[test]
+fn fired_storylets_and_heat_band_changes_are_polled_once() {
+    let mut engine = GameEngine::new(42);
+    engine.register_storylet("blowout".into(), "Blowout".into(), 90.0, 1.0);
+    engine.tick();
+    engine.poll_events();
+
+    engine
+        .force_fire_storylet("blowout", &[])
+        .expect("storylet is registered");
+
+    let events: Vec<ApiWorldEvent> = engine.poll_events().iter().map(ApiWorldEvent::from).collect();
+    let kinds: Vec<&str> = events.iter().map(|e| e.kind.as_str()).collect();
+    assert_eq!(kinds, vec!["storylet_fired", "heat_band_changed"]);
+    assert_eq!(events[0].subject, "blowout");
+    assert_eq!(events[1].from.as_deref(), Some("Low"));
+    assert!(events[0].seq < events[1].seq);
+    assert!(engine.poll_events().is_empty());
+}
@@ -0,0 +1,48 @@
+//! Long time-skips: `tick_until` and the montage frames of `fast_forward`.
+
+use syn_api::{ApiMontageFrame, GameEngine};
+
+#[test]
+fn tick_until_stops_once_the_condition_holds() {
+    let mut engine = GameEngine::new(11);
+    let start = engine.current_tick();
+
+    let advanced = engine.tick_until(100, |e| e.current_tick() >= start + 30);
+    assert_eq!(advanced, 30);
+    assert_eq!(engine.current_tick(), start + 30);
+
+    assert_eq!(engine.tick_until(100, |e| e.current_tick() >= start + 30), 0);
+    assert_eq!(engine.tick_until(5, |_| false), 5);
+}
+
+#[test]
+fn fast_forward_yields_a_frame_per_day() {
+    let mut engine = GameEngine::new(11);
+    let mut frames: Vec<ApiMontageFrame> = Vec::new();
+
+    let advanced = engine.fast_forward(24 * 3, |_| false, |frame| {
+        frames.push(frame);
+        true
+    });
+
+    assert_eq!(advanced, 24 * 3);
+    let days: Vec<u64> = frames.iter().filter(|f| f.day_boundary).map(|f| f.day).collect();
+    assert_eq!(days, vec![1, 2, 3]);
+    assert!(frames.windows(2).all(|w| w[0].tick < w[1].tick));
+    assert!(frames.iter().all(|f| !f.stats.stats.is_empty()));
+}
+
+#[test]
+fn fast_forward_stops_when_the_montage_does() {
+    let mut engine = GameEngine::new(11);
+    let mut frames = 0;
+
+    let advanced = engine.fast_forward(24 * 10, |_| false, |_| {
+        frames += 1;
+        false
+    });
+
+    assert_eq!(frames, 1);
+    assert!(advanced <= 24);
+    assert_eq!(engine.current_tick(), u64::from(advanced));
+}
@@ -0,0 +1,29 @@
+use syn_api::{ApiForcedFire, GameEngine};
+
+#[test]
+fn force_fire_bypasses_scoring_and_is_logged() {
+    let mut engine = GameEngine::new(42);
+    engine.register_storylet("demo_beat".into(), "Demo Beat".into(), 0.0, 0.0);
+
+    let fired = engine
+        .force_fire_storylet("demo_beat", &[("friend".to_string(), 2)])
+        .expect("storylet is registered");
+    assert_eq!(fired.storylet_id, "demo_beat");
+    assert_eq!(fired.warnings, vec!["override for unknown role 'friend'"]);
+    assert_eq!(engine.forced_fires().len(), 1);
+
+    let dto = ApiForcedFire::from_result("demo_beat", engine.force_fire_storylet("demo_beat", &[]));
+    assert!(dto.fired);
+    assert!(dto.error.is_none());
+    assert_eq!(engine.forced_fires().len(), 2);
+}
+
+#[test]
+fn unknown_storylet_is_reported() {
+    let mut engine = GameEngine::new(42);
+    let dto = ApiForcedFire::from_result("missing", engine.force_fire_storylet("missing", &[]));
+    assert!(!dto.fired);
+    assert_eq!(dto.storylet_id, "missing");
+    assert_eq!(dto.error.as_deref(), Some("unknown storylet 'missing'"));
+    assert!(engine.forced_fires().is_empty());
+}
@@ -0,0 +1,19 @@
+use syn_api::{ApiHeatForecast, GameEngine};
+
+#[test]
+fn heat_forecast_is_a_capped_deterministic_series() {
+    let engine = GameEngine::new(42);
+    let forecast = ApiHeatForecast::from(&engine.heat_forecast(24));
+
+    assert_eq!(forecast.points.len(), 24);
+    assert_eq!(forecast.current_band, engine.narrative_heat_level());
+    assert!(forecast
+        .points
+        .windows(2)
+        .all(|pair| pair[1].tick == pair[0].tick + 1));
+    assert_eq!(
+        engine.heat_forecast(24),
+        GameEngine::new(42).heat_forecast(24)
+    );
+    assert_eq!(engine.heat_forecast(1_000).points.len(), 168);
+}
@@ -0,0 +1,64 @@
+use syn_api::{GameEngine, JOURNAL_EXPORT_SCHEMA_VERSION};
+
+fn engine_with_memories() -> GameEngine {
+    let mut engine = GameEngine::new(7);
+    engine.register_npc(2, 30, "clerk".into(), "Harbor".into());
+    engine.tick_many(24 * 2 + 13);
+    engine.record_memory(1, "argued_with_friend".into(), -0.6);
+    engine.tick_many(24);
+    engine.record_memory(1, "made_up_with_friend".into(), 0.4);
+    engine
+}
+
+#[test]
+fn export_is_versioned_with_readable_timestamps() {
+    let engine = engine_with_memories();
+    let export = engine.export_journal(1);
+
+    assert_eq!(export.schema_version, JOURNAL_EXPORT_SCHEMA_VERSION);
+    assert_eq!(export.npc_id, 1);
+    assert!(!export.npc_name.is_empty());
+
+    let events: Vec<&str> = export.entries.iter().map(|e| e.event_id.as_str()).collect();
+    let argued = events.iter().position(|e| *e == "argued_with_friend");
+    let made_up = events.iter().position(|e| *e == "made_up_with_friend");
+    assert!(made_up < argued, "entries newest first: {:?}", events);
+
+    let entry = &export.entries[argued.unwrap()];
+    assert_eq!(entry.timestamp, "Day 3, Evening");
+}
+
+#[test]
+fn import_round_trips_through_json_and_skips_duplicates() {
+    let export = engine_with_memories().export_journal(1);
+    let json = serde_json::to_string(&export).unwrap();
+
+    let mut fresh = GameEngine::new(7);
+    let before = fresh.export_journal(1).entries.len();
+    let parsed = serde_json::from_str(&json).unwrap();
+    let imported = fresh.import_journal(&parsed).unwrap();
+    assert_eq!(imported, export.entries.len() - before);
+
+    let round_tripped = fresh.export_journal(1);
+    for entry in &export.entries {
+        assert!(round_tripped.entries.iter().any(|e| e.id == entry.id
+            && e.event_id == entry.event_id
+            && (e.emotional_intensity - entry.emotional_intensity).abs() < f32::EPSILON));
+    }
+
+    assert_eq!(fresh.import_journal(&parsed).unwrap(), 0);
+}
+
+#[test]
+fn import_rejects_unknown_schema_versions() {
+    let mut engine = GameEngine::new(7);
+    let mut export = engine_with_memories().export_journal(1);
+    export.schema_version = JOURNAL_EXPORT_SCHEMA_VERSION + 1;
+
+    assert!(engine.import_journal(&export).is_err());
+    assert!(engine
+        .export_journal(1)
+        .entries
+        .iter()
+        .all(|e| e.event_id != "argued_with_friend"));
+}
@@ -0,0 +1,25 @@
+use syn_api::{life_goal_catalog, ApiLifeGoalOption, CharacterArchetype, GameEngine, GoalStatus};
+
+#[test]
+fn goals_are_chosen_from_the_catalog_and_tracked() {
+    let options: Vec<ApiLifeGoalOption> = life_goal_catalog()
+        .iter()
+        .map(ApiLifeGoalOption::from)
+        .collect();
+    assert_eq!(options.len(), 4);
+    assert!(options.iter().all(|o| !o.requirements.is_empty()));
+
+    let mut engine = GameEngine::new(42);
+    assert!(engine.choose_life_goal("make_your_fortune"));
+    assert!(!engine.choose_life_goal("make_your_fortune"));
+    assert!(!engine.choose_life_goal("not_a_goal"));
+    assert!(engine.adopt_archetype_goal(CharacterArchetype::Analyst));
+
+    engine.tick_many(24);
+    let goals = engine.life_goals();
+    assert_eq!(goals.len(), 2);
+    assert_eq!(goals[0].goal.id, "make_your_fortune");
+    assert!(goals
+        .iter()
+        .all(|g| g.status == GoalStatus::Active && (0.0..=1.0).contains(&g.progress)));
+}
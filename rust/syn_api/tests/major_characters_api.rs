@@ -0,0 +1,56 @@
+use syn_api::{GameEngine, MemoryQuery, StoryletOutcome};
+use syn_core::relationship_model::{RelationshipAxis, RelationshipDelta};
+
+fn shared_moment() -> StoryletOutcome {
+    StoryletOutcome {
+        relationship_deltas: vec![RelationshipDelta {
+            actor_id: 1,
+            target_id: 2,
+            axis: RelationshipAxis::Trust,
+            delta: 0.5,
+            source: None,
+        }],
+        memory_event_id: "stayed_up_talking".into(),
+        emotional_intensity: 0.8,
+        ..Default::default()
+    }
+}
+
+#[test]
+fn shared_storylets_promote_a_major_character_who_checks_in() {
+    let mut engine = GameEngine::new(42);
+    engine.register_npc(1, 25, "barista".into(), "Harbor".into());
+    engine.register_npc(2, 27, "clerk".into(), "Harbor".into());
+    engine.register_storylet("late_night_talk".into(), "Late Night Talk".into(), 1.0, 1.0);
+
+    let batch = (0..4)
+        .map(|_| ("late_night_talk".to_string(), shared_moment()))
+        .collect();
+    engine.apply_outcome_batch(batch).unwrap();
+    engine.tick_many(24);
+
+    let majors: Vec<_> = engine.major_characters().collect();
+    assert_eq!(majors.len(), 1);
+    assert_eq!(majors[0].npc_id.0, 2);
+    assert_eq!(majors[0].source.as_str(), "emergent");
+
+    engine.tick_many(24 * 14);
+    let check_in = engine.pending_check_ins()[0].clone();
+    assert_eq!(check_in.npc_id.0, 2);
+    assert_eq!(check_in.storylet_id(), format!("check_in:{}", check_in.id));
+
+    let resolution = engine.respond_to_check_in(check_in.id, true).unwrap();
+    assert!(resolution.caught_up);
+    assert!(engine.pending_check_ins().is_empty());
+    assert!(engine.respond_to_check_in(check_in.id, true).is_err());
+
+    let query = MemoryQuery {
+        tags: vec!["check_in".into()],
+        ..Default::default()
+    };
+    let page = engine.search_memories(&query);
+    assert!(page
+        .hits
+        .iter()
+        .any(|hit| hit.entry.event_id == "check_in:caught_up"));
+}
@@ -0,0 +1,32 @@
+use syn_api::{ApiMemorySearchQuery, ApiMemorySearchResults, GameEngine, MemoryQuery};
+
+#[test]
+fn search_pages_through_the_player_journal() {
+    let mut engine = GameEngine::new(42);
+    let player = 1; // GameEngine::new always plays as NPC 1
+    engine.record_memory(player, "romance.first_date".into(), 0.7);
+    engine.tick();
+    engine.record_memory(player, "career.promotion".into(), 0.3);
+    engine.tick();
+    engine.record_memory(player, "romance.breakup".into(), -0.9);
+
+    let query = MemoryQuery::from(ApiMemorySearchQuery {
+        text: Some("romance".into()),
+        page_size: 1,
+        ..Default::default()
+    });
+    let results = ApiMemorySearchResults::from_page(&engine.search_memories(&query), &query);
+    assert_eq!(results.total, 2);
+    assert_eq!(results.page_size, 1);
+    assert_eq!(results.hits[0].entry.event_id, "romance.breakup");
+
+    let query = MemoryQuery::from(ApiMemorySearchQuery {
+        text: Some("romance".into()),
+        page: 1,
+        page_size: 1,
+        ..Default::default()
+    });
+    let results = ApiMemorySearchResults::from_page(&engine.search_memories(&query), &query);
+    assert_eq!(results.page, 1);
+    assert_eq!(results.hits[0].entry.event_id, "romance.first_date");
+}
@@ -1,4 +1,5 @@
 //! Test that engine_new_game returns correct player_age
+#![cfg(feature = "ffi")]
 
 use syn_api::{engine_new_game, ApiPlayerConfig};
 
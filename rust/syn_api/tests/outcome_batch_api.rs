@@ -0,0 +1,38 @@
+use syn_api::{ApiOutcomeBatchResult, GameEngine, StoryletOutcome};
+
+fn karma(delta: f32) -> StoryletOutcome {
+    StoryletOutcome {
+        karma_delta: Some(delta),
+        ..Default::default()
+    }
+}
+
+#[test]
+fn scripted_batch_applies_through_the_engine() {
+    let mut engine = GameEngine::new(42);
+    engine.register_storylet("scripted_good_deed".into(), "Good Deed".into(), 1.0, 1.0);
+    let before = engine.player_karma();
+
+    let result = ApiOutcomeBatchResult::from(engine.apply_outcome_batch(vec![
+        ("scripted_good_deed".into(), karma(5.0)),
+        ("scripted_good_deed".into(), karma(5.0)),
+    ]));
+    assert!(result.accepted, "{:?}", result.error);
+    assert_eq!(result.applied, 2);
+    assert!(engine.player_karma() > before);
+}
+
+#[test]
+fn rejected_batch_leaves_the_engine_untouched() {
+    let mut engine = GameEngine::new(42);
+    engine.register_storylet("scripted_good_deed".into(), "Good Deed".into(), 1.0, 1.0);
+    let before = engine.player_karma();
+
+    let result = ApiOutcomeBatchResult::from(engine.apply_outcome_batch(vec![
+        ("scripted_good_deed".into(), karma(5.0)),
+        ("not_registered".into(), karma(5.0)),
+    ]));
+    assert!(!result.accepted);
+    assert!(result.error.unwrap().contains("not_registered"));
+    assert_eq!(engine.player_karma(), before);
+}
@@ -0,0 +1,90 @@
+use syn_api::{ApiProfile, GameEngine, ProfileError, ProfileManager};
+use tempfile::TempDir;
+
+#[test]
+fn profiles_keep_worlds_and_storage_apart() {
+    let root = TempDir::new().unwrap();
+    let mut profiles = ProfileManager::new(root.path());
+
+    let main = profiles.create("main", 1, GameEngine::new(1)).unwrap();
+    assert!(main.active);
+    assert!(main.storage_dir.is_dir());
+    let alt = profiles.create("alt-b", 2, GameEngine::new(2)).unwrap();
+    assert!(!alt.active);
+    assert_ne!(main.storage_dir, alt.storage_dir);
+
+    profiles.active_world_mut().unwrap().tick_many(10);
+    profiles.switch("alt-b").unwrap();
+    profiles.active_world_mut().unwrap().tick_many(3);
+    assert_eq!(profiles.world("main").unwrap().current_tick(), 10);
+    assert_eq!(profiles.world("alt-b").unwrap().current_tick(), 3);
+
+    let listed: Vec<ApiProfile> = profiles.list().iter().map(ApiProfile::from).collect();
+    assert_eq!(listed.len(), 2);
+    assert_eq!(listed[0].name, "alt-b");
+    assert!(listed[0].active);
+    assert!(!listed[1].active);
+}
+
+#[test]
+fn invalid_duplicate_and_active_profiles_are_refused() {
+    let root = TempDir::new().unwrap();
+    let mut profiles = ProfileManager::new(root.path());
+    profiles.create("main", 1, GameEngine::new(1)).unwrap();
+    let other = profiles.create("other", 2, GameEngine::new(2)).unwrap();
+
+    assert!(matches!(
+        profiles.create("../escape", 3, GameEngine::new(3)),
+        Err(ProfileError::InvalidName(_))
+    ));
+    assert!(matches!(
+        profiles.create("main", 3, GameEngine::new(3)),
+        Err(ProfileError::AlreadyExists(_))
+    ));
+    assert_eq!(
+        profiles.switch("missing"),
+        Err(ProfileError::NotFound("missing".to_string()))
+    );
+    assert_eq!(
+        profiles.delete("main"),
+        Err(ProfileError::Active("main".to_string()))
+    );
+
+    profiles.delete("other").unwrap();
+    assert!(!other.storage_dir.exists());
+    assert!(!profiles.contains("other"));
+    assert_eq!(profiles.list().len(), 1);
+}
+
+#[test]
+fn profile_engines_keep_storage_and_saves_in_their_own_directory() {
+    let root = TempDir::new().unwrap();
+    let mut profiles = ProfileManager::new(root.path());
+    let main = profiles
+        .create_with("main", 1, |dir| GameEngine::new_in(1, dir))
+        .unwrap();
+    let alt = profiles
+        .create_with("alt", 2, |dir| GameEngine::new_in(2, dir))
+        .unwrap();
+
+    profiles.world_mut("main").unwrap().tick_many(10);
+    profiles.world_mut("alt").unwrap().tick_many(3);
+    profiles.world_mut("main").unwrap().save_to("life.sav").unwrap();
+    profiles.world_mut("alt").unwrap().save_to("life.sav").unwrap();
+
+    for info in [&main, &alt] {
+        let hot_files = std::fs::read_dir(&info.storage_dir)
+            .unwrap()
+            .filter_map(Result::ok)
+            .filter(|e| e.file_name().to_string_lossy().ends_with(".redb"))
+            .count();
+        assert!(hot_files > 0, "no sim storage in {}", info.storage_dir.display());
+        assert!(info.storage_dir.join("life.sav").is_file());
+    }
+
+    let main_loaded = GameEngine::load_from_in("life.sav", &main.storage_dir).unwrap();
+    let alt_loaded = GameEngine::load_from_in("life.sav", &alt.storage_dir).unwrap();
+    assert_eq!((main_loaded.world_seed(), main_loaded.current_tick()), (1, 10));
+    assert_eq!((alt_loaded.world_seed(), alt_loaded.current_tick()), (2, 3));
+    assert_eq!(main_loaded.storage_dir(), Some(main.storage_dir.as_path()));
+}
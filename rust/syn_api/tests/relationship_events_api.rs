@@ -0,0 +1,39 @@
+//! Relationship band shifts and milestones surfaced for UI toasts.
+
+use syn_api::{GameEngine, StoryletOutcome};
+use syn_core::relationship_model::{RelationshipAxis, RelationshipDelta};
+
+fn trust(delta: f32) -> StoryletOutcome {
+    StoryletOutcome {
+        relationship_deltas: vec![RelationshipDelta {
+            actor_id: 1,
+            target_id: 2,
+            axis: RelationshipAxis::Trust,
+            delta,
+            source: None,
+        }],
+        ..Default::default()
+    }
+}
+
+#[test]
+fn band_shifts_are_surfaced_once() {
+    let mut engine = GameEngine::new(42);
+    engine.register_npc(2, 30, "Teacher".to_string(), "Downtown".to_string());
+    engine.register_storylet("heart_to_heart".into(), "Heart to Heart".into(), 1.0, 1.0);
+    assert!(engine.poll_relationship_events().pressure.is_empty());
+
+    engine
+        .apply_outcome_batch(vec![("heart_to_heart".into(), trust(5.0))])
+        .expect("batch applies");
+
+    let events = engine.poll_relationship_events();
+    assert_eq!(events.pressure.len(), 1);
+    let event = &events.pressure[0];
+    assert_eq!((event.actor_id, event.target_id), (1, 2));
+    assert_eq!(event.kind, "TrustBandChanged");
+    assert_eq!(event.new_band, "Trusted");
+    assert!(!event.target_name.is_empty());
+
+    assert!(engine.poll_relationship_events().pressure.is_empty());
+}
@@ -0,0 +1,33 @@
+use syn_api::{ApiRivalAction, GameEngine, MemoryQuery};
+
+#[test]
+fn rivals_act_against_the_player_and_leave_journal_hooks() {
+    let mut engine = GameEngine::new(42);
+    let player = 1; // GameEngine::new always plays as NPC 1
+    for rival in 2..=6 {
+        engine.register_npc(rival, 30, "clerk".into(), "Downtown".into());
+        engine.set_relationship(rival, player, -5.0, -5.0, 0.0, 3.0, 10.0);
+    }
+
+    engine.tick_many(24 * 20);
+
+    let actions: Vec<ApiRivalAction> = engine
+        .rival_actions()
+        .iter()
+        .map(ApiRivalAction::from)
+        .collect();
+    assert!(!actions.is_empty());
+    assert!(actions.iter().all(|a| (2..=6).contains(&a.npc_id)));
+
+    // Every action is in the journal (dreams may replay them as well).
+    let query = MemoryQuery {
+        tags: vec!["rival_action".into()],
+        limit: 100,
+        ..Default::default()
+    };
+    let page = engine.search_memories(&query);
+    for action in &actions {
+        let id = format!("rival_action:{}", action.id);
+        assert!(page.hits.iter().any(|hit| hit.entry.id == id), "{}", id);
+    }
+}
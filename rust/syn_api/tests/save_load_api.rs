@@ -0,0 +1,59 @@
+use syn_api::{GameEngine, NpcId, SynError};
+use syn_director::{Storylet, StoryletCooldown, StoryletRole, StoryletRoles};
+
+#[test]
+fn a_saved_engine_resumes_mid_life() {
+    let dir = tempfile::tempdir().expect("temp dir");
+    let path = dir.path().join("life.sav");
+
+    let mut engine = GameEngine::new(77);
+    // Cooldowns are kept per cast NPC, so the beat needs a role
+    engine.replace_storylets(vec![Storylet {
+        id: "demo_beat".into(),
+        name: "Demo Beat".into(),
+        roles: StoryletRoles::from(vec![StoryletRole {
+            name: "friend".into(),
+            npc_id: NpcId(2),
+        }]),
+        cooldown: StoryletCooldown { ticks: 100 },
+        ..Default::default()
+    }]);
+    engine
+        .force_fire_storylet("demo_beat", &[])
+        .expect("storylet is registered");
+    engine.record_memory(1, "career.promotion".into(), 0.4);
+    for _ in 0..3 {
+        engine.tick();
+    }
+    engine.save_to(&path).expect("engine saves");
+
+    let loaded = GameEngine::load_from(&path).expect("engine loads");
+    assert_eq!(loaded.world_seed(), 77);
+    assert_eq!(loaded.current_tick(), engine.current_tick());
+    assert_eq!(loaded.world_snapshot(), engine.world_snapshot());
+
+    let events = |e: &GameEngine| -> Vec<String> {
+        e.get_npc_memories(1).into_iter().map(|m| m.event_id).collect()
+    };
+    assert!(events(&loaded).contains(&"career.promotion".to_string()));
+    assert_eq!(events(&loaded), events(&engine));
+
+    assert!(!engine.storylet_cooldowns().is_empty());
+    assert_eq!(loaded.storylet_cooldowns(), engine.storylet_cooldowns());
+}
+
+#[test]
+fn saving_replaces_the_previous_save() {
+    let dir = tempfile::tempdir().expect("temp dir");
+    let path = dir.path().join("life.sav");
+
+    assert!(matches!(
+        GameEngine::load_from(&path),
+        Err(SynError::NotFound(_))
+    ));
+
+    GameEngine::new(1).save_to(&path).expect("first save");
+    GameEngine::new(2).save_to(&path).expect("second save");
+    let loaded = GameEngine::load_from(&path).expect("engine loads");
+    assert_eq!(loaded.world_seed(), 2);
+}
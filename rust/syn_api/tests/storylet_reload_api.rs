@@ -0,0 +1,76 @@
+use std::fs;
+use std::path::Path;
+
+use syn_api::GameEngine;
+
+fn write_storylet(dir: &Path, file: &str, id: &str) {
+    fs::write(
+        dir.join(file),
+        format!(
+            r#"{{
+                "id": "{id}",
+                "name": "Reloaded Beat",
+                "tags": [],
+                "prerequisites": {{
+                    "stat_conditions": {{}},
+                    "life_stages": [],
+                    "tags": [],
+                    "relationship_states": [],
+                    "memory_tags_required": [],
+                    "memory_tags_forbidden": []
+                }},
+                "heat": 5.0,
+                "weight": 1.0,
+                "cooldown_ticks": 10,
+                "roles": []
+            }}"#
+        ),
+    )
+    .expect("write storylet");
+}
+
+#[test]
+fn edited_storylets_are_swapped_in_without_restarting() {
+    let mut engine = GameEngine::new(42);
+    let pack = tempfile::tempdir().expect("temp dir");
+    write_storylet(pack.path(), "beat.json", "reloaded_beat");
+
+    let report = engine
+        .reload_storylets(Some(pack.path()))
+        .expect("storylets reload");
+    assert_eq!(report.added, vec!["reloaded_beat".to_string()]);
+    engine
+        .force_fire_storylet("reloaded_beat", &[])
+        .expect("reloaded storylet is registered");
+
+    assert!(engine
+        .reload_storylets(Some(&pack.path().join("missing")))
+        .is_err());
+    engine
+        .force_fire_storylet("reloaded_beat", &[])
+        .expect("failed reload keeps the storylets");
+}
+
+#[test]
+fn a_bad_pack_file_fails_the_reload_and_keeps_the_old_storylets() {
+    let mut engine = GameEngine::new(42);
+    let pack = tempfile::tempdir().expect("temp dir");
+    write_storylet(pack.path(), "a_beat.json", "reloaded_beat");
+    engine
+        .reload_storylets(Some(pack.path()))
+        .expect("storylets reload");
+
+    write_storylet(pack.path(), "b_other.json", "reloaded_other_beat");
+    fs::write(pack.path().join("c_typo.json"), "{ \"id\": ").expect("write storylet");
+    let error = engine
+        .reload_storylets(Some(pack.path()))
+        .expect_err("typo fails the reload");
+    assert!(error.to_string().contains("c_typo.json"));
+
+    engine
+        .force_fire_storylet("reloaded_beat", &[])
+        .expect("old storylets stay registered");
+    assert!(engine
+        .force_fire_storylet("reloaded_other_beat", &[])
+        .is_err());
+}
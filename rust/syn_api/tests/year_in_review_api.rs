@@ -0,0 +1,35 @@
+use syn_api::GameEngine;
+
+#[test]
+fn year_in_review_is_stored_when_the_calendar_wraps() {
+    let mut engine = GameEngine::new(42);
+    engine.register_npc(2, 27, "clerk".into(), "Harbor".into());
+    assert!(engine.year_in_review(1).is_none());
+
+    let mut days = 0;
+    while engine.year_in_review(1).is_none() {
+        engine.tick_many(24);
+        days += 1;
+        assert!(days <= 366, "year 1 never closed");
+    }
+
+    let review = engine.year_in_review(1).unwrap();
+    assert_eq!(review.year, 1);
+    // January 1 itself is the first day of year 2.
+    assert_eq!(review.days, days - 1);
+    assert!(review.dominant_mood.is_some());
+    assert!(engine.year_in_review(2).is_none());
+}
+
+#[test]
+fn year_in_review_is_deterministic() {
+    let run = || {
+        let mut engine = GameEngine::new(7);
+        engine.register_npc(2, 27, "clerk".into(), "Harbor".into());
+        engine.tick_many(24 * 366);
+        engine.years_in_review().to_vec()
+    };
+    let first = run();
+    assert_eq!(first.len(), 1);
+    assert_eq!(first, run());
+}
@@ -1,10 +1,16 @@
 use anyhow::Result;
 use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
 use std::path::{Path, PathBuf};
 use syn_core::{Persistence, StoryletRecord};
 
+pub mod npc_prototypes;
 pub mod schemas;
 pub mod storylet;
+pub use npc_prototypes::{
+    import_npc_prototypes_from_dir, load_npc_prototypes_from_db, load_npc_prototypes_from_dir,
+    register_npc_prototypes, NpcPrototypeDef,
+};
 pub use schemas::*;
 
 /// Load all storylets stored inside the SQLite database at `db_path`.
@@ -61,6 +67,117 @@ pub fn summarize_storylets(directory: &Path) -> Result<Vec<StoryletSummary>> {
     Ok(summaries)
 }
 
+/// Key in a storylet file that allows it to replace an already-loaded storylet with the same ID.
+pub const OVERRIDE_KEY: &str = "override";
+
+/// A storylet file that was skipped while loading a content directory.
+#[derive(Debug, Clone, PartialEq)]
+pub struct StoryletFileError {
+    pub path: PathBuf,
+    pub message: String,
+}
+
+/// Result of layering a directory of storylet files over a base set.
+#[derive(Debug, Default)]
+pub struct StoryletPackLoad {
+    /// Merged storylets: base order first, new IDs appended in file order.
+    pub storylets: Vec<Storylet>,
+    /// Files that contributed a new or overriding storylet.
+    pub loaded_files: usize,
+    /// Files (or the directory itself) that were skipped; the rest still loads.
+    pub errors: Vec<StoryletFileError>,
+}
+
+/// Load every JSON storylet inside `directory` (recursively, sorted by path).
+///
+/// See [`load_storylet_packs`] for the override and error semantics.
+pub fn load_storylets_from_dir(directory: &Path) -> StoryletPackLoad {
+    load_storylet_packs(Vec::new(), directory)
+}
+
+/// Layer the JSON storylets inside `directory` over `base`.
+///
+/// Files are applied in path order so the result doesn't depend on the
+/// filesystem. A file whose storylet ID is already present (from `base` or an
+/// earlier file) is rejected unless it sets `"override": true`, in which case
+/// it replaces the earlier storylet in place. Unreadable or malformed files are
+/// reported in [`StoryletPackLoad::errors`] without aborting the load; if the
+/// directory itself can't be listed, `base` is returned unchanged with that
+/// error recorded.
+pub fn load_storylet_packs(base: Vec<Storylet>, directory: &Path) -> StoryletPackLoad {
+    let mut load = StoryletPackLoad {
+        storylets: base,
+        ..Default::default()
+    };
+    let mut files = match iter_json_files(directory) {
+        Ok(files) => files,
+        Err(err) => {
+            load.errors.push(StoryletFileError {
+                path: directory.to_path_buf(),
+                message: err.to_string(),
+            });
+            return load;
+        }
+    };
+    files.sort();
+
+    let mut index: HashMap<String, usize> = load
+        .storylets
+        .iter()
+        .enumerate()
+        .map(|(i, storylet)| (storylet.id.clone(), i))
+        .collect();
+
+    for path in files {
+        let (storylet, is_override) = match read_storylet_file(&path) {
+            Ok(parsed) => parsed,
+            Err(err) => {
+                load.errors.push(StoryletFileError {
+                    path,
+                    message: err.to_string(),
+                });
+                continue;
+            }
+        };
+
+        match index.get(&storylet.id) {
+            Some(&existing) if is_override => load.storylets[existing] = storylet,
+            Some(_) => {
+                load.errors.push(StoryletFileError {
+                    path,
+                    message: format!(
+                        "duplicate storylet id '{}' (set \"{}\": true to replace it)",
+                        storylet.id, OVERRIDE_KEY
+                    ),
+                });
+                continue;
+            }
+            None => {
+                index.insert(storylet.id.clone(), load.storylets.len());
+                load.storylets.push(storylet);
+            }
+        }
+        load.loaded_files += 1;
+    }
+
+    load
+}
+
+fn read_storylet_file(path: &Path) -> Result<(Storylet, bool)> {
+    let data = std::fs::read_to_string(path)?;
+    let mut value: serde_json::Value = serde_json::from_str(&data)?;
+    let is_override = match value
+        .as_object_mut()
+        .and_then(|obj| obj.remove(OVERRIDE_KEY))
+    {
+        None => false,
+        Some(serde_json::Value::Bool(flag)) => flag,
+        Some(other) => anyhow::bail!("\"{}\" must be a boolean, found {}", OVERRIDE_KEY, other),
+    };
+    let storylet: Storylet = serde_json::from_value(value)?;
+    Ok((storylet, is_override))
+}
+
 fn iter_json_files(directory: &Path) -> Result<Vec<PathBuf>> {
     fn recurse(dir: &Path, files: &mut Vec<PathBuf>) -> std::io::Result<()> {
         for entry in std::fs::read_dir(dir)? {
@@ -105,6 +222,8 @@ mod tests {
                 relationship_prereqs: vec![],
                 allowed_life_stages: vec![],
                 digital_legacy_prereq: None,
+                depends_on: vec![],
+                prefers_after: vec![],
             },
             heat: 40.0,
             weight: 0.5,
@@ -143,4 +262,67 @@ mod tests {
 
         let _ = fs::remove_dir_all(temp_base);
     }
+
+    fn write_pack_file(dir: &Path, name: &str, storylet: &Storylet, extra: &str) {
+        let mut value = serde_json::to_value(storylet).unwrap();
+        if !extra.is_empty() {
+            let extra: serde_json::Value = serde_json::from_str(extra).unwrap();
+            for (key, field) in extra.as_object().unwrap() {
+                value[key] = field.clone();
+            }
+        }
+        fs::write(
+            dir.join(name),
+            serde_json::to_string_pretty(&value).unwrap(),
+        )
+        .unwrap();
+    }
+
+    #[test]
+    fn test_directory_packs_override_and_isolate_errors() {
+        let unique = SystemTime::now()
+            .duration_since(std::time::UNIX_EPOCH)
+            .unwrap()
+            .as_nanos();
+        let dir = std::env::temp_dir().join(format!("syn_storylet_packs_{}", unique));
+        fs::create_dir_all(dir.join("b_patch")).unwrap();
+
+        let base = sample_storylet();
+        let mut other = sample_storylet();
+        other.id = "other".to_string();
+        write_pack_file(&dir, "a_base.json", &base, "");
+        write_pack_file(&dir, "c_other.json", &other, "");
+
+        // Same ID without the flag is rejected...
+        let mut clash = sample_storylet();
+        clash.name = "Clash".to_string();
+        write_pack_file(&dir.join("b_patch"), "1_clash.json", &clash, "");
+        // ...with it, the later file wins in place.
+        let mut tweak = sample_storylet();
+        tweak.weight = 2.0;
+        write_pack_file(
+            &dir.join("b_patch"),
+            "2_tweak.json",
+            &tweak,
+            r#"{"override": true}"#,
+        );
+        fs::write(dir.join("d_broken.json"), "{ not json").unwrap();
+        fs::write(dir.join("notes.txt"), "ignored").unwrap();
+
+        let load = load_storylets_from_dir(&dir);
+        let ids: Vec<&str> = load.storylets.iter().map(|s| s.id.as_str()).collect();
+        assert_eq!(ids, vec!["test_storylet", "other"]);
+        assert_eq!(load.storylets[0].weight, 2.0);
+        assert_eq!(load.storylets[0].name, "Test");
+        assert_eq!(load.loaded_files, 3);
+
+        let failed: Vec<&str> = load
+            .errors
+            .iter()
+            .map(|e| e.path.file_name().unwrap().to_str().unwrap())
+            .collect();
+        assert_eq!(failed, vec!["1_clash.json", "d_broken.json"]);
+
+        let _ = fs::remove_dir_all(dir);
+    }
 }
@@ -0,0 +1,360 @@
+//! Authoring format and loader for named NPC prototypes.
+//!
+//! Content authors ship recurring characters as JSON files (or import them into
+//! the content SQLite database). Each definition is validated against the
+//! world's district registry and the `NpcRoleTag` registry before being
+//! registered into `WorldState`, where it joins the casting pool used by the
+//! director's role assignment.
+
+use anyhow::{bail, Result};
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
+use std::path::Path;
+use syn_core::npc::{
+    NpcActivityKind, NpcPrototype, NpcRoleTag, NpcSchedule, NpcScheduleSlot, PersonalityVector,
+};
+use syn_core::time::DayPhase;
+use syn_core::{
+    AbstractNpc, AttachmentStyle, LifeStage, NpcId, NpcPrototypeRecord, Persistence, StatKind,
+    Stats, Traits, WorldState,
+};
+
+use crate::iter_json_files;
+
+/// Schedule in authoring form: a named preset or explicit day-phase slots.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+#[serde(untagged)]
+pub enum NpcScheduleDef {
+    /// One of "work", "school", "nightlife" or "home".
+    Preset(String),
+    /// Explicit slots per day phase.
+    Slots(Vec<NpcScheduleSlot>),
+}
+
+/// JSON authoring format for a named NPC prototype.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct NpcPrototypeDef {
+    pub id: u64,
+    pub display_name: String,
+    #[serde(default)]
+    pub role_label: Option<String>,
+    /// Role tag names, validated against `NpcRoleTag`.
+    #[serde(default)]
+    pub role_tags: Vec<String>,
+    /// Stat overrides on top of `Stats::default()`, keyed by stat name.
+    #[serde(default)]
+    pub base_stats: BTreeMap<String, f32>,
+    pub personality: PersonalityVector,
+    #[serde(default)]
+    pub active_stages: Vec<LifeStage>,
+    #[serde(default)]
+    pub schedule: Option<NpcScheduleDef>,
+    /// District name, validated against the world's district registry.
+    #[serde(default)]
+    pub home_district: Option<String>,
+    /// Starting age; derived from the first active stage when absent.
+    #[serde(default)]
+    pub age: Option<u32>,
+    #[serde(default)]
+    pub job: Option<String>,
+    /// Content tag weights in -1..1 used by casting; derived from personality when absent.
+    #[serde(default)]
+    pub tag_affinities: BTreeMap<String, f32>,
+    /// Marks a major character the story keeps returning to.
+    #[serde(default)]
+    pub major: bool,
+}
+
+impl NpcPrototypeDef {
+    /// Validate the definition against the world's districts and the role tag registry.
+    ///
+    /// Returns one message per problem, prefixed with the prototype id and field.
+    pub fn validate(&self, world: &WorldState) -> Vec<String> {
+        let mut issues = Vec::new();
+        let at = |field: &str| format!("npc_prototype {} ({}).{}", self.id, self.display_name, field);
+
+        if self.id == world.player_id.0 {
+            issues.push(format!("{}: id collides with the player id", at("id")));
+        }
+        if self.display_name.trim().is_empty() {
+            issues.push(format!("{}: must not be empty", at("display_name")));
+        }
+        for tag in &self.role_tags {
+            if NpcRoleTag::from_name(tag).is_none() {
+                issues.push(format!("{}: unknown role tag '{}'", at("role_tags"), tag));
+            }
+        }
+        for name in self.base_stats.keys() {
+            if StatKind::from_name(name).is_none() {
+                issues.push(format!("{}: unknown stat '{}'", at("base_stats"), name));
+            }
+        }
+        if let Some(district) = &self.home_district {
+            if world.districts.get_by_name(district).is_none() {
+                issues.push(format!("{}: unknown district '{}'", at("home_district"), district));
+            }
+        }
+        for (tag, weight) in &self.tag_affinities {
+            if tag.trim().is_empty() {
+                issues.push(format!("{}: empty tag", at("tag_affinities")));
+            }
+            if !(-1.0..=1.0).contains(weight) {
+                issues.push(format!(
+                    "{}: weight {} for '{}' is outside -1..1",
+                    at("tag_affinities"),
+                    weight,
+                    tag
+                ));
+            }
+        }
+        if let Some(NpcScheduleDef::Preset(name)) = &self.schedule {
+            if schedule_preset(name).is_none() {
+                issues.push(format!("{}: unknown preset '{}'", at("schedule"), name));
+            }
+        }
+
+        issues
+    }
+
+    /// Build the runtime prototype. Assumes `validate` passed; unknown names are skipped.
+    pub fn to_prototype(&self) -> NpcPrototype {
+        let mut base_stats = Stats::default();
+        for (name, value) in &self.base_stats {
+            if let Some(kind) = StatKind::from_name(name) {
+                base_stats.set(kind, *value);
+            }
+        }
+
+        let mut personality = self.personality;
+        personality.clamp();
+
+        let schedule = match &self.schedule {
+            Some(NpcScheduleDef::Preset(name)) => schedule_preset(name).unwrap_or_default(),
+            Some(NpcScheduleDef::Slots(slots)) => NpcSchedule {
+                daily_slots: slots.clone(),
+            },
+            None => NpcSchedule::default(),
+        };
+
+        NpcPrototype {
+            id: NpcId(self.id),
+            display_name: self.display_name.clone(),
+            role_label: self.role_label.clone(),
+            role_tags: self
+                .role_tags
+                .iter()
+                .filter_map(|t| NpcRoleTag::from_name(t))
+                .collect(),
+            personality,
+            base_stats,
+            active_stages: self.active_stages.clone(),
+            schedule,
+            home_district: self.home_district.clone(),
+            tag_affinities: self
+                .tag_affinities
+                .iter()
+                .map(|(tag, weight)| (tag.trim().to_lowercase(), weight.clamp(-1.0, 1.0)))
+                .collect(),
+            major: self.major,
+        }
+    }
+
+    fn starting_age(&self) -> u32 {
+        self.age.unwrap_or_else(|| match self.active_stages.first() {
+            Some(LifeStage::PreSim) => 4,
+            Some(LifeStage::Child) => 9,
+            Some(LifeStage::Teen) => 16,
+            Some(LifeStage::YoungAdult) => 24,
+            Some(LifeStage::Adult) | None => 35,
+            Some(LifeStage::Elder) => 70,
+            Some(LifeStage::Digital) => 90,
+        })
+    }
+}
+
+/// Resolve a named schedule preset into day-phase slots
+/// (morning, afternoon, evening, night).
+fn schedule_preset(name: &str) -> Option<NpcSchedule> {
+    use NpcActivityKind::*;
+    let activities = match name.to_ascii_lowercase().as_str() {
+        "work" => [Work, Work, Home, Home],
+        "school" => [School, School, Home, Home],
+        "nightlife" => [Home, Errands, Nightlife, Nightlife],
+        "home" => [Home, Home, Home, Home],
+        _ => return None,
+    };
+    Some(NpcSchedule {
+        daily_slots: DayPhase::all()
+            .into_iter()
+            .zip(activities)
+            .map(|(phase, activity)| NpcScheduleSlot { phase, activity })
+            .collect(),
+    })
+}
+
+/// Parse every prototype JSON file in `directory` (recursively), sorted by id.
+///
+/// A file may hold a single definition or an array of definitions.
+pub fn load_npc_prototypes_from_dir(directory: &Path) -> Result<Vec<NpcPrototypeDef>> {
+    let mut defs = Vec::new();
+    for path in iter_json_files(directory)? {
+        let data = std::fs::read_to_string(&path)?;
+        let value: serde_json::Value = serde_json::from_str(&data)?;
+        if value.is_array() {
+            defs.extend(serde_json::from_value::<Vec<NpcPrototypeDef>>(value)?);
+        } else {
+            defs.push(serde_json::from_value::<NpcPrototypeDef>(value)?);
+        }
+    }
+    defs.sort_by_key(|d| d.id);
+    Ok(defs)
+}
+
+/// Import every prototype JSON file in `directory` into the SQLite database.
+pub fn import_npc_prototypes_from_dir(db_path: &str, directory: &Path) -> Result<usize> {
+    let mut persistence = Persistence::new(db_path)?;
+    let defs = load_npc_prototypes_from_dir(directory)?;
+    for def in &defs {
+        persistence.upsert_npc_prototype_record(&NpcPrototypeRecord {
+            id: def.id,
+            display_name: def.display_name.clone(),
+            json_data: serde_json::to_string_pretty(def)?,
+        })?;
+    }
+    Ok(defs.len())
+}
+
+/// Load all prototype definitions stored in the SQLite database at `db_path`.
+pub fn load_npc_prototypes_from_db(db_path: &str) -> Result<Vec<NpcPrototypeDef>> {
+    let mut persistence = Persistence::new(db_path)?;
+    let mut defs = Vec::new();
+    for record in persistence.load_npc_prototype_records()? {
+        defs.push(serde_json::from_str(&record.json_data)?);
+    }
+    Ok(defs)
+}
+
+/// Validate and register prototypes into the world.
+///
+/// Each prototype is stored in `npc_prototypes`, gets an `AbstractNpc` instance
+/// (if one doesn't already exist) and is marked known so role assignment can
+/// cast it. Nothing is registered if any definition fails validation.
+pub fn register_npc_prototypes(world: &mut WorldState, defs: &[NpcPrototypeDef]) -> Result<usize> {
+    let mut issues: Vec<String> = defs.iter().flat_map(|d| d.validate(world)).collect();
+    let mut seen = std::collections::BTreeSet::new();
+    for def in defs {
+        if !seen.insert(def.id) {
+            issues.push(format!("npc_prototype {}: duplicate id", def.id));
+        }
+    }
+    if !issues.is_empty() {
+        bail!("invalid NPC prototypes:\n  {}", issues.join("\n  "));
+    }
+
+    for def in defs {
+        let proto = def.to_prototype();
+        let id = proto.id;
+        world.npcs.entry(id).or_insert_with(|| AbstractNpc {
+            id,
+            age: def.starting_age(),
+            job: def.job.clone().unwrap_or_default(),
+            district: def.home_district.clone().unwrap_or_default(),
+            household_id: 0,
+            traits: Traits::default(),
+            seed: id.0,
+            attachment_style: AttachmentStyle::Secure,
+        });
+        world.npc_prototypes.insert(id, proto);
+        world.ensure_npc_known(id);
+    }
+    Ok(defs.len())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use syn_core::WorldSeed;
+
+    fn sample_json() -> &'static str {
+        r#"{
+            "id": 42,
+            "display_name": "Mara Quinn",
+            "role_label": "Your Childhood Friend",
+            "role_tags": ["peer", "romantic_interest"],
+            "base_stats": { "charisma": 72.0, "mood": 3.0 },
+            "personality": {
+                "warmth": 0.8, "dominance": -0.2, "volatility": 0.1,
+                "conscientiousness": 0.6, "openness": 1.4
+            },
+            "active_stages": ["Child", "Teen"],
+            "schedule": "school",
+            "home_district": "Downtown",
+            "tag_affinities": { "Trouble": 0.9, "study": -0.4 }
+        }"#
+    }
+
+    #[test]
+    fn test_register_valid_prototype_joins_casting_pool() {
+        let mut world = WorldState::new(WorldSeed(7), NpcId(1));
+        let def: NpcPrototypeDef = serde_json::from_str(sample_json()).unwrap();
+        assert!(def.validate(&world).is_empty());
+
+        let count = register_npc_prototypes(&mut world, &[def]).unwrap();
+        assert_eq!(count, 1);
+
+        let proto = world.npc_prototype(NpcId(42)).unwrap();
+        assert_eq!(proto.role_tags, vec![NpcRoleTag::Peer, NpcRoleTag::RomanticInterest]);
+        assert_eq!(proto.base_stats.get(StatKind::Charisma), 72.0);
+        assert_eq!(proto.personality.openness, 1.0);
+        assert_eq!(proto.home_district.as_deref(), Some("Downtown"));
+        assert_eq!(
+            proto.schedule.activity_for_phase(DayPhase::Morning),
+            NpcActivityKind::School
+        );
+        assert_eq!(world.npcs.get(&NpcId(42)).map(|n| n.age), Some(9));
+        assert!(world.known_npcs.contains(&NpcId(42)));
+        assert_eq!(proto.tag_affinities.get("trouble"), Some(&0.9));
+        assert!((proto.tag_affinity(|t| t == "trouble" || t == "study") - 0.5).abs() < 1e-6);
+    }
+
+    #[test]
+    fn test_validation_reports_every_problem() {
+        let mut world = WorldState::new(WorldSeed(7), NpcId(1));
+        let mut def: NpcPrototypeDef = serde_json::from_str(sample_json()).unwrap();
+        def.role_tags.push("sidekick".into());
+        def.base_stats.insert("luck".into(), 10.0);
+        def.home_district = Some("Atlantis".into());
+        def.schedule = Some(NpcScheduleDef::Preset("vampire".into()));
+        def.tag_affinities.insert("chaos".into(), 3.0);
+
+        let issues = def.validate(&world);
+        assert_eq!(issues.len(), 5);
+        assert!(issues.iter().any(|i| i.contains("tag_affinities") && i.contains("chaos")));
+        assert!(issues.iter().any(|i| i.contains("role_tags") && i.contains("sidekick")));
+        assert!(issues.iter().any(|i| i.contains("home_district") && i.contains("Atlantis")));
+
+        assert!(register_npc_prototypes(&mut world, &[def]).is_err());
+        assert!(world.npc_prototypes.is_empty());
+    }
+
+    #[test]
+    fn test_import_and_load_prototypes_from_db() {
+        let unique = std::time::SystemTime::now()
+            .duration_since(std::time::UNIX_EPOCH)
+            .unwrap()
+            .as_nanos();
+        let temp_base = std::env::temp_dir().join(format!("syn_npc_proto_test_{}", unique));
+        let json_dir = temp_base.join("npcs");
+        std::fs::create_dir_all(&json_dir).unwrap();
+        std::fs::write(json_dir.join("mara.json"), sample_json()).unwrap();
+        let db_path = temp_base.join("content.sqlite");
+        let db_path = db_path.to_str().unwrap();
+
+        assert_eq!(import_npc_prototypes_from_dir(db_path, &json_dir).unwrap(), 1);
+        let loaded = load_npc_prototypes_from_db(db_path).unwrap();
+        assert_eq!(loaded.len(), 1);
+        assert_eq!(loaded[0].display_name, "Mara Quinn");
+
+        let _ = std::fs::remove_dir_all(temp_base);
+    }
+}
@@ -102,6 +102,12 @@ pub struct StoryletPrerequisites {
     /// Optional digital legacy prerequisite for PostLife storylets.
     #[serde(default)]
     pub digital_legacy_prereq: Option<DigitalLegacyPrereq>,
+    /// Storylet ids that must all have fired before this one is eligible.
+    #[serde(default)]
+    pub depends_on: Vec<String>,
+    /// Storylet ids that boost this one's score once any has fired.
+    #[serde(default)]
+    pub prefers_after: Vec<String>,
 }
 
 /// A role in a storylet (e.g., "target", "rival", "manager").
@@ -127,6 +133,9 @@ pub struct StoryletOutcome {
     pub relationship_deltas: Vec<RelationshipDelta>,
     #[serde(default)]
     pub karma_delta: Option<f32>,
+    /// Authored reputation changes scoped to a district or social cluster.
+    #[serde(default)]
+    pub reputation_deltas: Vec<syn_core::ReputationDelta>,
     #[serde(default)]
     pub memory_event_id: String,
     #[serde(default)]
@@ -145,6 +154,7 @@ impl Default for StoryletOutcome {
             stat_deltas: Vec::new(),
             relationship_deltas: Vec::new(),
             karma_delta: None,
+            reputation_deltas: Vec::new(),
             memory_event_id: "unknown".to_string(),
             emotional_intensity: 0.0,
             memory_tags: Vec::new(),
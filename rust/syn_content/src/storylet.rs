@@ -1,4 +1,5 @@
 pub use syn_director::{
-    InteractionTone, RelationshipPrereq, StoryActorRef, Storylet, StoryletActors, StoryletChoice,
-    StoryletLibrary, StoryletOutcome, StoryletPrerequisites, TimeAndLocationPrereqs,
+    DistrictAffinity, InteractionTone, RelationshipPrereq, RomanceReciprocity, StoryActorRef,
+    Storylet, StoryletActors, StoryletChoice, StoryletLibrary, StoryletOutcome,
+    StoryletPrerequisites, TimeAndLocationPrereqs,
 };
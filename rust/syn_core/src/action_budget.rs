@@ -0,0 +1,148 @@
+//! Daily action/energy budget for the player.
+//!
+//! Every storylet the player takes part in and every deliberate player action
+//! (practicing a skill, choosing to go out) spends action points. Points come
+//! back while resting during the night phase, so a day can only hold so many
+//! events. Storylets triggered by the player (`player_action`) are gated on the
+//! budget, and the director leans toward quieter content as the player tires.
+
+use crate::time::DayPhase;
+use serde::{Deserialize, Serialize};
+
+/// Storylet trigger kind for events the player actively chooses to do.
+pub const PLAYER_ACTION_TRIGGER: &str = "player_action";
+
+/// Default daily action points.
+pub const DEFAULT_MAX_ACTION_POINTS: f32 = 10.0;
+
+/// Cost of taking part in a storylet.
+pub const STORYLET_ACTION_COST: f32 = 2.0;
+
+/// Cost of a standalone player action (e.g. practicing a skill).
+pub const PLAYER_ACTION_COST: f32 = 1.0;
+
+/// Ticks in the rest phase (night, 6 of 24 hours).
+const REST_TICKS_PER_DAY: f32 = 6.0;
+
+/// The player's remaining energy for the day.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct ActionBudget {
+    /// Remaining action points.
+    pub points: f32,
+    /// Points available after a full night's rest.
+    pub max_points: f32,
+    /// Points regained per tick spent in a rest phase.
+    pub rest_regen_per_tick: f32,
+    /// Points spent since the start of `day`.
+    #[serde(default)]
+    pub spent_today: f32,
+    /// Day index `spent_today` refers to.
+    #[serde(default)]
+    pub day: u64,
+}
+
+impl Default for ActionBudget {
+    fn default() -> Self {
+        Self::new(DEFAULT_MAX_ACTION_POINTS)
+    }
+}
+
+impl ActionBudget {
+    /// A full budget that recovers completely over one night.
+    pub fn new(max_points: f32) -> Self {
+        let max_points = max_points.max(0.0);
+        ActionBudget {
+            points: max_points,
+            max_points,
+            rest_regen_per_tick: max_points / REST_TICKS_PER_DAY,
+            spent_today: 0.0,
+            day: 0,
+        }
+    }
+
+    /// True if `phase` lets the player recover.
+    pub fn is_rest_phase(phase: DayPhase) -> bool {
+        matches!(phase, DayPhase::Night)
+    }
+
+    /// True if at least `cost` points remain.
+    pub fn can_afford(&self, cost: f32) -> bool {
+        self.points >= cost
+    }
+
+    /// Spend `cost` points if affordable. Returns false (spending nothing) otherwise.
+    pub fn try_spend(&mut self, cost: f32) -> bool {
+        if !self.can_afford(cost) {
+            return false;
+        }
+        self.spend(cost);
+        true
+    }
+
+    /// Spend up to `cost` points; never goes below zero.
+    ///
+    /// Used for events that happen to the player regardless of energy.
+    pub fn spend(&mut self, cost: f32) {
+        let cost = cost.max(0.0).min(self.points);
+        self.points -= cost;
+        self.spent_today += cost;
+    }
+
+    /// Advance one tick: roll the daily counter and recover while resting.
+    pub fn on_tick(&mut self, phase: DayPhase, day: u64) {
+        if day != self.day {
+            self.day = day;
+            self.spent_today = 0.0;
+        }
+        if Self::is_rest_phase(phase) {
+            self.points = (self.points + self.rest_regen_per_tick).min(self.max_points);
+        }
+    }
+
+    /// Share of the budget used up, in 0.0..=1.0 (0 = fully rested).
+    pub fn fatigue(&self) -> f32 {
+        if self.max_points <= 0.0 {
+            return 1.0;
+        }
+        (1.0 - self.points / self.max_points).clamp(0.0, 1.0)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_spending_and_gating() {
+        let mut budget = ActionBudget::new(4.0);
+        assert!(budget.try_spend(STORYLET_ACTION_COST));
+        assert!(budget.try_spend(STORYLET_ACTION_COST));
+        assert!(!budget.try_spend(PLAYER_ACTION_COST));
+        assert_eq!(budget.points, 0.0);
+        assert_eq!(budget.spent_today, 4.0);
+        assert_eq!(budget.fatigue(), 1.0);
+
+        // Involuntary events never drive the budget negative.
+        budget.spend(STORYLET_ACTION_COST);
+        assert_eq!(budget.points, 0.0);
+    }
+
+    #[test]
+    fn test_rest_phase_recovers_over_one_night() {
+        let mut budget = ActionBudget::default();
+        budget.spend(budget.max_points);
+
+        budget.on_tick(DayPhase::Evening, 0);
+        assert_eq!(budget.points, 0.0, "no recovery outside the rest phase");
+
+        for _ in 0..6 {
+            budget.on_tick(DayPhase::Night, 0);
+        }
+        assert!((budget.points - budget.max_points).abs() < 1e-4);
+        assert_eq!(budget.spent_today, budget.max_points);
+
+        budget.on_tick(DayPhase::Morning, 1);
+        assert_eq!(budget.spent_today, 0.0);
+        assert_eq!(budget.day, 1);
+    }
+}
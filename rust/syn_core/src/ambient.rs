@@ -0,0 +1,728 @@
+//! Ambient micro-events: tiny flavor beats between storylets.
+//!
+//! When the director has been quiet for a while, the world occasionally
+//! produces a one-line beat ("Sam texts you a meme", "You see Riley across the
+//! street") from an [`AmbientTemplate`]. Templates are keyed on a relationship
+//! role, a tag from one of the player's recent memories, or the player's home
+//! district. Beats carry no choices: they are published to the news feed and
+//! recorded as low-intensity (hence low-salience) player memories. A beat
+//! about an NPC carries that NPC's [`ToneContext`], so the UI can tint it
+//! by how they feel about the player.
+//!
+//! Generation is deterministic: candidates are enumerated in template and NPC
+//! id order and drawn with a tick-seeded RNG.
+//!
+//! Small-talk templates (text with `{topic}`) also pick a [`ConversationTopic`]:
+//! shared history with the NPC, the player's recent memories, their skills, or
+//! recent news from their district and the city. Each player–NPC pair keeps a
+//! short ring buffer of topics already used, and a topic used within
+//! [`AmbientConfig::topic_repeat_window_ticks`] is not picked again for that
+//! pair; an NPC with nothing fresh to talk about gets no small-talk beat.
+
+use std::collections::{BTreeMap, VecDeque};
+
+use serde::{Deserialize, Serialize};
+
+use crate::dialogue_tone::{tone_context, ToneContext};
+use crate::relationship_model::{RelationshipRole, RelationshipVector};
+use crate::rng::DeterministicRng;
+use crate::types::{MemoryEntryRecord, NpcId, SimTick, WorldState};
+
+/// Tag added to every ambient beat and its memory.
+pub const AMBIENT_TAG: &str = "ambient";
+
+/// Largest emotional intensity an ambient memory may carry.
+pub const MAX_AMBIENT_INTENSITY: f32 = 0.15;
+
+/// Number of recent beats kept on [`AmbientState`].
+const RECENT_CAPACITY: usize = 16;
+
+/// Topics remembered per player–NPC pair.
+const TOPIC_HISTORY_CAPACITY: usize = 8;
+
+/// Placeholder small-talk templates use for the topic.
+const TOPIC_PLACEHOLDER: &str = "{topic}";
+
+/// What a template needs from the world to apply.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum AmbientCue {
+    /// An NPC the player currently sees in this role (fills `{npc}`).
+    Role(RelationshipRole),
+    /// A recent player memory with this tag (fills `{npc}` with its first other participant).
+    MemoryTag(String),
+    /// The player's home district (fills `{district}`).
+    District,
+}
+
+/// A flavor-beat template.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct AmbientTemplate {
+    /// Stable identifier.
+    pub id: String,
+    /// What the template is keyed on.
+    pub cue: AmbientCue,
+    /// Text with `{npc}` / `{district}` placeholders.
+    pub text: String,
+    /// Tags for the feed item and memory (checked against content preferences).
+    #[serde(default)]
+    pub tags: Vec<String>,
+    /// Emotional tone (-1..1); clamped to [`MAX_AMBIENT_INTENSITY`] for the memory.
+    #[serde(default)]
+    pub valence: f32,
+}
+
+impl AmbientTemplate {
+    fn new(id: &str, cue: AmbientCue, text: &str, tags: &[&str], valence: f32) -> Self {
+        Self {
+            id: id.to_string(),
+            cue,
+            text: text.to_string(),
+            tags: tags.iter().map(|t| t.to_string()).collect(),
+            valence,
+        }
+    }
+}
+
+/// The built-in template set.
+pub fn default_ambient_templates() -> Vec<AmbientTemplate> {
+    use AmbientCue::*;
+    use RelationshipRole::*;
+    vec![
+        AmbientTemplate::new(
+            "friend_meme",
+            Role(Friend),
+            "{npc} texts you a meme",
+            &["social", "phone"],
+            0.2,
+        ),
+        AmbientTemplate::new(
+            "ally_check_in",
+            Role(Ally),
+            "{npc} checks in to see how you're doing",
+            &["social", "support"],
+            0.2,
+        ),
+        AmbientTemplate::new(
+            "crush_like",
+            Role(Romance),
+            "{npc} liked your post",
+            &["romance", "phone"],
+            0.3,
+        ),
+        AmbientTemplate::new(
+            "family_call",
+            Role(Family),
+            "{npc} leaves you a voicemail",
+            &["family", "phone"],
+            0.1,
+        ),
+        AmbientTemplate::new(
+            "rival_sighting",
+            Role(Rival),
+            "You see {npc} across the street",
+            &["rival"],
+            -0.1,
+        ),
+        AmbientTemplate::new(
+            "party_echo",
+            MemoryTag("party".to_string()),
+            "A song comes on that reminds you of the party with {npc}",
+            &["memory", "nostalgia"],
+            0.1,
+        ),
+        AmbientTemplate::new(
+            "fight_echo",
+            MemoryTag("conflict".to_string()),
+            "You replay the argument with {npc} in your head",
+            &["memory", "conflict"],
+            -0.1,
+        ),
+        AmbientTemplate::new(
+            "friend_small_talk",
+            Role(Friend),
+            "{npc} catches you up on {topic}",
+            &["social", "small_talk"],
+            0.1,
+        ),
+        AmbientTemplate::new(
+            "acquaintance_small_talk",
+            Role(Acquaintance),
+            "You make small talk with {npc} about {topic}",
+            &["social", "small_talk"],
+            0.05,
+        ),
+        AmbientTemplate::new(
+            "district_stroll",
+            District,
+            "Someone painted a new mural in {district}",
+            &["district"],
+            0.05,
+        ),
+    ]
+}
+
+/// Pacing for ambient beats.
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct AmbientConfig {
+    /// Ticks after a storylet before ambient beats may appear.
+    pub quiet_after_storylet_ticks: u64,
+    /// Minimum ticks between two ambient beats.
+    pub min_gap_ticks: u64,
+    /// Chance per eligible tick that a beat happens.
+    pub chance_per_tick: f32,
+    /// How far back (in ticks) memories and news count as recent.
+    pub memory_recency_ticks: u64,
+    /// Ticks before a pair may return to a small-talk topic.
+    pub topic_repeat_window_ticks: u64,
+}
+
+impl Default for AmbientConfig {
+    fn default() -> Self {
+        Self {
+            quiet_after_storylet_ticks: 3,
+            min_gap_ticks: 6,
+            chance_per_tick: 0.25,
+            memory_recency_ticks: 168,
+            topic_repeat_window_ticks: 168,
+        }
+    }
+}
+
+/// A generated flavor beat.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct MicroEvent {
+    /// Sequence number (unique within a run).
+    pub seq: u64,
+    /// Tick the beat happened.
+    pub tick: u64,
+    /// Template it came from.
+    pub template_id: String,
+    /// Rendered text.
+    pub text: String,
+    /// NPC the beat is about, if any.
+    #[serde(default)]
+    pub npc_id: Option<NpcId>,
+    /// District the beat is about, if any.
+    #[serde(default)]
+    pub district: Option<String>,
+    /// How the NPC feels about the player, for beats about an NPC.
+    #[serde(default)]
+    pub tone: Option<ToneContext>,
+    /// Tags (always including [`AMBIENT_TAG`]).
+    #[serde(default)]
+    pub tags: Vec<String>,
+    /// Small-talk topic, for templates with `{topic}`.
+    #[serde(default)]
+    pub topic: Option<String>,
+}
+
+/// Where a small-talk topic came from.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum TopicSource {
+    /// A tag on a player memory the NPC took part in.
+    SharedHistory,
+    /// A tag on one of the player's recent memories.
+    Memory,
+    /// A skill the player has practiced.
+    Skill,
+    /// A recent news item about the player's district or the whole city.
+    News,
+}
+
+/// Something the player and an NPC could talk about.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ConversationTopic {
+    /// Text substituted for `{topic}`; also the topic's identity.
+    pub label: String,
+    /// Where it came from.
+    pub source: TopicSource,
+}
+
+/// A topic a pair talked about.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct RecentTopic {
+    /// Topic label.
+    pub topic: String,
+    /// Tick of the beat.
+    pub tick: u64,
+}
+
+/// Ambient generator state stored on `WorldState`.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct AmbientState {
+    /// Pacing.
+    #[serde(default)]
+    pub config: AmbientConfig,
+    /// Extra templates appended to the built-in set.
+    #[serde(default)]
+    pub templates: Vec<AmbientTemplate>,
+    /// Tick of the last storylet the director fired.
+    #[serde(default)]
+    pub last_storylet_tick: Option<u64>,
+    /// Most recent beats, oldest first.
+    #[serde(default)]
+    pub recent: VecDeque<MicroEvent>,
+    /// Recent small-talk topics per NPC (the pair is always player–NPC),
+    /// oldest first.
+    #[serde(default)]
+    pub topic_history: BTreeMap<u64, VecDeque<RecentTopic>>,
+    /// Next sequence number.
+    #[serde(default)]
+    next_seq: u64,
+}
+
+impl AmbientState {
+    /// Record that a storylet fired, holding ambient beats back for a while.
+    pub fn note_storylet(&mut self, tick: u64) {
+        self.last_storylet_tick = Some(tick);
+    }
+
+    /// Most recent beats first, at most `limit`.
+    pub fn latest(&self, limit: usize) -> impl Iterator<Item = &MicroEvent> {
+        self.recent.iter().rev().take(limit)
+    }
+
+    /// Whether the player and `npc` talked about `topic` within the repeat window.
+    pub fn topic_is_stale(&self, npc: NpcId, topic: &str, tick: u64) -> bool {
+        let window = self.config.topic_repeat_window_ticks;
+        self.topic_history.get(&npc.0).is_some_and(|history| {
+            history
+                .iter()
+                .any(|t| t.topic == topic && tick.saturating_sub(t.tick) < window)
+        })
+    }
+
+    fn note_topic(&mut self, npc: NpcId, topic: &str, tick: u64) {
+        let history = self.topic_history.entry(npc.0).or_default();
+        history.push_back(RecentTopic {
+            topic: topic.to_string(),
+            tick,
+        });
+        while history.len() > TOPIC_HISTORY_CAPACITY {
+            history.pop_front();
+        }
+    }
+
+    fn is_quiet(&self, tick: u64) -> bool {
+        let after_storylet = self
+            .last_storylet_tick
+            .is_none_or(|t| tick.saturating_sub(t) >= self.config.quiet_after_storylet_ticks);
+        let after_beat = self
+            .recent
+            .back()
+            .is_none_or(|e| tick.saturating_sub(e.tick) >= self.config.min_gap_ticks);
+        after_storylet && after_beat
+    }
+}
+
+/// A beat plus the memory recorded for it.
+#[derive(Debug, Clone, PartialEq)]
+pub struct AmbientBeat {
+    /// The published beat.
+    pub event: MicroEvent,
+    /// Player memory (also appended to `world.memory_entries`).
+    pub memory: MemoryEntryRecord,
+}
+
+/// Display name for `id`, falling back to `NPC #<id>` for unnamed NPCs.
+pub fn npc_name(world: &WorldState, id: NpcId) -> String {
+    match world.npc_prototypes.get(&id) {
+        Some(proto) if !proto.display_name.is_empty() => proto.display_name.clone(),
+        _ => format!("NPC #{}", id.0),
+    }
+}
+
+fn player_role_toward(world: &WorldState, npc: NpcId) -> RelationshipRole {
+    let rel = world.get_relationship(world.player_id, npc);
+    RelationshipVector {
+        affection: rel.affection,
+        trust: rel.trust,
+        attraction: rel.attraction,
+        familiarity: rel.familiarity,
+        resentment: rel.resentment,
+    }
+    .role()
+}
+
+/// Subjects a template could be about right now, in stable order.
+fn template_subjects(
+    world: &WorldState,
+    template: &AmbientTemplate,
+    tick: u64,
+    recency: u64,
+) -> Vec<(Option<NpcId>, Option<String>)> {
+    let player = world.player_id;
+    match &template.cue {
+        AmbientCue::Role(role) => {
+            let mut ids: Vec<NpcId> = world
+                .known_npcs
+                .iter()
+                .copied()
+                .filter(|&id| id != player && player_role_toward(world, id) == *role)
+                .collect();
+            ids.sort();
+            ids.dedup();
+            ids.into_iter().map(|id| (Some(id), None)).collect()
+        }
+        AmbientCue::MemoryTag(tag) => {
+            let mut ids: Vec<NpcId> = world
+                .memory_entries
+                .iter()
+                .filter(|m| m.npc_id == player && tick.saturating_sub(m.sim_tick.0) <= recency)
+                .filter(|m| m.tags.iter().any(|t| t.eq_ignore_ascii_case(tag)))
+                .filter_map(|m| {
+                    m.participants
+                        .iter()
+                        .map(|&p| NpcId(p))
+                        .find(|&p| p != player)
+                })
+                .collect();
+            ids.sort();
+            ids.dedup();
+            ids.into_iter().map(|id| (Some(id), None)).collect()
+        }
+        AmbientCue::District => world
+            .npcs
+            .get(&player)
+            .map(|npc| npc.district.clone())
+            .filter(|d| !d.is_empty())
+            .map(|d| vec![(None, Some(d))])
+            .unwrap_or_default(),
+    }
+}
+
+/// Topics the player and `npc` could talk about, in source order: shared
+/// history, recent memories, skills, then news (oldest first). Labels are
+/// unique; ambient memories are not a topic.
+pub fn conversation_topics(world: &WorldState, npc: NpcId, tick: u64) -> Vec<ConversationTopic> {
+    let player = world.player_id;
+    let recency = world.ambient.config.memory_recency_ticks;
+    let mut topics: Vec<ConversationTopic> = Vec::new();
+    let mut push = |label: &str, source: TopicSource| {
+        let label = label.trim();
+        if !label.is_empty() && !topics.iter().any(|t| t.label.eq_ignore_ascii_case(label)) {
+            topics.push(ConversationTopic {
+                label: label.to_string(),
+                source,
+            });
+        }
+    };
+
+    let player_memories: Vec<&MemoryEntryRecord> = world
+        .memory_entries
+        .iter()
+        .filter(|m| m.npc_id == player && !m.tags.iter().any(|t| t == AMBIENT_TAG))
+        .collect();
+    for memory in player_memories.iter().filter(|m| m.participants.contains(&npc.0)) {
+        for tag in &memory.tags {
+            push(tag, TopicSource::SharedHistory);
+        }
+    }
+    for memory in player_memories
+        .iter()
+        .filter(|m| tick.saturating_sub(m.sim_tick.0) <= recency)
+    {
+        for tag in &memory.tags {
+            push(tag, TopicSource::Memory);
+        }
+    }
+
+    let mut skills: Vec<&str> = world
+        .player_skills
+        .skills
+        .iter()
+        .filter(|(_, progress)| progress.practice_count > 0)
+        .map(|(id, _)| id.0.as_str())
+        .collect();
+    skills.sort_unstable();
+    for skill in skills {
+        push(skill, TopicSource::Skill);
+    }
+
+    let home = world
+        .npcs
+        .get(&player)
+        .and_then(|p| world.districts.get_by_name(&p.district))
+        .map(|d| d.id.0);
+    for item in world
+        .news_feed
+        .items_since(tick.saturating_sub(recency))
+        .filter(|item| item.district_id.is_none() || item.district_id == home)
+        .filter(|item| !item.tags.iter().any(|t| t == AMBIENT_TAG))
+    {
+        push(&item.headline, TopicSource::News);
+    }
+    topics
+}
+
+/// A template, its subject, and the fresh topics for small talk.
+type Candidate<'a> = (&'a AmbientTemplate, Option<NpcId>, Option<String>, Vec<ConversationTopic>);
+
+/// Maybe produce an ambient beat for the current tick.
+///
+/// Returns `None` while the director is busy, between beats, when the
+/// chance roll fails, or when no template applies. On success the beat is
+/// published to the news feed, appended to `world.ambient.recent`, and
+/// recorded as a low-intensity player memory.
+pub fn generate_micro_event(world: &mut WorldState) -> Option<AmbientBeat> {
+    let tick = world.current_tick.0;
+    if !world.ambient.is_quiet(tick) {
+        return None;
+    }
+    let config = world.ambient.config;
+    let mut rng = DeterministicRng::with_domain(world.seed.0, tick, "ambient_micro_event");
+    if !rng.gen_bool(config.chance_per_tick) {
+        return None;
+    }
+
+    let mut templates = default_ambient_templates();
+    templates.extend(world.ambient.templates.iter().cloned());
+    let last = world.ambient.recent.back();
+
+    let mut candidates: Vec<Candidate> = Vec::new();
+    for template in &templates {
+        let tags = template.tags.iter().map(String::as_str);
+        if !world.content_preferences.allows(None, tags) {
+            continue;
+        }
+        let small_talk = template.text.contains(TOPIC_PLACEHOLDER);
+        for (npc, district) in template_subjects(world, template, tick, config.memory_recency_ticks)
+        {
+            let repeat = last.is_some_and(|e| e.template_id == template.id && e.npc_id == npc);
+            if repeat {
+                continue;
+            }
+            let topics = match npc {
+                Some(id) if small_talk => {
+                    let fresh: Vec<ConversationTopic> = conversation_topics(world, id, tick)
+                        .into_iter()
+                        .filter(|t| !world.ambient.topic_is_stale(id, &t.label, tick))
+                        .collect();
+                    if fresh.is_empty() {
+                        continue; // Nothing new to talk about
+                    }
+                    fresh
+                }
+                _ if small_talk => continue,
+                _ => Vec::new(),
+            };
+            candidates.push((template, npc, district, topics));
+        }
+    }
+    if candidates.is_empty() {
+        return None;
+    }
+    let pick = (rng.gen_u64() % candidates.len() as u64) as usize;
+    let (template, npc_id, district, mut topics) = candidates.swap_remove(pick);
+    let topic = (!topics.is_empty())
+        .then(|| topics.swap_remove((rng.gen_u64() % topics.len() as u64) as usize).label);
+
+    let mut text = template.text.clone();
+    if let Some(id) = npc_id {
+        text = text.replace("{npc}", &npc_name(world, id));
+    }
+    if let Some(name) = &district {
+        text = text.replace("{district}", name);
+    }
+    if let Some(label) = &topic {
+        text = text.replace(TOPIC_PLACEHOLDER, label);
+    }
+    let mut tags = template.tags.clone();
+    tags.push(AMBIENT_TAG.to_string());
+
+    let seq = world.ambient.next_seq;
+    world.ambient.next_seq += 1;
+    let event = MicroEvent {
+        seq,
+        tick,
+        template_id: template.id.clone(),
+        text,
+        npc_id,
+        district,
+        tone: npc_id.map(|id| tone_context(world, id)),
+        tags,
+        topic,
+    };
+    let memory = MemoryEntryRecord {
+        id: format!("ambient:{}", seq),
+        event_id: format!("ambient:{}", template.id),
+        npc_id: world.player_id,
+        sim_tick: SimTick(tick),
+        emotional_intensity: template
+            .valence
+            .clamp(-MAX_AMBIENT_INTENSITY, MAX_AMBIENT_INTENSITY),
+        tags: event.tags.clone(),
+        participants: npc_id.map(|id| vec![id.0]).unwrap_or_default(),
+        ..Default::default()
+    };
+
+    world
+        .news_feed
+        .publish_ambient(tick, &event.text, &event.tags, npc_id);
+    world.memory_entries.push(memory.clone());
+    if let (Some(id), Some(label)) = (npc_id, &event.topic) {
+        world.ambient.note_topic(id, label, tick);
+    }
+    world.ambient.recent.push_back(event.clone());
+    while world.ambient.recent.len() > RECENT_CAPACITY {
+        world.ambient.recent.pop_front();
+    }
+
+    Some(AmbientBeat { event, memory })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::types::{Relationship, WorldSeed};
+
+    fn world_with_friend() -> WorldState {
+        let mut world = WorldState::new(WorldSeed(11), NpcId(1));
+        world.ambient.config.chance_per_tick = 1.0;
+        world.ensure_npc_known(NpcId(2));
+        world.set_relationship(
+            NpcId(1),
+            NpcId(2),
+            Relationship {
+                affection: 6.0,
+                trust: 5.0,
+                familiarity: 6.0,
+                ..Default::default()
+            },
+        );
+        world
+    }
+
+    #[test]
+    fn test_beat_is_published_and_remembered() {
+        let mut world = world_with_friend();
+        world.current_tick = SimTick(10);
+
+        let beat = generate_micro_event(&mut world).expect("a friend beat");
+        assert_eq!(beat.event.template_id, "friend_meme");
+        assert_eq!(beat.event.text, "NPC #2 texts you a meme");
+        assert!(beat.memory.emotional_intensity <= MAX_AMBIENT_INTENSITY);
+        assert_eq!(beat.memory.participants, vec![2]);
+        assert_eq!(beat.event.tone.map(|t| t.npc_id), Some(NpcId(2)));
+        assert_eq!(world.memory_entries.len(), 1);
+
+        let item = world.news_feed.latest(1).next().unwrap();
+        assert_eq!(item.source, crate::news_feed::NewsSource::Ambient);
+        assert_eq!(item.headline, beat.event.text);
+    }
+
+    #[test]
+    fn test_beats_respect_pacing_and_preferences() {
+        let mut world = world_with_friend();
+        world.current_tick = SimTick(10);
+        world.ambient.note_storylet(9);
+        assert!(generate_micro_event(&mut world).is_none());
+
+        world.current_tick = SimTick(12);
+        assert!(generate_micro_event(&mut world).is_some());
+        world.current_tick = SimTick(13);
+        assert!(generate_micro_event(&mut world).is_none());
+
+        world.content_preferences.block_tag("social");
+        world.current_tick = SimTick(40);
+        assert!(generate_micro_event(&mut world).is_none());
+    }
+
+    fn shared_memory(tags: &[&str]) -> MemoryEntryRecord {
+        MemoryEntryRecord {
+            id: "night_out".to_string(),
+            event_id: "night_out".to_string(),
+            npc_id: NpcId(1),
+            sim_tick: SimTick(0),
+            emotional_intensity: 0.5,
+            tags: tags.iter().map(|t| t.to_string()).collect(),
+            participants: vec![1, 2],
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn test_topics_come_from_history_skills_and_news() {
+        let mut world = world_with_friend();
+        world.memory_entries.push(shared_memory(&["party"]));
+        world.player_skills.skills.insert(
+            crate::skills::SkillId::new("cooking"),
+            crate::skills::SkillProgress {
+                practice_count: 3,
+                ..Default::default()
+            },
+        );
+        world.news_feed.items.push_back(crate::news_feed::NewsItem {
+            id: 0,
+            tick: 5,
+            day: 0,
+            source: crate::news_feed::NewsSource::BlackSwan,
+            sentiment: crate::news_feed::NewsSentiment::Negative,
+            headline: "the blackout".to_string(),
+            tags: vec!["blackout".to_string()],
+            district_id: None,
+            subject_id: None,
+        });
+
+        let topics = conversation_topics(&world, NpcId(2), 10);
+        let labels: Vec<(&str, TopicSource)> =
+            topics.iter().map(|t| (t.label.as_str(), t.source)).collect();
+        assert_eq!(
+            labels,
+            vec![
+                ("party", TopicSource::SharedHistory),
+                ("cooking", TopicSource::Skill),
+                ("the blackout", TopicSource::News),
+            ]
+        );
+        // Only shared history is specific to the NPC.
+        assert_eq!(conversation_topics(&world, NpcId(3), 10).len(), 3);
+        assert_eq!(conversation_topics(&world, NpcId(3), 10)[0].source, TopicSource::Memory);
+    }
+
+    #[test]
+    fn test_small_talk_avoids_repeating_topics_within_window() {
+        let mut world = world_with_friend();
+        world.memory_entries.push(shared_memory(&["party", "karaoke"]));
+
+        let mut used: Vec<(u64, String)> = Vec::new();
+        for tick in 0..400 {
+            world.current_tick = SimTick(tick);
+            if let Some(beat) = generate_micro_event(&mut world) {
+                if let Some(topic) = beat.event.topic {
+                    assert!(beat.event.text.contains(&topic));
+                    used.push((tick, topic));
+                }
+            }
+        }
+
+        assert!(used.iter().any(|(_, t)| t == "party"));
+        assert!(used.iter().any(|(_, t)| t == "karaoke"));
+        let window = world.ambient.config.topic_repeat_window_ticks;
+        for (i, (tick, topic)) in used.iter().enumerate() {
+            if let Some((next, _)) = used[i + 1..].iter().find(|(_, t)| t == topic) {
+                assert!(next - tick >= window, "{} repeated after {} ticks", topic, next - tick);
+            }
+        }
+        assert!(world.ambient.topic_history[&2].len() <= TOPIC_HISTORY_CAPACITY);
+    }
+
+    #[test]
+    fn test_generation_is_deterministic() {
+        let run = || {
+            let mut world = world_with_friend();
+            let mut texts = Vec::new();
+            for tick in 0..100 {
+                world.current_tick = SimTick(tick);
+                if let Some(beat) = generate_micro_event(&mut world) {
+                    texts.push((tick, beat.event.text));
+                }
+            }
+            texts
+        };
+        assert_eq!(run(), run());
+    }
+}
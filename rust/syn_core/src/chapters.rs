@@ -0,0 +1,382 @@
+//! Life chapters: compact summaries for the UI's life timeline.
+//!
+//! A chapter closes at every life stage transition and at every birthday
+//! (in-game year boundary). Closing it produces a [`ChapterSummary`]: the
+//! player's most salient memories from the chapter, the relationships that
+//! moved the most, and where each stat started and ended. The next chapter
+//! then starts from a fresh [`ChapterBaseline`].
+//!
+//! Summaries are kept on `WorldState` (and therefore in saves); they are
+//! small enough that a full life fits comfortably.
+
+use std::collections::BTreeMap;
+
+use serde::{Deserialize, Serialize};
+
+use crate::stats::{StatKind, ALL_STAT_KINDS};
+use crate::types::{LifeStage, NpcId, Relationship, Stats, WorldState};
+
+/// Most salient memories kept per chapter.
+pub const CHAPTER_TOP_MEMORIES: usize = 5;
+
+/// Relationship changes kept per chapter.
+pub const CHAPTER_TOP_RELATIONSHIPS: usize = 3;
+
+/// Why a chapter closed.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum ChapterBoundary {
+    /// The player entered a new life stage.
+    LifeStage,
+    /// The player had a birthday.
+    Year,
+}
+
+impl ChapterBoundary {
+    /// Stable lowercase label for UI/FFI.
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            ChapterBoundary::LifeStage => "life_stage",
+            ChapterBoundary::Year => "year",
+        }
+    }
+}
+
+/// A memory that defined a chapter.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct ChapterMemory {
+    /// Memory id.
+    pub memory_id: String,
+    /// Storylet/event that created the memory.
+    pub event_id: String,
+    /// Tick the memory was formed.
+    pub tick: u64,
+    /// Emotional intensity (sign kept; salience is its magnitude).
+    pub emotional_intensity: f32,
+    /// Memory tags.
+    #[serde(default)]
+    pub tags: Vec<String>,
+}
+
+/// How the player's view of one NPC changed over a chapter.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct ChapterRelationshipChange {
+    /// The NPC.
+    pub npc_id: NpcId,
+    /// Player→NPC relationship when the chapter opened.
+    pub start: Relationship,
+    /// Player→NPC relationship when it closed.
+    pub end: Relationship,
+    /// Sum of absolute axis changes.
+    pub magnitude: f32,
+}
+
+/// Start and end value of one stat over a chapter.
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub struct StatTrajectory {
+    /// The stat.
+    pub kind: StatKind,
+    /// Value when the chapter opened.
+    pub start: f32,
+    /// Value when it closed.
+    pub end: f32,
+}
+
+impl StatTrajectory {
+    /// Net change over the chapter.
+    pub fn delta(&self) -> f32 {
+        self.end - self.start
+    }
+}
+
+/// One closed chapter of the player's life.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct ChapterSummary {
+    /// Chapter number, starting at 0.
+    pub index: u32,
+    /// Tick the chapter opened.
+    pub start_tick: u64,
+    /// Tick the chapter closed.
+    pub end_tick: u64,
+    /// Life stage the chapter was lived in.
+    pub life_stage: LifeStage,
+    /// Player age (years) when the chapter opened.
+    pub start_age_years: u32,
+    /// What closed the chapter.
+    pub boundary: ChapterBoundary,
+    /// Most salient player memories, strongest first.
+    #[serde(default)]
+    pub top_memories: Vec<ChapterMemory>,
+    /// Relationships that moved the most, largest change first.
+    #[serde(default)]
+    pub relationship_changes: Vec<ChapterRelationshipChange>,
+    /// Every tracked stat's start and end value.
+    #[serde(default)]
+    pub stat_trajectory: Vec<StatTrajectory>,
+}
+
+/// State captured when a chapter opens.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct ChapterBaseline {
+    /// Tick the chapter opened.
+    pub start_tick: u64,
+    /// Life stage at the start.
+    pub life_stage: LifeStage,
+    /// Player age (years) at the start.
+    pub age_years: u32,
+    /// Player stats at the start.
+    pub stats: Stats,
+    /// Player→NPC relationships at the start, by NPC id.
+    #[serde(default)]
+    pub relationships: BTreeMap<u64, Relationship>,
+}
+
+impl ChapterBaseline {
+    fn capture(world: &WorldState) -> Self {
+        let player = world.player_id;
+        ChapterBaseline {
+            start_tick: world.current_tick.0,
+            life_stage: world.player_life_stage,
+            age_years: world.player_age_years,
+            stats: world.player_stats,
+            relationships: world
+                .relationships
+                .iter()
+                .filter(|((from, to), _)| *from == player && *to != player)
+                .map(|((_, to), rel)| (to.0, *rel))
+                .collect(),
+        }
+    }
+}
+
+/// Chapter bookkeeping stored on `WorldState`.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct ChapterState {
+    /// Closed chapters, oldest first.
+    #[serde(default)]
+    pub chapters: Vec<ChapterSummary>,
+    /// The open chapter's starting point (`None` until the first tick).
+    #[serde(default)]
+    pub open: Option<ChapterBaseline>,
+}
+
+fn relationship_distance(a: &Relationship, b: &Relationship) -> f32 {
+    (a.affection - b.affection).abs()
+        + (a.trust - b.trust).abs()
+        + (a.attraction - b.attraction).abs()
+        + (a.familiarity - b.familiarity).abs()
+        + (a.resentment - b.resentment).abs()
+}
+
+fn summarize(world: &WorldState, baseline: &ChapterBaseline, boundary: ChapterBoundary, index: u32) -> ChapterSummary {
+    let player = world.player_id;
+    let end_tick = world.current_tick.0;
+
+    let mut memories: Vec<&crate::types::MemoryEntryRecord> = world
+        .memory_entries
+        .iter()
+        .filter(|m| m.npc_id == player)
+        .filter(|m| m.sim_tick.0 >= baseline.start_tick && m.sim_tick.0 <= end_tick)
+        .collect();
+    // Stable sort keeps journal order among equally salient memories.
+    memories.sort_by(|a, b| {
+        b.emotional_intensity
+            .abs()
+            .total_cmp(&a.emotional_intensity.abs())
+    });
+    let top_memories = memories
+        .into_iter()
+        .take(CHAPTER_TOP_MEMORIES)
+        .map(|m| ChapterMemory {
+            memory_id: m.id.clone(),
+            event_id: m.event_id.clone(),
+            tick: m.sim_tick.0,
+            emotional_intensity: m.emotional_intensity,
+            tags: m.tags.clone(),
+        })
+        .collect();
+
+    let mut npc_ids: Vec<u64> = world
+        .relationships
+        .keys()
+        .filter(|(from, to)| *from == player && *to != player)
+        .map(|(_, to)| to.0)
+        .chain(baseline.relationships.keys().copied())
+        .collect();
+    npc_ids.sort_unstable();
+    npc_ids.dedup();
+    let mut relationship_changes: Vec<ChapterRelationshipChange> = npc_ids
+        .into_iter()
+        .filter_map(|id| {
+            let start = baseline.relationships.get(&id).copied().unwrap_or_default();
+            let end = world.get_relationship(player, NpcId(id));
+            let magnitude = relationship_distance(&start, &end);
+            (magnitude > 0.0).then_some(ChapterRelationshipChange {
+                npc_id: NpcId(id),
+                start,
+                end,
+                magnitude,
+            })
+        })
+        .collect();
+    relationship_changes.sort_by(|a, b| b.magnitude.total_cmp(&a.magnitude));
+    relationship_changes.truncate(CHAPTER_TOP_RELATIONSHIPS);
+
+    let stat_trajectory = ALL_STAT_KINDS
+        .iter()
+        .map(|&kind| StatTrajectory {
+            kind,
+            start: baseline.stats.get(kind),
+            end: world.player_stats.get(kind),
+        })
+        .collect();
+
+    ChapterSummary {
+        index,
+        start_tick: baseline.start_tick,
+        end_tick,
+        life_stage: baseline.life_stage,
+        start_age_years: baseline.age_years,
+        boundary,
+        top_memories,
+        relationship_changes,
+        stat_trajectory,
+    }
+}
+
+/// Close the open chapter if the player changed life stage or had a birthday.
+///
+/// Called from the daily tick after age and life stage are updated. The very
+/// first call only opens a chapter. Returns the summary of a chapter that
+/// just closed.
+pub fn update_chapters(world: &mut WorldState) -> Option<&ChapterSummary> {
+    let Some(baseline) = world.chapters.open.as_ref() else {
+        world.chapters.open = Some(ChapterBaseline::capture(world));
+        return None;
+    };
+
+    let boundary = if world.player_life_stage != baseline.life_stage {
+        ChapterBoundary::LifeStage
+    } else if world.player_age_years != baseline.age_years {
+        ChapterBoundary::Year
+    } else {
+        return None;
+    };
+
+    let index = u32::try_from(world.chapters.chapters.len()).unwrap_or(u32::MAX);
+    let summary = summarize(world, baseline, boundary, index);
+    world.chapters.chapters.push(summary);
+    world.chapters.open = Some(ChapterBaseline::capture(world));
+    world.chapters.chapters.last()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::types::{MemoryEntryRecord, SimTick, WorldSeed};
+
+    fn memory(npc: u64, id: &str, tick: u64, intensity: f32) -> MemoryEntryRecord {
+        MemoryEntryRecord {
+            id: id.to_string(),
+            event_id: id.to_string(),
+            npc_id: NpcId(npc),
+            sim_tick: SimTick(tick),
+            emotional_intensity: intensity,
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn first_update_only_opens_a_chapter() {
+        let mut world = WorldState::new(WorldSeed(1), NpcId(1));
+        assert!(update_chapters(&mut world).is_none());
+        assert!(world.chapters.chapters.is_empty());
+        assert_eq!(
+            world.chapters.open.as_ref().map(|b| b.start_tick),
+            Some(world.current_tick.0)
+        );
+        // Nothing changed: still no closed chapter.
+        assert!(update_chapters(&mut world).is_none());
+    }
+
+    #[test]
+    fn birthday_closes_a_year_chapter() {
+        let mut world = WorldState::new(WorldSeed(1), NpcId(1));
+        world.player_age_years = 20;
+        world.player_life_stage = LifeStage::YoungAdult;
+        update_chapters(&mut world);
+
+        world.current_tick = SimTick(100);
+        world.player_stats.mood = 4.0;
+        world.player_age_years = 21;
+        let summary = update_chapters(&mut world).unwrap();
+        assert_eq!(summary.index, 0);
+        assert_eq!(summary.boundary, ChapterBoundary::Year);
+        assert_eq!(summary.start_age_years, 20);
+        assert_eq!(summary.end_tick, 100);
+        let mood = summary
+            .stat_trajectory
+            .iter()
+            .find(|t| t.kind == StatKind::Mood)
+            .unwrap();
+        assert_eq!(mood.delta(), 4.0);
+        assert_eq!(world.chapters.open.as_ref().unwrap().age_years, 21);
+    }
+
+    #[test]
+    fn summary_ranks_memories_and_relationship_changes() {
+        let mut world = WorldState::new(WorldSeed(1), NpcId(1));
+        world.player_life_stage = LifeStage::Teen;
+        world.set_relationship(
+            NpcId(1),
+            NpcId(3),
+            Relationship {
+                affection: 2.0,
+                ..Default::default()
+            },
+        );
+        update_chapters(&mut world);
+
+        world.current_tick = SimTick(50);
+        for (i, intensity) in [0.1, -0.9, 0.5, 0.2, 0.3, 0.4, 0.05].iter().enumerate() {
+            world
+                .memory_entries
+                .push(memory(1, &format!("m{}", i), 10 + i as u64, *intensity));
+        }
+        // Other characters' memories are not part of the player's chapter.
+        world
+            .memory_entries
+            .push(memory(2, "npc", 20, 1.0));
+        world.set_relationship(
+            NpcId(1),
+            NpcId(2),
+            Relationship {
+                trust: 1.0,
+                ..Default::default()
+            },
+        );
+        world.set_relationship(
+            NpcId(1),
+            NpcId(3),
+            Relationship {
+                affection: -3.0,
+                resentment: 2.0,
+                ..Default::default()
+            },
+        );
+        world.player_life_stage = LifeStage::YoungAdult;
+
+        let summary = update_chapters(&mut world).unwrap();
+        assert_eq!(summary.boundary, ChapterBoundary::LifeStage);
+        assert_eq!(summary.life_stage, LifeStage::Teen);
+        let ids: Vec<&str> = summary
+            .top_memories
+            .iter()
+            .map(|m| m.memory_id.as_str())
+            .collect();
+        assert_eq!(ids, vec!["m1", "m2", "m5", "m4", "m3"]);
+        assert_eq!(summary.relationship_changes[0].npc_id, NpcId(3));
+        assert_eq!(summary.relationship_changes[0].magnitude, 7.0);
+        assert_eq!(summary.relationship_changes[1].npc_id, NpcId(2));
+    }
+}
@@ -12,6 +12,14 @@
 //! | `FastHashSet` | Membership checks | Same hash performance benefits |
 //! | `SmallVec8` | Small, stack-allocated vecs | Avoids heap for ≤8 elements |
 //! | `CompactString` | Short strings | Inline storage for ≤24 chars |
+//! | `OrderedMap` | Order-sensitive state | Iterates in key order |
+//!
+//! ## Deterministic Iteration
+//!
+//! Hash maps iterate in an order that depends on the hasher and insertion
+//! history. Wherever visiting order feeds float accumulation, queue order, or
+//! RNG draws, either store the data in an [`OrderedMap`] or walk the hash map
+//! through [`sorted_entries`], [`sorted_entries_mut`], or [`sorted_keys`].
 
 use rustc_hash::{FxHashMap, FxHashSet};
 use serde::{Deserialize, Serialize};
@@ -41,6 +49,32 @@ pub type SmallVec4<T> = SmallVec<[T; 4]>;
 /// Small vector for 16 elements (NPC lists, etc.)
 pub type SmallVec16<T> = SmallVec<[T; 16]>;
 
+/// Map for state whose iteration order affects simulation results.
+pub type OrderedMap<K, V> = std::collections::BTreeMap<K, V>;
+
+/// Entries of a hash map in ascending key order.
+pub fn sorted_entries<K: Ord, V, S>(map: &std::collections::HashMap<K, V, S>) -> Vec<(&K, &V)> {
+    let mut entries: Vec<(&K, &V)> = map.iter().collect();
+    entries.sort_unstable_by(|a, b| a.0.cmp(b.0));
+    entries
+}
+
+/// Mutable entries of a hash map in ascending key order.
+pub fn sorted_entries_mut<K: Ord, V, S>(
+    map: &mut std::collections::HashMap<K, V, S>,
+) -> Vec<(&K, &mut V)> {
+    let mut entries: Vec<(&K, &mut V)> = map.iter_mut().collect();
+    entries.sort_unstable_by(|a, b| a.0.cmp(b.0));
+    entries
+}
+
+/// Keys of a hash map in ascending order.
+pub fn sorted_keys<K: Ord + Clone, V, S>(map: &std::collections::HashMap<K, V, S>) -> Vec<K> {
+    let mut keys: Vec<K> = map.keys().cloned().collect();
+    keys.sort_unstable();
+    keys
+}
+
 /// Wrapper for FastHashMap that implements Serialize/Deserialize
 /// by converting to/from std HashMap.
 #[derive(Debug, Clone, Default)]
@@ -127,6 +161,13 @@ where
     }
 }
 
+impl<K: std::hash::Hash + Eq + Ord, V> SerializableFastMap<K, V> {
+    /// Iterate entries in ascending key order (stable across runs).
+    pub fn iter_sorted(&self) -> impl Iterator<Item = (&K, &V)> {
+        sorted_entries(&self.0).into_iter()
+    }
+}
+
 impl<K, V> Serialize for SerializableFastMap<K, V>
 where
     K: Serialize + std::hash::Hash + Eq,
@@ -235,4 +276,32 @@ mod tests {
         assert_eq!(short.as_str(), "hello");
         assert!(long.len() > 24); // Just verify it's longer than inline capacity
     }
+
+    #[test]
+    fn test_sorted_entries_ignore_insertion_order() {
+        let mut forward: FastHashMap<u64, f32> = FastHashMap::default();
+        let mut backward: std::collections::HashMap<u64, f32> = std::collections::HashMap::new();
+        for k in 0..64u64 {
+            forward.insert(k, 0.1 * k as f32);
+        }
+        for k in (0..64u64).rev() {
+            backward.insert(k, 0.1 * k as f32);
+        }
+
+        let sum = |entries: Vec<(&u64, &f32)>| {
+            entries.into_iter().fold(0.0f32, |acc, (_, v)| acc + v)
+        };
+        assert_eq!(sorted_keys(&forward), sorted_keys(&backward));
+        assert_eq!(
+            sum(sorted_entries(&forward)).to_bits(),
+            sum(sorted_entries(&backward)).to_bits()
+        );
+
+        for (k, v) in sorted_entries_mut(&mut backward) {
+            *v += *k as f32;
+        }
+        let wrapped: SerializableFastMap<u64, f32> = backward.into_iter().collect();
+        let keys: Vec<u64> = wrapped.iter_sorted().map(|(k, _)| *k).collect();
+        assert_eq!(keys, (0..64).collect::<Vec<_>>());
+    }
 }
@@ -0,0 +1,182 @@
+//! Player content preferences (comfort toggles).
+//!
+//! Players may opt out of entire narrative domains (e.g. Addiction, Trauma) or
+//! individual tags. Preferences live on `WorldState` so they are saved with the
+//! run and can be changed at any time; the director filters candidates against
+//! them before scoring.
+//!
+//! Storylets tagged [`STAGE_ENTRY_TAG`] mark mandatory life-stage transitions.
+//! They are never filtered, and preferences that try to block them are rejected
+//! by [`ContentPreferences::validate`].
+
+use crate::errors::SynError;
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeSet;
+
+/// Tag carried by mandatory stage-entry storylets.
+pub const STAGE_ENTRY_TAG: &str = "stage_entry";
+
+/// Domain/tag blocklist applied during storylet selection.
+///
+/// Names are stored lowercased so `"Trauma"` and `"trauma"` are equivalent.
+/// `BTreeSet` keeps serialization order stable across saves.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ContentPreferences {
+    /// Blocked narrative domains (e.g. "addiction", "trauma").
+    #[serde(default)]
+    pub blocked_domains: BTreeSet<String>,
+    /// Blocked storylet tags.
+    #[serde(default)]
+    pub blocked_tags: BTreeSet<String>,
+}
+
+fn normalize(name: &str) -> String {
+    name.trim().to_ascii_lowercase()
+}
+
+impl ContentPreferences {
+    /// Create an empty preference set (everything allowed).
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Build preferences from raw domain and tag lists.
+    pub fn from_lists<D, T>(domains: D, tags: T) -> Self
+    where
+        D: IntoIterator,
+        D::Item: AsRef<str>,
+        T: IntoIterator,
+        T::Item: AsRef<str>,
+    {
+        let mut prefs = Self::new();
+        for domain in domains {
+            prefs.block_domain(domain.as_ref());
+        }
+        for tag in tags {
+            prefs.block_tag(tag.as_ref());
+        }
+        prefs
+    }
+
+    /// True if nothing is blocked.
+    pub fn is_empty(&self) -> bool {
+        self.blocked_domains.is_empty() && self.blocked_tags.is_empty()
+    }
+
+    /// Block a narrative domain.
+    pub fn block_domain(&mut self, domain: &str) {
+        let name = normalize(domain);
+        if !name.is_empty() {
+            self.blocked_domains.insert(name);
+        }
+    }
+
+    /// Unblock a narrative domain.
+    pub fn unblock_domain(&mut self, domain: &str) {
+        self.blocked_domains.remove(&normalize(domain));
+    }
+
+    /// Block a storylet tag.
+    pub fn block_tag(&mut self, tag: &str) {
+        let name = normalize(tag);
+        if !name.is_empty() {
+            self.blocked_tags.insert(name);
+        }
+    }
+
+    /// Unblock a storylet tag.
+    pub fn unblock_tag(&mut self, tag: &str) {
+        self.blocked_tags.remove(&normalize(tag));
+    }
+
+    /// Check whether a domain is blocked.
+    pub fn is_domain_blocked(&self, domain: &str) -> bool {
+        !self.blocked_domains.is_empty() && self.blocked_domains.contains(&normalize(domain))
+    }
+
+    /// Check whether a tag is blocked.
+    pub fn is_tag_blocked(&self, tag: &str) -> bool {
+        !self.blocked_tags.is_empty() && self.blocked_tags.contains(&normalize(tag))
+    }
+
+    /// Decide whether content with the given domain and tags may be selected.
+    ///
+    /// Content tagged [`STAGE_ENTRY_TAG`] is always allowed. Tags that name a
+    /// blocked domain also block the content, so tag-only storylets (which
+    /// carry no explicit domain) still respect domain toggles.
+    pub fn allows<'a, I>(&self, domain: Option<&str>, tags: I) -> bool
+    where
+        I: IntoIterator<Item = &'a str>,
+    {
+        if self.is_empty() {
+            return true;
+        }
+
+        let tags: Vec<String> = tags.into_iter().map(normalize).collect();
+        if tags.iter().any(|t| t == STAGE_ENTRY_TAG) {
+            return true;
+        }
+
+        if domain.is_some_and(|d| self.is_domain_blocked(d)) {
+            return false;
+        }
+
+        !tags
+            .iter()
+            .any(|t| self.blocked_tags.contains(t) || self.blocked_domains.contains(t))
+    }
+
+    /// Validate that the preferences don't block mandatory content.
+    pub fn validate(&self) -> Result<(), SynError> {
+        if self.blocked_tags.contains(STAGE_ENTRY_TAG)
+            || self.blocked_domains.contains(STAGE_ENTRY_TAG)
+        {
+            return Err(SynError::InvalidState(format!(
+                "'{}' content is mandatory and cannot be blocked",
+                STAGE_ENTRY_TAG
+            )));
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_empty_preferences_allow_everything() {
+        let prefs = ContentPreferences::new();
+        assert!(prefs.allows(Some("trauma"), ["grief"]));
+        assert!(prefs.validate().is_ok());
+    }
+
+    #[test]
+    fn test_domain_block_is_case_insensitive() {
+        let prefs = ContentPreferences::from_lists(["Addiction"], Vec::<String>::new());
+        assert!(!prefs.allows(Some("addiction"), []));
+        assert!(!prefs.allows(Some("ADDICTION"), []));
+        assert!(prefs.allows(Some("romance"), []));
+    }
+
+    #[test]
+    fn test_tag_block_and_domain_named_tag() {
+        let prefs = ContentPreferences::from_lists(["trauma"], ["gore"]);
+        assert!(!prefs.allows(None, ["drama", "gore"]));
+        // Legacy storylets have no domain; a tag naming the domain still blocks.
+        assert!(!prefs.allows(None, ["trauma"]));
+        assert!(prefs.allows(None, ["drama"]));
+    }
+
+    #[test]
+    fn test_stage_entry_is_never_filtered() {
+        let prefs = ContentPreferences::from_lists(["trauma"], ["grief"]);
+        assert!(prefs.allows(Some("trauma"), ["grief", STAGE_ENTRY_TAG]));
+    }
+
+    #[test]
+    fn test_validate_rejects_blocking_stage_entry() {
+        let prefs = ContentPreferences::from_lists(Vec::<String>::new(), ["Stage_Entry"]);
+        assert!(prefs.validate().is_err());
+    }
+}
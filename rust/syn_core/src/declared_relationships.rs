@@ -0,0 +1,447 @@
+//! Declared relationship status ("define the relationship").
+//!
+//! A relationship's [`RelationshipState`] is derived from its axes and moves
+//! on its own. Some storylet choices also let the player say out loud what a
+//! relationship is: "we're dating", "you're my best friend", "we're done".
+//! That [`DeclaredStatus`] is stored per pair alongside the derived state and
+//! stays until another choice changes or clears it.
+//!
+//! Prerequisites and casting can reference the declared status, and a
+//! declaration that no longer fits how the pair actually feels (dating, but
+//! the derived state has slid to Rival) becomes a [`DeclaredMismatch`].
+//! Storylets with the trigger kind [`DECLARED_MISMATCH_TRIGGER`] (any
+//! mismatch) or `declared_mismatch:<status>` (e.g. `declared_mismatch:dating`)
+//! are eligible while a mismatch is pending; firing one answers it.
+
+use serde::{Deserialize, Serialize};
+
+use crate::types::{NpcId, RelationshipState, WorldState};
+
+/// Trigger kind for storylets reacting to any declared/derived mismatch.
+pub const DECLARED_MISMATCH_TRIGGER: &str = "declared_mismatch";
+
+/// Prefix of trigger kinds reacting to a mismatch of one declared status
+/// (`declared_mismatch:<status>`).
+pub const DECLARED_MISMATCH_TRIGGER_PREFIX: &str = "declared_mismatch:";
+
+/// What a pair has said their relationship is.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum DeclaredStatus {
+    /// Going out, not yet committed.
+    Dating,
+    /// A committed couple.
+    Partners,
+    /// Married.
+    Married,
+    /// Friends, said out loud.
+    Friends,
+    /// Best friends.
+    BestFriends,
+    /// Cut off from each other.
+    Estranged,
+    /// Open rivals.
+    Rivals,
+}
+
+/// All declared statuses.
+pub const ALL_DECLARED_STATUSES: [DeclaredStatus; 7] = [
+    DeclaredStatus::Dating,
+    DeclaredStatus::Partners,
+    DeclaredStatus::Married,
+    DeclaredStatus::Friends,
+    DeclaredStatus::BestFriends,
+    DeclaredStatus::Estranged,
+    DeclaredStatus::Rivals,
+];
+
+impl DeclaredStatus {
+    /// Content name (e.g. `"best_friends"`).
+    pub fn as_str(self) -> &'static str {
+        match self {
+            Self::Dating => "dating",
+            Self::Partners => "partners",
+            Self::Married => "married",
+            Self::Friends => "friends",
+            Self::BestFriends => "best_friends",
+            Self::Estranged => "estranged",
+            Self::Rivals => "rivals",
+        }
+    }
+
+    /// Parse a content name (case-insensitive).
+    pub fn from_name(name: &str) -> Option<Self> {
+        ALL_DECLARED_STATUSES
+            .iter()
+            .copied()
+            .find(|status| status.as_str().eq_ignore_ascii_case(name.trim()))
+    }
+
+    /// Whether a derived state is consistent with this declaration.
+    pub fn fits(self, derived: RelationshipState) -> bool {
+        use RelationshipState as S;
+        match self {
+            Self::Dating => matches!(derived, S::RomanticInterest | S::Partner | S::Spouse),
+            Self::Partners => matches!(derived, S::Partner | S::Spouse),
+            Self::Married => matches!(derived, S::Spouse),
+            Self::Friends => matches!(
+                derived,
+                S::Acquaintance | S::Friend | S::CloseFriend | S::BestFriend
+            ),
+            Self::BestFriends => matches!(derived, S::CloseFriend | S::BestFriend),
+            Self::Estranged => matches!(
+                derived,
+                S::Stranger | S::Acquaintance | S::Rival | S::Estranged | S::BrokenHeart
+            ),
+            Self::Rivals => matches!(derived, S::Rival | S::Estranged),
+        }
+    }
+
+    /// Trigger kind of storylets reacting to a mismatch of this status.
+    pub fn mismatch_trigger(self) -> String {
+        format!("{}{}", DECLARED_MISMATCH_TRIGGER_PREFIX, self.as_str())
+    }
+}
+
+impl std::fmt::Display for DeclaredStatus {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+/// Whether a storylet trigger kind reacts to declared/derived mismatches.
+pub fn is_declared_mismatch_trigger(kind: &str) -> bool {
+    kind == DECLARED_MISMATCH_TRIGGER || kind.starts_with(DECLARED_MISMATCH_TRIGGER_PREFIX)
+}
+
+/// A declared status for one pair.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct DeclaredRelationship {
+    /// Who declared it (usually the player).
+    pub actor_id: u64,
+    /// Who it was declared with.
+    pub target_id: u64,
+    /// What they said the relationship is.
+    pub status: DeclaredStatus,
+    /// Tick it was declared.
+    pub declared_tick: u64,
+    /// What declared it (e.g. `storylet:first_date`).
+    pub source: String,
+}
+
+impl DeclaredRelationship {
+    fn is_pair(&self, a: u64, b: u64) -> bool {
+        (self.actor_id == a && self.target_id == b) || (self.actor_id == b && self.target_id == a)
+    }
+}
+
+/// A declaration the derived state no longer fits.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct DeclaredMismatch {
+    /// Who declared the status.
+    pub actor_id: u64,
+    /// Who it was declared with.
+    pub target_id: u64,
+    /// What was declared.
+    pub declared: DeclaredStatus,
+    /// Derived state that contradicts it.
+    pub derived: RelationshipState,
+    /// Tick the mismatch was noticed.
+    pub tick: u64,
+    /// Whether a storylet has reacted to it. Answered mismatches stay until
+    /// the pair fits the declaration again, so they aren't raised twice.
+    #[serde(default)]
+    pub answered: bool,
+}
+
+/// An outcome's request to declare (or clear) a relationship status.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct RelationshipDeclaration {
+    /// Who declares it.
+    pub actor_id: u64,
+    /// Who it is declared with.
+    pub target_id: u64,
+    /// New status; `None` clears the declaration.
+    #[serde(default)]
+    pub status: Option<DeclaredStatus>,
+}
+
+/// Declared statuses stored on `WorldState`.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct DeclaredRelationshipState {
+    /// One declaration per pair, in the order they were made.
+    #[serde(default)]
+    pub declared: Vec<DeclaredRelationship>,
+    /// Current mismatches, oldest first.
+    #[serde(default)]
+    pub mismatches: Vec<DeclaredMismatch>,
+}
+
+impl DeclaredRelationshipState {
+    /// Declaration between `a` and `b`, in either direction.
+    pub fn get(&self, a: u64, b: u64) -> Option<&DeclaredRelationship> {
+        self.declared.iter().find(|d| d.is_pair(a, b))
+    }
+
+    /// Declared status between `a` and `b`, in either direction.
+    pub fn status_of(&self, a: u64, b: u64) -> Option<DeclaredStatus> {
+        self.get(a, b).map(|d| d.status)
+    }
+
+    /// NPCs `npc_id` has declared (or been declared) one of `statuses` with,
+    /// in declaration order.
+    pub fn partners_with<'a>(
+        &'a self,
+        npc_id: u64,
+        statuses: &'a [DeclaredStatus],
+    ) -> impl Iterator<Item = u64> + 'a {
+        self.declared
+            .iter()
+            .filter(move |d| statuses.contains(&d.status))
+            .filter_map(move |d| {
+                if d.actor_id == npc_id {
+                    Some(d.target_id)
+                } else if d.target_id == npc_id {
+                    Some(d.actor_id)
+                } else {
+                    None
+                }
+            })
+    }
+
+    /// The oldest unanswered mismatch a storylet with this trigger kind
+    /// reacts to.
+    pub fn mismatch_for(&self, trigger_kind: &str) -> Option<&DeclaredMismatch> {
+        self.mismatches.iter().find(|m| {
+            !m.answered
+                && (trigger_kind == DECLARED_MISMATCH_TRIGGER
+                    || m.declared
+                        .mismatch_trigger()
+                        .eq_ignore_ascii_case(trigger_kind))
+        })
+    }
+
+    /// Mark the mismatch a storylet with this trigger kind answers. Returns
+    /// it, if one was pending.
+    pub fn consume(&mut self, trigger_kind: &str) -> Option<DeclaredMismatch> {
+        let index = self.mismatches.iter().position(|m| {
+            !m.answered
+                && (trigger_kind == DECLARED_MISMATCH_TRIGGER
+                    || m.declared
+                        .mismatch_trigger()
+                        .eq_ignore_ascii_case(trigger_kind))
+        })?;
+        self.mismatches[index].answered = true;
+        Some(self.mismatches[index].clone())
+    }
+}
+
+/// Declare `status` between `actor` and `target`, replacing any earlier
+/// declaration for the pair. Returns the previous status.
+pub fn declare_relationship(
+    world: &mut WorldState,
+    actor: NpcId,
+    target: NpcId,
+    status: DeclaredStatus,
+    source: &str,
+) -> Option<DeclaredStatus> {
+    let previous = clear_declared_relationship(world, actor, target);
+    world
+        .declared_relationships
+        .declared
+        .push(DeclaredRelationship {
+            actor_id: actor.0,
+            target_id: target.0,
+            status,
+            declared_tick: world.current_tick.0,
+            source: source.to_string(),
+        });
+    previous
+}
+
+/// Remove the declaration between `actor` and `target` (and any pending
+/// mismatch for it). Returns the status that was cleared.
+pub fn clear_declared_relationship(
+    world: &mut WorldState,
+    actor: NpcId,
+    target: NpcId,
+) -> Option<DeclaredStatus> {
+    let state = &mut world.declared_relationships;
+    let index = state
+        .declared
+        .iter()
+        .position(|d| d.is_pair(actor.0, target.0))?;
+    let removed = state.declared.remove(index);
+    state
+        .mismatches
+        .retain(|m| !(m.actor_id == removed.actor_id && m.target_id == removed.target_id));
+    Some(removed.status)
+}
+
+/// Apply an outcome's declarations in order, attributed to `source`.
+pub fn apply_relationship_declarations(
+    world: &mut WorldState,
+    declarations: &[RelationshipDeclaration],
+    source: &str,
+) {
+    for declaration in declarations {
+        let actor = NpcId(declaration.actor_id);
+        let target = NpcId(declaration.target_id);
+        match declaration.status {
+            Some(status) => {
+                declare_relationship(world, actor, target, status, source);
+            }
+            None => {
+                clear_declared_relationship(world, actor, target);
+            }
+        }
+    }
+}
+
+/// Compare every declaration with its derived state. New mismatches are
+/// queued once; mismatches whose pair fits the declaration again are dropped.
+pub fn update_declared_mismatches(world: &mut WorldState) {
+    let tick = world.current_tick.0;
+    let mut mismatches = std::mem::take(&mut world.declared_relationships.mismatches);
+    for declared in &world.declared_relationships.declared {
+        let derived = world
+            .get_relationship(NpcId(declared.actor_id), NpcId(declared.target_id))
+            .state;
+        let pending = mismatches
+            .iter()
+            .position(|m| m.actor_id == declared.actor_id && m.target_id == declared.target_id);
+        match (declared.status.fits(derived), pending) {
+            (true, Some(index)) => {
+                mismatches.remove(index);
+            }
+            (false, None) => mismatches.push(DeclaredMismatch {
+                actor_id: declared.actor_id,
+                target_id: declared.target_id,
+                declared: declared.status,
+                derived,
+                tick,
+                answered: false,
+            }),
+            _ => {}
+        }
+    }
+    world.declared_relationships.mismatches = mismatches;
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::types::{Relationship, SimTick, WorldSeed};
+
+    fn world_with(state: RelationshipState) -> WorldState {
+        let mut world = WorldState::new(WorldSeed(5), NpcId(1));
+        world.current_tick = SimTick(30);
+        world.set_relationship(
+            NpcId(1),
+            NpcId(2),
+            Relationship {
+                state,
+                ..Default::default()
+            },
+        );
+        world
+    }
+
+    #[test]
+    fn declaration_is_per_pair_in_either_direction() {
+        let mut world = world_with(RelationshipState::Partner);
+        declare_relationship(
+            &mut world,
+            NpcId(1),
+            NpcId(2),
+            DeclaredStatus::Dating,
+            "test",
+        );
+        assert_eq!(
+            world.declared_relationships.status_of(2, 1),
+            Some(DeclaredStatus::Dating)
+        );
+
+        let previous = declare_relationship(
+            &mut world,
+            NpcId(2),
+            NpcId(1),
+            DeclaredStatus::Partners,
+            "test",
+        );
+        assert_eq!(previous, Some(DeclaredStatus::Dating));
+        assert_eq!(world.declared_relationships.declared.len(), 1);
+        assert_eq!(
+            world
+                .declared_relationships
+                .partners_with(1, &[DeclaredStatus::Partners])
+                .collect::<Vec<_>>(),
+            vec![2]
+        );
+        assert_eq!(
+            DeclaredStatus::from_name("Best_Friends"),
+            Some(DeclaredStatus::BestFriends)
+        );
+    }
+
+    #[test]
+    fn mismatch_is_raised_once_and_resolves() {
+        let mut world = world_with(RelationshipState::Partner);
+        declare_relationship(
+            &mut world,
+            NpcId(1),
+            NpcId(2),
+            DeclaredStatus::Dating,
+            "test",
+        );
+        update_declared_mismatches(&mut world);
+        assert!(world.declared_relationships.mismatches.is_empty());
+
+        let mut rel = world.get_relationship(NpcId(1), NpcId(2));
+        rel.state = RelationshipState::Rival;
+        world.set_relationship(NpcId(1), NpcId(2), rel.clone());
+        update_declared_mismatches(&mut world);
+        update_declared_mismatches(&mut world);
+        assert_eq!(world.declared_relationships.mismatches.len(), 1);
+        let state = &world.declared_relationships;
+        assert!(state.mismatch_for("declared_mismatch:dating").is_some());
+        assert!(state.mismatch_for(DECLARED_MISMATCH_TRIGGER).is_some());
+        assert!(state.mismatch_for("declared_mismatch:estranged").is_none());
+
+        rel.state = RelationshipState::RomanticInterest;
+        world.set_relationship(NpcId(1), NpcId(2), rel);
+        update_declared_mismatches(&mut world);
+        assert!(world.declared_relationships.mismatches.is_empty());
+    }
+
+    #[test]
+    fn consuming_answers_the_mismatch() {
+        let mut world = world_with(RelationshipState::Rival);
+        apply_relationship_declarations(
+            &mut world,
+            &[RelationshipDeclaration {
+                actor_id: 1,
+                target_id: 2,
+                status: Some(DeclaredStatus::BestFriends),
+            }],
+            "storylet:pact",
+        );
+        update_declared_mismatches(&mut world);
+        let answered = world
+            .declared_relationships
+            .consume("declared_mismatch:best_friends")
+            .expect("mismatch pending");
+        assert_eq!(answered.derived, RelationshipState::Rival);
+        assert_eq!(
+            world.declared_relationships.declared[0].source,
+            "storylet:pact"
+        );
+
+        // Still mismatched, but answered: not raised again until it resolves.
+        update_declared_mismatches(&mut world);
+        assert!(world
+            .declared_relationships
+            .mismatch_for(DECLARED_MISMATCH_TRIGGER)
+            .is_none());
+    }
+}
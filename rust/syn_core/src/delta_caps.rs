@@ -0,0 +1,309 @@
+//! Safety caps on storylet outcome deltas.
+//!
+//! A content typo like `delta: 200` should not wipe a stat. Every authored
+//! stat, relationship and karma delta passes through this module before it
+//! is applied:
+//!
+//! - a per-event cap bounds any single delta,
+//! - a per-day cap bounds the total magnitude applied to one target (a stat,
+//!   karma, or one pair's axis) within a game day.
+//!
+//! Deltas over a cap are clamped, never dropped, and each clamp is logged as
+//! a [`DeltaCapWarning`] so designers can find the offending content. Caps
+//! apply to authored values, before difficulty scaling and karma weighting.
+//! A cap of 0 or less disables that limit. Content validation rejects deltas
+//! above a separate, higher authoring maximum outright.
+
+use std::collections::BTreeMap;
+
+use serde::{Deserialize, Serialize};
+
+use crate::relationship_model::{RelationshipAxis, RelationshipDelta};
+use crate::stats::{StatDelta, StatKind};
+use crate::types::WorldState;
+
+/// Per-event and per-day delta caps.
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct DeltaCapConfig {
+    /// Whether deltas are capped at all.
+    pub enabled: bool,
+    /// Largest single stat delta (stats other than mood).
+    pub stat_per_event: f32,
+    /// Largest total stat change per stat per day.
+    pub stat_per_day: f32,
+    /// Largest single mood delta (mood runs -10..10).
+    pub mood_per_event: f32,
+    /// Largest total mood change per day.
+    pub mood_per_day: f32,
+    /// Largest single relationship axis delta (axes run -10..10).
+    pub relationship_per_event: f32,
+    /// Largest total change per pair and axis per day.
+    pub relationship_per_day: f32,
+    /// Largest single authored karma delta.
+    pub karma_per_event: f32,
+    /// Largest total authored karma change per day.
+    pub karma_per_day: f32,
+    /// Warnings kept in the log.
+    pub max_warnings: usize,
+}
+
+impl Default for DeltaCapConfig {
+    fn default() -> Self {
+        Self {
+            enabled: true,
+            stat_per_event: 50.0,
+            stat_per_day: 150.0,
+            mood_per_event: 10.0,
+            mood_per_day: 20.0,
+            relationship_per_event: 10.0,
+            relationship_per_day: 20.0,
+            karma_per_event: 50.0,
+            karma_per_day: 150.0,
+            max_warnings: 50,
+        }
+    }
+}
+
+/// Which cap clamped a delta.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum DeltaCapKind {
+    /// The single delta was over the per-event cap.
+    PerEvent,
+    /// The day's budget for the target was used up.
+    PerDay,
+}
+
+impl DeltaCapKind {
+    /// Stable lowercase label for UI/FFI.
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            DeltaCapKind::PerEvent => "per_event",
+            DeltaCapKind::PerDay => "per_day",
+        }
+    }
+}
+
+/// A logged clamp.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct DeltaCapWarning {
+    /// Tick the delta applied.
+    pub tick: u64,
+    /// What the delta targeted: `stat:<name>`, `karma` or
+    /// `relationship:<actor>:<target>:<axis>`.
+    pub target: String,
+    /// Authored delta.
+    pub requested: f32,
+    /// Delta actually applied.
+    pub applied: f32,
+    /// Which cap clamped it.
+    pub cap: DeltaCapKind,
+}
+
+/// Cap tuning, today's spent budgets and the warning log.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct DeltaCapState {
+    /// Cap tuning.
+    #[serde(default)]
+    pub config: DeltaCapConfig,
+    /// Day (`tick / 24`) the spent budgets belong to.
+    #[serde(default)]
+    pub day: u64,
+    /// Magnitude applied today per target.
+    #[serde(default)]
+    pub spent: BTreeMap<String, f32>,
+    /// Most recent clamps, oldest first.
+    #[serde(default)]
+    pub warnings: Vec<DeltaCapWarning>,
+}
+
+impl DeltaCapState {
+    /// Clamp `delta` for `target` against `per_event` and the remainder of
+    /// `per_day`, spend the budget and log any clamp.
+    fn cap(&mut self, tick: u64, target: String, delta: f32, per_event: f32, per_day: f32) -> f32 {
+        if !self.config.enabled {
+            return delta;
+        }
+        let day = tick / 24;
+        if day != self.day {
+            self.day = day;
+            self.spent.clear();
+        }
+
+        // NaN or infinite content is never applied as-is.
+        let mut applied = if delta.is_finite() { delta } else { 0.0 };
+        let mut cap = (!delta.is_finite()).then_some(DeltaCapKind::PerEvent);
+        if per_event > 0.0 && applied.abs() > per_event {
+            applied = per_event.copysign(applied);
+            cap = Some(DeltaCapKind::PerEvent);
+        }
+        if per_day > 0.0 {
+            let spent = self.spent.get(&target).copied().unwrap_or(0.0);
+            let remaining = (per_day - spent).max(0.0);
+            if applied.abs() > remaining {
+                applied = remaining.copysign(applied);
+                cap = Some(DeltaCapKind::PerDay);
+            }
+            *self.spent.entry(target.clone()).or_insert(0.0) += applied.abs();
+        }
+
+        if let Some(cap) = cap {
+            self.warnings.push(DeltaCapWarning {
+                tick,
+                target,
+                requested: delta,
+                applied,
+                cap,
+            });
+            let overflow = self.warnings.len().saturating_sub(self.config.max_warnings);
+            self.warnings.drain(..overflow);
+        }
+        applied
+    }
+}
+
+/// Lowercase content name of a stat, as used in cap targets.
+fn stat_name(kind: StatKind) -> String {
+    format!("{:?}", kind).to_lowercase()
+}
+
+/// Lowercase content name of an axis, as used in cap targets.
+fn axis_name(axis: RelationshipAxis) -> &'static str {
+    match axis {
+        RelationshipAxis::Affection => "affection",
+        RelationshipAxis::Trust => "trust",
+        RelationshipAxis::Attraction => "attraction",
+        RelationshipAxis::Familiarity => "familiarity",
+        RelationshipAxis::Resentment => "resentment",
+    }
+}
+
+/// Cap one authored player stat delta. Returns the delta to apply.
+pub fn cap_stat_delta(world: &mut WorldState, kind: StatKind, delta: f32) -> f32 {
+    let config = world.delta_caps.config;
+    let (per_event, per_day) = match kind {
+        StatKind::Mood => (config.mood_per_event, config.mood_per_day),
+        _ => (config.stat_per_event, config.stat_per_day),
+    };
+    let tick = world.current_tick.0;
+    let target = format!("stat:{}", stat_name(kind));
+    world.delta_caps.cap(tick, target, delta, per_event, per_day)
+}
+
+/// Cap a list of authored player stat deltas, in order.
+pub fn cap_stat_deltas(world: &mut WorldState, deltas: &[StatDelta]) -> Vec<StatDelta> {
+    deltas
+        .iter()
+        .map(|d| StatDelta {
+            kind: d.kind,
+            delta: cap_stat_delta(world, d.kind, d.delta),
+            source: d.source.clone(),
+        })
+        .collect()
+}
+
+/// Cap one authored karma delta. Returns the delta to apply.
+pub fn cap_karma_delta(world: &mut WorldState, delta: f32) -> f32 {
+    let config = world.delta_caps.config;
+    let tick = world.current_tick.0;
+    world.delta_caps.cap(
+        tick,
+        "karma".to_string(),
+        delta,
+        config.karma_per_event,
+        config.karma_per_day,
+    )
+}
+
+/// Cap one authored relationship delta on `actor`'s view of `target`.
+/// Returns the delta to apply.
+pub fn cap_relationship_delta(
+    world: &mut WorldState,
+    actor_id: u64,
+    target_id: u64,
+    axis: RelationshipAxis,
+    delta: f32,
+) -> f32 {
+    let config = world.delta_caps.config;
+    let tick = world.current_tick.0;
+    let target = format!("relationship:{}:{}:{}", actor_id, target_id, axis_name(axis));
+    world.delta_caps.cap(
+        tick,
+        target,
+        delta,
+        config.relationship_per_event,
+        config.relationship_per_day,
+    )
+}
+
+/// Cap a list of authored relationship deltas, in order.
+pub fn cap_relationship_deltas(
+    world: &mut WorldState,
+    deltas: &[RelationshipDelta],
+) -> Vec<RelationshipDelta> {
+    deltas
+        .iter()
+        .map(|d| RelationshipDelta {
+            delta: cap_relationship_delta(world, d.actor_id, d.target_id, d.axis, d.delta),
+            ..d.clone()
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::types::{NpcId, SimTick, WorldSeed};
+
+    #[test]
+    fn typo_deltas_are_clamped_and_logged() {
+        let mut world = WorldState::new(WorldSeed(5), NpcId(1));
+        assert!((cap_stat_delta(&mut world, StatKind::Health, -200.0) + 50.0).abs() < 1e-6);
+        assert!((cap_stat_delta(&mut world, StatKind::Mood, 8.0) - 8.0).abs() < 1e-6);
+        assert!((cap_karma_delta(&mut world, 5.0) - 5.0).abs() < 1e-6);
+        assert!(
+            (cap_relationship_delta(&mut world, 1, 2, RelationshipAxis::Trust, 30.0) - 10.0).abs()
+                < 1e-6
+        );
+
+        let warnings = &world.delta_caps.warnings;
+        assert_eq!(warnings.len(), 2);
+        assert_eq!(warnings[0].target, "stat:health");
+        assert_eq!(warnings[0].cap, DeltaCapKind::PerEvent);
+        assert!((warnings[0].requested + 200.0).abs() < 1e-6);
+        assert_eq!(warnings[1].target, "relationship:1:2:trust");
+    }
+
+    #[test]
+    fn daily_budget_is_per_target_and_resets_each_day() {
+        let mut world = WorldState::new(WorldSeed(5), NpcId(1));
+        world.delta_caps.config.relationship_per_day = 12.0;
+        let trust = |world: &mut WorldState| {
+            cap_relationship_delta(world, 1, 2, RelationshipAxis::Trust, -8.0)
+        };
+        assert!((trust(&mut world) + 8.0).abs() < 1e-6);
+        assert!((trust(&mut world) + 4.0).abs() < 1e-6);
+        assert!(trust(&mut world).abs() < 1e-6);
+        // Other axes and pairs have their own budgets.
+        assert!(
+            (cap_relationship_delta(&mut world, 1, 3, RelationshipAxis::Trust, -8.0) + 8.0).abs()
+                < 1e-6
+        );
+        assert_eq!(
+            world.delta_caps.warnings.last().map(|w| w.cap),
+            Some(DeltaCapKind::PerDay)
+        );
+
+        world.current_tick = SimTick(24);
+        assert!((trust(&mut world) + 8.0).abs() < 1e-6);
+    }
+
+    #[test]
+    fn disabled_caps_pass_deltas_through() {
+        let mut world = WorldState::new(WorldSeed(5), NpcId(1));
+        world.delta_caps.config.enabled = false;
+        assert!((cap_stat_delta(&mut world, StatKind::Wealth, 400.0) - 400.0).abs() < 1e-6);
+        assert!(world.delta_caps.warnings.is_empty());
+    }
+}
@@ -0,0 +1,499 @@
+//! Dependents the player looks after: pets, plants and younger siblings.
+//!
+//! Each dependent has a care `need` (0 = just tended, 1 = desperate) that
+//! builds every tick at a rate set by its kind. Once it passes
+//! `needs_care_threshold` the dynamic world flag from [`needs_care_flag`]
+//! goes up, so storylets tagged [`DEPENDENT_TAG`] and the kind's tag
+//! (`pet`, `plant`, `younger_sibling`) can prompt the player, and the
+//! director leans toward storylets carrying that kind's tag.
+//!
+//! Caring for a dependent ([`care_for_dependent`]) costs action points,
+//! resets its need, lifts the player's mood and karma a little and improves
+//! its wellbeing. Once a day every dependent left past `neglect_threshold`
+//! is neglected: mood and karma drop and its wellbeing falls. A pet or plant
+//! whose wellbeing reaches zero is lost (it runs away or wilts); a younger
+//! sibling can't be lost.
+//!
+//! Adoptions, care, neglect and losses are logged in
+//! [`DependentState::recent`] and leave a player memory tagged
+//! [`DEPENDENT_TAG`], the kind and what happened.
+
+use serde::{Deserialize, Serialize};
+
+use crate::action_budget::PLAYER_ACTION_COST;
+use crate::errors::{Result, SynError};
+use crate::flag_changes::{set_world_flag, FLAG_SOURCE_DEPENDENT};
+use crate::npc_mood::shift_mood;
+use crate::types::{MemoryEntryRecord, WorldState};
+
+/// Tag added to dependent memories; storylets about dependents carry it too.
+pub const DEPENDENT_TAG: &str = "dependent";
+
+/// Prefix of the dynamic world flag raised while a dependent needs care
+/// (`dependent_needs_care:<dependent_id>`).
+pub const NEEDS_CARE_FLAG_PREFIX: &str = "dependent_needs_care:";
+
+/// Dynamic world flag marking that dependent `id` needs care.
+pub fn needs_care_flag(id: u64) -> String {
+    format!("{}{}", NEEDS_CARE_FLAG_PREFIX, id)
+}
+
+/// What kind of dependent the player looks after.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum DependentKind {
+    /// A pet: needs feeding and attention several times a day.
+    Pet,
+    /// A houseplant: needs watering every few days.
+    Plant,
+    /// A younger sibling: needs time with the player most days.
+    YoungerSibling,
+}
+
+impl DependentKind {
+    /// All kinds.
+    pub const ALL: [DependentKind; 3] = [
+        DependentKind::Pet,
+        DependentKind::Plant,
+        DependentKind::YoungerSibling,
+    ];
+
+    /// Stable lowercase label for UI/FFI; also the kind's storylet tag.
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            DependentKind::Pet => "pet",
+            DependentKind::Plant => "plant",
+            DependentKind::YoungerSibling => "younger_sibling",
+        }
+    }
+
+    /// Parse a label produced by [`DependentKind::as_str`].
+    pub fn parse(label: &str) -> Option<Self> {
+        Self::ALL
+            .into_iter()
+            .find(|kind| kind.as_str().eq_ignore_ascii_case(label))
+    }
+
+    /// Whether neglect can end with the dependent gone.
+    pub fn can_be_lost(&self) -> bool {
+        !matches!(self, DependentKind::YoungerSibling)
+    }
+}
+
+/// What happened to a dependent.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum DependentEventKind {
+    /// The player took them in.
+    Adopted,
+    /// The player looked after them.
+    Cared,
+    /// A day went by without the care they needed.
+    Neglected,
+    /// Neglect drove them away for good.
+    Lost,
+}
+
+impl DependentEventKind {
+    /// Stable lowercase label for UI/FFI.
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            DependentEventKind::Adopted => "adopted",
+            DependentEventKind::Cared => "cared",
+            DependentEventKind::Neglected => "neglected",
+            DependentEventKind::Lost => "lost",
+        }
+    }
+
+    /// Emotional intensity of the player's memory.
+    fn intensity(&self) -> f32 {
+        match self {
+            DependentEventKind::Adopted => 0.4,
+            DependentEventKind::Cared => 0.1,
+            DependentEventKind::Neglected => -0.3,
+            DependentEventKind::Lost => -0.8,
+        }
+    }
+}
+
+/// Need rates and care/neglect outcomes.
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct DependentConfig {
+    /// Need gained per tick by a pet.
+    pub pet_need_per_tick: f32,
+    /// Need gained per tick by a plant.
+    pub plant_need_per_tick: f32,
+    /// Need gained per tick by a younger sibling.
+    pub sibling_need_per_tick: f32,
+    /// Need at which the needs-care flag goes up.
+    pub needs_care_threshold: f32,
+    /// Need at which the daily check counts the dependent as neglected.
+    pub neglect_threshold: f32,
+    /// Action points spent caring for a dependent.
+    pub care_action_cost: f32,
+    /// Player mood change from caring.
+    pub care_mood: f32,
+    /// Karma change from caring.
+    pub care_karma: f32,
+    /// Wellbeing gained from caring.
+    pub care_wellbeing: f32,
+    /// Player mood change per day of neglect.
+    pub neglect_mood: f32,
+    /// Karma change per day of neglect.
+    pub neglect_karma: f32,
+    /// Wellbeing lost per day of neglect.
+    pub neglect_wellbeing: f32,
+    /// Player mood change when a dependent is lost.
+    pub loss_mood: f32,
+    /// Events kept in `recent`.
+    pub max_recent: usize,
+}
+
+impl Default for DependentConfig {
+    fn default() -> Self {
+        Self {
+            pet_need_per_tick: 0.04,
+            plant_need_per_tick: 0.01,
+            sibling_need_per_tick: 0.025,
+            needs_care_threshold: 0.5,
+            neglect_threshold: 0.9,
+            care_action_cost: PLAYER_ACTION_COST,
+            care_mood: 0.5,
+            care_karma: 0.5,
+            care_wellbeing: 0.05,
+            neglect_mood: -1.0,
+            neglect_karma: -1.0,
+            neglect_wellbeing: 0.2,
+            loss_mood: -2.0,
+            max_recent: 20,
+        }
+    }
+}
+
+impl DependentConfig {
+    /// Need gained per tick by a dependent of `kind`.
+    pub fn need_per_tick(&self, kind: DependentKind) -> f32 {
+        match kind {
+            DependentKind::Pet => self.pet_need_per_tick,
+            DependentKind::Plant => self.plant_need_per_tick,
+            DependentKind::YoungerSibling => self.sibling_need_per_tick,
+        }
+    }
+}
+
+/// A pet, plant or younger sibling in the player's care.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct Dependent {
+    /// Sequential dependent id.
+    pub id: u64,
+    /// What they are.
+    pub kind: DependentKind,
+    /// Display name.
+    pub name: String,
+    /// How badly they need care (0..1).
+    pub need: f32,
+    /// How well they are doing overall (0..1).
+    pub wellbeing: f32,
+    /// Tick they were taken in.
+    pub adopted_tick: u64,
+    /// Tick they were last cared for.
+    #[serde(default)]
+    pub last_cared_tick: Option<u64>,
+    /// Tick they were lost, if they were.
+    #[serde(default)]
+    pub lost_tick: Option<u64>,
+}
+
+impl Dependent {
+    /// Whether neglect has already driven them away.
+    pub fn is_lost(&self) -> bool {
+        self.lost_tick.is_some()
+    }
+
+    /// Whether their need has passed the needs-care threshold.
+    pub fn needs_care(&self, config: &DependentConfig) -> bool {
+        !self.is_lost() && self.need >= config.needs_care_threshold
+    }
+}
+
+/// One thing that happened to a dependent.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct DependentEvent {
+    /// Sequential event id.
+    pub id: u64,
+    /// The dependent it happened to.
+    pub dependent_id: u64,
+    /// What happened.
+    pub kind: DependentEventKind,
+    /// Tick it happened.
+    pub tick: u64,
+}
+
+/// Dependent bookkeeping stored on `WorldState`.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct DependentState {
+    /// Need rates and outcomes.
+    #[serde(default)]
+    pub config: DependentConfig,
+    /// Every dependent the player has taken in, lost ones included.
+    #[serde(default)]
+    pub dependents: Vec<Dependent>,
+    /// Most recent events, oldest first.
+    #[serde(default)]
+    pub recent: Vec<DependentEvent>,
+    /// Next dependent id.
+    #[serde(default)]
+    next_id: u64,
+    /// Next event id.
+    #[serde(default)]
+    next_event_id: u64,
+}
+
+impl DependentState {
+    /// Look up a dependent by id.
+    pub fn get(&self, id: u64) -> Option<&Dependent> {
+        self.dependents.iter().find(|d| d.id == id)
+    }
+
+    /// Dependents still in the player's care.
+    pub fn active(&self) -> impl Iterator<Item = &Dependent> {
+        self.dependents.iter().filter(|d| !d.is_lost())
+    }
+
+    /// Whether one of `tags` is the kind of a dependent that needs care.
+    pub fn favors<'a>(&self, tags: impl IntoIterator<Item = &'a str>) -> bool {
+        let kinds: Vec<DependentKind> = tags.into_iter().filter_map(DependentKind::parse).collect();
+        self.dependents
+            .iter()
+            .any(|d| d.needs_care(&self.config) && kinds.contains(&d.kind))
+    }
+
+    fn get_mut(&mut self, id: u64) -> Option<&mut Dependent> {
+        self.dependents.iter_mut().find(|d| d.id == id)
+    }
+}
+
+/// Take a new dependent into the player's care. Returns its id.
+pub fn adopt_dependent(world: &mut WorldState, kind: DependentKind, name: &str) -> u64 {
+    let tick = world.current_tick.0;
+    let state = &mut world.dependents;
+    let id = state.next_id;
+    state.next_id += 1;
+    state.dependents.push(Dependent {
+        id,
+        kind,
+        name: name.to_string(),
+        need: 0.0,
+        wellbeing: 0.75,
+        adopted_tick: tick,
+        last_cared_tick: None,
+        lost_tick: None,
+    });
+    record_event(world, id, DependentEventKind::Adopted);
+    id
+}
+
+/// Look after dependent `id`, spending `care_action_cost` action points.
+///
+/// Fails if there is no such dependent, it has been lost, or the player is
+/// out of energy today.
+pub fn care_for_dependent(world: &mut WorldState, id: u64) -> Result<DependentEvent> {
+    let config = world.dependents.config;
+    let dependent = world
+        .dependents
+        .get(id)
+        .ok_or_else(|| SynError::NotFound(format!("dependent {}", id)))?;
+    if dependent.is_lost() {
+        return Err(SynError::InvalidState(format!("dependent {} was lost", id)));
+    }
+    if !world.action_budget.try_spend(config.care_action_cost) {
+        return Err(SynError::InvalidState("out of energy today".to_string()));
+    }
+
+    let tick = world.current_tick.0;
+    let player = world.player_id;
+    if let Some(dependent) = world.dependents.get_mut(id) {
+        dependent.need = 0.0;
+        dependent.wellbeing = (dependent.wellbeing + config.care_wellbeing).min(1.0);
+        dependent.last_cared_tick = Some(tick);
+    }
+    set_world_flag(world, &needs_care_flag(id), false, FLAG_SOURCE_DEPENDENT);
+    shift_mood(world, player, config.care_mood);
+    world.player_karma.apply_delta(config.care_karma);
+    Ok(record_event(world, id, DependentEventKind::Cared))
+}
+
+/// Per-tick pass: needs build, and dependents that need care raise their flag.
+pub fn tick_dependents(world: &mut WorldState) {
+    let config = world.dependents.config;
+    let mut needy = Vec::new();
+    for dependent in world.dependents.dependents.iter_mut() {
+        if dependent.is_lost() {
+            continue;
+        }
+        dependent.need = (dependent.need + config.need_per_tick(dependent.kind)).min(1.0);
+        if dependent.needs_care(&config) {
+            needy.push(dependent.id);
+        }
+    }
+    for id in needy {
+        set_world_flag(world, &needs_care_flag(id), true, FLAG_SOURCE_DEPENDENT);
+    }
+}
+
+/// Daily pass: dependents past `neglect_threshold` are neglected, and may be
+/// lost. Returns the events recorded.
+pub fn check_dependent_neglect(world: &mut WorldState) -> Vec<DependentEvent> {
+    let config = world.dependents.config;
+    let neglected: Vec<u64> = world
+        .dependents
+        .active()
+        .filter(|d| d.need >= config.neglect_threshold)
+        .map(|d| d.id)
+        .collect();
+
+    let tick = world.current_tick.0;
+    let player = world.player_id;
+    let mut events = Vec::new();
+    for id in neglected {
+        let Some(dependent) = world.dependents.get_mut(id) else {
+            continue;
+        };
+        dependent.wellbeing = (dependent.wellbeing - config.neglect_wellbeing).max(0.0);
+        let lost = dependent.wellbeing <= 0.0 && dependent.kind.can_be_lost();
+        if lost {
+            dependent.lost_tick = Some(tick);
+        }
+
+        shift_mood(world, player, config.neglect_mood);
+        world.player_karma.apply_delta(config.neglect_karma);
+        events.push(record_event(world, id, DependentEventKind::Neglected));
+        if lost {
+            shift_mood(world, player, config.loss_mood);
+            set_world_flag(world, &needs_care_flag(id), false, FLAG_SOURCE_DEPENDENT);
+            events.push(record_event(world, id, DependentEventKind::Lost));
+        }
+    }
+    events
+}
+
+/// Log an event and leave the player's memory of it.
+fn record_event(
+    world: &mut WorldState,
+    dependent_id: u64,
+    kind: DependentEventKind,
+) -> DependentEvent {
+    let tick = world.current_tick.0;
+    let player = world.player_id;
+    let config = world.dependents.config;
+    let dependent_kind = world
+        .dependents
+        .get(dependent_id)
+        .map(|d| d.kind)
+        .unwrap_or(DependentKind::Pet);
+    let id = world.dependents.next_event_id;
+    world.dependents.next_event_id += 1;
+
+    world.memory_entries.push(MemoryEntryRecord {
+        id: format!("dependent:{}", id),
+        event_id: format!(
+            "{}:{}:{}",
+            DEPENDENT_TAG,
+            dependent_kind.as_str(),
+            kind.as_str()
+        ),
+        npc_id: player,
+        sim_tick: world.current_tick,
+        emotional_intensity: kind.intensity(),
+        tags: vec![
+            DEPENDENT_TAG.to_string(),
+            dependent_kind.as_str().to_string(),
+            kind.as_str().to_string(),
+        ],
+        participants: vec![player.0],
+        ..Default::default()
+    });
+
+    let event = DependentEvent {
+        id,
+        dependent_id,
+        kind,
+        tick,
+    };
+    let state = &mut world.dependents;
+    state.recent.push(event.clone());
+    let overflow = state.recent.len().saturating_sub(config.max_recent);
+    state.recent.drain(..overflow);
+    event
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::stats::StatKind;
+    use crate::types::{NpcId, WorldSeed};
+
+    #[test]
+    fn needs_build_until_the_flag_goes_up_and_care_resets_them() {
+        let mut world = WorldState::new(WorldSeed(3), NpcId(1));
+        let id = adopt_dependent(&mut world, DependentKind::Pet, "Biscuit");
+        let flag = needs_care_flag(id);
+
+        for _ in 0..12 {
+            tick_dependents(&mut world);
+        }
+        assert!(!world.world_flags.has_any(&flag));
+        for _ in 0..13 {
+            tick_dependents(&mut world);
+        }
+        assert!(world.world_flags.has_any(&flag));
+        assert!(world.dependents.favors(["pet"]));
+        assert!(!world.dependents.favors(["plant"]));
+
+        let mood = world.player_stats.get(StatKind::Mood);
+        let event = care_for_dependent(&mut world, id).expect("care");
+        assert_eq!(event.kind, DependentEventKind::Cared);
+        assert!(!world.world_flags.has_any(&flag));
+        assert!(world.player_stats.get(StatKind::Mood) > mood);
+        assert!(world.player_karma.0 > 0.0);
+        let pet = world.dependents.get(id).unwrap();
+        assert!(pet.need < 1e-6 && pet.wellbeing > 0.75);
+        let memory = world.memory_entries.last().unwrap();
+        assert_eq!(memory.event_id, "dependent:pet:cared");
+    }
+
+    #[test]
+    fn neglected_plants_wilt_but_siblings_stay() {
+        let mut world = WorldState::new(WorldSeed(3), NpcId(1));
+        let plant = adopt_dependent(&mut world, DependentKind::Plant, "Fern");
+        let sibling = adopt_dependent(&mut world, DependentKind::YoungerSibling, "Milo");
+        for dependent in world.dependents.dependents.iter_mut() {
+            dependent.need = 1.0;
+        }
+
+        for _ in 0..3 {
+            check_dependent_neglect(&mut world);
+        }
+        assert!(!world.dependents.get(plant).unwrap().is_lost());
+        let events = check_dependent_neglect(&mut world);
+        assert!(events
+            .iter()
+            .any(|e| e.dependent_id == plant && e.kind == DependentEventKind::Lost));
+        assert!(world.dependents.get(plant).unwrap().is_lost());
+        assert!(!world.dependents.get(sibling).unwrap().is_lost());
+        assert!(world.player_karma.0 < 0.0);
+        assert!(care_for_dependent(&mut world, plant).is_err());
+        assert_eq!(world.dependents.active().count(), 1);
+    }
+
+    #[test]
+    fn caring_costs_energy() {
+        let mut world = WorldState::new(WorldSeed(3), NpcId(1));
+        let id = adopt_dependent(&mut world, DependentKind::Pet, "Biscuit");
+        world.action_budget.points = 0.0;
+        assert!(care_for_dependent(&mut world, id).is_err());
+        assert!(world.dependents.get(id).unwrap().last_cared_tick.is_none());
+        assert!(care_for_dependent(&mut world, 99).is_err());
+    }
+}
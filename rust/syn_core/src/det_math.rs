@@ -0,0 +1,329 @@
+//! Platform-independent float math for the deterministic core.
+//!
+//! The same seed must produce the same world on mobile and desktop. Rust's
+//! f32 `+ - * /`, `floor`, `round` and `abs` are exactly specified by IEEE 754
+//! and give identical bits everywhere, and rustc never fuses `a * b + c` into
+//! an FMA on its own. What does differ is the system libm behind `powf`,
+//! `exp`, `ln` and friends: glibc, bionic and Apple's libm may round the last
+//! bit differently, and a one-ulp difference in a decay factor compounds over
+//! thousands of ticks.
+//!
+//! Audit of the hot float paths (scoring, drift, heat, decay):
+//!
+//! - Gossip decay, per-pair relationship heat decay and memory intensity
+//!   trait curves were the only transcendental calls; they now use
+//!   [`half_life_factor`] and [`powf`] from this module, which are built
+//!   from exactly-rounded operations only.
+//! - Narrative heat, relationship drift, mood contagion and director scoring
+//!   are plain arithmetic over values visited in a fixed order (id-sorted
+//!   maps or sorted lists).
+//!
+//! On top of that, [`FloatMode::Quantized`] snaps the long-lived
+//! accumulators to a grid of [`QUANTUM`] after every tick (see
+//! [`quantize_accumulators`]), so rounding noise from any path the audit
+//! missed is absorbed instead of compounding. [`accumulator_digest`] hashes
+//! the same accumulators bit for bit for cross-platform golden tests.
+
+use std::f32::consts::{LN_2, SQRT_2};
+
+use serde::{Deserialize, Serialize};
+
+use crate::types::WorldState;
+
+/// Spacing of the quantized accumulation grid (1/4096, exact in binary).
+pub const QUANTUM: f32 = 1.0 / 4096.0;
+
+/// Taylor terms of `2^f = e^(f ln 2)` on `[0, 1)`, lowest order first.
+const EXP2_TERMS: [f32; 9] = [
+    1.0,
+    LN_2,
+    0.2402265,
+    0.05550411,
+    0.009618129,
+    0.0013333558,
+    0.0001540353,
+    1.5252734e-5,
+    1.3215487e-6,
+];
+
+/// Terms of `log2(m) = 2/ln 2 * atanh(t)` in powers of `t^2`, where
+/// `t = (m - 1) / (m + 1)`.
+const LOG2_TERMS: [f32; 5] = [2.88539, 0.9617967, 0.57707804, 0.41219857, 0.3205989];
+
+/// How accumulated floats are stored between ticks.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub enum FloatMode {
+    /// Full f32 precision.
+    #[default]
+    Native,
+    /// Accumulators are rounded to multiples of [`QUANTUM`] after every tick.
+    Quantized,
+}
+
+/// `2^x`, identical on every platform.
+///
+/// Results that would be subnormal are flushed to zero.
+pub fn exp2(x: f32) -> f32 {
+    if x.is_nan() {
+        return x;
+    }
+    if x >= 128.0 {
+        return f32::INFINITY;
+    }
+    if x < -126.0 {
+        return 0.0;
+    }
+    let whole = x.floor();
+    let frac = x - whole;
+    let poly = EXP2_TERMS.iter().rev().fold(0.0f32, |acc, &c| acc * frac + c);
+    let scale = f32::from_bits(((whole as i32 + 127) as u32) << 23);
+    poly * scale
+}
+
+/// `log2(x)`, identical on every platform.
+///
+/// Zero and subnormal inputs give negative infinity; negative inputs give NaN.
+pub fn log2(x: f32) -> f32 {
+    if x.is_nan() || x < 0.0 {
+        return f32::NAN;
+    }
+    if x < f32::MIN_POSITIVE {
+        return f32::NEG_INFINITY;
+    }
+    if x.is_infinite() {
+        return x;
+    }
+    let bits = x.to_bits();
+    let mut exponent = (bits >> 23) as i32 - 127;
+    let mut mantissa = f32::from_bits((bits & 0x007f_ffff) | 0x3f80_0000);
+    if mantissa > SQRT_2 {
+        mantissa *= 0.5;
+        exponent += 1;
+    }
+    let t = (mantissa - 1.0) / (mantissa + 1.0);
+    let t2 = t * t;
+    let series = LOG2_TERMS.iter().rev().fold(0.0f32, |acc, &c| acc * t2 + c);
+    exponent as f32 + t * series
+}
+
+/// `base^exponent` for non-negative `base`, identical on every platform.
+///
+/// A zero (or negative) base gives 0 for positive exponents, infinity for
+/// negative ones and 1 for a zero exponent, matching `f32::powf` at zero.
+pub fn powf(base: f32, exponent: f32) -> f32 {
+    if base > 0.0 {
+        return exp2(exponent * log2(base));
+    }
+    if exponent > 0.0 {
+        0.0
+    } else if exponent < 0.0 {
+        f32::INFINITY
+    } else {
+        1.0
+    }
+}
+
+/// Share of a value left after `elapsed` time with the given `half_life`:
+/// `0.5^(elapsed / half_life)`.
+pub fn half_life_factor(elapsed: f32, half_life: f32) -> f32 {
+    exp2(-(elapsed / half_life))
+}
+
+/// Round `value` to the nearest multiple of [`QUANTUM`].
+pub fn quantize(value: f32) -> f32 {
+    // Adding zero turns -0.0 into 0.0, so both zeros hash the same
+    (value / QUANTUM).round() * QUANTUM + 0.0
+}
+
+/// Snap the world's long-lived float accumulators to the [`QUANTUM`] grid
+/// when the world runs in [`FloatMode::Quantized`]; a no-op otherwise.
+///
+/// Covers player stats, karma, narrative heat and momentum, relationship
+/// axes, NPC moods and per-pair relationship heat.
+pub fn quantize_accumulators(world: &mut WorldState) {
+    if world.float_mode != FloatMode::Quantized {
+        return;
+    }
+    let stats = &mut world.player_stats;
+    for value in [
+        &mut stats.health,
+        &mut stats.intelligence,
+        &mut stats.charisma,
+        &mut stats.wealth,
+        &mut stats.mood,
+        &mut stats.appearance,
+        &mut stats.reputation,
+        &mut stats.wisdom,
+    ] {
+        *value = quantize(*value);
+    }
+    for value in [&mut stats.curiosity, &mut stats.energy, &mut stats.libido]
+        .into_iter()
+        .flatten()
+    {
+        *value = quantize(*value);
+    }
+    world.player_karma.0 = quantize(world.player_karma.0);
+    world.narrative_heat.set(quantize(world.narrative_heat.value()));
+    world.heat_momentum = quantize(world.heat_momentum);
+    for rel in world.relationships.values_mut() {
+        rel.affection = quantize(rel.affection);
+        rel.trust = quantize(rel.trust);
+        rel.attraction = quantize(rel.attraction);
+        rel.familiarity = quantize(rel.familiarity);
+        rel.resentment = quantize(rel.resentment);
+    }
+    world.npc_moods.map_values(quantize);
+    for pair in &mut world.relationship_heat.pairs {
+        pair.heat = quantize(pair.heat);
+        pair.baseline = quantize(pair.baseline);
+    }
+}
+
+/// FNV-1a hash of the current tick and the accumulators covered by
+/// [`quantize_accumulators`], over their exact bit patterns.
+///
+/// Relationships are hashed in id order, so the digest does not depend on
+/// HashMap iteration order. Golden tests compare it across platforms.
+pub fn accumulator_digest(world: &WorldState) -> u64 {
+    let mut digest = Fnv1a::default();
+    digest.write_u64(world.current_tick.0);
+    let stats = &world.player_stats;
+    for value in [
+        stats.health,
+        stats.intelligence,
+        stats.charisma,
+        stats.wealth,
+        stats.mood,
+        stats.appearance,
+        stats.reputation,
+        stats.wisdom,
+    ] {
+        digest.write_f32(value);
+    }
+    for value in [stats.curiosity, stats.energy, stats.libido] {
+        digest.write_f32(value.unwrap_or(f32::NAN));
+    }
+    digest.write_f32(world.player_karma.0);
+    digest.write_f32(world.narrative_heat.value());
+    digest.write_f32(world.heat_momentum);
+
+    let mut relationships: Vec<_> = world.relationships.iter().collect();
+    relationships.sort_by_key(|(key, _)| **key);
+    for ((from, to), rel) in relationships {
+        digest.write_u64(from.0);
+        digest.write_u64(to.0);
+        for value in [
+            rel.affection,
+            rel.trust,
+            rel.attraction,
+            rel.familiarity,
+            rel.resentment,
+        ] {
+            digest.write_f32(value);
+        }
+    }
+    for (npc, mood) in world.npc_moods.iter() {
+        digest.write_u64(npc.0);
+        digest.write_f32(mood);
+    }
+    for pair in &world.relationship_heat.pairs {
+        digest.write_u64(pair.pair.0);
+        digest.write_u64(pair.pair.1);
+        digest.write_f32(pair.heat);
+        digest.write_f32(pair.baseline);
+    }
+    digest.0
+}
+
+/// 64-bit FNV-1a, fixed across platforms and Rust versions (unlike
+/// `DefaultHasher`).
+struct Fnv1a(u64);
+
+impl Default for Fnv1a {
+    fn default() -> Self {
+        Fnv1a(0xcbf2_9ce4_8422_2325)
+    }
+}
+
+impl Fnv1a {
+    fn write(&mut self, bytes: &[u8]) {
+        for &byte in bytes {
+            self.0 ^= u64::from(byte);
+            self.0 = self.0.wrapping_mul(0x0000_0100_0000_01b3);
+        }
+    }
+
+    fn write_u64(&mut self, value: u64) {
+        self.write(&value.to_le_bytes());
+    }
+
+    fn write_f32(&mut self, value: f32) {
+        self.write(&value.to_bits().to_le_bytes());
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::types::{NpcId, Relationship, WorldSeed};
+
+    #[test]
+    fn matches_std_within_float_tolerance() {
+        for i in -400..=400 {
+            let x = i as f32 * 0.05;
+            let expected = x.exp2();
+            assert!(
+                (exp2(x) - expected).abs() <= expected * 1e-6,
+                "exp2({}) = {} vs {}",
+                x,
+                exp2(x),
+                expected
+            );
+        }
+        for i in 1..=1000 {
+            let x = i as f32 * 0.013;
+            assert!((log2(x) - x.log2()).abs() < 1e-6, "log2({})", x);
+            let expected = x.powf(1.7);
+            assert!((powf(x, 1.7) - expected).abs() <= expected * 2e-6, "powf({})", x);
+        }
+    }
+
+    #[test]
+    fn exact_cases_stay_exact() {
+        assert_eq!(exp2(0.0).to_bits(), 1.0f32.to_bits());
+        assert_eq!(exp2(-1.0).to_bits(), 0.5f32.to_bits());
+        assert_eq!(log2(8.0).to_bits(), 3.0f32.to_bits());
+        assert_eq!(half_life_factor(48.0, 24.0).to_bits(), 0.25f32.to_bits());
+        assert_eq!(powf(0.0, 2.0).to_bits(), 0.0f32.to_bits());
+        assert_eq!(powf(0.0, 0.0).to_bits(), 1.0f32.to_bits());
+        assert!(log2(-1.0).is_nan());
+        assert_eq!(exp2(-200.0).to_bits(), 0.0f32.to_bits());
+    }
+
+    #[test]
+    fn quantized_worlds_snap_accumulators_to_the_grid() {
+        let mut world = WorldState::new(WorldSeed(3), NpcId(1));
+        world.player_stats.mood = 1.000_01;
+        world.set_relationship(
+            NpcId(1),
+            NpcId(2),
+            Relationship {
+                affection: 3.333_33,
+                ..Default::default()
+            },
+        );
+        let native_digest = accumulator_digest(&world);
+
+        quantize_accumulators(&mut world);
+        assert_eq!(accumulator_digest(&world), native_digest);
+
+        world.float_mode = FloatMode::Quantized;
+        quantize_accumulators(&mut world);
+        assert_eq!(world.player_stats.mood.to_bits(), 1.0f32.to_bits());
+        let affection = world.get_relationship(NpcId(1), NpcId(2)).affection;
+        assert_eq!(affection.to_bits(), quantize(affection).to_bits());
+        assert!((affection - 3.333_33).abs() <= QUANTUM / 2.0);
+        assert_ne!(accumulator_digest(&world), native_digest);
+    }
+}
@@ -0,0 +1,294 @@
+//! Dialogue tone context: how an NPC's history with the player colors a scene.
+//!
+//! A friendly storylet should read differently with someone the player once
+//! betrayed. [`tone_context`] derives a per-pair [`ToneContext`] from how the
+//! NPC feels about the player (grudge from resentment, favor from affection
+//! and trust) and the memories the two share:
+//!
+//! - recent shared memories tilt grudge or favor by their emotional
+//!   intensity, fading linearly over `memory_window_ticks`,
+//! - a betrayal is never forgotten: any shared memory tagged with one of
+//!   [`BETRAYAL_TAGS`] raises the `remembers_betrayal` flag and keeps the
+//!   tone at least guarded, however the NPC feels otherwise,
+//! - recent kindness ([`KINDNESS_TAGS`]) raises `remembers_kindness`; with
+//!   favor clearly ahead of grudge it lets a remembered betrayal soften.
+//!
+//! The context is a pure function of the world. The director attaches it to
+//! event views and ambient beats carry it, so the UI can tint presentation
+//! without separate content variants.
+
+use serde::{Deserialize, Serialize};
+
+use crate::types::{MemoryEntryRecord, NpcId, WorldState};
+
+/// Memory tags that mark the player betraying an NPC (or the reverse).
+pub const BETRAYAL_TAGS: &[&str] = &["betrayal", "betrayer", "trust_broken"];
+
+/// Memory tags that mark the player doing right by an NPC.
+pub const KINDNESS_TAGS: &[&str] = &["support", "kindness", "help", "forgiveness", "loyalty"];
+
+/// Flag raised when the pair shares a betrayal memory.
+pub const TONE_FLAG_REMEMBERS_BETRAYAL: &str = "remembers_betrayal";
+/// Flag raised when the pair shares a recent kindness memory.
+pub const TONE_FLAG_REMEMBERS_KINDNESS: &str = "remembers_kindness";
+/// Flag raised while the NPC holds a grudge.
+pub const TONE_FLAG_GRUDGE: &str = "grudge";
+/// Flag raised while the NPC is fond of the player.
+pub const TONE_FLAG_FOND: &str = "fond";
+
+/// How an NPC's lines toward the player should read.
+#[derive(
+    Debug, Clone, Copy, Default, PartialEq, Eq, PartialOrd, Ord, Hash, Serialize, Deserialize,
+)]
+#[serde(rename_all = "snake_case")]
+pub enum DialogueTone {
+    /// Open and affectionate.
+    Warm,
+    /// No strong feelings either way.
+    #[default]
+    Neutral,
+    /// Polite but careful; old wounds show.
+    Guarded,
+    /// Openly resentful.
+    Bitter,
+}
+
+impl DialogueTone {
+    /// Stable lowercase label for UI/FFI.
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            DialogueTone::Warm => "warm",
+            DialogueTone::Neutral => "neutral",
+            DialogueTone::Guarded => "guarded",
+            DialogueTone::Bitter => "bitter",
+        }
+    }
+}
+
+/// Thresholds and weights for deriving a tone.
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct DialogueToneConfig {
+    /// Shared memories older than this no longer tilt grudge or favor.
+    pub memory_window_ticks: u64,
+    /// How much recent memories (summed, -1..1) shift grudge or favor.
+    pub memory_weight: f32,
+    /// Grudge at which the NPC is bitter.
+    pub bitter_grudge: f32,
+    /// Grudge at which the NPC is guarded.
+    pub guarded_grudge: f32,
+    /// Favor at which the NPC is warm.
+    pub warm_favor: f32,
+    /// Margin favor must hold over grudge, alongside recent kindness, before
+    /// a remembered betrayal stops keeping the tone guarded.
+    pub betrayal_forgiveness_margin: f32,
+}
+
+impl Default for DialogueToneConfig {
+    fn default() -> Self {
+        Self {
+            memory_window_ticks: 24 * 30,
+            memory_weight: 0.5,
+            bitter_grudge: 0.6,
+            guarded_grudge: 0.3,
+            warm_favor: 0.55,
+            betrayal_forgiveness_margin: 0.4,
+        }
+    }
+}
+
+/// How `npc_id` feels about the player right now, for presentation.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct ToneContext {
+    /// The NPC speaking.
+    pub npc_id: NpcId,
+    /// Overall tone.
+    pub tone: DialogueTone,
+    /// Grudge against the player (0..1).
+    pub grudge: f32,
+    /// Favor toward the player (0..1).
+    pub favor: f32,
+    /// Presentation flags (`remembers_betrayal`, `remembers_kindness`,
+    /// `grudge`, `fond`), in that order.
+    pub flags: Vec<String>,
+}
+
+impl ToneContext {
+    /// Whether `flag` is raised.
+    pub fn has_flag(&self, flag: &str) -> bool {
+        self.flags.iter().any(|f| f == flag)
+    }
+}
+
+/// Tone context for `npc_id` toward the player with default tuning.
+pub fn tone_context(world: &WorldState, npc_id: NpcId) -> ToneContext {
+    tone_context_with(world, npc_id, &DialogueToneConfig::default())
+}
+
+/// Tone context for `npc_id` toward the player.
+pub fn tone_context_with(
+    world: &WorldState,
+    npc_id: NpcId,
+    config: &DialogueToneConfig,
+) -> ToneContext {
+    let player = world.player_id;
+    let tick = world.current_tick.0;
+    let rel = world.get_relationship(npc_id, player);
+
+    let mut recent_valence = 0.0_f32;
+    let mut betrayed = false;
+    let mut kindness = false;
+    for memory in world
+        .memory_entries
+        .iter()
+        .filter(|m| is_shared(m, player, npc_id))
+    {
+        if has_any_tag(memory, BETRAYAL_TAGS) {
+            betrayed = true;
+        }
+        let age = tick.saturating_sub(memory.sim_tick.0);
+        if age > config.memory_window_ticks {
+            continue;
+        }
+        let fade = 1.0 - age as f32 / config.memory_window_ticks.max(1) as f32;
+        recent_valence += memory.emotional_intensity * fade;
+        if has_any_tag(memory, KINDNESS_TAGS) {
+            kindness = true;
+        }
+    }
+    let recent_valence = recent_valence.clamp(-1.0, 1.0);
+
+    let grudge = (rel.resentment.max(0.0) / 10.0
+        + (-recent_valence).max(0.0) * config.memory_weight)
+        .clamp(0.0, 1.0);
+    let favor = ((rel.affection + rel.trust) / 20.0
+        + recent_valence.max(0.0) * config.memory_weight)
+        .clamp(0.0, 1.0);
+
+    let forgiven = kindness && favor - grudge >= config.betrayal_forgiveness_margin;
+    let tone = if grudge >= config.bitter_grudge {
+        DialogueTone::Bitter
+    } else if grudge >= config.guarded_grudge || (betrayed && !forgiven) {
+        DialogueTone::Guarded
+    } else if favor >= config.warm_favor {
+        DialogueTone::Warm
+    } else {
+        DialogueTone::Neutral
+    };
+
+    let mut flags = Vec::new();
+    if betrayed {
+        flags.push(TONE_FLAG_REMEMBERS_BETRAYAL.to_string());
+    }
+    if kindness {
+        flags.push(TONE_FLAG_REMEMBERS_KINDNESS.to_string());
+    }
+    if grudge >= config.guarded_grudge {
+        flags.push(TONE_FLAG_GRUDGE.to_string());
+    }
+    if favor >= config.warm_favor {
+        flags.push(TONE_FLAG_FOND.to_string());
+    }
+
+    ToneContext {
+        npc_id,
+        tone,
+        grudge,
+        favor,
+        flags,
+    }
+}
+
+/// Whether `memory` is one either side of the pair holds about the other.
+fn is_shared(memory: &MemoryEntryRecord, player: NpcId, npc_id: NpcId) -> bool {
+    (memory.npc_id == player && memory.participants.contains(&npc_id.0))
+        || (memory.npc_id == npc_id && memory.participants.contains(&player.0))
+}
+
+fn has_any_tag(memory: &MemoryEntryRecord, tags: &[&str]) -> bool {
+    memory
+        .tags
+        .iter()
+        .any(|t| tags.iter().any(|tag| t.eq_ignore_ascii_case(tag)))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::types::{Relationship, SimTick, WorldSeed};
+
+    fn world_with_friend() -> WorldState {
+        let mut world = WorldState::new(WorldSeed(5), NpcId(1));
+        world.set_relationship(
+            NpcId(2),
+            NpcId(1),
+            Relationship {
+                affection: 7.0,
+                trust: 6.0,
+                familiarity: 6.0,
+                ..Default::default()
+            },
+        );
+        world
+    }
+
+    fn shared_memory(tick: u64, intensity: f32, tags: &[&str]) -> MemoryEntryRecord {
+        MemoryEntryRecord {
+            id: format!("m:{}", tick),
+            event_id: "test".to_string(),
+            npc_id: NpcId(2),
+            sim_tick: SimTick(tick),
+            emotional_intensity: intensity,
+            tags: tags.iter().map(|t| t.to_string()).collect(),
+            participants: vec![1],
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn friends_read_warm() {
+        let world = world_with_friend();
+        let ctx = tone_context(&world, NpcId(2));
+        assert_eq!(ctx.tone, DialogueTone::Warm);
+        assert!(ctx.has_flag(TONE_FLAG_FOND));
+        assert!(!ctx.has_flag(TONE_FLAG_REMEMBERS_BETRAYAL));
+    }
+
+    #[test]
+    fn an_old_betrayal_keeps_a_friend_guarded() {
+        let mut world = world_with_friend();
+        world
+            .memory_entries
+            .push(shared_memory(0, -0.9, &["betrayal"]));
+        world.current_tick = SimTick(24 * 365);
+
+        let ctx = tone_context(&world, NpcId(2));
+        assert!(ctx.has_flag(TONE_FLAG_REMEMBERS_BETRAYAL));
+        assert_eq!(ctx.tone, DialogueTone::Guarded);
+
+        // Enough recent kindness wins them back.
+        let now = world.current_tick.0;
+        world
+            .memory_entries
+            .push(shared_memory(now, 0.9, &["support"]));
+        let ctx = tone_context(&world, NpcId(2));
+        assert!(ctx.has_flag(TONE_FLAG_REMEMBERS_KINDNESS));
+        assert_eq!(ctx.tone, DialogueTone::Warm);
+    }
+
+    #[test]
+    fn resentment_turns_bitter() {
+        let mut world = world_with_friend();
+        world.set_relationship(
+            NpcId(2),
+            NpcId(1),
+            Relationship {
+                resentment: 8.0,
+                ..Default::default()
+            },
+        );
+        let ctx = tone_context(&world, NpcId(2));
+        assert_eq!(ctx.tone, DialogueTone::Bitter);
+        assert!(ctx.has_flag(TONE_FLAG_GRUDGE));
+    }
+}
@@ -3,12 +3,13 @@
 //! Per GDD §14: Districts own a micro-economy, crime potential, social clusters, and event hotspots.
 //! Districts evolve over time (gentrify, decay, rebound) based on global simulation values.
 
+use crate::collections::sorted_entries_mut;
 use crate::rng::DeterministicRng;
 use serde::{Deserialize, Serialize};
 use std::collections::HashMap;
 
 /// Unique identifier for a district.
-#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Serialize, Deserialize)]
 pub struct DistrictId(pub u32);
 
 impl DistrictId {
@@ -421,6 +422,13 @@ impl District {
         self.clamp();
     }
 
+    /// Apply underworld pressure from a player living here (see `crate::underworld`).
+    pub fn apply_gang_pressure(&mut self, delta: f32) {
+        self.gang_activity += delta;
+        self.crime_trend += delta * 0.02;
+        self.clamp();
+    }
+
     /// Clamp all values to valid ranges.
     fn clamp(&mut self) {
         self.economy = self.economy.clamp(0.0, 100.0);
@@ -449,6 +457,7 @@ impl Default for District {
 
 /// Registry of all districts in the world.
 #[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(default)]
 pub struct DistrictRegistry {
     /// All districts by ID
     pub districts: HashMap<DistrictId, District>,
@@ -566,9 +575,9 @@ impl DistrictRegistry {
         self.districts.values()
     }
 
-    /// Tick all districts forward.
+    /// Tick all districts forward (in id order, so RNG draws are reproducible).
     pub fn tick_all(&mut self, rng: &mut DeterministicRng) {
-        for district in self.districts.values_mut() {
+        for (_, district) in sorted_entries_mut(&mut self.districts) {
             district.tick(rng);
         }
     }
@@ -596,19 +605,23 @@ impl DistrictRegistry {
 
     /// Get average crime across all districts.
     pub fn average_crime(&self) -> f32 {
-        if self.districts.is_empty() {
-            return 0.0;
-        }
-        let total: f32 = self.districts.values().map(|d| d.crime).sum();
-        total / self.districts.len() as f32
+        self.average_by(|d| d.crime)
     }
 
     /// Get average economy across all districts.
     pub fn average_economy(&self) -> f32 {
+        self.average_by(|d| d.economy)
+    }
+
+    /// Average of `value` over all districts, summed in id order: float
+    /// addition is order-sensitive and HashMap order changes between runs.
+    fn average_by(&self, value: impl Fn(&District) -> f32) -> f32 {
         if self.districts.is_empty() {
             return 0.0;
         }
-        let total: f32 = self.districts.values().map(|d| d.economy).sum();
+        let mut districts: Vec<_> = self.districts.iter().collect();
+        districts.sort_by_key(|(id, _)| **id);
+        let total: f32 = districts.into_iter().map(|(_, d)| value(d)).sum();
         total / self.districts.len() as f32
     }
 }
@@ -617,6 +630,25 @@ impl DistrictRegistry {
 mod tests {
     use super::*;
 
+    #[test]
+    fn test_tick_all_independent_of_map_order() {
+        // Each registry gets its own hasher state, so iteration order differs.
+        let mut a = DistrictRegistry::generate_default_city(7);
+        let mut b = DistrictRegistry::generate_default_city(7);
+        let mut rng_a = DeterministicRng::new(99);
+        let mut rng_b = DeterministicRng::new(99);
+        for _ in 0..5 {
+            a.tick_all(&mut rng_a);
+            b.tick_all(&mut rng_b);
+        }
+
+        for (id, district) in &a.districts {
+            let other = &b.districts[id];
+            assert_eq!(district.economy.to_bits(), other.economy.to_bits());
+            assert_eq!(district.crime.to_bits(), other.crime.to_bits());
+        }
+    }
+
     #[test]
     fn test_district_creation() {
         let district = District::new(
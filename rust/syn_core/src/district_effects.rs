@@ -0,0 +1,99 @@
+//! Authored changes to a district's simulation from storylet outcomes.
+//!
+//! A [`DistrictEffect`] nudges one district's economy and crime through the
+//! same shocks the district simulation uses ([`District::apply_economic_event`]
+//! and [`District::apply_crime_event`], ripple effects included) and can
+//! raise a district-scoped world flag, `district:<name>:<flag>` (see
+//! [`district_flag`]), that later storylets and flag reactions key on. An
+//! effect without a district lands on the player's home district.
+//!
+//! [`District::apply_economic_event`]: crate::district::District::apply_economic_event
+//! [`District::apply_crime_event`]: crate::district::District::apply_crime_event
+
+use serde::{Deserialize, Serialize};
+
+use crate::flag_changes::set_world_flag;
+use crate::types::WorldState;
+
+/// One authored change to a district.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct DistrictEffect {
+    /// District name; `None` targets the player's home district.
+    #[serde(default)]
+    pub district: Option<String>,
+    /// Economy shock (positive booms, negative busts).
+    #[serde(default)]
+    pub economy_delta: f32,
+    /// Crime spike (positive) or crackdown (negative).
+    #[serde(default)]
+    pub crime_delta: f32,
+    /// Flag raised on the district, e.g. `"factory_closed"`.
+    #[serde(default)]
+    pub flag: Option<String>,
+}
+
+/// World flag name for `flag` scoped to `district`.
+pub fn district_flag(district: &str, flag: &str) -> String {
+    format!("district:{}:{}", district, flag)
+}
+
+/// Name of the district `effect` targets, if there is one.
+fn target_district(world: &WorldState, effect: &DistrictEffect) -> Option<String> {
+    effect
+        .district
+        .clone()
+        .or_else(|| world.npcs.get(&world.player_id).map(|p| p.district.clone()))
+        .filter(|name| world.districts.get_by_name(name).is_some())
+}
+
+/// Apply `effects` to the world's districts, attributing flags to `source`.
+///
+/// Effects naming an unknown district (or targeting the player's district
+/// when the player has none) are skipped.
+pub fn apply_district_effects(world: &mut WorldState, effects: &[DistrictEffect], source: &str) {
+    for effect in effects {
+        let Some(name) = target_district(world, effect) else {
+            continue;
+        };
+        if let Some(district) = world.districts.get_by_name_mut(&name) {
+            district.apply_economic_event(effect.economy_delta);
+            district.apply_crime_event(effect.crime_delta);
+        }
+        if let Some(flag) = &effect.flag {
+            set_world_flag(world, &district_flag(&name, flag), true, source);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::types::{NpcId, WorldSeed};
+
+    #[test]
+    fn effects_hit_the_named_district_and_raise_its_flag() {
+        let mut world = WorldState::new(WorldSeed(3), NpcId(1));
+        let before = world.districts.get_by_name("Industrial Park").map(|d| d.economy);
+        let effects = [
+            DistrictEffect {
+                district: Some("Industrial Park".to_string()),
+                economy_delta: -15.0,
+                flag: Some("factory_closed".to_string()),
+                ..Default::default()
+            },
+            DistrictEffect {
+                district: Some("Atlantis".to_string()),
+                crime_delta: 30.0,
+                ..Default::default()
+            },
+        ];
+
+        apply_district_effects(&mut world, &effects, "storylet:factory_closes");
+
+        let district = world.districts.get_by_name("Industrial Park").unwrap();
+        assert!(before.is_some_and(|b| district.economy < b));
+        assert!(world
+            .world_flags
+            .has_any(&district_flag("Industrial Park", "factory_closed")));
+    }
+}
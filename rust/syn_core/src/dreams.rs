@@ -0,0 +1,412 @@
+//! Dreams and flashbacks: memory-driven beats during the Night phase.
+//!
+//! Once in a while at night the player relives one of their high-salience
+//! memories. The resulting [`DreamEvent`] is a choiceless storylet that
+//! reuses the source memory's tags and participants: pleasant memories come
+//! back as dreams, painful ones as flashbacks. Its effects are symbolic — a
+//! mood nudge and a small drift in one personality trait.
+//!
+//! Dreams are bounded to one roll per night with a minimum gap in days, are
+//! tagged [`DREAM_TAG`] (flashbacks also [`FLASHBACK_TAG`]), and are skipped
+//! when the content preferences block those tags or any tag of the memory.
+//! Selection is deterministic: a tick-seeded RNG draws among candidate
+//! memories in journal order, weighted by salience.
+
+use std::collections::VecDeque;
+
+use serde::{Deserialize, Serialize};
+
+use crate::ambient::{npc_name, AMBIENT_TAG};
+use crate::rng::DeterministicRng;
+use crate::stats::StatKind;
+use crate::time::DayPhase;
+use crate::types::{MemoryEntryRecord, NpcId, SimTick, WorldState};
+
+/// Tag added to every dream and flashback and to their memories.
+pub const DREAM_TAG: &str = "dream";
+
+/// Extra tag added to flashbacks (dreams of painful memories).
+pub const FLASHBACK_TAG: &str = "flashback";
+
+/// Dream memories carry this fraction of the source memory's intensity.
+const DREAM_MEMORY_SCALE: f32 = 0.5;
+
+/// Number of recent dreams kept on [`DreamState`].
+const RECENT_CAPACITY: usize = 16;
+
+/// Whether a night brought back a pleasant or a painful memory.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum DreamKind {
+    /// Replays a positive memory; lifts mood and confidence.
+    Dream,
+    /// Replays a negative memory; lowers mood and stability.
+    Flashback,
+}
+
+impl DreamKind {
+    /// Stable lowercase label for UI/FFI.
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            DreamKind::Dream => "dream",
+            DreamKind::Flashback => "flashback",
+        }
+    }
+
+    /// Trait the dream drifts (see `Traits::TRAIT_NAMES`).
+    pub fn drifted_trait(&self) -> &'static str {
+        match self {
+            DreamKind::Dream => "confidence",
+            DreamKind::Flashback => "stability",
+        }
+    }
+}
+
+/// Frequency and strength of dreams.
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct DreamConfig {
+    /// Chance that a night brings a dream (rolled once per night).
+    pub chance_per_night: f32,
+    /// Minimum days between two dreams.
+    pub min_gap_days: u64,
+    /// Minimum memory salience (|emotional intensity|) worth dreaming about.
+    pub min_salience: f32,
+    /// Mood change per point of source intensity.
+    pub mood_scale: f32,
+    /// Trait drift per point of source salience.
+    pub trait_drift: f32,
+}
+
+impl Default for DreamConfig {
+    fn default() -> Self {
+        Self {
+            chance_per_night: 0.3,
+            min_gap_days: 2,
+            min_salience: 0.5,
+            mood_scale: 1.5,
+            trait_drift: 0.5,
+        }
+    }
+}
+
+/// A dream or flashback the player had.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct DreamEvent {
+    /// Sequence number (unique within a run).
+    pub seq: u64,
+    /// Tick the dream happened.
+    pub tick: u64,
+    /// Dream or flashback.
+    pub kind: DreamKind,
+    /// Memory the dream replays.
+    pub memory_id: String,
+    /// Storylet/event the source memory came from.
+    pub source_event_id: String,
+    /// Rendered text.
+    pub text: String,
+    /// Source memory tags plus [`DREAM_TAG`] (and [`FLASHBACK_TAG`]).
+    #[serde(default)]
+    pub tags: Vec<String>,
+    /// Participants of the source memory.
+    #[serde(default)]
+    pub participants: Vec<u64>,
+    /// Change applied to the player's mood.
+    pub mood_delta: f32,
+    /// Trait that drifted.
+    pub trait_name: String,
+    /// Change applied to that trait.
+    pub trait_delta: f32,
+}
+
+/// Dream bookkeeping stored on `WorldState`.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct DreamState {
+    /// Frequency and strength.
+    #[serde(default)]
+    pub config: DreamConfig,
+    /// Day of the last night that was rolled for a dream.
+    #[serde(default)]
+    pub last_roll_day: Option<u64>,
+    /// Day of the last dream.
+    #[serde(default)]
+    pub last_dream_day: Option<u64>,
+    /// Most recent dreams, oldest first.
+    #[serde(default)]
+    pub recent: VecDeque<DreamEvent>,
+    /// Next sequence number.
+    #[serde(default)]
+    next_seq: u64,
+}
+
+impl DreamState {
+    /// Most recent dreams first, at most `limit`.
+    pub fn latest(&self, limit: usize) -> impl Iterator<Item = &DreamEvent> {
+        self.recent.iter().rev().take(limit)
+    }
+
+    fn gap_elapsed(&self, day: u64) -> bool {
+        self.last_dream_day
+            .is_none_or(|d| day.saturating_sub(d) >= self.config.min_gap_days)
+    }
+
+    fn recently_dreamed(&self, memory_id: &str) -> bool {
+        self.recent.iter().any(|d| d.memory_id == memory_id)
+    }
+}
+
+/// A dream plus the memory recorded for it.
+#[derive(Debug, Clone, PartialEq)]
+pub struct DreamBeat {
+    /// The dream that happened.
+    pub event: DreamEvent,
+    /// Player memory (also appended to `world.memory_entries`).
+    pub memory: MemoryEntryRecord,
+}
+
+fn dream_tags(memory: &MemoryEntryRecord, kind: DreamKind) -> Vec<String> {
+    let mut tags: Vec<String> = memory
+        .tags
+        .iter()
+        .filter(|t| !t.eq_ignore_ascii_case(DREAM_TAG) && !t.eq_ignore_ascii_case(FLASHBACK_TAG))
+        .cloned()
+        .collect();
+    tags.push(DREAM_TAG.to_string());
+    if kind == DreamKind::Flashback {
+        tags.push(FLASHBACK_TAG.to_string());
+    }
+    tags
+}
+
+fn dream_text(world: &WorldState, memory: &MemoryEntryRecord, kind: DreamKind) -> String {
+    let event = memory.event_id.replace(['_', '.'], " ");
+    let names: Vec<String> = memory
+        .participants
+        .iter()
+        .map(|&p| NpcId(p))
+        .filter(|&p| p != world.player_id)
+        .map(|p| npc_name(world, p))
+        .collect();
+    let with = if names.is_empty() {
+        String::new()
+    } else {
+        format!(" with {}", names.join(", "))
+    };
+    match kind {
+        DreamKind::Dream => format!("You dream about {}{}", event, with),
+        DreamKind::Flashback => format!("A flashback to {}{} jolts you awake", event, with),
+    }
+}
+
+/// Maybe produce a dream for the current tick.
+///
+/// Only acts during [`DayPhase::Night`], rolling once per night and at most
+/// once every `min_gap_days`. On success the player's mood and one trait
+/// drift, the dream is appended to `world.dreams.recent`, and a dream memory
+/// (at half the source intensity) is recorded for the player.
+pub fn generate_dream(world: &mut WorldState) -> Option<DreamBeat> {
+    if world.game_time.phase != DayPhase::Night {
+        return None;
+    }
+    let day = world.game_time.day;
+    if world.dreams.last_roll_day == Some(day) || !world.dreams.gap_elapsed(day) {
+        return None;
+    }
+    world.dreams.last_roll_day = Some(day);
+
+    let tick = world.current_tick.0;
+    let config = world.dreams.config;
+    let mut rng = DeterministicRng::with_domain(world.seed.0, tick, "dream_night");
+    if !rng.gen_bool(config.chance_per_night) {
+        return None;
+    }
+
+    let player = world.player_id;
+    let candidates: Vec<(&MemoryEntryRecord, DreamKind, f32)> = world
+        .memory_entries
+        .iter()
+        .filter(|m| m.npc_id == player)
+        .filter(|m| {
+            !m.tags
+                .iter()
+                .any(|t| t.eq_ignore_ascii_case(DREAM_TAG) || t.eq_ignore_ascii_case(AMBIENT_TAG))
+        })
+        .filter(|m| !world.dreams.recently_dreamed(&m.id))
+        .filter_map(|m| {
+            let salience = m.emotional_intensity.abs().min(1.0);
+            if salience < config.min_salience {
+                return None;
+            }
+            let kind = if m.emotional_intensity < 0.0 {
+                DreamKind::Flashback
+            } else {
+                DreamKind::Dream
+            };
+            let tags = dream_tags(m, kind);
+            world
+                .content_preferences
+                .allows(None, tags.iter().map(String::as_str))
+                .then_some((m, kind, salience))
+        })
+        .collect();
+    if candidates.is_empty() {
+        return None;
+    }
+
+    let total: f32 = candidates.iter().map(|(_, _, s)| s).sum();
+    let mut roll = rng.gen_f32() * total;
+    let mut pick = candidates.len() - 1;
+    for (i, (_, _, salience)) in candidates.iter().enumerate() {
+        if roll < *salience {
+            pick = i;
+            break;
+        }
+        roll -= salience;
+    }
+    let (source, kind, salience) = candidates[pick];
+    let source = source.clone();
+
+    let mood_delta = source.emotional_intensity.clamp(-1.0, 1.0) * config.mood_scale;
+    let trait_delta = match kind {
+        DreamKind::Dream => salience * config.trait_drift,
+        DreamKind::Flashback => -salience * config.trait_drift,
+    };
+    world.player_stats.apply_delta(StatKind::Mood, mood_delta);
+    if let Some(npc) = world.npcs.get_mut(&player) {
+        match kind {
+            DreamKind::Dream => npc.traits.confidence += trait_delta,
+            DreamKind::Flashback => npc.traits.stability += trait_delta,
+        }
+        npc.traits.clamp();
+    }
+
+    let seq = world.dreams.next_seq;
+    world.dreams.next_seq += 1;
+    let event = DreamEvent {
+        seq,
+        tick,
+        kind,
+        memory_id: source.id.clone(),
+        source_event_id: source.event_id.clone(),
+        text: dream_text(world, &source, kind),
+        tags: dream_tags(&source, kind),
+        participants: source.participants.clone(),
+        mood_delta,
+        trait_name: kind.drifted_trait().to_string(),
+        trait_delta,
+    };
+    let memory = MemoryEntryRecord {
+        id: format!("dream:{}", seq),
+        event_id: format!("dream:{}", source.event_id),
+        npc_id: player,
+        sim_tick: SimTick(tick),
+        emotional_intensity: source.emotional_intensity * DREAM_MEMORY_SCALE,
+        tags: event.tags.clone(),
+        participants: event.participants.clone(),
+        ..Default::default()
+    };
+
+    world.dreams.last_dream_day = Some(day);
+    world.memory_entries.push(memory.clone());
+    world.dreams.recent.push_back(event.clone());
+    while world.dreams.recent.len() > RECENT_CAPACITY {
+        world.dreams.recent.pop_front();
+    }
+
+    Some(DreamBeat { event, memory })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::time::GameTime;
+    use crate::types::WorldSeed;
+
+    fn memory(id: &str, event: &str, intensity: f32, tags: &[&str]) -> MemoryEntryRecord {
+        MemoryEntryRecord {
+            id: id.to_string(),
+            event_id: event.to_string(),
+            npc_id: NpcId(1),
+            sim_tick: SimTick(5),
+            emotional_intensity: intensity,
+            tags: tags.iter().map(|t| t.to_string()).collect(),
+            participants: vec![1, 2],
+            ..Default::default()
+        }
+    }
+
+    fn world_at(tick: u64) -> WorldState {
+        let mut world = WorldState::new(WorldSeed(7), NpcId(1));
+        world.dreams.config.chance_per_night = 1.0;
+        world
+            .memory_entries
+            .push(memory("m1", "breakup_fight", -0.9, &["conflict"]));
+        world
+            .memory_entries
+            .push(memory("m2", "small_talk", 0.2, &["social"]));
+        set_tick(&mut world, tick);
+        world
+    }
+
+    fn set_tick(world: &mut WorldState, tick: u64) {
+        world.current_tick = SimTick(tick);
+        world.game_time = GameTime::from_tick(tick);
+    }
+
+    #[test]
+    fn test_flashback_reuses_memory_and_nudges_player() {
+        let mut world = world_at(20);
+        let mood_before = world.player_stats.get(StatKind::Mood);
+
+        let beat = generate_dream(&mut world).expect("a night flashback");
+        assert_eq!(beat.event.kind, DreamKind::Flashback);
+        assert_eq!(beat.event.memory_id, "m1");
+        assert_eq!(beat.event.participants, vec![1, 2]);
+        assert_eq!(beat.event.tags, vec!["conflict", DREAM_TAG, FLASHBACK_TAG]);
+        assert_eq!(
+            beat.event.text,
+            "A flashback to breakup fight with NPC #2 jolts you awake"
+        );
+        assert!(world.player_stats.get(StatKind::Mood) < mood_before);
+        assert!(beat.event.trait_delta < 0.0);
+        assert!((beat.memory.emotional_intensity + 0.45).abs() < 1e-4);
+        assert_eq!(world.memory_entries.len(), 3);
+    }
+
+    #[test]
+    fn test_dreams_are_bounded_to_nights_and_gaps() {
+        let mut world = world_at(10);
+        assert!(generate_dream(&mut world).is_none(), "not at midday");
+
+        set_tick(&mut world, 20);
+        assert!(generate_dream(&mut world).is_some());
+        set_tick(&mut world, 21);
+        assert!(generate_dream(&mut world).is_none(), "one roll per night");
+        set_tick(&mut world, 24 + 20);
+        assert!(generate_dream(&mut world).is_none(), "min gap not elapsed");
+
+        // m1 was just dreamed about and m2 is not salient enough.
+        set_tick(&mut world, 48 + 20);
+        assert!(generate_dream(&mut world).is_none());
+    }
+
+    #[test]
+    fn test_content_preferences_skip_dreams() {
+        let mut world = world_at(20);
+        world.content_preferences.block_tag(FLASHBACK_TAG);
+        assert!(generate_dream(&mut world).is_none());
+
+        let mut world = world_at(20);
+        world
+            .memory_entries
+            .push(memory("m3", "first_kiss", 0.8, &["romance"]));
+        world.content_preferences.block_tag("conflict");
+        let beat = generate_dream(&mut world).expect("the pleasant memory");
+        assert_eq!(beat.event.kind, DreamKind::Dream);
+        assert_eq!(beat.event.memory_id, "m3");
+
+        let mut world = world_at(20);
+        world.content_preferences.block_tag(DREAM_TAG);
+        assert!(generate_dream(&mut world).is_none());
+    }
+}
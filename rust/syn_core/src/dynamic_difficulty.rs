@@ -0,0 +1,442 @@
+//! Dynamic difficulty adjustment (DDA) on top of the character-creation
+//! [`Difficulty`].
+//!
+//! The chosen difficulty sets how hard storylet outcomes hit the player:
+//! negative stat deltas are multiplied by [`Difficulty::negative_modifier`],
+//! positive ones by [`Difficulty::positive_modifier`]. When
+//! [`DifficultyConfig::enabled`] is set, a controller also watches how the
+//! player is doing and nudges two knobs within designer-set bounds:
+//!
+//! - `negative_scale` further multiplies negative outcome deltas,
+//! - `heavy_mix` multiplies the director score of storylets carrying one of
+//!   [`HEAVY_CONTENT_TAGS`], shifting the storylet mix.
+//!
+//! Every storylet outcome that touches player stats is recorded as a sample
+//! with a net valence (each delta normalized by its stat's range). Once a day
+//! [`update_difficulty`] looks back over `window_ticks`: a high share of
+//! negative outcomes, or wellbeing (mood and health) falling by more than
+//! `trajectory_drop`, eases both knobs by `step`; a low share with steady or
+//! rising wellbeing hardens them; otherwise they settle back toward 1.0, as
+//! far as the bounds allow. Each change is logged as a [`DifficultyDecision`]
+//! so the behavior stays transparent. With the controller disabled (the
+//! default) both knobs stay at 1.0 and nothing is recorded.
+
+use serde::{Deserialize, Serialize};
+
+use crate::character_gen::Difficulty;
+use crate::stats::{StatDelta, StatKind};
+use crate::types::WorldState;
+
+/// Storylet tags whose share of the mix the controller adjusts.
+pub const HEAVY_CONTENT_TAGS: &[&str] = &["trauma", "conflict", "crisis", "loss", "grief"];
+
+/// Controller bounds and thresholds.
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct DifficultyConfig {
+    /// Whether the controller runs at all.
+    pub enabled: bool,
+    /// How far back samples and wellbeing are considered.
+    pub window_ticks: u64,
+    /// Outcome samples needed before the negative share counts.
+    pub min_samples: usize,
+    /// Share of negative outcomes at which the player is struggling.
+    pub struggling_share: f32,
+    /// Share of negative outcomes at or below which the player is thriving.
+    pub thriving_share: f32,
+    /// Wellbeing drop (0..1) over the window that counts as struggling.
+    pub trajectory_drop: f32,
+    /// How far one daily decision moves a knob.
+    pub step: f32,
+    /// Lowest `negative_scale` the controller may reach.
+    pub min_negative_scale: f32,
+    /// Highest `negative_scale` the controller may reach.
+    pub max_negative_scale: f32,
+    /// Lowest `heavy_mix` the controller may reach.
+    pub min_heavy_mix: f32,
+    /// Highest `heavy_mix` the controller may reach.
+    pub max_heavy_mix: f32,
+    /// Decisions kept in the log.
+    pub max_decisions: usize,
+}
+
+impl Default for DifficultyConfig {
+    fn default() -> Self {
+        Self {
+            enabled: false,
+            window_ticks: 24 * 14,
+            min_samples: 4,
+            struggling_share: 0.6,
+            thriving_share: 0.2,
+            trajectory_drop: 0.15,
+            step: 0.05,
+            min_negative_scale: 0.6,
+            max_negative_scale: 1.25,
+            min_heavy_mix: 0.5,
+            max_heavy_mix: 1.3,
+            max_decisions: 30,
+        }
+    }
+}
+
+/// What a daily evaluation did.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum DifficultyAction {
+    /// The player was struggling; outcomes soften and heavy content recedes.
+    Ease,
+    /// The player was cruising; outcomes bite harder and heavy content returns.
+    Harden,
+    /// Neither; the knobs drift back toward neutral.
+    Settle,
+}
+
+impl DifficultyAction {
+    /// Stable lowercase label for UI/FFI.
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            DifficultyAction::Ease => "ease",
+            DifficultyAction::Harden => "harden",
+            DifficultyAction::Settle => "settle",
+        }
+    }
+}
+
+/// One recorded storylet outcome.
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub struct OutcomeSample {
+    /// Tick the outcome applied.
+    pub tick: u64,
+    /// Net effect on the player (sum of range-normalized deltas).
+    pub valence: f32,
+}
+
+/// A logged controller decision.
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub struct DifficultyDecision {
+    /// Tick of the evaluation.
+    pub tick: u64,
+    /// What the controller did.
+    pub action: DifficultyAction,
+    /// Share of negative outcomes in the window.
+    pub negative_share: f32,
+    /// Outcome samples in the window.
+    pub samples: usize,
+    /// Wellbeing change over the window (-1..1).
+    pub trajectory: f32,
+    /// `negative_scale` after the decision.
+    pub negative_scale: f32,
+    /// `heavy_mix` after the decision.
+    pub heavy_mix: f32,
+}
+
+/// Chosen difficulty, controller state and decision log.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct DifficultyState {
+    /// Controller tuning.
+    pub config: DifficultyConfig,
+    /// Difficulty chosen at character creation.
+    pub base: Difficulty,
+    /// Extra multiplier on negative outcome deltas.
+    pub negative_scale: f32,
+    /// Score multiplier for storylets tagged with heavy content.
+    pub heavy_mix: f32,
+    /// Outcome samples inside the window.
+    pub samples: Vec<OutcomeSample>,
+    /// Daily wellbeing readings `(tick, 0..1)` inside the window.
+    pub wellbeing: Vec<(u64, f32)>,
+    /// Most recent decisions, oldest first.
+    pub decisions: Vec<DifficultyDecision>,
+}
+
+impl Default for DifficultyState {
+    fn default() -> Self {
+        Self {
+            config: DifficultyConfig::default(),
+            base: Difficulty::default(),
+            negative_scale: 1.0,
+            heavy_mix: 1.0,
+            samples: Vec::new(),
+            wellbeing: Vec::new(),
+            decisions: Vec::new(),
+        }
+    }
+}
+
+impl DifficultyState {
+    /// Multiplier applied to negative outcome deltas.
+    pub fn negative_modifier(&self) -> f32 {
+        self.base.negative_modifier() * self.negative_scale
+    }
+
+    /// Multiplier applied to positive outcome deltas.
+    pub fn positive_modifier(&self) -> f32 {
+        self.base.positive_modifier()
+    }
+
+    /// An outcome delta after difficulty scaling.
+    pub fn scale_delta(&self, delta: f32) -> f32 {
+        if delta < 0.0 {
+            delta * self.negative_modifier()
+        } else {
+            delta * self.positive_modifier()
+        }
+    }
+
+    /// Whether a storylet with `tags` counts as heavy content.
+    pub fn is_heavy<'a>(tags: impl IntoIterator<Item = &'a str>) -> bool {
+        tags.into_iter().any(|tag| {
+            HEAVY_CONTENT_TAGS
+                .iter()
+                .any(|heavy| tag.eq_ignore_ascii_case(heavy))
+        })
+    }
+
+    /// Director score multiplier for a storylet with `tags`.
+    pub fn mix_multiplier<'a>(&self, tags: impl IntoIterator<Item = &'a str>) -> f32 {
+        if Self::is_heavy(tags) {
+            self.heavy_mix
+        } else {
+            1.0
+        }
+    }
+
+    /// Outcome samples, negative share and wellbeing change over the window.
+    pub fn window_summary(&self) -> (usize, f32, f32) {
+        let samples = self.samples.len();
+        let negative = self.samples.iter().filter(|s| s.valence < 0.0).count();
+        let share = if samples == 0 {
+            0.0
+        } else {
+            negative as f32 / samples as f32
+        };
+        let trajectory = match (self.wellbeing.first(), self.wellbeing.last()) {
+            (Some((_, first)), Some((_, last))) => last - first,
+            _ => 0.0,
+        };
+        (samples, share, trajectory)
+    }
+}
+
+/// Range used to normalize a delta to `kind` when computing valence.
+fn stat_span(kind: StatKind) -> f32 {
+    match kind {
+        StatKind::Mood => 20.0,
+        StatKind::Reputation => 200.0,
+        _ => 100.0,
+    }
+}
+
+/// Player wellbeing (0..1): the mean of normalized mood and health.
+fn wellbeing(world: &WorldState) -> f32 {
+    let mood = (world.player_stats.mood + 10.0) / 20.0;
+    let health = world.player_stats.health / 100.0;
+    ((mood + health) / 2.0).clamp(0.0, 1.0)
+}
+
+/// Set the difficulty chosen at character creation.
+pub fn set_base_difficulty(world: &mut WorldState, difficulty: Difficulty) {
+    world.difficulty.base = difficulty;
+}
+
+/// Apply storylet outcome stat deltas to the player, capped (see
+/// [`crate::delta_caps`]) and scaled by difficulty, and record the outcome
+/// for the controller.
+pub fn apply_outcome_stat_deltas(world: &mut WorldState, deltas: &[StatDelta]) {
+    let capped = crate::delta_caps::cap_stat_deltas(world, deltas);
+    let scaled: Vec<(StatKind, f32)> = capped
+        .iter()
+        .map(|d| (d.kind, world.difficulty.scale_delta(d.delta)))
+        .collect();
+    for &(kind, delta) in &scaled {
+        world.player_stats.apply_delta(kind, delta);
+    }
+    record_outcome(world, scaled);
+}
+
+/// Record an outcome's (already scaled) player stat deltas as one sample.
+///
+/// Does nothing while the controller is disabled or the outcome is empty.
+pub fn record_outcome(world: &mut WorldState, deltas: impl IntoIterator<Item = (StatKind, f32)>) {
+    if !world.difficulty.config.enabled {
+        return;
+    }
+    let mut any = false;
+    let mut valence = 0.0_f32;
+    for (kind, delta) in deltas {
+        any = true;
+        valence += delta / stat_span(kind);
+    }
+    if !any {
+        return;
+    }
+    let tick = world.current_tick.0;
+    world.difficulty.samples.push(OutcomeSample { tick, valence });
+}
+
+/// Daily controller evaluation.
+///
+/// Reads wellbeing, drops samples and readings older than the window, and
+/// moves the knobs; logs and returns the decision when a knob changed.
+pub fn update_difficulty(world: &mut WorldState) -> Option<DifficultyDecision> {
+    if !world.difficulty.config.enabled {
+        return None;
+    }
+    let tick = world.current_tick.0;
+    let reading = wellbeing(world);
+    let state = &mut world.difficulty;
+    let config = state.config;
+    let cutoff = tick.saturating_sub(config.window_ticks);
+    state.wellbeing.push((tick, reading));
+    state.wellbeing.retain(|(t, _)| *t >= cutoff);
+    state.samples.retain(|s| s.tick >= cutoff);
+
+    let (samples, negative_share, trajectory) = state.window_summary();
+    let enough = samples >= config.min_samples;
+    let struggling = (enough && negative_share >= config.struggling_share)
+        || trajectory <= -config.trajectory_drop;
+    let thriving = enough && negative_share <= config.thriving_share && trajectory >= 0.0;
+
+    let (action, negative_scale, heavy_mix) = if struggling {
+        (
+            DifficultyAction::Ease,
+            (state.negative_scale - config.step)
+                .clamp(config.min_negative_scale, config.max_negative_scale),
+            (state.heavy_mix - config.step).clamp(config.min_heavy_mix, config.max_heavy_mix),
+        )
+    } else if thriving {
+        (
+            DifficultyAction::Harden,
+            (state.negative_scale + config.step)
+                .clamp(config.min_negative_scale, config.max_negative_scale),
+            (state.heavy_mix + config.step).clamp(config.min_heavy_mix, config.max_heavy_mix),
+        )
+    } else {
+        (
+            DifficultyAction::Settle,
+            settle(state.negative_scale, config.step / 2.0)
+                .clamp(config.min_negative_scale, config.max_negative_scale),
+            settle(state.heavy_mix, config.step / 2.0)
+                .clamp(config.min_heavy_mix, config.max_heavy_mix),
+        )
+    };
+
+    let changed = (negative_scale - state.negative_scale).abs() > f32::EPSILON
+        || (heavy_mix - state.heavy_mix).abs() > f32::EPSILON;
+    if !changed {
+        return None;
+    }
+    state.negative_scale = negative_scale;
+    state.heavy_mix = heavy_mix;
+    let decision = DifficultyDecision {
+        tick,
+        action,
+        negative_share,
+        samples,
+        trajectory,
+        negative_scale,
+        heavy_mix,
+    };
+    state.decisions.push(decision);
+    if state.decisions.len() > config.max_decisions {
+        let excess = state.decisions.len() - config.max_decisions;
+        state.decisions.drain(..excess);
+    }
+    Some(decision)
+}
+
+/// Move `value` toward 1.0 by at most `step`.
+fn settle(value: f32, step: f32) -> f32 {
+    if value > 1.0 {
+        (value - step).max(1.0)
+    } else {
+        (value + step).min(1.0)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::types::{NpcId, SimTick, WorldSeed};
+
+    fn enabled_world() -> WorldState {
+        let mut world = WorldState::new(WorldSeed(11), NpcId(1));
+        world.difficulty.config.enabled = true;
+        world
+    }
+
+    fn outcome(kind: StatKind, delta: f32) -> Vec<StatDelta> {
+        vec![StatDelta {
+            kind,
+            delta,
+            source: None,
+        }]
+    }
+
+    #[test]
+    fn disabled_controller_leaves_balanced_outcomes_untouched() {
+        let mut world = WorldState::new(WorldSeed(11), NpcId(1));
+        let before = world.player_stats.health;
+        apply_outcome_stat_deltas(&mut world, &outcome(StatKind::Health, -10.0));
+        assert!((world.player_stats.health - (before - 10.0)).abs() < 1e-4);
+        assert!(world.difficulty.samples.is_empty());
+        assert!(update_difficulty(&mut world).is_none());
+    }
+
+    #[test]
+    fn base_difficulty_scales_outcomes() {
+        let mut world = WorldState::new(WorldSeed(11), NpcId(1));
+        set_base_difficulty(&mut world, Difficulty::Harsh);
+        let before = world.player_stats.health;
+        apply_outcome_stat_deltas(&mut world, &outcome(StatKind::Health, -10.0));
+        assert!((world.player_stats.health - (before - 14.0)).abs() < 1e-4);
+    }
+
+    #[test]
+    fn a_losing_streak_eases_within_bounds_and_is_logged() {
+        let mut world = enabled_world();
+        for day in 1..=40u64 {
+            apply_outcome_stat_deltas(&mut world, &outcome(StatKind::Wealth, -5.0));
+            world.current_tick = SimTick(day * 24);
+            update_difficulty(&mut world);
+        }
+        let config = world.difficulty.config;
+        assert!((world.difficulty.negative_scale - config.min_negative_scale).abs() < 1e-4);
+        assert!((world.difficulty.heavy_mix - config.min_heavy_mix).abs() < 1e-4);
+        assert!(world
+            .difficulty
+            .decisions
+            .iter()
+            .all(|d| d.action == DifficultyAction::Ease));
+        assert!(world.difficulty.mix_multiplier(["trauma"]) < 1.0);
+        assert!((world.difficulty.mix_multiplier(["romance"]) - 1.0).abs() < 1e-6);
+    }
+
+    #[test]
+    fn a_winning_streak_hardens_then_settles() {
+        let mut world = enabled_world();
+        for day in 1..=5u64 {
+            apply_outcome_stat_deltas(&mut world, &outcome(StatKind::Wealth, 5.0));
+            world.current_tick = SimTick(day * 24);
+            update_difficulty(&mut world);
+        }
+        assert!(world.difficulty.negative_scale > 1.0);
+        assert_eq!(
+            world.difficulty.decisions.last().map(|d| d.action),
+            Some(DifficultyAction::Harden)
+        );
+
+        // Once the window empties the knobs drift back to neutral.
+        world.current_tick = SimTick(world.current_tick.0 + 24 * 30);
+        for _ in 0..10 {
+            world.current_tick = SimTick(world.current_tick.0 + 24);
+            update_difficulty(&mut world);
+        }
+        assert!((world.difficulty.negative_scale - 1.0).abs() < 1e-4);
+        assert_eq!(
+            world.difficulty.decisions.last().map(|d| d.action),
+            Some(DifficultyAction::Settle)
+        );
+    }
+}
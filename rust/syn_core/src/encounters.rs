@@ -0,0 +1,566 @@
+//! First meetings with NPCs the player does not know yet.
+//!
+//! Once a day the player may cross paths with someone new. Candidates are
+//! unknown NPCs from the population and prototype tables, scored by how
+//! naturally they would meet the player:
+//! - living in the same district,
+//! - sharing a schedule slot (work, school, nightlife, errands) with the
+//!   player's own daily routine,
+//! - being friends with someone the player already likes.
+//!
+//! One candidate is drawn by score. Meeting them creates a low-band
+//! relationship in both directions, adds them to `known_npcs` so storylets
+//! can cast them, leaves a player memory tagged [`ENCOUNTER_TAG`] and the
+//! [`EncounterSource`], and sets two dynamic world flags: the per-NPC flag
+//! from [`met_flag`] and [`NEW_ACQUAINTANCE_FLAG`], which stays up for
+//! `introduction_window_ticks` so introduction storylets can follow up.
+
+use std::collections::{BTreeMap, BTreeSet};
+
+use serde::{Deserialize, Serialize};
+
+use crate::flag_changes::{set_world_flag, FLAG_SOURCE_ENCOUNTER};
+use crate::npc::{NpcActivityKind, NpcSchedule, NpcScheduleSlot};
+use crate::relationship_heat::record_relationship_interaction;
+use crate::rng::DeterministicRng;
+use crate::time::DayPhase;
+use crate::types::{LifeStage, MemoryEntryRecord, NpcId, Relationship, WorldState};
+
+/// Tag added to first-meeting memories.
+pub const ENCOUNTER_TAG: &str = "first_meeting";
+
+/// Dynamic world flag raised while a fresh acquaintance awaits an introduction storylet.
+pub const NEW_ACQUAINTANCE_FLAG: &str = "new_acquaintance";
+
+/// Prefix of the dynamic world flag set when the player meets an NPC (`met:<npc_id>`).
+pub const MET_FLAG_PREFIX: &str = "met:";
+
+/// Dynamic world flag marking that the player has met `npc_id`.
+pub fn met_flag(npc_id: NpcId) -> String {
+    format!("{}{}", MET_FLAG_PREFIX, npc_id.0)
+}
+
+/// Where the player ran into someone new.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum EncounterSource {
+    /// Around the shared home district.
+    Neighborhood,
+    /// At work.
+    Work,
+    /// At school.
+    School,
+    /// Out at night.
+    Nightlife,
+    /// Running errands at the same time.
+    Errands,
+    /// Introduced by a friend they have in common.
+    MutualFriend,
+}
+
+impl EncounterSource {
+    /// Stable lowercase label for UI/FFI.
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            EncounterSource::Neighborhood => "neighborhood",
+            EncounterSource::Work => "work",
+            EncounterSource::School => "school",
+            EncounterSource::Nightlife => "nightlife",
+            EncounterSource::Errands => "errands",
+            EncounterSource::MutualFriend => "mutual_friend",
+        }
+    }
+
+    /// Source for a shared schedule activity, if it is a place people meet.
+    fn for_activity(activity: NpcActivityKind) -> Option<Self> {
+        match activity {
+            NpcActivityKind::Work => Some(EncounterSource::Work),
+            NpcActivityKind::School => Some(EncounterSource::School),
+            NpcActivityKind::Nightlife => Some(EncounterSource::Nightlife),
+            NpcActivityKind::Errands => Some(EncounterSource::Errands),
+            NpcActivityKind::Home | NpcActivityKind::OnlineOnly | NpcActivityKind::Offscreen => {
+                None
+            }
+        }
+    }
+}
+
+/// Tuning for daily encounters.
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub struct EncounterConfig {
+    /// Daily chance of meeting someone new, once off cooldown.
+    pub daily_chance: f32,
+    /// Minimum ticks between two encounters.
+    pub cooldown_ticks: u64,
+    /// Candidates scoring below this are not met.
+    pub min_score: f32,
+    /// Score for living in the player's district.
+    pub district_weight: f32,
+    /// Score per day phase spent at work or school together.
+    pub work_weight: f32,
+    /// Score per day phase spent out at night together.
+    pub nightlife_weight: f32,
+    /// Score per day phase spent running errands together.
+    pub errands_weight: f32,
+    /// Score per mutual friend (capped at `max_mutual_friends`).
+    pub mutual_friend_weight: f32,
+    /// Mutual friends counted toward the score.
+    pub max_mutual_friends: usize,
+    /// Ticks [`NEW_ACQUAINTANCE_FLAG`] stays up after a meeting.
+    pub introduction_window_ticks: u64,
+    /// Encounters kept in `recent`.
+    pub max_recent: usize,
+}
+
+impl Default for EncounterConfig {
+    fn default() -> Self {
+        Self {
+            daily_chance: 0.2,
+            cooldown_ticks: 72,
+            min_score: 1.0,
+            district_weight: 1.0,
+            work_weight: 0.75,
+            nightlife_weight: 0.75,
+            errands_weight: 0.25,
+            mutual_friend_weight: 1.5,
+            max_mutual_friends: 3,
+            introduction_window_ticks: 72,
+            max_recent: 20,
+        }
+    }
+}
+
+/// A first meeting with an NPC.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct Encounter {
+    /// Sequential encounter id.
+    pub id: u64,
+    /// Who the player met.
+    pub npc_id: NpcId,
+    /// Where they met.
+    pub source: EncounterSource,
+    /// The friend who introduced them, for [`EncounterSource::MutualFriend`].
+    #[serde(default)]
+    pub introduced_by: Option<NpcId>,
+    /// Tick they met.
+    pub tick: u64,
+}
+
+/// Encounter bookkeeping stored on `WorldState`.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct EncounterState {
+    /// Chances and weights.
+    #[serde(default)]
+    pub config: EncounterConfig,
+    /// Most recent encounters, oldest first.
+    #[serde(default)]
+    pub recent: Vec<Encounter>,
+    /// Tick of the last encounter.
+    #[serde(default)]
+    pub last_tick: Option<u64>,
+    /// Next encounter id.
+    #[serde(default)]
+    next_id: u64,
+}
+
+impl EncounterState {
+    /// The encounter in which the player met `npc_id`, if still in `recent`.
+    pub fn meeting_with(&self, npc_id: NpcId) -> Option<&Encounter> {
+        self.recent.iter().rev().find(|e| e.npc_id == npc_id)
+    }
+
+    fn cooling_down(&self, tick: u64) -> bool {
+        self.last_tick
+            .is_some_and(|last| tick.saturating_sub(last) < self.config.cooldown_ticks)
+    }
+}
+
+/// An unknown NPC's fit for a meeting.
+#[derive(Debug, Clone, PartialEq)]
+struct Candidate {
+    npc_id: NpcId,
+    score: f32,
+    source: EncounterSource,
+    introduced_by: Option<NpcId>,
+}
+
+/// The player's daily routine: their own schedule if authored, otherwise
+/// what their life stage implies.
+pub fn player_schedule(world: &WorldState) -> NpcSchedule {
+    if let Some(schedule) = world
+        .npc_prototype(world.player_id)
+        .map(|proto| &proto.schedule)
+        .filter(|schedule| !schedule.daily_slots.is_empty())
+    {
+        return schedule.clone();
+    }
+
+    let slots: &[(DayPhase, NpcActivityKind)] = match world.player_life_stage {
+        LifeStage::Child | LifeStage::Teen => &[
+            (DayPhase::Morning, NpcActivityKind::School),
+            (DayPhase::Afternoon, NpcActivityKind::School),
+        ],
+        LifeStage::YoungAdult => &[
+            (DayPhase::Morning, NpcActivityKind::Work),
+            (DayPhase::Afternoon, NpcActivityKind::Work),
+            (DayPhase::Night, NpcActivityKind::Nightlife),
+        ],
+        LifeStage::Adult => &[
+            (DayPhase::Morning, NpcActivityKind::Work),
+            (DayPhase::Afternoon, NpcActivityKind::Work),
+            (DayPhase::Evening, NpcActivityKind::Errands),
+        ],
+        LifeStage::Elder => &[(DayPhase::Afternoon, NpcActivityKind::Errands)],
+        _ => &[],
+    };
+    NpcSchedule {
+        daily_slots: slots
+            .iter()
+            .map(|&(phase, activity)| NpcScheduleSlot { phase, activity })
+            .collect(),
+    }
+}
+
+/// District an NPC lives in, from the population table or their prototype.
+fn district_of(world: &WorldState, npc_id: NpcId) -> Option<&str> {
+    world
+        .npcs
+        .get(&npc_id)
+        .map(|npc| npc.district.as_str())
+        .or_else(|| {
+            world
+                .npc_prototype(npc_id)
+                .and_then(|proto| proto.home_district.as_deref())
+        })
+        .filter(|district| !district.is_empty())
+}
+
+/// Whether `a` likes `b` enough to introduce them to others.
+fn is_friend(world: &WorldState, a: NpcId, b: NpcId) -> bool {
+    world
+        .relationships
+        .get(&(a, b))
+        .is_some_and(|rel| rel.affection >= 1.0 && rel.resentment < 5.0)
+}
+
+/// Score every unknown NPC, in id order so draws stay stable.
+fn candidates(world: &WorldState) -> Vec<Candidate> {
+    let config = &world.encounters.config;
+    let player = world.player_id;
+    let known: BTreeSet<NpcId> = world.known_npcs.iter().copied().collect();
+    let player_district = district_of(world, player);
+    let routine = player_schedule(world);
+    let friends: Vec<NpcId> = world
+        .known_npcs
+        .iter()
+        .copied()
+        .filter(|&id| is_friend(world, player, id))
+        .collect();
+
+    let pool: BTreeSet<NpcId> = world
+        .npcs
+        .keys()
+        .chain(world.npc_prototypes.keys())
+        .copied()
+        .filter(|id| *id != player && !known.contains(id))
+        .collect();
+
+    let mut scored = Vec::new();
+    for npc_id in pool {
+        let mut by_source: BTreeMap<EncounterSource, f32> = BTreeMap::new();
+        if player_district.is_some() && district_of(world, npc_id) == player_district {
+            by_source.insert(EncounterSource::Neighborhood, config.district_weight);
+        }
+
+        if let Some(schedule) = world.npc_prototype(npc_id).map(|proto| &proto.schedule) {
+            for phase in DayPhase::all() {
+                let activity = routine.activity_for_phase(phase);
+                if schedule.activity_for_phase(phase) != activity {
+                    continue;
+                }
+                let Some(source) = EncounterSource::for_activity(activity) else {
+                    continue;
+                };
+                let weight = match source {
+                    EncounterSource::Nightlife => config.nightlife_weight,
+                    EncounterSource::Errands => config.errands_weight,
+                    _ => config.work_weight,
+                };
+                *by_source.entry(source).or_default() += weight;
+            }
+        }
+
+        let mutual: Vec<NpcId> = friends
+            .iter()
+            .copied()
+            .filter(|&friend| is_friend(world, friend, npc_id) || is_friend(world, npc_id, friend))
+            .collect();
+        if !mutual.is_empty() {
+            let counted = mutual.len().min(config.max_mutual_friends) as f32;
+            by_source.insert(
+                EncounterSource::MutualFriend,
+                counted * config.mutual_friend_weight,
+            );
+        }
+
+        let score: f32 = by_source.values().sum();
+        if score < config.min_score {
+            continue;
+        }
+        // Ties go to the first source in declaration order.
+        let mut source = EncounterSource::Neighborhood;
+        let mut best = f32::MIN;
+        for (candidate_source, weight) in &by_source {
+            if *weight > best {
+                best = *weight;
+                source = *candidate_source;
+            }
+        }
+        scored.push(Candidate {
+            npc_id,
+            score,
+            source,
+            introduced_by: if source == EncounterSource::MutualFriend {
+                mutual.first().copied()
+            } else {
+                None
+            },
+        });
+    }
+    scored
+}
+
+/// Maybe have the player meet someone new. Returns the encounter, if any.
+///
+/// Runs once a day from `WorldState::tick` with a day-seeded RNG, so runs
+/// stay deterministic. The newcomer flag from an earlier meeting is lowered
+/// here once its introduction window has passed.
+pub fn scan_for_encounters(world: &mut WorldState) -> Option<Encounter> {
+    let tick = world.current_tick.0;
+    let config = world.encounters.config;
+    if world
+        .encounters
+        .last_tick
+        .is_some_and(|last| tick.saturating_sub(last) >= config.introduction_window_ticks)
+    {
+        set_world_flag(world, NEW_ACQUAINTANCE_FLAG, false, FLAG_SOURCE_ENCOUNTER);
+    }
+
+    if world.encounters.cooling_down(tick)
+        || !world
+            .content_preferences
+            .allows(None, [ENCOUNTER_TAG, "social"])
+    {
+        return None;
+    }
+
+    let mut rng = DeterministicRng::with_domain(world.seed.0, tick, "encounters");
+    if !rng.gen_bool(config.daily_chance) {
+        return None;
+    }
+    let pool = candidates(world);
+    let total: f32 = pool.iter().map(|c| c.score).sum();
+    if pool.is_empty() || total <= 0.0 {
+        return None;
+    }
+    let mut roll = rng.gen_f32() * total;
+    let mut picked = &pool[pool.len() - 1];
+    for candidate in &pool {
+        if roll < candidate.score {
+            picked = candidate;
+            break;
+        }
+        roll -= candidate.score;
+    }
+    let picked = picked.clone();
+    Some(meet_npc(
+        world,
+        picked.npc_id,
+        picked.source,
+        picked.introduced_by,
+    ))
+}
+
+/// Have the player meet `npc_id` and record it.
+///
+/// Storylets and scripted scenes can call this directly. Meeting someone
+/// the player already has a relationship with leaves that relationship as
+/// it is.
+pub fn meet_npc(
+    world: &mut WorldState,
+    npc_id: NpcId,
+    source: EncounterSource,
+    introduced_by: Option<NpcId>,
+) -> Encounter {
+    let tick = world.current_tick.0;
+    let player = world.player_id;
+    let config = world.encounters.config;
+    let id = world.encounters.next_id;
+    world.encounters.next_id += 1;
+
+    // A friend's word buys a little trust up front.
+    let trust = if introduced_by.is_some() { 1.0 } else { 0.0 };
+    for (from, to) in [(player, npc_id), (npc_id, player)] {
+        if !world.relationships.contains_key(&(from, to)) {
+            let mut rel = Relationship {
+                affection: 0.5,
+                trust,
+                familiarity: 1.0,
+                ..Relationship::default()
+            };
+            rel.state = rel.compute_next_state();
+            world.set_relationship(from, to, rel);
+        }
+    }
+    record_relationship_interaction(world, player, npc_id, 1.0);
+    world.ensure_npc_known(npc_id);
+
+    let mut participants = vec![player.0, npc_id.0];
+    participants.extend(introduced_by.map(|friend| friend.0));
+    world.memory_entries.push(MemoryEntryRecord {
+        id: format!("encounter:{}", id),
+        event_id: format!("{}:{}", ENCOUNTER_TAG, source.as_str()),
+        npc_id: player,
+        sim_tick: world.current_tick,
+        emotional_intensity: 0.2,
+        tags: vec![
+            ENCOUNTER_TAG.to_string(),
+            source.as_str().to_string(),
+            "social".to_string(),
+        ],
+        participants,
+        ..Default::default()
+    });
+    set_world_flag(world, &met_flag(npc_id), true, FLAG_SOURCE_ENCOUNTER);
+    set_world_flag(world, NEW_ACQUAINTANCE_FLAG, true, FLAG_SOURCE_ENCOUNTER);
+
+    let encounter = Encounter {
+        id,
+        npc_id,
+        source,
+        introduced_by,
+        tick,
+    };
+    let state = &mut world.encounters;
+    state.last_tick = Some(tick);
+    state.recent.push(encounter.clone());
+    let overflow = state.recent.len().saturating_sub(config.max_recent);
+    state.recent.drain(..overflow);
+    encounter
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::content_preferences::ContentPreferences;
+    use crate::types::{AbstractNpc, WorldSeed};
+
+    fn resident(id: u64, district: &str) -> AbstractNpc {
+        AbstractNpc {
+            id: NpcId(id),
+            age: 30,
+            job: "clerk".to_string(),
+            district: district.to_string(),
+            household_id: id,
+            traits: Default::default(),
+            seed: id,
+            attachment_style: Default::default(),
+        }
+    }
+
+    fn world_with_neighbor() -> WorldState {
+        let mut world = WorldState::new(WorldSeed(11), NpcId(1));
+        world.encounters.config.daily_chance = 1.0;
+        world.npcs.insert(NpcId(1), resident(1, "harbor"));
+        world.npcs.insert(NpcId(2), resident(2, "harbor"));
+        world.npcs.insert(NpcId(3), resident(3, "uptown"));
+        world
+    }
+
+    #[test]
+    fn neighbors_are_met_and_left_for_introductions() {
+        let mut world = world_with_neighbor();
+        world.current_tick.0 = 24;
+        let encounter = scan_for_encounters(&mut world).expect("encounter");
+        assert_eq!(encounter.npc_id, NpcId(2));
+        assert_eq!(encounter.source, EncounterSource::Neighborhood);
+
+        assert!(world.known_npcs.contains(&NpcId(2)));
+        let rel = world.get_relationship(NpcId(1), NpcId(2));
+        assert!(rel.familiarity > 0.0 && rel.affection < 1.0);
+        assert!(world.relationships.contains_key(&(NpcId(2), NpcId(1))));
+        let memory = world.memory_entries.last().unwrap();
+        assert_eq!(memory.id, "encounter:0");
+        assert!(memory.tags.contains(&ENCOUNTER_TAG.to_string()));
+        assert!(world.world_flags.has_dynamic(&met_flag(NpcId(2))));
+        assert!(world.world_flags.has_dynamic(NEW_ACQUAINTANCE_FLAG));
+
+        // Cooling down the next day; the uptown stranger never qualifies and
+        // the newcomer flag drops once the introduction window closes.
+        world.current_tick.0 = 48;
+        assert!(scan_for_encounters(&mut world).is_none());
+        world.current_tick.0 = 24 + world.encounters.config.cooldown_ticks;
+        assert!(scan_for_encounters(&mut world).is_none());
+        assert!(!world.world_flags.has_dynamic(NEW_ACQUAINTANCE_FLAG));
+        assert!(!world.known_npcs.contains(&NpcId(3)));
+    }
+
+    #[test]
+    fn friends_introduce_their_friends() {
+        let mut world = WorldState::new(WorldSeed(11), NpcId(1));
+        world.encounters.config.daily_chance = 1.0;
+        world.npcs.insert(NpcId(4), resident(4, "harbor"));
+        world.npcs.insert(NpcId(5), resident(5, "uptown"));
+        world.known_npcs.push(NpcId(4));
+        let friendly = Relationship {
+            affection: 4.0,
+            ..Relationship::default()
+        };
+        world.set_relationship(NpcId(1), NpcId(4), friendly);
+        world.set_relationship(NpcId(4), NpcId(5), friendly);
+
+        let encounter = scan_for_encounters(&mut world).expect("encounter");
+        assert_eq!(encounter.npc_id, NpcId(5));
+        assert_eq!(encounter.source, EncounterSource::MutualFriend);
+        assert_eq!(encounter.introduced_by, Some(NpcId(4)));
+        assert!(world.get_relationship(NpcId(1), NpcId(5)).trust > 0.0);
+    }
+
+    #[test]
+    fn shared_routine_and_preferences_shape_meetings() {
+        let mut world = WorldState::new(WorldSeed(11), NpcId(1));
+        world.player_life_stage = LifeStage::YoungAdult;
+        let coworker = crate::npc::NpcPrototype {
+            id: NpcId(6),
+            display_name: "Coworker".to_string(),
+            role_label: None,
+            role_tags: Vec::new(),
+            personality: crate::npc::PersonalityVector {
+                warmth: 0.3,
+                dominance: 0.0,
+                volatility: 0.0,
+                conscientiousness: 0.6,
+                openness: 0.5,
+            },
+            base_stats: Default::default(),
+            active_stages: Vec::new(),
+            schedule: NpcSchedule::default(),
+            home_district: None,
+            tag_affinities: Default::default(),
+            major: false,
+        }
+        .with_default_work_schedule();
+        world.npc_prototypes.insert(NpcId(6), coworker);
+
+        let pool = candidates(&world);
+        assert_eq!(pool.len(), 1);
+        assert_eq!(pool[0].source, EncounterSource::Work);
+
+        world.encounters.config.daily_chance = 1.0;
+        let mut prefs = ContentPreferences::default();
+        prefs.blocked_tags.insert("social".to_string());
+        world.content_preferences = prefs;
+        assert!(scan_for_encounters(&mut world).is_none());
+        assert!(world.known_npcs.is_empty());
+    }
+}
@@ -0,0 +1,228 @@
+//! Typed world events for embedders to observe without polling state.
+//!
+//! Systems publish a [`WorldEvent`] to the world's [`EventBus`] when
+//! something worth reacting to happens: a relationship milestone is queued,
+//! the narrative heat band moves, the player reaches a new life stage, a
+//! storylet fires. Events wait on the bus, stamped with a sequence number and
+//! tick, until an embedder drains them. The bus keeps at most `capacity`
+//! events, dropping the oldest first, so a world nobody polls stays bounded.
+//!
+//! Heat bands and life stages change in many places, so they are watched
+//! rather than published at each call site: [`watch_world_changes`] compares
+//! them against the last values it saw and publishes the difference. The
+//! world runs it at the end of every tick; the director runs it after an
+//! outcome moves heat.
+
+use serde::{Deserialize, Serialize};
+use std::collections::VecDeque;
+
+use crate::narrative_heat::NarrativeHeatBand;
+use crate::relationship_milestones::{RelationshipMilestoneEvent, RelationshipMilestoneKind};
+use crate::types::{LifeStage, WorldState};
+
+/// Something that happened in the world.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(tag = "type", rename_all = "snake_case")]
+pub enum WorldEvent {
+    /// A relationship milestone was queued.
+    MilestoneReached {
+        /// NPC whose relationship changed.
+        actor_id: u64,
+        /// NPC the relationship is with.
+        target_id: u64,
+        /// Type of milestone.
+        kind: RelationshipMilestoneKind,
+        /// Previous role label.
+        from_role: String,
+        /// New role label.
+        to_role: String,
+    },
+    /// Narrative heat crossed into another band.
+    HeatBandChanged {
+        /// Band before.
+        from: NarrativeHeatBand,
+        /// Band after.
+        to: NarrativeHeatBand,
+    },
+    /// The player moved to another life stage.
+    LifeStageChanged {
+        /// Stage before.
+        from: LifeStage,
+        /// Stage after.
+        to: LifeStage,
+    },
+    /// A storylet fired.
+    StoryletFired {
+        /// Storylet ID.
+        storylet_id: String,
+        /// The choice whose outcome was applied, if any.
+        choice_id: Option<String>,
+    },
+}
+
+impl WorldEvent {
+    /// Stable kind name: `milestone_reached`, `heat_band_changed`,
+    /// `life_stage_changed` or `storylet_fired`.
+    pub fn kind(&self) -> &'static str {
+        match self {
+            WorldEvent::MilestoneReached { .. } => "milestone_reached",
+            WorldEvent::HeatBandChanged { .. } => "heat_band_changed",
+            WorldEvent::LifeStageChanged { .. } => "life_stage_changed",
+            WorldEvent::StoryletFired { .. } => "storylet_fired",
+        }
+    }
+}
+
+impl From<&RelationshipMilestoneEvent> for WorldEvent {
+    fn from(event: &RelationshipMilestoneEvent) -> Self {
+        WorldEvent::MilestoneReached {
+            actor_id: event.actor_id,
+            target_id: event.target_id,
+            kind: event.kind,
+            from_role: event.from_role.clone(),
+            to_role: event.to_role.clone(),
+        }
+    }
+}
+
+/// A published event waiting to be drained.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct PublishedEvent {
+    /// Publication sequence number (starts at 1, never reused).
+    pub seq: u64,
+    /// Tick it was published at.
+    pub tick: u64,
+    /// The event.
+    pub event: WorldEvent,
+}
+
+fn default_capacity() -> usize {
+    256
+}
+
+/// Event bus state stored on the world.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct EventBus {
+    /// Most events kept; the oldest are dropped first.
+    #[serde(default = "default_capacity")]
+    pub capacity: usize,
+    /// Events not yet drained, oldest first.
+    #[serde(default)]
+    pub events: VecDeque<PublishedEvent>,
+    /// Sequence number of the last published event.
+    #[serde(default)]
+    pub last_seq: u64,
+    /// Heat band [`watch_world_changes`] last saw.
+    #[serde(default)]
+    pub last_heat_band: Option<NarrativeHeatBand>,
+    /// Life stage [`watch_world_changes`] last saw.
+    #[serde(default)]
+    pub last_life_stage: Option<LifeStage>,
+}
+
+impl Default for EventBus {
+    fn default() -> Self {
+        Self {
+            capacity: default_capacity(),
+            events: VecDeque::new(),
+            last_seq: 0,
+            last_heat_band: None,
+            last_life_stage: None,
+        }
+    }
+}
+
+impl EventBus {
+    /// Publish `event` at `tick`. Returns its sequence number.
+    pub fn publish(&mut self, tick: u64, event: WorldEvent) -> u64 {
+        self.last_seq += 1;
+        self.events.push_back(PublishedEvent {
+            seq: self.last_seq,
+            tick,
+            event,
+        });
+        while self.events.len() > self.capacity {
+            self.events.pop_front();
+        }
+        self.last_seq
+    }
+
+    /// Take every waiting event, oldest first.
+    pub fn drain(&mut self) -> Vec<PublishedEvent> {
+        self.events.drain(..).collect()
+    }
+
+    /// Waiting events, oldest first, without taking them.
+    pub fn pending(&self) -> impl Iterator<Item = &PublishedEvent> {
+        self.events.iter()
+    }
+}
+
+/// Publish `event` on the world's bus at the current tick.
+pub fn publish_world_event(world: &mut WorldState, event: WorldEvent) -> u64 {
+    let tick = world.current_tick.0;
+    world.event_bus.publish(tick, event)
+}
+
+/// Publish heat band and life stage changes since the last call.
+///
+/// The first call only records the current values.
+pub fn watch_world_changes(world: &mut WorldState) {
+    let tick = world.current_tick.0;
+    let band = world.narrative_heat.band();
+    let stage = world.player_life_stage;
+    let bus = &mut world.event_bus;
+
+    if let Some(from) = bus.last_heat_band.filter(|from| *from != band) {
+        bus.publish(tick, WorldEvent::HeatBandChanged { from, to: band });
+    }
+    bus.last_heat_band = Some(band);
+
+    if let Some(from) = bus.last_life_stage.filter(|from| *from != stage) {
+        bus.publish(tick, WorldEvent::LifeStageChanged { from, to: stage });
+    }
+    bus.last_life_stage = Some(stage);
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::types::{NpcId, WorldSeed};
+
+    #[test]
+    fn watched_changes_publish_once_and_drain_in_order() {
+        let mut world = WorldState::new(WorldSeed(8), NpcId(1));
+        watch_world_changes(&mut world);
+        assert_eq!(world.event_bus.pending().count(), 0);
+
+        world.add_heat(90.0);
+        world.player_life_stage = LifeStage::Teen;
+        watch_world_changes(&mut world);
+        watch_world_changes(&mut world);
+
+        let events = world.event_bus.drain();
+        let kinds: Vec<&str> = events.iter().map(|e| e.event.kind()).collect();
+        assert_eq!(kinds, vec!["heat_band_changed", "life_stage_changed"]);
+        assert_eq!(events[1].seq, 2);
+        assert_eq!(world.event_bus.pending().count(), 0);
+    }
+
+    #[test]
+    fn the_bus_drops_the_oldest_past_capacity() {
+        let mut bus = EventBus {
+            capacity: 2,
+            ..Default::default()
+        };
+        for id in ["a", "b", "c"] {
+            bus.publish(
+                1,
+                WorldEvent::StoryletFired {
+                    storylet_id: id.to_string(),
+                    choice_id: None,
+                },
+            );
+        }
+        let seqs: Vec<u64> = bus.pending().map(|e| e.seq).collect();
+        assert_eq!(seqs, vec![2, 3]);
+    }
+}
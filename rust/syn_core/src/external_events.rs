@@ -0,0 +1,554 @@
+//! External event injection.
+//!
+//! Embedders (a companion app, a scripted demo) can push happenings into the
+//! simulation. An event names one of a fixed set of kinds and carries a JSON
+//! payload; it is checked against the world's [`ExternalEventPolicy`] before
+//! anything changes, applied at the current tick in call order, and recorded
+//! in the narrative log so a replay of the same calls yields the same world.
+//!
+//! Kinds and payloads:
+//! - `world_flag`: `{"flag": "external:festival", "value": true}`
+//! - `heat`: `{"delta": 10.0}`
+//! - `memory`: `{"tags": ["concert"], "intensity": 0.6, "npc_id": 1, "participants": [2]}`
+//! - `trigger`: `{"trigger": "festival"}`: storylets with that trigger kind
+//!   are favored until one fires
+
+use crate::action_budget::PLAYER_ACTION_TRIGGER;
+use crate::errors::SynError;
+use crate::flag_changes::{set_world_flag, FLAG_SOURCE_EXTERNAL};
+use crate::types::{MemoryEntryRecord, NpcId, WorldState};
+use serde::{Deserialize, Serialize};
+use std::collections::VecDeque;
+
+/// Default number of records kept in the narrative log.
+pub const DEFAULT_NARRATIVE_LOG_CAPACITY: usize = 128;
+
+/// Whitelisted external event kinds.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub enum ExternalEventKind {
+    /// Set or clear a world flag.
+    WorldFlag,
+    /// Raise or lower narrative heat.
+    Heat,
+    /// Record a memory for the player or an NPC.
+    Memory,
+    /// Force a storylet trigger context.
+    Trigger,
+}
+
+impl ExternalEventKind {
+    /// All kinds, in declaration order.
+    pub const ALL: [ExternalEventKind; 4] = [
+        ExternalEventKind::WorldFlag,
+        ExternalEventKind::Heat,
+        ExternalEventKind::Memory,
+        ExternalEventKind::Trigger,
+    ];
+
+    /// Wire name used by the injection API.
+    pub fn as_str(self) -> &'static str {
+        match self {
+            ExternalEventKind::WorldFlag => "world_flag",
+            ExternalEventKind::Heat => "heat",
+            ExternalEventKind::Memory => "memory",
+            ExternalEventKind::Trigger => "trigger",
+        }
+    }
+
+    /// Parse a wire name (case-insensitive).
+    pub fn parse(name: &str) -> Option<Self> {
+        Self::ALL
+            .into_iter()
+            .find(|kind| kind.as_str().eq_ignore_ascii_case(name.trim()))
+    }
+}
+
+/// A parsed, not yet validated, external event.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub enum ExternalEvent {
+    /// Set (`value = true`) or clear a world flag.
+    WorldFlag {
+        /// Flag name.
+        flag: String,
+        /// New value.
+        value: bool,
+    },
+    /// Add `delta` to narrative heat.
+    Heat {
+        /// Heat change.
+        delta: f32,
+    },
+    /// Record a memory.
+    Memory {
+        /// Memory holder; the player if omitted.
+        npc_id: Option<u64>,
+        /// Memory tags.
+        tags: Vec<String>,
+        /// Emotional intensity (-1.0..=1.0).
+        intensity: f32,
+        /// Other NPCs involved.
+        participants: Vec<u64>,
+    },
+    /// Favor storylets with this trigger kind until one fires.
+    Trigger {
+        /// Trigger kind.
+        trigger: String,
+    },
+}
+
+#[derive(Deserialize)]
+struct WorldFlagPayload {
+    flag: String,
+    #[serde(default = "default_true")]
+    value: bool,
+}
+
+#[derive(Deserialize)]
+struct HeatPayload {
+    delta: f32,
+}
+
+#[derive(Deserialize)]
+struct MemoryPayload {
+    #[serde(default)]
+    npc_id: Option<u64>,
+    #[serde(default)]
+    tags: Vec<String>,
+    intensity: f32,
+    #[serde(default)]
+    participants: Vec<u64>,
+}
+
+#[derive(Deserialize)]
+struct TriggerPayload {
+    trigger: String,
+}
+
+fn default_true() -> bool {
+    true
+}
+
+impl ExternalEvent {
+    /// Parse an event from its kind name and JSON payload.
+    pub fn parse(kind: &str, payload: &str) -> Result<Self, SynError> {
+        let kind = ExternalEventKind::parse(kind).ok_or_else(|| {
+            SynError::InvalidState(format!("unknown external event kind '{}'", kind))
+        })?;
+        let bad_payload = |e: serde_json::Error| {
+            SynError::InvalidState(format!("invalid '{}' payload: {}", kind.as_str(), e))
+        };
+
+        Ok(match kind {
+            ExternalEventKind::WorldFlag => {
+                let p: WorldFlagPayload = serde_json::from_str(payload).map_err(bad_payload)?;
+                ExternalEvent::WorldFlag {
+                    flag: p.flag,
+                    value: p.value,
+                }
+            }
+            ExternalEventKind::Heat => {
+                let p: HeatPayload = serde_json::from_str(payload).map_err(bad_payload)?;
+                ExternalEvent::Heat { delta: p.delta }
+            }
+            ExternalEventKind::Memory => {
+                let p: MemoryPayload = serde_json::from_str(payload).map_err(bad_payload)?;
+                ExternalEvent::Memory {
+                    npc_id: p.npc_id,
+                    tags: p.tags,
+                    intensity: p.intensity,
+                    participants: p.participants,
+                }
+            }
+            ExternalEventKind::Trigger => {
+                let p: TriggerPayload = serde_json::from_str(payload).map_err(bad_payload)?;
+                ExternalEvent::Trigger { trigger: p.trigger }
+            }
+        })
+    }
+
+    /// The event's kind.
+    pub fn kind(&self) -> ExternalEventKind {
+        match self {
+            ExternalEvent::WorldFlag { .. } => ExternalEventKind::WorldFlag,
+            ExternalEvent::Heat { .. } => ExternalEventKind::Heat,
+            ExternalEvent::Memory { .. } => ExternalEventKind::Memory,
+            ExternalEvent::Trigger { .. } => ExternalEventKind::Trigger,
+        }
+    }
+
+    /// One-line description for the narrative log.
+    pub fn summary(&self) -> String {
+        match self {
+            ExternalEvent::WorldFlag { flag, value } => format!("flag {} = {}", flag, value),
+            ExternalEvent::Heat { delta } => format!("heat {:+.1}", delta),
+            ExternalEvent::Memory {
+                npc_id,
+                tags,
+                intensity,
+                ..
+            } => format!(
+                "memory [{}] ({:+.2}) for {}",
+                tags.join(", "),
+                intensity,
+                npc_id.map_or_else(|| "player".to_string(), |id| format!("npc {}", id))
+            ),
+            ExternalEvent::Trigger { trigger } => format!("trigger {}", trigger),
+        }
+    }
+}
+
+/// What embedders are allowed to inject.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct ExternalEventPolicy {
+    /// Kinds accepted at all.
+    pub allowed_kinds: Vec<ExternalEventKind>,
+    /// World flags must start with one of these prefixes, so embedders cannot
+    /// flip flags owned by storylets or systems.
+    pub allowed_flag_prefixes: Vec<String>,
+    /// Largest accepted heat change, in either direction.
+    pub max_heat_delta: f32,
+    /// Most tags on one injected memory.
+    pub max_memory_tags: usize,
+    /// Most forced triggers waiting at once.
+    pub max_pending_triggers: usize,
+}
+
+impl Default for ExternalEventPolicy {
+    fn default() -> Self {
+        Self {
+            allowed_kinds: ExternalEventKind::ALL.to_vec(),
+            allowed_flag_prefixes: vec!["external:".to_string()],
+            max_heat_delta: 20.0,
+            max_memory_tags: 8,
+            max_pending_triggers: 4,
+        }
+    }
+}
+
+impl ExternalEventPolicy {
+    /// Check an event against the whitelist without applying it.
+    pub fn validate(&self, event: &ExternalEvent, world: &WorldState) -> Result<(), SynError> {
+        let reject = |msg: String| Err(SynError::InvalidState(msg));
+
+        if !self.allowed_kinds.contains(&event.kind()) {
+            return reject(format!(
+                "external '{}' events are not allowed",
+                event.kind().as_str()
+            ));
+        }
+
+        match event {
+            ExternalEvent::WorldFlag { flag, .. } => {
+                if !self
+                    .allowed_flag_prefixes
+                    .iter()
+                    .any(|prefix| flag.starts_with(prefix.as_str()) && flag.len() > prefix.len())
+                {
+                    return reject(format!("flag '{}' is not in an allowed namespace", flag));
+                }
+            }
+            ExternalEvent::Heat { delta } => {
+                if !delta.is_finite() || delta.abs() > self.max_heat_delta {
+                    return reject(format!(
+                        "heat delta {} outside ±{}",
+                        delta, self.max_heat_delta
+                    ));
+                }
+            }
+            ExternalEvent::Memory {
+                npc_id,
+                tags,
+                intensity,
+                participants,
+            } => {
+                if tags.is_empty() || tags.len() > self.max_memory_tags {
+                    return reject(format!(
+                        "memory needs 1..={} tags, got {}",
+                        self.max_memory_tags,
+                        tags.len()
+                    ));
+                }
+                if tags.iter().any(|t| t.trim().is_empty()) {
+                    return reject("memory tags must not be empty".to_string());
+                }
+                if !intensity.is_finite() || !(-1.0..=1.0).contains(intensity) {
+                    return reject(format!("memory intensity {} outside -1.0..=1.0", intensity));
+                }
+                for id in npc_id.iter().chain(participants) {
+                    if !is_known_actor(world, NpcId(*id)) {
+                        return reject(format!("unknown npc {}", id));
+                    }
+                }
+            }
+            ExternalEvent::Trigger { trigger } => {
+                if trigger.is_empty()
+                    || !trigger
+                        .chars()
+                        .all(|c| c.is_ascii_alphanumeric() || c == '_' || c == ':')
+                {
+                    return reject(format!("invalid trigger '{}'", trigger));
+                }
+                if trigger.eq_ignore_ascii_case(PLAYER_ACTION_TRIGGER) {
+                    return reject(format!("trigger '{}' is reserved", trigger));
+                }
+                if world.external_events.forced_triggers.len() >= self.max_pending_triggers {
+                    return reject("too many pending forced triggers".to_string());
+                }
+            }
+        }
+        Ok(())
+    }
+}
+
+fn is_known_actor(world: &WorldState, id: NpcId) -> bool {
+    id == world.player_id || world.npcs.contains_key(&id) || world.npc_prototypes.contains_key(&id)
+}
+
+/// One injected event in the narrative log.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct NarrativeLogEntry {
+    /// Injection sequence number (starts at 1, never reused).
+    pub seq: u64,
+    /// Tick the event was applied at.
+    pub tick: u64,
+    /// Event kind.
+    pub kind: ExternalEventKind,
+    /// Human-readable description.
+    pub summary: String,
+}
+
+/// External event state stored on the world.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct ExternalEvents {
+    /// Injection whitelist.
+    #[serde(default)]
+    pub policy: ExternalEventPolicy,
+    /// Applied events, oldest first (ring buffer).
+    #[serde(default)]
+    pub log: VecDeque<NarrativeLogEntry>,
+    /// Log capacity.
+    #[serde(default = "default_log_capacity")]
+    pub log_capacity: usize,
+    /// Forced trigger kinds waiting for a storylet, oldest first.
+    #[serde(default)]
+    pub forced_triggers: VecDeque<String>,
+    /// Sequence number of the last injected event.
+    #[serde(default)]
+    pub last_seq: u64,
+}
+
+fn default_log_capacity() -> usize {
+    DEFAULT_NARRATIVE_LOG_CAPACITY
+}
+
+impl Default for ExternalEvents {
+    fn default() -> Self {
+        Self {
+            policy: ExternalEventPolicy::default(),
+            log: VecDeque::new(),
+            log_capacity: DEFAULT_NARRATIVE_LOG_CAPACITY,
+            forced_triggers: VecDeque::new(),
+            last_seq: 0,
+        }
+    }
+}
+
+impl ExternalEvents {
+    /// Whether a forced trigger of this kind is waiting.
+    pub fn is_forced(&self, trigger: &str) -> bool {
+        self.forced_triggers
+            .iter()
+            .any(|t| t.eq_ignore_ascii_case(trigger))
+    }
+
+    /// Consume the oldest forced trigger of this kind. Returns whether one was waiting.
+    pub fn consume_trigger(&mut self, trigger: &str) -> bool {
+        match self
+            .forced_triggers
+            .iter()
+            .position(|t| t.eq_ignore_ascii_case(trigger))
+        {
+            Some(idx) => {
+                self.forced_triggers.remove(idx);
+                true
+            }
+            None => false,
+        }
+    }
+
+    /// Most recent log entries first, at most `limit`.
+    pub fn latest(&self, limit: usize) -> impl Iterator<Item = &NarrativeLogEntry> {
+        self.log.iter().rev().take(limit)
+    }
+
+    fn record(&mut self, tick: u64, event: &ExternalEvent) -> u64 {
+        self.last_seq += 1;
+        self.log.push_back(NarrativeLogEntry {
+            seq: self.last_seq,
+            tick,
+            kind: event.kind(),
+            summary: event.summary(),
+        });
+        while self.log.len() > self.log_capacity {
+            self.log.pop_front();
+        }
+        self.last_seq
+    }
+}
+
+/// Result of applying an external event.
+#[derive(Debug, Clone, PartialEq)]
+pub struct InjectedEvent {
+    /// Narrative log sequence number.
+    pub seq: u64,
+    /// Memory to hand to the memory system, for `memory` events.
+    ///
+    /// Also appended to `world.memory_entries` so it is saved with the world.
+    pub memory: Option<MemoryEntryRecord>,
+}
+
+/// Validate and apply an external event at the world's current tick.
+///
+/// Rejected events change nothing and are not logged.
+pub fn inject_external_event(
+    world: &mut WorldState,
+    event: ExternalEvent,
+) -> Result<InjectedEvent, SynError> {
+    world.external_events.policy.validate(&event, world)?;
+
+    let tick = world.current_tick;
+    let seq = world.external_events.record(tick.0, &event);
+    let mut memory = None;
+
+    match event {
+        ExternalEvent::WorldFlag { flag, value } => {
+            set_world_flag(world, &flag, value, FLAG_SOURCE_EXTERNAL);
+        }
+        ExternalEvent::Heat { delta } => world.narrative_heat.add(delta),
+        ExternalEvent::Memory {
+            npc_id,
+            tags,
+            intensity,
+            participants,
+        } => {
+            let record = MemoryEntryRecord {
+                id: format!("external_{}", seq),
+                event_id: "external:memory".to_string(),
+                npc_id: npc_id.map_or(world.player_id, NpcId),
+                sim_tick: tick,
+                emotional_intensity: intensity,
+                tags,
+                participants,
+                ..Default::default()
+            };
+            world.memory_entries.push(record.clone());
+            memory = Some(record);
+        }
+        ExternalEvent::Trigger { trigger } => {
+            world.external_events.forced_triggers.push_back(trigger)
+        }
+    }
+
+    Ok(InjectedEvent { seq, memory })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::types::WorldSeed;
+
+    fn world() -> WorldState {
+        WorldState::new(WorldSeed(3), NpcId(1))
+    }
+
+    #[test]
+    fn test_parse_known_kinds() {
+        let event = ExternalEvent::parse("World_Flag", r#"{"flag": "external:rain"}"#).unwrap();
+        assert_eq!(
+            event,
+            ExternalEvent::WorldFlag {
+                flag: "external:rain".to_string(),
+                value: true
+            }
+        );
+        assert!(ExternalEvent::parse("teleport", "{}").is_err());
+        assert!(ExternalEvent::parse("heat", r#"{"amount": 3}"#).is_err());
+    }
+
+    #[test]
+    fn test_flag_outside_namespace_is_rejected_and_not_logged() {
+        let mut world = world();
+        let event = ExternalEvent::WorldFlag {
+            flag: "has_ever_worked".to_string(),
+            value: true,
+        };
+        assert!(inject_external_event(&mut world, event).is_err());
+        assert!(!world.world_flags.has_any("has_ever_worked"));
+        assert!(world.external_events.log.is_empty());
+    }
+
+    #[test]
+    fn test_applied_events_are_logged_in_order() {
+        let mut world = world();
+        let heat_before = world.narrative_heat.value();
+        let events = [
+            ExternalEvent::parse("world_flag", r#"{"flag": "external:festival"}"#).unwrap(),
+            ExternalEvent::parse("heat", r#"{"delta": 5}"#).unwrap(),
+            ExternalEvent::parse("trigger", r#"{"trigger": "festival"}"#).unwrap(),
+        ];
+        for event in events {
+            inject_external_event(&mut world, event).unwrap();
+        }
+
+        assert!(world.world_flags.has_any("external:festival"));
+        assert!((world.narrative_heat.value() - heat_before - 5.0).abs() < 1e-4);
+        assert!(world.external_events.is_forced("festival"));
+        let seqs: Vec<u64> = world.external_events.log.iter().map(|e| e.seq).collect();
+        assert_eq!(seqs, vec![1, 2, 3]);
+        assert_eq!(world.external_events.log[1].summary, "heat +5.0");
+    }
+
+    #[test]
+    fn test_heat_and_trigger_limits() {
+        let mut world = world();
+        assert!(inject_external_event(&mut world, ExternalEvent::Heat { delta: 50.0 }).is_err());
+
+        let reserved = ExternalEvent::Trigger {
+            trigger: PLAYER_ACTION_TRIGGER.to_string(),
+        };
+        assert!(inject_external_event(&mut world, reserved).is_err());
+
+        let max = world.external_events.policy.max_pending_triggers;
+        for i in 0..max {
+            let event = ExternalEvent::Trigger {
+                trigger: format!("demo_{}", i),
+            };
+            inject_external_event(&mut world, event).unwrap();
+        }
+        let overflow = ExternalEvent::Trigger {
+            trigger: "demo_extra".to_string(),
+        };
+        assert!(inject_external_event(&mut world, overflow).is_err());
+        assert!(world.external_events.consume_trigger("demo_0"));
+        assert!(!world.external_events.consume_trigger("demo_0"));
+    }
+
+    #[test]
+    fn test_memory_events_need_known_actors() {
+        let mut world = world();
+        let unknown = ExternalEvent::parse(
+            "memory",
+            r#"{"npc_id": 99, "tags": ["concert"], "intensity": 0.5}"#,
+        )
+        .unwrap();
+        assert!(inject_external_event(&mut world, unknown).is_err());
+
+        let own =
+            ExternalEvent::parse("memory", r#"{"tags": ["concert"], "intensity": 0.5}"#).unwrap();
+        let injected = inject_external_event(&mut world, own).unwrap();
+        let memory = injected.memory.unwrap();
+        assert_eq!(memory.npc_id, world.player_id);
+        assert_eq!(memory.id, "external_1");
+        assert_eq!(world.memory_entries, vec![memory]);
+    }
+}
@@ -0,0 +1,242 @@
+//! World flag change stream for reactive storylets.
+//!
+//! Flags flipped through [`set_world_flag`] are recorded as [`FlagChange`]s
+//! (flag, old and new value, source, tick). The director treats a recent
+//! change as a trigger context: a storylet whose trigger kind is
+//! `flag_set:<flag>` or `flag_cleared:<flag>` is eligible only while a
+//! matching change is pending, so a reaction lands the same day the
+//! transition happened (`got_fired` set → a reaction that evening).
+//!
+//! A change stays pending for `reaction_window_ticks`. When a reaction
+//! fires it consumes the change and puts the flag on a reaction cooldown,
+//! so a flag that flips back and forth doesn't get a reaction every time.
+//!
+//! Sources name what flipped the flag: `storylet:<id>`, `external`,
+//! `calendar`, `encounter`, `rival`, `dependent`, `jealousy`.
+
+use serde::{Deserialize, Serialize};
+use std::collections::{BTreeMap, VecDeque};
+
+use crate::types::WorldState;
+
+/// Trigger kind prefix for reactions to a flag being set.
+pub const FLAG_SET_TRIGGER_PREFIX: &str = "flag_set:";
+/// Trigger kind prefix for reactions to a flag being cleared.
+pub const FLAG_CLEARED_TRIGGER_PREFIX: &str = "flag_cleared:";
+
+/// Source for flags set by an injected external event.
+pub const FLAG_SOURCE_EXTERNAL: &str = "external";
+/// Source for calendar flags.
+pub const FLAG_SOURCE_CALENDAR: &str = "calendar";
+/// Source for first-meeting flags.
+pub const FLAG_SOURCE_ENCOUNTER: &str = "encounter";
+/// Source for rival flags.
+pub const FLAG_SOURCE_RIVAL: &str = "rival";
+/// Source for dependent needs-care flags.
+pub const FLAG_SOURCE_DEPENDENT: &str = "dependent";
+/// Source for PostLife flags.
+pub const FLAG_SOURCE_POST_LIFE: &str = "post_life";
+/// Source for jealous confrontation flags.
+pub const FLAG_SOURCE_JEALOUSY: &str = "jealousy";
+/// Source for flags raised by storylet gossip getting around.
+pub const FLAG_SOURCE_GOSSIP: &str = "gossip";
+
+/// Source for flags set by a storylet's outcome.
+pub fn storylet_flag_source(storylet_id: &str) -> String {
+    format!("storylet:{}", storylet_id)
+}
+
+/// One flag flip.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct FlagChange {
+    /// Flag name.
+    pub flag: String,
+    /// Value before the change.
+    pub old: bool,
+    /// Value after the change.
+    pub new: bool,
+    /// What flipped it.
+    pub source: String,
+    /// Tick of the change.
+    pub tick: u64,
+}
+
+impl FlagChange {
+    /// Trigger kind reaction storylets name to react to this change.
+    pub fn trigger_kind(&self) -> String {
+        let prefix = if self.new {
+            FLAG_SET_TRIGGER_PREFIX
+        } else {
+            FLAG_CLEARED_TRIGGER_PREFIX
+        };
+        format!("{}{}", prefix, self.flag)
+    }
+}
+
+/// Whether a storylet trigger kind reacts to flag changes.
+pub fn is_flag_trigger(kind: &str) -> bool {
+    kind.starts_with(FLAG_SET_TRIGGER_PREFIX) || kind.starts_with(FLAG_CLEARED_TRIGGER_PREFIX)
+}
+
+/// Tuning for flag change reactions.
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct FlagChangeConfig {
+    /// Ticks a change stays pending for a reaction.
+    pub reaction_window_ticks: u64,
+    /// Ticks after a reaction before the same flag can be reacted to again.
+    pub reaction_cooldown_ticks: u64,
+    /// Most changes kept; the oldest are dropped first.
+    pub capacity: usize,
+}
+
+impl Default for FlagChangeConfig {
+    fn default() -> Self {
+        Self {
+            reaction_window_ticks: 24,
+            reaction_cooldown_ticks: 72,
+            capacity: 64,
+        }
+    }
+}
+
+/// Flag change state stored on the world.
+#[derive(Debug, Clone, PartialEq, Default, Serialize, Deserialize)]
+pub struct FlagChangeState {
+    /// Tuning.
+    #[serde(default)]
+    pub config: FlagChangeConfig,
+    /// Changes not yet reacted to, oldest first.
+    #[serde(default)]
+    pub pending: VecDeque<FlagChange>,
+    /// Tick of the last reaction per flag.
+    #[serde(default)]
+    pub last_reaction_tick: BTreeMap<String, u64>,
+}
+
+impl FlagChangeState {
+    /// Record a change, dropping expired and overflowing ones.
+    pub fn record(&mut self, change: FlagChange) {
+        let window = self.config.reaction_window_ticks;
+        let tick = change.tick;
+        self.pending
+            .retain(|c| tick.saturating_sub(c.tick) <= window);
+        self.pending.push_back(change);
+        while self.pending.len() > self.config.capacity {
+            self.pending.pop_front();
+        }
+    }
+
+    /// Whether `flag` was reacted to within the cooldown.
+    pub fn on_cooldown(&self, flag: &str, tick: u64) -> bool {
+        self.last_reaction_tick
+            .get(flag)
+            .is_some_and(|last| tick.saturating_sub(*last) < self.config.reaction_cooldown_ticks)
+    }
+
+    /// The oldest change a storylet with this trigger kind can react to at
+    /// `tick`: within the window and off cooldown.
+    pub fn pending_for(&self, trigger_kind: &str, tick: u64) -> Option<&FlagChange> {
+        self.pending.iter().find(|c| {
+            tick.saturating_sub(c.tick) <= self.config.reaction_window_ticks
+                && !self.on_cooldown(&c.flag, tick)
+                && c.trigger_kind().eq_ignore_ascii_case(trigger_kind)
+        })
+    }
+
+    /// Consume the change a reaction with this trigger kind answers and put
+    /// its flag on cooldown. Returns the change, if one was pending.
+    pub fn consume(&mut self, trigger_kind: &str, tick: u64) -> Option<FlagChange> {
+        let change = self.pending_for(trigger_kind, tick)?.clone();
+        self.pending.retain(|c| c.flag != change.flag);
+        self.last_reaction_tick.insert(change.flag.clone(), tick);
+        Some(change)
+    }
+
+    /// Pending changes, newest first.
+    pub fn latest(&self, limit: usize) -> impl Iterator<Item = &FlagChange> {
+        self.pending.iter().rev().take(limit)
+    }
+}
+
+/// Set or clear a world flag, recording the change if it flipped.
+///
+/// Returns the recorded change (`None` if the flag already had that value).
+pub fn set_world_flag(
+    world: &mut WorldState,
+    flag: &str,
+    value: bool,
+    source: &str,
+) -> Option<FlagChange> {
+    let old = world.world_flags.has_any(flag);
+    if old == value {
+        return None;
+    }
+    if value {
+        world.world_flags.set_any(flag);
+    } else {
+        world.world_flags.clear_any(flag);
+    }
+    let change = FlagChange {
+        flag: flag.to_string(),
+        old,
+        new: value,
+        source: source.to_string(),
+        tick: world.current_tick.0,
+    };
+    world.flag_changes.record(change.clone());
+    Some(change)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::types::{NpcId, SimTick, WorldSeed};
+
+    #[test]
+    fn only_real_flips_are_recorded() {
+        let mut world = WorldState::new(WorldSeed(1), NpcId(1));
+        world.current_tick = SimTick(10);
+
+        let change = set_world_flag(&mut world, "got_fired", true, "storylet:layoffs").unwrap();
+        assert!(!change.old && change.new);
+        assert_eq!(change.trigger_kind(), "flag_set:got_fired");
+        assert!(world.world_flags.has_any("got_fired"));
+        assert!(set_world_flag(&mut world, "got_fired", true, "storylet:layoffs").is_none());
+        assert_eq!(world.flag_changes.pending.len(), 1);
+
+        let cleared = set_world_flag(&mut world, "got_fired", false, FLAG_SOURCE_EXTERNAL).unwrap();
+        assert_eq!(cleared.trigger_kind(), "flag_cleared:got_fired");
+    }
+
+    #[test]
+    fn reactions_expire_and_respect_the_cooldown() {
+        let mut world = WorldState::new(WorldSeed(1), NpcId(1));
+        set_world_flag(&mut world, "got_fired", true, "storylet:layoffs");
+        let state = &mut world.flag_changes;
+        let window = state.config.reaction_window_ticks;
+
+        assert!(state.pending_for("flag_set:got_fired", window).is_some());
+        assert!(state
+            .pending_for("flag_set:got_fired", window + 1)
+            .is_none());
+        assert!(state.pending_for("flag_cleared:got_fired", 0).is_none());
+
+        assert!(state.consume("flag_set:got_fired", 5).is_some());
+        assert!(state.consume("flag_set:got_fired", 5).is_none());
+
+        // Flipping again within the cooldown doesn't earn another reaction.
+        world.current_tick = SimTick(6);
+        set_world_flag(&mut world, "got_fired", false, FLAG_SOURCE_EXTERNAL);
+        set_world_flag(&mut world, "got_fired", true, FLAG_SOURCE_EXTERNAL);
+        let state = &world.flag_changes;
+        assert!(state.pending_for("flag_set:got_fired", 6).is_none());
+        let later = 5 + state.config.reaction_cooldown_ticks;
+        world.current_tick = SimTick(later);
+        set_world_flag(&mut world, "got_fired", false, FLAG_SOURCE_EXTERNAL);
+        assert!(world
+            .flag_changes
+            .pending_for("flag_cleared:got_fired", later)
+            .is_some());
+    }
+}
@@ -4,10 +4,12 @@
 //! and rumor amplification mechanics. Information spreads through social networks
 //! based on relationship strength, NPC traits, and event salience.
 
+use crate::flag_changes::{set_world_flag, FLAG_SOURCE_GOSSIP};
 use crate::rng::DeterministicRng;
-use crate::types::{AbstractNpc, NpcId, Relationship, WorldState};
+use crate::types::{AbstractNpc, MemoryEntryRecord, NpcId, Relationship, WorldState};
 use serde::{Deserialize, Serialize};
-use std::collections::{HashMap, HashSet};
+use crate::collections::sorted_keys;
+use std::collections::{BTreeSet, HashMap, HashSet};
 
 /// A piece of gossip that can spread through social networks.
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -96,7 +98,7 @@ impl Rumor {
     /// Calculate decay factor based on age (older = less impactful)
     pub fn decay_factor(&self, current_tick: u64, half_life_ticks: u64) -> f32 {
         let age = current_tick.saturating_sub(self.created_tick);
-        let decay = 0.5_f32.powf(age as f32 / half_life_ticks as f32);
+        let decay = crate::det_math::half_life_factor(age as f32, half_life_ticks as f32);
         decay.clamp(0.01, 1.0)
     }
 }
@@ -166,8 +168,8 @@ pub struct SpreadResult {
 pub struct SocialCluster {
     /// Cluster identifier
     pub id: String,
-    /// Core members of this cluster
-    pub members: HashSet<NpcId>,
+    /// Core members of this cluster (ordered for stable trust averaging)
+    pub members: BTreeSet<NpcId>,
     /// Cluster cohesion (how tightly connected, 0.0-1.0)
     pub cohesion: f32,
     /// Average trust within the cluster
@@ -179,7 +181,7 @@ impl SocialCluster {
     pub fn new(id: impl Into<String>) -> Self {
         Self {
             id: id.into(),
-            members: HashSet::new(),
+            members: BTreeSet::new(),
             cohesion: 0.5,
             internal_trust: 0.5,
         }
@@ -532,12 +534,16 @@ impl GossipSystem {
     ) -> Vec<SpreadResult> {
         let mut results = Vec::new();
 
-        // Collect NPCs who know rumors and might spread them
-        let spreaders: Vec<(NpcId, Vec<String>)> = self
-            .knowledge
-            .iter()
-            .map(|(npc_id, known)| (*npc_id, known.keys().cloned().collect()))
+        // Collect NPCs who know rumors and might spread them, in id order so
+        // a seed replays the same spread
+        let spreaders: Vec<(NpcId, Vec<String>)> = sorted_keys(&self.knowledge)
+            .into_iter()
+            .map(|npc_id| {
+                let known = self.knowledge.get(&npc_id).map(sorted_keys).unwrap_or_default();
+                (npc_id, known)
+            })
             .collect();
+        let receiver_ids = sorted_keys(npcs);
 
         for (spreader_id, known_rumors) in spreaders {
             let spreader = match npcs.get(&spreader_id) {
@@ -562,10 +568,13 @@ impl GossipSystem {
                 }
 
                 // Find potential recipients (NPCs the spreader knows)
-                for (receiver_id, receiver) in npcs {
+                for receiver_id in &receiver_ids {
                     if *receiver_id == spreader_id {
                         continue;
                     }
+                    let Some(receiver) = npcs.get(receiver_id) else {
+                        continue;
+                    };
 
                     let rel_key = (spreader_id, *receiver_id);
                     let relationship = relationships.get(&rel_key);
@@ -626,7 +635,9 @@ impl GossipSystem {
         let mut assigned: HashSet<NpcId> = HashSet::new();
         let mut cluster_id = 0;
 
-        for npc_id in npcs.keys() {
+        // Visit NPCs in id order so cluster ids and membership are reproducible.
+        let npc_ids = sorted_keys(npcs);
+        for npc_id in &npc_ids {
             if assigned.contains(npc_id) {
                 continue;
             }
@@ -638,7 +649,7 @@ impl GossipSystem {
             // Find connected NPCs with strong relationships
             let mut to_check = vec![*npc_id];
             while let Some(current) = to_check.pop() {
-                for other_id in npcs.keys() {
+                for other_id in &npc_ids {
                     if assigned.contains(other_id) {
                         continue;
                     }
@@ -730,6 +741,114 @@ impl GossipSystem {
     }
 }
 
+/// Storylet tags that start a rumor when a storylet carrying them fires.
+pub const GOSSIP_WORTHY_TAGS: &[&str] =
+    &["scandal", "breakup", "affair", "betrayal", "divorce", "arrest"];
+/// Gossip-worthy tags whose rumors are scandalous.
+const SCANDAL_TAGS: &[&str] = &["scandal", "affair"];
+/// Prefix of the ids of rumors started by fired storylets.
+pub const STORYLET_RUMOR_PREFIX: &str = "storylet:";
+/// Memory tag on everything learned through storylet gossip.
+pub const GOSSIP_MEMORY_TAG: &str = "gossip";
+
+/// World flag (and memory tag) for storylet gossip tagged `tag`.
+pub fn gossip_flag(tag: &str) -> String {
+    format!("gossip_{}", tag)
+}
+
+/// Start a rumor about the player for a storylet fired at `tick`.
+///
+/// Only storylets with a gossip-worthy tag, and no `gossip` tag of their own,
+/// start one. The first NPC in `cast` who isn't the player tells it first;
+/// without one, the most sociable NPC the player knows does. Returns the
+/// rumor id, or `None` when the storylet isn't gossip-worthy or nobody is
+/// around to tell it.
+pub fn start_storylet_rumor(
+    world: &mut WorldState,
+    storylet_id: &str,
+    tags: &[String],
+    cast: &[NpcId],
+    tick: u64,
+) -> Option<String> {
+    let gossip_tags: Vec<String> = GOSSIP_WORTHY_TAGS
+        .iter()
+        .filter(|worthy| tags.iter().any(|tag| tag.eq_ignore_ascii_case(worthy)))
+        .map(|worthy| worthy.to_string())
+        .collect();
+    // Reactions to gossip don't start more of it
+    let reacts_to_gossip = tags.iter().any(|tag| tag.eq_ignore_ascii_case(GOSSIP_MEMORY_TAG));
+    if gossip_tags.is_empty() || reacts_to_gossip {
+        return None;
+    }
+
+    let player = world.player_id;
+    let in_world = |id: &NpcId| *id != player && world.npcs.contains_key(id);
+    let sociability = |id: &NpcId| {
+        world
+            .npcs
+            .get(id)
+            .map_or(0.0, |npc| npc.traits.sociability)
+    };
+    let originator = cast.iter().copied().find(in_world).or_else(|| {
+        world
+            .known_npcs
+            .iter()
+            .copied()
+            .filter(in_world)
+            // Ties go to the lowest id
+            .max_by(|a, b| sociability(a).total_cmp(&sociability(b)).then(b.cmp(a)))
+    })?;
+
+    let id = format!("{}{}@{}", STORYLET_RUMOR_PREFIX, storylet_id, tick);
+    let scandalous = gossip_tags.iter().any(|tag| SCANDAL_TAGS.contains(&tag.as_str()));
+    let mut rumor = Rumor::new(id.clone(), storylet_id, player, originator, tick)
+        .with_salience(0.6)
+        .with_valence(-0.6)
+        .with_reputation_impact(-1.0)
+        .with_tags(gossip_tags);
+    if scandalous {
+        rumor = rumor.scandalous();
+    }
+    world.gossip.create_rumor(rumor);
+    remember_storylet_gossip(world, &id, originator, 1.0);
+    Some(id)
+}
+
+/// Give everyone who just heard storylet gossip a memory of it and raise its
+/// `gossip_<tag>` flags.
+pub fn record_storylet_gossip_spread(world: &mut WorldState, results: &[SpreadResult]) {
+    for result in results {
+        if result.accepted && result.rumor_id.starts_with(STORYLET_RUMOR_PREFIX) {
+            remember_storylet_gossip(world, &result.rumor_id, result.recipient_id, result.belief);
+        }
+    }
+}
+
+fn remember_storylet_gossip(world: &mut WorldState, rumor_id: &str, npc_id: NpcId, belief: f32) {
+    let Some(rumor) = world.gossip.rumors.get(rumor_id) else {
+        return;
+    };
+    let flags: Vec<String> = rumor.tags.iter().map(|tag| gossip_flag(tag)).collect();
+    let mut tags = vec![GOSSIP_MEMORY_TAG.to_string()];
+    tags.extend(flags.iter().cloned());
+    world.memory_entries.push(MemoryEntryRecord {
+        id: format!("{}:{}", rumor_id, npc_id.0),
+        event_id: rumor.source_event_id.clone(),
+        npc_id,
+        sim_tick: world.current_tick,
+        emotional_intensity: (rumor.salience * belief).clamp(0.0, 1.0),
+        tags,
+        participants: vec![rumor.subject_id.0, npc_id.0],
+        ..Default::default()
+    });
+    // Only gossip that has got around counts as the talk of the town
+    if npc_id != rumor.originator_id {
+        for flag in &flags {
+            set_world_flag(world, flag, true, FLAG_SOURCE_GOSSIP);
+        }
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
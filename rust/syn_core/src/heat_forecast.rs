@@ -0,0 +1,237 @@
+//! Short-horizon narrative heat forecast.
+//!
+//! The UI foreshadows "something is brewing" with a small sparkline of where
+//! narrative heat is headed. [`forecast_heat`] projects heat forward a few
+//! ticks from what the world already knows is coming, without advancing it:
+//!
+//! - the per-tick decay `WorldState::tick` applies,
+//! - the current trend (heat momentum), fading at the same rate it does,
+//! - queued relationship pressure events, landing one per tick by priority,
+//! - scheduled follow-ups (life goal payoffs, open proposals, pending flag
+//!   reactions), landing at a fixed spacing.
+//!
+//! The projection is a pure function of the world, so the same save always
+//! forecasts the same series.
+
+use serde::{Deserialize, Serialize};
+
+use crate::narrative_heat::NarrativeHeatBand;
+use crate::types::WorldState;
+
+/// Longest horizon a forecast covers (one week).
+pub const MAX_HEAT_FORECAST_TICKS: u64 = 168;
+
+/// Tuning for the heat forecast.
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct HeatForecastConfig {
+    /// Heat lost per tick (matches `WorldState::tick`).
+    pub decay_per_tick: f32,
+    /// Share of current momentum added to heat each tick.
+    pub trend_weight: f32,
+    /// Momentum kept from one tick to the next (matches `WorldState::tick`).
+    pub momentum_retention: f32,
+    /// Heat a lowest-priority pressure event adds when it lands.
+    pub pressure_event_heat: f32,
+    /// Extra heat per pressure event priority level.
+    pub pressure_priority_heat: f32,
+    /// Heat a scheduled follow-up adds when it lands.
+    pub follow_up_heat: f32,
+    /// Ticks between scheduled follow-ups landing.
+    pub follow_up_spacing_ticks: u64,
+}
+
+impl Default for HeatForecastConfig {
+    fn default() -> Self {
+        Self {
+            decay_per_tick: 0.2,
+            trend_weight: 0.1,
+            momentum_retention: 0.7,
+            pressure_event_heat: 3.0,
+            pressure_priority_heat: 1.5,
+            follow_up_heat: 5.0,
+            follow_up_spacing_ticks: 6,
+        }
+    }
+}
+
+/// Projected heat at one future tick.
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub struct HeatForecastPoint {
+    /// World tick this point projects.
+    pub tick: u64,
+    /// Projected heat (0-100).
+    pub heat: f32,
+    /// Band of the projected heat.
+    pub band: NarrativeHeatBand,
+}
+
+/// Projected heat over the next few ticks.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct HeatForecast {
+    /// Heat now.
+    pub current_heat: f32,
+    /// Band now.
+    pub current_band: NarrativeHeatBand,
+    /// One point per future tick, nearest first.
+    pub points: Vec<HeatForecastPoint>,
+    /// Hottest band reached over the horizon.
+    pub peak_band: NarrativeHeatBand,
+    /// Whether heat is projected to climb into a hotter band.
+    pub brewing: bool,
+}
+
+/// Forecast heat over the next `horizon_ticks` (capped at
+/// [`MAX_HEAT_FORECAST_TICKS`]) with default tuning.
+pub fn forecast_heat(world: &WorldState, horizon_ticks: u64) -> HeatForecast {
+    forecast_heat_with(world, horizon_ticks, &HeatForecastConfig::default())
+}
+
+/// Forecast heat over the next `horizon_ticks` with explicit tuning.
+pub fn forecast_heat_with(
+    world: &WorldState,
+    horizon_ticks: u64,
+    config: &HeatForecastConfig,
+) -> HeatForecast {
+    let horizon = horizon_ticks.min(MAX_HEAT_FORECAST_TICKS);
+    let now = world.current_tick.0;
+
+    // Pressure events land one per tick, highest priority first.
+    let mut priorities: Vec<u8> = world
+        .relationship_pressure
+        .queue
+        .iter()
+        .map(|event| event.kind.priority())
+        .collect();
+    priorities.sort_unstable_by(|a, b| b.cmp(a));
+    let mut pressure = priorities.into_iter();
+
+    let mut follow_ups = world.life_goals.pending_storylets.len()
+        + world.proposals.pending.len()
+        + world.flag_changes.pending.len();
+    let spacing = config.follow_up_spacing_ticks.max(1);
+
+    let mut heat = world.narrative_heat;
+    let mut momentum = world.heat_momentum;
+    let mut points = Vec::new();
+    for step in 1..=horizon {
+        heat.add(-config.decay_per_tick);
+        heat.add(momentum * config.trend_weight);
+        momentum *= config.momentum_retention;
+
+        if let Some(priority) = pressure.next() {
+            heat.add(
+                config.pressure_event_heat + config.pressure_priority_heat * f32::from(priority),
+            );
+        }
+        if step % spacing == 0 && follow_ups > 0 {
+            follow_ups -= 1;
+            heat.add(config.follow_up_heat);
+        }
+
+        points.push(HeatForecastPoint {
+            tick: now + step,
+            heat: heat.value(),
+            band: heat.band(),
+        });
+    }
+
+    let current_band = world.narrative_heat.band();
+    let peak_band = points
+        .iter()
+        .map(|p| p.band)
+        .fold(current_band, |peak, band| {
+            if band_rank(band) > band_rank(peak) {
+                band
+            } else {
+                peak
+            }
+        });
+    HeatForecast {
+        current_heat: world.narrative_heat.value(),
+        current_band,
+        points,
+        peak_band,
+        brewing: band_rank(peak_band) > band_rank(current_band),
+    }
+}
+
+fn band_rank(band: NarrativeHeatBand) -> u8 {
+    match band {
+        NarrativeHeatBand::Low => 0,
+        NarrativeHeatBand::Medium => 1,
+        NarrativeHeatBand::High => 2,
+        NarrativeHeatBand::Critical => 3,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::narrative_heat::NarrativeHeat;
+    use crate::relationship_pressure::{RelationshipEventKind, RelationshipPressureEvent};
+    use crate::types::{NpcId, SimTick, WorldSeed};
+
+    fn decay_only(world: &WorldState, ticks: u64, config: &HeatForecastConfig) -> f32 {
+        let mut heat = world.narrative_heat;
+        for _ in 0..ticks {
+            heat.add(-config.decay_per_tick);
+        }
+        heat.value()
+    }
+
+    fn world() -> WorldState {
+        let mut world = WorldState::new(WorldSeed(3), NpcId(1));
+        world.current_tick = SimTick(50);
+        world.narrative_heat = NarrativeHeat::new(20.0);
+        world.heat_momentum = 0.0;
+        world
+    }
+
+    #[test]
+    fn quiet_world_cools_and_is_not_brewing() {
+        let world = world();
+        let forecast = forecast_heat(&world, 12);
+        assert_eq!(forecast.points.len(), 12);
+        assert_eq!(forecast.points[0].tick, 51);
+        let config = HeatForecastConfig::default();
+        let last = forecast.points.last().unwrap().heat;
+        assert!((last - decay_only(&world, 12, &config)).abs() < 1e-4);
+        assert!(!forecast.brewing);
+        assert_eq!(forecast.peak_band, NarrativeHeatBand::Low);
+    }
+
+    #[test]
+    fn queued_pressure_and_follow_ups_brew_and_are_deterministic() {
+        let mut world = world();
+        for actor_id in 2..6 {
+            world
+                .relationship_pressure
+                .push_event(RelationshipPressureEvent {
+                    actor_id,
+                    target_id: 1,
+                    kind: RelationshipEventKind::ResentmentBandChanged,
+                    old_band: "Irritated".to_string(),
+                    new_band: "Hostile".to_string(),
+                    source: None,
+                    tick: Some(50),
+                });
+        }
+        world
+            .life_goals
+            .pending_storylets
+            .push("goal_payoff_career".to_string());
+
+        let forecast = forecast_heat(&world, 12);
+        assert!(forecast.brewing);
+        assert_eq!(forecast.peak_band, NarrativeHeatBand::High);
+        assert!(forecast.points[3].heat > forecast.points[0].heat);
+        assert_eq!(forecast, forecast_heat(&world, 12));
+    }
+
+    #[test]
+    fn horizon_is_capped() {
+        let forecast = forecast_heat(&world(), 10_000);
+        assert_eq!(forecast.points.len(), MAX_HEAT_FORECAST_TICKS as usize);
+    }
+}
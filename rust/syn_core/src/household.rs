@@ -0,0 +1,415 @@
+//! Households and cohabitation.
+//!
+//! `AbstractNpc::household_id` groups generated families, but says nothing
+//! about characters moving in together during play. The [`HouseholdRegistry`]
+//! tracks households formed at runtime: who lives together, in which
+//! district, and since when.
+//!
+//! Storylet outcomes change households through flag operations named
+//! `move_in:<npc_id>` or `move_in:<role>:<npc_id>` (e.g. `move_in:roommate:42`).
+//! Setting the flag moves the NPC into the player's household; clearing it
+//! moves them out again. See [`apply_move_in_flag`].
+
+use crate::types::{NpcId, SimTick, WorldState};
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
+
+/// Prefix of flag operations that move NPCs in with (or out from) the player.
+pub const MOVE_IN_FLAG_PREFIX: &str = "move_in:";
+
+/// First id assigned by the registry, kept clear of generated family ids.
+pub const HOUSEHOLD_ID_BASE: u64 = 1_000_000;
+
+/// How a member relates to the rest of the household.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize, Default)]
+pub enum HouseholdRole {
+    /// The character whose home it is (the player for player households).
+    Head,
+    /// Romantic partner living with the head.
+    #[default]
+    Partner,
+    /// Shares the home without being family or a partner.
+    Roommate,
+    /// Relative (child, parent, sibling) living in the home.
+    Family,
+}
+
+impl HouseholdRole {
+    /// Parse a role name as used in move-in flags.
+    pub fn parse(s: &str) -> Option<Self> {
+        match s.to_ascii_lowercase().as_str() {
+            "head" => Some(HouseholdRole::Head),
+            "partner" => Some(HouseholdRole::Partner),
+            "roommate" => Some(HouseholdRole::Roommate),
+            "family" => Some(HouseholdRole::Family),
+            _ => None,
+        }
+    }
+
+    /// Stable lowercase label.
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            HouseholdRole::Head => "head",
+            HouseholdRole::Partner => "partner",
+            HouseholdRole::Roommate => "roommate",
+            HouseholdRole::Family => "family",
+        }
+    }
+}
+
+/// A single resident of a household.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct HouseholdMember {
+    /// The resident.
+    pub npc_id: NpcId,
+    /// Relation to the rest of the household.
+    pub role: HouseholdRole,
+    /// Tick the resident moved in.
+    pub joined_tick: SimTick,
+    /// Household the NPC belonged to before moving in (restored on move-out).
+    #[serde(default)]
+    pub previous_household_id: Option<u64>,
+}
+
+/// A group of characters living together.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct Household {
+    /// Household identifier (shared with `AbstractNpc::household_id`).
+    pub id: u64,
+    /// Residents, ordered by NPC id.
+    pub members: Vec<HouseholdMember>,
+    /// District the household lives in, if known.
+    #[serde(default)]
+    pub district: Option<String>,
+    /// Tick the head first shared the home; `None` while living alone.
+    #[serde(default)]
+    pub cohabiting_since: Option<SimTick>,
+}
+
+impl Household {
+    /// True if `npc_id` lives here.
+    pub fn contains(&self, npc_id: NpcId) -> bool {
+        self.members.iter().any(|m| m.npc_id == npc_id)
+    }
+
+    /// Look up a resident.
+    pub fn member(&self, npc_id: NpcId) -> Option<&HouseholdMember> {
+        self.members.iter().find(|m| m.npc_id == npc_id)
+    }
+
+    /// True if more than one character lives here.
+    pub fn is_shared(&self) -> bool {
+        self.members.len() > 1
+    }
+}
+
+/// Registry of households formed during play.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct HouseholdRegistry {
+    /// Households by id.
+    #[serde(default)]
+    pub households: BTreeMap<u64, Household>,
+    /// Next id to assign.
+    #[serde(default = "household_id_base")]
+    next_id: u64,
+}
+
+fn household_id_base() -> u64 {
+    HOUSEHOLD_ID_BASE
+}
+
+impl Default for HouseholdRegistry {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl HouseholdRegistry {
+    /// Create an empty registry.
+    pub fn new() -> Self {
+        HouseholdRegistry {
+            households: BTreeMap::new(),
+            next_id: HOUSEHOLD_ID_BASE,
+        }
+    }
+
+    /// Household `npc_id` currently lives in, if any.
+    pub fn household_of(&self, npc_id: NpcId) -> Option<&Household> {
+        self.households.values().find(|h| h.contains(npc_id))
+    }
+
+    /// True if `a` and `b` share a household.
+    pub fn lives_with(&self, a: NpcId, b: NpcId) -> bool {
+        a != b && self.household_of(a).map(|h| h.contains(b)).unwrap_or(false)
+    }
+
+    /// Everyone `npc_id` lives with (excluding themselves), ordered by id.
+    pub fn housemates(&self, npc_id: NpcId) -> Vec<NpcId> {
+        self.household_of(npc_id)
+            .map(|h| {
+                h.members
+                    .iter()
+                    .map(|m| m.npc_id)
+                    .filter(|id| *id != npc_id)
+                    .collect()
+            })
+            .unwrap_or_default()
+    }
+
+    /// Move `npc_id` into `head`'s household, creating it if needed.
+    ///
+    /// An NPC already living elsewhere in the registry moves out first; one
+    /// already living with `head` just has their role updated. Returns the
+    /// household id.
+    pub fn move_in(
+        &mut self,
+        head: NpcId,
+        npc_id: NpcId,
+        role: HouseholdRole,
+        tick: SimTick,
+        previous_household_id: Option<u64>,
+        district: Option<String>,
+    ) -> u64 {
+        let household_id = match self.household_of(head) {
+            Some(h) => h.id,
+            None => self.create(head, tick, district.clone()),
+        };
+
+        if npc_id == head {
+            return household_id;
+        }
+
+        let already_home = self
+            .households
+            .get(&household_id)
+            .map(|h| h.contains(npc_id))
+            .unwrap_or(false);
+        if !already_home {
+            self.move_out(npc_id);
+        }
+
+        let Some(household) = self.households.get_mut(&household_id) else {
+            return household_id;
+        };
+        if let Some(member) = household.members.iter_mut().find(|m| m.npc_id == npc_id) {
+            member.role = role;
+            return household_id;
+        }
+
+        household.members.push(HouseholdMember {
+            npc_id,
+            role,
+            joined_tick: tick,
+            previous_household_id,
+        });
+        household.members.sort_by_key(|m| m.npc_id.0);
+        if household.district.is_none() {
+            household.district = district;
+        }
+        if household.cohabiting_since.is_none() {
+            household.cohabiting_since = Some(tick);
+        }
+        household_id
+    }
+
+    /// Move `npc_id` out of their household. Returns the departing member.
+    ///
+    /// A household left with a single resident stops cohabiting; an empty one
+    /// is removed.
+    pub fn move_out(&mut self, npc_id: NpcId) -> Option<HouseholdMember> {
+        let household_id = self.household_of(npc_id)?.id;
+        let household = self.households.get_mut(&household_id)?;
+        let idx = household.members.iter().position(|m| m.npc_id == npc_id)?;
+        let member = household.members.remove(idx);
+
+        if household.members.is_empty() {
+            self.households.remove(&household_id);
+        } else if !household.is_shared() {
+            household.cohabiting_since = None;
+        }
+        Some(member)
+    }
+
+    fn create(&mut self, head: NpcId, tick: SimTick, district: Option<String>) -> u64 {
+        let id = self.next_id;
+        self.next_id += 1;
+        self.households.insert(
+            id,
+            Household {
+                id,
+                members: vec![HouseholdMember {
+                    npc_id: head,
+                    role: HouseholdRole::Head,
+                    joined_tick: tick,
+                    previous_household_id: None,
+                }],
+                district,
+                cohabiting_since: None,
+            },
+        );
+        id
+    }
+}
+
+/// Parse a move-in flag into `(role, npc_id)`.
+///
+/// Accepts `move_in:<npc_id>` (partner) and `move_in:<role>:<npc_id>`.
+pub fn parse_move_in_flag(flag: &str) -> Option<(HouseholdRole, NpcId)> {
+    let rest = flag.strip_prefix(MOVE_IN_FLAG_PREFIX)?;
+    let (role, id) = match rest.split_once(':') {
+        Some((role, id)) => (HouseholdRole::parse(role)?, id),
+        None => (HouseholdRole::default(), rest),
+    };
+    id.trim().parse().ok().map(|id| (role, NpcId(id)))
+}
+
+/// Apply a move-in flag operation to the world.
+///
+/// Setting the flag moves the NPC into the player's household (adopting the
+/// household's district, or the NPC's own if the household has none); clearing
+/// it moves them out and restores their previous household id. Returns
+/// `false` if `flag` is not a move-in flag.
+pub fn apply_move_in_flag(world: &mut WorldState, flag: &str, set: bool, tick: SimTick) -> bool {
+    let Some((role, npc_id)) = parse_move_in_flag(flag) else {
+        return false;
+    };
+
+    if set {
+        let previous = world.npcs.get(&npc_id).map(|npc| npc.household_id);
+        let district = world
+            .npcs
+            .get(&npc_id)
+            .map(|npc| npc.district.clone())
+            .or_else(|| {
+                world
+                    .npc_prototypes
+                    .get(&npc_id)
+                    .and_then(|proto| proto.home_district.clone())
+            });
+        let household_id =
+            world
+                .households
+                .move_in(world.player_id, npc_id, role, tick, previous, district);
+        if let Some(npc) = world.npcs.get_mut(&npc_id) {
+            npc.household_id = household_id;
+        }
+    } else if world.households.lives_with(world.player_id, npc_id) {
+        if let Some(member) = world.households.move_out(npc_id) {
+            if let (Some(npc), Some(previous)) =
+                (world.npcs.get_mut(&npc_id), member.previous_household_id)
+            {
+                npc.household_id = previous;
+            }
+        }
+    }
+    true
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::types::WorldSeed;
+
+    #[test]
+    fn test_parse_move_in_flag() {
+        assert_eq!(
+            parse_move_in_flag("move_in:42"),
+            Some((HouseholdRole::Partner, NpcId(42)))
+        );
+        assert_eq!(
+            parse_move_in_flag("move_in:roommate:7"),
+            Some((HouseholdRole::Roommate, NpcId(7)))
+        );
+        assert_eq!(parse_move_in_flag("move_in:pet:7"), None);
+        assert_eq!(parse_move_in_flag("met_childhood_friend"), None);
+    }
+
+    #[test]
+    fn test_move_in_and_out_tracks_cohabitation() {
+        let mut registry = HouseholdRegistry::new();
+        let (player, partner, roommate) = (NpcId(1), NpcId(2), NpcId(3));
+
+        let id = registry.move_in(
+            player,
+            partner,
+            HouseholdRole::Partner,
+            SimTick(10),
+            Some(5),
+            Some("Downtown".to_string()),
+        );
+        registry.move_in(
+            player,
+            roommate,
+            HouseholdRole::Roommate,
+            SimTick(20),
+            None,
+            None,
+        );
+
+        let household = registry.household_of(player).unwrap();
+        assert_eq!(household.id, id);
+        assert_eq!(household.cohabiting_since, Some(SimTick(10)));
+        assert_eq!(household.district.as_deref(), Some("Downtown"));
+        assert!(registry.lives_with(partner, roommate));
+        assert_eq!(registry.housemates(player), vec![partner, roommate]);
+
+        let departed = registry.move_out(partner).unwrap();
+        assert_eq!(departed.previous_household_id, Some(5));
+        assert!(registry
+            .household_of(player)
+            .unwrap()
+            .cohabiting_since
+            .is_some());
+
+        registry.move_out(roommate);
+        assert_eq!(
+            registry.household_of(player).unwrap().cohabiting_since,
+            None
+        );
+        assert!(!registry.lives_with(player, roommate));
+    }
+
+    #[test]
+    fn test_apply_move_in_flag_updates_npc_household() {
+        let mut world = WorldState::new(WorldSeed(1), NpcId(1));
+        world.npcs.insert(
+            NpcId(2),
+            crate::types::AbstractNpc {
+                id: NpcId(2),
+                age: 30,
+                job: "Chef".to_string(),
+                district: "Harbor".to_string(),
+                household_id: 9,
+                traits: Default::default(),
+                seed: 2,
+                attachment_style: Default::default(),
+            },
+        );
+
+        assert!(apply_move_in_flag(
+            &mut world,
+            "move_in:2",
+            true,
+            SimTick(3)
+        ));
+        let household = world.households.household_of(NpcId(1)).unwrap().clone();
+        assert_eq!(world.npcs[&NpcId(2)].household_id, household.id);
+        assert_eq!(household.district.as_deref(), Some("Harbor"));
+
+        assert!(apply_move_in_flag(
+            &mut world,
+            "move_in:2",
+            false,
+            SimTick(4)
+        ));
+        assert_eq!(world.npcs[&NpcId(2)].household_id, 9);
+        assert!(!world.households.lives_with(NpcId(1), NpcId(2)));
+
+        assert!(!apply_move_in_flag(
+            &mut world,
+            "some_flag",
+            true,
+            SimTick(5)
+        ));
+    }
+}
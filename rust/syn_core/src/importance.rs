@@ -0,0 +1,577 @@
+//! Major characters: the NPCs the story keeps returning to.
+//!
+//! Most NPCs drift in and out of the player's life. A few are *major
+//! characters*, either authored (`NpcPrototype::major`) or promoted once the
+//! memories they share with the player carry enough weight (see
+//! [`ImportanceConfig`] and [`shared_salience`]). Major characters
+//! - get a casting bonus when the director fills storylet roles,
+//! - lose relationship ground to drift more slowly,
+//! - are pinned to Tier0, so background culling never drops them, and
+//! - check in with the player periodically. A pending check-in is delivered
+//!   by the director as a special storylet (see [`CHECK_IN_STORYLET_PREFIX`])
+//!   and answered with [`respond_to_check_in`].
+//!
+//! Promotion is evaluated once a day by [`update_importance`]. Major status
+//! is never revoked.
+
+use std::collections::{BTreeMap, BTreeSet};
+
+use serde::{Deserialize, Serialize};
+
+use crate::ambient::npc_name;
+use crate::errors::{Result, SynError};
+use crate::relationship_heat::record_relationship_interaction;
+use crate::relationships::RelationshipAxis;
+use crate::types::{MemoryEntryRecord, NpcId, Relationship, WorldState};
+
+/// Storylet id prefix of check-in events (`check_in:<id>`).
+pub const CHECK_IN_STORYLET_PREFIX: &str = "check_in:";
+
+/// Choice id that makes time for a check-in.
+pub const CHECK_IN_CATCH_UP_CHOICE: &str = "catch_up";
+
+/// Choice id that brushes a check-in off.
+pub const CHECK_IN_BRUSH_OFF_CHOICE: &str = "brush_off";
+
+/// Tag added to check-in memories.
+pub const CHECK_IN_TAG: &str = "check_in";
+
+/// Axis changes applied to one side of a relationship.
+type AxisDeltas = &'static [(RelationshipAxis, f32)];
+
+/// How an NPC became a major character.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum ImportanceSource {
+    /// Marked major by content authors.
+    Authored,
+    /// Promoted by the weight of shared memories.
+    Emergent,
+}
+
+impl ImportanceSource {
+    /// Stable lowercase label for UI/FFI.
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            ImportanceSource::Authored => "authored",
+            ImportanceSource::Emergent => "emergent",
+        }
+    }
+}
+
+/// Promotion thresholds and the perks major characters get.
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct ImportanceConfig {
+    /// Shared memories needed before an NPC can be promoted.
+    pub promotion_min_memories: usize,
+    /// Summed |emotional intensity| of shared memories needed for promotion.
+    pub promotion_salience: f32,
+    /// Most emergent major characters at once (authored ones do not count).
+    pub max_emergent: usize,
+    /// Ticks between check-ins from the same major character.
+    pub check_in_interval_ticks: u64,
+    /// Multiplier on relationship drift for pairs involving a major character.
+    pub decay_factor: f32,
+    /// Added to a major character's role score when casting storylets.
+    pub casting_bonus: f32,
+}
+
+impl Default for ImportanceConfig {
+    fn default() -> Self {
+        Self {
+            promotion_min_memories: 4,
+            promotion_salience: 2.5,
+            max_emergent: 5,
+            check_in_interval_ticks: 336,
+            decay_factor: 0.5,
+            casting_bonus: 5.0,
+        }
+    }
+}
+
+/// An NPC the story treats as a major character.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct MajorCharacter {
+    /// The NPC.
+    pub npc_id: NpcId,
+    /// Authored or promoted.
+    pub source: ImportanceSource,
+    /// Tick they became major.
+    pub since_tick: u64,
+    /// Tick of their last check-in (or promotion, before the first one).
+    pub last_check_in_tick: u64,
+}
+
+/// A major character reaching out, waiting for the player's answer.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct CheckIn {
+    /// Check-in id (unique within a run).
+    pub id: u64,
+    /// NPC checking in.
+    pub npc_id: NpcId,
+    /// Tick the check-in was queued.
+    pub created_tick: u64,
+}
+
+impl CheckIn {
+    /// Storylet id the director presents this check-in under.
+    pub fn storylet_id(&self) -> String {
+        format!("{}{}", CHECK_IN_STORYLET_PREFIX, self.id)
+    }
+}
+
+/// Result of answering a check-in.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct CheckInResolution {
+    /// The answered check-in.
+    pub check_in: CheckIn,
+    /// Whether the player made time for them.
+    pub caught_up: bool,
+    /// NPC→player relationship afterwards.
+    pub npc_to_player: Relationship,
+    /// Player→NPC relationship afterwards.
+    pub player_to_npc: Relationship,
+}
+
+/// How much an NPC and the player have been through together.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Serialize, Deserialize)]
+pub struct SharedSalience {
+    /// Memories involving both of them.
+    pub memories: usize,
+    /// Summed |emotional intensity| of those memories.
+    pub salience: f32,
+}
+
+/// Major character bookkeeping stored on `WorldState`.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct ImportanceState {
+    /// Thresholds and perks.
+    #[serde(default)]
+    pub config: ImportanceConfig,
+    /// Major characters by NPC id.
+    #[serde(default)]
+    pub majors: BTreeMap<u64, MajorCharacter>,
+    /// Check-ins waiting for an answer, oldest first.
+    #[serde(default)]
+    pub pending_check_ins: Vec<CheckIn>,
+    /// Storylet memories shared with each NPC, by NPC id (see
+    /// [`note_shared_memory`]).
+    #[serde(default)]
+    pub storylet_memories: BTreeMap<u64, SharedSalience>,
+    /// Next check-in id.
+    #[serde(default)]
+    next_id: u64,
+}
+
+impl ImportanceState {
+    /// Look up a major character.
+    pub fn get(&self, npc_id: NpcId) -> Option<&MajorCharacter> {
+        self.majors.get(&npc_id.0)
+    }
+
+    /// The check-in the director should present next (the oldest).
+    pub fn next_check_in(&self) -> Option<&CheckIn> {
+        self.pending_check_ins.first()
+    }
+
+    fn emergent_count(&self) -> usize {
+        self.majors
+            .values()
+            .filter(|m| m.source == ImportanceSource::Emergent)
+            .count()
+    }
+
+    fn has_pending_from(&self, npc_id: NpcId) -> bool {
+        self.pending_check_ins.iter().any(|c| c.npc_id == npc_id)
+    }
+
+    fn insert(&mut self, npc_id: NpcId, source: ImportanceSource, tick: u64) -> bool {
+        if self.majors.contains_key(&npc_id.0) {
+            return false;
+        }
+        self.majors.insert(
+            npc_id.0,
+            MajorCharacter {
+                npc_id,
+                source,
+                since_tick: tick,
+                last_check_in_tick: tick,
+            },
+        );
+        true
+    }
+}
+
+/// Whether `npc_id` is a major character, promoted or authored.
+///
+/// Authored majors count before the daily update has registered them.
+pub fn is_major_character(world: &WorldState, npc_id: NpcId) -> bool {
+    world.importance.majors.contains_key(&npc_id.0)
+        || world.npc_prototype(npc_id).is_some_and(|proto| proto.major)
+}
+
+/// Every major character, promoted or authored, in id order.
+pub fn major_character_ids(world: &WorldState) -> BTreeSet<NpcId> {
+    world
+        .importance
+        .majors
+        .values()
+        .map(|m| m.npc_id)
+        .chain(
+            world
+                .npc_prototypes
+                .values()
+                .filter(|proto| proto.major && proto.id != world.player_id)
+                .map(|proto| proto.id),
+        )
+        .collect()
+}
+
+/// Multiplier on relationship drift for the `a`/`b` pair: the configured
+/// `decay_factor` if either is a major character, otherwise 1.0.
+pub fn relationship_decay_factor(world: &WorldState, a: NpcId, b: NpcId) -> f32 {
+    if is_major_character(world, a) || is_major_character(world, b) {
+        world.importance.config.decay_factor
+    } else {
+        1.0
+    }
+}
+
+/// Count a storylet memory the player shares with `npcs`.
+///
+/// Storylet memories live in the memory system, which the core cannot see,
+/// so the director reports them here as it records them.
+pub fn note_shared_memory(
+    world: &mut WorldState,
+    npcs: impl IntoIterator<Item = NpcId>,
+    emotional_intensity: f32,
+) {
+    let player = world.player_id;
+    let npcs: BTreeSet<NpcId> = npcs.into_iter().filter(|id| *id != player).collect();
+    for npc in npcs {
+        let shared = world.importance.storylet_memories.entry(npc.0).or_default();
+        shared.memories += 1;
+        shared.salience += emotional_intensity.abs();
+    }
+}
+
+/// Memories the player shares with each NPC, keyed by NPC id: the player's
+/// memory records on the world plus noted storylet memories.
+pub(crate) fn shared_salience_by_npc(world: &WorldState) -> BTreeMap<u64, SharedSalience> {
+    let player = world.player_id.0;
+    let mut shared = world.importance.storylet_memories.clone();
+    for entry in &world.memory_entries {
+        if entry.npc_id.0 != player && !entry.participants.contains(&player) {
+            continue;
+        }
+        let npcs: BTreeSet<u64> = entry
+            .participants
+            .iter()
+            .copied()
+            .chain((entry.npc_id.0 != player).then_some(entry.npc_id.0))
+            .filter(|id| *id != player)
+            .collect();
+        for npc in npcs {
+            let s = shared.entry(npc).or_default();
+            s.memories += 1;
+            s.salience += entry.emotional_intensity.abs();
+        }
+    }
+    shared
+}
+
+/// How much `npc_id` and the player have been through together.
+pub fn shared_salience(world: &WorldState, npc_id: NpcId) -> SharedSalience {
+    shared_salience_by_npc(world)
+        .remove(&npc_id.0)
+        .unwrap_or_default()
+}
+
+/// Make `npc_id` a major character. Returns false if they already are one
+/// (or are the player).
+pub fn mark_major_character(
+    world: &mut WorldState,
+    npc_id: NpcId,
+    source: ImportanceSource,
+) -> bool {
+    if npc_id == world.player_id {
+        return false;
+    }
+    let tick = world.current_tick.0;
+    world.importance.insert(npc_id, source, tick)
+}
+
+/// Register authored majors, promote NPCs whose shared memories crossed the
+/// thresholds, and queue check-ins that are due. Returns newly promoted NPCs.
+///
+/// Candidates are promoted by salience, highest first (ties by id), until
+/// `max_emergent` is reached. Each major character has at most one pending
+/// check-in.
+pub fn update_importance(world: &mut WorldState) -> Vec<NpcId> {
+    let tick = world.current_tick.0;
+    let config = world.importance.config;
+
+    let mut authored: Vec<NpcId> = world
+        .npc_prototypes
+        .values()
+        .filter(|proto| proto.major)
+        .map(|proto| proto.id)
+        .collect();
+    authored.sort_by_key(|id| id.0);
+    for npc_id in authored {
+        mark_major_character(world, npc_id, ImportanceSource::Authored);
+    }
+
+    let mut candidates: Vec<(u64, SharedSalience)> = shared_salience_by_npc(world)
+        .into_iter()
+        .filter(|(npc, s)| {
+            !world.importance.majors.contains_key(npc)
+                && s.memories >= config.promotion_min_memories
+                && s.salience >= config.promotion_salience
+        })
+        .collect();
+    candidates.sort_by(|(a_id, a), (b_id, b)| {
+        b.salience
+            .total_cmp(&a.salience)
+            .then_with(|| a_id.cmp(b_id))
+    });
+
+    let mut promoted = Vec::new();
+    for (npc, _) in candidates {
+        if world.importance.emergent_count() >= config.max_emergent {
+            break;
+        }
+        if mark_major_character(world, NpcId(npc), ImportanceSource::Emergent) {
+            promoted.push(NpcId(npc));
+        }
+    }
+
+    let due: Vec<NpcId> = world
+        .importance
+        .majors
+        .values()
+        .filter(|m| tick.saturating_sub(m.last_check_in_tick) >= config.check_in_interval_ticks)
+        .map(|m| m.npc_id)
+        .collect();
+    for npc_id in due {
+        if world.importance.has_pending_from(npc_id) {
+            continue;
+        }
+        let id = world.importance.next_id;
+        world.importance.next_id += 1;
+        world.importance.pending_check_ins.push(CheckIn {
+            id,
+            npc_id,
+            created_tick: tick,
+        });
+    }
+
+    promoted
+}
+
+/// Parse a check-in storylet id (`check_in:<id>`).
+pub fn parse_check_in_storylet_id(storylet_id: &str) -> Option<u64> {
+    storylet_id
+        .strip_prefix(CHECK_IN_STORYLET_PREFIX)?
+        .parse()
+        .ok()
+}
+
+/// Title and `(choice id, label)` pairs for presenting a check-in.
+pub fn check_in_prompt(
+    world: &WorldState,
+    check_in: &CheckIn,
+) -> (String, [(&'static str, &'static str); 2]) {
+    (
+        format!("{} checks in on you", npc_name(world, check_in.npc_id)),
+        [
+            (CHECK_IN_CATCH_UP_CHOICE, "Make time for them"),
+            (CHECK_IN_BRUSH_OFF_CHOICE, "Not now"),
+        ],
+    )
+}
+
+/// Axis changes on the NPC→player and player→NPC sides for an answer.
+fn check_in_deltas(caught_up: bool) -> (AxisDeltas, AxisDeltas) {
+    use RelationshipAxis::*;
+    if caught_up {
+        (
+            &[(Affection, 0.5), (Trust, 0.3), (Familiarity, 0.5)],
+            &[(Affection, 0.5), (Familiarity, 0.5)],
+        )
+    } else {
+        (&[(Affection, -0.3), (Resentment, 0.2)], &[])
+    }
+}
+
+/// Answer a pending check-in.
+///
+/// Either answer resets the NPC's check-in clock. Catching up warms both
+/// directions of the relationship; brushing them off cools the NPC a little.
+/// A player memory tagged [`CHECK_IN_TAG`] records the answer.
+pub fn respond_to_check_in(
+    world: &mut WorldState,
+    check_in_id: u64,
+    catch_up: bool,
+) -> Result<CheckInResolution> {
+    let idx = world
+        .importance
+        .pending_check_ins
+        .iter()
+        .position(|c| c.id == check_in_id)
+        .ok_or_else(|| SynError::NotFound(format!("check-in {}", check_in_id)))?;
+    let check_in = world.importance.pending_check_ins.remove(idx);
+    let (player, npc) = (world.player_id, check_in.npc_id);
+    let tick = world.current_tick;
+
+    if let Some(major) = world.importance.majors.get_mut(&npc.0) {
+        major.last_check_in_tick = tick.0;
+    }
+
+    let (npc_deltas, player_deltas) = check_in_deltas(catch_up);
+    let mut npc_to_player = world.get_relationship(npc, player);
+    let mut player_to_npc = world.get_relationship(player, npc);
+    for (axis, delta) in npc_deltas {
+        npc_to_player.apply_delta(*axis, *delta);
+    }
+    for (axis, delta) in player_deltas {
+        player_to_npc.apply_delta(*axis, *delta);
+    }
+    npc_to_player.state = npc_to_player.compute_next_state();
+    player_to_npc.state = player_to_npc.compute_next_state();
+    world.set_relationship(npc, player, npc_to_player);
+    world.set_relationship(player, npc, player_to_npc);
+    let magnitude: f32 = npc_deltas
+        .iter()
+        .chain(player_deltas)
+        .map(|(_, delta)| delta.abs())
+        .sum();
+    record_relationship_interaction(world, npc, player, magnitude);
+
+    let answer = if catch_up { "caught_up" } else { "brushed_off" };
+    world.memory_entries.push(MemoryEntryRecord {
+        id: format!("check_in:{}", check_in.id),
+        event_id: format!("check_in:{}", answer),
+        npc_id: player,
+        sim_tick: tick,
+        emotional_intensity: if catch_up { 0.4 } else { -0.2 },
+        tags: vec![CHECK_IN_TAG.to_string(), answer.to_string()],
+        participants: vec![player.0, npc.0],
+        ..Default::default()
+    });
+
+    Ok(CheckInResolution {
+        check_in,
+        caught_up: catch_up,
+        npc_to_player,
+        player_to_npc,
+    })
+}
+
+/// Resolve a check-in presented as a storylet choice.
+///
+/// Returns `None` if `storylet_id` is not a check-in event, or the check-in
+/// is gone or the choice unknown.
+pub fn choose_check_in_option(
+    world: &mut WorldState,
+    storylet_id: &str,
+    choice_id: &str,
+) -> Option<CheckInResolution> {
+    let id = parse_check_in_storylet_id(storylet_id)?;
+    let catch_up = match choice_id {
+        CHECK_IN_CATCH_UP_CHOICE => true,
+        CHECK_IN_BRUSH_OFF_CHOICE => false,
+        _ => return None,
+    };
+    respond_to_check_in(world, id, catch_up).ok()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::types::{SimTick, WorldSeed};
+
+    fn shared_memory(world: &mut WorldState, npc: u64, intensity: f32) {
+        let n = world.memory_entries.len();
+        world.memory_entries.push(MemoryEntryRecord {
+            id: format!("m{}", n),
+            event_id: "shared".into(),
+            npc_id: world.player_id,
+            emotional_intensity: intensity,
+            participants: vec![world.player_id.0, npc],
+            ..Default::default()
+        });
+    }
+
+    #[test]
+    fn test_shared_memories_promote_to_major() {
+        let mut world = WorldState::new(WorldSeed(3), NpcId(1));
+        for _ in 0..3 {
+            shared_memory(&mut world, 2, 0.9);
+        }
+        assert!(update_importance(&mut world).is_empty());
+        assert!(!is_major_character(&world, NpcId(2)));
+
+        shared_memory(&mut world, 2, -0.8);
+        assert_eq!(update_importance(&mut world), vec![NpcId(2)]);
+        let major = world.importance.get(NpcId(2)).unwrap();
+        assert_eq!(major.source, ImportanceSource::Emergent);
+        assert_eq!(shared_salience(&world, NpcId(2)).memories, 4);
+    }
+
+    #[test]
+    fn test_emergent_promotions_are_capped_by_salience() {
+        let mut world = WorldState::new(WorldSeed(3), NpcId(1));
+        world.importance.config.max_emergent = 1;
+        for _ in 0..4 {
+            shared_memory(&mut world, 2, 0.7);
+            shared_memory(&mut world, 3, 0.9);
+        }
+        assert_eq!(update_importance(&mut world), vec![NpcId(3)]);
+        assert!(!is_major_character(&world, NpcId(2)));
+    }
+
+    #[test]
+    fn test_major_characters_check_in_periodically() {
+        let mut world = WorldState::new(WorldSeed(3), NpcId(1));
+        assert!(mark_major_character(
+            &mut world,
+            NpcId(2),
+            ImportanceSource::Authored
+        ));
+        assert!(!mark_major_character(
+            &mut world,
+            NpcId(1),
+            ImportanceSource::Authored
+        ));
+
+        update_importance(&mut world);
+        assert!(world.importance.next_check_in().is_none());
+
+        world.current_tick = SimTick(world.importance.config.check_in_interval_ticks);
+        update_importance(&mut world);
+        update_importance(&mut world);
+        assert_eq!(world.importance.pending_check_ins.len(), 1);
+
+        let check_in = world.importance.next_check_in().unwrap().clone();
+        let resolution = choose_check_in_option(
+            &mut world,
+            &check_in.storylet_id(),
+            CHECK_IN_CATCH_UP_CHOICE,
+        )
+        .unwrap();
+        assert!(resolution.caught_up);
+        assert!(world.get_relationship(NpcId(2), NpcId(1)).affection > 0.0);
+        assert!(world.importance.pending_check_ins.is_empty());
+        assert_eq!(
+            world.importance.get(NpcId(2)).unwrap().last_check_in_tick,
+            world.current_tick.0
+        );
+        assert!(world
+            .memory_entries
+            .iter()
+            .any(|m| m.event_id == "check_in:caught_up"));
+        assert!(respond_to_check_in(&mut world, check_in.id, true).is_err());
+    }
+}
@@ -280,6 +280,18 @@ macro_rules! define_interned_id {
             pub fn as_str(&self) -> &'static str {
                 self.0.as_str()
             }
+
+            /// Returns the raw `u32` key of the interned string.
+            #[inline]
+            pub fn key(&self) -> u32 {
+                self.0.key()
+            }
+
+            /// Looks up an already-interned ID without interning `s`.
+            #[inline]
+            pub fn get(s: &str) -> Option<Self> {
+                InternedStr::get(s).map(Self)
+            }
         }
 
         impl fmt::Debug for $name {
@@ -357,6 +369,17 @@ define_interned_id!(
     StoryletTag
 );
 
+define_interned_id!(
+    /// An interned runtime handle for a storylet ID.
+    ///
+    /// Runtime maps (cooldowns, usage counters, tag indices) key on this
+    /// `u32` handle; the human-readable ID is only resolved for serialization
+    /// and diagnostics.
+    ///
+    /// Example: `StoryletHandle::new("morning_coffee")`
+    StoryletHandle
+);
+
 define_interned_id!(
     /// An interned trait name.
     ///
@@ -461,6 +484,22 @@ mod tests {
         assert_eq!(json, "\"alice\"");
     }
 
+    #[test]
+    fn test_storylet_handle_map_keys_serialize_as_strings() {
+        use std::collections::HashMap;
+
+        let mut usage: HashMap<StoryletHandle, u32> = HashMap::new();
+        usage.insert(StoryletHandle::new("first_kiss"), 2);
+
+        let json = serde_json::to_string(&usage).unwrap();
+        assert_eq!(json, "{\"first_kiss\":2}");
+
+        let restored: HashMap<StoryletHandle, u32> = serde_json::from_str(&json).unwrap();
+        let handle = StoryletHandle::get("first_kiss").unwrap();
+        assert_eq!(handle.key(), StoryletHandle::new("first_kiss").key());
+        assert_eq!(restored.get(&handle), Some(&2));
+    }
+
     #[test]
     fn test_hash_performance() {
         use std::collections::HashMap;
@@ -0,0 +1,448 @@
+//! Jealousy across love triangles.
+//!
+//! When the player's romance with someone moves up a band (affection or
+//! attraction, in either direction), other NPCs who are attracted to the
+//! player may take it badly. An NPC reacts when:
+//! - their attraction toward the player is at least `min_attraction`, and
+//! - they know about it: they hold a fresh rumor about the player or the new
+//!   partner, share a recent memory with the partner, or are in the same
+//!   social cluster.
+//!
+//! A jealous NPC gains resentment toward the partner and, less, toward the
+//! player. Both changes go through the pressure tracker, so band crossings
+//! queue ordinary `ResentmentBandChanged` events (source `jealousy:<partner>`)
+//! for storylets to react to, and resentment toward the player feeds rival
+//! actions. Once resentment toward the partner reaches `confront_resentment`
+//! the NPC confronts the player: the flag from [`jealousy_flag`] is set,
+//! heat rises and the player remembers the scene.
+//!
+//! Bands are compared against the ones the pass last saw, so the reaction
+//! follows whatever raised them: storylet outcomes, drift or direct edits.
+
+use std::collections::BTreeMap;
+
+use serde::{Deserialize, Serialize};
+
+use crate::flag_changes::{set_world_flag, FLAG_SOURCE_JEALOUSY};
+use crate::relationship_heat::record_relationship_interaction;
+use crate::relationship_model::RelationshipVector;
+use crate::relationship_pressure::shift_relationship_axis;
+use crate::relationships::RelationshipAxis;
+use crate::types::{MemoryEntryRecord, NpcId, Relationship, WorldState};
+
+/// Tag added to jealousy memories.
+pub const JEALOUSY_TAG: &str = "jealousy";
+
+/// Prefix of the dynamic world flag set when an NPC confronts the player
+/// out of jealousy (`jealous_confrontation:<npc_id>`).
+pub const JEALOUSY_FLAG_PREFIX: &str = "jealous_confrontation:";
+
+/// Dynamic world flag marking that `npc_id` confronted the player.
+pub fn jealousy_flag(npc_id: NpcId) -> String {
+    format!("{}{}", JEALOUSY_FLAG_PREFIX, npc_id.0)
+}
+
+/// Thresholds and pacing for jealousy.
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct JealousyConfig {
+    /// Whether the pass runs at all.
+    pub enabled: bool,
+    /// NPC→player attraction at which an NPC can get jealous.
+    pub min_attraction: f32,
+    /// Resentment gained toward the new partner per band-up.
+    pub partner_resentment: f32,
+    /// Resentment gained toward the player per band-up.
+    pub player_resentment: f32,
+    /// Resentment toward the partner at which the NPC confronts the player.
+    pub confront_resentment: f32,
+    /// Ticks an NPC waits before reacting again.
+    pub cooldown_ticks: u64,
+    /// How far back rumors and shared memories count as awareness.
+    pub awareness_ticks: u64,
+    /// Logged reactions kept for the UI.
+    pub max_recent: usize,
+}
+
+impl Default for JealousyConfig {
+    fn default() -> Self {
+        Self {
+            enabled: true,
+            min_attraction: 3.0,
+            partner_resentment: 1.5,
+            player_resentment: 0.75,
+            confront_resentment: 6.0,
+            cooldown_ticks: 24,
+            awareness_ticks: 24 * 30,
+            max_recent: 20,
+        }
+    }
+}
+
+/// Which romantic axis moved up.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum JealousyTrigger {
+    /// Attraction crossed into a higher band.
+    Attraction,
+    /// Affection crossed into a higher band.
+    Affection,
+}
+
+impl JealousyTrigger {
+    /// Stable lowercase label for UI/FFI.
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            JealousyTrigger::Attraction => "attraction",
+            JealousyTrigger::Affection => "affection",
+        }
+    }
+}
+
+/// One jealous reaction, as shown to the player.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct JealousyEvent {
+    /// Reaction id (unique within a run).
+    pub id: u64,
+    /// Tick it happened.
+    pub tick: u64,
+    /// The jealous NPC.
+    pub jealous_id: NpcId,
+    /// The player's partner they are jealous of.
+    pub partner_id: NpcId,
+    /// What moved up in the player's romance.
+    pub trigger: JealousyTrigger,
+    /// Jealous NPC's resentment toward the partner afterwards.
+    pub resentment: f32,
+    /// Whether they confronted the player.
+    pub confronted: bool,
+}
+
+/// Highest affection and attraction band ranks between the player and an
+/// NPC, over both directions.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub struct RomanceBands {
+    /// Affection band rank (0 = Stranger).
+    pub affection: u8,
+    /// Attraction band rank (0 = None).
+    pub attraction: u8,
+}
+
+impl RomanceBands {
+    fn of(rel: &Relationship) -> Self {
+        let vector = RelationshipVector::from(rel);
+        RomanceBands {
+            affection: vector.affection_band() as u8,
+            attraction: vector.attraction_band() as u8,
+        }
+    }
+
+    fn max(self, other: Self) -> Self {
+        RomanceBands {
+            affection: self.affection.max(other.affection),
+            attraction: self.attraction.max(other.attraction),
+        }
+    }
+}
+
+/// Jealousy bookkeeping stored on `WorldState`.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct JealousyState {
+    /// Thresholds and pacing.
+    #[serde(default)]
+    pub config: JealousyConfig,
+    /// Most recent reactions, oldest first.
+    #[serde(default)]
+    pub recent: Vec<JealousyEvent>,
+    /// Romance bands with each NPC as of the last pass, by NPC id.
+    #[serde(default)]
+    pub bands: BTreeMap<u64, RomanceBands>,
+    /// Last tick each NPC (by id) reacted.
+    #[serde(default)]
+    pub last_reacted: BTreeMap<u64, u64>,
+    /// Whether `bands` has been filled; the first pass only records.
+    #[serde(default)]
+    primed: bool,
+    /// Next reaction id.
+    #[serde(default)]
+    next_id: u64,
+}
+
+impl JealousyState {
+    fn cooling_down(&self, npc_id: NpcId, tick: u64) -> bool {
+        self.last_reacted
+            .get(&npc_id.0)
+            .is_some_and(|last| tick.saturating_sub(*last) < self.config.cooldown_ticks)
+    }
+}
+
+/// Romance bands between the player and every NPC they have a relationship with.
+fn romance_bands(world: &WorldState) -> BTreeMap<u64, RomanceBands> {
+    let player = world.player_id;
+    let mut bands: BTreeMap<u64, RomanceBands> = BTreeMap::new();
+    for ((from, to), rel) in &world.relationships {
+        let other = match (*from == player, *to == player) {
+            (true, false) => *to,
+            (false, true) => *from,
+            _ => continue,
+        };
+        let pair = RomanceBands::of(rel);
+        bands
+            .entry(other.0)
+            .and_modify(|existing| *existing = existing.max(pair))
+            .or_insert(pair);
+    }
+    bands
+}
+
+/// Whether `npc` has heard about the player and `partner`.
+fn is_aware(world: &WorldState, npc: NpcId, partner: NpcId, config: &JealousyConfig) -> bool {
+    let tick = world.current_tick.0;
+    let player = world.player_id;
+    let heard = world.gossip.knowledge.get(&npc).is_some_and(|known| {
+        known.keys().any(|rumor_id| {
+            world.gossip.rumors.get(rumor_id).is_some_and(|rumor| {
+                (rumor.subject_id == partner || rumor.subject_id == player)
+                    && rumor.is_fresh(tick, config.awareness_ticks)
+            })
+        })
+    });
+    let same_circle = world
+        .gossip
+        .clusters
+        .values()
+        .any(|cluster| cluster.contains(npc) && cluster.contains(partner));
+    let involves = |memory: &MemoryEntryRecord, id: NpcId| {
+        memory.npc_id == id || memory.participants.contains(&id.0)
+    };
+    let shared_memory = world.memory_entries.iter().any(|memory| {
+        tick.saturating_sub(memory.sim_tick.0) <= config.awareness_ticks
+            && involves(memory, npc)
+            && involves(memory, partner)
+    });
+    heard || same_circle || shared_memory
+}
+
+/// React to the player's romance with `partner` moving up a band.
+fn react_to_romance(
+    world: &mut WorldState,
+    partner: NpcId,
+    trigger: JealousyTrigger,
+) -> Vec<JealousyEvent> {
+    let tick = world.current_tick.0;
+    let player = world.player_id;
+    let config = world.jealousy.config;
+
+    let mut admirers: Vec<NpcId> = world
+        .relationships
+        .iter()
+        .filter(|((from, to), rel)| {
+            *to == player
+                && *from != player
+                && *from != partner
+                && rel.attraction >= config.min_attraction
+        })
+        .map(|((from, _), _)| *from)
+        .collect();
+    admirers.sort_by_key(|id| id.0);
+
+    let mut events = Vec::new();
+    for npc in admirers {
+        if world.jealousy.cooling_down(npc, tick) || !is_aware(world, npc, partner, &config) {
+            continue;
+        }
+        events.push(become_jealous(world, npc, partner, trigger));
+    }
+    events
+}
+
+/// Apply one jealous reaction and record it.
+fn become_jealous(
+    world: &mut WorldState,
+    npc: NpcId,
+    partner: NpcId,
+    trigger: JealousyTrigger,
+) -> JealousyEvent {
+    let tick = world.current_tick.0;
+    let player = world.player_id;
+    let config = world.jealousy.config;
+    let id = world.jealousy.next_id;
+    world.jealousy.next_id += 1;
+
+    let source = format!("{}:{}", JEALOUSY_TAG, partner.0);
+    let resentment = shift_relationship_axis(
+        world,
+        npc,
+        partner,
+        RelationshipAxis::Resentment,
+        config.partner_resentment,
+        &source,
+    )
+    .resentment;
+    shift_relationship_axis(
+        world,
+        npc,
+        player,
+        RelationshipAxis::Resentment,
+        config.player_resentment,
+        &source,
+    );
+
+    let confronted = resentment >= config.confront_resentment;
+    if confronted {
+        record_relationship_interaction(world, npc, player, 2.0);
+        world.add_heat(2.0);
+        world.memory_entries.push(MemoryEntryRecord {
+            id: format!("{}:{}", JEALOUSY_TAG, id),
+            event_id: format!("{}:confrontation", JEALOUSY_TAG),
+            npc_id: player,
+            sim_tick: world.current_tick,
+            emotional_intensity: -0.5,
+            tags: vec![
+                JEALOUSY_TAG.to_string(),
+                "confrontation".to_string(),
+                "conflict".to_string(),
+            ],
+            participants: vec![player.0, npc.0, partner.0],
+            ..Default::default()
+        });
+        set_world_flag(world, &jealousy_flag(npc), true, FLAG_SOURCE_JEALOUSY);
+    }
+
+    let event = JealousyEvent {
+        id,
+        tick,
+        jealous_id: npc,
+        partner_id: partner,
+        trigger,
+        resentment,
+        confronted,
+    };
+    let state = &mut world.jealousy;
+    state.last_reacted.insert(npc.0, tick);
+    state.recent.push(event.clone());
+    if state.recent.len() > config.max_recent {
+        let excess = state.recent.len() - config.max_recent;
+        state.recent.drain(..excess);
+    }
+    event
+}
+
+/// Run the jealousy pass: compare the player's romance bands with the last
+/// pass and let aware admirers react to every band-up. Returns the reactions.
+///
+/// Partners and admirers are visited in id order, so runs stay deterministic.
+/// Nothing happens while disabled or while content preferences block
+/// jealousy or conflict.
+pub fn tick_jealousy(world: &mut WorldState) -> Vec<JealousyEvent> {
+    let current = romance_bands(world);
+    let previous = std::mem::replace(&mut world.jealousy.bands, current.clone());
+    let primed = std::mem::replace(&mut world.jealousy.primed, true);
+    if !primed
+        || !world.jealousy.config.enabled
+        || !world
+            .content_preferences
+            .allows(None, [JEALOUSY_TAG, "conflict"])
+    {
+        return Vec::new();
+    }
+
+    let baseline = RomanceBands::of(&Relationship::default());
+    let mut events = Vec::new();
+    for (partner, bands) in current {
+        let before = previous.get(&partner).copied().unwrap_or(baseline);
+        let trigger = if bands.attraction > before.attraction {
+            JealousyTrigger::Attraction
+        } else if bands.affection > before.affection {
+            JealousyTrigger::Affection
+        } else {
+            continue;
+        };
+        events.extend(react_to_romance(world, NpcId(partner), trigger));
+    }
+    events
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::gossip::SocialCluster;
+    use crate::types::WorldSeed;
+
+    fn attracted(attraction: f32) -> Relationship {
+        Relationship {
+            attraction,
+            affection: 3.0,
+            ..Default::default()
+        }
+    }
+
+    /// Player 1 dates NPC 2; NPCs 3 and 4 are attracted to the player, but
+    /// only 3 moves in the same circle as 2.
+    fn triangle() -> WorldState {
+        let mut world = WorldState::new(WorldSeed(5), NpcId(1));
+        world.set_relationship(NpcId(1), NpcId(2), attracted(2.0));
+        world.set_relationship(NpcId(3), NpcId(1), attracted(7.0));
+        world.set_relationship(NpcId(4), NpcId(1), attracted(7.0));
+        let mut circle = SocialCluster::new("gym");
+        circle.add_member(NpcId(2));
+        circle.add_member(NpcId(3));
+        world.gossip.clusters.insert("gym".to_string(), circle);
+        tick_jealousy(&mut world);
+        world
+    }
+
+    #[test]
+    fn aware_admirers_resent_the_new_partner() {
+        let mut world = triangle();
+        world.set_relationship(NpcId(1), NpcId(2), attracted(6.5));
+        let events = tick_jealousy(&mut world);
+
+        assert_eq!(events.len(), 1);
+        assert_eq!(events[0].jealous_id, NpcId(3));
+        assert_eq!(events[0].trigger, JealousyTrigger::Attraction);
+        assert!(!events[0].confronted);
+        assert!(world.get_relationship(NpcId(3), NpcId(2)).resentment > 1.0);
+        assert!(world.get_relationship(NpcId(3), NpcId(1)).resentment > 0.0);
+        assert!(world.get_relationship(NpcId(4), NpcId(2)).resentment.abs() < 1e-6);
+        assert!(world
+            .relationship_pressure
+            .queue
+            .iter()
+            .any(|e| e.actor_id == 3 && e.source.as_deref() == Some("jealousy:2")));
+
+        // No further band change, no further reaction
+        assert!(tick_jealousy(&mut world).is_empty());
+    }
+
+    #[test]
+    fn simmering_jealousy_ends_in_a_confrontation() {
+        let mut world = triangle();
+        world.set_relationship(
+            NpcId(3),
+            NpcId(2),
+            Relationship {
+                resentment: 5.0,
+                ..Default::default()
+            },
+        );
+        world.set_relationship(
+            NpcId(2),
+            NpcId(1),
+            Relationship {
+                affection: 9.0,
+                ..Default::default()
+            },
+        );
+        let events = tick_jealousy(&mut world);
+
+        assert_eq!(events.len(), 1);
+        assert_eq!(events[0].trigger, JealousyTrigger::Affection);
+        assert!(events[0].confronted);
+        assert!(world.world_flags.has_any(&jealousy_flag(NpcId(3))));
+        assert!(world
+            .memory_entries
+            .iter()
+            .any(|m| m.id == format!("jealousy:{}", events[0].id) && m.npc_id == NpcId(1)));
+    }
+}
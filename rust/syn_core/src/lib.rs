@@ -1,13 +1,48 @@
 //! syn_core: Foundation types, RNG, and persistence for SYN simulation engine.
 //!
 //! This crate provides:
-//! - Seeded RNG for deterministic simulation
+//! - Seeded RNG for deterministic simulation, plus a separate cosmetic RNG for UI flavor
 //! - Core types (Stats, Traits, Relationships, NPCs, World)
 //! - SQLite persistence layer
 //! - Utility types for serialization and querying
 //! - Character generation from seeds
 //! - District system with crime/economy simulation
+//! - Storylet-authored district effects: economy and crime shocks, district flags
 //! - Gossip/social spread mechanics
+//! - Households and player cohabitation
+//! - Daily action/energy budget for the player
+//! - NPC mood ledger (shared by outcomes and emotional contagion)
+//! - Karma-driven underworld exposure track
+//! - Configurable karma model and district/cluster reputation
+//! - Validated external event injection with a narrative log
+//! - Ambient micro-events between storylets
+//! - Memory-driven dreams and flashbacks at night
+//! - NPC-initiated relationship proposals (partners, moving in, breaking off)
+//! - Life chapter summaries for the timeline (per life stage and year)
+//! - Player life goals with progress tracking and payoff/failure storylets
+//! - Per-pair relationship heat that spikes on interactions and decays
+//! - Rival NPCs who gossip about, sabotage, or confront the player
+//! - First meetings that grow the player's social circle over a life
+//! - Major characters, authored or earned through shared memories
+//! - Year in Review recaps computed locally at each calendar year's end
+//! - World flag change stream that reaction storylets trigger on
+//! - Event bus of typed world events (milestones, heat bands, life stages, fired storylets) for embedders
+//! - Optional NPC reaction latency with interceptable pending deltas
+//! - Short-horizon narrative heat forecast for UI pacing hints
+//! - Declared relationship statuses set by choices, with mismatch triggers
+//! - Pets, plants and younger siblings with care needs and neglect outcomes
+//! - Per-pair dialogue tone context from grudges, favor and shared memories
+//! - Optional dynamic difficulty adjustment with a transparent decision log
+//! - PostLife chapter for the Digital stage: signal, coherence, digital NPCs, legacy nudges
+//! - Per-event and per-day caps on outcome deltas, clamped with a logged warning
+//! - Player-authored notes on NPCs and journal entries, ignored by the simulation
+//! - Session-aware pacing from UI session markers: big beats early, none at the end
+//! - Platform-independent float math and an optional quantized accumulation mode
+//! - Jealousy in love triangles: admirers resent the player's new partner
+//! - Bystanders forming opinions of NPCs from how they treat others
+//! - Health and mood crisis detection with hysteresis for guaranteed interventions
+//! - NPCs spawned from character generation when no one known can fill a storylet role
+//! - Calendar with seasons, holidays and seed-derived birthdays
 //! - Population simulation with job markets and demographics
 //! - Failure/recovery systems with trauma spirals
 //! - High-performance collection types (FxHashMap, SmallVec)
@@ -24,50 +59,119 @@
 #[cfg(feature = "mimalloc-allocator")]
 pub mod allocator;
 
+pub mod action_budget;
+pub mod ambient;
+pub mod chapters;
 pub mod character_gen;
 pub mod collections;
+pub mod content_preferences;
+pub mod declared_relationships;
+pub mod delta_caps;
+pub mod det_math;
+pub mod dependents;
+pub mod dialogue_tone;
+pub mod dynamic_difficulty;
 pub mod digital_legacy;
 pub mod district;
+pub mod district_effects;
+pub mod dreams;
+pub mod encounters;
 pub mod errors;
+pub mod event_bus;
+pub mod external_events;
 pub mod failure_recovery;
+pub mod flag_changes;
 pub mod gossip;
 pub mod gossip_pressure;
+pub mod heat_forecast;
+pub mod household;
+pub mod importance;
 pub mod intern;
+pub mod jealousy;
+pub mod life_goals;
 pub mod life_stage;
+pub mod moral_ledger;
 pub mod narrative_heat;
+pub mod news_feed;
 pub mod npc;
 pub mod npc_actions;
 pub mod npc_behavior;
+pub mod npc_mood;
+pub mod npc_spawning;
+pub mod opinions;
 pub mod district_pressure;
 pub mod persistence;
+pub mod player_notes;
 pub mod population;
+pub mod post_life;
+pub mod proposals;
+pub mod reaction_latency;
+pub mod relationship_heat;
 pub mod relationship_milestones;
 pub mod relationship_model;
 pub mod relationship_pressure;
 pub mod relationships;
+pub mod rival_actions;
 pub mod rng;
+pub mod session_pacing;
 pub mod skills;
 pub mod snapshot;
 pub mod stats;
 pub mod time;
 pub mod types;
+pub mod underworld;
 pub mod world_flags;
+pub mod year_review;
 
+pub use action_budget::*;
+pub use ambient::*;
+pub use chapters::*;
 pub use character_gen::*;
 pub use collections::*;
+pub use content_preferences::*;
+pub use declared_relationships::*;
+pub use delta_caps::*;
+pub use dependents::*;
+pub use dialogue_tone::*;
+pub use dynamic_difficulty::*;
 pub use district::*;
+pub use district_effects::*;
+pub use dreams::*;
+pub use encounters::*;
 pub use errors::*;
+pub use event_bus::*;
+pub use external_events::*;
 pub use failure_recovery::*;
+pub use flag_changes::*;
 pub use gossip::*;
+pub use heat_forecast::*;
+pub use household::*;
+pub use importance::*;
 pub use intern::*;
+pub use jealousy::*;
+pub use life_goals::*;
+pub use moral_ledger::*;
+pub use news_feed::*;
+pub use npc_mood::*;
+pub use npc_spawning::*;
+pub use opinions::*;
 pub use persistence::*;
+pub use player_notes::*;
 pub use population::*;
+pub use post_life::*;
+pub use proposals::*;
+pub use reaction_latency::*;
+pub use relationship_heat::*;
 pub use relationships::*;
+pub use rival_actions::*;
 pub use rng::*;
+pub use session_pacing::*;
 pub use skills::*;
 pub use stats::*;
 pub use types::*;
+pub use underworld::*;
 pub use world_flags::*;
+pub use year_review::*;
 
 /// Library version
 pub const VERSION: &str = env!("CARGO_PKG_VERSION");
@@ -0,0 +1,517 @@
+//! Player life goals.
+//!
+//! A [`LifeGoal`] is something the player is working toward: a stat to
+//! raise, a skill to learn, relationships to build, or storylets to live
+//! through. Goals are picked at character creation (see
+//! [`life_goal_catalog`]) or emerge from the player's archetype
+//! ([`archetype_goal`]), and are tracked in [`LifeGoalState`].
+//!
+//! Once a day [`update_life_goals`] recomputes each active goal's progress.
+//! A goal whose requirements are all met is achieved; one still open when the
+//! player reaches its `deadline_age` has failed. Either way the player gets a
+//! memory tagged [`LIFE_GOAL_TAG`], and the goal's payoff or failure storylet
+//! (if authored) is queued for the director to deliver next.
+//!
+//! While a goal is active the director favors storylets carrying any of its
+//! `tags`.
+
+use serde::{Deserialize, Serialize};
+
+use crate::character_gen::CharacterArchetype;
+use crate::skills::SkillId;
+use crate::stats::{clamp_for, StatKind};
+use crate::types::{MemoryEntryRecord, RelationshipState, WorldState};
+
+/// Tag added to life goal memories.
+pub const LIFE_GOAL_TAG: &str = "life_goal";
+
+/// Most goals the player can pursue at once.
+pub const MAX_ACTIVE_GOALS: usize = 3;
+
+/// One condition a goal needs.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(tag = "type", rename_all = "snake_case")]
+pub enum GoalRequirement {
+    /// A player stat at or above `min`.
+    Stat {
+        /// Stat to raise.
+        stat: StatKind,
+        /// Target value.
+        min: f32,
+    },
+    /// A player skill at or above `min_level` (0-5).
+    Skill {
+        /// Skill id (e.g. "programming").
+        skill: String,
+        /// Target level.
+        min_level: u8,
+    },
+    /// At least `count` NPCs the player regards with `state`.
+    Relationships {
+        /// Relationship state to reach.
+        state: RelationshipState,
+        /// How many NPCs.
+        count: u32,
+    },
+    /// A storylet fired at least once.
+    StoryletFired {
+        /// Storylet id.
+        storylet_id: String,
+    },
+}
+
+impl GoalRequirement {
+    /// Short label for UI checklists (e.g. "Wealth 70+").
+    pub fn describe(&self) -> String {
+        match self {
+            GoalRequirement::Stat { stat, min } => format!("{:?} {}+", stat, min),
+            GoalRequirement::Skill { skill, min_level } => {
+                format!("{} level {}+", skill, min_level)
+            }
+            GoalRequirement::Relationships { state, count } => format!("{} x {:?}", count, state),
+            GoalRequirement::StoryletFired { storylet_id } => format!("Experience {}", storylet_id),
+        }
+    }
+
+    /// How close the player is to meeting this requirement (0.0..=1.0).
+    pub fn progress(&self, world: &WorldState) -> f32 {
+        let fraction = match self {
+            GoalRequirement::Stat { stat, min } => {
+                let floor = clamp_for(*stat, f32::MIN);
+                let span = *min - floor;
+                if span <= 0.0 {
+                    1.0
+                } else {
+                    (world.player_stats.get(*stat) - floor) / span
+                }
+            }
+            GoalRequirement::Skill { skill, min_level } => {
+                if *min_level == 0 {
+                    1.0
+                } else {
+                    let level = world.player_skills.get_level(&SkillId::new(skill.as_str()));
+                    level as f32 / *min_level as f32
+                }
+            }
+            GoalRequirement::Relationships { state, count } => {
+                if *count == 0 {
+                    1.0
+                } else {
+                    let player = world.player_id;
+                    let have = world
+                        .relationships
+                        .iter()
+                        .filter(|((from, to), rel)| {
+                            *from == player && *to != player && rel.state == *state
+                        })
+                        .count();
+                    have as f32 / *count as f32
+                }
+            }
+            GoalRequirement::StoryletFired { storylet_id } => {
+                if world.storylet_usage.count(storylet_id) > 0 {
+                    1.0
+                } else {
+                    0.0
+                }
+            }
+        };
+        fraction.clamp(0.0, 1.0)
+    }
+}
+
+/// Something the player is working toward.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct LifeGoal {
+    /// Stable goal id.
+    pub id: String,
+    /// Display title.
+    pub title: String,
+    /// Conditions that must all hold.
+    pub requirements: Vec<GoalRequirement>,
+    /// Storylet tags the director favors while the goal is active.
+    #[serde(default)]
+    pub tags: Vec<String>,
+    /// Player age by which the goal must be met (`None` never expires).
+    #[serde(default)]
+    pub deadline_age: Option<u32>,
+    /// Storylet delivered when the goal is achieved.
+    #[serde(default)]
+    pub payoff_storylet: Option<String>,
+    /// Storylet delivered when the goal fails.
+    #[serde(default)]
+    pub failure_storylet: Option<String>,
+}
+
+impl LifeGoal {
+    /// Overall progress: the mean of the requirements' progress.
+    pub fn progress(&self, world: &WorldState) -> f32 {
+        if self.requirements.is_empty() {
+            return 1.0;
+        }
+        let total: f32 = self.requirements.iter().map(|r| r.progress(world)).sum();
+        total / self.requirements.len() as f32
+    }
+
+    /// Whether every requirement is met.
+    pub fn is_met(&self, world: &WorldState) -> bool {
+        self.requirements.iter().all(|r| r.progress(world) >= 1.0)
+    }
+}
+
+/// Where a tracked goal stands.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum GoalStatus {
+    /// Still being pursued.
+    Active,
+    /// All requirements met.
+    Achieved,
+    /// The deadline passed first.
+    Failed,
+}
+
+impl GoalStatus {
+    /// Stable lowercase label for UI/FFI.
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            GoalStatus::Active => "active",
+            GoalStatus::Achieved => "achieved",
+            GoalStatus::Failed => "failed",
+        }
+    }
+}
+
+/// A goal the player has taken on.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct TrackedGoal {
+    /// The goal.
+    pub goal: LifeGoal,
+    /// Current status.
+    pub status: GoalStatus,
+    /// Progress at the last update (0.0..=1.0).
+    pub progress: f32,
+    /// Tick the goal was taken on.
+    pub started_tick: u64,
+    /// Tick the goal was achieved or failed.
+    #[serde(default)]
+    pub resolved_tick: Option<u64>,
+}
+
+/// Life goal bookkeeping stored on `WorldState`.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct LifeGoalState {
+    /// Goals in the order they were taken on.
+    #[serde(default)]
+    pub goals: Vec<TrackedGoal>,
+    /// Payoff/failure storylets waiting to be delivered, oldest first.
+    #[serde(default)]
+    pub pending_storylets: Vec<String>,
+}
+
+impl LifeGoalState {
+    /// Goals still being pursued.
+    pub fn active(&self) -> impl Iterator<Item = &TrackedGoal> {
+        self.goals.iter().filter(|g| g.status == GoalStatus::Active)
+    }
+
+    /// Look up a goal by id.
+    pub fn get(&self, goal_id: &str) -> Option<&TrackedGoal> {
+        self.goals.iter().find(|g| g.goal.id == goal_id)
+    }
+
+    /// Whether any active goal favors one of `tags`.
+    pub fn favors<'a>(&self, tags: impl IntoIterator<Item = &'a str>) -> bool {
+        let tags: Vec<&str> = tags.into_iter().collect();
+        self.active().any(|g| {
+            g.goal
+                .tags
+                .iter()
+                .any(|t| tags.iter().any(|tag| t.eq_ignore_ascii_case(tag)))
+        })
+    }
+
+    /// The payoff/failure storylet the director should deliver next.
+    pub fn next_pending_storylet(&self) -> Option<&str> {
+        self.pending_storylets.first().map(String::as_str)
+    }
+
+    /// Mark a queued payoff/failure storylet as delivered. Returns whether it
+    /// was queued.
+    pub fn take_pending_storylet(&mut self, storylet_id: &str) -> bool {
+        match self.pending_storylets.iter().position(|s| s == storylet_id) {
+            Some(idx) => {
+                self.pending_storylets.remove(idx);
+                true
+            }
+            None => false,
+        }
+    }
+}
+
+/// Goals offered at character creation, one per archetype.
+pub fn life_goal_catalog() -> Vec<LifeGoal> {
+    [
+        CharacterArchetype::Storyteller,
+        CharacterArchetype::Analyst,
+        CharacterArchetype::Dreamer,
+        CharacterArchetype::Challenger,
+    ]
+    .iter()
+    .map(|a| archetype_goal(*a))
+    .collect()
+}
+
+/// The goal an archetype gravitates toward when the player picks none.
+pub fn archetype_goal(archetype: CharacterArchetype) -> LifeGoal {
+    let (id, title, requirements, tags) = match archetype {
+        CharacterArchetype::Storyteller => (
+            "find_your_people",
+            "Find your people",
+            vec![
+                GoalRequirement::Relationships {
+                    state: RelationshipState::CloseFriend,
+                    count: 3,
+                },
+                GoalRequirement::Stat {
+                    stat: StatKind::Charisma,
+                    min: 60.0,
+                },
+            ],
+            vec!["friendship", "social"],
+        ),
+        CharacterArchetype::Analyst => (
+            "master_a_craft",
+            "Master a craft",
+            vec![
+                GoalRequirement::Stat {
+                    stat: StatKind::Intelligence,
+                    min: 70.0,
+                },
+                GoalRequirement::Skill {
+                    skill: "science".to_string(),
+                    min_level: 3,
+                },
+            ],
+            vec!["career", "education"],
+        ),
+        CharacterArchetype::Dreamer => (
+            "make_something_that_lasts",
+            "Make something that lasts",
+            vec![
+                GoalRequirement::Skill {
+                    skill: "art".to_string(),
+                    min_level: 3,
+                },
+                GoalRequirement::Stat {
+                    stat: StatKind::Wisdom,
+                    min: 60.0,
+                },
+            ],
+            vec!["creative", "art"],
+        ),
+        CharacterArchetype::Challenger => (
+            "make_your_fortune",
+            "Make your fortune",
+            vec![
+                GoalRequirement::Stat {
+                    stat: StatKind::Wealth,
+                    min: 70.0,
+                },
+                GoalRequirement::Stat {
+                    stat: StatKind::Reputation,
+                    min: 30.0,
+                },
+            ],
+            vec!["career", "money"],
+        ),
+    };
+    LifeGoal {
+        id: id.to_string(),
+        title: title.to_string(),
+        requirements,
+        tags: tags.into_iter().map(str::to_string).collect(),
+        deadline_age: Some(40),
+        payoff_storylet: Some(format!("goal_achieved_{}", id)),
+        failure_storylet: Some(format!("goal_failed_{}", id)),
+    }
+}
+
+/// Take on a goal. Returns false if it is already tracked or the player is
+/// pursuing [`MAX_ACTIVE_GOALS`] already.
+pub fn adopt_life_goal(world: &mut WorldState, goal: LifeGoal) -> bool {
+    let state = &world.life_goals;
+    if state.get(&goal.id).is_some() || state.active().count() >= MAX_ACTIVE_GOALS {
+        return false;
+    }
+    let progress = goal.progress(world);
+    world.life_goals.goals.push(TrackedGoal {
+        goal,
+        status: GoalStatus::Active,
+        progress,
+        started_tick: world.current_tick.0,
+        resolved_tick: None,
+    });
+    true
+}
+
+/// Refresh progress on active goals and resolve the ones that are met or
+/// past their deadline. Returns the ids of goals resolved by this call.
+pub fn update_life_goals(world: &mut WorldState) -> Vec<String> {
+    let tick = world.current_tick;
+    let age = world.player_age_years;
+    let mut resolved = Vec::new();
+    for idx in 0..world.life_goals.goals.len() {
+        let tracked = &world.life_goals.goals[idx];
+        if tracked.status != GoalStatus::Active {
+            continue;
+        }
+        let progress = tracked.goal.progress(world);
+        let status = if tracked.goal.is_met(world) {
+            GoalStatus::Achieved
+        } else if tracked
+            .goal
+            .deadline_age
+            .is_some_and(|deadline| age >= deadline)
+        {
+            GoalStatus::Failed
+        } else {
+            GoalStatus::Active
+        };
+
+        let tracked = &mut world.life_goals.goals[idx];
+        tracked.progress = progress;
+        if status == GoalStatus::Active {
+            continue;
+        }
+        tracked.status = status;
+        tracked.resolved_tick = Some(tick.0);
+        let goal = tracked.goal.clone();
+
+        let storylet = match status {
+            GoalStatus::Achieved => goal.payoff_storylet.clone(),
+            _ => goal.failure_storylet.clone(),
+        };
+        if let Some(storylet) = storylet {
+            world.life_goals.pending_storylets.push(storylet);
+        }
+        world.memory_entries.push(MemoryEntryRecord {
+            id: format!("life_goal:{}", goal.id),
+            event_id: format!("life_goal:{}:{}", goal.id, status.as_str()),
+            npc_id: world.player_id,
+            sim_tick: tick,
+            emotional_intensity: if status == GoalStatus::Achieved {
+                0.8
+            } else {
+                -0.6
+            },
+            tags: vec![LIFE_GOAL_TAG.to_string(), status.as_str().to_string()],
+            participants: vec![world.player_id.0],
+            ..Default::default()
+        });
+        resolved.push(goal.id);
+    }
+    resolved
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::types::{NpcId, Relationship, WorldSeed};
+
+    fn goal(requirements: Vec<GoalRequirement>) -> LifeGoal {
+        LifeGoal {
+            id: "test_goal".to_string(),
+            title: "Test".to_string(),
+            requirements,
+            tags: vec!["career".to_string()],
+            deadline_age: Some(30),
+            payoff_storylet: Some("payoff".to_string()),
+            failure_storylet: Some("failure".to_string()),
+        }
+    }
+
+    #[test]
+    fn progress_averages_requirements() {
+        let mut world = WorldState::new(WorldSeed(1), NpcId(1));
+        world.player_stats.wealth = 25.0;
+        world.set_relationship(
+            NpcId(1),
+            NpcId(2),
+            Relationship {
+                state: RelationshipState::CloseFriend,
+                ..Relationship::default()
+            },
+        );
+        let goal = goal(vec![
+            GoalRequirement::Stat {
+                stat: StatKind::Wealth,
+                min: 50.0,
+            },
+            GoalRequirement::Relationships {
+                state: RelationshipState::CloseFriend,
+                count: 2,
+            },
+            GoalRequirement::StoryletFired {
+                storylet_id: "first_job".to_string(),
+            },
+        ]);
+        assert!((goal.progress(&world) - (0.5 + 0.5 + 0.0) / 3.0).abs() < 1e-6);
+
+        world.storylet_usage.record_fire("first_job");
+        assert!((goal.progress(&world) - (0.5 + 0.5 + 1.0) / 3.0).abs() < 1e-6);
+    }
+
+    #[test]
+    fn met_goals_pay_off_and_late_goals_fail() {
+        let mut world = WorldState::new(WorldSeed(1), NpcId(1));
+        assert!(adopt_life_goal(
+            &mut world,
+            goal(vec![GoalRequirement::Stat {
+                stat: StatKind::Wealth,
+                min: 50.0,
+            }])
+        ));
+        assert!(world.life_goals.favors(["Career"]));
+
+        world.player_stats.wealth = 80.0;
+        assert_eq!(update_life_goals(&mut world), vec!["test_goal"]);
+        let tracked = world.life_goals.get("test_goal").unwrap();
+        assert_eq!(tracked.status, GoalStatus::Achieved);
+        assert_eq!(world.life_goals.next_pending_storylet(), Some("payoff"));
+        assert!(!world.life_goals.favors(["career"]));
+        assert!(world
+            .memory_entries
+            .iter()
+            .any(|m| m.event_id == "life_goal:test_goal:achieved"));
+
+        let mut world = WorldState::new(WorldSeed(1), NpcId(1));
+        adopt_life_goal(
+            &mut world,
+            goal(vec![GoalRequirement::Stat {
+                stat: StatKind::Wealth,
+                min: 100.0,
+            }]),
+        );
+        world.player_age_years = 30;
+        update_life_goals(&mut world);
+        assert_eq!(
+            world.life_goals.get("test_goal").unwrap().status,
+            GoalStatus::Failed
+        );
+        assert!(world.life_goals.take_pending_storylet("failure"));
+        assert_eq!(world.life_goals.next_pending_storylet(), None);
+    }
+
+    #[test]
+    fn adoption_rejects_duplicates_and_caps_active_goals() {
+        let mut world = WorldState::new(WorldSeed(1), NpcId(1));
+        let catalog = life_goal_catalog();
+        assert_eq!(catalog.len(), 4);
+        assert!(adopt_life_goal(&mut world, catalog[0].clone()));
+        assert!(!adopt_life_goal(&mut world, catalog[0].clone()));
+        assert!(adopt_life_goal(&mut world, catalog[1].clone()));
+        assert!(adopt_life_goal(&mut world, catalog[2].clone()));
+        assert!(!adopt_life_goal(&mut world, catalog[3].clone()));
+    }
+}
@@ -0,0 +1,316 @@
+//! Authored moral bookkeeping: the karma model and scoped reputation.
+//!
+//! Karma used to move only as a side effect of outcome emotional intensity.
+//! The [`KarmaModel`] makes the weighting explicit (how much intensity counts,
+//! how virtuous and vicious choices are scaled) and lets karma drift back
+//! toward neutral over time. Alongside it, the [`MoralLedger`] keeps the
+//! player's reputation per district and per social cluster, moved by
+//! per-choice [`ReputationDelta`]s authored on storylet outcomes.
+
+use std::collections::BTreeMap;
+
+use serde::{Deserialize, Serialize};
+
+use crate::types::{NpcId, WorldState};
+
+/// Bounds for a scoped reputation value.
+pub const REPUTATION_MIN: f32 = -100.0;
+/// Bounds for a scoped reputation value.
+pub const REPUTATION_MAX: f32 = 100.0;
+/// Scoped reputation closer to neutral than this is dropped from the ledger.
+const REPUTATION_EPSILON: f32 = 0.01;
+
+/// Weightings and decay for player karma.
+///
+/// The defaults reproduce the historical behavior: intensity counts ×10,
+/// authored deltas apply as written, and karma never drifts.
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq)]
+pub struct KarmaModel {
+    /// Karma per point of outcome emotional intensity (-1..1).
+    #[serde(default = "KarmaModel::default_intensity_weight")]
+    pub intensity_weight: f32,
+    /// Multiplier for positive authored karma deltas.
+    #[serde(default = "KarmaModel::default_choice_weight")]
+    pub virtue_weight: f32,
+    /// Multiplier for negative authored karma deltas.
+    #[serde(default = "KarmaModel::default_choice_weight")]
+    pub vice_weight: f32,
+    /// Fraction of karma that drifts back toward neutral each day (0..1).
+    #[serde(default)]
+    pub daily_decay: f32,
+    /// Fraction of each scoped reputation that fades each day (0..1).
+    #[serde(default)]
+    pub reputation_daily_decay: f32,
+}
+
+impl KarmaModel {
+    fn default_intensity_weight() -> f32 {
+        10.0
+    }
+
+    fn default_choice_weight() -> f32 {
+        1.0
+    }
+
+    /// Scale an authored karma delta by the virtue/vice weighting.
+    pub fn weigh_choice(&self, delta: f32) -> f32 {
+        if delta >= 0.0 {
+            delta * self.virtue_weight
+        } else {
+            delta * self.vice_weight
+        }
+    }
+
+    /// Karma implied by an outcome's emotional intensity.
+    pub fn weigh_intensity(&self, emotional_intensity: f32) -> f32 {
+        emotional_intensity * self.intensity_weight
+    }
+}
+
+impl Default for KarmaModel {
+    fn default() -> Self {
+        Self {
+            intensity_weight: Self::default_intensity_weight(),
+            virtue_weight: Self::default_choice_weight(),
+            vice_weight: Self::default_choice_weight(),
+            daily_decay: 0.0,
+            reputation_daily_decay: 0.0,
+        }
+    }
+}
+
+/// Who a reputation change is scoped to.
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum ReputationScope {
+    /// Everyone living in a district (by district name).
+    District(String),
+    /// A gossip social cluster (by cluster id, e.g. "cluster_0").
+    Cluster(String),
+}
+
+/// An authored reputation change attached to a choice outcome.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct ReputationDelta {
+    /// Who hears about it.
+    pub scope: ReputationScope,
+    /// Change in standing (positive is favorable).
+    pub delta: f32,
+}
+
+/// The player's karma model and scoped reputation.
+#[derive(Debug, Clone, Default, Serialize, Deserialize, PartialEq)]
+pub struct MoralLedger {
+    /// How karma is weighted and decays.
+    #[serde(default)]
+    pub karma_model: KarmaModel,
+    /// Reputation per district name (-100..100).
+    #[serde(default)]
+    pub district_reputation: BTreeMap<String, f32>,
+    /// Reputation per social cluster id (-100..100).
+    #[serde(default)]
+    pub cluster_reputation: BTreeMap<String, f32>,
+}
+
+impl MoralLedger {
+    /// Apply a scoped reputation change.
+    pub fn apply_reputation(&mut self, delta: &ReputationDelta) {
+        let (map, key) = match &delta.scope {
+            ReputationScope::District(name) => (&mut self.district_reputation, name),
+            ReputationScope::Cluster(id) => (&mut self.cluster_reputation, id),
+        };
+        let value = map.entry(key.clone()).or_insert(0.0);
+        *value = (*value + delta.delta).clamp(REPUTATION_MIN, REPUTATION_MAX);
+        if value.abs() < REPUTATION_EPSILON {
+            map.remove(key);
+        }
+    }
+
+    /// The player's standing in a district (0 when unknown).
+    pub fn district_reputation(&self, district: &str) -> f32 {
+        self.district_reputation
+            .get(district)
+            .copied()
+            .unwrap_or(0.0)
+    }
+
+    /// The player's standing with a social cluster (0 when unknown).
+    pub fn cluster_reputation(&self, cluster_id: &str) -> f32 {
+        self.cluster_reputation
+            .get(cluster_id)
+            .copied()
+            .unwrap_or(0.0)
+    }
+
+    /// Fade scoped reputation toward neutral by the model's daily rate.
+    pub fn decay_reputation_daily(&mut self) {
+        let keep = (1.0 - self.karma_model.reputation_daily_decay).clamp(0.0, 1.0);
+        if keep >= 1.0 {
+            return;
+        }
+        for map in [&mut self.district_reputation, &mut self.cluster_reputation] {
+            for value in map.values_mut() {
+                *value *= keep;
+            }
+            map.retain(|_, value| value.abs() >= REPUTATION_EPSILON);
+        }
+    }
+}
+
+/// Apply an outcome's karma through the world's karma model.
+///
+/// `emotional_intensity` feeds karma directly; the authored `karma_delta`
+/// is weighted by virtue/vice and also counts toward underworld exposure.
+pub fn apply_outcome_karma(
+    world: &mut WorldState,
+    emotional_intensity: f32,
+    karma_delta: Option<f32>,
+) {
+    let model = world.moral_ledger.karma_model;
+    world
+        .player_karma
+        .apply_delta(model.weigh_intensity(emotional_intensity));
+    if let Some(delta) = karma_delta {
+        apply_authored_karma(world, delta);
+    }
+}
+
+/// Apply an authored karma delta, capped (see [`crate::delta_caps`]) and
+/// weighted by the world's karma model.
+pub fn apply_authored_karma(world: &mut WorldState, delta: f32) {
+    let delta = crate::delta_caps::cap_karma_delta(world, delta);
+    let weighted = world.moral_ledger.karma_model.weigh_choice(delta);
+    crate::underworld::apply_karma(world, weighted);
+}
+
+/// Apply authored reputation changes to the player's ledger.
+pub fn apply_reputation_deltas(world: &mut WorldState, deltas: &[ReputationDelta]) {
+    for delta in deltas {
+        world.moral_ledger.apply_reputation(delta);
+    }
+}
+
+/// How the district and social cluster an NPC belongs to regard the player.
+///
+/// Averages the NPC's district standing with the standing of every gossip
+/// cluster the NPC is a member of.
+pub fn reputation_among(world: &WorldState, npc_id: NpcId) -> f32 {
+    let ledger = &world.moral_ledger;
+    let mut total = 0.0;
+    let mut scopes = 0;
+    if let Some(npc) = world.npcs.get(&npc_id) {
+        total += ledger.district_reputation(&npc.district);
+        scopes += 1;
+    }
+    let mut clusters: Vec<&String> = world
+        .gossip
+        .clusters
+        .iter()
+        .filter(|(_, cluster)| cluster.contains(npc_id))
+        .map(|(id, _)| id)
+        .collect();
+    clusters.sort();
+    for id in clusters {
+        total += ledger.cluster_reputation(id);
+        scopes += 1;
+    }
+    if scopes == 0 {
+        0.0
+    } else {
+        total / scopes as f32
+    }
+}
+
+/// Daily moral drift: karma relaxes toward neutral and reputation fades.
+///
+/// Runs on the district phase cadence; only acts on day boundaries.
+pub fn tick_moral_ledger(world: &mut WorldState) {
+    if !world.current_tick.0.is_multiple_of(24) {
+        return;
+    }
+    let decay = world.moral_ledger.karma_model.daily_decay.clamp(0.0, 1.0);
+    if decay > 0.0 {
+        world.player_karma.0 *= 1.0 - decay;
+    }
+    world.moral_ledger.decay_reputation_daily();
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::types::WorldSeed;
+
+    fn district(name: &str, delta: f32) -> ReputationDelta {
+        ReputationDelta {
+            scope: ReputationScope::District(name.to_string()),
+            delta,
+        }
+    }
+
+    #[test]
+    fn test_default_model_matches_legacy_karma() {
+        let mut world = WorldState::new(WorldSeed(3), NpcId(1));
+        apply_outcome_karma(&mut world, 0.5, Some(-20.0));
+        assert!((world.player_karma.0 - (5.0 - 20.0)).abs() < 1e-4);
+    }
+
+    #[test]
+    fn test_weightings_and_decay() {
+        let mut world = WorldState::new(WorldSeed(3), NpcId(1));
+        world.moral_ledger.karma_model = KarmaModel {
+            intensity_weight: 0.0,
+            virtue_weight: 0.5,
+            vice_weight: 2.0,
+            daily_decay: 0.5,
+            reputation_daily_decay: 0.5,
+        };
+        apply_outcome_karma(&mut world, 1.0, Some(10.0));
+        assert!((world.player_karma.0 - 5.0).abs() < 1e-4);
+        apply_authored_karma(&mut world, -10.0);
+        assert!((world.player_karma.0 + 15.0).abs() < 1e-4);
+
+        apply_reputation_deltas(&mut world, &[district("Downtown", 40.0)]);
+        world.current_tick.0 = 24;
+        tick_moral_ledger(&mut world);
+        assert!((world.player_karma.0 + 7.5).abs() < 1e-4);
+        assert!((world.moral_ledger.district_reputation("Downtown") - 20.0).abs() < 1e-4);
+    }
+
+    #[test]
+    fn test_scoped_reputation_clamps_and_averages() {
+        let mut world = WorldState::new(WorldSeed(3), NpcId(1));
+        apply_reputation_deltas(
+            &mut world,
+            &[
+                district("Downtown", 80.0),
+                district("Downtown", 80.0),
+                ReputationDelta {
+                    scope: ReputationScope::Cluster("cluster_0".to_string()),
+                    delta: -20.0,
+                },
+            ],
+        );
+        assert_eq!(world.moral_ledger.district_reputation("Downtown"), 100.0);
+
+        let npc = crate::types::AbstractNpc {
+            id: NpcId(2),
+            age: 30,
+            job: "Barista".to_string(),
+            district: "Downtown".to_string(),
+            household_id: 2,
+            traits: Default::default(),
+            seed: 2,
+            attachment_style: Default::default(),
+        };
+        world.npcs.insert(NpcId(2), npc);
+        let mut cluster = crate::gossip::SocialCluster::new("cluster_0".to_string());
+        cluster.add_member(NpcId(2));
+        world
+            .gossip
+            .clusters
+            .insert("cluster_0".to_string(), cluster);
+
+        assert!((reputation_among(&world, NpcId(2)) - 40.0).abs() < 1e-4);
+        assert_eq!(reputation_among(&world, NpcId(9)), 0.0);
+    }
+}
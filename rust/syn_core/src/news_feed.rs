@@ -0,0 +1,575 @@
+//! Ambient world news feed.
+//!
+//! Once per in-game day the world condenses what happened "off screen" into a
+//! handful of short, sentiment-tagged headlines: district threshold events,
+//! black swan events, newsworthy storylets and salient gossip. Items live in a
+//! fixed-size ring buffer on `WorldState`.
+//!
+//! Generation is deterministic: candidates are sorted by stable keys before
+//! being truncated to the daily cap, so the same seed yields the same feed.
+//!
+//! The director treats fresh news as another trigger source: storylets tagged
+//! [`NEWS_REACTION_TAG`] whose tags overlap a recent headline get a score bonus.
+
+use crate::district_pressure::{DistrictEventKind, DistrictPressureEvent};
+use crate::gossip::Rumor;
+use crate::npc::NpcPrototype;
+use crate::population::{BlackSwanEvent, PopulationSimulation};
+use crate::types::NpcId;
+use serde::{Deserialize, Serialize};
+use std::collections::{HashMap, VecDeque};
+
+/// Storylets carrying this tag are reported to the feed when they fire
+/// (e.g. background NPC storylets the player only hears about).
+pub const NEWSWORTHY_TAG: &str = "newsworthy";
+
+/// Storylets carrying this tag react to recent headlines with matching tags.
+pub const NEWS_REACTION_TAG: &str = "news_reaction";
+
+/// Default number of items retained in the ring buffer.
+pub const DEFAULT_NEWS_CAPACITY: usize = 64;
+
+/// Default maximum number of items generated per day.
+pub const DEFAULT_NEWS_ITEMS_PER_DAY: usize = 6;
+
+/// Minimum rumor salience for gossip to make the news (scandals always do).
+const GOSSIP_SALIENCE_THRESHOLD: f32 = 0.6;
+
+/// Ticks per in-game day.
+const TICKS_PER_DAY: u64 = 24;
+
+/// Overall tone of a news item.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub enum NewsSentiment {
+    /// Good news (booms, praise).
+    Positive,
+    /// Neither good nor bad.
+    Neutral,
+    /// Bad news (crises, scandals).
+    Negative,
+}
+
+impl NewsSentiment {
+    /// Classify a valence in -1.0..=1.0.
+    pub fn from_valence(valence: f32) -> Self {
+        if valence > 0.2 {
+            NewsSentiment::Positive
+        } else if valence < -0.2 {
+            NewsSentiment::Negative
+        } else {
+            NewsSentiment::Neutral
+        }
+    }
+
+    /// Stable lowercase label for UI/FFI.
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            NewsSentiment::Positive => "positive",
+            NewsSentiment::Neutral => "neutral",
+            NewsSentiment::Negative => "negative",
+        }
+    }
+}
+
+/// Where a news item came from. Ordering doubles as daily priority.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Serialize, Deserialize)]
+pub enum NewsSource {
+    /// Population-level black swan event.
+    BlackSwan,
+    /// District pressure threshold event.
+    District,
+    /// Storylet tagged `newsworthy` that fired.
+    Storylet,
+    /// Salient or scandalous rumor.
+    Gossip,
+    /// Ambient micro-event (published immediately, not part of the daily pass).
+    Ambient,
+}
+
+impl NewsSource {
+    /// Stable lowercase label for UI/FFI.
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            NewsSource::BlackSwan => "black_swan",
+            NewsSource::District => "district",
+            NewsSource::Storylet => "storylet",
+            NewsSource::Gossip => "gossip",
+            NewsSource::Ambient => "ambient",
+        }
+    }
+}
+
+/// A single headline in the feed.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct NewsItem {
+    /// Monotonic item id (unique within a run).
+    pub id: u64,
+    /// Tick the underlying event happened.
+    pub tick: u64,
+    /// Day the item was published.
+    pub day: u64,
+    /// Where the story came from.
+    pub source: NewsSource,
+    /// Overall tone.
+    pub sentiment: NewsSentiment,
+    /// Short human-readable headline.
+    pub headline: String,
+    /// Narrative tags (used for director news reactions).
+    #[serde(default)]
+    pub tags: Vec<String>,
+    /// District the story is about, if any.
+    #[serde(default)]
+    pub district_id: Option<u32>,
+    /// NPC the story is about, if any.
+    #[serde(default)]
+    pub subject_id: Option<NpcId>,
+}
+
+/// A storylet firing waiting to be published with the next daily feed.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+struct StoryletReport {
+    tick: u64,
+    storylet_id: String,
+    headline: String,
+    tags: Vec<String>,
+}
+
+/// Ring buffer of generated news items.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct NewsFeed {
+    /// Published items, oldest first.
+    #[serde(default)]
+    pub items: VecDeque<NewsItem>,
+    /// Maximum items retained.
+    pub capacity: usize,
+    /// Maximum items generated per day.
+    pub max_items_per_day: usize,
+    /// Next item id.
+    #[serde(default)]
+    next_id: u64,
+    /// Storylet reports collected since the last daily generation.
+    #[serde(default)]
+    pending_reports: Vec<StoryletReport>,
+}
+
+impl Default for NewsFeed {
+    fn default() -> Self {
+        Self::new(DEFAULT_NEWS_CAPACITY)
+    }
+}
+
+/// Inputs for a daily news generation pass (borrowed from `WorldState`).
+pub struct NewsSources<'a> {
+    /// Recent district threshold events.
+    pub district_events: &'a VecDeque<DistrictPressureEvent>,
+    /// Population simulation (black swan events).
+    pub population: &'a PopulationSimulation,
+    /// Active rumors by id.
+    pub rumors: &'a HashMap<String, Rumor>,
+    /// Prototypes used to name rumor subjects.
+    pub npc_prototypes: &'a HashMap<NpcId, NpcPrototype>,
+    /// The player (rumors about them read "you").
+    pub player_id: NpcId,
+}
+
+impl NewsFeed {
+    /// Create an empty feed retaining at most `capacity` items.
+    pub fn new(capacity: usize) -> Self {
+        Self {
+            items: VecDeque::new(),
+            capacity: capacity.max(1),
+            max_items_per_day: DEFAULT_NEWS_ITEMS_PER_DAY,
+            next_id: 0,
+            pending_reports: Vec::new(),
+        }
+    }
+
+    /// Number of retained items.
+    pub fn len(&self) -> usize {
+        self.items.len()
+    }
+
+    /// True if nothing has been published yet.
+    pub fn is_empty(&self) -> bool {
+        self.items.is_empty()
+    }
+
+    /// Most recent items first, at most `limit`.
+    pub fn latest(&self, limit: usize) -> impl Iterator<Item = &NewsItem> {
+        self.items.iter().rev().take(limit)
+    }
+
+    /// Items published at or after `since_tick`.
+    pub fn items_since(&self, since_tick: u64) -> impl Iterator<Item = &NewsItem> {
+        self.items
+            .iter()
+            .filter(move |item| item.tick >= since_tick)
+    }
+
+    /// Queue a fired storylet for the next daily feed.
+    pub fn report_storylet(
+        &mut self,
+        tick: u64,
+        storylet_id: &str,
+        headline: &str,
+        tags: &[String],
+    ) {
+        self.pending_reports.push(StoryletReport {
+            tick,
+            storylet_id: storylet_id.to_string(),
+            headline: headline.to_string(),
+            tags: tags.to_vec(),
+        });
+    }
+
+    /// Publish an ambient micro-event right away (see [`crate::ambient`]).
+    pub fn publish_ambient(
+        &mut self,
+        tick: u64,
+        text: &str,
+        tags: &[String],
+        subject_id: Option<NpcId>,
+    ) {
+        let item = NewsItem {
+            id: self.next_id,
+            tick,
+            day: tick / TICKS_PER_DAY,
+            source: NewsSource::Ambient,
+            sentiment: NewsSentiment::Neutral,
+            headline: text.to_string(),
+            tags: tags.to_vec(),
+            district_id: None,
+            subject_id,
+        };
+        self.next_id += 1;
+        self.push(item);
+    }
+
+    /// Generate the feed for the day ending at `now`.
+    ///
+    /// Looks at events in the window `(now - 24, now]`, sorts candidates by
+    /// source priority and stable keys, and publishes up to
+    /// `max_items_per_day` of them. Returns the number of items published.
+    pub fn generate_daily(&mut self, now: u64, sources: &NewsSources<'_>) -> usize {
+        let window_start = now.saturating_sub(TICKS_PER_DAY);
+        let in_window = |tick: u64| tick > window_start && tick <= now;
+
+        let mut candidates: Vec<(NewsSource, u64, String, NewsDraft)> = Vec::new();
+
+        for (idx, event) in sources.population.active_events.iter().enumerate() {
+            let started = black_swan_started_tick(event);
+            if in_window(started) {
+                candidates.push((
+                    NewsSource::BlackSwan,
+                    started,
+                    format!("{:04}", idx),
+                    black_swan_draft(sources.population, event),
+                ));
+            }
+        }
+
+        for event in sources.district_events.iter().filter(|e| in_window(e.tick)) {
+            candidates.push((
+                NewsSource::District,
+                event.tick,
+                format!("{:08}:{:?}", event.district_id, event.kind),
+                district_draft(event),
+            ));
+        }
+
+        for report in std::mem::take(&mut self.pending_reports) {
+            if !in_window(report.tick) {
+                continue;
+            }
+            let key = report.storylet_id.clone();
+            candidates.push((
+                NewsSource::Storylet,
+                report.tick,
+                key,
+                storylet_draft(report),
+            ));
+        }
+
+        for rumor in sources
+            .rumors
+            .values()
+            .filter(|r| in_window(r.created_tick))
+        {
+            if rumor.is_scandalous || rumor.salience >= GOSSIP_SALIENCE_THRESHOLD {
+                candidates.push((
+                    NewsSource::Gossip,
+                    rumor.created_tick,
+                    rumor.id.clone(),
+                    gossip_draft(rumor, sources),
+                ));
+            }
+        }
+
+        candidates.sort_by(|a, b| (a.0, a.1, &a.2).cmp(&(b.0, b.1, &b.2)));
+        candidates.truncate(self.max_items_per_day);
+
+        let day = now / TICKS_PER_DAY;
+        let published = candidates.len();
+        for (source, tick, _, draft) in candidates {
+            let item = NewsItem {
+                id: self.next_id,
+                tick,
+                day,
+                source,
+                sentiment: draft.sentiment,
+                headline: draft.headline,
+                tags: draft.tags,
+                district_id: draft.district_id,
+                subject_id: draft.subject_id,
+            };
+            self.next_id += 1;
+            self.push(item);
+        }
+        published
+    }
+
+    fn push(&mut self, item: NewsItem) {
+        while self.items.len() >= self.capacity {
+            self.items.pop_front();
+        }
+        self.items.push_back(item);
+    }
+}
+
+/// Fields of a news item before it gets an id.
+struct NewsDraft {
+    sentiment: NewsSentiment,
+    headline: String,
+    tags: Vec<String>,
+    district_id: Option<u32>,
+    subject_id: Option<NpcId>,
+}
+
+fn black_swan_started_tick(event: &BlackSwanEvent) -> u64 {
+    match event {
+        BlackSwanEvent::Recession { started_tick, .. }
+        | BlackSwanEvent::Boom { started_tick, .. }
+        | BlackSwanEvent::Pandemic { started_tick, .. }
+        | BlackSwanEvent::NaturalDisaster { started_tick, .. }
+        | BlackSwanEvent::CorporateCollapse { started_tick, .. }
+        | BlackSwanEvent::TechRevolution { started_tick, .. }
+        | BlackSwanEvent::HousingCrisis { started_tick, .. }
+        | BlackSwanEvent::CrimeWave { started_tick, .. } => *started_tick,
+    }
+}
+
+fn black_swan_draft(population: &PopulationSimulation, event: &BlackSwanEvent) -> NewsDraft {
+    let (sentiment, tags): (NewsSentiment, &[&str]) = match event {
+        BlackSwanEvent::Boom { .. } => (NewsSentiment::Positive, &["economy", "prosperity"]),
+        BlackSwanEvent::TechRevolution { .. } => {
+            (NewsSentiment::Neutral, &["economy", "technology"])
+        }
+        BlackSwanEvent::Recession { .. } => (NewsSentiment::Negative, &["economy", "crisis"]),
+        BlackSwanEvent::CorporateCollapse { .. } => {
+            (NewsSentiment::Negative, &["economy", "unemployment"])
+        }
+        BlackSwanEvent::Pandemic { .. } => (NewsSentiment::Negative, &["health", "crisis"]),
+        BlackSwanEvent::NaturalDisaster { .. } => {
+            (NewsSentiment::Negative, &["disaster", "crisis"])
+        }
+        BlackSwanEvent::HousingCrisis { .. } => (NewsSentiment::Negative, &["housing", "crisis"]),
+        BlackSwanEvent::CrimeWave { .. } => (NewsSentiment::Negative, &["crime", "danger"]),
+    };
+    NewsDraft {
+        sentiment,
+        headline: population.describe_event(event),
+        tags: tags.iter().map(|t| t.to_string()).collect(),
+        district_id: None,
+        subject_id: None,
+    }
+}
+
+fn district_draft(event: &DistrictPressureEvent) -> NewsDraft {
+    let name = &event.district_name;
+    let (sentiment, headline) = match event.kind {
+        DistrictEventKind::CrimeSpike => {
+            (NewsSentiment::Negative, format!("Crime spikes in {}", name))
+        }
+        DistrictEventKind::CrimeDrop => (
+            NewsSentiment::Positive,
+            format!("Streets of {} grow quieter", name),
+        ),
+        DistrictEventKind::EconomicCrash => (
+            NewsSentiment::Negative,
+            format!("{} economy in freefall", name),
+        ),
+        DistrictEventKind::EconomicBoom => (
+            NewsSentiment::Positive,
+            format!("Business is booming in {}", name),
+        ),
+        DistrictEventKind::UnemploymentCrisis => {
+            (NewsSentiment::Negative, format!("Layoffs sweep {}", name))
+        }
+        DistrictEventKind::GangTakeover => (
+            NewsSentiment::Negative,
+            format!("Gangs tighten their grip on {}", name),
+        ),
+        DistrictEventKind::SocialUnrest => (
+            NewsSentiment::Negative,
+            format!("Protests erupt in {}", name),
+        ),
+        DistrictEventKind::GentrificationDisplacement => (
+            NewsSentiment::Negative,
+            format!("Rising rents push residents out of {}", name),
+        ),
+        DistrictEventKind::EnvironmentalCrisis => (
+            NewsSentiment::Negative,
+            format!("Pollution alert issued for {}", name),
+        ),
+    };
+    let mut tags: Vec<String> = event.kind.tags().iter().map(|t| t.to_string()).collect();
+    tags.push("district".to_string());
+    NewsDraft {
+        sentiment,
+        headline,
+        tags,
+        district_id: Some(event.district_id),
+        subject_id: None,
+    }
+}
+
+fn storylet_draft(report: StoryletReport) -> NewsDraft {
+    NewsDraft {
+        sentiment: NewsSentiment::Neutral,
+        headline: report.headline,
+        tags: report
+            .tags
+            .into_iter()
+            .filter(|t| t != NEWSWORTHY_TAG)
+            .collect(),
+        district_id: None,
+        subject_id: None,
+    }
+}
+
+fn gossip_draft(rumor: &Rumor, sources: &NewsSources<'_>) -> NewsDraft {
+    let subject = if rumor.subject_id == sources.player_id {
+        "you".to_string()
+    } else {
+        sources
+            .npc_prototypes
+            .get(&rumor.subject_id)
+            .map(|p| p.display_name.clone())
+            .unwrap_or_else(|| "a local resident".to_string())
+    };
+    let headline = if rumor.is_scandalous {
+        format!("Scandalous rumors swirl around {}", subject)
+    } else if rumor.valence > 0.2 {
+        format!("People are saying good things about {}", subject)
+    } else {
+        format!("Whispers about {} make the rounds", subject)
+    };
+    let mut tags = rumor.tags.clone();
+    tags.push("gossip".to_string());
+    NewsDraft {
+        sentiment: NewsSentiment::from_valence(rumor.valence),
+        headline,
+        tags,
+        district_id: None,
+        subject_id: Some(rumor.subject_id),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn district_event(
+        tick: u64,
+        district_id: u32,
+        kind: DistrictEventKind,
+    ) -> DistrictPressureEvent {
+        DistrictPressureEvent {
+            district_id,
+            district_name: format!("District {}", district_id),
+            kind,
+            value: 0.0,
+            tick,
+        }
+    }
+
+    #[test]
+    fn test_daily_generation_is_deterministic_and_windowed() {
+        let mut events = VecDeque::new();
+        events.push_back(district_event(30, 2, DistrictEventKind::CrimeSpike));
+        events.push_back(district_event(40, 1, DistrictEventKind::EconomicBoom));
+        // Outside the (24, 48] window.
+        events.push_back(district_event(10, 3, DistrictEventKind::SocialUnrest));
+
+        let population = PopulationSimulation::default();
+        let mut rumors = HashMap::new();
+        let mut scandal = Rumor::new("r1", "evt", NpcId(7), NpcId(8), 45);
+        scandal.is_scandalous = true;
+        scandal.valence = -0.8;
+        rumors.insert(scandal.id.clone(), scandal);
+        rumors.insert(
+            "r2".to_string(),
+            Rumor::new("r2", "evt", NpcId(9), NpcId(8), 46),
+        );
+
+        let prototypes = HashMap::new();
+        let sources = NewsSources {
+            district_events: &events,
+            population: &population,
+            rumors: &rumors,
+            npc_prototypes: &prototypes,
+            player_id: NpcId(1),
+        };
+
+        let mut a = NewsFeed::default();
+        let mut b = NewsFeed::default();
+        assert_eq!(a.generate_daily(48, &sources), 3);
+        b.generate_daily(48, &sources);
+        assert_eq!(a, b);
+
+        let headlines: Vec<&str> = a.items.iter().map(|i| i.headline.as_str()).collect();
+        assert_eq!(
+            headlines,
+            vec![
+                "Crime spikes in District 2",
+                "Business is booming in District 1",
+                "Scandalous rumors swirl around a local resident",
+            ]
+        );
+        assert_eq!(a.items[0].sentiment, NewsSentiment::Negative);
+        assert_eq!(a.items[1].sentiment, NewsSentiment::Positive);
+        assert!(a.items.iter().all(|i| i.day == 2));
+    }
+
+    #[test]
+    fn test_ring_buffer_drops_oldest() {
+        let mut feed = NewsFeed::new(3);
+        let events = VecDeque::new();
+        let population = PopulationSimulation::default();
+        let rumors = HashMap::new();
+        let prototypes = HashMap::new();
+        let sources = NewsSources {
+            district_events: &events,
+            population: &population,
+            rumors: &rumors,
+            npc_prototypes: &prototypes,
+            player_id: NpcId(1),
+        };
+
+        for day in 1..=5u64 {
+            let tick = day * 24;
+            feed.report_storylet(
+                tick,
+                "fair",
+                &format!("Day {} fair", day),
+                &[NEWSWORTHY_TAG.to_string()],
+            );
+            feed.generate_daily(tick, &sources);
+        }
+
+        assert_eq!(feed.len(), 3);
+        let latest: Vec<u64> = feed.latest(2).map(|i| i.id).collect();
+        assert_eq!(latest, vec![4, 3]);
+        assert!(feed.items.iter().all(|i| i.tags.is_empty()));
+    }
+}
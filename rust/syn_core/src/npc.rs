@@ -2,6 +2,7 @@
 
 use crate::time::DayPhase;
 use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
 
 use crate::types::NpcId;
 use crate::{LifeStage, Stats};
@@ -25,6 +26,36 @@ pub enum NpcRoleTag {
     Mentor,
     /// Low-impact background NPC.
     Background,
+    /// Underworld contact (fence, dealer, fixer); drawn in by underworld exposure.
+    Fixer,
+}
+
+impl NpcRoleTag {
+    /// Every registered role tag, in declaration order.
+    pub const ALL: [NpcRoleTag; 9] = [
+        NpcRoleTag::Family,
+        NpcRoleTag::Peer,
+        NpcRoleTag::Coworker,
+        NpcRoleTag::Authority,
+        NpcRoleTag::RomanticInterest,
+        NpcRoleTag::Antagonist,
+        NpcRoleTag::Mentor,
+        NpcRoleTag::Background,
+        NpcRoleTag::Fixer,
+    ];
+
+    /// Parse a role tag from its name ("RomanticInterest", "romantic_interest", ...).
+    pub fn from_name(name: &str) -> Option<Self> {
+        let wanted: String = name
+            .chars()
+            .filter(|c| *c != '_' && *c != '-' && !c.is_whitespace())
+            .collect::<String>()
+            .to_ascii_lowercase();
+        Self::ALL
+            .iter()
+            .copied()
+            .find(|tag| format!("{:?}", tag).to_ascii_lowercase() == wanted)
+    }
 }
 
 /// Personality vector (GDD-aligned axes).
@@ -91,6 +122,49 @@ pub struct NpcPrototype {
     /// Default daily schedule template for this NPC.
     #[serde(default)]
     pub schedule: NpcSchedule,
+
+    /// District this NPC lives in (by name), if authored.
+    #[serde(default)]
+    pub home_district: Option<String>,
+
+    /// Content tag weights (-1..1): positive tags draw this NPC into storylets,
+    /// negative ones keep them out. Derived from personality when empty.
+    #[serde(default)]
+    pub tag_affinities: BTreeMap<String, f32>,
+
+    /// Authored major character: cast first, drifts slowly, checks in on
+    /// the player (see `importance`).
+    #[serde(default)]
+    pub major: bool,
+}
+
+/// Tag affinities implied by a personality, used when none are authored.
+///
+/// Volatile NPCs attract trouble, warm ones friendship and romance, dominant
+/// ones rivalry, diligent ones work, open ones adventure.
+pub fn default_tag_affinities(personality: &PersonalityVector) -> BTreeMap<String, f32> {
+    let p = personality;
+    // conscientiousness/openness are 0..1; center them like the other axes
+    let diligence = p.conscientiousness * 2.0 - 1.0;
+    let openness = p.openness * 2.0 - 1.0;
+    [
+        ("conflict", p.volatility * 0.6 + p.dominance * 0.3 - p.warmth * 0.3),
+        ("trouble", p.volatility * 0.7 - diligence * 0.3),
+        ("drama", p.volatility * 0.5 + openness * 0.2),
+        ("crime", p.volatility * 0.4 - diligence * 0.5),
+        ("friendship", p.warmth * 0.7),
+        ("romance", p.warmth * 0.5 + openness * 0.2),
+        ("support", p.warmth * 0.6 - p.volatility * 0.2),
+        ("rivalry", p.dominance * 0.6 - p.warmth * 0.2),
+        ("career", diligence * 0.5 + p.dominance * 0.3),
+        ("work", diligence * 0.6),
+        ("study", diligence * 0.4 + openness * 0.3),
+        ("adventure", openness * 0.6 + p.volatility * 0.2),
+        ("party", openness * 0.4 + p.warmth * 0.3 - diligence * 0.2),
+    ]
+    .into_iter()
+    .map(|(tag, weight)| (tag.to_string(), weight.clamp(-1.0, 1.0)))
+    .collect()
 }
 
 /// High-level activity type for schedule and presence.
@@ -142,6 +216,27 @@ impl NpcSchedule {
 }
 
 impl NpcPrototype {
+    /// Authored tag affinities, or the ones implied by personality.
+    pub fn effective_tag_affinities(&self) -> BTreeMap<String, f32> {
+        if self.tag_affinities.is_empty() {
+            default_tag_affinities(&self.personality)
+        } else {
+            self.tag_affinities.clone()
+        }
+    }
+
+    /// How strongly this NPC gravitates toward content, in -1..1.
+    ///
+    /// Sums the affinity of every tag for which `has_tag` returns true.
+    pub fn tag_affinity(&self, mut has_tag: impl FnMut(&str) -> bool) -> f32 {
+        self.effective_tag_affinities()
+            .iter()
+            .filter(|(tag, _)| has_tag(tag))
+            .map(|(_, weight)| weight)
+            .sum::<f32>()
+            .clamp(-1.0, 1.0)
+    }
+
     /// Set a default work schedule (work morning/afternoon, home evening/night).
     pub fn with_default_work_schedule(mut self) -> Self {
         self.schedule = NpcSchedule {
@@ -0,0 +1,130 @@
+//! NPC mood ledger.
+//!
+//! The player's mood lives in `WorldState::player_stats`; NPC moods live in the
+//! [`NpcMoods`] ledger so storylet outcomes and simulation passes (such as
+//! emotional contagion in `syn_sim`) can move them. Values share the player's
+//! mood range (-10..=10) and default to neutral (0.0).
+//!
+//! The ledger also keeps a per-character *baseline*: the mood observed at the
+//! end of the previous contagion pass. The difference between the current
+//! mood and the baseline is the change a character can pass on to people
+//! close to them.
+
+use crate::stats::{clamp_for, StatKind};
+use crate::types::{NpcId, WorldState};
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
+
+/// Mood values for NPCs, plus baselines used to measure recent mood changes.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct NpcMoods {
+    /// Current mood per NPC (missing entries are neutral).
+    #[serde(default)]
+    moods: BTreeMap<NpcId, f32>,
+    /// Mood at the end of the last contagion pass, per character.
+    #[serde(default)]
+    baselines: BTreeMap<NpcId, f32>,
+}
+
+impl NpcMoods {
+    /// Create an empty ledger.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Current mood of an NPC (0.0 if never set).
+    pub fn get(&self, npc_id: NpcId) -> f32 {
+        self.moods.get(&npc_id).copied().unwrap_or(0.0)
+    }
+
+    /// Set an NPC's mood, clamped to the mood range.
+    pub fn set(&mut self, npc_id: NpcId, value: f32) {
+        self.moods.insert(npc_id, clamp_for(StatKind::Mood, value));
+    }
+
+    /// Shift an NPC's mood by `delta`, clamped to the mood range.
+    pub fn apply_delta(&mut self, npc_id: NpcId, delta: f32) {
+        let current = self.get(npc_id);
+        self.set(npc_id, current + delta);
+    }
+
+    /// Iterate tracked NPC moods in id order.
+    pub fn iter(&self) -> impl Iterator<Item = (NpcId, f32)> + '_ {
+        self.moods.iter().map(|(id, mood)| (*id, *mood))
+    }
+
+    /// Mood a character had at the end of the last contagion pass.
+    ///
+    /// Characters that have never been observed are neutral, so an NPC's first
+    /// mood change registers in full.
+    pub fn baseline(&self, id: NpcId) -> f32 {
+        self.baselines.get(&id).copied().unwrap_or(0.0)
+    }
+
+    /// Record the mood a character ended a contagion pass with.
+    pub fn set_baseline(&mut self, id: NpcId, mood: f32) {
+        self.baselines.insert(id, mood);
+    }
+
+    /// Apply `f` to every mood and baseline (used by quantized accumulation).
+    pub(crate) fn map_values(&mut self, f: impl Fn(f32) -> f32) {
+        for value in self.moods.values_mut().chain(self.baselines.values_mut()) {
+            *value = f(*value);
+        }
+    }
+
+    /// Number of NPCs with a tracked mood.
+    pub fn len(&self) -> usize {
+        self.moods.len()
+    }
+
+    /// True when no NPC mood has been recorded.
+    pub fn is_empty(&self) -> bool {
+        self.moods.is_empty()
+    }
+}
+
+/// Current mood of any character: the player's stat or the NPC's ledger entry.
+pub fn mood_of(world: &WorldState, id: NpcId) -> f32 {
+    if id == world.player_id {
+        world.player_stats.mood
+    } else {
+        world.npc_moods.get(id)
+    }
+}
+
+/// Shift any character's mood by `delta`, clamped to the mood range.
+pub fn shift_mood(world: &mut WorldState, id: NpcId, delta: f32) {
+    if id == world.player_id {
+        world.player_stats.apply_delta(StatKind::Mood, delta);
+    } else {
+        world.npc_moods.apply_delta(id, delta);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn moods_default_to_neutral_and_clamp() {
+        let mut moods = NpcMoods::new();
+        assert_eq!(moods.get(NpcId(3)), 0.0);
+
+        moods.apply_delta(NpcId(3), -4.0);
+        moods.apply_delta(NpcId(3), -9.0);
+        assert_eq!(moods.get(NpcId(3)), -10.0);
+        assert_eq!(moods.baseline(NpcId(3)), 0.0);
+    }
+
+    #[test]
+    fn serde_roundtrip_keeps_baselines() {
+        let mut moods = NpcMoods::new();
+        moods.set(NpcId(2), -3.5);
+        moods.set_baseline(NpcId(2), -1.0);
+
+        let json = serde_json::to_string(&moods).unwrap();
+        let back: NpcMoods = serde_json::from_str(&json).unwrap();
+        assert_eq!(back, moods);
+    }
+}
@@ -0,0 +1,204 @@
+//! NPCs spawned on demand to fill a storylet's role.
+//!
+//! A storylet that casts its actor by role tag dies quietly when nobody the
+//! player knows carries the tag. [`spawn_npc_for_role`] casts a newcomer
+//! instead: a fresh id, a character rolled by [`generate_character`] from
+//! the world seed and that id (so the same world always spawns the same
+//! person), an [`NpcPrototype`] carrying the role tag and an [`AbstractNpc`]
+//! in the population. The newcomer joins `known_npcs` so later storylets
+//! can cast them again, and [`SpawnedNpcState`] remembers which storylet
+//! brought them in.
+
+use std::collections::BTreeMap;
+
+use serde::{Deserialize, Serialize};
+
+use crate::character_gen::{generate_character, CharacterArchetype, CharacterGenConfig};
+use crate::npc::{NpcPrototype, NpcRoleTag};
+use crate::rng::DeterministicRng;
+use crate::types::{AbstractNpc, NpcId, Traits, WorldState};
+
+/// First names spawned NPCs are drawn from.
+const SPAWN_NAMES: [&str; 16] = [
+    "Alex", "Bea", "Cam", "Dana", "Eli", "Fern", "Gus", "Hana", "Ivo", "Jo", "Kai", "Lena", "Milo",
+    "Nia", "Omar", "Pia",
+];
+
+/// Where a spawned NPC came from.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct SpawnOrigin {
+    /// Storylet whose role the NPC was spawned to fill.
+    pub storylet_id: String,
+    /// Role tag nobody known could fill.
+    pub role_tag: NpcRoleTag,
+    /// Tick the NPC was spawned.
+    pub tick: u64,
+}
+
+/// Spawned NPCs keyed by NPC id, stored on `WorldState`.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct SpawnedNpcState {
+    /// Origin of every spawned NPC.
+    #[serde(default)]
+    pub origins: BTreeMap<u64, SpawnOrigin>,
+}
+
+impl SpawnedNpcState {
+    /// Where `npc_id` came from, if they were spawned.
+    pub fn origin_of(&self, npc_id: NpcId) -> Option<&SpawnOrigin> {
+        self.origins.get(&npc_id.0)
+    }
+}
+
+/// Archetype a newcomer in `role` is rolled with.
+fn archetype_for(role: NpcRoleTag) -> CharacterArchetype {
+    match role {
+        NpcRoleTag::Family | NpcRoleTag::Mentor | NpcRoleTag::Background => {
+            CharacterArchetype::Storyteller
+        }
+        NpcRoleTag::Coworker | NpcRoleTag::Authority => CharacterArchetype::Analyst,
+        NpcRoleTag::Peer | NpcRoleTag::RomanticInterest => CharacterArchetype::Dreamer,
+        NpcRoleTag::Antagonist | NpcRoleTag::Fixer => CharacterArchetype::Challenger,
+    }
+}
+
+/// Age of a newcomer in `role`, relative to the player's.
+fn age_for(role: NpcRoleTag, player_age: u32) -> u32 {
+    match role {
+        NpcRoleTag::Peer | NpcRoleTag::RomanticInterest | NpcRoleTag::Antagonist => player_age,
+        NpcRoleTag::Coworker => player_age + 4,
+        NpcRoleTag::Fixer => (player_age + 8).max(21),
+        NpcRoleTag::Authority | NpcRoleTag::Mentor => (player_age + 20).max(30),
+        NpcRoleTag::Family => player_age + 26,
+        NpcRoleTag::Background => 35,
+    }
+}
+
+/// Job of a newcomer in `role` at `age`.
+fn job_for(role: NpcRoleTag, age: u32, player_job: Option<&str>) -> String {
+    let job = match role {
+        _ if age < 19 => "Student",
+        NpcRoleTag::Coworker => player_job.unwrap_or("Clerk"),
+        NpcRoleTag::Authority => "Manager",
+        NpcRoleTag::Mentor => "Coach",
+        NpcRoleTag::Fixer => "Fixer",
+        _ => "Clerk",
+    };
+    job.to_string()
+}
+
+/// First id no NPC, prototype or the player uses.
+fn next_free_npc_id(world: &WorldState) -> NpcId {
+    let highest = world
+        .npcs
+        .keys()
+        .chain(world.npc_prototypes.keys())
+        .chain(world.known_npcs.iter())
+        .chain(std::iter::once(&world.player_id))
+        .map(|id| id.0)
+        .max()
+        .unwrap_or(0);
+    NpcId(highest + 1)
+}
+
+/// Spawn a new NPC carrying `role` for `storylet_id` and return their id.
+///
+/// The NPC lives in the player's district when the player has one, is
+/// active in the player's life stage and is known to the player at once.
+pub fn spawn_npc_for_role(
+    world: &mut WorldState,
+    role: NpcRoleTag,
+    storylet_id: &str,
+    tick: u64,
+) -> NpcId {
+    let id = next_free_npc_id(world);
+    let seed = world.seed.0 ^ id.0.wrapping_mul(0x9E37_79B9_7F4A_7C15);
+    let mut rng = DeterministicRng::with_domain(world.seed.0, id.0, "npc_spawn");
+    let name_index = usize::try_from(rng.gen_u64() % SPAWN_NAMES.len() as u64).unwrap_or(0);
+    let character = generate_character(
+        seed,
+        &CharacterGenConfig {
+            name: SPAWN_NAMES[name_index].to_string(),
+            archetype: archetype_for(role),
+            ..Default::default()
+        },
+    );
+
+    let player = world.npcs.get(&world.player_id);
+    let district = player
+        .map(|p| p.district.clone())
+        .filter(|d| !d.is_empty())
+        .unwrap_or_else(|| character.starting_district.clone());
+    let age = age_for(role, world.player_age_years);
+    let job = job_for(role, age, player.map(|p| p.job.as_str()));
+
+    let mut prototype = NpcPrototype {
+        id,
+        display_name: character.name.clone(),
+        role_label: None,
+        role_tags: vec![role],
+        personality: character.personality,
+        base_stats: character.stats,
+        active_stages: vec![world.player_life_stage],
+        schedule: Default::default(),
+        home_district: Some(district.clone()),
+        tag_affinities: BTreeMap::new(),
+        major: false,
+    };
+    if matches!(role, NpcRoleTag::Coworker | NpcRoleTag::Authority | NpcRoleTag::Mentor) {
+        prototype = prototype.with_default_work_schedule();
+    }
+
+    world.npcs.insert(
+        id,
+        AbstractNpc {
+            id,
+            age,
+            job,
+            district,
+            household_id: id.0,
+            traits: Traits::default(),
+            seed,
+            attachment_style: character.attachment_style,
+        },
+    );
+    world.npc_prototypes.insert(id, prototype);
+    world.ensure_npc_known(id);
+    world.spawned_npcs.origins.insert(
+        id.0,
+        SpawnOrigin {
+            storylet_id: storylet_id.to_string(),
+            role_tag: role,
+            tick,
+        },
+    );
+    id
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::types::WorldSeed;
+
+    #[test]
+    fn spawning_is_deterministic_and_recorded() {
+        let spawn = || {
+            let mut world = WorldState::new(WorldSeed(9), NpcId(1));
+            world.ensure_npc_known(NpcId(4));
+            let id = spawn_npc_for_role(&mut world, NpcRoleTag::Mentor, "first_lesson", 12);
+            (world, id)
+        };
+        let (world, id) = spawn();
+        let (again, again_id) = spawn();
+
+        assert_eq!(id, NpcId(5));
+        assert_eq!(again_id, id);
+        assert_eq!(world.npc_prototypes[&id], again.npc_prototypes[&id]);
+        assert_eq!(world.npc_prototypes[&id].role_tags, vec![NpcRoleTag::Mentor]);
+        assert!(world.npcs[&id].age >= 30);
+        assert!(world.known_npcs.contains(&id));
+        let origin = world.spawned_npcs.origin_of(id).expect("origin recorded");
+        assert_eq!(origin.storylet_id, "first_lesson");
+        assert_eq!(origin.tick, 12);
+    }
+}
@@ -0,0 +1,332 @@
+//! Third-party opinions: NPCs judging how others treat each other.
+//!
+//! Storylet outcomes only change relationships among the cast. This pass lets
+//! bystanders react too. A relationship delta changes how `actor` feels about
+//! `target`, so it says something about how `target` treated `actor`: a trust
+//! gain means `target` came through for them, a resentment gain means
+//! `target` wronged them.
+//!
+//! Observers are:
+//! - witnesses: other NPCs named in the same outcome, and
+//! - NPCs who hear about it: members of a social cluster with `actor` or
+//!   `target` (weighted by `hearsay_weight`).
+//!
+//! Each observer's stance toward `actor` (affection minus resentment, scaled
+//! to -1..1) decides the direction. Someone who likes `actor` respects a
+//! `target` who stood by them and resents one who hurt them; someone who
+//! resents `actor` judges the other way round. Changes are summed per
+//! observer and subject, capped at `max_shift` per outcome, and go through
+//! the pressure tracker with source `opinion:<source>`.
+//!
+//! The player never observes: their feelings are theirs to choose.
+
+use std::collections::{BTreeMap, BTreeSet};
+
+use serde::{Deserialize, Serialize};
+
+use crate::relationship_model::{RelationshipAxis as ModelAxis, RelationshipDelta};
+use crate::relationship_pressure::shift_relationship_axis;
+use crate::relationships::RelationshipAxis;
+use crate::types::{NpcId, WorldState};
+
+/// Bounds and weights for third-party opinions.
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct OpinionConfig {
+    /// Whether observers react at all.
+    pub enabled: bool,
+    /// Share of an observed change that carries over to the observer's view.
+    pub influence: f32,
+    /// Weight of a witnessed change.
+    pub witness_weight: f32,
+    /// Weight of a change heard about through a social cluster.
+    pub hearsay_weight: f32,
+    /// Weakest stance toward the affected NPC that still colors a judgment.
+    pub min_stance: f32,
+    /// Largest shift one observer's view of one NPC takes per outcome.
+    pub max_shift: f32,
+    /// Most observers reacting to one outcome (witnesses first, then by id).
+    pub max_observers: usize,
+    /// Logged opinion shifts kept for the UI.
+    pub max_recent: usize,
+}
+
+impl Default for OpinionConfig {
+    fn default() -> Self {
+        Self {
+            enabled: true,
+            influence: 0.5,
+            witness_weight: 1.0,
+            hearsay_weight: 0.4,
+            min_stance: 0.2,
+            max_shift: 1.5,
+            max_observers: 8,
+            max_recent: 30,
+        }
+    }
+}
+
+/// One observer changing their view of someone over how they treated another.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct OpinionShift {
+    /// Tick it happened.
+    pub tick: u64,
+    /// NPC whose view changed.
+    pub observer_id: NpcId,
+    /// NPC they now see differently.
+    pub subject_id: NpcId,
+    /// NPC whose treatment they judged.
+    pub about_id: NpcId,
+    /// Signed shift: positive is respect, negative is resentment.
+    pub shift: f32,
+    /// Whether they saw it happen (otherwise they heard about it).
+    pub witnessed: bool,
+    /// What they observed (e.g. `storylet:<id>`).
+    pub source: String,
+}
+
+/// Third-party opinion settings and log, stored on `WorldState`.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct OpinionState {
+    /// Bounds and weights.
+    #[serde(default)]
+    pub config: OpinionConfig,
+    /// Most recent shifts, oldest first.
+    #[serde(default)]
+    pub recent: Vec<OpinionShift>,
+}
+
+/// How a change to `actor`'s view of `target` reflects on `target`:
+/// +1 when it reads as good treatment, -1 as bad, `None` when it says nothing.
+fn treatment_sign(delta: &RelationshipDelta) -> Option<f32> {
+    let sign = match delta.axis {
+        ModelAxis::Affection | ModelAxis::Trust => 1.0,
+        ModelAxis::Resentment => -1.0,
+        ModelAxis::Attraction | ModelAxis::Familiarity => return None,
+    };
+    if delta.delta > 0.0 {
+        Some(sign)
+    } else if delta.delta < 0.0 {
+        Some(-sign)
+    } else {
+        None
+    }
+}
+
+/// Observers of an outcome touching `involved`, with their weights, in
+/// reaction order: witnesses by id, then hearsay by id.
+fn observers(world: &WorldState, involved: &BTreeSet<u64>) -> Vec<(NpcId, bool)> {
+    let config = &world.opinions.config;
+    let player = world.player_id.0;
+    let mut heard: BTreeSet<u64> = BTreeSet::new();
+    for cluster in world.gossip.clusters.values() {
+        if involved.iter().any(|id| cluster.contains(NpcId(*id))) {
+            heard.extend(cluster.members.iter().map(|id| id.0));
+        }
+    }
+    involved
+        .iter()
+        .map(|id| (*id, true))
+        .chain(heard.difference(involved).map(|id| (*id, false)))
+        .filter(|(id, _)| *id != player)
+        .take(config.max_observers)
+        .map(|(id, witnessed)| (NpcId(id), witnessed))
+        .collect()
+}
+
+fn involved_npcs(deltas: &[RelationshipDelta]) -> BTreeSet<u64> {
+    deltas
+        .iter()
+        .flat_map(|d| [d.actor_id, d.target_id])
+        .collect()
+}
+
+/// Observer→subject pairs [`observe_relationship_deltas`] could change for
+/// `deltas`, so callers can snapshot them (e.g. for undo).
+pub fn opinion_pairs(world: &WorldState, deltas: &[RelationshipDelta]) -> Vec<(NpcId, NpcId)> {
+    if !world.opinions.config.enabled {
+        return Vec::new();
+    }
+    let mut pairs: BTreeSet<(NpcId, NpcId)> = BTreeSet::new();
+    for (observer, _) in observers(world, &involved_npcs(deltas)) {
+        for delta in deltas {
+            if observer.0 != delta.actor_id && observer.0 != delta.target_id {
+                pairs.insert((observer, NpcId(delta.target_id)));
+            }
+        }
+    }
+    pairs.into_iter().collect()
+}
+
+/// Let bystanders judge the relationship changes of one outcome.
+///
+/// `deltas` are the outcome's relationship deltas as authored; `source`
+/// names the outcome (e.g. `storylet:<id>`). Returns the shifts applied.
+pub fn observe_relationship_deltas(
+    world: &mut WorldState,
+    deltas: &[RelationshipDelta],
+    source: &str,
+) -> Vec<OpinionShift> {
+    let config = world.opinions.config;
+    if !config.enabled || deltas.is_empty() {
+        return Vec::new();
+    }
+    let involved = involved_npcs(deltas);
+
+    // (observer, subject) -> (summed shift, NPC whose treatment was judged, witnessed)
+    let mut judged: BTreeMap<(u64, u64), (f32, u64, bool)> = BTreeMap::new();
+    for (observer, witnessed) in observers(world, &involved) {
+        let weight = if witnessed {
+            config.witness_weight
+        } else {
+            config.hearsay_weight
+        };
+        for delta in deltas {
+            if delta.actor_id == delta.target_id
+                || observer.0 == delta.actor_id
+                || observer.0 == delta.target_id
+            {
+                continue;
+            }
+            let Some(sign) = treatment_sign(delta) else {
+                continue;
+            };
+            let view = world.get_relationship(observer, NpcId(delta.actor_id));
+            let stance = ((view.affection - view.resentment) / 10.0).clamp(-1.0, 1.0);
+            if stance.abs() < config.min_stance {
+                continue;
+            }
+            let shift = sign * delta.delta.abs() * stance * config.influence * weight;
+            let entry = judged
+                .entry((observer.0, delta.target_id))
+                .or_insert((0.0, delta.actor_id, witnessed));
+            entry.0 += shift;
+        }
+    }
+
+    let tick = world.current_tick.0;
+    let opinion_source = format!("opinion:{}", source);
+    let mut shifts = Vec::new();
+    for ((observer, subject), (total, about, witnessed)) in judged {
+        let shift = total.clamp(-config.max_shift, config.max_shift);
+        if shift.abs() < f32::EPSILON {
+            continue;
+        }
+        let (observer, subject) = (NpcId(observer), NpcId(subject));
+        if shift > 0.0 {
+            for (axis, amount) in [
+                (RelationshipAxis::Trust, shift),
+                (RelationshipAxis::Affection, shift * 0.5),
+            ] {
+                shift_relationship_axis(world, observer, subject, axis, amount, &opinion_source);
+            }
+        } else {
+            for (axis, amount) in [
+                (RelationshipAxis::Resentment, -shift),
+                (RelationshipAxis::Trust, shift * 0.5),
+            ] {
+                shift_relationship_axis(world, observer, subject, axis, amount, &opinion_source);
+            }
+        }
+        shifts.push(OpinionShift {
+            tick,
+            observer_id: observer,
+            subject_id: subject,
+            about_id: NpcId(about),
+            shift,
+            witnessed,
+            source: source.to_string(),
+        });
+    }
+
+    let state = &mut world.opinions;
+    state.recent.extend(shifts.iter().cloned());
+    if state.recent.len() > config.max_recent {
+        let excess = state.recent.len() - config.max_recent;
+        state.recent.drain(..excess);
+    }
+    shifts
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::gossip::SocialCluster;
+    use crate::types::{Relationship, WorldSeed};
+
+    fn delta(actor: u64, target: u64, axis: ModelAxis, amount: f32) -> RelationshipDelta {
+        RelationshipDelta {
+            actor_id: actor,
+            target_id: target,
+            axis,
+            delta: amount,
+            source: None,
+        }
+    }
+
+    fn feels(world: &mut WorldState, from: u64, to: u64, affection: f32, resentment: f32) {
+        world.set_relationship(
+            NpcId(from),
+            NpcId(to),
+            Relationship {
+                affection,
+                resentment,
+                ..Default::default()
+            },
+        );
+    }
+
+    #[test]
+    fn friends_respect_whoever_stands_by_their_friend() {
+        let mut world = WorldState::new(WorldSeed(9), NpcId(1));
+        // NPC 3 likes NPC 2, NPC 4 can't stand NPC 2; both hear via the cluster
+        feels(&mut world, 3, 2, 8.0, 0.0);
+        feels(&mut world, 4, 2, 0.0, 8.0);
+        let mut circle = SocialCluster::new("block");
+        for id in [2, 3, 4] {
+            circle.add_member(NpcId(id));
+        }
+        world.gossip.clusters.insert("block".to_string(), circle);
+
+        // The player comes through for NPC 2
+        let shifts = observe_relationship_deltas(
+            &mut world,
+            &[delta(2, 1, ModelAxis::Trust, 3.0)],
+            "storylet:defend_friend",
+        );
+
+        assert_eq!(shifts.len(), 2);
+        let friend = world.get_relationship(NpcId(3), NpcId(1));
+        let enemy = world.get_relationship(NpcId(4), NpcId(1));
+        assert!(friend.trust > 0.0 && friend.affection > 0.0);
+        assert!(enemy.resentment > 0.0 && enemy.trust < 0.0);
+        assert!(shifts.iter().all(|s| !s.witnessed && s.subject_id == NpcId(1)));
+        // The player never forms opinions, and strangers don't care
+        assert!(world.get_relationship(NpcId(1), NpcId(2)).trust.abs() < 1e-6);
+        // NPC 4 crossed into irritation with the player
+        assert!(world.relationship_pressure.queue.iter().any(|e| {
+            e.actor_id == 4 && e.source.as_deref() == Some("opinion:storylet:defend_friend")
+        }));
+    }
+
+    #[test]
+    fn shifts_are_capped_per_outcome() {
+        let mut world = WorldState::new(WorldSeed(9), NpcId(1));
+        feels(&mut world, 3, 2, 10.0, 0.0);
+        let deltas = [
+            delta(2, 5, ModelAxis::Resentment, 8.0),
+            delta(2, 5, ModelAxis::Affection, -8.0),
+            // Witnessed by NPC 3, who is named in the outcome
+            delta(3, 5, ModelAxis::Familiarity, 1.0),
+        ];
+        let shifts = observe_relationship_deltas(&mut world, &deltas, "storylet:betrayal");
+
+        assert_eq!(shifts.len(), 1);
+        assert!(shifts[0].witnessed);
+        assert!((shifts[0].shift + OpinionConfig::default().max_shift).abs() < 1e-6);
+        assert_eq!(shifts[0].about_id, NpcId(2));
+
+        world.opinions.config.enabled = false;
+        assert!(observe_relationship_deltas(&mut world, &deltas, "storylet:betrayal").is_empty());
+    }
+}
@@ -14,6 +14,12 @@ use serde::{Deserialize, Serialize};
 struct RelationshipMilestoneSerializable {
     last_role: HashMap<String, crate::relationship_model::RelationshipRole>,
     queue: VecDeque<crate::relationship_milestones::RelationshipMilestoneEvent>,
+    #[serde(default)]
+    pending: Vec<crate::relationship_milestones::PendingMilestone>,
+    #[serde(default)]
+    history: VecDeque<crate::relationship_milestones::MilestoneRecord>,
+    #[serde(default)]
+    tuning: crate::relationship_milestones::MilestoneTuning,
 }
 
 fn map_invalid_query(err: rusqlite::Error, context: &str) -> rusqlite::Error {
@@ -53,6 +59,38 @@ struct WorldRow {
     memory_entries: String,
     district_state: String,
     world_flags: String,
+    content_preferences: String,
+    households: String,
+    npc_moods: String,
+    underworld: String,
+    external_events: String,
+    moral_ledger: String,
+    ambient: String,
+    dreams: String,
+    proposals: String,
+    chapters: String,
+    relationship_heat: String,
+    rival_actions: String,
+    life_goals: String,
+    encounters: String,
+    importance: String,
+    year_review: String,
+    flag_changes: String,
+    reaction_latency: String,
+    declared_relationships: String,
+    dependents: String,
+    difficulty: String,
+    post_life: String,
+    delta_caps: String,
+    player_notes: String,
+    session_pacing: String,
+    float_mode: String,
+    jealousy: String,
+    opinions: String,
+    stat_crisis: String,
+    spawned_npcs: String,
+    player_skills: String,
+    districts: String,
 }
 
 /// Persistence layer for SYN world state.
@@ -95,6 +133,38 @@ impl Persistence {
     /// - digital_legacy: TEXT (JSON)
     /// - district_state: TEXT (JSON)
     /// - world_flags: TEXT (JSON)
+    /// - content_preferences: TEXT (JSON)
+    /// - households: TEXT (JSON)
+    /// - npc_moods: TEXT (JSON)
+    /// - underworld: TEXT (JSON)
+    /// - external_events: TEXT (JSON)
+    /// - moral_ledger: TEXT (JSON)
+    /// - ambient: TEXT (JSON)
+    /// - dreams: TEXT (JSON)
+    /// - proposals: TEXT (JSON)
+    /// - chapters: TEXT (JSON)
+    /// - relationship_heat: TEXT (JSON)
+    /// - rival_actions: TEXT (JSON)
+    /// - life_goals: TEXT (JSON)
+    /// - encounters: TEXT (JSON)
+    /// - importance: TEXT (JSON)
+    /// - year_review: TEXT (JSON)
+    /// - flag_changes: TEXT (JSON)
+    /// - reaction_latency: TEXT (JSON)
+    /// - declared_relationships: TEXT (JSON)
+    /// - dependents: TEXT (JSON)
+    /// - difficulty: TEXT (JSON)
+    /// - post_life: TEXT (JSON)
+    /// - delta_caps: TEXT (JSON)
+    /// - player_notes: TEXT (JSON)
+    /// - session_pacing: TEXT (JSON)
+    /// - float_mode: TEXT (JSON)
+    /// - jealousy: TEXT (JSON)
+    /// - opinions: TEXT (JSON)
+    /// - stat_crisis: TEXT (JSON)
+    /// - spawned_npcs: TEXT (JSON)
+    /// - player_skills: TEXT (JSON)
+    /// - districts: TEXT (JSON)
     fn init_schema(&mut self) -> SqlResult<()> {
         self.conn.execute_batch(
             "
@@ -123,6 +193,38 @@ impl Persistence {
                 memory_entries TEXT NOT NULL DEFAULT '[]',
                 district_state TEXT NOT NULL DEFAULT '{}',
                 world_flags TEXT NOT NULL DEFAULT '{}',
+                content_preferences TEXT NOT NULL DEFAULT '{}',
+                households TEXT NOT NULL DEFAULT '{}',
+                npc_moods TEXT NOT NULL DEFAULT '{}',
+                underworld TEXT NOT NULL DEFAULT '{}',
+                external_events TEXT NOT NULL DEFAULT '{}',
+                moral_ledger TEXT NOT NULL DEFAULT '{}',
+                ambient TEXT NOT NULL DEFAULT '{}',
+                dreams TEXT NOT NULL DEFAULT '{}',
+                proposals TEXT NOT NULL DEFAULT '{}',
+                chapters TEXT NOT NULL DEFAULT '{}',
+                relationship_heat TEXT NOT NULL DEFAULT '{}',
+                rival_actions TEXT NOT NULL DEFAULT '{}',
+                life_goals TEXT NOT NULL DEFAULT '{}',
+                encounters TEXT NOT NULL DEFAULT '{}',
+                importance TEXT NOT NULL DEFAULT '{}',
+                year_review TEXT NOT NULL DEFAULT '{}',
+                flag_changes TEXT NOT NULL DEFAULT '{}',
+                reaction_latency TEXT NOT NULL DEFAULT '{}',
+                declared_relationships TEXT NOT NULL DEFAULT '{}',
+                dependents TEXT NOT NULL DEFAULT '{}',
+                difficulty TEXT NOT NULL DEFAULT '{}',
+                post_life TEXT NOT NULL DEFAULT '{}',
+                delta_caps TEXT NOT NULL DEFAULT '{}',
+                player_notes TEXT NOT NULL DEFAULT '{}',
+                session_pacing TEXT NOT NULL DEFAULT '{}',
+                float_mode TEXT NOT NULL DEFAULT '\"Native\"',
+                jealousy TEXT NOT NULL DEFAULT '{}',
+                opinions TEXT NOT NULL DEFAULT '{}',
+                stat_crisis TEXT NOT NULL DEFAULT '{}',
+                spawned_npcs TEXT NOT NULL DEFAULT '{}',
+                player_skills TEXT NOT NULL DEFAULT '{}',
+                districts TEXT NOT NULL DEFAULT '{}',
                 created_at DATETIME DEFAULT CURRENT_TIMESTAMP,
                 updated_at DATETIME DEFAULT CURRENT_TIMESTAMP
             );
@@ -164,9 +266,35 @@ impl Persistence {
                 updated_at DATETIME DEFAULT CURRENT_TIMESTAMP
             );
 
+            CREATE TABLE IF NOT EXISTS npc_instances (
+                id INTEGER PRIMARY KEY,
+                world_seed INTEGER NOT NULL,
+                npc_id INTEGER NOT NULL,
+                instance_data TEXT NOT NULL,
+                FOREIGN KEY(world_seed) REFERENCES world_state(seed),
+                UNIQUE(world_seed, npc_id)
+            );
+
+            CREATE TABLE IF NOT EXISTS npc_prototype_defs (
+                id INTEGER PRIMARY KEY,
+                display_name TEXT NOT NULL,
+                json_data TEXT NOT NULL,
+                created_at DATETIME DEFAULT CURRENT_TIMESTAMP,
+                updated_at DATETIME DEFAULT CURRENT_TIMESTAMP
+            );
+
+            CREATE TABLE IF NOT EXISTS runtime_state (
+                world_seed INTEGER NOT NULL,
+                key TEXT NOT NULL,
+                json_data TEXT NOT NULL,
+                FOREIGN KEY(world_seed) REFERENCES world_state(seed),
+                PRIMARY KEY(world_seed, key)
+            );
+
             CREATE INDEX IF NOT EXISTS idx_relationships ON relationships(world_seed, from_npc_id);
             CREATE INDEX IF NOT EXISTS idx_npcs ON npcs(world_seed, npc_id);
             CREATE INDEX IF NOT EXISTS idx_memories ON memory_entries(world_seed, npc_id);
+            CREATE INDEX IF NOT EXISTS idx_npc_instances ON npc_instances(world_seed, npc_id);
             ",
         )?;
         // Backfill columns if schema existed before; SQLite errors are ignored if columns already exist.
@@ -242,6 +370,134 @@ impl Persistence {
             "ALTER TABLE world_state ADD COLUMN world_flags TEXT NOT NULL DEFAULT '{}'",
             params![],
         );
+        let _ = self.conn.execute(
+            "ALTER TABLE world_state ADD COLUMN content_preferences TEXT NOT NULL DEFAULT '{}'",
+            params![],
+        );
+        let _ = self.conn.execute(
+            "ALTER TABLE world_state ADD COLUMN households TEXT NOT NULL DEFAULT '{}'",
+            params![],
+        );
+        let _ = self.conn.execute(
+            "ALTER TABLE world_state ADD COLUMN npc_moods TEXT NOT NULL DEFAULT '{}'",
+            params![],
+        );
+        let _ = self.conn.execute(
+            "ALTER TABLE world_state ADD COLUMN underworld TEXT NOT NULL DEFAULT '{}'",
+            params![],
+        );
+        let _ = self.conn.execute(
+            "ALTER TABLE world_state ADD COLUMN external_events TEXT NOT NULL DEFAULT '{}'",
+            params![],
+        );
+        let _ = self.conn.execute(
+            "ALTER TABLE world_state ADD COLUMN moral_ledger TEXT NOT NULL DEFAULT '{}'",
+            params![],
+        );
+        let _ = self.conn.execute(
+            "ALTER TABLE world_state ADD COLUMN ambient TEXT NOT NULL DEFAULT '{}'",
+            params![],
+        );
+        let _ = self.conn.execute(
+            "ALTER TABLE world_state ADD COLUMN dreams TEXT NOT NULL DEFAULT '{}'",
+            params![],
+        );
+        let _ = self.conn.execute(
+            "ALTER TABLE world_state ADD COLUMN proposals TEXT NOT NULL DEFAULT '{}'",
+            params![],
+        );
+        let _ = self.conn.execute(
+            "ALTER TABLE world_state ADD COLUMN chapters TEXT NOT NULL DEFAULT '{}'",
+            params![],
+        );
+        let _ = self.conn.execute(
+            "ALTER TABLE world_state ADD COLUMN relationship_heat TEXT NOT NULL DEFAULT '{}'",
+            params![],
+        );
+        let _ = self.conn.execute(
+            "ALTER TABLE world_state ADD COLUMN rival_actions TEXT NOT NULL DEFAULT '{}'",
+            params![],
+        );
+        let _ = self.conn.execute(
+            "ALTER TABLE world_state ADD COLUMN life_goals TEXT NOT NULL DEFAULT '{}'",
+            params![],
+        );
+        let _ = self.conn.execute(
+            "ALTER TABLE world_state ADD COLUMN encounters TEXT NOT NULL DEFAULT '{}'",
+            params![],
+        );
+        let _ = self.conn.execute(
+            "ALTER TABLE world_state ADD COLUMN importance TEXT NOT NULL DEFAULT '{}'",
+            params![],
+        );
+        let _ = self.conn.execute(
+            "ALTER TABLE world_state ADD COLUMN year_review TEXT NOT NULL DEFAULT '{}'",
+            params![],
+        );
+        let _ = self.conn.execute(
+            "ALTER TABLE world_state ADD COLUMN flag_changes TEXT NOT NULL DEFAULT '{}'",
+            params![],
+        );
+        let _ = self.conn.execute(
+            "ALTER TABLE world_state ADD COLUMN reaction_latency TEXT NOT NULL DEFAULT '{}'",
+            params![],
+        );
+        let _ = self.conn.execute(
+            "ALTER TABLE world_state ADD COLUMN declared_relationships TEXT NOT NULL DEFAULT '{}'",
+            params![],
+        );
+        let _ = self.conn.execute(
+            "ALTER TABLE world_state ADD COLUMN dependents TEXT NOT NULL DEFAULT '{}'",
+            params![],
+        );
+        let _ = self.conn.execute(
+            "ALTER TABLE world_state ADD COLUMN difficulty TEXT NOT NULL DEFAULT '{}'",
+            params![],
+        );
+        let _ = self.conn.execute(
+            "ALTER TABLE world_state ADD COLUMN post_life TEXT NOT NULL DEFAULT '{}'",
+            params![],
+        );
+        let _ = self.conn.execute(
+            "ALTER TABLE world_state ADD COLUMN delta_caps TEXT NOT NULL DEFAULT '{}'",
+            params![],
+        );
+        let _ = self.conn.execute(
+            "ALTER TABLE world_state ADD COLUMN player_notes TEXT NOT NULL DEFAULT '{}'",
+            params![],
+        );
+        let _ = self.conn.execute(
+            "ALTER TABLE world_state ADD COLUMN session_pacing TEXT NOT NULL DEFAULT '{}'",
+            params![],
+        );
+        let _ = self.conn.execute(
+            "ALTER TABLE world_state ADD COLUMN float_mode TEXT NOT NULL DEFAULT '\"Native\"'",
+            params![],
+        );
+        let _ = self.conn.execute(
+            "ALTER TABLE world_state ADD COLUMN jealousy TEXT NOT NULL DEFAULT '{}'",
+            params![],
+        );
+        let _ = self.conn.execute(
+            "ALTER TABLE world_state ADD COLUMN opinions TEXT NOT NULL DEFAULT '{}'",
+            params![],
+        );
+        let _ = self.conn.execute(
+            "ALTER TABLE world_state ADD COLUMN stat_crisis TEXT NOT NULL DEFAULT '{}'",
+            params![],
+        );
+        let _ = self.conn.execute(
+            "ALTER TABLE world_state ADD COLUMN spawned_npcs TEXT NOT NULL DEFAULT '{}'",
+            params![],
+        );
+        let _ = self.conn.execute(
+            "ALTER TABLE world_state ADD COLUMN player_skills TEXT NOT NULL DEFAULT '{}'",
+            params![],
+        );
+        let _ = self.conn.execute(
+            "ALTER TABLE world_state ADD COLUMN districts TEXT NOT NULL DEFAULT '{}'",
+            params![],
+        );
         Ok(())
     }
 
@@ -250,7 +506,7 @@ impl Persistence {
         let row = self.world_to_row(world)?;
 
         self.conn.execute(
-            "INSERT OR REPLACE INTO world_state (seed, player_id, current_tick, player_stats, player_age, player_age_years, player_days_since_birth, player_life_stage, player_karma, narrative_heat, heat_momentum, relationships, npcs, npc_prototypes, known_npcs, game_time_tick, relationship_pressure, relationship_milestones, digital_legacy, storylet_usage, memory_entries, district_state, world_flags) VALUES (?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?)",
+            "INSERT OR REPLACE INTO world_state (seed, player_id, current_tick, player_stats, player_age, player_age_years, player_days_since_birth, player_life_stage, player_karma, narrative_heat, heat_momentum, relationships, npcs, npc_prototypes, known_npcs, game_time_tick, relationship_pressure, relationship_milestones, digital_legacy, storylet_usage, memory_entries, district_state, world_flags, content_preferences, households, npc_moods, underworld, external_events, moral_ledger, ambient, dreams, proposals, chapters, relationship_heat, rival_actions, life_goals, encounters, importance, year_review, flag_changes, reaction_latency, declared_relationships, dependents, difficulty, post_life, delta_caps, player_notes, session_pacing, float_mode, jealousy, opinions, stat_crisis, spawned_npcs, player_skills, districts) VALUES (?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?)",
             params![
                 row.seed,
                 row.player_id,
@@ -275,6 +531,38 @@ impl Persistence {
                 row.memory_entries,
                 row.district_state,
                 row.world_flags,
+                row.content_preferences,
+                row.households,
+                row.npc_moods,
+                row.underworld,
+                row.external_events,
+                row.moral_ledger,
+                row.ambient,
+                row.dreams,
+                row.proposals,
+                row.chapters,
+                row.relationship_heat,
+                row.rival_actions,
+                row.life_goals,
+                row.encounters,
+                row.importance,
+                row.year_review,
+                row.flag_changes,
+                row.reaction_latency,
+                row.declared_relationships,
+                row.dependents,
+                row.difficulty,
+                row.post_life,
+                row.delta_caps,
+                row.player_notes,
+                row.session_pacing,
+                row.float_mode,
+                row.jealousy,
+                row.opinions,
+                row.stat_crisis,
+                row.spawned_npcs,
+                row.player_skills,
+                row.districts,
             ],
         )
         .map_err(|e| map_invalid_query(e, "save_world INSERT"))?;
@@ -304,7 +592,7 @@ impl Persistence {
     /// Load world state from database.
     pub fn load_world(&mut self, seed: WorldSeed) -> SqlResult<WorldState> {
         let mut stmt = self.conn.prepare(
-            "SELECT seed, player_id, current_tick, player_stats, player_age, player_age_years, player_days_since_birth, player_life_stage, player_karma, narrative_heat, heat_momentum, relationships, npcs, npc_prototypes, known_npcs, game_time_tick, relationship_pressure, relationship_milestones, digital_legacy, storylet_usage, memory_entries, district_state, world_flags
+            "SELECT seed, player_id, current_tick, player_stats, player_age, player_age_years, player_days_since_birth, player_life_stage, player_karma, narrative_heat, heat_momentum, relationships, npcs, npc_prototypes, known_npcs, game_time_tick, relationship_pressure, relationship_milestones, digital_legacy, storylet_usage, memory_entries, district_state, world_flags, content_preferences, households, npc_moods, underworld, external_events, moral_ledger, ambient, dreams, proposals, chapters, relationship_heat, rival_actions, life_goals, encounters, importance, year_review, flag_changes, reaction_latency, declared_relationships, dependents, difficulty, post_life, delta_caps, player_notes, session_pacing, float_mode, jealousy, opinions, stat_crisis, spawned_npcs, player_skills, districts
              FROM world_state WHERE seed = ?",
         )?;
 
@@ -333,6 +621,38 @@ impl Persistence {
                 memory_entries: row.get::<_, String>(20)?,
                 district_state: row.get::<_, String>(21)?,
                 world_flags: row.get::<_, String>(22)?,
+                content_preferences: row.get::<_, String>(23)?,
+                households: row.get::<_, String>(24)?,
+                npc_moods: row.get::<_, String>(25)?,
+                underworld: row.get::<_, String>(26)?,
+                external_events: row.get::<_, String>(27)?,
+                moral_ledger: row.get::<_, String>(28)?,
+                ambient: row.get::<_, String>(29)?,
+                dreams: row.get::<_, String>(30)?,
+                proposals: row.get::<_, String>(31)?,
+                chapters: row.get::<_, String>(32)?,
+                relationship_heat: row.get::<_, String>(33)?,
+                rival_actions: row.get::<_, String>(34)?,
+                life_goals: row.get::<_, String>(35)?,
+                encounters: row.get::<_, String>(36)?,
+                importance: row.get::<_, String>(37)?,
+                year_review: row.get::<_, String>(38)?,
+                flag_changes: row.get::<_, String>(39)?,
+                reaction_latency: row.get::<_, String>(40)?,
+                declared_relationships: row.get::<_, String>(41)?,
+                dependents: row.get::<_, String>(42)?,
+                difficulty: row.get::<_, String>(43)?,
+                post_life: row.get::<_, String>(44)?,
+                delta_caps: row.get::<_, String>(45)?,
+                player_notes: row.get::<_, String>(46)?,
+                session_pacing: row.get::<_, String>(47)?,
+                float_mode: row.get::<_, String>(48)?,
+                jealousy: row.get::<_, String>(49)?,
+                opinions: row.get::<_, String>(50)?,
+                stat_crisis: row.get::<_, String>(51)?,
+                spawned_npcs: row.get::<_, String>(52)?,
+                player_skills: row.get::<_, String>(53)?,
+                districts: row.get::<_, String>(54)?,
             })
         })?;
 
@@ -367,6 +687,9 @@ impl Persistence {
                 .map(|((a, b), role)| (format!("{}-{}", a, b), *role))
                 .collect(),
             queue: world.relationship_milestones.queue.clone(),
+            pending: world.relationship_milestones.pending.clone(),
+            history: world.relationship_milestones.history.clone(),
+            tuning: world.relationship_milestones.tuning,
         };
 
         Ok(WorldRow {
@@ -405,6 +728,70 @@ impl Persistence {
                 .map_err(|_| rusqlite::Error::InvalidQuery)?,
             world_flags: serde_json::to_string(&world.world_flags)
                 .map_err(|_| rusqlite::Error::InvalidQuery)?,
+            content_preferences: serde_json::to_string(&world.content_preferences)
+                .map_err(|_| rusqlite::Error::InvalidQuery)?,
+            households: serde_json::to_string(&world.households)
+                .map_err(|_| rusqlite::Error::InvalidQuery)?,
+            npc_moods: serde_json::to_string(&world.npc_moods)
+                .map_err(|_| rusqlite::Error::InvalidQuery)?,
+            underworld: serde_json::to_string(&world.underworld)
+                .map_err(|_| rusqlite::Error::InvalidQuery)?,
+            external_events: serde_json::to_string(&world.external_events)
+                .map_err(|_| rusqlite::Error::InvalidQuery)?,
+            moral_ledger: serde_json::to_string(&world.moral_ledger)
+                .map_err(|_| rusqlite::Error::InvalidQuery)?,
+            ambient: serde_json::to_string(&world.ambient)
+                .map_err(|_| rusqlite::Error::InvalidQuery)?,
+            dreams: serde_json::to_string(&world.dreams)
+                .map_err(|_| rusqlite::Error::InvalidQuery)?,
+            proposals: serde_json::to_string(&world.proposals)
+                .map_err(|_| rusqlite::Error::InvalidQuery)?,
+            chapters: serde_json::to_string(&world.chapters)
+                .map_err(|_| rusqlite::Error::InvalidQuery)?,
+            relationship_heat: serde_json::to_string(&world.relationship_heat)
+                .map_err(|_| rusqlite::Error::InvalidQuery)?,
+            rival_actions: serde_json::to_string(&world.rival_actions)
+                .map_err(|_| rusqlite::Error::InvalidQuery)?,
+            life_goals: serde_json::to_string(&world.life_goals)
+                .map_err(|_| rusqlite::Error::InvalidQuery)?,
+            encounters: serde_json::to_string(&world.encounters)
+                .map_err(|_| rusqlite::Error::InvalidQuery)?,
+            importance: serde_json::to_string(&world.importance)
+                .map_err(|_| rusqlite::Error::InvalidQuery)?,
+            year_review: serde_json::to_string(&world.year_review)
+                .map_err(|_| rusqlite::Error::InvalidQuery)?,
+            flag_changes: serde_json::to_string(&world.flag_changes)
+                .map_err(|_| rusqlite::Error::InvalidQuery)?,
+            reaction_latency: serde_json::to_string(&world.reaction_latency)
+                .map_err(|_| rusqlite::Error::InvalidQuery)?,
+            declared_relationships: serde_json::to_string(&world.declared_relationships)
+                .map_err(|_| rusqlite::Error::InvalidQuery)?,
+            dependents: serde_json::to_string(&world.dependents)
+                .map_err(|_| rusqlite::Error::InvalidQuery)?,
+            difficulty: serde_json::to_string(&world.difficulty)
+                .map_err(|_| rusqlite::Error::InvalidQuery)?,
+            post_life: serde_json::to_string(&world.post_life)
+                .map_err(|_| rusqlite::Error::InvalidQuery)?,
+            delta_caps: serde_json::to_string(&world.delta_caps)
+                .map_err(|_| rusqlite::Error::InvalidQuery)?,
+            player_notes: serde_json::to_string(&world.player_notes)
+                .map_err(|_| rusqlite::Error::InvalidQuery)?,
+            session_pacing: serde_json::to_string(&world.session_pacing)
+                .map_err(|_| rusqlite::Error::InvalidQuery)?,
+            float_mode: serde_json::to_string(&world.float_mode)
+                .map_err(|_| rusqlite::Error::InvalidQuery)?,
+            jealousy: serde_json::to_string(&world.jealousy)
+                .map_err(|_| rusqlite::Error::InvalidQuery)?,
+            opinions: serde_json::to_string(&world.opinions)
+                .map_err(|_| rusqlite::Error::InvalidQuery)?,
+            stat_crisis: serde_json::to_string(&world.stat_crisis)
+                .map_err(|_| rusqlite::Error::InvalidQuery)?,
+            spawned_npcs: serde_json::to_string(&world.spawned_npcs)
+                .map_err(|_| rusqlite::Error::InvalidQuery)?,
+            player_skills: serde_json::to_string(&world.player_skills)
+                .map_err(|_| rusqlite::Error::InvalidQuery)?,
+            districts: serde_json::to_string(&world.districts)
+                .map_err(|_| rusqlite::Error::InvalidQuery)?,
         })
     }
 
@@ -442,6 +829,9 @@ impl Persistence {
                 })
                 .collect(),
             queue: relationship_milestones_serializable.queue,
+            pending: relationship_milestones_serializable.pending,
+            history: relationship_milestones_serializable.history,
+            tuning: relationship_milestones_serializable.tuning,
         };
         let digital_legacy: crate::digital_legacy::DigitalLegacyState =
             serde_json::from_str(&row.digital_legacy).map_err(|_| rusqlite::Error::InvalidQuery)?;
@@ -453,6 +843,77 @@ impl Persistence {
             serde_json::from_str(&row.district_state).map_err(|_| rusqlite::Error::InvalidQuery)?;
         let world_flags: crate::world_flags::WorldFlags =
             serde_json::from_str(&row.world_flags).map_err(|_| rusqlite::Error::InvalidQuery)?;
+        let content_preferences: crate::content_preferences::ContentPreferences =
+            serde_json::from_str(&row.content_preferences)
+                .map_err(|_| rusqlite::Error::InvalidQuery)?;
+        let households: crate::household::HouseholdRegistry =
+            serde_json::from_str(&row.households).map_err(|_| rusqlite::Error::InvalidQuery)?;
+        let npc_moods: crate::npc_mood::NpcMoods =
+            serde_json::from_str(&row.npc_moods).map_err(|_| rusqlite::Error::InvalidQuery)?;
+        let underworld: crate::underworld::UnderworldExposure =
+            serde_json::from_str(&row.underworld).map_err(|_| rusqlite::Error::InvalidQuery)?;
+        let external_events: crate::external_events::ExternalEvents =
+            serde_json::from_str(&row.external_events)
+                .map_err(|_| rusqlite::Error::InvalidQuery)?;
+        let moral_ledger: crate::moral_ledger::MoralLedger =
+            serde_json::from_str(&row.moral_ledger).map_err(|_| rusqlite::Error::InvalidQuery)?;
+        let ambient: crate::ambient::AmbientState =
+            serde_json::from_str(&row.ambient).map_err(|_| rusqlite::Error::InvalidQuery)?;
+        let dreams: crate::dreams::DreamState =
+            serde_json::from_str(&row.dreams).map_err(|_| rusqlite::Error::InvalidQuery)?;
+        let proposals: crate::proposals::ProposalState =
+            serde_json::from_str(&row.proposals).map_err(|_| rusqlite::Error::InvalidQuery)?;
+        let chapters: crate::chapters::ChapterState =
+            serde_json::from_str(&row.chapters).map_err(|_| rusqlite::Error::InvalidQuery)?;
+        let relationship_heat: crate::relationship_heat::RelationshipHeatState =
+            serde_json::from_str(&row.relationship_heat).map_err(|_| rusqlite::Error::InvalidQuery)?;
+        let rival_actions: crate::rival_actions::RivalActionState =
+            serde_json::from_str(&row.rival_actions).map_err(|_| rusqlite::Error::InvalidQuery)?;
+        let life_goals: crate::life_goals::LifeGoalState =
+            serde_json::from_str(&row.life_goals).map_err(|_| rusqlite::Error::InvalidQuery)?;
+        let encounters: crate::encounters::EncounterState =
+            serde_json::from_str(&row.encounters).map_err(|_| rusqlite::Error::InvalidQuery)?;
+        let importance: crate::importance::ImportanceState =
+            serde_json::from_str(&row.importance).map_err(|_| rusqlite::Error::InvalidQuery)?;
+        let year_review: crate::year_review::YearReviewState =
+            serde_json::from_str(&row.year_review).map_err(|_| rusqlite::Error::InvalidQuery)?;
+        let flag_changes: crate::flag_changes::FlagChangeState =
+            serde_json::from_str(&row.flag_changes).map_err(|_| rusqlite::Error::InvalidQuery)?;
+        let reaction_latency: crate::reaction_latency::ReactionLatencyState =
+            serde_json::from_str(&row.reaction_latency).map_err(|_| rusqlite::Error::InvalidQuery)?;
+        let declared_relationships: crate::declared_relationships::DeclaredRelationshipState =
+            serde_json::from_str(&row.declared_relationships)
+                .map_err(|_| rusqlite::Error::InvalidQuery)?;
+        let dependents: crate::dependents::DependentState =
+            serde_json::from_str(&row.dependents).map_err(|_| rusqlite::Error::InvalidQuery)?;
+        let difficulty: crate::dynamic_difficulty::DifficultyState =
+            serde_json::from_str(&row.difficulty).map_err(|_| rusqlite::Error::InvalidQuery)?;
+        let post_life: crate::post_life::PostLifeState =
+            serde_json::from_str(&row.post_life).map_err(|_| rusqlite::Error::InvalidQuery)?;
+        let delta_caps: crate::delta_caps::DeltaCapState =
+            serde_json::from_str(&row.delta_caps).map_err(|_| rusqlite::Error::InvalidQuery)?;
+        let player_notes: crate::player_notes::PlayerNotesState =
+            serde_json::from_str(&row.player_notes).map_err(|_| rusqlite::Error::InvalidQuery)?;
+        let session_pacing: crate::session_pacing::SessionPacingState =
+            serde_json::from_str(&row.session_pacing).map_err(|_| rusqlite::Error::InvalidQuery)?;
+        let float_mode: crate::det_math::FloatMode =
+            serde_json::from_str(&row.float_mode).map_err(|_| rusqlite::Error::InvalidQuery)?;
+        let jealousy: crate::jealousy::JealousyState =
+            serde_json::from_str(&row.jealousy).map_err(|_| rusqlite::Error::InvalidQuery)?;
+        let opinions: crate::opinions::OpinionState =
+            serde_json::from_str(&row.opinions).map_err(|_| rusqlite::Error::InvalidQuery)?;
+        let stat_crisis: crate::stats::StatCrisisState =
+            serde_json::from_str(&row.stat_crisis).map_err(|_| rusqlite::Error::InvalidQuery)?;
+        let spawned_npcs: crate::npc_spawning::SpawnedNpcState =
+            serde_json::from_str(&row.spawned_npcs).map_err(|_| rusqlite::Error::InvalidQuery)?;
+        let player_skills: crate::skills::PlayerSkills =
+            serde_json::from_str(&row.player_skills).map_err(|_| rusqlite::Error::InvalidQuery)?;
+        // Saves from before districts were stored regenerate the seed's city
+        let mut districts: crate::district::DistrictRegistry =
+            serde_json::from_str(&row.districts).map_err(|_| rusqlite::Error::InvalidQuery)?;
+        if districts.is_empty() {
+            districts = crate::district::DistrictRegistry::generate_default_city(seed.0);
+        }
         let relationships_pairs: Vec<((u64, u64), Relationship)> =
             serde_json::from_str(&row.relationships).map_err(|_| rusqlite::Error::InvalidQuery)?;
         let mut relationships: HashMap<(NpcId, NpcId), Relationship> = HashMap::new();
@@ -510,14 +971,47 @@ impl Persistence {
             storylet_usage,
             memory_entries,
             district_state,
-            districts: crate::district::DistrictRegistry::generate_default_city(seed.0),
+            districts,
             district_pressure: crate::district_pressure::DistrictPressureState::default(),
-            player_skills: crate::skills::PlayerSkills::default(),
+            player_skills,
             gossip: crate::gossip::GossipSystem::default(),
             gossip_pressure: crate::gossip_pressure::GossipPressureState::default(),
             population: crate::population::PopulationSimulation::default(),
             failure_recovery: crate::failure_recovery::FailureRecoverySystem::default(),
             world_flags,
+            content_preferences,
+            news_feed: crate::news_feed::NewsFeed::default(),
+            households,
+            action_budget: crate::action_budget::ActionBudget::default(),
+            npc_moods,
+            underworld,
+            external_events,
+            moral_ledger,
+            ambient,
+            dreams,
+            proposals,
+            chapters,
+            relationship_heat,
+            rival_actions,
+            life_goals,
+            encounters,
+            importance,
+            year_review,
+            flag_changes,
+            event_bus: crate::event_bus::EventBus::default(),
+            reaction_latency,
+            declared_relationships,
+            dependents,
+            difficulty,
+            post_life,
+            delta_caps,
+            player_notes,
+            session_pacing,
+            float_mode,
+            jealousy,
+            opinions,
+            stat_crisis,
+            spawned_npcs,
         };
 
         // Normalize any legacy skew: if game_time_tick wasn't stored (defaulted to 0), sync it with current_tick
@@ -536,15 +1030,57 @@ impl Persistence {
         )?;
         self.conn
             .execute("DELETE FROM npcs WHERE world_seed = ?", params![seed.0])?;
+        self.conn.execute(
+            "DELETE FROM npc_instances WHERE world_seed = ?",
+            params![seed.0],
+        )?;
         self.conn.execute(
             "DELETE FROM relationships WHERE world_seed = ?",
             params![seed.0],
         )?;
+        self.conn.execute(
+            "DELETE FROM runtime_state WHERE world_seed = ?",
+            params![seed.0],
+        )?;
         self.conn
             .execute("DELETE FROM world_state WHERE seed = ?", params![seed.0])?;
         Ok(())
     }
 
+    /// Seeds of every saved world, in ascending order.
+    pub fn world_seeds(&mut self) -> SqlResult<Vec<WorldSeed>> {
+        let mut stmt = self
+            .conn
+            .prepare("SELECT seed FROM world_state ORDER BY seed")?;
+        let seeds = stmt
+            .query_map([], |row| row.get::<_, u64>(0))?
+            .map(|seed| seed.map(WorldSeed))
+            .collect();
+        seeds
+    }
+
+    /// Save runtime state that lives outside [`WorldState`] (memory
+    /// journals, director cooldowns, ...) as JSON under `key`.
+    pub fn save_runtime_state(&mut self, seed: WorldSeed, key: &str, json: &str) -> SqlResult<()> {
+        self.conn.execute(
+            "INSERT OR REPLACE INTO runtime_state (world_seed, key, json_data) VALUES (?, ?, ?)",
+            params![seed.0, key, json],
+        )?;
+        Ok(())
+    }
+
+    /// Load the runtime state saved under `key`, if any.
+    pub fn load_runtime_state(&mut self, seed: WorldSeed, key: &str) -> SqlResult<Option<String>> {
+        let mut stmt = self
+            .conn
+            .prepare("SELECT json_data FROM runtime_state WHERE world_seed = ? AND key = ?")?;
+        let mut rows = stmt.query(params![seed.0, key])?;
+        match rows.next()? {
+            Some(row) => Ok(Some(row.get(0)?)),
+            None => Ok(None),
+        }
+    }
+
     /// Check if a world exists.
     pub fn world_exists(&mut self, seed: WorldSeed) -> SqlResult<bool> {
         let mut stmt = self
@@ -592,6 +1128,82 @@ impl Persistence {
         self.conn.execute("DELETE FROM storylets", [])?;
         Ok(())
     }
+
+    /// Replace the live NPC instances saved for a world.
+    ///
+    /// The simulation layer owns the instance format; this stores one JSON
+    /// blob per NPC so behavior, busy timers and activity survive a reload.
+    pub fn save_npc_instance_records(
+        &mut self,
+        seed: WorldSeed,
+        records: &[NpcInstanceRecord],
+    ) -> SqlResult<()> {
+        let tx = self.conn.transaction()?;
+        tx.execute(
+            "DELETE FROM npc_instances WHERE world_seed = ?",
+            params![seed.0],
+        )?;
+        for record in records {
+            tx.execute(
+                "INSERT INTO npc_instances (world_seed, npc_id, instance_data) VALUES (?, ?, ?)",
+                params![seed.0, record.npc_id as i64, record.json_data],
+            )?;
+        }
+        tx.commit()
+    }
+
+    /// Load the live NPC instances saved for a world, ordered by NPC id.
+    ///
+    /// Empty for worlds saved before instances were persisted.
+    pub fn load_npc_instance_records(&mut self, seed: WorldSeed) -> SqlResult<Vec<NpcInstanceRecord>> {
+        let mut stmt = self.conn.prepare(
+            "SELECT npc_id, instance_data FROM npc_instances WHERE world_seed = ? ORDER BY npc_id",
+        )?;
+        let rows = stmt.query_map(params![seed.0], |row| {
+            Ok(NpcInstanceRecord {
+                npc_id: row.get::<_, i64>(0)?.cast_unsigned(),
+                json_data: row.get(1)?,
+            })
+        })?;
+        let mut results = Vec::new();
+        for row in rows {
+            results.push(row?);
+        }
+        Ok(results)
+    }
+
+    /// Insert or update an authored NPC prototype stored as JSON.
+    pub fn upsert_npc_prototype_record(&mut self, record: &NpcPrototypeRecord) -> SqlResult<()> {
+        self.conn.execute(
+            "INSERT INTO npc_prototype_defs (id, display_name, json_data, created_at, updated_at)
+             VALUES (?, ?, ?, CURRENT_TIMESTAMP, CURRENT_TIMESTAMP)
+             ON CONFLICT(id) DO UPDATE SET
+                 display_name = excluded.display_name,
+                 json_data = excluded.json_data,
+                 updated_at = CURRENT_TIMESTAMP",
+            params![record.id as i64, record.display_name, record.json_data],
+        )?;
+        Ok(())
+    }
+
+    /// Load every authored NPC prototype JSON blob from SQLite, ordered by id.
+    pub fn load_npc_prototype_records(&mut self) -> SqlResult<Vec<NpcPrototypeRecord>> {
+        let mut stmt = self
+            .conn
+            .prepare("SELECT id, display_name, json_data FROM npc_prototype_defs ORDER BY id")?;
+        let rows = stmt.query_map([], |row| {
+            Ok(NpcPrototypeRecord {
+                id: row.get::<_, i64>(0)?.cast_unsigned(),
+                display_name: row.get(1)?,
+                json_data: row.get(2)?,
+            })
+        })?;
+        let mut results = Vec::new();
+        for row in rows {
+            results.push(row?);
+        }
+        Ok(results)
+    }
 }
 
 /// Serialized storylet entry stored in SQLite.
@@ -605,6 +1217,26 @@ pub struct StoryletRecord {
     pub json_data: String,
 }
 
+/// Serialized NPC prototype definition stored in SQLite.
+#[derive(Debug, Clone)]
+pub struct NpcPrototypeRecord {
+    /// NPC identifier.
+    pub id: u64,
+    /// Display name for the NPC.
+    pub display_name: String,
+    /// Full JSON authoring data for the prototype.
+    pub json_data: String,
+}
+
+/// Serialized live NPC instance (simulation registry entry) stored in SQLite.
+#[derive(Debug, Clone, PartialEq)]
+pub struct NpcInstanceRecord {
+    /// NPC identifier.
+    pub npc_id: u64,
+    /// JSON-encoded instance state.
+    pub json_data: String,
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -695,6 +1327,104 @@ mod tests {
         });
         world.district_state.insert("Downtown".into(), "ok".into());
         world.world_flags.set_any("met_childhood_friend");
+        world.content_preferences.block_domain("addiction");
+        world.households.move_in(
+            world.player_id,
+            NpcId(2),
+            crate::household::HouseholdRole::Roommate,
+            SimTick(5),
+            None,
+            None,
+        );
+        world.npc_moods.set(NpcId(2), -6.0);
+        world.underworld.raise(35.0, 11);
+        crate::external_events::inject_external_event(
+            &mut world,
+            crate::external_events::ExternalEvent::Trigger {
+                trigger: "festival".to_string(),
+            },
+        )
+        .unwrap();
+        world.moral_ledger.karma_model.daily_decay = 0.05;
+        world.ambient.note_storylet(3);
+        world.dreams.last_dream_day = Some(2);
+        world.proposals.last_proposed.insert("2:be_partners".to_string(), 4);
+        world
+            .relationship_heat
+            .record_interaction(NpcId(1), NpcId(2), 3.0, 5);
+        world.rival_actions.last_acted.insert(2, 5);
+        crate::life_goals::adopt_life_goal(
+            &mut world,
+            crate::life_goals::archetype_goal(crate::CharacterArchetype::Analyst),
+        );
+        world.encounters.last_tick = Some(5);
+        world.importance.config.max_emergent = 2;
+        crate::year_review::update_year_review(&mut world);
+        crate::flag_changes::set_world_flag(&mut world, "got_fired", true, "storylet:layoffs");
+        world.reaction_latency.config.enabled = true;
+        crate::declared_relationships::declare_relationship(
+            &mut world,
+            NpcId(1),
+            NpcId(2),
+            crate::DeclaredStatus::Dating,
+            "storylet:first_date",
+        );
+        let pet = crate::dependents::adopt_dependent(&mut world, crate::DependentKind::Pet, "Biscuit");
+        let _ = crate::dependents::care_for_dependent(&mut world, pet);
+        world.difficulty.config.enabled = true;
+        crate::dynamic_difficulty::set_base_difficulty(&mut world, crate::Difficulty::Forgiving);
+        crate::dynamic_difficulty::apply_outcome_stat_deltas(
+            &mut world,
+            &[crate::StatDelta {
+                kind: crate::StatKind::Wealth,
+                delta: -5.0,
+                source: None,
+            }],
+        );
+        world.post_life.started_tick = Some(world.current_tick.0);
+        world.post_life.signal = 42.0;
+        world.post_life.coherence = 77.0;
+        crate::delta_caps::cap_stat_delta(&mut world, crate::StatKind::Wealth, 400.0);
+        world.session_pacing.start(1_000, 0);
+        world.session_pacing.end(1_600, world.current_tick.0);
+        world.session_pacing.start(2_000, world.current_tick.0);
+        world.float_mode = crate::det_math::FloatMode::Quantized;
+        world.jealousy.last_reacted.insert(9, 12);
+        world.jealousy.recent.push(crate::jealousy::JealousyEvent {
+            id: 0,
+            tick: 12,
+            jealous_id: NpcId(9),
+            partner_id: NpcId(2),
+            trigger: crate::jealousy::JealousyTrigger::Affection,
+            resentment: 4.5,
+            confronted: false,
+        });
+        world.opinions.config.hearsay_weight = 0.25;
+        world.stat_crisis.config.response_window_ticks = 6;
+        world.player_stats.mood = -8.0;
+        crate::stats::tick_stat_crisis(&mut world);
+        world.opinions.recent.push(crate::opinions::OpinionShift {
+            tick: 12,
+            observer_id: NpcId(9),
+            subject_id: NpcId(1),
+            about_id: NpcId(2),
+            shift: -0.5,
+            witnessed: true,
+            source: "storylet:argument".to_string(),
+        });
+        world.player_notes.set(
+            &crate::player_notes::NoteTarget::Memory("m1".to_string()),
+            "the day everything changed",
+            3,
+        );
+        crate::chapters::update_chapters(&mut world);
+        crate::moral_ledger::apply_reputation_deltas(
+            &mut world,
+            &[crate::moral_ledger::ReputationDelta {
+                scope: crate::moral_ledger::ReputationScope::District("Downtown".to_string()),
+                delta: -12.0,
+            }],
+        );
         let proto = NpcPrototype {
             id: NpcId(2),
             display_name: "Tester".to_string(),
@@ -710,6 +1440,9 @@ mod tests {
             base_stats: Stats::default(),
             active_stages: vec![LifeStage::YoungAdult],
             schedule: NpcSchedule::default(),
+            home_district: None,
+            tag_affinities: Default::default(),
+            major: false,
         };
         world.npc_prototypes.insert(proto.id, proto.clone());
         world.digital_legacy.primary_imprint = Some(DigitalImprint {
@@ -732,7 +1465,7 @@ mod tests {
         assert_eq!(loaded.narrative_heat.value(), 40.0);
         assert_eq!(loaded.heat_momentum, 5.0);
         assert_eq!(loaded.known_npcs, world.known_npcs);
-        assert_eq!(loaded.storylet_usage.times_fired.get("s1"), Some(&3));
+        assert_eq!(loaded.storylet_usage.count("s1"), 3);
         assert_eq!(
             loaded
                 .relationship_milestones
@@ -763,12 +1496,61 @@ mod tests {
             Some("Tester")
         );
         assert!(loaded.digital_legacy.primary_imprint.is_some());
-        assert_eq!(loaded.memory_entries.len(), 1);
+        // The test memory, plus adopting and caring for the pet
+        let memories: Vec<(&str, &str)> = loaded
+            .memory_entries
+            .iter()
+            .map(|m| (m.id.as_str(), m.event_id.as_str()))
+            .collect();
+        assert_eq!(
+            memories,
+            [
+                ("m1", "evt"),
+                ("dependent:0", "dependent:pet:adopted"),
+                ("dependent:1", "dependent:pet:cared"),
+            ]
+        );
         assert_eq!(
             loaded.district_state.get("Downtown"),
             Some(&"ok".to_string())
         );
         assert!(loaded.world_flags.has_any("met_childhood_friend"));
+        assert!(loaded.content_preferences.is_domain_blocked("addiction"));
+        assert!(loaded.households.lives_with(loaded.player_id, NpcId(2)));
+        assert_eq!(loaded.npc_moods.get(NpcId(2)), -6.0);
+        assert_eq!(loaded.underworld, world.underworld);
+        assert_eq!(loaded.external_events, world.external_events);
+        assert_eq!(loaded.moral_ledger, world.moral_ledger);
+        assert_eq!(loaded.ambient, world.ambient);
+        assert_eq!(loaded.dreams, world.dreams);
+        assert_eq!(loaded.proposals, world.proposals);
+        assert_eq!(loaded.chapters, world.chapters);
+        assert_eq!(loaded.relationship_heat, world.relationship_heat);
+        assert_eq!(loaded.rival_actions, world.rival_actions);
+        assert_eq!(loaded.life_goals, world.life_goals);
+        assert_eq!(loaded.encounters, world.encounters);
+        assert_eq!(loaded.importance, world.importance);
+        assert_eq!(loaded.year_review, world.year_review);
+        assert_eq!(loaded.flag_changes, world.flag_changes);
+        assert_eq!(loaded.reaction_latency, world.reaction_latency);
+        assert_eq!(loaded.declared_relationships, world.declared_relationships);
+        assert_eq!(loaded.dependents, world.dependents);
+        assert_eq!(loaded.difficulty, world.difficulty);
+        assert_eq!(loaded.post_life, world.post_life);
+        assert_eq!(loaded.delta_caps, world.delta_caps);
+        assert_eq!(loaded.delta_caps.warnings.len(), 1);
+        assert_eq!(loaded.player_notes, world.player_notes);
+        assert_eq!(
+            loaded.player_notes.memory_note("m1").as_deref(),
+            Some("the day everything changed")
+        );
+        assert_eq!(loaded.session_pacing, world.session_pacing);
+        assert_eq!(loaded.session_pacing.history.len(), 1);
+        assert_eq!(loaded.float_mode, crate::det_math::FloatMode::Quantized);
+        assert_eq!(loaded.jealousy, world.jealousy);
+        assert_eq!(loaded.opinions, world.opinions);
+        assert_eq!(loaded.stat_crisis, world.stat_crisis);
+        assert_eq!(loaded.stat_crisis.active.len(), 1);
         let _ = snapshot_json(&loaded);
 
         let _ = fs::remove_file(db_path);
@@ -954,6 +1736,35 @@ mod tests {
         serde_json::to_value(json_safe).expect("world snapshot should serialize to json value")
     }
 
+    #[test]
+    fn test_npc_instance_records_replace_and_delete() {
+        let db_path = "test_npc_instances.db";
+        let _ = fs::remove_file(db_path);
+
+        let mut db = Persistence::new(db_path).expect("Failed to create persistence");
+        let world = WorldState::new(WorldSeed(11), NpcId(1));
+        db.save_world(&world).expect("Failed to save world");
+        assert!(db.load_npc_instance_records(WorldSeed(11)).unwrap().is_empty());
+
+        let record = |id: u64, data: &str| NpcInstanceRecord {
+            npc_id: id,
+            json_data: data.to_string(),
+        };
+        db.save_npc_instance_records(WorldSeed(11), &[record(3, "{}"), record(2, "{}")])
+            .unwrap();
+        db.save_npc_instance_records(WorldSeed(11), &[record(2, "{\"busy\":1}")])
+            .unwrap();
+        assert_eq!(
+            db.load_npc_instance_records(WorldSeed(11)).unwrap(),
+            vec![record(2, "{\"busy\":1}")]
+        );
+
+        db.delete_world(WorldSeed(11)).unwrap();
+        assert!(db.load_npc_instance_records(WorldSeed(11)).unwrap().is_empty());
+
+        let _ = fs::remove_file(db_path);
+    }
+
     #[test]
     fn test_persistence_round_trip_snapshot() {
         let db_path = "test_persistence_round_trip.db";
@@ -1008,6 +1819,9 @@ mod tests {
             base_stats: Stats::default(),
             active_stages: vec![LifeStage::YoungAdult],
             schedule: NpcSchedule::default(),
+            home_district: None,
+            tag_affinities: Default::default(),
+            major: false,
         };
         world.npc_prototypes.insert(proto.id, proto);
 
@@ -0,0 +1,141 @@
+//! Player-authored notes on NPCs and journal entries.
+//!
+//! Notes are the player's own words ("owes me $20", "this is when it went
+//! wrong"). They are saved with the world and shown alongside the NPC or
+//! memory they annotate, but nothing in the simulation reads them: they feed
+//! no scoring, RNG or eligibility, and are left out of the determinism
+//! snapshot, so two runs that differ only in notes stay identical.
+//!
+//! Notes are keyed by target rather than stored on the memory itself, so a
+//! note outlives journal pruning and archival of the entry it annotates.
+
+use std::collections::BTreeMap;
+use std::fmt;
+
+use serde::{Deserialize, Serialize};
+
+use crate::errors::{Result, SynError};
+use crate::types::NpcId;
+
+/// Longest note kept, in characters; longer text is truncated.
+pub const MAX_NOTE_CHARS: usize = 2000;
+
+/// What a note is attached to.
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum NoteTarget {
+    /// An NPC (`npc:<id>`).
+    Npc(NpcId),
+    /// A journal entry, by memory id (`memory:<id>`).
+    Memory(String),
+}
+
+impl NoteTarget {
+    /// Parse `npc:<id>` or `memory:<id>`.
+    pub fn parse(target: &str) -> Result<Self> {
+        let invalid = || {
+            SynError::InvalidState(format!(
+                "note target '{}' is not npc:<id> or memory:<id>",
+                target
+            ))
+        };
+        let (kind, id) = target.trim().split_once(':').ok_or_else(invalid)?;
+        match kind {
+            "npc" => id.parse().map(|id| NoteTarget::Npc(NpcId(id))).map_err(|_| invalid()),
+            "memory" if !id.is_empty() => Ok(NoteTarget::Memory(id.to_string())),
+            _ => Err(invalid()),
+        }
+    }
+}
+
+impl fmt::Display for NoteTarget {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            NoteTarget::Npc(id) => write!(f, "npc:{}", id.0),
+            NoteTarget::Memory(id) => write!(f, "memory:{}", id),
+        }
+    }
+}
+
+/// A player note.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct PlayerNote {
+    /// Note text.
+    pub text: String,
+    /// Tick the note was last edited.
+    pub updated_tick: u64,
+}
+
+/// All player notes, keyed by target (`npc:<id>` / `memory:<id>`).
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct PlayerNotesState {
+    /// Notes by target key.
+    #[serde(default)]
+    pub notes: BTreeMap<String, PlayerNote>,
+}
+
+impl PlayerNotesState {
+    /// Set the note on `target`. Blank text removes it.
+    ///
+    /// Returns the stored note, or `None` if it was removed.
+    pub fn set(&mut self, target: &NoteTarget, text: &str, tick: u64) -> Option<&PlayerNote> {
+        let key = target.to_string();
+        let text = text.trim();
+        if text.is_empty() {
+            self.notes.remove(&key);
+            return None;
+        }
+        let note = PlayerNote {
+            text: text.chars().take(MAX_NOTE_CHARS).collect(),
+            updated_tick: tick,
+        };
+        self.notes.insert(key.clone(), note);
+        self.notes.get(&key)
+    }
+
+    /// The note on `target`, if any.
+    pub fn get(&self, target: &NoteTarget) -> Option<&PlayerNote> {
+        self.notes.get(&target.to_string())
+    }
+
+    /// Note text on an NPC.
+    pub fn npc_note(&self, id: NpcId) -> Option<String> {
+        self.get(&NoteTarget::Npc(id)).map(|n| n.text.clone())
+    }
+
+    /// Note text on a journal entry.
+    pub fn memory_note(&self, memory_id: &str) -> Option<String> {
+        self.get(&NoteTarget::Memory(memory_id.to_string()))
+            .map(|n| n.text.clone())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn targets_parse_and_round_trip() {
+        assert_eq!(NoteTarget::parse("npc:7").unwrap(), NoteTarget::Npc(NpcId(7)));
+        let memory = NoteTarget::parse("memory:prom-120").unwrap();
+        assert_eq!(memory, NoteTarget::Memory("prom-120".to_string()));
+        assert_eq!(memory.to_string(), "memory:prom-120");
+        for bad in ["npc:x", "memory:", "district:3", "7"] {
+            assert!(NoteTarget::parse(bad).is_err(), "{} should not parse", bad);
+        }
+    }
+
+    #[test]
+    fn blank_text_removes_and_long_text_is_truncated() {
+        let mut notes = PlayerNotesState::default();
+        let target = NoteTarget::Npc(NpcId(2));
+        let long = "x".repeat(MAX_NOTE_CHARS + 10);
+        assert_eq!(
+            notes.set(&target, &long, 5).map(|n| n.text.chars().count()),
+            Some(MAX_NOTE_CHARS)
+        );
+        notes.set(&target, "  owes me $20 ", 6);
+        assert_eq!(notes.npc_note(NpcId(2)).as_deref(), Some("owes me $20"));
+        assert!(notes.set(&target, "   ", 7).is_none());
+        assert!(notes.notes.is_empty());
+    }
+}
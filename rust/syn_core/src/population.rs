@@ -4,6 +4,7 @@
 //! cohorts, and black-swan events. The population evolves over time affecting
 //! job availability, housing costs, and social dynamics.
 
+use crate::collections::sorted_entries_mut;
 use crate::district::DistrictId;
 use crate::rng::DeterministicRng;
 use serde::{Deserialize, Serialize};
@@ -92,7 +93,7 @@ impl AgeCohort {
 }
 
 /// Job sector categories.
-#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Serialize, Deserialize)]
 pub enum JobSector {
     /// Technology, software, IT
     Technology,
@@ -708,7 +709,8 @@ impl PopulationSimulation {
 
     /// Update all job markets.
     fn update_job_markets(&mut self, rng: &mut DeterministicRng) {
-        for market in self.job_markets.values_mut() {
+        // Sector order decides which market consumes which RNG draw.
+        for (_, market) in sorted_entries_mut(&mut self.job_markets) {
             market.tick(self.economic_index, rng);
         }
 
@@ -937,7 +939,8 @@ impl PopulationSimulation {
         })
     }
 
-    fn describe_event(&self, event: &BlackSwanEvent) -> String {
+    /// Human-readable headline for a black swan event.
+    pub fn describe_event(&self, event: &BlackSwanEvent) -> String {
         match event {
             BlackSwanEvent::Recession { severity, .. } => {
                 format!("Economic recession begins (severity: {:.0}%)", severity * 100.0)
@@ -0,0 +1,612 @@
+//! PostLife: the Digital stage as a playable chapter.
+//!
+//! Once the player reaches [`LifeStage::Digital`] the daily pass opens the
+//! PostLife chapter. The ghost has two resources:
+//!
+//! - `signal` (0..`max_signal`): reach into the world, regenerated daily and
+//!   spent on actions;
+//! - `coherence` (0..100): how intact the imprint is. It erodes every day,
+//!   faster for a life lived in isolation, and communing restores it. At zero
+//!   the ghost fades, the [`POST_LIFE_FADED_FLAG`] goes up and the chapter ends.
+//!
+//! Entering PostLife seeds digital-only NPCs: echoes of the people the player
+//! held dearest and an archivist construct. [`commune_with_digital_npc`]
+//! spends signal to deepen a bond and steady coherence.
+//!
+//! [`nudge_descendant`] is the legacy's influence on the living world: it
+//! spends signal (and a little coherence) to lean storylets carrying a tag
+//! toward a descendant for `nudge_days`, and shifts the descendant's mood by
+//! how bright or dark the legacy is. The director multiplies the score of
+//! nudged storylets by [`PostLifeState::nudge_multiplier`].
+//!
+//! While PostLife is active only `StoryDomain::Digital` storylets are
+//! eligible (see `syn_director::domain_unlocked`).
+//!
+//! Entering, communing, nudging and fading leave player memories tagged
+//! [`POST_LIFE_TAG`].
+
+use serde::{Deserialize, Serialize};
+
+use crate::errors::{Result, SynError};
+use crate::flag_changes::{set_world_flag, FLAG_SOURCE_POST_LIFE};
+use crate::household::HouseholdRole;
+use crate::npc_mood::shift_mood;
+use crate::types::{LifeStage, MemoryEntryRecord, NpcId, WorldState};
+
+/// Tag added to PostLife memories.
+pub const POST_LIFE_TAG: &str = "post_life";
+
+/// Dynamic world flag raised when the ghost's coherence runs out.
+pub const POST_LIFE_FADED_FLAG: &str = "post_life_faded";
+
+/// Minimum age gap for a family member to count as a descendant.
+pub const DESCENDANT_MIN_AGE_GAP: u32 = 16;
+
+/// Resource rates and action costs.
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct PostLifeConfig {
+    /// Signal cap.
+    pub max_signal: f32,
+    /// Signal regained per day.
+    pub signal_per_day: f32,
+    /// Coherence lost per day.
+    pub coherence_decay_per_day: f32,
+    /// Extra daily coherence loss for a fully isolated legacy
+    /// (scaled by how negative `connection_vs_isolation` is).
+    pub isolation_decay_per_day: f32,
+    /// Signal spent communing with a digital NPC.
+    pub commune_signal_cost: f32,
+    /// Coherence restored by communing.
+    pub commune_coherence: f32,
+    /// Bond gained by communing (0..1).
+    pub commune_bond: f32,
+    /// Signal spent nudging a descendant.
+    pub nudge_signal_cost: f32,
+    /// Coherence spent nudging a descendant.
+    pub nudge_coherence_cost: f32,
+    /// How long a nudge lasts, in days.
+    pub nudge_days: u64,
+    /// Score multiplier for storylets carrying a nudged tag.
+    pub nudge_score_multiplier: f32,
+    /// Descendant mood shift per unit of `light_vs_shadow` when nudged.
+    pub nudge_mood: f32,
+    /// Echoes seeded on entering PostLife.
+    pub max_echoes: usize,
+    /// Affection toward the player an NPC needs to leave an echo.
+    pub echo_min_affection: f32,
+    /// Events kept in `recent`.
+    pub max_recent: usize,
+}
+
+impl Default for PostLifeConfig {
+    fn default() -> Self {
+        Self {
+            max_signal: 100.0,
+            signal_per_day: 10.0,
+            coherence_decay_per_day: 1.5,
+            isolation_decay_per_day: 1.5,
+            commune_signal_cost: 15.0,
+            commune_coherence: 5.0,
+            commune_bond: 0.15,
+            nudge_signal_cost: 30.0,
+            nudge_coherence_cost: 3.0,
+            nudge_days: 7,
+            nudge_score_multiplier: 1.75,
+            nudge_mood: 1.0,
+            max_echoes: 3,
+            echo_min_affection: 3.0,
+            max_recent: 20,
+        }
+    }
+}
+
+/// What a digital NPC is.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum DigitalNpcKind {
+    /// An echo of someone the player loved, built from their shared history.
+    Echo,
+    /// The construct that keeps the archive of digital minds.
+    Archivist,
+}
+
+impl DigitalNpcKind {
+    /// Stable lowercase label for UI/FFI.
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            DigitalNpcKind::Echo => "echo",
+            DigitalNpcKind::Archivist => "archivist",
+        }
+    }
+}
+
+/// A character that only exists in the Digital stage.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct DigitalNpc {
+    /// Sequential id.
+    pub id: u64,
+    /// What they are.
+    pub kind: DigitalNpcKind,
+    /// The living NPC an echo was built from.
+    #[serde(default)]
+    pub source_npc: Option<NpcId>,
+    /// Closeness to the ghost (0..1).
+    pub bond: f32,
+    /// Tick the ghost last communed with them.
+    #[serde(default)]
+    pub last_communed_tick: Option<u64>,
+}
+
+/// The legacy leaning a descendant's storylets toward a tag.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct LegacyNudge {
+    /// The descendant being nudged.
+    pub descendant: NpcId,
+    /// Storylet tag the nudge favors.
+    pub tag: String,
+    /// Tick the nudge started.
+    pub started_tick: u64,
+    /// Tick the nudge wears off.
+    pub expires_tick: u64,
+}
+
+/// What happened in PostLife.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum PostLifeEventKind {
+    /// The chapter opened.
+    Entered,
+    /// The ghost communed with a digital NPC.
+    Communed,
+    /// The ghost nudged a descendant.
+    Nudged,
+    /// Coherence ran out.
+    Faded,
+}
+
+impl PostLifeEventKind {
+    /// Stable lowercase label for UI/FFI.
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            PostLifeEventKind::Entered => "entered",
+            PostLifeEventKind::Communed => "communed",
+            PostLifeEventKind::Nudged => "nudged",
+            PostLifeEventKind::Faded => "faded",
+        }
+    }
+
+    /// Emotional intensity of the player's memory.
+    fn intensity(&self) -> f32 {
+        match self {
+            PostLifeEventKind::Entered => 0.6,
+            PostLifeEventKind::Communed => 0.3,
+            PostLifeEventKind::Nudged => 0.2,
+            PostLifeEventKind::Faded => -0.7,
+        }
+    }
+}
+
+/// One PostLife event.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct PostLifeEvent {
+    /// Sequential event id.
+    pub id: u64,
+    /// What happened.
+    pub kind: PostLifeEventKind,
+    /// Digital NPC communed with, if any.
+    #[serde(default)]
+    pub digital_npc: Option<u64>,
+    /// Descendant nudged, if any.
+    #[serde(default)]
+    pub descendant: Option<NpcId>,
+    /// Tick it happened.
+    pub tick: u64,
+}
+
+/// PostLife bookkeeping stored on `WorldState`.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct PostLifeState {
+    /// Resource rates and action costs.
+    #[serde(default)]
+    pub config: PostLifeConfig,
+    /// Tick the chapter opened; None while the player is alive.
+    #[serde(default)]
+    pub started_tick: Option<u64>,
+    /// Tick the ghost faded, if it has.
+    #[serde(default)]
+    pub faded_tick: Option<u64>,
+    /// Reach into the world, spent on actions.
+    #[serde(default)]
+    pub signal: f32,
+    /// How intact the imprint is (0..100).
+    #[serde(default)]
+    pub coherence: f32,
+    /// Digital-only NPCs.
+    #[serde(default)]
+    pub digital_npcs: Vec<DigitalNpc>,
+    /// Nudges still in effect.
+    #[serde(default)]
+    pub nudges: Vec<LegacyNudge>,
+    /// Most recent events, oldest first.
+    #[serde(default)]
+    pub recent: Vec<PostLifeEvent>,
+    /// Next digital NPC id.
+    #[serde(default)]
+    next_npc_id: u64,
+    /// Next event id.
+    #[serde(default)]
+    next_event_id: u64,
+}
+
+impl PostLifeState {
+    /// Whether the PostLife chapter has opened.
+    pub fn is_active(&self) -> bool {
+        self.started_tick.is_some()
+    }
+
+    /// Whether the ghost has faded.
+    pub fn is_faded(&self) -> bool {
+        self.faded_tick.is_some()
+    }
+
+    /// Look up a digital NPC by id.
+    pub fn digital_npc(&self, id: u64) -> Option<&DigitalNpc> {
+        self.digital_npcs.iter().find(|n| n.id == id)
+    }
+
+    /// Score multiplier for a storylet with `tags`: boosted if a nudge
+    /// favors one of them.
+    pub fn nudge_multiplier<'a>(&self, tags: impl IntoIterator<Item = &'a str>) -> f32 {
+        if self.nudges.is_empty() {
+            return 1.0;
+        }
+        let nudged = tags
+            .into_iter()
+            .any(|tag| self.nudges.iter().any(|n| n.tag.eq_ignore_ascii_case(tag)));
+        if nudged {
+            self.config.nudge_score_multiplier
+        } else {
+            1.0
+        }
+    }
+
+    fn add_digital_npc(&mut self, kind: DigitalNpcKind, source_npc: Option<NpcId>, bond: f32) {
+        let id = self.next_npc_id;
+        self.next_npc_id += 1;
+        self.digital_npcs.push(DigitalNpc {
+            id,
+            kind,
+            source_npc,
+            bond,
+            last_communed_tick: None,
+        });
+    }
+}
+
+/// Family members at least [`DESCENDANT_MIN_AGE_GAP`] years younger than
+/// the player, ordered by id.
+///
+/// Family means sharing the player's generated household, living with the
+/// player in a family role, or a family bond in the digital imprint.
+pub fn descendants(world: &WorldState) -> Vec<NpcId> {
+    let player = world.player_id;
+    let player_household = world.npcs.get(&player).map(|npc| npc.household_id);
+    let imprint = world.digital_legacy.primary_imprint.as_ref();
+    let mut ids: Vec<NpcId> = world
+        .npcs
+        .values()
+        .filter(|npc| npc.id != player)
+        .filter(|npc| npc.age + DESCENDANT_MIN_AGE_GAP <= world.player_age_years)
+        .filter(|npc| {
+            player_household == Some(npc.household_id)
+                || (world.households.lives_with(player, npc.id)
+                    && world
+                        .households
+                        .household_of(npc.id)
+                        .and_then(|h| h.member(npc.id))
+                        .is_some_and(|m| m.role == HouseholdRole::Family))
+                || imprint.is_some_and(|i| {
+                    i.relationship_roles.get(&npc.id)
+                        == Some(&crate::relationship_model::RelationshipRole::Family)
+                })
+        })
+        .map(|npc| npc.id)
+        .collect();
+    ids.sort();
+    ids
+}
+
+/// Open the PostLife chapter if the player is in the Digital stage.
+///
+/// Coherence starts higher for a connected life. Echoes are seeded from the
+/// NPCs with the most affection for the player, plus one archivist. Returns
+/// false if the chapter was already open or the player is still alive.
+pub fn enter_post_life(world: &mut WorldState) -> bool {
+    if world.post_life.is_active() || world.player_life_stage != LifeStage::Digital {
+        return false;
+    }
+    let config = world.post_life.config;
+    let connection = world
+        .digital_legacy
+        .primary_imprint
+        .as_ref()
+        .map(|i| i.legacy_vector.connection_vs_isolation)
+        .unwrap_or(0.0);
+
+    let player = world.player_id;
+    let mut loved: Vec<(NpcId, f32)> = world
+        .relationships
+        .iter()
+        .filter(|((from, to), rel)| {
+            *to == player && *from != player && rel.affection >= config.echo_min_affection
+        })
+        .map(|((from, _), rel)| (*from, rel.affection))
+        .collect();
+    loved.sort_by(|a, b| b.1.total_cmp(&a.1).then(a.0.cmp(&b.0)));
+    loved.truncate(config.max_echoes);
+
+    let state = &mut world.post_life;
+    state.started_tick = Some(world.current_tick.0);
+    state.signal = config.max_signal / 2.0;
+    state.coherence = (70.0 + 30.0 * connection).clamp(10.0, 100.0);
+    for (npc, affection) in loved {
+        state.add_digital_npc(
+            DigitalNpcKind::Echo,
+            Some(npc),
+            (affection / 10.0).clamp(0.0, 1.0),
+        );
+    }
+    state.add_digital_npc(DigitalNpcKind::Archivist, None, 0.0);
+    record_event(world, PostLifeEventKind::Entered, None, None);
+    true
+}
+
+/// Daily pass: open the chapter on reaching the Digital stage, regenerate
+/// signal, erode coherence, expire nudges and fade the ghost at zero.
+pub fn tick_post_life(world: &mut WorldState) {
+    if !world.post_life.is_active() {
+        enter_post_life(world);
+        return;
+    }
+    if world.post_life.is_faded() {
+        return;
+    }
+    let config = world.post_life.config;
+    let isolation = world
+        .digital_legacy
+        .primary_imprint
+        .as_ref()
+        .map(|i| (-i.legacy_vector.connection_vs_isolation).max(0.0))
+        .unwrap_or(0.0);
+    let tick = world.current_tick.0;
+
+    let state = &mut world.post_life;
+    state.signal = (state.signal + config.signal_per_day).min(config.max_signal);
+    state.coherence = (state.coherence
+        - config.coherence_decay_per_day
+        - config.isolation_decay_per_day * isolation)
+        .max(0.0);
+    state.nudges.retain(|n| n.expires_tick > tick);
+
+    if state.coherence <= 0.0 {
+        state.faded_tick = Some(tick);
+        state.nudges.clear();
+        set_world_flag(world, POST_LIFE_FADED_FLAG, true, FLAG_SOURCE_POST_LIFE);
+        record_event(world, PostLifeEventKind::Faded, None, None);
+    }
+}
+
+/// Commune with digital NPC `id`, spending signal to deepen the bond and
+/// restore coherence.
+pub fn commune_with_digital_npc(world: &mut WorldState, id: u64) -> Result<PostLifeEvent> {
+    ensure_playable(world)?;
+    let config = world.post_life.config;
+    if world.post_life.digital_npc(id).is_none() {
+        return Err(SynError::NotFound(format!("digital npc {}", id)));
+    }
+    spend_signal(world, config.commune_signal_cost)?;
+
+    let tick = world.current_tick.0;
+    let state = &mut world.post_life;
+    state.coherence = (state.coherence + config.commune_coherence).min(100.0);
+    if let Some(npc) = state.digital_npcs.iter_mut().find(|n| n.id == id) {
+        npc.bond = (npc.bond + config.commune_bond).min(1.0);
+        npc.last_communed_tick = Some(tick);
+    }
+    Ok(record_event(
+        world,
+        PostLifeEventKind::Communed,
+        Some(id),
+        None,
+    ))
+}
+
+/// Lean storylets tagged `tag` toward `descendant` for `nudge_days`.
+///
+/// Replaces any nudge already on that descendant. Fails outside PostLife,
+/// for someone who is not a descendant, or without enough signal.
+pub fn nudge_descendant(
+    world: &mut WorldState,
+    descendant: NpcId,
+    tag: &str,
+) -> Result<LegacyNudge> {
+    ensure_playable(world)?;
+    let tag = tag.trim();
+    if tag.is_empty() {
+        return Err(SynError::InvalidState("nudge needs a tag".to_string()));
+    }
+    if !descendants(world).contains(&descendant) {
+        return Err(SynError::NotFound(format!("descendant {}", descendant.0)));
+    }
+    let config = world.post_life.config;
+    spend_signal(world, config.nudge_signal_cost)?;
+
+    let tick = world.current_tick.0;
+    let nudge = LegacyNudge {
+        descendant,
+        tag: tag.to_string(),
+        started_tick: tick,
+        expires_tick: tick + config.nudge_days * 24,
+    };
+    let state = &mut world.post_life;
+    state.coherence = (state.coherence - config.nudge_coherence_cost).max(0.0);
+    state.nudges.retain(|n| n.descendant != descendant);
+    state.nudges.push(nudge.clone());
+
+    let light = world
+        .digital_legacy
+        .primary_imprint
+        .as_ref()
+        .map(|i| i.legacy_vector.light_vs_shadow)
+        .unwrap_or(0.0);
+    shift_mood(world, descendant, config.nudge_mood * light);
+    record_event(world, PostLifeEventKind::Nudged, None, Some(descendant));
+    Ok(nudge)
+}
+
+fn ensure_playable(world: &WorldState) -> Result<()> {
+    if !world.post_life.is_active() {
+        return Err(SynError::InvalidState("not in PostLife".to_string()));
+    }
+    if world.post_life.is_faded() {
+        return Err(SynError::InvalidState("the imprint has faded".to_string()));
+    }
+    Ok(())
+}
+
+fn spend_signal(world: &mut WorldState, cost: f32) -> Result<()> {
+    if world.post_life.signal < cost {
+        return Err(SynError::InvalidState("not enough signal".to_string()));
+    }
+    world.post_life.signal -= cost;
+    Ok(())
+}
+
+/// Log an event and leave the player's memory of it.
+fn record_event(
+    world: &mut WorldState,
+    kind: PostLifeEventKind,
+    digital_npc: Option<u64>,
+    descendant: Option<NpcId>,
+) -> PostLifeEvent {
+    let tick = world.current_tick.0;
+    let player = world.player_id;
+    let config = world.post_life.config;
+    let id = world.post_life.next_event_id;
+    world.post_life.next_event_id += 1;
+
+    let mut participants = vec![player.0];
+    participants.extend(descendant.map(|d| d.0));
+    world.memory_entries.push(MemoryEntryRecord {
+        id: format!("post_life:{}", id),
+        event_id: format!("{}:{}", POST_LIFE_TAG, kind.as_str()),
+        npc_id: player,
+        sim_tick: world.current_tick,
+        emotional_intensity: kind.intensity(),
+        tags: vec![POST_LIFE_TAG.to_string(), kind.as_str().to_string()],
+        participants,
+        ..Default::default()
+    });
+
+    let event = PostLifeEvent {
+        id,
+        kind,
+        digital_npc,
+        descendant,
+        tick,
+    };
+    let state = &mut world.post_life;
+    state.recent.push(event.clone());
+    let overflow = state.recent.len().saturating_sub(config.max_recent);
+    state.recent.drain(..overflow);
+    event
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::types::{AbstractNpc, AttachmentStyle, Relationship, Traits, WorldSeed};
+
+    fn npc(id: u64, age: u32, household_id: u64) -> AbstractNpc {
+        AbstractNpc {
+            id: NpcId(id),
+            age,
+            job: String::new(),
+            district: String::new(),
+            household_id,
+            traits: Traits::default(),
+            seed: id,
+            attachment_style: AttachmentStyle::Secure,
+        }
+    }
+
+    fn digital_world() -> WorldState {
+        let mut world = WorldState::new(WorldSeed(11), NpcId(1));
+        world.player_age_years = 92;
+        world.player_life_stage = LifeStage::Digital;
+        world.npcs.insert(NpcId(1), npc(1, 92, 7));
+        // A grandchild in the family household and an old friend who loved the player.
+        world.npcs.insert(NpcId(2), npc(2, 40, 7));
+        world.npcs.insert(NpcId(3), npc(3, 90, 9));
+        world.relationships.insert(
+            (NpcId(3), NpcId(1)),
+            Relationship {
+                affection: 8.0,
+                ..Default::default()
+            },
+        );
+        world
+    }
+
+    #[test]
+    fn entering_seeds_echoes_and_the_archivist() {
+        let mut world = digital_world();
+        tick_post_life(&mut world);
+
+        let state = &world.post_life;
+        assert!(state.is_active());
+        assert_eq!(state.digital_npcs.len(), 2);
+        assert_eq!(state.digital_npcs[0].kind, DigitalNpcKind::Echo);
+        assert_eq!(state.digital_npcs[0].source_npc, Some(NpcId(3)));
+        assert_eq!(state.digital_npcs[1].kind, DigitalNpcKind::Archivist);
+        assert_eq!(descendants(&world), vec![NpcId(2)]);
+        assert!(world
+            .memory_entries
+            .iter()
+            .any(|m| m.event_id == "post_life:entered"));
+    }
+
+    #[test]
+    fn nudges_spend_signal_and_favor_tagged_storylets() {
+        let mut world = digital_world();
+        enter_post_life(&mut world);
+        let signal = world.post_life.signal;
+
+        assert!(nudge_descendant(&mut world, NpcId(3), "career").is_err());
+        let nudge = nudge_descendant(&mut world, NpcId(2), "career").unwrap();
+        assert_eq!(nudge.expires_tick, world.current_tick.0 + 7 * 24);
+        assert!((world.post_life.signal - (signal - 30.0)).abs() < 1e-4);
+        assert!(world.post_life.nudge_multiplier(["career", "work"]) > 1.0);
+        assert!((world.post_life.nudge_multiplier(["romance"]) - 1.0).abs() < f32::EPSILON);
+
+        let echo = world.post_life.digital_npcs[0].id;
+        commune_with_digital_npc(&mut world, echo).unwrap();
+        assert!(
+            commune_with_digital_npc(&mut world, echo).is_err(),
+            "out of signal"
+        );
+    }
+
+    #[test]
+    fn coherence_runs_out_and_the_ghost_fades() {
+        let mut world = digital_world();
+        enter_post_life(&mut world);
+        world.post_life.coherence = 1.0;
+        tick_post_life(&mut world);
+
+        assert!(world.post_life.is_faded());
+        assert!(world.world_flags.has_any(POST_LIFE_FADED_FLAG));
+        assert!(nudge_descendant(&mut world, NpcId(2), "career").is_err());
+    }
+}
@@ -0,0 +1,610 @@
+//! NPC-initiated relationship proposals.
+//!
+//! Relationship states normally change implicitly as axis values cross the
+//! thresholds in `Relationship::compute_next_state`. Proposals make the big
+//! transitions explicit: once a day, every NPC whose view of the player has
+//! crossed a [`ProposalConfig`] threshold may ask the player to
+//! - become partners ([`ProposalKind::BePartners`]),
+//! - move in together ([`ProposalKind::MoveIn`]), or
+//! - end the friendship ([`ProposalKind::EndFriendship`]).
+//!
+//! Pending proposals are delivered by the director as special storylets with
+//! an accept and a reject choice (see [`PROPOSAL_STORYLET_PREFIX`]).
+//! [`respond_to_proposal`] applies the answer to both directions of the
+//! relationship. Unanswered proposals lapse after `expiry_ticks`, and an NPC
+//! does not repeat the same proposal until `cooldown_ticks` have passed.
+
+use std::collections::BTreeMap;
+
+use serde::{Deserialize, Serialize};
+
+use crate::ambient::npc_name;
+use crate::errors::{Result, SynError};
+use crate::household::{apply_move_in_flag, MOVE_IN_FLAG_PREFIX};
+use crate::relationship_heat::record_relationship_interaction;
+use crate::relationships::RelationshipAxis;
+use crate::types::{
+    LifeStage, MemoryEntryRecord, NpcId, Relationship, RelationshipState, WorldState,
+};
+
+/// Storylet id prefix of proposal events (`proposal:<id>`).
+pub const PROPOSAL_STORYLET_PREFIX: &str = "proposal:";
+
+/// Choice id that accepts a proposal.
+pub const PROPOSAL_ACCEPT_CHOICE: &str = "accept";
+
+/// Choice id that rejects a proposal.
+pub const PROPOSAL_REJECT_CHOICE: &str = "reject";
+
+/// Tag added to proposal memories.
+pub const PROPOSAL_TAG: &str = "proposal";
+
+/// Axis changes applied to one side of a relationship.
+type AxisDeltas = &'static [(RelationshipAxis, f32)];
+
+/// What an NPC is asking the player.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum ProposalKind {
+    /// Become romantic partners.
+    BePartners,
+    /// Move in together (the NPC joins the player's household).
+    MoveIn,
+    /// End the friendship.
+    EndFriendship,
+}
+
+impl ProposalKind {
+    /// Stable lowercase label for UI/FFI.
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            ProposalKind::BePartners => "be_partners",
+            ProposalKind::MoveIn => "move_in",
+            ProposalKind::EndFriendship => "end_friendship",
+        }
+    }
+
+    /// Content tag the proposal carries for comfort filtering.
+    pub fn content_tag(&self) -> &'static str {
+        match self {
+            ProposalKind::BePartners | ProposalKind::MoveIn => "romance",
+            ProposalKind::EndFriendship => "conflict",
+        }
+    }
+
+    fn title(&self, name: &str) -> String {
+        match self {
+            ProposalKind::BePartners => format!("{} asks you to be partners", name),
+            ProposalKind::MoveIn => format!("{} asks you to move in together", name),
+            ProposalKind::EndFriendship => format!("{} wants to end your friendship", name),
+        }
+    }
+
+    fn choice_labels(&self) -> (&'static str, &'static str) {
+        match self {
+            ProposalKind::BePartners => ("Say yes", "Turn them down"),
+            ProposalKind::MoveIn => ("Move in together", "Not yet"),
+            ProposalKind::EndFriendship => ("Let them go", "Ask to stay friends"),
+        }
+    }
+
+    /// Axis changes on the NPC→player and player→NPC sides for an answer.
+    fn deltas(&self, accepted: bool) -> (AxisDeltas, AxisDeltas) {
+        use RelationshipAxis::*;
+        match (self, accepted) {
+            (ProposalKind::BePartners, true) => (
+                &[(Affection, 1.0), (Trust, 1.0), (Attraction, 1.0)],
+                &[(Affection, 1.0), (Trust, 1.0), (Attraction, 1.0)],
+            ),
+            (ProposalKind::BePartners, false) => (
+                &[(Attraction, -1.5), (Affection, -1.0), (Resentment, 1.0)],
+                &[(Familiarity, 0.5)],
+            ),
+            (ProposalKind::MoveIn, true) => (
+                &[(Trust, 1.0), (Affection, 0.5), (Familiarity, 1.0)],
+                &[(Trust, 1.0), (Affection, 0.5), (Familiarity, 1.0)],
+            ),
+            (ProposalKind::MoveIn, false) => {
+                (&[(Trust, -1.0), (Resentment, 0.5)], &[(Trust, -0.5)])
+            }
+            (ProposalKind::EndFriendship, true) => (
+                &[(Affection, -2.0), (Trust, -1.0)],
+                &[(Affection, -2.0), (Trust, -1.0)],
+            ),
+            (ProposalKind::EndFriendship, false) => {
+                (&[(Resentment, -1.5), (Affection, 0.5)], &[(Trust, -1.0)])
+            }
+        }
+    }
+
+    /// State both sides take on acceptance (`None` keeps the computed state).
+    fn accepted_state(&self) -> Option<RelationshipState> {
+        match self {
+            ProposalKind::BePartners => Some(RelationshipState::Partner),
+            ProposalKind::MoveIn => None,
+            ProposalKind::EndFriendship => Some(RelationshipState::Estranged),
+        }
+    }
+}
+
+/// Thresholds and pacing for proposals. Axis thresholds are measured on the
+/// NPC→player relationship.
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct ProposalConfig {
+    /// Minimum attraction before an NPC asks to be partners.
+    pub partners_min_attraction: f32,
+    /// Minimum affection before an NPC asks to be partners.
+    pub partners_min_affection: f32,
+    /// Minimum trust before an NPC asks to be partners.
+    pub partners_min_trust: f32,
+    /// Minimum trust before a partner asks to move in.
+    pub move_in_min_trust: f32,
+    /// Minimum affection before a partner asks to move in.
+    pub move_in_min_affection: f32,
+    /// Minimum resentment before a friend asks to end the friendship.
+    pub end_friendship_min_resentment: f32,
+    /// Ticks an unanswered proposal stays open.
+    pub expiry_ticks: u64,
+    /// Ticks before an NPC repeats the same kind of proposal.
+    pub cooldown_ticks: u64,
+    /// Maximum proposals waiting for an answer at once.
+    pub max_pending: usize,
+}
+
+impl Default for ProposalConfig {
+    fn default() -> Self {
+        Self {
+            partners_min_attraction: 6.0,
+            partners_min_affection: 6.0,
+            partners_min_trust: 4.0,
+            move_in_min_trust: 7.0,
+            move_in_min_affection: 7.0,
+            end_friendship_min_resentment: 6.0,
+            expiry_ticks: 72,
+            cooldown_ticks: 336,
+            max_pending: 3,
+        }
+    }
+}
+
+/// A proposal waiting for the player's answer.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct RelationshipProposal {
+    /// Proposal id (unique within a run).
+    pub id: u64,
+    /// NPC making the proposal.
+    pub npc_id: NpcId,
+    /// What they are asking.
+    pub kind: ProposalKind,
+    /// Tick the proposal was made.
+    pub created_tick: u64,
+}
+
+impl RelationshipProposal {
+    /// Storylet id the director presents this proposal under.
+    pub fn storylet_id(&self) -> String {
+        format!("{}{}", PROPOSAL_STORYLET_PREFIX, self.id)
+    }
+}
+
+/// Result of answering a proposal.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct ProposalResolution {
+    /// The answered proposal.
+    pub proposal: RelationshipProposal,
+    /// Whether the player accepted.
+    pub accepted: bool,
+    /// NPC→player relationship afterwards.
+    pub npc_to_player: Relationship,
+    /// Player→NPC relationship afterwards.
+    pub player_to_npc: Relationship,
+}
+
+/// Proposal bookkeeping stored on `WorldState`.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct ProposalState {
+    /// Thresholds and pacing.
+    #[serde(default)]
+    pub config: ProposalConfig,
+    /// Proposals waiting for an answer, oldest first.
+    #[serde(default)]
+    pub pending: Vec<RelationshipProposal>,
+    /// Last tick each `<npc_id>:<kind>` proposal was made.
+    #[serde(default)]
+    pub last_proposed: BTreeMap<String, u64>,
+    /// Next proposal id.
+    #[serde(default)]
+    next_id: u64,
+}
+
+fn cooldown_key(npc_id: NpcId, kind: ProposalKind) -> String {
+    format!("{}:{}", npc_id.0, kind.as_str())
+}
+
+impl ProposalState {
+    /// The proposal the director should present next (the oldest).
+    pub fn next_pending(&self) -> Option<&RelationshipProposal> {
+        self.pending.first()
+    }
+
+    /// Look up a pending proposal.
+    pub fn get(&self, id: u64) -> Option<&RelationshipProposal> {
+        self.pending.iter().find(|p| p.id == id)
+    }
+
+    fn cooling_down(&self, npc_id: NpcId, kind: ProposalKind, tick: u64) -> bool {
+        self.last_proposed
+            .get(&cooldown_key(npc_id, kind))
+            .is_some_and(|last| tick.saturating_sub(*last) < self.config.cooldown_ticks)
+    }
+
+    fn has_pending_from(&self, npc_id: NpcId) -> bool {
+        self.pending.iter().any(|p| p.npc_id == npc_id)
+    }
+}
+
+/// Parse a proposal storylet id (`proposal:<id>`).
+pub fn parse_proposal_storylet_id(storylet_id: &str) -> Option<u64> {
+    storylet_id
+        .strip_prefix(PROPOSAL_STORYLET_PREFIX)?
+        .parse()
+        .ok()
+}
+
+fn is_friendship(state: RelationshipState) -> bool {
+    matches!(
+        state,
+        RelationshipState::Friend | RelationshipState::CloseFriend | RelationshipState::BestFriend
+    )
+}
+
+fn is_partnered(state: RelationshipState) -> bool {
+    matches!(
+        state,
+        RelationshipState::Partner | RelationshipState::Spouse
+    )
+}
+
+/// The proposal an NPC would make given both sides of the relationship.
+fn proposal_for(
+    world: &WorldState,
+    config: &ProposalConfig,
+    npc_id: NpcId,
+    npc_view: &Relationship,
+) -> Option<ProposalKind> {
+    let player_view = world.get_relationship(world.player_id, npc_id);
+    let stage = world.player_life_stage;
+    let romance_stage = !matches!(
+        stage,
+        LifeStage::PreSim | LifeStage::Child | LifeStage::Digital
+    );
+
+    if is_partnered(player_view.state) {
+        let adult = romance_stage && stage != LifeStage::Teen;
+        let ready = npc_view.trust >= config.move_in_min_trust
+            && npc_view.affection >= config.move_in_min_affection;
+        return (adult && ready && !world.households.lives_with(world.player_id, npc_id))
+            .then_some(ProposalKind::MoveIn);
+    }
+
+    if romance_stage
+        && npc_view.attraction >= config.partners_min_attraction
+        && npc_view.affection >= config.partners_min_affection
+        && npc_view.trust >= config.partners_min_trust
+    {
+        return Some(ProposalKind::BePartners);
+    }
+
+    (is_friendship(player_view.state)
+        && npc_view.resentment >= config.end_friendship_min_resentment)
+        .then_some(ProposalKind::EndFriendship)
+}
+
+/// Expire stale proposals and let NPCs whose feelings crossed a threshold
+/// make new ones. Returns the ids of new proposals.
+///
+/// NPCs are visited in id order so runs stay deterministic. Each NPC has at
+/// most one open proposal, and proposals blocked by the player's content
+/// preferences are never made.
+pub fn scan_for_proposals(world: &mut WorldState) -> Vec<u64> {
+    let tick = world.current_tick.0;
+    let config = world.proposals.config;
+    world
+        .proposals
+        .pending
+        .retain(|p| tick.saturating_sub(p.created_tick) < config.expiry_ticks);
+
+    let player = world.player_id;
+    let mut npc_views: Vec<(NpcId, Relationship)> = world
+        .relationships
+        .iter()
+        .filter(|((from, to), _)| *to == player && *from != player)
+        .map(|((from, _), rel)| (*from, *rel))
+        .collect();
+    npc_views.sort_by_key(|(id, _)| id.0);
+
+    let mut created = Vec::new();
+    for (npc_id, npc_view) in npc_views {
+        if world.proposals.pending.len() >= config.max_pending {
+            break;
+        }
+        if world.proposals.has_pending_from(npc_id) {
+            continue;
+        }
+        let Some(kind) = proposal_for(world, &config, npc_id, &npc_view) else {
+            continue;
+        };
+        if world.proposals.cooling_down(npc_id, kind, tick)
+            || !world
+                .content_preferences
+                .allows(None, [PROPOSAL_TAG, kind.content_tag()])
+        {
+            continue;
+        }
+
+        let id = world.proposals.next_id;
+        world.proposals.next_id += 1;
+        world.proposals.pending.push(RelationshipProposal {
+            id,
+            npc_id,
+            kind,
+            created_tick: tick,
+        });
+        world
+            .proposals
+            .last_proposed
+            .insert(cooldown_key(npc_id, kind), tick);
+        created.push(id);
+    }
+    created
+}
+
+/// Title and `(choice id, label)` pairs for presenting a proposal.
+pub fn proposal_prompt(
+    world: &WorldState,
+    proposal: &RelationshipProposal,
+) -> (String, [(&'static str, &'static str); 2]) {
+    let (accept, reject) = proposal.kind.choice_labels();
+    (
+        proposal.kind.title(&npc_name(world, proposal.npc_id)),
+        [
+            (PROPOSAL_ACCEPT_CHOICE, accept),
+            (PROPOSAL_REJECT_CHOICE, reject),
+        ],
+    )
+}
+
+fn apply_deltas(rel: &mut Relationship, deltas: &[(RelationshipAxis, f32)]) {
+    for (axis, delta) in deltas {
+        rel.apply_delta(*axis, *delta);
+    }
+    rel.state = rel.compute_next_state();
+}
+
+/// Answer a pending proposal.
+///
+/// Both directions of the relationship move; accepting also commits the
+/// transition itself (partners, a shared household, or estrangement). A
+/// player memory tagged [`PROPOSAL_TAG`] records the answer.
+pub fn respond_to_proposal(
+    world: &mut WorldState,
+    proposal_id: u64,
+    accept: bool,
+) -> Result<ProposalResolution> {
+    let idx = world
+        .proposals
+        .pending
+        .iter()
+        .position(|p| p.id == proposal_id)
+        .ok_or_else(|| SynError::NotFound(format!("proposal {}", proposal_id)))?;
+    let proposal = world.proposals.pending.remove(idx);
+    let (player, npc) = (world.player_id, proposal.npc_id);
+    let tick = world.current_tick;
+
+    let (npc_deltas, player_deltas) = proposal.kind.deltas(accept);
+    let mut npc_to_player = world.get_relationship(npc, player);
+    let mut player_to_npc = world.get_relationship(player, npc);
+    apply_deltas(&mut npc_to_player, npc_deltas);
+    apply_deltas(&mut player_to_npc, player_deltas);
+    if accept {
+        if let Some(state) = proposal.kind.accepted_state() {
+            npc_to_player.state = state;
+            player_to_npc.state = state;
+        }
+        match proposal.kind {
+            ProposalKind::MoveIn => {
+                let flag = format!("{}{}", MOVE_IN_FLAG_PREFIX, npc.0);
+                apply_move_in_flag(world, &flag, true, tick);
+            }
+            ProposalKind::EndFriendship if world.households.lives_with(player, npc) => {
+                let flag = format!("{}{}", MOVE_IN_FLAG_PREFIX, npc.0);
+                apply_move_in_flag(world, &flag, false, tick);
+            }
+            _ => {}
+        }
+    }
+    world.set_relationship(npc, player, npc_to_player);
+    world.set_relationship(player, npc, player_to_npc);
+    let magnitude: f32 = npc_deltas
+        .iter()
+        .chain(player_deltas)
+        .map(|(_, delta)| delta.abs())
+        .sum();
+    record_relationship_interaction(world, npc, player, magnitude);
+
+    let answer = if accept { "accepted" } else { "rejected" };
+    world.memory_entries.push(MemoryEntryRecord {
+        id: format!("proposal:{}", proposal.id),
+        event_id: format!("proposal:{}:{}", proposal.kind.as_str(), answer),
+        npc_id: player,
+        sim_tick: tick,
+        emotional_intensity: if accept { 0.6 } else { -0.4 },
+        tags: vec![
+            PROPOSAL_TAG.to_string(),
+            proposal.kind.as_str().to_string(),
+            answer.to_string(),
+        ],
+        participants: vec![player.0, npc.0],
+        ..Default::default()
+    });
+
+    Ok(ProposalResolution {
+        proposal,
+        accepted: accept,
+        npc_to_player,
+        player_to_npc,
+    })
+}
+
+/// Resolve a proposal presented as a storylet choice.
+///
+/// Returns `None` if `storylet_id` is not a proposal event, or the proposal
+/// is gone or the choice unknown.
+pub fn choose_proposal_option(
+    world: &mut WorldState,
+    storylet_id: &str,
+    choice_id: &str,
+) -> Option<ProposalResolution> {
+    let id = parse_proposal_storylet_id(storylet_id)?;
+    let accept = match choice_id {
+        PROPOSAL_ACCEPT_CHOICE => true,
+        PROPOSAL_REJECT_CHOICE => false,
+        _ => return None,
+    };
+    respond_to_proposal(world, id, accept).ok()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::types::{SimTick, WorldSeed};
+
+    fn world_with_npc_view(npc_view: Relationship, player_view: Relationship) -> WorldState {
+        let mut world = WorldState::new(WorldSeed(7), NpcId(1));
+        world.player_life_stage = LifeStage::Adult;
+        world.set_relationship(NpcId(2), NpcId(1), npc_view);
+        world.set_relationship(NpcId(1), NpcId(2), player_view);
+        world
+    }
+
+    fn smitten() -> Relationship {
+        Relationship {
+            affection: 7.0,
+            trust: 5.0,
+            attraction: 7.0,
+            familiarity: 4.0,
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn test_threshold_crossing_creates_single_proposal() {
+        let mut world = world_with_npc_view(smitten(), Relationship::default());
+
+        let created = scan_for_proposals(&mut world);
+        assert_eq!(created.len(), 1);
+        let proposal = world.proposals.next_pending().unwrap();
+        assert_eq!(proposal.kind, ProposalKind::BePartners);
+        assert_eq!(proposal.npc_id, NpcId(2));
+
+        // Still pending: no duplicate.
+        assert!(scan_for_proposals(&mut world).is_empty());
+    }
+
+    #[test]
+    fn test_accepting_partners_updates_both_directions() {
+        let mut world = world_with_npc_view(smitten(), Relationship::default());
+        let id = scan_for_proposals(&mut world)[0];
+
+        let resolution = respond_to_proposal(&mut world, id, true).unwrap();
+        assert!(resolution.accepted);
+        assert_eq!(
+            world.get_relationship(NpcId(2), NpcId(1)).state,
+            RelationshipState::Partner
+        );
+        assert_eq!(
+            world.get_relationship(NpcId(1), NpcId(2)).state,
+            RelationshipState::Partner
+        );
+        assert!(world.get_relationship(NpcId(1), NpcId(2)).attraction > 0.0);
+        assert!(world.proposals.pending.is_empty());
+        assert!(world
+            .memory_entries
+            .iter()
+            .any(|m| m.event_id == "proposal:be_partners:accepted"));
+    }
+
+    #[test]
+    fn test_rejection_cools_down_repeat_proposals() {
+        let mut world = world_with_npc_view(smitten(), Relationship::default());
+        let id = scan_for_proposals(&mut world)[0];
+
+        respond_to_proposal(&mut world, id, false).unwrap();
+        assert!(world.get_relationship(NpcId(2), NpcId(1)).resentment > 0.0);
+
+        // Feelings are still strong, but the NPC waits before asking again.
+        let mut npc_view = world.get_relationship(NpcId(2), NpcId(1));
+        npc_view.attraction = 8.0;
+        npc_view.affection = 8.0;
+        world.set_relationship(NpcId(2), NpcId(1), npc_view);
+        assert!(scan_for_proposals(&mut world).is_empty());
+
+        world.current_tick = SimTick(world.proposals.config.cooldown_ticks);
+        assert_eq!(scan_for_proposals(&mut world).len(), 1);
+    }
+
+    #[test]
+    fn test_partner_asks_to_move_in() {
+        let partner = Relationship {
+            affection: 8.0,
+            trust: 8.0,
+            attraction: 8.0,
+            state: RelationshipState::Partner,
+            ..Default::default()
+        };
+        let mut world = world_with_npc_view(partner, partner);
+        let id = scan_for_proposals(&mut world)[0];
+        assert_eq!(world.proposals.get(id).unwrap().kind, ProposalKind::MoveIn);
+
+        let resolution = choose_proposal_option(
+            &mut world,
+            &format!("{}{}", PROPOSAL_STORYLET_PREFIX, id),
+            PROPOSAL_ACCEPT_CHOICE,
+        )
+        .unwrap();
+        assert!(resolution.accepted);
+        assert!(world.households.lives_with(NpcId(1), NpcId(2)));
+    }
+
+    #[test]
+    fn test_resentful_friend_and_expiry() {
+        let npc_view = Relationship {
+            resentment: 7.0,
+            familiarity: 5.0,
+            ..Default::default()
+        };
+        let friend = Relationship {
+            state: RelationshipState::Friend,
+            ..Default::default()
+        };
+        let mut world = world_with_npc_view(npc_view, friend);
+        let id = scan_for_proposals(&mut world)[0];
+        assert_eq!(
+            world.proposals.get(id).unwrap().kind,
+            ProposalKind::EndFriendship
+        );
+
+        world.current_tick = SimTick(world.proposals.config.expiry_ticks);
+        scan_for_proposals(&mut world);
+        assert!(world.proposals.get(id).is_none());
+        assert!(respond_to_proposal(&mut world, id, true).is_err());
+    }
+
+    #[test]
+    fn test_content_preferences_block_romance_proposals() {
+        let mut world = world_with_npc_view(smitten(), Relationship::default());
+        world.content_preferences.block_tag("romance");
+        assert!(scan_for_proposals(&mut world).is_empty());
+    }
+}
@@ -0,0 +1,288 @@
+//! Delayed NPC reactions.
+//!
+//! Relationship deltas from an outcome normally land at once. With reaction
+//! latency enabled, deltas an NPC feels (the actor is not the player) are
+//! held as [`PendingReaction`]s instead and applied in equal slices over the
+//! next `ticks` ticks, so an insult sinks in over the afternoon rather than
+//! the instant it's spoken.
+//!
+//! While a reaction is still pending it can be intercepted: a storylet with
+//! the [`REACTION_PENDING_TRIGGER`] trigger is eligible only while an NPC is
+//! still processing something the player did, and its outcome can cancel part
+//! of what hasn't landed yet via [`ReactionIntercept`] (apologizing before
+//! they've fully taken in the insult).
+
+use serde::{Deserialize, Serialize};
+
+use crate::relationship_model::{RelationshipAxis as ModelAxis, RelationshipDelta};
+use crate::types::{NpcId, WorldState};
+use crate::RelationshipAxis;
+
+/// Trigger kind for storylets that intercept a reaction still sinking in.
+pub const REACTION_PENDING_TRIGGER: &str = "reaction_pending";
+
+/// Tuning for delayed NPC reactions.
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct ReactionLatencyConfig {
+    /// Whether NPC-side deltas are delayed at all. Off by default.
+    pub enabled: bool,
+    /// Ticks over which a delayed delta is applied.
+    pub ticks: u64,
+}
+
+impl Default for ReactionLatencyConfig {
+    fn default() -> Self {
+        Self {
+            enabled: false,
+            ticks: 6,
+        }
+    }
+}
+
+/// The part of an NPC's reaction that hasn't been applied yet.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct PendingReaction {
+    /// NPC who is reacting.
+    pub actor_id: u64,
+    /// Who they're reacting to.
+    pub target_id: u64,
+    /// Axis being changed.
+    pub axis: RelationshipAxis,
+    /// Full authored delta.
+    pub total: f32,
+    /// Portion still to apply.
+    pub remaining: f32,
+    /// Ticks left to apply `remaining` over.
+    pub ticks_left: u64,
+    /// Source event/storylet of the delta.
+    #[serde(default)]
+    pub source: Option<String>,
+    /// Tick the reaction started.
+    pub started_tick: u64,
+}
+
+/// An outcome's request to cancel part of a pending reaction.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct ReactionIntercept {
+    /// NPC whose reaction is intercepted.
+    pub actor_id: u64,
+    /// Who they're reacting to.
+    pub target_id: u64,
+    /// Share of the remaining delta to cancel (0.0..=1.0).
+    pub fraction: f32,
+}
+
+/// Reaction latency state stored on the world.
+#[derive(Debug, Clone, PartialEq, Default, Serialize, Deserialize)]
+pub struct ReactionLatencyState {
+    /// Tuning.
+    #[serde(default)]
+    pub config: ReactionLatencyConfig,
+    /// Reactions still sinking in, oldest first.
+    #[serde(default)]
+    pub pending: Vec<PendingReaction>,
+}
+
+impl ReactionLatencyState {
+    /// Pending reactions of `actor_id` toward `target_id`.
+    pub fn pending_for(
+        &self,
+        actor_id: u64,
+        target_id: u64,
+    ) -> impl Iterator<Item = &PendingReaction> {
+        self.pending
+            .iter()
+            .filter(move |r| r.actor_id == actor_id && r.target_id == target_id)
+    }
+
+    /// Whether any NPC is still reacting to `target_id`.
+    pub fn has_pending_toward(&self, target_id: u64) -> bool {
+        self.pending.iter().any(|r| r.target_id == target_id)
+    }
+}
+
+fn core_axis(axis: ModelAxis) -> RelationshipAxis {
+    match axis {
+        ModelAxis::Affection => RelationshipAxis::Affection,
+        ModelAxis::Trust => RelationshipAxis::Trust,
+        ModelAxis::Attraction => RelationshipAxis::Attraction,
+        ModelAxis::Familiarity => RelationshipAxis::Familiarity,
+        ModelAxis::Resentment => RelationshipAxis::Resentment,
+    }
+}
+
+/// Hold back one relationship delta if it is NPC-side and latency is on.
+///
+/// Returns `true` when the delta was queued as a [`PendingReaction`]; the
+/// caller applies it immediately otherwise.
+pub fn defer_npc_reaction(
+    world: &mut WorldState,
+    actor_id: u64,
+    target_id: u64,
+    axis: RelationshipAxis,
+    delta: f32,
+    source: Option<String>,
+) -> bool {
+    let config = world.reaction_latency.config;
+    if !config.enabled
+        || config.ticks == 0
+        || actor_id == world.player_id.0
+        || delta.abs() <= f32::EPSILON
+    {
+        return false;
+    }
+    world.reaction_latency.pending.push(PendingReaction {
+        actor_id,
+        target_id,
+        axis,
+        total: delta,
+        remaining: delta,
+        ticks_left: config.ticks,
+        source,
+        started_tick: world.current_tick.0,
+    });
+    true
+}
+
+/// Hold back the NPC-side deltas of an outcome when latency is enabled.
+///
+/// Returns the deltas to apply now: all of them when latency is off,
+/// otherwise only those the player is the actor of.
+pub fn defer_npc_reactions(
+    world: &mut WorldState,
+    deltas: &[RelationshipDelta],
+) -> Vec<RelationshipDelta> {
+    deltas
+        .iter()
+        .filter(|d| {
+            !defer_npc_reaction(
+                world,
+                d.actor_id,
+                d.target_id,
+                core_axis(d.axis),
+                d.delta,
+                d.source.clone(),
+            )
+        })
+        .cloned()
+        .collect()
+}
+
+/// Cancel part of the pending reactions an intercept names. Returns the
+/// total magnitude cancelled.
+pub fn intercept_pending_reactions(world: &mut WorldState, intercept: &ReactionIntercept) -> f32 {
+    let fraction = intercept.fraction.clamp(0.0, 1.0);
+    let mut cancelled = 0.0;
+    for reaction in world.reaction_latency.pending.iter_mut() {
+        if reaction.actor_id == intercept.actor_id && reaction.target_id == intercept.target_id {
+            let cut = reaction.remaining * fraction;
+            reaction.remaining -= cut;
+            cancelled += cut.abs();
+        }
+    }
+    world
+        .reaction_latency
+        .pending
+        .retain(|r| r.remaining.abs() > f32::EPSILON);
+    cancelled
+}
+
+/// Apply one tick's slice of every pending reaction.
+pub fn tick_reaction_latency(world: &mut WorldState) {
+    if world.reaction_latency.pending.is_empty() {
+        return;
+    }
+    let pending = std::mem::take(&mut world.reaction_latency.pending);
+    let mut still_pending = Vec::with_capacity(pending.len());
+    for mut reaction in pending {
+        let step = reaction.remaining / reaction.ticks_left.max(1) as f32;
+        let actor = NpcId(reaction.actor_id);
+        let target = NpcId(reaction.target_id);
+        let mut rel = world.get_relationship(actor, target);
+        rel.apply_delta(reaction.axis, step);
+        rel.state = rel.compute_next_state();
+        world.set_relationship(actor, target, rel);
+        crate::relationship_heat::record_relationship_interaction(world, actor, target, step.abs());
+
+        reaction.remaining -= step;
+        reaction.ticks_left = reaction.ticks_left.saturating_sub(1);
+        if reaction.ticks_left > 0 {
+            still_pending.push(reaction);
+        }
+    }
+    world.reaction_latency.pending = still_pending;
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::types::{SimTick, WorldSeed};
+
+    fn insult() -> RelationshipDelta {
+        RelationshipDelta {
+            actor_id: 2,
+            target_id: 1,
+            axis: ModelAxis::Resentment,
+            delta: 3.0,
+            source: Some("storylet:insult".to_string()),
+        }
+    }
+
+    #[test]
+    fn npc_deltas_sink_in_over_the_configured_ticks() {
+        let mut world = WorldState::new(WorldSeed(1), NpcId(1));
+        world.reaction_latency.config.enabled = true;
+        let player_side = RelationshipDelta {
+            actor_id: 1,
+            target_id: 2,
+            ..insult()
+        };
+
+        let now = defer_npc_reactions(&mut world, &[insult(), player_side]);
+        assert_eq!(now.len(), 1);
+        assert_eq!(now[0].actor_id, 1);
+        assert!(world.reaction_latency.has_pending_toward(1));
+
+        for _ in 0..3 {
+            tick_reaction_latency(&mut world);
+        }
+        let half = world.get_relationship(NpcId(2), NpcId(1)).resentment;
+        assert!((half - 1.5).abs() < 1e-4);
+        for _ in 0..3 {
+            tick_reaction_latency(&mut world);
+        }
+        let full = world.get_relationship(NpcId(2), NpcId(1)).resentment;
+        assert!((full - 3.0).abs() < 1e-4);
+        assert!(world.reaction_latency.pending.is_empty());
+    }
+
+    #[test]
+    fn intercept_cancels_what_has_not_landed() {
+        let mut world = WorldState::new(WorldSeed(1), NpcId(1));
+        world.current_tick = SimTick(10);
+        world.reaction_latency.config.enabled = true;
+        defer_npc_reactions(&mut world, &[insult()]);
+        tick_reaction_latency(&mut world);
+
+        let cancelled = intercept_pending_reactions(
+            &mut world,
+            &ReactionIntercept {
+                actor_id: 2,
+                target_id: 1,
+                fraction: 1.0,
+            },
+        );
+        assert!((cancelled - 2.5).abs() < 1e-4);
+        assert!(world.reaction_latency.pending.is_empty());
+        let resentment = world.get_relationship(NpcId(2), NpcId(1)).resentment;
+        assert!((resentment - 0.5).abs() < 1e-4);
+    }
+
+    #[test]
+    fn latency_off_applies_everything_now() {
+        let mut world = WorldState::new(WorldSeed(1), NpcId(1));
+        assert_eq!(defer_npc_reactions(&mut world, &[insult()]).len(), 1);
+        assert!(world.reaction_latency.pending.is_empty());
+    }
+}
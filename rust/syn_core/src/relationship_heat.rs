@@ -0,0 +1,213 @@
+//! Per-pair relationship heat: how charged a relationship is right now.
+//!
+//! `Relationship::heat` measures how extreme a pair's axes are; this module
+//! tracks how *active* the pair has been lately. Every interaction that moves
+//! a pair's axes spikes its heat in proportion to the size of the change
+//! ([`record_relationship_interaction`]), and heat decays exponentially with
+//! a configurable half-life. Decay is evaluated lazily from the tick of the
+//! last update, so untouched pairs cost nothing per tick.
+//!
+//! The director favours storylets that cast a hot pair (see
+//! [`RelationshipHeatState::selection_multiplier`]). Once a day each pair's
+//! heat is sampled as the baseline for its rising/cooling trend, and pairs
+//! that have gone cold are dropped.
+//!
+//! Pairs are unordered: an interaction from A toward B heats A↔B.
+
+use serde::{Deserialize, Serialize};
+
+use crate::types::{NpcId, WorldState};
+
+/// Pairs whose heat falls below this at the daily sample are dropped.
+const COLD_HEAT: f32 = 0.01;
+
+/// Spike, decay and selection tuning for relationship heat.
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct RelationshipHeatConfig {
+    /// Heat (0..1) added per point of absolute axis change.
+    pub spike_per_point: f32,
+    /// Ticks for heat to halve without interaction.
+    pub half_life_ticks: u64,
+    /// Selection multiplier for a storylet casting a pair at full heat;
+    /// scales linearly down to 1.0 at zero heat.
+    pub max_selection_multiplier: f32,
+}
+
+impl Default for RelationshipHeatConfig {
+    fn default() -> Self {
+        Self {
+            spike_per_point: 0.1,
+            half_life_ticks: 72,
+            max_selection_multiplier: 2.0,
+        }
+    }
+}
+
+/// Heat of one unordered NPC pair.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct PairHeat {
+    /// The pair, lower id first.
+    pub pair: (u64, u64),
+    /// Heat (0..1) as of `updated_tick`.
+    pub heat: f32,
+    /// Tick `heat` was last brought up to date.
+    pub updated_tick: u64,
+    /// Heat at the last daily sample, the reference for the trend.
+    pub baseline: f32,
+    /// Tick of the last interaction between the pair.
+    pub last_interaction_tick: u64,
+}
+
+impl PairHeat {
+    /// Heat at `tick` after decay since `updated_tick`.
+    pub fn heat_at(&self, tick: u64, config: &RelationshipHeatConfig) -> f32 {
+        let elapsed = tick.saturating_sub(self.updated_tick) as f32;
+        let half_life = config.half_life_ticks.max(1) as f32;
+        self.heat * crate::det_math::half_life_factor(elapsed, half_life)
+    }
+}
+
+/// Heat of every recently active pair.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct RelationshipHeatState {
+    /// Tuning.
+    #[serde(default)]
+    pub config: RelationshipHeatConfig,
+    /// Active pairs, sorted by pair.
+    #[serde(default)]
+    pub pairs: Vec<PairHeat>,
+}
+
+fn pair_key(a: NpcId, b: NpcId) -> (u64, u64) {
+    (a.0.min(b.0), a.0.max(b.0))
+}
+
+impl RelationshipHeatState {
+    /// Heat entry of a pair, if it has interacted recently.
+    pub fn pair(&self, a: NpcId, b: NpcId) -> Option<&PairHeat> {
+        let key = pair_key(a, b);
+        self.pairs
+            .binary_search_by_key(&key, |p| p.pair)
+            .ok()
+            .map(|i| &self.pairs[i])
+    }
+
+    /// Spike a pair's heat for an interaction of the given magnitude
+    /// (sum of absolute axis changes). Self-pairs are ignored.
+    pub fn record_interaction(&mut self, a: NpcId, b: NpcId, magnitude: f32, tick: u64) {
+        if a == b || magnitude <= 0.0 {
+            return;
+        }
+        let spike = magnitude * self.config.spike_per_point;
+        let key = pair_key(a, b);
+        let index = match self.pairs.binary_search_by_key(&key, |p| p.pair) {
+            Ok(i) => i,
+            Err(i) => {
+                self.pairs.insert(
+                    i,
+                    PairHeat {
+                        pair: key,
+                        heat: 0.0,
+                        updated_tick: tick,
+                        baseline: 0.0,
+                        last_interaction_tick: tick,
+                    },
+                );
+                i
+            }
+        };
+        let config = self.config;
+        let entry = &mut self.pairs[index];
+        entry.heat = (entry.heat_at(tick, &config) + spike).min(1.0);
+        entry.updated_tick = tick;
+        entry.last_interaction_tick = tick;
+    }
+
+    /// Current heat (0..1) of a pair.
+    pub fn heat(&self, a: NpcId, b: NpcId, tick: u64) -> f32 {
+        self.pair(a, b)
+            .map(|p| p.heat_at(tick, &self.config))
+            .unwrap_or(0.0)
+    }
+
+    /// Change in heat since the last daily sample (-1.0 cooling .. +1.0 rising).
+    pub fn trend(&self, a: NpcId, b: NpcId, tick: u64) -> f32 {
+        self.pair(a, b)
+            .map(|p| (p.heat_at(tick, &self.config) - p.baseline).clamp(-1.0, 1.0))
+            .unwrap_or(0.0)
+    }
+
+    /// Selection multiplier (1.0..`max_selection_multiplier`) for a storylet
+    /// casting this pair.
+    pub fn selection_multiplier(&self, a: NpcId, b: NpcId, tick: u64) -> f32 {
+        let max = self.config.max_selection_multiplier.max(1.0);
+        1.0 + self.heat(a, b, tick) * (max - 1.0)
+    }
+
+    /// Daily upkeep: fold decay into every pair, record the trend baseline
+    /// and drop pairs that have gone cold.
+    pub fn sample_daily(&mut self, tick: u64) {
+        let config = self.config;
+        for entry in &mut self.pairs {
+            entry.heat = entry.heat_at(tick, &config);
+            entry.updated_tick = tick;
+            entry.baseline = entry.heat;
+        }
+        self.pairs.retain(|p| p.heat >= COLD_HEAT);
+    }
+}
+
+/// Record an interaction between two characters at the current tick.
+///
+/// `magnitude` is the sum of absolute axis changes it caused.
+pub fn record_relationship_interaction(world: &mut WorldState, a: NpcId, b: NpcId, magnitude: f32) {
+    let tick = world.current_tick.0;
+    world
+        .relationship_heat
+        .record_interaction(a, b, magnitude, tick);
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn interactions_spike_and_heat_decays_by_half_life() {
+        let mut state = RelationshipHeatState::default();
+        state.record_interaction(NpcId(2), NpcId(1), 3.0, 100);
+
+        assert!((state.heat(NpcId(1), NpcId(2), 100) - 0.3).abs() < 1e-6);
+        assert!((state.heat(NpcId(1), NpcId(2), 172) - 0.15).abs() < 1e-6);
+        assert!(state.selection_multiplier(NpcId(1), NpcId(2), 100) > 1.29);
+        assert_eq!(state.selection_multiplier(NpcId(1), NpcId(3), 100), 1.0);
+
+        // Spikes stack on the decayed value and saturate at 1.0.
+        state.record_interaction(NpcId(1), NpcId(2), 20.0, 172);
+        assert_eq!(state.heat(NpcId(1), NpcId(2), 172), 1.0);
+    }
+
+    #[test]
+    fn daily_sample_sets_trend_baseline_and_prunes_cold_pairs() {
+        let mut state = RelationshipHeatState::default();
+        state.record_interaction(NpcId(1), NpcId(2), 5.0, 0);
+        state.record_interaction(NpcId(1), NpcId(3), 0.2, 0);
+        state.sample_daily(24);
+
+        assert_eq!(state.trend(NpcId(1), NpcId(2), 24), 0.0);
+        state.record_interaction(NpcId(1), NpcId(2), 2.0, 30);
+        assert!(state.trend(NpcId(1), NpcId(2), 30) > 0.1);
+        assert!(state.trend(NpcId(1), NpcId(2), 300) < 0.0);
+
+        state.sample_daily(24 * 30);
+        assert!(state.pairs.is_empty());
+    }
+
+    #[test]
+    fn self_pairs_and_empty_interactions_are_ignored() {
+        let mut state = RelationshipHeatState::default();
+        state.record_interaction(NpcId(1), NpcId(1), 5.0, 0);
+        state.record_interaction(NpcId(1), NpcId(2), 0.0, 0);
+        assert!(state.pairs.is_empty());
+    }
+}
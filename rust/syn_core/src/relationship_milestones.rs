@@ -47,6 +47,60 @@ pub struct RelationshipMilestoneEvent {
     pub tick: Option<u64>,
 }
 
+/// Tuning for milestone confirmation, cooldowns and dedup.
+///
+/// Relationship values drifting around a role boundary would otherwise flip
+/// Friend→Rival→Friend every few ticks and queue a milestone each time.
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq)]
+pub struct MilestoneTuning {
+    /// Ticks a new role must persist before its milestone is queued.
+    pub confirm_ticks: u64,
+    /// Ticks before the same milestone kind can fire again for the same pair.
+    pub cooldown_ticks: u64,
+    /// Ticks after a milestone during which any other milestone for the same
+    /// pair is dropped as noise.
+    pub dedup_window_ticks: u64,
+}
+
+impl Default for MilestoneTuning {
+    fn default() -> Self {
+        Self {
+            confirm_ticks: 24,      // 1 day
+            cooldown_ticks: 168,    // 1 week
+            dedup_window_ticks: 72, // 3 days
+        }
+    }
+}
+
+/// A role change waiting out the confirmation window.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct PendingMilestone {
+    /// NPC whose relationship changed.
+    pub actor_id: u64,
+    /// NPC the relationship is with.
+    pub target_id: u64,
+    /// Role the pair moved into.
+    pub role: RelationshipRole,
+    /// Tick the pair first entered `role`.
+    pub since_tick: u64,
+    /// Milestone detected for the transition, if any.
+    #[serde(default)]
+    pub event: Option<RelationshipMilestoneEvent>,
+}
+
+/// A queued milestone, kept for cooldown and dedup checks.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct MilestoneRecord {
+    /// NPC whose relationship changed.
+    pub actor_id: u64,
+    /// NPC the relationship is with.
+    pub target_id: u64,
+    /// Type of milestone.
+    pub kind: RelationshipMilestoneKind,
+    /// Tick the milestone was queued.
+    pub tick: u64,
+}
+
 /// State for tracking relationship milestones.
 #[derive(Debug, Clone, Default, Serialize, Deserialize, PartialEq)]
 pub struct RelationshipMilestoneState {
@@ -56,6 +110,15 @@ pub struct RelationshipMilestoneState {
     /// FIFO queue of recent milestone events.
     #[serde(default)]
     pub queue: VecDeque<RelationshipMilestoneEvent>,
+    /// Role changes not yet confirmed.
+    #[serde(default)]
+    pub pending: Vec<PendingMilestone>,
+    /// Recently queued milestones (pruned to the cooldown/dedup horizon).
+    #[serde(default)]
+    pub history: VecDeque<MilestoneRecord>,
+    /// Confirmation, cooldown and dedup tuning.
+    #[serde(default)]
+    pub tuning: MilestoneTuning,
 }
 
 impl RelationshipMilestoneState {
@@ -79,7 +142,20 @@ impl RelationshipMilestoneState {
         self.queue.front()
     }
 
+    /// Pending (unconfirmed) role change for a pair, if any.
+    pub fn pending_for_pair(&self, actor_id: u64, target_id: u64) -> Option<&PendingMilestone> {
+        self.pending
+            .iter()
+            .find(|p| p.actor_id == actor_id && p.target_id == target_id)
+    }
+
     /// Evaluate a relationship for milestones and record any that occurred.
+    ///
+    /// With a `tick`, a new role only counts once it has held for
+    /// `tuning.confirm_ticks`; flipping back before then cancels it. Confirmed
+    /// milestones are then subject to per-pair cooldown and dedup. Without a
+    /// tick, role changes apply immediately (only exact queue duplicates are
+    /// dropped). Returns the milestone queued by this call, if any.
     #[allow(clippy::too_many_arguments)]
     pub fn evaluate_and_record_milestones_for_pair(
         &mut self,
@@ -89,7 +165,7 @@ impl RelationshipMilestoneState {
         memory_tags: &[String],
         source: Option<String>,
         tick: Option<u64>,
-    ) {
+    ) -> Option<RelationshipMilestoneEvent> {
         let new_role = current_rel.role();
         let prev_role = self
             .last_role
@@ -97,20 +173,142 @@ impl RelationshipMilestoneState {
             .copied()
             .unwrap_or(RelationshipRole::Stranger);
 
-        if let Some(kind) = Self::detect_milestone(prev_role, new_role, memory_tags) {
-            self.queue.push_back(RelationshipMilestoneEvent {
+        let Some(now) = tick else {
+            let queued = Self::build_event(
                 actor_id,
                 target_id,
-                kind,
-                from_role: prev_role.to_string(),
-                to_role: new_role.to_string(),
-                reason: Self::reason_for_milestone(kind, memory_tags),
+                prev_role,
+                new_role,
+                memory_tags,
                 source,
-                tick,
-            });
+                None,
+            )
+            .filter(|event| !self.is_queued(event));
+            if let Some(event) = &queued {
+                self.queue.push_back(event.clone());
+            }
+            self.record_role_for_pair(actor_id, target_id, new_role);
+            return queued;
+        };
+
+        let existing = self
+            .pending
+            .iter()
+            .position(|p| p.actor_id == actor_id && p.target_id == target_id);
+
+        // Back to the confirmed role: the excursion was noise.
+        if new_role == prev_role {
+            if let Some(idx) = existing {
+                self.pending.remove(idx);
+            }
+            return None;
         }
 
+        let idx = match existing {
+            Some(idx) if self.pending[idx].role == new_role => idx,
+            Some(idx) => {
+                self.pending[idx] = PendingMilestone {
+                    actor_id,
+                    target_id,
+                    role: new_role,
+                    since_tick: now,
+                    event: None,
+                };
+                idx
+            }
+            None => {
+                self.pending.push(PendingMilestone {
+                    actor_id,
+                    target_id,
+                    role: new_role,
+                    since_tick: now,
+                    event: None,
+                });
+                self.pending.len() - 1
+            }
+        };
+
+        let pending = &mut self.pending[idx];
+        if pending.event.is_none() {
+            pending.event = Self::build_event(
+                actor_id,
+                target_id,
+                prev_role,
+                new_role,
+                memory_tags,
+                source,
+                Some(pending.since_tick),
+            );
+        }
+        if now.saturating_sub(pending.since_tick) < self.tuning.confirm_ticks {
+            return None;
+        }
+
+        let confirmed = self.pending.remove(idx);
         self.record_role_for_pair(actor_id, target_id, new_role);
+        let event = confirmed.event?;
+        self.queue_confirmed(event.clone(), now).then_some(event)
+    }
+
+    /// Queue a confirmed milestone unless it is on cooldown, inside the pair's
+    /// dedup window, or already queued. Returns whether it was queued.
+    fn queue_confirmed(&mut self, event: RelationshipMilestoneEvent, now: u64) -> bool {
+        let MilestoneTuning {
+            cooldown_ticks,
+            dedup_window_ticks,
+            ..
+        } = self.tuning;
+        let horizon = cooldown_ticks.max(dedup_window_ticks);
+        self.history.retain(|r| now.saturating_sub(r.tick) < horizon);
+
+        let suppressed = self.history.iter().any(|r| {
+            let age = now.saturating_sub(r.tick);
+            r.actor_id == event.actor_id
+                && r.target_id == event.target_id
+                && (age < dedup_window_ticks || (r.kind == event.kind && age < cooldown_ticks))
+        });
+        if suppressed || self.is_queued(&event) {
+            return false;
+        }
+
+        self.history.push_back(MilestoneRecord {
+            actor_id: event.actor_id,
+            target_id: event.target_id,
+            kind: event.kind,
+            tick: now,
+        });
+        self.queue.push_back(event);
+        true
+    }
+
+    /// Whether the same milestone for the same pair is already waiting in the queue.
+    fn is_queued(&self, event: &RelationshipMilestoneEvent) -> bool {
+        self.queue.iter().any(|e| {
+            e.actor_id == event.actor_id && e.target_id == event.target_id && e.kind == event.kind
+        })
+    }
+
+    #[allow(clippy::too_many_arguments)]
+    fn build_event(
+        actor_id: u64,
+        target_id: u64,
+        prev_role: RelationshipRole,
+        new_role: RelationshipRole,
+        memory_tags: &[String],
+        source: Option<String>,
+        tick: Option<u64>,
+    ) -> Option<RelationshipMilestoneEvent> {
+        let kind = Self::detect_milestone(prev_role, new_role, memory_tags)?;
+        Some(RelationshipMilestoneEvent {
+            actor_id,
+            target_id,
+            kind,
+            from_role: prev_role.to_string(),
+            to_role: new_role.to_string(),
+            reason: Self::reason_for_milestone(kind, memory_tags),
+            source,
+            tick,
+        })
     }
 
     /// Detect if a role transition constitutes a milestone based on memory tags.
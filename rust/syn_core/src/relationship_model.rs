@@ -9,12 +9,137 @@
 //! - **Resentment**: Hostility, grudges (-10 to +10)
 //!
 //! Axes are converted to bands for eligibility checking and UI display.
+//!
+//! How deltas land on an axis is tuned per axis by [`RelationshipTuning`]:
+//! hard range, a soft cap past which movement gets harder (elasticity), and
+//! separate scales for gains and losses. The defaults reproduce a plain
+//! ±10 clamp; install different tuning with [`set_relationship_tuning`].
+
+use std::sync::RwLock;
 
 use serde::{Deserialize, Serialize};
 
-/// Clamp a relationship axis value to valid range.
-fn clamp_axis(value: f32) -> f32 {
-    value.clamp(-10.0, 10.0)
+/// How deltas land on one relationship axis.
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct AxisTuning {
+    /// Lowest value the axis can reach.
+    pub min: f32,
+    /// Highest value the axis can reach.
+    pub max: f32,
+    /// Distance from zero past which movement toward either extreme is resisted.
+    pub soft_cap: f32,
+    /// Resistance at the extremes (0.0 = none, 1.0 = the last point can't be reached).
+    pub elasticity: f32,
+    /// Scale applied to positive deltas.
+    pub growth: f32,
+    /// Scale applied to negative deltas.
+    pub decay: f32,
+}
+
+impl AxisTuning {
+    /// Plain clamp to -10..+10, no elasticity, symmetric growth and decay.
+    pub const DEFAULT: Self = Self {
+        min: -10.0,
+        max: 10.0,
+        soft_cap: 10.0,
+        elasticity: 0.0,
+        growth: 1.0,
+        decay: 1.0,
+    };
+
+    /// Clamp a value to this axis' range.
+    pub fn clamp(&self, value: f32) -> f32 {
+        value.clamp(self.min, self.max)
+    }
+
+    /// The value `current` moves to when `delta` is applied.
+    pub fn apply(&self, current: f32, delta: f32) -> f32 {
+        let scaled = if delta >= 0.0 {
+            delta * self.growth
+        } else {
+            delta * self.decay
+        };
+        let moved = if scaled >= 0.0 {
+            Self::resist(current, scaled, self.soft_cap, self.max, self.elasticity)
+        } else {
+            -Self::resist(-current, -scaled, self.soft_cap, -self.min, self.elasticity)
+        };
+        self.clamp(current + moved)
+    }
+
+    /// Movement of a non-negative `delta` from `from` toward `hard`. Movement
+    /// past `soft` is scaled down the closer it starts to `hard`.
+    fn resist(from: f32, delta: f32, soft: f32, hard: f32, elasticity: f32) -> f32 {
+        if elasticity <= 0.0 || soft >= hard {
+            return delta;
+        }
+        let free = (soft - from).clamp(0.0, delta);
+        let start = from.max(soft);
+        let depth = ((start - soft) / (hard - soft)).clamp(0.0, 1.0);
+        free + (delta - free) * (1.0 - elasticity.min(1.0) * depth)
+    }
+}
+
+impl Default for AxisTuning {
+    fn default() -> Self {
+        Self::DEFAULT
+    }
+}
+
+/// Per-axis tuning for how relationship deltas are applied.
+#[derive(Debug, Clone, Copy, PartialEq, Default, Serialize, Deserialize)]
+#[serde(default)]
+pub struct RelationshipTuning {
+    /// Affection axis.
+    pub affection: AxisTuning,
+    /// Trust axis.
+    pub trust: AxisTuning,
+    /// Attraction axis.
+    pub attraction: AxisTuning,
+    /// Familiarity axis.
+    pub familiarity: AxisTuning,
+    /// Resentment axis.
+    pub resentment: AxisTuning,
+}
+
+impl RelationshipTuning {
+    /// Plain ±10 clamp on every axis.
+    pub const DEFAULT: Self = Self {
+        affection: AxisTuning::DEFAULT,
+        trust: AxisTuning::DEFAULT,
+        attraction: AxisTuning::DEFAULT,
+        familiarity: AxisTuning::DEFAULT,
+        resentment: AxisTuning::DEFAULT,
+    };
+
+    /// Tuning for one axis.
+    pub fn axis(&self, axis: RelationshipAxis) -> &AxisTuning {
+        match axis {
+            RelationshipAxis::Affection => &self.affection,
+            RelationshipAxis::Trust => &self.trust,
+            RelationshipAxis::Attraction => &self.attraction,
+            RelationshipAxis::Familiarity => &self.familiarity,
+            RelationshipAxis::Resentment => &self.resentment,
+        }
+    }
+}
+
+/// Tuning used by every `apply_delta`.
+static RELATIONSHIP_TUNING: RwLock<RelationshipTuning> = RwLock::new(RelationshipTuning::DEFAULT);
+
+/// The relationship tuning currently in effect.
+pub fn relationship_tuning() -> RelationshipTuning {
+    *RELATIONSHIP_TUNING
+        .read()
+        .unwrap_or_else(|poisoned| poisoned.into_inner())
+}
+
+/// Replace the relationship tuning used by every `apply_delta`.
+pub fn set_relationship_tuning(tuning: RelationshipTuning) {
+    *RELATIONSHIP_TUNING
+        .write()
+        .unwrap_or_else(|poisoned| poisoned.into_inner()) = tuning;
 }
 
 /// The 5 axes of the relationship model.
@@ -32,6 +157,18 @@ pub enum RelationshipAxis {
     Resentment,
 }
 
+impl From<crate::RelationshipAxis> for RelationshipAxis {
+    fn from(axis: crate::RelationshipAxis) -> Self {
+        match axis {
+            crate::RelationshipAxis::Affection => Self::Affection,
+            crate::RelationshipAxis::Trust => Self::Trust,
+            crate::RelationshipAxis::Attraction => Self::Attraction,
+            crate::RelationshipAxis::Familiarity => Self::Familiarity,
+            crate::RelationshipAxis::Resentment => Self::Resentment,
+        }
+    }
+}
+
 /// High-level relationship roles derived from axis combinations.
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
 pub enum RelationshipRole {
@@ -81,6 +218,18 @@ pub struct RelationshipVector {
     pub resentment: f32,
 }
 
+impl From<&crate::types::Relationship> for RelationshipVector {
+    fn from(rel: &crate::types::Relationship) -> Self {
+        RelationshipVector {
+            affection: rel.affection,
+            trust: rel.trust,
+            attraction: rel.attraction,
+            familiarity: rel.familiarity,
+            resentment: rel.resentment,
+        }
+    }
+}
+
 impl RelationshipVector {
     /// Get the value of a specific axis.
     pub fn get(&self, axis: RelationshipAxis) -> f32 {
@@ -93,9 +242,9 @@ impl RelationshipVector {
         }
     }
 
-    /// Set the value of a specific axis (clamped to -10..+10).
+    /// Set the value of a specific axis (clamped to the axis' tuned range).
     pub fn set(&mut self, axis: RelationshipAxis, value: f32) {
-        let clamped = clamp_axis(value);
+        let clamped = relationship_tuning().axis(axis).clamp(value);
         match axis {
             RelationshipAxis::Affection => self.affection = clamped,
             RelationshipAxis::Trust => self.trust = clamped,
@@ -105,9 +254,27 @@ impl RelationshipVector {
         }
     }
 
-    /// Apply a delta to a specific axis.
+    /// Apply a delta to a specific axis using the current [`RelationshipTuning`].
     pub fn apply_delta(&mut self, axis: RelationshipAxis, delta: f32) {
-        self.set(axis, self.get(axis) + delta);
+        self.apply_delta_with(axis, delta, &relationship_tuning());
+    }
+
+    /// Apply a delta to a specific axis using explicit tuning.
+    pub fn apply_delta_with(
+        &mut self,
+        axis: RelationshipAxis,
+        delta: f32,
+        tuning: &RelationshipTuning,
+    ) {
+        let tuned = tuning.axis(axis);
+        let value = tuned.apply(self.get(axis), delta);
+        match axis {
+            RelationshipAxis::Affection => self.affection = value,
+            RelationshipAxis::Trust => self.trust = value,
+            RelationshipAxis::Attraction => self.attraction = value,
+            RelationshipAxis::Familiarity => self.familiarity = value,
+            RelationshipAxis::Resentment => self.resentment = value,
+        }
     }
 
     /// Get the affection band for this vector.
@@ -3,6 +3,10 @@
 //! Tracks band transitions in relationships to trigger narrative events.
 //! When a relationship axis crosses a band threshold (e.g., Trust goes from
 //! "Wary" to "Trusted"), a pressure event is generated that storylets can react to.
+//!
+//! The state also stamps the tick each pair's relationship last changed, so
+//! the UI can poll only the pairs that changed since its last snapshot
+//! ([`RelationshipPressureState::pairs_changed_since`]).
 
 use serde::{Deserialize, Serialize};
 use std::collections::{HashMap, VecDeque};
@@ -10,6 +14,8 @@ use std::collections::{HashMap, VecDeque};
 use crate::relationship_model::{
     AffectionBand, AttractionBand, RelationshipVector, ResentmentBand, TrustBand,
 };
+use crate::relationships::RelationshipAxis;
+use crate::types::{NpcId, Relationship, WorldState};
 
 /// Snapshot of all relationship bands at a point in time.
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
@@ -50,6 +56,21 @@ pub enum RelationshipEventKind {
     ResentmentBandChanged,
 }
 
+impl RelationshipEventKind {
+    /// Eviction priority when the queue is full (higher survives longer).
+    ///
+    /// Resentment and trust shifts drive conflict and betrayal arcs, so they
+    /// outlive attraction and affection drift.
+    pub fn priority(self) -> u8 {
+        match self {
+            RelationshipEventKind::ResentmentBandChanged => 3,
+            RelationshipEventKind::TrustBandChanged => 2,
+            RelationshipEventKind::AttractionBandChanged => 1,
+            RelationshipEventKind::AffectionBandChanged => 0,
+        }
+    }
+}
+
 /// A relationship pressure event (band transition).
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
 pub struct RelationshipPressureEvent {
@@ -71,6 +92,71 @@ pub struct RelationshipPressureEvent {
     pub tick: Option<u64>,
 }
 
+/// Tick a pair's relationship last changed.
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq)]
+pub struct RelationshipChangeStamp {
+    /// NPC whose relationship changed.
+    pub actor_id: u64,
+    /// NPC the relationship is with.
+    pub target_id: u64,
+    /// Tick of the latest change.
+    pub tick: u64,
+}
+
+/// Capacity limits that keep pressure tracking bounded over long runs.
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq)]
+pub struct PressureQueueLimits {
+    /// Maximum pending events; the oldest lowest-priority event is evicted beyond this.
+    #[serde(default = "PressureQueueLimits::default_max_events")]
+    pub max_events: usize,
+    /// Maximum tracked changed pairs; the oldest pair is dropped beyond this.
+    #[serde(default = "PressureQueueLimits::default_max_changed_pairs")]
+    pub max_changed_pairs: usize,
+}
+
+impl PressureQueueLimits {
+    fn default_max_events() -> usize {
+        64
+    }
+
+    fn default_max_changed_pairs() -> usize {
+        256
+    }
+}
+
+impl Default for PressureQueueLimits {
+    fn default() -> Self {
+        Self {
+            max_events: Self::default_max_events(),
+            max_changed_pairs: Self::default_max_changed_pairs(),
+        }
+    }
+}
+
+/// Counters for events and pairs the state discarded to stay within its limits.
+#[derive(Debug, Clone, Copy, Default, Serialize, Deserialize, PartialEq, Eq)]
+pub struct PressureQueueMetrics {
+    /// Events evicted because the queue was over capacity.
+    #[serde(default)]
+    pub evicted_events: u64,
+    /// Events removed by `decay_queue` for being too old.
+    #[serde(default)]
+    pub expired_events: u64,
+    /// Events folded into an earlier event for the same pair, axis and tick.
+    #[serde(default)]
+    pub compacted_events: u64,
+    /// Changed pairs dropped because the list was over capacity.
+    #[serde(default)]
+    pub dropped_pairs: u64,
+}
+
+impl PressureQueueMetrics {
+    /// Total events discarded for any reason other than being consumed.
+    pub fn dropped_events(&self) -> u64 {
+        self.evicted_events + self.expired_events
+    }
+}
+
 /// State for tracking relationship pressure events.
 #[derive(Debug, Clone, Default, Serialize, Deserialize, PartialEq)]
 pub struct RelationshipPressureState {
@@ -85,6 +171,18 @@ pub struct RelationshipPressureState {
     /// Legacy/simple tracking of changed pairs (kept for compatibility with prior logic).
     #[serde(default)]
     pub changed_pairs: Vec<(u64, u64)>,
+
+    /// Capacity limits for `queue` and `changed_pairs`.
+    #[serde(default)]
+    pub limits: PressureQueueLimits,
+
+    /// Drop and compaction counters.
+    #[serde(default)]
+    pub metrics: PressureQueueMetrics,
+
+    /// Latest change tick per pair, sorted by `(actor_id, target_id)`.
+    #[serde(default)]
+    pub change_stamps: Vec<RelationshipChangeStamp>,
 }
 
 impl RelationshipPressureState {
@@ -103,9 +201,9 @@ impl RelationshipPressureState {
 
         let key = (actor_id, target_id);
 
-        if let Some(old_snapshot) = self.last_bands.get(&key) {
+        if let Some(old_snapshot) = self.last_bands.get(&key).cloned() {
             if old_snapshot.affection != new_snapshot.affection {
-                self.queue.push_back(RelationshipPressureEvent {
+                self.push_event(RelationshipPressureEvent {
                     actor_id,
                     target_id,
                     kind: AffectionBandChanged,
@@ -117,7 +215,7 @@ impl RelationshipPressureState {
             }
 
             if old_snapshot.trust != new_snapshot.trust {
-                self.queue.push_back(RelationshipPressureEvent {
+                self.push_event(RelationshipPressureEvent {
                     actor_id,
                     target_id,
                     kind: TrustBandChanged,
@@ -129,7 +227,7 @@ impl RelationshipPressureState {
             }
 
             if old_snapshot.attraction != new_snapshot.attraction {
-                self.queue.push_back(RelationshipPressureEvent {
+                self.push_event(RelationshipPressureEvent {
                     actor_id,
                     target_id,
                     kind: AttractionBandChanged,
@@ -141,7 +239,7 @@ impl RelationshipPressureState {
             }
 
             if old_snapshot.resentment != new_snapshot.resentment {
-                self.queue.push_back(RelationshipPressureEvent {
+                self.push_event(RelationshipPressureEvent {
                     actor_id,
                     target_id,
                     kind: ResentmentBandChanged,
@@ -154,13 +252,109 @@ impl RelationshipPressureState {
         }
 
         // Keep simple changed_pairs tracking for legacy consumers.
-        if !self.changed_pairs.contains(&key) {
-            self.changed_pairs.push(key);
+        self.mark_pair_changed(actor_id, target_id);
+        if let Some(tick) = tick {
+            self.stamp_change(actor_id, target_id, tick);
         }
 
         self.last_bands.insert(key, new_snapshot);
     }
 
+    /// Queue a band change event, compacting and enforcing `limits.max_events`.
+    ///
+    /// A second crossing on the same pair and axis within one tick is folded
+    /// into the pending event (keeping its original `old_band`); if the band
+    /// ends up where it started, the event is dropped entirely.
+    pub fn push_event(&mut self, event: RelationshipPressureEvent) {
+        if event.tick.is_some() {
+            if let Some(index) = self.queue.iter().position(|pending| {
+                pending.actor_id == event.actor_id
+                    && pending.target_id == event.target_id
+                    && pending.kind == event.kind
+                    && pending.tick == event.tick
+            }) {
+                self.metrics.compacted_events += 1;
+                if self.queue[index].old_band == event.new_band {
+                    self.queue.remove(index);
+                } else {
+                    let pending = &mut self.queue[index];
+                    pending.new_band = event.new_band;
+                    pending.source = event.source;
+                }
+                return;
+            }
+        }
+
+        self.queue.push_back(event);
+        self.enforce_event_limit(self.limits.max_events);
+    }
+
+    /// Record that a pair's relationship changed, enforcing `limits.max_changed_pairs`.
+    pub fn mark_pair_changed(&mut self, actor_id: u64, target_id: u64) {
+        let key = (actor_id, target_id);
+        if self.changed_pairs.contains(&key) {
+            return;
+        }
+        self.changed_pairs.push(key);
+        let max = self.limits.max_changed_pairs;
+        if self.changed_pairs.len() > max {
+            let excess = self.changed_pairs.len() - max;
+            self.changed_pairs.drain(..excess);
+            self.metrics.dropped_pairs += excess as u64;
+        }
+    }
+
+    /// Record that a pair's relationship changed at `tick`.
+    ///
+    /// One stamp is kept per pair; a stamp never moves back in time.
+    pub fn stamp_change(&mut self, actor_id: u64, target_id: u64, tick: u64) {
+        let key = (actor_id, target_id);
+        match self
+            .change_stamps
+            .binary_search_by_key(&key, |s| (s.actor_id, s.target_id))
+        {
+            Ok(index) => {
+                let stamp = &mut self.change_stamps[index];
+                stamp.tick = stamp.tick.max(tick);
+            }
+            Err(index) => self.change_stamps.insert(
+                index,
+                RelationshipChangeStamp {
+                    actor_id,
+                    target_id,
+                    tick,
+                },
+            ),
+        }
+    }
+
+    /// Pairs whose relationship changed at or after `since_tick`, in
+    /// `(actor_id, target_id)` order.
+    ///
+    /// Changes made during `since_tick` itself are included, so a caller
+    /// passing the tick of its last snapshot may see a pair again but never
+    /// misses one.
+    pub fn pairs_changed_since(&self, since_tick: u64) -> Vec<(u64, u64)> {
+        self.change_stamps
+            .iter()
+            .filter(|s| s.tick >= since_tick)
+            .map(|s| (s.actor_id, s.target_id))
+            .collect()
+    }
+
+    /// Evict the oldest lowest-priority events until at most `max_events` remain.
+    fn enforce_event_limit(&mut self, max_events: usize) {
+        while self.queue.len() > max_events {
+            let Some(lowest) = self.queue.iter().map(|e| e.kind.priority()).min() else {
+                break;
+            };
+            if let Some(index) = self.queue.iter().position(|e| e.kind.priority() == lowest) {
+                self.queue.remove(index);
+                self.metrics.evicted_events += 1;
+            }
+        }
+    }
+
     /// Pop the next pressure event from the queue.
     pub fn pop_next_event(&mut self) -> Option<RelationshipPressureEvent> {
         self.queue.pop_front()
@@ -178,17 +372,17 @@ impl RelationshipPressureState {
     /// # Arguments
     /// * `current_tick` - The current simulation tick
     /// * `max_age_ticks` - Events older than this are removed (default: 168 = 7 days)
-    /// * `max_queue_size` - Maximum events to keep (oldest dropped first)
+    /// * `max_queue_size` - Maximum events to keep (oldest lowest-priority dropped first)
     pub fn decay_queue(&mut self, current_tick: u64, max_age_ticks: u64, max_queue_size: usize) {
         // Remove events older than max_age_ticks
+        let before = self.queue.len();
         self.queue.retain(|event| {
             event.tick.map_or(true, |t| current_tick.saturating_sub(t) <= max_age_ticks)
         });
+        self.metrics.expired_events += (before - self.queue.len()) as u64;
 
-        // Enforce max queue size (drop oldest events)
-        while self.queue.len() > max_queue_size {
-            self.queue.pop_front();
-        }
+        // Enforce max queue size
+        self.enforce_event_limit(max_queue_size);
 
         // Also clean up stale changed_pairs (keep only recent pairs in queue)
         let active_pairs: std::collections::HashSet<(u64, u64)> = self
@@ -209,3 +403,39 @@ impl RelationshipPressureState {
         self.queue.len()
     }
 }
+
+/// Shift one axis of `from`'s relationship toward `to`, queueing pressure
+/// events attributed to `source` for any band the shift crosses.
+///
+/// For systemic reactions (jealousy, observers) that change a single pair
+/// outside the storylet outcome pipeline. Returns the updated relationship.
+pub fn shift_relationship_axis(
+    world: &mut WorldState,
+    from: NpcId,
+    to: NpcId,
+    axis: RelationshipAxis,
+    amount: f32,
+    source: &str,
+) -> Relationship {
+    let tick = world.current_tick.0;
+    let mut rel = world.get_relationship(from, to);
+    // Seed the snapshot for untracked pairs so the crossing is detected
+    world
+        .relationship_pressure
+        .last_bands
+        .entry((from.0, to.0))
+        .or_insert_with(|| {
+            RelationshipBandSnapshot::from_vector(&RelationshipVector::from(&rel))
+        });
+    rel.apply_delta(axis, amount);
+    rel.state = rel.compute_next_state();
+    world.set_relationship(from, to, rel);
+    world.relationship_pressure.update_for_pair(
+        from.0,
+        to.0,
+        &RelationshipVector::from(&rel),
+        Some(source.to_string()),
+        Some(tick),
+    );
+    rel
+}
@@ -24,6 +24,25 @@ pub enum RelationshipAxis {
     Resentment,
 }
 
+/// All relationship axes in canonical order.
+pub const ALL_RELATIONSHIP_AXES: [RelationshipAxis; 5] = [
+    RelationshipAxis::Affection,
+    RelationshipAxis::Trust,
+    RelationshipAxis::Attraction,
+    RelationshipAxis::Familiarity,
+    RelationshipAxis::Resentment,
+];
+
+impl RelationshipAxis {
+    /// Parse an axis from its content name (case-insensitive, e.g. "trust").
+    pub fn from_name(name: &str) -> Option<Self> {
+        ALL_RELATIONSHIP_AXES
+            .iter()
+            .copied()
+            .find(|axis| format!("{:?}", axis).eq_ignore_ascii_case(name.trim()))
+    }
+}
+
 /// 5-axis relationship vector.
 #[derive(Debug, Clone, Serialize, Deserialize, Default)]
 pub struct RelationshipVector {
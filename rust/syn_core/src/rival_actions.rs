@@ -0,0 +1,428 @@
+//! Hostile actions rival NPCs take against the player.
+//!
+//! Once a day, every NPC whose resentment toward the player has reached the
+//! rival band (`min_resentment`, the start of `ResentmentBand::Hostile` by
+//! default) may act against them:
+//! - spread a damaging rumor ([`RivalActionKind::SpreadGossip`]),
+//! - undermine the player's standing in the NPC's district
+//!   ([`RivalActionKind::SabotageReputation`]), or
+//! - confront the player directly ([`RivalActionKind::Confront`]).
+//!
+//! The chance to act grows with resentment and shrinks with empathy; which
+//! action is taken is weighted by the rival's personality. Each rival waits
+//! `cooldown_ticks` between actions.
+//!
+//! Every action is logged in [`RivalActionState::recent`] for the UI, leaves
+//! a player memory tagged [`RIVAL_ACTION_TAG`] and the action kind, and sets
+//! the dynamic world flag from [`rival_flag`] so revenge and reconciliation
+//! storylets can pick up the thread. Clearing the flag is up to that content.
+
+use std::collections::BTreeMap;
+
+use serde::{Deserialize, Serialize};
+
+use crate::flag_changes::{set_world_flag, FLAG_SOURCE_RIVAL};
+use crate::gossip::Rumor;
+use crate::moral_ledger::{ReputationDelta, ReputationScope};
+use crate::npc_mood::shift_mood;
+use crate::relationship_heat::record_relationship_interaction;
+use crate::relationships::RelationshipAxis;
+use crate::rng::DeterministicRng;
+use crate::types::{MemoryEntryRecord, NpcId, Relationship, Traits, WorldState};
+
+/// Tag added to rival action memories and rumors.
+pub const RIVAL_ACTION_TAG: &str = "rival_action";
+
+/// Prefix of the dynamic world flag set when a rival acts (`rival_acted:<npc_id>`).
+pub const RIVAL_FLAG_PREFIX: &str = "rival_acted:";
+
+/// Dynamic world flag marking that `npc_id` has acted against the player.
+pub fn rival_flag(npc_id: NpcId) -> String {
+    format!("{}{}", RIVAL_FLAG_PREFIX, npc_id.0)
+}
+
+/// What a rival does to the player.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum RivalActionKind {
+    /// Start a negative rumor about the player.
+    SpreadGossip,
+    /// Talk the player down in the rival's district.
+    SabotageReputation,
+    /// Confront the player face to face.
+    Confront,
+}
+
+impl RivalActionKind {
+    /// All kinds, in selection order.
+    pub const ALL: [RivalActionKind; 3] = [
+        RivalActionKind::SpreadGossip,
+        RivalActionKind::SabotageReputation,
+        RivalActionKind::Confront,
+    ];
+
+    /// Stable lowercase label for UI/FFI.
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            RivalActionKind::SpreadGossip => "spread_gossip",
+            RivalActionKind::SabotageReputation => "sabotage_reputation",
+            RivalActionKind::Confront => "confront",
+        }
+    }
+
+    /// How strongly a personality leans toward this action.
+    fn weight(&self, traits: &Traits) -> f32 {
+        let weight = match self {
+            RivalActionKind::SpreadGossip => traits.sociability / 100.0 + traits.charm / 200.0,
+            RivalActionKind::SabotageReputation => {
+                traits.ambition / 100.0 + (100.0 - traits.empathy) / 200.0
+            }
+            RivalActionKind::Confront => {
+                traits.impulsivity / 100.0 + (100.0 - traits.stability) / 200.0
+            }
+        };
+        weight.max(0.05)
+    }
+
+    /// Player's mood change when on the receiving end.
+    fn mood_delta(&self) -> f32 {
+        match self {
+            RivalActionKind::SpreadGossip => -0.5,
+            RivalActionKind::SabotageReputation => -1.0,
+            RivalActionKind::Confront => -1.5,
+        }
+    }
+
+    /// Emotional intensity of the player's memory.
+    fn intensity(&self) -> f32 {
+        match self {
+            RivalActionKind::SpreadGossip => -0.4,
+            RivalActionKind::SabotageReputation => -0.5,
+            RivalActionKind::Confront => -0.7,
+        }
+    }
+}
+
+/// Thresholds and pacing for rival actions.
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct RivalActionConfig {
+    /// NPC→player resentment at which an NPC counts as a rival.
+    pub min_resentment: f32,
+    /// Daily chance for a rival at `min_resentment` with average empathy.
+    pub base_chance: f32,
+    /// Ticks a rival waits between actions.
+    pub cooldown_ticks: u64,
+    /// Maximum actions taken across all rivals per day.
+    pub max_per_day: usize,
+    /// District reputation lost to sabotage.
+    pub sabotage_reputation: f32,
+    /// Reputation impact of a rival's rumor when believed.
+    pub gossip_reputation_impact: f32,
+    /// Logged actions kept for the UI.
+    pub max_recent: usize,
+}
+
+impl Default for RivalActionConfig {
+    fn default() -> Self {
+        Self {
+            min_resentment: 6.0,
+            base_chance: 0.15,
+            cooldown_ticks: 168,
+            max_per_day: 2,
+            sabotage_reputation: 3.0,
+            gossip_reputation_impact: -4.0,
+            max_recent: 20,
+        }
+    }
+}
+
+/// One hostile act, as shown to the player.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct RivalAction {
+    /// Action id (unique within a run).
+    pub id: u64,
+    /// The rival.
+    pub npc_id: NpcId,
+    /// What they did.
+    pub kind: RivalActionKind,
+    /// Tick it happened.
+    pub tick: u64,
+    /// Rival's resentment toward the player when they acted.
+    pub resentment: f32,
+}
+
+/// Rival action bookkeeping stored on `WorldState`.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct RivalActionState {
+    /// Thresholds and pacing.
+    #[serde(default)]
+    pub config: RivalActionConfig,
+    /// Most recent actions, oldest first.
+    #[serde(default)]
+    pub recent: Vec<RivalAction>,
+    /// Last tick each rival (by NPC id) acted.
+    #[serde(default)]
+    pub last_acted: BTreeMap<u64, u64>,
+    /// Next action id.
+    #[serde(default)]
+    next_id: u64,
+}
+
+impl RivalActionState {
+    /// The most recent action a given NPC took against the player.
+    pub fn last_by(&self, npc_id: NpcId) -> Option<&RivalAction> {
+        self.recent.iter().rev().find(|a| a.npc_id == npc_id)
+    }
+
+    fn cooling_down(&self, npc_id: NpcId, tick: u64) -> bool {
+        self.last_acted
+            .get(&npc_id.0)
+            .is_some_and(|last| tick.saturating_sub(*last) < self.config.cooldown_ticks)
+    }
+}
+
+/// Daily chance that a rival acts, from resentment and empathy.
+fn action_chance(config: &RivalActionConfig, resentment: f32, traits: &Traits) -> f32 {
+    let span = (10.0 - config.min_resentment).max(f32::EPSILON);
+    let intensity = 1.0 + ((resentment - config.min_resentment) / span).clamp(0.0, 1.0);
+    let restraint = 1.0 - traits.empathy.clamp(0.0, 100.0) / 200.0;
+    (config.base_chance * intensity * restraint).clamp(0.0, 1.0)
+}
+
+/// Pick an action kind by personality weight.
+fn choose_kind(traits: &Traits, rng: &mut DeterministicRng) -> RivalActionKind {
+    let weights = RivalActionKind::ALL.map(|kind| kind.weight(traits));
+    let mut roll = rng.gen_f32() * weights.iter().sum::<f32>();
+    for (kind, weight) in RivalActionKind::ALL.iter().zip(weights) {
+        if roll < weight {
+            return *kind;
+        }
+        roll -= weight;
+    }
+    RivalActionKind::Confront
+}
+
+/// Let rivals act against the player. Returns the actions taken.
+///
+/// Rivals are visited in id order with a day-seeded RNG so runs stay
+/// deterministic. Nothing happens while the player's content preferences
+/// block conflict.
+pub fn scan_for_rival_actions(world: &mut WorldState) -> Vec<RivalAction> {
+    let tick = world.current_tick.0;
+    let config = world.rival_actions.config;
+    if !world
+        .content_preferences
+        .allows(None, [RIVAL_ACTION_TAG, "conflict"])
+    {
+        return Vec::new();
+    }
+
+    let player = world.player_id;
+    let mut rivals: Vec<(NpcId, Relationship)> = world
+        .relationships
+        .iter()
+        .filter(|((from, to), rel)| {
+            *to == player && *from != player && rel.resentment >= config.min_resentment
+        })
+        .map(|((from, _), rel)| (*from, *rel))
+        .collect();
+    rivals.sort_by_key(|(id, _)| id.0);
+
+    let mut rng = DeterministicRng::with_domain(world.seed.0, tick, "rival_actions");
+    let mut taken = Vec::new();
+    for (npc_id, npc_view) in rivals {
+        if taken.len() >= config.max_per_day {
+            break;
+        }
+        if world.rival_actions.cooling_down(npc_id, tick) {
+            continue;
+        }
+        let traits = world
+            .npcs
+            .get(&npc_id)
+            .map(|npc| npc.traits)
+            .unwrap_or_default();
+        if !rng.gen_bool(action_chance(&config, npc_view.resentment, &traits)) {
+            continue;
+        }
+        let kind = choose_kind(&traits, &mut rng);
+        taken.push(perform_rival_action(
+            world,
+            npc_id,
+            kind,
+            npc_view.resentment,
+        ));
+    }
+    taken
+}
+
+/// Carry out one rival action and record it.
+fn perform_rival_action(
+    world: &mut WorldState,
+    npc_id: NpcId,
+    kind: RivalActionKind,
+    resentment: f32,
+) -> RivalAction {
+    let tick = world.current_tick.0;
+    let player = world.player_id;
+    let config = world.rival_actions.config;
+    let id = world.rival_actions.next_id;
+    world.rival_actions.next_id += 1;
+
+    match kind {
+        RivalActionKind::SpreadGossip => {
+            let rumor = Rumor::new(
+                format!("rival_gossip:{}", id),
+                format!("{}:{}", RIVAL_ACTION_TAG, kind.as_str()),
+                player,
+                npc_id,
+                tick,
+            )
+            .with_valence(-0.6)
+            .with_salience(0.6)
+            .with_reputation_impact(config.gossip_reputation_impact)
+            .with_tags(vec![RIVAL_ACTION_TAG.to_string()]);
+            world.gossip.create_rumor(rumor);
+        }
+        RivalActionKind::SabotageReputation => {
+            if let Some(district) = world.npcs.get(&npc_id).map(|npc| npc.district.clone()) {
+                world.moral_ledger.apply_reputation(&ReputationDelta {
+                    scope: ReputationScope::District(district),
+                    delta: -config.sabotage_reputation,
+                });
+            }
+        }
+        RivalActionKind::Confront => {
+            // Saying it out loud vents some of the rival's resentment and
+            // plants some in the player.
+            let mut npc_to_player = world.get_relationship(npc_id, player);
+            let mut player_to_npc = world.get_relationship(player, npc_id);
+            npc_to_player.apply_delta(RelationshipAxis::Resentment, -1.0);
+            npc_to_player.apply_delta(RelationshipAxis::Familiarity, 0.5);
+            player_to_npc.apply_delta(RelationshipAxis::Resentment, 1.0);
+            player_to_npc.apply_delta(RelationshipAxis::Familiarity, 0.5);
+            world.set_relationship(npc_id, player, npc_to_player);
+            world.set_relationship(player, npc_id, player_to_npc);
+            record_relationship_interaction(world, npc_id, player, 3.0);
+            world.add_heat(2.0);
+        }
+    }
+    shift_mood(world, player, kind.mood_delta());
+
+    world.memory_entries.push(MemoryEntryRecord {
+        id: format!("rival_action:{}", id),
+        event_id: format!("{}:{}", RIVAL_ACTION_TAG, kind.as_str()),
+        npc_id: player,
+        sim_tick: world.current_tick,
+        emotional_intensity: kind.intensity(),
+        tags: vec![
+            RIVAL_ACTION_TAG.to_string(),
+            kind.as_str().to_string(),
+            "conflict".to_string(),
+        ],
+        participants: vec![player.0, npc_id.0],
+        ..Default::default()
+    });
+    set_world_flag(world, &rival_flag(npc_id), true, FLAG_SOURCE_RIVAL);
+
+    let action = RivalAction {
+        id,
+        npc_id,
+        kind,
+        tick,
+        resentment,
+    };
+    let state = &mut world.rival_actions;
+    state.last_acted.insert(npc_id.0, tick);
+    state.recent.push(action.clone());
+    let overflow = state.recent.len().saturating_sub(config.max_recent);
+    state.recent.drain(..overflow);
+    action
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::content_preferences::ContentPreferences;
+    use crate::types::WorldSeed;
+
+    fn world_with_rival(resentment: f32) -> WorldState {
+        let mut world = WorldState::new(WorldSeed(7), NpcId(1));
+        world.rival_actions.config.base_chance = 1.0;
+        let rel = Relationship {
+            resentment,
+            ..Relationship::default()
+        };
+        world.set_relationship(NpcId(2), NpcId(1), rel);
+        world
+    }
+
+    #[test]
+    fn rivals_act_and_leave_hooks() {
+        let mut world = world_with_rival(9.0);
+        world.current_tick.0 = 24;
+        let actions = scan_for_rival_actions(&mut world);
+        assert_eq!(actions.len(), 1);
+        let action = &actions[0];
+        assert_eq!(action.npc_id, NpcId(2));
+
+        assert_eq!(world.rival_actions.last_by(NpcId(2)), Some(action));
+        assert!(world.world_flags.has_dynamic(&rival_flag(NpcId(2))));
+        let memory = world.memory_entries.last().unwrap();
+        assert!(memory.tags.contains(&RIVAL_ACTION_TAG.to_string()));
+        assert!(memory.tags.contains(&action.kind.as_str().to_string()));
+        assert_eq!(memory.participants, vec![1, 2]);
+
+        // The rival is cooling down the next day.
+        world.current_tick.0 = 48;
+        assert!(scan_for_rival_actions(&mut world).is_empty());
+        world.current_tick.0 = 24 + world.rival_actions.config.cooldown_ticks;
+        assert_eq!(scan_for_rival_actions(&mut world).len(), 1);
+    }
+
+    #[test]
+    fn mild_resentment_and_blocked_conflict_keep_rivals_quiet() {
+        let mut world = world_with_rival(5.0);
+        assert!(scan_for_rival_actions(&mut world).is_empty());
+
+        let mut world = world_with_rival(9.0);
+        let mut prefs = ContentPreferences::default();
+        prefs.blocked_tags.insert("conflict".to_string());
+        world.content_preferences = prefs;
+        assert!(scan_for_rival_actions(&mut world).is_empty());
+        assert!(world.rival_actions.recent.is_empty());
+    }
+
+    #[test]
+    fn personality_steers_the_action() {
+        let mut rng = DeterministicRng::new(3);
+        let gossip = Traits {
+            sociability: 100.0,
+            charm: 100.0,
+            ambition: 0.0,
+            empathy: 100.0,
+            impulsivity: 0.0,
+            stability: 100.0,
+            ..Traits::default()
+        };
+        let picks: Vec<RivalActionKind> = (0..50).map(|_| choose_kind(&gossip, &mut rng)).collect();
+        let gossip_picks = picks
+            .iter()
+            .filter(|k| **k == RivalActionKind::SpreadGossip)
+            .count();
+        assert!(gossip_picks > 35, "{}", gossip_picks);
+
+        let config = RivalActionConfig::default();
+        let kind = Traits {
+            empathy: 100.0,
+            ..Traits::default()
+        };
+        let cold = Traits {
+            empathy: 0.0,
+            ..Traits::default()
+        };
+        assert!(action_chance(&config, 6.0, &kind) < action_chance(&config, 6.0, &cold));
+        assert!(action_chance(&config, 10.0, &cold) > action_chance(&config, 6.0, &cold));
+    }
+}
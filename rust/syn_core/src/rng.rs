@@ -1,9 +1,135 @@
 //! Deterministic RNG using seeded ChaCha8 for reproducible simulation.
+//!
+//! Two kinds of randomness live here, kept apart by type:
+//!
+//! - [`DeterministicRng`]: simulation randomness. Anything that can change
+//!   world state draws from it, so a save replays identically.
+//! - [`CosmeticRng`]: presentation randomness (flavor text picks, portrait
+//!   variation). Obtained from [`WorldState::cosmetic_rng`], which borrows the
+//!   world immutably. It is deterministic per seed, tick and context, but it
+//!   exposes no seed, cannot be converted into a `DeterministicRng` and is
+//!   never persisted, so drawing from it can never move the simulation.
+//!
+//! While [`start_rng_recording`] is on, every domain stream (from
+//! [`DeterministicRng::with_domain`] or [`deterministic_rng_from_world`])
+//! counts its draws on a per-thread tape, read back with
+//! [`drain_rng_recording`]. Replay logs use the counts to confirm a re-run
+//! consumed randomness exactly like the original.
 
 use crate::WorldState;
 use rand::{Rng, SeedableRng};
 use rand_chacha::ChaCha8Rng;
 use serde::{Deserialize, Deserializer, Serialize, Serializer};
+use std::cell::RefCell;
+
+/// Draws taken from one domain stream at one tick while recording.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct RngDraws {
+    /// Tick the draws were taken at.
+    pub tick: u64,
+    /// Domain stream name.
+    pub domain: String,
+    /// Number of draws taken.
+    pub draws: u32,
+}
+
+#[derive(Default)]
+struct RngTape {
+    /// Bumped on every drain so streams opened before it start a new entry.
+    generation: u64,
+    streams: Vec<RngDraws>,
+}
+
+thread_local! {
+    static RNG_TAPE: RefCell<Option<RngTape>> = const { RefCell::new(None) };
+}
+
+/// Start counting domain stream draws on this thread, discarding any
+/// recording already in progress.
+pub fn start_rng_recording() {
+    RNG_TAPE.with(|tape| *tape.borrow_mut() = Some(RngTape::default()));
+}
+
+/// Stop counting draws on this thread.
+pub fn stop_rng_recording() {
+    RNG_TAPE.with(|tape| *tape.borrow_mut() = None);
+}
+
+/// Draws counted since recording started or since the last drain, in the
+/// order the streams were opened. Streams that never drew are left out and
+/// repeated (tick, domain) streams are merged. Empty when not recording.
+pub fn drain_rng_recording() -> Vec<RngDraws> {
+    RNG_TAPE.with(|tape| {
+        let mut tape = tape.borrow_mut();
+        let Some(tape) = tape.as_mut() else {
+            return Vec::new();
+        };
+        tape.generation += 1;
+        let mut merged: Vec<RngDraws> = Vec::new();
+        for stream in tape.streams.drain(..).filter(|stream| stream.draws > 0) {
+            match merged
+                .iter_mut()
+                .find(|m| m.tick == stream.tick && m.domain == stream.domain)
+            {
+                Some(existing) => existing.draws = existing.draws.saturating_add(stream.draws),
+                None => merged.push(stream),
+            }
+        }
+        merged
+    })
+}
+
+/// Where a recorded stream counts its draws.
+#[derive(Debug, Clone)]
+struct TapeSlot {
+    tick: u64,
+    domain: String,
+    generation: u64,
+    index: usize,
+}
+
+impl TapeSlot {
+    /// Open a tape entry for a new stream, if this thread is recording.
+    fn open(tick: u64, domain: &str) -> Option<Self> {
+        RNG_TAPE.with(|tape| {
+            let mut tape = tape.borrow_mut();
+            let tape = tape.as_mut()?;
+            tape.streams.push(RngDraws {
+                tick,
+                domain: domain.to_string(),
+                draws: 0,
+            });
+            Some(TapeSlot {
+                tick,
+                domain: domain.to_string(),
+                generation: tape.generation,
+                index: tape.streams.len() - 1,
+            })
+        })
+    }
+
+    fn note_draw(&mut self) {
+        RNG_TAPE.with(|tape| {
+            let mut tape = tape.borrow_mut();
+            let Some(tape) = tape.as_mut() else {
+                return;
+            };
+            if tape.generation != self.generation {
+                // Drained since this stream opened: count on a fresh entry.
+                tape.streams.push(RngDraws {
+                    tick: self.tick,
+                    domain: self.domain.clone(),
+                    draws: 0,
+                });
+                self.generation = tape.generation;
+                self.index = tape.streams.len() - 1;
+            }
+            if let Some(stream) = tape.streams.get_mut(self.index) {
+                stream.draws = stream.draws.saturating_add(1);
+            }
+        });
+    }
+}
 
 /// Wrapper around ChaCha8Rng for deterministic randomness.
 /// All randomness in SYN derives from seeded instances of this generator.
@@ -11,6 +137,8 @@ use serde::{Deserialize, Deserializer, Serialize, Serializer};
 pub struct DeterministicRng {
     inner: ChaCha8Rng,
     seed: u64,
+    /// Set for domain streams opened while recording.
+    tape: Option<TapeSlot>,
 }
 
 impl Serialize for DeterministicRng {
@@ -38,6 +166,7 @@ impl DeterministicRng {
         DeterministicRng {
             inner: ChaCha8Rng::seed_from_u64(seed),
             seed,
+            tape: None,
         }
     }
 
@@ -67,33 +196,49 @@ impl DeterministicRng {
             .wrapping_add(tick.wrapping_mul(0x85ebca6b))
             .wrapping_add(domain_hash);
 
-        Self::new(mixed)
+        Self::new(mixed).recorded_as(tick, domain)
+    }
+
+    fn recorded_as(mut self, tick: u64, domain: &str) -> Self {
+        self.tape = TapeSlot::open(tick, domain);
+        self
+    }
+
+    fn note_draw(&mut self) {
+        if let Some(slot) = self.tape.as_mut() {
+            slot.note_draw();
+        }
     }
 
     /// Generate a random u32.
     pub fn gen_u32(&mut self) -> u32 {
         use rand::Rng;
+        self.note_draw();
         self.inner.r#gen()
     }
 
     /// Generate a random u64.
     pub fn gen_u64(&mut self) -> u64 {
         use rand::Rng;
+        self.note_draw();
         self.inner.r#gen()
     }
 
     /// Generate a random f32 in range [0.0..1.0).
     pub fn gen_f32(&mut self) -> f32 {
+        self.note_draw();
         self.inner.gen_range(0.0..1.0)
     }
 
     /// Generate a random value in range [min..max).
     pub fn gen_range_i32(&mut self, min: i32, max: i32) -> i32 {
+        self.note_draw();
         self.inner.gen_range(min..max)
     }
 
     /// Generate a random value in range [min..max).
     pub fn gen_range_f32(&mut self, min: f32, max: f32) -> f32 {
+        self.note_draw();
         self.inner.gen_range(min..max)
     }
 
@@ -121,6 +266,62 @@ pub fn deterministic_rng_from_world(world: &WorldState) -> DeterministicRng {
         .tick_index
         .wrapping_mul(0x9E37_79B9_7F4A_7C15);
     DeterministicRng::new(world.seed.0 ^ mix)
+        .recorded_as(world.game_time.tick_index, "world_tick")
+}
+
+/// Presentation-only randomness; see the module docs.
+///
+/// Deliberately has no `Serialize`, no seed accessor and no way to derive a
+/// [`DeterministicRng`]: values drawn here must only ever reach the UI.
+#[derive(Debug, Clone)]
+pub struct CosmeticRng {
+    inner: ChaCha8Rng,
+}
+
+impl CosmeticRng {
+    /// Stream for `context` at a given seed and tick. Cosmetic streams are
+    /// mixed with their own constants, so they never coincide with a
+    /// simulation stream from [`DeterministicRng::with_domain`].
+    pub(crate) fn new(world_seed: u64, tick: u64, context: &str) -> Self {
+        let context_hash = context.bytes().fold(0xcbf2_9ce4_8422_2325u64, |acc, b| {
+            (acc ^ b as u64).wrapping_mul(0x0100_0000_01b3)
+        });
+        let mixed = (world_seed ^ 0xC05E_71C0_C05E_71C0)
+            .wrapping_mul(0xff51_afd7_ed55_8ccd)
+            .wrapping_add(tick.wrapping_mul(0xc4ce_b9fe_1a85_ec53))
+            ^ context_hash;
+        CosmeticRng {
+            inner: ChaCha8Rng::seed_from_u64(mixed),
+        }
+    }
+
+    /// Random value in [0.0..1.0).
+    pub fn gen_f32(&mut self) -> f32 {
+        self.inner.gen_range(0.0..1.0)
+    }
+
+    /// Random index in [0..len), or 0 when `len` is 0.
+    pub fn gen_index(&mut self, len: usize) -> usize {
+        if len == 0 {
+            0
+        } else {
+            self.inner.gen_range(0..len)
+        }
+    }
+
+    /// True with the given probability (0.0..1.0).
+    pub fn gen_bool(&mut self, probability: f32) -> bool {
+        self.gen_f32() < probability
+    }
+
+    /// Pick one item, e.g. a flavor line or portrait variant.
+    pub fn choose<'a, T>(&mut self, items: &'a [T]) -> Option<&'a T> {
+        if items.is_empty() {
+            None
+        } else {
+            items.get(self.gen_index(items.len()))
+        }
+    }
 }
 
 #[cfg(test)]
@@ -187,6 +388,25 @@ mod tests {
         assert_ne!(val1, val3);
     }
 
+    #[test]
+    fn cosmetic_streams_are_deterministic_and_disjoint_from_simulation() {
+        let world = WorldState::new(crate::WorldSeed(12345), crate::NpcId(1));
+
+        let mut a = world.cosmetic_rng("portrait");
+        let mut b = world.cosmetic_rng("portrait");
+        let mut c = world.cosmetic_rng("flavor_text");
+        let draw = |rng: &mut CosmeticRng| (0..20).map(|_| rng.gen_index(1000)).collect::<Vec<_>>();
+        let picks = draw(&mut a);
+        assert_eq!(picks, draw(&mut b));
+        assert_ne!(picks, draw(&mut c));
+        assert_eq!(c.choose::<u8>(&[]), None);
+
+        // Same seed, tick and name as a simulation domain: still a different stream.
+        let mut sim = DeterministicRng::with_domain(12345, world.current_tick.0, "portrait");
+        let mut cosmetic = CosmeticRng::new(12345, world.current_tick.0, "portrait");
+        assert_ne!(sim.gen_u32(), cosmetic.inner.r#gen::<u32>());
+    }
+
     #[test]
     fn test_different_ticks_differ() {
         let mut rng1 = DeterministicRng::with_domain(12345, 100, "tiers");
@@ -194,4 +414,41 @@ mod tests {
 
         assert_ne!(rng1.gen_u32(), rng2.gen_u32());
     }
+
+    #[test]
+    fn recording_counts_domain_draws_in_open_order() {
+        start_rng_recording();
+        let mut tiers = DeterministicRng::with_domain(7, 3, "tiers");
+        let _unused = DeterministicRng::with_domain(7, 3, "unused");
+        let mut raw = DeterministicRng::new(7);
+        tiers.gen_u32();
+        let _ = tiers.gen_bool(0.5);
+        raw.gen_u32();
+        let mut director = DeterministicRng::with_domain(7, 3, "director");
+        director.gen_range_i32(0, 4);
+
+        let draws = drain_rng_recording();
+        assert_eq!(
+            draws,
+            vec![
+                RngDraws {
+                    tick: 3,
+                    domain: "tiers".to_string(),
+                    draws: 2,
+                },
+                RngDraws {
+                    tick: 3,
+                    domain: "director".to_string(),
+                    draws: 1,
+                },
+            ]
+        );
+
+        // Streams opened before a drain keep counting afterwards.
+        tiers.gen_u64();
+        assert_eq!(drain_rng_recording()[0].draws, 1);
+        stop_rng_recording();
+        tiers.gen_u64();
+        assert!(drain_rng_recording().is_empty());
+    }
 }
@@ -0,0 +1,258 @@
+//! Session-aware pacing for short mobile play sessions.
+//!
+//! The UI reports `session_start` / `session_end` markers with its own clock
+//! (seconds). Each closed session is remembered as how many real minutes it
+//! lasted and how many ticks the player advanced in it. From that history the
+//! director estimates how long the open session will run, in ticks, and where
+//! the player is in it:
+//!
+//! - **Prime** (the first [`SessionPacingConfig::prime_share`] of the
+//!   expected length): high-heat storylets get a score bonus, so the best
+//!   content lands while the player is settled in.
+//! - **Late**: neutral.
+//! - **Wind-down** (the last [`SessionPacingConfig::wind_down_minutes`]):
+//!   critical beats are scored down so they don't fire just before the app
+//!   is backgrounded.
+//!
+//! Session position is measured in ticks advanced since the start marker, so
+//! selection depends only on the markers and the simulation, never on a clock
+//! read during a tick. Without an open session the multiplier is 1.0.
+
+use std::collections::VecDeque;
+
+use serde::{Deserialize, Serialize};
+
+/// Closed sessions kept for estimating session length and tick rate.
+pub const SESSION_HISTORY_LEN: usize = 10;
+
+/// Tuning for session-aware pacing.
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct SessionPacingConfig {
+    /// Expected session length before any session has been recorded.
+    pub expected_minutes: f32,
+    /// Ticks the player advances per real minute before any session has
+    /// been recorded.
+    pub default_ticks_per_minute: f32,
+    /// Share of the expected length that counts as prime time.
+    pub prime_share: f32,
+    /// Minutes before the expected end in which critical beats are avoided.
+    pub wind_down_minutes: f32,
+    /// Score bonus for a max-heat storylet during prime time.
+    pub prime_heat_bonus: f32,
+    /// Score multiplier for critical beats during wind-down.
+    pub wind_down_critical_multiplier: f32,
+    /// Heat share (0..1) at which a storylet counts as a critical beat.
+    pub critical_heat_share: f32,
+}
+
+impl Default for SessionPacingConfig {
+    fn default() -> Self {
+        Self {
+            expected_minutes: 12.0,
+            default_ticks_per_minute: 1.0,
+            prime_share: 0.6,
+            wind_down_minutes: 3.0,
+            prime_heat_bonus: 0.4,
+            wind_down_critical_multiplier: 0.1,
+            critical_heat_share: 0.8,
+        }
+    }
+}
+
+/// A closed play session.
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub struct SessionRecord {
+    /// Real minutes between the start and end markers.
+    pub minutes: f32,
+    /// Ticks advanced during the session.
+    pub ticks: u64,
+}
+
+/// The session currently open.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub struct OpenSession {
+    /// UI clock at the start marker, in seconds.
+    pub started_at_secs: u64,
+    /// World tick at the start marker.
+    pub started_tick: u64,
+}
+
+/// Where the player is in the open session.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum SessionPhase {
+    /// No session is open; pacing is unaffected.
+    Offline,
+    /// Early-to-mid session: high-heat storylets are preferred.
+    Prime,
+    /// Past prime time, before wind-down.
+    Late,
+    /// Close to the expected end: critical beats are avoided.
+    WindDown,
+}
+
+impl SessionPhase {
+    /// Stable lowercase name for UI and logs.
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            SessionPhase::Offline => "offline",
+            SessionPhase::Prime => "prime",
+            SessionPhase::Late => "late",
+            SessionPhase::WindDown => "wind_down",
+        }
+    }
+}
+
+/// Session markers and recent session history.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct SessionPacingState {
+    /// Tuning.
+    #[serde(default)]
+    pub config: SessionPacingConfig,
+    /// The open session, if any.
+    #[serde(default)]
+    pub open: Option<OpenSession>,
+    /// Recently closed sessions, oldest first.
+    #[serde(default)]
+    pub history: VecDeque<SessionRecord>,
+}
+
+impl SessionPacingState {
+    /// Open a session. A session left open (no end marker) is discarded
+    /// rather than recorded, since its length is unknown.
+    pub fn start(&mut self, at_secs: u64, tick: u64) {
+        self.open = Some(OpenSession {
+            started_at_secs: at_secs,
+            started_tick: tick,
+        });
+    }
+
+    /// Close the open session and record it.
+    ///
+    /// Returns the record, or `None` if no session was open or the end
+    /// marker is not after the start.
+    pub fn end(&mut self, at_secs: u64, tick: u64) -> Option<SessionRecord> {
+        let open = self.open.take()?;
+        if at_secs <= open.started_at_secs {
+            return None;
+        }
+        let record = SessionRecord {
+            minutes: (at_secs - open.started_at_secs) as f32 / 60.0,
+            ticks: tick.saturating_sub(open.started_tick),
+        };
+        if self.history.len() >= SESSION_HISTORY_LEN {
+            self.history.pop_front();
+        }
+        self.history.push_back(record);
+        Some(record)
+    }
+
+    /// Ticks advanced per real minute across recorded sessions.
+    pub fn ticks_per_minute(&self) -> f32 {
+        let minutes: f32 = self.history.iter().map(|s| s.minutes).sum();
+        let ticks: u64 = self.history.iter().map(|s| s.ticks).sum();
+        if minutes > 0.0 && ticks > 0 {
+            ticks as f32 / minutes
+        } else {
+            self.config.default_ticks_per_minute
+        }
+    }
+
+    /// Expected session length in minutes: the recorded average, or the
+    /// configured default.
+    pub fn expected_minutes(&self) -> f32 {
+        if self.history.is_empty() {
+            return self.config.expected_minutes;
+        }
+        self.history.iter().map(|s| s.minutes).sum::<f32>() / self.history.len() as f32
+    }
+
+    /// Where the player is in the open session at `tick`.
+    pub fn phase(&self, tick: u64) -> SessionPhase {
+        let Some(open) = self.open else {
+            return SessionPhase::Offline;
+        };
+        let rate = self.ticks_per_minute();
+        let expected = self.expected_minutes() * rate;
+        let elapsed = tick.saturating_sub(open.started_tick) as f32;
+        if expected - elapsed <= self.config.wind_down_minutes * rate {
+            SessionPhase::WindDown
+        } else if elapsed < expected * self.config.prime_share {
+            SessionPhase::Prime
+        } else {
+            SessionPhase::Late
+        }
+    }
+
+    /// Director score multiplier for a storylet at `tick`.
+    ///
+    /// `heat_share` is the storylet's heat scaled to 0..1; `critical` marks
+    /// beats authored as critical regardless of heat.
+    pub fn score_multiplier(&self, tick: u64, heat_share: f32, critical: bool) -> f32 {
+        let heat_share = heat_share.clamp(0.0, 1.0);
+        match self.phase(tick) {
+            SessionPhase::Offline | SessionPhase::Late => 1.0,
+            SessionPhase::Prime => 1.0 + self.config.prime_heat_bonus * heat_share,
+            SessionPhase::WindDown => {
+                if critical || heat_share >= self.config.critical_heat_share {
+                    self.config.wind_down_critical_multiplier
+                } else {
+                    1.0
+                }
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn phases_follow_recorded_session_lengths() {
+        let mut state = SessionPacingState::default();
+        assert_eq!(state.phase(0), SessionPhase::Offline);
+
+        // Two 10-minute sessions of 20 ticks each: 2 ticks a minute
+        state.start(0, 0);
+        state.end(600, 20);
+        state.start(1000, 20);
+        state.end(1600, 40);
+        assert!((state.ticks_per_minute() - 2.0).abs() < 1e-6);
+        assert!((state.expected_minutes() - 10.0).abs() < 1e-6);
+
+        state.start(5000, 100);
+        assert_eq!(state.phase(105), SessionPhase::Prime);
+        assert_eq!(state.phase(112), SessionPhase::Late);
+        // Wind-down is the last 3 minutes, 6 ticks, of a 20-tick session
+        assert_eq!(state.phase(114), SessionPhase::WindDown);
+        assert_eq!(state.phase(130), SessionPhase::WindDown);
+    }
+
+    #[test]
+    fn critical_beats_are_held_back_only_in_wind_down() {
+        let mut state = SessionPacingState::default();
+        assert!((state.score_multiplier(0, 1.0, true) - 1.0).abs() < 1e-6);
+
+        state.start(0, 0);
+        let config = state.config;
+        assert!((state.score_multiplier(1, 1.0, false) - (1.0 + config.prime_heat_bonus)).abs() < 1e-6);
+        assert!((state.score_multiplier(1, 0.0, false) - 1.0).abs() < 1e-6);
+
+        // Default length 12 minutes at 1 tick a minute; wind-down from tick 9
+        assert!(
+            (state.score_multiplier(10, 0.9, false) - config.wind_down_critical_multiplier).abs()
+                < 1e-6
+        );
+        assert!(
+            (state.score_multiplier(10, 0.2, true) - config.wind_down_critical_multiplier).abs()
+                < 1e-6
+        );
+        assert!((state.score_multiplier(10, 0.2, false) - 1.0).abs() < 1e-6);
+
+        // Ending without a later clock reading records nothing
+        assert!(state.end(0, 10).is_none());
+        assert!(state.history.is_empty());
+        assert_eq!(state.phase(10), SessionPhase::Offline);
+    }
+}
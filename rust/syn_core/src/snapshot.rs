@@ -330,7 +330,7 @@ impl WorldSnapshot {
             .times_fired
             .iter()
             .map(|(id, &count)| SnapshotStoryletUsage {
-                storylet_id: id.clone(),
+                storylet_id: id.to_string(),
                 count,
                 last_tick: 0, // times_fired doesn't track last tick
             })
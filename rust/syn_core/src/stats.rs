@@ -1,13 +1,32 @@
-//! Stats system: player and NPC stats, clamp helpers, and bands.
+//! Stats system: player and NPC stats, clamp helpers, bands, and crisis
+//! detection.
 //!
 //! Stats are clamped to valid ranges:
 //! - Most stats: 0-100
 //! - Mood: -10 to +10
 //! - Reputation: -100 to +100
 //! - Karma: -100 to +100
+//!
+//! # Stat crises
+//!
+//! When the player's health or mood falls to a crisis threshold, a
+//! [`StatCrisis`] opens and stays open until the stat recovers past a
+//! separate, higher exit threshold. The gap between the two keeps a stat that
+//! hovers around one line from flapping in and out of crisis.
+//!
+//! An open crisis is a trigger context for the director. Storylets whose
+//! trigger kind is `stat_crisis:health` or `stat_crisis:mood` (a friend
+//! checking in, a collapse) become eligible and are prioritized. One must
+//! fire within `response_window_ticks`; past that, the director considers
+//! nothing else. After an intervention, a crisis that is still open asks for
+//! another one `recheck_interval_ticks` later.
+
+use std::collections::VecDeque;
 
 use serde::{Deserialize, Serialize};
 
+use crate::types::WorldState;
+
 /// Authoritative stat kinds for all systems.
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
 pub enum StatKind {
@@ -35,6 +54,16 @@ pub enum StatKind {
     Libido,
 }
 
+impl StatKind {
+    /// Parse a stat kind from its content name (case-insensitive, e.g. "mood").
+    pub fn from_name(name: &str) -> Option<Self> {
+        ALL_STAT_KINDS
+            .iter()
+            .copied()
+            .find(|kind| format!("{:?}", kind).eq_ignore_ascii_case(name.trim()))
+    }
+}
+
 /// Ordered list of all stat kinds.
 pub const ALL_STAT_KINDS: [StatKind; 11] = [
     StatKind::Health,
@@ -119,3 +148,326 @@ pub fn apply_stat_deltas(stats: &mut crate::Stats, deltas: &[StatDelta]) {
         stats.apply_delta(d.kind, d.delta);
     }
 }
+
+/// Trigger kind prefix for storylets that respond to a stat crisis.
+pub const STAT_CRISIS_TRIGGER_PREFIX: &str = "stat_crisis:";
+
+/// Whether a storylet trigger kind responds to a stat crisis.
+pub fn is_stat_crisis_trigger(kind: &str) -> bool {
+    kind.starts_with(STAT_CRISIS_TRIGGER_PREFIX)
+}
+
+/// Player stats watched for crises.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord, Serialize, Deserialize)]
+pub enum CrisisStat {
+    /// Health collapsing.
+    Health,
+    /// Mood bottoming out.
+    Mood,
+}
+
+impl CrisisStat {
+    /// Every watched stat.
+    pub const ALL: [CrisisStat; 2] = [CrisisStat::Health, CrisisStat::Mood];
+
+    /// The underlying stat.
+    pub fn kind(self) -> StatKind {
+        match self {
+            Self::Health => StatKind::Health,
+            Self::Mood => StatKind::Mood,
+        }
+    }
+
+    /// Content name (`health`, `mood`).
+    pub fn name(self) -> &'static str {
+        match self {
+            Self::Health => "health",
+            Self::Mood => "mood",
+        }
+    }
+
+    /// Trigger kind intervention storylets name to respond to this crisis.
+    pub fn trigger_kind(self) -> String {
+        format!("{}{}", STAT_CRISIS_TRIGGER_PREFIX, self.name())
+    }
+
+    /// The stat named by a `stat_crisis:<stat>` trigger kind.
+    pub fn from_trigger_kind(kind: &str) -> Option<Self> {
+        let name = kind.strip_prefix(STAT_CRISIS_TRIGGER_PREFIX)?;
+        Self::ALL
+            .into_iter()
+            .find(|stat| stat.name().eq_ignore_ascii_case(name))
+    }
+}
+
+/// Entry and exit thresholds for one stat.
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub struct CrisisBand {
+    /// A crisis opens when the stat falls to this value or below.
+    pub enter_at: f32,
+    /// An open crisis ends once the stat rises above this value.
+    pub exit_above: f32,
+}
+
+/// Tuning for stat crisis detection and the director's response.
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct StatCrisisConfig {
+    /// Whether crises are detected at all.
+    pub enabled: bool,
+    /// Health thresholds (0-100).
+    pub health: CrisisBand,
+    /// Mood thresholds (-10 to +10).
+    pub mood: CrisisBand,
+    /// Most ticks a crisis waits for an intervention before the director
+    /// considers nothing else.
+    pub response_window_ticks: u64,
+    /// Ticks after an intervention before a crisis that is still open asks
+    /// for another.
+    pub recheck_interval_ticks: u64,
+    /// Ended crises kept for the UI.
+    pub max_history: usize,
+}
+
+impl Default for StatCrisisConfig {
+    fn default() -> Self {
+        Self {
+            enabled: true,
+            health: CrisisBand {
+                enter_at: 20.0,
+                exit_above: 35.0,
+            },
+            mood: CrisisBand {
+                enter_at: -6.0,
+                exit_above: -3.0,
+            },
+            response_window_ticks: 12,
+            recheck_interval_ticks: 72,
+            max_history: 20,
+        }
+    }
+}
+
+impl StatCrisisConfig {
+    /// Thresholds for `stat`.
+    pub fn band(&self, stat: CrisisStat) -> CrisisBand {
+        match stat {
+            CrisisStat::Health => self.health,
+            CrisisStat::Mood => self.mood,
+        }
+    }
+}
+
+/// An open crisis.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct StatCrisis {
+    /// Stat in crisis.
+    pub stat: CrisisStat,
+    /// Tick the crisis opened.
+    pub started_tick: u64,
+    /// Stat value when it opened.
+    pub value_at_start: f32,
+    /// First tick at which the crisis wants an intervention.
+    pub response_from_tick: u64,
+    /// Tick by which an intervention must have fired.
+    pub due_tick: u64,
+    /// Interventions fired so far.
+    pub interventions: u32,
+}
+
+impl StatCrisis {
+    /// Whether the crisis wants an intervention at `tick`.
+    pub fn awaiting_response(&self, tick: u64) -> bool {
+        tick >= self.response_from_tick
+    }
+
+    /// Whether the response window has run out at `tick`.
+    pub fn overdue(&self, tick: u64) -> bool {
+        tick >= self.due_tick
+    }
+}
+
+/// A crisis that has ended.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct ResolvedCrisis {
+    /// Stat that was in crisis.
+    pub stat: CrisisStat,
+    /// Tick it opened.
+    pub started_tick: u64,
+    /// Tick the stat recovered.
+    pub ended_tick: u64,
+    /// Interventions fired while it was open.
+    pub interventions: u32,
+}
+
+/// A crisis opening or ending.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum CrisisTransition {
+    /// The stat fell to its crisis threshold.
+    Entered(CrisisStat),
+    /// The stat recovered past its exit threshold.
+    Exited(CrisisStat),
+}
+
+/// Stat crisis tuning, open crises and history, stored on `WorldState`.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct StatCrisisState {
+    /// Tuning.
+    #[serde(default)]
+    pub config: StatCrisisConfig,
+    /// Open crises, in the order they opened.
+    #[serde(default)]
+    pub active: Vec<StatCrisis>,
+    /// Ended crises, oldest first.
+    #[serde(default)]
+    pub history: VecDeque<ResolvedCrisis>,
+}
+
+impl StatCrisisState {
+    /// Open and close crises for the player's current `stats`.
+    pub fn update(&mut self, stats: &crate::Stats, tick: u64) -> Vec<CrisisTransition> {
+        if !self.config.enabled {
+            return Vec::new();
+        }
+        let mut transitions = Vec::new();
+        for stat in CrisisStat::ALL {
+            let value = stats.get(stat.kind());
+            let band = self.config.band(stat);
+            match self.active.iter().position(|c| c.stat == stat) {
+                None if value <= band.enter_at => {
+                    self.active.push(StatCrisis {
+                        stat,
+                        started_tick: tick,
+                        value_at_start: value,
+                        response_from_tick: tick,
+                        due_tick: tick + self.config.response_window_ticks,
+                        interventions: 0,
+                    });
+                    transitions.push(CrisisTransition::Entered(stat));
+                }
+                Some(index) if value > band.exit_above => {
+                    let crisis = self.active.remove(index);
+                    self.history.push_back(ResolvedCrisis {
+                        stat,
+                        started_tick: crisis.started_tick,
+                        ended_tick: tick,
+                        interventions: crisis.interventions,
+                    });
+                    transitions.push(CrisisTransition::Exited(stat));
+                }
+                _ => {}
+            }
+        }
+        while self.history.len() > self.config.max_history {
+            self.history.pop_front();
+        }
+        transitions
+    }
+
+    /// The open crisis for `stat`, if any.
+    pub fn crisis(&self, stat: CrisisStat) -> Option<&StatCrisis> {
+        self.active.iter().find(|c| c.stat == stat)
+    }
+
+    /// The open crisis a storylet with this trigger kind can respond to at
+    /// `tick`.
+    pub fn awaiting_response(&self, trigger_kind: &str, tick: u64) -> Option<&StatCrisis> {
+        let stat = CrisisStat::from_trigger_kind(trigger_kind)?;
+        self.crisis(stat).filter(|c| c.awaiting_response(tick))
+    }
+
+    /// The open crisis whose response window ran out first, if any has at
+    /// `tick`.
+    pub fn overdue(&self, tick: u64) -> Option<&StatCrisis> {
+        self.active
+            .iter()
+            .filter(|c| c.overdue(tick))
+            .min_by_key(|c| (c.due_tick, c.stat))
+    }
+
+    /// Record an intervention for the crisis this trigger kind responds to,
+    /// restarting its window after the recheck interval. Returns whether a
+    /// crisis was waiting.
+    pub fn respond(&mut self, trigger_kind: &str, tick: u64) -> bool {
+        let Some(stat) = CrisisStat::from_trigger_kind(trigger_kind) else {
+            return false;
+        };
+        let config = self.config;
+        let Some(crisis) = self
+            .active
+            .iter_mut()
+            .find(|c| c.stat == stat && c.awaiting_response(tick))
+        else {
+            return false;
+        };
+        crisis.interventions += 1;
+        crisis.response_from_tick = tick + config.recheck_interval_ticks;
+        crisis.due_tick = crisis.response_from_tick + config.response_window_ticks;
+        true
+    }
+}
+
+/// Open and close the player's stat crises for this tick.
+pub fn tick_stat_crisis(world: &mut WorldState) -> Vec<CrisisTransition> {
+    let tick = world.current_tick.0;
+    world.stat_crisis.update(&world.player_stats, tick)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Stats;
+
+    #[test]
+    fn crises_open_and_close_with_hysteresis() {
+        let mut state = StatCrisisState::default();
+        let mut stats = Stats {
+            mood: -5.0,
+            ..Default::default()
+        };
+        assert!(state.update(&stats, 1).is_empty());
+
+        stats.mood = -7.0;
+        assert_eq!(
+            state.update(&stats, 2),
+            vec![CrisisTransition::Entered(CrisisStat::Mood)]
+        );
+        // Recovering past the entry line is not enough
+        stats.mood = -4.0;
+        assert!(state.update(&stats, 3).is_empty());
+        stats.mood = -6.5;
+        assert!(state.update(&stats, 4).is_empty());
+        assert_eq!(state.crisis(CrisisStat::Mood).map(|c| c.started_tick), Some(2));
+
+        stats.mood = -2.0;
+        assert_eq!(
+            state.update(&stats, 5),
+            vec![CrisisTransition::Exited(CrisisStat::Mood)]
+        );
+        assert!(state.active.is_empty());
+        assert_eq!(state.history.len(), 1);
+    }
+
+    #[test]
+    fn interventions_restart_the_window() {
+        let mut state = StatCrisisState::default();
+        let stats = Stats {
+            health: 10.0,
+            ..Default::default()
+        };
+        state.update(&stats, 100);
+        let window = state.config.response_window_ticks;
+        assert!(state.awaiting_response("stat_crisis:health", 100).is_some());
+        assert!(state.awaiting_response("stat_crisis:mood", 100).is_none());
+        assert!(state.overdue(100 + window - 1).is_none());
+        assert!(state.overdue(100 + window).is_some());
+
+        assert!(state.respond("stat_crisis:health", 105));
+        assert!(!state.respond("stat_crisis:health", 106));
+        let recheck = 105 + state.config.recheck_interval_ticks;
+        assert!(state.awaiting_response("stat_crisis:health", recheck - 1).is_none());
+        assert!(state.awaiting_response("stat_crisis:health", recheck).is_some());
+        assert!(state.overdue(recheck + window).is_some());
+        assert_eq!(state.crisis(CrisisStat::Health).map(|c| c.interventions), Some(1));
+    }
+}
@@ -2,9 +2,21 @@
 //!
 //! Manages game time progression with 24 ticks per day (1 tick = 1 in-game hour).
 //! Provides day phases for NPC schedules and narrative pacing.
+//!
+//! The calendar runs on 365-day years of twelve months. It is anchored to
+//! the player's birthday, so the player ages exactly on the day the calendar
+//! says is their birthday. Every birthday is derived from the world seed
+//! and NPC id ([`birthday_day_of_year`]); nothing about the calendar is
+//! stored. Once a day [`update_calendar_flags`] raises dynamic world flags
+//! for the season, month, holiday and birthdays so storylets can gate on
+//! them (see [`CALENDAR_FLAG_PREFIXES`]).
 
 use serde::{Deserialize, Serialize};
 
+use crate::flag_changes::{set_world_flag, FLAG_SOURCE_CALENDAR};
+use crate::rng::DeterministicRng;
+use crate::types::{NpcId, WorldState};
+
 /// Coarse-grained day phase, used for schedules and narrative pacing.
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
 pub enum DayPhase {
@@ -103,6 +115,339 @@ impl GameTime {
     }
 }
 
+/// Days in a calendar year.
+pub const DAYS_PER_YEAR: u16 = 365;
+
+/// Calendar month.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum Month {
+    /// 31 days.
+    January,
+    /// 28 days; there are no leap years.
+    February,
+    /// 31 days.
+    March,
+    /// 30 days.
+    April,
+    /// 31 days.
+    May,
+    /// 30 days.
+    June,
+    /// 31 days.
+    July,
+    /// 31 days.
+    August,
+    /// 30 days.
+    September,
+    /// 31 days.
+    October,
+    /// 30 days.
+    November,
+    /// 31 days.
+    December,
+}
+
+impl Month {
+    /// All months in calendar order.
+    pub const ALL: [Month; 12] = [
+        Month::January,
+        Month::February,
+        Month::March,
+        Month::April,
+        Month::May,
+        Month::June,
+        Month::July,
+        Month::August,
+        Month::September,
+        Month::October,
+        Month::November,
+        Month::December,
+    ];
+
+    /// Number of days in this month.
+    pub fn days(&self) -> u8 {
+        match self {
+            Month::February => 28,
+            Month::April | Month::June | Month::September | Month::November => 30,
+            _ => 31,
+        }
+    }
+
+    /// Season the month falls in.
+    pub fn season(&self) -> Season {
+        match self {
+            Month::December | Month::January | Month::February => Season::Winter,
+            Month::March | Month::April | Month::May => Season::Spring,
+            Month::June | Month::July | Month::August => Season::Summer,
+            Month::September | Month::October | Month::November => Season::Autumn,
+        }
+    }
+
+    /// Stable lowercase label for flags/UI.
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Month::January => "january",
+            Month::February => "february",
+            Month::March => "march",
+            Month::April => "april",
+            Month::May => "may",
+            Month::June => "june",
+            Month::July => "july",
+            Month::August => "august",
+            Month::September => "september",
+            Month::October => "october",
+            Month::November => "november",
+            Month::December => "december",
+        }
+    }
+}
+
+/// Season of the year.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum Season {
+    /// March to May.
+    Spring,
+    /// June to August.
+    Summer,
+    /// September to November.
+    Autumn,
+    /// December to February.
+    Winter,
+}
+
+impl Season {
+    /// Stable lowercase label for flags/UI.
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Season::Spring => "spring",
+            Season::Summer => "summer",
+            Season::Autumn => "autumn",
+            Season::Winter => "winter",
+        }
+    }
+}
+
+/// Named holidays on fixed dates.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum Holiday {
+    /// January 1.
+    NewYear,
+    /// February 14.
+    Valentines,
+    /// June 21.
+    Midsummer,
+    /// October 31.
+    Halloween,
+    /// December 25.
+    WinterHoliday,
+    /// December 31.
+    NewYearsEve,
+}
+
+impl Holiday {
+    /// All holidays in calendar order.
+    pub const ALL: [Holiday; 6] = [
+        Holiday::NewYear,
+        Holiday::Valentines,
+        Holiday::Midsummer,
+        Holiday::Halloween,
+        Holiday::WinterHoliday,
+        Holiday::NewYearsEve,
+    ];
+
+    /// Month and day the holiday falls on.
+    pub fn date(&self) -> (Month, u8) {
+        match self {
+            Holiday::NewYear => (Month::January, 1),
+            Holiday::Valentines => (Month::February, 14),
+            Holiday::Midsummer => (Month::June, 21),
+            Holiday::Halloween => (Month::October, 31),
+            Holiday::WinterHoliday => (Month::December, 25),
+            Holiday::NewYearsEve => (Month::December, 31),
+        }
+    }
+
+    /// Stable lowercase label for flags/UI.
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Holiday::NewYear => "new_year",
+            Holiday::Valentines => "valentines",
+            Holiday::Midsummer => "midsummer",
+            Holiday::Halloween => "halloween",
+            Holiday::WinterHoliday => "winter_holiday",
+            Holiday::NewYearsEve => "new_years_eve",
+        }
+    }
+}
+
+/// A day on the calendar.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub struct CalendarDate {
+    /// Month of the year.
+    pub month: Month,
+    /// Day of the month (1-based).
+    pub day: u8,
+    /// Day of the year (0-based, 0 = January 1).
+    pub day_of_year: u16,
+}
+
+impl CalendarDate {
+    /// The date for a 0-based day of the year (wraps past the year's end).
+    pub fn from_day_of_year(day_of_year: u16) -> Self {
+        let day_of_year = day_of_year % DAYS_PER_YEAR;
+        let mut remaining = day_of_year;
+        for month in Month::ALL {
+            let days = u16::from(month.days());
+            if remaining < days {
+                return Self {
+                    month,
+                    day: remaining as u8 + 1,
+                    day_of_year,
+                };
+            }
+            remaining -= days;
+        }
+        unreachable!("month lengths sum to DAYS_PER_YEAR")
+    }
+
+    /// Season the date falls in.
+    pub fn season(&self) -> Season {
+        self.month.season()
+    }
+
+    /// Holiday on this date, if any.
+    pub fn holiday(&self) -> Option<Holiday> {
+        Holiday::ALL
+            .into_iter()
+            .find(|holiday| holiday.date() == (self.month, self.day))
+    }
+}
+
+/// A calendar day storylets can be tied to.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum CalendarDay {
+    /// A named holiday.
+    Holiday(Holiday),
+    /// The player's birthday.
+    PlayerBirthday,
+    /// The birthday of the storylet's primary NPC.
+    TargetBirthday,
+}
+
+/// Flag for the current season (`season:winter`).
+pub const SEASON_FLAG_PREFIX: &str = "season:";
+/// Flag for the current month (`month:december`).
+pub const MONTH_FLAG_PREFIX: &str = "month:";
+/// Flag for today's holiday (`holiday:halloween`).
+pub const HOLIDAY_FLAG_PREFIX: &str = "holiday:";
+/// Birthday flags: `birthday:player`, `birthday:npc` when any known NPC has
+/// a birthday, and `birthday:<npc_id>` per NPC.
+pub const BIRTHDAY_FLAG_PREFIX: &str = "birthday:";
+/// Every prefix [`update_calendar_flags`] manages.
+pub const CALENDAR_FLAG_PREFIXES: [&str; 4] = [
+    SEASON_FLAG_PREFIX,
+    MONTH_FLAG_PREFIX,
+    HOLIDAY_FLAG_PREFIX,
+    BIRTHDAY_FLAG_PREFIX,
+];
+
+/// Day of the year (0-based) an NPC was born on, derived from the world seed.
+pub fn birthday_day_of_year(world_seed: u64, npc_id: NpcId) -> u16 {
+    let mut rng = DeterministicRng::with_domain(world_seed, npc_id.0, "birthday");
+    (rng.gen_u32() % u32::from(DAYS_PER_YEAR)) as u16
+}
+
+/// An NPC's (or the player's) birthday.
+pub fn birthday(world: &WorldState, npc_id: NpcId) -> CalendarDate {
+    CalendarDate::from_day_of_year(birthday_day_of_year(world.seed.0, npc_id))
+}
+
+/// Today's date. The player's age in days counts from their birthday, so
+/// the calendar advances with it.
+pub fn calendar_date(world: &WorldState) -> CalendarDate {
+    let born = u32::from(birthday_day_of_year(world.seed.0, world.player_id));
+    let day_of_year = (born + world.player_days_since_birth) % u32::from(DAYS_PER_YEAR);
+    CalendarDate::from_day_of_year(day_of_year as u16)
+}
+
+/// Whether today is `npc_id`'s birthday.
+pub fn is_birthday(world: &WorldState, npc_id: NpcId) -> bool {
+    birthday_day_of_year(world.seed.0, npc_id) == calendar_date(world).day_of_year
+}
+
+/// Known NPCs whose birthday is today, in id order.
+pub fn birthdays_today(world: &WorldState) -> Vec<NpcId> {
+    let mut ids: Vec<NpcId> = world
+        .known_npcs
+        .iter()
+        .copied()
+        .filter(|&id| id != world.player_id && is_birthday(world, id))
+        .collect();
+    ids.sort_by_key(|id| id.0);
+    ids.dedup();
+    ids
+}
+
+/// Whether today is the given calendar day. `target` is the storylet's
+/// primary NPC, needed for [`CalendarDay::TargetBirthday`].
+pub fn is_calendar_day(world: &WorldState, day: CalendarDay, target: Option<NpcId>) -> bool {
+    match day {
+        CalendarDay::Holiday(holiday) => calendar_date(world).holiday() == Some(holiday),
+        CalendarDay::PlayerBirthday => is_birthday(world, world.player_id),
+        CalendarDay::TargetBirthday => target.is_some_and(|id| is_birthday(world, id)),
+    }
+}
+
+/// Replace yesterday's calendar flags with today's.
+///
+/// Runs once a day from `WorldState::tick`, after the player's age has
+/// advanced.
+pub fn update_calendar_flags(world: &mut WorldState) {
+    let date = calendar_date(world);
+    let mut flags = vec![
+        format!("{}{}", SEASON_FLAG_PREFIX, date.season().as_str()),
+        format!("{}{}", MONTH_FLAG_PREFIX, date.month.as_str()),
+    ];
+    if let Some(holiday) = date.holiday() {
+        flags.push(format!("{}{}", HOLIDAY_FLAG_PREFIX, holiday.as_str()));
+    }
+    if is_birthday(world, world.player_id) {
+        flags.push(format!("{}player", BIRTHDAY_FLAG_PREFIX));
+    }
+    let npcs = birthdays_today(world);
+    if !npcs.is_empty() {
+        flags.push(format!("{}npc", BIRTHDAY_FLAG_PREFIX));
+    }
+    flags.extend(
+        npcs.iter()
+            .map(|id| format!("{}{}", BIRTHDAY_FLAG_PREFIX, id.0)),
+    );
+
+    // Only flags that actually flip are recorded as changes.
+    let mut stale: Vec<String> = world
+        .world_flags
+        .dynamic_flags()
+        .filter(|flag| {
+            CALENDAR_FLAG_PREFIXES
+                .iter()
+                .any(|prefix| flag.starts_with(prefix))
+        })
+        .filter(|flag| !flags.iter().any(|f| f == flag))
+        .map(str::to_string)
+        .collect();
+    stale.sort();
+    for flag in &stale {
+        set_world_flag(world, flag, false, FLAG_SOURCE_CALENDAR);
+    }
+    for flag in &flags {
+        set_world_flag(world, flag, true, FLAG_SOURCE_CALENDAR);
+    }
+}
+
 /// Canonical tick context passed through the simulation loop.
 /// Carries the current tick index so systems share a single source of truth.
 #[derive(Debug, Clone, Copy, Default)]
@@ -110,3 +455,67 @@ pub struct TickContext {
     /// Current tick index in the simulation.
     pub tick_index: u64,
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::types::WorldSeed;
+
+    #[test]
+    fn calendar_covers_the_year() {
+        assert_eq!(
+            Month::ALL.iter().map(|m| u16::from(m.days())).sum::<u16>(),
+            DAYS_PER_YEAR
+        );
+        let first = CalendarDate::from_day_of_year(0);
+        assert_eq!((first.month, first.day), (Month::January, 1));
+        assert_eq!(first.holiday(), Some(Holiday::NewYear));
+        let last = CalendarDate::from_day_of_year(DAYS_PER_YEAR - 1);
+        assert_eq!((last.month, last.day), (Month::December, 31));
+        assert_eq!(last.season(), Season::Winter);
+        let halloween = CalendarDate::from_day_of_year(303);
+        assert_eq!(halloween.holiday(), Some(Holiday::Halloween));
+        assert_eq!(halloween.season(), Season::Autumn);
+    }
+
+    #[test]
+    fn player_birthday_lines_up_with_aging() {
+        let mut world = WorldState::new(WorldSeed(21), NpcId(1));
+        let born = birthday(&world, NpcId(1));
+        // A fresh world starts on a birthday (age 6, 6 * 365 days).
+        assert_eq!(calendar_date(&world), born);
+        assert_eq!(birthday_day_of_year(21, NpcId(1)), born.day_of_year);
+
+        world.player_days_since_birth += 1;
+        assert!(!is_birthday(&world, NpcId(1)));
+        world.player_days_since_birth += u32::from(DAYS_PER_YEAR) - 1;
+        assert!(is_calendar_day(&world, CalendarDay::PlayerBirthday, None));
+    }
+
+    #[test]
+    fn daily_flags_replace_yesterdays() {
+        let mut world = WorldState::new(WorldSeed(21), NpcId(1));
+        let tomorrow = (calendar_date(&world).day_of_year + 1) % DAYS_PER_YEAR;
+        let friend = (2..5000)
+            .map(NpcId)
+            .find(|&id| birthday_day_of_year(21, id) == tomorrow)
+            .expect("someone is born tomorrow");
+        world.known_npcs.push(friend);
+
+        update_calendar_flags(&mut world);
+        assert!(world.world_flags.has_dynamic("birthday:player"));
+        assert!(!world.world_flags.has_dynamic("birthday:npc"));
+        let season = calendar_date(&world).season();
+        assert!(world
+            .world_flags
+            .has_dynamic(&format!("season:{}", season.as_str())));
+
+        world.player_days_since_birth += 1;
+        update_calendar_flags(&mut world);
+        assert!(!world.world_flags.has_dynamic("birthday:player"));
+        assert!(world.world_flags.has_dynamic("birthday:npc"));
+        assert!(world
+            .world_flags
+            .has_dynamic(&format!("birthday:{}", friend.0)));
+    }
+}
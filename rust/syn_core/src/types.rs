@@ -5,6 +5,7 @@ use crate::district::DistrictRegistry;
 use crate::failure_recovery::FailureRecoverySystem;
 use crate::gossip::GossipSystem;
 use crate::gossip_pressure::GossipPressureState;
+use crate::intern::StoryletHandle;
 use crate::narrative_heat::{NarrativeHeat, NarrativeHeatBand};
 use crate::npc::NpcPrototype;
 use crate::population::PopulationSimulation;
@@ -213,6 +214,23 @@ impl Traits {
         }
     }
 
+    /// Set a trait value by name (case-insensitive), clamped to [0..100].
+    /// Returns false if the trait name is not recognized.
+    pub fn set_by_name(&mut self, name: &str, value: f32) -> bool {
+        let slot = match name.to_lowercase().as_str() {
+            "stability" => &mut self.stability,
+            "confidence" => &mut self.confidence,
+            "sociability" => &mut self.sociability,
+            "empathy" => &mut self.empathy,
+            "impulsivity" => &mut self.impulsivity,
+            "ambition" => &mut self.ambition,
+            "charm" => &mut self.charm,
+            _ => return false,
+        };
+        *slot = value.clamp(0.0, 100.0);
+        true
+    }
+
     /// List of all valid trait names.
     pub const TRAIT_NAMES: &'static [&'static str] = &[
         "stability",
@@ -582,32 +600,29 @@ impl Default for Relationship {
 }
 
 impl Relationship {
-    /// Clamp all axes to [-10..+10].
+    /// Clamp all axes to their tuned ranges (-10..+10 by default).
     pub fn clamp(&mut self) {
-        self.affection = self.affection.clamp(-10.0, 10.0);
-        self.trust = self.trust.clamp(-10.0, 10.0);
-        self.attraction = self.attraction.clamp(-10.0, 10.0);
-        self.familiarity = self.familiarity.clamp(-10.0, 10.0);
-        self.resentment = self.resentment.clamp(-10.0, 10.0);
+        let tuning = crate::relationship_model::relationship_tuning();
+        self.affection = tuning.affection.clamp(self.affection);
+        self.trust = tuning.trust.clamp(self.trust);
+        self.attraction = tuning.attraction.clamp(self.attraction);
+        self.familiarity = tuning.familiarity.clamp(self.familiarity);
+        self.resentment = tuning.resentment.clamp(self.resentment);
     }
 
-    /// Apply a delta to a specific relationship axis.
+    /// Apply a delta to a specific relationship axis, using the current
+    /// [`crate::relationship_model::RelationshipTuning`].
     pub fn apply_delta(&mut self, axis: crate::RelationshipAxis, delta: f32) {
-        match axis {
-            crate::RelationshipAxis::Affection => {
-                self.affection = (self.affection + delta).clamp(-10.0, 10.0)
-            }
-            crate::RelationshipAxis::Trust => self.trust = (self.trust + delta).clamp(-10.0, 10.0),
-            crate::RelationshipAxis::Attraction => {
-                self.attraction = (self.attraction + delta).clamp(-10.0, 10.0)
-            }
-            crate::RelationshipAxis::Familiarity => {
-                self.familiarity = (self.familiarity + delta).clamp(-10.0, 10.0)
-            }
-            crate::RelationshipAxis::Resentment => {
-                self.resentment = (self.resentment + delta).clamp(-10.0, 10.0)
-            }
-        }
+        let tuning = crate::relationship_model::relationship_tuning();
+        let tuned = tuning.axis(axis.into());
+        let value = match axis {
+            crate::RelationshipAxis::Affection => &mut self.affection,
+            crate::RelationshipAxis::Trust => &mut self.trust,
+            crate::RelationshipAxis::Attraction => &mut self.attraction,
+            crate::RelationshipAxis::Familiarity => &mut self.familiarity,
+            crate::RelationshipAxis::Resentment => &mut self.resentment,
+        };
+        *value = tuned.apply(*value, delta);
     }
 
     /// Calculate relationship "heat" (0..1 scale) based on axes.
@@ -746,7 +761,7 @@ impl Default for AttachmentStyle {
 }
 
 /// NPC identifier (unique within a world).
-#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Serialize, Deserialize)]
 pub struct NpcId(pub u64);
 
 impl NpcId {
@@ -839,9 +854,52 @@ impl Default for Karma {
 /// Tracks how many times each storylet has been fired.
 #[derive(Debug, Clone, Serialize, Deserialize, Default, PartialEq)]
 pub struct StoryletUsageState {
-    /// storylet_id -> times fired
+    /// storylet handle -> times fired (serialized with string IDs as keys)
+    #[serde(default)]
+    pub times_fired: HashMap<StoryletHandle, u32>,
+    /// Domains of the last [`RECENT_FIRE_WINDOW`] fires, newest last.
     #[serde(default)]
-    pub times_fired: HashMap<String, u32>,
+    pub recent_domains: Vec<Vec<String>>,
+}
+
+/// How many recent fires [`StoryletUsageState`] keeps the domains of.
+pub const RECENT_FIRE_WINDOW: usize = 8;
+
+impl StoryletUsageState {
+    /// How many times the storylet with this ID has fired.
+    pub fn count(&self, storylet_id: &str) -> u32 {
+        StoryletHandle::get(storylet_id)
+            .and_then(|handle| self.times_fired.get(&handle).copied())
+            .unwrap_or(0)
+    }
+
+    /// Record one firing of a storylet, returning the new count.
+    pub fn record_fire(&mut self, storylet_id: &str) -> u32 {
+        let counter = self
+            .times_fired
+            .entry(StoryletHandle::new(storylet_id))
+            .or_insert(0);
+        *counter += 1;
+        *counter
+    }
+
+    /// Record the domains of a storylet that just fired.
+    pub fn record_domains<S: AsRef<str>>(&mut self, domains: &[S]) {
+        self.recent_domains
+            .push(domains.iter().map(|d| d.as_ref().to_string()).collect());
+        if self.recent_domains.len() > RECENT_FIRE_WINDOW {
+            self.recent_domains.remove(0);
+        }
+    }
+
+    /// How many fires ago `domain` last fired (0 = the latest fire), within
+    /// the recent window.
+    pub fn fires_since_domain(&self, domain: &str) -> Option<usize> {
+        self.recent_domains
+            .iter()
+            .rev()
+            .position(|domains| domains.iter().any(|d| d == domain))
+    }
 }
 
 /// Serializable memory entry snapshot (mirrors syn_memory::MemoryEntry without depending on that crate).
@@ -975,6 +1033,106 @@ pub struct WorldState {
     /// World flags toggled by storylets and systems (bitflag-optimized).
     #[serde(default)]
     pub world_flags: crate::world_flags::WorldFlags,
+    /// Player comfort toggles (blocked domains/tags) enforced by the director.
+    #[serde(default)]
+    pub content_preferences: crate::content_preferences::ContentPreferences,
+    /// Daily ambient news headlines (ring buffer).
+    #[serde(default)]
+    pub news_feed: crate::news_feed::NewsFeed,
+    /// Households formed during play (who lives with whom).
+    #[serde(default)]
+    pub households: crate::household::HouseholdRegistry,
+    /// Player's daily action/energy budget.
+    #[serde(default)]
+    pub action_budget: crate::action_budget::ActionBudget,
+    /// NPC moods (the player's mood stays in `player_stats`).
+    #[serde(default)]
+    pub npc_moods: crate::npc_mood::NpcMoods,
+    /// Player's underworld exposure (grows with negative karma and crime).
+    #[serde(default)]
+    pub underworld: crate::underworld::UnderworldExposure,
+    /// Injected external events: whitelist, narrative log, forced triggers.
+    #[serde(default)]
+    pub external_events: crate::external_events::ExternalEvents,
+    /// Karma model and district/cluster reputation.
+    #[serde(default)]
+    pub moral_ledger: crate::moral_ledger::MoralLedger,
+    /// Ambient micro-event generator (pacing, custom templates, recent beats).
+    #[serde(default)]
+    pub ambient: crate::ambient::AmbientState,
+    /// Night-time dreams and flashbacks (frequency, recent dreams).
+    #[serde(default)]
+    pub dreams: crate::dreams::DreamState,
+    /// NPC-initiated relationship proposals awaiting the player's answer.
+    #[serde(default)]
+    pub proposals: crate::proposals::ProposalState,
+    /// Closed life chapters and the baseline of the open one.
+    #[serde(default)]
+    pub chapters: crate::chapters::ChapterState,
+    /// Recent per-pair interaction heat.
+    #[serde(default)]
+    pub relationship_heat: crate::relationship_heat::RelationshipHeatState,
+    /// Rival NPCs' hostile actions against the player.
+    #[serde(default)]
+    pub rival_actions: crate::rival_actions::RivalActionState,
+    /// Life goals the player is pursuing.
+    #[serde(default)]
+    pub life_goals: crate::life_goals::LifeGoalState,
+    /// First meetings that introduced new NPCs to the player.
+    #[serde(default)]
+    pub encounters: crate::encounters::EncounterState,
+    /// Major characters and their pending check-ins.
+    #[serde(default)]
+    pub importance: crate::importance::ImportanceState,
+    /// Year in Review recaps and the running tally for the current year.
+    #[serde(default)]
+    pub year_review: crate::year_review::YearReviewState,
+    /// World flag changes awaiting reaction storylets, and per-flag reaction cooldowns.
+    #[serde(default)]
+    pub flag_changes: crate::flag_changes::FlagChangeState,
+    /// Typed world events waiting for an embedder to drain them.
+    #[serde(default)]
+    pub event_bus: crate::event_bus::EventBus,
+    /// Delayed NPC reactions still sinking in
+    #[serde(default)]
+    pub reaction_latency: crate::reaction_latency::ReactionLatencyState,
+    /// Declared relationship statuses and their mismatches with derived states
+    #[serde(default)]
+    pub declared_relationships: crate::declared_relationships::DeclaredRelationshipState,
+    /// Pets, plants and younger siblings in the player's care.
+    #[serde(default)]
+    pub dependents: crate::dependents::DependentState,
+    /// Chosen difficulty and the optional dynamic difficulty controller.
+    #[serde(default)]
+    pub difficulty: crate::dynamic_difficulty::DifficultyState,
+    /// The Digital stage's PostLife chapter: signal, coherence, digital NPCs.
+    #[serde(default)]
+    pub post_life: crate::post_life::PostLifeState,
+    /// Per-event and per-day caps on outcome deltas, with the clamp log.
+    #[serde(default)]
+    pub delta_caps: crate::delta_caps::DeltaCapState,
+    /// Player-authored notes on NPCs and journal entries. Never read by the
+    /// simulation, so not part of [`WorldStateSnapshot`].
+    #[serde(default)]
+    pub player_notes: crate::player_notes::PlayerNotesState,
+    /// UI session markers and recent session lengths for session-aware pacing.
+    #[serde(default)]
+    pub session_pacing: crate::session_pacing::SessionPacingState,
+    /// Native or quantized float accumulation.
+    #[serde(default)]
+    pub float_mode: crate::det_math::FloatMode,
+    /// Romance bands last seen and recent jealous reactions.
+    #[serde(default)]
+    pub jealousy: crate::jealousy::JealousyState,
+    /// Third-party opinion settings and recent shifts.
+    #[serde(default)]
+    pub opinions: crate::opinions::OpinionState,
+    /// Open player stat crises and the director's response windows.
+    #[serde(default)]
+    pub stat_crisis: crate::stats::StatCrisisState,
+    /// NPCs spawned to fill a storylet's role, with the storylet they came from.
+    #[serde(default)]
+    pub spawned_npcs: crate::npc_spawning::SpawnedNpcState,
 }
 
 impl WorldState {
@@ -1011,6 +1169,39 @@ impl WorldState {
             population: PopulationSimulation::default(),
             failure_recovery: FailureRecoverySystem::default(),
             world_flags: crate::world_flags::WorldFlags::new(),
+            content_preferences: crate::content_preferences::ContentPreferences::new(),
+            news_feed: crate::news_feed::NewsFeed::default(),
+            households: crate::household::HouseholdRegistry::new(),
+            action_budget: crate::action_budget::ActionBudget::default(),
+            npc_moods: crate::npc_mood::NpcMoods::new(),
+            underworld: crate::underworld::UnderworldExposure::default(),
+            external_events: crate::external_events::ExternalEvents::default(),
+            moral_ledger: crate::moral_ledger::MoralLedger::default(),
+            ambient: crate::ambient::AmbientState::default(),
+            dreams: crate::dreams::DreamState::default(),
+            proposals: crate::proposals::ProposalState::default(),
+            chapters: crate::chapters::ChapterState::default(),
+            relationship_heat: crate::relationship_heat::RelationshipHeatState::default(),
+            rival_actions: crate::rival_actions::RivalActionState::default(),
+            life_goals: crate::life_goals::LifeGoalState::default(),
+            encounters: crate::encounters::EncounterState::default(),
+            importance: crate::importance::ImportanceState::default(),
+            year_review: crate::year_review::YearReviewState::default(),
+            flag_changes: crate::flag_changes::FlagChangeState::default(),
+            event_bus: crate::event_bus::EventBus::default(),
+            reaction_latency: crate::reaction_latency::ReactionLatencyState::default(),
+            declared_relationships: crate::declared_relationships::DeclaredRelationshipState::default(),
+            dependents: crate::dependents::DependentState::default(),
+            difficulty: crate::dynamic_difficulty::DifficultyState::default(),
+            post_life: crate::post_life::PostLifeState::default(),
+            delta_caps: crate::delta_caps::DeltaCapState::default(),
+            player_notes: crate::player_notes::PlayerNotesState::default(),
+            session_pacing: crate::session_pacing::SessionPacingState::default(),
+            float_mode: crate::det_math::FloatMode::default(),
+            jealousy: crate::jealousy::JealousyState::default(),
+            opinions: crate::opinions::OpinionState::default(),
+            stat_crisis: crate::stats::StatCrisisState::default(),
+            spawned_npcs: crate::npc_spawning::SpawnedNpcState::default(),
         }
     }
 
@@ -1029,9 +1220,13 @@ impl WorldState {
             .unwrap_or_default()
     }
 
-    /// Update relationship between two NPCs.
+    /// Update relationship between two NPCs, stamping the change for
+    /// incremental relationship snapshots.
     pub fn set_relationship(&mut self, from: NpcId, to: NpcId, rel: Relationship) {
-        self.relationships.insert((from, to), rel);
+        if self.relationships.insert((from, to), rel) != Some(rel) {
+            self.relationship_pressure
+                .stamp_change(from.0, to.0, self.current_tick.0);
+        }
     }
 
     /// Apply a list of relationship deltas to the player's relationships.
@@ -1041,6 +1236,12 @@ impl WorldState {
             current.apply_delta(d.axis, d.delta);
             current.state = current.compute_next_state();
             self.set_relationship(self.player_id, d.target_id, current);
+            crate::relationship_heat::record_relationship_interaction(
+                self,
+                self.player_id,
+                d.target_id,
+                d.delta.abs(),
+            );
         }
     }
 
@@ -1062,6 +1263,15 @@ impl WorldState {
         // Advance coarse-grained game time with 24 ticks per day (4 phases x 6 ticks each)
         self.game_time.advance_ticks_with_tpd(1, 24);
         ctx.tick_index = self.game_time.tick_index;
+        // Recover energy while resting; reset the daily spend counter at midnight.
+        self.action_budget
+            .on_tick(self.game_time.phase, self.game_time.day);
+        // NPC reactions still sinking in land another slice
+        crate::reaction_latency::tick_reaction_latency(self);
+        // Dependents grow hungrier, thirstier or lonelier
+        crate::dependents::tick_dependents(self);
+        // Health or mood falling into (or climbing out of) crisis
+        crate::stats::tick_stat_crisis(self);
         // Daily progression: increment days since birth every 24 ticks.
         if self.current_tick.0 % 24 == 0 {
             self.player_days_since_birth = self.player_days_since_birth.saturating_add(1);
@@ -1071,6 +1281,12 @@ impl WorldState {
             self.player_age_years = derived_years;
             self.player_age = derived_years;
             self.player_life_stage = LifeStage::from_age(self.player_age_years);
+            // Birthdays and life stage transitions close a timeline chapter
+            crate::chapters::update_chapters(self);
+            // Sample per-pair heat for its trend and drop cold pairs
+            self.relationship_heat.sample_daily(self.current_tick.0);
+            // Season, holiday and birthday flags for today
+            crate::time::update_calendar_flags(self);
         }
         // Tick districts (every 6 ticks = 1 phase to reduce compute)
         if self.current_tick.0 % 6 == 0 {
@@ -1088,6 +1304,10 @@ impl WorldState {
             }
             // Decay old district pressure events (same TTL as relationship pressure)
             self.district_pressure.decay_queue(current_tick, 168, 10);
+            // Underworld exposure feeds the player's district and draws in contacts
+            crate::underworld::tick_underworld(self);
+            // Karma and scoped reputation drift back toward neutral
+            crate::moral_ledger::tick_moral_ledger(self);
         }
         // Tick gossip spread (every 6 ticks to match district phase cadence)
         if self.current_tick.0 % 6 == 0 {
@@ -1106,6 +1326,8 @@ impl WorldState {
                 current_tick,
                 &mut rng,
             );
+            // Whoever heard storylet gossip remembers it
+            crate::gossip::record_storylet_gossip_spread(self, &spread_results);
 
             // Build valence and scandalous lookup maps from current rumors
             let valences: std::collections::HashMap<String, f32> = self
@@ -1143,6 +1365,39 @@ impl WorldState {
                 self.gossip.cleanup(current_tick);
             }
         }
+        // Publish the daily news feed (after districts and gossip have ticked)
+        if self.current_tick.0.is_multiple_of(24) {
+            let sources = crate::news_feed::NewsSources {
+                district_events: &self.district_pressure.queue,
+                population: &self.population,
+                rumors: &self.gossip.rumors,
+                npc_prototypes: &self.npc_prototypes,
+                player_id: self.player_id,
+            };
+            self.news_feed.generate_daily(self.current_tick.0, &sources);
+            // NPCs whose feelings crossed a threshold ask the player for an answer
+            crate::proposals::scan_for_proposals(self);
+            // Rivals act on their resentment
+            crate::rival_actions::scan_for_rival_actions(self);
+            // Goals that were met or ran out of time queue their payoffs
+            crate::life_goals::update_life_goals(self);
+            // The player may cross paths with someone new
+            crate::encounters::scan_for_encounters(self);
+            // Shared history makes major characters, who check in on the player
+            crate::importance::update_importance(self);
+            // Tally the day into the Year in Review, closing the year on January 1
+            crate::year_review::update_year_review(self);
+            // Declared statuses that no longer fit how the pair feels raise a mismatch
+            crate::declared_relationships::update_declared_mismatches(self);
+            // Dependents left without care for too long are neglected
+            crate::dependents::check_dependent_neglect(self);
+            // Dynamic difficulty reviews how the player has been doing
+            crate::dynamic_difficulty::update_difficulty(self);
+            // The ghost's signal and coherence move once the player is Digital
+            crate::post_life::tick_post_life(self);
+        }
+        // Admirers who hear about the player's romance moving up a band get jealous
+        crate::jealousy::tick_jealousy(self);
         // Decay narrative heat over time (-0.1 per tick)
         self.narrative_heat.add(-0.1);
         self.narrative_heat.add(-0.1);
@@ -1152,6 +1407,8 @@ impl WorldState {
         if self.heat_momentum.abs() < 0.05 {
             self.heat_momentum = 0.0;
         }
+        // Heat band and life stage changes go out on the event bus
+        crate::event_bus::watch_world_changes(self);
     }
 
     /// Get narrative heat level descriptor.
@@ -1197,6 +1454,13 @@ impl WorldState {
         (self.heat_momentum / 50.0).clamp(-1.0, 1.0)
     }
 
+    /// Presentation-only randomness for `context` (e.g. `"portrait"`), fixed
+    /// for this seed and tick. Never feeds simulation state; see
+    /// [`crate::rng::CosmeticRng`].
+    pub fn cosmetic_rng(&self, context: &str) -> crate::rng::CosmeticRng {
+        crate::rng::CosmeticRng::new(self.seed.0, self.current_tick.0, context)
+    }
+
     /// Estimate the player's appetite for a given behavior action (0.25..3.0 scale).
     pub fn player_behavior_bias(&self, action: BehaviorAction) -> f32 {
         if let Some(npc) = self.npcs.get(&self.player_id) {
@@ -1260,6 +1524,60 @@ pub struct WorldStateSnapshot {
     pub district_state: HashMap<String, String>,
     /// World flags.
     pub world_flags: crate::world_flags::WorldFlags,
+    /// NPC moods.
+    pub npc_moods: crate::npc_mood::NpcMoods,
+    /// Underworld exposure.
+    pub underworld: crate::underworld::UnderworldExposure,
+    /// External events (narrative log and forced triggers).
+    pub external_events: crate::external_events::ExternalEvents,
+    /// Karma model and scoped reputation.
+    pub moral_ledger: crate::moral_ledger::MoralLedger,
+    /// Ambient micro-event state.
+    pub ambient: crate::ambient::AmbientState,
+    /// Dream state.
+    pub dreams: crate::dreams::DreamState,
+    /// Relationship proposals.
+    pub proposals: crate::proposals::ProposalState,
+    /// Life chapter summaries.
+    pub chapters: crate::chapters::ChapterState,
+    /// Per-pair relationship heat.
+    pub relationship_heat: crate::relationship_heat::RelationshipHeatState,
+    /// Rival action log and cooldowns.
+    pub rival_actions: crate::rival_actions::RivalActionState,
+    /// Life goals and their queued payoff storylets.
+    pub life_goals: crate::life_goals::LifeGoalState,
+    /// First meetings that introduced new NPCs to the player.
+    pub encounters: crate::encounters::EncounterState,
+    /// Major characters and their pending check-ins.
+    pub importance: crate::importance::ImportanceState,
+    /// Year in Review state.
+    pub year_review: crate::year_review::YearReviewState,
+    /// Flag change stream.
+    pub flag_changes: crate::flag_changes::FlagChangeState,
+    /// Delayed NPC reactions still sinking in
+    pub reaction_latency: crate::reaction_latency::ReactionLatencyState,
+    /// Declared relationship statuses and their mismatches with derived states
+    pub declared_relationships: crate::declared_relationships::DeclaredRelationshipState,
+    /// Dependents in the player's care.
+    pub dependents: crate::dependents::DependentState,
+    /// Difficulty and dynamic difficulty controller.
+    pub difficulty: crate::dynamic_difficulty::DifficultyState,
+    /// PostLife chapter state.
+    pub post_life: crate::post_life::PostLifeState,
+    /// Outcome delta caps, spent budgets and clamp log.
+    pub delta_caps: crate::delta_caps::DeltaCapState,
+    /// Session markers and history for session-aware pacing.
+    pub session_pacing: crate::session_pacing::SessionPacingState,
+    /// Native or quantized float accumulation.
+    pub float_mode: crate::det_math::FloatMode,
+    /// Jealousy bands, cooldowns and reactions.
+    pub jealousy: crate::jealousy::JealousyState,
+    /// Third-party opinion settings and shifts.
+    pub opinions: crate::opinions::OpinionState,
+    /// Open stat crises and response windows.
+    pub stat_crisis: crate::stats::StatCrisisState,
+    /// Spawned NPCs and their origin storylets.
+    pub spawned_npcs: crate::npc_spawning::SpawnedNpcState,
 }
 
 impl WorldStateSnapshot {
@@ -1288,8 +1606,104 @@ impl WorldStateSnapshot {
             memory_entries: world.memory_entries.clone(),
             district_state: world.district_state.clone(),
             world_flags: world.world_flags.clone(),
+            npc_moods: world.npc_moods.clone(),
+            underworld: world.underworld.clone(),
+            external_events: world.external_events.clone(),
+            moral_ledger: world.moral_ledger.clone(),
+            ambient: world.ambient.clone(),
+            dreams: world.dreams.clone(),
+            proposals: world.proposals.clone(),
+            chapters: world.chapters.clone(),
+            relationship_heat: world.relationship_heat.clone(),
+            rival_actions: world.rival_actions.clone(),
+            life_goals: world.life_goals.clone(),
+            encounters: world.encounters.clone(),
+            importance: world.importance.clone(),
+            year_review: world.year_review.clone(),
+            flag_changes: world.flag_changes.clone(),
+            reaction_latency: world.reaction_latency.clone(),
+            declared_relationships: world.declared_relationships.clone(),
+            dependents: world.dependents.clone(),
+            difficulty: world.difficulty.clone(),
+            post_life: world.post_life.clone(),
+            delta_caps: world.delta_caps.clone(),
+            session_pacing: world.session_pacing.clone(),
+            float_mode: world.float_mode,
+            jealousy: world.jealousy.clone(),
+            opinions: world.opinions.clone(),
+            stat_crisis: world.stat_crisis.clone(),
+            spawned_npcs: world.spawned_npcs.clone(),
         }
     }
+
+    /// Name of the first field (in declaration order) that differs from `other`.
+    ///
+    /// Determinism checks use this to point at the subsystem that diverged.
+    /// Floats are compared exactly on purpose: any drift is a divergence.
+    #[allow(clippy::float_cmp)]
+    pub fn first_divergence(&self, other: &Self) -> Option<&'static str> {
+        macro_rules! compare {
+            ($($field:ident),+ $(,)?) => {
+                $(
+                    if self.$field != other.$field {
+                        return Some(stringify!($field));
+                    }
+                )+
+            };
+        }
+        compare!(
+            seed,
+            current_tick,
+            player_id,
+            player_stats,
+            player_age_years,
+            player_days_since_birth,
+            player_life_stage,
+            player_karma,
+            narrative_heat,
+            heat_momentum,
+            relationships,
+            npcs,
+            relationship_pressure,
+            relationship_milestones,
+            digital_legacy,
+            npc_prototypes,
+            known_npcs,
+            game_time_tick,
+            storylet_usage,
+            memory_entries,
+            district_state,
+            world_flags,
+            npc_moods,
+            underworld,
+            external_events,
+            moral_ledger,
+            ambient,
+            dreams,
+            proposals,
+            chapters,
+            relationship_heat,
+            rival_actions,
+            life_goals,
+            encounters,
+            importance,
+            year_review,
+            flag_changes,
+            reaction_latency,
+            declared_relationships,
+            dependents,
+            difficulty,
+            post_life,
+            delta_caps,
+            session_pacing,
+            float_mode,
+            jealousy,
+            opinions,
+            stat_crisis,
+            spawned_npcs,
+        );
+        None
+    }
 }
 
 #[cfg(test)]
@@ -1318,6 +1732,22 @@ mod tests {
         assert!(heat > 0.0 && heat < 1.0);
     }
 
+    #[test]
+    fn test_storylet_usage_recent_domains() {
+        let mut usage = StoryletUsageState::default();
+        usage.record_domains(&["romance", "family"]);
+        usage.record_domains(&["career"]);
+        assert_eq!(usage.fires_since_domain("career"), Some(0));
+        assert_eq!(usage.fires_since_domain("family"), Some(1));
+        assert_eq!(usage.fires_since_domain("trauma"), None);
+
+        for _ in 0..RECENT_FIRE_WINDOW {
+            usage.record_domains(&["slice_of_life"]);
+        }
+        assert_eq!(usage.recent_domains.len(), RECENT_FIRE_WINDOW);
+        assert_eq!(usage.fires_since_domain("career"), None);
+    }
+
     #[test]
     fn test_life_stage_from_age() {
         assert_eq!(LifeStage::from_age(10), LifeStage::Child);
@@ -1577,4 +2007,19 @@ mod tests {
         assert!(broken_heart.is_recovering());
         assert!(!friend.is_recovering());
     }
+
+    #[test]
+    fn test_snapshot_first_divergence_names_field() {
+        let world = WorldState::new(WorldSeed(9), NpcId(1));
+        let mut other = world.clone();
+        let snap = WorldStateSnapshot::from_world(&world);
+        assert_eq!(snap.first_divergence(&WorldStateSnapshot::from_world(&other)), None);
+
+        other.npc_moods.set(NpcId(4), -2.0);
+        other.player_stats.mood += 0.5;
+        assert_eq!(
+            snap.first_divergence(&WorldStateSnapshot::from_world(&other)),
+            Some("player_stats")
+        );
+    }
 }
@@ -0,0 +1,283 @@
+//! Underworld exposure: the systemic consequence of low karma.
+//!
+//! Negative-karma outcomes and crime-tagged memories raise the player's
+//! [`UnderworldExposure`] (0..=100). As exposure climbs through the
+//! [`UnderworldStanding`] bands it:
+//! - unlocks the underworld storylet domain (from `Connected` upward),
+//! - draws NPCs with specific role tags (fixers first, then rivals and the
+//!   police) toward the player,
+//! - feeds gang activity in the player's home district.
+//!
+//! Exposure cools slowly once the player has kept clean for a week.
+
+use crate::npc::NpcRoleTag;
+use crate::types::{NpcId, WorldState};
+use crate::RelationshipAxis;
+use serde::{Deserialize, Serialize};
+
+/// Memory tags that count as criminal involvement.
+pub const CRIME_MEMORY_TAGS: &[&str] = &[
+    "crime",
+    "theft",
+    "drugs",
+    "smuggling",
+    "black_market",
+    "violence",
+    "arrest",
+    "underworld",
+];
+
+/// Exposure gained per point of negative karma.
+pub const KARMA_EXPOSURE_RATE: f32 = 0.5;
+/// Exposure gained per crime-tagged memory.
+pub const CRIME_MEMORY_EXPOSURE: f32 = 4.0;
+/// Exposure lost per day once the player has kept clean for [`COOLDOWN_TICKS`].
+pub const DAILY_EXPOSURE_DECAY: f32 = 0.5;
+/// Ticks without new exposure before it starts to decay (7 days).
+pub const COOLDOWN_TICKS: u64 = 168;
+/// Familiarity an attracted NPC gains toward the player per day.
+pub const ATTRACTION_FAMILIARITY: f32 = 0.2;
+
+/// How deep the player is in the underworld.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Serialize, Deserialize)]
+pub enum UnderworldStanding {
+    /// No meaningful exposure (< 10).
+    Clean,
+    /// Rumors and small favors (10..30).
+    Noticed,
+    /// Known to the underworld; its storylets are open (30..60).
+    Connected,
+    /// Part of it (60+).
+    Embedded,
+}
+
+impl UnderworldStanding {
+    /// Band for an exposure level.
+    pub fn from_level(level: f32) -> Self {
+        if level >= 60.0 {
+            UnderworldStanding::Embedded
+        } else if level >= 30.0 {
+            UnderworldStanding::Connected
+        } else if level >= 10.0 {
+            UnderworldStanding::Noticed
+        } else {
+            UnderworldStanding::Clean
+        }
+    }
+
+    /// Role tags of NPCs drawn toward a player at this standing.
+    pub fn attracted_role_tags(&self) -> &'static [NpcRoleTag] {
+        match self {
+            UnderworldStanding::Clean => &[],
+            UnderworldStanding::Noticed => &[NpcRoleTag::Fixer],
+            UnderworldStanding::Connected => &[NpcRoleTag::Fixer, NpcRoleTag::Antagonist],
+            UnderworldStanding::Embedded => &[
+                NpcRoleTag::Fixer,
+                NpcRoleTag::Antagonist,
+                NpcRoleTag::Authority,
+            ],
+        }
+    }
+}
+
+/// The player's underworld exposure track.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct UnderworldExposure {
+    /// Exposure level (0..=100).
+    #[serde(default)]
+    pub level: f32,
+    /// Crime-tagged memories recorded so far.
+    #[serde(default)]
+    pub crime_memories: u32,
+    /// Tick at which exposure last rose.
+    #[serde(default)]
+    pub last_raised_tick: Option<u64>,
+}
+
+impl UnderworldExposure {
+    /// Current band.
+    pub fn standing(&self) -> UnderworldStanding {
+        UnderworldStanding::from_level(self.level)
+    }
+
+    /// Whether underworld-domain storylets may fire.
+    pub fn domain_unlocked(&self) -> bool {
+        self.standing() >= UnderworldStanding::Connected
+    }
+
+    /// Raise exposure by `amount` (clamped to 0..=100).
+    pub fn raise(&mut self, amount: f32, tick: u64) {
+        if amount <= 0.0 {
+            return;
+        }
+        self.level = (self.level + amount).clamp(0.0, 100.0);
+        self.last_raised_tick = Some(tick);
+    }
+
+    /// Account for a karma change; only negative deltas raise exposure.
+    pub fn record_karma_delta(&mut self, delta: f32, tick: u64) {
+        if delta < 0.0 {
+            self.raise(-delta * KARMA_EXPOSURE_RATE, tick);
+        }
+    }
+
+    /// Account for a memory's tags. Returns true if any tag was criminal.
+    pub fn record_memory_tags<'a>(
+        &mut self,
+        tags: impl IntoIterator<Item = &'a str>,
+        tick: u64,
+    ) -> bool {
+        let criminal = tags
+            .into_iter()
+            .any(|tag| CRIME_MEMORY_TAGS.iter().any(|c| tag.eq_ignore_ascii_case(c)));
+        if criminal {
+            self.crime_memories = self.crime_memories.saturating_add(1);
+            self.raise(CRIME_MEMORY_EXPOSURE, tick);
+        }
+        criminal
+    }
+
+    /// Daily cooling: exposure decays once nothing has raised it for a week.
+    pub fn decay_daily(&mut self, tick: u64) {
+        let cooled = self
+            .last_raised_tick
+            .is_none_or(|last| tick.saturating_sub(last) >= COOLDOWN_TICKS);
+        if cooled {
+            self.level = (self.level - DAILY_EXPOSURE_DECAY).max(0.0);
+        }
+    }
+
+    /// Gang activity added to the player's home district per phase.
+    pub fn district_gang_pressure(&self) -> f32 {
+        if self.standing() >= UnderworldStanding::Connected {
+            self.level * 0.01
+        } else {
+            0.0
+        }
+    }
+}
+
+/// Apply a karma delta to the player and update underworld exposure.
+pub fn apply_karma(world: &mut WorldState, delta: f32) {
+    world.player_karma.apply_delta(delta);
+    let tick = world.current_tick.0;
+    world.underworld.record_karma_delta(delta, tick);
+}
+
+/// Record a player memory's tags against underworld exposure.
+pub fn record_crime_memory<'a>(
+    world: &mut WorldState,
+    tags: impl IntoIterator<Item = &'a str>,
+) -> bool {
+    let tick = world.current_tick.0;
+    world.underworld.record_memory_tags(tags, tick)
+}
+
+/// Per-phase underworld pass, run alongside the district tick.
+///
+/// Feeds gang activity in the player's home district and, once a day, cools
+/// exposure and draws attracted NPCs closer to the player.
+pub fn tick_underworld(world: &mut WorldState) {
+    let pressure = world.underworld.district_gang_pressure();
+    if pressure > 0.0 {
+        if let Some(name) = world.npcs.get(&world.player_id).map(|n| n.district.clone()) {
+            if let Some(district) = world.districts.get_by_name_mut(&name) {
+                district.apply_gang_pressure(pressure);
+            }
+        }
+    }
+
+    let tick = world.current_tick.0;
+    if !tick.is_multiple_of(24) {
+        return;
+    }
+    world.underworld.decay_daily(tick);
+
+    let tags = world.underworld.standing().attracted_role_tags();
+    if tags.is_empty() {
+        return;
+    }
+    let attracted: Vec<NpcId> = world
+        .known_npcs
+        .iter()
+        .copied()
+        .filter(|id| {
+            world
+                .npc_prototype(*id)
+                .is_some_and(|proto| proto.role_tags.iter().any(|t| tags.contains(t)))
+        })
+        .collect();
+    let player = world.player_id;
+    for id in attracted {
+        let mut rel = world.get_relationship(id, player);
+        rel.apply_delta(RelationshipAxis::Familiarity, ATTRACTION_FAMILIARITY);
+        world.set_relationship(id, player, rel);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::types::WorldSeed;
+
+    #[test]
+    fn negative_karma_and_crime_memories_raise_standing() {
+        let mut exposure = UnderworldExposure::default();
+        exposure.record_karma_delta(15.0, 1);
+        assert_eq!(exposure.standing(), UnderworldStanding::Clean);
+
+        exposure.record_karma_delta(-30.0, 2);
+        assert_eq!(exposure.standing(), UnderworldStanding::Noticed);
+        assert!(!exposure.domain_unlocked());
+
+        assert!(!exposure.record_memory_tags(["betrayal"], 3));
+        for tick in 4..8 {
+            assert!(exposure.record_memory_tags(["Theft", "guilt"], tick));
+        }
+        assert_eq!(exposure.crime_memories, 4);
+        assert_eq!(exposure.standing(), UnderworldStanding::Connected);
+        assert!(exposure.domain_unlocked());
+    }
+
+    #[test]
+    fn exposure_cools_only_after_a_clean_week() {
+        let mut exposure = UnderworldExposure::default();
+        exposure.raise(20.0, 100);
+        exposure.decay_daily(100 + 24);
+        assert_eq!(exposure.level, 20.0);
+
+        exposure.decay_daily(100 + COOLDOWN_TICKS);
+        assert_eq!(exposure.level, 20.0 - DAILY_EXPOSURE_DECAY);
+    }
+
+    #[test]
+    fn connected_player_feeds_home_district_gangs() {
+        let mut world = WorldState::new(WorldSeed(3), NpcId(1));
+        let district = world.districts.list_names()[0].clone();
+        world.npcs.insert(
+            NpcId(1),
+            crate::AbstractNpc {
+                id: NpcId(1),
+                age: 20,
+                job: "Courier".to_string(),
+                district: district.clone(),
+                household_id: 1,
+                traits: crate::Traits::default(),
+                seed: 1,
+                attachment_style: crate::AttachmentStyle::Secure,
+            },
+        );
+        let before = world.districts.get_by_name(&district).unwrap().gang_activity;
+
+        tick_underworld(&mut world);
+        assert_eq!(
+            world.districts.get_by_name(&district).unwrap().gang_activity,
+            before
+        );
+
+        apply_karma(&mut world, -80.0);
+        assert_eq!(world.player_karma.0, -80.0);
+        tick_underworld(&mut world);
+        assert!(world.districts.get_by_name(&district).unwrap().gang_activity > before);
+    }
+}
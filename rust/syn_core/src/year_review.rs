@@ -0,0 +1,317 @@
+//! Year in Review: a local recap of each calendar year.
+//!
+//! Once a day [`update_year_review`] tallies the player's mood band and
+//! karma change into the running year. When the calendar wraps to January 1
+//! the year is closed into a [`YearInReview`] and a new tally starts. The
+//! recap compares snapshots taken at the start of the year against the world
+//! at its end: shared memories (the memory records plus noted storylet
+//! memories), storylet fire counts and the narrative log's sequence number.
+//!
+//! Everything is derived from world state, so the same seed and choices give
+//! the same recap. Nothing leaves the device.
+//!
+//! Year 1 is the calendar year the game started in, which may be partial.
+
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
+
+use crate::stats::MoodBand;
+use crate::time::calendar_date;
+use crate::types::{NpcId, WorldState};
+
+/// Mood bands in the order ties are broken for the dominant mood.
+const MOOD_BANDS: [MoodBand; 5] = [
+    MoodBand::Neutral,
+    MoodBand::High,
+    MoodBand::Low,
+    MoodBand::Euphoric,
+    MoodBand::Despair,
+];
+
+/// Recap of one calendar year.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct YearInReview {
+    /// Calendar year (1 = the year the game started in).
+    pub year: u32,
+    /// First tick of the year (or of the game, for year 1).
+    pub start_tick: u64,
+    /// Tick the year was closed at.
+    pub end_tick: u64,
+    /// Days tallied.
+    pub days: u32,
+    /// NPC the player shared the most new memories with.
+    pub most_seen_npc: Option<NpcId>,
+    /// Memories shared with `most_seen_npc` this year.
+    pub most_seen_memories: u32,
+    /// Mood band the player spent the most days in.
+    pub dominant_mood: Option<MoodBand>,
+    /// Days spent in `dominant_mood`.
+    pub dominant_mood_days: u32,
+    /// Largest day-over-day karma change (signed).
+    pub biggest_karma_swing: f32,
+    /// Tick of the day the biggest swing was seen.
+    pub biggest_karma_swing_tick: u64,
+    /// Storylet fired this year with the fewest fires overall.
+    pub rarest_storylet: Option<String>,
+    /// Lifetime fires of `rarest_storylet` at year end.
+    pub rarest_storylet_fires: u32,
+    /// Storylet fires this year.
+    pub storylets_fired: u32,
+    /// Narrative log entries added this year.
+    pub narrative_events: u32,
+}
+
+/// Running tally for the current year.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct YearTally {
+    /// Calendar year being tallied.
+    pub year: u32,
+    /// Tick the tally started at.
+    pub start_tick: u64,
+    /// Days tallied per mood band, in `MOOD_BANDS` order.
+    pub mood_days: [u32; 5],
+    /// Karma at the last daily sample.
+    pub last_karma: f32,
+    /// Largest day-over-day karma change so far.
+    pub karma_swing: f32,
+    /// Tick of the largest karma change.
+    pub karma_swing_tick: u64,
+    /// Storylet fire counts at the start of the year.
+    pub storylet_counts: BTreeMap<String, u32>,
+    /// Memories shared with each NPC at the start of the year.
+    pub shared_memories: BTreeMap<u64, usize>,
+    /// Narrative log sequence number at the start of the year.
+    pub narrative_seq: u64,
+}
+
+impl YearTally {
+    fn start(world: &WorldState, year: u32) -> Self {
+        Self {
+            year,
+            start_tick: world.current_tick.0,
+            mood_days: [0; 5],
+            last_karma: world.player_karma.0,
+            karma_swing: 0.0,
+            karma_swing_tick: world.current_tick.0,
+            storylet_counts: storylet_counts(world),
+            shared_memories: shared_memory_counts(world),
+            narrative_seq: world.external_events.last_seq,
+        }
+    }
+
+    fn days(&self) -> u32 {
+        self.mood_days.iter().sum()
+    }
+
+    fn sample(&mut self, world: &WorldState) {
+        let band = world.player_stats.mood_band();
+        if let Some(idx) = MOOD_BANDS.iter().position(|b| *b == band) {
+            self.mood_days[idx] += 1;
+        }
+        let karma = world.player_karma.0;
+        let swing = karma - self.last_karma;
+        if swing.abs() > self.karma_swing.abs() {
+            self.karma_swing = swing;
+            self.karma_swing_tick = world.current_tick.0;
+        }
+        self.last_karma = karma;
+    }
+
+    fn close(&self, world: &WorldState) -> YearInReview {
+        let mut most_seen: Option<(u64, u32)> = None;
+        for (npc, count) in shared_memory_counts(world) {
+            let before = self.shared_memories.get(&npc).copied().unwrap_or(0);
+            let gained = u32::try_from(count.saturating_sub(before)).unwrap_or(u32::MAX);
+            if gained > 0 && most_seen.is_none_or(|(_, best)| gained > best) {
+                most_seen = Some((npc, gained));
+            }
+        }
+
+        let mut dominant: Option<(MoodBand, u32)> = None;
+        for (band, days) in MOOD_BANDS.iter().zip(self.mood_days) {
+            if days > 0 && dominant.is_none_or(|(_, best)| days > best) {
+                dominant = Some((*band, days));
+            }
+        }
+
+        let mut rarest: Option<(String, u32)> = None;
+        let mut storylets_fired = 0;
+        for (id, count) in storylet_counts(world) {
+            let before = self.storylet_counts.get(&id).copied().unwrap_or(0);
+            let fired = count.saturating_sub(before);
+            if fired == 0 {
+                continue;
+            }
+            storylets_fired += fired;
+            if rarest.as_ref().is_none_or(|(_, best)| count < *best) {
+                rarest = Some((id, count));
+            }
+        }
+
+        YearInReview {
+            year: self.year,
+            start_tick: self.start_tick,
+            end_tick: world.current_tick.0,
+            days: self.days(),
+            most_seen_npc: most_seen.map(|(npc, _)| NpcId(npc)),
+            most_seen_memories: most_seen.map_or(0, |(_, count)| count),
+            dominant_mood: dominant.map(|(band, _)| band),
+            dominant_mood_days: dominant.map_or(0, |(_, days)| days),
+            biggest_karma_swing: self.karma_swing,
+            biggest_karma_swing_tick: self.karma_swing_tick,
+            rarest_storylet_fires: rarest.as_ref().map_or(0, |(_, count)| *count),
+            rarest_storylet: rarest.map(|(id, _)| id),
+            storylets_fired,
+            narrative_events: u32::try_from(
+                world
+                    .external_events
+                    .last_seq
+                    .saturating_sub(self.narrative_seq),
+            )
+            .unwrap_or(u32::MAX),
+        }
+    }
+}
+
+/// Year in Review state stored on the world.
+#[derive(Debug, Clone, PartialEq, Default, Serialize, Deserialize)]
+pub struct YearReviewState {
+    /// Tally for the year in progress (`None` before the first daily update).
+    #[serde(default)]
+    pub tally: Option<YearTally>,
+    /// Closed years, oldest first.
+    #[serde(default)]
+    pub reviews: Vec<YearInReview>,
+}
+
+impl YearReviewState {
+    /// Recap for a closed year.
+    pub fn get(&self, year: u32) -> Option<&YearInReview> {
+        self.reviews.iter().find(|review| review.year == year)
+    }
+
+    /// Calendar year in progress (1 before the first daily update).
+    pub fn current_year(&self) -> u32 {
+        self.tally.as_ref().map_or(1, |tally| tally.year)
+    }
+}
+
+/// Storylet fire counts keyed by storylet id, in id order.
+fn storylet_counts(world: &WorldState) -> BTreeMap<String, u32> {
+    world
+        .storylet_usage
+        .times_fired
+        .iter()
+        .map(|(handle, count)| (handle.as_str().to_string(), *count))
+        .collect()
+}
+
+fn shared_memory_counts(world: &WorldState) -> BTreeMap<u64, usize> {
+    crate::importance::shared_salience_by_npc(world)
+        .into_iter()
+        .map(|(npc, shared)| (npc, shared.memories))
+        .collect()
+}
+
+/// Tally today into the running year, closing it first if the calendar has
+/// wrapped to January 1. Returns the recap of a year closed today.
+///
+/// Runs once a day from `WorldState::tick`, after the calendar has advanced.
+pub fn update_year_review(world: &mut WorldState) -> Option<YearInReview> {
+    let new_year = calendar_date(world).day_of_year == 0;
+    let mut closed = None;
+    let mut tally = match world.year_review.tally.take() {
+        Some(tally) if new_year && tally.days() > 0 => {
+            let review = tally.close(world);
+            world.year_review.reviews.push(review.clone());
+            closed = Some(review);
+            YearTally::start(world, tally.year + 1)
+        }
+        Some(tally) => tally,
+        None => YearTally::start(world, 1),
+    };
+    tally.sample(world);
+    world.year_review.tally = Some(tally);
+    closed
+}
+
+/// Recap for a closed calendar year.
+pub fn year_in_review(world: &WorldState, year: u32) -> Option<&YearInReview> {
+    world.year_review.get(year)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::time::DAYS_PER_YEAR;
+    use crate::types::{MemoryEntryRecord, SimTick, WorldSeed};
+
+    fn advance_day(world: &mut WorldState) -> Option<YearInReview> {
+        world.current_tick = SimTick(world.current_tick.0 + 24);
+        world.player_days_since_birth += 1;
+        update_year_review(world)
+    }
+
+    /// Advance until the calendar reaches January 1, returning the recap.
+    fn advance_to_new_year(world: &mut WorldState) -> YearInReview {
+        for _ in 0..=DAYS_PER_YEAR {
+            if let Some(review) = advance_day(world) {
+                return review;
+            }
+        }
+        panic!("calendar never wrapped");
+    }
+
+    #[test]
+    fn year_closes_on_january_first() {
+        let mut world = WorldState::new(WorldSeed(3), NpcId(1));
+        update_year_review(&mut world);
+        world.player_stats.mood = 5.0;
+        world.player_karma.0 = -30.0;
+
+        let review = advance_to_new_year(&mut world);
+        assert_eq!(review.year, 1);
+        assert_eq!(calendar_date(&world).day_of_year, 0);
+        assert_eq!(review.dominant_mood, Some(MoodBand::High));
+        assert_eq!(review.biggest_karma_swing, -30.0);
+        assert_eq!(world.year_review.current_year(), 2);
+        assert_eq!(year_in_review(&world, 1), Some(&review));
+
+        let second = advance_to_new_year(&mut world);
+        assert_eq!(second.year, 2);
+        assert_eq!(second.days, u32::from(DAYS_PER_YEAR));
+        assert_eq!(second.biggest_karma_swing, 0.0);
+    }
+
+    #[test]
+    fn recap_counts_only_this_years_memories_and_storylets() {
+        let mut world = WorldState::new(WorldSeed(3), NpcId(1));
+        world.storylet_usage.record_fire("yr_old_favorite");
+        world.storylet_usage.record_fire("yr_old_favorite");
+        world.memory_entries.push(MemoryEntryRecord {
+            id: "m0".into(),
+            npc_id: NpcId(1),
+            participants: vec![3],
+            ..Default::default()
+        });
+        update_year_review(&mut world);
+
+        for id in ["m1", "m2"] {
+            world.memory_entries.push(MemoryEntryRecord {
+                id: id.into(),
+                npc_id: NpcId(1),
+                participants: vec![2],
+                ..Default::default()
+            });
+        }
+        world.storylet_usage.record_fire("yr_old_favorite");
+        world.storylet_usage.record_fire("yr_rare_encounter");
+
+        let review = advance_to_new_year(&mut world);
+        assert_eq!(review.most_seen_npc, Some(NpcId(2)));
+        assert_eq!(review.most_seen_memories, 2);
+        assert_eq!(review.storylets_fired, 2);
+        assert_eq!(review.rarest_storylet.as_deref(), Some("yr_rare_encounter"));
+        assert_eq!(review.rarest_storylet_fires, 1);
+    }
+}
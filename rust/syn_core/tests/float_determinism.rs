@@ -0,0 +1,84 @@
+//! Golden bit patterns for the platform-independent float math.
+//!
+//! The expected values were derived with exact f32 arithmetic, independent of
+//! any libm, so a mismatch on any platform means the deterministic core would
+//! drift there.
+
+use syn_core::det_math::{exp2, half_life_factor, log2, powf, quantize};
+
+fn assert_bits(name: &str, actual: f32, expected: u32) {
+    assert_eq!(
+        actual.to_bits(),
+        expected,
+        "{} = {} ({:#010x}), golden {} ({:#010x})",
+        name,
+        actual,
+        actual.to_bits(),
+        f32::from_bits(expected),
+        expected
+    );
+}
+
+#[test]
+fn exp2_matches_golden_bits() {
+    for (x, bits) in [
+        (-3.75, 0x3d98_37f0),
+        (-0.5, 0x3f35_04f3),
+        (0.1, 0x3f89_2fdf),
+        (0.3333, 0x3fa1_4424),
+        (1.5, 0x4035_04f3),
+        (7.25, 0x4318_37f0),
+    ] {
+        assert_bits(&format!("exp2({})", x), exp2(x), bits);
+    }
+}
+
+#[test]
+fn log2_matches_golden_bits() {
+    for (x, bits) in [
+        (0.001, 0xc11f_73da),
+        (0.3, 0xbfde_54e3),
+        (0.75, 0xbed4_7fcc),
+        (1.9, 0x3f6d_0e4c),
+        (10.0, 0x4054_9a78),
+        (1000.0, 0x411f_73da),
+    ] {
+        assert_bits(&format!("log2({})", x), log2(x), bits);
+    }
+}
+
+#[test]
+fn powf_and_decay_match_golden_bits() {
+    for (base, exponent, bits) in [
+        (0.25, 1.5, 0x3e00_0000),
+        (0.6, 2.0, 0x3eb8_51ec),
+        (0.9, 0.5, 0x3f72_dce8),
+        (0.05, 3.0, 0x3903_1270),
+    ] {
+        assert_bits(&format!("powf({}, {})", base, exponent), powf(base, exponent), bits);
+    }
+    for (elapsed, half_life, bits) in [
+        (7.0, 24.0, 0x3f51_2424),
+        (100.0, 168.0, 0x3f29_7496),
+        (1.0, 3.0, 0x3f4b_2ff4),
+    ] {
+        assert_bits(
+            &format!("half_life_factor({}, {})", elapsed, half_life),
+            half_life_factor(elapsed, half_life),
+            bits,
+        );
+    }
+}
+
+#[test]
+fn quantize_matches_golden_bits() {
+    for (x, bits) in [
+        (0.1, 0x3dcd_0000),
+        (-2.5371, 0xc022_6000),
+        (55.55555, 0x425e_3900),
+        // Tiny negatives round to positive zero
+        (-0.00006, 0x0000_0000),
+    ] {
+        assert_bits(&format!("quantize({})", x), quantize(x), bits);
+    }
+}
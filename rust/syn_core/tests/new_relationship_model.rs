@@ -81,3 +81,63 @@ fn apply_relationship_deltas_applies_all() {
     assert_eq!(vec.get(RelationshipAxis::Affection), 3.0);
     assert_eq!(vec.get(RelationshipAxis::Trust), -2.0);
 }
+
+#[test]
+fn tuning_scales_growth_and_decay_per_axis() {
+    let tuning = RelationshipTuning {
+        trust: AxisTuning {
+            growth: 0.5,
+            decay: 2.0,
+            ..AxisTuning::DEFAULT
+        },
+        ..RelationshipTuning::DEFAULT
+    };
+    let mut vec = RelationshipVector::default();
+
+    vec.apply_delta_with(RelationshipAxis::Trust, 2.0, &tuning);
+    assert_eq!(vec.get(RelationshipAxis::Trust), 1.0);
+    vec.apply_delta_with(RelationshipAxis::Trust, -1.0, &tuning);
+    assert_eq!(vec.get(RelationshipAxis::Trust), -1.0);
+
+    // Untuned axes keep the plain clamp.
+    vec.apply_delta_with(RelationshipAxis::Affection, 12.0, &tuning);
+    assert_eq!(vec.get(RelationshipAxis::Affection), 10.0);
+}
+
+#[test]
+fn elasticity_makes_the_last_points_hard_to_reach() {
+    let tuning = RelationshipTuning {
+        affection: AxisTuning {
+            soft_cap: 8.0,
+            elasticity: 0.5,
+            ..AxisTuning::DEFAULT
+        },
+        resentment: AxisTuning {
+            min: 0.0,
+            ..AxisTuning::DEFAULT
+        },
+        ..RelationshipTuning::DEFAULT
+    };
+    let mut vec = RelationshipVector::default();
+
+    // Below the soft cap deltas land in full; past it they shrink.
+    vec.apply_delta_with(RelationshipAxis::Affection, 8.0, &tuning);
+    assert_eq!(vec.get(RelationshipAxis::Affection), 8.0);
+    vec.apply_delta_with(RelationshipAxis::Affection, 1.0, &tuning);
+    assert_eq!(vec.get(RelationshipAxis::Affection), 9.0);
+    vec.apply_delta_with(RelationshipAxis::Affection, 1.0, &tuning);
+    assert!((vec.get(RelationshipAxis::Affection) - 9.75).abs() < 1e-5);
+
+    // Moving back toward zero is not resisted.
+    vec.apply_delta_with(RelationshipAxis::Affection, -1.75, &tuning);
+    assert_eq!(vec.get(RelationshipAxis::Affection), 8.0);
+
+    // The same soft cap resists the negative extreme too.
+    vec.set(RelationshipAxis::Affection, -9.0);
+    vec.apply_delta_with(RelationshipAxis::Affection, -1.0, &tuning);
+    assert!((vec.get(RelationshipAxis::Affection) + 9.75).abs() < 1e-5);
+
+    // Per-axis range.
+    vec.apply_delta_with(RelationshipAxis::Resentment, -3.0, &tuning);
+    assert_eq!(vec.get(RelationshipAxis::Resentment), 0.0);
+}
@@ -29,6 +29,9 @@ fn test_personality_clamp_and_world_prototype_lookup() {
         base_stats: Stats::default(),
         active_stages: vec![LifeStage::Child, LifeStage::Teen],
         schedule: Default::default(),
+        home_district: None,
+        tag_affinities: Default::default(),
+        major: false,
     };
 
     let mut world = WorldState::new(WorldSeed(42), NpcId(1));
@@ -42,3 +45,14 @@ fn test_personality_clamp_and_world_prototype_lookup() {
     world.ensure_npc_known(id);
     assert!(world.known_npcs.contains(&id));
 }
+
+#[test]
+fn test_role_tag_and_stat_kind_names() {
+    assert_eq!(NpcRoleTag::from_name("romantic_interest"), Some(NpcRoleTag::RomanticInterest));
+    assert_eq!(NpcRoleTag::from_name("Mentor"), Some(NpcRoleTag::Mentor));
+    assert_eq!(NpcRoleTag::from_name("sidekick"), None);
+
+    assert_eq!(syn_core::StatKind::from_name("mood"), Some(syn_core::StatKind::Mood));
+    assert_eq!(syn_core::StatKind::from_name("Charisma"), Some(syn_core::StatKind::Charisma));
+    assert_eq!(syn_core::StatKind::from_name("luck"), None);
+}
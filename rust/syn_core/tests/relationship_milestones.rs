@@ -30,6 +30,20 @@ fn enqueue_event_with_roles_and_reason() {
     let tags = vec!["betrayal".to_string()];
 
     state.evaluate_and_record_milestones_for_pair(1, 2, &rel, &tags, Some("test".into()), Some(10));
+    assert!(
+        state.peek_next().is_none(),
+        "role change must be confirmed first"
+    );
+
+    let confirm_at = 10 + state.tuning.confirm_ticks;
+    state.evaluate_and_record_milestones_for_pair(
+        1,
+        2,
+        &rel,
+        &[],
+        Some("drift".into()),
+        Some(confirm_at),
+    );
 
     let event: RelationshipMilestoneEvent = state.pop_next().expect("expected event in queue");
     assert_eq!(event.actor_id, 1);
@@ -40,3 +54,146 @@ fn enqueue_event_with_roles_and_reason() {
     assert_eq!(event.source.as_deref(), Some("test"));
     assert_eq!(event.tick, Some(10));
 }
+
+fn friend_with_resentment(resentment: f32) -> RelationshipVector {
+    RelationshipVector {
+        affection: 6.0,
+        trust: 6.0,
+        attraction: 0.0,
+        familiarity: 0.0,
+        resentment,
+    }
+}
+
+fn betrayal() -> Vec<String> {
+    vec!["betrayal".to_string()]
+}
+
+#[test]
+fn oscillation_inside_confirmation_window_queues_nothing() {
+    let mut state = RelationshipMilestoneState::default();
+    state.record_role_for_pair(1, 2, RelationshipRole::Friend);
+    let confirm = state.tuning.confirm_ticks;
+
+    // Resentment wobbles across the Hostile boundary every few ticks
+    for step in 0..20u64 {
+        let resentment = if step % 2 == 0 { 6.1 } else { 5.9 };
+        let rel = friend_with_resentment(resentment);
+        state.evaluate_and_record_milestones_for_pair(
+            1,
+            2,
+            &rel,
+            &betrayal(),
+            None,
+            Some(step * (confirm / 2)),
+        );
+    }
+
+    assert!(state.queue.is_empty());
+    assert_eq!(state.last_role[&(1, 2)], RelationshipRole::Friend);
+    assert!(state.pending_for_pair(1, 2).is_none());
+}
+
+#[test]
+fn persisted_role_is_confirmed_once() {
+    let mut state = RelationshipMilestoneState::default();
+    state.record_role_for_pair(1, 2, RelationshipRole::Friend);
+    let rival = friend_with_resentment(9.0);
+    let confirm = state.tuning.confirm_ticks;
+
+    for tick in [0, confirm / 2, confirm, confirm + 1, confirm * 3] {
+        state.evaluate_and_record_milestones_for_pair(1, 2, &rival, &betrayal(), None, Some(tick));
+    }
+
+    assert_eq!(state.queue.len(), 1);
+    assert_eq!(state.queue[0].tick, Some(0));
+    assert_eq!(state.last_role[&(1, 2)], RelationshipRole::Rival);
+    assert_eq!(state.history.len(), 1);
+}
+
+#[test]
+fn same_kind_is_on_cooldown_per_pair() {
+    let mut state = RelationshipMilestoneState::default();
+    state.tuning.confirm_ticks = 0;
+    state.tuning.dedup_window_ticks = 0;
+    let cooldown = state.tuning.cooldown_ticks;
+    let friend = friend_with_resentment(0.0);
+    let rival = friend_with_resentment(9.0);
+
+    for pair_target in [2, 3] {
+        state.record_role_for_pair(1, pair_target, RelationshipRole::Friend);
+        state.evaluate_and_record_milestones_for_pair(
+            1,
+            pair_target,
+            &rival,
+            &betrayal(),
+            None,
+            Some(0),
+        );
+    }
+    assert_eq!(state.queue.len(), 2, "one milestone per pair");
+    state.queue.clear();
+
+    // Pair (1, 2) falls out and back in before the cooldown expires
+    state.evaluate_and_record_milestones_for_pair(1, 2, &friend, &[], None, Some(10));
+    state.evaluate_and_record_milestones_for_pair(1, 2, &rival, &betrayal(), None, Some(20));
+    assert!(state.queue.is_empty(), "same kind is on cooldown");
+
+    state.evaluate_and_record_milestones_for_pair(1, 2, &friend, &[], None, Some(cooldown + 10));
+    state.evaluate_and_record_milestones_for_pair(
+        1,
+        2,
+        &rival,
+        &betrayal(),
+        None,
+        Some(cooldown + 20),
+    );
+    assert_eq!(state.queue.len(), 1, "fires again after the cooldown");
+}
+
+#[test]
+fn dedup_window_drops_reversal_milestones() {
+    let mut state = RelationshipMilestoneState::default();
+    state.tuning.confirm_ticks = 0;
+    let window = state.tuning.dedup_window_ticks;
+    state.record_role_for_pair(1, 2, RelationshipRole::Friend);
+
+    state.evaluate_and_record_milestones_for_pair(
+        1,
+        2,
+        &friend_with_resentment(9.0),
+        &betrayal(),
+        None,
+        Some(0),
+    );
+    // Rival -> Friend with shared trauma would be RivalToAlly, but it lands inside the window
+    let trauma = vec!["shared_trauma".to_string()];
+    state.evaluate_and_record_milestones_for_pair(
+        1,
+        2,
+        &friend_with_resentment(0.0),
+        &trauma,
+        None,
+        Some(window - 1),
+    );
+
+    assert_eq!(state.queue.len(), 1);
+    assert_eq!(
+        state.queue[0].kind,
+        RelationshipMilestoneKind::FriendToRival
+    );
+    assert_eq!(state.last_role[&(1, 2)], RelationshipRole::Friend);
+}
+
+#[test]
+fn untimed_evaluation_skips_duplicate_queue_entries() {
+    let mut state = RelationshipMilestoneState::default();
+    let rival = friend_with_resentment(9.0);
+
+    for _ in 0..2 {
+        state.record_role_for_pair(1, 2, RelationshipRole::Friend);
+        state.evaluate_and_record_milestones_for_pair(1, 2, &rival, &betrayal(), None, None);
+    }
+
+    assert_eq!(state.queue.len(), 1);
+}
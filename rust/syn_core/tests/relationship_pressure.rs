@@ -1,5 +1,7 @@
 use syn_core::relationship_model::RelationshipVector;
-use syn_core::relationship_pressure::{RelationshipEventKind, RelationshipPressureState};
+use syn_core::relationship_pressure::{
+    PressureQueueLimits, RelationshipEventKind, RelationshipPressureState,
+};
 
 #[test]
 fn records_band_change_events() {
@@ -160,3 +162,133 @@ fn has_pending_events_and_pending_count() {
     assert!(!pressure.has_pending_events());
     assert_eq!(pressure.pending_count(), 0);
 }
+
+#[test]
+fn same_tick_crossings_are_compacted() {
+    let mut pressure = RelationshipPressureState::default();
+    let low = RelationshipVector {
+        affection: 0.0,
+        trust: 0.0,
+        attraction: 0.0,
+        familiarity: 0.0,
+        resentment: 0.0,
+    };
+    let close = RelationshipVector {
+        affection: 7.0,
+        ..low
+    };
+    let devoted = RelationshipVector {
+        affection: 9.5,
+        ..low
+    };
+
+    pressure.update_for_pair(1, 2, &low, None, Some(5));
+    pressure.update_for_pair(1, 2, &close, None, Some(5));
+    pressure.update_for_pair(1, 2, &devoted, Some("late".to_string()), Some(5));
+
+    assert_eq!(pressure.pending_count(), 1);
+    assert_eq!(pressure.metrics.compacted_events, 1);
+    let event = pressure.peek_next_event().unwrap();
+    assert_eq!(event.old_band, low.affection_band().to_string());
+    assert_eq!(event.new_band, devoted.affection_band().to_string());
+    assert_eq!(event.source.as_deref(), Some("late"));
+
+    // Swinging back within the same tick cancels the event out.
+    pressure.update_for_pair(1, 2, &low, None, Some(5));
+    assert!(!pressure.has_pending_events());
+
+    // A later tick is a separate event.
+    pressure.update_for_pair(1, 2, &close, None, Some(6));
+    assert_eq!(pressure.pending_count(), 1);
+}
+
+#[test]
+fn full_queue_evicts_oldest_low_priority_event() {
+    let mut pressure = RelationshipPressureState {
+        limits: PressureQueueLimits {
+            max_events: 2,
+            max_changed_pairs: 2,
+        },
+        ..Default::default()
+    };
+    let low = RelationshipVector {
+        affection: 0.0,
+        trust: 0.0,
+        attraction: 0.0,
+        familiarity: 0.0,
+        resentment: 0.0,
+    };
+    let resentful = RelationshipVector {
+        resentment: 7.0,
+        ..low
+    };
+    let fond = RelationshipVector {
+        affection: 7.0,
+        ..low
+    };
+
+    pressure.update_for_pair(1, 2, &low, None, Some(1));
+    pressure.update_for_pair(1, 2, &resentful, None, Some(1));
+    pressure.update_for_pair(3, 4, &low, None, Some(2));
+    pressure.update_for_pair(3, 4, &fond, None, Some(2));
+    pressure.update_for_pair(5, 6, &low, None, Some(3));
+    pressure.update_for_pair(5, 6, &fond, None, Some(3));
+
+    assert_eq!(pressure.pending_count(), 2);
+    assert_eq!(pressure.metrics.evicted_events, 1);
+    let kinds: Vec<(u64, RelationshipEventKind)> = pressure
+        .queue
+        .iter()
+        .map(|e| (e.actor_id, e.kind))
+        .collect();
+    assert_eq!(
+        kinds,
+        vec![
+            (1, RelationshipEventKind::ResentmentBandChanged),
+            (5, RelationshipEventKind::AffectionBandChanged),
+        ]
+    );
+
+    assert_eq!(pressure.changed_pairs, vec![(3, 4), (5, 6)]);
+    assert_eq!(pressure.metrics.dropped_pairs, 1);
+
+    pressure.decay_queue(500, 10, 10);
+    assert_eq!(pressure.metrics.expired_events, 2);
+    assert_eq!(pressure.metrics.dropped_events(), 3);
+}
+
+#[test]
+fn change_stamps_track_latest_tick_per_pair() {
+    let mut pressure = RelationshipPressureState {
+        limits: PressureQueueLimits {
+            max_events: 2,
+            max_changed_pairs: 1,
+        },
+        ..Default::default()
+    };
+    let rel = RelationshipVector {
+        affection: 1.0,
+        trust: 0.0,
+        attraction: 0.0,
+        familiarity: 0.0,
+        resentment: 0.0,
+    };
+
+    pressure.update_for_pair(3, 4, &rel, None, Some(5));
+    pressure.update_for_pair(1, 2, &rel, None, Some(9));
+    pressure.stamp_change(3, 4, 12);
+    // Stamps never move back in time.
+    pressure.stamp_change(1, 2, 7);
+    // Changes without a tick are not stamped.
+    pressure.update_for_pair(5, 6, &rel, None, None);
+
+    assert_eq!(pressure.pairs_changed_since(0), vec![(1, 2), (3, 4)]);
+    assert_eq!(pressure.pairs_changed_since(9), vec![(1, 2), (3, 4)]);
+    assert_eq!(pressure.pairs_changed_since(10), vec![(3, 4)]);
+    assert!(pressure.pairs_changed_since(13).is_empty());
+
+    // Unlike changed_pairs, stamps are not dropped by limits or decay.
+    pressure.decay_queue(500, 10, 10);
+    assert!(pressure.changed_pairs.is_empty());
+    assert_eq!(pressure.pairs_changed_since(0).len(), 2);
+}
@@ -11,6 +11,9 @@
 //! 6. Updates state (heat, cooldowns, last_fired, pressures/milestones, queue)
 //! 7. Returns a compact result for the simulation engine to apply
 //!
+//! `dry_run()` runs steps 1-5 against a scratch copy of the director state and
+//! returns a [`DirectorDryRun`] instead, leaving the director untouched.
+//!
 //! # Example
 //!
 //! ```ignore
@@ -119,6 +122,30 @@ impl DirectorStepResult {
     }
 }
 
+/// What a director step would do, computed without mutating the director.
+///
+/// Returned by `CompiledEventDirector::dry_run` for authoring tools that
+/// show a live "what's next" panel.
+#[derive(Debug, Clone, Default)]
+pub struct DirectorDryRun {
+    /// The storylet `step` would fire at this tick, if any.
+    pub would_fire: Option<FiredStorylet>,
+
+    /// Every scored candidate, best selection score first, with the full
+    /// breakdown explaining its rank.
+    pub candidates: Vec<ScoredCandidate>,
+
+    /// Statistics `step` would report.
+    pub stats: StepStats,
+}
+
+impl DirectorDryRun {
+    /// Key of the storylet that would fire, if any.
+    pub fn would_fire_key(&self) -> Option<StoryletKey> {
+        self.would_fire.as_ref().map(|f| f.key)
+    }
+}
+
 /// Statistics about a director step.
 #[derive(Debug, Clone, Default, Serialize, Deserialize)]
 pub struct StepStats {
@@ -0,0 +1,161 @@
+//! Automatic memory tags for outcomes that move relationship axes sharply.
+//!
+//! When an outcome shifts one pair's axis by at least that axis's threshold,
+//! the memory it records is tagged `<axis>_spike` (rise) or `<axis>_loss`
+//! (drop), e.g. `trust_loss` or `attraction_spike`. Memory-gated storylets can
+//! then key off those tags without every author remembering to add them.
+//!
+//! Deltas are netted per (actor, target, axis) first, so an outcome that nudges
+//! trust up and back down again does not count as a spike.
+
+use serde::{Deserialize, Serialize};
+use syn_core::relationship_model::RelationshipAxis;
+
+/// Minimum net delta on one pair's axis that earns a memory tag.
+///
+/// Axes run -10..10. A threshold of 0 or less disables tagging for that axis.
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct AxisTagThresholds {
+    pub affection: f32,
+    pub trust: f32,
+    pub attraction: f32,
+    pub familiarity: f32,
+    pub resentment: f32,
+}
+
+impl Default for AxisTagThresholds {
+    fn default() -> Self {
+        Self {
+            affection: 3.0,
+            trust: 3.0,
+            attraction: 3.0,
+            // Familiarity builds slowly; only a big jump is memorable.
+            familiarity: 4.0,
+            resentment: 3.0,
+        }
+    }
+}
+
+impl AxisTagThresholds {
+    /// Thresholds that never emit a tag.
+    pub fn disabled() -> Self {
+        Self {
+            affection: 0.0,
+            trust: 0.0,
+            attraction: 0.0,
+            familiarity: 0.0,
+            resentment: 0.0,
+        }
+    }
+
+    /// Threshold for one axis.
+    pub fn threshold(&self, axis: RelationshipAxis) -> f32 {
+        match axis {
+            RelationshipAxis::Affection => self.affection,
+            RelationshipAxis::Trust => self.trust,
+            RelationshipAxis::Attraction => self.attraction,
+            RelationshipAxis::Familiarity => self.familiarity,
+            RelationshipAxis::Resentment => self.resentment,
+        }
+    }
+}
+
+/// Content name of an axis, as used in tags.
+pub fn axis_tag_name(axis: RelationshipAxis) -> &'static str {
+    match axis {
+        RelationshipAxis::Affection => "affection",
+        RelationshipAxis::Trust => "trust",
+        RelationshipAxis::Attraction => "attraction",
+        RelationshipAxis::Familiarity => "familiarity",
+        RelationshipAxis::Resentment => "resentment",
+    }
+}
+
+/// Tags earned by a set of `((actor, target), axis, delta)` changes.
+///
+/// Returns sorted, deduplicated tags; empty when nothing crossed a threshold.
+pub fn axis_memory_tags(
+    thresholds: &AxisTagThresholds,
+    deltas: impl IntoIterator<Item = ((u64, u64), RelationshipAxis, f32)>,
+) -> Vec<String> {
+    let mut net: Vec<((u64, u64), RelationshipAxis, f32)> = Vec::new();
+    for (pair, axis, delta) in deltas {
+        match net.iter_mut().find(|(p, a, _)| *p == pair && *a == axis) {
+            Some((_, _, total)) => *total += delta,
+            None => net.push((pair, axis, delta)),
+        }
+    }
+
+    let mut tags: Vec<String> = net
+        .into_iter()
+        .filter_map(|(_, axis, total)| {
+            let threshold = thresholds.threshold(axis);
+            if threshold <= 0.0 {
+                None
+            } else if total >= threshold {
+                Some(format!("{}_spike", axis_tag_name(axis)))
+            } else if total <= -threshold {
+                Some(format!("{}_loss", axis_tag_name(axis)))
+            } else {
+                None
+            }
+        })
+        .collect();
+    tags.sort();
+    tags.dedup();
+    tags
+}
+
+/// Append `extra` tags to `tags`, skipping ones already present.
+pub(crate) fn merge_tags(tags: &mut Vec<String>, extra: Vec<String>) {
+    for tag in extra {
+        if !tags.contains(&tag) {
+            tags.push(tag);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn large_moves_earn_spike_and_loss_tags() {
+        let tags = axis_memory_tags(
+            &AxisTagThresholds::default(),
+            vec![
+                ((1, 2), RelationshipAxis::Trust, -4.0),
+                ((1, 2), RelationshipAxis::Attraction, 3.0),
+                ((1, 2), RelationshipAxis::Affection, 1.0),
+            ],
+        );
+        assert_eq!(tags, vec!["attraction_spike", "trust_loss"]);
+    }
+
+    #[test]
+    fn deltas_net_per_pair_and_respect_disabled_axes() {
+        let thresholds = AxisTagThresholds {
+            resentment: 0.0,
+            ..Default::default()
+        };
+        let tags = axis_memory_tags(
+            &thresholds,
+            vec![
+                // Up and back down on one pair: no spike.
+                ((1, 2), RelationshipAxis::Trust, 3.0),
+                ((1, 2), RelationshipAxis::Trust, -2.0),
+                // Two pairs each below threshold do not add up.
+                ((1, 2), RelationshipAxis::Affection, 2.0),
+                ((1, 3), RelationshipAxis::Affection, 2.0),
+                ((1, 2), RelationshipAxis::Resentment, 8.0),
+            ],
+        );
+        assert!(tags.is_empty());
+        assert!(axis_memory_tags(
+            &AxisTagThresholds::disabled(),
+            vec![((1, 2), RelationshipAxis::Trust, -9.0)],
+        )
+        .is_empty());
+    }
+}
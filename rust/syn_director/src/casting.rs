@@ -0,0 +1,254 @@
+//! Casting open storylet roles from the world.
+//!
+//! A legacy storylet's fixed roles name their NPC. Open roles
+//! ([`StoryletRoles::open`]) only describe who fits: a name, whether the
+//! storylet needs it filled and an optional `constraints` expression. When the
+//! storylet is checked or fired, [`CastingEngine`] picks the best-fitting NPC
+//! for each open role:
+//!
+//! - `constraints` is a [prerequisite expression](crate::prereq_expr) with
+//!   the candidate as its target, so `trust >= Trusted AND
+//!   has_memory(betrayal)` filters on relationship bands and the candidate's
+//!   memories. Candidates it rejects are never cast.
+//! - Candidates are then scored on their fit for the role name (relationship,
+//!   memory tags, tag affinity, major characters, as in
+//!   [`RoleAssignmentEngine`]), their traits, whether they live in the
+//!   player's district and, with a registry, whether they are active.
+//!   [`RoleScoring::weights`] scales each factor (`"role"`, `"traits"`,
+//!   `"district"`, `"active"`; 1.0 when unset).
+//!
+//! Roles are cast in declaration order, each to the highest scorer (ties go
+//! to the lowest id). Nobody plays two roles unless one lists the other in
+//! `allow_same_as`, and NPCs in fixed roles are never recast.
+
+use std::fmt;
+
+use syn_core::{NpcId, SimTick, Traits, WorldState};
+use syn_memory::MemorySystem;
+use syn_sim::{NpcLodTier, NpcRegistry};
+use syn_storylets::RoleSlot as OpenRole;
+
+use crate::prereq_expr::{PrereqEvalContext, PrereqExpr, PrereqExprError};
+use crate::{
+    EligibilityContext, RoleAssignment, RoleAssignmentEngine, RoleScoring, Storylet, StoryletRoles,
+};
+
+/// Bonus for a candidate living in the player's district.
+const DISTRICT_BONUS: f32 = 2.0;
+/// Bonus for a candidate the registry simulates at full fidelity.
+const ACTIVE_BONUS: f32 = 1.0;
+/// Score for a perfect trait fit (every preferred trait at 100).
+const TRAIT_SCALE: f32 = 2.0;
+
+/// Why a storylet's open roles couldn't be cast.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum CastingError {
+    /// A required role has no candidate that satisfies it.
+    Unfilled { role: String },
+    /// A role's `constraints` doesn't parse.
+    BadConstraint { role: String, error: PrereqExprError },
+}
+
+impl fmt::Display for CastingError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            CastingError::Unfilled { role } => write!(f, "no candidate can play role '{}'", role),
+            CastingError::BadConstraint { role, error } => {
+                write!(f, "role '{}' has an invalid constraint: {}", role, error)
+            }
+        }
+    }
+}
+
+impl std::error::Error for CastingError {}
+
+/// Casts a storylet's open roles from the NPCs in the world.
+pub struct CastingEngine<'a> {
+    ctx: EligibilityContext<'a>,
+    registry: Option<&'a NpcRegistry>,
+}
+
+impl<'a> CastingEngine<'a> {
+    pub fn new(world: &'a WorldState, memory: &'a MemorySystem, current_tick: SimTick) -> Self {
+        CastingEngine {
+            ctx: EligibilityContext {
+                world,
+                memory,
+                current_tick,
+            },
+            registry: None,
+        }
+    }
+
+    /// Also cast NPCs from `registry`, favouring the ones it simulates actively.
+    pub fn with_registry(mut self, registry: &'a NpcRegistry) -> Self {
+        self.registry = Some(registry);
+        self
+    }
+
+    /// NPCs who may be cast: the player's known NPCs and, with a registry,
+    /// every NPC it holds, as long as they exist in the world. Sorted by id;
+    /// never includes the player.
+    pub fn candidates(&self) -> Vec<NpcId> {
+        let world = self.ctx.world;
+        let registered = self
+            .registry
+            .into_iter()
+            .flat_map(|registry| registry.instances.keys().copied());
+        let mut candidates: Vec<NpcId> = world
+            .known_npcs
+            .iter()
+            .copied()
+            .chain(registered)
+            .filter(|id| *id != world.player_id && world.npcs.contains_key(id))
+            .collect();
+        candidates.sort_by_key(|id| id.0);
+        candidates.dedup();
+        candidates
+    }
+
+    /// How well `candidate` fits `role`. Higher is better; `-INFINITY` means
+    /// they can't play it.
+    pub fn score(&self, role: &OpenRole, candidate: NpcId, storylet: &Storylet) -> f32 {
+        let world = self.ctx.world;
+        let scoring = &storylet.roles.scoring;
+        let tags = &storylet.prerequisites.tags;
+        let has_tag = |tag: &str| tags.iter().any(|t| t.eq_ignore_ascii_case(tag));
+
+        let role_fit = RoleAssignmentEngine::from_context(&self.ctx)
+            .compute_role_score(&role.name, candidate, &has_tag);
+        let mut score = role_fit * weight(scoring, "role");
+        if let Some(npc) = world.npcs.get(&candidate) {
+            score += trait_fit(&role.name, &npc.traits) * weight(scoring, "traits");
+        }
+        if shares_player_district(world, candidate) {
+            score += DISTRICT_BONUS * weight(scoring, "district");
+        }
+        let active = self
+            .registry
+            .and_then(|registry| registry.get(candidate))
+            .is_some_and(|instance| instance.tier == NpcLodTier::Tier1Active);
+        if active {
+            score += ACTIVE_BONUS * weight(scoring, "active");
+        }
+        score
+    }
+
+    /// Cast `storylet`'s open roles. Optional roles nobody fits are left out
+    /// of the assignment; a required one fails the cast.
+    pub fn cast(&self, storylet: &Storylet) -> Result<RoleAssignment, CastingError> {
+        let roles: &StoryletRoles = &storylet.roles;
+        let fixed: Vec<NpcId> = roles.iter().map(|role| role.npc_id).collect();
+        let candidates = self.candidates();
+        let mut assignment = RoleAssignment::new();
+
+        for role in &roles.open {
+            let constraint = role
+                .constraints
+                .as_deref()
+                .map(PrereqExpr::parse)
+                .transpose()
+                .map_err(|error| CastingError::BadConstraint {
+                    role: role.name.clone(),
+                    error,
+                })?;
+
+            let best = candidates
+                .iter()
+                .copied()
+                .filter(|id| !fixed.contains(id))
+                .filter(|id| can_share(role, *id, &roles.open, &assignment))
+                .filter(|id| {
+                    constraint.iter().all(|expr| {
+                        expr.evaluate(&PrereqEvalContext {
+                            world: self.ctx.world,
+                            memory: self.ctx.memory,
+                            target: Some(*id),
+                        })
+                    })
+                })
+                .map(|id| (id, self.score(role, id, storylet)))
+                .filter(|(_, score)| *score > -f32::INFINITY)
+                .min_by(|a, b| b.1.total_cmp(&a.1).then(a.0 .0.cmp(&b.0 .0)));
+
+            match best {
+                Some((npc_id, _)) => {
+                    assignment.insert(role.name.clone(), npc_id);
+                }
+                None if role.required => {
+                    return Err(CastingError::Unfilled {
+                        role: role.name.clone(),
+                    });
+                }
+                None => {}
+            }
+        }
+        Ok(assignment)
+    }
+}
+
+fn weight(scoring: &RoleScoring, factor: &str) -> f32 {
+    scoring.weights.get(factor).copied().unwrap_or(1.0)
+}
+
+/// Whether `candidate` may play `role` given the roles already cast.
+fn can_share(role: &OpenRole, candidate: NpcId, open: &[OpenRole], cast: &RoleAssignment) -> bool {
+    cast.iter()
+        .filter(|(_, npc_id)| **npc_id == candidate)
+        .all(|(other, _)| {
+            role.allow_same_as.contains(other)
+                || open
+                    .iter()
+                    .any(|slot| slot.name == *other && slot.allow_same_as.contains(&role.name))
+        })
+}
+
+/// Trait fit for a role name, from -`TRAIT_SCALE` to `TRAIT_SCALE`.
+fn trait_fit(role_name: &str, traits: &Traits) -> f32 {
+    let role = role_name.to_lowercase();
+    let (a, b) = if role.contains("rival") || role.contains("antagonist") {
+        (traits.ambition, traits.impulsivity)
+    } else if role.contains("friend") || role.contains("ally") {
+        (traits.empathy, traits.sociability)
+    } else if role.contains("romance") || role.contains("love") {
+        (traits.charm, traits.empathy)
+    } else if role.contains("mentor") || role.contains("guide") {
+        (traits.stability, traits.confidence)
+    } else if role.contains("manager") || role.contains("boss") {
+        (traits.confidence, traits.ambition)
+    } else {
+        (traits.sociability, traits.stability)
+    };
+    ((a + b) / 2.0 - 50.0) / 50.0 * TRAIT_SCALE
+}
+
+/// Whether `npc_id` lives in the same district as the player.
+fn shares_player_district(world: &WorldState, npc_id: NpcId) -> bool {
+    let district = |id: NpcId| {
+        world
+            .npcs
+            .get(&id)
+            .map(|npc| npc.district.as_str())
+            .filter(|district| !district.is_empty())
+    };
+    district(world.player_id).is_some_and(|player| district(npc_id) == Some(player))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn trait_fit_follows_the_role_name() {
+        let traits = Traits {
+            ambition: 100.0,
+            impulsivity: 100.0,
+            empathy: 0.0,
+            sociability: 0.0,
+            ..Traits::default()
+        };
+
+        assert!(trait_fit("rival", &traits) > 1.9);
+        assert!(trait_fit("Best Friend", &traits) < -1.9);
+    }
+}
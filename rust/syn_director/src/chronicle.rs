@@ -0,0 +1,171 @@
+//! The life chronicle: every storylet the legacy director fired.
+//!
+//! [`EventDirector::fire_storylet`] appends a [`ChronicleEntry`] for each
+//! storylet it fires (forced fires included): who was cast, which choice was
+//! taken, what the outcome changed and how hot the world was at the time.
+//! The UI renders it as a life timeline; tools query it by tick, NPC or
+//! storylet.
+
+use serde::{Deserialize, Serialize};
+use syn_core::{NpcId, StatDelta};
+
+use crate::{EventDirector, StoryletOutcome};
+
+/// What a fired storylet's outcome changed, as kept in the chronicle.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct ChronicleOutcome {
+    /// Event ID of the memory the outcome recorded.
+    pub memory_event_id: String,
+    #[serde(default)]
+    pub stat_deltas: Vec<StatDelta>,
+    #[serde(default)]
+    pub karma_delta: Option<f32>,
+    #[serde(default)]
+    pub heat_spike: f32,
+}
+
+impl From<&StoryletOutcome> for ChronicleOutcome {
+    fn from(outcome: &StoryletOutcome) -> Self {
+        Self {
+            memory_event_id: outcome.memory_event_id.clone(),
+            stat_deltas: outcome.stat_deltas.clone(),
+            karma_delta: outcome.karma_delta,
+            heat_spike: outcome.heat_spike,
+        }
+    }
+}
+
+/// One fired storylet.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct ChronicleEntry {
+    /// Position in the chronicle, starting at 1.
+    pub seq: u64,
+    pub storylet_id: String,
+    pub storylet_name: String,
+    /// Tick it fired at.
+    pub tick: u64,
+    /// Role name and NPC for each role, as cast when it fired.
+    pub roles: Vec<(String, NpcId)>,
+    /// The choice whose outcome was applied, if the outcome came from one.
+    #[serde(default)]
+    pub choice_id: Option<String>,
+    pub outcome: ChronicleOutcome,
+    /// Narrative heat just before the outcome applied.
+    pub heat: f32,
+}
+
+impl ChronicleEntry {
+    /// Whether `npc` was cast in any role.
+    pub fn involves(&self, npc: NpcId) -> bool {
+        self.roles.iter().any(|(_, id)| *id == npc)
+    }
+}
+
+/// Every storylet fired through an [`EventDirector`], oldest first.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct Chronicle {
+    entries: Vec<ChronicleEntry>,
+}
+
+impl Chronicle {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Append an entry, numbering it after the last one.
+    pub(crate) fn record(&mut self, mut entry: ChronicleEntry) -> u64 {
+        entry.seq = self.entries.last().map_or(1, |last| last.seq + 1);
+        let seq = entry.seq;
+        self.entries.push(entry);
+        seq
+    }
+
+    /// Every entry, oldest first.
+    pub fn entries(&self) -> &[ChronicleEntry] {
+        &self.entries
+    }
+
+    pub fn len(&self) -> usize {
+        self.entries.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.entries.is_empty()
+    }
+
+    /// The most recently fired storylet.
+    pub fn latest(&self) -> Option<&ChronicleEntry> {
+        self.entries.last()
+    }
+
+    /// Entries fired at or after `tick`.
+    pub fn since(&self, tick: u64) -> Vec<&ChronicleEntry> {
+        self.entries.iter().filter(|entry| entry.tick >= tick).collect()
+    }
+
+    /// Entries fired in `from..=to`.
+    pub fn between(&self, from: u64, to: u64) -> Vec<&ChronicleEntry> {
+        self.entries
+            .iter()
+            .filter(|entry| (from..=to).contains(&entry.tick))
+            .collect()
+    }
+
+    /// Entries casting `npc` in any role.
+    pub fn involving(&self, npc: NpcId) -> Vec<&ChronicleEntry> {
+        self.entries.iter().filter(|entry| entry.involves(npc)).collect()
+    }
+
+    /// Every time `storylet_id` fired.
+    pub fn for_storylet(&self, storylet_id: &str) -> Vec<&ChronicleEntry> {
+        self.entries
+            .iter()
+            .filter(|entry| entry.storylet_id == storylet_id)
+            .collect()
+    }
+}
+
+impl EventDirector {
+    /// Every storylet this director fired, oldest first.
+    pub fn chronicle(&self) -> &Chronicle {
+        &self.chronicle
+    }
+
+    /// Replace the chronicle with one taken from a save.
+    pub fn restore_chronicle(&mut self, chronicle: Chronicle) {
+        self.chronicle = chronicle;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn entry(tick: u64, npc: u64) -> ChronicleEntry {
+        ChronicleEntry {
+            seq: 0,
+            storylet_id: format!("beat_{}", tick),
+            storylet_name: String::new(),
+            tick,
+            roles: vec![("friend".to_string(), NpcId(npc))],
+            choice_id: None,
+            outcome: ChronicleOutcome::default(),
+            heat: 0.0,
+        }
+    }
+
+    #[test]
+    fn entries_are_numbered_and_queried_by_tick_and_npc() {
+        let mut chronicle = Chronicle::new();
+        for (tick, npc) in [(1, 2), (5, 3), (5, 2), (9, 4)] {
+            chronicle.record(entry(tick, npc));
+        }
+
+        assert_eq!(chronicle.latest().map(|e| e.seq), Some(4));
+        assert_eq!(chronicle.since(5).len(), 3);
+        assert_eq!(chronicle.between(2, 5).len(), 2);
+        assert!(chronicle.between(6, 8).is_empty());
+        assert_eq!(chronicle.involving(NpcId(2)).len(), 2);
+        assert_eq!(chronicle.for_storylet("beat_9")[0].seq, 4);
+    }
+}
@@ -9,6 +9,7 @@
 //! storylets from `syn_storylets`.
 
 use crate::config::DirectorConfig;
+use crate::outcome_validation::{OutcomeValidationError, ResolvedOutcome, ResolvedOutcomeTable};
 use crate::pacing;
 use crate::pipeline::{CandidateSet, EligibilityPipeline, IndexPrefilterParams};
 use crate::queue::{QueuedEvent, QueueSource};
@@ -44,6 +45,9 @@ pub struct CompiledEventDirector<S: StoryletSource> {
     
     /// Immutable configuration parameters.
     config: DirectorConfig,
+
+    /// Outcomes resolved when the library was handed over, plus quarantined storylets.
+    outcomes: ResolvedOutcomeTable,
 }
 
 impl<S: StoryletSource> CompiledEventDirector<S> {
@@ -51,10 +55,12 @@ impl<S: StoryletSource> CompiledEventDirector<S> {
     ///
     /// Initializes DirectorState with sensible defaults (tick 0, no heat, LowKey phase).
     pub fn new(storylets: S, config: DirectorConfig) -> Self {
+        let outcomes = ResolvedOutcomeTable::resolve(&storylets);
         CompiledEventDirector {
             storylets,
             state: DirectorState::new(),
             config,
+            outcomes,
         }
     }
 
@@ -87,10 +93,12 @@ impl<S: StoryletSource> CompiledEventDirector<S> {
         snapshot: crate::persistence::DirectorSnapshot,
     ) -> Self {
         // Note: We could validate snapshot.config_version here if needed
+        let outcomes = ResolvedOutcomeTable::resolve(&storylets);
         CompiledEventDirector {
             storylets,
             state: snapshot.state,
             config,
+            outcomes,
         }
     }
 
@@ -299,7 +307,7 @@ impl<S: StoryletSource> CompiledEventDirector<S> {
             &self.state,
             &self.config,
         );
-        pipeline.run(ctx)
+        self.without_quarantined(pipeline.run(ctx))
     }
 
     /// Compute candidates with custom index prefilter parameters.
@@ -316,7 +324,33 @@ impl<S: StoryletSource> CompiledEventDirector<S> {
             &self.state,
             &self.config,
         );
-        pipeline.run_with_params(ctx, params)
+        self.without_quarantined(pipeline.run_with_params(ctx, params))
+    }
+
+    /// Drop quarantined storylets from every filtered stage of a candidate set.
+    fn without_quarantined(&self, mut set: CandidateSet) -> CandidateSet {
+        for stage in [
+            &mut set.after_index_prefilter,
+            &mut set.after_prereq_filter,
+            &mut set.after_cooldown_filter,
+            &mut set.after_pacing_filter,
+        ] {
+            stage.retain(|key| !self.outcomes.is_quarantined(*key));
+        }
+        set
+    }
+
+    /// Pre-resolved outcome for a storylet, if it passed load-time validation.
+    ///
+    /// Callers applying a fired storylet should use this instead of matching
+    /// stat, axis and role names themselves.
+    pub fn resolved_outcome(&self, key: StoryletKey) -> Option<&ResolvedOutcome> {
+        self.outcomes.get(key)
+    }
+
+    /// Validation errors for storylets quarantined when the library was loaded.
+    pub fn quarantined(&self) -> impl Iterator<Item = &OutcomeValidationError> + '_ {
+        self.outcomes.errors()
     }
 
     /// Score a storylet for selection priority.
@@ -435,6 +469,9 @@ impl<S: StoryletSource> CompiledEventDirector<S> {
         };
 
         for &key in eligible_keys {
+            if self.outcomes.is_quarantined(key) {
+                continue;
+            }
             if let Some(storylet) = self.storylets.get_storylet_by_key(key) {
                 // Verify role assignment is possible
                 let role_engine = RoleAssignmentEngine::from_context(&ctx);
@@ -501,6 +538,7 @@ impl<S: StoryletSource> CompiledEventDirector<S> {
             &storylet.tags,
             tick,
         );
+        self.state.starvation.reset(storylet.key);
 
         // Apply cooldowns from storylet config
         if let Some(global_ticks) = storylet.cooldowns.global_cooldown_ticks {
@@ -597,9 +635,11 @@ impl<S: StoryletSource> CompiledEventDirector<S> {
     ) -> SelectionResult {
         // First, check for forced events
         let forced_events = self.state.pending_queue.pop_forced_ready(self.state.tick);
-        if !forced_events.is_empty() {
+        let forced = forced_events
+            .iter()
+            .find(|e| !self.outcomes.is_quarantined(e.storylet_key));
+        if let Some(event) = forced {
             // Return the first forced event (highest priority)
-            let event = &forced_events[0];
             return SelectionResult {
                 selected_key: Some(event.storylet_key),
                 from_queue: true,
@@ -610,13 +650,15 @@ impl<S: StoryletSource> CompiledEventDirector<S> {
 
         // Get regular queued events (non-forced)
         let queued_events = self.state.pending_queue.pop_ready(self.state.tick);
+        let queued = queued_events
+            .iter()
+            .find(|e| !self.outcomes.is_quarantined(e.storylet_key));
         
         // If we have queued events, they compete with fresh candidates
-        if !queued_events.is_empty() {
+        if let Some(event) = queued {
             // For now, return the highest-priority queued event
             // In a more sophisticated implementation, queued events would be scored
             // alongside fresh candidates
-            let event = &queued_events[0];
             return SelectionResult {
                 selected_key: Some(event.storylet_key),
                 from_queue: true,
@@ -654,7 +696,7 @@ impl<S: StoryletSource> CompiledEventDirector<S> {
     /// 3. Runs the eligibility pipeline for fresh candidates
     /// 4. Combines queued and fresh candidates
     /// 5. Scores and selects a storylet deterministically
-    /// 6. Updates state (heat, cooldowns, last_fired, pressures/milestones)
+    /// 6. Updates state (heat, cooldowns, last_fired, starvation, pressures/milestones)
     /// 7. Returns a compact result for the simulation engine to apply
     ///
     /// # Arguments
@@ -685,86 +727,32 @@ impl<S: StoryletSource> CompiledEventDirector<S> {
         tick: SimTick, 
         ctx: &'a EligibilityContext<'a>
     ) -> crate::api::DirectorStepResult {
-        use crate::api::{DirectorStepResult, FiredStorylet, StepStats};
-        use crate::pipeline::EligibilityPipeline;
+        use crate::api::{DirectorStepResult, FiredStorylet};
         use crate::pressure;
-        use crate::scoring::ScoringEngine;
         
-        // 1. Update tick and pacing (on_tick_advance calls pacing::on_tick_start internally)
-        self.on_tick_advance(tick);
-        
-        // 2. Tick pressures and milestones
-        pressure::tick_pressures(&mut self.state, &self.config.pressure, tick);
-        
-        // 3. Check for pressure crises and queue forced events
-        let crisis_events = pressure::check_pressure_crises(&self.state, &self.config.pressure, tick);
-        for event in crisis_events {
-            self.state.pending_queue.push(event, self.config.queue.max_size);
-        }
-        
-        // 4. Check for milestone climaxes and queue events
-        let climax_events = pressure::check_milestone_climaxes(&mut self.state.milestones, tick);
-        for event in climax_events {
-            self.state.pending_queue.push(event, self.config.queue.max_size);
-        }
-        
-        // 5. Dequeue ready events
-        let ready_from_queue = self.state.pending_queue.pop_ready(tick);
-        
-        // 6. Run the eligibility pipeline for fresh candidates
-        let pipeline = EligibilityPipeline::new(&self.storylets, &self.state, &self.config);
-        let candidate_set = pipeline.run(ctx);
-        let fresh_keys = candidate_set.final_candidates();
-        
-        // 7. Merge queued and fresh candidates
-        let mut merged_keys: Vec<StoryletKey> = Vec::with_capacity(
-            ready_from_queue.len() + fresh_keys.len()
+        // 1-10. Advance time and pressures, gather and score candidates
+        let plan = Self::plan_step(
+            &self.storylets,
+            &self.config,
+            &self.outcomes,
+            &mut self.state,
+            tick,
+            ctx,
         );
-        
-        // Add queue storylets (they have priority in scoring)
-        for qe in &ready_from_queue {
-            if !merged_keys.contains(&qe.storylet_key) {
-                merged_keys.push(qe.storylet_key);
-            }
-        }
-        
-        // Add fresh candidates not already in queue
-        for &key in fresh_keys {
-            if !merged_keys.contains(&key) {
-                merged_keys.push(key);
-            }
-        }
-        
-        // 8. Early exit if no candidates
-        if merged_keys.is_empty() {
+        let Some(scoring_results) = &plan.scoring else {
             return DirectorStepResult {
                 fired: None,
                 #[cfg(feature = "debug_candidates")]
                 debug_candidates: None,
-                stats: StepStats {
-                    queue_ready_count: ready_from_queue.len(),
-                    fresh_candidate_count: fresh_keys.len(),
-                    merged_candidate_count: 0,
-                    viable_candidate_count: 0,
-                    narrative_heat: self.state.narrative_heat,
-                    narrative_phase: format!("{:?}", self.state.narrative_phase),
-                },
+                stats: plan.stats(&self.state),
             };
-        }
-        
-        // 9. Get compiled storylets for scoring
-        let storylets: Vec<_> = merged_keys.iter()
-            .filter_map(|&key| self.storylets.get_storylet_by_key(key))
-            .collect();
+        };
         
-        // 10. Score candidates
-        let scoring_engine = ScoringEngine::new(
-            &self.config.scoring,
-            &self.config.pacing,
-            &self.state,
-            ctx.world.seed.0,
+        // Age every candidate that was passed over; the winner is reset.
+        self.state.starvation.record_pass(
+            &plan.merged_keys,
+            scoring_results.selected.as_ref().map(|c| c.key),
         );
-        let scoring_results = scoring_engine.score_and_select(&storylets, ctx.world);
         
         // 11. Pick the winning candidate
         let selected = match &scoring_results.selected {
@@ -774,15 +762,8 @@ impl<S: StoryletSource> CompiledEventDirector<S> {
                 return DirectorStepResult {
                     fired: None,
                     #[cfg(feature = "debug_candidates")]
-                    debug_candidates: Some(scoring_results.candidates),
-                    stats: StepStats {
-                        queue_ready_count: ready_from_queue.len(),
-                        fresh_candidate_count: fresh_keys.len(),
-                        merged_candidate_count: merged_keys.len(),
-                        viable_candidate_count: scoring_results.stats.viable_count,
-                        narrative_heat: self.state.narrative_heat,
-                        narrative_phase: format!("{:?}", self.state.narrative_phase),
-                    },
+                    debug_candidates: Some(scoring_results.candidates.clone()),
+                    stats: plan.stats(&self.state),
                 };
             }
         };
@@ -843,30 +824,202 @@ impl<S: StoryletSource> CompiledEventDirector<S> {
         }
         
         // 14. Determine if this came from the queue
-        let is_from_queue = ready_from_queue.iter().any(|qe| qe.storylet_key == chosen_key);
-        let queue_source = ready_from_queue
-            .iter()
-            .find(|qe| qe.storylet_key == chosen_key)
-            .map(|qe| qe.source);
+        let queue_source = plan.queue_source(chosen_key);
         
         // 15. Build the result
         let fired = FiredStorylet::new(
             chosen_key,
             selected,
-            is_from_queue,
+            queue_source.is_some(),
             queue_source,
         );
         
-        let stats = StepStats {
-            queue_ready_count: ready_from_queue.len(),
-            fresh_candidate_count: fresh_keys.len(),
-            merged_candidate_count: merged_keys.len(),
-            viable_candidate_count: scoring_results.stats.viable_count,
-            narrative_heat: self.state.narrative_heat,
-            narrative_phase: format!("{:?}", self.state.narrative_phase),
+        DirectorStepResult::with_fired(fired, plan.stats(&self.state))
+    }
+
+    /// Preview what [`step`](Self::step) would fire at `tick`, and why.
+    ///
+    /// Runs the same pipeline against a scratch copy of the director state,
+    /// so nothing observable changes: queued and pressure-crisis events stay
+    /// queued, cooldowns and starvation counters are untouched, and selection
+    /// stays seeded purely from `(world seed, tick)`. Dev tools can call this
+    /// every frame; the next real `step` at the same tick picks the same
+    /// storylet.
+    pub fn dry_run<'a>(
+        &self,
+        tick: SimTick,
+        ctx: &'a EligibilityContext<'a>,
+    ) -> crate::api::DirectorDryRun {
+        use crate::api::{DirectorDryRun, FiredStorylet};
+
+        let mut scratch = self.state.clone();
+        let plan = Self::plan_step(
+            &self.storylets,
+            &self.config,
+            &self.outcomes,
+            &mut scratch,
+            tick,
+            ctx,
+        );
+        let stats = plan.stats(&scratch);
+        let Some(scoring) = plan.scoring.as_ref() else {
+            return DirectorDryRun {
+                would_fire: None,
+                candidates: Vec::new(),
+                stats,
+            };
         };
+
+        let would_fire = scoring.selected.clone().map(|selected| {
+            let queue_source = plan.queue_source(selected.key);
+            FiredStorylet::new(selected.key, selected, queue_source.is_some(), queue_source)
+        });
+        let mut candidates = scoring.candidates.clone();
+        candidates.sort_by(|a, b| {
+            b.selection_score
+                .total_cmp(&a.selection_score)
+                .then(a.key.cmp(&b.key))
+        });
+
+        DirectorDryRun {
+            would_fire,
+            candidates,
+            stats,
+        }
+    }
+
+    /// Selection half of [`step`](Self::step): advance `state` to `tick`,
+    /// queue pressure crises and milestone climaxes, drain ready events, and
+    /// score queued plus fresh candidates.
+    ///
+    /// Takes the director's parts separately so `dry_run` can pass a scratch
+    /// state.
+    fn plan_step(
+        storylets: &S,
+        config: &DirectorConfig,
+        outcomes: &ResolvedOutcomeTable,
+        state: &mut DirectorState,
+        tick: SimTick,
+        ctx: &EligibilityContext<'_>,
+    ) -> StepPlan {
+        use crate::pressure;
+
+        // 1. Update tick and pacing
+        state.tick = tick;
+        pacing::on_tick_start(state, &config.pacing);
+        
+        // 2. Tick pressures and milestones
+        pressure::tick_pressures(state, &config.pressure, tick);
+        
+        // 3. Check for pressure crises and queue forced events
+        let crisis_events = pressure::check_pressure_crises(state, &config.pressure, tick);
+        for event in crisis_events {
+            state.pending_queue.push(event, config.queue.max_size);
+        }
+        
+        // 4. Check for milestone climaxes and queue events
+        let climax_events = pressure::check_milestone_climaxes(&mut state.milestones, tick);
+        for event in climax_events {
+            state.pending_queue.push(event, config.queue.max_size);
+        }
+        
+        // 5. Dequeue ready events
+        let ready_from_queue = state.pending_queue.pop_ready(tick);
+        
+        // 6. Run the eligibility pipeline for fresh candidates
+        let pipeline = EligibilityPipeline::new(storylets, state, config);
+        let candidate_set = pipeline.run(ctx);
+        let fresh_keys = candidate_set.final_candidates();
+        
+        // 7. Merge queued and fresh candidates
+        let mut merged_keys: Vec<StoryletKey> = Vec::with_capacity(
+            ready_from_queue.len() + fresh_keys.len()
+        );
+        
+        // Add queue storylets (they have priority in scoring)
+        for qe in &ready_from_queue {
+            if !merged_keys.contains(&qe.storylet_key)
+                && !outcomes.is_quarantined(qe.storylet_key)
+            {
+                merged_keys.push(qe.storylet_key);
+            }
+        }
+        
+        // Add fresh candidates not already in queue
+        for &key in fresh_keys {
+            if !merged_keys.contains(&key) && !outcomes.is_quarantined(key) {
+                merged_keys.push(key);
+            }
+        }
+        let fresh_candidate_count = fresh_keys.len();
+        
+        // 8. Early exit if no candidates
+        if merged_keys.is_empty() {
+            return StepPlan {
+                ready_from_queue,
+                fresh_candidate_count,
+                merged_keys,
+                scoring: None,
+            };
+        }
+        
+        // 9. Get compiled storylets for scoring
+        let candidates: Vec<_> = merged_keys.iter()
+            .filter_map(|&key| storylets.get_storylet_by_key(key))
+            .collect();
         
-        DirectorStepResult::with_fired(fired, stats)
+        // 10. Score candidates
+        let scoring_engine = ScoringEngine::new(
+            &config.scoring,
+            &config.pacing,
+            state,
+            ctx.world.seed.0,
+        );
+        let scoring = scoring_engine.score_and_select(&candidates, ctx.world);
+        
+        StepPlan {
+            ready_from_queue,
+            fresh_candidate_count,
+            merged_keys,
+            scoring: Some(scoring),
+        }
+    }
+}
+
+/// Candidates gathered and scored by the selection half of a director step.
+struct StepPlan {
+    /// Queued events that were ready at the step's tick.
+    ready_from_queue: Vec<QueuedEvent>,
+    /// Candidates produced by the eligibility pipeline.
+    fresh_candidate_count: usize,
+    /// Queued plus fresh candidates, minus quarantined storylets.
+    merged_keys: Vec<StoryletKey>,
+    /// Scoring results, or `None` when there were no candidates.
+    scoring: Option<ScoringResults>,
+}
+
+impl StepPlan {
+    /// Queue source of a candidate, if it came from the queue.
+    fn queue_source(&self, key: StoryletKey) -> Option<QueueSource> {
+        self.ready_from_queue
+            .iter()
+            .find(|qe| qe.storylet_key == key)
+            .map(|qe| qe.source)
+    }
+
+    /// Step statistics, reading heat and phase from `state`.
+    fn stats(&self, state: &DirectorState) -> crate::api::StepStats {
+        crate::api::StepStats {
+            queue_ready_count: self.ready_from_queue.len(),
+            fresh_candidate_count: self.fresh_candidate_count,
+            merged_candidate_count: self.merged_keys.len(),
+            viable_candidate_count: self
+                .scoring
+                .as_ref()
+                .map_or(0, |scoring| scoring.stats.viable_count),
+            narrative_heat: state.narrative_heat,
+            narrative_phase: format!("{:?}", state.narrative_phase),
+        }
     }
 }
 
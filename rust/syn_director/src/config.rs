@@ -213,6 +213,20 @@ pub struct ScoringConfig {
     
     /// Minimum weight below which storylets are excluded.
     pub min_viable_weight: f32,
+    
+    /// Bonus per tick a storylet has been eligible but not selected.
+    /// Lets niche content eventually beat perennial high-weight storylets.
+    pub starvation_bonus_per_tick: f32,
+    
+    /// Ticks of starvation tolerated before the aging bonus starts.
+    pub starvation_grace_ticks: u64,
+    
+    /// Maximum aging bonus for starved storylets.
+    pub max_starvation_bonus: f32,
+    
+    /// Pacing penalty for a max-heat storylet when the player is exhausted.
+    /// Scales with player fatigue and storylet heat.
+    pub fatigue_penalty: f32,
 }
 
 impl Default for ScoringConfig {
@@ -225,6 +239,10 @@ impl Default for ScoringConfig {
             recency_decay_ticks: 48, // ~2 days game time
             variety_bonus: 1.2,
             min_viable_weight: 0.1,
+            starvation_bonus_per_tick: 0.05,
+            starvation_grace_ticks: 12,      // ~half a day
+            max_starvation_bonus: 2.0,       // at most triples the score
+            fatigue_penalty: 0.5,
         }
     }
 }
@@ -487,6 +505,8 @@ mod tests {
         assert!(scoring.pressure_match_bonus > 1.0);
         assert!(scoring.personality_match_bonus > 1.0);
         assert!(scoring.variety_bonus > 1.0);
+        assert!(scoring.starvation_bonus_per_tick > 0.0);
+        assert!(scoring.max_starvation_bonus > 0.0);
     }
 
     #[test]
@@ -0,0 +1,144 @@
+//! Per-storylet and per-NPC cooldowns for the legacy
+//! [`EventDirector`](crate::EventDirector).
+
+use std::collections::HashMap;
+
+use serde::{Deserialize, Serialize};
+use syn_core::{NpcId, SimTick, StoryletHandle};
+
+/// Cooldown tracker to prevent storylet repetition.
+///
+/// Keyed by interned [`StoryletHandle`]s so hot-path checks never allocate.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub(crate) struct CooldownTracker {
+    global_cooldowns: HashMap<StoryletHandle, SimTick>, // storylet -> until_tick
+    npc_cooldowns: HashMap<(StoryletHandle, NpcId), SimTick>, // (storylet, npc_id) -> until_tick
+}
+
+/// One active cooldown, in a form that survives a save file.
+///
+/// Storylet handles are process-local, so entries name the storylet by ID.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct CooldownEntry {
+    pub storylet_id: String,
+    /// The NPC the cooldown applies to, or `None` for the global cooldown.
+    #[serde(default)]
+    pub npc_id: Option<NpcId>,
+    pub until: SimTick,
+}
+
+impl CooldownTracker {
+    pub(crate) fn new() -> Self {
+        CooldownTracker {
+            global_cooldowns: HashMap::new(),
+            npc_cooldowns: HashMap::new(),
+        }
+    }
+
+    pub(crate) fn is_ready(
+        &self,
+        handle: StoryletHandle,
+        npc_id: NpcId,
+        current_tick: SimTick,
+    ) -> bool {
+        let global_ready = self
+            .global_cooldowns
+            .get(&handle)
+            .map(|until| current_tick.0 >= until.0)
+            .unwrap_or(true);
+
+        let npc_ready = self
+            .npc_cooldowns
+            .get(&(handle, npc_id))
+            .map(|until| current_tick.0 >= until.0)
+            .unwrap_or(true);
+
+        global_ready && npc_ready
+    }
+
+    pub(crate) fn mark_cooldown(
+        &mut self,
+        handle: StoryletHandle,
+        npc_id: NpcId,
+        cooldown_ticks: u32,
+        current_tick: SimTick,
+    ) {
+        let until = SimTick::new(current_tick.0 + cooldown_ticks as u64);
+        self.global_cooldowns.insert(handle, until);
+        self.npc_cooldowns.insert((handle, npc_id), until);
+    }
+
+    /// Every cooldown, sorted so saves are stable.
+    pub(crate) fn entries(&self) -> Vec<CooldownEntry> {
+        let global = self.global_cooldowns.iter().map(|(handle, until)| CooldownEntry {
+            storylet_id: handle.as_str().to_string(),
+            npc_id: None,
+            until: *until,
+        });
+        let per_npc = self
+            .npc_cooldowns
+            .iter()
+            .map(|((handle, npc_id), until)| CooldownEntry {
+                storylet_id: handle.as_str().to_string(),
+                npc_id: Some(*npc_id),
+                until: *until,
+            });
+        let mut entries: Vec<CooldownEntry> = global.chain(per_npc).collect();
+        entries.sort_by(|a, b| (&a.storylet_id, a.npc_id).cmp(&(&b.storylet_id, b.npc_id)));
+        entries
+    }
+
+    /// Drop the cooldowns of every storylet `keep` rejects.
+    pub(crate) fn retain_storylets(&mut self, keep: impl Fn(StoryletHandle) -> bool) {
+        self.global_cooldowns.retain(|handle, _| keep(*handle));
+        self.npc_cooldowns.retain(|(handle, _), _| keep(*handle));
+    }
+
+    /// Replace every cooldown with `entries`.
+    pub(crate) fn restore(&mut self, entries: &[CooldownEntry]) {
+        self.global_cooldowns.clear();
+        self.npc_cooldowns.clear();
+        for entry in entries {
+            let handle = StoryletHandle::new(&entry.storylet_id);
+            match entry.npc_id {
+                Some(npc_id) => {
+                    self.npc_cooldowns.insert((handle, npc_id), entry.until);
+                }
+                None => {
+                    self.global_cooldowns.insert(handle, entry.until);
+                }
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn cooldown_blocks_until_it_expires() {
+        let storylet = StoryletHandle::new("cooldown_test_storylet");
+        let mut tracker = CooldownTracker::new();
+        assert!(tracker.is_ready(storylet, NpcId(2), SimTick(0)));
+
+        tracker.mark_cooldown(storylet, NpcId(2), 10, SimTick(5));
+        assert!(!tracker.is_ready(storylet, NpcId(2), SimTick(14)));
+        // The global cooldown holds for other NPCs too
+        assert!(!tracker.is_ready(storylet, NpcId(3), SimTick(14)));
+        assert!(tracker.is_ready(storylet, NpcId(2), SimTick(15)));
+    }
+
+    #[test]
+    fn entries_restore_the_same_cooldowns() {
+        let storylet = StoryletHandle::new("cooldown_restore_storylet");
+        let mut tracker = CooldownTracker::new();
+        tracker.mark_cooldown(storylet, NpcId(4), 10, SimTick(0));
+
+        let mut restored = CooldownTracker::new();
+        restored.restore(&tracker.entries());
+        assert_eq!(restored.entries(), tracker.entries());
+        assert!(!restored.is_ready(storylet, NpcId(5), SimTick(9)));
+        assert!(restored.is_ready(storylet, NpcId(4), SimTick(10)));
+    }
+}
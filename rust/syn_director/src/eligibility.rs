@@ -8,18 +8,22 @@
 //! - World state flags and conditions
 //! - Cooldowns (global, per-actor, per-relationship, per-district)
 //! - Life stages
+//! - Age gating of mature domains and tags (see `syn_storylets::age_gating`)
+//! - Domain unlocks (the underworld domain needs underworld exposure)
 
 use syn_core::{SimTick, StatKind, WorldState};
 use syn_core::LifeStage as CoreLifeStage;
 use syn_memory::MemorySystem;
 use syn_storylets::library::StoryletKey;
-use syn_storylets::{Prerequisites, GlobalFlags, WorldStatePrerequisites, MemoryPrerequisites};
+use syn_storylets::{
+    GlobalFlags, MemoryPrerequisites, Prerequisites, StoryDomain, WorldStatePrerequisites,
+};
 
 use crate::StoryletSource;
 
 /// Convert from syn_core::LifeStage to syn_storylets::LifeStage.
 /// PreSim maps to Child since PreSim is not playable and similar to Child in terms of content.
-fn convert_core_life_stage_to_storylet(core_stage: CoreLifeStage) -> syn_storylets::LifeStage {
+pub(crate) fn convert_core_life_stage_to_storylet(core_stage: CoreLifeStage) -> syn_storylets::LifeStage {
     match core_stage {
         CoreLifeStage::PreSim => syn_storylets::LifeStage::Child, // Not playable, default to Child
         CoreLifeStage::Child => syn_storylets::LifeStage::Child,
@@ -32,9 +36,7 @@ fn convert_core_life_stage_to_storylet(core_stage: CoreLifeStage) -> syn_storyle
 }
 
 /// Convert from syn_storylets::LifeStage to syn_core::LifeStage.
-/// (Currently unused but kept for future integration)
-#[allow(dead_code)]
-fn convert_storylet_life_stage_to_core(story_stage: syn_storylets::LifeStage) -> CoreLifeStage {
+pub(crate) fn convert_storylet_life_stage_to_core(story_stage: syn_storylets::LifeStage) -> CoreLifeStage {
     match story_stage {
         syn_storylets::LifeStage::Child => CoreLifeStage::Child,
         syn_storylets::LifeStage::Teen => CoreLifeStage::Teen,
@@ -45,6 +47,79 @@ fn convert_storylet_life_stage_to_core(story_stage: syn_storylets::LifeStage) ->
     }
 }
 
+/// Check a compiled storylet against the player's content preferences.
+///
+/// Blocked domains and tags remove a storylet from selection; mandatory
+/// stage-entry content is always allowed (see `syn_core::content_preferences`).
+pub fn content_allowed(
+    storylet: &syn_storylets::library::CompiledStorylet,
+    world: &WorldState,
+) -> bool {
+    world.content_preferences.allows(
+        Some(storylet.domain.as_str()),
+        storylet.tags.iter().map(|t| t.0.as_str()),
+    )
+}
+
+/// Check a compiled storylet against the age-gating map.
+///
+/// Applies to every storylet whatever its authored life stages: mature
+/// domains and tags never reach a player younger than their minimum stage.
+pub fn age_appropriate(
+    storylet: &syn_storylets::library::CompiledStorylet,
+    world: &WorldState,
+) -> bool {
+    syn_storylets::age_gating::age_appropriate(
+        convert_core_life_stage_to_storylet(world.player_life_stage),
+        Some(storylet.domain),
+        storylet.tags.iter().map(|t| t.0.as_str()),
+    )
+}
+
+/// Whether the player has unlocked a storylet domain.
+///
+/// Every domain is open except [`StoryDomain::Underworld`], which needs the
+/// player's underworld exposure to reach `Connected` (see `syn_core::underworld`).
+/// Once the PostLife chapter opens (see `syn_core::post_life`), only
+/// [`StoryDomain::Digital`] remains.
+pub fn domain_unlocked(domain: StoryDomain, world: &WorldState) -> bool {
+    if world.post_life.is_active() {
+        return domain == StoryDomain::Digital;
+    }
+    domain != StoryDomain::Underworld || world.underworld.domain_unlocked()
+}
+
+/// Check if a relationship meets all the specified axis thresholds.
+///
+/// Unknown axis names are ignored.
+pub(crate) fn relationship_meets_thresholds(
+    relationship: &syn_core::types::Relationship,
+    thresholds: &[syn_storylets::RelationshipThreshold],
+) -> bool {
+    for threshold in thresholds {
+        let axis_value = match threshold.axis.to_lowercase().as_str() {
+            "affection" => relationship.affection,
+            "trust" => relationship.trust,
+            "attraction" => relationship.attraction,
+            "familiarity" => relationship.familiarity,
+            "resentment" => relationship.resentment,
+            _ => continue, // Unknown axis, skip
+        };
+
+        if let Some(min) = threshold.min {
+            if axis_value < min {
+                return false;
+            }
+        }
+        if let Some(max) = threshold.max {
+            if axis_value > max {
+                return false;
+            }
+        }
+    }
+    true
+}
+
 /// Context needed to evaluate storylet eligibility.
 ///
 /// Contains all references necessary to check prerequisites without coupling to specific systems.
@@ -96,7 +171,10 @@ impl<'a, S: StoryletSource> EligibilityEngine<'a, S> {
         // Check detailed prerequisites for each candidate
         for key in candidates {
             if let Some(storylet) = self.storylets.get_storylet_by_key(key) {
-                if self.is_storylet_eligible(&storylet, ctx) {
+                if content_allowed(storylet, ctx.world)
+                    && age_appropriate(storylet, ctx.world)
+                    && self.is_storylet_eligible(storylet, ctx)
+                {
                     eligible.push(key);
                 }
             }
@@ -114,6 +192,11 @@ impl<'a, S: StoryletSource> EligibilityEngine<'a, S> {
 
     /// Check if a single storylet is eligible given the current context.
     fn is_storylet_eligible(&self, storylet: &syn_storylets::library::CompiledStorylet, ctx: &EligibilityContext) -> bool {
+        // 0. Locked domains (underworld content needs underworld exposure)
+        if !domain_unlocked(storylet.domain, ctx.world) {
+            return false;
+        }
+
         // 1. Check stat thresholds
         if !self.check_stat_thresholds(&storylet.prerequisites, ctx) {
             return false;
@@ -144,6 +227,11 @@ impl<'a, S: StoryletSource> EligibilityEngine<'a, S> {
             return false;
         }
 
+        // 7. Check hard dependencies on earlier storylets
+        if !self.check_dependencies(&storylet.prerequisites, ctx) {
+            return false;
+        }
+
         true
     }
 
@@ -274,7 +362,7 @@ impl<'a, S: StoryletSource> EligibilityEngine<'a, S> {
                 };
 
                 // Check all thresholds for this relationship
-                if self.relationship_meets_thresholds(&relationship, &rel_prereq.thresholds) {
+                if relationship_meets_thresholds(&relationship, &rel_prereq.thresholds) {
                     found_valid_candidate = true;
                     break;
                 }
@@ -288,36 +376,6 @@ impl<'a, S: StoryletSource> EligibilityEngine<'a, S> {
         true
     }
 
-    /// Check if a relationship meets all the specified axis thresholds.
-    fn relationship_meets_thresholds(
-        &self,
-        relationship: &syn_core::types::Relationship,
-        thresholds: &[syn_storylets::RelationshipThreshold],
-    ) -> bool {
-        for threshold in thresholds {
-            let axis_value = match threshold.axis.to_lowercase().as_str() {
-                "affection" => relationship.affection,
-                "trust" => relationship.trust,
-                "attraction" => relationship.attraction,
-                "familiarity" => relationship.familiarity,
-                "resentment" => relationship.resentment,
-                _ => continue, // Unknown axis, skip
-            };
-
-            if let Some(min) = threshold.min {
-                if axis_value < min {
-                    return false;
-                }
-            }
-            if let Some(max) = threshold.max {
-                if axis_value > max {
-                    return false;
-                }
-            }
-        }
-        true
-    }
-
     /// Check memory prerequisites by querying MemorySystem.
     fn check_memory_prerequisites(&self, prereqs: &Prerequisites, ctx: &EligibilityContext) -> bool {
         if let Some(ref mem_prereqs) = prereqs.memory_prerequisites {
@@ -434,6 +492,15 @@ impl<'a, S: StoryletSource> EligibilityEngine<'a, S> {
         
         true
     }
+
+    /// Check that every `depends_on` storylet has fired at least once.
+    fn check_dependencies(&self, prereqs: &Prerequisites, ctx: &EligibilityContext) -> bool {
+        prereqs
+            .depends_on
+            .iter()
+            .flatten()
+            .all(|id| ctx.world.storylet_usage.count(&id.0) > 0)
+    }
 }
 
 #[cfg(test)]
@@ -579,6 +646,100 @@ mod tests {
         assert!(eligible.is_empty(), "Storylet should not be eligible without flag");
     }
 
+    #[test]
+    fn test_underworld_domain_needs_exposure() {
+        let storylet = CompiledStorylet {
+            id: StoryletId::new("fence_the_goods"),
+            key: StoryletKey(0),
+            name: "Fence The Goods".to_string(),
+            description: None,
+            tags: vec![],
+            domain: StoryDomain::Underworld,
+            life_stage: syn_storylets::LifeStage::Adult,
+            heat: 4,
+            weight: 1.0,
+            roles: vec![],
+            prerequisites: Prerequisites::default(),
+            cooldowns: Cooldowns::default(),
+            outcomes: Outcome::default(),
+            follow_ups_resolved: vec![],
+        };
+        let source = MockStoryletSource {
+            storylets: vec![storylet.clone()],
+        };
+        let engine = EligibilityEngine::new(&source);
+        let mut world = WorldState::new(syn_core::WorldSeed(42), NpcId(1));
+        let memory = MemorySystem::new();
+
+        let ctx = EligibilityContext {
+            world: &world,
+            memory: &memory,
+            current_tick: SimTick(0),
+        };
+        assert!(!engine.is_storylet_eligible_public(&storylet, &ctx));
+
+        syn_core::apply_karma(&mut world, -70.0);
+        let ctx = EligibilityContext {
+            world: &world,
+            memory: &memory,
+            current_tick: SimTick(0),
+        };
+        assert!(engine.is_storylet_eligible_public(&storylet, &ctx));
+    }
+
+    #[test]
+    fn test_post_life_only_unlocks_the_digital_domain() {
+        let mut world = WorldState::new(syn_core::WorldSeed(42), NpcId(1));
+        assert!(domain_unlocked(StoryDomain::SliceOfLife, &world));
+        assert!(domain_unlocked(StoryDomain::Digital, &world));
+
+        world.post_life.started_tick = Some(0);
+        assert!(!domain_unlocked(StoryDomain::SliceOfLife, &world));
+        assert!(!domain_unlocked(StoryDomain::Underworld, &world));
+        assert!(domain_unlocked(StoryDomain::Digital, &world));
+    }
+
+    #[test]
+    fn test_mature_tags_are_gated_by_player_life_stage() {
+        let child_storylet = |key: u32, id: &str, tags: Vec<Tag>| CompiledStorylet {
+            id: StoryletId::new(id),
+            key: StoryletKey(key),
+            name: id.to_string(),
+            description: None,
+            tags,
+            domain: StoryDomain::SliceOfLife,
+            life_stage: syn_storylets::LifeStage::Child,
+            heat: 1,
+            weight: 1.0,
+            roles: vec![],
+            prerequisites: Prerequisites::default(),
+            cooldowns: Cooldowns::default(),
+            outcomes: Outcome::default(),
+            follow_ups_resolved: vec![],
+        };
+        let source = MockStoryletSource {
+            storylets: vec![
+                child_storylet(0, "sneak_a_beer", vec![Tag::new("alcohol")]),
+                child_storylet(1, "lemonade_stand", vec![Tag::new("family")]),
+            ],
+        };
+        let engine = EligibilityEngine::new(&source);
+        let mut world = WorldState::new(syn_core::WorldSeed(42), NpcId(1));
+        world.player_life_stage = CoreLifeStage::Child;
+        let memory = MemorySystem::new();
+
+        let ctx = EligibilityContext {
+            world: &world,
+            memory: &memory,
+            current_tick: SimTick(0),
+        };
+        assert_eq!(engine.find_eligible_storylets(&ctx), vec![StoryletKey(1)]);
+        assert!(!age_appropriate(&source.storylets[0], &world));
+
+        world.player_life_stage = CoreLifeStage::Teen;
+        assert!(age_appropriate(&source.storylets[0], &world));
+    }
+
     #[test]
     fn test_global_flag_gating() {
         // Create a storylet with no flags
@@ -802,8 +963,8 @@ mod tests {
             heat: 4,
             weight: 1.0,
             roles: vec![
-                RoleSlot { name: "protagonist".to_string(), required: true, constraints: None },
-                RoleSlot { name: "trusted_friend".to_string(), required: true, constraints: None },
+                RoleSlot { name: "protagonist".to_string(), required: true, constraints: None, allow_same_as: vec![] },
+                RoleSlot { name: "trusted_friend".to_string(), required: true, constraints: None, allow_same_as: vec![] },
             ],
             prerequisites: Prerequisites {
                 relationship_prerequisites: Some(vec![
@@ -909,8 +1070,8 @@ mod tests {
             heat: 7,
             weight: 1.0,
             roles: vec![
-                RoleSlot { name: "protagonist".to_string(), required: true, constraints: None },
-                RoleSlot { name: "rival".to_string(), required: true, constraints: None },
+                RoleSlot { name: "protagonist".to_string(), required: true, constraints: None, allow_same_as: vec![] },
+                RoleSlot { name: "rival".to_string(), required: true, constraints: None, allow_same_as: vec![] },
             ],
             prerequisites: Prerequisites {
                 relationship_prerequisites: Some(vec![
@@ -1021,8 +1182,8 @@ mod tests {
             heat: 2,
             weight: 1.0,
             roles: vec![
-                RoleSlot { name: "protagonist".to_string(), required: true, constraints: None },
-                RoleSlot { name: "stranger".to_string(), required: true, constraints: None },
+                RoleSlot { name: "protagonist".to_string(), required: true, constraints: None, allow_same_as: vec![] },
+                RoleSlot { name: "stranger".to_string(), required: true, constraints: None, allow_same_as: vec![] },
             ],
             prerequisites: Prerequisites {
                 relationship_prerequisites: Some(vec![
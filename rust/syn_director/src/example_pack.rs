@@ -0,0 +1,250 @@
+//! The example content pack and the fixture world it is written for.
+//!
+//! `content/example_pack/` at the repository root holds ~30 storylets: a
+//! romance, a career, a workplace conflict, a friendship and a family arc,
+//! plus slice-of-life routines. Between them they use every part of the
+//! storylet schema (role slots; stat, trait, relationship, memory, flag and
+//! world-state prerequisites; `depends_on`/`prefers_after` chains; cooldowns;
+//! follow-ups; milestone memories) and show the pack's skill-check idiom: a
+//! pass/fail pair of storylets gated on opposite sides of one trait. Every
+//! storylet embeds a `test_fixture`, so [`run_storylet_fixtures`] checks the
+//! pack like any other content. The pack's README is the authoring guide.
+//!
+//! [`example_world`] builds the cast the pack is written against, and
+//! [`run_example_pack`] plays it through the compiled storylet pipeline one
+//! beat per day. Integration tests and the headless runner use both, so the
+//! pack doubles as a realistic regression corpus for the director.
+//!
+//! [`run_storylet_fixtures`]: crate::run_storylet_fixtures
+
+use std::path::PathBuf;
+
+use syn_core::rng::DeterministicRng;
+use syn_core::time::TickContext;
+use syn_core::types::{AbstractNpc, AttachmentStyle, Relationship, Traits};
+use syn_core::{LifeStage, NpcId, SimTick, SocialCluster, StatKind, WorldSeed, WorldState};
+use syn_memory::MemorySystem;
+use syn_storylets::compiler::StoryletCompiler;
+use syn_storylets::errors::StoryletCompileError;
+use syn_storylets::library::StoryletLibrary;
+use syn_storylets::validation::default_storylet_validator;
+
+use crate::EventDirector;
+
+/// The player in [`example_world`].
+pub const EXAMPLE_PLAYER: NpcId = NpcId(1);
+/// Close friend: warm and trusting in both directions.
+pub const EXAMPLE_FRIEND: NpcId = NpcId(2);
+/// Love interest: mutual attraction nobody has acted on yet.
+pub const EXAMPLE_CRUSH: NpcId = NpcId(3);
+/// Coworker who already resents the player.
+pub const EXAMPLE_RIVAL: NpcId = NpcId(4);
+/// Senior coworker the player trusts.
+pub const EXAMPLE_MENTOR: NpcId = NpcId(5);
+/// Sibling: as familiar as it gets, with an old grudge.
+pub const EXAMPLE_SIBLING: NpcId = NpcId(6);
+/// Neighbors with lukewarm, seed-dependent relationships.
+pub const EXAMPLE_NEIGHBORS: [NpcId; 4] = [NpcId(7), NpcId(8), NpcId(9), NpcId(10)];
+
+/// Ticks between director beats in [`run_example_pack`] (one per day).
+pub const EXAMPLE_BEAT_INTERVAL: u64 = 24;
+
+/// Directory holding the example pack's storylet JSON.
+pub fn example_pack_dir() -> PathBuf {
+    PathBuf::from(env!("CARGO_MANIFEST_DIR"))
+        .join("..")
+        .join("..")
+        .join("content")
+        .join("example_pack")
+}
+
+/// Compile the example pack with the default validator.
+pub fn load_example_pack() -> Result<StoryletLibrary, Vec<StoryletCompileError>> {
+    StoryletCompiler::new(default_storylet_validator()).compile_from_dir(example_pack_dir())
+}
+
+/// Build the world the example pack is written against.
+///
+/// A young-adult player with a friend, a crush, a rival, a mentor and a
+/// sibling (fixed, so every arc can start), plus four neighbors whose
+/// relationships with the player vary with `seed`. The friend and crush
+/// share a social circle, as do the rival and mentor at work.
+pub fn example_world(seed: u64) -> (WorldState, MemorySystem) {
+    let mut world = WorldState::new(WorldSeed(seed), EXAMPLE_PLAYER);
+    // Aging re-derives the life stage from the days since birth each day
+    world.player_age = 24;
+    world.player_age_years = 24;
+    world.player_days_since_birth = 24 * 365;
+    world.player_life_stage = LifeStage::YoungAdult;
+
+    for (kind, value) in [
+        (StatKind::Health, 70.0),
+        (StatKind::Wealth, 40.0),
+        (StatKind::Mood, 1.0),
+        (StatKind::Reputation, 2.0),
+        (StatKind::Energy, 60.0),
+    ] {
+        world.player_stats.set(kind, value);
+    }
+    let player_traits = Traits {
+        stability: 55.0,
+        confidence: 55.0,
+        sociability: 60.0,
+        empathy: 60.0,
+        impulsivity: 40.0,
+        ambition: 65.0,
+        charm: 65.0,
+    };
+    add_npc(&mut world, EXAMPLE_PLAYER, 24, "designer", player_traits);
+
+    let cast = [
+        (EXAMPLE_FRIEND, 25, "barista"),
+        (EXAMPLE_CRUSH, 26, "musician"),
+        (EXAMPLE_RIVAL, 29, "designer"),
+        (EXAMPLE_MENTOR, 47, "art director"),
+        (EXAMPLE_SIBLING, 31, "nurse"),
+    ];
+    for (id, age, job) in cast {
+        add_npc(&mut world, id, age, job, Traits::default());
+        world.ensure_npc_known(id);
+    }
+
+    // (from, to, affection, trust, attraction, familiarity, resentment)
+    let relationships = [
+        (EXAMPLE_PLAYER, EXAMPLE_FRIEND, 8.0, 6.0, 0.0, 5.0, 0.0),
+        (EXAMPLE_FRIEND, EXAMPLE_PLAYER, 7.0, 7.0, 0.0, 5.0, 0.0),
+        (EXAMPLE_PLAYER, EXAMPLE_CRUSH, 4.0, 3.0, 6.0, 3.0, 0.0),
+        (EXAMPLE_CRUSH, EXAMPLE_PLAYER, 3.0, 2.0, 4.0, 3.0, 0.0),
+        (EXAMPLE_PLAYER, EXAMPLE_RIVAL, 0.0, -2.0, 0.0, 4.0, 5.0),
+        (EXAMPLE_RIVAL, EXAMPLE_PLAYER, 0.0, -1.0, 0.0, 4.0, 4.0),
+        (EXAMPLE_PLAYER, EXAMPLE_MENTOR, 3.0, 9.0, 0.0, 7.0, 0.0),
+        (EXAMPLE_MENTOR, EXAMPLE_PLAYER, 3.0, 5.0, 0.0, 7.0, 0.0),
+        (EXAMPLE_PLAYER, EXAMPLE_SIBLING, 6.0, 4.0, 0.0, 9.0, 2.0),
+        (EXAMPLE_SIBLING, EXAMPLE_PLAYER, 6.0, 5.0, 0.0, 9.0, 2.0),
+    ];
+    for (from, to, affection, trust, attraction, familiarity, resentment) in relationships {
+        let relationship = Relationship {
+            affection,
+            trust,
+            attraction,
+            familiarity,
+            resentment,
+            ..Default::default()
+        };
+        world.set_relationship(from, to, relationship);
+    }
+
+    let mut rng = DeterministicRng::new(seed);
+    for id in EXAMPLE_NEIGHBORS {
+        let traits = Traits {
+            sociability: rng.gen_range_f32(20.0, 80.0),
+            empathy: rng.gen_range_f32(20.0, 80.0),
+            ..Default::default()
+        };
+        let age = 30 + rng.gen_range_i32(0, 30).unsigned_abs();
+        add_npc(&mut world, id, age, "", traits);
+        world.ensure_npc_known(id);
+        let relationship = Relationship {
+            affection: rng.gen_range_f32(-1.0, 3.0),
+            trust: rng.gen_range_f32(-1.0, 3.0),
+            familiarity: rng.gen_range_f32(0.0, 4.0),
+            ..Default::default()
+        };
+        world.set_relationship(EXAMPLE_PLAYER, id, relationship);
+        world.set_relationship(id, EXAMPLE_PLAYER, relationship);
+    }
+
+    for (name, members) in [
+        ("friends", [EXAMPLE_FRIEND, EXAMPLE_CRUSH]),
+        ("office", [EXAMPLE_RIVAL, EXAMPLE_MENTOR]),
+    ] {
+        let mut cluster = SocialCluster::new(name);
+        for id in members {
+            cluster.add_member(id);
+        }
+        world.gossip.clusters.insert(name.to_string(), cluster);
+    }
+    world
+        .district_state
+        .insert("crime_level".to_string(), "0.2".to_string());
+
+    (world, MemorySystem::new())
+}
+
+fn add_npc(world: &mut WorldState, id: NpcId, age: u32, job: &str, traits: Traits) {
+    world.npcs.insert(
+        id,
+        AbstractNpc {
+            id,
+            age,
+            job: job.to_string(),
+            district: "Downtown".to_string(),
+            household_id: id.0,
+            traits,
+            seed: id.0,
+            attachment_style: AttachmentStyle::Secure,
+        },
+    );
+}
+
+/// One storylet fired while playing the example pack.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ExampleBeat {
+    /// Tick it fired on.
+    pub tick: SimTick,
+    /// Storylet that fired.
+    pub storylet_id: String,
+}
+
+/// Result of [`run_example_pack`].
+#[derive(Debug)]
+pub struct ExampleRun {
+    /// Storylets fired, in order.
+    pub beats: Vec<ExampleBeat>,
+    /// World after the last tick.
+    pub world: WorldState,
+    /// Memories after the last tick.
+    pub memory: MemorySystem,
+}
+
+impl ExampleRun {
+    /// How often `storylet_id` fired.
+    pub fn count(&self, storylet_id: &str) -> usize {
+        self.beats
+            .iter()
+            .filter(|b| b.storylet_id == storylet_id)
+            .count()
+    }
+}
+
+/// Play `library` over [`example_world`] for `days` in-game days.
+///
+/// The world ticks normally; once every [`EXAMPLE_BEAT_INTERVAL`] ticks the
+/// director's compiled pipeline may fire one storylet. Everything is seeded
+/// from `seed`, so the same inputs always give the same beats.
+pub fn run_example_pack(library: &StoryletLibrary, seed: u64, days: u64) -> ExampleRun {
+    let (mut world, mut memory) = example_world(seed);
+    let mut director = EventDirector::new();
+    director.load_compiled_outcomes(library);
+
+    let mut beats = Vec::new();
+    let mut ctx = TickContext::default();
+    for _ in 0..days * EXAMPLE_BEAT_INTERVAL {
+        world.tick(&mut ctx);
+        let tick = world.current_tick;
+        if tick.0 % EXAMPLE_BEAT_INTERVAL != 0 {
+            continue;
+        }
+        if let Some(storylet_id) =
+            director.tick_compiled_storylets_simple(library, &mut world, &mut memory, tick)
+        {
+            beats.push(ExampleBeat { tick, storylet_id });
+        }
+    }
+
+    ExampleRun {
+        beats,
+        world,
+        memory,
+    }
+}
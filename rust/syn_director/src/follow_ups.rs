@@ -0,0 +1,130 @@
+//! Delayed follow-ups for compiled storylet chains.
+//!
+//! A compiled storylet's outcome can name [`FollowUpStorylet`]s. When
+//! [`EventDirector::tick_compiled_storylets_simple`] fires it, each follow-up
+//! is queued to come due `delay_ticks` later (0 = as soon as the director
+//! ticks again).
+//! On every tick the oldest due follow-up fires before normal selection,
+//! whatever its prerequisites or cooldown, so a chain plays out in order.
+//!
+//! A follow-up with `conditional_on_flag` only fires if that world flag is
+//! set when it comes due; otherwise the branch is dropped. Follow-ups whose
+//! target is missing from the library, quarantined or can't be cast are
+//! dropped too.
+
+use serde::{Deserialize, Serialize};
+use syn_core::{SimTick, WorldState};
+use syn_memory::MemorySystem;
+use syn_storylets::library::{CompiledStorylet, StoryletLibrary};
+use syn_storylets::{FollowUpStorylet, StoryletId};
+
+use crate::{EligibilityContext, EventDirector, RoleAssignmentEngine};
+
+/// A follow-up waiting for its tick.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct PendingFollowUp {
+    /// Storylet to fire.
+    pub storylet_id: String,
+    /// Storylet whose outcome scheduled it.
+    pub scheduled_by: String,
+    /// First tick it may fire at.
+    pub due_tick: u64,
+    /// World flag that must be set when it comes due.
+    #[serde(default)]
+    pub conditional_on_flag: Option<String>,
+}
+
+impl PendingFollowUp {
+    fn new(follow_up: &FollowUpStorylet, scheduled_by: &str, current_tick: SimTick) -> Self {
+        Self {
+            storylet_id: follow_up.storylet_id.clone(),
+            scheduled_by: scheduled_by.to_string(),
+            due_tick: current_tick.0 + u64::from(follow_up.delay_ticks),
+            conditional_on_flag: follow_up.conditional_on_flag.clone(),
+        }
+    }
+
+    /// Whether its flag condition holds (always, without one).
+    pub fn condition_met(&self, world: &WorldState) -> bool {
+        match &self.conditional_on_flag {
+            Some(flag) => world.world_flags.has_any(flag),
+            None => true,
+        }
+    }
+}
+
+/// Follow-ups queued by fired storylets, in the order they were scheduled.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub(crate) struct FollowUpScheduler {
+    pending: Vec<PendingFollowUp>,
+}
+
+impl FollowUpScheduler {
+    /// Queue every follow-up named by `storylet`'s outcome.
+    pub(crate) fn schedule(&mut self, storylet: &CompiledStorylet, current_tick: SimTick) {
+        for follow_up in storylet.outcomes.follow_ups.iter().flatten() {
+            self.pending
+                .push(PendingFollowUp::new(follow_up, &storylet.id.0, current_tick));
+        }
+    }
+
+    /// Remove and return the oldest follow-up due by `current_tick`.
+    fn take_due(&mut self, current_tick: SimTick) -> Option<PendingFollowUp> {
+        let due = self
+            .pending
+            .iter()
+            .enumerate()
+            .filter(|(_, pending)| pending.due_tick <= current_tick.0)
+            .min_by_key(|(index, pending)| (pending.due_tick, *index))
+            .map(|(index, _)| index)?;
+        Some(self.pending.remove(due))
+    }
+}
+
+impl EventDirector {
+    /// Follow-ups waiting to fire, in the order they were scheduled.
+    pub fn pending_follow_ups(&self) -> &[PendingFollowUp] {
+        &self.follow_ups.pending
+    }
+
+    /// Replace the pending follow-ups with ones taken from a save.
+    pub fn restore_follow_ups(&mut self, pending: Vec<PendingFollowUp>) {
+        self.follow_ups.pending = pending;
+    }
+
+    /// Fire the oldest due follow-up whose condition holds, dropping due
+    /// ones that can't fire. Returns the fired storylet's ID.
+    pub(crate) fn fire_due_follow_up(
+        &mut self,
+        library: &StoryletLibrary,
+        world: &mut WorldState,
+        memory: &mut MemorySystem,
+        current_tick: SimTick,
+    ) -> Option<String> {
+        while let Some(pending) = self.follow_ups.take_due(current_tick) {
+            if !pending.condition_met(world) {
+                continue;
+            }
+            let Some(storylet) = library.get_by_id(&StoryletId::new(&pending.storylet_id)) else {
+                continue;
+            };
+            if self.compiled_outcomes.is_quarantined(storylet.key) {
+                continue;
+            }
+            let assignments = {
+                let ctx = EligibilityContext {
+                    world,
+                    memory,
+                    current_tick,
+                };
+                RoleAssignmentEngine::from_context(&ctx).assign_roles_for_storylet(storylet, None)
+            };
+            let Some(assignments) = assignments else {
+                continue;
+            };
+            self.fire_compiled_storylet(storylet, &assignments, world, memory, current_tick);
+            return Some(storylet.id.0.clone());
+        }
+        None
+    }
+}
@@ -0,0 +1,126 @@
+//! Forcing a specific storylet to fire.
+//!
+//! Content authors testing one storylet and scripted demo flows need to fire
+//! it now, whatever the scores say. [`EventDirector::force_fire_storylet`]
+//! skips selection but otherwise fires the storylet the normal way: roles
+//! are cast (with the caller's overrides applied first), outcomes run
+//! through the outcome pipeline, and cooldowns, usage and the news feed are
+//! updated as for any fired storylet.
+//!
+//! Prerequisites are soft-checked: every eligibility check the storylet
+//! fails is reported as a warning instead of blocking the fire. Each forced
+//! fire is kept in a short log ([`EventDirector::forced_fires`]).
+
+use std::fmt;
+
+use syn_core::{NpcId, SimTick, WorldState};
+use syn_memory::MemorySystem;
+
+use crate::{EventDirector, StoryletOutcome};
+
+/// Most forced fires kept in the director's log.
+const FORCED_FIRE_LOG_CAPACITY: usize = 32;
+
+/// A storylet fired by [`EventDirector::force_fire_storylet`].
+#[derive(Debug, Clone, PartialEq)]
+pub struct ForcedFire {
+    /// Storylet that fired.
+    pub storylet_id: String,
+    /// Tick it fired at.
+    pub tick: u64,
+    /// Role name and NPC for each role, as cast when it fired.
+    pub cast: Vec<(String, NpcId)>,
+    /// Failed prerequisites, unknown override roles and recast NPCs.
+    pub warnings: Vec<String>,
+}
+
+/// Why a storylet couldn't be forced. Nothing fires on error.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ForceFireError {
+    /// The director does not know this storylet.
+    UnknownStorylet(String),
+}
+
+impl fmt::Display for ForceFireError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::UnknownStorylet(id) => write!(f, "unknown storylet '{}'", id),
+        }
+    }
+}
+
+impl std::error::Error for ForceFireError {}
+
+impl EventDirector {
+    /// Fire `storylet_id` now, bypassing scoring.
+    ///
+    /// `cast_overrides` pins roles to NPCs by role name before casting;
+    /// overrides naming NPCs that don't exist are recast like any stale
+    /// role. The first choice's outcome is applied (an empty outcome if the
+    /// storylet has no choices).
+    pub fn force_fire_storylet(
+        &mut self,
+        storylet_id: &str,
+        cast_overrides: &[(String, NpcId)],
+        world: &mut WorldState,
+        memory: &mut MemorySystem,
+        current_tick: SimTick,
+    ) -> Result<ForcedFire, ForceFireError> {
+        let Some((handle, base)) = self.registered_storylet(storylet_id) else {
+            return Err(ForceFireError::UnknownStorylet(storylet_id.to_string()));
+        };
+        let mut storylet = base.clone();
+        let mut warnings = Vec::new();
+
+        for (role, npc_id) in cast_overrides {
+            match storylet.roles.iter_mut().find(|slot| slot.name == *role) {
+                Some(slot) => slot.npc_id = *npc_id,
+                None => warnings.push(format!("override for unknown role '{}'", role)),
+            }
+        }
+
+        for check in self.eligibility_failures(handle, &storylet, world, memory, current_tick, true) {
+            warnings.push(format!("prerequisite not met: {}", check));
+        }
+
+        let cast = match self.cast_storylet_roles(&storylet, world, memory, current_tick) {
+            Some(cast) => cast.into_owned(),
+            None => storylet.clone(),
+        };
+        for (wanted, slot) in storylet.roles.iter().zip(cast.roles.iter()) {
+            if wanted.npc_id != slot.npc_id {
+                warnings.push(format!(
+                    "role '{}': npc {} doesn't exist, recast to {}",
+                    slot.name, wanted.npc_id.0, slot.npc_id.0
+                ));
+            }
+        }
+
+        let (outcome, choice_id) = match cast.outcomes.choices.first() {
+            Some(choice) => (choice.outcome.clone(), Some(choice.id.clone())),
+            None => (StoryletOutcome::default(), None),
+        };
+        self.fire_storylet_as(&cast, world, memory, outcome, choice_id, current_tick);
+
+        let fired = ForcedFire {
+            storylet_id: cast.id.clone(),
+            tick: current_tick.0,
+            cast: cast
+                .roles
+                .iter()
+                .map(|slot| (slot.name.clone(), slot.npc_id))
+                .collect(),
+            warnings,
+        };
+        if self.forced_fires.len() == FORCED_FIRE_LOG_CAPACITY {
+            self.forced_fires.remove(0);
+        }
+        self.forced_fires.push(fired.clone());
+        Ok(fired)
+    }
+
+    /// Forced fires, oldest first (the most recent 32).
+    pub fn forced_fires(&self) -> &[ForcedFire] {
+        &self.forced_fires
+    }
+}
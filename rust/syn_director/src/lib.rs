@@ -8,7 +8,10 @@
 //! This crate provides two director implementations:
 //!
 //! - **`EventDirector`** (legacy): Works with the old `Storylet` type defined in this crate.
-//!   Preserved for backward compatibility.
+//!   Preserved for backward compatibility. Its pieces live in `prereqs`
+//!   (prerequisite types and checks), `storylet_scoring` (score multipliers),
+//!   `selection` (picking and previewing the next event) and `outcomes`
+//!   (applying choices); everything is re-exported from the crate root.
 //!
 //! - **`CompiledEventDirector<S>`** (new): Works with compiled storylets via the `StoryletSource`
 //!   trait. Uses consolidated `DirectorState` and `DirectorConfig` for clean separation of
@@ -21,29 +24,40 @@
 //! - **`EligibilityEngine`**: Filters storylets based on prerequisites
 //! - **`RoleAssignmentEngine`**: Assigns NPCs to storylet roles
 //! - **`StoryletSource`**: Trait abstracting storylet library access
+//! - **`ResolvedOutcomeTable`**: Load-time outcome validation; quarantines invalid storylets
+//! - **`EventDirector::apply_outcome_batch`**: Transactional scripted outcome sequences
+//! - **`EventDirector::force_fire_storylet`**: Fire a specific storylet, bypassing scoring
+//! - **`OutcomePipeline`**: Ordered, extensible stages that apply a storylet outcome
+//! - **`run_storylet_fixtures`**: Runs the `test_fixture` blocks embedded in storylet JSON
+//! - **`example_pack`**: The example content pack, its fixture world, and a runner that
+//!   plays it through the director
+//! - **`AxisTagThresholds`**: Auto-tags outcome memories with sharp axis moves (`trust_loss`, ...)
+//! - **`ScoringStrategy`**: Swappable, self-explaining storylet scoring for the legacy director
+//! - **`PrereqExpr`**: AND/OR/NOT prerequisite expressions authored as strings on legacy storylets
+//! - **`Chronicle`**: Every storylet the legacy director fired, with cast, choice and outcome
+//! - **`PendingFollowUp`**: Delayed, flag-conditional follow-ups queued by compiled storylet outcomes
+//! - **`SelectionMode`**: Best-score or weighted top-K picks with a recent-domain penalty
+//! - **`CastingEngine`**: Casts open legacy storylet roles to the best-fitting NPCs when they fire
+//! - **`PacingBudget`**: Daily and per-domain heat budgets and weekly arc caps that spread drama out
+//! - **`EventDirector::replace_storylets`**: Hot-swaps the legacy storylets, keeping cooldowns
+//! - **`ReplayRecorder`** / **`replay_from_log`**: Records a director loop run (inputs, RNG
+//!   draws, presented storylets) and replays it from the seed, reporting the first divergence
+//! - **`OutcomeBranch`**: `if` / skill-check branches inside an outcome, settled when it fires
 
 use serde::{Deserialize, Serialize};
+use std::borrow::Cow;
 use std::collections::HashMap;
-use syn_core::npc::NpcActivityKind;
+use std::sync::Mutex;
 use syn_core::npc::NpcRoleTag;
-use syn_core::npc_behavior::{BehaviorKind, BehaviorSnapshot};
-use syn_core::time::DayPhase;
 use syn_core::{
-    apply_stat_deltas, behavior_action_from_tags, deterministic_rng_from_world,
+    behavior_action_from_tags,
     narrative_heat::NarrativeHeatBand,
-    relationship_milestones::RelationshipMilestoneEvent,
-    relationship_model::{
-        AffectionBand, AttractionBand, RelationshipAxis as ModelRelationshipAxis, RelationshipDelta, RelationshipVector,
-        ResentmentBand, TrustBand,
-    },
-    relationship_pressure::{RelationshipEventKind, RelationshipPressureEvent},
-    district_pressure::DistrictPressureEvent,
-    gossip_pressure::{GossipEventKind, GossipPressureEvent},
-    LifeStage, NpcId, RelationshipAxis as CoreRelationshipAxis, RelationshipState, SimTick, StatDelta, StoryletUsageState, WorldState,
+    relationship_model::RelationshipAxis as ModelRelationshipAxis,
+    NpcId, PLAYER_ACTION_TRIGGER, STORYLET_ACTION_COST, SimTick, StoryletHandle, WorldState,
 };
-use syn_memory::{MemoryEntry, MemorySystem};
+use syn_memory::{MemoryEntry, MemoryIntensityContext, MemorySystem};
 use syn_query::RelationshipQuery;
-use syn_sim::{tick_world, NpcRegistry, SimState};
+use syn_sim::NpcRegistry;
 
 // Core modules
 pub mod storylet_library;
@@ -55,6 +69,29 @@ pub mod storylet_loader;
 pub mod storylet_source;
 pub mod eligibility;
 pub mod role_assignment;
+pub mod casting;
+pub mod outcome_validation;
+pub mod outcome_batch;
+pub mod forced_fire;
+pub mod chronicle;
+pub mod follow_ups;
+pub mod library_reload;
+pub mod replay;
+pub mod outcome_pipeline;
+pub mod outcome_branches;
+pub mod undo;
+pub mod storylet_fixtures;
+pub mod example_pack;
+pub mod axis_tags;
+
+// Legacy director, split by concern behind the re-exports below
+pub mod prereqs;
+pub mod prereq_expr;
+pub mod storylet_scoring;
+pub mod scoring_strategy;
+pub mod selection;
+pub mod outcomes;
+mod cooldowns;
 
 // New consolidated director system
 pub mod state;
@@ -63,20 +100,53 @@ pub mod compiled_director;
 pub mod pipeline;
 pub mod scoring;
 pub mod pacing;
+pub mod pacing_budget;
 pub mod queue;
 pub mod pressure;
 pub mod persistence;
 pub mod api;
 
 // Re-exports for backward compatibility
-pub use storylet_library::{EventContext, StoryletId, StoryletLibrary, tags_to_bitset};
+pub use storylet_library::{EventContext, StoryletLibrary, tags_to_bitset};
 pub use tag_bitset::TagBitset;
 pub use storylet_outcome::{MemoryEntryTemplate, StoryletOutcomeSet, WorldFlagUpdate};
 pub use storylet_roles::{RoleAssignment, RoleScoring, RoleSlot, StoryletRoles};
 pub use storylet_source::StoryletSource;
-pub use eligibility::{EligibilityContext, EligibilityEngine};
-pub use role_assignment::{RoleAssignmentEngine, RoleAssignments, RoleCandidate};
+pub use eligibility::{
+    age_appropriate, content_allowed, domain_unlocked, EligibilityContext, EligibilityEngine,
+};
+pub use role_assignment::{
+    CastingOutcome, CastingRefusal, RefusalReason, RoleAssignmentEngine, RoleAssignments,
+    RoleCandidate, COOPERATIVE_TAG,
+};
+pub use outcome_validation::{
+    OutcomeReferenceError, OutcomeRole, OutcomeStat, OutcomeValidationError, ResolvedOutcome,
+    ResolvedOutcomeTable,
+};
 pub use syn_storylets::library::CompiledStorylet;
+pub use outcome_batch::{OutcomeBatchError, OutcomeBatchReport};
+pub use forced_fire::{ForceFireError, ForcedFire};
+pub use chronicle::{Chronicle, ChronicleEntry, ChronicleOutcome};
+pub use follow_ups::PendingFollowUp;
+pub use library_reload::LibraryReload;
+pub use casting::{CastingEngine, CastingError};
+pub use replay::{
+    replay_from_log, ReplayError, ReplayEvent, ReplayLog, ReplayRecorder, ReplayReport,
+    REPLAY_LOG_VERSION,
+};
+pub use outcome_pipeline::{
+    DistrictStage, FlagStage, GossipStage, HeatStage, KarmaStage, MemoryStage, ObserverStage,
+    OutcomeContext, OutcomePipeline, OutcomeStage, PressureStage, RelationshipStage, StatStage,
+};
+pub use undo::ChoiceUndo;
+pub use outcome_branches::{resolve_outcome_branches, BranchContext, OutcomeBranch, SkillCheck};
+pub use axis_tags::{axis_memory_tags, axis_tag_name, AxisTagThresholds};
+pub use storylet_fixtures::{
+    check_storylet_fixture, fixture_world, run_storylet_fixtures, FixtureFailure, FixtureReport,
+};
+pub use example_pack::{
+    example_pack_dir, example_world, load_example_pack, run_example_pack, ExampleBeat, ExampleRun,
+};
 
 // New director system re-exports
 pub use state::{
@@ -101,6 +171,7 @@ pub use compiled_director::{CompiledEventDirector, SelectionResult};
 pub use pipeline::{CandidateSet, EligibilityPipeline, IndexPrefilterParams, PipelineStats};
 pub use scoring::{ScoredCandidate, ScoringEngine, ScoringResults, ScoringStats, score_candidates, pick_storylet_from_scored};
 pub use pacing::{on_tick_start, on_event_fired, heat_alignment_factor, is_heat_appropriate};
+pub use pacing_budget::{HeatSpend, PacingBudget, PacingCaps};
 pub use queue::{schedule_follow_up, schedule_milestone, schedule_pressure_relief};
 pub use persistence::{
     DirectorSnapshot, DirectorPersistError,
@@ -108,9 +179,57 @@ pub use persistence::{
     serialize_to_json, deserialize_from_json,
     CURRENT_FORMAT_VERSION, SNAPSHOT_MAGIC,
 };
-pub use api::{FiredStorylet, DirectorStepResult, StepStats};
-
-pub type StoryletPrereqs = StoryletPrerequisites;
+pub use api::{DirectorDryRun, FiredStorylet, DirectorStepResult, StepStats};
+
+// Legacy director facade
+pub use prereqs::{
+    storylet_dependencies_met, DigitalLegacyPrereq, DistrictAffinity, DistrictCondition,
+    GlobalWorldStateFlag, MemoryEchoFlag, PersonalityCondition, RelationshipPrereq,
+    RelationshipThreshold, SkillRequirement, StatCondition, StoryletPrereqs, StoryletPrerequisites,
+    TimeAndLocationPrereqs,
+};
+pub use storylet_scoring::{
+    dependent_care_score_multiplier, difficulty_mix_score_multiplier,
+    district_affinity_score_multiplier, life_goal_score_multiplier, npc_intent_score_multiplier,
+    npc_tag_affinity_score_multiplier, post_life_nudge_score_multiplier,
+    prefers_after_score_multiplier, relationship_heat_score_multiplier, score_storylet_full_simple,
+    score_storylet_full_with_registry,
+};
+pub use prereq_expr::{
+    CompareOp, CompareValue, ExprSubject, PrereqEvalContext, PrereqExpr, PrereqExprError,
+};
+pub use scoring_strategy::{
+    DefaultScoringStrategy, ScoreExplanation, ScoreFactor, ScoreFactorKind, ScoringContext,
+    ScoringStrategy, StoryletScore,
+};
+pub use selection::{
+    advance_and_select, event_view_for, preview_next_event, select_next_event_view,
+    recency_multiplier, select_next_event_with_registry, select_storylet_weighted,
+    storylet_domains, storylet_is_eligible, DirectorChoiceView, DirectorContext,
+    DirectorEventView, DirectorPreview, DirectorPreviewCandidate, SelectionMode,
+};
+pub use outcomes::{
+    apply_choice_and_advance, apply_choice_with_undo, apply_storylet_choice_outcome,
+    apply_storylet_outcome, apply_storylet_outcome_with_memory, next_hot_relationship,
+    next_relationship_milestone, prepare_storylet_execution, SkillXpReward, StoryletOutcome,
+};
+use prereqs::{
+    check_age_gate, check_content_preferences, check_digital_legacy_prereq,
+    check_life_stage_prereqs, check_relationship_prereqs, declared_status_allows,
+    npc_is_available_for_player, trigger_context_ready,
+};
+use storylet_scoring::{
+    cast_relationship_heat_multiplier, storylet_targets_pair,
+    DEPENDENT_CARE_SCORE_MULTIPLIER, LIFE_GOAL_SCORE_MULTIPLIER, PREFERS_AFTER_SCORE_MULTIPLIER,
+};
+use outcomes::{
+    apply_flag_operation, apply_outcome_stat, consume_forced_trigger, outcome_actor,
+    report_if_newsworthy,
+};
+use selection::preempt_for_overdue_crisis;
+use cooldowns::CooldownTracker;
+use follow_ups::FollowUpScheduler;
+pub use cooldowns::CooldownEntry;
 
 /// Trigger metadata for a storylet (placeholder, GDD 3.16.1).
 #[derive(Debug, Clone, Default, Serialize, Deserialize)]
@@ -130,7 +249,9 @@ pub struct StoryletCooldown {
 /// A storylet: condition-driven narrative fragment with roles, outcomes, and cooldowns.
 #[derive(Debug, Clone, Serialize)]
 pub struct Storylet {
-    pub id: StoryletId,
+    /// Human-readable ID, for serialization and diagnostics. The director
+    /// interns it into a [`StoryletHandle`] when the storylet is registered.
+    pub id: String,
     #[serde(default)]
     pub name: String,
     pub tags: TagBitset,
@@ -148,7 +269,7 @@ pub struct Storylet {
 
 impl Storylet {
     pub fn new(
-        id: StoryletId,
+        id: String,
         tags: TagBitset,
         prerequisites: StoryletPrereqs,
         roles: StoryletRoles,
@@ -226,231 +347,21 @@ pub enum InteractionTone {
 pub struct StoryletChoice {
     pub id: String,
     pub label: String,
-    pub outcome: StoryletOutcome,
-}
-
-/// Relationship-based prerequisite (additive, non-breaking).
-#[derive(Debug, Clone, Serialize, Deserialize)]
-pub struct RelationshipPrereq {
-    /// Which actor owns the relationship. None defaults to the player.
-    #[serde(default)]
-    pub actor_id: Option<u64>,
-    /// Target NPC the prereq references.
-    pub target_id: u64,
-    /// Relationship axis to inspect.
-    pub axis: ModelRelationshipAxis,
-    /// Optional numeric bounds for the axis value.
-    #[serde(default)]
-    pub min_value: Option<f32>,
-    #[serde(default)]
-    pub max_value: Option<f32>,
-    /// Optional band-based gating (fuzzy thresholds).
-    #[serde(default)]
-    pub min_band: Option<String>,
-    #[serde(default)]
-    pub max_band: Option<String>,
-}
-
-/// Digital legacy prerequisite for PostLife storylets.
-#[derive(Debug, Clone, Serialize, Deserialize)]
-pub struct DigitalLegacyPrereq {
-    /// Only relevant in Digital/PostLife; if true and stage != Digital, prereq fails.
-    #[serde(default)]
-    pub require_post_life: bool,
-
-    /// Optional bounds on legacy components (-1.0 .. 1.0)
-    #[serde(default)]
-    pub min_compassion_vs_cruelty: Option<f32>,
-    #[serde(default)]
-    pub max_compassion_vs_cruelty: Option<f32>,
-
-    #[serde(default)]
-    pub min_ambition_vs_comfort: Option<f32>,
-    #[serde(default)]
-    pub max_ambition_vs_comfort: Option<f32>,
-
-    #[serde(default)]
-    pub min_connection_vs_isolation: Option<f32>,
-    #[serde(default)]
-    pub max_connection_vs_isolation: Option<f32>,
-
-    #[serde(default)]
-    pub min_stability_vs_chaos: Option<f32>,
-    #[serde(default)]
-    pub max_stability_vs_chaos: Option<f32>,
-
-    #[serde(default)]
-    pub min_light_vs_shadow: Option<f32>,
-    #[serde(default)]
-    pub max_light_vs_shadow: Option<f32>,
-}
-
-/// Conditions that must be met for a storylet to be eligible.
-#[derive(Debug, Clone, Serialize, Deserialize, Default)]
-pub struct StoryletPrerequisites {
-    #[serde(default)]
-    pub stat_conditions: Vec<StatCondition>,
-    #[serde(default)]
-    pub personality_conditions: Vec<PersonalityCondition>,
-    #[serde(default)]
-    pub relationship_conditions: Vec<RelationshipThreshold>,
-    #[serde(default)]
-    pub district_conditions: Vec<DistrictCondition>,
-    #[serde(default)]
-    pub memory_echo_conditions: Vec<MemoryEchoFlag>,
-    #[serde(default)]
-    pub global_conditions: Vec<GlobalWorldStateFlag>,
-    #[serde(default)]
-    pub life_stage: Option<LifeStage>,
-
-    pub min_relationship_affection: Option<f32>,
-    pub min_relationship_resentment: Option<f32>,
-    #[serde(default)]
-    pub stat_ranges: HashMap<String, (f32, f32)>, // legacy range-based checks
-    pub life_stages: Vec<String>,                     // ["Teen", "Adult", "Elder"]
-    pub tags: Vec<String>,                            // must have these tags
-    pub relationship_states: Vec<RelationshipState>, // Only fire if relationship is in one of these states
-    // Memory prerequisites for event echoes
-    pub memory_tags_required: Vec<String>, // NPC must have memory with at least one of these tags
-    pub memory_tags_forbidden: Vec<String>, // NPC must NOT have memory with these tags (conflict avoidance)
-    pub memory_recency_ticks: Option<u64>, // If specified, memory must be within N ticks (default: 7 days = 168 ticks)
-    /// Optional relationship-based prerequisites (additive).
-    #[serde(default)]
-    pub relationship_prereqs: Vec<RelationshipPrereq>,
-    /// Optional allowed life stages for this storylet.
-    #[serde(default)]
-    pub allowed_life_stages: Vec<LifeStage>,
-    /// Optional digital legacy prerequisite for PostLife storylets.
-    #[serde(default)]
-    pub digital_legacy_prereq: Option<DigitalLegacyPrereq>,
-
-    /// Optional time/location gating aligned with NPC schedule.
-    #[serde(default)]
-    pub time_and_location: Option<TimeAndLocationPrereqs>,
-
-    /// Skill requirements for this storylet.
+    /// Skills the player needs for this choice; shown locked until met.
     #[serde(default)]
     pub skill_requirements: Vec<SkillRequirement>,
+    pub outcome: StoryletOutcome,
 }
 
-#[derive(Debug, Clone, Serialize, Deserialize, Default)]
-pub struct StatCondition {
-    #[serde(default)]
-    pub kind: String,
-    #[serde(default)]
-    pub min: f32,
-    #[serde(default)]
-    pub max: f32,
-}
-
-#[derive(Debug, Clone, Serialize, Deserialize, Default)]
-pub struct PersonalityCondition {
-    #[serde(default)]
-    pub trait_name: String,
-    #[serde(default)]
-    pub min: f32,
-    #[serde(default)]
-    pub max: f32,
-}
-
-#[derive(Debug, Clone, Serialize, Deserialize, Default)]
-pub struct RelationshipThreshold {
-    #[serde(default)]
-    pub axis: String,
-    #[serde(default)]
-    pub min: f32,
-    #[serde(default)]
-    pub max: f32,
-}
-
-#[derive(Debug, Clone, Serialize, Deserialize, Default)]
-pub struct DistrictCondition {
-    #[serde(default)]
-    pub district: String,
-}
-
-#[derive(Debug, Clone, Serialize, Deserialize, Default)]
-pub struct MemoryEchoFlag {
-    #[serde(default)]
-    pub tag: String,
-}
-
-#[derive(Debug, Clone, Serialize, Deserialize, Default)]
-pub struct GlobalWorldStateFlag {
-    #[serde(default)]
-    pub flag: String,
-    #[serde(default)]
-    pub value: bool,
-}
-
-/// Skill requirement for storylet eligibility.
-#[derive(Debug, Clone, Serialize, Deserialize, Default)]
-pub struct SkillRequirement {
-    /// Skill ID that must be learned (e.g., "programming", "cooking")
-    #[serde(default)]
-    pub skill_id: String,
-    /// Minimum tier required (0=Novice, 1=Beginner, 2=Intermediate, 3=Advanced, 4=Expert, 5=Master)
-    #[serde(default)]
-    pub min_tier: Option<u8>,
-    /// Minimum XP required (alternative to tier)
-    #[serde(default)]
-    pub min_xp: Option<u32>,
-    /// Maximum tier allowed (for beginner-only content)
-    #[serde(default)]
-    pub max_tier: Option<u8>,
-}
-
-impl SkillRequirement {
-    /// Check if player skills meet this requirement.
-    pub fn is_met(&self, player_skills: &syn_core::skills::SkillState) -> bool {
-        use syn_core::skills::SkillId;
-        
-        let skill_id = SkillId::new(&self.skill_id);
-        let tier = player_skills.get_tier(&skill_id);
-        let xp = player_skills.get_xp(&skill_id);
-
-        // Check minimum tier
-        if let Some(min_tier) = self.min_tier {
-            if tier.as_level() < min_tier {
-                return false;
-            }
-        }
-
-        // Check minimum XP
-        if let Some(min_xp) = self.min_xp {
-            if xp < min_xp {
-                return false;
-            }
-        }
-
-        // Check maximum tier
-        if let Some(max_tier) = self.max_tier {
-            if tier.as_level() > max_tier {
-                return false;
-            }
-        }
-
-        true
-    }
-}
-
-impl StoryletPrerequisites {
-    pub fn passes(&self, _ctx: &EventContext) -> bool {
-        true
+impl StoryletChoice {
+    /// Whether the player meets every skill requirement.
+    pub fn is_unlocked(&self, world: &WorldState) -> bool {
+        self.skill_requirements
+            .iter()
+            .all(|req| req.is_met(&world.player_skills))
     }
 }
 
-/// Optional time/location prerequisites for storylets.
-#[derive(Debug, Clone, Serialize, Deserialize, Default)]
-pub struct TimeAndLocationPrereqs {
-    /// Allowed day phases for this storylet (if empty: any).
-    #[serde(default)]
-    pub allowed_phases: Vec<DayPhase>,
-    /// Required NPC activity kinds for primary actor (if any).
-    #[serde(default)]
-    pub allowed_npc_activities: Vec<NpcActivityKind>,
-}
-
 /// A role in a storylet (e.g., "target", "rival", "manager").
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct StoryletRole {
@@ -458,6 +369,25 @@ pub struct StoryletRole {
     pub npc_id: NpcId,
 }
 
+/// Content diagnostic: a storylet role names an NPC who doesn't exist.
+///
+/// Usually content written against NPC ids that have since shifted. The
+/// director recasts the role instead of rejecting the storylet.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct MissingRoleNpc {
+    /// Storylet with the stale reference.
+    pub storylet_id: String,
+    /// Role naming the NPC.
+    pub role: String,
+    /// The NPC that doesn't exist.
+    pub npc_id: NpcId,
+    /// Who the role was recast to when first seen (None: nobody fit, so
+    /// the storylet was rejected).
+    pub recast_to: Option<NpcId>,
+    /// Tick it was first seen.
+    pub tick: u64,
+}
+
 /// Storylet actor reference used by Director to locate/focus NPCs.
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub enum StoryActorRef {
@@ -482,7 +412,8 @@ pub struct StoryletActors {
 }
 
 /// For now, keep this simple:
-/// - RoleTag => pick some NPC with that role tag known to the player.
+/// - RoleTag => pick some NPC with that role tag known to the player,
+///   preferring major characters.
 /// - NpcId(u64) => wrap into NpcId.
 pub fn resolve_actor_ref_to_npc(
     world: &WorldState,
@@ -500,764 +431,239 @@ pub fn resolve_actor_ref_to_npc(
             }
         }
         StoryActorRef::RoleTag(tag) => {
+            // Major characters get first call on a role they fit.
+            let mut fallback = None;
             for npc_id in &world.known_npcs {
                 if let Some(proto) = world.npc_prototype(*npc_id) {
                     if proto.role_tags.contains(tag) {
                         let id = *npc_id;
                         if npc_is_available_for_player(world, registry, id) {
-                            return Some(id);
+                            if syn_core::is_major_character(world, id) {
+                                return Some(id);
+                            }
+                            fallback.get_or_insert(id);
                         }
                     }
                 }
             }
-            None
+            fallback
         }
     }
 }
 
-/// Is this NPC available to share a scene with the player right now?
-/// Simple rule: offscreen / online-only may only work for certain storylets.
-fn npc_is_available_for_player(world: &WorldState, registry: &NpcRegistry, npc_id: NpcId) -> bool {
-    if let Some(inst) = registry.get(npc_id) {
-        match inst.current_activity {
-            NpcActivityKind::Offscreen => false,
-            NpcActivityKind::Nightlife => {
-                matches!(world.game_time.phase, DayPhase::Evening | DayPhase::Night)
-            }
-            NpcActivityKind::OnlineOnly => true,
-            _ => true,
-        }
-    } else {
-        // If NPC not instantiated yet, allow Director to spawn them
-        true
-    }
-}
-
-/// Check time and NPC location/activity prerequisites against current world/registry state.
-fn check_time_and_location_prereqs(
-    world: &WorldState,
+/// Resolve `actor_ref` like [`resolve_actor_ref_to_npc`], casting a newcomer
+/// when no available known NPC carries its role tag (see
+/// [`syn_core::spawn_npc_for_role`]). The newcomer's origin is `storylet_id`.
+pub fn resolve_or_spawn_actor(
+    world: &mut WorldState,
     registry: &NpcRegistry,
-    storylet: &Storylet,
-) -> bool {
-    let Some(pr) = &storylet.prerequisites.time_and_location else {
-        return true;
-    };
-
-    // Phase gating
-    if !pr.allowed_phases.is_empty() && !pr.allowed_phases.contains(&world.game_time.phase) {
-        return false;
-    }
-
-    // NPC activity gating (if we have an NPC actor)
-    if pr.allowed_npc_activities.is_empty() {
-        return true;
+    actor_ref: &StoryActorRef,
+    storylet_id: &str,
+    tick: u64,
+) -> Option<NpcId> {
+    if let Some(npc_id) = resolve_actor_ref_to_npc(world, registry, actor_ref) {
+        return Some(npc_id);
     }
-
-    let Some(actors) = &storylet.outcomes.actors else {
-        return true;
-    };
-    if let Some(ref primary) = actors.primary {
-        if let Some(npc_id) = resolve_actor_ref_to_npc(world, registry, primary) {
-            if let Some(inst) = registry.get(npc_id) {
-                return pr.allowed_npc_activities.contains(&inst.current_activity);
-            }
-        }
+    match actor_ref {
+        StoryActorRef::RoleTag(tag) => Some(syn_core::spawn_npc_for_role(
+            world,
+            *tag,
+            storylet_id,
+            tick,
+        )),
+        StoryActorRef::NpcId(_) | StoryActorRef::Player => None,
     }
-    true
-}
-
-/// Internal: access an NPC's current behavior snapshot from the registry.
-fn get_npc_behavior<'a>(registry: &'a NpcRegistry, npc_id: NpcId) -> Option<&'a BehaviorSnapshot> {
-    registry.get(npc_id)?.behavior.as_ref()
 }
 
-fn tone_matches_behavior(tone: &InteractionTone, beh: &BehaviorKind) -> bool {
-    match (tone, beh) {
-        (InteractionTone::Support, BehaviorKind::SeekSocial) => true,
-        (InteractionTone::Attention, BehaviorKind::SeekRecognition) => true,
-        (InteractionTone::Conflict, BehaviorKind::SeekAutonomy) => true,
-        (InteractionTone::Withdrawal, BehaviorKind::SeekComfort) => true,
-        (InteractionTone::Stability, BehaviorKind::SeekSecurity) => true,
-        _ => false,
-    }
+/// A storylet's primary NPC: its resolved primary actor, else its first role.
+fn primary_npc(world: &WorldState, registry: &NpcRegistry, storylet: &Storylet) -> Option<NpcId> {
+    storylet
+        .outcomes
+        .actors
+        .as_ref()
+        .and_then(|actors| actors.primary.as_ref())
+        .and_then(|actor| resolve_actor_ref_to_npc(world, registry, actor))
+        .or_else(|| storylet.roles.first().map(|role| role.npc_id))
 }
 
-/// Public helper: compute an intent-based score multiplier for a storylet.
-/// Additive and side-effect free.
-pub fn npc_intent_score_multiplier(
-    world: &WorldState,
+/// Districts a storylet's scene can be set in: the player's district and
+/// the primary NPC's.
+fn scene_districts<'a>(
+    world: &'a WorldState,
     registry: &NpcRegistry,
     storylet: &Storylet,
-) -> f32 {
-    let tone = match &storylet.outcomes.interaction_tone {
-        Some(t) => t,
-        None => return 1.0,
-    };
-
-    let Some(actors) = &storylet.outcomes.actors else {
-        return 1.0;
-    };
-
-    if let Some(primary_ref) = &actors.primary {
-        if let Some(npc_id) = resolve_actor_ref_to_npc(world, registry, primary_ref) {
-            if let Some(snapshot) = get_npc_behavior(registry, npc_id) {
-                return if tone_matches_behavior(tone, &snapshot.chosen_intent.kind) {
-                    1.3
-                } else {
-                    0.9
-                }
-            }
-        }
-    }
-
-    1.0
-}
-
-/// Prepare storylet execution by focusing relevant NPCs in the simulation registry.
-pub fn prepare_storylet_execution(
-    world: &mut WorldState,
-    registry: &mut NpcRegistry,
-    storylet: &Storylet,
-    tick: u64,
-) {
-    if let Some(actors) = &storylet.outcomes.actors {
-        if let Some(ref primary) = actors.primary {
-            if let Some(npc_id) = resolve_actor_ref_to_npc(world, registry, primary) {
-                registry.focus_npc_for_scene(world, npc_id, tick);
-                world.ensure_npc_known(npc_id);
-            }
-        }
-        if let Some(ref secondary) = actors.secondary {
-            if let Some(npc_id) = resolve_actor_ref_to_npc(world, registry, secondary) {
-                registry.focus_npc_for_scene(world, npc_id, tick);
-                world.ensure_npc_known(npc_id);
-            }
-        }
-    }
-}
-
-/// Outcome of a storylet firing: stat changes, relationship shifts, memory entries.
-#[derive(Debug, Clone, Serialize, Deserialize)]
-pub struct StoryletOutcome {
-    /// Unified stat changes from this outcome.
-    ///
-    /// JSON content uses `stat_impacts`; we expose it as `stat_deltas` in code.
-    #[serde(rename = "stat_impacts", alias = "stat_deltas", default)]
-    pub stat_deltas: Vec<StatDelta>,
-    #[serde(
-        rename = "relationship_impacts",
-        alias = "relationship_deltas",
-        default
-    )]
-    pub relationship_deltas: Vec<RelationshipDelta>,
-    #[serde(default)]
-    pub karma_delta: Option<f32>,
-    #[serde(default)]
-    pub memory_event_id: String,
-    #[serde(default)]
-    pub emotional_intensity: f32, // -1.0 to +1.0
-    #[serde(default)]
-    pub memory_tags: Vec<String>, // Tags applied to recorded memory
-    #[serde(default)]
-    pub heat_spike: f32, // Additional world heat delta from choices
-    #[serde(default)]
-    pub next_storylet: Option<String>,
-}
-
-impl Default for StoryletOutcome {
-    fn default() -> Self {
-        StoryletOutcome {
-            stat_deltas: Vec::new(),
-            relationship_deltas: Vec::new(),
-            karma_delta: None,
-            memory_event_id: "unknown".to_string(),
-            emotional_intensity: 0.0,
-            memory_tags: Vec::new(),
-            heat_spike: 0.0,
-            next_storylet: None,
+) -> Vec<&'a str> {
+    let primary = primary_npc(world, registry, storylet);
+
+    let mut districts = Vec::with_capacity(2);
+    for npc_id in std::iter::once(world.player_id).chain(primary) {
+        let district = world
+            .npcs
+            .get(&npc_id)
+            .map(|npc| npc.district.as_str())
+            .or_else(|| {
+                world
+                    .npc_prototypes
+                    .get(&npc_id)
+                    .and_then(|proto| proto.home_district.as_deref())
+            });
+        if let Some(d) = district.filter(|d| !d.is_empty()) {
+            districts.push(d);
         }
     }
+    districts
 }
 
-fn affection_band_rank(band: AffectionBand) -> u8 {
-    match band {
-        AffectionBand::Stranger => 0,
-        AffectionBand::Acquaintance => 1,
-        AffectionBand::Friendly => 2,
-        AffectionBand::Close => 3,
-        AffectionBand::Devoted => 4,
-    }
-}
-
-fn trust_band_rank(band: TrustBand) -> u8 {
-    match band {
-        TrustBand::Unknown => 0,
-        TrustBand::Wary => 1,
-        TrustBand::Neutral => 2,
-        TrustBand::Trusted => 3,
-        TrustBand::DeepTrust => 4,
-    }
-}
-
-fn attraction_band_rank(band: AttractionBand) -> u8 {
-    match band {
-        AttractionBand::None => 0,
-        AttractionBand::Curious => 1,
-        AttractionBand::Interested => 2,
-        AttractionBand::Strong => 3,
-        AttractionBand::Intense => 4,
-    }
+/// True if the storylet is something the player actively chooses to do.
+fn is_player_action(storylet: &Storylet) -> bool {
+    storylet
+        .triggers
+        .kind
+        .as_deref()
+        .map(|k| k.eq_ignore_ascii_case(PLAYER_ACTION_TRIGGER))
+        .unwrap_or(false)
 }
 
-fn resentment_band_rank(band: ResentmentBand) -> u8 {
-    match band {
-        ResentmentBand::None => 0,
-        ResentmentBand::Irritated => 1,
-        ResentmentBand::Resentful => 2,
-        ResentmentBand::Hostile => 3,
-        ResentmentBand::Vindictive => 4,
-    }
-}
+/// Prerequisite tag marking romance storylets, which need the target to
+/// reciprocate (see [`RomanceReciprocity`]).
+pub const ROMANCE_TAG: &str = "romance";
 
-fn familiarity_band_rank(value: f32) -> u8 {
-    if value <= -5.0 {
-        0
-    } else if value < 1.0 {
-        1
-    } else if value < 5.0 {
-        2
-    } else if value < 8.0 {
-        3
-    } else {
-        4
-    }
+fn default_romance_min_attraction() -> f32 {
+    3.0
 }
 
-fn band_rank_for(axis: ModelRelationshipAxis, rel: &RelationshipVector) -> u8 {
-    match axis {
-        ModelRelationshipAxis::Affection => affection_band_rank(rel.affection_band()),
-        ModelRelationshipAxis::Trust => trust_band_rank(rel.trust_band()),
-        ModelRelationshipAxis::Attraction => attraction_band_rank(rel.attraction_band()),
-        ModelRelationshipAxis::Familiarity => familiarity_band_rank(rel.familiarity),
-        ModelRelationshipAxis::Resentment => resentment_band_rank(rel.resentment_band()),
-    }
+fn default_romance_min_affection() -> f32 {
+    2.0
 }
 
-fn band_rank_from_name(axis: ModelRelationshipAxis, name: &str) -> Option<u8> {
-    let lowered = name.to_ascii_lowercase();
-    match axis {
-        ModelRelationshipAxis::Affection | ModelRelationshipAxis::Familiarity => {
-            Some(affection_band_rank(match lowered.as_str() {
-                "stranger" => AffectionBand::Stranger,
-                "acquaintance" => AffectionBand::Acquaintance,
-                "friendly" => AffectionBand::Friendly,
-                "close" => AffectionBand::Close,
-                "devoted" => AffectionBand::Devoted,
-                _ => AffectionBand::Stranger,
-            }))
-        }
-        ModelRelationshipAxis::Trust => Some(trust_band_rank(match lowered.as_str() {
-            "unknown" => TrustBand::Unknown,
-            "wary" => TrustBand::Wary,
-            "neutral" => TrustBand::Neutral,
-            "trusted" => TrustBand::Trusted,
-            "deeptrust" | "deep_trust" | "deep trust" => TrustBand::DeepTrust,
-            _ => TrustBand::Unknown,
-        })),
-        ModelRelationshipAxis::Attraction => Some(attraction_band_rank(match lowered.as_str() {
-            "none" => AttractionBand::None,
-            "curious" => AttractionBand::Curious,
-            "interested" => AttractionBand::Interested,
-            "strong" => AttractionBand::Strong,
-            "intense" => AttractionBand::Intense,
-            _ => AttractionBand::None,
-        })),
-        ModelRelationshipAxis::Resentment => Some(resentment_band_rank(match lowered.as_str() {
-            "none" => ResentmentBand::None,
-            "irritated" => ResentmentBand::Irritated,
-            "resentful" => ResentmentBand::Resentful,
-            "hostile" => ResentmentBand::Hostile,
-            "vindictive" => ResentmentBand::Vindictive,
-            _ => ResentmentBand::None,
-        })),
-    }
+/// How much the target must feel for the player before a romance storylet
+/// plays as written. Measured on the NPC→player relationship.
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub struct RomanceReciprocity {
+    /// Minimum NPC→player attraction (default 3.0, "Interested").
+    #[serde(default = "default_romance_min_attraction")]
+    pub min_attraction: f32,
+    /// Minimum NPC→player affection (default 2.0).
+    #[serde(default = "default_romance_min_affection")]
+    pub min_affection: f32,
 }
 
-fn check_relationship_prereqs(
-    world: &WorldState,
-    prereqs: &[RelationshipPrereq],
-    default_actor_id: NpcId,
-) -> bool {
-    for prereq in prereqs {
-        let actor = NpcId(prereq.actor_id.unwrap_or(default_actor_id.0));
-        let target = NpcId(prereq.target_id);
-
-        let rel = match world.relationships.get(&(actor, target)) {
-            Some(r) => r,
-            None => return false,
-        };
-
-        let rel_vec = RelationshipVector {
-            affection: rel.affection,
-            trust: rel.trust,
-            attraction: rel.attraction,
-            familiarity: rel.familiarity,
-            resentment: rel.resentment,
-        };
-
-        let value = rel_vec.get(prereq.axis);
-        if let Some(min_v) = prereq.min_value {
-            if value < min_v {
-                return false;
-            }
-        }
-        if let Some(max_v) = prereq.max_value {
-            if value > max_v {
-                return false;
-            }
-        }
-
-        let band_rank = band_rank_for(prereq.axis, &rel_vec);
-        if let Some(ref min_band_name) = prereq.min_band {
-            if let Some(min_rank) = band_rank_from_name(prereq.axis, min_band_name) {
-                if band_rank < min_rank {
-                    return false;
-                }
-            }
-        }
-        if let Some(ref max_band_name) = prereq.max_band {
-            if let Some(max_rank) = band_rank_from_name(prereq.axis, max_band_name) {
-                if band_rank > max_rank {
-                    return false;
-                }
-            }
+impl Default for RomanceReciprocity {
+    fn default() -> Self {
+        Self {
+            min_attraction: default_romance_min_attraction(),
+            min_affection: default_romance_min_affection(),
         }
     }
-
-    true
-}
-
-fn check_life_stage_prereqs(world: &WorldState, pre: &StoryletPrerequisites) -> bool {
-    if pre.allowed_life_stages.is_empty() {
-        return true;
-    }
-    pre.allowed_life_stages.contains(&world.player_life_stage)
 }
 
-fn check_digital_legacy_prereq(world: &WorldState, pre: &Option<DigitalLegacyPrereq>) -> bool {
-    let Some(pre) = pre else {
-        return true;
-    };
-
-    // If require_post_life is true, and we're not in Digital, fail.
-    if pre.require_post_life && !matches!(world.player_life_stage, LifeStage::Digital) {
-        return false;
-    }
-
-    let imprint = match &world.digital_legacy.primary_imprint {
-        Some(i) => i,
-        None => {
-            // No imprint yet; if we require any bounds, fail.
-            return false;
-        }
-    };
-
-    let lv = &imprint.legacy_vector;
-
-    let between = |v: f32, min: &Option<f32>, max: &Option<f32>| {
-        if let Some(m) = min {
-            if v < *m {
-                return false;
-            }
-        }
-        if let Some(m) = max {
-            if v > *m {
-                return false;
-            }
-        }
-        true
-    };
-
-    between(
-        lv.compassion_vs_cruelty,
-        &pre.min_compassion_vs_cruelty,
-        &pre.max_compassion_vs_cruelty,
-    ) && between(
-        lv.ambition_vs_comfort,
-        &pre.min_ambition_vs_comfort,
-        &pre.max_ambition_vs_comfort,
-    ) && between(
-        lv.connection_vs_isolation,
-        &pre.min_connection_vs_isolation,
-        &pre.max_connection_vs_isolation,
-    ) && between(
-        lv.stability_vs_chaos,
-        &pre.min_stability_vs_chaos,
-        &pre.max_stability_vs_chaos,
-    ) && between(
-        lv.light_vs_shadow,
-        &pre.min_light_vs_shadow,
-        &pre.max_light_vs_shadow,
-    )
+/// How a romance storylet's target responds to the player.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum RomanceResponse {
+    /// Not a romance storylet, or it has no target.
+    NotRomance,
+    /// The target returns the player's interest.
+    Reciprocated,
+    /// The target does not; the storylet plays its rejection path, if any.
+    Unreciprocated,
 }
 
-fn memory_tags_for_pair(memory: &MemorySystem, actor_id: u64, target_id: u64) -> Vec<String> {
-    memory
-        .journals
-        .values()
-        .flat_map(|journal| journal.entries.iter())
-        .filter(|m| {
-            let has_actor = m.participants.contains(&actor_id);
-            let has_target = m.participants.contains(&target_id);
-            has_actor && has_target
-        })
-        .flat_map(|m| m.tags.clone())
-        .collect()
-}
-
-fn storylet_targets_pair(
-    pre: &StoryletPrerequisites,
-    actor_id: u64,
-    target_id: u64,
-    default_actor_id: u64,
-) -> bool {
-    for r in &pre.relationship_prereqs {
-        let a = r.actor_id.unwrap_or(default_actor_id);
-        if a == actor_id && r.target_id == target_id {
-            return true;
-        }
-    }
-    false
-}
-
-fn storylet_matches_pressure_kind(
-    pre: &StoryletPrerequisites,
-    event: &RelationshipPressureEvent,
-) -> bool {
-    use RelationshipEventKind::*;
-
-    let axis = match event.kind {
-        AffectionBandChanged => Some(ModelRelationshipAxis::Affection),
-        TrustBandChanged => Some(ModelRelationshipAxis::Trust),
-        AttractionBandChanged => Some(ModelRelationshipAxis::Attraction),
-        ResentmentBandChanged => Some(ModelRelationshipAxis::Resentment),
-    };
-
-    axis.map(|axis| pre.relationship_prereqs.iter().any(|r| r.axis == axis))
-        .unwrap_or(false)
+fn is_romance(storylet: &Storylet) -> bool {
+    storylet
+        .prerequisites
+        .tags
+        .iter()
+        .any(|t| t.eq_ignore_ascii_case(ROMANCE_TAG))
 }
 
-fn score_storylet_with_pressure(
-    director: &EventDirector,
+fn romance_response_for(
     world: &WorldState,
     storylet: &Storylet,
-    hot_event: Option<&RelationshipPressureEvent>,
-) -> f32 {
-    let mut score = director.score_storylet(storylet, world);
-
-    let pre = &storylet.prerequisites;
-    let default_actor_id = world.player_id.0;
-
-    if let Some(event) = hot_event {
-        if storylet_targets_pair(pre, event.actor_id, event.target_id, default_actor_id) {
-            score += 50.0;
-
-            if storylet_matches_pressure_kind(pre, event) {
-                score += 25.0;
-            }
-        }
-    }
-
-    score
-}
-
-fn storylet_heat_band_match(heat_band: NarrativeHeatBand, storylet: &Storylet) -> bool {
-    let Some(category) = &storylet.outcomes.heat_category else {
-        return true;
+    target: Option<NpcId>,
+) -> RomanceResponse {
+    let Some(target) = target.filter(|id| *id != world.player_id) else {
+        return RomanceResponse::NotRomance;
     };
-
-    match (heat_band, category) {
-        (NarrativeHeatBand::Low, StoryletHeatCategory::SliceOfLife) => true,
-        (NarrativeHeatBand::Medium, StoryletHeatCategory::RisingTension) => true,
-        (NarrativeHeatBand::High, StoryletHeatCategory::HighDrama) => true,
-        (NarrativeHeatBand::Critical, StoryletHeatCategory::CriticalArc) => true,
-        (NarrativeHeatBand::Medium, StoryletHeatCategory::SliceOfLife) => true,
-        (NarrativeHeatBand::High, StoryletHeatCategory::RisingTension) => true,
-        (NarrativeHeatBand::Critical, StoryletHeatCategory::HighDrama) => true,
-        _ => false,
-    }
-}
-
-fn heat_score_multiplier(heat_band: NarrativeHeatBand, storylet: &Storylet) -> f32 {
-    let Some(category) = &storylet.outcomes.heat_category else {
-        return 1.0;
-    };
-
-    match (heat_band, category) {
-        (NarrativeHeatBand::Low, StoryletHeatCategory::SliceOfLife) => 1.3,
-        (NarrativeHeatBand::Medium, StoryletHeatCategory::RisingTension) => 1.4,
-        (NarrativeHeatBand::High, StoryletHeatCategory::HighDrama) => 1.5,
-        (NarrativeHeatBand::Critical, StoryletHeatCategory::CriticalArc) => 1.7,
-        (NarrativeHeatBand::Medium, StoryletHeatCategory::SliceOfLife) => 0.9,
-        (NarrativeHeatBand::High, StoryletHeatCategory::RisingTension) => 1.1,
-        (NarrativeHeatBand::Critical, StoryletHeatCategory::HighDrama) => 1.2,
-        (NarrativeHeatBand::Low, StoryletHeatCategory::HighDrama) => 0.4,
-        (NarrativeHeatBand::Low, StoryletHeatCategory::CriticalArc) => 0.2,
-        (NarrativeHeatBand::Medium, StoryletHeatCategory::CriticalArc) => 0.5,
-        (NarrativeHeatBand::High, StoryletHeatCategory::SliceOfLife) => 0.7,
-        _ => 1.0,
-    }
-}
-
-fn life_stage_score_multiplier(world: &WorldState, pre: &StoryletPrerequisites) -> f32 {
-    if pre.allowed_life_stages.is_empty() {
-        return 1.0;
-    }
-    if pre.allowed_life_stages.contains(&world.player_life_stage) {
-        1.2
+    if !is_romance(storylet) {
+        return RomanceResponse::NotRomance;
+    }
+    let gate = storylet
+        .prerequisites
+        .romance_reciprocity
+        .unwrap_or_default();
+    let rel = world.get_relationship(target, world.player_id);
+    if rel.attraction >= gate.min_attraction && rel.affection >= gate.min_affection {
+        RomanceResponse::Reciprocated
     } else {
-        0.8
+        RomanceResponse::Unreciprocated
     }
 }
 
-fn digital_legacy_score_multiplier(world: &WorldState, pre: &Option<DigitalLegacyPrereq>) -> f32 {
-    let Some(pre) = pre else {
-        return 1.0;
-    };
-
-    if !matches!(world.player_life_stage, LifeStage::Digital) {
-        return 1.0;
-    }
-
-    if world.digital_legacy.primary_imprint.is_none() {
-        return 1.0;
-    }
-
-    // For now: if we have any bound at all, and prereq passes, give a small boost.
-    // (We already checked prereqs in find_eligible.)
-    let has_any_bounds = pre.min_compassion_vs_cruelty.is_some()
-        || pre.max_compassion_vs_cruelty.is_some()
-        || pre.min_ambition_vs_comfort.is_some()
-        || pre.max_ambition_vs_comfort.is_some()
-        || pre.min_connection_vs_isolation.is_some()
-        || pre.max_connection_vs_isolation.is_some()
-        || pre.min_stability_vs_chaos.is_some()
-        || pre.max_stability_vs_chaos.is_some()
-        || pre.min_light_vs_shadow.is_some()
-        || pre.max_light_vs_shadow.is_some();
-
-    if has_any_bounds {
-        1.25
-    } else {
-        1.0
-    }
-}
-
-/// Check if a storylet's tags match any district pressure event tags.
-fn storylet_matches_district_pressure(
-    storylet: &Storylet,
-    event: &DistrictPressureEvent,
-) -> bool {
-    let event_tags = event.kind.tags();
-    // Convert event tags to a bitset and check for overlap
-    let event_bitset = TagBitset::from_tags_slice(
-        &event_tags.iter().map(|s| s.to_string()).collect::<Vec<_>>()
-    );
-    storylet.tags.matches(&event_bitset)
-}
-
-/// Score bonus from district pressure events.
-///
-/// Returns a bonus if the storylet:
-/// 1. Has district_conditions matching a district with pending pressure events (+30)
-/// 2. Has tags matching the pressure event type (+20)
-fn score_district_pressure_bonus(
+/// Public helper: whether a romance storylet's primary NPC reciprocates.
+pub fn romance_response(
     world: &WorldState,
+    registry: &NpcRegistry,
     storylet: &Storylet,
-) -> f32 {
-    let pre = &storylet.prerequisites;
-    let mut bonus = 0.0;
-
-    // Check if storylet has district conditions
-    for dc in &pre.district_conditions {
-        // Find matching pressure events for this district
-        for event in world.district_pressure.queue.iter() {
-            if event.district_name == dc.district {
-                // District match bonus
-                bonus += 30.0;
-                // Tag match bonus (additional if storylet tags match pressure type)
-                if storylet_matches_district_pressure(storylet, event) {
-                    bonus += 20.0;
-                }
-                break; // Only one bonus per district condition
-            }
-        }
-    }
-
-    // Also check storylet tags for general crisis/prosperity themes
-    if bonus == 0.0 && !world.district_pressure.queue.is_empty() {
-        for event in world.district_pressure.queue.iter() {
-            if storylet_matches_district_pressure(storylet, event) {
-                bonus += 15.0;
-                break;
-            }
-        }
-    }
-
-    bonus
+) -> RomanceResponse {
+    romance_response_for(world, storylet, primary_npc(world, registry, storylet))
 }
 
-/// Check if a storylet's tags match any gossip pressure event tags.
-fn storylet_matches_gossip_pressure(
-    storylet: &Storylet,
-    event: &GossipPressureEvent,
-) -> bool {
-    let event_tags = event.kind.tags();
-    // Convert event tags to a bitset and check for overlap
-    let event_bitset = TagBitset::from_tags_slice(
-        &event_tags.iter().map(|s| s.to_string()).collect::<Vec<_>>()
-    );
-    storylet.tags.matches(&event_bitset)
+/// Unreciprocated romance can only fire if it has a refusal beat to play.
+fn romance_allows(response: RomanceResponse, storylet: &Storylet) -> bool {
+    response != RomanceResponse::Unreciprocated || !storylet.outcomes.rejection_choices.is_empty()
 }
 
-/// Score bonus from gossip pressure events.
+/// Public helper: the choices the player gets for a storylet right now.
 ///
-/// Returns a bonus if the storylet has tags matching gossip event types:
-/// - Scandal (+25), Betrayal (+20), Reputation damage (+15), other gossip (+10)
-fn score_gossip_pressure_bonus(
+/// Unreciprocated romance storylets offer their rejection choices instead.
+pub fn active_choices<'a>(
     world: &WorldState,
-    storylet: &Storylet,
-) -> f32 {
-    let mut bonus = 0.0;
-
-    for event in &world.gossip_pressure.events {
-        if storylet_matches_gossip_pressure(storylet, event) {
-            // Scale bonus by event severity and type
-            let type_bonus = match event.kind {
-                GossipEventKind::ScandalCirculating => 25.0,
-                GossipEventKind::BetrayalGossip => 20.0,
-                GossipEventKind::ReputationDamaged => 15.0,
-                GossipEventKind::SocialExclusion => 12.0,
-                GossipEventKind::NegativeRumorSpreading => 10.0,
-                GossipEventKind::PositiveBuzz => 5.0,
-            };
-            bonus += type_bonus * event.severity;
+    registry: &NpcRegistry,
+    storylet: &'a Storylet,
+) -> &'a [StoryletChoice] {
+    match romance_response(world, registry, storylet) {
+        RomanceResponse::Unreciprocated if !storylet.outcomes.rejection_choices.is_empty() => {
+            &storylet.outcomes.rejection_choices
         }
+        _ => &storylet.outcomes.choices,
     }
-
-    // Cap total gossip bonus to prevent overwhelming other factors
-    bonus.min(50.0)
 }
 
-fn score_storylet_full(
-    director: &EventDirector,
+/// Who a storylet's first role should go to when it has to be recast and
+/// the storylet is about a declared status: the other side of the mismatch
+/// it reacts to, else the first NPC the player has declared one of its
+/// `declared_statuses` with.
+fn declared_cast_candidate(
     world: &WorldState,
     storylet: &Storylet,
-    hot_event: Option<&RelationshipPressureEvent>,
-) -> f32 {
-    let base = score_storylet_with_pressure(director, world, storylet, hot_event);
-    let heat_band = world.narrative_heat.band();
-    let heat_mult = heat_score_multiplier(heat_band, storylet);
-    let stage_mult = life_stage_score_multiplier(world, &storylet.prerequisites);
-    let legacy_mult =
-        digital_legacy_score_multiplier(world, &storylet.prerequisites.digital_legacy_prereq);
-    // Pressure bonuses (additive)
-    let district_bonus = score_district_pressure_bonus(world, storylet);
-    let gossip_bonus = score_gossip_pressure_bonus(world, storylet);
-    let mut score = base * heat_mult * stage_mult * legacy_mult + district_bonus + gossip_bonus;
-    if storylet.outcomes.heat_category.is_some() && !storylet_heat_band_match(heat_band, storylet) {
-        score *= 0.5;
-    }
-    score
-}
-
-/// Variant scoring that considers NPC intent via the registry.
-pub fn score_storylet_full_with_registry(
-    director: &EventDirector,
-    world: &WorldState,
-    registry: &NpcRegistry,
-    storylet: &Storylet,
-    hot_event: Option<&RelationshipPressureEvent>,
-) -> f32 {
-    // If time/location prereqs fail, short-circuit to 0 score.
-    if !check_time_and_location_prereqs(world, registry, storylet) {
-        return 0.0;
-    }
-    let base = score_storylet_full(director, world, storylet, hot_event);
-    let intent_mult = npc_intent_score_multiplier(world, registry, storylet);
-    (base * intent_mult).clamp(0.0, 100.0)
-}
-
-/// Variant selection API that uses NPC intent when available.
-pub fn select_next_event_with_registry<'a>(
-    director: &'a EventDirector,
-    world: &WorldState,
-    registry: &NpcRegistry,
-    memory: &MemorySystem,
-    current_tick: SimTick,
-) -> Option<&'a Storylet> {
-    let eligible = director.find_eligible(world, memory, current_tick);
-    if eligible.is_empty() {
-        return None;
-    }
-    let hot_event_opt = world.relationship_pressure.peek_next_event();
-    let mut best_storylet: Option<&Storylet> = None;
-    let mut best_score = f32::MIN;
-    for storylet in eligible {
-        let score =
-            score_storylet_full_with_registry(director, world, registry, storylet, hot_event_opt);
-        if score > best_score {
-            best_score = score;
-            best_storylet = Some(storylet);
-        }
-    }
-    best_storylet
-}
-
-/// Cooldown tracker to prevent storylet repetition.
-#[derive(Debug, Clone, Serialize, Deserialize)]
-struct CooldownTracker {
-    global_cooldowns: HashMap<String, SimTick>, // storylet_id -> until_tick
-    npc_cooldowns: HashMap<(String, NpcId), SimTick>, // (storylet_id, npc_id) -> until_tick
+    taken: &[NpcId],
+) -> Option<NpcId> {
+    let player = world.player_id.0;
+    let other_side = |a: u64, b: u64| if a == player { b } else { a };
+    let usable = |id: &u64| {
+        let id = NpcId(*id);
+        id != world.player_id && !taken.contains(&id) && world.npcs.contains_key(&id)
+    };
+    let state = &world.declared_relationships;
+    let from_mismatch = storylet
+        .triggers
+        .kind
+        .as_deref()
+        .filter(|kind| syn_core::is_declared_mismatch_trigger(kind))
+        .and_then(|kind| state.mismatch_for(kind))
+        .map(|m| other_side(m.actor_id, m.target_id))
+        .filter(usable);
+    from_mismatch
+        .or_else(|| {
+            state
+                .partners_with(player, &storylet.prerequisites.declared_statuses)
+                .find(usable)
+        })
+        .map(NpcId)
 }
 
-impl CooldownTracker {
-    fn new() -> Self {
-        CooldownTracker {
-            global_cooldowns: HashMap::new(),
-            npc_cooldowns: HashMap::new(),
-        }
-    }
-
-    fn is_ready(&self, storylet_id: &str, npc_id: NpcId, current_tick: SimTick) -> bool {
-        let global_ready = self
-            .global_cooldowns
-            .get(storylet_id)
-            .map(|until| current_tick.0 >= until.0)
-            .unwrap_or(true);
-
-        let npc_ready = self
-            .npc_cooldowns
-            .get(&(storylet_id.to_string(), npc_id))
-            .map(|until| current_tick.0 >= until.0)
-            .unwrap_or(true);
-
-        global_ready && npc_ready
-    }
-
-    fn mark_cooldown(
-        &mut self,
-        storylet_id: &str,
-        npc_id: NpcId,
-        cooldown_ticks: u32,
-        current_tick: SimTick,
-    ) {
-        let until = SimTick::new(current_tick.0 + cooldown_ticks as u64);
-        self.global_cooldowns.insert(storylet_id.to_string(), until);
-        self.npc_cooldowns
-            .insert((storylet_id.to_string(), npc_id), until);
-    }
-}
+/// Emotional intensity of the player's memory of being turned down by an NPC.
+const REFUSAL_MEMORY_INTENSITY: f32 = -0.2;
 
 /// Event Director: orchestrates storylet selection and firing.
 ///
@@ -1272,22 +678,225 @@ pub struct EventDirector {
     /// Reference to the compiled storylet library (via trait object).
     /// This allows us to work with both in-memory and memory-mapped libraries.
     storylets: Vec<Storylet>,
+    /// Interned handle of each entry in `storylets`, resolved on registration.
+    storylet_handles: Vec<StoryletHandle>,
+    /// Position in `storylets` of the first storylet with each handle.
+    storylet_index: HashMap<StoryletHandle, usize>,
     cooldowns: CooldownTracker,
+    /// Pre-resolved outcomes for the compiled library, plus its quarantine list.
+    compiled_outcomes: ResolvedOutcomeTable,
+    /// Stages applied when a legacy storylet fires.
+    outcome_pipeline: OutcomePipeline,
+    /// Storylet roles seen naming a nonexistent NPC, first sighting only.
+    content_diagnostics: Mutex<Vec<MissingRoleNpc>>,
+    /// Storylets fired by `force_fire_storylet`, most recent last.
+    forced_fires: Vec<ForcedFire>,
+    /// Every storylet fired, oldest first.
+    chronicle: Chronicle,
+    /// Follow-ups queued by fired compiled storylets.
+    follow_ups: FollowUpScheduler,
+    /// Ranks eligible storylets in `select_next_event`.
+    scoring_strategy: Box<dyn ScoringStrategy>,
+    /// Picks among the ranked storylets.
+    selection_mode: SelectionMode,
+    /// Heat fired over the last week, held to daily and weekly caps.
+    pacing_budget: PacingBudget,
 }
 
 impl EventDirector {
     pub fn new() -> Self {
         EventDirector {
             storylets: Vec::new(),
+            storylet_handles: Vec::new(),
+            storylet_index: HashMap::new(),
             cooldowns: CooldownTracker::new(),
+            compiled_outcomes: ResolvedOutcomeTable::default(),
+            outcome_pipeline: OutcomePipeline::standard(),
+            content_diagnostics: Mutex::new(Vec::new()),
+            forced_fires: Vec::new(),
+            chronicle: Chronicle::new(),
+            follow_ups: FollowUpScheduler::default(),
+            scoring_strategy: Box::new(DefaultScoringStrategy),
+            selection_mode: SelectionMode::Best,
+            pacing_budget: PacingBudget::default(),
         }
     }
 
+    /// Rank eligible storylets with `strategy` instead of the
+    /// [`DefaultScoringStrategy`].
+    pub fn set_scoring_strategy(&mut self, strategy: Box<dyn ScoringStrategy>) {
+        self.scoring_strategy = strategy;
+    }
+
+    /// Every active storylet cooldown, for save files.
+    pub fn cooldown_entries(&self) -> Vec<CooldownEntry> {
+        self.cooldowns.entries()
+    }
+
+    /// Replace the director's cooldowns with ones taken from a save.
+    pub fn restore_cooldowns(&mut self, entries: &[CooldownEntry]) {
+        self.cooldowns.restore(entries);
+    }
+
+    /// The strategy ranking eligible storylets.
+    pub fn scoring_strategy(&self) -> &dyn ScoringStrategy {
+        self.scoring_strategy.as_ref()
+    }
+
+    /// Score `storylet` with this director's strategy, with the factors
+    /// behind the score.
+    pub fn explain_score(&self, world: &WorldState, storylet: &Storylet) -> StoryletScore {
+        let hot_event_opt = world.relationship_pressure.peek_next_event();
+        self.scoring_strategy
+            .score(&ScoringContext::new(self, world, storylet, hot_event_opt))
+    }
+
+    /// Add a stage that runs after the standard outcome stages whenever a
+    /// storylet fires through this director.
+    pub fn register_outcome_stage(&mut self, stage: Box<dyn OutcomeStage>) {
+        self.outcome_pipeline.register(stage);
+    }
+
+    /// Stages applied when a storylet fires, in order.
+    pub fn outcome_pipeline(&self) -> &OutcomePipeline {
+        &self.outcome_pipeline
+    }
+
+    /// Change how far a relationship axis must move before the outcome's
+    /// memory is tagged `<axis>_spike` / `<axis>_loss`.
+    pub fn set_axis_tag_thresholds(&mut self, thresholds: AxisTagThresholds) {
+        self.outcome_pipeline.set_axis_tag_thresholds(thresholds);
+    }
+
+    /// Validate and pre-resolve the outcomes of a compiled storylet library.
+    ///
+    /// Call once after loading the library. Storylets whose outcomes name an
+    /// unknown stat, relationship axis or role are quarantined and never fired
+    /// by `tick_compiled_storylets_simple`; the returned errors locate each
+    /// offending field.
+    pub fn load_compiled_outcomes<S: StoryletSource + ?Sized>(
+        &mut self,
+        library: &S,
+    ) -> Vec<OutcomeValidationError> {
+        self.compiled_outcomes = ResolvedOutcomeTable::resolve(library);
+        self.compiled_outcomes.errors().cloned().collect()
+    }
+
     /// Register a storylet (legacy, for backward compatibility).
     pub fn register_storylet(&mut self, storylet: Storylet) {
+        let handle = StoryletHandle::new(&storylet.id);
+        self.storylet_index.entry(handle).or_insert(self.storylets.len());
+        self.storylet_handles.push(handle);
         self.storylets.push(storylet);
     }
 
+    /// The registered storylet with `storylet_id`, with its handle.
+    pub(crate) fn registered_storylet(&self, storylet_id: &str) -> Option<(StoryletHandle, &Storylet)> {
+        // An ID that was never interned was never registered.
+        let handle = StoryletHandle::get(storylet_id)?;
+        let index = *self.storylet_index.get(&handle)?;
+        Some((handle, &self.storylets[index]))
+    }
+
+    /// Storylet roles that named a nonexistent NPC, in the order first seen.
+    ///
+    /// Each (storylet, role, NPC) is reported once.
+    pub fn content_diagnostics(&self) -> Vec<MissingRoleNpc> {
+        self.content_diagnostics
+            .lock()
+            .map(|log| log.clone())
+            .unwrap_or_default()
+    }
+
+    /// Resolve a storylet's roles against the world.
+    ///
+    /// A role whose explicit NPC doesn't exist is recast through the
+    /// [`RoleAssignmentEngine`] from the known NPCs not already in the
+    /// storylet, and the stale reference is logged as a content diagnostic.
+    /// Open roles are then cast by the [`CastingEngine`] and appended as
+    /// fixed ones.
+    /// Returns the storylet unchanged when every role NPC exists and none is
+    /// open, a cast copy otherwise, or `None` if a role cannot be recast or a
+    /// required open role cannot be cast.
+    pub fn cast_storylet_roles<'s>(
+        &self,
+        storylet: &'s Storylet,
+        world: &WorldState,
+        memory: &MemorySystem,
+        current_tick: SimTick,
+    ) -> Option<Cow<'s, Storylet>> {
+        if storylet.roles.open.is_empty()
+            && storylet
+                .roles
+                .iter()
+                .all(|role| world.npcs.contains_key(&role.npc_id))
+        {
+            return Some(Cow::Borrowed(storylet));
+        }
+
+        let ctx = EligibilityContext {
+            world,
+            memory,
+            current_tick,
+        };
+        let engine = RoleAssignmentEngine::from_context(&ctx);
+        let mut cast = storylet.clone();
+        let mut taken: Vec<NpcId> = storylet
+            .roles
+            .iter()
+            .map(|role| role.npc_id)
+            .filter(|id| world.npcs.contains_key(id))
+            .collect();
+        for (index, role) in cast.roles.iter_mut().enumerate() {
+            if world.npcs.contains_key(&role.npc_id) {
+                continue;
+            }
+            let declared = if index == 0 {
+                declared_cast_candidate(world, storylet, &taken)
+            } else {
+                None
+            };
+            let recast = declared
+                .or_else(|| engine.recast_role(&role.name, &storylet.prerequisites.tags, &taken));
+            self.log_missing_role_npc(MissingRoleNpc {
+                storylet_id: storylet.id.clone(),
+                role: role.name.clone(),
+                npc_id: role.npc_id,
+                recast_to: recast,
+                tick: current_tick.0,
+            });
+            role.npc_id = recast?;
+            taken.push(role.npc_id);
+        }
+
+        let assignment = CastingEngine::new(world, memory, current_tick)
+            .cast(&cast)
+            .ok()?;
+        for slot in std::mem::take(&mut cast.roles.open) {
+            if let Some(&npc_id) = assignment.get(&slot.name) {
+                cast.roles.push(StoryletRole {
+                    name: slot.name,
+                    npc_id,
+                });
+            }
+        }
+        Some(Cow::Owned(cast))
+    }
+
+    fn log_missing_role_npc(&self, diagnostic: MissingRoleNpc) {
+        let Ok(mut log) = self.content_diagnostics.lock() else {
+            return;
+        };
+        let seen = log.iter().any(|d| {
+            d.storylet_id == diagnostic.storylet_id
+                && d.role == diagnostic.role
+                && d.npc_id == diagnostic.npc_id
+        });
+        if !seen {
+            log.push(diagnostic);
+        }
+    }
+
     /// Find eligible storylets based on world state.
     /// NOTE: This uses the old Storylet system. For new compiled storylets,
     /// use the EligibilityEngine directly.
@@ -1299,43 +908,127 @@ impl EventDirector {
     ) -> Vec<&Storylet> {
         self.storylets
             .iter()
-            .filter(|s| self.is_eligible(s, world, memory, current_tick))
+            .zip(&self.storylet_handles)
+            .filter(|(s, handle)| self.is_eligible(**handle, s, world, memory, current_tick))
+            .map(|(s, _)| s)
             .collect()
     }
 
     /// Check if a storylet is eligible to fire.
     fn is_eligible(
         &self,
+        handle: StoryletHandle,
         storylet: &Storylet,
         world: &WorldState,
         memory: &MemorySystem,
         current_tick: SimTick,
     ) -> bool {
+        self.eligibility_failures(handle, storylet, world, memory, current_tick, false)
+            .is_empty()
+    }
+
+    /// Names of the eligibility checks a storylet fails, in check order.
+    ///
+    /// Stops at the first failure unless `all` is set. Checks after role
+    /// casting are skipped when no one can be cast (`"roles"`).
+    pub(crate) fn eligibility_failures(
+        &self,
+        handle: StoryletHandle,
+        storylet: &Storylet,
+        world: &WorldState,
+        memory: &MemorySystem,
+        current_tick: SimTick,
+        all: bool,
+    ) -> Vec<&'static str> {
+        let mut failures = Vec::new();
+        macro_rules! fail {
+            ($check:expr) => {{
+                failures.push($check);
+                if !all {
+                    return failures;
+                }
+            }};
+        }
+
         // Check cooldown
         if !self
             .cooldowns
-            .is_ready(&storylet.id, world.player_id, current_tick)
+            .is_ready(handle, world.player_id, current_tick)
         {
-            return false;
+            fail!("cooldown");
         }
 
-        // Check prerequisites
-        for role in &storylet.roles {
-            if !world.npcs.contains_key(&role.npc_id) {
-                return false; // Role NPC doesn't exist
-            }
+        // Respect player content toggles
+        if !check_content_preferences(world, &storylet.prerequisites) {
+            fail!("content_preferences");
+        }
+
+        // Never surface mature content below its minimum life stage
+        if !check_age_gate(world, &storylet.prerequisites) {
+            fail!("age_gate");
+        }
+
+        // Authored stat, personality, district, memory echo and global conditions
+        if let Some(check) = storylet.prerequisites.failed_condition(world, Some(memory)) {
+            fail!(check);
+        }
+
+        // Hard dependencies must have fired
+        if !storylet_dependencies_met(&storylet.prerequisites, &world.storylet_usage) {
+            fail!("dependencies");
+        }
+
+        // Reactions need a flag change or a pending NPC reaction to answer
+        if !trigger_context_ready(world, storylet, current_tick) {
+            fail!("trigger_context");
+        }
+
+        // Player-initiated storylets need energy left in today's budget
+        if is_player_action(storylet) && !world.action_budget.can_afford(STORYLET_ACTION_COST) {
+            fail!("action_budget");
         }
 
+        // Role NPCs that don't exist are recast; reject only if that fails
+        let Some(storylet) = self.cast_storylet_roles(storylet, world, memory, current_tick) else {
+            failures.push("roles");
+            return failures;
+        };
+        let storylet = storylet.as_ref();
+
         // Check relationship conditions
         if let Some(min_affection) = storylet.prerequisites.min_relationship_affection {
             if let Some(target_role) = storylet.roles.get(0) {
                 let rel = world.get_relationship(world.player_id, target_role.npc_id);
                 if rel.affection < min_affection {
-                    return false;
+                    fail!("min_relationship_affection");
                 }
             }
         }
 
+        // Check household (cohabitation) conditions
+        if let Some(lives_with) = storylet.prerequisites.lives_with_target {
+            if let Some(target_role) = storylet.roles.first() {
+                if world.households.lives_with(world.player_id, target_role.npc_id) != lives_with {
+                    fail!("lives_with_target");
+                }
+            }
+        }
+
+        // Check target mood (support storylets)
+        if let Some(max_mood) = storylet.prerequisites.max_target_mood {
+            if let Some(target_role) = storylet.roles.first() {
+                if syn_core::mood_of(world, target_role.npc_id) > max_mood {
+                    fail!("max_target_mood");
+                }
+            }
+        }
+
+        // Check romance reciprocity (target's side of the relationship)
+        let target = storylet.roles.first().map(|role| role.npc_id);
+        if !romance_allows(romance_response_for(world, storylet, target), storylet) {
+            fail!("romance");
+        }
+
         // Check relationship state conditions
         if !storylet.prerequisites.relationship_states.is_empty() {
             if let Some(target_role) = storylet.roles.get(0) {
@@ -1346,11 +1039,15 @@ impl EventDirector {
                     .relationship_states
                     .contains(&rel.state)
                 {
-                    return false;
+                    fail!("relationship_states");
                 }
             }
         }
 
+        if !declared_status_allows(world, storylet) {
+            fail!("declared_statuses");
+        }
+
         // Check memory prerequisites
         if !storylet.prerequisites.memory_tags_required.is_empty() {
             if let Some(target_role) = storylet.roles.get(0) {
@@ -1362,10 +1059,10 @@ impl EventDirector {
                         .iter()
                         .any(|tag| !journal.memories_with_tag(tag).is_empty());
                     if !has_required_tag {
-                        return false;
+                        fail!("memory_tags_required");
                     }
                 } else {
-                    return false; // No journal for this NPC
+                    fail!("memory_tags_required"); // No journal for this NPC
                 }
             }
         }
@@ -1381,7 +1078,7 @@ impl EventDirector {
                         .iter()
                         .any(|tag| !journal.memories_with_tag(tag).is_empty());
                     if has_forbidden_tag {
-                        return false;
+                        fail!("memory_tags_forbidden");
                     }
                 }
             }
@@ -1405,7 +1102,7 @@ impl EventDirector {
                                     .any(|m| m.tags.contains(tag))
                             });
                         if !has_recent_tag {
-                            return false;
+                            fail!("memory_recency_ticks");
                         }
                     }
                 }
@@ -1413,7 +1110,7 @@ impl EventDirector {
         }
 
         if !check_life_stage_prereqs(world, &storylet.prerequisites) {
-            return false;
+            fail!("life_stage");
         }
 
         // Relationship prereqs using the new relationship model (additive, non-breaking).
@@ -1422,15 +1119,27 @@ impl EventDirector {
             &storylet.prerequisites.relationship_prereqs,
             world.player_id,
         ) {
-            return false;
+            fail!("relationship_prereqs");
+        }
+
+        // Authored boolean expression over stats, relationships, memories and flags
+        if let Some(expression) = &storylet.prerequisites.expression {
+            let ctx = PrereqEvalContext {
+                world,
+                memory,
+                target: storylet.roles.first().map(|role| role.npc_id),
+            };
+            if !expression.evaluate(&ctx) {
+                fail!("expression");
+            }
         }
 
         // Digital legacy prereqs for PostLife storylets.
         if !check_digital_legacy_prereq(world, &storylet.prerequisites.digital_legacy_prereq) {
-            return false;
+            fail!("digital_legacy");
         }
 
-        true
+        failures
     }
 
     /// Score a storylet for selection (0.0..100.0).
@@ -1458,6 +1167,21 @@ impl EventDirector {
         // Apply narrative heat multiplier (0.5..2.0 based on current heat level)
         score *= world.heat_multiplier();
 
+        // Favor storylets that serve an active life goal
+        score *= life_goal_score_multiplier(world, storylet);
+
+        // Favor storylets about dependents that need care
+        score *= dependent_care_score_multiplier(world, storylet);
+
+        // Dynamic difficulty shifts how often heavy storylets come up
+        score *= difficulty_mix_score_multiplier(world, storylet);
+
+        // Favor storylets a PostLife nudge points a descendant toward
+        score *= post_life_nudge_score_multiplier(world, storylet);
+
+        // Favor storylets whose preferred predecessors have fired
+        score *= prefers_after_score_multiplier(world, storylet);
+
         // Behavior intent: prioritize storylets that match current player drive
         if let Some(action) = behavior_action_from_tags(&storylet.prerequisites.tags) {
             let intent = world.player_behavior_bias(action);
@@ -1467,7 +1191,8 @@ impl EventDirector {
         score.clamp(0.0, 100.0)
     }
 
-    /// Select the best eligible storylet(s) to fire this tick.
+    /// Select the eligible storylet to fire this tick: the best scored, or
+    /// one drawn per the director's [`SelectionMode`].
     pub fn select_next_event(
         &self,
         world: &WorldState,
@@ -1478,20 +1203,18 @@ impl EventDirector {
         if eligible.is_empty() {
             return None;
         }
+        // An overdue stat crisis preempts normal scoring
+        let eligible = preempt_for_overdue_crisis(world, eligible, |s| *s, current_tick);
 
         let hot_event_opt = world.relationship_pressure.peek_next_event();
-        let mut best_storylet: Option<&Storylet> = None;
-        let mut best_score = f32::MIN;
-
-        for storylet in eligible {
-            let score = score_storylet_full(self, world, storylet, hot_event_opt);
-            if score > best_score {
-                best_score = score;
-                best_storylet = Some(storylet);
-            }
-        }
-
-        best_storylet
+        let scored = eligible
+            .into_iter()
+            .map(|storylet| {
+                let ctx = ScoringContext::new(self, world, storylet, hot_event_opt);
+                (storylet, self.scoring_strategy.score(&ctx).score)
+            })
+            .collect();
+        self.pick_scored(world, scored, current_tick)
     }
 
     /// Fire a storylet: update world state with outcomes.
@@ -1503,6 +1226,53 @@ impl EventDirector {
         outcome: StoryletOutcome,
         current_tick: SimTick,
     ) {
+        self.fire_storylet_as(storylet, world, memory, outcome, None, current_tick);
+    }
+
+    /// Fire a storylet with the outcome of its choice `choice_id`.
+    ///
+    /// Returns false, firing nothing, if the storylet has no such choice or
+    /// the player lacks the skills it requires.
+    pub fn fire_storylet_choice(
+        &mut self,
+        storylet: &Storylet,
+        world: &mut WorldState,
+        memory: &mut MemorySystem,
+        choice_id: &str,
+        current_tick: SimTick,
+    ) -> bool {
+        let Some(choice) = storylet.outcomes.choices.iter().find(|c| c.id == choice_id) else {
+            return false;
+        };
+        if !choice.is_unlocked(world) {
+            return false;
+        }
+        let outcome = choice.outcome.clone();
+        self.fire_storylet_as(
+            storylet,
+            world,
+            memory,
+            outcome,
+            Some(choice_id.to_string()),
+            current_tick,
+        );
+        true
+    }
+
+    /// Fire a storylet, chronicling `choice_id` as the choice taken.
+    pub(crate) fn fire_storylet_as(
+        &mut self,
+        storylet: &Storylet,
+        world: &mut WorldState,
+        memory: &mut MemorySystem,
+        outcome: StoryletOutcome,
+        choice_id: Option<String>,
+        current_tick: SimTick,
+    ) {
+        // Fire with the same cast eligibility saw
+        let cast = self.cast_storylet_roles(storylet, world, memory, current_tick);
+        let storylet = cast.as_deref().unwrap_or(storylet);
+
         // If the selected storylet targets the current hot pair, consume that event.
         if let Some(event) = world.relationship_pressure.peek_next_event() {
             let default_actor_id = world.player_id.0;
@@ -1516,16 +1286,67 @@ impl EventDirector {
             }
         }
 
-        apply_storylet_outcome_with_memory(world, memory, storylet, &outcome, current_tick);
+        // An unreciprocated romance plays its first refusal beat instead.
+        let target = storylet.roles.first().map(|role| role.npc_id);
+        let (outcome, choice_id) = match romance_response_for(world, storylet, target) {
+            RomanceResponse::Unreciprocated => match storylet.outcomes.rejection_choices.first() {
+                Some(choice) => (choice.outcome.clone(), Some(choice.id.clone())),
+                None => (outcome, choice_id),
+            },
+            _ => (outcome, choice_id),
+        };
+        let outcome = {
+            let mut branches =
+                BranchContext::new(world, memory, target, &storylet.id, current_tick);
+            resolve_outcome_branches(&outcome, &mut branches)
+        };
+
+        self.chronicle.record(ChronicleEntry {
+            seq: 0,
+            storylet_id: storylet.id.clone(),
+            storylet_name: storylet.name.clone(),
+            tick: current_tick.0,
+            roles: storylet
+                .roles
+                .iter()
+                .map(|slot| (slot.name.clone(), slot.npc_id))
+                .collect(),
+            choice_id: choice_id.clone(),
+            outcome: ChronicleOutcome::from(&outcome),
+            heat: world.narrative_heat.value(),
+        });
+        syn_core::publish_world_event(
+            world,
+            syn_core::WorldEvent::StoryletFired {
+                storylet_id: storylet.id.clone(),
+                choice_id,
+            },
+        );
+
+        self.outcome_pipeline
+            .run(world, memory, storylet, &outcome, current_tick);
+        world.action_budget.spend(STORYLET_ACTION_COST);
+        consume_forced_trigger(world, storylet, current_tick);
         // Mark cooldown
         if let Some(first_role) = storylet.roles.first() {
             self.cooldowns.mark_cooldown(
-                &storylet.id,
+                StoryletHandle::new(&storylet.id),
                 first_role.npc_id,
                 storylet.cooldown.ticks,
                 current_tick,
             );
         }
+        world.storylet_usage.record_fire(&storylet.id);
+        world.storylet_usage.record_domains(&storylet_domains(storylet));
+        self.pacing_budget.record(storylet, current_tick);
+        world.ambient.note_storylet(current_tick.0);
+        report_if_newsworthy(
+            world,
+            &storylet.id,
+            &storylet.name,
+            &storylet.prerequisites.tags,
+            current_tick,
+        );
 
         if matches!(world.narrative_heat.band(), NarrativeHeatBand::Critical) {
             if let Some(cat) = &storylet.outcomes.heat_category {
@@ -1558,6 +1379,9 @@ impl EventDirector {
     /// 3. Weight and select based on heat, weight, personality bias
     /// 4. Fire the selected storylet(s) and update all world state
     ///
+    /// A due follow-up (see [`follow_ups`](crate::follow_ups)) takes the tick instead of
+    /// normal selection.
+    ///
     /// # Determinism
     /// All selection is deterministic. With the same world seed and state,
     /// the same storylet will be selected. RNG is seeded from (world_seed, tick, player_id).
@@ -1570,6 +1394,11 @@ impl EventDirector {
         memory: &mut MemorySystem,
         current_tick: SimTick,
     ) -> Option<String> {
+        // Scheduled chains come first
+        if let Some(fired) = self.fire_due_follow_up(library, world, memory, current_tick) {
+            return Some(fired);
+        }
+
         // Build eligibility context from current world state
         let eligibility_ctx = EligibilityContext {
             world,
@@ -1587,23 +1416,33 @@ impl EventDirector {
 
         // For each eligible key, get the full storylet and try role assignment
         let mut weighted_candidates: Vec<(syn_storylets::StoryletId, f32)> = Vec::new();
+        // Storylets that can only play as an NPC refusing their role
+        let mut refused: Vec<(syn_storylets::StoryletId, CastingRefusal)> = Vec::new();
 
         for key in &eligible_keys {
+            if self.compiled_outcomes.is_quarantined(*key) {
+                continue;
+            }
             // Get the compiled storylet from the library
             if let Some(compiled_storylet) = library.get_by_key(key.clone()) {
                 // Try to assign roles for this storylet
                 let role_engine = RoleAssignmentEngine::from_context(&eligibility_ctx);
-                match role_engine.assign_roles_for_storylet(compiled_storylet, None) {
-                    Some(_assignments) => {
+                match role_engine.cast_or_refuse(compiled_storylet, None) {
+                    CastingOutcome::Cast(assignments) => {
                         // Role assignment succeeded, compute weight
-                        let weight = self.compute_weighted_score(
-                            compiled_storylet,
-                            world,
-                            current_tick,
-                        );
+                        let weight =
+                            self.compute_weighted_score(compiled_storylet, world, current_tick)
+                                * cast_relationship_heat_multiplier(world, &assignments);
                         weighted_candidates.push((compiled_storylet.id.clone(), weight));
                     }
-                    None => {
+                    CastingOutcome::Refused(refusal) => {
+                        // The beat still happens, as a refusal
+                        let weight =
+                            self.compute_weighted_score(compiled_storylet, world, current_tick);
+                        weighted_candidates.push((compiled_storylet.id.clone(), weight));
+                        refused.push((compiled_storylet.id.clone(), refusal));
+                    }
+                    CastingOutcome::Unfilled => {
                         // Role assignment failed, skip this storylet
                         continue;
                     }
@@ -1634,13 +1473,19 @@ impl EventDirector {
                 }
             })?;
 
+        if let Some((_, refusal)) = refused.iter().find(|(id, _)| *id == selected_id) {
+            self.fire_refusal_event(selected, refusal, world, memory, current_tick);
+            return Some(selected_id.0.clone());
+        }
+
         // Assign roles again (we know this will succeed because we already did it above)
         let role_engine = RoleAssignmentEngine::from_context(&eligibility_ctx);
-        let _assignments = role_engine.assign_roles_for_storylet(selected, None)?;
+        let assignments = role_engine.assign_roles_for_storylet(selected, None)?;
 
         // Fire the storylet: apply all outcomes
         self.fire_compiled_storylet(
             selected,
+            &assignments,
             world,
             memory,
             current_tick,
@@ -1659,7 +1504,7 @@ impl EventDirector {
         &self,
         storylet: &CompiledStorylet,
         world: &WorldState,
-        _current_tick: SimTick,
+        current_tick: SimTick,
     ) -> f32 {
         let mut score = storylet.weight;
 
@@ -1672,6 +1517,50 @@ impl EventDirector {
         // Apply narrative heat multiplier (0.5..2.0 based on current heat level)
         score *= world.heat_multiplier();
 
+        // Session pacing: big beats early, critical ones not right before the end
+        score *= world.session_pacing.score_multiplier(
+            current_tick.0,
+            storylet.heat as f32 / 10.0,
+            false,
+        );
+
+        // Favor storylets that serve an active life goal
+        if world
+            .life_goals
+            .favors(storylet.tags.iter().map(|t| t.0.as_str()))
+        {
+            score *= LIFE_GOAL_SCORE_MULTIPLIER;
+        }
+
+        // Favor storylets about dependents that need care
+        if world
+            .dependents
+            .favors(storylet.tags.iter().map(|t| t.0.as_str()))
+        {
+            score *= DEPENDENT_CARE_SCORE_MULTIPLIER;
+        }
+
+        // Dynamic difficulty shifts how often heavy storylets come up
+        score *= world
+            .difficulty
+            .mix_multiplier(storylet.tags.iter().map(|t| t.0.as_str()));
+
+        // Favor storylets a PostLife nudge points a descendant toward
+        score *= world
+            .post_life
+            .nudge_multiplier(storylet.tags.iter().map(|t| t.0.as_str()));
+
+        // Favor storylets whose preferred predecessors have fired
+        if storylet
+            .prerequisites
+            .prefers_after
+            .iter()
+            .flatten()
+            .any(|id| world.storylet_usage.count(&id.0) > 0)
+        {
+            score *= PREFERS_AFTER_SCORE_MULTIPLIER;
+        }
+
         score.clamp(0.0, 100.0)
     }
 
@@ -1713,103 +1602,97 @@ impl EventDirector {
     /// Fire a compiled storylet: apply all outcomes to the world state.
     ///
     /// This updates:
-    /// - Player stats via outcomes
-    /// - Relationships via outcomes (simple case: player with single NPC)
+    /// - Player stats and karma via the pre-resolved outcome
+    /// - Relationships between the actors cast into the outcome's roles
     /// - Memory entries via the memory system
     /// - Narrative heat according to storylet heat
     /// - Cooldowns for this storylet
+    /// - The news feed, if the storylet is tagged newsworthy
+    /// - The follow-up queue, with the outcome's follow-ups
     fn fire_compiled_storylet(
         &mut self,
         storylet: &CompiledStorylet,
+        assignments: &RoleAssignments,
         world: &mut WorldState,
         memory: &mut MemorySystem,
         current_tick: SimTick,
     ) {
-        // Apply stat deltas from the outcome if present
-        if let Some(stat_deltas) = &storylet.outcomes.stat_deltas {
-            for stat_delta in stat_deltas {
-                match stat_delta.stat.as_str() {
-                    "mood" => {
-                        world.player_stats.mood = 
-                            (world.player_stats.mood + stat_delta.delta).clamp(-100.0, 100.0);
-                    }
-                    "health" => {
-                        world.player_stats.health = 
-                            (world.player_stats.health + stat_delta.delta).clamp(0.0, 100.0);
-                    }
-                    "intelligence" => {
-                        world.player_stats.intelligence = 
-                            (world.player_stats.intelligence + stat_delta.delta).clamp(0.0, 100.0);
-                    }
-                    "charisma" => {
-                        world.player_stats.charisma = 
-                            (world.player_stats.charisma + stat_delta.delta).clamp(0.0, 100.0);
-                    }
-                    "wealth" => {
-                        world.player_stats.wealth = 
-                            (world.player_stats.wealth + stat_delta.delta).clamp(0.0, 100.0);
-                    }
-                    "appearance" => {
-                        world.player_stats.appearance = 
-                            (world.player_stats.appearance + stat_delta.delta).clamp(0.0, 100.0);
-                    }
-                    "reputation" => {
-                        world.player_stats.reputation = 
-                            (world.player_stats.reputation + stat_delta.delta).clamp(-100.0, 100.0);
-                    }
-                    "wisdom" => {
-                        world.player_stats.wisdom = 
-                            (world.player_stats.wisdom + stat_delta.delta).clamp(0.0, 100.0);
-                    }
-                    "curiosity" => {
-                        if let Some(ref mut curiosity) = world.player_stats.curiosity {
-                            *curiosity = (*curiosity + stat_delta.delta).clamp(0.0, 100.0);
-                        }
-                    }
-                    "energy" => {
-                        if let Some(ref mut energy) = world.player_stats.energy {
-                            *energy = (*energy + stat_delta.delta).clamp(0.0, 100.0);
-                        }
-                    }
-                    "libido" => {
-                        if let Some(ref mut libido) = world.player_stats.libido {
-                            *libido = (*libido + stat_delta.delta).clamp(0.0, 100.0);
-                        }
-                    }
-                    _ => {} // Unknown stat, skip
-                }
+        // Outcomes are normally resolved at load time; resolve on the fly for
+        // libraries that were never passed to `load_compiled_outcomes`.
+        let outcome = match self.compiled_outcomes.get(storylet.key) {
+            Some(outcome) => outcome.clone(),
+            None => ResolvedOutcome::resolve(storylet).unwrap_or_default(),
+        };
+
+        let applied: Vec<_> = outcome
+            .stat_deltas
+            .iter()
+            .filter_map(|d| apply_outcome_stat(world, d.stat, d.delta))
+            .collect();
+        syn_core::record_outcome(world, applied);
+
+        for rel_delta in &outcome.relationship_deltas {
+            let from = outcome_actor(rel_delta.from, storylet, assignments, world);
+            let to = outcome_actor(rel_delta.to, storylet, assignments, world);
+            let (Some(from), Some(to)) = (from, to) else {
+                continue;
+            };
+            if from == to {
+                continue;
             }
+            let delta = syn_core::cap_relationship_delta(
+                world,
+                from.0,
+                to.0,
+                ModelRelationshipAxis::from(rel_delta.axis),
+                rel_delta.delta,
+            );
+            if syn_core::defer_npc_reaction(
+                world,
+                from.0,
+                to.0,
+                rel_delta.axis,
+                delta,
+                Some(format!("storylet:{}", storylet.id.0)),
+            ) {
+                continue;
+            }
+            let mut rel = world.get_relationship(from, to);
+            rel.apply_delta(rel_delta.axis, delta);
+            rel.state = rel.compute_next_state();
+            world.set_relationship(from, to, rel);
+            syn_core::record_relationship_interaction(world, from, to, delta.abs());
         }
 
-        // Apply relationship deltas from the outcome if present
-        if let Some(rel_deltas) = &storylet.outcomes.relationship_deltas {
-            for rel_delta in rel_deltas {
-                // Extract the target NPC ID from the role name (simple heuristic)
-                // In a real implementation, this would be looked up from role assignments
-                if let Some(npc_id) = parse_npc_id_from_role(&rel_delta.to_role) {
-                    let mut rel = world.get_relationship(world.player_id, NpcId(npc_id));
-                    
-                    // Apply the relationship delta to the appropriate axis
-                    match rel_delta.axis.as_str() {
-                        "affection" => rel.affection = (rel.affection + rel_delta.delta).clamp(-10.0, 10.0),
-                        "trust" => rel.trust = (rel.trust + rel_delta.delta).clamp(-10.0, 10.0),
-                        "attraction" => rel.attraction = (rel.attraction + rel_delta.delta).clamp(-10.0, 10.0),
-                        "familiarity" => rel.familiarity = (rel.familiarity + rel_delta.delta).clamp(-10.0, 10.0),
-                        "resentment" => rel.resentment = (rel.resentment + rel_delta.delta).clamp(-10.0, 10.0),
-                        _ => {} // Unknown axis, skip
-                    }
-
-                    // Recompute relationship state
-                    rel.state = rel.compute_next_state();
+        for mood_delta in &outcome.mood_deltas {
+            if let Some(actor) = outcome_actor(mood_delta.role, storylet, assignments, world) {
+                syn_core::shift_mood(world, actor, mood_delta.delta);
+            }
+        }
 
-                    world.set_relationship(world.player_id, NpcId(npc_id), rel);
-                }
+        // Apply flag operations (move-in flags update households)
+        if let Some(flag_ops) = &storylet.outcomes.flag_operations {
+            let source = syn_core::storylet_flag_source(&storylet.id.0);
+            for op in flag_ops {
+                apply_flag_operation(world, &op.flag, op.set, current_tick, &source);
             }
         }
 
+        // Relationship moves big enough to remember, keyed by the cast pair
+        let pair_tags = axis_tags::axis_memory_tags(
+            &self.outcome_pipeline.axis_tag_thresholds(),
+            outcome.relationship_deltas.iter().filter_map(|d| {
+                let from = outcome_actor(d.from, storylet, assignments, world)?;
+                let to = outcome_actor(d.to, storylet, assignments, world)?;
+                Some(((from.0, to.0), ModelRelationshipAxis::from(d.axis), d.delta))
+            }),
+        );
+
         // Record memory entries from the storylet if present
         if let Some(memory_entries) = &storylet.outcomes.memory_entries {
             for mem_entry in memory_entries {
+                let mut tags = mem_entry.tags.clone();
+                axis_tags::merge_tags(&mut tags, pair_tags.clone());
                 let entry = MemoryEntry::new(
                     format!("{}-{}", storylet.id.0, current_tick.0),
                     storylet.id.0.clone(),
@@ -1817,191 +1700,79 @@ impl EventDirector {
                     current_tick,
                     // Convert intensity (0..10) to emotional_intensity (-1.0..1.0)
                     (mem_entry.intensity as f32 / 10.0).clamp(-1.0, 1.0),
+                )
+                .with_tags(tags);
+
+                // TODO: Set stat_deltas, relationship_deltas, participants from mem_entry metadata
+                let intensity = entry.emotional_intensity;
+                let ctx = MemoryIntensityContext::for_npc(world, world.player_id);
+                memory.record_memory(entry, ctx.as_ref());
+                // Everyone cast into the storylet shares the memory with the player
+                syn_core::note_shared_memory(
+                    world,
+                    assignments.mapping.values().copied(),
+                    intensity,
                 );
-                
-                // TODO: Set stat_deltas, relationship_deltas, tags, participants from mem_entry metadata
-                memory.record_memory(entry);
             }
         }
 
         // Update narrative heat based on storylet heat
         world.narrative_heat.add(storylet.heat as f32);
+        world.action_budget.spend(STORYLET_ACTION_COST);
 
         // Mark cooldown for this storylet
         self.cooldowns.mark_cooldown(
-            &storylet.id.0,
+            StoryletHandle::new(&storylet.id.0),
             world.player_id,
             100, // Default 100-tick cooldown (TODO: make configurable via storylet.cooldowns)
             current_tick,
         );
-    }
-}
-
-fn apply_relationship_outcome(
-    rels: &mut HashMap<(u64, u64), RelationshipVector>,
-    deltas: &[RelationshipDelta],
-) {
-    for d in deltas {
-        let vec = rels
-            .entry((d.actor_id, d.target_id))
-            .or_insert_with(RelationshipVector::default);
-        vec.apply_delta(d.axis, d.delta);
-    }
-}
-
-/// Simple helper to extract an NPC ID from a role name.
-/// For now, this is a placeholder that tries to parse the role name as a number or returns None.
-/// In a real implementation, this would use the role assignments from the Event Director.
-fn parse_npc_id_from_role(role_name: &str) -> Option<u64> {
-    // Try to parse the role name directly as a number
-    role_name.parse().ok()
-}
-
-/// Update relationship pressure flags for pairs that had relationship changes.
-/// For now, this simply tracks which pairs had deltas; future refinement
-/// can track actual band crossings.
-fn update_relationship_pressure_flags(world: &mut WorldState, deltas: &[RelationshipDelta]) {
-    for delta in deltas {
-        let pair = (delta.actor_id, delta.target_id);
-        // Simple check: if this pair already exists, skip duplicate
-        if !world.relationship_pressure.changed_pairs.contains(&pair) {
-            world.relationship_pressure.changed_pairs.push(pair);
-        }
-    }
-}
-
-pub fn apply_storylet_outcome_with_memory(
-    world: &mut WorldState,
-    memory: &mut MemorySystem,
-    storylet: &Storylet,
-    outcome: &StoryletOutcome,
-    current_tick: SimTick,
-) {
-    // Apply stat impacts
-    apply_stat_deltas(&mut world.player_stats, &outcome.stat_deltas);
-
-    // New additive relationship delta handling using the unified model (non-breaking).
-    let mut rel_buffer: HashMap<(u64, u64), RelationshipVector> = HashMap::new();
-    for delta in &outcome.relationship_deltas {
-        rel_buffer
-            .entry((delta.actor_id, delta.target_id))
-            .or_insert_with(|| {
-                let current = world.get_relationship(NpcId(delta.actor_id), NpcId(delta.target_id));
-                RelationshipVector {
-                    affection: current.affection,
-                    trust: current.trust,
-                    attraction: current.attraction,
-                    familiarity: current.familiarity,
-                    resentment: current.resentment,
-                }
-            });
-    }
-    // Seed pressure snapshots before applying deltas so band changes are detectable immediately.
-    for ((actor_id, target_id), vec) in &rel_buffer {
-        world.relationship_pressure.update_for_pair(
-            *actor_id,
-            *target_id,
-            vec,
-            None,
-            Some(current_tick.0),
-        );
-    }
-
-    apply_relationship_outcome(&mut rel_buffer, &outcome.relationship_deltas);
-    for ((actor_id, target_id), vec) in rel_buffer {
-        let mut current = world.get_relationship(NpcId(actor_id), NpcId(target_id));
-        current.affection = vec.affection;
-        current.trust = vec.trust;
-        current.attraction = vec.attraction;
-        current.familiarity = vec.familiarity;
-        current.resentment = vec.resentment;
-        current.state = current.compute_next_state();
-        world.set_relationship(NpcId(actor_id), NpcId(target_id), current);
-
-        world.relationship_pressure.update_for_pair(
-            actor_id,
-            target_id,
-            &vec,
-            Some(format!("storylet:{}", storylet.id)),
-            Some(current_tick.0),
-        );
 
-        let tags = memory_tags_for_pair(memory, actor_id, target_id);
-        world
-            .relationship_milestones
-            .evaluate_and_record_milestones_for_pair(
-                actor_id,
-                target_id,
-                &vec,
-                &tags,
-                Some(format!("storylet:{}", storylet.id)),
-                Some(current_tick.0),
-            );
-    }
-
-    // Update karma (based on outcome emotional intensity)
-    world
-        .player_karma
-        .apply_delta(outcome.emotional_intensity * 10.0);
-    if let Some(k) = outcome.karma_delta {
-        world.player_karma.apply_delta(k);
-    }
-
-    // Global heat reactions: base storylet heat plus optional spikes/damps.
-    world.add_heat(storylet.heat as f32);
-    if outcome.heat_spike > 0.0 {
-        world.add_heat(outcome.heat_spike);
-    } else if outcome.heat_spike < 0.0 {
-        world.reduce_heat(outcome.heat_spike.abs());
-    }
-
-    if outcome
-        .memory_tags
-        .iter()
-        .any(|tag| tag.eq_ignore_ascii_case("trigger"))
-    {
-        world.add_heat(10.0);
+        world.storylet_usage.record_fire(&storylet.id.0);
+        world.storylet_usage.record_domains(&[storylet.domain.as_str()]);
+        world.ambient.note_storylet(current_tick.0);
+        let tags: Vec<String> = storylet.tags.iter().map(|t| t.0.clone()).collect();
+        report_if_newsworthy(world, &storylet.id.0, &storylet.name, &tags, current_tick);
+        self.follow_ups.schedule(storylet, current_tick);
     }
 
-    // Record memory for the player (UI will render via journal)
-    if !outcome.memory_event_id.is_empty() {
+    /// Play a storylet as a refusal micro-event: the NPC turns the player
+    /// down instead of taking their role.
+    ///
+    /// None of the storylet's outcomes apply. The player remembers the
+    /// rebuff (`refusal:<storylet>`, tagged `refusal` plus the reason and
+    /// role) and the storylet goes on cooldown without counting as fired.
+    fn fire_refusal_event(
+        &mut self,
+        storylet: &CompiledStorylet,
+        refusal: &CastingRefusal,
+        world: &mut WorldState,
+        memory: &mut MemorySystem,
+        current_tick: SimTick,
+    ) {
         let mut entry = MemoryEntry::new(
-            format!("mem_player_{}_{}", world.player_id.0, current_tick.0),
-            outcome.memory_event_id.clone(),
+            format!("refusal:{}-{}", storylet.id.0, current_tick.0),
+            format!("refusal:{}", storylet.id.0),
             world.player_id,
             current_tick,
-            outcome.emotional_intensity,
-        );
-
-        if !outcome.stat_deltas.is_empty() {
-            entry = entry.with_stat_deltas(outcome.stat_deltas.clone());
-        }
-
-        if !outcome.memory_tags.is_empty() {
-            entry = entry.with_tags(outcome.memory_tags.clone());
-        }
-
-        memory.record_memory(entry);
-    }
+            REFUSAL_MEMORY_INTENSITY,
+        )
+        .with_tags(vec![
+            "refusal".to_string(),
+            refusal.reason.as_str().to_string(),
+            refusal.role.clone(),
+        ]);
+        entry.participants = vec![world.player_id.0, refusal.actor_id.0];
+        let ctx = MemoryIntensityContext::for_npc(world, world.player_id);
+        memory.record_memory(entry, ctx.as_ref());
 
-    // Update relationship pressure flags for any pairs that had relationship changes
-    if !outcome.relationship_deltas.is_empty() {
-        update_relationship_pressure_flags(world, &outcome.relationship_deltas);
+        self.cooldowns.mark_cooldown(
+            StoryletHandle::new(&storylet.id.0),
+            world.player_id,
+            100, // Same default cooldown as a fired storylet
+            current_tick,
+        );
     }
-
-    // Decay the relationship pressure queue to prevent unbounded growth
-    // Max age: 168 ticks (7 days), Max queue size: 10 events
-    world
-        .relationship_pressure
-        .decay_queue(current_tick.0, 168, 10);
-}
-
-pub fn next_hot_relationship(world: &mut WorldState) -> Option<RelationshipPressureEvent> {
-    world.relationship_pressure.pop_next_event()
-}
-
-pub fn next_relationship_milestone(world: &mut WorldState) -> Option<RelationshipMilestoneEvent> {
-    world.relationship_milestones.pop_next()
 }
 
 impl Default for EventDirector {
@@ -2010,252 +1781,12 @@ impl Default for EventDirector {
     }
 }
 
-fn storylet_check_stat_prereqs(_world: &WorldState, _pre: &StoryletPrerequisites) -> bool {
-    true
-}
-
-fn storylet_check_heat_prereqs(_world: &WorldState, _pre: &StoryletPrerequisites) -> bool {
-    true
-}
-
-fn storylet_check_relationship_prereqs(world: &WorldState, pre: &StoryletPrerequisites) -> bool {
-    check_relationship_prereqs(world, &pre.relationship_prereqs, world.player_id)
-}
-
-fn storylet_check_time_and_location_prereqs(
-    world: &WorldState,
-    sim: &SimState,
-    storylet: &Storylet,
-) -> bool {
-    check_time_and_location_prereqs(world, &sim.npc_registry, storylet)
-}
-
-fn relationship_pressure_score_multiplier(
-    _world: &WorldState,
-    _sim: &SimState,
-    _storylet: &Storylet,
-) -> f32 {
-    1.0
-}
-
-#[derive(Debug, Clone, Serialize, Deserialize)]
-pub struct DirectorChoiceView {
-    pub id: String,
-    pub label: String,
-}
-
-#[derive(Debug, Clone, Serialize, Deserialize)]
-pub struct DirectorEventView {
-    pub storylet_id: String,
-    pub title: String,
-    pub choices: Vec<DirectorChoiceView>,
-}
-
-pub struct DirectorContext<'a> {
-    pub library: &'a StoryletLibrary,
-    pub world: &'a WorldState,
-    pub sim: &'a SimState,
-    pub usage: &'a StoryletUsageState,
-}
-
-pub fn storylet_is_eligible(
-    world: &WorldState,
-    sim: &SimState,
-    storylet: &Storylet,
-    usage: &StoryletUsageState,
-) -> bool {
-    let pre = &storylet.prerequisites;
-
-    if let Some(max) = storylet.outcomes.max_uses {
-        let used = usage.times_fired.get(&storylet.id).copied().unwrap_or(0);
-        if used >= max {
-            return false;
-        }
-    }
-
-    if !storylet_check_stat_prereqs(world, pre) {
-        return false;
-    }
-    if !check_life_stage_prereqs(world, pre) {
-        return false;
-    }
-    if !storylet_check_heat_prereqs(world, pre) {
-        return false;
-    }
-    if !storylet_check_relationship_prereqs(world, pre) {
-        return false;
-    }
-    if !storylet_check_time_and_location_prereqs(world, sim, storylet) {
-        return false;
-    }
-    if !check_digital_legacy_prereq(world, &pre.digital_legacy_prereq) {
-        return false;
-    }
-
-    true
-}
-
-pub fn score_storylet_full_simple(
-    world: &WorldState,
-    sim: &SimState,
-    storylet: &Storylet,
-) -> f32 {
-    let base = if storylet.weight > 0.0 {
-        storylet.weight
-    } else {
-        1.0
-    };
-
-    let heat_band = world.narrative_heat.band();
-    let heat_mult = heat_score_multiplier(heat_band, storylet);
-    let stage_mult = life_stage_score_multiplier(world, &storylet.prerequisites);
-    let legacy_mult =
-        digital_legacy_score_multiplier(world, &storylet.prerequisites.digital_legacy_prereq);
-    let npc_intent_mult = npc_intent_score_multiplier(world, &sim.npc_registry, storylet);
-    let pressure_mult = relationship_pressure_score_multiplier(world, sim, storylet);
-
-    base * heat_mult * stage_mult * legacy_mult * npc_intent_mult * pressure_mult
-}
-
-pub fn select_storylet_weighted<'a>(
-    world: &WorldState,
-    sim: &SimState,
-    library: &'a StoryletLibrary,
-    usage: &StoryletUsageState,
-) -> Option<&'a Storylet> {
-    let mut scored: Vec<(&Storylet, f32)> = library
-        .storylets
-        .iter()
-        .filter(|s| storylet_is_eligible(world, sim, s, usage))
-        .map(|s| {
-            let score = score_storylet_full_simple(world, sim, s).max(0.0);
-            (s, score)
-        })
-        .collect();
-
-    if scored.is_empty() {
-        return None;
-    }
-
-    let total: f32 = scored.iter().map(|(_, w)| *w).sum();
-    if total <= 0.0 {
-        scored.sort_by(|(a, _), (b, _)| a.id.cmp(&b.id));
-        return Some(scored[0].0);
-    }
-
-    let mut rng = deterministic_rng_from_world(world);
-    let roll = rng.gen_f32() * total;
-    let mut acc = 0.0;
-    for (s, w) in &scored {
-        acc += *w;
-        if roll <= acc {
-            return Some(s);
-        }
-    }
-
-    scored.last().map(|(s, _)| *s)
-}
-
-pub fn apply_storylet_outcome(
-    world: &mut WorldState,
-    _sim: &mut SimState,
-    outcome: &StoryletOutcome,
-) {
-    if !outcome.stat_deltas.is_empty() {
-        apply_stat_deltas(&mut world.player_stats, &outcome.stat_deltas);
-    }
-
-    if !outcome.relationship_deltas.is_empty() {
-        for delta in &outcome.relationship_deltas {
-            let actor = NpcId(delta.actor_id);
-            let target = NpcId(delta.target_id);
-            let mut rel = world.get_relationship(actor, target);
-            let axis = match delta.axis {
-                ModelRelationshipAxis::Affection => CoreRelationshipAxis::Affection,
-                ModelRelationshipAxis::Trust => CoreRelationshipAxis::Trust,
-                ModelRelationshipAxis::Attraction => CoreRelationshipAxis::Attraction,
-                ModelRelationshipAxis::Familiarity => CoreRelationshipAxis::Familiarity,
-                ModelRelationshipAxis::Resentment => CoreRelationshipAxis::Resentment,
-            };
-            rel.apply_delta(axis, delta.delta);
-            rel.state = rel.compute_next_state();
-            world.set_relationship(actor, target, rel);
-        }
-    }
-
-    if let Some(delta) = outcome.karma_delta {
-        world.player_karma.apply_delta(delta);
-    }
-}
-
-pub fn apply_storylet_choice_outcome(
-    world: &mut WorldState,
-    sim: &mut SimState,
-    storylet: &Storylet,
-    choice: &StoryletChoice,
-) {
-    apply_storylet_outcome(world, sim, &choice.outcome);
-
-    let usage = &mut world.storylet_usage;
-    let counter = usage.times_fired.entry(storylet.id.clone()).or_insert(0);
-    *counter += 1;
-}
-
-pub fn select_next_event_view(
-    world: &mut WorldState,
-    sim: &mut SimState,
-    library: &StoryletLibrary,
-) -> Option<DirectorEventView> {
-    let usage = &world.storylet_usage;
-    let storylet = select_storylet_weighted(world, sim, library, usage)?;
-
-    let choices = storylet
-        .outcomes
-        .choices
-        .iter()
-        .map(|c| DirectorChoiceView {
-            id: c.id.clone(),
-            label: c.label.clone(),
-        })
-        .collect();
-
-    Some(DirectorEventView {
-        storylet_id: storylet.id.clone(),
-        title: storylet.name.clone(),
-        choices,
-    })
-}
-
-pub fn apply_choice_and_advance(
-    world: &mut WorldState,
-    sim: &mut SimState,
-    library: &StoryletLibrary,
-    storylet_id: &str,
-    choice_id: &str,
-    ticks_to_advance: u32,
-) -> Option<DirectorEventView> {
-    let storylet = library.storylets.iter().find(|s| s.id == storylet_id)?;
-    let choice = storylet
-        .outcomes
-        .choices
-        .iter()
-        .find(|c| c.id == choice_id)?;
-
-    apply_storylet_choice_outcome(world, sim, storylet, choice);
-
-    if ticks_to_advance > 0 {
-        tick_world(world, sim, ticks_to_advance);
-    }
-
-    select_next_event_view(world, sim, library)
-}
-
 #[cfg(test)]
 mod tests {
     use super::*;
     use syn_core::{
-        relationship_model::RelationshipAxis as ModelRelationshipAxis, NpcId, Relationship,
-        StatDelta, StatKind, WorldSeed, WorldState,
+        relationship_model::{RelationshipAxis as ModelRelationshipAxis, RelationshipDelta},
+        NpcId, Relationship, RelationshipState, StatDelta, StatKind, WorldSeed, WorldState,
     };
 
     fn base_storylet(id: &str) -> Storylet {
@@ -2392,44 +1923,6 @@ mod tests {
         assert_eq!(world.player_stats.get(StatKind::Mood), initial_mood - 2.0);
     }
 
-    #[test]
-    fn apply_storylet_outcome_uses_stat_deltas_and_karma() {
-        let mut world = WorldState::new(WorldSeed(1), NpcId(1));
-        let mut memory = MemorySystem::new();
-        let mut storylet = base_storylet("outcome_test");
-        storylet.name = "Outcome Test".to_string();
-        let outcome = StoryletOutcome {
-            stat_deltas: vec![
-                StatDelta {
-                    kind: StatKind::Mood,
-                    delta: -5.0,
-                    source: Some("test".into()),
-                },
-                StatDelta {
-                    kind: StatKind::Reputation,
-                    delta: 10.0,
-                    source: Some("test".into()),
-                },
-            ],
-            karma_delta: Some(-20.0),
-            ..Default::default()
-        };
-
-        apply_storylet_outcome_with_memory(
-            &mut world,
-            &mut memory,
-            &storylet,
-            &outcome,
-            SimTick(0),
-        );
-
-        assert!(world.player_stats.get(StatKind::Mood) <= 10.0);
-        assert!(world.player_stats.get(StatKind::Mood) >= -10.0);
-        assert_eq!(world.player_stats.get(StatKind::Reputation), 10.0);
-        let karma_val = world.player_karma.0;
-        assert!(karma_val >= -100.0 && karma_val <= 100.0);
-    }
-
     #[test]
     fn test_heat_and_memory_spike() {
         let mut director = EventDirector::new();
@@ -2512,7 +2005,7 @@ mod tests {
         director.register_storylet(romance_storylet.clone());
 
         // Romance event should NOT fire with Stranger state
-        assert!(!director.is_eligible(&romance_storylet, &world, &memory, SimTick(0)));
+        assert!(!director.is_eligible(StoryletHandle::new(&romance_storylet.id), &romance_storylet, &world, &memory, SimTick(0)));
 
         // Now set to Friend state
         world.set_relationship(
@@ -2529,7 +2022,7 @@ mod tests {
         );
 
         // Romance event SHOULD fire with Friend state
-        assert!(director.is_eligible(&romance_storylet, &world, &memory, SimTick(0)));
+        assert!(director.is_eligible(StoryletHandle::new(&romance_storylet.id), &romance_storylet, &world, &memory, SimTick(0)));
     }
 
     #[test]
@@ -2692,7 +2185,7 @@ mod tests {
 
         // Event should NOT fire without required memory
         let mut memory = MemorySystem::new();
-        assert!(!director.is_eligible(&echo_storylet, &world, &memory, SimTick(100)));
+        assert!(!director.is_eligible(StoryletHandle::new(&echo_storylet.id), &echo_storylet, &world, &memory, SimTick(100)));
 
         // Add a "betrayal" memory entry to NPC
         let memory_entry = MemoryEntry::new(
@@ -2704,10 +2197,10 @@ mod tests {
         )
         .with_tags(vec!["betrayal"]);
 
-        memory.record_memory(memory_entry);
+        memory.record_memory(memory_entry, None);
 
         // Event SHOULD fire now with required memory present
-        assert!(director.is_eligible(&echo_storylet, &world, &memory, SimTick(100)));
+        assert!(director.is_eligible(StoryletHandle::new(&echo_storylet.id), &echo_storylet, &world, &memory, SimTick(100)));
     }
 
     #[test]
@@ -2752,7 +2245,7 @@ mod tests {
         let mut memory = MemorySystem::new();
 
         // Event SHOULD fire without forbidden trauma memories
-        assert!(director.is_eligible(&fragile_storylet, &world, &memory, SimTick(100)));
+        assert!(director.is_eligible(StoryletHandle::new(&fragile_storylet.id), &fragile_storylet, &world, &memory, SimTick(100)));
 
         // Add a traumatic memory
         let trauma_entry = MemoryEntry::new(
@@ -2764,10 +2257,10 @@ mod tests {
         )
         .with_tags(vec!["trauma"]);
 
-        memory.record_memory(trauma_entry);
+        memory.record_memory(trauma_entry, None);
 
         // Event should NOT fire now with traumatic memory present (conflict avoidance)
-        assert!(!director.is_eligible(&fragile_storylet, &world, &memory, SimTick(100)));
+        assert!(!director.is_eligible(StoryletHandle::new(&fragile_storylet.id), &fragile_storylet, &world, &memory, SimTick(100)));
     }
 
     #[test]
@@ -2813,7 +2306,7 @@ mod tests {
         let mut memory = MemorySystem::new();
 
         // Event should NOT fire without recent confrontation memory
-        assert!(!director.is_eligible(&follow_up_storylet, &world, &memory, SimTick(100)));
+        assert!(!director.is_eligible(StoryletHandle::new(&follow_up_storylet.id), &follow_up_storylet, &world, &memory, SimTick(100)));
 
         // Add an OLD confrontation memory (150 ticks ago, outside recency window)
         let old_confrontation = MemoryEntry::new(
@@ -2825,10 +2318,10 @@ mod tests {
         )
         .with_tags(vec!["confrontation"]);
 
-        memory.record_memory(old_confrontation);
+        memory.record_memory(old_confrontation, None);
 
         // Event should NOT fire (memory outside recency window)
-        assert!(!director.is_eligible(&follow_up_storylet, &world, &memory, SimTick(100)));
+        assert!(!director.is_eligible(StoryletHandle::new(&follow_up_storylet.id), &follow_up_storylet, &world, &memory, SimTick(100)));
 
         // Add a RECENT confrontation memory (within last 50 ticks)
         let recent_confrontation = MemoryEntry::new(
@@ -2840,10 +2333,10 @@ mod tests {
         )
         .with_tags(vec!["confrontation"]);
 
-        memory.record_memory(recent_confrontation);
+        memory.record_memory(recent_confrontation, None);
 
         // Event SHOULD fire now (recent memory within window)
-        assert!(director.is_eligible(&follow_up_storylet, &world, &memory, SimTick(100)));
+        assert!(director.is_eligible(StoryletHandle::new(&follow_up_storylet.id), &follow_up_storylet, &world, &memory, SimTick(100)));
     }
 
     #[test]
@@ -2888,7 +2381,7 @@ mod tests {
         let mut memory = MemorySystem::new();
 
         // Event should NOT fire without either tag
-        assert!(!director.is_eligible(&complex_storylet, &world, &memory, SimTick(100)));
+        assert!(!director.is_eligible(StoryletHandle::new(&complex_storylet.id), &complex_storylet, &world, &memory, SimTick(100)));
 
         // Add a "jealousy" memory (first required tag)
         let jealousy_memory = MemoryEntry::new(
@@ -2900,10 +2393,10 @@ mod tests {
         )
         .with_tags(vec!["jealousy"]);
 
-        memory.record_memory(jealousy_memory);
+        memory.record_memory(jealousy_memory, None);
 
         // Event SHOULD fire now (has one of the required tags)
-        assert!(director.is_eligible(&complex_storylet, &world, &memory, SimTick(100)));
+        assert!(director.is_eligible(StoryletHandle::new(&complex_storylet.id), &complex_storylet, &world, &memory, SimTick(100)));
 
         // Add a "love_confession" memory (second required tag)
         let confession_memory = MemoryEntry::new(
@@ -2915,9 +2408,9 @@ mod tests {
         )
         .with_tags(vec!["love_confession"]);
 
-        memory.record_memory(confession_memory);
+        memory.record_memory(confession_memory, None);
 
         // Event SHOULD STILL fire (now has both)
-        assert!(director.is_eligible(&complex_storylet, &world, &memory, SimTick(100)));
+        assert!(director.is_eligible(StoryletHandle::new(&complex_storylet.id), &complex_storylet, &world, &memory, SimTick(100)));
     }
 }
@@ -0,0 +1,55 @@
+//! Hot-reloading the legacy director's storylets.
+//!
+//! [`EventDirector::replace_storylets`] swaps a freshly loaded library in
+//! without restarting the engine, so authors can tweak content and see it
+//! on the next tick. Loading is the caller's business: the engine re-reads
+//! the same database and content packs it started from.
+//!
+//! Cooldowns of storylets still in the library carry over; those of removed
+//! storylets are dropped. Usage counts live on the world and are untouched.
+
+use crate::{EventDirector, Storylet};
+
+/// What a reload changed. IDs are sorted.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct LibraryReload {
+    /// Storylets new to the library.
+    pub added: Vec<String>,
+    /// Storylets no longer in the library.
+    pub removed: Vec<String>,
+    /// Storylets in both, whose cooldowns were kept.
+    pub kept: Vec<String>,
+}
+
+impl EventDirector {
+    /// Swap in `storylets` for the registered ones, keeping the cooldowns of
+    /// IDs in both. Missing-role diagnostics are cleared so fixed content
+    /// stops being reported.
+    pub fn replace_storylets(&mut self, storylets: Vec<Storylet>) -> LibraryReload {
+        let mut old: Vec<String> = self.storylets.iter().map(|s| s.id.clone()).collect();
+        let mut new: Vec<String> = storylets.iter().map(|s| s.id.clone()).collect();
+        old.sort();
+        old.dedup();
+        new.sort();
+        new.dedup();
+
+        let report = LibraryReload {
+            added: new.iter().filter(|id| old.binary_search(id).is_err()).cloned().collect(),
+            removed: old.iter().filter(|id| new.binary_search(id).is_err()).cloned().collect(),
+            kept: new.iter().filter(|id| old.binary_search(id).is_ok()).cloned().collect(),
+        };
+
+        self.storylets.clear();
+        self.storylet_handles.clear();
+        self.storylet_index.clear();
+        for storylet in storylets {
+            self.register_storylet(storylet);
+        }
+        let index = &self.storylet_index;
+        self.cooldowns.retain_storylets(|handle| index.contains_key(&handle));
+        if let Ok(mut diagnostics) = self.content_diagnostics.lock() {
+            diagnostics.clear();
+        }
+        report
+    }
+}
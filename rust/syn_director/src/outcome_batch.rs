@@ -0,0 +1,197 @@
+//! Scripted outcome batches.
+//!
+//! Tests and cutscene-like sequences apply several outcomes back to back
+//! without going through selection. [`EventDirector::apply_outcome_batch`]
+//! validates the whole batch before touching the world, so a bad entry
+//! leaves everything unchanged. Each entry then applies like a fired
+//! storylet (stats, karma, heat, memory, flags, cooldown), but relationship
+//! changes are buffered across the batch and evaluated for pressure and
+//! milestones once at the end, so a sequence that crosses a band and comes
+//! back does not leave a spurious event behind.
+//!
+//! Batches skip the action budget, forced-trigger consumption and romance
+//! refusal substitution: the script decides what happens.
+
+use std::fmt;
+
+use syn_core::relationship_model::RelationshipVector;
+use syn_core::{OrderedMap, SimTick, WorldState};
+use syn_memory::MemorySystem;
+
+use crate::outcomes::{
+    apply_outcome_side_effects, apply_reaction_intercepts, buffer_relationship_deltas,
+    commit_relationship_buffer, grant_skill_xp, update_relationship_pressure_flags,
+    StoryletOutcome,
+};
+use crate::EventDirector;
+
+/// Why a batch was rejected. Rejected batches change nothing.
+#[derive(Debug, Clone, PartialEq)]
+pub enum OutcomeBatchError {
+    /// Entry `index` names a storylet the director does not know.
+    UnknownStorylet { index: usize, storylet_id: String },
+    /// Entry `index` carries a NaN or infinite value in `field`.
+    NonFiniteValue { index: usize, field: &'static str },
+}
+
+impl fmt::Display for OutcomeBatchError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::UnknownStorylet { index, storylet_id } => {
+                write!(
+                    f,
+                    "batch entry {}: unknown storylet '{}'",
+                    index, storylet_id
+                )
+            }
+            Self::NonFiniteValue { index, field } => {
+                write!(f, "batch entry {}: non-finite value in {}", index, field)
+            }
+        }
+    }
+}
+
+impl std::error::Error for OutcomeBatchError {}
+
+/// Summary of an applied batch.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct OutcomeBatchReport {
+    /// Entries applied, in order.
+    pub applied: usize,
+    /// Relationship pairs changed by the batch, in pair order.
+    pub pairs_changed: Vec<(u64, u64)>,
+    /// Pressure events queued by the combined evaluation pass.
+    pub pressure_events: usize,
+    /// Milestone events queued by the combined evaluation pass.
+    pub milestone_events: usize,
+}
+
+fn first_non_finite(outcome: &StoryletOutcome) -> Option<&'static str> {
+    if outcome.stat_deltas.iter().any(|d| !d.delta.is_finite()) {
+        return Some("stat_deltas");
+    }
+    if outcome
+        .relationship_deltas
+        .iter()
+        .any(|d| !d.delta.is_finite())
+    {
+        return Some("relationship_deltas");
+    }
+    if outcome.karma_delta.is_some_and(|k| !k.is_finite()) {
+        return Some("karma_delta");
+    }
+    if !outcome.emotional_intensity.is_finite() {
+        return Some("emotional_intensity");
+    }
+    if !outcome.heat_spike.is_finite() {
+        return Some("heat_spike");
+    }
+    if outcome
+        .reaction_intercepts
+        .iter()
+        .any(|i| !i.fraction.is_finite())
+    {
+        return Some("reaction_intercepts");
+    }
+    None
+}
+
+impl EventDirector {
+    /// Apply `(storylet_id, outcome)` pairs in order as one transaction.
+    ///
+    /// Every entry is validated first; on error nothing is applied. Cooldowns
+    /// are marked for each storylet as if it had fired at `current_tick`.
+    pub fn apply_outcome_batch(
+        &mut self,
+        world: &mut WorldState,
+        memory: &mut MemorySystem,
+        batch: Vec<(String, StoryletOutcome)>,
+        current_tick: SimTick,
+    ) -> Result<OutcomeBatchReport, OutcomeBatchError> {
+        let mut resolved = Vec::with_capacity(batch.len());
+        for (index, (storylet_id, outcome)) in batch.into_iter().enumerate() {
+            let Some((handle, storylet)) = self.registered_storylet(&storylet_id) else {
+                return Err(OutcomeBatchError::UnknownStorylet { index, storylet_id });
+            };
+            if let Some(field) = first_non_finite(&outcome) {
+                return Err(OutcomeBatchError::NonFiniteValue { index, field });
+            }
+            resolved.push((handle, storylet.clone(), outcome));
+        }
+
+        let pressure_before = world.relationship_pressure.queue.len();
+        let milestones_before = world.relationship_milestones.queue.len();
+
+        let mut rel_buffer: OrderedMap<(u64, u64), RelationshipVector> = OrderedMap::new();
+        for (handle, storylet, outcome) in &resolved {
+            syn_core::apply_outcome_stat_deltas(world, &outcome.stat_deltas);
+            grant_skill_xp(world, &outcome.skill_xp_rewards, current_tick);
+            apply_reaction_intercepts(world, outcome);
+            buffer_relationship_deltas(
+                world,
+                &mut rel_buffer,
+                &outcome.relationship_deltas,
+                current_tick,
+            );
+            apply_outcome_side_effects(
+                world,
+                memory,
+                storylet,
+                outcome,
+                current_tick,
+                self.outcome_pipeline.axis_tag_thresholds(),
+            );
+            let source = syn_core::storylet_flag_source(&storylet.id);
+            syn_core::apply_relationship_declarations(
+                world,
+                &outcome.relationship_declarations,
+                &source,
+            );
+            if let Some(first_role) = storylet.roles.first() {
+                self.cooldowns.mark_cooldown(
+                    *handle,
+                    first_role.npc_id,
+                    storylet.cooldown.ticks,
+                    current_tick,
+                );
+            }
+        }
+
+        // One combined pressure/milestone pass over the net changes.
+        let pairs_changed: Vec<(u64, u64)> = rel_buffer.keys().copied().collect();
+        let ids: Vec<&str> = resolved.iter().map(|(_, s, _)| s.id.as_str()).collect();
+        commit_relationship_buffer(
+            world,
+            memory,
+            rel_buffer,
+            &format!("batch:{}", ids.join(",")),
+            current_tick,
+        );
+        let all_deltas: Vec<_> = resolved
+            .iter()
+            .flat_map(|(_, _, outcome)| outcome.relationship_deltas.iter().cloned())
+            .collect();
+        if !all_deltas.is_empty() {
+            update_relationship_pressure_flags(world, &all_deltas);
+        }
+        world
+            .relationship_pressure
+            .decay_queue(current_tick.0, 168, 10);
+        world.ambient.note_storylet(current_tick.0);
+
+        Ok(OutcomeBatchReport {
+            applied: resolved.len(),
+            pairs_changed,
+            pressure_events: world
+                .relationship_pressure
+                .queue
+                .len()
+                .saturating_sub(pressure_before),
+            milestone_events: world
+                .relationship_milestones
+                .queue
+                .len()
+                .saturating_sub(milestones_before),
+        })
+    }
+}
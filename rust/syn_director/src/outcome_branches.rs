@@ -0,0 +1,172 @@
+//! Conditional branches inside a storylet outcome.
+//!
+//! A [`StoryletOutcome`] may carry `branches`. Each one picks between a
+//! `then` and an optional `else` outcome when the storylet fires, so one
+//! choice can succeed or fail without authoring a second storylet:
+//!
+//! ```json
+//! "outcome": {
+//!     "stat_impacts": [{ "kind": "Energy", "delta": -5 }],
+//!     "branches": [
+//!         { "if": "trust >= Trusted", "then": { "karma_delta": 2 } },
+//!         {
+//!             "check": { "stat": "Charisma", "modifier": -10 },
+//!             "then": { "memory_tags": ["won_them_over"] },
+//!             "else": { "stat_impacts": [{ "kind": "Mood", "delta": -2 }] }
+//!         }
+//!     ]
+//! }
+//! ```
+//!
+//! - `if` is a [prerequisite expression](crate::prereq_expr) with the
+//!   storylet's first role as its target.
+//! - `check` rolls 0-100 under the player's stat (scaled to 0-100) plus
+//!   `modifier`.
+//!
+//! A branch with both takes `then` only when both pass, and one with
+//! neither always takes it. Rolls come from a stream keyed by the world
+//! seed, the tick and the storylet id, so firing the same storylet at the
+//! same tick always lands on the same branches. [`resolve_outcome_branches`]
+//! folds the picked outcomes (and their own branches, depth first) into the
+//! base one: lists are appended, karma and heat add up, and a branch's
+//! memory event, emotional intensity and next storylet replace the base's
+//! when it sets them.
+
+use serde::{Deserialize, Serialize};
+use syn_core::{DeterministicRng, NpcId, SimTick, StatKind, WorldState};
+use syn_memory::MemorySystem;
+
+use crate::prereq_expr::{PrereqEvalContext, PrereqExpr};
+use crate::StoryletOutcome;
+
+/// A roll under one of the player's stats.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct SkillCheck {
+    pub stat: StatKind,
+    /// Added to the stat (on its 0-100 scale) before rolling; negative makes
+    /// the check harder.
+    #[serde(default)]
+    pub modifier: f32,
+}
+
+impl SkillCheck {
+    /// Chance out of 100 that the check passes for `world`'s player.
+    pub fn chance(&self, world: &WorldState) -> f32 {
+        let value = world.player_stats.get(self.stat);
+        let scaled = match self.stat {
+            StatKind::Mood => (value + 10.0) * 5.0,
+            StatKind::Reputation => (value + 100.0) / 2.0,
+            _ => value,
+        };
+        (scaled + self.modifier).clamp(0.0, 100.0)
+    }
+}
+
+/// One conditional branch of an outcome.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct OutcomeBranch {
+    #[serde(default, rename = "if")]
+    pub condition: Option<PrereqExpr>,
+    #[serde(default)]
+    pub check: Option<SkillCheck>,
+    #[serde(default)]
+    pub then: StoryletOutcome,
+    #[serde(default, rename = "else")]
+    pub otherwise: Option<StoryletOutcome>,
+}
+
+/// What a storylet's branches are evaluated against when it fires.
+pub struct BranchContext<'a> {
+    pub world: &'a WorldState,
+    pub memory: &'a MemorySystem,
+    /// The storylet's target (first role), if cast.
+    pub target: Option<NpcId>,
+    rng: DeterministicRng,
+}
+
+impl<'a> BranchContext<'a> {
+    pub fn new(
+        world: &'a WorldState,
+        memory: &'a MemorySystem,
+        target: Option<NpcId>,
+        storylet_id: &str,
+        tick: SimTick,
+    ) -> Self {
+        BranchContext {
+            world,
+            memory,
+            target,
+            rng: DeterministicRng::with_domain(
+                world.seed.0,
+                tick.0,
+                &format!("outcome_branch:{}", storylet_id),
+            ),
+        }
+    }
+
+    /// Whether `branch` takes its `then` outcome. Rolls only for a check
+    /// whose condition held.
+    fn passes(&mut self, branch: &OutcomeBranch) -> bool {
+        let ctx = PrereqEvalContext {
+            world: self.world,
+            memory: self.memory,
+            target: self.target,
+        };
+        if let Some(condition) = &branch.condition {
+            if !condition.evaluate(&ctx) {
+                return false;
+            }
+        }
+        match &branch.check {
+            Some(check) => self.rng.gen_range_f32(0.0, 100.0) < check.chance(self.world),
+            None => true,
+        }
+    }
+}
+
+/// `outcome` with every branch settled: a flat outcome with no branches.
+pub fn resolve_outcome_branches(
+    outcome: &StoryletOutcome,
+    ctx: &mut BranchContext<'_>,
+) -> StoryletOutcome {
+    let mut resolved = StoryletOutcome {
+        branches: Vec::new(),
+        ..outcome.clone()
+    };
+    for branch in &outcome.branches {
+        let picked = if ctx.passes(branch) {
+            Some(&branch.then)
+        } else {
+            branch.otherwise.as_ref()
+        };
+        if let Some(picked) = picked {
+            merge_outcome(&mut resolved, resolve_outcome_branches(picked, ctx));
+        }
+    }
+    resolved
+}
+
+fn merge_outcome(into: &mut StoryletOutcome, from: StoryletOutcome) {
+    into.stat_deltas.extend(from.stat_deltas);
+    into.relationship_deltas.extend(from.relationship_deltas);
+    into.karma_delta = match (into.karma_delta, from.karma_delta) {
+        (Some(a), Some(b)) => Some(a + b),
+        (a, b) => a.or(b),
+    };
+    into.reputation_deltas.extend(from.reputation_deltas);
+    if !from.memory_event_id.is_empty() && from.memory_event_id != "unknown" {
+        into.memory_event_id = from.memory_event_id;
+    }
+    if from.emotional_intensity.abs() > f32::EPSILON {
+        into.emotional_intensity = from.emotional_intensity;
+    }
+    into.memory_tags.extend(from.memory_tags);
+    into.heat_spike += from.heat_spike;
+    if from.next_storylet.is_some() {
+        into.next_storylet = from.next_storylet;
+    }
+    into.reaction_intercepts.extend(from.reaction_intercepts);
+    into.relationship_declarations.extend(from.relationship_declarations);
+    into.skill_xp_rewards.extend(from.skill_xp_rewards);
+    into.flags.extend(from.flags);
+}
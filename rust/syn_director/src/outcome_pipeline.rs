@@ -0,0 +1,434 @@
+//! Staged application of storylet outcomes.
+//!
+//! Applying an outcome touches several systems: player stats, relationships
+//! and their pressure/milestone tracking, karma and reputation, narrative
+//! heat, and the player's memory journal. Each of those is an
+//! [`OutcomeStage`], and an [`OutcomePipeline`] runs them in a fixed order:
+//!
+//! 1. [`StatStage`]: player stat deltas and skill XP
+//! 2. [`RelationshipStage`]: reaction intercepts, relationship deltas (NPC-side
+//!    ones delayed when reaction latency is on), pressure snapshots,
+//!    milestones, declared relationship statuses
+//! 3. [`ObserverStage`]: bystanders' opinions of how the cast treated each other
+//! 4. [`KarmaStage`]: karma and district reputation
+//! 5. [`DistrictStage`]: the storylet's economy/crime shocks and district flags
+//! 6. [`HeatStage`]: storylet heat, authored spikes/damps, trigger warnings,
+//!    heat band changes on the event bus
+//! 7. [`MemoryStage`]: the player's journal entry, shared with the NPCs involved
+//!    and tagged for sharp axis moves (see [`crate::axis_tags`])
+//! 8. [`PressureStage`]: pressure flags for changed pairs and queue decay
+//! 9. [`GossipStage`]: a rumor about the player for gossip-worthy storylets
+//! 10. [`FlagStage`]: world flags the storylet and outcome set or clear
+//!
+//! Systems that react to outcomes (gossip, goals, ...) register their own
+//! stage with [`OutcomePipeline::register`] or
+//! [`EventDirector::register_outcome_stage`](crate::EventDirector::register_outcome_stage)
+//! instead of editing the core application path. Registered stages run
+//! after the standard ones, in registration order.
+//!
+//! # Example
+//!
+//! ```ignore
+//! let mut pipeline = OutcomePipeline::standard();
+//! pipeline.register(Box::new(MyGossipStage));
+//! pipeline.run(&mut world, &mut memory, &storylet, &outcome, tick);
+//! ```
+
+use std::fmt;
+
+use syn_core::relationship_model::RelationshipVector;
+use syn_core::{NpcId, OrderedMap, SimTick, WorldState};
+use syn_memory::{MemoryEntry, MemoryIntensityContext, MemorySystem};
+
+use crate::axis_tags::{axis_memory_tags, merge_tags, AxisTagThresholds};
+use crate::outcomes::{
+    apply_reaction_intercepts, buffer_relationship_deltas, commit_relationship_buffer,
+    apply_outcome_flags, grant_skill_xp, update_relationship_pressure_flags, StoryletOutcome,
+};
+use crate::Storylet;
+
+/// Max age, in ticks, of queued relationship pressure events (7 days).
+const PRESSURE_QUEUE_MAX_AGE: u64 = 168;
+/// Max number of queued relationship pressure events.
+const PRESSURE_QUEUE_MAX_LEN: usize = 10;
+
+/// The outcome being applied, shared by every stage.
+#[derive(Debug, Clone, Copy)]
+pub struct OutcomeContext<'a> {
+    pub storylet: &'a Storylet,
+    pub outcome: &'a StoryletOutcome,
+    pub current_tick: SimTick,
+    /// Axis moves that earn an automatic memory tag.
+    pub axis_tag_thresholds: AxisTagThresholds,
+}
+
+impl<'a> OutcomeContext<'a> {
+    pub fn new(
+        storylet: &'a Storylet,
+        outcome: &'a StoryletOutcome,
+        current_tick: SimTick,
+    ) -> Self {
+        Self {
+            storylet,
+            outcome,
+            current_tick,
+            axis_tag_thresholds: AxisTagThresholds::default(),
+        }
+    }
+
+    /// Use `thresholds` for automatic axis memory tags.
+    pub fn with_axis_tag_thresholds(mut self, thresholds: AxisTagThresholds) -> Self {
+        self.axis_tag_thresholds = thresholds;
+        self
+    }
+}
+
+/// One step of outcome application.
+pub trait OutcomeStage: Send + Sync {
+    /// Stable name, used to inspect pipeline order.
+    fn name(&self) -> &'static str;
+
+    /// Apply this stage's share of the outcome.
+    fn apply(&self, world: &mut WorldState, memory: &mut MemorySystem, ctx: &OutcomeContext<'_>);
+}
+
+/// Applies the outcome's player stat deltas, scaled by difficulty, and its
+/// skill XP rewards.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct StatStage;
+
+impl OutcomeStage for StatStage {
+    fn name(&self) -> &'static str {
+        "stats"
+    }
+
+    fn apply(&self, world: &mut WorldState, _memory: &mut MemorySystem, ctx: &OutcomeContext<'_>) {
+        syn_core::apply_outcome_stat_deltas(world, &ctx.outcome.stat_deltas);
+        grant_skill_xp(world, &ctx.outcome.skill_xp_rewards, ctx.current_tick);
+    }
+}
+
+/// Applies relationship deltas and evaluates pressure and milestones once
+/// per changed pair, attributed to `storylet:<id>`.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct RelationshipStage;
+
+impl OutcomeStage for RelationshipStage {
+    fn name(&self) -> &'static str {
+        "relationships"
+    }
+
+    fn apply(&self, world: &mut WorldState, memory: &mut MemorySystem, ctx: &OutcomeContext<'_>) {
+        apply_reaction_intercepts(world, ctx.outcome);
+        let mut rel_buffer: OrderedMap<(u64, u64), RelationshipVector> = OrderedMap::new();
+        buffer_relationship_deltas(
+            world,
+            &mut rel_buffer,
+            &ctx.outcome.relationship_deltas,
+            ctx.current_tick,
+        );
+        commit_relationship_buffer(
+            world,
+            memory,
+            rel_buffer,
+            &format!("storylet:{}", ctx.storylet.id),
+            ctx.current_tick,
+        );
+        syn_core::apply_relationship_declarations(
+            world,
+            &ctx.outcome.relationship_declarations,
+            &syn_core::storylet_flag_source(&ctx.storylet.id),
+        );
+    }
+}
+
+/// Lets witnesses and cluster-mates of the cast adjust their own views of
+/// the participants (see [`syn_core::opinions`]).
+#[derive(Debug, Clone, Copy, Default)]
+pub struct ObserverStage;
+
+impl OutcomeStage for ObserverStage {
+    fn name(&self) -> &'static str {
+        "observers"
+    }
+
+    fn apply(&self, world: &mut WorldState, _memory: &mut MemorySystem, ctx: &OutcomeContext<'_>) {
+        syn_core::observe_relationship_deltas(
+            world,
+            &ctx.outcome.relationship_deltas,
+            &syn_core::storylet_flag_source(&ctx.storylet.id),
+        );
+    }
+}
+
+/// Applies karma (emotional intensity plus authored delta) and district
+/// reputation deltas.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct KarmaStage;
+
+impl OutcomeStage for KarmaStage {
+    fn name(&self) -> &'static str {
+        "karma"
+    }
+
+    fn apply(&self, world: &mut WorldState, _memory: &mut MemorySystem, ctx: &OutcomeContext<'_>) {
+        let outcome = ctx.outcome;
+        syn_core::apply_outcome_karma(world, outcome.emotional_intensity, outcome.karma_delta);
+        syn_core::apply_reputation_deltas(world, &outcome.reputation_deltas);
+    }
+}
+
+/// Applies the storylet's district effects (see [`syn_core::district_effects`]),
+/// attributing district flags to `storylet:<id>`.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct DistrictStage;
+
+impl OutcomeStage for DistrictStage {
+    fn name(&self) -> &'static str {
+        "districts"
+    }
+
+    fn apply(&self, world: &mut WorldState, _memory: &mut MemorySystem, ctx: &OutcomeContext<'_>) {
+        syn_core::apply_district_effects(
+            world,
+            &ctx.storylet.outcomes.district_effects,
+            &syn_core::storylet_flag_source(&ctx.storylet.id),
+        );
+    }
+}
+
+/// Global heat reactions: base storylet heat plus optional spikes/damps,
+/// and a jolt for outcomes tagged `trigger`. A band change goes out on the
+/// world's event bus right away.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct HeatStage;
+
+impl OutcomeStage for HeatStage {
+    fn name(&self) -> &'static str {
+        "heat"
+    }
+
+    fn apply(&self, world: &mut WorldState, _memory: &mut MemorySystem, ctx: &OutcomeContext<'_>) {
+        let outcome = ctx.outcome;
+        world.add_heat(ctx.storylet.heat as f32);
+        if outcome.heat_spike > 0.0 {
+            world.add_heat(outcome.heat_spike);
+        } else if outcome.heat_spike < 0.0 {
+            world.reduce_heat(outcome.heat_spike.abs());
+        }
+
+        if outcome
+            .memory_tags
+            .iter()
+            .any(|tag| tag.eq_ignore_ascii_case("trigger"))
+        {
+            world.add_heat(10.0);
+        }
+        syn_core::watch_world_changes(world);
+    }
+}
+
+/// Records the player's memory of the outcome (UI renders it via the journal)
+/// and notes it as shared with the NPCs whose relationships it changed.
+///
+/// Relationship deltas past [`OutcomeContext::axis_tag_thresholds`] add
+/// `<axis>_spike` / `<axis>_loss` tags to the authored ones.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct MemoryStage;
+
+impl OutcomeStage for MemoryStage {
+    fn name(&self) -> &'static str {
+        "memory"
+    }
+
+    fn apply(&self, world: &mut WorldState, memory: &mut MemorySystem, ctx: &OutcomeContext<'_>) {
+        let outcome = ctx.outcome;
+        if outcome.memory_event_id.is_empty() {
+            return;
+        }
+
+        let mut entry = MemoryEntry::new(
+            format!("mem_player_{}_{}", world.player_id.0, ctx.current_tick.0),
+            outcome.memory_event_id.clone(),
+            world.player_id,
+            ctx.current_tick,
+            outcome.emotional_intensity,
+        );
+
+        if !outcome.stat_deltas.is_empty() {
+            entry = entry.with_stat_deltas(outcome.stat_deltas.clone());
+        }
+
+        if !outcome.memory_tags.is_empty() {
+            syn_core::record_crime_memory(world, outcome.memory_tags.iter().map(String::as_str));
+        }
+        let mut tags = outcome.memory_tags.clone();
+        merge_tags(
+            &mut tags,
+            axis_memory_tags(
+                &ctx.axis_tag_thresholds,
+                outcome
+                    .relationship_deltas
+                    .iter()
+                    .map(|d| ((d.actor_id, d.target_id), d.axis, d.delta)),
+            ),
+        );
+        if !tags.is_empty() {
+            entry = entry.with_tags(tags);
+        }
+
+        let ctx = MemoryIntensityContext::for_npc(world, world.player_id);
+        memory.record_memory(entry, ctx.as_ref());
+
+        // NPCs the outcome touched share this memory with the player
+        let npcs = outcome
+            .relationship_deltas
+            .iter()
+            .flat_map(|d| [NpcId(d.actor_id), NpcId(d.target_id)]);
+        syn_core::note_shared_memory(world, npcs, outcome.emotional_intensity);
+    }
+}
+
+/// Flags pairs the outcome touched for relationship pressure and decays
+/// the pressure queue so it cannot grow without bound.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct PressureStage;
+
+impl OutcomeStage for PressureStage {
+    fn name(&self) -> &'static str {
+        "pressure"
+    }
+
+    fn apply(&self, world: &mut WorldState, _memory: &mut MemorySystem, ctx: &OutcomeContext<'_>) {
+        if !ctx.outcome.relationship_deltas.is_empty() {
+            update_relationship_pressure_flags(world, &ctx.outcome.relationship_deltas);
+        }
+        world.relationship_pressure.decay_queue(
+            ctx.current_tick.0,
+            PRESSURE_QUEUE_MAX_AGE,
+            PRESSURE_QUEUE_MAX_LEN,
+        );
+    }
+}
+
+/// Starts a rumor about the player when the storylet carries a gossip-worthy
+/// tag (see [`syn_core::start_storylet_rumor`]), told first by its cast.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct GossipStage;
+
+impl OutcomeStage for GossipStage {
+    fn name(&self) -> &'static str {
+        "gossip"
+    }
+
+    fn apply(&self, world: &mut WorldState, _memory: &mut MemorySystem, ctx: &OutcomeContext<'_>) {
+        let cast: Vec<NpcId> = ctx.storylet.roles.iter().map(|role| role.npc_id).collect();
+        syn_core::start_storylet_rumor(
+            world,
+            &ctx.storylet.id,
+            &ctx.storylet.prerequisites.tags,
+            &cast,
+            ctx.current_tick.0,
+        );
+    }
+}
+
+/// Sets and clears the storylet's world flags, then the outcome's (move-in
+/// flags update households), attributed to `storylet:<id>`.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct FlagStage;
+
+impl OutcomeStage for FlagStage {
+    fn name(&self) -> &'static str {
+        "flags"
+    }
+
+    fn apply(&self, world: &mut WorldState, _memory: &mut MemorySystem, ctx: &OutcomeContext<'_>) {
+        apply_outcome_flags(world, ctx.storylet, ctx.outcome, ctx.current_tick);
+    }
+}
+
+/// Ordered list of outcome stages.
+pub struct OutcomePipeline {
+    stages: Vec<Box<dyn OutcomeStage>>,
+    axis_tag_thresholds: AxisTagThresholds,
+}
+
+impl OutcomePipeline {
+    /// The standard stages, in application order.
+    pub fn standard() -> Self {
+        Self {
+            stages: vec![
+                Box::new(StatStage),
+                Box::new(RelationshipStage),
+                Box::new(ObserverStage),
+                Box::new(KarmaStage),
+                Box::new(DistrictStage),
+                Box::new(HeatStage),
+                Box::new(MemoryStage),
+                Box::new(PressureStage),
+                Box::new(GossipStage),
+                Box::new(FlagStage),
+            ],
+            axis_tag_thresholds: AxisTagThresholds::default(),
+        }
+    }
+
+    /// A pipeline with no stages; useful for testing a stage in isolation.
+    pub fn empty() -> Self {
+        Self {
+            stages: Vec::new(),
+            axis_tag_thresholds: AxisTagThresholds::default(),
+        }
+    }
+
+    /// Append a stage; it runs after every stage already registered.
+    pub fn register(&mut self, stage: Box<dyn OutcomeStage>) {
+        self.stages.push(stage);
+    }
+
+    /// Thresholds passed to stages for automatic axis memory tags.
+    pub fn axis_tag_thresholds(&self) -> AxisTagThresholds {
+        self.axis_tag_thresholds
+    }
+
+    /// Change the thresholds for automatic axis memory tags.
+    pub fn set_axis_tag_thresholds(&mut self, thresholds: AxisTagThresholds) {
+        self.axis_tag_thresholds = thresholds;
+    }
+
+    /// Stage names in application order.
+    pub fn stage_names(&self) -> Vec<&'static str> {
+        self.stages.iter().map(|stage| stage.name()).collect()
+    }
+
+    /// Apply `outcome` by running every stage in order.
+    pub fn run(
+        &self,
+        world: &mut WorldState,
+        memory: &mut MemorySystem,
+        storylet: &Storylet,
+        outcome: &StoryletOutcome,
+        current_tick: SimTick,
+    ) {
+        let ctx = OutcomeContext::new(storylet, outcome, current_tick)
+            .with_axis_tag_thresholds(self.axis_tag_thresholds);
+        for stage in &self.stages {
+            stage.apply(world, memory, &ctx);
+        }
+    }
+}
+
+impl Default for OutcomePipeline {
+    fn default() -> Self {
+        Self::standard()
+    }
+}
+
+impl fmt::Debug for OutcomePipeline {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("OutcomePipeline")
+            .field("stages", &self.stage_names())
+            .finish()
+    }
+}
@@ -0,0 +1,455 @@
+//! Load-time validation and pre-resolution of compiled storylet outcomes.
+//!
+//! Content refers to stats, relationship axes and roles by name. Resolving those
+//! names once, when the library is loaded, turns typos into precise load errors
+//! instead of outcomes that silently do nothing when the storylet fires.
+//!
+//! Storylets with any unresolvable reference are quarantined: they stay in the
+//! library (keys and follow-up references remain stable) but the directors never
+//! select them.
+
+use std::collections::{BTreeMap, HashMap};
+use std::fmt;
+
+use syn_core::{NpcId, RelationshipAxis, StatKind};
+use syn_storylets::library::{CompiledStorylet, StoryletKey};
+use syn_storylets::StoryletId;
+
+use crate::storylet_source::StoryletSource;
+
+/// Role names that always refer to the player character.
+const PLAYER_ROLE_NAMES: [&str; 2] = ["protagonist", "player"];
+
+/// Stat targeted by an outcome stat delta.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum OutcomeStat {
+    /// One of the player's core stats.
+    Stat(StatKind),
+    /// The player's karma (tracked outside `Stats`).
+    Karma,
+}
+
+impl OutcomeStat {
+    /// Resolve a content stat name (case-insensitive, e.g. "mood" or "karma").
+    pub fn from_name(name: &str) -> Option<Self> {
+        if name.trim().eq_ignore_ascii_case("karma") {
+            return Some(OutcomeStat::Karma);
+        }
+        StatKind::from_name(name).map(OutcomeStat::Stat)
+    }
+}
+
+/// Actor referenced by a role name in an outcome.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum OutcomeRole {
+    /// The player character ("protagonist" or "player").
+    Player,
+    /// A declared role slot, by index into `CompiledStorylet::roles`.
+    Slot(usize),
+    /// A literal NPC id (legacy numeric role names).
+    Npc(NpcId),
+}
+
+impl OutcomeRole {
+    /// Resolve a role name against the storylet's declared role slots.
+    pub fn resolve(storylet: &CompiledStorylet, name: &str) -> Option<Self> {
+        let name = name.trim();
+        if PLAYER_ROLE_NAMES
+            .iter()
+            .any(|p| p.eq_ignore_ascii_case(name))
+        {
+            return Some(OutcomeRole::Player);
+        }
+        if let Some(index) = storylet.roles.iter().position(|r| r.name == name) {
+            return Some(OutcomeRole::Slot(index));
+        }
+        name.parse().ok().map(|id| OutcomeRole::Npc(NpcId(id)))
+    }
+}
+
+/// A stat delta with its stat name resolved.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct ResolvedStatDelta {
+    /// Stat to modify.
+    pub stat: OutcomeStat,
+    /// Amount to change (+/-).
+    pub delta: f32,
+}
+
+/// A relationship delta with its roles and axis resolved.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct ResolvedRelationshipDelta {
+    /// Actor whose view of the relationship changes.
+    pub from: OutcomeRole,
+    /// Actor the relationship points at.
+    pub to: OutcomeRole,
+    /// Axis to modify.
+    pub axis: RelationshipAxis,
+    /// Amount to change (+/-).
+    pub delta: f32,
+}
+
+/// A mood delta with its role resolved.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct ResolvedMoodDelta {
+    /// Actor whose mood shifts.
+    pub role: OutcomeRole,
+    /// Amount to change (+/-).
+    pub delta: f32,
+}
+
+/// The applicable parts of a storylet outcome, fully resolved.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct ResolvedOutcome {
+    /// Player stat changes, in authoring order.
+    pub stat_deltas: Vec<ResolvedStatDelta>,
+    /// Relationship changes, in authoring order.
+    pub relationship_deltas: Vec<ResolvedRelationshipDelta>,
+    /// Mood changes (player or NPC), in authoring order.
+    pub mood_deltas: Vec<ResolvedMoodDelta>,
+}
+
+impl ResolvedOutcome {
+    /// Resolve every name referenced by a storylet's outcome.
+    ///
+    /// Returns all problems found, not just the first, each with the path of
+    /// the offending field (e.g. `outcomes.stat_deltas[2].stat`).
+    pub fn resolve(storylet: &CompiledStorylet) -> Result<Self, Vec<OutcomeValidationError>> {
+        let outcomes = &storylet.outcomes;
+        let mut resolved = ResolvedOutcome::default();
+        let mut errors = Vec::new();
+        let mut fail = |location: String, reason: OutcomeReferenceError| {
+            errors.push(OutcomeValidationError {
+                storylet_id: storylet.id.clone(),
+                key: storylet.key,
+                location,
+                reason,
+            });
+        };
+
+        for (i, d) in outcomes.stat_deltas.iter().flatten().enumerate() {
+            match OutcomeStat::from_name(&d.stat) {
+                Some(stat) => resolved.stat_deltas.push(ResolvedStatDelta {
+                    stat,
+                    delta: d.delta,
+                }),
+                None => fail(
+                    format!("outcomes.stat_deltas[{}].stat", i),
+                    OutcomeReferenceError::UnknownStat(d.stat.clone()),
+                ),
+            }
+        }
+
+        for (i, d) in outcomes.relationship_deltas.iter().flatten().enumerate() {
+            let from = OutcomeRole::resolve(storylet, &d.from_role);
+            let to = OutcomeRole::resolve(storylet, &d.to_role);
+            let axis = RelationshipAxis::from_name(&d.axis);
+            if from.is_none() {
+                fail(
+                    format!("outcomes.relationship_deltas[{}].from_role", i),
+                    OutcomeReferenceError::UnknownRole(d.from_role.clone()),
+                );
+            }
+            if to.is_none() {
+                fail(
+                    format!("outcomes.relationship_deltas[{}].to_role", i),
+                    OutcomeReferenceError::UnknownRole(d.to_role.clone()),
+                );
+            }
+            if axis.is_none() {
+                fail(
+                    format!("outcomes.relationship_deltas[{}].axis", i),
+                    OutcomeReferenceError::UnknownAxis(d.axis.clone()),
+                );
+            }
+            if let (Some(from), Some(to), Some(axis)) = (from, to, axis) {
+                resolved
+                    .relationship_deltas
+                    .push(ResolvedRelationshipDelta {
+                        from,
+                        to,
+                        axis,
+                        delta: d.delta,
+                    });
+            }
+        }
+
+        for (i, d) in outcomes.mood_deltas.iter().flatten().enumerate() {
+            match OutcomeRole::resolve(storylet, &d.role) {
+                Some(role) => resolved.mood_deltas.push(ResolvedMoodDelta {
+                    role,
+                    delta: d.delta,
+                }),
+                None => fail(
+                    format!("outcomes.mood_deltas[{}].role", i),
+                    OutcomeReferenceError::UnknownRole(d.role.clone()),
+                ),
+            }
+        }
+
+        for (i, c) in outcomes.trait_changes.iter().flatten().enumerate() {
+            if OutcomeRole::resolve(storylet, &c.role).is_none() {
+                fail(
+                    format!("outcomes.trait_changes[{}].role", i),
+                    OutcomeReferenceError::UnknownRole(c.role.clone()),
+                );
+            }
+        }
+
+        for (i, m) in outcomes.memory_entries.iter().flatten().enumerate() {
+            for role in m.roles.split(',').map(str::trim).filter(|r| !r.is_empty()) {
+                if OutcomeRole::resolve(storylet, role).is_none() {
+                    fail(
+                        format!("outcomes.memory_entries[{}].roles", i),
+                        OutcomeReferenceError::UnknownRole(role.to_string()),
+                    );
+                }
+            }
+        }
+
+        if errors.is_empty() {
+            Ok(resolved)
+        } else {
+            Err(errors)
+        }
+    }
+}
+
+/// What was wrong with a name referenced by an outcome.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum OutcomeReferenceError {
+    /// Not a `StatKind` (or karma).
+    UnknownStat(String),
+    /// Not a relationship axis.
+    UnknownAxis(String),
+    /// Neither the player, a declared role slot, nor a numeric NPC id.
+    UnknownRole(String),
+}
+
+/// An unresolvable reference in a storylet outcome, with its exact location.
+#[derive(Debug, Clone, PartialEq)]
+pub struct OutcomeValidationError {
+    /// Storylet containing the reference.
+    pub storylet_id: StoryletId,
+    /// Compiled key of that storylet.
+    pub key: StoryletKey,
+    /// Path of the offending field, e.g. `outcomes.relationship_deltas[0].axis`.
+    pub location: String,
+    /// What was wrong with it.
+    pub reason: OutcomeReferenceError,
+}
+
+impl fmt::Display for OutcomeValidationError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "storylet '{}' at {}: ",
+            self.storylet_id.0, self.location
+        )?;
+        match &self.reason {
+            OutcomeReferenceError::UnknownStat(name) => write!(f, "unknown stat '{}'", name),
+            OutcomeReferenceError::UnknownAxis(name) => {
+                write!(f, "unknown relationship axis '{}'", name)
+            }
+            OutcomeReferenceError::UnknownRole(name) => write!(f, "unknown role '{}'", name),
+        }
+    }
+}
+
+impl std::error::Error for OutcomeValidationError {}
+
+/// Resolved outcomes for every storylet in a library, plus the quarantine list.
+#[derive(Debug, Clone, Default)]
+pub struct ResolvedOutcomeTable {
+    outcomes: HashMap<StoryletKey, ResolvedOutcome>,
+    quarantined: BTreeMap<StoryletKey, Vec<OutcomeValidationError>>,
+}
+
+impl ResolvedOutcomeTable {
+    /// Resolve every storylet in `source`, quarantining the invalid ones.
+    pub fn resolve<S: StoryletSource + ?Sized>(source: &S) -> Self {
+        let mut table = ResolvedOutcomeTable::default();
+        for storylet in source.iter_all_storylets() {
+            match ResolvedOutcome::resolve(storylet) {
+                Ok(outcome) => {
+                    table.outcomes.insert(storylet.key, outcome);
+                }
+                Err(errors) => {
+                    table.quarantined.insert(storylet.key, errors);
+                }
+            }
+        }
+        table
+    }
+
+    /// Resolve every storylet in `source`, rejecting the library if any is invalid.
+    pub fn resolve_strict<S: StoryletSource + ?Sized>(
+        source: &S,
+    ) -> Result<Self, Vec<OutcomeValidationError>> {
+        let table = Self::resolve(source);
+        if table.quarantined.is_empty() {
+            Ok(table)
+        } else {
+            Err(table.errors().cloned().collect())
+        }
+    }
+
+    /// Resolved outcome for a valid storylet.
+    pub fn get(&self, key: StoryletKey) -> Option<&ResolvedOutcome> {
+        self.outcomes.get(&key)
+    }
+
+    /// True if the storylet failed validation and must not be selected.
+    pub fn is_quarantined(&self, key: StoryletKey) -> bool {
+        self.quarantined.contains_key(&key)
+    }
+
+    /// Keys of all quarantined storylets, in key order.
+    pub fn quarantined_keys(&self) -> impl Iterator<Item = StoryletKey> + '_ {
+        self.quarantined.keys().copied()
+    }
+
+    /// Every validation error, ordered by storylet key then field.
+    pub fn errors(&self) -> impl Iterator<Item = &OutcomeValidationError> + '_ {
+        self.quarantined.values().flatten()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use syn_storylets::library::StoryletLibrary;
+    use syn_storylets::{
+        LifeStage, MemoryEntry, Outcome, RelationshipDelta, RoleSlot, StatDelta, StoryDomain,
+    };
+
+    fn storylet(key: u32, id: &str, outcomes: Outcome) -> CompiledStorylet {
+        CompiledStorylet {
+            id: StoryletId::new(id),
+            key: StoryletKey(key),
+            name: id.to_string(),
+            description: None,
+            tags: vec![],
+            domain: StoryDomain::Romance,
+            life_stage: LifeStage::Adult,
+            heat: 3,
+            weight: 1.0,
+            roles: vec![RoleSlot {
+                name: "love_interest".to_string(),
+                required: true,
+                constraints: None,
+                allow_same_as: vec![],
+            }],
+            prerequisites: Default::default(),
+            cooldowns: Default::default(),
+            outcomes,
+            follow_ups_resolved: vec![],
+        }
+    }
+
+    fn library(storylets: Vec<CompiledStorylet>) -> StoryletLibrary {
+        let mut lib = StoryletLibrary::new();
+        for s in storylets {
+            lib.id_to_key.insert(s.id.clone(), s.key);
+            lib.storylets.push(s);
+            lib.total_count += 1;
+        }
+        lib
+    }
+
+    #[test]
+    fn test_resolves_stats_axes_and_roles() {
+        let outcomes = Outcome {
+            stat_deltas: Some(vec![
+                StatDelta {
+                    stat: "Mood".to_string(),
+                    delta: 2.0,
+                },
+                StatDelta {
+                    stat: "karma".to_string(),
+                    delta: -5.0,
+                },
+            ]),
+            relationship_deltas: Some(vec![RelationshipDelta {
+                from_role: "protagonist".to_string(),
+                to_role: "love_interest".to_string(),
+                axis: "attraction".to_string(),
+                delta: 1.5,
+            }]),
+            ..Default::default()
+        };
+        let resolved = ResolvedOutcome::resolve(&storylet(0, "date", outcomes)).unwrap();
+
+        assert_eq!(
+            resolved.stat_deltas[0].stat,
+            OutcomeStat::Stat(StatKind::Mood)
+        );
+        assert_eq!(resolved.stat_deltas[1].stat, OutcomeStat::Karma);
+        assert_eq!(
+            resolved.relationship_deltas[0],
+            ResolvedRelationshipDelta {
+                from: OutcomeRole::Player,
+                to: OutcomeRole::Slot(0),
+                axis: RelationshipAxis::Attraction,
+                delta: 1.5,
+            }
+        );
+    }
+
+    #[test]
+    fn test_invalid_storylets_are_quarantined_with_locations() {
+        let bad = Outcome {
+            stat_deltas: Some(vec![
+                StatDelta {
+                    stat: "mood".to_string(),
+                    delta: 1.0,
+                },
+                StatDelta {
+                    stat: "stress".to_string(),
+                    delta: 4.0,
+                },
+            ]),
+            relationship_deltas: Some(vec![RelationshipDelta {
+                from_role: "protagonist".to_string(),
+                to_role: "rival".to_string(),
+                axis: "jealousy".to_string(),
+                delta: 1.0,
+            }]),
+            memory_entries: Some(vec![MemoryEntry {
+                roles: "protagonist, love_interest, ghost".to_string(),
+                tags: vec![],
+                intensity: 3,
+                description: None,
+            }]),
+            ..Default::default()
+        };
+        let lib = library(vec![
+            storylet(0, "fine", Outcome::default()),
+            storylet(1, "broken", bad),
+        ]);
+
+        let table = ResolvedOutcomeTable::resolve(&lib);
+        assert!(table.get(StoryletKey(0)).is_some());
+        assert!(!table.is_quarantined(StoryletKey(0)));
+        assert!(table.is_quarantined(StoryletKey(1)));
+        assert_eq!(
+            table.quarantined_keys().collect::<Vec<_>>(),
+            vec![StoryletKey(1)]
+        );
+
+        let messages: Vec<String> = table.errors().map(|e| e.to_string()).collect();
+        assert_eq!(
+            messages,
+            vec![
+                "storylet 'broken' at outcomes.stat_deltas[1].stat: unknown stat 'stress'",
+                "storylet 'broken' at outcomes.relationship_deltas[0].to_role: unknown role 'rival'",
+                "storylet 'broken' at outcomes.relationship_deltas[0].axis: \
+                 unknown relationship axis 'jealousy'",
+                "storylet 'broken' at outcomes.memory_entries[0].roles: unknown role 'ghost'",
+            ]
+        );
+
+        let strict = ResolvedOutcomeTable::resolve_strict(&lib).unwrap_err();
+        assert_eq!(strict.len(), 4);
+    }
+}
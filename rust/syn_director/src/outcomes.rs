@@ -0,0 +1,680 @@
+//! Applying storylet outcomes to the world.
+//!
+//! Relationship deltas (buffered through reaction latency), stat and flag
+//! changes, memory recording, pressure flags and news, for both direct
+//! storylet outcomes and player choices (with undo).
+
+use serde::{Deserialize, Serialize};
+use syn_core::{
+    apply_move_in_flag, NEWSWORTHY_TAG, NpcId, OrderedMap,
+    RelationshipAxis as CoreRelationshipAxis, SimTick, StatDelta, WorldEvent, WorldState,
+};
+use syn_core::relationship_milestones::RelationshipMilestoneEvent;
+use syn_core::relationship_model::{
+    RelationshipAxis as ModelRelationshipAxis, RelationshipDelta, RelationshipVector,
+};
+use syn_core::relationship_pressure::RelationshipPressureEvent;
+use syn_memory::MemorySystem;
+use syn_sim::{NpcRegistry, SimState};
+
+use crate::{
+    active_choices, resolve_or_spawn_actor, AxisTagThresholds, ChoiceUndo, CompiledStorylet,
+    DistrictStage, FlagStage, HeatStage, KarmaStage, MemoryStage, OutcomeContext, OutcomePipeline,
+    OutcomeRole, OutcomeStage, OutcomeStat, RoleAssignments, Storylet, StoryletChoice,
+    StoryletLibrary, WorldFlagUpdate,
+};
+use crate::outcome_branches::{resolve_outcome_branches, BranchContext, OutcomeBranch};
+use crate::selection::{advance_and_select, DirectorEventView};
+use crate::storylet_scoring::memory_tags_for_pair;
+
+/// Prepare storylet execution by focusing relevant NPCs in the simulation registry.
+///
+/// A role-tagged actor nobody known can play is spawned on the spot (see
+/// [`resolve_or_spawn_actor`]) rather than leaving the scene empty.
+pub fn prepare_storylet_execution(
+    world: &mut WorldState,
+    registry: &mut NpcRegistry,
+    storylet: &Storylet,
+    tick: u64,
+) {
+    if let Some(actors) = &storylet.outcomes.actors {
+        for actor in [&actors.primary, &actors.secondary].into_iter().flatten() {
+            let cast = resolve_or_spawn_actor(world, registry, actor, &storylet.id, tick);
+            if let Some(npc_id) = cast {
+                registry.focus_npc_for_scene(world, npc_id, tick);
+                world.ensure_npc_known(npc_id);
+            }
+        }
+    }
+}
+
+/// Outcome of a storylet firing: stat changes, relationship shifts, memory entries.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct StoryletOutcome {
+    /// Unified stat changes from this outcome.
+    ///
+    /// JSON content uses `stat_impacts`; we expose it as `stat_deltas` in code.
+    #[serde(rename = "stat_impacts", alias = "stat_deltas", default)]
+    pub stat_deltas: Vec<StatDelta>,
+    #[serde(
+        rename = "relationship_impacts",
+        alias = "relationship_deltas",
+        default
+    )]
+    pub relationship_deltas: Vec<RelationshipDelta>,
+    #[serde(default)]
+    pub karma_delta: Option<f32>,
+    /// Authored reputation changes scoped to a district or social cluster.
+    #[serde(default)]
+    pub reputation_deltas: Vec<syn_core::ReputationDelta>,
+    #[serde(default)]
+    pub memory_event_id: String,
+    #[serde(default)]
+    pub emotional_intensity: f32, // -1.0 to +1.0
+    #[serde(default)]
+    pub memory_tags: Vec<String>, // Tags applied to recorded memory
+    #[serde(default)]
+    pub heat_spike: f32, // Additional world heat delta from choices
+    #[serde(default)]
+    pub next_storylet: Option<String>,
+    /// Pending NPC reactions this outcome cuts short (see
+    /// [`syn_core::reaction_latency`]).
+    #[serde(default)]
+    pub reaction_intercepts: Vec<syn_core::ReactionIntercept>,
+    /// Relationship statuses this choice declares or clears (see
+    /// [`syn_core::declared_relationships`]).
+    #[serde(default)]
+    pub relationship_declarations: Vec<syn_core::RelationshipDeclaration>,
+    /// Conditional branches settled when the storylet fires (see
+    /// [`crate::outcome_branches`]).
+    #[serde(default)]
+    pub branches: Vec<OutcomeBranch>,
+    /// Skill XP the player earns.
+    #[serde(default)]
+    pub skill_xp_rewards: Vec<SkillXpReward>,
+    /// World flags this outcome sets or clears, after the storylet's own.
+    #[serde(default, alias = "flag_operations")]
+    pub flags: Vec<WorldFlagUpdate>,
+}
+
+/// XP granted to one of the player's skills by an outcome.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct SkillXpReward {
+    /// Skill ID (e.g., "programming", "cooking").
+    pub skill_id: String,
+    pub xp: u32,
+}
+
+/// Add each reward's XP to the player's skills, learning new ones on the way.
+pub(crate) fn grant_skill_xp(world: &mut WorldState, rewards: &[SkillXpReward], tick: SimTick) {
+    for reward in rewards {
+        world
+            .player_skills
+            .get_or_create_mut(&syn_core::skills::SkillId::new(&reward.skill_id))
+            .add_xp(reward.xp, tick.0);
+    }
+}
+
+impl Default for StoryletOutcome {
+    fn default() -> Self {
+        StoryletOutcome {
+            stat_deltas: Vec::new(),
+            relationship_deltas: Vec::new(),
+            karma_delta: None,
+            reputation_deltas: Vec::new(),
+            memory_event_id: "unknown".to_string(),
+            emotional_intensity: 0.0,
+            memory_tags: Vec::new(),
+            heat_spike: 0.0,
+            next_storylet: None,
+            reaction_intercepts: Vec::new(),
+            relationship_declarations: Vec::new(),
+            branches: Vec::new(),
+            skill_xp_rewards: Vec::new(),
+            flags: Vec::new(),
+        }
+    }
+}
+
+impl StoryletOutcome {
+    /// Relationship deltas this outcome could apply on any branch.
+    pub fn all_relationship_deltas(&self) -> Vec<RelationshipDelta> {
+        let mut deltas = self.relationship_deltas.clone();
+        for branch in &self.branches {
+            deltas.extend(branch.then.all_relationship_deltas());
+            if let Some(otherwise) = &branch.otherwise {
+                deltas.extend(otherwise.all_relationship_deltas());
+            }
+        }
+        deltas
+    }
+}
+
+/// A fired storylet answers the oldest forced trigger of its kind, if any,
+/// or the flag change, declared/derived mismatch or stat crisis it reacts to.
+pub(crate) fn consume_forced_trigger(world: &mut WorldState, storylet: &Storylet, current_tick: SimTick) {
+    if let Some(kind) = storylet.triggers.kind.as_deref() {
+        if syn_core::is_stat_crisis_trigger(kind) {
+            world.stat_crisis.respond(kind, current_tick.0);
+        } else if syn_core::is_flag_trigger(kind) {
+            world.flag_changes.consume(kind, current_tick.0);
+        } else if syn_core::is_declared_mismatch_trigger(kind) {
+            world.declared_relationships.consume(kind);
+        } else {
+            world.external_events.consume_trigger(kind);
+        }
+    }
+}
+
+/// Queue a fired storylet for the daily news feed if it is tagged newsworthy.
+pub(crate) fn report_if_newsworthy(
+    world: &mut WorldState,
+    storylet_id: &str,
+    headline: &str,
+    tags: &[String],
+    current_tick: SimTick,
+) {
+    if tags.iter().any(|t| t.eq_ignore_ascii_case(NEWSWORTHY_TAG)) {
+        world
+            .news_feed
+            .report_storylet(current_tick.0, storylet_id, headline, tags);
+    }
+}
+
+pub(crate) fn apply_relationship_outcome(
+    rels: &mut OrderedMap<(u64, u64), RelationshipVector>,
+    deltas: &[RelationshipDelta],
+) {
+    for d in deltas {
+        let vec = rels
+            .entry((d.actor_id, d.target_id))
+            .or_insert_with(RelationshipVector::default);
+        vec.apply_delta(d.axis, d.delta);
+    }
+}
+
+/// Apply a storylet flag operation.
+///
+/// Move-in flags (`move_in:...`) update the player's household; anything else
+/// sets or clears a world flag.
+pub(crate) fn apply_flag_operation(
+    world: &mut WorldState,
+    flag: &str,
+    set: bool,
+    current_tick: SimTick,
+    source: &str,
+) {
+    if apply_move_in_flag(world, flag, set, current_tick) {
+        return;
+    }
+    syn_core::set_world_flag(world, flag, set, source);
+}
+
+/// Apply the storylet's flag updates, then the outcome's, attributed to
+/// `storylet:<id>`.
+pub(crate) fn apply_outcome_flags(
+    world: &mut WorldState,
+    storylet: &Storylet,
+    outcome: &StoryletOutcome,
+    current_tick: SimTick,
+) {
+    let source = syn_core::storylet_flag_source(&storylet.id);
+    for update in storylet.outcomes.flags.iter().chain(&outcome.flags) {
+        apply_flag_operation(world, &update.flag, update.value, current_tick, &source);
+    }
+}
+
+/// Apply a resolved outcome stat delta to the player, capped and scaled by
+/// difficulty.
+///
+/// Stage-specific stats (curiosity, energy, libido) only change while the
+/// player currently has them. Returns the stat and scaled delta applied;
+/// karma is not scaled and not returned.
+pub(crate) fn apply_outcome_stat(
+    world: &mut WorldState,
+    stat: OutcomeStat,
+    delta: f32,
+) -> Option<(syn_core::StatKind, f32)> {
+    let kind = match stat {
+        OutcomeStat::Karma => {
+            syn_core::apply_authored_karma(world, delta);
+            return None;
+        }
+        OutcomeStat::Stat(kind) => kind,
+    };
+    let tracked = match kind {
+        syn_core::StatKind::Curiosity => world.player_stats.curiosity.is_some(),
+        syn_core::StatKind::Energy => world.player_stats.energy.is_some(),
+        syn_core::StatKind::Libido => world.player_stats.libido.is_some(),
+        _ => true,
+    };
+    if !tracked {
+        return None;
+    }
+    let delta = syn_core::cap_stat_delta(world, kind, delta);
+    let delta = world.difficulty.scale_delta(delta);
+    world.player_stats.apply_delta(kind, delta);
+    Some((kind, delta))
+}
+
+/// Actor cast into an outcome role, if the role was filled.
+pub(crate) fn outcome_actor(
+    role: OutcomeRole,
+    storylet: &CompiledStorylet,
+    assignments: &RoleAssignments,
+    world: &WorldState,
+) -> Option<NpcId> {
+    match role {
+        OutcomeRole::Player => Some(world.player_id),
+        OutcomeRole::Slot(index) => storylet
+            .roles
+            .get(index)
+            .and_then(|slot| assignments.mapping.get(&slot.name).copied()),
+        OutcomeRole::Npc(id) => Some(id),
+    }
+}
+
+/// Update relationship pressure flags for pairs that had relationship changes.
+/// For now, this simply tracks which pairs had deltas; future refinement
+/// can track actual band crossings.
+pub(crate) fn update_relationship_pressure_flags(world: &mut WorldState, deltas: &[RelationshipDelta]) {
+    for delta in deltas {
+        world
+            .relationship_pressure
+            .mark_pair_changed(delta.actor_id, delta.target_id);
+    }
+}
+
+/// Apply an outcome through the standard [`OutcomePipeline`].
+pub fn apply_storylet_outcome_with_memory(
+    world: &mut WorldState,
+    memory: &mut MemorySystem,
+    storylet: &Storylet,
+    outcome: &StoryletOutcome,
+    current_tick: SimTick,
+) {
+    OutcomePipeline::standard().run(world, memory, storylet, outcome, current_tick);
+}
+
+/// Fold relationship deltas into a per-pair buffer.
+///
+/// New additive relationship delta handling using the unified model (non-breaking).
+/// Ordered by pair so pressure/milestone queues fill in a stable order. Pairs
+/// entering the buffer seed their pressure snapshot first, so band changes are
+/// detectable when the buffer is committed.
+pub(crate) fn buffer_relationship_deltas(
+    world: &mut WorldState,
+    rel_buffer: &mut OrderedMap<(u64, u64), RelationshipVector>,
+    deltas: &[RelationshipDelta],
+    current_tick: SimTick,
+) {
+    // Typo-sized deltas are clamped; with reaction latency on, NPC-side
+    // deltas sink in over later ticks
+    let capped = syn_core::cap_relationship_deltas(world, deltas);
+    let deltas = &syn_core::defer_npc_reactions(world, &capped)[..];
+    for delta in deltas {
+        let key = (delta.actor_id, delta.target_id);
+        if rel_buffer.contains_key(&key) {
+            continue;
+        }
+        let current = world.get_relationship(NpcId(delta.actor_id), NpcId(delta.target_id));
+        let vec = RelationshipVector {
+            affection: current.affection,
+            trust: current.trust,
+            attraction: current.attraction,
+            familiarity: current.familiarity,
+            resentment: current.resentment,
+        };
+        world.relationship_pressure.update_for_pair(
+            delta.actor_id,
+            delta.target_id,
+            &vec,
+            None,
+            Some(current_tick.0),
+        );
+        rel_buffer.insert(key, vec);
+    }
+
+    apply_relationship_outcome(rel_buffer, deltas);
+    for delta in deltas {
+        syn_core::record_relationship_interaction(
+            world,
+            NpcId(delta.actor_id),
+            NpcId(delta.target_id),
+            delta.delta.abs(),
+        );
+    }
+}
+
+/// Cut short the pending NPC reactions an outcome intercepts.
+pub(crate) fn apply_reaction_intercepts(world: &mut WorldState, outcome: &StoryletOutcome) {
+    for intercept in &outcome.reaction_intercepts {
+        syn_core::intercept_pending_reactions(world, intercept);
+    }
+}
+
+/// Write buffered relationships back to the world and run one pressure and
+/// milestone evaluation per pair, attributed to `source`.
+pub(crate) fn commit_relationship_buffer(
+    world: &mut WorldState,
+    memory: &MemorySystem,
+    rel_buffer: OrderedMap<(u64, u64), RelationshipVector>,
+    source: &str,
+    current_tick: SimTick,
+) {
+    for ((actor_id, target_id), vec) in rel_buffer {
+        let mut current = world.get_relationship(NpcId(actor_id), NpcId(target_id));
+        current.affection = vec.affection;
+        current.trust = vec.trust;
+        current.attraction = vec.attraction;
+        current.familiarity = vec.familiarity;
+        current.resentment = vec.resentment;
+        current.state = current.compute_next_state();
+        world.set_relationship(NpcId(actor_id), NpcId(target_id), current);
+
+        world.relationship_pressure.update_for_pair(
+            actor_id,
+            target_id,
+            &vec,
+            Some(source.to_string()),
+            Some(current_tick.0),
+        );
+
+        let tags = memory_tags_for_pair(memory, actor_id, target_id);
+        let milestone = world
+            .relationship_milestones
+            .evaluate_and_record_milestones_for_pair(
+                actor_id,
+                target_id,
+                &vec,
+                &tags,
+                Some(source.to_string()),
+                Some(current_tick.0),
+            );
+        if let Some(milestone) = &milestone {
+            syn_core::publish_world_event(world, WorldEvent::from(milestone));
+        }
+    }
+}
+
+/// Karma, reputation, district effects, heat, the player's memory of an
+/// outcome and its world flags.
+pub(crate) fn apply_outcome_side_effects(
+    world: &mut WorldState,
+    memory: &mut MemorySystem,
+    storylet: &Storylet,
+    outcome: &StoryletOutcome,
+    current_tick: SimTick,
+    axis_tag_thresholds: AxisTagThresholds,
+) {
+    let ctx = OutcomeContext::new(storylet, outcome, current_tick)
+        .with_axis_tag_thresholds(axis_tag_thresholds);
+    KarmaStage.apply(world, memory, &ctx);
+    DistrictStage.apply(world, memory, &ctx);
+    HeatStage.apply(world, memory, &ctx);
+    MemoryStage.apply(world, memory, &ctx);
+    FlagStage.apply(world, memory, &ctx);
+}
+
+pub fn next_hot_relationship(world: &mut WorldState) -> Option<RelationshipPressureEvent> {
+    world.relationship_pressure.pop_next_event()
+}
+
+pub fn next_relationship_milestone(world: &mut WorldState) -> Option<RelationshipMilestoneEvent> {
+    world.relationship_milestones.pop_next()
+}
+
+pub fn apply_storylet_outcome(
+    world: &mut WorldState,
+    _sim: &mut SimState,
+    outcome: &StoryletOutcome,
+) {
+    if !outcome.stat_deltas.is_empty() {
+        syn_core::apply_outcome_stat_deltas(world, &outcome.stat_deltas);
+    }
+    grant_skill_xp(world, &outcome.skill_xp_rewards, world.current_tick);
+
+    apply_reaction_intercepts(world, outcome);
+    if !outcome.relationship_deltas.is_empty() {
+        let capped = syn_core::cap_relationship_deltas(world, &outcome.relationship_deltas);
+        for delta in &syn_core::defer_npc_reactions(world, &capped) {
+            let actor = NpcId(delta.actor_id);
+            let target = NpcId(delta.target_id);
+            let mut rel = world.get_relationship(actor, target);
+            let axis = match delta.axis {
+                ModelRelationshipAxis::Affection => CoreRelationshipAxis::Affection,
+                ModelRelationshipAxis::Trust => CoreRelationshipAxis::Trust,
+                ModelRelationshipAxis::Attraction => CoreRelationshipAxis::Attraction,
+                ModelRelationshipAxis::Familiarity => CoreRelationshipAxis::Familiarity,
+                ModelRelationshipAxis::Resentment => CoreRelationshipAxis::Resentment,
+            };
+            rel.apply_delta(axis, delta.delta);
+            rel.state = rel.compute_next_state();
+            world.set_relationship(actor, target, rel);
+            syn_core::record_relationship_interaction(world, actor, target, delta.delta.abs());
+        }
+    }
+
+    if let Some(delta) = outcome.karma_delta {
+        syn_core::apply_authored_karma(world, delta);
+    }
+    syn_core::apply_reputation_deltas(world, &outcome.reputation_deltas);
+}
+
+pub fn apply_storylet_choice_outcome(
+    world: &mut WorldState,
+    sim: &mut SimState,
+    storylet: &Storylet,
+    choice: &StoryletChoice,
+) {
+    prepare_storylet_execution(world, &mut sim.npc_registry, storylet, world.current_tick.0);
+    syn_core::publish_world_event(
+        world,
+        WorldEvent::StoryletFired {
+            storylet_id: storylet.id.clone(),
+            choice_id: Some(choice.id.clone()),
+        },
+    );
+    let target = storylet.roles.first().map(|role| role.npc_id);
+    let memory = MemorySystem::new();
+    let mut branches =
+        BranchContext::new(world, &memory, target, &storylet.id, world.current_tick);
+    let outcome = resolve_outcome_branches(&choice.outcome, &mut branches);
+    apply_storylet_outcome(world, sim, &outcome);
+    syn_core::observe_relationship_deltas(
+        world,
+        &outcome.relationship_deltas,
+        &syn_core::storylet_flag_source(&storylet.id),
+    );
+    syn_core::apply_relationship_declarations(
+        world,
+        &outcome.relationship_declarations,
+        &syn_core::storylet_flag_source(&storylet.id),
+    );
+    syn_core::apply_district_effects(
+        world,
+        &storylet.outcomes.district_effects,
+        &syn_core::storylet_flag_source(&storylet.id),
+    );
+    apply_outcome_flags(world, storylet, &outcome, world.current_tick);
+    consume_forced_trigger(world, storylet, world.current_tick);
+
+    world.storylet_usage.record_fire(&storylet.id);
+    world
+        .storylet_usage
+        .record_domains(&crate::selection::storylet_domains(storylet));
+    world.life_goals.take_pending_storylet(&storylet.id);
+    world.ambient.note_storylet(world.current_tick.0);
+}
+
+/// Find a storylet and one of its currently offered choices.
+pub(crate) fn find_active_choice<'a>(
+    world: &WorldState,
+    sim: &SimState,
+    library: &'a StoryletLibrary,
+    storylet_id: &str,
+    choice_id: &str,
+) -> Option<(&'a Storylet, &'a StoryletChoice)> {
+    let storylet = library.storylets.iter().find(|s| s.id == storylet_id)?;
+    let choice = active_choices(world, &sim.npc_registry, storylet)
+        .iter()
+        .find(|c| c.id == choice_id && c.is_unlocked(world))?;
+    Some((storylet, choice))
+}
+
+/// Apply a choice, advance time and pick the next event.
+///
+/// Proposal events (`proposal:<id>`) and check-ins (`check_in:<id>`) are
+/// answered through [`syn_core::choose_proposal_option`] and
+/// [`syn_core::choose_check_in_option`] instead of a library storylet.
+pub fn apply_choice_and_advance(
+    world: &mut WorldState,
+    sim: &mut SimState,
+    library: &StoryletLibrary,
+    storylet_id: &str,
+    choice_id: &str,
+    ticks_to_advance: u32,
+) -> Option<DirectorEventView> {
+    if syn_core::parse_proposal_storylet_id(storylet_id).is_some() {
+        syn_core::choose_proposal_option(world, storylet_id, choice_id)?;
+        return advance_and_select(world, sim, library, ticks_to_advance);
+    }
+    if syn_core::parse_check_in_storylet_id(storylet_id).is_some() {
+        syn_core::choose_check_in_option(world, storylet_id, choice_id)?;
+        return advance_and_select(world, sim, library, ticks_to_advance);
+    }
+
+    let (storylet, choice) = find_active_choice(world, sim, library, storylet_id, choice_id)?;
+
+    apply_storylet_choice_outcome(world, sim, storylet, choice);
+
+    advance_and_select(world, sim, library, ticks_to_advance)
+}
+
+/// Apply a choice without advancing time, returning its [`ChoiceUndo`].
+///
+/// Returns `None` (and changes nothing) if the storylet or choice is not on
+/// offer. The undo stays valid until the world's tick advances.
+pub fn apply_choice_with_undo(
+    world: &mut WorldState,
+    sim: &mut SimState,
+    library: &StoryletLibrary,
+    storylet_id: &str,
+    choice_id: &str,
+) -> Option<ChoiceUndo> {
+    let (storylet, choice) = find_active_choice(world, sim, library, storylet_id, choice_id)?;
+
+    let undo = ChoiceUndo::capture(world, storylet, choice);
+    apply_storylet_choice_outcome(world, sim, storylet, choice);
+    Some(undo)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use syn_core::{StatKind, WorldSeed};
+
+    fn named_storylet(id: &str) -> Storylet {
+        Storylet {
+            id: id.to_string(),
+            name: id.to_string(),
+            ..Storylet::default()
+        }
+    }
+
+    #[test]
+    fn apply_storylet_outcome_uses_stat_deltas_and_karma() {
+        let mut world = WorldState::new(WorldSeed(1), NpcId(1));
+        let mut memory = MemorySystem::new();
+        let mut storylet = named_storylet("outcome_test");
+        storylet.name = "Outcome Test".to_string();
+        let outcome = StoryletOutcome {
+            stat_deltas: vec![
+                StatDelta {
+                    kind: StatKind::Mood,
+                    delta: -5.0,
+                    source: Some("test".into()),
+                },
+                StatDelta {
+                    kind: StatKind::Reputation,
+                    delta: 10.0,
+                    source: Some("test".into()),
+                },
+            ],
+            karma_delta: Some(-20.0),
+            ..Default::default()
+        };
+
+        apply_storylet_outcome_with_memory(
+            &mut world,
+            &mut memory,
+            &storylet,
+            &outcome,
+            SimTick(0),
+        );
+
+        assert!(world.player_stats.get(StatKind::Mood) <= 10.0);
+        assert!(world.player_stats.get(StatKind::Mood) >= -10.0);
+        assert_eq!(world.player_stats.get(StatKind::Reputation), 10.0);
+        let karma_val = world.player_karma.0;
+        assert!(karma_val >= -100.0 && karma_val <= 100.0);
+    }
+
+    #[test]
+    fn crime_outcomes_raise_underworld_exposure() {
+        let mut world = WorldState::new(WorldSeed(1), NpcId(1));
+        let mut memory = MemorySystem::new();
+        let storylet = named_storylet("shoplift");
+        let outcome = StoryletOutcome {
+            karma_delta: Some(-20.0),
+            memory_event_id: "shoplift".to_string(),
+            memory_tags: vec!["theft".to_string()],
+            ..Default::default()
+        };
+
+        apply_storylet_outcome_with_memory(
+            &mut world,
+            &mut memory,
+            &storylet,
+            &outcome,
+            SimTick(0),
+        );
+
+        assert_eq!(world.underworld.crime_memories, 1);
+        assert!((world.underworld.level - 14.0).abs() < 1e-4);
+        assert_eq!(
+            world.underworld.standing(),
+            syn_core::UnderworldStanding::Noticed
+        );
+    }
+
+    #[test]
+    fn authored_choice_moves_scoped_reputation_and_weighted_karma() {
+        let mut world = WorldState::new(WorldSeed(1), NpcId(1));
+        world.moral_ledger.karma_model.vice_weight = 0.5;
+        let mut memory = MemorySystem::new();
+        let storylet = named_storylet("snitch");
+        let outcome: StoryletOutcome = serde_json::from_str(
+            r#"{
+                "karma_delta": -20.0,
+                "reputation_deltas": [
+                    { "scope": { "district": "Harbor" }, "delta": -15.0 },
+                    { "scope": { "cluster": "cluster_0" }, "delta": 5.0 }
+                ]
+            }"#,
+        )
+        .unwrap();
+
+        apply_storylet_outcome_with_memory(
+            &mut world,
+            &mut memory,
+            &storylet,
+            &outcome,
+            SimTick(0),
+        );
+
+        assert!((world.player_karma.0 + 10.0).abs() < 1e-4);
+        assert_eq!(world.moral_ledger.district_reputation("Harbor"), -15.0);
+        assert_eq!(world.moral_ledger.cluster_reputation("cluster_0"), 5.0);
+    }
+}
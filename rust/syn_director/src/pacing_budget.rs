@@ -0,0 +1,236 @@
+//! Heat budgets that spread drama out over in-game days.
+//!
+//! [`pacing`](crate::pacing) keeps the narrative heat in phase; it doesn't
+//! stop a hot stretch from firing one high-heat storylet after another. The
+//! [`PacingBudget`] remembers the heat every storylet fired through
+//! [`EventDirector::fire_storylet`] spent over the last week and turns it into
+//! a score multiplier:
+//!
+//! - A day's heat (and each story domain's share of it) is a soft budget: a
+//!   storylet that would overspend it is scaled by the fraction that still
+//!   fits, down to 0.0 once the day is spent. Storylets without heat are never
+//!   held back.
+//! - Heat categories have hard caps: past `critical_arcs_per_week`
+//!   `CriticalArc` storylets in the last 7 days, or `high_drama_per_day`
+//!   `HighDrama` ones today, the multiplier is 0.0.
+//!
+//! The [`DefaultScoringStrategy`](crate::DefaultScoringStrategy) applies it as
+//! the `pacing_budget` factor.
+
+use serde::{Deserialize, Serialize};
+use syn_core::SimTick;
+
+use crate::selection::storylet_domains;
+use crate::{EventDirector, Storylet, StoryletHeatCategory};
+
+/// Days a spend counts against the weekly caps.
+const WEEK_DAYS: u32 = 7;
+
+/// Limits the [`PacingBudget`] enforces.
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub struct PacingCaps {
+    /// Storylet heat that may fire per in-game day.
+    pub daily_heat: f32,
+    /// Storylet heat that may fire per story domain per day.
+    pub domain_daily_heat: f32,
+    /// `CriticalArc` storylets allowed in any 7 days.
+    pub critical_arcs_per_week: u32,
+    /// `HighDrama` storylets allowed per day.
+    pub high_drama_per_day: u32,
+}
+
+impl Default for PacingCaps {
+    fn default() -> Self {
+        Self {
+            daily_heat: 150.0,
+            domain_daily_heat: 80.0,
+            critical_arcs_per_week: 1,
+            high_drama_per_day: 2,
+        }
+    }
+}
+
+/// Heat one fired storylet spent.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct HeatSpend {
+    pub storylet_id: String,
+    /// In-game day it fired on.
+    pub day: u32,
+    pub heat: f32,
+    /// Its story domains (see [`storylet_domains`]).
+    pub domains: Vec<String>,
+    #[serde(default)]
+    pub category: Option<StoryletHeatCategory>,
+}
+
+/// Heat spent over the last week, against [`PacingCaps`].
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct PacingBudget {
+    #[serde(default)]
+    caps: PacingCaps,
+    /// Spends from the last [`WEEK_DAYS`] days, oldest first.
+    #[serde(default)]
+    spends: Vec<HeatSpend>,
+}
+
+impl PacingBudget {
+    pub fn new(caps: PacingCaps) -> Self {
+        Self {
+            caps,
+            spends: Vec::new(),
+        }
+    }
+
+    pub fn caps(&self) -> PacingCaps {
+        self.caps
+    }
+
+    /// Spends still counting against a cap, oldest first.
+    pub fn spends(&self) -> &[HeatSpend] {
+        &self.spends
+    }
+
+    /// Charge `storylet`'s heat to the day of `tick`.
+    pub fn record(&mut self, storylet: &Storylet, tick: SimTick) {
+        let day = tick.days_elapsed();
+        self.spends.retain(|spend| spend.day + WEEK_DAYS > day);
+        self.spends.push(HeatSpend {
+            storylet_id: storylet.id.clone(),
+            day,
+            heat: storylet.heat.max(0) as f32,
+            domains: storylet_domains(storylet),
+            category: storylet.outcomes.heat_category.clone(),
+        });
+    }
+
+    /// Heat spent on `day`.
+    pub fn heat_spent_on(&self, day: u32) -> f32 {
+        self.spends_on(day).map(|spend| spend.heat).sum()
+    }
+
+    /// Heat `domain` spent on `day`.
+    pub fn domain_heat_spent_on(&self, day: u32, domain: &str) -> f32 {
+        self.spends_on(day)
+            .filter(|spend| spend.domains.iter().any(|d| d == domain))
+            .map(|spend| spend.heat)
+            .sum()
+    }
+
+    /// Storylets of `category` fired in the `days` days up to and including `day`.
+    pub fn category_count(&self, category: &StoryletHeatCategory, day: u32, days: u32) -> u32 {
+        let count = self
+            .spends
+            .iter()
+            .filter(|spend| spend.day <= day && spend.day + days > day)
+            .filter(|spend| spend.category.as_ref() == Some(category))
+            .count();
+        u32::try_from(count).unwrap_or(u32::MAX)
+    }
+
+    /// Score multiplier (0.0..=1.0) for firing `storylet` at `tick`.
+    pub fn multiplier(&self, storylet: &Storylet, tick: SimTick) -> f32 {
+        let day = tick.days_elapsed();
+        let capped = match storylet.outcomes.heat_category {
+            Some(StoryletHeatCategory::CriticalArc) => {
+                self.category_count(&StoryletHeatCategory::CriticalArc, day, WEEK_DAYS)
+                    >= self.caps.critical_arcs_per_week
+            }
+            Some(StoryletHeatCategory::HighDrama) => {
+                self.category_count(&StoryletHeatCategory::HighDrama, day, 1)
+                    >= self.caps.high_drama_per_day
+            }
+            _ => false,
+        };
+        if capped {
+            return 0.0;
+        }
+
+        let heat = storylet.heat.max(0) as f32;
+        if heat <= 0.0 {
+            return 1.0;
+        }
+        let daily = fits(self.caps.daily_heat - self.heat_spent_on(day), heat);
+        let domain = storylet_domains(storylet)
+            .iter()
+            .map(|domain| {
+                fits(
+                    self.caps.domain_daily_heat - self.domain_heat_spent_on(day, domain),
+                    heat,
+                )
+            })
+            .fold(1.0, f32::min);
+        daily * domain
+    }
+
+    fn spends_on(&self, day: u32) -> impl Iterator<Item = &HeatSpend> {
+        self.spends.iter().filter(move |spend| spend.day == day)
+    }
+}
+
+/// Fraction of `heat` that fits in what's left of a budget.
+fn fits(remaining: f32, heat: f32) -> f32 {
+    (remaining / heat).clamp(0.0, 1.0)
+}
+
+impl EventDirector {
+    /// Heat spent over the last week and the caps it's held to.
+    pub fn pacing_budget(&self) -> &PacingBudget {
+        &self.pacing_budget
+    }
+
+    /// Hold drama to `caps`, keeping the heat already spent.
+    pub fn set_pacing_caps(&mut self, caps: PacingCaps) {
+        self.pacing_budget.caps = caps;
+    }
+
+    /// Replace the pacing budget with one taken from a save.
+    pub fn restore_pacing_budget(&mut self, budget: PacingBudget) {
+        self.pacing_budget = budget;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::StoryletOutcomeSet;
+
+    fn storylet(id: &str, heat: i32, category: Option<StoryletHeatCategory>) -> Storylet {
+        Storylet {
+            id: id.to_string(),
+            heat,
+            outcomes: StoryletOutcomeSet {
+                heat_category: category,
+                ..Default::default()
+            },
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn one_critical_arc_per_week() {
+        let mut budget = PacingBudget::default();
+        let arc = storylet("pacing_arc", 10, Some(StoryletHeatCategory::CriticalArc));
+        assert!((budget.multiplier(&arc, SimTick(0)) - 1.0).abs() < f32::EPSILON);
+
+        budget.record(&arc, SimTick(0));
+        assert!(budget.multiplier(&arc, SimTick(6 * 24)).abs() < f32::EPSILON);
+        assert!((budget.multiplier(&arc, SimTick(7 * 24)) - 1.0).abs() < f32::EPSILON);
+    }
+
+    #[test]
+    fn daily_heat_scales_down_what_no_longer_fits() {
+        let mut budget = PacingBudget::new(PacingCaps {
+            daily_heat: 100.0,
+            domain_daily_heat: 100.0,
+            ..Default::default()
+        });
+        budget.record(&storylet("pacing_fight", 80, None), SimTick(1));
+
+        let hot = storylet("pacing_fight_again", 40, None);
+        assert!((budget.multiplier(&hot, SimTick(2)) - 0.5).abs() < 1e-6);
+        let tea = storylet("pacing_tea", 0, None);
+        assert!((budget.multiplier(&tea, SimTick(2)) - 1.0).abs() < f32::EPSILON);
+        // Tomorrow the budget is fresh
+        assert!((budget.multiplier(&hot, SimTick(25)) - 1.0).abs() < f32::EPSILON);
+    }
+}
@@ -23,7 +23,7 @@
 //! ```
 
 use crate::config::DirectorConfig;
-use crate::eligibility::EligibilityContext;
+use crate::eligibility::{age_appropriate, content_allowed, EligibilityContext};
 use crate::pacing;
 use crate::state::DirectorState;
 use crate::storylet_source::StoryletSource;
@@ -218,15 +218,33 @@ impl<'a, S: StoryletSource> EligibilityPipeline<'a, S> {
         self.storylets.candidates_for_life_stage(life_stage)
     }
 
-    /// Apply index-level prefiltering (tags, domains).
+    /// Apply index-level prefiltering (tags, domains, content preferences,
+    /// age gating).
     fn apply_index_prefilter(
         &self,
         candidates: &[StoryletKey],
-        _ctx: &EligibilityContext<'_>,
+        ctx: &EligibilityContext<'_>,
         params: &IndexPrefilterParams,
     ) -> Vec<StoryletKey> {
         let mut filtered = candidates.to_vec();
 
+        // Drop domains/tags the player has switched off
+        if !ctx.world.content_preferences.is_empty() {
+            filtered.retain(|&key| {
+                self.storylets
+                    .get_storylet_by_key(key)
+                    .is_some_and(|storylet| content_allowed(storylet, ctx.world))
+            });
+        }
+
+        // Mature content never reaches a player below its minimum stage, even
+        // when a life stage override widened the candidate set
+        filtered.retain(|&key| {
+            self.storylets
+                .get_storylet_by_key(key)
+                .is_some_and(|storylet| age_appropriate(storylet, ctx.world))
+        });
+
         // Filter by required tags (all must be present)
         if !params.required_tags.is_empty() {
             filtered.retain(|&key| {
@@ -636,6 +654,25 @@ mod tests {
         assert_eq!(candidates.after_index_prefilter.len(), 1);
     }
 
+    #[test]
+    fn test_content_preferences_filtering() {
+        let library = create_test_library();
+        let state = DirectorState::new();
+        let config = DirectorConfig::for_testing();
+
+        let mut world = create_adult_world();
+        world.content_preferences.block_domain("Romance");
+        world.content_preferences.block_tag("routine");
+        let memory = MemorySystem::new();
+        let ctx = create_test_context(&world, &memory, SimTick::new(0));
+
+        let pipeline = EligibilityPipeline::new(&library, &state, &config);
+        let candidates = pipeline.run(&ctx);
+
+        // Only the conflict storylet survives (romance domain + routine tag blocked)
+        assert_eq!(candidates.after_index_prefilter, vec![StoryletKey(2)]);
+    }
+
     #[test]
     fn test_cooldown_filtering() {
         let library = create_test_library();
@@ -0,0 +1,629 @@
+//! Boolean prerequisite expressions for legacy storylets.
+//!
+//! [`StoryletPrerequisites`](crate::StoryletPrerequisites) fields are all
+//! AND'ed together. For anything else, authors write an `expression` string
+//! that combines conditions with `AND`, `OR`, `NOT` and parentheses:
+//!
+//! ```json
+//! "prerequisites": {
+//!     "expression": "(trust >= Trusted AND has_memory(betrayal)) OR heat > 70"
+//! }
+//! ```
+//!
+//! Conditions:
+//!
+//! - `<stat> <op> <number>`: a player stat by name (`mood`, `health`, ...)
+//! - `heat <op> <number>`: narrative heat (0-100)
+//! - `<axis> <op> <number | band>`: the player's relationship with the target
+//!   (first role) on `affection`, `trust`, `attraction`, `familiarity` or
+//!   `resentment`, by value or by band name (`Trusted`, `Close`, ...)
+//! - `has_memory(tag)`: the target's journal (the player's when no one is
+//!   cast) or a world memory record they hold (gossip they heard, ...) has
+//!   this tag
+//! - `has_flag(name)`: a world flag is set
+//! - `fired(storylet_id)`: the storylet has fired at least once
+//! - `true`, `false`
+//!
+//! Operators are `>=`, `<=`, `>`, `<`, `==` (or `=`) and `!=`. Keywords are
+//! case-insensitive, and `&&`, `||` and `!` work too. Arguments may be quoted
+//! (`has_flag("met:alex")`). Expressions are parsed when the storylet is
+//! loaded, so typos fail there rather than at selection time. Relationship
+//! conditions are false when the storylet has no target.
+
+use std::fmt;
+
+use serde::{Deserialize, Serialize};
+use syn_core::relationship_model::{RelationshipAxis, RelationshipVector};
+use syn_core::{NpcId, StatKind, WorldState};
+use syn_memory::MemorySystem;
+
+use crate::prereqs::{band_rank_for, band_rank_from_name};
+
+/// A parsed prerequisite expression. (De)serializes as its source string.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(try_from = "String", into = "String")]
+pub enum PrereqExpr {
+    /// Every operand holds.
+    And(Vec<PrereqExpr>),
+    /// At least one operand holds.
+    Or(Vec<PrereqExpr>),
+    Not(Box<PrereqExpr>),
+    Compare {
+        subject: ExprSubject,
+        op: CompareOp,
+        value: CompareValue,
+    },
+    HasMemory(String),
+    HasFlag(String),
+    Fired(String),
+    Literal(bool),
+}
+
+/// The left-hand side of a comparison.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ExprSubject {
+    Stat(StatKind),
+    Heat,
+    /// Player → target relationship axis.
+    Relationship(RelationshipAxis),
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum CompareOp {
+    Lt,
+    Le,
+    Gt,
+    Ge,
+    Eq,
+    Ne,
+}
+
+/// The right-hand side of a comparison.
+#[derive(Debug, Clone, PartialEq)]
+pub enum CompareValue {
+    Number(f32),
+    /// A relationship band, as written and by rank.
+    Band { name: String, rank: u8 },
+}
+
+/// Why an expression failed to parse.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct PrereqExprError {
+    /// Byte offset into the expression.
+    pub position: usize,
+    pub message: String,
+}
+
+impl fmt::Display for PrereqExprError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{} (at offset {})", self.message, self.position)
+    }
+}
+
+impl std::error::Error for PrereqExprError {}
+
+/// What an expression is evaluated against.
+#[derive(Debug, Clone, Copy)]
+pub struct PrereqEvalContext<'a> {
+    pub world: &'a WorldState,
+    pub memory: &'a MemorySystem,
+    /// The storylet's target (first role), if cast.
+    pub target: Option<NpcId>,
+}
+
+impl PrereqExpr {
+    /// Parse an expression.
+    pub fn parse(source: &str) -> Result<Self, PrereqExprError> {
+        let tokens = tokenize(source)?;
+        let mut parser = Parser {
+            tokens,
+            pos: 0,
+            end: source.len(),
+        };
+        let expr = parser.parse_or()?;
+        match parser.peek() {
+            None => Ok(expr),
+            Some((position, token)) => Err(PrereqExprError {
+                position: *position,
+                message: format!("unexpected {}", token.describe()),
+            }),
+        }
+    }
+
+    /// Whether the expression holds.
+    pub fn evaluate(&self, ctx: &PrereqEvalContext<'_>) -> bool {
+        match self {
+            PrereqExpr::And(items) => items.iter().all(|e| e.evaluate(ctx)),
+            PrereqExpr::Or(items) => items.iter().any(|e| e.evaluate(ctx)),
+            PrereqExpr::Not(inner) => !inner.evaluate(ctx),
+            PrereqExpr::Compare { subject, op, value } => evaluate_compare(ctx, *subject, *op, value),
+            PrereqExpr::HasMemory(tag) => {
+                let owner = ctx.target.unwrap_or(ctx.world.player_id);
+                ctx.memory
+                    .journals
+                    .get(&owner)
+                    .is_some_and(|journal| !journal.memories_with_tag(tag).is_empty())
+                    || ctx
+                        .world
+                        .memory_entries
+                        .iter()
+                        .any(|entry| entry.npc_id == owner && entry.tags.iter().any(|t| t == tag))
+            }
+            PrereqExpr::HasFlag(flag) => ctx.world.world_flags.has_any(flag),
+            PrereqExpr::Fired(id) => ctx.world.storylet_usage.count(id) > 0,
+            PrereqExpr::Literal(value) => *value,
+        }
+    }
+}
+
+fn evaluate_compare(
+    ctx: &PrereqEvalContext<'_>,
+    subject: ExprSubject,
+    op: CompareOp,
+    value: &CompareValue,
+) -> bool {
+    let relationship = |axis| {
+        let target = ctx.target?;
+        let vector = ctx
+            .world
+            .relationships
+            .get(&(ctx.world.player_id, target))
+            .map(|rel| RelationshipVector {
+                affection: rel.affection,
+                trust: rel.trust,
+                attraction: rel.attraction,
+                familiarity: rel.familiarity,
+                resentment: rel.resentment,
+            })
+            .unwrap_or_default();
+        Some((vector.get(axis), band_rank_for(axis, &vector)))
+    };
+    match (subject, value) {
+        (ExprSubject::Stat(kind), CompareValue::Number(rhs)) => {
+            op.holds(ctx.world.player_stats.get(kind), *rhs)
+        }
+        (ExprSubject::Heat, CompareValue::Number(rhs)) => {
+            op.holds(ctx.world.narrative_heat.value(), *rhs)
+        }
+        (ExprSubject::Relationship(axis), CompareValue::Number(rhs)) => {
+            relationship(axis).is_some_and(|(lhs, _)| op.holds(lhs, *rhs))
+        }
+        (ExprSubject::Relationship(axis), CompareValue::Band { rank, .. }) => relationship(axis)
+            .is_some_and(|(_, lhs)| op.holds(f32::from(lhs), f32::from(*rank))),
+        // The parser only accepts bands for relationship axes
+        (_, CompareValue::Band { .. }) => false,
+    }
+}
+
+impl CompareOp {
+    fn holds(self, lhs: f32, rhs: f32) -> bool {
+        match self {
+            CompareOp::Lt => lhs < rhs,
+            CompareOp::Le => lhs <= rhs,
+            CompareOp::Gt => lhs > rhs,
+            CompareOp::Ge => lhs >= rhs,
+            CompareOp::Eq => (lhs - rhs).abs() < f32::EPSILON,
+            CompareOp::Ne => (lhs - rhs).abs() >= f32::EPSILON,
+        }
+    }
+
+    fn symbol(self) -> &'static str {
+        match self {
+            CompareOp::Lt => "<",
+            CompareOp::Le => "<=",
+            CompareOp::Gt => ">",
+            CompareOp::Ge => ">=",
+            CompareOp::Eq => "==",
+            CompareOp::Ne => "!=",
+        }
+    }
+}
+
+impl ExprSubject {
+    fn from_name(name: &str) -> Option<Self> {
+        let axis = match name.to_ascii_lowercase().as_str() {
+            "heat" => return Some(ExprSubject::Heat),
+            "affection" => RelationshipAxis::Affection,
+            "trust" => RelationshipAxis::Trust,
+            "attraction" => RelationshipAxis::Attraction,
+            "familiarity" => RelationshipAxis::Familiarity,
+            "resentment" => RelationshipAxis::Resentment,
+            _ => return StatKind::from_name(name).map(ExprSubject::Stat),
+        };
+        Some(ExprSubject::Relationship(axis))
+    }
+}
+
+impl fmt::Display for ExprSubject {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ExprSubject::Stat(kind) => write!(f, "{}", format!("{:?}", kind).to_ascii_lowercase()),
+            ExprSubject::Heat => f.write_str("heat"),
+            ExprSubject::Relationship(axis) => {
+                write!(f, "{}", format!("{:?}", axis).to_ascii_lowercase())
+            }
+        }
+    }
+}
+
+/// Band names accepted for each axis (lowercase).
+fn band_names(axis: RelationshipAxis) -> &'static [&'static str] {
+    match axis {
+        RelationshipAxis::Affection | RelationshipAxis::Familiarity => {
+            &["stranger", "acquaintance", "friendly", "close", "devoted"]
+        }
+        RelationshipAxis::Trust => &["unknown", "wary", "neutral", "trusted", "deeptrust", "deep_trust"],
+        RelationshipAxis::Attraction => &["none", "curious", "interested", "strong", "intense"],
+        RelationshipAxis::Resentment => &["none", "irritated", "resentful", "hostile", "vindictive"],
+    }
+}
+
+impl fmt::Display for PrereqExpr {
+    /// The canonical source form, which parses back to the same expression.
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fn operand(f: &mut fmt::Formatter<'_>, expr: &PrereqExpr) -> fmt::Result {
+            match expr {
+                PrereqExpr::And(_) | PrereqExpr::Or(_) => write!(f, "({})", expr),
+                _ => write!(f, "{}", expr),
+            }
+        }
+        fn joined(f: &mut fmt::Formatter<'_>, items: &[PrereqExpr], sep: &str) -> fmt::Result {
+            for (i, item) in items.iter().enumerate() {
+                if i > 0 {
+                    f.write_str(sep)?;
+                }
+                operand(f, item)?;
+            }
+            Ok(())
+        }
+        fn call(f: &mut fmt::Formatter<'_>, name: &str, arg: &str) -> fmt::Result {
+            let bare = arg.chars().next().is_some_and(is_ident_start)
+                && arg.chars().all(is_ident_char)
+                && keyword(arg).is_none();
+            if bare {
+                write!(f, "{}({})", name, arg)
+            } else {
+                write!(f, "{}(\"{}\")", name, arg)
+            }
+        }
+        match self {
+            PrereqExpr::And(items) => joined(f, items, " AND "),
+            PrereqExpr::Or(items) => joined(f, items, " OR "),
+            PrereqExpr::Not(inner) => {
+                f.write_str("NOT ")?;
+                operand(f, inner)
+            }
+            PrereqExpr::Compare { subject, op, value } => {
+                write!(f, "{} {} ", subject, op.symbol())?;
+                match value {
+                    CompareValue::Number(n) => write!(f, "{}", n),
+                    CompareValue::Band { name, .. } => f.write_str(name),
+                }
+            }
+            PrereqExpr::HasMemory(tag) => call(f, "has_memory", tag),
+            PrereqExpr::HasFlag(flag) => call(f, "has_flag", flag),
+            PrereqExpr::Fired(id) => call(f, "fired", id),
+            PrereqExpr::Literal(value) => write!(f, "{}", value),
+        }
+    }
+}
+
+impl TryFrom<String> for PrereqExpr {
+    type Error = PrereqExprError;
+
+    fn try_from(source: String) -> Result<Self, Self::Error> {
+        PrereqExpr::parse(&source)
+    }
+}
+
+impl From<PrereqExpr> for String {
+    fn from(expr: PrereqExpr) -> Self {
+        expr.to_string()
+    }
+}
+
+impl std::str::FromStr for PrereqExpr {
+    type Err = PrereqExprError;
+
+    fn from_str(source: &str) -> Result<Self, Self::Err> {
+        PrereqExpr::parse(source)
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+enum Token {
+    LParen,
+    RParen,
+    And,
+    Or,
+    Not,
+    Op(CompareOp),
+    Ident(String),
+    Str(String),
+    Number(f32),
+}
+
+impl Token {
+    fn describe(&self) -> String {
+        match self {
+            Token::LParen => "'('".to_string(),
+            Token::RParen => "')'".to_string(),
+            Token::And => "AND".to_string(),
+            Token::Or => "OR".to_string(),
+            Token::Not => "NOT".to_string(),
+            Token::Op(op) => format!("'{}'", op.symbol()),
+            Token::Ident(name) => format!("'{}'", name),
+            Token::Str(s) => format!("\"{}\"", s),
+            Token::Number(n) => format!("{}", n),
+        }
+    }
+}
+
+fn is_ident_start(c: char) -> bool {
+    c.is_alphabetic() || c == '_'
+}
+
+fn is_ident_char(c: char) -> bool {
+    c.is_alphanumeric() || matches!(c, '_' | '.' | ':' | '-')
+}
+
+fn keyword(word: &str) -> Option<Token> {
+    match word.to_ascii_lowercase().as_str() {
+        "and" => Some(Token::And),
+        "or" => Some(Token::Or),
+        "not" => Some(Token::Not),
+        _ => None,
+    }
+}
+
+fn tokenize(source: &str) -> Result<Vec<(usize, Token)>, PrereqExprError> {
+    let chars: Vec<(usize, char)> = source.char_indices().collect();
+    let next_is = |i: usize, expected: char| chars.get(i + 1).is_some_and(|&(_, c)| c == expected);
+    let error = |position: usize, message: String| PrereqExprError { position, message };
+    let mut tokens = Vec::new();
+    let mut i = 0;
+    while let Some(&(pos, c)) = chars.get(i) {
+        let (token, width) = match c {
+            c if c.is_whitespace() => {
+                i += 1;
+                continue;
+            }
+            '(' => (Token::LParen, 1),
+            ')' => (Token::RParen, 1),
+            '&' if next_is(i, '&') => (Token::And, 2),
+            '|' if next_is(i, '|') => (Token::Or, 2),
+            '!' if next_is(i, '=') => (Token::Op(CompareOp::Ne), 2),
+            '!' => (Token::Not, 1),
+            '>' if next_is(i, '=') => (Token::Op(CompareOp::Ge), 2),
+            '>' => (Token::Op(CompareOp::Gt), 1),
+            '<' if next_is(i, '=') => (Token::Op(CompareOp::Le), 2),
+            '<' => (Token::Op(CompareOp::Lt), 1),
+            '=' if next_is(i, '=') => (Token::Op(CompareOp::Eq), 2),
+            '=' => (Token::Op(CompareOp::Eq), 1),
+            '"' => {
+                let close = chars[i + 1..]
+                    .iter()
+                    .position(|&(_, c)| c == '"')
+                    .ok_or_else(|| error(pos, "unterminated string".to_string()))?;
+                let text: String = chars[i + 1..i + 1 + close].iter().map(|&(_, c)| c).collect();
+                (Token::Str(text), close + 2)
+            }
+            c if c.is_ascii_digit() || c == '-' || c == '.' => {
+                let len = 1 + chars[i + 1..]
+                    .iter()
+                    .take_while(|&&(_, c)| c.is_ascii_digit() || c == '.')
+                    .count();
+                let text: String = chars[i..i + len].iter().map(|&(_, c)| c).collect();
+                let number = text
+                    .parse::<f32>()
+                    .map_err(|_| error(pos, format!("invalid number '{}'", text)))?;
+                (Token::Number(number), len)
+            }
+            c if is_ident_start(c) => {
+                let len = 1 + chars[i + 1..]
+                    .iter()
+                    .take_while(|&&(_, c)| is_ident_char(c))
+                    .count();
+                let word: String = chars[i..i + len].iter().map(|&(_, c)| c).collect();
+                (keyword(&word).unwrap_or(Token::Ident(word)), len)
+            }
+            other => return Err(error(pos, format!("unexpected character '{}'", other))),
+        };
+        tokens.push((pos, token));
+        i += width;
+    }
+    Ok(tokens)
+}
+
+struct Parser {
+    tokens: Vec<(usize, Token)>,
+    pos: usize,
+    /// Offset reported for errors at the end of input.
+    end: usize,
+}
+
+impl Parser {
+    fn peek(&self) -> Option<&(usize, Token)> {
+        self.tokens.get(self.pos)
+    }
+
+    fn next(&mut self) -> Result<(usize, Token), PrereqExprError> {
+        let token = self.tokens.get(self.pos).cloned().ok_or_else(|| PrereqExprError {
+            position: self.end,
+            message: "unexpected end of expression".to_string(),
+        })?;
+        self.pos += 1;
+        Ok(token)
+    }
+
+    fn eat(&mut self, expected: &Token) -> bool {
+        if self.peek().is_some_and(|(_, token)| token == expected) {
+            self.pos += 1;
+            true
+        } else {
+            false
+        }
+    }
+
+    fn expect(&mut self, expected: Token) -> Result<(), PrereqExprError> {
+        let (position, token) = self.next()?;
+        if token == expected {
+            Ok(())
+        } else {
+            Err(PrereqExprError {
+                position,
+                message: format!("expected {}, found {}", expected.describe(), token.describe()),
+            })
+        }
+    }
+
+    fn parse_or(&mut self) -> Result<PrereqExpr, PrereqExprError> {
+        let mut items = vec![self.parse_and()?];
+        while self.eat(&Token::Or) {
+            items.push(self.parse_and()?);
+        }
+        Ok(if items.len() == 1 {
+            items.remove(0)
+        } else {
+            PrereqExpr::Or(items)
+        })
+    }
+
+    fn parse_and(&mut self) -> Result<PrereqExpr, PrereqExprError> {
+        let mut items = vec![self.parse_not()?];
+        while self.eat(&Token::And) {
+            items.push(self.parse_not()?);
+        }
+        Ok(if items.len() == 1 {
+            items.remove(0)
+        } else {
+            PrereqExpr::And(items)
+        })
+    }
+
+    fn parse_not(&mut self) -> Result<PrereqExpr, PrereqExprError> {
+        if self.eat(&Token::Not) {
+            Ok(PrereqExpr::Not(Box::new(self.parse_not()?)))
+        } else {
+            self.parse_atom()
+        }
+    }
+
+    fn parse_atom(&mut self) -> Result<PrereqExpr, PrereqExprError> {
+        let (position, token) = self.next()?;
+        let name = match token {
+            Token::LParen => {
+                let expr = self.parse_or()?;
+                self.expect(Token::RParen)?;
+                return Ok(expr);
+            }
+            Token::Ident(name) => name,
+            other => {
+                return Err(PrereqExprError {
+                    position,
+                    message: format!("expected a condition, found {}", other.describe()),
+                })
+            }
+        };
+        let lowered = name.to_ascii_lowercase();
+        match lowered.as_str() {
+            "true" => return Ok(PrereqExpr::Literal(true)),
+            "false" => return Ok(PrereqExpr::Literal(false)),
+            _ => {}
+        }
+        if self.eat(&Token::LParen) {
+            let (arg_position, arg) = self.next()?;
+            let arg = match arg {
+                Token::Ident(arg) | Token::Str(arg) => arg,
+                other => {
+                    return Err(PrereqExprError {
+                        position: arg_position,
+                        message: format!("expected an argument, found {}", other.describe()),
+                    })
+                }
+            };
+            self.expect(Token::RParen)?;
+            return match lowered.as_str() {
+                "has_memory" => Ok(PrereqExpr::HasMemory(arg)),
+                "has_flag" => Ok(PrereqExpr::HasFlag(arg)),
+                "fired" => Ok(PrereqExpr::Fired(arg)),
+                _ => Err(PrereqExprError {
+                    position,
+                    message: format!("unknown function '{}'", name),
+                }),
+            };
+        }
+
+        let subject = ExprSubject::from_name(&name).ok_or_else(|| PrereqExprError {
+            position,
+            message: format!("unknown stat or relationship axis '{}'", name),
+        })?;
+        let (op_position, op) = self.next()?;
+        let Token::Op(op) = op else {
+            return Err(PrereqExprError {
+                position: op_position,
+                message: format!("expected a comparison after '{}', found {}", name, op.describe()),
+            });
+        };
+        let (value_position, value) = self.next()?;
+        let value = match (subject, value) {
+            (_, Token::Number(n)) => CompareValue::Number(n),
+            (ExprSubject::Relationship(axis), Token::Ident(band)) => {
+                let known = band_names(axis).contains(&band.to_ascii_lowercase().as_str());
+                let rank = known
+                    .then(|| band_rank_from_name(axis, &band))
+                    .flatten()
+                    .ok_or_else(|| PrereqExprError {
+                        position: value_position,
+                        message: format!("unknown {} band '{}'", subject, band),
+                    })?;
+                CompareValue::Band { name: band, rank }
+            }
+            (_, other) => {
+                return Err(PrereqExprError {
+                    position: value_position,
+                    message: format!("expected a number for '{}', found {}", name, other.describe()),
+                })
+            }
+        };
+        Ok(PrereqExpr::Compare { subject, op, value })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parses_precedence_and_round_trips() {
+        let expr = PrereqExpr::parse("(trust >= Trusted AND has_memory(betrayal)) OR heat > 70").unwrap();
+        let PrereqExpr::Or(items) = &expr else {
+            panic!("expected OR at the top: {:?}", expr);
+        };
+        assert!(matches!(&items[0], PrereqExpr::And(inner) if inner.len() == 2));
+
+        // AND binds tighter than OR, NOT tighter than both
+        let loose = PrereqExpr::parse("not fired(a) && mood < -2 || has_flag(\"met:alex\")").unwrap();
+        assert_eq!(
+            loose.to_string(),
+            "(NOT fired(a) AND mood < -2) OR has_flag(met:alex)"
+        );
+        assert_eq!(PrereqExpr::parse(&loose.to_string()).unwrap(), loose);
+    }
+
+    #[test]
+    fn rejects_unknown_names_and_malformed_input() {
+        for (source, position) in [
+            ("charm_level > 3", 0),
+            ("trust >= BestFriends", 9),
+            ("heat > Trusted", 7),
+            ("mood >", 6),
+            ("has_memories(x)", 0),
+            ("(heat > 1", 9),
+            ("heat > 1 heat", 9),
+        ] {
+            let err = PrereqExpr::parse(source).unwrap_err();
+            assert_eq!(err.position, position, "{}: {}", source, err);
+        }
+    }
+}
@@ -0,0 +1,964 @@
+//! Storylet prerequisites for the legacy [`EventDirector`](crate::EventDirector).
+//!
+//! The prerequisite types authored on a [`Storylet`](crate::Storylet) and the
+//! checks that gate eligibility: stat, personality, district, memory echo and
+//! global conditions, relationship bands, life stage, content preferences,
+//! age gates, digital legacy, time/location, declared statuses, trigger
+//! context, storylet dependencies and authored expressions.
+
+use std::collections::HashMap;
+
+use serde::{Deserialize, Serialize};
+use syn_core::{
+    LifeStage, NpcId, RelationshipState, SimTick, StatKind, StoryletUsageState, WorldState,
+};
+use syn_core::npc::NpcActivityKind;
+use syn_core::relationship_model::{
+    AffectionBand, AttractionBand, RelationshipAxis as ModelRelationshipAxis, RelationshipVector,
+    ResentmentBand, TrustBand,
+};
+use syn_core::time::{CalendarDay, DayPhase, Season};
+use syn_memory::MemorySystem;
+use syn_sim::{NpcRegistry, SimState};
+
+use crate::prereq_expr::PrereqExpr;
+use crate::{
+    primary_npc, resolve_actor_ref_to_npc, scene_districts, EventContext, RomanceReciprocity,
+    Storylet,
+};
+
+pub type StoryletPrereqs = StoryletPrerequisites;
+
+/// Relationship-based prerequisite (additive, non-breaking).
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct RelationshipPrereq {
+    /// Which actor owns the relationship. None defaults to the player.
+    #[serde(default)]
+    pub actor_id: Option<u64>,
+    /// Target NPC the prereq references.
+    pub target_id: u64,
+    /// Relationship axis to inspect.
+    pub axis: ModelRelationshipAxis,
+    /// Optional numeric bounds for the axis value.
+    #[serde(default)]
+    pub min_value: Option<f32>,
+    #[serde(default)]
+    pub max_value: Option<f32>,
+    /// Optional band-based gating (fuzzy thresholds).
+    #[serde(default)]
+    pub min_band: Option<String>,
+    #[serde(default)]
+    pub max_band: Option<String>,
+}
+
+/// Digital legacy prerequisite for PostLife storylets.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct DigitalLegacyPrereq {
+    /// Only relevant in Digital/PostLife; if true and stage != Digital, prereq fails.
+    #[serde(default)]
+    pub require_post_life: bool,
+
+    /// Optional bounds on legacy components (-1.0 .. 1.0)
+    #[serde(default)]
+    pub min_compassion_vs_cruelty: Option<f32>,
+    #[serde(default)]
+    pub max_compassion_vs_cruelty: Option<f32>,
+
+    #[serde(default)]
+    pub min_ambition_vs_comfort: Option<f32>,
+    #[serde(default)]
+    pub max_ambition_vs_comfort: Option<f32>,
+
+    #[serde(default)]
+    pub min_connection_vs_isolation: Option<f32>,
+    #[serde(default)]
+    pub max_connection_vs_isolation: Option<f32>,
+
+    #[serde(default)]
+    pub min_stability_vs_chaos: Option<f32>,
+    #[serde(default)]
+    pub max_stability_vs_chaos: Option<f32>,
+
+    #[serde(default)]
+    pub min_light_vs_shadow: Option<f32>,
+    #[serde(default)]
+    pub max_light_vs_shadow: Option<f32>,
+}
+
+/// Conditions that must be met for a storylet to be eligible.
+#[derive(Debug, Clone, Serialize, Deserialize, Default)]
+pub struct StoryletPrerequisites {
+    /// Player stats that must each lie in range.
+    #[serde(default)]
+    pub stat_conditions: Vec<StatCondition>,
+    /// Player traits that must each lie in range.
+    #[serde(default)]
+    pub personality_conditions: Vec<PersonalityCondition>,
+    #[serde(default)]
+    pub relationship_conditions: Vec<RelationshipThreshold>,
+    /// Districts the player must be in one of.
+    #[serde(default)]
+    pub district_conditions: Vec<DistrictCondition>,
+    /// Memory tags the player must all remember.
+    #[serde(default)]
+    pub memory_echo_conditions: Vec<MemoryEchoFlag>,
+    /// World flags that must each be set (or unset).
+    #[serde(default)]
+    pub global_conditions: Vec<GlobalWorldStateFlag>,
+    #[serde(default)]
+    pub life_stage: Option<LifeStage>,
+
+    pub min_relationship_affection: Option<f32>,
+    pub min_relationship_resentment: Option<f32>,
+    #[serde(default)]
+    pub stat_ranges: HashMap<String, (f32, f32)>, // legacy range-based checks
+    pub life_stages: Vec<String>,                     // ["Teen", "Adult", "Elder"]
+    pub tags: Vec<String>,                            // must have these tags
+    pub relationship_states: Vec<RelationshipState>, // Only fire if relationship is in one of these states
+    // Memory prerequisites for event echoes
+    pub memory_tags_required: Vec<String>, // NPC must have memory with at least one of these tags
+    pub memory_tags_forbidden: Vec<String>, // NPC must NOT have memory with these tags (conflict avoidance)
+    pub memory_recency_ticks: Option<u64>, // If specified, memory must be within N ticks (default: 7 days = 168 ticks)
+    /// Optional relationship-based prerequisites (additive).
+    #[serde(default)]
+    pub relationship_prereqs: Vec<RelationshipPrereq>,
+    /// Optional allowed life stages for this storylet.
+    #[serde(default)]
+    pub allowed_life_stages: Vec<LifeStage>,
+    /// Optional digital legacy prerequisite for PostLife storylets.
+    #[serde(default)]
+    pub digital_legacy_prereq: Option<DigitalLegacyPrereq>,
+
+    /// Optional time/location gating aligned with NPC schedule.
+    #[serde(default)]
+    pub time_and_location: Option<TimeAndLocationPrereqs>,
+
+    /// Skill requirements for this storylet.
+    #[serde(default)]
+    pub skill_requirements: Vec<SkillRequirement>,
+
+    /// Household gating: `Some(true)` requires the player to live with the
+    /// target (first role), `Some(false)` requires them not to.
+    #[serde(default)]
+    pub lives_with_target: Option<bool>,
+
+    /// Support gating: only eligible while the target's (first role) mood is
+    /// at or below this value, e.g. after a crisis spread through their circle.
+    #[serde(default)]
+    pub max_target_mood: Option<f32>,
+
+    /// Reciprocity thresholds for romance-tagged storylets; the defaults
+    /// apply when unset.
+    #[serde(default)]
+    pub romance_reciprocity: Option<RomanceReciprocity>,
+
+    /// Hard dependencies: storylet ids that must all have fired before this
+    /// one is eligible (e.g. "second_date" depends on "first_date").
+    #[serde(default)]
+    pub depends_on: Vec<String>,
+
+    /// Soft dependencies: storylet ids that boost this one's score once any
+    /// of them has fired; never required.
+    #[serde(default)]
+    pub prefers_after: Vec<String>,
+
+    /// Declared status gating: the player and the target (first role) must
+    /// have declared one of these (e.g. only while they say they're dating).
+    #[serde(default)]
+    pub declared_statuses: Vec<syn_core::DeclaredStatus>,
+
+    /// Boolean expression checked on top of the fields above, e.g.
+    /// `"(trust >= Trusted AND has_memory(betrayal)) OR heat > 70"`
+    /// (see [`prereq_expr`](crate::prereq_expr)).
+    #[serde(default)]
+    pub expression: Option<PrereqExpr>,
+}
+
+/// A player stat (`mood`, `health`, ...) within `min..=max`. Either bound
+/// may be left out to leave the range open on that side. Unknown stat names
+/// are skipped.
+#[derive(Debug, Clone, Serialize, Deserialize, Default)]
+pub struct StatCondition {
+    #[serde(default)]
+    pub kind: String,
+    #[serde(default)]
+    pub min: Option<f32>,
+    #[serde(default)]
+    pub max: Option<f32>,
+}
+
+/// A player trait (`empathy`, `charm`, ...) within `min..=max`, with the same
+/// optional bounds as [`StatCondition`]. Unknown traits, or a player without
+/// traits, are skipped.
+#[derive(Debug, Clone, Serialize, Deserialize, Default)]
+pub struct PersonalityCondition {
+    #[serde(default)]
+    pub trait_name: String,
+    #[serde(default)]
+    pub min: Option<f32>,
+    #[serde(default)]
+    pub max: Option<f32>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, Default)]
+pub struct RelationshipThreshold {
+    #[serde(default)]
+    pub axis: String,
+    #[serde(default)]
+    pub min: f32,
+    #[serde(default)]
+    pub max: f32,
+}
+
+/// A district the player may be in (matched case-insensitively). Passes when
+/// the player's district is unknown.
+#[derive(Debug, Clone, Serialize, Deserialize, Default)]
+pub struct DistrictCondition {
+    #[serde(default)]
+    pub district: String,
+}
+
+/// A tag on one of the player's memories.
+#[derive(Debug, Clone, Serialize, Deserialize, Default)]
+pub struct MemoryEchoFlag {
+    #[serde(default)]
+    pub tag: String,
+}
+
+/// A world flag that must be set (`value: true`) or unset (`value: false`).
+#[derive(Debug, Clone, Serialize, Deserialize, Default)]
+pub struct GlobalWorldStateFlag {
+    #[serde(default)]
+    pub flag: String,
+    #[serde(default)]
+    pub value: bool,
+}
+
+/// Skill requirement for storylet eligibility.
+#[derive(Debug, Clone, Serialize, Deserialize, Default)]
+pub struct SkillRequirement {
+    /// Skill ID that must be learned (e.g., "programming", "cooking")
+    #[serde(default)]
+    pub skill_id: String,
+    /// Minimum tier required (0=Novice, 1=Beginner, 2=Intermediate, 3=Advanced, 4=Expert, 5=Master)
+    #[serde(default)]
+    pub min_tier: Option<u8>,
+    /// Minimum XP required (alternative to tier)
+    #[serde(default)]
+    pub min_xp: Option<u32>,
+    /// Maximum tier allowed (for beginner-only content)
+    #[serde(default)]
+    pub max_tier: Option<u8>,
+}
+
+impl SkillRequirement {
+    /// Check if player skills meet this requirement.
+    pub fn is_met(&self, player_skills: &syn_core::skills::SkillState) -> bool {
+        use syn_core::skills::SkillId;
+        
+        let skill_id = SkillId::new(&self.skill_id);
+        let tier = player_skills.get_tier(&skill_id);
+        let xp = player_skills.get_xp(&skill_id);
+
+        // Check minimum tier
+        if let Some(min_tier) = self.min_tier {
+            if tier.as_level() < min_tier {
+                return false;
+            }
+        }
+
+        // Check minimum XP
+        if let Some(min_xp) = self.min_xp {
+            if xp < min_xp {
+                return false;
+            }
+        }
+
+        // Check maximum tier
+        if let Some(max_tier) = self.max_tier {
+            if tier.as_level() > max_tier {
+                return false;
+            }
+        }
+
+        true
+    }
+}
+
+/// Whether `value` lies within the bounds that are given. Unknown values
+/// (`None`) pass.
+fn in_authored_range(value: Option<f32>, min: Option<f32>, max: Option<f32>) -> bool {
+    match value {
+        Some(value) => min.is_none_or(|min| value >= min) && max.is_none_or(|max| value <= max),
+        None => true,
+    }
+}
+
+/// Where the condition lists read the player's state from.
+trait ConditionState {
+    fn stat(&self, name: &str) -> Option<f32>;
+    fn trait_value(&self, name: &str) -> Option<f32>;
+    fn district(&self) -> Option<&str>;
+    fn remembers(&self, tag: &str) -> bool;
+    fn flag(&self, name: &str) -> bool;
+}
+
+/// The live world, plus the memory system when the caller has one.
+struct LiveConditions<'a> {
+    world: &'a WorldState,
+    memory: Option<&'a MemorySystem>,
+}
+
+impl ConditionState for LiveConditions<'_> {
+    fn stat(&self, name: &str) -> Option<f32> {
+        StatKind::from_name(name).map(|kind| self.world.player_stats.get(kind))
+    }
+
+    fn trait_value(&self, name: &str) -> Option<f32> {
+        self.world
+            .npcs
+            .get(&self.world.player_id)
+            .and_then(|npc| npc.traits.get_by_name(name))
+    }
+
+    fn district(&self) -> Option<&str> {
+        let player = self.world.player_id;
+        self.world
+            .npcs
+            .get(&player)
+            .map(|npc| npc.district.as_str())
+            .or_else(|| {
+                self.world
+                    .npc_prototypes
+                    .get(&player)
+                    .and_then(|proto| proto.home_district.as_deref())
+            })
+            .filter(|district| !district.is_empty())
+    }
+
+    fn remembers(&self, tag: &str) -> bool {
+        let player = self.world.player_id;
+        let in_journal = self
+            .memory
+            .and_then(|memory| memory.journals.get(&player))
+            .is_some_and(|journal| !journal.memories_with_tag(tag).is_empty());
+        in_journal
+            || self
+                .world
+                .memory_entries
+                .iter()
+                .any(|entry| entry.npc_id == player && entry.tags.iter().any(|t| t == tag))
+    }
+
+    fn flag(&self, name: &str) -> bool {
+        self.world.world_flags.has_any(name)
+    }
+}
+
+impl ConditionState for EventContext {
+    fn stat(&self, name: &str) -> Option<f32> {
+        self.stats.get(&name.to_ascii_lowercase()).copied()
+    }
+
+    fn trait_value(&self, name: &str) -> Option<f32> {
+        self.traits.get(&name.to_ascii_lowercase()).copied()
+    }
+
+    fn district(&self) -> Option<&str> {
+        self.district_state.as_deref()
+    }
+
+    fn remembers(&self, tag: &str) -> bool {
+        self.memory_echoes.iter().any(|echo| echo == tag)
+    }
+
+    fn flag(&self, name: &str) -> bool {
+        self.global_state.get(name).copied().unwrap_or(false)
+    }
+}
+
+impl StoryletPrerequisites {
+    /// Whether the stat, personality, district, memory echo and global
+    /// conditions hold for the state captured in `ctx`.
+    pub fn passes(&self, ctx: &EventContext) -> bool {
+        self.failed_condition_in(ctx).is_none()
+    }
+
+    /// Whether the stat, personality, district, memory echo and global
+    /// conditions hold in `world`. Memory echoes check the player's journal
+    /// in `memory`, if given, and the world's memory records.
+    pub fn conditions_met(&self, world: &WorldState, memory: Option<&MemorySystem>) -> bool {
+        self.failed_condition(world, memory).is_none()
+    }
+
+    /// The first condition list that fails in `world` (`"stat_conditions"`,
+    /// `"personality_conditions"`, ...), if any.
+    pub fn failed_condition(
+        &self,
+        world: &WorldState,
+        memory: Option<&MemorySystem>,
+    ) -> Option<&'static str> {
+        self.failed_condition_in(&LiveConditions { world, memory })
+    }
+
+    fn failed_condition_in(&self, state: &impl ConditionState) -> Option<&'static str> {
+        let stats_hold = self
+            .stat_conditions
+            .iter()
+            .all(|c| in_authored_range(state.stat(&c.kind), c.min, c.max));
+        if !stats_hold {
+            return Some("stat_conditions");
+        }
+
+        let traits_hold = self
+            .personality_conditions
+            .iter()
+            .all(|c| in_authored_range(state.trait_value(&c.trait_name), c.min, c.max));
+        if !traits_hold {
+            return Some("personality_conditions");
+        }
+
+        if !self.district_conditions.is_empty() {
+            if let Some(current) = state.district() {
+                let here = self
+                    .district_conditions
+                    .iter()
+                    .any(|c| c.district.eq_ignore_ascii_case(current));
+                if !here {
+                    return Some("district_conditions");
+                }
+            }
+        }
+
+        if !self.memory_echo_conditions.iter().all(|c| state.remembers(&c.tag)) {
+            return Some("memory_echo_conditions");
+        }
+
+        if !self.global_conditions.iter().all(|c| state.flag(&c.flag) == c.value) {
+            return Some("global_conditions");
+        }
+
+        None
+    }
+}
+
+/// Optional time/location prerequisites for storylets.
+#[derive(Debug, Clone, Serialize, Deserialize, Default)]
+pub struct TimeAndLocationPrereqs {
+    /// Allowed day phases for this storylet (if empty: any).
+    #[serde(default)]
+    pub allowed_phases: Vec<DayPhase>,
+    /// Allowed seasons for this storylet (if empty: any).
+    #[serde(default)]
+    pub allowed_seasons: Vec<Season>,
+    /// Calendar days this storylet belongs to: holidays, the player's or the
+    /// primary NPC's birthday (if empty: any day).
+    #[serde(default)]
+    pub calendar_days: Vec<CalendarDay>,
+    /// Required NPC activity kinds for primary actor (if any).
+    #[serde(default)]
+    pub allowed_npc_activities: Vec<NpcActivityKind>,
+    /// District the scene must be set in: the player or the primary NPC
+    /// must be there (if None: anywhere).
+    #[serde(default)]
+    pub required_district: Option<String>,
+    /// Districts where this storylet is local color, with score multipliers.
+    #[serde(default)]
+    pub preferred_districts: Vec<DistrictAffinity>,
+}
+
+/// Score multiplier for a storylet when the scene is set in a given district.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct DistrictAffinity {
+    /// District name (matched case-insensitively).
+    pub district: String,
+    /// Score multiplier while the player or primary NPC is in this district.
+    #[serde(default = "default_district_affinity_multiplier")]
+    pub multiplier: f32,
+}
+
+pub(crate) fn default_district_affinity_multiplier() -> f32 {
+    1.5
+}
+
+/// Is this NPC available to share a scene with the player right now?
+/// Simple rule: offscreen / online-only may only work for certain storylets.
+pub(crate) fn npc_is_available_for_player(world: &WorldState, registry: &NpcRegistry, npc_id: NpcId) -> bool {
+    if let Some(inst) = registry.get(npc_id) {
+        match inst.current_activity {
+            NpcActivityKind::Offscreen => false,
+            NpcActivityKind::Nightlife => {
+                matches!(world.game_time.phase, DayPhase::Evening | DayPhase::Night)
+            }
+            NpcActivityKind::OnlineOnly => true,
+            _ => true,
+        }
+    } else {
+        // If NPC not instantiated yet, allow Director to spawn them
+        true
+    }
+}
+
+/// Check time and NPC location/activity prerequisites against current world/registry state.
+pub(crate) fn check_time_and_location_prereqs(
+    world: &WorldState,
+    registry: &NpcRegistry,
+    storylet: &Storylet,
+) -> bool {
+    let Some(pr) = &storylet.prerequisites.time_and_location else {
+        return true;
+    };
+
+    // Phase gating
+    if !pr.allowed_phases.is_empty() && !pr.allowed_phases.contains(&world.game_time.phase) {
+        return false;
+    }
+
+    // Calendar gating
+    if !pr.allowed_seasons.is_empty()
+        && !pr
+            .allowed_seasons
+            .contains(&syn_core::time::calendar_date(world).season())
+    {
+        return false;
+    }
+    if !pr.calendar_days.is_empty() {
+        let target = primary_npc(world, registry, storylet);
+        if !pr
+            .calendar_days
+            .iter()
+            .any(|day| syn_core::time::is_calendar_day(world, *day, target))
+        {
+            return false;
+        }
+    }
+
+    // District gating (player or primary NPC must be there)
+    if let Some(required) = &pr.required_district {
+        if !scene_districts(world, registry, storylet)
+            .iter()
+            .any(|d| d.eq_ignore_ascii_case(required))
+        {
+            return false;
+        }
+    }
+
+    // NPC activity gating (if we have an NPC actor)
+    if pr.allowed_npc_activities.is_empty() {
+        return true;
+    }
+
+    let Some(actors) = &storylet.outcomes.actors else {
+        return true;
+    };
+    if let Some(ref primary) = actors.primary {
+        if let Some(npc_id) = resolve_actor_ref_to_npc(world, registry, primary) {
+            if let Some(inst) = registry.get(npc_id) {
+                return pr.allowed_npc_activities.contains(&inst.current_activity);
+            }
+        }
+    }
+    true
+}
+
+/// Whether every `depends_on` storylet has fired at least once.
+pub fn storylet_dependencies_met(pre: &StoryletPrerequisites, usage: &StoryletUsageState) -> bool {
+    pre.depends_on.iter().all(|id| usage.count(id) > 0)
+}
+
+pub(crate) fn affection_band_rank(band: AffectionBand) -> u8 {
+    match band {
+        AffectionBand::Stranger => 0,
+        AffectionBand::Acquaintance => 1,
+        AffectionBand::Friendly => 2,
+        AffectionBand::Close => 3,
+        AffectionBand::Devoted => 4,
+    }
+}
+
+pub(crate) fn trust_band_rank(band: TrustBand) -> u8 {
+    match band {
+        TrustBand::Unknown => 0,
+        TrustBand::Wary => 1,
+        TrustBand::Neutral => 2,
+        TrustBand::Trusted => 3,
+        TrustBand::DeepTrust => 4,
+    }
+}
+
+pub(crate) fn attraction_band_rank(band: AttractionBand) -> u8 {
+    match band {
+        AttractionBand::None => 0,
+        AttractionBand::Curious => 1,
+        AttractionBand::Interested => 2,
+        AttractionBand::Strong => 3,
+        AttractionBand::Intense => 4,
+    }
+}
+
+pub(crate) fn resentment_band_rank(band: ResentmentBand) -> u8 {
+    match band {
+        ResentmentBand::None => 0,
+        ResentmentBand::Irritated => 1,
+        ResentmentBand::Resentful => 2,
+        ResentmentBand::Hostile => 3,
+        ResentmentBand::Vindictive => 4,
+    }
+}
+
+pub(crate) fn familiarity_band_rank(value: f32) -> u8 {
+    if value <= -5.0 {
+        0
+    } else if value < 1.0 {
+        1
+    } else if value < 5.0 {
+        2
+    } else if value < 8.0 {
+        3
+    } else {
+        4
+    }
+}
+
+pub(crate) fn band_rank_for(axis: ModelRelationshipAxis, rel: &RelationshipVector) -> u8 {
+    match axis {
+        ModelRelationshipAxis::Affection => affection_band_rank(rel.affection_band()),
+        ModelRelationshipAxis::Trust => trust_band_rank(rel.trust_band()),
+        ModelRelationshipAxis::Attraction => attraction_band_rank(rel.attraction_band()),
+        ModelRelationshipAxis::Familiarity => familiarity_band_rank(rel.familiarity),
+        ModelRelationshipAxis::Resentment => resentment_band_rank(rel.resentment_band()),
+    }
+}
+
+pub(crate) fn band_rank_from_name(axis: ModelRelationshipAxis, name: &str) -> Option<u8> {
+    let lowered = name.to_ascii_lowercase();
+    match axis {
+        ModelRelationshipAxis::Affection | ModelRelationshipAxis::Familiarity => {
+            Some(affection_band_rank(match lowered.as_str() {
+                "stranger" => AffectionBand::Stranger,
+                "acquaintance" => AffectionBand::Acquaintance,
+                "friendly" => AffectionBand::Friendly,
+                "close" => AffectionBand::Close,
+                "devoted" => AffectionBand::Devoted,
+                _ => AffectionBand::Stranger,
+            }))
+        }
+        ModelRelationshipAxis::Trust => Some(trust_band_rank(match lowered.as_str() {
+            "unknown" => TrustBand::Unknown,
+            "wary" => TrustBand::Wary,
+            "neutral" => TrustBand::Neutral,
+            "trusted" => TrustBand::Trusted,
+            "deeptrust" | "deep_trust" | "deep trust" => TrustBand::DeepTrust,
+            _ => TrustBand::Unknown,
+        })),
+        ModelRelationshipAxis::Attraction => Some(attraction_band_rank(match lowered.as_str() {
+            "none" => AttractionBand::None,
+            "curious" => AttractionBand::Curious,
+            "interested" => AttractionBand::Interested,
+            "strong" => AttractionBand::Strong,
+            "intense" => AttractionBand::Intense,
+            _ => AttractionBand::None,
+        })),
+        ModelRelationshipAxis::Resentment => Some(resentment_band_rank(match lowered.as_str() {
+            "none" => ResentmentBand::None,
+            "irritated" => ResentmentBand::Irritated,
+            "resentful" => ResentmentBand::Resentful,
+            "hostile" => ResentmentBand::Hostile,
+            "vindictive" => ResentmentBand::Vindictive,
+            _ => ResentmentBand::None,
+        })),
+    }
+}
+
+pub(crate) fn check_relationship_prereqs(
+    world: &WorldState,
+    prereqs: &[RelationshipPrereq],
+    default_actor_id: NpcId,
+) -> bool {
+    for prereq in prereqs {
+        let actor = NpcId(prereq.actor_id.unwrap_or(default_actor_id.0));
+        let target = NpcId(prereq.target_id);
+
+        let rel = match world.relationships.get(&(actor, target)) {
+            Some(r) => r,
+            None => return false,
+        };
+
+        let rel_vec = RelationshipVector {
+            affection: rel.affection,
+            trust: rel.trust,
+            attraction: rel.attraction,
+            familiarity: rel.familiarity,
+            resentment: rel.resentment,
+        };
+
+        let value = rel_vec.get(prereq.axis);
+        if let Some(min_v) = prereq.min_value {
+            if value < min_v {
+                return false;
+            }
+        }
+        if let Some(max_v) = prereq.max_value {
+            if value > max_v {
+                return false;
+            }
+        }
+
+        let band_rank = band_rank_for(prereq.axis, &rel_vec);
+        if let Some(ref min_band_name) = prereq.min_band {
+            if let Some(min_rank) = band_rank_from_name(prereq.axis, min_band_name) {
+                if band_rank < min_rank {
+                    return false;
+                }
+            }
+        }
+        if let Some(ref max_band_name) = prereq.max_band {
+            if let Some(max_rank) = band_rank_from_name(prereq.axis, max_band_name) {
+                if band_rank > max_rank {
+                    return false;
+                }
+            }
+        }
+    }
+
+    true
+}
+
+pub(crate) fn check_life_stage_prereqs(world: &WorldState, pre: &StoryletPrerequisites) -> bool {
+    if pre.allowed_life_stages.is_empty() {
+        return true;
+    }
+    pre.allowed_life_stages.contains(&world.player_life_stage)
+}
+
+/// Player content toggles. Legacy storylets carry no domain, so domain blocks
+/// are matched against tag names.
+pub(crate) fn check_content_preferences(world: &WorldState, pre: &StoryletPrerequisites) -> bool {
+    world
+        .content_preferences
+        .allows(None, pre.tags.iter().map(String::as_str))
+}
+
+/// Age gating. Legacy storylets carry no domain, so domain minimums are
+/// matched against tag names.
+pub(crate) fn check_age_gate(world: &WorldState, pre: &StoryletPrerequisites) -> bool {
+    syn_storylets::age_gating::age_appropriate(
+        crate::eligibility::convert_core_life_stage_to_storylet(world.player_life_stage),
+        None,
+        pre.tags.iter().map(String::as_str),
+    )
+}
+
+pub(crate) fn check_digital_legacy_prereq(world: &WorldState, pre: &Option<DigitalLegacyPrereq>) -> bool {
+    let Some(pre) = pre else {
+        return true;
+    };
+
+    // If require_post_life is true, and we're not in Digital, fail.
+    if pre.require_post_life && !matches!(world.player_life_stage, LifeStage::Digital) {
+        return false;
+    }
+
+    let imprint = match &world.digital_legacy.primary_imprint {
+        Some(i) => i,
+        None => {
+            // No imprint yet; if we require any bounds, fail.
+            return false;
+        }
+    };
+
+    let lv = &imprint.legacy_vector;
+
+    let between = |v: f32, min: &Option<f32>, max: &Option<f32>| {
+        if let Some(m) = min {
+            if v < *m {
+                return false;
+            }
+        }
+        if let Some(m) = max {
+            if v > *m {
+                return false;
+            }
+        }
+        true
+    };
+
+    between(
+        lv.compassion_vs_cruelty,
+        &pre.min_compassion_vs_cruelty,
+        &pre.max_compassion_vs_cruelty,
+    ) && between(
+        lv.ambition_vs_comfort,
+        &pre.min_ambition_vs_comfort,
+        &pre.max_ambition_vs_comfort,
+    ) && between(
+        lv.connection_vs_isolation,
+        &pre.min_connection_vs_isolation,
+        &pre.max_connection_vs_isolation,
+    ) && between(
+        lv.stability_vs_chaos,
+        &pre.min_stability_vs_chaos,
+        &pre.max_stability_vs_chaos,
+    ) && between(
+        lv.light_vs_shadow,
+        &pre.min_light_vs_shadow,
+        &pre.max_light_vs_shadow,
+    )
+}
+
+/// Reaction storylets (trigger kind `flag_set:<flag>` or
+/// `flag_cleared:<flag>`) are eligible only while a matching flag change is
+/// pending and the flag is off its reaction cooldown. Interception storylets
+/// (`reaction_pending`) need an NPC still taking in something the player did,
+/// and mismatch storylets (`declared_mismatch[:<status>]`) an unanswered
+/// declared status the relationship no longer fits. Intervention storylets
+/// (`stat_crisis:<stat>`) need an open crisis of that stat waiting for one.
+pub(crate) fn trigger_context_ready(world: &WorldState, storylet: &Storylet, current_tick: SimTick) -> bool {
+    match storylet.triggers.kind.as_deref() {
+        Some(kind) if syn_core::is_stat_crisis_trigger(kind) => world
+            .stat_crisis
+            .awaiting_response(kind, current_tick.0)
+            .is_some(),
+        Some(kind) if syn_core::is_flag_trigger(kind) => world
+            .flag_changes
+            .pending_for(kind, current_tick.0)
+            .is_some(),
+        Some(kind) if syn_core::is_declared_mismatch_trigger(kind) => world
+            .declared_relationships
+            .mismatch_for(kind)
+            .is_some(),
+        Some(syn_core::REACTION_PENDING_TRIGGER) => world
+            .reaction_latency
+            .has_pending_toward(world.player_id.0),
+        _ => true,
+    }
+}
+
+/// Declared status gating: with `declared_statuses` set, the player and the
+/// target (first role) must have declared one of them.
+pub(crate) fn declared_status_allows(world: &WorldState, storylet: &Storylet) -> bool {
+    let statuses = &storylet.prerequisites.declared_statuses;
+    if statuses.is_empty() {
+        return true;
+    }
+    storylet.roles.first().is_some_and(|target| {
+        world
+            .declared_relationships
+            .status_of(world.player_id.0, target.npc_id.0)
+            .is_some_and(|status| statuses.contains(&status))
+    })
+}
+
+/// Stat, personality, district, memory echo and global conditions, for the
+/// director loop (memory echoes read the world's memory records).
+pub(crate) fn storylet_check_condition_prereqs(world: &WorldState, pre: &StoryletPrerequisites) -> bool {
+    pre.conditions_met(world, None)
+}
+
+pub(crate) fn storylet_check_relationship_prereqs(world: &WorldState, pre: &StoryletPrerequisites) -> bool {
+    check_relationship_prereqs(world, &pre.relationship_prereqs, world.player_id)
+}
+
+pub(crate) fn storylet_check_time_and_location_prereqs(
+    world: &WorldState,
+    sim: &SimState,
+    storylet: &Storylet,
+) -> bool {
+    check_time_and_location_prereqs(world, &sim.npc_registry, storylet)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use syn_core::WorldSeed;
+
+    #[test]
+    fn band_names_rank_in_order_ignoring_case() {
+        let rank = |name| band_rank_from_name(ModelRelationshipAxis::Affection, name);
+        assert!(rank("Stranger") < rank("friendly"));
+        assert!(rank("friendly") < rank("DEVOTED"));
+    }
+
+    #[test]
+    fn life_stage_gate_allows_listed_stages_only() {
+        let mut world = WorldState::new(WorldSeed(1), NpcId(1));
+        world.player_life_stage = LifeStage::Adult;
+        let mut pre = StoryletPrerequisites::default();
+        assert!(check_life_stage_prereqs(&world, &pre));
+
+        pre.allowed_life_stages = vec![LifeStage::Teen];
+        assert!(!check_life_stage_prereqs(&world, &pre));
+        pre.allowed_life_stages.push(LifeStage::Adult);
+        assert!(check_life_stage_prereqs(&world, &pre));
+    }
+
+    #[test]
+    fn passes_reads_conditions_from_the_event_context() {
+        let pre = StoryletPrerequisites {
+            stat_conditions: vec![StatCondition {
+                kind: "Mood".to_string(),
+                min: Some(3.0),
+                max: None,
+            }],
+            district_conditions: vec![DistrictCondition {
+                district: "Harbor".to_string(),
+            }],
+            memory_echo_conditions: vec![MemoryEchoFlag {
+                tag: "goodbye".to_string(),
+            }],
+            global_conditions: vec![GlobalWorldStateFlag {
+                flag: "curfew".to_string(),
+                value: false,
+            }],
+            ..Default::default()
+        };
+        let mut ctx = EventContext::default();
+        assert!(!pre.passes(&ctx));
+
+        // Unknown stats and districts don't gate
+        ctx.memory_echoes.push("goodbye".to_string());
+        assert!(pre.passes(&ctx));
+
+        ctx.stats.insert("mood".to_string(), 1.0);
+        assert!(!pre.passes(&ctx));
+        ctx.stats.insert("mood".to_string(), 8.0);
+        assert!(pre.passes(&ctx));
+
+        ctx.district_state = Some("Uptown".to_string());
+        assert!(!pre.passes(&ctx));
+        ctx.district_state = Some("harbor".to_string());
+        ctx.global_state.insert("curfew".to_string(), true);
+        assert!(!pre.passes(&ctx));
+    }
+
+    #[test]
+    fn authored_ranges_check_only_the_bounds_given() {
+        let max_only: StatCondition =
+            serde_json::from_str(r#"{"kind":"mood","max":-3}"#).unwrap();
+        assert_eq!((max_only.min, max_only.max), (None, Some(-3.0)));
+        let negative: StatCondition =
+            serde_json::from_str(r#"{"kind":"reputation","min":-50,"max":-10}"#).unwrap();
+
+        let in_range = |c: &StatCondition, value: f32| in_authored_range(Some(value), c.min, c.max);
+        assert!(in_range(&max_only, -8.0));
+        assert!(in_range(&max_only, -3.0));
+        assert!(!in_range(&max_only, 0.0));
+        assert!(in_range(&negative, -30.0));
+        assert!(!in_range(&negative, -60.0));
+        assert!(!in_range(&negative, 0.0));
+        assert!(in_authored_range(Some(-100.0), None, None));
+    }
+
+    #[test]
+    fn dependencies_need_every_listed_storylet_to_have_fired() {
+        let pre = StoryletPrerequisites {
+            depends_on: vec!["first_day".to_string(), "first_fight".to_string()],
+            ..Default::default()
+        };
+        let mut usage = StoryletUsageState::default();
+        usage.record_fire("first_day");
+        assert!(!storylet_dependencies_met(&pre, &usage));
+        usage.record_fire("first_fight");
+        assert!(storylet_dependencies_met(&pre, &usage));
+    }
+}
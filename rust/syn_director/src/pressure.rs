@@ -8,8 +8,7 @@
 //! events that address pressures or advance milestones.
 
 use serde::{Deserialize, Serialize};
-use std::collections::HashMap;
-use syn_core::SimTick;
+use syn_core::{OrderedMap, SimTick};
 use syn_storylets::library::StoryletKey;
 use syn_storylets::{StoryDomain, Tag};
 
@@ -22,7 +21,7 @@ use crate::state::DirectorState;
 // ============================================================================
 
 /// Unique identifier for a pressure.
-#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Serialize, Deserialize)]
 pub struct PressureId(pub u64);
 
 impl PressureId {
@@ -185,8 +184,8 @@ impl Pressure {
 /// State tracking all active pressures.
 #[derive(Debug, Clone, Serialize, Deserialize, Default)]
 pub struct PressureState {
-    /// Active pressures indexed by ID.
-    pub active: HashMap<PressureId, Pressure>,
+    /// Active pressures indexed by ID (ordered, so bonus sums are stable).
+    pub active: OrderedMap<PressureId, Pressure>,
     
     /// Next ID to assign.
     next_id: u64,
@@ -196,7 +195,7 @@ impl PressureState {
     /// Create new empty pressure state.
     pub fn new() -> Self {
         PressureState {
-            active: HashMap::new(),
+            active: OrderedMap::new(),
             next_id: 1,
         }
     }
@@ -267,7 +266,7 @@ impl PressureState {
 // ============================================================================
 
 /// Unique identifier for a milestone.
-#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Serialize, Deserialize)]
 pub struct MilestoneId(pub u64);
 
 impl MilestoneId {
@@ -432,8 +431,8 @@ impl Milestone {
 /// State tracking all milestones.
 #[derive(Debug, Clone, Serialize, Deserialize, Default)]
 pub struct MilestoneState {
-    /// All milestones indexed by ID.
-    pub milestones: HashMap<MilestoneId, Milestone>,
+    /// All milestones indexed by ID (ordered, so bonus sums are stable).
+    pub milestones: OrderedMap<MilestoneId, Milestone>,
     
     /// Next ID to assign.
     next_id: u64,
@@ -443,7 +442,7 @@ impl MilestoneState {
     /// Create new empty milestone state.
     pub fn new() -> Self {
         MilestoneState {
-            milestones: HashMap::new(),
+            milestones: OrderedMap::new(),
             next_id: 1,
         }
     }
@@ -0,0 +1,309 @@
+//! Recording a run of the director loop and replaying it.
+//!
+//! A [`ReplayRecorder`] wraps [`advance_and_select`] and
+//! [`apply_choice_and_advance`] and writes a compact [`ReplayLog`]: the
+//! world seed, then for every call the input (ticks advanced, the player's
+//! choice and so the storylet it fired), the draws each RNG domain stream
+//! took while it ran (see [`syn_core::start_rng_recording`]) and the
+//! storylet the director presented next.
+//!
+//! [`replay_from_log`] feeds the same inputs to a fresh world built from the
+//! same seed and checks every entry it produces against the log, so the first
+//! divergent draw or pick is reported at its index. When it succeeds the
+//! world is in the state the recorded run ended in.
+//!
+//! The recorder only sees what goes through it: drive every state change of
+//! a recorded run through its methods. Recording is per thread, and one
+//! recorder may be active per thread at a time.
+
+use std::fmt;
+
+use serde::{Deserialize, Serialize};
+use syn_core::{drain_rng_recording, start_rng_recording, stop_rng_recording, WorldState};
+use syn_sim::SimState;
+
+use crate::{advance_and_select, apply_choice_and_advance, DirectorEventView, StoryletLibrary};
+
+/// Current [`ReplayLog::version`].
+pub const REPLAY_LOG_VERSION: u32 = 1;
+
+/// One entry of a [`ReplayLog`].
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(tag = "kind", rename_all = "snake_case")]
+pub enum ReplayEvent {
+    /// Time advanced by `ticks`, then the next event was selected.
+    Advance { ticks: u32 },
+    /// The player picked `choice_id` on `storylet_id`, firing it, then time
+    /// advanced by `ticks`.
+    Choice {
+        storylet_id: String,
+        choice_id: String,
+        ticks: u32,
+    },
+    /// Draws the `domain` stream for `tick` took during the preceding input.
+    Rng { tick: u64, domain: String, draws: u32 },
+    /// The storylet the director presented after the preceding input, if any.
+    Presented {
+        tick: u64,
+        storylet_id: Option<String>,
+    },
+}
+
+/// Everything needed to reproduce a recorded run from its seed.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ReplayLog {
+    pub version: u32,
+    /// World seed the run started from.
+    pub seed: u64,
+    pub events: Vec<ReplayEvent>,
+}
+
+impl Default for ReplayLog {
+    fn default() -> Self {
+        ReplayLog {
+            version: REPLAY_LOG_VERSION,
+            seed: 0,
+            events: Vec::new(),
+        }
+    }
+}
+
+impl ReplayLog {
+    /// The player's inputs, in order.
+    pub fn inputs(&self) -> impl Iterator<Item = &ReplayEvent> {
+        self.events.iter().filter(|event| {
+            matches!(event, ReplayEvent::Advance { .. } | ReplayEvent::Choice { .. })
+        })
+    }
+}
+
+/// Records a run of the director loop into a [`ReplayLog`].
+///
+/// Starts RNG recording on this thread when created and stops it when
+/// finished or dropped.
+pub struct ReplayRecorder {
+    log: ReplayLog,
+}
+
+impl ReplayRecorder {
+    /// Start recording a run of `world`. Draws taken before this are not
+    /// part of the log.
+    pub fn start(world: &WorldState) -> Self {
+        start_rng_recording();
+        ReplayRecorder {
+            log: ReplayLog {
+                seed: world.seed.0,
+                ..Default::default()
+            },
+        }
+    }
+
+    /// [`advance_and_select`], recorded.
+    pub fn advance_and_select(
+        &mut self,
+        world: &mut WorldState,
+        sim: &mut SimState,
+        library: &StoryletLibrary,
+        ticks_to_advance: u32,
+    ) -> Option<DirectorEventView> {
+        self.log.events.push(ReplayEvent::Advance {
+            ticks: ticks_to_advance,
+        });
+        let view = advance_and_select(world, sim, library, ticks_to_advance);
+        self.record_result(world, view.as_ref());
+        view
+    }
+
+    /// [`apply_choice_and_advance`], recorded.
+    pub fn apply_choice_and_advance(
+        &mut self,
+        world: &mut WorldState,
+        sim: &mut SimState,
+        library: &StoryletLibrary,
+        storylet_id: &str,
+        choice_id: &str,
+        ticks_to_advance: u32,
+    ) -> Option<DirectorEventView> {
+        self.log.events.push(ReplayEvent::Choice {
+            storylet_id: storylet_id.to_string(),
+            choice_id: choice_id.to_string(),
+            ticks: ticks_to_advance,
+        });
+        let view = apply_choice_and_advance(
+            world,
+            sim,
+            library,
+            storylet_id,
+            choice_id,
+            ticks_to_advance,
+        );
+        self.record_result(world, view.as_ref());
+        view
+    }
+
+    /// The log so far.
+    pub fn log(&self) -> &ReplayLog {
+        &self.log
+    }
+
+    /// Stop recording and hand back the log.
+    pub fn finish(mut self) -> ReplayLog {
+        std::mem::take(&mut self.log)
+    }
+
+    fn record_result(&mut self, world: &WorldState, view: Option<&DirectorEventView>) {
+        self.log
+            .events
+            .extend(drain_rng_recording().into_iter().map(|draws| ReplayEvent::Rng {
+                tick: draws.tick,
+                domain: draws.domain,
+                draws: draws.draws,
+            }));
+        self.log.events.push(ReplayEvent::Presented {
+            tick: world.game_time.tick_index,
+            storylet_id: view.map(|view| view.storylet_id.clone()),
+        });
+    }
+}
+
+impl Drop for ReplayRecorder {
+    fn drop(&mut self) {
+        stop_rng_recording();
+    }
+}
+
+/// Why a replay failed.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ReplayError {
+    /// The log was written by an incompatible version.
+    UnsupportedVersion(u32),
+    /// The world wasn't built from the log's seed.
+    SeedMismatch { log: u64, world: u64 },
+    /// The replay produced something other than the log's entry at `index`
+    /// (`None` when it ran out of entries to produce).
+    Diverged {
+        index: usize,
+        expected: ReplayEvent,
+        actual: Option<ReplayEvent>,
+    },
+    /// The replay produced more entries than the log holds.
+    Overran { index: usize, actual: ReplayEvent },
+}
+
+impl fmt::Display for ReplayError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ReplayError::UnsupportedVersion(version) => {
+                write!(f, "unsupported replay log version {}", version)
+            }
+            ReplayError::SeedMismatch { log, world } => {
+                write!(f, "log was recorded with seed {} but world has seed {}", log, world)
+            }
+            ReplayError::Diverged {
+                index,
+                expected,
+                actual,
+            } => write!(
+                f,
+                "replay diverged at entry {}: expected {:?}, got {:?}",
+                index, expected, actual
+            ),
+            ReplayError::Overran { index, actual } => {
+                write!(f, "replay produced extra entry {}: {:?}", index, actual)
+            }
+        }
+    }
+}
+
+impl std::error::Error for ReplayError {}
+
+/// What a successful replay did.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ReplayReport {
+    /// Inputs fed to the director.
+    pub inputs: usize,
+    /// Log entries checked.
+    pub events: usize,
+    /// The event presented at the end of the run.
+    pub last_presented: Option<String>,
+}
+
+/// Replay `log` on `world` and `sim`, which must be freshly built the same
+/// way the recorded run's were (same seed, same setup). Stops at the first
+/// entry that doesn't match.
+pub fn replay_from_log(
+    world: &mut WorldState,
+    sim: &mut SimState,
+    library: &StoryletLibrary,
+    log: &ReplayLog,
+) -> Result<ReplayReport, ReplayError> {
+    if log.version != REPLAY_LOG_VERSION {
+        return Err(ReplayError::UnsupportedVersion(log.version));
+    }
+    if log.seed != world.seed.0 {
+        return Err(ReplayError::SeedMismatch {
+            log: log.seed,
+            world: world.seed.0,
+        });
+    }
+
+    let mut recorder = ReplayRecorder::start(world);
+    let mut checked = 0;
+    let mut last_presented = None;
+    let inputs: Vec<&ReplayEvent> = log.inputs().collect();
+    for input in &inputs {
+        let view = match input {
+            ReplayEvent::Advance { ticks } => {
+                recorder.advance_and_select(world, sim, library, *ticks)
+            }
+            ReplayEvent::Choice {
+                storylet_id,
+                choice_id,
+                ticks,
+            } => recorder.apply_choice_and_advance(
+                world,
+                sim,
+                library,
+                storylet_id,
+                choice_id,
+                *ticks,
+            ),
+            _ => continue,
+        };
+        last_presented = view.map(|view| view.storylet_id);
+
+        let produced = &recorder.log().events;
+        while checked < produced.len() {
+            let actual = &produced[checked];
+            match log.events.get(checked) {
+                Some(expected) if expected == actual => checked += 1,
+                Some(expected) => {
+                    return Err(ReplayError::Diverged {
+                        index: checked,
+                        expected: expected.clone(),
+                        actual: Some(actual.clone()),
+                    });
+                }
+                None => {
+                    return Err(ReplayError::Overran {
+                        index: checked,
+                        actual: actual.clone(),
+                    });
+                }
+            }
+        }
+    }
+    if let Some(expected) = log.events.get(checked) {
+        return Err(ReplayError::Diverged {
+            index: checked,
+            expected: expected.clone(),
+            actual: None,
+        });
+    }
+
+    Ok(ReplayReport {
+        inputs: inputs.len(),
+        events: checked,
+        last_presented,
+    })
+}
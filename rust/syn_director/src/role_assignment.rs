@@ -6,20 +6,133 @@
 //! - NPC traits/personality
 //! - Current mood
 //! - Contextual factors (e.g., district/cluster membership)
+//! - Tag affinity: how strongly the NPC's prototype gravitates toward the
+//!   storylet's tags
 //!
 //! All scoring is deterministic, using seeded RNG derived from world seed, tick, storylet,
 //! and role name to ensure reproducible casting decisions.
+//!
+//! Casting respects constraints between roles: an actor fills one role unless the
+//! roles opt into sharing (`RoleSlot::allow_same_as`), and relationship prerequisites
+//! between roles (e.g. the rival resents the target) must hold for the cast actors.
+//! When the best candidates conflict, casting backtracks deterministically.
+//!
+//! NPCs may refuse cooperative roles (friend, ally, romance, mentor, ... or any
+//! role in a storylet tagged `cooperative`): one whose resentment toward the
+//! player is Hostile or Vindictive, or who holds strongly negative memories of
+//! the player, is passed over and the role is re-cast. When nobody else can
+//! fill it, [`RoleAssignmentEngine::cast_or_refuse`] reports the refusal so the
+//! director can play it as a refusal micro-event instead.
 
 use std::collections::{HashMap, HashSet};
 
+use syn_core::relationship_model::{RelationshipVector, ResentmentBand};
 use syn_core::{
     deterministic_rng_from_world, NpcId, SimTick, StatKind, WorldState,
 };
 use syn_storylets::library::{CompiledStorylet, StoryletKey};
+use syn_storylets::RoleSlot;
 
-use crate::eligibility::EligibilityContext;
+use crate::eligibility::{relationship_meets_thresholds, EligibilityContext};
 use syn_memory::MemorySystem;
 
+/// Upper bound on candidate placements tried while casting one storylet.
+///
+/// Keeps backtracking cheap when many NPCs are known and the constraints
+/// cannot be met together.
+const MAX_CASTING_STEPS: usize = 4096;
+
+/// Score added per unit of prototype tag affinity (affinity is in -1..1).
+const TAG_AFFINITY_WEIGHT: f32 = 10.0;
+
+/// Storylet tag that makes every role cooperative.
+pub const COOPERATIVE_TAG: &str = "cooperative";
+
+/// Role name fragments that ask for a willing participant.
+const COOPERATIVE_ROLE_PATTERNS: [&str; 8] = [
+    "friend", "ally", "romance", "love", "mentor", "guide", "confidant", "partner",
+];
+
+/// Player memories at or below this intensity count as strongly negative.
+const STRONG_NEGATIVE_INTENSITY: f32 = -0.5;
+
+/// Summed magnitude of an NPC's strongly negative memories of the player at
+/// which they refuse cooperative roles.
+const REFUSAL_MEMORY_WEIGHT: f32 = 1.5;
+
+/// Why an NPC refused a role.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum RefusalReason {
+    /// Their resentment toward the player is Hostile or Vindictive.
+    Resentment(ResentmentBand),
+    /// They hold strongly negative memories of the player.
+    Memories {
+        /// Number of strongly negative memories.
+        count: usize,
+        /// Summed magnitude of their intensity.
+        weight: f32,
+    },
+}
+
+impl RefusalReason {
+    /// Stable lowercase label for tags and UI.
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            RefusalReason::Resentment(_) => "resentment",
+            RefusalReason::Memories { .. } => "memories",
+        }
+    }
+}
+
+/// An NPC declining a role.
+#[derive(Debug, Clone, PartialEq)]
+pub struct CastingRefusal {
+    /// The NPC who refused.
+    pub actor_id: NpcId,
+    /// Role they were asked to fill.
+    pub role: String,
+    /// Why they refused.
+    pub reason: RefusalReason,
+}
+
+/// Result of casting a storylet with refusals considered.
+#[derive(Debug, Clone)]
+pub enum CastingOutcome {
+    /// Every required role is filled by a willing actor.
+    Cast(RoleAssignments),
+    /// A required role could only be filled by someone who refuses it.
+    Refused(CastingRefusal),
+    /// The required roles cannot be filled at all.
+    Unfilled,
+}
+
+/// Whether a role name refers to the player in relationship prerequisites.
+fn is_player_role(name: &str) -> bool {
+    name.eq_ignore_ascii_case("protagonist") || name.eq_ignore_ascii_case("player")
+}
+
+/// Whether `role` asks for a willing participant.
+fn is_cooperative(storylet: &CompiledStorylet, role: &RoleSlot) -> bool {
+    if is_player_role(&role.name) {
+        return false;
+    }
+    if storylet
+        .tags
+        .iter()
+        .any(|t| t.0.eq_ignore_ascii_case(COOPERATIVE_TAG))
+    {
+        return true;
+    }
+    let name = role.name.to_lowercase();
+    COOPERATIVE_ROLE_PATTERNS.iter().any(|p| name.contains(p))
+}
+
+/// Whether two roles may be filled by the same actor.
+fn may_share_actor(a: &RoleSlot, b: &RoleSlot) -> bool {
+    a.allow_same_as.iter().any(|n| n.eq_ignore_ascii_case(&b.name))
+        || b.allow_same_as.iter().any(|n| n.eq_ignore_ascii_case(&a.name))
+}
+
 /// Result of assigning actors to storylet roles.
 #[derive(Debug, Clone)]
 pub struct RoleAssignments {
@@ -27,6 +140,8 @@ pub struct RoleAssignments {
     pub storylet_key: StoryletKey,
     /// Mapping from role name to assigned actor ID.
     pub mapping: HashMap<String, NpcId>,
+    /// Actors ranked ahead of the one cast who refused their role, in role order.
+    pub refusals: Vec<CastingRefusal>,
 }
 
 /// A candidate NPC for a specific role with computed score.
@@ -67,20 +182,121 @@ impl<'a> RoleAssignmentEngine<'a> {
     /// Attempt to assign roles for a storylet given available candidates.
     ///
     /// Returns `Some(RoleAssignments)` if all required roles can be filled.
-    /// Returns `None` if any required role cannot be filled (no suitable candidate).
+    /// Returns `None` if any required role cannot be filled (no suitable candidate,
+    /// or no combination of candidates satisfies the casting constraints).
     ///
     /// # Algorithm
     /// 1. Filter candidate pool (player + known NPCs)
-    /// 2. For each role (required first):
-    ///    - Score all available candidates based on relationship, traits, mood
-    ///    - Select highest scoring candidate (with seeded tie-breaking)
-    ///    - Remove selected candidate from future consideration
-    /// 3. If any required role cannot be filled, return None
-    /// 4. Return complete mapping
+    /// 2. Rank the candidates for each role by relationship, traits, mood
+    ///    (seeded tie-breaking decides the front of each ranking)
+    /// 3. Cast required roles depth-first in declaration order, taking the best
+    ///    ranked candidate that satisfies the constraints and backtracking to the
+    ///    next one when a later role cannot be filled
+    /// 4. Cast optional roles (best effort) with the best remaining candidate
+    ///    that satisfies the constraints
+    ///
+    /// # Constraints
+    /// - An actor fills at most one role, unless either role lists the other
+    ///   in `allow_same_as`
+    /// - `relationship_prerequisites` between two cast roles must hold for the
+    ///   cast actors; "protagonist"/"player" resolve to the player when the
+    ///   storylet has no role slot of that name
+    ///
+    /// NPCs who refuse a cooperative role are skipped for it (see
+    /// [`Self::refusal`]); the ones ranked ahead of the actor cast are listed
+    /// in [`RoleAssignments::refusals`].
     pub fn assign_roles_for_storylet(
         &self,
         storylet: &CompiledStorylet,
         candidate_npcs: Option<&[NpcId]>,
+    ) -> Option<RoleAssignments> {
+        self.assign_roles(storylet, candidate_npcs, true)
+    }
+
+    /// Cast a storylet, telling a refusal apart from an unfillable cast.
+    ///
+    /// Willing actors are cast as in [`Self::assign_roles_for_storylet`]. If
+    /// that fails but a cast ignoring refusals exists, the first required
+    /// role whose actor refuses is reported as [`CastingOutcome::Refused`].
+    pub fn cast_or_refuse(
+        &self,
+        storylet: &CompiledStorylet,
+        candidate_npcs: Option<&[NpcId]>,
+    ) -> CastingOutcome {
+        if let Some(assignments) = self.assign_roles(storylet, candidate_npcs, true) {
+            return CastingOutcome::Cast(assignments);
+        }
+        let Some(unwilling) = self.assign_roles(storylet, candidate_npcs, false) else {
+            return CastingOutcome::Unfilled;
+        };
+        storylet
+            .roles
+            .iter()
+            .filter(|role| role.required)
+            .find_map(|role| {
+                let actor = *unwilling.mapping.get(&role.name)?;
+                self.refusal(storylet, role, actor)
+            })
+            .map_or(CastingOutcome::Unfilled, CastingOutcome::Refused)
+    }
+
+    /// Whether `actor` refuses `role` in `storylet`, and why.
+    ///
+    /// Only cooperative roles can be refused, and never by the player.
+    pub fn refusal(
+        &self,
+        storylet: &CompiledStorylet,
+        role: &RoleSlot,
+        actor: NpcId,
+    ) -> Option<CastingRefusal> {
+        if actor == self.world.player_id || !is_cooperative(storylet, role) {
+            return None;
+        }
+        let refuse = |reason| {
+            Some(CastingRefusal {
+                actor_id: actor,
+                role: role.name.clone(),
+                reason,
+            })
+        };
+
+        let resentment = self.world.get_relationship(actor, self.world.player_id).resentment;
+        let band = RelationshipVector {
+            resentment,
+            ..Default::default()
+        }
+        .resentment_band();
+        if matches!(band, ResentmentBand::Hostile | ResentmentBand::Vindictive) {
+            return refuse(RefusalReason::Resentment(band));
+        }
+
+        let player = self.world.player_id.0;
+        let (count, weight) = self
+            .memory
+            .get_journal(actor)
+            .map(|journal| {
+                journal
+                    .entries
+                    .iter()
+                    .filter(|m| {
+                        m.participants.contains(&player)
+                            && m.emotional_intensity <= STRONG_NEGATIVE_INTENSITY
+                    })
+                    .fold((0, 0.0), |(n, w), m| (n + 1, w + m.emotional_intensity.abs()))
+            })
+            .unwrap_or((0, 0.0));
+        if weight >= REFUSAL_MEMORY_WEIGHT {
+            return refuse(RefusalReason::Memories { count, weight });
+        }
+        None
+    }
+
+    /// Cast the storylet's roles, optionally passing over refusing actors.
+    fn assign_roles(
+        &self,
+        storylet: &CompiledStorylet,
+        candidate_npcs: Option<&[NpcId]>,
+        honor_refusals: bool,
     ) -> Option<RoleAssignments> {
         // Gather candidate pool: player + specified NPCs or all known NPCs
         let mut candidates = vec![self.world.player_id];
@@ -96,71 +312,245 @@ impl<'a> RoleAssignmentEngine<'a> {
             candidates.into_iter().filter(|id| unique.insert(*id)).collect()
         };
 
-        // Build role assignments
-        let mut assignments = HashMap::new();
-        let mut used_actors = HashSet::new();
-
-        // Required roles first
-        let required_roles: Vec<_> = storylet
+        // Required roles first, with backtracking
+        let required_roles: Vec<&RoleSlot> = storylet
             .roles
             .iter()
             .filter(|r| r.required)
             .collect();
+        let mut passed_over = Vec::new();
+        let rankings: Vec<Vec<NpcId>> = required_roles
+            .iter()
+            .map(|role| {
+                let ranked = self.rank_candidates_for_role(&role.name, &candidates, storylet);
+                if honor_refusals {
+                    self.drop_refusals(storylet, role, ranked, &mut passed_over)
+                } else {
+                    ranked
+                }
+            })
+            .collect();
 
-        for role in required_roles {
-            let scored = self.score_candidates_for_role(
-                &role.name,
-                &candidates,
-                &used_actors,
-                storylet.key,
-            );
+        let mut cast = Vec::with_capacity(storylet.roles.len());
+        let mut steps = 0;
+        if !self.cast_required_roles(storylet, &required_roles, &rankings, &mut cast, &mut steps) {
+            return None; // Required roles cannot be filled together
+        }
 
-            if scored.is_empty() {
-                return None; // Required role cannot be filled
+        // Optional roles (best effort)
+        for role in storylet.roles.iter().filter(|r| !r.required) {
+            let mut ranked = self.rank_candidates_for_role(&role.name, &candidates, storylet);
+            if honor_refusals {
+                ranked = self.drop_refusals(storylet, role, ranked, &mut passed_over);
             }
+            if let Some(actor) = ranked
+                .into_iter()
+                .find(|&actor| self.fits_role(storylet, role, actor, &cast))
+            {
+                cast.push((role, actor));
+            }
+            // Optional role left unfilled is acceptable
+        }
 
-            // Select best candidate with deterministic tie-breaking
-            let best = self.select_candidate_deterministically(
-                &scored,
-                storylet.key,
-                &role.name,
-            );
+        // Only refusals that actually changed who was cast are worth reporting
+        let refusals = passed_over
+            .into_iter()
+            .filter(|(refusal, ranked_below)| {
+                cast.iter()
+                    .any(|(role, actor)| role.name == refusal.role && ranked_below.contains(actor))
+            })
+            .map(|(refusal, _)| refusal)
+            .collect();
 
-            used_actors.insert(best.actor_id);
-            assignments.insert(role.name.clone(), best.actor_id);
+        Some(RoleAssignments {
+            storylet_key: storylet.key,
+            mapping: cast
+                .into_iter()
+                .map(|(role, actor)| (role.name.clone(), actor))
+                .collect(),
+            refusals,
+        })
+    }
+
+    /// Remove actors who refuse `role` from its ranking.
+    ///
+    /// The player never stands in for an NPC who refused, so they are also
+    /// dropped when ranked below a refuser. Each refusal is recorded in
+    /// `passed_over` with the willing actors ranked below the refuser, so it
+    /// can be reported if one of them is cast.
+    fn drop_refusals(
+        &self,
+        storylet: &CompiledStorylet,
+        role: &RoleSlot,
+        ranked: Vec<NpcId>,
+        passed_over: &mut Vec<(CastingRefusal, Vec<NpcId>)>,
+    ) -> Vec<NpcId> {
+        let mut refusals = Vec::new();
+        let mut willing = Vec::with_capacity(ranked.len());
+        for actor in ranked {
+            match self.refusal(storylet, role, actor) {
+                Some(refusal) => refusals.push((refusal, willing.len())),
+                None if actor == self.world.player_id && !refusals.is_empty() => {}
+                None => willing.push(actor),
+            }
         }
+        passed_over.extend(
+            refusals
+                .into_iter()
+                .map(|(refusal, above)| (refusal, willing[above..].to_vec())),
+        );
+        willing
+    }
 
-        // Optional roles (best effort)
-        let optional_roles: Vec<_> = storylet
-            .roles
+    /// Recast a legacy storylet role whose explicit NPC does not exist.
+    ///
+    /// Candidates are known NPCs present in the world, other than the player
+    /// and the actors in `taken`. They are scored like compiled roles (role
+    /// name, relationship, memories, affinity for `tags`, major characters);
+    /// ties go to the lowest id. Returns `None` if nobody is left to cast.
+    pub fn recast_role(&self, role_name: &str, tags: &[String], taken: &[NpcId]) -> Option<NpcId> {
+        let candidates: Vec<NpcId> = self
+            .world
+            .known_npcs
             .iter()
-            .filter(|r| !r.required)
+            .copied()
+            .filter(|id| {
+                *id != self.world.player_id
+                    && !taken.contains(id)
+                    && self.world.npcs.contains_key(id)
+            })
             .collect();
+        let has_tag = |tag: &str| tags.iter().any(|t| t.eq_ignore_ascii_case(tag));
 
-        for role in optional_roles {
-            let scored = self.score_candidates_for_role(
-                &role.name,
-                &candidates,
-                &used_actors,
-                storylet.key,
-            );
-
-            if !scored.is_empty() {
-                let best = self.select_candidate_deterministically(
-                    &scored,
-                    storylet.key,
-                    &role.name,
-                );
-                used_actors.insert(best.actor_id);
-                assignments.insert(role.name.clone(), best.actor_id);
+        candidates
+            .into_iter()
+            .map(|actor_id| RoleCandidate {
+                actor_id,
+                score: self.compute_role_score(role_name, actor_id, &has_tag),
+            })
+            .filter(|c| c.score > -f32::INFINITY)
+            .min_by(|a, b| {
+                b.score
+                    .total_cmp(&a.score)
+                    .then(a.actor_id.0.cmp(&b.actor_id.0))
+            })
+            .map(|c| c.actor_id)
+    }
+
+    /// Depth-first casting of the required roles.
+    ///
+    /// `cast` holds the roles filled so far (one per depth). Candidates are
+    /// tried in ranking order, so the first complete cast found is the same
+    /// for the same world state. Gives up after [`MAX_CASTING_STEPS`] placements.
+    fn cast_required_roles<'r>(
+        &self,
+        storylet: &CompiledStorylet,
+        roles: &[&'r RoleSlot],
+        rankings: &[Vec<NpcId>],
+        cast: &mut Vec<(&'r RoleSlot, NpcId)>,
+        steps: &mut usize,
+    ) -> bool {
+        let depth = cast.len();
+        let Some(&role) = roles.get(depth) else {
+            return true;
+        };
+
+        for &actor in &rankings[depth] {
+            if *steps >= MAX_CASTING_STEPS {
+                return false;
             }
-            // Optional role left unfilled is acceptable
+            *steps += 1;
+
+            if !self.fits_role(storylet, role, actor, cast) {
+                continue;
+            }
+            cast.push((role, actor));
+            if self.cast_required_roles(storylet, roles, rankings, cast, steps) {
+                return true;
+            }
+            cast.pop();
         }
 
-        Some(RoleAssignments {
-            storylet_key: storylet.key,
-            mapping: assignments,
-        })
+        false
+    }
+
+    /// Whether `actor` can fill `role` alongside the roles already cast.
+    ///
+    /// Checks actor reuse and every relationship prerequisite linking `role`
+    /// to a role that is already resolved; prerequisites whose other side is
+    /// not cast yet are checked when that side is cast.
+    fn fits_role(
+        &self,
+        storylet: &CompiledStorylet,
+        role: &RoleSlot,
+        actor: NpcId,
+        cast: &[(&RoleSlot, NpcId)],
+    ) -> bool {
+        let reused = cast
+            .iter()
+            .any(|(other, id)| *id == actor && !may_share_actor(role, other));
+        if reused {
+            return false;
+        }
+
+        let Some(rel_prereqs) = &storylet.prerequisites.relationship_prerequisites else {
+            return true;
+        };
+
+        let resolve = |name: &str| -> Option<NpcId> {
+            if name.eq_ignore_ascii_case(&role.name) {
+                return Some(actor);
+            }
+            if let Some((_, id)) = cast.iter().find(|(r, _)| r.name.eq_ignore_ascii_case(name)) {
+                return Some(*id);
+            }
+            let has_slot = storylet.roles.iter().any(|r| r.name.eq_ignore_ascii_case(name));
+            (is_player_role(name) && !has_slot).then_some(self.world.player_id)
+        };
+
+        rel_prereqs
+            .iter()
+            .filter(|p| {
+                p.from_role.eq_ignore_ascii_case(&role.name)
+                    || p.to_role.eq_ignore_ascii_case(&role.name)
+            })
+            .all(|p| match (resolve(&p.from_role), resolve(&p.to_role)) {
+                (Some(from), Some(to)) if from != to => relationship_meets_thresholds(
+                    &self.world.get_relationship(from, to),
+                    &p.thresholds,
+                ),
+                // Other side not cast yet, or a shared actor has no relationship with itself
+                _ => true,
+            })
+    }
+
+    /// Rank the candidates for a role, best first.
+    ///
+    /// The seeded tie-break winner leads; the rest follow by descending score,
+    /// then by id, so backtracking explores alternatives in a stable order.
+    fn rank_candidates_for_role(
+        &self,
+        role_name: &str,
+        candidates: &[NpcId],
+        storylet: &CompiledStorylet,
+    ) -> Vec<NpcId> {
+        let mut scored = self.score_candidates_for_role(role_name, candidates, storylet);
+        if scored.is_empty() {
+            return Vec::new();
+        }
+
+        let best = self
+            .select_candidate_deterministically(&scored, storylet.key, role_name)
+            .actor_id;
+        scored.sort_by(|a, b| {
+            b.score
+                .total_cmp(&a.score)
+                .then(a.actor_id.0.cmp(&b.actor_id.0))
+        });
+
+        let mut ranked = vec![best];
+        ranked.extend(scored.iter().map(|c| c.actor_id).filter(|&id| id != best));
+        ranked
     }
 
     /// Score all candidates for a specific role.
@@ -170,21 +560,21 @@ impl<'a> RoleAssignmentEngine<'a> {
     /// - **Traits**: Empathy, impulsivity, dominance, creativity
     /// - **Mood**: Current emotional state
     /// - **Role context**: "rival", "friend", "romance", "manager", etc.
+    /// - **Tag affinity**: the NPC's pull toward the storylet's tags
     fn score_candidates_for_role(
         &self,
         role_name: &str,
         candidates: &[NpcId],
-        already_used: &HashSet<NpcId>,
-        storylet_key: StoryletKey,
+        storylet: &CompiledStorylet,
     ) -> Vec<RoleCandidate> {
+        let has_tag = |tag: &str| storylet.tags.iter().any(|t| t.0.eq_ignore_ascii_case(tag));
         candidates
             .iter()
-            .filter(|id| !already_used.contains(id))
             .map(|&actor_id| {
                 let score = self.compute_role_score(
                     role_name,
                     actor_id,
-                    storylet_key,
+                    &has_tag,
                 );
                 RoleCandidate { actor_id, score }
             })
@@ -196,11 +586,12 @@ impl<'a> RoleAssignmentEngine<'a> {
     ///
     /// Returns a score where higher values indicate better fit.
     /// Returns `-INFINITY` for candidates that cannot fill the role.
-    fn compute_role_score(
+    /// `has_tag` tells whether the storylet carries a tag.
+    pub(crate) fn compute_role_score(
         &self,
         role_name: &str,
         actor_id: NpcId,
-        _storylet_key: StoryletKey,
+        has_tag: &dyn Fn(&str) -> bool,
     ) -> f32 {
         // Normalize role name to compare against standard types
         let normalized_role = role_name.to_lowercase();
@@ -256,9 +647,29 @@ impl<'a> RoleAssignmentEngine<'a> {
         // Memory-aware scoring: NPCs with relevant memory tags score higher for matching roles
         score += self.compute_memory_score(&normalized_role, actor_id);
 
+        // Tag affinity: a chaotic friend is the one who turns up in trouble
+        score += self.compute_tag_affinity_score(has_tag, actor_id);
+
+        // Major characters are cast first when they fit at all
+        if syn_core::is_major_character(self.world, actor_id) {
+            score += self.world.importance.config.casting_bonus;
+        }
+
         score
     }
 
+    /// Score contribution from the actor's prototype tag affinities.
+    ///
+    /// Authored affinities win; otherwise they are derived from personality.
+    /// Actors without a prototype (including the player) contribute nothing.
+    fn compute_tag_affinity_score(&self, has_tag: &dyn Fn(&str) -> bool, actor_id: NpcId) -> f32 {
+        let Some(proto) = self.world.npc_prototype(actor_id) else {
+            return 0.0;
+        };
+        let affinity = proto.tag_affinity(has_tag);
+        affinity * TAG_AFFINITY_WEIGHT
+    }
+
     /// Compute memory-based score contribution for an actor in a role.
     ///
     /// Maps memory tags to role affinities. For example:
@@ -421,6 +832,7 @@ mod tests {
     use syn_core::RelationshipState;
     use syn_storylets::library::CompiledStorylet;
     use syn_storylets::{Cooldowns, Outcome, Prerequisites, RoleSlot, StoryDomain, LifeStage, StoryletId};
+    use syn_storylets::{RelationshipPrerequisites, RelationshipThreshold};
 
     struct TestSetup {
         world: WorldState,
@@ -496,6 +908,7 @@ mod tests {
             name: "friend".to_string(),
             required: true,
             constraints: None,
+            allow_same_as: vec![],
         };
 
         let storylet = make_test_storylet("meet_friend", vec![friend_role]);
@@ -528,6 +941,7 @@ mod tests {
             name: "rival".to_string(),
             required: true,
             constraints: None,
+            allow_same_as: vec![],
         };
 
         let storylet = make_test_storylet("confrontation", vec![rival_role]);
@@ -555,6 +969,7 @@ mod tests {
             name: "participant".to_string(),
             required: true,
             constraints: None,
+            allow_same_as: vec![],
         };
 
         let storylet = make_test_storylet("event", vec![generic_role]);
@@ -587,6 +1002,7 @@ mod tests {
             name: "friend".to_string(),
             required: true,
             constraints: None,
+            allow_same_as: vec![],
         };
 
         let storylet = make_test_storylet("meet_friend", vec![friend_role]);
@@ -612,12 +1028,14 @@ mod tests {
             name: "protagonist".to_string(),
             required: true,
             constraints: None,
+            allow_same_as: vec![],
         };
 
         let optional_role = RoleSlot {
             name: "witness".to_string(),
             required: false,
             constraints: None,
+            allow_same_as: vec![],
         };
 
         let storylet = make_test_storylet("solo_event", vec![required_role, optional_role]);
@@ -647,12 +1065,14 @@ mod tests {
             name: "primary".to_string(),
             required: true,
             constraints: None,
+            allow_same_as: vec![],
         };
 
         let role2 = RoleSlot {
             name: "secondary".to_string(),
             required: true,
             constraints: None,
+            allow_same_as: vec![],
         };
 
         let storylet = make_test_storylet("two_person", vec![role1, role2]);
@@ -688,7 +1108,7 @@ mod tests {
         );
         entry.tags = vec!["betrayal".to_string(), "trust_broken".to_string()];
         entry.participants = vec![1, 2]; // Player (1) and NPC (2)
-        setup.memory.record_memory(entry);
+        setup.memory.record_memory(entry, None);
 
         let engine = RoleAssignmentEngine {
             world: &setup.world,
@@ -700,6 +1120,7 @@ mod tests {
             name: "rival".to_string(),
             required: true,
             constraints: None,
+            allow_same_as: vec![],
         };
 
         let storylet = make_test_storylet("confrontation", vec![rival_role]);
@@ -738,7 +1159,7 @@ mod tests {
         );
         entry.tags = vec!["support".to_string(), "help".to_string(), "bonding".to_string()];
         entry.participants = vec![1, 2];
-        setup.memory.record_memory(entry);
+        setup.memory.record_memory(entry, None);
 
         // Add another support memory to boost further
         let mut entry2 = MemoryEntry::new(
@@ -750,7 +1171,7 @@ mod tests {
         );
         entry2.tags = vec!["shared_moment".to_string(), "trust".to_string()];
         entry2.participants = vec![1, 2];
-        setup.memory.record_memory(entry2);
+        setup.memory.record_memory(entry2, None);
 
         let engine = RoleAssignmentEngine {
             world: &setup.world,
@@ -762,6 +1183,7 @@ mod tests {
             name: "friend".to_string(),
             required: true,
             constraints: None,
+            allow_same_as: vec![],
         };
 
         let storylet = make_test_storylet("hangout", vec![friend_role]);
@@ -799,7 +1221,7 @@ mod tests {
         );
         old_entry.tags = vec!["betrayal".to_string()];
         old_entry.participants = vec![1, 3];
-        setup.memory.record_memory(old_entry);
+        setup.memory.record_memory(old_entry, None);
 
         // Recent betrayal memory for NpcId(2)
         let mut recent_entry = MemoryEntry::new(
@@ -811,7 +1233,7 @@ mod tests {
         );
         recent_entry.tags = vec!["betrayal".to_string()];
         recent_entry.participants = vec![1, 2];
-        setup.memory.record_memory(recent_entry);
+        setup.memory.record_memory(recent_entry, None);
 
         let engine = RoleAssignmentEngine {
             world: &setup.world,
@@ -823,6 +1245,7 @@ mod tests {
             name: "rival".to_string(),
             required: true,
             constraints: None,
+            allow_same_as: vec![],
         };
 
         let storylet = make_test_storylet("argument", vec![rival_role]);
@@ -860,7 +1283,7 @@ mod tests {
         );
         entry.tags = vec!["betrayal".to_string()];
         entry.participants = vec![1, 2];
-        setup.memory.record_memory(entry);
+        setup.memory.record_memory(entry, None);
 
         let engine = RoleAssignmentEngine {
             world: &setup.world,
@@ -872,6 +1295,7 @@ mod tests {
             name: "romance_interest".to_string(),
             required: true,
             constraints: None,
+            allow_same_as: vec![],
         };
 
         let storylet = make_test_storylet("date", vec![romance_role]);
@@ -889,4 +1313,213 @@ mod tests {
             "Betrayal memory should not boost romance role - higher attraction NPC should win"
         );
     }
+
+    fn role(name: &str, allow_same_as: &[&str]) -> RoleSlot {
+        RoleSlot {
+            name: name.to_string(),
+            required: true,
+            constraints: None,
+            allow_same_as: allow_same_as.iter().map(|n| n.to_string()).collect(),
+        }
+    }
+
+    fn rival_resents_target(min: f32) -> Prerequisites {
+        Prerequisites {
+            relationship_prerequisites: Some(vec![RelationshipPrerequisites {
+                from_role: "rival".to_string(),
+                to_role: "target".to_string(),
+                thresholds: vec![RelationshipThreshold {
+                    axis: "resentment".to_string(),
+                    min: Some(min),
+                    max: None,
+                }],
+            }]),
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn test_allow_same_as_permits_double_casting() {
+        let setup = TestSetup::new()
+            .with_npc_relationship(NpcId(1), NpcId(2), 8.0, 8.0, 0.0, 0.0)
+            .with_npc_relationship(NpcId(1), NpcId(3), 2.0, 2.0, 0.0, 0.0);
+
+        let engine = RoleAssignmentEngine {
+            world: &setup.world,
+            memory: &setup.memory,
+            current_tick: SimTick(0),
+        };
+
+        let distinct = make_test_storylet(
+            "heart_to_heart",
+            vec![role("friend", &[]), role("confidant", &[])],
+        );
+        let result = engine
+            .assign_roles_for_storylet(&distinct, Some(&[NpcId(2), NpcId(3)]))
+            .unwrap();
+        assert_eq!(result.mapping.get("friend"), Some(&NpcId(2)));
+        assert_ne!(result.mapping.get("confidant"), Some(&NpcId(2)));
+
+        let shared = make_test_storylet(
+            "heart_to_heart",
+            vec![role("friend", &[]), role("confidant", &["friend"])],
+        );
+        let result = engine
+            .assign_roles_for_storylet(&shared, Some(&[NpcId(2), NpcId(3)]))
+            .unwrap();
+        assert_eq!(result.mapping.get("friend"), Some(&NpcId(2)));
+        assert_eq!(result.mapping.get("confidant"), Some(&NpcId(2)));
+    }
+
+    #[test]
+    fn test_relationship_constraint_backtracks_deterministically() {
+        // NpcId(2) is the best target, but only NpcId(4) is resented by the rival.
+        let setup = TestSetup::new()
+            .with_npc_relationship(NpcId(1), NpcId(2), 8.0, 8.0, 0.0, 0.0)
+            .with_npc_relationship(NpcId(1), NpcId(4), 3.0, 3.0, 0.0, 0.0)
+            .with_npc_relationship(NpcId(1), NpcId(3), -2.0, -3.0, 0.0, 8.0)
+            .with_npc_relationship(NpcId(3), NpcId(4), 0.0, 0.0, 0.0, 7.0);
+
+        let engine = RoleAssignmentEngine {
+            world: &setup.world,
+            memory: &setup.memory,
+            current_tick: SimTick(0),
+        };
+
+        let mut storylet = make_test_storylet(
+            "showdown",
+            vec![role("target", &[]), role("rival", &[])],
+        );
+        storylet.prerequisites = rival_resents_target(5.0);
+
+        let pool = [NpcId(2), NpcId(3), NpcId(4)];
+        let first = engine.assign_roles_for_storylet(&storylet, Some(&pool)).unwrap();
+        assert_eq!(first.mapping.get("target"), Some(&NpcId(4)));
+        assert_eq!(first.mapping.get("rival"), Some(&NpcId(3)));
+
+        let again = engine.assign_roles_for_storylet(&storylet, Some(&pool)).unwrap();
+        assert_eq!(again.mapping, first.mapping);
+
+        // Nobody resents anyone that much: the storylet cannot be cast.
+        storylet.prerequisites = rival_resents_target(9.0);
+        assert!(engine.assign_roles_for_storylet(&storylet, Some(&pool)).is_none());
+    }
+
+    fn prototype(id: u64, volatility: f32, tag_affinities: &[(&str, f32)]) -> syn_core::npc::NpcPrototype {
+        syn_core::npc::NpcPrototype {
+            id: NpcId(id),
+            display_name: format!("npc_{}", id),
+            role_label: None,
+            role_tags: vec![],
+            personality: syn_core::npc::PersonalityVector {
+                warmth: 0.0,
+                dominance: 0.0,
+                volatility,
+                conscientiousness: 0.5,
+                openness: 0.5,
+            },
+            base_stats: syn_core::Stats::default(),
+            active_stages: vec![],
+            schedule: Default::default(),
+            home_district: None,
+            tag_affinities: tag_affinities
+                .iter()
+                .map(|(tag, weight)| (tag.to_string(), *weight))
+                .collect(),
+            major: false,
+        }
+    }
+
+    #[test]
+    fn test_tag_affinity_draws_npc_into_matching_storylets() {
+        let mut setup = TestSetup::new()
+            .with_npc_relationship(NpcId(1), NpcId(2), 5.0, 5.0, 0.0, 0.0)
+            .with_npc_relationship(NpcId(1), NpcId(3), 5.0, 5.0, 0.0, 0.0);
+        setup.world.npc_prototypes.insert(NpcId(2), prototype(2, 0.0, &[("study", 0.8)]));
+        setup.world.npc_prototypes.insert(NpcId(3), prototype(3, 0.0, &[("trouble", 0.8)]));
+
+        let engine = RoleAssignmentEngine {
+            world: &setup.world,
+            memory: &setup.memory,
+            current_tick: SimTick(0),
+        };
+        let role = RoleSlot {
+            name: "friend".to_string(),
+            required: true,
+            constraints: None,
+            allow_same_as: vec![],
+        };
+
+        let mut storylet = make_test_storylet("night_out", vec![role]);
+        storylet.tags = vec![syn_storylets::Tag::new("Trouble")];
+        let result = engine.assign_roles_for_storylet(&storylet, Some(&[NpcId(2), NpcId(3)]));
+        assert_eq!(result.unwrap().mapping.get("friend"), Some(&NpcId(3)));
+
+        storylet.tags = vec![syn_storylets::Tag::new("study")];
+        let result = engine.assign_roles_for_storylet(&storylet, Some(&[NpcId(2), NpcId(3)]));
+        assert_eq!(result.unwrap().mapping.get("friend"), Some(&NpcId(2)));
+    }
+
+    #[test]
+    fn test_tag_affinity_defaults_from_personality() {
+        let mut setup = TestSetup::new()
+            .with_npc_relationship(NpcId(1), NpcId(2), 5.0, 5.0, 0.0, 0.0)
+            .with_npc_relationship(NpcId(1), NpcId(3), 5.0, 5.0, 0.0, 0.0);
+        // Neither has authored affinities; NPC 3 is the chaotic one.
+        setup.world.npc_prototypes.insert(NpcId(2), prototype(2, -0.8, &[]));
+        setup.world.npc_prototypes.insert(NpcId(3), prototype(3, 0.9, &[]));
+
+        let engine = RoleAssignmentEngine {
+            world: &setup.world,
+            memory: &setup.memory,
+            current_tick: SimTick(0),
+        };
+        let role = RoleSlot {
+            name: "friend".to_string(),
+            required: true,
+            constraints: None,
+            allow_same_as: vec![],
+        };
+        let mut storylet = make_test_storylet("bar_fight", vec![role]);
+        storylet.tags = vec![syn_storylets::Tag::new("trouble")];
+
+        let result = engine.assign_roles_for_storylet(&storylet, Some(&[NpcId(2), NpcId(3)]));
+        assert_eq!(result.unwrap().mapping.get("friend"), Some(&NpcId(3)));
+    }
+
+    #[test]
+    fn test_major_character_cast_ahead_of_slightly_closer_friend() {
+        let mut setup = TestSetup::new()
+            .with_npc_relationship(NpcId(1), NpcId(2), 5.0, 5.0, 0.0, 0.0)
+            .with_npc_relationship(NpcId(1), NpcId(3), 4.5, 5.0, 0.0, 0.0);
+        let role = RoleSlot {
+            name: "friend".to_string(),
+            required: true,
+            constraints: None,
+            allow_same_as: vec![],
+        };
+        let storylet = make_test_storylet("coffee", vec![role]);
+        let pool = [NpcId(2), NpcId(3)];
+
+        let engine = RoleAssignmentEngine {
+            world: &setup.world,
+            memory: &setup.memory,
+            current_tick: SimTick(0),
+        };
+        let result = engine.assign_roles_for_storylet(&storylet, Some(&pool));
+        assert_eq!(result.unwrap().mapping.get("friend"), Some(&NpcId(2)));
+
+        syn_core::mark_major_character(
+            &mut setup.world,
+            NpcId(3),
+            syn_core::ImportanceSource::Emergent,
+        );
+        let engine = RoleAssignmentEngine {
+            world: &setup.world,
+            memory: &setup.memory,
+            current_tick: SimTick(0),
+        };
+        let result = engine.assign_roles_for_storylet(&storylet, Some(&pool));
+        assert_eq!(result.unwrap().mapping.get("friend"), Some(&NpcId(3)));
+    }
 }
@@ -42,6 +42,10 @@ pub struct ScoredCandidate {
     /// Bonus from advancing toward narrative milestones.
     pub milestone_bonus: f32,
     
+    /// Aging bonus for storylets that have been eligible but not selected.
+    #[serde(default)]
+    pub starvation_bonus: f32,
+    
     /// Penalty from pacing constraints (recency, variety).
     /// This is subtracted, not multiplied.
     pub pacing_penalty: f32,
@@ -51,7 +55,7 @@ pub struct ScoredCandidate {
     pub jitter: f32,
     
     /// Final computed score (before jitter).
-    /// `total_score = base_weight * heat_alignment * (1 + context_bonus + pressure_bonus + milestone_bonus) - pacing_penalty`
+    /// `total_score = base_weight * heat_alignment * (1 + context_bonus + pressure_bonus + milestone_bonus + starvation_bonus) - pacing_penalty`
     pub total_score: f32,
     
     /// Final score including jitter for selection.
@@ -77,6 +81,7 @@ impl ScoredCandidate {
             context_bonus,
             pressure_bonus,
             milestone_bonus,
+            0.0,
             pacing_penalty,
         );
         let selection_score = total_score + jitter;
@@ -88,6 +93,7 @@ impl ScoredCandidate {
             context_bonus,
             pressure_bonus,
             milestone_bonus,
+            starvation_bonus: 0.0,
             pacing_penalty,
             jitter,
             total_score,
@@ -95,6 +101,22 @@ impl ScoredCandidate {
         }
     }
     
+    /// Apply a starvation (aging) bonus and recompute the scores.
+    pub fn with_starvation_bonus(mut self, starvation_bonus: f32) -> Self {
+        self.starvation_bonus = starvation_bonus;
+        self.total_score = Self::compute_total(
+            self.base_weight,
+            self.heat_alignment,
+            self.context_bonus,
+            self.pressure_bonus,
+            self.milestone_bonus,
+            starvation_bonus,
+            self.pacing_penalty,
+        );
+        self.selection_score = self.total_score + self.jitter;
+        self
+    }
+    
     /// Compute the total score from components.
     fn compute_total(
         base_weight: f32,
@@ -102,9 +124,12 @@ impl ScoredCandidate {
         context_bonus: f32,
         pressure_bonus: f32,
         milestone_bonus: f32,
+        starvation_bonus: f32,
         pacing_penalty: f32,
     ) -> f32 {
-        let multiplied = base_weight * heat_alignment * (1.0 + context_bonus + pressure_bonus + milestone_bonus);
+        let multiplied = base_weight
+            * heat_alignment
+            * (1.0 + context_bonus + pressure_bonus + milestone_bonus + starvation_bonus);
         (multiplied - pacing_penalty).max(0.0)
     }
     
@@ -259,10 +284,14 @@ impl<'a> ScoringEngine<'a> {
         // 5. Milestone bonus (advances narrative milestones)
         let milestone_bonus = self.compute_milestone_bonus(storylet);
         
-        // 6. Pacing penalty (recency, variety)
-        let pacing_penalty = self.compute_pacing_penalty(storylet);
+        // 6. Starvation bonus (eligible but repeatedly passed over)
+        let starvation_bonus = self.compute_starvation_bonus(storylet);
+        
+        // 7. Pacing penalty (recency, variety, player fatigue)
+        let pacing_penalty =
+            self.compute_pacing_penalty(storylet) + self.compute_fatigue_penalty(storylet, world);
         
-        // 7. Deterministic jitter for tie-breaking
+        // 8. Deterministic jitter for tie-breaking
         let jitter = self.compute_jitter(storylet.key);
         
         ScoredCandidate::new(
@@ -275,6 +304,7 @@ impl<'a> ScoringEngine<'a> {
             pacing_penalty,
             jitter,
         )
+        .with_starvation_bonus(starvation_bonus)
     }
     
     /// Compute heat alignment multiplier.
@@ -349,6 +379,17 @@ impl<'a> ScoringEngine<'a> {
         bonus.min(1.0) // Cap at +100%
     }
     
+    /// Compute starvation (aging) bonus.
+    ///
+    /// Grows linearly with the ticks a storylet has been eligible but not
+    /// selected, after a grace period, up to `max_starvation_bonus`.
+    fn compute_starvation_bonus(&self, storylet: &CompiledStorylet) -> f32 {
+        let starved = self.state.starvation.starved_ticks(storylet.key);
+        let aged = starved.saturating_sub(self.scoring_config.starvation_grace_ticks);
+        let bonus = aged as f32 * self.scoring_config.starvation_bonus_per_tick;
+        bonus.clamp(0.0, self.scoring_config.max_starvation_bonus.max(0.0))
+    }
+    
     /// Compute pacing penalty.
     ///
     /// Penalizes storylets that:
@@ -378,6 +419,15 @@ impl<'a> ScoringEngine<'a> {
         penalty
     }
     
+    /// Compute fatigue penalty.
+    ///
+    /// As the player's daily action budget runs out, high-heat storylets are
+    /// pushed back so the day winds down.
+    fn compute_fatigue_penalty(&self, storylet: &CompiledStorylet, world: &WorldState) -> f32 {
+        let heat = (storylet.heat as f32 / 10.0).clamp(0.0, 1.0);
+        self.scoring_config.fatigue_penalty * world.action_budget.fatigue() * heat
+    }
+    
     /// Compute deterministic jitter for tie-breaking.
     ///
     /// Uses a hash of (key, tick, seed) to produce a small consistent value
@@ -794,6 +844,61 @@ mod tests {
             penalty_recent, penalty_fresh);
     }
 
+    #[test]
+    fn test_starvation_bonus_grows_and_caps() {
+        let mut state = create_test_state();
+        let scoring_config = create_test_scoring_config();
+        let pacing_config = create_test_pacing_config();
+        let niche = create_mock_storylet(1, 1.0, 3);
+        let grace = scoring_config.starvation_grace_ticks;
+
+        let bonus_after = |state: &DirectorState| {
+            ScoringEngine::new(&scoring_config, &pacing_config, state, 12345)
+                .score_storylet(&niche, &WorldState::new(syn_core::WorldSeed(1), NpcId::new(1)))
+                .starvation_bonus
+        };
+
+        assert_eq!(bonus_after(&state), 0.0);
+
+        state.starvation.starved_ticks.insert(niche.key, grace);
+        assert_eq!(bonus_after(&state), 0.0, "no bonus within the grace period");
+
+        state.starvation.starved_ticks.insert(niche.key, grace + 10);
+        let growing = bonus_after(&state);
+        assert!((growing - 10.0 * scoring_config.starvation_bonus_per_tick).abs() < 1e-6);
+
+        state.starvation.starved_ticks.insert(niche.key, grace + 100_000);
+        assert_eq!(bonus_after(&state), scoring_config.max_starvation_bonus);
+    }
+
+    #[test]
+    fn test_starvation_bonus_raises_total_score() {
+        let plain = ScoredCandidate::new(StoryletKey(1), 1.0, 1.0, 0.0, 0.0, 0.0, 0.0, 0.0);
+        let starved = plain.clone().with_starvation_bonus(1.0);
+        assert!((starved.total_score - 2.0 * plain.total_score).abs() < 1e-6);
+        assert!((starved.selection_score - starved.total_score).abs() < 1e-6);
+    }
+
+    #[test]
+    fn test_fatigue_penalizes_high_heat_storylets() {
+        let state = create_test_state();
+        let scoring_config = create_test_scoring_config();
+        let pacing_config = create_test_pacing_config();
+        let engine = ScoringEngine::new(&scoring_config, &pacing_config, &state, 12345);
+        let mut world = WorldState::new(syn_core::WorldSeed(1), NpcId::new(1));
+        let intense = create_mock_storylet(1, 1.0, 9);
+        let quiet = create_mock_storylet(2, 1.0, 1);
+
+        assert_eq!(engine.compute_fatigue_penalty(&intense, &world), 0.0);
+
+        let max_points = world.action_budget.max_points;
+        world.action_budget.spend(max_points);
+        let intense_penalty = engine.compute_fatigue_penalty(&intense, &world);
+        let quiet_penalty = engine.compute_fatigue_penalty(&quiet, &world);
+        assert!(intense_penalty > quiet_penalty);
+        assert!(intense_penalty <= scoring_config.fatigue_penalty);
+    }
+
     #[test]
     fn test_score_and_select_integration() {
         let state = create_test_state();
@@ -0,0 +1,308 @@
+//! Pluggable scoring for the legacy [`EventDirector`].
+//!
+//! The director ranks eligible storylets with a [`ScoringStrategy`]. The
+//! default, [`DefaultScoringStrategy`], is the fixed pipeline of multipliers
+//! and bonuses in [`storylet_scoring`](crate::storylet_scoring). Tests and
+//! experiments can swap in their own strategy (author-weighted, heuristic,
+//! ...) with [`EventDirector::set_scoring_strategy`] instead of forking the
+//! director.
+//!
+//! Every strategy returns a [`StoryletScore`]: the score plus a
+//! [`ScoreExplanation`] listing the factors that produced it.
+//!
+//! # Example
+//!
+//! ```ignore
+//! struct AuthorWeighted;
+//!
+//! impl ScoringStrategy for AuthorWeighted {
+//!     fn name(&self) -> &'static str {
+//!         "author_weighted"
+//!     }
+//!
+//!     fn score(&self, ctx: &ScoringContext<'_>) -> StoryletScore {
+//!         StoryletScore::from_base("weight", ctx.storylet.weight)
+//!     }
+//! }
+//!
+//! director.set_scoring_strategy(Box::new(AuthorWeighted));
+//! ```
+
+use std::fmt;
+
+use serde::{Deserialize, Serialize};
+use syn_core::narrative_heat::NarrativeHeatBand;
+use syn_core::relationship_pressure::RelationshipPressureEvent;
+use syn_core::{SessionPacingState, WorldState};
+use syn_sim::NpcRegistry;
+
+use crate::prereqs::check_time_and_location_prereqs;
+use crate::storylet_scoring::{
+    dependent_care_score_multiplier, difficulty_mix_score_multiplier,
+    digital_legacy_score_multiplier, district_affinity_score_multiplier,
+    fatigue_score_multiplier, forced_trigger_score_multiplier, heat_score_multiplier,
+    life_goal_score_multiplier, life_stage_score_multiplier, npc_intent_score_multiplier,
+    npc_tag_affinity_score_multiplier, post_life_nudge_score_multiplier,
+    relationship_heat_score_multiplier, score_district_pressure_bonus,
+    score_gossip_pressure_bonus, score_news_reaction_bonus, score_storylet_with_pressure,
+    session_score_multiplier, storylet_heat_band_match,
+};
+use crate::{EventDirector, Storylet};
+
+/// Everything a [`ScoringStrategy`] may look at for one storylet.
+#[derive(Clone, Copy)]
+pub struct ScoringContext<'a> {
+    pub director: &'a EventDirector,
+    pub world: &'a WorldState,
+    /// NPC registry, when the caller has one (enables intent and district
+    /// scoring, and time/location prerequisites).
+    pub registry: Option<&'a NpcRegistry>,
+    pub storylet: &'a Storylet,
+    /// The relationship pressure event at the head of the queue.
+    pub pressure: Option<&'a RelationshipPressureEvent>,
+    /// Current narrative heat band.
+    pub heat_band: NarrativeHeatBand,
+    /// How much of today's action budget is spent (0.0..=1.0).
+    pub fatigue: f32,
+    /// Play-session pacing.
+    pub session: &'a SessionPacingState,
+}
+
+impl<'a> ScoringContext<'a> {
+    /// Context for `storylet`, with pacing read from `world`.
+    pub fn new(
+        director: &'a EventDirector,
+        world: &'a WorldState,
+        storylet: &'a Storylet,
+        pressure: Option<&'a RelationshipPressureEvent>,
+    ) -> Self {
+        Self {
+            director,
+            world,
+            registry: None,
+            storylet,
+            pressure,
+            heat_band: world.narrative_heat.band(),
+            fatigue: world.action_budget.fatigue(),
+            session: &world.session_pacing,
+        }
+    }
+
+    /// Score with NPC intent and district affinity from `registry`.
+    pub fn with_registry(mut self, registry: &'a NpcRegistry) -> Self {
+        self.registry = Some(registry);
+        self
+    }
+}
+
+impl fmt::Debug for ScoringContext<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("ScoringContext")
+            .field("storylet", &self.storylet.id)
+            .field("tick", &self.world.current_tick)
+            .field("registry", &self.registry.is_some())
+            .field("pressure", &self.pressure)
+            .field("heat_band", &self.heat_band)
+            .field("fatigue", &self.fatigue)
+            .finish()
+    }
+}
+
+/// How a [`ScoreFactor`] combines with the score so far.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum ScoreFactorKind {
+    /// The starting score.
+    Base,
+    /// Multiplies the score so far.
+    Multiplier,
+    /// Added to the score so far.
+    Bonus,
+}
+
+/// One named step of a score.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct ScoreFactor {
+    pub name: String,
+    pub kind: ScoreFactorKind,
+    pub value: f32,
+}
+
+impl ScoreFactor {
+    /// Whether the factor left the score unchanged.
+    pub fn is_neutral(&self) -> bool {
+        match self.kind {
+            ScoreFactorKind::Base => false,
+            ScoreFactorKind::Multiplier => (self.value - 1.0).abs() < f32::EPSILON,
+            ScoreFactorKind::Bonus => self.value.abs() < f32::EPSILON,
+        }
+    }
+}
+
+/// The factors behind a score, in the order they were applied.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct ScoreExplanation {
+    pub factors: Vec<ScoreFactor>,
+    /// Anything else worth knowing (short circuits, clamping, ...).
+    pub notes: Vec<String>,
+}
+
+impl ScoreExplanation {
+    pub fn push(&mut self, name: impl Into<String>, kind: ScoreFactorKind, value: f32) {
+        self.factors.push(ScoreFactor {
+            name: name.into(),
+            kind,
+            value,
+        });
+    }
+
+    pub fn note(&mut self, note: impl Into<String>) {
+        self.notes.push(note.into());
+    }
+
+    /// Value of the first factor named `name`.
+    pub fn factor(&self, name: &str) -> Option<f32> {
+        self.factors.iter().find(|f| f.name == name).map(|f| f.value)
+    }
+}
+
+impl fmt::Display for ScoreExplanation {
+    /// `base 12.00 × heat 1.20 + news 10.00 (clamped to 100)`, skipping
+    /// neutral factors.
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let mut first = true;
+        for factor in self.factors.iter().filter(|factor| !factor.is_neutral()) {
+            let op = match factor.kind {
+                ScoreFactorKind::Base => "",
+                ScoreFactorKind::Multiplier => "× ",
+                ScoreFactorKind::Bonus => "+ ",
+            };
+            if !first {
+                f.write_str(" ")?;
+            }
+            write!(f, "{}{} {:.2}", op, factor.name, factor.value)?;
+            first = false;
+        }
+        for note in &self.notes {
+            write!(f, " ({})", note)?;
+        }
+        Ok(())
+    }
+}
+
+/// A strategy's verdict on one storylet.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct StoryletScore {
+    pub score: f32,
+    pub explanation: ScoreExplanation,
+}
+
+impl StoryletScore {
+    /// A score made of a single base factor.
+    pub fn from_base(name: impl Into<String>, score: f32) -> Self {
+        let mut explanation = ScoreExplanation::default();
+        explanation.push(name, ScoreFactorKind::Base, score);
+        Self { score, explanation }
+    }
+}
+
+/// Ranks eligible storylets for the legacy director. Higher scores win.
+pub trait ScoringStrategy: Send + Sync {
+    /// Stable name, for logs and experiment reports.
+    fn name(&self) -> &'static str;
+
+    /// Score the storylet in `ctx` and explain how.
+    fn score(&self, ctx: &ScoringContext<'_>) -> StoryletScore;
+}
+
+/// The standard pipeline: the director's base score with relationship
+/// pressure, times every multiplier in
+/// [`storylet_scoring`](crate::storylet_scoring) and the director's
+/// [`PacingBudget`](crate::PacingBudget), plus district, gossip and
+/// news bonuses. With a registry it also applies time/location
+/// prerequisites, NPC intent and district affinity, and clamps to 0..=100.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct DefaultScoringStrategy;
+
+impl ScoringStrategy for DefaultScoringStrategy {
+    fn name(&self) -> &'static str {
+        "default"
+    }
+
+    fn score(&self, ctx: &ScoringContext<'_>) -> StoryletScore {
+        let world = ctx.world;
+        let storylet = ctx.storylet;
+        let mut explanation = ScoreExplanation::default();
+
+        if let Some(registry) = ctx.registry {
+            if !check_time_and_location_prereqs(world, registry, storylet) {
+                explanation.note("time or location prerequisites not met");
+                return StoryletScore {
+                    score: 0.0,
+                    explanation,
+                };
+            }
+        }
+
+        let mut score = score_storylet_with_pressure(ctx.director, world, storylet, ctx.pressure);
+        explanation.push("base", ScoreFactorKind::Base, score);
+
+        let multipliers = [
+            ("heat", heat_score_multiplier(ctx.heat_band, storylet)),
+            ("life_stage", life_stage_score_multiplier(world, &storylet.prerequisites)),
+            (
+                "digital_legacy",
+                digital_legacy_score_multiplier(world, &storylet.prerequisites.digital_legacy_prereq),
+            ),
+            ("fatigue", fatigue_score_multiplier(world, storylet)),
+            ("session", session_score_multiplier(world, storylet)),
+            ("forced_trigger", forced_trigger_score_multiplier(world, storylet)),
+            ("npc_affinity", npc_tag_affinity_score_multiplier(world, storylet)),
+            ("pair_heat", relationship_heat_score_multiplier(world, storylet)),
+            ("life_goal", life_goal_score_multiplier(world, storylet)),
+            ("dependent_care", dependent_care_score_multiplier(world, storylet)),
+            ("difficulty", difficulty_mix_score_multiplier(world, storylet)),
+            ("post_life_nudge", post_life_nudge_score_multiplier(world, storylet)),
+            (
+                "pacing_budget",
+                ctx.director.pacing_budget().multiplier(storylet, world.current_tick),
+            ),
+        ];
+        for (name, mult) in multipliers {
+            score *= mult;
+            explanation.push(name, ScoreFactorKind::Multiplier, mult);
+        }
+
+        let bonuses = [
+            ("district_pressure", score_district_pressure_bonus(world, storylet)),
+            ("gossip_pressure", score_gossip_pressure_bonus(world, storylet)),
+            ("news", score_news_reaction_bonus(world, storylet)),
+        ];
+        for (name, bonus) in bonuses {
+            score += bonus;
+            explanation.push(name, ScoreFactorKind::Bonus, bonus);
+        }
+
+        if storylet.outcomes.heat_category.is_some()
+            && !storylet_heat_band_match(ctx.heat_band, storylet)
+        {
+            score *= 0.5;
+            explanation.push("heat_band_mismatch", ScoreFactorKind::Multiplier, 0.5);
+        }
+
+        if let Some(registry) = ctx.registry {
+            let intent_mult = npc_intent_score_multiplier(world, registry, storylet);
+            let district_mult = district_affinity_score_multiplier(world, registry, storylet);
+            explanation.push("npc_intent", ScoreFactorKind::Multiplier, intent_mult);
+            explanation.push("district_affinity", ScoreFactorKind::Multiplier, district_mult);
+            let unclamped = score * intent_mult * district_mult;
+            score = unclamped.clamp(0.0, 100.0);
+            if unclamped > 100.0 {
+                explanation.note("clamped to 100");
+            } else if unclamped < 0.0 {
+                explanation.note("clamped to 0");
+            }
+        }
+
+        StoryletScore { score, explanation }
+    }
+}
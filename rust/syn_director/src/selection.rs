@@ -0,0 +1,532 @@
+//! Storylet selection for the legacy director loop.
+//!
+//! Eligibility over a [`StoryletLibrary`](crate::StoryletLibrary), weighted
+//! deterministic picks, the [`DirectorEventView`] handed to the UI (with
+//! pending proposals, check-ins and goal storylets taking precedence), the
+//! guaranteed-response window for stat crises, the authoring preview of
+//! what would fire next, and the [`SelectionMode`] the director picks among
+//! scored storylets with.
+
+use serde::{Deserialize, Serialize};
+use syn_core::{
+    deterministic_rng_from_world, tone_context, DeterministicRng, NpcId, SimTick,
+    StoryletUsageState, ToneContext, WorldState,
+};
+use syn_memory::MemorySystem;
+use syn_sim::{tick_world, NpcRegistry, SimState};
+use syn_storylets::StoryDomain;
+
+use crate::{
+    active_choices, primary_npc, romance_allows, romance_response, EventDirector, Storylet,
+    StoryletLibrary,
+};
+use crate::prereqs::{
+    check_age_gate, check_content_preferences, check_digital_legacy_prereq,
+    check_life_stage_prereqs, declared_status_allows, storylet_check_condition_prereqs,
+    storylet_check_relationship_prereqs, storylet_check_time_and_location_prereqs, storylet_dependencies_met, trigger_context_ready,
+};
+use crate::scoring_strategy::ScoringContext;
+use crate::storylet_scoring::score_eligible_storylets;
+
+/// Variant selection API that uses NPC intent when available.
+pub fn select_next_event_with_registry<'a>(
+    director: &'a EventDirector,
+    world: &WorldState,
+    registry: &NpcRegistry,
+    memory: &MemorySystem,
+    current_tick: SimTick,
+) -> Option<&'a Storylet> {
+    let eligible = director.find_eligible(world, memory, current_tick);
+    if eligible.is_empty() {
+        return None;
+    }
+    let eligible = preempt_for_overdue_crisis(world, eligible, |s| *s, current_tick);
+    let hot_event_opt = world.relationship_pressure.peek_next_event();
+    let scored = eligible
+        .into_iter()
+        .map(|storylet| {
+            let ctx = ScoringContext::new(director, world, storylet, hot_event_opt)
+                .with_registry(registry);
+            (storylet, director.scoring_strategy().score(&ctx).score)
+        })
+        .collect();
+    director.pick_scored(world, scored, current_tick)
+}
+
+/// How the legacy director picks among scored eligible storylets.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Serialize, Deserialize)]
+pub enum SelectionMode {
+    /// The highest score wins; the earliest registered on ties.
+    #[default]
+    Best,
+    /// Sample one of the `k` best storylets, weighted by score.
+    ///
+    /// Before ranking, a storylet sharing a domain with a recent fire has its
+    /// score scaled by `1 - recency_penalty / (n + 1)`, where `n` is how many
+    /// fires ago that domain last fired (see [`storylet_domains`]). With
+    /// `recency_penalty` at 1.0 the domain that just fired can't fire again
+    /// straight away. Draws come from the world's deterministic RNG.
+    WeightedTopK { k: usize, recency_penalty: f32 },
+}
+
+/// The story domains a legacy storylet belongs to: its tags that name a
+/// [`StoryDomain`], or its ID when none does.
+pub fn storylet_domains(storylet: &Storylet) -> Vec<String> {
+    let mut domains: Vec<String> = storylet
+        .prerequisites
+        .tags
+        .iter()
+        .filter_map(|tag| StoryDomain::from_tag(tag))
+        .map(|domain| domain.as_str().to_string())
+        .collect();
+    domains.dedup();
+    if domains.is_empty() {
+        domains.push(storylet.id.clone());
+    }
+    domains
+}
+
+/// Score multiplier for `storylet` given the domains that fired recently.
+pub fn recency_multiplier(
+    usage: &StoryletUsageState,
+    storylet: &Storylet,
+    recency_penalty: f32,
+) -> f32 {
+    storylet_domains(storylet)
+        .iter()
+        .filter_map(|domain| usage.fires_since_domain(domain))
+        .min()
+        .map_or(1.0, |fires_ago| {
+            (1.0 - recency_penalty / (fires_ago + 1) as f32).max(0.0)
+        })
+}
+
+impl EventDirector {
+    /// Pick storylets with `mode` instead of always taking the best score.
+    pub fn set_selection_mode(&mut self, mode: SelectionMode) {
+        self.selection_mode = mode;
+    }
+
+    pub fn selection_mode(&self) -> SelectionMode {
+        self.selection_mode
+    }
+
+    /// Pick one of the scored storylets (in eligibility order) with the
+    /// director's [`SelectionMode`].
+    pub(crate) fn pick_scored<'a>(
+        &self,
+        world: &WorldState,
+        scored: Vec<(&'a Storylet, f32)>,
+        current_tick: SimTick,
+    ) -> Option<&'a Storylet> {
+        match self.selection_mode {
+            SelectionMode::Best => {
+                let mut best_storylet = None;
+                let mut best_score = f32::MIN;
+                for (storylet, score) in scored {
+                    if score > best_score {
+                        best_score = score;
+                        best_storylet = Some(storylet);
+                    }
+                }
+                best_storylet
+            }
+            SelectionMode::WeightedTopK { k, recency_penalty } => {
+                let mut ranked: Vec<(&Storylet, f32)> = scored
+                    .into_iter()
+                    .map(|(storylet, score)| {
+                        let multiplier =
+                            recency_multiplier(&world.storylet_usage, storylet, recency_penalty);
+                        (storylet, (score * multiplier).max(0.0))
+                    })
+                    .collect();
+                ranked.sort_by(|(a, a_score), (b, b_score)| {
+                    b_score.total_cmp(a_score).then_with(|| a.id.cmp(&b.id))
+                });
+                ranked.truncate(k.max(1));
+
+                let total: f32 = ranked.iter().map(|(_, score)| *score).sum();
+                if total <= 0.0 {
+                    return ranked.first().map(|(storylet, _)| *storylet);
+                }
+                let mut rng =
+                    DeterministicRng::with_domain(world.seed.0, current_tick.0, "storylet_selection");
+                let roll = rng.gen_f32() * total;
+                let mut acc = 0.0;
+                for (storylet, score) in &ranked {
+                    acc += *score;
+                    if roll < acc {
+                        return Some(storylet);
+                    }
+                }
+                ranked.last().map(|(storylet, _)| *storylet)
+            }
+        }
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct DirectorChoiceView {
+    pub id: String,
+    pub label: String,
+    /// The player lacks the skills this choice requires; shown but not
+    /// selectable.
+    #[serde(default)]
+    pub locked: bool,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct DirectorEventView {
+    pub storylet_id: String,
+    pub title: String,
+    pub choices: Vec<DirectorChoiceView>,
+    /// How each NPC in the scene feels about the player, by NPC id, so the
+    /// UI can tint their lines without separate content variants.
+    #[serde(default)]
+    pub tone: Vec<ToneContext>,
+}
+
+pub struct DirectorContext<'a> {
+    pub library: &'a StoryletLibrary,
+    pub world: &'a WorldState,
+    pub sim: &'a SimState,
+    pub usage: &'a StoryletUsageState,
+}
+
+pub fn storylet_is_eligible(
+    world: &WorldState,
+    sim: &SimState,
+    storylet: &Storylet,
+    usage: &StoryletUsageState,
+) -> bool {
+    let pre = &storylet.prerequisites;
+
+    if let Some(max) = storylet.outcomes.max_uses {
+        let used = usage.count(&storylet.id);
+        if used >= max {
+            return false;
+        }
+    }
+    if !storylet_dependencies_met(pre, usage) {
+        return false;
+    }
+    if !trigger_context_ready(world, storylet, world.current_tick) {
+        return false;
+    }
+
+    if !check_content_preferences(world, pre) {
+        return false;
+    }
+    if !check_age_gate(world, pre) {
+        return false;
+    }
+    if !storylet_check_condition_prereqs(world, pre) {
+        return false;
+    }
+    if !check_life_stage_prereqs(world, pre) {
+        return false;
+    }
+    if !storylet_check_relationship_prereqs(world, pre) {
+        return false;
+    }
+    if !declared_status_allows(world, storylet) {
+        return false;
+    }
+    if !storylet_check_time_and_location_prereqs(world, sim, storylet) {
+        return false;
+    }
+    if !check_digital_legacy_prereq(world, &pre.digital_legacy_prereq) {
+        return false;
+    }
+    if !romance_allows(romance_response(world, &sim.npc_registry, storylet), storylet) {
+        return false;
+    }
+
+    true
+}
+
+/// The guaranteed-response window for stat crises: once a crisis has waited
+/// its `response_window_ticks` for an intervention, only candidates answering
+/// it are kept, whatever they scored. With none eligible, selection carries
+/// on as usual.
+pub(crate) fn preempt_for_overdue_crisis<T>(
+    world: &WorldState,
+    candidates: Vec<T>,
+    storylet_of: impl Fn(&T) -> &Storylet,
+    current_tick: SimTick,
+) -> Vec<T> {
+    let Some(crisis) = world.stat_crisis.overdue(current_tick.0) else {
+        return candidates;
+    };
+    let kind = crisis.stat.trigger_kind();
+    let answers = |candidate: &T| {
+        storylet_of(candidate)
+            .triggers
+            .kind
+            .as_deref()
+            .is_some_and(|k| k.eq_ignore_ascii_case(&kind))
+    };
+    if candidates.iter().any(&answers) {
+        candidates.into_iter().filter(|c| answers(c)).collect()
+    } else {
+        candidates
+    }
+}
+
+pub fn select_storylet_weighted<'a>(
+    world: &WorldState,
+    sim: &SimState,
+    library: &'a StoryletLibrary,
+    usage: &StoryletUsageState,
+) -> Option<&'a Storylet> {
+    let scored = score_eligible_storylets(world, sim, library, usage);
+    let mut scored = preempt_for_overdue_crisis(world, scored, |(s, _)| *s, world.current_tick);
+
+    if scored.is_empty() {
+        return None;
+    }
+
+    let total: f32 = scored.iter().map(|(_, w)| *w).sum();
+    if total <= 0.0 {
+        scored.sort_by(|(a, _), (b, _)| a.id.cmp(&b.id));
+        return Some(scored[0].0);
+    }
+
+    let mut rng = deterministic_rng_from_world(world);
+    let roll = rng.gen_f32() * total;
+    let mut acc = 0.0;
+    for (s, w) in &scored {
+        acc += *w;
+        if roll <= acc {
+            return Some(s);
+        }
+    }
+
+    scored.last().map(|(s, _)| *s)
+}
+
+pub fn select_next_event_view(
+    world: &mut WorldState,
+    sim: &mut SimState,
+    library: &StoryletLibrary,
+) -> Option<DirectorEventView> {
+    if let Some(view) = pending_proposal_view(world).or_else(|| pending_check_in_view(world)) {
+        return Some(view);
+    }
+    // Drop queued goal storylets the library does not author.
+    world
+        .life_goals
+        .pending_storylets
+        .retain(|id| library.storylets.iter().any(|s| &s.id == id));
+    if let Some(storylet) = pending_goal_storylet(world, library) {
+        return Some(event_view_for(world, sim, storylet));
+    }
+    let usage = &world.storylet_usage;
+    let storylet = select_storylet_weighted(world, sim, library, usage)?;
+    Some(event_view_for(world, sim, storylet))
+}
+
+/// The oldest pending NPC proposal as an accept/reject event.
+///
+/// Proposals wait on the player, so they are delivered ahead of any authored
+/// storylet.
+pub(crate) fn pending_proposal_view(world: &WorldState) -> Option<DirectorEventView> {
+    let proposal = world.proposals.next_pending()?;
+    let (title, choices) = syn_core::proposal_prompt(world, proposal);
+    Some(DirectorEventView {
+        storylet_id: proposal.storylet_id(),
+        title,
+        choices: choices
+            .iter()
+            .map(|(id, label)| DirectorChoiceView {
+                id: id.to_string(),
+                label: label.to_string(),
+                locked: false,
+            })
+            .collect(),
+        tone: vec![tone_context(world, proposal.npc_id)],
+    })
+}
+
+/// The oldest pending major character check-in as a catch-up/brush-off event.
+///
+/// Check-ins are delivered right after proposals.
+pub(crate) fn pending_check_in_view(world: &WorldState) -> Option<DirectorEventView> {
+    let check_in = world.importance.next_check_in()?;
+    let (title, choices) = syn_core::check_in_prompt(world, check_in);
+    Some(DirectorEventView {
+        storylet_id: check_in.storylet_id(),
+        title,
+        choices: choices
+            .iter()
+            .map(|(id, label)| DirectorChoiceView {
+                id: id.to_string(),
+                label: label.to_string(),
+                locked: false,
+            })
+            .collect(),
+        tone: vec![tone_context(world, check_in.npc_id)],
+    })
+}
+
+/// The oldest queued life goal payoff/failure storylet the library authors.
+///
+/// Like proposals, these are delivered ahead of the weighted draw.
+pub(crate) fn pending_goal_storylet<'a>(
+    world: &WorldState,
+    library: &'a StoryletLibrary,
+) -> Option<&'a Storylet> {
+    world
+        .life_goals
+        .pending_storylets
+        .iter()
+        .find_map(|id| library.storylets.iter().find(|s| &s.id == id))
+}
+
+/// The view of `storylet` handed to the UI: its title, the choices open in
+/// this world (those the player lacks the skills for marked locked) and the
+/// tone of the NPCs on stage.
+pub fn event_view_for(world: &WorldState, sim: &SimState, storylet: &Storylet) -> DirectorEventView {
+    let choices = active_choices(world, &sim.npc_registry, storylet)
+        .iter()
+        .map(|c| DirectorChoiceView {
+            id: c.id.clone(),
+            label: c.label.clone(),
+            locked: !c.is_unlocked(world),
+        })
+        .collect();
+
+    DirectorEventView {
+        storylet_id: storylet.id.clone(),
+        title: storylet.name.clone(),
+        choices,
+        tone: scene_tone(world, sim, storylet),
+    }
+}
+
+/// Tone contexts for the NPCs a storylet puts on stage: its primary NPC,
+/// its roles and its relationship prerequisite targets, in NPC id order.
+pub(crate) fn scene_tone(world: &WorldState, sim: &SimState, storylet: &Storylet) -> Vec<ToneContext> {
+    let mut cast: Vec<NpcId> = primary_npc(world, &sim.npc_registry, storylet)
+        .into_iter()
+        .chain(storylet.roles.iter().map(|role| role.npc_id))
+        .chain(
+            storylet
+                .prerequisites
+                .relationship_prereqs
+                .iter()
+                .map(|r| NpcId(r.target_id)),
+        )
+        .filter(|&id| id != world.player_id)
+        .collect();
+    cast.sort_unstable();
+    cast.dedup();
+    cast.into_iter().map(|id| tone_context(world, id)).collect()
+}
+
+/// One eligible storylet in a [`DirectorPreview`].
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct DirectorPreviewCandidate {
+    /// Storylet id.
+    pub storylet_id: String,
+    /// Display title.
+    pub title: String,
+    /// Selection weight (see `score_storylet_full_simple`).
+    pub weight: f32,
+    /// Share of the total weight: the chance of being drawn (0.0..=1.0).
+    pub chance: f32,
+}
+
+/// What the director loop would pick next, and why.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct DirectorPreview {
+    /// The event `select_next_event_view` would return right now.
+    pub selected: Option<DirectorEventView>,
+    /// Every eligible storylet, highest weight first.
+    pub candidates: Vec<DirectorPreviewCandidate>,
+}
+
+/// Dry run of [`select_next_event_view`] for authoring tools.
+///
+/// Only borrows the world and simulation, so it can be called as often as a
+/// "what's next" panel refreshes: usage counts, pressure queues and the
+/// selection roll (seeded from the world) are left exactly as they were.
+pub fn preview_next_event(
+    world: &WorldState,
+    sim: &SimState,
+    library: &StoryletLibrary,
+) -> DirectorPreview {
+    let usage = &world.storylet_usage;
+    let scored = score_eligible_storylets(world, sim, library, usage);
+    let total: f32 = scored.iter().map(|(_, w)| *w).sum();
+
+    let mut candidates: Vec<DirectorPreviewCandidate> = scored
+        .iter()
+        .map(|(s, weight)| DirectorPreviewCandidate {
+            storylet_id: s.id.clone(),
+            title: s.name.clone(),
+            weight: *weight,
+            chance: if total > 0.0 { weight / total } else { 0.0 },
+        })
+        .collect();
+    candidates.sort_by(|a, b| {
+        b.weight
+            .total_cmp(&a.weight)
+            .then_with(|| a.storylet_id.cmp(&b.storylet_id))
+    });
+
+    DirectorPreview {
+        selected: pending_proposal_view(world)
+            .or_else(|| pending_check_in_view(world))
+            .or_else(|| {
+                pending_goal_storylet(world, library)
+                    .or_else(|| select_storylet_weighted(world, sim, library, usage))
+                    .map(|storylet| event_view_for(world, sim, storylet))
+            }),
+        candidates,
+    }
+}
+
+/// Advance the simulation (if asked) and pick the next event.
+pub fn advance_and_select(
+    world: &mut WorldState,
+    sim: &mut SimState,
+    library: &StoryletLibrary,
+    ticks_to_advance: u32,
+) -> Option<DirectorEventView> {
+    if ticks_to_advance > 0 {
+        tick_world(world, sim, ticks_to_advance);
+    }
+
+    select_next_event_view(world, sim, library)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use syn_core::WorldSeed;
+
+    #[test]
+    fn registry_selection_picks_the_best_scoring_storylet() {
+        let mut director = EventDirector::new();
+        let world = WorldState::new(WorldSeed(7), NpcId(1));
+        let memory = MemorySystem::new();
+        let registry = NpcRegistry::default();
+        let select = |director: &EventDirector| {
+            select_next_event_with_registry(director, &world, &registry, &memory, SimTick(0))
+                .map(|s| s.id.clone())
+        };
+        assert_eq!(select(&director), None);
+
+        for (id, weight) in [("quiet", 0.2), ("big", 2.0)] {
+            director.register_storylet(Storylet {
+                id: id.to_string(),
+                name: id.to_string(),
+                heat: 10,
+                weight,
+                ..Storylet::default()
+            });
+        }
+        assert_eq!(select(&director).as_deref(), Some("big"));
+    }
+}
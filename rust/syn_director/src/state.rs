@@ -50,6 +50,10 @@ pub struct DirectorState {
     
     /// Tick when the current phase started (for min_phase_duration checks).
     pub phase_started_at: SimTick,
+    
+    /// How long eligible storylets have gone without being selected.
+    #[serde(default)]
+    pub starvation: StarvationState,
 }
 
 impl DirectorState {
@@ -65,6 +69,7 @@ impl DirectorState {
             cooldowns: CooldownState::new(),
             last_fired: LastFiredState::new(),
             phase_started_at: SimTick::new(0),
+            starvation: StarvationState::new(),
         }
     }
 
@@ -185,6 +190,49 @@ pub enum NarrativePhase {
     Recovery,
 }
 
+/// Tracks how long eligible storylets have gone without being selected.
+///
+/// Rarely-eligible storylets otherwise keep losing to perennial high-weight
+/// content. The scoring engine turns these counters into a growing aging
+/// bonus; a storylet's counter resets when it fires.
+#[derive(Debug, Clone, Serialize, Deserialize, Default)]
+pub struct StarvationState {
+    /// Ticks each storylet was a candidate but not selected.
+    pub starved_ticks: HashMap<StoryletKey, u64>,
+}
+
+impl StarvationState {
+    /// Create a new empty StarvationState.
+    pub fn new() -> Self {
+        StarvationState {
+            starved_ticks: HashMap::new(),
+        }
+    }
+
+    /// Ticks a storylet has been eligible without being selected.
+    pub fn starved_ticks(&self, key: StoryletKey) -> u64 {
+        self.starved_ticks.get(&key).copied().unwrap_or(0)
+    }
+
+    /// Record a selection pass: every candidate except `selected` ages by one
+    /// tick, and `selected` (if any) is reset.
+    pub fn record_pass(&mut self, candidates: &[StoryletKey], selected: Option<StoryletKey>) {
+        for &key in candidates {
+            if Some(key) != selected {
+                *self.starved_ticks.entry(key).or_insert(0) += 1;
+            }
+        }
+        if let Some(key) = selected {
+            self.reset(key);
+        }
+    }
+
+    /// Reset a storylet's counter (called when it fires).
+    pub fn reset(&mut self, key: StoryletKey) {
+        self.starved_ticks.remove(&key);
+    }
+}
+
 /// Tracks when storylets, domains, and tags last fired.
 ///
 /// Used for:
@@ -545,6 +593,26 @@ mod tests {
         assert_eq!(state.narrative_phase, NarrativePhase::LowKey);
     }
 
+    #[test]
+    fn test_starvation_state_ages_and_resets() {
+        let mut starvation = StarvationState::new();
+        let (a, b) = (StoryletKey(1), StoryletKey(2));
+
+        starvation.record_pass(&[a, b], Some(a));
+        starvation.record_pass(&[a, b], Some(a));
+        assert_eq!(starvation.starved_ticks(a), 0);
+        assert_eq!(starvation.starved_ticks(b), 2);
+
+        // No selection at all still ages every candidate.
+        starvation.record_pass(&[a, b], None);
+        assert_eq!(starvation.starved_ticks(a), 1);
+        assert_eq!(starvation.starved_ticks(b), 3);
+
+        starvation.record_pass(&[a, b], Some(b));
+        assert_eq!(starvation.starved_ticks(a), 2);
+        assert_eq!(starvation.starved_ticks(b), 0);
+    }
+
     #[test]
     fn test_narrative_phase_default() {
         let phase = NarrativePhase::default();
@@ -0,0 +1,240 @@
+//! Harness for the `test_fixture` blocks embedded in storylet JSON.
+//!
+//! Each fixture (see `syn_storylets::fixtures`) describes a minimal world, whether
+//! its storylet should be eligible there, and optionally a golden
+//! [`OutcomeSummary`]. [`run_storylet_fixtures`] compiles a storylet directory,
+//! builds each fixture's world, runs the same eligibility filters the director
+//! uses, and reports every mismatch, so a content regression fails CI without a
+//! hand-written Rust test per storylet.
+
+use std::path::{Path, PathBuf};
+
+use syn_core::types::{AbstractNpc, AttachmentStyle, Relationship, Traits};
+use syn_core::{NpcId, SimTick, StatKind, WorldSeed, WorldState};
+use syn_memory::{MemoryEntry, MemorySystem};
+use syn_storylets::compiler::StoryletCompiler;
+use syn_storylets::errors::StoryletCompileError;
+use syn_storylets::fixtures::{FixtureWorld, OutcomeSummary, StoryletTestFixture};
+use syn_storylets::library::{CompiledStorylet, StoryletLibrary};
+use syn_storylets::validation::default_storylet_validator;
+use syn_storylets::StoryletId;
+
+use crate::eligibility::{
+    convert_storylet_life_stage_to_core, EligibilityContext, EligibilityEngine,
+};
+
+/// Player id used by fixture worlds; role NPCs are numbered after it.
+pub const FIXTURE_PLAYER_ID: NpcId = NpcId(1);
+
+/// One fixture that did not hold.
+#[derive(Debug, Clone, PartialEq)]
+pub struct FixtureFailure {
+    /// Storylet the fixture belongs to.
+    pub storylet_id: StoryletId,
+    /// JSON file the storylet was loaded from.
+    pub path: PathBuf,
+    /// Fixture description, if authored.
+    pub description: Option<String>,
+    /// What went wrong, one line per mismatch.
+    pub mismatches: Vec<String>,
+}
+
+impl std::fmt::Display for FixtureFailure {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{} ({})", self.storylet_id.0, self.path.display())?;
+        if let Some(description) = &self.description {
+            write!(f, " [{}]", description)?;
+        }
+        for mismatch in &self.mismatches {
+            write!(f, "\n  - {}", mismatch)?;
+        }
+        Ok(())
+    }
+}
+
+/// Result of running every fixture in a storylet directory.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct FixtureReport {
+    /// Number of fixtures run.
+    pub run: usize,
+    /// Fixtures that failed.
+    pub failures: Vec<FixtureFailure>,
+}
+
+impl FixtureReport {
+    /// Whether every fixture held.
+    pub fn passed(&self) -> bool {
+        self.failures.is_empty()
+    }
+}
+
+/// Compile `dir` and run every embedded storylet fixture in it.
+///
+/// Compilation errors are returned as-is; fixture mismatches land in the report.
+pub fn run_storylet_fixtures<P: AsRef<Path>>(
+    dir: P,
+) -> Result<FixtureReport, Vec<StoryletCompileError>> {
+    let compiler = StoryletCompiler::new(default_storylet_validator());
+    let library = compiler.compile_from_dir(dir.as_ref())?;
+    let fixtures = compiler.load_test_fixtures(dir.as_ref())?;
+
+    let mut report = FixtureReport::default();
+    for (id, path, fixture) in fixtures {
+        report.run += 1;
+        let mismatches = match library.get_by_id(&id) {
+            Some(storylet) => check_storylet_fixture(&library, storylet, &fixture),
+            None => vec!["storylet missing from compiled library".to_string()],
+        };
+        if !mismatches.is_empty() {
+            report.failures.push(FixtureFailure {
+                storylet_id: id,
+                path,
+                description: fixture.description.clone(),
+                mismatches,
+            });
+        }
+    }
+    Ok(report)
+}
+
+/// Check one storylet against its fixture; returns the mismatches.
+pub fn check_storylet_fixture(
+    library: &StoryletLibrary,
+    storylet: &CompiledStorylet,
+    fixture: &StoryletTestFixture,
+) -> Vec<String> {
+    let mut mismatches = Vec::new();
+
+    let (world, memory) = fixture_world(&fixture.world, storylet);
+    let ctx = EligibilityContext {
+        world: &world,
+        memory: &memory,
+        current_tick: world.current_tick,
+    };
+    let eligible = EligibilityEngine::new(library)
+        .find_eligible_storylets(&ctx)
+        .contains(&storylet.key);
+    if eligible != fixture.expect_eligible {
+        mismatches.push(format!(
+            "expected eligible = {}, got {}",
+            fixture.expect_eligible, eligible
+        ));
+    }
+
+    if let Some(expected) = &fixture.expected_outcome {
+        let actual = OutcomeSummary::from_outcome(&storylet.outcomes);
+        mismatches.extend(
+            expected
+                .mismatches(&actual)
+                .into_iter()
+                .map(|m| format!("outcome {}", m)),
+        );
+    }
+
+    mismatches
+}
+
+/// Build the world and memory system a fixture describes.
+///
+/// The player defaults to the storylet's life stage. Every role other than
+/// "protagonist"/"player" named in a relationship becomes a known NPC.
+pub fn fixture_world(
+    setup: &FixtureWorld,
+    storylet: &CompiledStorylet,
+) -> (WorldState, MemorySystem) {
+    let player = FIXTURE_PLAYER_ID;
+    let mut world = WorldState::new(WorldSeed(0), player);
+    let mut memory = MemorySystem::new();
+
+    world.player_life_stage =
+        convert_storylet_life_stage_to_core(setup.life_stage.unwrap_or(storylet.life_stage));
+
+    for (name, value) in &setup.stats {
+        if let Some(kind) = StatKind::from_name(name) {
+            world.player_stats.set(kind, *value);
+        }
+    }
+
+    let mut traits = Traits::default();
+    for (name, value) in &setup.traits {
+        traits.set_by_name(name, *value);
+    }
+    world.npcs.insert(player, fixture_npc(player, traits));
+
+    for flag in &setup.flags {
+        world.world_flags.set_dynamic(flag.clone());
+    }
+    for (key, value) in &setup.district_state {
+        world.district_state.insert(key.clone(), value.clone());
+    }
+    for id in &setup.fired_storylets {
+        world.storylet_usage.record_fire(&id.0);
+    }
+    for (i, tag) in setup.memory_tags.iter().enumerate() {
+        let entry = MemoryEntry::new(
+            format!("fixture:{}", i),
+            "fixture".to_string(),
+            player,
+            SimTick(0),
+            0.0,
+        )
+        .with_tags(vec![tag.clone()]);
+        memory.record_memory(entry, None);
+    }
+
+    let mut roles: Vec<String> = Vec::new();
+    let mut role_npc = |role: &str, world: &mut WorldState| -> NpcId {
+        if is_player_role(role) {
+            return player;
+        }
+        let index = match roles.iter().position(|r| r == role) {
+            Some(index) => index,
+            None => {
+                roles.push(role.to_string());
+                roles.len() - 1
+            }
+        };
+        let id = NpcId(player.0 + 1 + index as u64);
+        world
+            .npcs
+            .entry(id)
+            .or_insert_with(|| fixture_npc(id, Traits::default()));
+        world.ensure_npc_known(id);
+        id
+    };
+    for rel in &setup.relationships {
+        let from = role_npc(&rel.from_role, &mut world);
+        let to = role_npc(&rel.to_role, &mut world);
+        let mut relationship = Relationship::default();
+        for (axis, value) in &rel.axes {
+            match axis.to_lowercase().as_str() {
+                "affection" => relationship.affection = *value,
+                "trust" => relationship.trust = *value,
+                "attraction" => relationship.attraction = *value,
+                "familiarity" => relationship.familiarity = *value,
+                "resentment" => relationship.resentment = *value,
+                _ => {}
+            }
+        }
+        world.set_relationship(from, to, relationship);
+    }
+
+    (world, memory)
+}
+
+fn is_player_role(role: &str) -> bool {
+    role.eq_ignore_ascii_case("protagonist") || role.eq_ignore_ascii_case("player")
+}
+
+fn fixture_npc(id: NpcId, traits: Traits) -> AbstractNpc {
+    AbstractNpc {
+        id,
+        age: 30,
+        job: String::new(),
+        district: String::new(),
+        household_id: id.0,
+        traits,
+        seed: id.0,
+        attachment_style: AttachmentStyle::Secure,
+    }
+}
@@ -5,10 +5,9 @@ use std::{
     path::Path,
 };
 
-use crate::{storylet_loader, Storylet, TagBitset};
+use syn_core::StoryletHandle;
 
-/// Stable identifier for a storylet within the library.
-pub type StoryletId = String;
+use crate::{storylet_loader, Storylet, TagBitset};
 
 /// Minimal event context used for storylet eligibility checks.
 #[derive(Debug, Clone, Default, Serialize, Deserialize)]
@@ -68,6 +67,12 @@ pub struct EventContext {
     pub relationship_vector: RelationshipVectorContext,
     #[serde(default)]
     pub needs: NeedsContext,
+    /// Player stats by lowercase name, for stat conditions.
+    #[serde(default)]
+    pub stats: HashMap<String, f32>,
+    /// Player traits by lowercase name, for personality conditions.
+    #[serde(default)]
+    pub traits: HashMap<String, f32>,
     #[serde(default)]
     pub memory_echoes: Vec<String>,
     #[serde(default)]
@@ -81,17 +86,24 @@ pub struct EventContext {
     #[serde(default)]
     pub lod_tier: u8,
     #[serde(default)]
-    pub storylet_cooldowns: std::collections::HashMap<String, u64>,
+    pub storylet_cooldowns: std::collections::HashMap<StoryletHandle, u64>,
     #[serde(default)]
     pub seed: u64,
 }
 
 /// Container for all compiled storylets plus a tag index for fast lookup.
+///
+/// Handles are interned once as storylets are ingested; call
+/// [`Self::rebuild_index`] after deserializing one.
 #[derive(Debug, Clone, Default, Serialize, Deserialize)]
 pub struct StoryletLibrary {
     pub storylets: Vec<Storylet>,
+    /// Tag bitset -> storylet handles.
     #[serde(default)]
-    pub tag_index: HashMap<TagBitset, Vec<StoryletId>>,
+    pub tag_index: HashMap<TagBitset, Vec<StoryletHandle>>,
+    /// Storylet handle -> position in `storylets`.
+    #[serde(skip)]
+    handles: HashMap<StoryletHandle, usize>,
 }
 
 impl StoryletLibrary {
@@ -100,6 +112,7 @@ impl StoryletLibrary {
         Self {
             storylets: Vec::new(),
             tag_index: HashMap::new(),
+            handles: HashMap::new(),
         }
     }
 
@@ -152,6 +165,7 @@ impl StoryletLibrary {
         Self {
             storylets: Vec::new(),  // Legacy field, not used with binary
             tag_index: HashMap::new(),  // Legacy field, not used with binary
+            handles: HashMap::new(),
         }
     }
 
@@ -176,27 +190,28 @@ impl StoryletLibrary {
 
         self.tag_index
             .get(&context.required_tags)
-            .map(|ids| {
-                ids.iter()
-                    .filter_map(|id| self.storylets.iter().find(|s| &s.id == id))
-                    .collect()
-            })
+            .map(|handles| handles.iter().filter_map(|handle| self.get(*handle)).collect())
             .unwrap_or_default()
     }
 
+    /// The storylet with `handle`, if the library holds one.
+    pub fn get(&self, handle: StoryletHandle) -> Option<&Storylet> {
+        self.handles.get(&handle).map(|&index| &self.storylets[index])
+    }
+
     /// Rebuild the tag index based on current storylets.
     pub fn rebuild_index(&mut self) {
         let storylets = std::mem::take(&mut self.storylets);
+        self.tag_index.clear();
+        self.handles.clear();
         self.ingest_storylets(storylets);
     }
 
     fn ingest_storylets(&mut self, storylets: Vec<Storylet>) {
         for storylet in storylets.into_iter() {
-            let bitset = storylet.tags;
-            self.tag_index
-                .entry(bitset)
-                .or_default()
-                .push(storylet.id.clone());
+            let handle = StoryletHandle::new(&storylet.id);
+            self.tag_index.entry(storylet.tags).or_default().push(handle);
+            self.handles.entry(handle).or_insert(self.storylets.len());
             self.storylets.push(storylet);
         }
     }
@@ -2,7 +2,7 @@ use serde::Deserialize;
 use std::path::Path;
 
 use crate::{
-    storylet_library::tags_to_bitset, Storylet, StoryletCooldown, StoryletOutcomeSet,
+    storylet_library::tags_to_bitset, RoleScoring, Storylet, StoryletCooldown, StoryletOutcomeSet,
     StoryletPrerequisites, StoryletRole, StoryletRoles, StoryletTrigger,
 };
 
@@ -17,6 +17,11 @@ pub(crate) struct StoryletSerde {
     pub prerequisites: StoryletPrerequisites,
     #[serde(default)]
     pub roles: Vec<StoryletRole>,
+    /// Roles cast from the world when the storylet fires.
+    #[serde(default)]
+    pub role_slots: Vec<syn_storylets::RoleSlot>,
+    #[serde(default)]
+    pub role_scoring: RoleScoring,
     pub heat: i32,
     #[serde(default)]
     pub triggers: StoryletTrigger,
@@ -33,7 +38,10 @@ impl From<StoryletSerde> for Storylet {
             src.id,
             tags_to_bitset(&src.tags),
             src.prerequisites,
-            StoryletRoles::from(src.roles),
+            StoryletRoles {
+                scoring: src.role_scoring,
+                ..StoryletRoles::from(src.roles).with_open(src.role_slots)
+            },
             src.heat,
             src.triggers,
             src.outcomes,
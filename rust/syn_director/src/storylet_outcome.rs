@@ -4,7 +4,8 @@ use crate::{InteractionTone, StoryletActors, StoryletChoice, StoryletHeatCategor
 use syn_core::{Stats, Relationship};
 use syn_memory::MemorySystem;
 use syn_storage::HybridStorage;
-use crate::{StatDelta, RelationshipDelta};
+use syn_storylets::FlagOperation;
+use syn_core::{relationship_model::RelationshipDelta, DistrictEffect, StatDelta};
 
 #[derive(Debug, Clone, Serialize, Deserialize, Default)]
 pub struct MemoryEntryTemplate {
@@ -14,14 +15,25 @@ pub struct MemoryEntryTemplate {
     pub summary: String,
 }
 
+/// A world flag to set (`value: true`) or clear. Also accepts the compiled
+/// format's `set` key.
 #[derive(Debug, Clone, Serialize, Deserialize, Default)]
 pub struct WorldFlagUpdate {
     #[serde(default)]
     pub flag: String,
-    #[serde(default)]
+    #[serde(default, alias = "set")]
     pub value: bool,
 }
 
+impl From<FlagOperation> for WorldFlagUpdate {
+    fn from(op: FlagOperation) -> Self {
+        WorldFlagUpdate {
+            flag: op.flag,
+            value: op.set,
+        }
+    }
+}
+
 /// Outcome set for a storylet (GDD 3.16.4)
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct StoryletOutcomeSet {
@@ -35,10 +47,17 @@ pub struct StoryletOutcomeSet {
     pub memory: MemoryEntryTemplate,
     #[serde(default)]
     pub flags: Vec<WorldFlagUpdate>,
+    /// Economy, crime and flag changes to named districts (or the player's).
+    #[serde(default)]
+    pub district_effects: Vec<DistrictEffect>,
 
     // Existing metadata used by director
     #[serde(default)]
     pub choices: Vec<StoryletChoice>,
+    /// Refusal beat offered instead of `choices` when a romance storylet's
+    /// target does not reciprocate. Without one, such storylets are ineligible.
+    #[serde(default)]
+    pub rejection_choices: Vec<StoryletChoice>,
     #[serde(default)]
     pub max_uses: Option<u32>,
     #[serde(default)]
@@ -57,7 +76,9 @@ impl Default for StoryletOutcomeSet {
             relationship_deltas: Vec::new(),
             memory: MemoryEntryTemplate::default(),
             flags: Vec::new(),
+            district_effects: Vec::new(),
             choices: Vec::new(),
+            rejection_choices: Vec::new(),
             max_uses: None,
             heat_category: None,
             actors: None,
@@ -70,7 +91,7 @@ impl Default for StoryletOutcomeSet {
 #[derive(Default)]
 pub struct SimulationContext<'a> {
     pub world: Option<&'a mut crate::WorldState>,
-    pub sim: Option<&'a mut crate::SimState>,
+    pub sim: Option<&'a mut syn_sim::SimState>,
     pub stats: Option<&'a mut Stats>,
     pub relationships: Option<&'a mut Relationship>,
     pub memory: Option<&'a mut MemorySystem>,
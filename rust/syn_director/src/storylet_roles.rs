@@ -20,6 +20,10 @@ pub struct StoryletRoles {
     pub required: Vec<RoleSlot>,
     #[serde(default)]
     pub scoring: RoleScoring,
+    /// Roles cast from the world when the storylet fires (see
+    /// [`CastingEngine`](crate::CastingEngine)).
+    #[serde(default)]
+    pub open: Vec<syn_storylets::RoleSlot>,
 }
 
 impl StoryletRoles {
@@ -27,9 +31,16 @@ impl StoryletRoles {
         StoryletRoles {
             required,
             scoring: RoleScoring::default(),
+            open: Vec::new(),
         }
     }
 
+    /// Add roles to cast from the world.
+    pub fn with_open(mut self, open: Vec<syn_storylets::RoleSlot>) -> Self {
+        self.open = open;
+        self
+    }
+
     /// Assign roles based on context (placeholder).
     pub fn assign(&self, _ctx: &EventContext) -> Option<RoleAssignment> {
         // Stub: integrate role selection logic when available.
@@ -0,0 +1,716 @@
+//! Score multipliers and bonuses for the legacy
+//! [`EventDirector`](crate::EventDirector).
+//!
+//! Each factor (narrative heat, pressure, NPC intent and affinity, life goals,
+//! dependents, difficulty, fatigue, session pacing, news, ...) is a small
+//! function of the world and the storylet. The director combines them through
+//! its [`ScoringStrategy`](crate::ScoringStrategy), by default
+//! [`DefaultScoringStrategy`]; [`score_storylet_full_simple`] is the
+//! registry-driven variant used by the director loop. The compiled pipeline's
+//! scoring lives in [`scoring`](crate::scoring).
+
+use syn_core::{LifeStage, NEWS_REACTION_TAG, NpcId, StoryletUsageState, WorldState};
+use syn_core::district_pressure::DistrictPressureEvent;
+use syn_core::gossip_pressure::{GossipEventKind, GossipPressureEvent};
+use syn_core::narrative_heat::NarrativeHeatBand;
+use syn_core::npc_behavior::{BehaviorKind, BehaviorSnapshot};
+use syn_core::relationship_model::RelationshipAxis as ModelRelationshipAxis;
+use syn_core::relationship_pressure::{RelationshipEventKind, RelationshipPressureEvent};
+use syn_memory::MemorySystem;
+use syn_sim::{NpcRegistry, SimState};
+
+use crate::{
+    resolve_actor_ref_to_npc, scene_districts, EventDirector, InteractionTone, RoleAssignments,
+    Storylet, StoryletHeatCategory, StoryletLibrary, TagBitset,
+};
+use crate::prereqs::{DigitalLegacyPrereq, StoryletPrerequisites};
+use crate::scoring_strategy::{DefaultScoringStrategy, ScoringContext, ScoringStrategy};
+use crate::selection::storylet_is_eligible;
+
+/// Public helper: score multiplier from a storylet's preferred districts.
+///
+/// Uses the strongest affinity matching the player's or primary NPC's
+/// district; 1.0 when the storylet has no affinity for either.
+pub fn district_affinity_score_multiplier(
+    world: &WorldState,
+    registry: &NpcRegistry,
+    storylet: &Storylet,
+) -> f32 {
+    let Some(pr) = &storylet.prerequisites.time_and_location else {
+        return 1.0;
+    };
+    if pr.preferred_districts.is_empty() {
+        return 1.0;
+    }
+
+    let districts = scene_districts(world, registry, storylet);
+    pr.preferred_districts
+        .iter()
+        .filter(|affinity| {
+            districts
+                .iter()
+                .any(|d| d.eq_ignore_ascii_case(&affinity.district))
+        })
+        .map(|affinity| affinity.multiplier.max(0.0))
+        .reduce(f32::max)
+        .unwrap_or(1.0)
+}
+
+/// Internal: access an NPC's current behavior snapshot from the registry.
+pub(crate) fn get_npc_behavior<'a>(registry: &'a NpcRegistry, npc_id: NpcId) -> Option<&'a BehaviorSnapshot> {
+    registry.get(npc_id)?.behavior.as_ref()
+}
+
+pub(crate) fn tone_matches_behavior(tone: &InteractionTone, beh: &BehaviorKind) -> bool {
+    match (tone, beh) {
+        (InteractionTone::Support, BehaviorKind::SeekSocial) => true,
+        (InteractionTone::Attention, BehaviorKind::SeekRecognition) => true,
+        (InteractionTone::Conflict, BehaviorKind::SeekAutonomy) => true,
+        (InteractionTone::Withdrawal, BehaviorKind::SeekComfort) => true,
+        (InteractionTone::Stability, BehaviorKind::SeekSecurity) => true,
+        _ => false,
+    }
+}
+
+/// Public helper: compute an intent-based score multiplier for a storylet.
+/// Additive and side-effect free.
+pub fn npc_intent_score_multiplier(
+    world: &WorldState,
+    registry: &NpcRegistry,
+    storylet: &Storylet,
+) -> f32 {
+    let tone = match &storylet.outcomes.interaction_tone {
+        Some(t) => t,
+        None => return 1.0,
+    };
+
+    let Some(actors) = &storylet.outcomes.actors else {
+        return 1.0;
+    };
+
+    if let Some(primary_ref) = &actors.primary {
+        if let Some(npc_id) = resolve_actor_ref_to_npc(world, registry, primary_ref) {
+            if let Some(snapshot) = get_npc_behavior(registry, npc_id) {
+                return if tone_matches_behavior(tone, &snapshot.chosen_intent.kind) {
+                    1.3
+                } else {
+                    0.9
+                }
+            }
+        }
+    }
+
+    1.0
+}
+
+/// Score multiplier range applied by [`npc_tag_affinity_score_multiplier`].
+pub(crate) const BACKGROUND_AFFINITY_SPREAD: f32 = 0.5;
+
+/// NPCs an NPC-to-NPC background storylet plays out between: both sides of
+/// every relationship prerequisite that does not involve the player.
+pub(crate) fn background_storylet_npcs(world: &WorldState, pre: &StoryletPrerequisites) -> Vec<NpcId> {
+    let player = world.player_id.0;
+    let mut npcs: Vec<NpcId> = pre
+        .relationship_prereqs
+        .iter()
+        .filter_map(|r| r.actor_id.map(|actor| (actor, r.target_id)))
+        .filter(|&(actor, target)| actor != player && target != player)
+        .flat_map(|(actor, target)| [NpcId(actor), NpcId(target)])
+        .collect();
+    npcs.sort_unstable();
+    npcs.dedup();
+    npcs
+}
+
+/// Public helper: score multiplier for NPC-to-NPC background storylets from
+/// the involved NPCs' tag affinities.
+///
+/// Averages each prototype's affinity for the storylet's tags and maps it to
+/// 0.5..1.5. Storylets involving the player, and NPCs without a prototype,
+/// leave the score unchanged.
+pub fn npc_tag_affinity_score_multiplier(world: &WorldState, storylet: &Storylet) -> f32 {
+    let affinities: Vec<f32> = background_storylet_npcs(world, &storylet.prerequisites)
+        .into_iter()
+        .filter_map(|id| world.npc_prototype(id))
+        .map(|proto| {
+            proto.tag_affinity(|tag| {
+                storylet
+                    .tags
+                    .matches(&TagBitset::from_tags(vec![tag.to_string()]))
+            })
+        })
+        .collect();
+    if affinities.is_empty() {
+        return 1.0;
+    }
+    let mean = affinities.iter().sum::<f32>() / affinities.len() as f32;
+    1.0 + mean * BACKGROUND_AFFINITY_SPREAD
+}
+
+/// Selection multiplier of the hottest of `pairs` (1.0 if none is hot).
+pub(crate) fn hottest_pair_multiplier(world: &WorldState, pairs: impl Iterator<Item = (NpcId, NpcId)>) -> f32 {
+    let tick = world.current_tick.0;
+    pairs
+        .map(|(a, b)| world.relationship_heat.selection_multiplier(a, b, tick))
+        .fold(1.0, f32::max)
+}
+
+/// Public helper: score multiplier from the hottest relationship pair a
+/// storylet's relationship prerequisites cast (actor defaults to the player).
+pub fn relationship_heat_score_multiplier(world: &WorldState, storylet: &Storylet) -> f32 {
+    let player = world.player_id;
+    hottest_pair_multiplier(
+        world,
+        storylet
+            .prerequisites
+            .relationship_prereqs
+            .iter()
+            .map(|r| (r.actor_id.map(NpcId).unwrap_or(player), NpcId(r.target_id))),
+    )
+}
+
+/// Score multiplier from the hottest pair among the player and the actors
+/// cast into a compiled storylet's roles.
+pub(crate) fn cast_relationship_heat_multiplier(world: &WorldState, assignments: &RoleAssignments) -> f32 {
+    let mut cast: Vec<NpcId> = assignments.mapping.values().copied().collect();
+    cast.push(world.player_id);
+    cast.sort_unstable();
+    cast.dedup();
+    let pairs = cast
+        .iter()
+        .enumerate()
+        .flat_map(|(i, &a)| cast[i + 1..].iter().map(move |&b| (a, b)));
+    hottest_pair_multiplier(world, pairs)
+}
+
+/// Storylets serving one of the player's active life goals score this many
+/// times higher.
+pub(crate) const LIFE_GOAL_SCORE_MULTIPLIER: f32 = 1.5;
+
+/// Public helper: boost storylets that carry a tag of an active life goal.
+pub fn life_goal_score_multiplier(world: &WorldState, storylet: &Storylet) -> f32 {
+    let serves_goal = world.life_goals.active().any(|g| {
+        g.goal
+            .tags
+            .iter()
+            .any(|tag| storylet.tags.matches(&TagBitset::from_tags(vec![tag.to_string()])))
+    });
+    if serves_goal {
+        LIFE_GOAL_SCORE_MULTIPLIER
+    } else {
+        1.0
+    }
+}
+
+/// Storylets tagged with the kind of a dependent that needs care score this
+/// many times higher.
+pub(crate) const DEPENDENT_CARE_SCORE_MULTIPLIER: f32 = 1.4;
+
+/// Public helper: boost storylets about a pet, plant or sibling that needs care.
+pub fn dependent_care_score_multiplier(world: &WorldState, storylet: &Storylet) -> f32 {
+    let config = &world.dependents.config;
+    let serves_dependent = world.dependents.active().any(|d| {
+        d.needs_care(config)
+            && storylet
+                .tags
+                .matches(&TagBitset::from_tags(vec![d.kind.as_str().to_string()]))
+    });
+    if serves_dependent {
+        DEPENDENT_CARE_SCORE_MULTIPLIER
+    } else {
+        1.0
+    }
+}
+
+/// Public helper: shift the mix of heavy storylets (trauma, conflict, ...)
+/// by the dynamic difficulty controller's `heavy_mix`.
+pub fn difficulty_mix_score_multiplier(world: &WorldState, storylet: &Storylet) -> f32 {
+    let heavy_mix = world.difficulty.heavy_mix;
+    if (heavy_mix - 1.0).abs() <= f32::EPSILON {
+        return 1.0;
+    }
+    let heavy = TagBitset::from_tags(
+        syn_core::HEAVY_CONTENT_TAGS
+            .iter()
+            .map(|t| t.to_string())
+            .collect(),
+    );
+    if storylet.tags.matches(&heavy) {
+        heavy_mix
+    } else {
+        1.0
+    }
+}
+
+/// Public helper: boost storylets carrying a tag the player's ghost nudged a
+/// descendant toward during the PostLife chapter.
+pub fn post_life_nudge_score_multiplier(world: &WorldState, storylet: &Storylet) -> f32 {
+    let nudges = &world.post_life.nudges;
+    if nudges.is_empty() {
+        return 1.0;
+    }
+    let nudged = TagBitset::from_tags(nudges.iter().map(|n| n.tag.clone()).collect());
+    if storylet.tags.matches(&nudged) {
+        world.post_life.config.nudge_score_multiplier
+    } else {
+        1.0
+    }
+}
+
+/// Storylets whose `prefers_after` storylets have fired score this many
+/// times higher.
+pub(crate) const PREFERS_AFTER_SCORE_MULTIPLIER: f32 = 1.5;
+
+/// Public helper: boost storylets once one of their `prefers_after`
+/// storylets has fired.
+pub fn prefers_after_score_multiplier(world: &WorldState, storylet: &Storylet) -> f32 {
+    let usage = &world.storylet_usage;
+    if storylet
+        .prerequisites
+        .prefers_after
+        .iter()
+        .any(|id| usage.count(id) > 0)
+    {
+        PREFERS_AFTER_SCORE_MULTIPLIER
+    } else {
+        1.0
+    }
+}
+
+pub(crate) fn memory_tags_for_pair(memory: &MemorySystem, actor_id: u64, target_id: u64) -> Vec<String> {
+    memory
+        .journals
+        .values()
+        .flat_map(|journal| journal.entries.iter())
+        .filter(|m| {
+            let has_actor = m.participants.contains(&actor_id);
+            let has_target = m.participants.contains(&target_id);
+            has_actor && has_target
+        })
+        .flat_map(|m| m.tags.clone())
+        .collect()
+}
+
+pub(crate) fn storylet_targets_pair(
+    pre: &StoryletPrerequisites,
+    actor_id: u64,
+    target_id: u64,
+    default_actor_id: u64,
+) -> bool {
+    for r in &pre.relationship_prereqs {
+        let a = r.actor_id.unwrap_or(default_actor_id);
+        if a == actor_id && r.target_id == target_id {
+            return true;
+        }
+    }
+    false
+}
+
+pub(crate) fn storylet_matches_pressure_kind(
+    pre: &StoryletPrerequisites,
+    event: &RelationshipPressureEvent,
+) -> bool {
+    use RelationshipEventKind::*;
+
+    let axis = match event.kind {
+        AffectionBandChanged => Some(ModelRelationshipAxis::Affection),
+        TrustBandChanged => Some(ModelRelationshipAxis::Trust),
+        AttractionBandChanged => Some(ModelRelationshipAxis::Attraction),
+        ResentmentBandChanged => Some(ModelRelationshipAxis::Resentment),
+    };
+
+    axis.map(|axis| pre.relationship_prereqs.iter().any(|r| r.axis == axis))
+        .unwrap_or(false)
+}
+
+pub(crate) fn score_storylet_with_pressure(
+    director: &EventDirector,
+    world: &WorldState,
+    storylet: &Storylet,
+    hot_event: Option<&RelationshipPressureEvent>,
+) -> f32 {
+    let mut score = director.score_storylet(storylet, world);
+
+    let pre = &storylet.prerequisites;
+    let default_actor_id = world.player_id.0;
+
+    if let Some(event) = hot_event {
+        if storylet_targets_pair(pre, event.actor_id, event.target_id, default_actor_id) {
+            score += 50.0;
+
+            if storylet_matches_pressure_kind(pre, event) {
+                score += 25.0;
+            }
+        }
+    }
+
+    score
+}
+
+pub(crate) fn storylet_heat_band_match(heat_band: NarrativeHeatBand, storylet: &Storylet) -> bool {
+    let Some(category) = &storylet.outcomes.heat_category else {
+        return true;
+    };
+
+    match (heat_band, category) {
+        (NarrativeHeatBand::Low, StoryletHeatCategory::SliceOfLife) => true,
+        (NarrativeHeatBand::Medium, StoryletHeatCategory::RisingTension) => true,
+        (NarrativeHeatBand::High, StoryletHeatCategory::HighDrama) => true,
+        (NarrativeHeatBand::Critical, StoryletHeatCategory::CriticalArc) => true,
+        (NarrativeHeatBand::Medium, StoryletHeatCategory::SliceOfLife) => true,
+        (NarrativeHeatBand::High, StoryletHeatCategory::RisingTension) => true,
+        (NarrativeHeatBand::Critical, StoryletHeatCategory::HighDrama) => true,
+        _ => false,
+    }
+}
+
+pub(crate) fn heat_score_multiplier(heat_band: NarrativeHeatBand, storylet: &Storylet) -> f32 {
+    let Some(category) = &storylet.outcomes.heat_category else {
+        return 1.0;
+    };
+
+    match (heat_band, category) {
+        (NarrativeHeatBand::Low, StoryletHeatCategory::SliceOfLife) => 1.3,
+        (NarrativeHeatBand::Medium, StoryletHeatCategory::RisingTension) => 1.4,
+        (NarrativeHeatBand::High, StoryletHeatCategory::HighDrama) => 1.5,
+        (NarrativeHeatBand::Critical, StoryletHeatCategory::CriticalArc) => 1.7,
+        (NarrativeHeatBand::Medium, StoryletHeatCategory::SliceOfLife) => 0.9,
+        (NarrativeHeatBand::High, StoryletHeatCategory::RisingTension) => 1.1,
+        (NarrativeHeatBand::Critical, StoryletHeatCategory::HighDrama) => 1.2,
+        (NarrativeHeatBand::Low, StoryletHeatCategory::HighDrama) => 0.4,
+        (NarrativeHeatBand::Low, StoryletHeatCategory::CriticalArc) => 0.2,
+        (NarrativeHeatBand::Medium, StoryletHeatCategory::CriticalArc) => 0.5,
+        (NarrativeHeatBand::High, StoryletHeatCategory::SliceOfLife) => 0.7,
+        _ => 1.0,
+    }
+}
+
+/// Storylets whose trigger kind was forced by an injected external event
+/// score this many times higher until one of them fires.
+pub(crate) const FORCED_TRIGGER_SCORE_MULTIPLIER: f32 = 10.0;
+
+pub(crate) fn forced_trigger_score_multiplier(world: &WorldState, storylet: &Storylet) -> f32 {
+    match storylet.triggers.kind.as_deref() {
+        Some(kind) if world.external_events.is_forced(kind) => FORCED_TRIGGER_SCORE_MULTIPLIER,
+        // A player in crisis gets an intervention ahead of normal content
+        Some(kind)
+            if world
+                .stat_crisis
+                .awaiting_response(kind, world.current_tick.0)
+                .is_some() =>
+        {
+            FORCED_TRIGGER_SCORE_MULTIPLIER
+        }
+        // A pending flag change forces its reactions the same way
+        Some(kind) if world.flag_changes.pending_for(kind, world.current_tick.0).is_some() => {
+            FORCED_TRIGGER_SCORE_MULTIPLIER
+        }
+        // ...and so does a declared status the relationship no longer fits
+        Some(kind) if world.declared_relationships.mismatch_for(kind).is_some() => {
+            FORCED_TRIGGER_SCORE_MULTIPLIER
+        }
+        _ => 1.0,
+    }
+}
+
+/// Tired players get quieter days: high-heat storylets lose up to half their
+/// score as the daily action budget runs out.
+pub(crate) fn fatigue_score_multiplier(world: &WorldState, storylet: &Storylet) -> f32 {
+    let heat = (storylet.heat as f32 / 100.0).clamp(0.0, 1.0);
+    1.0 - 0.5 * world.action_budget.fatigue() * heat
+}
+
+/// Big beats early in a play session, no critical beats just before the
+/// player is expected to put the phone down.
+pub(crate) fn session_score_multiplier(world: &WorldState, storylet: &Storylet) -> f32 {
+    let heat = storylet.heat as f32 / 100.0;
+    let critical = matches!(
+        storylet.outcomes.heat_category,
+        Some(StoryletHeatCategory::CriticalArc)
+    );
+    world
+        .session_pacing
+        .score_multiplier(world.current_tick.0, heat, critical)
+}
+
+pub(crate) fn life_stage_score_multiplier(world: &WorldState, pre: &StoryletPrerequisites) -> f32 {
+    if pre.allowed_life_stages.is_empty() {
+        return 1.0;
+    }
+    if pre.allowed_life_stages.contains(&world.player_life_stage) {
+        1.2
+    } else {
+        0.8
+    }
+}
+
+pub(crate) fn digital_legacy_score_multiplier(world: &WorldState, pre: &Option<DigitalLegacyPrereq>) -> f32 {
+    let Some(pre) = pre else {
+        return 1.0;
+    };
+
+    if !matches!(world.player_life_stage, LifeStage::Digital) {
+        return 1.0;
+    }
+
+    if world.digital_legacy.primary_imprint.is_none() {
+        return 1.0;
+    }
+
+    // For now: if we have any bound at all, and prereq passes, give a small boost.
+    // (We already checked prereqs in find_eligible.)
+    let has_any_bounds = pre.min_compassion_vs_cruelty.is_some()
+        || pre.max_compassion_vs_cruelty.is_some()
+        || pre.min_ambition_vs_comfort.is_some()
+        || pre.max_ambition_vs_comfort.is_some()
+        || pre.min_connection_vs_isolation.is_some()
+        || pre.max_connection_vs_isolation.is_some()
+        || pre.min_stability_vs_chaos.is_some()
+        || pre.max_stability_vs_chaos.is_some()
+        || pre.min_light_vs_shadow.is_some()
+        || pre.max_light_vs_shadow.is_some();
+
+    if has_any_bounds {
+        1.25
+    } else {
+        1.0
+    }
+}
+
+/// Check if a storylet's tags match any district pressure event tags.
+pub(crate) fn storylet_matches_district_pressure(
+    storylet: &Storylet,
+    event: &DistrictPressureEvent,
+) -> bool {
+    let event_tags = event.kind.tags();
+    // Convert event tags to a bitset and check for overlap
+    let event_bitset = TagBitset::from_tags_slice(
+        &event_tags.iter().map(|s| s.to_string()).collect::<Vec<_>>()
+    );
+    storylet.tags.matches(&event_bitset)
+}
+
+/// Score bonus from district pressure events.
+///
+/// Returns a bonus if the storylet:
+/// 1. Has district_conditions matching a district with pending pressure events (+30)
+/// 2. Has tags matching the pressure event type (+20)
+pub(crate) fn score_district_pressure_bonus(
+    world: &WorldState,
+    storylet: &Storylet,
+) -> f32 {
+    let pre = &storylet.prerequisites;
+    let mut bonus = 0.0;
+
+    // Check if storylet has district conditions
+    for dc in &pre.district_conditions {
+        // Find matching pressure events for this district
+        for event in world.district_pressure.queue.iter() {
+            if event.district_name == dc.district {
+                // District match bonus
+                bonus += 30.0;
+                // Tag match bonus (additional if storylet tags match pressure type)
+                if storylet_matches_district_pressure(storylet, event) {
+                    bonus += 20.0;
+                }
+                break; // Only one bonus per district condition
+            }
+        }
+    }
+
+    // Also check storylet tags for general crisis/prosperity themes
+    if bonus == 0.0 && !world.district_pressure.queue.is_empty() {
+        for event in world.district_pressure.queue.iter() {
+            if storylet_matches_district_pressure(storylet, event) {
+                bonus += 15.0;
+                break;
+            }
+        }
+    }
+
+    bonus
+}
+
+/// Check if a storylet's tags match any gossip pressure event tags.
+pub(crate) fn storylet_matches_gossip_pressure(
+    storylet: &Storylet,
+    event: &GossipPressureEvent,
+) -> bool {
+    let event_tags = event.kind.tags();
+    // Convert event tags to a bitset and check for overlap
+    let event_bitset = TagBitset::from_tags_slice(
+        &event_tags.iter().map(|s| s.to_string()).collect::<Vec<_>>()
+    );
+    storylet.tags.matches(&event_bitset)
+}
+
+/// Score bonus from gossip pressure events.
+///
+/// Returns a bonus if the storylet has tags matching gossip event types:
+/// - Scandal (+25), Betrayal (+20), Reputation damage (+15), other gossip (+10)
+pub(crate) fn score_gossip_pressure_bonus(
+    world: &WorldState,
+    storylet: &Storylet,
+) -> f32 {
+    let mut bonus = 0.0;
+
+    for event in &world.gossip_pressure.events {
+        if storylet_matches_gossip_pressure(storylet, event) {
+            // Scale bonus by event severity and type
+            let type_bonus = match event.kind {
+                GossipEventKind::ScandalCirculating => 25.0,
+                GossipEventKind::BetrayalGossip => 20.0,
+                GossipEventKind::ReputationDamaged => 15.0,
+                GossipEventKind::SocialExclusion => 12.0,
+                GossipEventKind::NegativeRumorSpreading => 10.0,
+                GossipEventKind::PositiveBuzz => 5.0,
+            };
+            bonus += type_bonus * event.severity;
+        }
+    }
+
+    // Cap total gossip bonus to prevent overwhelming other factors
+    bonus.min(50.0)
+}
+
+/// Score bonus for storylets reacting to recent news.
+///
+/// Only storylets tagged `news_reaction` qualify; each headline from the last
+/// day whose tags overlap the storylet's adds +10 (capped at +30).
+pub(crate) fn score_news_reaction_bonus(world: &WorldState, storylet: &Storylet) -> f32 {
+    if !storylet
+        .prerequisites
+        .tags
+        .iter()
+        .any(|t| t.eq_ignore_ascii_case(NEWS_REACTION_TAG))
+    {
+        return 0.0;
+    }
+
+    let since = world.current_tick.0.saturating_sub(24);
+    let mut bonus: f32 = 0.0;
+    for item in world.news_feed.items_since(since) {
+        if storylet.tags.matches(&TagBitset::from_tags_slice(&item.tags)) {
+            bonus += 10.0;
+        }
+    }
+    bonus.min(30.0)
+}
+
+/// Score a storylet with the [`DefaultScoringStrategy`], considering NPC
+/// intent via the registry.
+pub fn score_storylet_full_with_registry(
+    director: &EventDirector,
+    world: &WorldState,
+    registry: &NpcRegistry,
+    storylet: &Storylet,
+    hot_event: Option<&RelationshipPressureEvent>,
+) -> f32 {
+    let ctx = ScoringContext::new(director, world, storylet, hot_event).with_registry(registry);
+    DefaultScoringStrategy.score(&ctx).score
+}
+
+pub(crate) fn relationship_pressure_score_multiplier(
+    _world: &WorldState,
+    _sim: &SimState,
+    _storylet: &Storylet,
+) -> f32 {
+    1.0
+}
+
+pub fn score_storylet_full_simple(
+    world: &WorldState,
+    sim: &SimState,
+    storylet: &Storylet,
+) -> f32 {
+    let base = if storylet.weight > 0.0 {
+        storylet.weight
+    } else {
+        1.0
+    };
+
+    let heat_band = world.narrative_heat.band();
+    let heat_mult = heat_score_multiplier(heat_band, storylet);
+    let stage_mult = life_stage_score_multiplier(world, &storylet.prerequisites);
+    let legacy_mult =
+        digital_legacy_score_multiplier(world, &storylet.prerequisites.digital_legacy_prereq);
+    let npc_intent_mult = npc_intent_score_multiplier(world, &sim.npc_registry, storylet);
+    let pressure_mult = relationship_pressure_score_multiplier(world, sim, storylet);
+    let district_mult = district_affinity_score_multiplier(world, &sim.npc_registry, storylet);
+    let forced_mult = forced_trigger_score_multiplier(world, storylet);
+    let pair_heat_mult = relationship_heat_score_multiplier(world, storylet);
+    let goal_mult = life_goal_score_multiplier(world, storylet);
+    let dependent_mult = dependent_care_score_multiplier(world, storylet);
+    let difficulty_mult = difficulty_mix_score_multiplier(world, storylet);
+    let nudge_mult = post_life_nudge_score_multiplier(world, storylet);
+    let prefers_after_mult = prefers_after_score_multiplier(world, storylet);
+
+    base * heat_mult
+        * stage_mult
+        * legacy_mult
+        * npc_intent_mult
+        * pressure_mult
+        * district_mult
+        * forced_mult
+        * pair_heat_mult
+        * goal_mult
+        * dependent_mult
+        * difficulty_mult
+        * nudge_mult
+        * prefers_after_mult
+}
+
+/// Eligible storylets for the director loop with their selection weights, in library order.
+pub(crate) fn score_eligible_storylets<'a>(
+    world: &WorldState,
+    sim: &SimState,
+    library: &'a StoryletLibrary,
+    usage: &StoryletUsageState,
+) -> Vec<(&'a Storylet, f32)> {
+    library
+        .storylets
+        .iter()
+        .filter(|s| storylet_is_eligible(world, sim, s, usage))
+        .map(|s| {
+            let score = score_storylet_full_simple(world, sim, s).max(0.0);
+            (s, score)
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use syn_core::WorldSeed;
+
+    fn categorized(category: Option<StoryletHeatCategory>) -> Storylet {
+        let mut storylet = Storylet::default();
+        storylet.outcomes.heat_category = category;
+        storylet
+    }
+
+    #[test]
+    fn heat_multiplier_favors_categories_matching_the_band() {
+        let critical = categorized(Some(StoryletHeatCategory::CriticalArc));
+        assert!(heat_score_multiplier(NarrativeHeatBand::Critical, &critical) > 1.0);
+        assert!(heat_score_multiplier(NarrativeHeatBand::Low, &critical) < 1.0);
+        assert!(!storylet_heat_band_match(NarrativeHeatBand::Low, &critical));
+
+        let uncategorized = categorized(None);
+        assert!((heat_score_multiplier(NarrativeHeatBand::Low, &uncategorized) - 1.0).abs() < 1e-6);
+        assert!(storylet_heat_band_match(NarrativeHeatBand::Low, &uncategorized));
+    }
+
+    #[test]
+    fn life_stage_multiplier_rewards_matching_stages() {
+        let mut world = WorldState::new(WorldSeed(1), NpcId(1));
+        world.player_life_stage = LifeStage::Adult;
+        let mut pre = StoryletPrerequisites::default();
+        assert!((life_stage_score_multiplier(&world, &pre) - 1.0).abs() < 1e-6);
+
+        pre.allowed_life_stages = vec![LifeStage::Adult];
+        let matching = life_stage_score_multiplier(&world, &pre);
+        pre.allowed_life_stages = vec![LifeStage::Elder];
+        assert!(matching > life_stage_score_multiplier(&world, &pre));
+    }
+}
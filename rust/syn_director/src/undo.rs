@@ -0,0 +1,153 @@
+//! Single-step rollback of the last director-loop choice.
+//!
+//! Before a choice is applied, [`ChoiceUndo::capture`] records the slices of
+//! the world that [`apply_storylet_choice_outcome`](crate::apply_storylet_choice_outcome)
+//! can touch: player stats, skills and karma, the underworld track and moral ledger
+//! that karma and reputation feed, the districts the storylet shocks, the
+//! relationships named by the outcome and the per-pair heat they spike, bystanders' views of the cast
+//! ([`syn_core::opinions`]) and the pressure events they raise, storylet usage, pending forced triggers,
+//! stat crisis response windows and life goal payoffs, the ambient quiet timer, the outcome samples the
+//! dynamic difficulty controller records, the daily delta cap budgets the outcome spends, and the
+//! world flags it sets or clears along with their change log.
+//! [`ChoiceUndo::restore`] writes them back.
+//!
+//! An undo is only valid at the tick the choice was made. Once time advances,
+//! simulation systems and tick-seeded RNG streams have already consumed the
+//! choice's effects, so rolling back then would fork the run; such undos are
+//! refused.
+
+use std::collections::VecDeque;
+
+use syn_core::{
+    DeltaCapState, DistrictRegistry, Karma, MoralLedger, NpcId, OpinionState, OutcomeSample,
+    Relationship, RelationshipHeatState, SimTick, StatCrisisState, Stats, StoryletUsageState,
+    UnderworldExposure, WorldState,
+};
+
+use syn_core::flag_changes::FlagChangeState;
+use syn_core::relationship_pressure::RelationshipPressureState;
+use syn_core::skills::PlayerSkills;
+use syn_core::world_flags::WorldFlags;
+
+use crate::{Storylet, StoryletChoice};
+
+/// The pre-choice state needed to roll back one choice.
+#[derive(Debug, Clone)]
+pub struct ChoiceUndo {
+    /// Tick the choice was made; the undo expires when the world moves on.
+    pub tick: SimTick,
+    /// Storylet the choice belonged to.
+    pub storylet_id: String,
+    /// The choice that was applied.
+    pub choice_id: String,
+    player_stats: Stats,
+    player_skills: PlayerSkills,
+    player_karma: Karma,
+    underworld: UnderworldExposure,
+    moral_ledger: MoralLedger,
+    districts: DistrictRegistry,
+    /// Relationships named by the outcome or open to bystanders' opinions
+    /// (`None` if the pair did not exist).
+    relationships: Vec<((NpcId, NpcId), Option<Relationship>)>,
+    opinions: OpinionState,
+    relationship_pressure: RelationshipPressureState,
+    relationship_heat: RelationshipHeatState,
+    storylet_usage: StoryletUsageState,
+    forced_triggers: VecDeque<String>,
+    stat_crisis: StatCrisisState,
+    pending_goal_storylets: Vec<String>,
+    ambient_last_storylet_tick: Option<u64>,
+    difficulty_samples: Vec<OutcomeSample>,
+    delta_caps: DeltaCapState,
+    world_flags: WorldFlags,
+    flag_changes: FlagChangeState,
+}
+
+impl ChoiceUndo {
+    /// Record what `choice` of `storylet` is about to change.
+    pub fn capture(world: &WorldState, storylet: &Storylet, choice: &StoryletChoice) -> Self {
+        let deltas = choice.outcome.all_relationship_deltas();
+        let mut pairs: Vec<(NpcId, NpcId)> = deltas
+            .iter()
+            .map(|d| (NpcId(d.actor_id), NpcId(d.target_id)))
+            .collect();
+        pairs.extend(syn_core::opinion_pairs(world, &deltas));
+        pairs.sort();
+        pairs.dedup();
+
+        ChoiceUndo {
+            tick: world.current_tick,
+            storylet_id: storylet.id.clone(),
+            choice_id: choice.id.clone(),
+            player_stats: world.player_stats.clone(),
+            player_skills: world.player_skills.clone(),
+            player_karma: world.player_karma,
+            underworld: world.underworld.clone(),
+            moral_ledger: world.moral_ledger.clone(),
+            districts: world.districts.clone(),
+            relationships: pairs
+                .into_iter()
+                .map(|pair| (pair, world.relationships.get(&pair).copied()))
+                .collect(),
+            opinions: world.opinions.clone(),
+            relationship_pressure: world.relationship_pressure.clone(),
+            relationship_heat: world.relationship_heat.clone(),
+            storylet_usage: world.storylet_usage.clone(),
+            forced_triggers: world.external_events.forced_triggers.clone(),
+            stat_crisis: world.stat_crisis.clone(),
+            pending_goal_storylets: world.life_goals.pending_storylets.clone(),
+            ambient_last_storylet_tick: world.ambient.last_storylet_tick,
+            difficulty_samples: world.difficulty.samples.clone(),
+            delta_caps: world.delta_caps.clone(),
+            world_flags: world.world_flags.clone(),
+            flag_changes: world.flag_changes.clone(),
+        }
+    }
+
+    /// Whether the world is still at the tick the choice was made.
+    pub fn is_valid(&self, world: &WorldState) -> bool {
+        world.current_tick == self.tick
+    }
+
+    /// Roll the world back to before the choice.
+    ///
+    /// Returns `false` (leaving the world untouched) once time has advanced.
+    pub fn restore(self, world: &mut WorldState) -> bool {
+        if !self.is_valid(world) {
+            return false;
+        }
+        world.player_stats = self.player_stats;
+        world.player_skills = self.player_skills;
+        world.player_karma = self.player_karma;
+        world.underworld = self.underworld;
+        world.moral_ledger = self.moral_ledger;
+        world.districts = self.districts;
+        // Before the relationships, so their restores stay stamped as changes
+        world.relationship_pressure = self.relationship_pressure;
+        for (pair, rel) in self.relationships {
+            match rel {
+                Some(rel) => world.set_relationship(pair.0, pair.1, rel),
+                None => {
+                    if world.relationships.remove(&pair).is_some() {
+                        let tick = world.current_tick.0;
+                        world
+                            .relationship_pressure
+                            .stamp_change(pair.0 .0, pair.1 .0, tick);
+                    }
+                }
+            }
+        }
+        world.opinions = self.opinions;
+        world.relationship_heat = self.relationship_heat;
+        world.storylet_usage = self.storylet_usage;
+        world.external_events.forced_triggers = self.forced_triggers;
+        world.stat_crisis = self.stat_crisis;
+        world.life_goals.pending_storylets = self.pending_goal_storylets;
+        world.ambient.last_storylet_tick = self.ambient_last_storylet_tick;
+        world.difficulty.samples = self.difficulty_samples;
+        world.delta_caps = self.delta_caps;
+        world.world_flags = self.world_flags;
+        world.flag_changes = self.flag_changes;
+        true
+    }
+}
@@ -0,0 +1,75 @@
+use syn_core::{
+    NpcId, SimTick, WorldSeed, WorldState, PLAYER_ACTION_TRIGGER, STORYLET_ACTION_COST,
+};
+use syn_director::{
+    EventDirector, Storylet, StoryletCooldown, StoryletOutcome, StoryletOutcomeSet,
+    StoryletPrerequisites, StoryletRoles, StoryletTrigger, TagBitset,
+};
+use syn_memory::MemorySystem;
+
+fn build_storylet(id: &str, trigger: Option<&str>) -> Storylet {
+    Storylet {
+        id: id.to_string(),
+        name: id.to_string(),
+        tags: TagBitset::default(),
+        prerequisites: StoryletPrerequisites::default(),
+        roles: StoryletRoles::default(),
+        heat: 20,
+        triggers: StoryletTrigger {
+            kind: trigger.map(|t| t.to_string()),
+        },
+        outcomes: StoryletOutcomeSet::default(),
+        cooldown: StoryletCooldown { ticks: 0 },
+        weight: 0.5,
+    }
+}
+
+fn eligible_ids(
+    director: &EventDirector,
+    world: &WorldState,
+    memory: &MemorySystem,
+) -> Vec<String> {
+    director
+        .find_eligible(world, memory, world.current_tick)
+        .iter()
+        .map(|s| s.id.clone())
+        .collect()
+}
+
+#[test]
+fn player_action_storylets_require_energy() {
+    let mut director = EventDirector::new();
+    let mut world = WorldState::new(WorldSeed(5), NpcId(1));
+    let mut memory = MemorySystem::new();
+
+    let night_out = build_storylet("night_out", Some(PLAYER_ACTION_TRIGGER));
+    director.register_storylet(night_out.clone());
+    director.register_storylet(build_storylet("phone_call", None));
+    assert_eq!(
+        eligible_ids(&director, &world, &memory),
+        vec!["night_out", "phone_call"]
+    );
+
+    // Each storylet the player takes part in spends from today's budget.
+    let start = world.action_budget.points;
+    while world.action_budget.can_afford(STORYLET_ACTION_COST) {
+        director.fire_storylet(
+            &night_out,
+            &mut world,
+            &mut memory,
+            StoryletOutcome::default(),
+            SimTick(1),
+        );
+    }
+    assert!(world.action_budget.points < start);
+    assert_eq!(eligible_ids(&director, &world, &memory), vec!["phone_call"]);
+
+    // A night's rest restores the budget.
+    for _ in 0..24 {
+        world.tick(&mut Default::default());
+    }
+    assert_eq!(
+        eligible_ids(&director, &world, &memory),
+        vec!["night_out", "phone_call"]
+    );
+}
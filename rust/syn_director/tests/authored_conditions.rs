@@ -0,0 +1,149 @@
+//! Stat, personality, district, memory echo and global conditions gate
+//! storylets in both the director loop and `EventDirector` selection.
+
+use syn_core::{
+    AbstractNpc, AttachmentStyle, MemoryEntryRecord, NpcId, SimTick, Traits, WorldSeed, WorldState,
+};
+use syn_director::{
+    storylet_is_eligible, DistrictCondition, EventDirector, GlobalWorldStateFlag, MemoryEchoFlag,
+    PersonalityCondition, StatCondition, Storylet, StoryletCooldown, StoryletPrerequisites,
+};
+use syn_memory::{MemoryEntry, MemorySystem};
+use syn_sim::SimState;
+
+fn farewell_at_the_docks() -> Storylet {
+    Storylet {
+        id: "farewell_at_the_docks".to_string(),
+        name: "Farewell at the Docks".to_string(),
+        prerequisites: StoryletPrerequisites {
+            stat_conditions: vec![StatCondition {
+                kind: "mood".to_string(),
+                min: Some(-10.0),
+                max: Some(-3.0),
+            }],
+            personality_conditions: vec![PersonalityCondition {
+                trait_name: "empathy".to_string(),
+                min: Some(60.0),
+                max: None,
+            }],
+            district_conditions: vec![DistrictCondition {
+                district: "Harbor".to_string(),
+            }],
+            memory_echo_conditions: vec![MemoryEchoFlag {
+                tag: "goodbye".to_string(),
+            }],
+            global_conditions: vec![GlobalWorldStateFlag {
+                flag: "storm_season".to_string(),
+                value: true,
+            }],
+            ..Default::default()
+        },
+        heat: 20,
+        cooldown: StoryletCooldown { ticks: 0 },
+        weight: 1.0,
+        ..Default::default()
+    }
+}
+
+/// A world and journal meeting every condition, remembered on both sides.
+fn world_meeting_every_condition() -> (WorldState, MemorySystem) {
+    let mut world = WorldState::new(WorldSeed(9), NpcId(1));
+    world.npcs.insert(
+        NpcId(1),
+        AbstractNpc {
+            id: NpcId(1),
+            age: 28,
+            job: "Sailor".to_string(),
+            district: "harbor".to_string(),
+            household_id: 1,
+            traits: Traits {
+                empathy: 70.0,
+                ..Traits::default()
+            },
+            seed: 1,
+            attachment_style: AttachmentStyle::Secure,
+        },
+    );
+    world.player_stats.mood = -5.0;
+    world.world_flags.set_any("storm_season");
+    world.memory_entries.push(MemoryEntryRecord {
+        npc_id: NpcId(1),
+        tags: vec!["goodbye".to_string()],
+        ..Default::default()
+    });
+
+    let mut memory = MemorySystem::new();
+    let mut goodbye = MemoryEntry::new(
+        "mem_1".into(),
+        "event_goodbye".into(),
+        NpcId(1),
+        SimTick(0),
+        -0.4,
+    );
+    goodbye.tags = vec!["goodbye".into()];
+    memory.record_memory(goodbye, None);
+    (world, memory)
+}
+
+#[test]
+fn every_condition_list_gates_both_selection_paths() {
+    let sim = SimState::new();
+    let storylet = farewell_at_the_docks();
+    let mut director = EventDirector::new();
+    director.register_storylet(storylet.clone());
+
+    let eligible_in_loop =
+        |world: &WorldState| storylet_is_eligible(world, &sim, &storylet, &world.storylet_usage);
+    let eligible_in_director = |world: &WorldState, memory: &MemorySystem| {
+        !director.find_eligible(world, memory, SimTick(1)).is_empty()
+    };
+
+    let (world, memory) = world_meeting_every_condition();
+    assert!(eligible_in_loop(&world));
+    assert!(eligible_in_director(&world, &memory));
+
+    let breakers: Vec<(&str, fn(&mut WorldState, &mut MemorySystem))> = vec![
+        ("stat_conditions", |world, _| world.player_stats.mood = 2.0),
+        ("personality_conditions", |world, _| {
+            if let Some(npc) = world.npcs.get_mut(&NpcId(1)) {
+                npc.traits.empathy = 40.0;
+            }
+        }),
+        ("district_conditions", |world, _| {
+            if let Some(npc) = world.npcs.get_mut(&NpcId(1)) {
+                npc.district = "Uptown".to_string();
+            }
+        }),
+        ("memory_echo_conditions", |world, memory| {
+            world.memory_entries.clear();
+            memory.journals.clear();
+        }),
+        ("global_conditions", |world, _| world.world_flags.clear_any("storm_season")),
+    ];
+    for (check, break_it) in breakers {
+        let (mut world, mut memory) = world_meeting_every_condition();
+        break_it(&mut world, &mut memory);
+        assert_eq!(
+            storylet.prerequisites.failed_condition(&world, Some(&memory)),
+            Some(check)
+        );
+        assert!(!eligible_in_loop(&world), "{} ignored by the director loop", check);
+        assert!(
+            !eligible_in_director(&world, &memory),
+            "{} ignored by EventDirector",
+            check
+        );
+    }
+}
+
+#[test]
+fn memory_echoes_read_either_memory_source() {
+    let storylet = farewell_at_the_docks();
+    let (mut world, memory) = world_meeting_every_condition();
+    world.memory_entries.clear();
+
+    // The journal alone is enough when the caller has one...
+    assert!(storylet.prerequisites.conditions_met(&world, Some(&memory)));
+    // ...while the director loop only sees the world's records
+    assert!(!storylet.prerequisites.conditions_met(&world, None));
+}
@@ -0,0 +1,78 @@
+use syn_core::relationship_model::{RelationshipAxis, RelationshipDelta};
+use syn_core::{NpcId, SimTick, WorldSeed, WorldState};
+use syn_director::{
+    AxisTagThresholds, OutcomePipeline, Storylet, StoryletCooldown, StoryletOutcome,
+    StoryletOutcomeSet, StoryletPrerequisites, StoryletRoles, TagBitset,
+};
+use syn_memory::MemorySystem;
+
+fn storylet() -> Storylet {
+    Storylet {
+        id: "broken_promise".into(),
+        name: "Broken Promise".into(),
+        tags: TagBitset::default(),
+        prerequisites: StoryletPrerequisites::default(),
+        roles: StoryletRoles::default(),
+        heat: 0,
+        triggers: Default::default(),
+        outcomes: StoryletOutcomeSet::default(),
+        cooldown: StoryletCooldown { ticks: 0 },
+        weight: 1.0,
+    }
+}
+
+fn delta(axis: RelationshipAxis, delta: f32) -> RelationshipDelta {
+    RelationshipDelta {
+        actor_id: 1,
+        target_id: 2,
+        axis,
+        delta,
+        source: None,
+    }
+}
+
+fn outcome() -> StoryletOutcome {
+    StoryletOutcome {
+        relationship_deltas: vec![
+            delta(RelationshipAxis::Trust, -4.0),
+            delta(RelationshipAxis::Resentment, 3.5),
+            delta(RelationshipAxis::Affection, -1.0),
+        ],
+        memory_event_id: "broken_promise".into(),
+        memory_tags: vec!["betrayal".into()],
+        emotional_intensity: -0.6,
+        ..Default::default()
+    }
+}
+
+fn recorded_tags(pipeline: &OutcomePipeline) -> Vec<String> {
+    let mut world = WorldState::new(WorldSeed(9), NpcId(1));
+    let mut memory = MemorySystem::new();
+    pipeline.run(&mut world, &mut memory, &storylet(), &outcome(), SimTick(5));
+    let journal = memory.get_journal(NpcId(1)).expect("player journal");
+    assert_eq!(journal.entries.len(), 1);
+    journal.entries[0].tags.clone()
+}
+
+#[test]
+fn sharp_axis_moves_tag_the_outcome_memory() {
+    let tags = recorded_tags(&OutcomePipeline::standard());
+    assert_eq!(tags, vec!["betrayal", "resentment_spike", "trust_loss"]);
+}
+
+#[test]
+fn thresholds_are_configurable() {
+    let mut pipeline = OutcomePipeline::standard();
+    pipeline.set_axis_tag_thresholds(AxisTagThresholds {
+        affection: 1.0,
+        resentment: 5.0,
+        ..Default::default()
+    });
+    assert_eq!(
+        recorded_tags(&pipeline),
+        vec!["betrayal", "affection_loss", "trust_loss"]
+    );
+
+    pipeline.set_axis_tag_thresholds(AxisTagThresholds::disabled());
+    assert_eq!(recorded_tags(&pipeline), vec!["betrayal"]);
+}
@@ -0,0 +1,103 @@
+use syn_core::time::{
+    birthday_day_of_year, calendar_date, CalendarDay, Holiday, Season, DAYS_PER_YEAR,
+};
+use syn_core::{NpcId, WorldSeed, WorldState};
+use syn_director::{
+    storylet_is_eligible, Storylet, StoryletCooldown, StoryletOutcomeSet, StoryletPrerequisites,
+    StoryletRole, StoryletRoles, TagBitset, TimeAndLocationPrereqs,
+};
+use syn_sim::SimState;
+
+fn calendar_storylet(target: NpcId, when: TimeAndLocationPrereqs) -> Storylet {
+    Storylet {
+        id: "calendar_scene".to_string(),
+        name: "Calendar Scene".to_string(),
+        tags: TagBitset::default(),
+        prerequisites: StoryletPrerequisites {
+            time_and_location: Some(when),
+            ..Default::default()
+        },
+        roles: StoryletRoles::from(vec![StoryletRole {
+            name: "target".to_string(),
+            npc_id: target,
+        }]),
+        heat: 2,
+        triggers: Default::default(),
+        outcomes: StoryletOutcomeSet::default(),
+        cooldown: StoryletCooldown { ticks: 0 },
+        weight: 1.0,
+    }
+}
+
+/// Move the calendar to a day of the year without changing the player's age.
+fn set_day_of_year(world: &mut WorldState, day_of_year: u16) {
+    let today = calendar_date(world).day_of_year;
+    let ahead = (day_of_year + DAYS_PER_YEAR - today) % DAYS_PER_YEAR;
+    world.player_days_since_birth += u32::from(ahead);
+}
+
+fn eligible(world: &WorldState, storylet: &Storylet) -> bool {
+    storylet_is_eligible(world, &SimState::new(), storylet, &world.storylet_usage)
+}
+
+#[test]
+fn birthday_storylets_follow_the_player_and_target() {
+    let mut world = WorldState::new(WorldSeed(8), NpcId(1));
+    let player_party = calendar_storylet(
+        NpcId(2),
+        TimeAndLocationPrereqs {
+            calendar_days: vec![CalendarDay::PlayerBirthday],
+            ..Default::default()
+        },
+    );
+    let friend_party = calendar_storylet(
+        NpcId(2),
+        TimeAndLocationPrereqs {
+            calendar_days: vec![CalendarDay::TargetBirthday],
+            ..Default::default()
+        },
+    );
+
+    // New worlds start on the player's birthday.
+    assert!(eligible(&world, &player_party));
+
+    set_day_of_year(&mut world, birthday_day_of_year(8, NpcId(2)));
+    assert_eq!(
+        eligible(&world, &player_party),
+        birthday_day_of_year(8, NpcId(1)) == birthday_day_of_year(8, NpcId(2))
+    );
+    assert!(eligible(&world, &friend_party));
+
+    world.player_days_since_birth += 1;
+    assert!(!eligible(&world, &friend_party));
+}
+
+#[test]
+fn seasonal_and_holiday_storylets_wait_for_their_day() {
+    let mut world = WorldState::new(WorldSeed(8), NpcId(1));
+    let snow_day = calendar_storylet(
+        NpcId(2),
+        TimeAndLocationPrereqs {
+            allowed_seasons: vec![Season::Winter],
+            ..Default::default()
+        },
+    );
+    let costume_party = calendar_storylet(
+        NpcId(2),
+        TimeAndLocationPrereqs {
+            calendar_days: vec![CalendarDay::Holiday(Holiday::Halloween)],
+            ..Default::default()
+        },
+    );
+
+    set_day_of_year(&mut world, 180); // June 30
+    assert!(!eligible(&world, &snow_day));
+    assert!(!eligible(&world, &costume_party));
+
+    set_day_of_year(&mut world, 10); // January 11
+    assert!(eligible(&world, &snow_day));
+
+    set_day_of_year(&mut world, 303); // October 31
+    assert!(eligible(&world, &costume_party));
+    assert!(!eligible(&world, &snow_day));
+}
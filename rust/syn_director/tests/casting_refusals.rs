@@ -0,0 +1,184 @@
+//! NPCs with grudges against the player refuse cooperative roles: the role is
+//! re-cast, or the beat plays as a refusal micro-event.
+
+use syn_core::relationship_model::ResentmentBand;
+use syn_core::{NpcId, Relationship, SimTick, StatKind, WorldSeed, WorldState};
+use syn_director::{
+    CastingOutcome, EligibilityContext, EventDirector, RefusalReason, RoleAssignmentEngine,
+};
+use syn_memory::{MemoryEntry, MemorySystem};
+use syn_storylets::library::{CompiledStorylet, StoryletKey, StoryletLibrary};
+use syn_storylets::{LifeStage, Outcome, RoleSlot, StatDelta, StoryDomain, StoryletId};
+
+fn storylet(role: &str) -> CompiledStorylet {
+    CompiledStorylet {
+        id: StoryletId::new("coffee_with_friend"),
+        key: StoryletKey(0),
+        name: "Coffee With a Friend".to_string(),
+        description: None,
+        tags: vec![],
+        domain: StoryDomain::Friendship,
+        life_stage: LifeStage::Adult,
+        heat: 2,
+        weight: 1.0,
+        roles: vec![RoleSlot {
+            name: role.to_string(),
+            required: true,
+            constraints: None,
+            allow_same_as: vec![],
+        }],
+        prerequisites: Default::default(),
+        cooldowns: Default::default(),
+        outcomes: Outcome {
+            stat_deltas: Some(vec![StatDelta {
+                stat: "mood".to_string(),
+                delta: 1.0,
+            }]),
+            ..Default::default()
+        },
+        follow_ups_resolved: vec![],
+    }
+}
+
+fn library(s: CompiledStorylet) -> StoryletLibrary {
+    let mut lib = StoryletLibrary::new();
+    lib.id_to_key.insert(s.id.clone(), s.key);
+    lib.life_stage_index
+        .entry(s.life_stage)
+        .or_default()
+        .push(s.key);
+    lib.domain_index.entry(s.domain).or_default().push(s.key);
+    lib.storylets.push(s);
+    lib.total_count += 1;
+    lib
+}
+
+/// Player 1 is closest to NPC 2, who resents them; NPC 3 is a lukewarm friend.
+fn world() -> WorldState {
+    let mut world = WorldState::new(WorldSeed(11), NpcId(1));
+    world.player_life_stage = syn_core::LifeStage::Adult;
+    world.known_npcs = vec![NpcId(2), NpcId(3)];
+    let friend = |affection| Relationship {
+        affection,
+        trust: affection,
+        ..Default::default()
+    };
+    world.set_relationship(NpcId(1), NpcId(2), friend(8.0));
+    world.set_relationship(NpcId(1), NpcId(3), friend(3.0));
+    world.set_relationship(
+        NpcId(2),
+        NpcId(1),
+        Relationship {
+            resentment: 7.0,
+            ..Default::default()
+        },
+    );
+    world
+}
+
+/// A bitter memory NPC `npc` holds of the player.
+fn grudge(npc: u64, id: &str) -> MemoryEntry {
+    let mut entry = MemoryEntry::new(
+        id.to_string(),
+        "betrayal".to_string(),
+        NpcId(npc),
+        SimTick(0),
+        -0.8,
+    );
+    entry.participants = vec![1, npc];
+    entry
+}
+
+#[test]
+fn hostile_npc_refuses_and_the_role_is_recast() {
+    let world = world();
+    let memory = MemorySystem::new();
+    let ctx = EligibilityContext {
+        world: &world,
+        memory: &memory,
+        current_tick: SimTick(0),
+    };
+    let engine = RoleAssignmentEngine::from_context(&ctx);
+
+    let assignments = engine
+        .assign_roles_for_storylet(&storylet("friend"), None)
+        .expect("NPC 3 should take the role");
+    assert_eq!(assignments.mapping.get("friend"), Some(&NpcId(3)));
+    assert_eq!(assignments.refusals.len(), 1);
+    assert_eq!(assignments.refusals[0].actor_id, NpcId(2));
+    assert_eq!(
+        assignments.refusals[0].reason,
+        RefusalReason::Resentment(ResentmentBand::Hostile)
+    );
+
+    // Rivals are not asked to cooperate, so resentment doesn't stop them.
+    let rivalry = storylet("rival");
+    assert!(engine.refusal(&rivalry, &rivalry.roles[0], NpcId(2)).is_none());
+}
+
+#[test]
+fn bitter_memories_turn_the_beat_into_a_refusal() {
+    let mut world = world();
+    world.known_npcs = vec![NpcId(3)];
+    let mut memory = MemorySystem::new();
+    memory.record_memory(grudge(3, "fight-1"), None);
+    memory.record_memory(grudge(3, "fight-2"), None);
+    let lib = library(storylet("friend"));
+
+    let ctx = EligibilityContext {
+        world: &world,
+        memory: &memory,
+        current_tick: SimTick(1),
+    };
+    match RoleAssignmentEngine::from_context(&ctx).cast_or_refuse(&lib.storylets[0], None) {
+        CastingOutcome::Refused(refusal) => {
+            assert_eq!(refusal.actor_id, NpcId(3));
+            assert_eq!(refusal.role, "friend");
+            assert!(matches!(
+                refusal.reason,
+                RefusalReason::Memories { count: 2, .. }
+            ));
+        }
+        other => panic!("expected a refusal, got {:?}", other),
+    }
+
+    let mut director = EventDirector::new();
+    let mood_before = world.player_stats.get(StatKind::Mood);
+    let fired = director.tick_compiled_storylets_simple(&lib, &mut world, &mut memory, SimTick(1));
+    assert_eq!(fired.as_deref(), Some("coffee_with_friend"));
+
+    // The outcome never applied and the storylet didn't count as fired.
+    assert!((world.player_stats.get(StatKind::Mood) - mood_before).abs() < 1e-6);
+    assert_eq!(world.storylet_usage.count("coffee_with_friend"), 0);
+
+    let journal = memory.get_journal(NpcId(1)).expect("player remembers the rebuff");
+    let rebuff = journal
+        .entries
+        .iter()
+        .find(|m| m.event_id == "refusal:coffee_with_friend")
+        .expect("refusal memory recorded");
+    assert!(rebuff.tags.iter().any(|t| t == "refusal"));
+    assert!(rebuff.tags.iter().any(|t| t == "memories"));
+    assert_eq!(rebuff.participants, vec![1, 3]);
+}
+
+#[test]
+fn nobody_to_cast_is_not_a_refusal() {
+    let mut world = world();
+    world.known_npcs.clear();
+    let memory = MemorySystem::new();
+    let ctx = EligibilityContext {
+        world: &world,
+        memory: &memory,
+        current_tick: SimTick(0),
+    };
+    // The player can take one role, but nobody is left for the other.
+    let mut double_date = storylet("friend");
+    let mut partner = double_date.roles[0].clone();
+    partner.name = "partner".to_string();
+    double_date.roles.push(partner);
+    assert!(matches!(
+        RoleAssignmentEngine::from_context(&ctx).cast_or_refuse(&double_date, None),
+        CastingOutcome::Unfilled
+    ));
+}
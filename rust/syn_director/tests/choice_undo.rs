@@ -0,0 +1,139 @@
+use syn_core::{
+    relationship_model::{RelationshipAxis, RelationshipDelta},
+    NpcId, Relationship, ReputationDelta, ReputationScope, SocialCluster, StatDelta, StatKind,
+    WorldSeed, WorldState, WorldStateSnapshot,
+};
+use syn_director::{
+    apply_choice_with_undo, tags_to_bitset, Storylet, StoryletChoice, StoryletCooldown,
+    StoryletLibrary, StoryletOutcome, StoryletOutcomeSet, WorldFlagUpdate,
+};
+use syn_sim::{tick_world, SimState};
+
+fn library() -> StoryletLibrary {
+    let storylet = Storylet {
+        id: "argument".to_string(),
+        name: "Argument".to_string(),
+        tags: tags_to_bitset(&[]),
+        heat: 1,
+        weight: 1.0,
+        outcomes: StoryletOutcomeSet {
+            choices: vec![StoryletChoice {
+                id: "lash_out".to_string(),
+                label: "Lash out".to_string(),
+                skill_requirements: Vec::new(),
+                outcome: StoryletOutcome {
+                    stat_deltas: vec![StatDelta {
+                        kind: StatKind::Mood,
+                        delta: -3.0,
+                        source: None,
+                    }],
+                    relationship_deltas: vec![RelationshipDelta {
+                        actor_id: 2,
+                        target_id: 1,
+                        axis: RelationshipAxis::Resentment,
+                        delta: 4.0,
+                        source: None,
+                    }],
+                    karma_delta: Some(-10.0),
+                    reputation_deltas: vec![ReputationDelta {
+                        scope: ReputationScope::District("Downtown".to_string()),
+                        delta: -5.0,
+                    }],
+                    flags: vec![WorldFlagUpdate {
+                        flag: "argued_with_2".to_string(),
+                        value: true,
+                    }],
+                    ..Default::default()
+                },
+            }],
+            ..Default::default()
+        },
+        cooldown: StoryletCooldown { ticks: 0 },
+        ..Default::default()
+    };
+    StoryletLibrary::from_storylets(vec![storylet])
+}
+
+/// First difference between `before` and the restored `world`, once the
+/// change stamps the rollback itself leaves on `reverted` pairs (so UI polls
+/// pick up the revert) are checked and set aside.
+fn restored_divergence(
+    before: &WorldStateSnapshot,
+    world: &WorldState,
+    reverted: &[(u64, u64)],
+) -> Option<&'static str> {
+    let mut after = WorldStateSnapshot::from_world(world);
+    let stamps = std::mem::take(&mut after.relationship_pressure.change_stamps);
+    for pair in reverted {
+        assert!(stamps.iter().any(|s| (s.actor_id, s.target_id) == *pair), "{:?}", pair);
+    }
+    after.relationship_pressure.change_stamps = before.relationship_pressure.change_stamps.clone();
+    before.first_divergence(&after)
+}
+
+#[test]
+fn undo_restores_the_world_before_the_choice() {
+    let mut world = WorldState::new(WorldSeed(7), NpcId(1));
+    let mut sim = SimState::new();
+    let library = library();
+    let before = WorldStateSnapshot::from_world(&world);
+
+    let undo = apply_choice_with_undo(&mut world, &mut sim, &library, "argument", "lash_out")
+        .expect("choice is on offer");
+    assert_eq!(world.player_stats.get(StatKind::Mood), -3.0);
+    assert_eq!(world.get_relationship(NpcId(2), NpcId(1)).resentment, 4.0);
+    assert_eq!(world.storylet_usage.count("argument"), 1);
+    assert!(world.world_flags.has_any("argued_with_2"));
+
+    assert!(undo.restore(&mut world));
+    assert_eq!(restored_divergence(&before, &world, &[(2, 1)]), None);
+    assert!(!world.world_flags.has_any("argued_with_2"));
+    assert!(!world.relationships.contains_key(&(NpcId(2), NpcId(1))));
+}
+
+#[test]
+fn undo_takes_back_what_bystanders_thought_of_the_choice() {
+    let mut world = WorldState::new(WorldSeed(7), NpcId(1));
+    let mut sim = SimState::new();
+    let library = library();
+    // NPC 3 is close to NPC 2 and hears about the argument through their circle
+    world.set_relationship(
+        NpcId(3),
+        NpcId(2),
+        Relationship {
+            affection: 8.0,
+            ..Default::default()
+        },
+    );
+    let mut circle = SocialCluster::new("friends");
+    circle.add_member(NpcId(2));
+    circle.add_member(NpcId(3));
+    world.gossip.clusters.insert("friends".to_string(), circle);
+    let before = WorldStateSnapshot::from_world(&world);
+
+    let undo = apply_choice_with_undo(&mut world, &mut sim, &library, "argument", "lash_out")
+        .expect("choice is on offer");
+    assert!(world.get_relationship(NpcId(3), NpcId(1)).resentment > 0.0);
+    assert_eq!(world.opinions.recent.len(), 1);
+
+    assert!(undo.restore(&mut world));
+    assert_eq!(restored_divergence(&before, &world, &[(2, 1), (3, 1)]), None);
+    assert!(!world.relationships.contains_key(&(NpcId(3), NpcId(1))));
+}
+
+#[test]
+fn undo_expires_once_time_advances() {
+    let mut world = WorldState::new(WorldSeed(7), NpcId(1));
+    let mut sim = SimState::new();
+    let library = library();
+
+    let undo = apply_choice_with_undo(&mut world, &mut sim, &library, "argument", "lash_out")
+        .expect("choice is on offer");
+    tick_world(&mut world, &mut sim, 1);
+
+    assert!(!undo.is_valid(&world));
+    assert!(!undo.restore(&mut world));
+    assert_eq!(world.storylet_usage.count("argument"), 1);
+
+    assert!(apply_choice_with_undo(&mut world, &mut sim, &library, "argument", "nope").is_none());
+}
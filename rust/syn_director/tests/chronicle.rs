@@ -0,0 +1,96 @@
+//! Every storylet the director fires lands in its chronicle.
+
+use syn_core::{
+    AbstractNpc, AttachmentStyle, NpcId, SimTick, StatDelta, StatKind, Traits, WorldSeed,
+    WorldState,
+};
+use syn_director::{
+    EventDirector, Storylet, StoryletChoice, StoryletCooldown, StoryletOutcome,
+    StoryletOutcomeSet, StoryletRole, StoryletRoles,
+};
+use syn_memory::MemorySystem;
+
+fn coffee_with_friend() -> Storylet {
+    let choice = |id: &str, mood: f32| StoryletChoice {
+        id: id.to_string(),
+        label: id.to_string(),
+        skill_requirements: Vec::new(),
+        outcome: StoryletOutcome {
+            stat_deltas: vec![StatDelta {
+                kind: StatKind::Mood,
+                delta: mood,
+                source: None,
+            }],
+            memory_event_id: format!("coffee_{}", id),
+            ..Default::default()
+        },
+    };
+    Storylet {
+        id: "coffee_with_friend".to_string(),
+        name: "Coffee with a Friend".to_string(),
+        roles: StoryletRoles::from(vec![StoryletRole {
+            name: "friend".to_string(),
+            npc_id: NpcId(2),
+        }]),
+        outcomes: StoryletOutcomeSet {
+            choices: vec![choice("open_up", 2.0), choice("deflect", -1.0)],
+            ..Default::default()
+        },
+        cooldown: StoryletCooldown { ticks: 0 },
+        weight: 1.0,
+        ..Default::default()
+    }
+}
+
+fn world_with_friend() -> WorldState {
+    let mut world = WorldState::new(WorldSeed(11), NpcId(1));
+    world.npcs.insert(
+        NpcId(2),
+        AbstractNpc {
+            id: NpcId(2),
+            age: 30,
+            job: "Barista".to_string(),
+            district: "Downtown".to_string(),
+            household_id: 3,
+            traits: Traits::default(),
+            seed: 2,
+            attachment_style: AttachmentStyle::Secure,
+        },
+    );
+    world
+}
+
+#[test]
+fn fired_storylets_are_chronicled_with_cast_choice_and_outcome() {
+    let mut director = EventDirector::new();
+    let storylet = coffee_with_friend();
+    director.register_storylet(storylet.clone());
+    let mut world = world_with_friend();
+    let mut memory = MemorySystem::new();
+    world.narrative_heat.set(35.0);
+
+    assert!(!director.fire_storylet_choice(&storylet, &mut world, &mut memory, "shrug", SimTick(1)));
+    assert!(director.chronicle().is_empty());
+
+    assert!(director.fire_storylet_choice(&storylet, &mut world, &mut memory, "deflect", SimTick(4)));
+    director
+        .force_fire_storylet("coffee_with_friend", &[], &mut world, &mut memory, SimTick(9))
+        .expect("storylet is registered");
+
+    let chronicle = director.chronicle();
+    assert_eq!(chronicle.len(), 2);
+    let first = &chronicle.entries()[0];
+    assert_eq!(first.seq, 1);
+    assert_eq!(first.tick, 4);
+    assert_eq!(first.roles, vec![("friend".to_string(), NpcId(2))]);
+    assert_eq!(first.choice_id.as_deref(), Some("deflect"));
+    assert_eq!(first.outcome.memory_event_id, "coffee_deflect");
+    assert!((first.heat - 35.0).abs() < f32::EPSILON);
+
+    // Forced fires take the first choice
+    let latest = chronicle.latest().expect("latest entry");
+    assert_eq!(latest.choice_id.as_deref(), Some("open_up"));
+    assert_eq!(chronicle.since(5).len(), 1);
+    assert_eq!(chronicle.involving(NpcId(2)).len(), 2);
+    assert!(chronicle.involving(NpcId(7)).is_empty());
+}
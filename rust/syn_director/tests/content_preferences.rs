@@ -0,0 +1,56 @@
+use syn_core::{NpcId, SimTick, WorldSeed, WorldState, STAGE_ENTRY_TAG};
+use syn_director::{
+    storylet_is_eligible, tags_to_bitset, EventDirector, Storylet, StoryletCooldown,
+    StoryletOutcomeSet, StoryletPrerequisites, StoryletRoles,
+};
+use syn_memory::MemorySystem;
+use syn_sim::SimState;
+
+fn storylet_with_tags(id: &str, tags: &[&str]) -> Storylet {
+    let tags: Vec<String> = tags.iter().map(|t| t.to_string()).collect();
+    Storylet {
+        id: id.to_string(),
+        name: id.to_string(),
+        tags: tags_to_bitset(&tags),
+        prerequisites: StoryletPrerequisites {
+            tags,
+            ..Default::default()
+        },
+        roles: StoryletRoles::default(),
+        heat: 10,
+        triggers: Default::default(),
+        outcomes: StoryletOutcomeSet::default(),
+        cooldown: StoryletCooldown { ticks: 0 },
+        weight: 1.0,
+    }
+}
+
+#[test]
+fn blocked_domains_and_tags_are_not_selected() {
+    let mut director = EventDirector::new();
+    let mut world = WorldState::new(WorldSeed(1), NpcId(1));
+    world.content_preferences.block_domain("Addiction");
+    world.content_preferences.block_tag("gore");
+    let memory = MemorySystem::new();
+
+    director.register_storylet(storylet_with_tags("relapse", &["addiction", "drama"]));
+    director.register_storylet(storylet_with_tags("accident", &["gore"]));
+    director.register_storylet(storylet_with_tags("picnic", &["friendship"]));
+
+    let eligible = director.find_eligible(&world, &memory, SimTick(0));
+    let ids: Vec<&str> = eligible.iter().map(|s| s.id.as_str()).collect();
+    assert_eq!(ids, vec!["picnic"]);
+}
+
+#[test]
+fn stage_entry_content_ignores_blocklist() {
+    let mut world = WorldState::new(WorldSeed(1), NpcId(1));
+    world.content_preferences.block_domain("trauma");
+    let sim = SimState::new();
+
+    let entry = storylet_with_tags("teen_entry", &["trauma", STAGE_ENTRY_TAG]);
+    let plain = storylet_with_tags("grief", &["trauma"]);
+
+    assert!(storylet_is_eligible(&world, &sim, &entry, &world.storylet_usage));
+    assert!(!storylet_is_eligible(&world, &sim, &plain, &world.storylet_usage));
+}
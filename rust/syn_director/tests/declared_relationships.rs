@@ -0,0 +1,148 @@
+//! Declared relationship statuses gate, cast and trigger storylets.
+
+use syn_core::{
+    update_declared_mismatches, AbstractNpc, AttachmentStyle, DeclaredStatus, NpcId,
+    RelationshipDeclaration, RelationshipState, SimTick, Traits, WorldSeed, WorldState,
+};
+use syn_director::{
+    apply_storylet_choice_outcome, storylet_is_eligible, EventDirector, Storylet, StoryletChoice,
+    StoryletCooldown, StoryletOutcome, StoryletOutcomeSet, StoryletPrerequisites, StoryletRole,
+    StoryletRoles, StoryletTrigger,
+};
+use syn_memory::MemorySystem;
+use syn_sim::SimState;
+
+fn eligible(world: &WorldState, sim: &SimState, storylet: &Storylet) -> bool {
+    storylet_is_eligible(world, sim, storylet, &world.storylet_usage)
+}
+
+fn npc(id: u64) -> AbstractNpc {
+    AbstractNpc {
+        id: NpcId(id),
+        age: 28,
+        job: "Barista".to_string(),
+        district: "Downtown".to_string(),
+        household_id: 1,
+        traits: Traits::default(),
+        seed: id,
+        attachment_style: AttachmentStyle::Secure,
+    }
+}
+
+fn world() -> WorldState {
+    let mut world = WorldState::new(WorldSeed(7), NpcId(1));
+    for id in [2, 3] {
+        world.npcs.insert(NpcId(id), npc(id));
+    }
+    world.known_npcs = vec![NpcId(2), NpcId(3)];
+    world.current_tick = SimTick(48);
+    world
+}
+
+fn storylet(id: &str, target: u64) -> Storylet {
+    Storylet {
+        id: id.to_string(),
+        name: id.to_string(),
+        heat: 5,
+        weight: 1.0,
+        roles: StoryletRoles::from(vec![StoryletRole {
+            name: "partner".to_string(),
+            npc_id: NpcId(target),
+        }]),
+        outcomes: StoryletOutcomeSet {
+            choices: vec![StoryletChoice {
+                id: "ok".to_string(),
+                label: "Okay".to_string(),
+                skill_requirements: Vec::new(),
+                outcome: StoryletOutcome::default(),
+            }],
+            ..Default::default()
+        },
+        cooldown: StoryletCooldown { ticks: 0 },
+        ..Default::default()
+    }
+}
+
+fn dating_only(target: u64) -> Storylet {
+    Storylet {
+        prerequisites: StoryletPrerequisites {
+            declared_statuses: vec![DeclaredStatus::Dating],
+            ..Default::default()
+        },
+        ..storylet("anniversary_dinner", target)
+    }
+}
+
+#[test]
+fn a_choice_declares_the_status_prerequisites_check() {
+    let mut world = world();
+    let mut sim = SimState::new();
+    let gated = dating_only(2);
+    assert!(!eligible(&world, &sim, &gated));
+
+    let mut dtr = storylet("define_the_relationship", 2);
+    dtr.outcomes.choices[0].outcome.relationship_declarations = vec![RelationshipDeclaration {
+        actor_id: 1,
+        target_id: 2,
+        status: Some(DeclaredStatus::Dating),
+    }];
+    let choice = dtr.outcomes.choices[0].clone();
+    apply_storylet_choice_outcome(&mut world, &mut sim, &dtr, &choice);
+
+    let declared = world.declared_relationships.get(1, 2).expect("declared");
+    assert_eq!(declared.status, DeclaredStatus::Dating);
+    assert_eq!(declared.source, "storylet:define_the_relationship");
+    assert!(eligible(&world, &sim, &gated));
+    // Someone else the player hasn't declared anything with stays gated.
+    assert!(!eligible(&world, &sim, &dating_only(3)));
+}
+
+#[test]
+fn stale_roles_are_cast_to_the_declared_partner() {
+    let mut world = world();
+    let memory = MemorySystem::new();
+    syn_core::declare_relationship(
+        &mut world,
+        NpcId(1),
+        NpcId(3),
+        DeclaredStatus::Dating,
+        "test",
+    );
+
+    let director = EventDirector::new();
+    let gated = dating_only(99);
+    let cast = director
+        .cast_storylet_roles(&gated, &world, &memory, world.current_tick)
+        .expect("cast");
+    assert_eq!(cast.roles[0].npc_id, NpcId(3));
+}
+
+#[test]
+fn mismatch_triggers_a_reaction_once() {
+    let mut world = world();
+    let mut sim = SimState::new();
+    syn_core::declare_relationship(
+        &mut world,
+        NpcId(1),
+        NpcId(2),
+        DeclaredStatus::Dating,
+        "test",
+    );
+    let mut rel = world.get_relationship(NpcId(1), NpcId(2));
+    rel.state = RelationshipState::Rival;
+    world.set_relationship(NpcId(1), NpcId(2), rel);
+
+    let mut reckoning = storylet("are_we_still_together", 2);
+    reckoning.triggers = StoryletTrigger {
+        kind: Some("declared_mismatch:dating".to_string()),
+    };
+    assert!(!eligible(&world, &sim, &reckoning));
+
+    update_declared_mismatches(&mut world);
+    assert!(eligible(&world, &sim, &reckoning));
+
+    let choice = reckoning.outcomes.choices[0].clone();
+    apply_storylet_choice_outcome(&mut world, &mut sim, &reckoning, &choice);
+    update_declared_mismatches(&mut world);
+    assert!(!eligible(&world, &sim, &reckoning));
+}
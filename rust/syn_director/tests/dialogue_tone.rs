@@ -0,0 +1,77 @@
+use syn_core::{
+    DialogueTone, MemoryEntryRecord, NpcId, Relationship, SimTick, WorldSeed, WorldState,
+    TONE_FLAG_REMEMBERS_BETRAYAL,
+};
+use syn_director::{
+    select_next_event_view, Storylet, StoryletChoice, StoryletCooldown, StoryletLibrary,
+    StoryletOutcome, StoryletOutcomeSet, StoryletRole, StoryletRoles,
+};
+use syn_sim::SimState;
+
+fn coffee_with_friend() -> Storylet {
+    Storylet {
+        id: "coffee_catch_up".to_string(),
+        name: "Coffee Catch-Up".to_string(),
+        roles: StoryletRoles::from(vec![StoryletRole {
+            name: "friend".to_string(),
+            npc_id: NpcId(2),
+        }]),
+        outcomes: StoryletOutcomeSet {
+            choices: vec![StoryletChoice {
+                id: "chat".to_string(),
+                label: "Chat".to_string(),
+                skill_requirements: Vec::new(),
+                outcome: StoryletOutcome::default(),
+            }],
+            ..Default::default()
+        },
+        cooldown: StoryletCooldown { ticks: 0 },
+        weight: 1.0,
+        ..Default::default()
+    }
+}
+
+fn world_with_friend() -> WorldState {
+    let mut world = WorldState::new(WorldSeed(8), NpcId(1));
+    world.set_relationship(
+        NpcId(2),
+        NpcId(1),
+        Relationship {
+            affection: 7.0,
+            trust: 6.0,
+            familiarity: 6.0,
+            ..Default::default()
+        },
+    );
+    world
+}
+
+#[test]
+fn event_view_carries_each_cast_members_tone() {
+    let library = StoryletLibrary::from_storylets(vec![coffee_with_friend()]);
+
+    let mut sim = SimState::new();
+    let mut world = world_with_friend();
+    let view = select_next_event_view(&mut world, &mut sim, &library).expect("coffee fires");
+    assert_eq!(view.tone.len(), 1);
+    assert_eq!(view.tone[0].npc_id, NpcId(2));
+    assert_eq!(view.tone[0].tone, DialogueTone::Warm);
+
+    // Same friendly storylet, but the friend remembers being betrayed.
+    let mut sim = SimState::new();
+    let mut world = world_with_friend();
+    world.memory_entries.push(MemoryEntryRecord {
+        id: "betrayal:1".to_string(),
+        event_id: "told_their_secret".to_string(),
+        npc_id: NpcId(2),
+        sim_tick: SimTick(0),
+        emotional_intensity: -0.8,
+        tags: vec!["betrayal".to_string()],
+        participants: vec![1],
+        ..Default::default()
+    });
+    let view = select_next_event_view(&mut world, &mut sim, &library).expect("coffee fires");
+    assert_eq!(view.storylet_id, "coffee_catch_up");
+    assert_eq!(view.tone[0].tone, DialogueTone::Guarded);
+    assert!(view.tone[0].has_flag(TONE_FLAG_REMEMBERS_BETRAYAL));
+}
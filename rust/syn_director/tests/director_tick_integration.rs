@@ -29,6 +29,7 @@ fn apply_choice_advances_time_and_applies_outcome() {
             choices: vec![StoryletChoice {
                 id: "c1".to_string(),
                 label: "Proceed".to_string(),
+                skill_requirements: Vec::new(),
                 outcome: StoryletOutcome {
                     stat_deltas: vec![StatDelta {
                         kind: StatKind::Mood,
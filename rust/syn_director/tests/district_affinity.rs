@@ -0,0 +1,104 @@
+use syn_core::{AbstractNpc, AttachmentStyle, NpcId, Traits, WorldSeed, WorldState};
+use syn_director::{
+    score_storylet_full_simple, storylet_is_eligible, DistrictAffinity, Storylet,
+    StoryletCooldown, StoryletOutcomeSet, StoryletPrerequisites, StoryletRole, StoryletRoles,
+    TagBitset, TimeAndLocationPrereqs,
+};
+use syn_sim::SimState;
+
+fn npc(id: u64, district: &str) -> AbstractNpc {
+    AbstractNpc {
+        id: NpcId(id),
+        age: 30,
+        job: "Barista".to_string(),
+        district: district.to_string(),
+        household_id: id,
+        traits: Traits::default(),
+        seed: id,
+        attachment_style: AttachmentStyle::Secure,
+    }
+}
+
+fn world_with(player_district: &str, npc_district: &str) -> WorldState {
+    let mut world = WorldState::new(WorldSeed(5), NpcId(1));
+    world.npcs.insert(NpcId(1), npc(1, player_district));
+    world.npcs.insert(NpcId(2), npc(2, npc_district));
+    world
+}
+
+fn local_storylet(location: TimeAndLocationPrereqs) -> Storylet {
+    Storylet {
+        id: "street_food_cart".to_string(),
+        name: "Street Food Cart".to_string(),
+        tags: TagBitset::default(),
+        prerequisites: StoryletPrerequisites {
+            time_and_location: Some(location),
+            ..Default::default()
+        },
+        roles: StoryletRoles::from(vec![StoryletRole {
+            name: "target".to_string(),
+            npc_id: NpcId(2),
+        }]),
+        heat: 5,
+        triggers: Default::default(),
+        outcomes: StoryletOutcomeSet::default(),
+        cooldown: StoryletCooldown { ticks: 0 },
+        weight: 1.0,
+    }
+}
+
+#[test]
+fn required_district_matches_player_or_primary_npc() {
+    let sim = SimState::new();
+    let storylet = local_storylet(TimeAndLocationPrereqs {
+        required_district: Some("Downtown".to_string()),
+        ..Default::default()
+    });
+
+    let player_there = world_with("downtown", "Suburbs");
+    let npc_there = world_with("Suburbs", "Downtown");
+    let neither = world_with("Suburbs", "Suburbs");
+
+    assert!(storylet_is_eligible(&player_there, &sim, &storylet, &player_there.storylet_usage));
+    assert!(storylet_is_eligible(&npc_there, &sim, &storylet, &npc_there.storylet_usage));
+    assert!(!storylet_is_eligible(&neither, &sim, &storylet, &neither.storylet_usage));
+}
+
+#[test]
+fn preferred_districts_scale_score() {
+    let sim = SimState::new();
+    let storylet = local_storylet(TimeAndLocationPrereqs {
+        preferred_districts: vec![
+            DistrictAffinity {
+                district: "Downtown".to_string(),
+                multiplier: 2.0,
+            },
+            DistrictAffinity {
+                district: "Suburbs".to_string(),
+                multiplier: 0.5,
+            },
+        ],
+        ..Default::default()
+    });
+
+    let elsewhere = score_storylet_full_simple(&world_with("Harbor", "Harbor"), &sim, &storylet);
+    let downtown = score_storylet_full_simple(&world_with("Harbor", "Downtown"), &sim, &storylet);
+    let suburbs = score_storylet_full_simple(&world_with("Suburbs", "Harbor"), &sim, &storylet);
+    // Strongest affinity wins when player and NPC are in different preferred districts
+    let both = score_storylet_full_simple(&world_with("Suburbs", "Downtown"), &sim, &storylet);
+
+    assert!((downtown - elsewhere * 2.0).abs() < 1e-4);
+    assert!((suburbs - elsewhere * 0.5).abs() < 1e-4);
+    assert!((both - downtown).abs() < 1e-4);
+}
+
+#[test]
+fn district_affinity_deserializes_with_default_multiplier() {
+    let location: TimeAndLocationPrereqs = serde_json::from_str(
+        r#"{ "required_district": "Downtown", "preferred_districts": [{ "district": "Downtown" }] }"#,
+    )
+    .unwrap();
+
+    assert_eq!(location.required_district.as_deref(), Some("Downtown"));
+    assert!((location.preferred_districts[0].multiplier - 1.5).abs() < f32::EPSILON);
+}
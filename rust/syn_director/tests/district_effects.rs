@@ -0,0 +1,112 @@
+//! Storylet outcomes that move the district simulation.
+
+use syn_core::{district_flag, NpcId, SimTick, WorldSeed, WorldState};
+use syn_director::storylet_loader::parse_storylet_str;
+use syn_director::{apply_choice_and_advance, EventDirector, Storylet, StoryletLibrary};
+use syn_memory::MemorySystem;
+use syn_sim::SimState;
+
+const FACTORY_CLOSES: &str = r#"{
+  "id": "factory_closes",
+  "heat": 30,
+  "weight": 1.0,
+  "prerequisites": {
+    "life_stages": [],
+    "tags": [],
+    "relationship_states": [],
+    "memory_tags_required": [],
+    "memory_tags_forbidden": []
+  },
+  "outcomes": {
+    "district_effects": [
+      {
+        "district": "Industrial Park",
+        "economy_delta": -25.0,
+        "flag": "factory_closed"
+      },
+      { "crime_delta": 20.0 }
+    ],
+    "choices": [{ "id": "read_the_news", "label": "Read the news", "outcome": {} }]
+  }
+}"#;
+
+fn factory_closes() -> Storylet {
+    parse_storylet_str(FACTORY_CLOSES).expect("storylet parses")
+}
+
+/// A world whose player lives in the Eastside Projects.
+fn world() -> WorldState {
+    let mut world = WorldState::new(WorldSeed(14), NpcId(1));
+    world.npcs.insert(
+        NpcId(1),
+        syn_core::AbstractNpc {
+            id: NpcId(1),
+            age: 24,
+            job: "Clerk".to_string(),
+            district: "Eastside Projects".to_string(),
+            household_id: 1,
+            traits: syn_core::Traits::default(),
+            seed: 3,
+            attachment_style: syn_core::AttachmentStyle::Secure,
+        },
+    );
+    world
+}
+
+fn economy(world: &WorldState, name: &str) -> f32 {
+    world.districts.get_by_name(name).expect("district exists").economy
+}
+
+fn crime(world: &WorldState, name: &str) -> f32 {
+    world.districts.get_by_name(name).expect("district exists").crime
+}
+
+fn assert_factory_closed(before: &WorldState, after: &WorldState) {
+    assert!(economy(after, "Industrial Park") < economy(before, "Industrial Park"));
+    assert!(crime(after, "Eastside Projects") > crime(before, "Eastside Projects"));
+    assert!((economy(after, "Downtown") - economy(before, "Downtown")).abs() < 1e-6);
+    assert!(after
+        .world_flags
+        .has_any(&district_flag("Industrial Park", "factory_closed")));
+}
+
+#[test]
+fn fired_storylets_shock_their_districts() {
+    let mut director = EventDirector::new();
+    let storylet = factory_closes();
+    director.register_storylet(storylet.clone());
+    let before = world();
+    let mut world = before.clone();
+    let mut memory = MemorySystem::new();
+
+    assert!(director.fire_storylet_choice(
+        &storylet,
+        &mut world,
+        &mut memory,
+        "read_the_news",
+        SimTick(1),
+    ));
+
+    assert_factory_closed(&before, &world);
+    let change = world.flag_changes.latest(1).next().expect("flag change recorded");
+    assert_eq!(change.source, "storylet:factory_closes");
+}
+
+#[test]
+fn director_loop_choices_shock_their_districts() {
+    let before = world();
+    let mut world = before.clone();
+    let mut sim = SimState::new();
+    let library = StoryletLibrary::from_storylets(vec![factory_closes()]);
+
+    apply_choice_and_advance(
+        &mut world,
+        &mut sim,
+        &library,
+        "factory_closes",
+        "read_the_news",
+        0,
+    );
+
+    assert_factory_closed(&before, &world);
+}
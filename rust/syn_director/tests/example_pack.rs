@@ -0,0 +1,118 @@
+//! The example content pack: schema coverage, fixtures, and a full playthrough
+//! over the fixture world as a director regression corpus.
+
+use std::collections::HashSet;
+
+use syn_director::example_pack::EXAMPLE_NEIGHBORS;
+use syn_director::{
+    example_pack_dir, example_world, load_example_pack, run_example_pack, run_storylet_fixtures,
+    EligibilityContext, EligibilityEngine, ResolvedOutcomeTable,
+};
+use syn_storylets::validation::library_warnings;
+
+#[test]
+fn example_pack_compiles_without_warnings_or_quarantine() {
+    let library = load_example_pack().expect("example pack compiles");
+    assert!(library.total_count >= 30, "{} storylets", library.total_count);
+    assert!(library_warnings(&library).is_empty());
+    assert!(ResolvedOutcomeTable::resolve_strict(&library).is_ok());
+
+    for storylet in &library.storylets {
+        assert!(
+            storylet.follow_ups_resolved.iter().all(|f| f.target_key.is_some()),
+            "{} has a dangling follow-up",
+            storylet.id.0
+        );
+        // Skill checks come in pairs gated on one trait
+        if storylet.tags.iter().any(|t| t.0 == "skill_check") {
+            let gates = storylet.prerequisites.trait_thresholds.as_deref().unwrap_or_default();
+            assert_eq!(gates.len(), 1, "{}", storylet.id.0);
+        }
+    }
+}
+
+#[test]
+fn every_example_storylet_carries_a_passing_fixture() {
+    let library = load_example_pack().expect("example pack compiles");
+    let report = run_storylet_fixtures(example_pack_dir()).expect("example pack compiles");
+    assert_eq!(report.run, library.storylets.len());
+    assert!(
+        report.passed(),
+        "{}",
+        report
+            .failures
+            .iter()
+            .map(|f| f.to_string())
+            .collect::<Vec<_>>()
+            .join("\n")
+    );
+}
+
+#[test]
+fn the_fixture_world_opens_every_arc_but_no_later_beat() {
+    let library = load_example_pack().expect("example pack compiles");
+    let (world, memory) = example_world(7);
+    assert_eq!(world.known_npcs.len(), 5 + EXAMPLE_NEIGHBORS.len());
+
+    let ctx = EligibilityContext {
+        world: &world,
+        memory: &memory,
+        current_tick: world.current_tick,
+    };
+    let eligible: HashSet<&str> = EligibilityEngine::new(&library)
+        .find_eligible_storylets(&ctx)
+        .into_iter()
+        .filter_map(|key| library.get_by_key(key))
+        .map(|s| s.id.0.as_str())
+        .collect();
+
+    for opener in [
+        "example.romance.meet_cute",
+        "example.career.job_hunt",
+        "example.family.old_argument",
+        "example.friendship.coffee_catchup",
+    ] {
+        assert!(eligible.contains(opener), "{} should open", opener);
+    }
+    for later in [
+        "example.romance.first_date",
+        "example.career.first_day",
+        "example.conflict.confront_rival",
+        "example.life.late_walk_home",
+    ] {
+        assert!(!eligible.contains(later), "{} should wait", later);
+    }
+}
+
+#[test]
+fn playing_the_pack_is_deterministic_and_follows_the_arcs() {
+    let library = load_example_pack().expect("example pack compiles");
+    let run = run_example_pack(&library, 42, 60);
+    let again = run_example_pack(&library, 42, 60);
+    assert_eq!(run.beats, again.beats);
+    assert!(run.beats.len() >= 30, "only {} beats in 60 days", run.beats.len());
+
+    // Nothing fires before the storylets it depends on
+    let mut fired: HashSet<&str> = HashSet::new();
+    let mut progressed = false;
+    for beat in &run.beats {
+        let storylet = library
+            .storylets
+            .iter()
+            .find(|s| s.id.0 == beat.storylet_id)
+            .expect("fired storylet is in the pack");
+        let deps = storylet.prerequisites.depends_on.as_deref().unwrap_or_default();
+        for dep in deps {
+            assert!(
+                fired.contains(dep.0.as_str()),
+                "{} fired at tick {} before {}",
+                beat.storylet_id,
+                beat.tick.0,
+                dep.0
+            );
+        }
+        progressed |= !deps.is_empty();
+        fired.insert(beat.storylet_id.as_str());
+    }
+    assert!(progressed, "no arc got past its opening beat");
+}
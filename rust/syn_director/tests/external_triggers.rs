@@ -0,0 +1,45 @@
+use syn_core::{inject_external_event, ExternalEvent, NpcId, WorldSeed, WorldState};
+use syn_director::{
+    score_storylet_full_simple, Storylet, StoryletCooldown, StoryletOutcomeSet, StoryletTrigger,
+    TagBitset,
+};
+use syn_sim::SimState;
+
+fn festival_storylet() -> Storylet {
+    Storylet {
+        id: "street_festival".to_string(),
+        name: "Street Festival".to_string(),
+        tags: TagBitset::default(),
+        prerequisites: Default::default(),
+        roles: Default::default(),
+        heat: 5,
+        triggers: StoryletTrigger {
+            kind: Some("festival".to_string()),
+        },
+        outcomes: StoryletOutcomeSet::default(),
+        cooldown: StoryletCooldown { ticks: 0 },
+        weight: 1.0,
+    }
+}
+
+#[test]
+fn forced_trigger_boosts_matching_storylets_until_consumed() {
+    let sim = SimState::new();
+    let storylet = festival_storylet();
+    let mut world = WorldState::new(WorldSeed(8), NpcId(1));
+
+    let before = score_storylet_full_simple(&world, &sim, &storylet);
+    inject_external_event(
+        &mut world,
+        ExternalEvent::Trigger {
+            trigger: "festival".to_string(),
+        },
+    )
+    .unwrap();
+    let forced = score_storylet_full_simple(&world, &sim, &storylet);
+    assert!((forced - before * 10.0).abs() < 1e-4);
+
+    assert!(world.external_events.consume_trigger("festival"));
+    let after = score_storylet_full_simple(&world, &sim, &storylet);
+    assert!((after - before).abs() < 1e-4);
+}
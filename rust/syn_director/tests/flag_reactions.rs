@@ -0,0 +1,86 @@
+//! Reaction storylets fire off recent world flag changes.
+
+use syn_core::{set_world_flag, NpcId, SimTick, WorldSeed, WorldState};
+use syn_director::{
+    apply_storylet_choice_outcome, score_storylet_full_simple, storylet_is_eligible, Storylet,
+    StoryletChoice, StoryletCooldown, StoryletOutcomeSet, StoryletTrigger,
+};
+use syn_sim::SimState;
+
+fn eligible(world: &WorldState, sim: &SimState, storylet: &Storylet) -> bool {
+    storylet_is_eligible(world, sim, storylet, &world.storylet_usage)
+}
+
+fn reaction_storylet() -> Storylet {
+    Storylet {
+        id: "fired_evening".to_string(),
+        name: "The Evening After".to_string(),
+        heat: 5,
+        triggers: StoryletTrigger {
+            kind: Some("flag_set:got_fired".to_string()),
+        },
+        outcomes: StoryletOutcomeSet {
+            choices: vec![StoryletChoice {
+                id: "drink".to_string(),
+                label: "Drink it off".to_string(),
+                skill_requirements: Vec::new(),
+                outcome: Default::default(),
+            }],
+            ..Default::default()
+        },
+        cooldown: StoryletCooldown { ticks: 0 },
+        weight: 1.0,
+        ..Default::default()
+    }
+}
+
+#[test]
+fn reaction_is_eligible_only_after_the_flag_flips() {
+    let sim = SimState::new();
+    let storylet = reaction_storylet();
+    let mut world = WorldState::new(WorldSeed(8), NpcId(1));
+    world.current_tick = SimTick(100);
+
+    assert!(!eligible(&world, &sim, &storylet));
+    let before = score_storylet_full_simple(&world, &sim, &storylet);
+
+    set_world_flag(&mut world, "got_fired", true, "storylet:layoffs");
+    assert!(eligible(&world, &sim, &storylet));
+    let boosted = score_storylet_full_simple(&world, &sim, &storylet);
+    assert!(boosted > before);
+
+    // A reaction to the opposite transition stays quiet.
+    let mut cleared = reaction_storylet();
+    cleared.triggers.kind = Some("flag_cleared:got_fired".to_string());
+    assert!(!eligible(&world, &sim, &cleared));
+
+    let window = world.flag_changes.config.reaction_window_ticks;
+    world.current_tick = SimTick(100 + window + 1);
+    assert!(!eligible(&world, &sim, &storylet));
+}
+
+#[test]
+fn firing_consumes_the_change_and_starts_the_cooldown() {
+    let mut sim = SimState::new();
+    let storylet = reaction_storylet();
+    let mut world = WorldState::new(WorldSeed(8), NpcId(1));
+    world.current_tick = SimTick(100);
+    set_world_flag(&mut world, "got_fired", true, "storylet:layoffs");
+
+    let choice = storylet.outcomes.choices[0].clone();
+    apply_storylet_choice_outcome(&mut world, &mut sim, &storylet, &choice);
+    assert!(world.flag_changes.pending.is_empty());
+    assert!(!eligible(&world, &sim, &storylet));
+
+    // Rehired and fired again the next day: still on cooldown.
+    world.current_tick = SimTick(124);
+    set_world_flag(&mut world, "got_fired", false, "storylet:rehired");
+    set_world_flag(&mut world, "got_fired", true, "storylet:layoffs");
+    assert!(!eligible(&world, &sim, &storylet));
+
+    let cooldown = world.flag_changes.config.reaction_cooldown_ticks;
+    world.current_tick = SimTick(100 + cooldown);
+    set_world_flag(&mut world, "got_fired", false, "storylet:rehired");
+    set_world_flag(&mut world, "got_fired", true, "storylet:layoffs");
+    assert!(eligible(&world, &sim, &storylet));
+}
@@ -0,0 +1,132 @@
+//! Compiled storylets schedule their follow-ups, which fire on later ticks.
+
+use syn_core::{NpcId, SimTick, WorldSeed, WorldState};
+use syn_director::EventDirector;
+use syn_memory::MemorySystem;
+use syn_storylets::library::{CompiledStorylet, StoryletKey, StoryletLibrary};
+use syn_storylets::{
+    FlagOperation, FollowUpStorylet, GlobalFlags, LifeStage, Outcome, Prerequisites, StoryDomain,
+    StoryletId,
+};
+
+fn storylet(id: &str, key: u32, prerequisites: Prerequisites, outcomes: Outcome) -> CompiledStorylet {
+    CompiledStorylet {
+        id: StoryletId::new(id),
+        key: StoryletKey(key),
+        name: id.to_string(),
+        description: None,
+        tags: vec![],
+        domain: StoryDomain::Career,
+        life_stage: LifeStage::Adult,
+        heat: 2,
+        weight: 1.0,
+        roles: vec![],
+        prerequisites,
+        cooldowns: Default::default(),
+        outcomes,
+        follow_ups_resolved: vec![],
+    }
+}
+
+/// Only reachable as a follow-up: requires a flag nothing sets.
+fn chained_only() -> Prerequisites {
+    Prerequisites {
+        global_flags: Some(GlobalFlags {
+            must_be_set: vec!["never_set".to_string()],
+            must_be_unset: vec![],
+        }),
+        ..Default::default()
+    }
+}
+
+fn follow_up(id: &str, delay_ticks: u32, flag: Option<&str>) -> FollowUpStorylet {
+    FollowUpStorylet {
+        storylet_id: id.to_string(),
+        delay_ticks,
+        conditional_on_flag: flag.map(str::to_string),
+    }
+}
+
+/// A one-off job offer chaining into a first day and, if the job was
+/// taken, a celebration.
+fn library() -> StoryletLibrary {
+    let job_offer = storylet(
+        "job_offer",
+        0,
+        Prerequisites {
+            global_flags: Some(GlobalFlags {
+                must_be_set: vec![],
+                must_be_unset: vec!["job_offered".to_string()],
+            }),
+            ..Default::default()
+        },
+        Outcome {
+            flag_operations: Some(vec![FlagOperation {
+                flag: "job_offered".to_string(),
+                set: true,
+            }]),
+            follow_ups: Some(vec![
+                follow_up("celebration", 0, Some("took_job")),
+                follow_up("first_day", 3, None),
+            ]),
+            ..Default::default()
+        },
+    );
+    let first_day = storylet("first_day", 1, chained_only(), Outcome::default());
+    let celebration = storylet("celebration", 2, chained_only(), Outcome::default());
+
+    let mut lib = StoryletLibrary::new();
+    for s in [job_offer, first_day, celebration] {
+        lib.id_to_key.insert(s.id.clone(), s.key);
+        lib.life_stage_index.entry(s.life_stage).or_default().push(s.key);
+        lib.domain_index.entry(s.domain).or_default().push(s.key);
+        lib.storylets.push(s);
+        lib.total_count += 1;
+    }
+    lib
+}
+
+fn run(took_job: bool) -> Vec<(u64, String)> {
+    let lib = library();
+    let mut director = EventDirector::new();
+    let mut world = WorldState::new(WorldSeed(21), NpcId(1));
+    world.player_life_stage = syn_core::LifeStage::Adult;
+    let mut memory = MemorySystem::new();
+
+    let mut fired = Vec::new();
+    for tick in 1..=6 {
+        if let Some(id) =
+            director.tick_compiled_storylets_simple(&lib, &mut world, &mut memory, SimTick(tick))
+        {
+            if id == "job_offer" {
+                assert_eq!(director.pending_follow_ups().len(), 2);
+                if took_job {
+                    world.world_flags.set_any("took_job");
+                }
+            }
+            fired.push((tick, id));
+        }
+    }
+    assert!(director.pending_follow_ups().is_empty());
+    fired
+}
+
+#[test]
+fn follow_ups_fire_after_their_delay() {
+    assert_eq!(
+        run(true),
+        vec![
+            (1, "job_offer".to_string()),
+            (2, "celebration".to_string()),
+            (4, "first_day".to_string()),
+        ]
+    );
+}
+
+#[test]
+fn a_follow_up_whose_flag_is_unset_is_dropped() {
+    assert_eq!(
+        run(false),
+        vec![(1, "job_offer".to_string()), (4, "first_day".to_string())]
+    );
+}
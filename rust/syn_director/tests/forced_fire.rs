@@ -0,0 +1,136 @@
+//! Forcing a storylet bypasses scoring but still casts, warns and applies.
+
+use syn_core::{
+    AbstractNpc, AttachmentStyle, NpcId, SimTick, StatDelta, StatKind, Traits, WorldSeed,
+    WorldState,
+};
+use syn_director::{
+    EventDirector, ForceFireError, Storylet, StoryletChoice, StoryletOutcome, StoryletOutcomeSet,
+    StoryletPrerequisites, StoryletRole, StoryletRoles,
+};
+use syn_memory::MemorySystem;
+
+fn npc(id: u64) -> AbstractNpc {
+    AbstractNpc {
+        id: NpcId(id),
+        age: 30,
+        job: "Teacher".to_string(),
+        district: "Downtown".to_string(),
+        household_id: 1,
+        traits: Traits::default(),
+        seed: id,
+        attachment_style: AttachmentStyle::Secure,
+    }
+}
+
+fn world() -> WorldState {
+    let mut world = WorldState::new(WorldSeed(42), NpcId(1));
+    for id in [3, 4] {
+        world.npcs.insert(NpcId(id), npc(id));
+    }
+    world.known_npcs = vec![NpcId(3), NpcId(4)];
+    world
+}
+
+fn director() -> EventDirector {
+    let mut director = EventDirector::new();
+    director.register_storylet(Storylet {
+        id: "coffee_catch_up".to_string(),
+        name: "Coffee Catch-Up".to_string(),
+        heat: 10,
+        weight: 1.0,
+        prerequisites: StoryletPrerequisites {
+            min_relationship_affection: Some(5.0),
+            ..Default::default()
+        },
+        roles: StoryletRoles::from(vec![StoryletRole {
+            name: "friend".to_string(),
+            npc_id: NpcId(3),
+        }]),
+        outcomes: StoryletOutcomeSet {
+            choices: vec![StoryletChoice {
+                id: "chat".to_string(),
+                label: "Chat".to_string(),
+                skill_requirements: Vec::new(),
+                outcome: StoryletOutcome {
+                    stat_deltas: vec![StatDelta {
+                        kind: StatKind::Mood,
+                        delta: 2.0,
+                        source: None,
+                    }],
+                    ..Default::default()
+                },
+            }],
+            ..Default::default()
+        },
+        ..Default::default()
+    });
+    director
+}
+
+#[test]
+fn ineligible_storylet_fires_with_warnings_and_overrides() {
+    let mut world = world();
+    let mut memory = MemorySystem::new();
+    let mut director = director();
+    let tick = SimTick(0);
+    assert!(director.find_eligible(&world, &memory, tick).is_empty());
+    let mood = world.player_stats.get(StatKind::Mood);
+
+    let overrides = vec![
+        ("friend".to_string(), NpcId(4)),
+        ("rival".to_string(), NpcId(3)),
+    ];
+    let fired = director
+        .force_fire_storylet("coffee_catch_up", &overrides, &mut world, &mut memory, tick)
+        .expect("storylet is known");
+
+    assert_eq!(fired.cast, vec![("friend".to_string(), NpcId(4))]);
+    assert!(fired
+        .warnings
+        .iter()
+        .any(|w| w == "override for unknown role 'rival'"));
+    assert!(fired
+        .warnings
+        .iter()
+        .any(|w| w == "prerequisite not met: min_relationship_affection"));
+    assert!((world.player_stats.get(StatKind::Mood) - mood - 2.0).abs() < 1e-4);
+    assert_eq!(world.storylet_usage.count("coffee_catch_up"), 1);
+    assert_eq!(director.forced_fires(), &[fired]);
+}
+
+#[test]
+fn missing_override_npc_is_recast_with_a_warning() {
+    let mut world = world();
+    let mut memory = MemorySystem::new();
+    let mut director = director();
+
+    let fired = director
+        .force_fire_storylet(
+            "coffee_catch_up",
+            &[("friend".to_string(), NpcId(99))],
+            &mut world,
+            &mut memory,
+            SimTick(0),
+        )
+        .unwrap();
+    assert_ne!(fired.cast[0].1, NpcId(99));
+    assert!(fired
+        .warnings
+        .iter()
+        .any(|w| w.starts_with("role 'friend': npc 99 doesn't exist")));
+}
+
+#[test]
+fn unknown_storylet_fires_nothing() {
+    let mut world = world();
+    let mut memory = MemorySystem::new();
+    let mut director = director();
+
+    let err = director
+        .force_fire_storylet("nope", &[], &mut world, &mut memory, SimTick(0))
+        .unwrap_err();
+    assert_eq!(err, ForceFireError::UnknownStorylet("nope".to_string()));
+    assert!(director.forced_fires().is_empty());
+    assert_eq!(world.storylet_usage.count("nope"), 0);
+}
@@ -0,0 +1,130 @@
+use syn_core::{HouseholdRole, NpcId, SimTick, WorldSeed, WorldState};
+use syn_director::{
+    EventDirector, Storylet, StoryletCooldown, StoryletOutcome, StoryletOutcomeSet,
+    StoryletPrerequisites, StoryletRole, StoryletRoles, TagBitset, WorldFlagUpdate,
+};
+use syn_memory::MemorySystem;
+
+fn build_storylet(
+    id: &str,
+    lives_with_target: Option<bool>,
+    flags: Vec<WorldFlagUpdate>,
+) -> Storylet {
+    Storylet {
+        id: id.to_string(),
+        name: "Storylet".to_string(),
+        tags: TagBitset::default(),
+        prerequisites: StoryletPrerequisites {
+            lives_with_target,
+            ..Default::default()
+        },
+        roles: StoryletRoles::from(vec![StoryletRole {
+            name: "target".to_string(),
+            npc_id: NpcId(2),
+        }]),
+        heat: 10,
+        triggers: Default::default(),
+        outcomes: StoryletOutcomeSet {
+            flags,
+            ..Default::default()
+        },
+        cooldown: StoryletCooldown { ticks: 0 },
+        weight: 0.5,
+    }
+}
+
+fn world_with_npc() -> WorldState {
+    let mut world = WorldState::new(WorldSeed(42), NpcId(1));
+    world.npcs.insert(
+        NpcId(2),
+        syn_core::AbstractNpc {
+            id: NpcId(2),
+            age: 28,
+            job: "Nurse".to_string(),
+            district: "Riverside".to_string(),
+            household_id: 7,
+            traits: syn_core::Traits::default(),
+            seed: 99,
+            attachment_style: syn_core::AttachmentStyle::Secure,
+        },
+    );
+    world
+}
+
+#[test]
+fn move_in_flag_enables_lives_with_target_storylets() {
+    let mut director = EventDirector::new();
+    let mut world = world_with_npc();
+    let mut memory = MemorySystem::new();
+
+    let move_in = build_storylet(
+        "move_in_together",
+        Some(false),
+        vec![WorldFlagUpdate {
+            flag: "move_in:partner:2".to_string(),
+            value: true,
+        }],
+    );
+    director.register_storylet(move_in.clone());
+    director.register_storylet(build_storylet("breakfast_together", Some(true), Vec::new()));
+
+    let eligible: Vec<&str> = director
+        .find_eligible(&world, &memory, SimTick(1))
+        .iter()
+        .map(|s| s.id.as_str())
+        .collect();
+    assert_eq!(eligible, vec!["move_in_together"]);
+
+    director.fire_storylet(
+        &move_in,
+        &mut world,
+        &mut memory,
+        StoryletOutcome::default(),
+        SimTick(1),
+    );
+
+    let household = world.households.household_of(world.player_id).unwrap();
+    assert_eq!(household.cohabiting_since, Some(SimTick(1)));
+    assert_eq!(household.district.as_deref(), Some("Riverside"));
+    assert_eq!(
+        household.member(NpcId(2)).unwrap().role,
+        HouseholdRole::Partner
+    );
+    assert_eq!(world.npcs[&NpcId(2)].household_id, household.id);
+    assert!(!world.world_flags.has_any("move_in:partner:2"));
+
+    let eligible: Vec<&str> = director
+        .find_eligible(&world, &memory, SimTick(2))
+        .iter()
+        .map(|s| s.id.as_str())
+        .collect();
+    assert_eq!(eligible, vec!["breakfast_together"]);
+}
+
+#[test]
+fn clearing_move_in_flag_moves_npc_out() {
+    let mut director = EventDirector::new();
+    let mut world = world_with_npc();
+    let mut memory = MemorySystem::new();
+
+    for (id, value) in [("move_in", true), ("move_out", false)] {
+        let storylet = build_storylet(
+            id,
+            None,
+            vec![WorldFlagUpdate {
+                flag: "move_in:2".to_string(),
+                value,
+            }],
+        );
+        director.fire_storylet(
+            &storylet,
+            &mut world,
+            &mut memory,
+            StoryletOutcome::default(),
+            SimTick(3),
+        );
+    }
+
+    assert!(!world.households.lives_with(world.player_id, NpcId(2)));
+    assert_eq!(world.npcs[&NpcId(2)].household_id, 7);
+}
@@ -0,0 +1,60 @@
+//! Reloading the storylet library swaps content but keeps cooldowns.
+
+use syn_core::{NpcId, SimTick};
+use syn_director::storylet_loader::parse_storylet_str;
+use syn_director::{CooldownEntry, EventDirector, LibraryReload, Storylet};
+
+fn storylet(id: &str, weight: f32) -> Storylet {
+    parse_storylet_str(&format!(
+        r#"{{ "id": "{}", "name": "{}", "heat": 10, "weight": {} }}"#,
+        id, id, weight
+    ))
+    .expect("storylet parses")
+}
+
+fn cooldown(storylet_id: &str) -> CooldownEntry {
+    CooldownEntry {
+        storylet_id: storylet_id.to_string(),
+        npc_id: Some(NpcId(2)),
+        until: SimTick(50),
+    }
+}
+
+fn director_ids(director: &EventDirector) -> Vec<String> {
+    director
+        .all_storylets()
+        .iter()
+        .map(|storylet| storylet.id.clone())
+        .collect()
+}
+
+#[test]
+fn reload_swaps_storylets_and_keeps_cooldowns_of_surviving_ids() {
+    let mut director = EventDirector::new();
+    director.replace_storylets(vec![
+        storylet("reload_coffee", 1.0),
+        storylet("reload_old_beat", 1.0),
+    ]);
+    director.restore_cooldowns(&[cooldown("reload_coffee"), cooldown("reload_old_beat")]);
+
+    // Edit one storylet, drop another, add two
+    let report = director.replace_storylets(vec![
+        storylet("reload_coffee", 5.0),
+        storylet("reload_new_beat", 1.0),
+        storylet("reload_other_beat", 1.0),
+    ]);
+    assert_eq!(
+        report,
+        LibraryReload {
+            added: vec!["reload_new_beat".to_string(), "reload_other_beat".to_string()],
+            removed: vec!["reload_old_beat".to_string()],
+            kept: vec!["reload_coffee".to_string()],
+        }
+    );
+    assert_eq!(
+        director_ids(&director),
+        vec!["reload_coffee", "reload_new_beat", "reload_other_beat"]
+    );
+    assert!((director.all_storylets()[0].weight - 5.0).abs() < f32::EPSILON);
+    assert_eq!(director.cooldown_entries(), vec![cooldown("reload_coffee")]);
+}
@@ -0,0 +1,106 @@
+use syn_core::{
+    adopt_life_goal, update_life_goals, GoalRequirement, GoalStatus, LifeGoal, NpcId, StatKind,
+    WorldSeed, WorldState,
+};
+use syn_director::{
+    apply_choice_and_advance, life_goal_score_multiplier, preview_next_event,
+    score_storylet_full_simple, select_next_event_view, tags_to_bitset, Storylet, StoryletChoice,
+    StoryletCooldown, StoryletLibrary, StoryletOutcome, StoryletOutcomeSet,
+};
+use syn_sim::SimState;
+
+fn storylet(id: &str, tags: &[&str]) -> Storylet {
+    let tags: Vec<String> = tags.iter().map(|t| t.to_string()).collect();
+    Storylet {
+        id: id.to_string(),
+        name: id.to_string(),
+        tags: tags_to_bitset(&tags),
+        outcomes: StoryletOutcomeSet {
+            choices: vec![StoryletChoice {
+                id: "go".to_string(),
+                label: "Go".to_string(),
+                skill_requirements: Vec::new(),
+                outcome: StoryletOutcome::default(),
+            }],
+            ..Default::default()
+        },
+        cooldown: StoryletCooldown { ticks: 0 },
+        weight: 1.0,
+        ..Default::default()
+    }
+}
+
+fn wealth_goal(payoff: &str) -> LifeGoal {
+    LifeGoal {
+        id: "get_rich".to_string(),
+        title: "Get rich".to_string(),
+        requirements: vec![GoalRequirement::Stat {
+            stat: StatKind::Wealth,
+            min: 60.0,
+        }],
+        tags: vec!["money".to_string()],
+        deadline_age: None,
+        payoff_storylet: Some(payoff.to_string()),
+        failure_storylet: None,
+    }
+}
+
+#[test]
+fn active_goals_favor_matching_storylets() {
+    let mut world = WorldState::new(WorldSeed(3), NpcId(1));
+    let sim = SimState::new();
+    let side_hustle = storylet("side_hustle", &["money"]);
+    let picnic = storylet("picnic", &["leisure"]);
+    let before = score_storylet_full_simple(&world, &sim, &side_hustle);
+
+    assert!(adopt_life_goal(&mut world, wealth_goal("goal_payoff")));
+    assert_eq!(life_goal_score_multiplier(&world, &side_hustle), 1.5);
+    assert_eq!(life_goal_score_multiplier(&world, &picnic), 1.0);
+    assert!(score_storylet_full_simple(&world, &sim, &side_hustle) > before);
+}
+
+#[test]
+fn payoff_storylet_is_delivered_next_and_cleared_when_played() {
+    let mut world = WorldState::new(WorldSeed(3), NpcId(1));
+    let mut sim = SimState::new();
+    let mut library = StoryletLibrary::new();
+    library.storylets.push(storylet("coffee_run", &[]));
+    library.storylets.push(storylet("goal_payoff", &[]));
+
+    adopt_life_goal(&mut world, wealth_goal("goal_payoff"));
+    world.player_stats.wealth = 75.0;
+    assert_eq!(update_life_goals(&mut world), vec!["get_rich"]);
+    assert_eq!(
+        world.life_goals.get("get_rich").map(|g| g.status),
+        Some(GoalStatus::Achieved)
+    );
+
+    let preview = preview_next_event(&world, &sim, &library);
+    assert_eq!(
+        preview.selected.map(|v| v.storylet_id),
+        Some("goal_payoff".to_string())
+    );
+    let view = select_next_event_view(&mut world, &mut sim, &library).unwrap();
+    assert_eq!(view.storylet_id, "goal_payoff");
+
+    let next =
+        apply_choice_and_advance(&mut world, &mut sim, &library, "goal_payoff", "go", 0).unwrap();
+    assert!(world.life_goals.pending_storylets.is_empty());
+    assert_ne!(next.storylet_id, "goal_payoff");
+}
+
+#[test]
+fn unauthored_payoffs_are_dropped() {
+    let mut world = WorldState::new(WorldSeed(3), NpcId(1));
+    let mut sim = SimState::new();
+    let mut library = StoryletLibrary::new();
+    library.storylets.push(storylet("coffee_run", &[]));
+
+    adopt_life_goal(&mut world, wealth_goal("not_written_yet"));
+    world.player_stats.wealth = 75.0;
+    update_life_goals(&mut world);
+
+    let view = select_next_event_view(&mut world, &mut sim, &library).unwrap();
+    assert_eq!(view.storylet_id, "coffee_run");
+    assert!(world.life_goals.pending_storylets.is_empty());
+}
@@ -0,0 +1,94 @@
+use syn_core::{
+    mark_major_character, update_importance, ImportanceSource, NpcId, SimTick, WorldSeed,
+    WorldState, CHECK_IN_BRUSH_OFF_CHOICE, CHECK_IN_CATCH_UP_CHOICE,
+};
+use syn_director::{
+    apply_choice_and_advance, preview_next_event, select_next_event_view, Storylet, StoryletChoice,
+    StoryletCooldown, StoryletLibrary, StoryletOutcome, StoryletOutcomeSet,
+};
+use syn_sim::SimState;
+
+fn library() -> StoryletLibrary {
+    let mut library = StoryletLibrary::new();
+    library.storylets.push(Storylet {
+        id: "coffee_run".to_string(),
+        name: "Coffee Run".to_string(),
+        outcomes: StoryletOutcomeSet {
+            choices: vec![StoryletChoice {
+                id: "go".to_string(),
+                label: "Go".to_string(),
+                skill_requirements: Vec::new(),
+                outcome: StoryletOutcome::default(),
+            }],
+            ..Default::default()
+        },
+        cooldown: StoryletCooldown { ticks: 0 },
+        weight: 1.0,
+        ..Default::default()
+    });
+    library
+}
+
+/// NPC 2 is a major character whose check-in is due.
+fn world_with_due_check_in() -> (WorldState, String) {
+    let mut world = WorldState::new(WorldSeed(9), NpcId(1));
+    mark_major_character(&mut world, NpcId(2), ImportanceSource::Authored);
+    world.current_tick = SimTick(world.importance.config.check_in_interval_ticks);
+    update_importance(&mut world);
+    let storylet_id = world.importance.next_check_in().unwrap().storylet_id();
+    (world, storylet_id)
+}
+
+#[test]
+fn due_check_in_is_delivered_before_authored_storylets() {
+    let (mut world, storylet_id) = world_with_due_check_in();
+    let mut sim = SimState::new();
+    let library = library();
+
+    let view = select_next_event_view(&mut world, &mut sim, &library).unwrap();
+    assert_eq!(view.storylet_id, storylet_id);
+    let choice_ids: Vec<&str> = view.choices.iter().map(|c| c.id.as_str()).collect();
+    assert_eq!(
+        choice_ids,
+        vec![CHECK_IN_CATCH_UP_CHOICE, CHECK_IN_BRUSH_OFF_CHOICE]
+    );
+
+    let preview = preview_next_event(&world, &sim, &library);
+    assert_eq!(preview.selected.map(|v| v.storylet_id), Some(storylet_id));
+}
+
+#[test]
+fn answering_through_the_director_loop_resets_the_check_in_clock() {
+    let (mut world, storylet_id) = world_with_due_check_in();
+    let mut sim = SimState::new();
+    let library = library();
+
+    let next = apply_choice_and_advance(
+        &mut world,
+        &mut sim,
+        &library,
+        &storylet_id,
+        CHECK_IN_BRUSH_OFF_CHOICE,
+        0,
+    )
+    .unwrap();
+
+    assert_eq!(next.storylet_id, "coffee_run");
+    assert!(world.importance.pending_check_ins.is_empty());
+    assert!(world.get_relationship(NpcId(2), NpcId(1)).affection < 0.0);
+    assert_eq!(
+        world.importance.get(NpcId(2)).unwrap().last_check_in_tick,
+        world.current_tick.0
+    );
+
+    // Answering twice does nothing.
+    assert!(apply_choice_and_advance(
+        &mut world,
+        &mut sim,
+        &library,
+        &storylet_id,
+        CHECK_IN_CATCH_UP_CHOICE,
+        0,
+    )
+    .is_none());
+}
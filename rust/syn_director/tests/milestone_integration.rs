@@ -10,6 +10,8 @@ use syn_memory::{MemoryEntry, MemorySystem};
 #[test]
 fn storylet_outcome_records_friend_to_rival_milestone() {
     let mut world = WorldState::new(WorldSeed(1), NpcId(1));
+    // Queue on the first evaluation instead of waiting out the confirmation window
+    world.relationship_milestones.tuning.confirm_ticks = 0;
     world.relationships.insert(
         (NpcId(1), NpcId(2)),
         Relationship {
@@ -37,7 +39,7 @@ fn storylet_outcome_records_friend_to_rival_milestone() {
     );
     mem_entry.tags = vec!["betrayal".into()];
     mem_entry.participants = vec![1, 2];
-    memory.record_memory(mem_entry);
+    memory.record_memory(mem_entry, None);
 
     let prereqs = StoryletPrerequisites {
         relationship_prereqs: vec![syn_director::RelationshipPrereq {
@@ -0,0 +1,99 @@
+//! Storylets whose roles name NPCs that don't exist are recast, not rejected.
+
+use syn_core::{AbstractNpc, AttachmentStyle, NpcId, SimTick, Traits, WorldSeed, WorldState};
+use syn_director::{EventDirector, Storylet, StoryletRole, StoryletRoles};
+use syn_memory::MemorySystem;
+
+fn npc(id: u64) -> AbstractNpc {
+    AbstractNpc {
+        id: NpcId(id),
+        age: 30,
+        job: "Teacher".to_string(),
+        district: "Downtown".to_string(),
+        household_id: 1,
+        traits: Traits::default(),
+        seed: id,
+        attachment_style: AttachmentStyle::Secure,
+    }
+}
+
+/// Player 1 knows NPCs 3 (a close friend) and 4; NPC 5 exists but is a stranger.
+fn world() -> WorldState {
+    let mut world = WorldState::new(WorldSeed(42), NpcId(1));
+    for id in [3, 4, 5] {
+        world.npcs.insert(NpcId(id), npc(id));
+    }
+    world.known_npcs = vec![NpcId(4), NpcId(3)];
+    let mut friend = world.get_relationship(NpcId(1), NpcId(3));
+    friend.affection = 6.0;
+    friend.trust = 5.0;
+    world.set_relationship(NpcId(1), NpcId(3), friend);
+    world
+}
+
+fn storylet_with_role(npc_id: u64) -> Storylet {
+    Storylet {
+        id: "coffee_catch_up".to_string(),
+        name: "Coffee Catch-Up".to_string(),
+        roles: StoryletRoles::from(vec![StoryletRole {
+            name: "friend".to_string(),
+            npc_id: NpcId(npc_id),
+        }]),
+        ..Storylet::default()
+    }
+}
+
+#[test]
+fn missing_role_npc_is_recast_and_reported_once() {
+    let world = world();
+    let memory = MemorySystem::new();
+    let mut director = EventDirector::new();
+    let storylet = storylet_with_role(2);
+    director.register_storylet(storylet.clone());
+
+    let cast = director
+        .cast_storylet_roles(&storylet, &world, &memory, SimTick(0))
+        .expect("role should be recast");
+    assert_eq!(cast.roles[0].npc_id, NpcId(3));
+
+    assert_eq!(director.find_eligible(&world, &memory, SimTick(0)).len(), 1);
+    assert_eq!(director.find_eligible(&world, &memory, SimTick(1)).len(), 1);
+
+    let diagnostics = director.content_diagnostics();
+    assert_eq!(diagnostics.len(), 1);
+    assert_eq!(diagnostics[0].storylet_id, "coffee_catch_up");
+    assert_eq!(diagnostics[0].role, "friend");
+    assert_eq!(diagnostics[0].npc_id, NpcId(2));
+    assert_eq!(diagnostics[0].recast_to, Some(NpcId(3)));
+    assert_eq!(diagnostics[0].tick, 0);
+}
+
+#[test]
+fn existing_role_npc_is_used_as_is() {
+    let world = world();
+    let memory = MemorySystem::new();
+    let director = EventDirector::new();
+    let storylet = storylet_with_role(4);
+
+    let cast = director
+        .cast_storylet_roles(&storylet, &world, &memory, SimTick(0))
+        .unwrap();
+    assert_eq!(cast.roles[0].npc_id, NpcId(4));
+    assert!(director.content_diagnostics().is_empty());
+}
+
+#[test]
+fn storylet_is_rejected_when_nobody_can_be_recast() {
+    let mut world = world();
+    world.known_npcs.clear();
+    let memory = MemorySystem::new();
+    let mut director = EventDirector::new();
+    director.register_storylet(storylet_with_role(2));
+
+    assert!(director
+        .find_eligible(&world, &memory, SimTick(0))
+        .is_empty());
+    let diagnostics = director.content_diagnostics();
+    assert_eq!(diagnostics.len(), 1);
+    assert_eq!(diagnostics[0].recast_to, None);
+}
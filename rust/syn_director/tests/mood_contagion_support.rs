@@ -0,0 +1,95 @@
+use syn_core::{NpcId, Relationship, WorldSeed, WorldState};
+use syn_director::{
+    EventDirector, Storylet, StoryletCooldown, StoryletOutcomeSet, StoryletPrerequisites,
+    StoryletRole, StoryletRoles, TagBitset,
+};
+use syn_memory::MemorySystem;
+use syn_sim::{advance_simulation_ticks, SimulationTickConfig, WorldSimState};
+
+fn support_storylet() -> Storylet {
+    Storylet {
+        id: "check_in_on_friend".to_string(),
+        name: "Check In On A Friend".to_string(),
+        tags: TagBitset::default(),
+        prerequisites: StoryletPrerequisites {
+            max_target_mood: Some(-1.0),
+            ..Default::default()
+        },
+        roles: StoryletRoles::from(vec![StoryletRole {
+            name: "friend".to_string(),
+            npc_id: NpcId(2),
+        }]),
+        heat: 10,
+        triggers: Default::default(),
+        outcomes: StoryletOutcomeSet::default(),
+        cooldown: StoryletCooldown { ticks: 0 },
+        weight: 0.5,
+    }
+}
+
+fn bond(world: &mut WorldState, from: u64, to: u64, affection: f32) {
+    world.set_relationship(
+        NpcId(from),
+        NpcId(to),
+        Relationship {
+            affection,
+            ..Default::default()
+        },
+    );
+}
+
+#[test]
+fn crisis_cascades_into_support_storylet_eligibility() {
+    let mut director = EventDirector::new();
+    director.register_storylet(support_storylet());
+    let memory = MemorySystem::new();
+
+    let mut world = WorldState::new(WorldSeed(42), NpcId(1));
+    world.npcs.insert(
+        NpcId(2),
+        syn_core::AbstractNpc {
+            id: NpcId(2),
+            age: 30,
+            job: "Teacher".to_string(),
+            district: "Riverside".to_string(),
+            household_id: 7,
+            traits: syn_core::Traits::default(),
+            seed: 99,
+            attachment_style: syn_core::AttachmentStyle::Secure,
+        },
+    );
+    bond(&mut world, 1, 2, 7.0); // player is close to NPC 2
+    bond(&mut world, 2, 3, 9.0); // NPC 2 is devoted to NPC 3
+    world.npc_moods.set(NpcId(3), -9.0);
+
+    assert!(director
+        .find_eligible(&world, &memory, world.current_tick)
+        .is_empty());
+
+    // Contagion runs on the first mid-frequency tick (every 6 hours).
+    let mut sim_state = WorldSimState::new();
+    advance_simulation_ticks(
+        &mut world,
+        &mut sim_state,
+        &SimulationTickConfig::default(),
+        6,
+    );
+
+    assert!(world.npc_moods.get(NpcId(2)) <= -1.0);
+    let eligible: Vec<&str> = director
+        .find_eligible(&world, &memory, world.current_tick)
+        .iter()
+        .map(|s| s.id.as_str())
+        .collect();
+    assert_eq!(eligible, vec!["check_in_on_friend"]);
+
+    // The next pass carries NPC 2's shift on to the player.
+    let mood_before = world.player_stats.mood;
+    advance_simulation_ticks(
+        &mut world,
+        &mut sim_state,
+        &SimulationTickConfig::default(),
+        6,
+    );
+    assert!(world.player_stats.mood < mood_before);
+}
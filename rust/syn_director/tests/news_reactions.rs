@@ -0,0 +1,68 @@
+use syn_core::{
+    NewsSource, NpcId, SimTick, WorldSeed, WorldState, NEWSWORTHY_TAG, NEWS_REACTION_TAG,
+};
+use syn_director::{
+    tags_to_bitset, EventDirector, Storylet, StoryletCooldown, StoryletOutcome, StoryletOutcomeSet,
+    StoryletPrerequisites, StoryletRoles,
+};
+use syn_memory::MemorySystem;
+
+fn storylet_with_tags(id: &str, tags: &[&str]) -> Storylet {
+    let tags: Vec<String> = tags.iter().map(|t| t.to_string()).collect();
+    Storylet {
+        id: id.to_string(),
+        name: format!("{} headline", id),
+        tags: tags_to_bitset(&tags),
+        prerequisites: StoryletPrerequisites {
+            tags,
+            ..Default::default()
+        },
+        roles: StoryletRoles::default(),
+        heat: 10,
+        triggers: Default::default(),
+        outcomes: StoryletOutcomeSet::default(),
+        cooldown: StoryletCooldown { ticks: 0 },
+        weight: 1.0,
+    }
+}
+
+#[test]
+fn newsworthy_storylets_are_published_and_trigger_reactions() {
+    let mut world = WorldState::new(WorldSeed(3), NpcId(1));
+    let mut memory = MemorySystem::new();
+    let mut director = EventDirector::new();
+
+    let heist = storylet_with_tags("bank_heist", &[NEWSWORTHY_TAG, "crime"]);
+    director.fire_storylet(
+        &heist,
+        &mut world,
+        &mut memory,
+        StoryletOutcome::default(),
+        SimTick(5),
+    );
+    assert!(
+        world.news_feed.is_empty(),
+        "reports publish with the daily feed"
+    );
+
+    world.current_tick = SimTick(23);
+    world.tick(&mut Default::default());
+    let reported: Vec<&str> = world
+        .news_feed
+        .latest(10)
+        .filter(|i| i.source == NewsSource::Storylet)
+        .map(|i| i.headline.as_str())
+        .collect();
+    assert_eq!(reported, vec!["bank_heist headline"]);
+
+    director.register_storylet(storylet_with_tags("gossip_at_school", &["crime"]));
+    director.register_storylet(storylet_with_tags(
+        "talk_about_heist",
+        &[NEWS_REACTION_TAG, "crime"],
+    ));
+
+    let chosen = director
+        .select_next_event(&world, &memory, world.current_tick)
+        .expect("expected a storylet");
+    assert_eq!(chosen.id, "talk_about_heist");
+}
@@ -1,7 +1,7 @@
 use syn_core::npc::{NpcPrototype, NpcRoleTag, PersonalityVector};
 use syn_core::{LifeStage, NpcId, Stats, WorldSeed, WorldState};
 use syn_director::{
-    prepare_storylet_execution, resolve_actor_ref_to_npc, StoryActorRef, Storylet, StoryletActors,
+    prepare_storylet_execution, resolve_actor_ref_to_npc, resolve_or_spawn_actor, StoryActorRef, Storylet, StoryletActors,
     StoryletCooldown, StoryletOutcomeSet, StoryletPrerequisites, StoryletRole, StoryletRoles,
     TagBitset,
 };
@@ -24,6 +24,9 @@ fn make_world_with_known_tag(id: NpcId, tag: NpcRoleTag) -> WorldState {
         base_stats: Stats::default(),
         active_stages: vec![LifeStage::Teen, LifeStage::Adult],
         schedule: Default::default(),
+        home_district: None,
+        tag_affinities: Default::default(),
+        major: false,
     };
     world.npc_prototypes.insert(id, proto);
     world.ensure_npc_known(id);
@@ -75,3 +78,51 @@ fn test_prepare_storylet_execution_focuses_npc() {
         .expect("NPC should be instantiated and focused");
     assert!(matches!(inst.lod, syn_sim::NpcLod::Tier2Active));
 }
+
+#[test]
+fn test_unfilled_role_tag_spawns_a_newcomer() {
+    let mut world = make_world_with_known_tag(NpcId(55), NpcRoleTag::Peer);
+    let mut registry = NpcRegistry::default();
+    let mentor = StoryActorRef::RoleTag(NpcRoleTag::Mentor);
+    assert_eq!(resolve_actor_ref_to_npc(&world, &registry, &mentor), None);
+
+    let mut outcomes = StoryletOutcomeSet::default();
+    outcomes.actors = Some(StoryletActors {
+        primary: Some(mentor.clone()),
+        secondary: Some(StoryActorRef::RoleTag(NpcRoleTag::Peer)),
+    });
+    let storylet = Storylet {
+        id: "first_lesson".into(),
+        name: "First Lesson".into(),
+        tags: TagBitset::default(),
+        prerequisites: StoryletPrerequisites::default(),
+        roles: StoryletRoles::default(),
+        heat: 0,
+        triggers: Default::default(),
+        outcomes,
+        cooldown: StoryletCooldown { ticks: 0 },
+        weight: 1.0,
+    };
+    prepare_storylet_execution(&mut world, &mut registry, &storylet, 30);
+
+    let spawned = resolve_actor_ref_to_npc(&world, &registry, &mentor).expect("mentor spawned");
+    assert_eq!(spawned, NpcId(56));
+    assert!(world.known_npcs.contains(&spawned));
+    assert!(world.npcs.contains_key(&spawned));
+    assert!(registry.get(spawned).is_some());
+    let origin = world
+        .spawned_npcs
+        .origin_of(spawned)
+        .expect("origin recorded");
+    assert_eq!(origin.storylet_id, "first_lesson");
+    assert_eq!(origin.role_tag, NpcRoleTag::Mentor);
+    // The known peer still plays the peer; nobody else was spawned.
+    assert_eq!(world.spawned_npcs.origins.len(), 1);
+
+    // Once cast, the newcomer is reused rather than spawned again.
+    assert_eq!(
+        resolve_or_spawn_actor(&mut world, &registry, &mentor, "second_lesson", 40),
+        Some(spawned)
+    );
+    assert_eq!(world.spawned_npcs.origins.len(), 1);
+}
@@ -0,0 +1,114 @@
+use syn_core::{
+    scan_for_proposals, LifeStage, NpcId, Relationship, RelationshipState, WorldSeed, WorldState,
+    PROPOSAL_ACCEPT_CHOICE, PROPOSAL_REJECT_CHOICE,
+};
+use syn_director::{
+    apply_choice_and_advance, preview_next_event, select_next_event_view, Storylet, StoryletChoice,
+    StoryletCooldown, StoryletLibrary, StoryletOutcome, StoryletOutcomeSet,
+};
+use syn_sim::SimState;
+
+fn everyday_storylet() -> Storylet {
+    Storylet {
+        id: "coffee_run".to_string(),
+        name: "Coffee Run".to_string(),
+        outcomes: StoryletOutcomeSet {
+            choices: vec![StoryletChoice {
+                id: "go".to_string(),
+                label: "Go".to_string(),
+                skill_requirements: Vec::new(),
+                outcome: StoryletOutcome::default(),
+            }],
+            ..Default::default()
+        },
+        cooldown: StoryletCooldown { ticks: 0 },
+        weight: 1.0,
+        ..Default::default()
+    }
+}
+
+fn library() -> StoryletLibrary {
+    let mut library = StoryletLibrary::new();
+    library.storylets.push(everyday_storylet());
+    library
+}
+
+/// NPC 2 has fallen for the player.
+fn smitten_world() -> WorldState {
+    let mut world = WorldState::new(WorldSeed(9), NpcId(1));
+    world.player_life_stage = LifeStage::YoungAdult;
+    world.set_relationship(
+        NpcId(2),
+        NpcId(1),
+        Relationship {
+            affection: 7.0,
+            trust: 5.0,
+            attraction: 7.5,
+            familiarity: 3.0,
+            ..Default::default()
+        },
+    );
+    world
+}
+
+#[test]
+fn pending_proposal_is_delivered_before_authored_storylets() {
+    let mut world = smitten_world();
+    let mut sim = SimState::new();
+    let library = library();
+    let id = scan_for_proposals(&mut world)[0];
+
+    let view = select_next_event_view(&mut world, &mut sim, &library).unwrap();
+    assert_eq!(view.storylet_id, format!("proposal:{}", id));
+    let choice_ids: Vec<&str> = view.choices.iter().map(|c| c.id.as_str()).collect();
+    assert_eq!(
+        choice_ids,
+        vec![PROPOSAL_ACCEPT_CHOICE, PROPOSAL_REJECT_CHOICE]
+    );
+
+    let preview = preview_next_event(&world, &sim, &library);
+    assert_eq!(
+        preview.selected.map(|v| v.storylet_id),
+        Some(view.storylet_id.clone())
+    );
+}
+
+#[test]
+fn accepting_through_the_director_loop_makes_partners() {
+    let mut world = smitten_world();
+    let mut sim = SimState::new();
+    let library = library();
+    let id = scan_for_proposals(&mut world)[0];
+
+    let next = apply_choice_and_advance(
+        &mut world,
+        &mut sim,
+        &library,
+        &format!("proposal:{}", id),
+        PROPOSAL_ACCEPT_CHOICE,
+        0,
+    )
+    .unwrap();
+
+    assert_eq!(next.storylet_id, "coffee_run");
+    assert_eq!(
+        world.get_relationship(NpcId(1), NpcId(2)).state,
+        RelationshipState::Partner
+    );
+    assert_eq!(
+        world.get_relationship(NpcId(2), NpcId(1)).state,
+        RelationshipState::Partner
+    );
+    assert!(world.proposals.pending.is_empty());
+
+    // Answering twice does nothing.
+    assert!(apply_choice_and_advance(
+        &mut world,
+        &mut sim,
+        &library,
+        &format!("proposal:{}", id),
+        PROPOSAL_REJECT_CHOICE,
+        0,
+    )
+    .is_none());
+}
@@ -0,0 +1,95 @@
+use syn_core::npc::{NpcPrototype, PersonalityVector};
+use syn_core::relationship_model::RelationshipAxis;
+use syn_core::{NpcId, Stats, WorldSeed, WorldState};
+use syn_director::{
+    npc_tag_affinity_score_multiplier, RelationshipPrereq, Storylet, StoryletCooldown,
+    StoryletOutcomeSet, StoryletPrerequisites, TagBitset,
+};
+
+fn prototype(id: u64, volatility: f32) -> NpcPrototype {
+    NpcPrototype {
+        id: NpcId(id),
+        display_name: format!("npc_{}", id),
+        role_label: None,
+        role_tags: vec![],
+        personality: PersonalityVector {
+            warmth: 0.0,
+            dominance: 0.0,
+            volatility,
+            conscientiousness: 0.5,
+            openness: 0.5,
+        },
+        base_stats: Stats::default(),
+        active_stages: vec![],
+        schedule: Default::default(),
+        home_district: None,
+        tag_affinities: Default::default(),
+        major: false,
+    }
+}
+
+fn world_with(volatility: f32) -> WorldState {
+    let mut world = WorldState::new(WorldSeed(3), NpcId(1));
+    world.npc_prototypes.insert(NpcId(2), prototype(2, volatility));
+    world.npc_prototypes.insert(NpcId(3), prototype(3, volatility));
+    world
+}
+
+fn storylet(tag: &str, actor_id: Option<u64>) -> Storylet {
+    Storylet {
+        id: "bar_brawl".to_string(),
+        name: "Bar Brawl".to_string(),
+        tags: TagBitset::from_tags(vec![tag.to_string()]),
+        prerequisites: StoryletPrerequisites {
+            relationship_prereqs: vec![RelationshipPrereq {
+                actor_id,
+                target_id: 3,
+                axis: RelationshipAxis::Resentment,
+                min_value: None,
+                max_value: None,
+                min_band: None,
+                max_band: None,
+            }],
+            ..Default::default()
+        },
+        cooldown: StoryletCooldown { ticks: 0 },
+        outcomes: StoryletOutcomeSet::default(),
+        weight: 1.0,
+        ..Default::default()
+    }
+}
+
+#[test]
+fn volatile_npcs_pull_background_trouble_forward() {
+    let background = storylet("trouble", Some(2));
+
+    let chaotic = npc_tag_affinity_score_multiplier(&world_with(1.0), &background);
+    let calm = npc_tag_affinity_score_multiplier(&world_with(-1.0), &background);
+    assert!(chaotic > 1.2, "chaotic multiplier {}", chaotic);
+    assert!(calm < 0.8, "calm multiplier {}", calm);
+}
+
+#[test]
+fn authored_affinities_override_personality() {
+    let mut world = world_with(1.0);
+    for id in [2, 3] {
+        let proto = world.npc_prototypes.get_mut(&NpcId(id)).unwrap();
+        proto.tag_affinities.insert("trouble".to_string(), -1.0);
+    }
+
+    let multiplier = npc_tag_affinity_score_multiplier(&world, &storylet("trouble", Some(2)));
+    assert_eq!(multiplier, 0.5);
+}
+
+#[test]
+fn storylets_with_the_player_are_unaffected() {
+    let world = world_with(1.0);
+    assert_eq!(
+        npc_tag_affinity_score_multiplier(&world, &storylet("trouble", None)),
+        1.0
+    );
+    assert_eq!(
+        npc_tag_affinity_score_multiplier(&world, &storylet("trouble", Some(1))),
+        1.0
+    );
+}
@@ -0,0 +1,79 @@
+use syn_core::relationship_model::{RelationshipAxis, RelationshipDelta};
+use syn_core::{NpcId, Relationship, SimTick, WorldSeed, WorldState};
+use syn_director::{
+    apply_storylet_outcome_with_memory, Storylet, StoryletCooldown, StoryletOutcome,
+    StoryletOutcomeSet, StoryletPrerequisites, StoryletRoles, TagBitset,
+};
+use syn_memory::MemorySystem;
+
+fn storylet() -> Storylet {
+    Storylet {
+        id: "group_fight".into(),
+        name: "Group Fight".into(),
+        tags: TagBitset::default(),
+        prerequisites: StoryletPrerequisites::default(),
+        roles: StoryletRoles::default(),
+        heat: 0,
+        triggers: Default::default(),
+        outcomes: StoryletOutcomeSet::default(),
+        cooldown: StoryletCooldown { ticks: 0 },
+        weight: 1.0,
+    }
+}
+
+fn delta(actor_id: u64, target_id: u64, axis: RelationshipAxis, delta: f32) -> RelationshipDelta {
+    RelationshipDelta {
+        actor_id,
+        target_id,
+        axis,
+        delta,
+        source: None,
+    }
+}
+
+/// Apply the same deltas to a world whose relationships were inserted in the
+/// given pair order, returning the resulting event queues.
+fn run(pairs: &[(u64, u64)], deltas: Vec<RelationshipDelta>) -> (String, String) {
+    let mut world = WorldState::new(WorldSeed(3), NpcId(1));
+    for &(a, b) in pairs {
+        let rel = Relationship {
+            affection: 4.5,
+            trust: 3.0,
+            ..Default::default()
+        };
+        world.set_relationship(NpcId(a), NpcId(b), rel);
+    }
+    let mut memory = MemorySystem::new();
+    let outcome = StoryletOutcome {
+        relationship_deltas: deltas,
+        ..Default::default()
+    };
+    apply_storylet_outcome_with_memory(&mut world, &mut memory, &storylet(), &outcome, SimTick(9));
+
+    (
+        format!("{:?}", world.relationship_pressure.queue),
+        format!("{:?}", world.relationship_milestones.queue),
+    )
+}
+
+#[test]
+fn outcome_event_queues_ignore_insertion_order() {
+    let pairs = [(1, 2), (1, 3), (2, 3), (3, 1), (4, 2)];
+    let deltas = vec![
+        delta(1, 2, RelationshipAxis::Affection, 4.0),
+        delta(3, 1, RelationshipAxis::Resentment, 6.0),
+        delta(2, 3, RelationshipAxis::Trust, -5.0),
+        delta(1, 3, RelationshipAxis::Affection, 2.0),
+    ];
+
+    let mut reversed_pairs = pairs;
+    reversed_pairs.reverse();
+    let mut reversed_deltas = deltas.clone();
+    reversed_deltas.reverse();
+
+    let forward = run(&pairs, deltas);
+    let backward = run(&reversed_pairs, reversed_deltas);
+
+    assert!(forward.0.len() > 2, "expected band-change events");
+    assert_eq!(forward, backward);
+}
@@ -0,0 +1,199 @@
+use syn_core::relationship_model::{RelationshipAxis, RelationshipDelta, RelationshipRole};
+use syn_core::{NpcId, Relationship, SimTick, StatDelta, StatKind, WorldSeed, WorldState};
+use syn_director::{
+    EventDirector, OutcomeBatchError, Storylet, StoryletCooldown, StoryletOutcome,
+    StoryletOutcomeSet, StoryletRole, StoryletRoles,
+};
+use syn_memory::{MemoryEntry, MemorySystem};
+
+fn storylet(id: &str) -> Storylet {
+    Storylet {
+        id: id.into(),
+        name: id.into(),
+        roles: StoryletRoles::from(vec![StoryletRole {
+            name: "target".into(),
+            npc_id: NpcId(2),
+        }]),
+        heat: 1,
+        outcomes: StoryletOutcomeSet::default(),
+        cooldown: StoryletCooldown { ticks: 48 },
+        weight: 1.0,
+        ..Default::default()
+    }
+}
+
+fn resentment(delta: f32) -> StoryletOutcome {
+    StoryletOutcome {
+        relationship_deltas: vec![RelationshipDelta {
+            actor_id: 1,
+            target_id: 2,
+            axis: RelationshipAxis::Resentment,
+            delta,
+            source: None,
+        }],
+        ..Default::default()
+    }
+}
+
+fn friends_world() -> WorldState {
+    let mut world = WorldState::new(WorldSeed(3), NpcId(1));
+    world.relationship_milestones.tuning.confirm_ticks = 0;
+    world.npcs.insert(
+        NpcId(2),
+        syn_core::AbstractNpc {
+            id: NpcId(2),
+            age: 30,
+            job: "Teacher".to_string(),
+            district: "Downtown".to_string(),
+            household_id: 1,
+            traits: syn_core::Traits::default(),
+            seed: 12345,
+            attachment_style: syn_core::AttachmentStyle::Secure,
+        },
+    );
+    world.set_relationship(
+        NpcId(1),
+        NpcId(2),
+        Relationship {
+            affection: 6.0,
+            trust: 6.0,
+            ..Default::default()
+        },
+    );
+    world
+        .relationship_milestones
+        .record_role_for_pair(1, 2, RelationshipRole::Friend);
+    world
+}
+
+#[test]
+fn batch_applies_in_order_and_marks_cooldowns() {
+    let mut world = friends_world();
+    let mut memory = MemorySystem::new();
+    let mut director = EventDirector::new();
+    director.register_storylet(storylet("argument"));
+    director.register_storylet(storylet("apology"));
+    assert_eq!(director.find_eligible(&world, &memory, SimTick(0)).len(), 2);
+
+    let mut apology = resentment(-2.0);
+    apology.stat_deltas = vec![StatDelta {
+        kind: StatKind::Mood,
+        delta: 1.5,
+        source: None,
+    }];
+    let report = director
+        .apply_outcome_batch(
+            &mut world,
+            &mut memory,
+            vec![
+                ("argument".to_string(), resentment(5.0)),
+                ("apology".to_string(), apology),
+            ],
+            SimTick(0),
+        )
+        .expect("batch applies");
+
+    assert_eq!(report.applied, 2);
+    assert_eq!(report.pairs_changed, vec![(1, 2)]);
+    let rel = world.get_relationship(NpcId(1), NpcId(2));
+    assert!(
+        (rel.resentment - 3.0).abs() < 1e-4,
+        "resentment {}",
+        rel.resentment
+    );
+    assert!(director
+        .find_eligible(&world, &memory, SimTick(1))
+        .is_empty());
+}
+
+#[test]
+fn pressure_and_milestones_see_only_the_net_change() {
+    let mut world = friends_world();
+    let mut memory = MemorySystem::new();
+    let mut director = EventDirector::new();
+    // Friend-to-rival milestones need a betrayal between the pair.
+    let mut betrayal = MemoryEntry::new(
+        "mem_1".into(),
+        "event_betrayal".into(),
+        NpcId(1),
+        SimTick(0),
+        -0.8,
+    );
+    betrayal.tags = vec!["betrayal".into()];
+    betrayal.participants = vec![1, 2];
+    memory.record_memory(betrayal, None);
+    director.register_storylet(storylet("blowup"));
+    director.register_storylet(storylet("makeup"));
+
+    let report = director
+        .apply_outcome_batch(
+            &mut world,
+            &mut memory,
+            vec![
+                ("blowup".to_string(), resentment(9.0)),
+                ("makeup".to_string(), resentment(-9.0)),
+            ],
+            SimTick(0),
+        )
+        .expect("batch applies");
+
+    assert_eq!(report.milestone_events, 0);
+    assert!(world.relationship_milestones.pop_next().is_none());
+
+    // The blowup on its own does cross into rivalry. On the next day, as the
+    // swings above spent most of today's delta cap for the pair.
+    world.current_tick = SimTick(24);
+    let report = director
+        .apply_outcome_batch(
+            &mut world,
+            &mut memory,
+            vec![("blowup".to_string(), resentment(9.0))],
+            SimTick(24),
+        )
+        .expect("batch applies");
+    assert_eq!(report.milestone_events, 1);
+}
+
+#[test]
+fn rejected_batch_changes_nothing() {
+    let mut world = friends_world();
+    let mut memory = MemorySystem::new();
+    let mut director = EventDirector::new();
+    director.register_storylet(storylet("argument"));
+    let before = world.get_relationship(NpcId(1), NpcId(2));
+
+    let err = director
+        .apply_outcome_batch(
+            &mut world,
+            &mut memory,
+            vec![
+                ("argument".to_string(), resentment(5.0)),
+                ("missing".to_string(), resentment(1.0)),
+            ],
+            SimTick(0),
+        )
+        .unwrap_err();
+    assert_eq!(
+        err,
+        OutcomeBatchError::UnknownStorylet {
+            index: 1,
+            storylet_id: "missing".to_string(),
+        }
+    );
+
+    let err = director
+        .apply_outcome_batch(
+            &mut world,
+            &mut memory,
+            vec![("argument".to_string(), resentment(f32::NAN))],
+            SimTick(0),
+        )
+        .unwrap_err();
+    assert!(matches!(
+        err,
+        OutcomeBatchError::NonFiniteValue { index: 0, .. }
+    ));
+
+    assert_eq!(world.get_relationship(NpcId(1), NpcId(2)), before);
+    assert_eq!(director.find_eligible(&world, &memory, SimTick(0)).len(), 1);
+}
@@ -0,0 +1,136 @@
+//! Conditional and skill-check branches inside a storylet outcome.
+
+use syn_core::{NpcId, Relationship, SimTick, StatKind, WorldSeed, WorldState};
+use syn_director::storylet_loader::parse_storylet_str;
+use syn_director::{resolve_outcome_branches, BranchContext, EventDirector, Storylet};
+use syn_memory::MemorySystem;
+
+const CONFESSION: &str = r#"{
+  "id": "late_night_confession",
+  "heat": 20,
+  "weight": 1.0,
+  "roles": [{ "name": "friend", "npc_id": 2 }],
+  "prerequisites": {
+    "life_stages": [],
+    "tags": [],
+    "relationship_states": [],
+    "memory_tags_required": [],
+    "memory_tags_forbidden": []
+  },
+  "outcomes": {
+    "choices": [
+      {
+        "id": "tell_them",
+        "label": "Tell them everything",
+        "outcome": {
+          "memory_tags": ["confession"],
+          "branches": [
+            {
+              "if": "trust >= Trusted",
+              "then": { "memory_tags": ["kept_secret"] },
+              "else": { "memory_tags": ["secret_spilled"], "heat_spike": 5.0 }
+            },
+            {
+              "check": { "stat": "Charisma", "modifier": 0 },
+              "then": {
+                "stat_impacts": [{ "kind": "Mood", "delta": 2.0 }],
+                "branches": [{ "then": { "karma_delta": 1.0 } }]
+              },
+              "else": { "stat_impacts": [{ "kind": "Mood", "delta": -2.0 }] }
+            }
+          ]
+        }
+      }
+    ]
+  }
+}"#;
+
+fn confession() -> Storylet {
+    parse_storylet_str(CONFESSION).expect("storylet parses")
+}
+
+fn world(trust: f32, charisma: f32) -> WorldState {
+    let mut world = WorldState::new(WorldSeed(21), NpcId(1));
+    world.npcs.insert(
+        NpcId(2),
+        syn_core::AbstractNpc {
+            id: NpcId(2),
+            age: 30,
+            job: "Teacher".to_string(),
+            district: "Riverside".to_string(),
+            household_id: 7,
+            traits: syn_core::Traits::default(),
+            seed: 99,
+            attachment_style: syn_core::AttachmentStyle::Secure,
+        },
+    );
+    world.player_stats.charisma = charisma;
+    world.set_relationship(
+        NpcId(1),
+        NpcId(2),
+        Relationship {
+            trust,
+            ..Default::default()
+        },
+    );
+    world
+}
+
+fn resolve(world: &WorldState, tick: u64) -> syn_director::StoryletOutcome {
+    let storylet = confession();
+    let memory = MemorySystem::new();
+    let mut ctx =
+        BranchContext::new(world, &memory, Some(NpcId(2)), &storylet.id, SimTick(tick));
+    resolve_outcome_branches(&storylet.outcomes.choices[0].outcome, &mut ctx)
+}
+
+#[test]
+fn conditions_and_checks_pick_the_branch() {
+    let trusted = resolve(&world(4.0, 100.0), 3);
+    assert_eq!(trusted.memory_tags, vec!["confession", "kept_secret"]);
+    assert!(trusted.branches.is_empty());
+    assert_eq!(trusted.stat_deltas.len(), 1);
+    assert!(trusted.stat_deltas[0].delta > 0.0);
+    assert!(trusted.karma_delta.is_some_and(|k| (k - 1.0).abs() < 1e-6));
+
+    let wary = resolve(&world(0.0, 0.0), 3);
+    assert_eq!(wary.memory_tags, vec!["confession", "secret_spilled"]);
+    assert!((wary.heat_spike - 5.0).abs() < 1e-6);
+    assert_eq!(wary.stat_deltas[0].kind, StatKind::Mood);
+    assert!(wary.stat_deltas[0].delta < 0.0);
+    assert_eq!(wary.karma_delta, None);
+}
+
+#[test]
+fn skill_checks_roll_the_same_at_the_same_tick() {
+    let first: Vec<f32> = (0..20)
+        .map(|tick| resolve(&world(0.0, 50.0), tick).stat_deltas[0].delta)
+        .collect();
+    let again: Vec<f32> = (0..20)
+        .map(|tick| resolve(&world(0.0, 50.0), tick).stat_deltas[0].delta)
+        .collect();
+    assert_eq!(first, again);
+    assert!(first.iter().any(|d| *d > 0.0));
+    assert!(first.iter().any(|d| *d < 0.0));
+}
+
+#[test]
+fn fired_choices_apply_their_branch() {
+    let mut director = EventDirector::new();
+    let storylet = confession();
+    director.register_storylet(storylet.clone());
+    let mut world = world(0.0, 100.0);
+    let mut memory = MemorySystem::new();
+    let mood = world.player_stats.mood;
+
+    assert!(director.fire_storylet_choice(
+        &storylet,
+        &mut world,
+        &mut memory,
+        "tell_them",
+        SimTick(1),
+    ));
+    assert!(world.player_stats.mood > mood);
+    let outcome = &director.chronicle().entries()[0].outcome;
+    assert!((outcome.heat_spike - 5.0).abs() < 1e-6);
+}
@@ -0,0 +1,114 @@
+//! World flags set and cleared by storylet outcomes, read back by global
+//! conditions.
+
+use syn_core::{NpcId, SimTick, WorldSeed, WorldState};
+use syn_director::storylet_loader::parse_storylet_str;
+use syn_director::{
+    apply_choice_and_advance, storylet_is_eligible, EventDirector, GlobalWorldStateFlag, Storylet,
+    StoryletCooldown, StoryletLibrary, StoryletPrerequisites,
+};
+use syn_memory::MemorySystem;
+use syn_sim::SimState;
+
+const CONFESSION: &str = r#"{
+  "id": "confession",
+  "heat": 10,
+  "weight": 1.0,
+  "prerequisites": {
+    "life_stages": [],
+    "tags": [],
+    "relationship_states": [],
+    "memory_tags_required": [],
+    "memory_tags_forbidden": []
+  },
+  "outcomes": {
+    "flags": [{ "flag": "confession_heard", "value": true }],
+    "choices": [
+      {
+        "id": "tell_the_truth",
+        "label": "Tell the truth",
+        "outcome": {
+          "flags": [
+            { "flag": "told_the_truth", "value": true },
+            { "flag": "keeping_a_secret", "set": false }
+          ]
+        }
+      },
+      { "id": "lie", "label": "Lie", "outcome": {} }
+    ]
+  }
+}"#;
+
+fn confession() -> Storylet {
+    parse_storylet_str(CONFESSION).expect("storylet parses")
+}
+
+/// Only open once the player has come clean.
+fn clean_slate() -> Storylet {
+    Storylet {
+        id: "clean_slate".to_string(),
+        name: "Clean Slate".to_string(),
+        prerequisites: StoryletPrerequisites {
+            global_conditions: vec![
+                GlobalWorldStateFlag {
+                    flag: "told_the_truth".to_string(),
+                    value: true,
+                },
+                GlobalWorldStateFlag {
+                    flag: "keeping_a_secret".to_string(),
+                    value: false,
+                },
+            ],
+            ..Default::default()
+        },
+        cooldown: StoryletCooldown { ticks: 0 },
+        weight: 1.0,
+        ..Default::default()
+    }
+}
+
+fn world() -> WorldState {
+    let mut world = WorldState::new(WorldSeed(12), NpcId(1));
+    world.world_flags.set_any("keeping_a_secret");
+    world
+}
+
+fn clean_slate_open(world: &WorldState, sim: &SimState) -> bool {
+    storylet_is_eligible(world, sim, &clean_slate(), &world.storylet_usage)
+}
+
+#[test]
+fn choice_flags_open_storylets_gated_on_them() {
+    let mut world = world();
+    let mut sim = SimState::new();
+    let library = StoryletLibrary::from_storylets(vec![confession()]);
+    assert!(!clean_slate_open(&world, &sim));
+
+    apply_choice_and_advance(&mut world, &mut sim, &library, "confession", "tell_the_truth", 0);
+
+    assert!(world.world_flags.has_any("confession_heard"));
+    assert!(world.world_flags.has_any("told_the_truth"));
+    assert!(!world.world_flags.has_any("keeping_a_secret"));
+    assert!(clean_slate_open(&world, &sim));
+}
+
+#[test]
+fn fired_choices_set_only_their_own_flags() {
+    let mut director = EventDirector::new();
+    let storylet = confession();
+    director.register_storylet(storylet.clone());
+    let mut world = world();
+    let mut memory = MemorySystem::new();
+
+    assert!(director.fire_storylet_choice(&storylet, &mut world, &mut memory, "lie", SimTick(1)));
+
+    assert!(world.world_flags.has_any("confession_heard"));
+    assert!(!world.world_flags.has_any("told_the_truth"));
+    assert!(world.world_flags.has_any("keeping_a_secret"));
+    let sources: Vec<&str> = world
+        .flag_changes
+        .latest(4)
+        .map(|change| change.source.as_str())
+        .collect();
+    assert_eq!(sources, vec!["storylet:confession"]);
+}
@@ -0,0 +1,154 @@
+use std::sync::{Arc, Mutex};
+
+use syn_core::relationship_model::{RelationshipAxis, RelationshipDelta};
+use syn_core::{NpcId, SimTick, WorldSeed, WorldState};
+use syn_director::{
+    EventDirector, HeatStage, MemoryStage, OutcomeContext, OutcomePipeline, OutcomeStage,
+    RelationshipStage, Storylet, StoryletCooldown, StoryletOutcome, StoryletOutcomeSet,
+    StoryletPrerequisites, StoryletRoles, TagBitset,
+};
+use syn_memory::MemorySystem;
+
+fn storylet(heat: i32) -> Storylet {
+    Storylet {
+        id: "heated_argument".into(),
+        name: "Heated Argument".into(),
+        tags: TagBitset::default(),
+        prerequisites: StoryletPrerequisites::default(),
+        roles: StoryletRoles::default(),
+        heat,
+        triggers: Default::default(),
+        outcomes: StoryletOutcomeSet::default(),
+        cooldown: StoryletCooldown { ticks: 0 },
+        weight: 1.0,
+    }
+}
+
+fn outcome() -> StoryletOutcome {
+    StoryletOutcome {
+        relationship_deltas: vec![RelationshipDelta {
+            actor_id: 1,
+            target_id: 2,
+            axis: RelationshipAxis::Affection,
+            delta: 3.0,
+            source: None,
+        }],
+        memory_event_id: "argued_with_friend".into(),
+        emotional_intensity: -0.4,
+        ..Default::default()
+    }
+}
+
+/// Records the player's affection toward NPC 2 as seen when the stage runs.
+struct AffectionProbe(Arc<Mutex<Vec<f32>>>);
+
+impl OutcomeStage for AffectionProbe {
+    fn name(&self) -> &'static str {
+        "affection_probe"
+    }
+
+    fn apply(&self, world: &mut WorldState, _memory: &mut MemorySystem, _ctx: &OutcomeContext<'_>) {
+        let affection = world.get_relationship(NpcId(1), NpcId(2)).affection;
+        self.0.lock().unwrap().push(affection);
+    }
+}
+
+#[test]
+fn standard_pipeline_runs_stages_in_documented_order() {
+    assert_eq!(
+        OutcomePipeline::standard().stage_names(),
+        vec![
+            "stats",
+            "relationships",
+            "observers",
+            "karma",
+            "districts",
+            "heat",
+            "memory",
+            "pressure",
+            "gossip",
+            "flags"
+        ]
+    );
+}
+
+#[test]
+fn stages_apply_only_their_own_slice() {
+    let mut world = WorldState::new(WorldSeed(5), NpcId(1));
+    let mut memory = MemorySystem::new();
+    let heat_before = world.narrative_heat.value();
+
+    let mut heat_only = OutcomePipeline::empty();
+    heat_only.register(Box::new(HeatStage));
+    heat_only.run(
+        &mut world,
+        &mut memory,
+        &storylet(4),
+        &outcome(),
+        SimTick(3),
+    );
+
+    assert!(world.narrative_heat.value() > heat_before);
+    assert!(world.get_relationship(NpcId(1), NpcId(2)).affection.abs() < f32::EPSILON);
+    assert!(memory.get_journal(NpcId(1)).is_none());
+
+    let mut memory_only = OutcomePipeline::empty();
+    memory_only.register(Box::new(MemoryStage));
+    memory_only.run(
+        &mut world,
+        &mut memory,
+        &storylet(4),
+        &outcome(),
+        SimTick(3),
+    );
+
+    let journal = memory.get_journal(NpcId(1)).expect("player journal");
+    assert_eq!(journal.entries.len(), 1);
+    assert_eq!(journal.entries[0].event_id, "argued_with_friend");
+
+    let mut relationships_only = OutcomePipeline::empty();
+    relationships_only.register(Box::new(RelationshipStage));
+    relationships_only.run(
+        &mut world,
+        &mut memory,
+        &storylet(4),
+        &outcome(),
+        SimTick(3),
+    );
+
+    assert!((world.get_relationship(NpcId(1), NpcId(2)).affection - 3.0).abs() < f32::EPSILON);
+}
+
+#[test]
+fn registered_stage_runs_after_standard_stages() {
+    let seen = Arc::new(Mutex::new(Vec::new()));
+    let mut pipeline = OutcomePipeline::standard();
+    pipeline.register(Box::new(AffectionProbe(Arc::clone(&seen))));
+    assert_eq!(pipeline.stage_names().last(), Some(&"affection_probe"));
+
+    let mut world = WorldState::new(WorldSeed(5), NpcId(1));
+    let mut memory = MemorySystem::new();
+    pipeline.run(
+        &mut world,
+        &mut memory,
+        &storylet(0),
+        &outcome(),
+        SimTick(3),
+    );
+
+    assert_eq!(*seen.lock().unwrap(), vec![3.0]);
+}
+
+#[test]
+fn director_fires_storylets_through_registered_stages() {
+    let seen = Arc::new(Mutex::new(Vec::new()));
+    let mut director = EventDirector::new();
+    director.register_outcome_stage(Box::new(AffectionProbe(Arc::clone(&seen))));
+
+    let mut world = WorldState::new(WorldSeed(5), NpcId(1));
+    let mut memory = MemorySystem::new();
+    director.fire_storylet(&storylet(0), &mut world, &mut memory, outcome(), SimTick(3));
+
+    assert_eq!(*seen.lock().unwrap(), vec![3.0]);
+    assert!(memory.get_journal(NpcId(1)).is_some());
+}
@@ -0,0 +1,103 @@
+use syn_core::{NpcId, Relationship, SimTick, StatKind, WorldSeed, WorldState};
+use syn_director::{EventDirector, OutcomeReferenceError};
+use syn_memory::MemorySystem;
+use syn_storylets::library::{CompiledStorylet, StoryletKey, StoryletLibrary};
+use syn_storylets::{
+    LifeStage, Outcome, RelationshipDelta, RoleSlot, StatDelta, StoryDomain, StoryletId,
+};
+
+fn storylet(key: u32, id: &str, outcomes: Outcome) -> CompiledStorylet {
+    CompiledStorylet {
+        id: StoryletId::new(id),
+        key: StoryletKey(key),
+        name: id.to_string(),
+        description: None,
+        tags: vec![],
+        domain: StoryDomain::Friendship,
+        life_stage: LifeStage::Adult,
+        heat: 2,
+        weight: 1.0,
+        roles: vec![RoleSlot {
+            name: "friend".to_string(),
+            required: true,
+            constraints: None,
+            allow_same_as: vec![],
+        }],
+        prerequisites: Default::default(),
+        cooldowns: Default::default(),
+        outcomes,
+        follow_ups_resolved: vec![],
+    }
+}
+
+fn library(storylets: Vec<CompiledStorylet>) -> StoryletLibrary {
+    let mut lib = StoryletLibrary::new();
+    for s in storylets {
+        lib.id_to_key.insert(s.id.clone(), s.key);
+        lib.life_stage_index
+            .entry(s.life_stage)
+            .or_default()
+            .push(s.key);
+        lib.domain_index.entry(s.domain).or_default().push(s.key);
+        lib.storylets.push(s);
+        lib.total_count += 1;
+    }
+    lib
+}
+
+#[test]
+fn quarantined_storylets_never_fire_and_valid_outcomes_apply_to_cast_roles() {
+    let broken = Outcome {
+        stat_deltas: Some(vec![StatDelta {
+            stat: "stress".to_string(),
+            delta: 5.0,
+        }]),
+        ..Default::default()
+    };
+    let coffee = Outcome {
+        stat_deltas: Some(vec![StatDelta {
+            stat: "mood".to_string(),
+            delta: 1.0,
+        }]),
+        relationship_deltas: Some(vec![RelationshipDelta {
+            from_role: "protagonist".to_string(),
+            to_role: "friend".to_string(),
+            axis: "trust".to_string(),
+            delta: 3.0,
+        }]),
+        ..Default::default()
+    };
+    let lib = library(vec![
+        storylet(0, "stressful_day", broken),
+        storylet(1, "coffee_with_friend", coffee),
+    ]);
+
+    let mut director = EventDirector::new();
+    let errors = director.load_compiled_outcomes(&lib);
+    assert_eq!(errors.len(), 1);
+    assert_eq!(errors[0].storylet_id, StoryletId::new("stressful_day"));
+    assert_eq!(errors[0].location, "outcomes.stat_deltas[0].stat");
+    assert_eq!(
+        errors[0].reason,
+        OutcomeReferenceError::UnknownStat("stress".to_string())
+    );
+
+    let mut world = WorldState::new(WorldSeed(11), NpcId(1));
+    world.player_life_stage = syn_core::LifeStage::Adult;
+    world.known_npcs.push(NpcId(2));
+    world.set_relationship(
+        world.player_id,
+        NpcId(2),
+        Relationship {
+            affection: 5.0,
+            ..Default::default()
+        },
+    );
+    let mut memory = MemorySystem::new();
+    let mood_before = world.player_stats.get(StatKind::Mood);
+
+    let fired = director.tick_compiled_storylets_simple(&lib, &mut world, &mut memory, SimTick(1));
+    assert_eq!(fired.as_deref(), Some("coffee_with_friend"));
+    assert_eq!(world.player_stats.get(StatKind::Mood), mood_before + 1.0);
+    assert_eq!(world.get_relationship(world.player_id, NpcId(2)).trust, 3.0);
+}
@@ -0,0 +1,72 @@
+//! The pacing budget spreads high-heat storylets across days and weeks.
+
+use syn_core::{NpcId, SimTick, WorldSeed, WorldState};
+use syn_director::{
+    EventDirector, PacingCaps, Storylet, StoryletCooldown, StoryletHeatCategory, StoryletOutcome,
+    StoryletOutcomeSet,
+};
+use syn_memory::MemorySystem;
+
+fn build_storylet(id: &str, heat: i32, category: StoryletHeatCategory, weight: f32) -> Storylet {
+    Storylet {
+        id: id.to_string(),
+        name: id.to_string(),
+        heat,
+        outcomes: StoryletOutcomeSet {
+            heat_category: Some(category),
+            ..Default::default()
+        },
+        cooldown: StoryletCooldown { ticks: 0 },
+        weight,
+        ..Default::default()
+    }
+}
+
+fn pacing_factor(director: &EventDirector, world: &WorldState, storylet: &Storylet) -> f32 {
+    director
+        .explain_score(world, storylet)
+        .explanation
+        .factor("pacing_budget")
+        .expect("the default strategy reports the pacing budget")
+}
+
+#[test]
+fn a_critical_arc_fires_at_most_once_a_week() {
+    let mut director = EventDirector::new();
+    let showdown = build_storylet("showdown", 80, StoryletHeatCategory::CriticalArc, 50.0);
+    director.register_storylet(showdown.clone());
+    let mut world = WorldState::new(WorldSeed(5), NpcId(1));
+    let mut memory = MemorySystem::new();
+
+    assert!((pacing_factor(&director, &world, &showdown) - 1.0).abs() < f32::EPSILON);
+    let outcome = StoryletOutcome::default();
+    director.fire_storylet(&showdown, &mut world, &mut memory, outcome, SimTick(0));
+
+    world.current_tick = SimTick(3 * 24);
+    assert!(pacing_factor(&director, &world, &showdown).abs() < f32::EPSILON);
+    world.current_tick = SimTick(7 * 24);
+    assert!((pacing_factor(&director, &world, &showdown) - 1.0).abs() < f32::EPSILON);
+}
+
+#[test]
+fn a_spent_day_holds_back_heat_but_not_quiet_moments() {
+    let mut director = EventDirector::new();
+    director.set_pacing_caps(PacingCaps {
+        daily_heat: 60.0,
+        ..Default::default()
+    });
+    let argument = build_storylet("argument", 40, StoryletHeatCategory::RisingTension, 5.0);
+    let coffee = build_storylet("coffee", 0, StoryletHeatCategory::SliceOfLife, 1.0);
+    director.register_storylet(argument.clone());
+    director.register_storylet(coffee.clone());
+    let mut world = WorldState::new(WorldSeed(5), NpcId(1));
+    let mut memory = MemorySystem::new();
+
+    let outcome = StoryletOutcome::default();
+    director.fire_storylet(&argument, &mut world, &mut memory, outcome, SimTick(1));
+    world.current_tick = SimTick(2);
+
+    assert!((pacing_factor(&director, &world, &argument) - 0.5).abs() < 1e-6);
+    assert!((pacing_factor(&director, &world, &coffee) - 1.0).abs() < f32::EPSILON);
+    assert!((director.pacing_budget().heat_spent_on(0) - 40.0).abs() < f32::EPSILON);
+}
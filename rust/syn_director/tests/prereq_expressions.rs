@@ -0,0 +1,96 @@
+//! Storylets gated by an authored prerequisite expression.
+
+use syn_core::{NpcId, Relationship, SimTick, WorldSeed, WorldState};
+use syn_director::storylet_loader::parse_storylet_str;
+use syn_director::EventDirector;
+use syn_memory::{MemoryEntry, MemorySystem};
+
+const OLD_WOUNDS: &str = r#"{
+  "id": "old_wounds",
+  "heat": 30,
+  "weight": 1.0,
+  "roles": [{ "name": "friend", "npc_id": 2 }],
+  "prerequisites": {
+    "life_stages": [],
+    "tags": [],
+    "relationship_states": [],
+    "memory_tags_required": [],
+    "memory_tags_forbidden": [],
+    "expression": "(trust >= Trusted AND has_memory(betrayal)) OR heat > 70"
+  }
+}"#;
+
+fn world_with_friend() -> WorldState {
+    let mut world = WorldState::new(WorldSeed(5), NpcId(1));
+    world.npcs.insert(
+        NpcId(2),
+        syn_core::AbstractNpc {
+            id: NpcId(2),
+            age: 30,
+            job: "Teacher".to_string(),
+            district: "Riverside".to_string(),
+            household_id: 7,
+            traits: syn_core::Traits::default(),
+            seed: 99,
+            attachment_style: syn_core::AttachmentStyle::Secure,
+        },
+    );
+    world.narrative_heat.set(10.0);
+    world
+}
+
+fn eligible(director: &EventDirector, world: &WorldState, memory: &MemorySystem) -> bool {
+    !director.find_eligible(world, memory, SimTick(1)).is_empty()
+}
+
+#[test]
+fn either_branch_of_the_expression_opens_the_storylet() {
+    let mut director = EventDirector::new();
+    director.register_storylet(parse_storylet_str(OLD_WOUNDS).expect("storylet parses"));
+    let mut memory = MemorySystem::new();
+
+    let mut world = world_with_friend();
+    assert!(!eligible(&director, &world, &memory));
+
+    // Trust alone isn't enough...
+    world.set_relationship(
+        NpcId(1),
+        NpcId(2),
+        Relationship {
+            trust: 4.0,
+            ..Default::default()
+        },
+    );
+    assert!(!eligible(&director, &world, &memory));
+
+    // ...until the friend remembers a betrayal
+    let mut betrayal = MemoryEntry::new(
+        "mem_1".into(),
+        "event_betrayal".into(),
+        NpcId(2),
+        SimTick(0),
+        -0.6,
+    );
+    betrayal.tags = vec!["betrayal".into()];
+    memory.record_memory(betrayal, None);
+    assert!(eligible(&director, &world, &memory));
+
+    // A hot enough world opens it regardless
+    let mut hot = world_with_friend();
+    hot.narrative_heat.set(80.0);
+    assert!(eligible(&director, &hot, &MemorySystem::new()));
+}
+
+#[test]
+fn expressions_are_checked_at_load_and_saved_in_canonical_form() {
+    let typo = OLD_WOUNDS.replace("Trusted", "Besties");
+    let err = parse_storylet_str(&typo).unwrap_err();
+    assert!(err.to_string().contains("unknown trust band 'Besties'"), "{}", err);
+
+    let storylet = parse_storylet_str(OLD_WOUNDS).expect("storylet parses");
+    let saved = serde_json::to_value(&storylet.prerequisites).unwrap();
+    assert_eq!(
+        saved["expression"],
+        "(trust >= Trusted AND has_memory(betrayal)) OR heat > 70"
+    );
+}
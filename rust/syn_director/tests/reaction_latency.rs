@@ -0,0 +1,119 @@
+//! NPC reactions sink in over several ticks and can be intercepted.
+
+use syn_core::relationship_model::{RelationshipAxis, RelationshipDelta};
+use syn_core::{NpcId, ReactionIntercept, WorldSeed, WorldState};
+use syn_director::{
+    apply_storylet_choice_outcome, storylet_is_eligible, Storylet, StoryletChoice, StoryletOutcome,
+    StoryletOutcomeSet, StoryletTrigger,
+};
+use syn_sim::{tick_world, SimState};
+
+fn storylet(id: &str, trigger: Option<&str>, outcome: StoryletOutcome) -> Storylet {
+    Storylet {
+        id: id.to_string(),
+        name: id.to_string(),
+        triggers: StoryletTrigger {
+            kind: trigger.map(str::to_string),
+        },
+        outcomes: StoryletOutcomeSet {
+            choices: vec![StoryletChoice {
+                id: "go".to_string(),
+                label: "Go".to_string(),
+                skill_requirements: Vec::new(),
+                outcome,
+            }],
+            ..Default::default()
+        },
+        weight: 1.0,
+        ..Default::default()
+    }
+}
+
+fn insult() -> Storylet {
+    storylet(
+        "insult",
+        None,
+        StoryletOutcome {
+            relationship_deltas: vec![RelationshipDelta {
+                actor_id: 2,
+                target_id: 1,
+                axis: RelationshipAxis::Resentment,
+                delta: 6.0,
+                source: None,
+            }],
+            ..Default::default()
+        },
+    )
+}
+
+fn apology() -> Storylet {
+    storylet(
+        "apologize_quickly",
+        Some("reaction_pending"),
+        StoryletOutcome {
+            reaction_intercepts: vec![ReactionIntercept {
+                actor_id: 2,
+                target_id: 1,
+                fraction: 1.0,
+            }],
+            ..Default::default()
+        },
+    )
+}
+
+fn fire(world: &mut WorldState, sim: &mut SimState, storylet: &Storylet) {
+    let choice = storylet.outcomes.choices[0].clone();
+    apply_storylet_choice_outcome(world, sim, storylet, &choice);
+}
+
+fn eligible(world: &WorldState, sim: &SimState, storylet: &Storylet) -> bool {
+    storylet_is_eligible(world, sim, storylet, &world.storylet_usage)
+}
+
+fn resentment(world: &WorldState) -> f32 {
+    world.get_relationship(NpcId(2), NpcId(1)).resentment
+}
+
+#[test]
+fn apologizing_before_it_sinks_in_cuts_the_reaction_short() {
+    let mut world = WorldState::new(WorldSeed(5), NpcId(1));
+    let mut sim = SimState::new();
+    world.reaction_latency.config.enabled = true;
+    let apology = apology();
+    assert!(!eligible(&world, &sim, &apology));
+
+    fire(&mut world, &mut sim, &insult());
+    assert!(resentment(&world).abs() < 1e-4);
+    assert!(eligible(&world, &sim, &apology));
+
+    tick_world(&mut world, &mut sim, 2);
+    assert!((resentment(&world) - 2.0).abs() < 1e-4);
+
+    fire(&mut world, &mut sim, &apology);
+    assert!(world.reaction_latency.pending.is_empty());
+    assert!(!eligible(&world, &sim, &apology));
+    tick_world(&mut world, &mut sim, 6);
+    assert!((resentment(&world) - 2.0).abs() < 1e-4);
+}
+
+#[test]
+fn unanswered_reactions_land_in_full() {
+    let mut world = WorldState::new(WorldSeed(5), NpcId(1));
+    let mut sim = SimState::new();
+    world.reaction_latency.config.enabled = true;
+
+    fire(&mut world, &mut sim, &insult());
+    // Six ticks is the default latency.
+    tick_world(&mut world, &mut sim, 6);
+    assert!((resentment(&world) - 6.0).abs() < 1e-4);
+    assert!(world.reaction_latency.pending.is_empty());
+}
+
+#[test]
+fn reactions_are_instant_by_default() {
+    let mut world = WorldState::new(WorldSeed(5), NpcId(1));
+    let mut sim = SimState::new();
+
+    fire(&mut world, &mut sim, &insult());
+    assert!((resentment(&world) - 6.0).abs() < 1e-4);
+}
@@ -0,0 +1,81 @@
+use syn_core::relationship_model::{RelationshipAxis, RelationshipDelta};
+use syn_core::{NpcId, SimTick, WorldSeed, WorldState};
+use syn_director::{
+    apply_storylet_outcome_with_memory, relationship_heat_score_multiplier, RelationshipPrereq,
+    Storylet, StoryletCooldown, StoryletOutcome, StoryletOutcomeSet, StoryletPrerequisites,
+    TagBitset,
+};
+use syn_memory::MemorySystem;
+
+fn storylet_with_npc(target_id: u64) -> Storylet {
+    Storylet {
+        id: "late_night_call".to_string(),
+        name: "Late Night Call".to_string(),
+        tags: TagBitset::default(),
+        prerequisites: StoryletPrerequisites {
+            relationship_prereqs: vec![RelationshipPrereq {
+                actor_id: None,
+                target_id,
+                axis: RelationshipAxis::Affection,
+                min_value: None,
+                max_value: None,
+                min_band: None,
+                max_band: None,
+            }],
+            ..Default::default()
+        },
+        cooldown: StoryletCooldown { ticks: 0 },
+        outcomes: StoryletOutcomeSet::default(),
+        weight: 1.0,
+        ..Default::default()
+    }
+}
+
+fn interact(world: &mut WorldState, target_id: u64, delta: f32) {
+    let mut memory = MemorySystem::new();
+    let outcome = StoryletOutcome {
+        relationship_deltas: vec![RelationshipDelta {
+            actor_id: 1,
+            target_id,
+            axis: RelationshipAxis::Affection,
+            delta,
+            source: None,
+        }],
+        ..Default::default()
+    };
+    let storylet = storylet_with_npc(target_id);
+    let tick = world.current_tick;
+    apply_storylet_outcome_with_memory(world, &mut memory, &storylet, &outcome, tick);
+}
+
+#[test]
+fn outcomes_heat_the_pair_and_favour_storylets_casting_it() {
+    let mut world = WorldState::new(WorldSeed(5), NpcId(1));
+    assert_eq!(
+        relationship_heat_score_multiplier(&world, &storylet_with_npc(2)),
+        1.0
+    );
+
+    interact(&mut world, 2, 4.0);
+
+    let heat = world
+        .relationship_heat
+        .heat(NpcId(2), NpcId(1), world.current_tick.0);
+    assert!((heat - 0.4).abs() < 1e-6, "heat {}", heat);
+    let hot = relationship_heat_score_multiplier(&world, &storylet_with_npc(2));
+    let cold = relationship_heat_score_multiplier(&world, &storylet_with_npc(3));
+    assert!(hot > 1.3, "hot multiplier {}", hot);
+    assert_eq!(cold, 1.0);
+}
+
+#[test]
+fn heat_cools_off_without_interaction() {
+    let mut world = WorldState::new(WorldSeed(5), NpcId(1));
+    interact(&mut world, 2, 4.0);
+    let fresh = relationship_heat_score_multiplier(&world, &storylet_with_npc(2));
+
+    world.current_tick = SimTick(world.current_tick.0 + 24 * 7);
+    let week_later = relationship_heat_score_multiplier(&world, &storylet_with_npc(2));
+    assert!(week_later < fresh);
+    assert!(week_later > 1.0);
+}
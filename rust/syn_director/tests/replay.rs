@@ -0,0 +1,146 @@
+//! A recorded director loop run replays to the same world from its seed.
+
+use syn_core::{NpcId, StatDelta, StatKind, WorldSeed, WorldState, WorldStateSnapshot};
+use syn_director::{
+    replay_from_log, ReplayError, ReplayEvent, ReplayLog, ReplayRecorder, Storylet,
+    StoryletChoice, StoryletCooldown, StoryletLibrary, StoryletOutcome, StoryletOutcomeSet,
+};
+use syn_sim::SimState;
+
+fn choice(id: &str, kind: StatKind, delta: f32) -> StoryletChoice {
+    StoryletChoice {
+        id: id.to_string(),
+        label: id.to_string(),
+        skill_requirements: Vec::new(),
+        outcome: StoryletOutcome {
+            stat_deltas: vec![StatDelta {
+                kind,
+                delta,
+                source: None,
+            }],
+            ..Default::default()
+        },
+    }
+}
+
+fn storylet(id: &str, weight: f32, choices: Vec<StoryletChoice>) -> Storylet {
+    Storylet {
+        id: id.to_string(),
+        name: id.to_string(),
+        heat: 5,
+        weight,
+        outcomes: StoryletOutcomeSet {
+            choices,
+            ..Default::default()
+        },
+        cooldown: StoryletCooldown { ticks: 0 },
+        ..Default::default()
+    }
+}
+
+fn library() -> StoryletLibrary {
+    StoryletLibrary::from_storylets(vec![
+        storylet(
+            "replay_coffee",
+            1.0,
+            vec![
+                choice("linger", StatKind::Mood, 2.0),
+                choice("rush", StatKind::Energy, -3.0),
+            ],
+        ),
+        storylet(
+            "replay_overtime",
+            1.5,
+            vec![
+                choice("stay", StatKind::Wealth, 4.0),
+                choice("leave", StatKind::Mood, 1.0),
+            ],
+        ),
+    ])
+}
+
+/// Play a few days, alternating which choice the player takes.
+fn record_run(seed: u64) -> (ReplayLog, WorldState) {
+    let library = library();
+    let mut world = WorldState::new(WorldSeed(seed), NpcId(1));
+    let mut sim = SimState::new();
+    let mut recorder = ReplayRecorder::start(&world);
+
+    let mut view = recorder.advance_and_select(&mut world, &mut sim, &library, 24);
+    for turn in 0..6 {
+        view = match view {
+            Some(event) => {
+                let choice = &event.choices[turn % event.choices.len()];
+                recorder.apply_choice_and_advance(
+                    &mut world,
+                    &mut sim,
+                    &library,
+                    &event.storylet_id,
+                    &choice.id,
+                    12,
+                )
+            }
+            None => recorder.advance_and_select(&mut world, &mut sim, &library, 24),
+        };
+    }
+    (recorder.finish(), world)
+}
+
+#[test]
+fn replaying_a_log_reproduces_the_recorded_world() {
+    let (log, recorded) = record_run(404);
+    assert!(log
+        .events
+        .iter()
+        .any(|event| matches!(event, ReplayEvent::Rng { .. })));
+    assert_eq!(log.inputs().count(), 7);
+
+    // The log survives a round trip through JSON.
+    let log: ReplayLog =
+        serde_json::from_str(&serde_json::to_string(&log).expect("serializes")).expect("parses");
+
+    let mut world = WorldState::new(WorldSeed(404), NpcId(1));
+    let mut sim = SimState::new();
+    let report = replay_from_log(&mut world, &mut sim, &library(), &log).expect("replays");
+
+    assert_eq!(report.inputs, 7);
+    assert_eq!(report.events, log.events.len());
+    assert_eq!(
+        WorldStateSnapshot::from_world(&world)
+            .first_divergence(&WorldStateSnapshot::from_world(&recorded)),
+        None
+    );
+}
+
+#[test]
+fn replay_reports_where_a_tampered_log_diverges() {
+    let (mut log, _) = record_run(404);
+    let index = log
+        .events
+        .iter()
+        .position(|event| matches!(event, ReplayEvent::Rng { .. }))
+        .expect("run drew randomness");
+    if let ReplayEvent::Rng { draws, .. } = &mut log.events[index] {
+        *draws += 1;
+    }
+
+    let mut world = WorldState::new(WorldSeed(404), NpcId(1));
+    let mut sim = SimState::new();
+    let err = replay_from_log(&mut world, &mut sim, &library(), &log).expect_err("diverges");
+    assert!(matches!(err, ReplayError::Diverged { index: i, .. } if i == index));
+}
+
+#[test]
+fn replay_refuses_a_world_with_another_seed() {
+    let (log, _) = record_run(404);
+    let mut world = WorldState::new(WorldSeed(405), NpcId(1));
+    let mut sim = SimState::new();
+
+    assert_eq!(
+        replay_from_log(&mut world, &mut sim, &library(), &log),
+        Err(ReplayError::SeedMismatch {
+            log: 404,
+            world: 405
+        })
+    );
+}
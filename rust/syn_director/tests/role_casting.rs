@@ -0,0 +1,115 @@
+//! Open storylet roles are cast from the world when the storylet fires.
+
+use syn_core::{
+    AbstractNpc, AttachmentStyle, NpcId, Relationship, SimTick, Traits, WorldSeed, WorldState,
+};
+use syn_director::storylet_loader::parse_storylet_str;
+use syn_director::{CastingEngine, CastingError, EventDirector, Storylet, StoryletOutcome};
+use syn_memory::MemorySystem;
+
+fn npc(id: u64, district: &str, traits: Traits) -> AbstractNpc {
+    AbstractNpc {
+        id: NpcId(id),
+        age: 30,
+        job: "Clerk".to_string(),
+        district: district.to_string(),
+        household_id: id,
+        traits,
+        seed: id,
+        attachment_style: AttachmentStyle::Secure,
+    }
+}
+
+/// Player 1 in Downtown. NPC 2 trusts them but lives uptown, NPC 3 is a
+/// driven Downtown neighbour, NPC 4 a Downtown stranger.
+fn neighbourhood() -> WorldState {
+    let mut world = WorldState::new(WorldSeed(21), NpcId(1));
+    world.npcs.insert(NpcId(1), npc(1, "Downtown", Traits::default()));
+    world.npcs.insert(NpcId(2), npc(2, "Uptown", Traits::default()));
+    let driven = Traits {
+        ambition: 95.0,
+        impulsivity: 90.0,
+        ..Traits::default()
+    };
+    world.npcs.insert(NpcId(3), npc(3, "Downtown", driven));
+    world.npcs.insert(NpcId(4), npc(4, "Downtown", Traits::default()));
+    world.known_npcs = vec![NpcId(2), NpcId(3), NpcId(4)];
+    world.set_relationship(
+        NpcId(1),
+        NpcId(2),
+        Relationship {
+            trust: 6.0,
+            affection: 5.0,
+            ..Default::default()
+        },
+    );
+    world
+}
+
+fn promotion_race() -> Storylet {
+    parse_storylet_str(
+        r#"{
+            "id": "promotion_race",
+            "name": "Promotion Race",
+            "role_slots": [
+                { "name": "confidant", "required": true, "constraints": "trust >= 5" },
+                { "name": "rival", "required": true, "constraints": null },
+                { "name": "witness", "required": false, "constraints": "trust >= 9" }
+            ],
+            "heat": 30,
+            "weight": 1.0
+        }"#,
+    )
+    .expect("storylet parses")
+}
+
+#[test]
+fn open_roles_are_cast_to_the_best_fit_and_recorded_when_fired() {
+    let mut director = EventDirector::new();
+    let storylet = promotion_race();
+    director.register_storylet(storylet.clone());
+    let mut world = neighbourhood();
+    let mut memory = MemorySystem::new();
+
+    let assignment = CastingEngine::new(&world, &memory, SimTick(1))
+        .cast(&storylet)
+        .expect("required roles cast");
+    assert_eq!(assignment.get("confidant"), Some(&NpcId(2)));
+    assert_eq!(assignment.get("rival"), Some(&NpcId(3)));
+    assert_eq!(assignment.get("witness"), None);
+
+    director.fire_storylet(
+        &storylet,
+        &mut world,
+        &mut memory,
+        StoryletOutcome::default(),
+        SimTick(1),
+    );
+    let entry = director.chronicle().latest().expect("storylet fired");
+    assert_eq!(
+        entry.roles,
+        vec![
+            ("confidant".to_string(), NpcId(2)),
+            ("rival".to_string(), NpcId(3)),
+        ]
+    );
+}
+
+#[test]
+fn storylet_is_not_castable_when_a_required_role_has_no_fit() {
+    let director = EventDirector::new();
+    let mut storylet = promotion_race();
+    storylet.roles.open[0].constraints = Some("trust >= 9".to_string());
+    let world = neighbourhood();
+    let memory = MemorySystem::new();
+
+    assert_eq!(
+        CastingEngine::new(&world, &memory, SimTick(1)).cast(&storylet),
+        Err(CastingError::Unfilled {
+            role: "confidant".to_string()
+        })
+    );
+    assert!(director
+        .cast_storylet_roles(&storylet, &world, &memory, SimTick(1))
+        .is_none());
+}
@@ -0,0 +1,171 @@
+use syn_core::{
+    relationship_model::{RelationshipAxis, RelationshipDelta},
+    LifeStage, NpcId, Relationship, StatDelta, StatKind, WorldSeed, WorldState,
+};
+use syn_director::{
+    apply_choice_and_advance, romance_response, select_next_event_view, storylet_is_eligible,
+    RomanceReciprocity, RomanceResponse, Storylet, StoryletChoice, StoryletCooldown,
+    StoryletLibrary, StoryletOutcome, StoryletOutcomeSet, StoryletPrerequisites, StoryletRole,
+    StoryletRoles,
+};
+use syn_sim::SimState;
+
+fn choice(id: &str, outcome: StoryletOutcome) -> StoryletChoice {
+    StoryletChoice {
+        id: id.to_string(),
+        label: id.to_string(),
+        skill_requirements: Vec::new(),
+        outcome,
+    }
+}
+
+fn confession(rejection_choices: Vec<StoryletChoice>) -> Storylet {
+    Storylet {
+        id: "confession".to_string(),
+        name: "Confession".to_string(),
+        prerequisites: StoryletPrerequisites {
+            tags: vec!["romance".to_string()],
+            ..Default::default()
+        },
+        roles: StoryletRoles::from(vec![StoryletRole {
+            name: "love_interest".to_string(),
+            npc_id: NpcId(2),
+        }]),
+        outcomes: StoryletOutcomeSet {
+            choices: vec![choice("kiss", StoryletOutcome::default())],
+            rejection_choices,
+            ..Default::default()
+        },
+        cooldown: StoryletCooldown { ticks: 0 },
+        weight: 1.0,
+        ..Default::default()
+    }
+}
+
+fn turned_down() -> StoryletChoice {
+    choice(
+        "accept_refusal",
+        StoryletOutcome {
+            stat_deltas: vec![StatDelta {
+                kind: StatKind::Mood,
+                delta: -2.0,
+                source: None,
+            }],
+            relationship_deltas: vec![RelationshipDelta {
+                actor_id: 1,
+                target_id: 2,
+                axis: RelationshipAxis::Attraction,
+                delta: -1.0,
+                source: None,
+            }],
+            ..Default::default()
+        },
+    )
+}
+
+/// Player is smitten; the NPC's side is set by the caller.
+fn world_with_npc_feelings(attraction: f32, affection: f32) -> WorldState {
+    let mut world = WorldState::new(WorldSeed(21), NpcId(1));
+    world.player_life_stage = LifeStage::YoungAdult;
+    world.set_relationship(
+        NpcId(1),
+        NpcId(2),
+        Relationship {
+            attraction: 8.0,
+            affection: 6.0,
+            ..Default::default()
+        },
+    );
+    world.set_relationship(
+        NpcId(2),
+        NpcId(1),
+        Relationship {
+            attraction,
+            affection,
+            ..Default::default()
+        },
+    );
+    world
+}
+
+#[test]
+fn one_sided_romance_without_refusal_beat_is_ineligible() {
+    let sim = SimState::new();
+    let storylet = confession(Vec::new());
+
+    let one_sided = world_with_npc_feelings(0.5, 4.0);
+    assert_eq!(
+        romance_response(&one_sided, &sim.npc_registry, &storylet),
+        RomanceResponse::Unreciprocated
+    );
+    assert!(!storylet_is_eligible(
+        &one_sided,
+        &sim,
+        &storylet,
+        &one_sided.storylet_usage
+    ));
+
+    let mutual = world_with_npc_feelings(5.0, 4.0);
+    assert_eq!(
+        romance_response(&mutual, &sim.npc_registry, &storylet),
+        RomanceResponse::Reciprocated
+    );
+    assert!(storylet_is_eligible(
+        &mutual,
+        &sim,
+        &storylet,
+        &mutual.storylet_usage
+    ));
+}
+
+#[test]
+fn one_sided_romance_plays_rejection_path() {
+    let mut sim = SimState::new();
+    let mut world = world_with_npc_feelings(0.5, 4.0);
+    let library = StoryletLibrary::from_storylets(vec![confession(vec![turned_down()])]);
+
+    let view = select_next_event_view(&mut world, &mut sim, &library).expect("refusal should fire");
+    let ids: Vec<&str> = view.choices.iter().map(|c| c.id.as_str()).collect();
+    assert_eq!(ids, vec!["accept_refusal"]);
+
+    // The romantic choice is not on offer while the NPC is uninterested.
+    assert!(
+        apply_choice_and_advance(&mut world, &mut sim, &library, "confession", "kiss", 0).is_none()
+    );
+
+    apply_choice_and_advance(
+        &mut world,
+        &mut sim,
+        &library,
+        "confession",
+        "accept_refusal",
+        0,
+    );
+    assert_eq!(world.player_stats.get(StatKind::Mood), -2.0);
+    assert_eq!(world.get_relationship(NpcId(1), NpcId(2)).attraction, 7.0);
+}
+
+#[test]
+fn reciprocity_thresholds_can_be_overridden() {
+    let sim = SimState::new();
+    let mut storylet = confession(Vec::new());
+    storylet.prerequisites.romance_reciprocity =
+        Some(serde_json::from_str::<RomanceReciprocity>(r#"{ "min_attraction": 0.0 }"#).unwrap());
+
+    let world = world_with_npc_feelings(0.5, 4.0);
+    assert!(storylet_is_eligible(
+        &world,
+        &sim,
+        &storylet,
+        &world.storylet_usage
+    ));
+
+    // Affection keeps its default threshold.
+    let cold = world_with_npc_feelings(0.5, 1.0);
+    assert!(!storylet_is_eligible(
+        &cold,
+        &sim,
+        &storylet,
+        &cold.storylet_usage
+    ));
+}
@@ -0,0 +1,87 @@
+//! The director ranks storylets with a swappable scoring strategy.
+
+use syn_core::{NpcId, WorldSeed, WorldState};
+use syn_director::{
+    score_storylet_full_with_registry, DefaultScoringStrategy, EventDirector, ScoreFactorKind,
+    ScoringContext, ScoringStrategy, Storylet, StoryletCooldown, StoryletOutcomeSet,
+    StoryletPrerequisites, StoryletRoles, StoryletScore, StoryletTrigger, TagBitset,
+};
+use syn_memory::MemorySystem;
+use syn_sim::NpcRegistry;
+
+fn build_storylet(id: &str, heat: i32, weight: f32) -> Storylet {
+    Storylet {
+        id: id.to_string(),
+        name: id.to_string(),
+        tags: TagBitset::default(),
+        prerequisites: StoryletPrerequisites::default(),
+        roles: StoryletRoles::default(),
+        heat,
+        triggers: StoryletTrigger::default(),
+        outcomes: StoryletOutcomeSet::default(),
+        cooldown: StoryletCooldown { ticks: 0 },
+        weight,
+    }
+}
+
+/// Prefers quiet storylets, whatever their authored weight.
+struct QuietFirst;
+
+impl ScoringStrategy for QuietFirst {
+    fn name(&self) -> &'static str {
+        "quiet_first"
+    }
+
+    fn score(&self, ctx: &ScoringContext<'_>) -> StoryletScore {
+        StoryletScore::from_base("calm", 100.0 - ctx.storylet.heat as f32)
+    }
+}
+
+#[test]
+fn the_default_strategy_explains_the_standard_pipeline() {
+    let mut director = EventDirector::new();
+    let storylet = build_storylet("big_night_out", 90, 50.0);
+    director.register_storylet(storylet.clone());
+    let world = WorldState::new(WorldSeed(3), NpcId(1));
+    let registry = NpcRegistry::default();
+
+    assert_eq!(director.scoring_strategy().name(), "default");
+    let explained = director.explain_score(&world, &storylet);
+    assert_eq!(explained.explanation.factors[0].kind, ScoreFactorKind::Base);
+    assert!(explained.explanation.factor("fatigue").is_some());
+    assert!(explained.explanation.factor("npc_intent").is_none());
+    assert!(explained.explanation.to_string().starts_with("base"));
+
+    // With a registry, the strategy matches the public scoring helper
+    let ctx = ScoringContext::new(&director, &world, &storylet, None).with_registry(&registry);
+    let scored = DefaultScoringStrategy.score(&ctx);
+    let helper = score_storylet_full_with_registry(&director, &world, &registry, &storylet, None);
+    assert!((scored.score - helper).abs() < f32::EPSILON);
+    assert!(scored.explanation.factor("district_affinity").is_some());
+}
+
+#[test]
+fn a_custom_strategy_changes_what_the_director_picks() {
+    let mut director = EventDirector::new();
+    director.register_storylet(build_storylet("big_night_out", 90, 50.0));
+    director.register_storylet(build_storylet("quiet_read", 10, 1.0));
+    let world = WorldState::new(WorldSeed(3), NpcId(1));
+    let memory = MemorySystem::new();
+    let tick = world.current_tick;
+
+    let pick = |director: &EventDirector| {
+        director
+            .select_next_event(&world, &memory, tick)
+            .map(|s| s.id.clone())
+    };
+    assert_eq!(pick(&director).as_deref(), Some("big_night_out"));
+
+    director.set_scoring_strategy(Box::new(QuietFirst));
+    assert_eq!(director.scoring_strategy().name(), "quiet_first");
+    assert_eq!(pick(&director).as_deref(), Some("quiet_read"));
+
+    let quiet = build_storylet("quiet_read", 10, 1.0);
+    let explained = director.explain_score(&world, &quiet);
+    let calm = explained.explanation.factor("calm").expect("calm factor");
+    assert!((calm - 90.0).abs() < f32::EPSILON);
+}
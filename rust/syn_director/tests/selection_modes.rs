@@ -0,0 +1,110 @@
+//! Weighted top-K selection keeps the same domain from firing back to back.
+
+use syn_core::{NpcId, SimTick, WorldSeed, WorldState};
+use syn_director::{
+    storylet_domains, EventDirector, ScoringContext, ScoringStrategy, SelectionMode, Storylet,
+    StoryletCooldown, StoryletOutcome, StoryletPrerequisites, StoryletScore,
+};
+use syn_memory::MemorySystem;
+
+fn build_storylet(id: &str, domain: &str, weight: f32) -> Storylet {
+    Storylet {
+        id: id.to_string(),
+        name: id.to_string(),
+        prerequisites: StoryletPrerequisites {
+            tags: vec![domain.to_string()],
+            ..Default::default()
+        },
+        cooldown: StoryletCooldown { ticks: 0 },
+        weight,
+        ..Default::default()
+    }
+}
+
+/// Scores a storylet by its authored weight alone.
+struct AuthorWeighted;
+
+impl ScoringStrategy for AuthorWeighted {
+    fn name(&self) -> &'static str {
+        "author_weighted"
+    }
+
+    fn score(&self, ctx: &ScoringContext<'_>) -> StoryletScore {
+        StoryletScore::from_base("weight", ctx.storylet.weight)
+    }
+}
+
+fn director(mode: SelectionMode) -> EventDirector {
+    let mut director = EventDirector::new();
+    director.set_scoring_strategy(Box::new(AuthorWeighted));
+    director.set_selection_mode(mode);
+    director.register_storylet(build_storylet("first_date", "romance", 10.0));
+    director.register_storylet(build_storylet("second_date", "Romance", 9.0));
+    director.register_storylet(build_storylet("promotion", "career", 3.0));
+    director.register_storylet(build_storylet("game_night", "friendship", 2.0));
+    director
+}
+
+/// Select and fire a storylet each tick, returning the fired IDs.
+fn run(mut director: EventDirector, ticks: u64) -> Vec<String> {
+    let mut world = WorldState::new(WorldSeed(17), NpcId(1));
+    // Old enough for the romance storylets
+    world.player_life_stage = syn_core::LifeStage::Adult;
+    let mut memory = MemorySystem::new();
+    let mut fired = Vec::new();
+    for tick in 1..=ticks {
+        let Some(storylet) = director
+            .select_next_event(&world, &memory, SimTick(tick))
+            .cloned()
+        else {
+            break;
+        };
+        director.fire_storylet(
+            &storylet,
+            &mut world,
+            &mut memory,
+            StoryletOutcome::default(),
+            SimTick(tick),
+        );
+        fired.push(storylet.id);
+    }
+    fired
+}
+
+#[test]
+fn best_mode_always_takes_the_top_score() {
+    let fired = run(director(SelectionMode::Best), 5);
+    assert_eq!(fired, vec!["first_date"; 5]);
+}
+
+#[test]
+fn weighted_top_k_spreads_domains_deterministically() {
+    let mode = SelectionMode::WeightedTopK {
+        k: 3,
+        recency_penalty: 1.0,
+    };
+    let fired = run(director(mode), 12);
+    assert_eq!(fired.len(), 12);
+
+    let domains: Vec<Vec<String>> = fired
+        .iter()
+        .map(|id| {
+            let storylet = build_storylet(id, domain_of(id), 1.0);
+            storylet_domains(&storylet)
+        })
+        .collect();
+    for pair in domains.windows(2) {
+        assert_ne!(pair[0], pair[1], "same domain fired back to back: {:?}", fired);
+    }
+    assert!(fired.iter().any(|id| id != "first_date"));
+
+    assert_eq!(run(director(mode), 12), fired);
+}
+
+fn domain_of(id: &str) -> &'static str {
+    match id {
+        "first_date" | "second_date" => "romance",
+        "promotion" => "career",
+        _ => "friendship",
+    }
+}
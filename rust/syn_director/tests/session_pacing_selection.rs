@@ -0,0 +1,60 @@
+//! Critical beats are held back near the expected end of a play session.
+
+use syn_core::{NpcId, SessionPhase, SimTick, WorldSeed, WorldState};
+use syn_director::{
+    EventDirector, Storylet, StoryletCooldown, StoryletHeatCategory, StoryletOutcomeSet,
+    StoryletPrerequisites, StoryletRoles, TagBitset,
+};
+use syn_memory::MemorySystem;
+
+fn storylet(id: &str, category: StoryletHeatCategory) -> Storylet {
+    let outcomes = StoryletOutcomeSet {
+        heat_category: Some(category),
+        ..Default::default()
+    };
+    Storylet {
+        id: id.to_string(),
+        name: id.to_string(),
+        tags: TagBitset::default(),
+        prerequisites: StoryletPrerequisites::default(),
+        roles: StoryletRoles::default(),
+        heat: 10,
+        triggers: Default::default(),
+        outcomes,
+        cooldown: StoryletCooldown { ticks: 0 },
+        weight: 1.0,
+    }
+}
+
+fn select_at(director: &EventDirector, world: &mut WorldState, tick: u64) -> String {
+    world.current_tick = SimTick(tick);
+    director
+        .select_next_event(world, &MemorySystem::new(), SimTick(tick))
+        .expect("expected selection")
+        .id
+        .clone()
+}
+
+#[test]
+fn critical_beats_wait_until_the_next_session() {
+    let mut director = EventDirector::new();
+    director.register_storylet(storylet("slice", StoryletHeatCategory::SliceOfLife));
+    director.register_storylet(storylet("critical", StoryletHeatCategory::CriticalArc));
+    let mut world = WorldState::new(WorldSeed(1), NpcId(1));
+    world.narrative_heat.set(85.0);
+
+    // Without session markers pacing is unchanged
+    assert_eq!(select_at(&director, &mut world, 10), "critical");
+
+    // Default session: 12 minutes at 1 tick a minute, last 3 are wind-down
+    world.session_pacing.start(0, 0);
+    assert_eq!(world.session_pacing.phase(2), SessionPhase::Prime);
+    assert_eq!(select_at(&director, &mut world, 2), "critical");
+    assert_eq!(world.session_pacing.phase(10), SessionPhase::WindDown);
+    assert_eq!(select_at(&director, &mut world, 10), "slice");
+
+    // A fresh session starts in prime time again
+    world.session_pacing.end(720, 10);
+    world.session_pacing.start(5_000, 10);
+    assert_eq!(select_at(&director, &mut world, 11), "critical");
+}
@@ -0,0 +1,111 @@
+//! Skill XP from storylet outcomes and choices locked behind skill tiers.
+
+use syn_core::skills::{SkillId, SkillTier};
+use syn_core::{NpcId, SimTick, WorldSeed, WorldState};
+use syn_director::storylet_loader::parse_storylet_str;
+use syn_director::{
+    apply_choice_and_advance, event_view_for, EventDirector, Storylet, StoryletLibrary,
+};
+use syn_memory::MemorySystem;
+use syn_sim::SimState;
+
+const OPEN_MIC: &str = r#"{
+  "id": "open_mic",
+  "heat": 10,
+  "weight": 1.0,
+  "prerequisites": {
+    "life_stages": [],
+    "tags": [],
+    "relationship_states": [],
+    "memory_tags_required": [],
+    "memory_tags_forbidden": []
+  },
+  "outcomes": {
+    "choices": [
+      {
+        "id": "play_a_cover",
+        "label": "Play a song everyone knows",
+        "outcome": {
+          "skill_xp_rewards": [{ "skill_id": "guitar", "xp": 150 }]
+        }
+      },
+      {
+        "id": "improvise",
+        "label": "Improvise a solo",
+        "skill_requirements": [{ "skill_id": "guitar", "min_tier": 2 }],
+        "outcome": {
+          "skill_xp_rewards": [{ "skill_id": "guitar", "xp": 50 }]
+        }
+      }
+    ]
+  }
+}"#;
+
+fn open_mic() -> Storylet {
+    parse_storylet_str(OPEN_MIC).expect("storylet parses")
+}
+
+fn guitar_xp(world: &WorldState) -> u32 {
+    world.player_skills.get_xp(&SkillId::new("guitar"))
+}
+
+fn improvise_locked(world: &WorldState, sim: &SimState) -> bool {
+    let view = event_view_for(world, sim, &open_mic());
+    view.choices
+        .iter()
+        .find(|c| c.id == "improvise")
+        .expect("locked choices stay in the view")
+        .locked
+}
+
+#[test]
+fn choices_grant_xp_and_unlock_at_the_required_tier() {
+    let mut world = WorldState::new(WorldSeed(5), NpcId(1));
+    let mut sim = SimState::new();
+    let library = StoryletLibrary::from_storylets(vec![open_mic()]);
+
+    assert!(improvise_locked(&world, &sim));
+    assert!(
+        apply_choice_and_advance(&mut world, &mut sim, &library, "open_mic", "improvise", 0)
+            .is_none()
+    );
+    assert_eq!(guitar_xp(&world), 0);
+
+    apply_choice_and_advance(&mut world, &mut sim, &library, "open_mic", "play_a_cover", 0);
+    assert_eq!(guitar_xp(&world), 150);
+    assert_eq!(
+        world.player_skills.get_tier(&SkillId::new("guitar")),
+        SkillTier::Beginner
+    );
+    assert!(improvise_locked(&world, &sim));
+
+    apply_choice_and_advance(&mut world, &mut sim, &library, "open_mic", "play_a_cover", 0);
+    assert!(!improvise_locked(&world, &sim));
+    apply_choice_and_advance(&mut world, &mut sim, &library, "open_mic", "improvise", 0);
+    assert_eq!(guitar_xp(&world), 350);
+}
+
+#[test]
+fn director_refuses_locked_choices() {
+    let mut director = EventDirector::new();
+    let storylet = open_mic();
+    director.register_storylet(storylet.clone());
+    let mut world = WorldState::new(WorldSeed(5), NpcId(1));
+    let mut memory = MemorySystem::new();
+
+    assert!(!director.fire_storylet_choice(
+        &storylet,
+        &mut world,
+        &mut memory,
+        "improvise",
+        SimTick(1),
+    ));
+    assert!(director.fire_storylet_choice(
+        &storylet,
+        &mut world,
+        &mut memory,
+        "play_a_cover",
+        SimTick(1),
+    ));
+    assert_eq!(guitar_xp(&world), 150);
+}
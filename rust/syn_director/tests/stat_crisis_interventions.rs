@@ -0,0 +1,130 @@
+//! A player in a health or mood crisis gets an intervention within the
+//! response window, however well other content scores.
+
+use syn_core::{CrisisStat, NpcId, SimTick, WorldSeed, WorldState};
+use syn_director::{
+    EventDirector, Storylet, StoryletCooldown, StoryletOutcome, StoryletOutcomeSet,
+    StoryletPrerequisites, StoryletRoles, StoryletTrigger, TagBitset,
+};
+use syn_memory::MemorySystem;
+
+fn build_storylet(id: &str, trigger: Option<&str>, weight: f32) -> Storylet {
+    Storylet {
+        id: id.to_string(),
+        name: id.to_string(),
+        tags: TagBitset::default(),
+        prerequisites: StoryletPrerequisites::default(),
+        roles: StoryletRoles::default(),
+        heat: 40,
+        triggers: StoryletTrigger {
+            kind: trigger.map(|t| t.to_string()),
+        },
+        outcomes: StoryletOutcomeSet::default(),
+        cooldown: StoryletCooldown { ticks: 0 },
+        weight,
+    }
+}
+
+/// Tick the world, firing whatever the director picks each tick, until
+/// `until` fires or `max_ticks` pass. Returns the tick it fired on.
+fn play_until(
+    director: &mut EventDirector,
+    world: &mut WorldState,
+    memory: &mut MemorySystem,
+    until: &str,
+    max_ticks: u64,
+) -> Option<u64> {
+    for _ in 0..max_ticks {
+        world.player_stats.mood = -8.0;
+        world.tick(&mut Default::default());
+        let tick = world.current_tick;
+        let Some(chosen) = director.select_next_event(world, memory, tick).cloned() else {
+            continue;
+        };
+        director.fire_storylet(&chosen, world, memory, StoryletOutcome::default(), tick);
+        if chosen.id == until {
+            return Some(tick.0);
+        }
+    }
+    None
+}
+
+#[test]
+fn a_mood_crisis_cannot_be_ignored_past_the_response_window() {
+    let mut director = EventDirector::new();
+    director.register_storylet(build_storylet("big_night_out", None, 50.0));
+    director.register_storylet(build_storylet("friend_checks_in", Some("stat_crisis:mood"), 0.01));
+    let mut world = WorldState::new(WorldSeed(11), NpcId(1));
+    let mut memory = MemorySystem::new();
+    let window = world.stat_crisis.config.response_window_ticks;
+
+    // No crisis: the check-in isn't even eligible
+    let eligible = director.find_eligible(&world, &memory, world.current_tick);
+    assert_eq!(eligible.len(), 1);
+
+    let fired = play_until(&mut director, &mut world, &mut memory, "friend_checks_in", 200)
+        .expect("the intervention fired");
+    let crisis = world
+        .stat_crisis
+        .crisis(CrisisStat::Mood)
+        .expect("mood is still in crisis");
+    assert!(
+        fired - crisis.started_tick <= window,
+        "crisis at {} went unanswered until {}",
+        crisis.started_tick,
+        fired
+    );
+    assert_eq!(crisis.interventions, 1);
+
+    // Once answered, the crisis waits out the recheck interval...
+    let recheck = world.stat_crisis.config.recheck_interval_ticks;
+    let next = play_until(
+        &mut director,
+        &mut world,
+        &mut memory,
+        "friend_checks_in",
+        recheck + window + 1,
+    )
+    .expect("the crisis asked for another intervention");
+    assert!(next - fired >= recheck);
+    assert!(next - fired <= recheck + window);
+
+    // ...and a recovered player gets no more check-ins
+    world.player_stats.mood = 0.0;
+    world.tick(&mut Default::default());
+    assert!(world.stat_crisis.active.is_empty());
+    let eligible = director.find_eligible(&world, &memory, world.current_tick);
+    assert!(eligible.iter().all(|s| s.id == "big_night_out"));
+}
+
+#[test]
+fn a_health_crisis_waits_for_its_own_interventions() {
+    let mut director = EventDirector::new();
+    director.register_storylet(build_storylet("big_night_out", None, 50.0));
+    director.register_storylet(build_storylet("friend_checks_in", Some("stat_crisis:mood"), 0.01));
+    director.register_storylet(build_storylet("collapse", Some("stat_crisis:health"), 0.01));
+    let mut world = WorldState::new(WorldSeed(11), NpcId(1));
+    let memory = MemorySystem::new();
+
+    world.player_stats.health = 12.0;
+    world.tick(&mut Default::default());
+    let started = world.current_tick.0;
+    let window = world.stat_crisis.config.response_window_ticks;
+
+    let ids = |world: &WorldState| -> Vec<String> {
+        director
+            .find_eligible(world, &memory, world.current_tick)
+            .iter()
+            .map(|s| s.id.clone())
+            .collect()
+    };
+    assert_eq!(ids(&world), vec!["big_night_out", "collapse"]);
+
+    // Within the window the collapse competes on score; past it, it is the
+    // only choice
+    world.current_tick = SimTick(started + window);
+    let chosen = director
+        .select_next_event(&world, &memory, world.current_tick)
+        .map(|s| s.id.clone());
+    assert_eq!(chosen.as_deref(), Some("collapse"));
+}
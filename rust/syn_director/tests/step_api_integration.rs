@@ -522,3 +522,63 @@ fn test_multi_step_evolution() {
     // Verify tick tracking is correct
     assert_eq!(director.state().tick.0, 20, "Should be at tick 20");
 }
+
+// ============================================================================
+// Dry Run Tests
+// ============================================================================
+
+#[test]
+fn test_dry_run_predicts_step_without_mutating() {
+    use syn_director::persistence::serialize_to_json;
+
+    let library = create_test_library();
+    let config = DirectorConfig::for_testing();
+    let mut director = CompiledEventDirector::new(library, config);
+
+    let queued = QueuedEvent::new(
+        StoryletKey(2),
+        SimTick::new(5),
+        10,
+        false,
+        QueueSource::FollowUp,
+    );
+    director.state_mut().pending_queue.push_unchecked(queued);
+    let pressure = Pressure::new(
+        PressureId(1),
+        PressureKind::Financial,
+        SimTick::new(0),
+        "Rent due".into(),
+    )
+    .with_severity(0.9);
+    director.state_mut().active_pressures.add_pressure(pressure);
+
+    let world = create_adult_world(12345);
+    let memory = MemorySystem::new();
+    let warmup = SimTick::new(1);
+    director.step(warmup, &create_context(&world, &memory, warmup));
+
+    let before = serialize_to_json(&director.snapshot()).unwrap();
+    let tick = SimTick::new(5);
+    let ctx = create_context(&world, &memory, tick);
+    let preview = director.dry_run(tick, &ctx);
+
+    // Repeated previews agree and leave the director untouched.
+    assert_eq!(director.dry_run(tick, &ctx).would_fire_key(), preview.would_fire_key());
+    assert_eq!(serialize_to_json(&director.snapshot()).unwrap(), before);
+    assert_eq!(director.queue_len(), 1);
+    assert_eq!(preview.stats.queue_ready_count, 1);
+    assert!(preview
+        .candidates
+        .windows(2)
+        .all(|pair| pair[0].selection_score >= pair[1].selection_score));
+
+    // The real step then does exactly what the preview said.
+    let result = director.step(tick, &ctx);
+    assert!(preview.would_fire.is_some());
+    assert_eq!(result.fired_key(), preview.would_fire_key());
+    assert_eq!(
+        result.fired.map(|f| f.is_from_queue),
+        preview.would_fire.map(|f| f.is_from_queue)
+    );
+    assert_eq!(director.queue_len(), 0);
+}
@@ -0,0 +1,101 @@
+use syn_core::{NpcId, SimTick, WorldSeed, WorldState};
+use syn_director::{
+    score_storylet_full_simple, storylet_is_eligible, EventDirector, Storylet, StoryletCooldown,
+    StoryletOutcome, StoryletOutcomeSet, StoryletPrerequisites, StoryletRoles, TagBitset,
+};
+use syn_memory::MemorySystem;
+use syn_sim::SimState;
+
+fn build_storylet(id: &str, depends_on: &[&str], prefers_after: &[&str]) -> Storylet {
+    Storylet {
+        id: id.to_string(),
+        name: id.to_string(),
+        tags: TagBitset::default(),
+        prerequisites: StoryletPrerequisites {
+            depends_on: depends_on.iter().map(|s| s.to_string()).collect(),
+            prefers_after: prefers_after.iter().map(|s| s.to_string()).collect(),
+            ..Default::default()
+        },
+        roles: StoryletRoles::default(),
+        heat: 50,
+        triggers: Default::default(),
+        outcomes: StoryletOutcomeSet::default(),
+        cooldown: StoryletCooldown { ticks: 0 },
+        weight: 1.0,
+    }
+}
+
+#[test]
+fn hard_dependency_gates_until_prerequisite_fires() {
+    let mut director = EventDirector::new();
+    let mut world = WorldState::new(WorldSeed(11), NpcId(1));
+    let mut memory = MemorySystem::new();
+    let sim = SimState::new();
+
+    let first_date = build_storylet("first_date", &[], &[]);
+    let second_date = build_storylet("second_date", &["first_date"], &[]);
+    director.register_storylet(first_date.clone());
+    director.register_storylet(second_date.clone());
+
+    let eligible: Vec<&str> = director
+        .find_eligible(&world, &memory, SimTick(1))
+        .iter()
+        .map(|s| s.id.as_str())
+        .collect();
+    assert_eq!(eligible, vec!["first_date"]);
+    assert!(!storylet_is_eligible(
+        &world,
+        &sim,
+        &second_date,
+        &world.storylet_usage
+    ));
+
+    director.fire_storylet(
+        &first_date,
+        &mut world,
+        &mut memory,
+        StoryletOutcome::default(),
+        SimTick(1),
+    );
+
+    assert_eq!(world.storylet_usage.count("first_date"), 1);
+    assert!(director
+        .find_eligible(&world, &memory, SimTick(2))
+        .iter()
+        .any(|s| s.id == "second_date"));
+    assert!(storylet_is_eligible(
+        &world,
+        &sim,
+        &second_date,
+        &world.storylet_usage
+    ));
+}
+
+#[test]
+fn soft_dependency_boosts_score_without_gating() {
+    let director = EventDirector::new();
+    let mut world = WorldState::new(WorldSeed(11), NpcId(1));
+    let sim = SimState::new();
+    let follow_up = build_storylet("coffee_again", &[], &["first_date"]);
+
+    assert!(storylet_is_eligible(
+        &world,
+        &sim,
+        &follow_up,
+        &world.storylet_usage
+    ));
+    let before = director.score_storylet(&follow_up, &world);
+    let before_simple = score_storylet_full_simple(&world, &sim, &follow_up);
+
+    world.storylet_usage.record_fire("first_date");
+
+    let after = director.score_storylet(&follow_up, &world);
+    let after_simple = score_storylet_full_simple(&world, &sim, &follow_up);
+    assert!(
+        (after - before * 1.5).abs() < 1e-4,
+        "{} vs {}",
+        after,
+        before
+    );
+    assert!((after_simple - before_simple * 1.5).abs() < 1e-4);
+}
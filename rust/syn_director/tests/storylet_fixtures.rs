@@ -0,0 +1,125 @@
+//! Embedded storylet fixtures run as a test suite.
+
+use std::fs;
+use std::path::Path;
+
+use syn_director::run_storylet_fixtures;
+use tempfile::TempDir;
+
+const PROPOSAL: &str = r#"{
+  "id": "romance.proposal",
+  "name": "The Proposal",
+  "description": null,
+  "tags": ["romance"],
+  "domain": "romance",
+  "life_stage": "adult",
+  "heat": 7,
+  "weight": 1.0,
+  "roles": [
+    { "name": "protagonist", "required": true, "constraints": null },
+    { "name": "partner", "required": true, "constraints": null }
+  ],
+  "prerequisites": {
+    "relationship_prerequisites": [
+      {
+        "from_role": "protagonist",
+        "to_role": "partner",
+        "thresholds": [{ "axis": "affection", "min": 7.0, "max": null }]
+      }
+    ],
+    "memory_prerequisites": { "must_have_tags": ["romance"], "must_not_have_tags": [] },
+    "global_flags": { "must_be_set": [], "must_be_unset": ["married"] }
+  },
+  "triggers": [],
+  "cooldowns": {},
+  "outcomes": {
+    "stat_deltas": [{ "stat": "mood", "delta": 3.0 }],
+    "flag_operations": [{ "flag": "married", "set": true }],
+    "memory_entries": [
+      { "roles": "protagonist,partner", "tags": ["romance", "milestone"], "intensity": 8, "description": null }
+    ]
+  },
+  "test_fixture": {
+    "description": "a devoted partner after a romantic memory",
+    "world": {
+      "memory_tags": ["romance"],
+      "relationships": [
+        { "from_role": "protagonist", "to_role": "partner", "axes": { "affection": 8.0 } }
+      ]
+    },
+    "expect_eligible": true,
+    "expected_outcome": {
+      "stat_deltas": { "mood": 3.0 },
+      "flags_set": ["married"],
+      "memory_tags": ["milestone", "romance"]
+    }
+  }
+}"#;
+
+const RECONCILE: &str = r#"{
+  "id": "romance.reconcile",
+  "name": "Making Up",
+  "description": null,
+  "tags": ["romance"],
+  "domain": "romance",
+  "life_stage": "adult",
+  "heat": 4,
+  "weight": 1.0,
+  "roles": [],
+  "prerequisites": {
+    "memory_prerequisites": { "must_have_tags": ["conflict"], "must_not_have_tags": [] }
+  },
+  "triggers": [],
+  "cooldowns": {},
+  "outcomes": {},
+  "test_fixture": {
+    "description": "nothing to make up for",
+    "expect_eligible": false
+  }
+}"#;
+
+fn write_storylet(dir: &Path, name: &str, json: &str) {
+    fs::write(dir.join(name), json).unwrap();
+}
+
+#[test]
+fn embedded_fixtures_pass() {
+    let dir = TempDir::new().unwrap();
+    write_storylet(dir.path(), "proposal.json", PROPOSAL);
+    write_storylet(dir.path(), "reconcile.json", RECONCILE);
+
+    let report = run_storylet_fixtures(dir.path()).expect("storylets compile");
+    assert_eq!(report.run, 2);
+    assert!(
+        report.passed(),
+        "{}",
+        report
+            .failures
+            .iter()
+            .map(|f| f.to_string())
+            .collect::<Vec<_>>()
+            .join("\n")
+    );
+}
+
+#[test]
+fn content_regressions_are_reported() {
+    let dir = TempDir::new().unwrap();
+    // Someone softens the proposal's mood bump and raises the affection bar,
+    // but leaves the fixture as authored.
+    let edited = PROPOSAL
+        .replace(r#""delta": 3.0"#, r#""delta": 1.0"#)
+        .replace(r#""min": 7.0"#, r#""min": 9.0"#);
+    write_storylet(dir.path(), "proposal.json", &edited);
+    write_storylet(dir.path(), "reconcile.json", RECONCILE);
+
+    let report = run_storylet_fixtures(dir.path()).expect("storylets compile");
+    assert_eq!(report.run, 2);
+    assert_eq!(report.failures.len(), 1);
+
+    let failure = &report.failures[0];
+    assert_eq!(failure.storylet_id.0, "romance.proposal");
+    assert_eq!(failure.mismatches.len(), 2);
+    assert!(failure.mismatches[0].starts_with("expected eligible = true"));
+    assert!(failure.mismatches[1].contains("stat 'mood'"));
+}
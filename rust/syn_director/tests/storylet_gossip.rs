@@ -0,0 +1,144 @@
+//! Gossip-worthy storylets start rumors that spread and open reactions.
+
+use syn_core::time::TickContext;
+use syn_core::{
+    gossip_flag, AbstractNpc, AttachmentStyle, NpcId, Relationship, SimTick, Traits, WorldSeed,
+    WorldState,
+};
+use syn_director::storylet_loader::parse_storylet_str;
+use syn_director::{EventDirector, Storylet, StoryletOutcome};
+use syn_memory::MemorySystem;
+
+fn npc(id: u64) -> AbstractNpc {
+    AbstractNpc {
+        id: NpcId(id),
+        age: 30,
+        job: "Barista".to_string(),
+        district: "Downtown".to_string(),
+        household_id: id,
+        traits: Traits {
+            sociability: 90.0,
+            ..Traits::default()
+        },
+        seed: id,
+        attachment_style: AttachmentStyle::Secure,
+    }
+}
+
+/// Player 1; NPC 2 saw the breakup and is close to NPC 3.
+fn cafe() -> WorldState {
+    let mut world = WorldState::new(WorldSeed(77), NpcId(1));
+    for id in 1..=3 {
+        world.npcs.insert(NpcId(id), npc(id));
+    }
+    world.known_npcs = vec![NpcId(2), NpcId(3)];
+    world.set_relationship(
+        NpcId(2),
+        NpcId(3),
+        Relationship {
+            familiarity: 9.0,
+            trust: 6.0,
+            ..Default::default()
+        },
+    );
+    world
+}
+
+fn storylet(raw: &str) -> Storylet {
+    parse_storylet_str(raw).expect("storylet parses")
+}
+
+fn public_breakup() -> Storylet {
+    storylet(
+        r#"{
+            "id": "public_breakup",
+            "heat": 20,
+            "weight": 1.0,
+            "roles": [{ "name": "witness", "npc_id": 2 }],
+            "prerequisites": {
+                "life_stages": [],
+                "tags": ["breakup"],
+                "relationship_states": [],
+                "memory_tags_required": [],
+                "memory_tags_forbidden": []
+            }
+        }"#,
+    )
+}
+
+#[test]
+fn a_breakup_gets_around_and_opens_reactions() {
+    let mut director = EventDirector::new();
+    director.register_storylet(storylet(
+        r#"{
+            "id": "whispers_at_work",
+            "heat": 10,
+            "weight": 1.0,
+            "prerequisites": {
+                "life_stages": [],
+                "tags": ["gossip"],
+                "relationship_states": [],
+                "memory_tags_required": [],
+                "memory_tags_forbidden": [],
+                "expression": "has_flag(gossip_breakup)"
+            }
+        }"#,
+    ));
+    let mut world = cafe();
+    let mut memory = MemorySystem::new();
+
+    director.fire_storylet(
+        &public_breakup(),
+        &mut world,
+        &mut memory,
+        StoryletOutcome::default(),
+        SimTick(1),
+    );
+    let rumor = world
+        .gossip
+        .rumors
+        .values()
+        .find(|rumor| rumor.source_event_id == "public_breakup")
+        .expect("breakup starts a rumor");
+    assert_eq!(rumor.subject_id, NpcId(1));
+    assert_eq!(rumor.originator_id, NpcId(2));
+    assert!(director
+        .find_eligible(&world, &memory, SimTick(1))
+        .is_empty());
+
+    let mut ctx = TickContext::default();
+    for _ in 0..24 * 7 {
+        world.tick(&mut ctx);
+    }
+
+    let heard = |npc: u64| {
+        world.memory_entries.iter().any(|entry| {
+            entry.npc_id == NpcId(npc) && entry.tags.contains(&gossip_flag("breakup"))
+        })
+    };
+    assert!(heard(2), "the witness remembers what they saw");
+    assert!(heard(3), "the witness's friend heard about it");
+    assert!(world.world_flags.has_any(&gossip_flag("breakup")));
+    let tick = world.current_tick;
+    let eligible = director.find_eligible(&world, &memory, tick);
+    assert_eq!(eligible.len(), 1);
+    assert_eq!(eligible[0].id, "whispers_at_work");
+}
+
+#[test]
+fn ordinary_storylets_start_no_rumors() {
+    let mut director = EventDirector::new();
+    let mut world = cafe();
+    let mut memory = MemorySystem::new();
+    let mut quiet = public_breakup();
+    quiet.prerequisites.tags = vec!["romance".to_string()];
+
+    director.fire_storylet(
+        &quiet,
+        &mut world,
+        &mut memory,
+        StoryletOutcome::default(),
+        SimTick(1),
+    );
+    assert!(world.gossip.rumors.is_empty());
+}
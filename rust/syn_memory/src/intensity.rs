@@ -0,0 +1,220 @@
+//! Personality-modulated memory intensity.
+//!
+//! Volatile, empathetic characters form more intense memories than stoic ones.
+//! When a memory is recorded with a [`MemoryIntensityContext`], its raw
+//! `emotional_intensity` is scaled by a multiplier built from per-trait curves
+//! ([`MemoryIntensityCurves`]). The result is always clamped to -1.0..=1.0.
+
+use serde::{Deserialize, Serialize};
+use syn_core::det_math::powf;
+use syn_core::npc::PersonalityVector;
+use syn_core::{NpcId, Traits, WorldState};
+
+/// Response curve for a single personality axis.
+///
+/// The axis value is centered on -1.0..=1.0 (0 = average). Its contribution to
+/// the intensity multiplier is `gain * sign(x) * |x|^exponent`.
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub struct TraitCurve {
+    /// Multiplier contribution at the trait extreme (x = ±1).
+    pub gain: f32,
+    /// Shape of the curve; 1.0 is linear, >1.0 only reacts near the extremes.
+    pub exponent: f32,
+}
+
+impl TraitCurve {
+    pub const fn linear(gain: f32) -> Self {
+        TraitCurve {
+            gain,
+            exponent: 1.0,
+        }
+    }
+
+    /// Evaluate the curve for a centered trait value.
+    pub fn evaluate(&self, x: f32) -> f32 {
+        let x = x.clamp(-1.0, 1.0);
+        self.gain * x.signum() * powf(x.abs(), self.exponent.max(0.0))
+    }
+}
+
+/// Per-trait curves used to recalibrate memory intensity.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct MemoryIntensityCurves {
+    /// Calm (-1) ↔ volatile (+1).
+    pub volatility: TraitCurve,
+    /// Detached (-1) ↔ sensitive (+1).
+    pub empathy: TraitCurve,
+    /// Cautious (-1) ↔ reckless (+1).
+    pub impulsivity: TraitCurve,
+    /// Lower bound on the combined multiplier.
+    pub min_multiplier: f32,
+    /// Upper bound on the combined multiplier.
+    pub max_multiplier: f32,
+}
+
+impl Default for MemoryIntensityCurves {
+    fn default() -> Self {
+        MemoryIntensityCurves {
+            volatility: TraitCurve::linear(0.4),
+            empathy: TraitCurve::linear(0.3),
+            impulsivity: TraitCurve::linear(0.15),
+            min_multiplier: 0.5,
+            max_multiplier: 1.6,
+        }
+    }
+}
+
+impl MemoryIntensityCurves {
+    /// Combined intensity multiplier for a personality profile.
+    pub fn multiplier(&self, ctx: &MemoryIntensityContext) -> f32 {
+        let raw = 1.0
+            + self.volatility.evaluate(ctx.volatility)
+            + self.empathy.evaluate(ctx.empathy)
+            + self.impulsivity.evaluate(ctx.impulsivity);
+        let lo = self.min_multiplier.max(0.0);
+        let hi = self.max_multiplier.max(lo);
+        raw.clamp(lo, hi)
+    }
+
+    /// Recalibrate a raw emotional intensity; the result stays in -1.0..=1.0.
+    pub fn adjust(&self, intensity: f32, ctx: &MemoryIntensityContext) -> f32 {
+        (intensity * self.multiplier(ctx)).clamp(-1.0, 1.0)
+    }
+}
+
+/// Personality profile of the character forming a memory.
+///
+/// All axes are centered on -1.0..=1.0 so that an average character (0.0
+/// everywhere) keeps the raw intensity unchanged.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Serialize, Deserialize)]
+pub struct MemoryIntensityContext {
+    pub volatility: f32,
+    pub empathy: f32,
+    pub impulsivity: f32,
+}
+
+impl MemoryIntensityContext {
+    /// Build from 0-100 [`Traits`] (player and abstract NPCs).
+    pub fn from_traits(traits: &Traits) -> Self {
+        let center = |v: f32| ((v - 50.0) / 50.0).clamp(-1.0, 1.0);
+        MemoryIntensityContext {
+            volatility: -center(traits.stability),
+            empathy: center(traits.empathy),
+            impulsivity: center(traits.impulsivity),
+        }
+    }
+
+    /// Build from an authored [`PersonalityVector`] (NPC prototypes).
+    ///
+    /// Warmth stands in for empathy; low conscientiousness reads as impulsive.
+    pub fn from_personality(personality: &PersonalityVector) -> Self {
+        MemoryIntensityContext {
+            volatility: personality.volatility.clamp(-1.0, 1.0),
+            empathy: personality.warmth.clamp(-1.0, 1.0),
+            impulsivity: (1.0 - 2.0 * personality.conscientiousness).clamp(-1.0, 1.0),
+        }
+    }
+
+    /// Look up the personality of `npc_id` in the world.
+    ///
+    /// Prefers the simulated traits of an abstract NPC and falls back to the
+    /// prototype personality. Returns `None` if neither is known.
+    pub fn for_npc(world: &WorldState, npc_id: NpcId) -> Option<Self> {
+        if let Some(npc) = world.npcs.get(&npc_id) {
+            return Some(Self::from_traits(&npc.traits));
+        }
+        world
+            .npc_prototypes
+            .get(&npc_id)
+            .map(|proto| Self::from_personality(&proto.personality))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn volatile_empath() -> MemoryIntensityContext {
+        MemoryIntensityContext {
+            volatility: 1.0,
+            empathy: 1.0,
+            impulsivity: 1.0,
+        }
+    }
+
+    fn stoic() -> MemoryIntensityContext {
+        MemoryIntensityContext {
+            volatility: -1.0,
+            empathy: -1.0,
+            impulsivity: -1.0,
+        }
+    }
+
+    #[test]
+    fn test_average_personality_is_neutral() {
+        let curves = MemoryIntensityCurves::default();
+        let ctx = MemoryIntensityContext::default();
+        assert!((curves.adjust(0.4, &ctx) - 0.4).abs() < 1e-6);
+        assert!((curves.adjust(-0.4, &ctx) + 0.4).abs() < 1e-6);
+    }
+
+    #[test]
+    fn test_volatile_character_forms_stronger_memories_than_stoic() {
+        let curves = MemoryIntensityCurves::default();
+        let strong = curves.adjust(-0.5, &volatile_empath());
+        let weak = curves.adjust(-0.5, &stoic());
+        assert!(strong < -0.5);
+        assert!(weak > -0.5 && weak < 0.0);
+    }
+
+    #[test]
+    fn test_adjusted_intensity_is_clamped() {
+        let curves = MemoryIntensityCurves::default();
+        assert!((curves.adjust(0.9, &volatile_empath()) - 1.0).abs() < 1e-6);
+        assert!((curves.adjust(-0.9, &volatile_empath()) + 1.0).abs() < 1e-6);
+    }
+
+    #[test]
+    fn test_multiplier_respects_bounds() {
+        let curves = MemoryIntensityCurves {
+            volatility: TraitCurve::linear(5.0),
+            ..Default::default()
+        };
+        assert!((curves.multiplier(&volatile_empath()) - curves.max_multiplier).abs() < 1e-6);
+        assert!((curves.multiplier(&stoic()) - curves.min_multiplier).abs() < 1e-6);
+
+        // Inverted bounds collapse to the lower bound instead of panicking.
+        let inverted = MemoryIntensityCurves {
+            min_multiplier: 1.2,
+            max_multiplier: 0.8,
+            ..Default::default()
+        };
+        assert!((inverted.multiplier(&stoic()) - 1.2).abs() < 1e-6);
+    }
+
+    #[test]
+    fn test_context_from_traits_and_personality() {
+        let traits = Traits {
+            stability: 0.0,
+            empathy: 100.0,
+            impulsivity: 50.0,
+            ..Default::default()
+        };
+        let ctx = MemoryIntensityContext::from_traits(&traits);
+        assert!((ctx.volatility - 1.0).abs() < 1e-6);
+        assert!((ctx.empathy - 1.0).abs() < 1e-6);
+        assert!(ctx.impulsivity.abs() < 1e-6);
+
+        let personality = PersonalityVector {
+            warmth: -0.5,
+            dominance: 0.0,
+            volatility: 3.0,
+            conscientiousness: 1.0,
+            openness: 0.5,
+        };
+        let ctx = MemoryIntensityContext::from_personality(&personality);
+        assert!((ctx.volatility - 1.0).abs() < 1e-6);
+        assert!((ctx.empathy + 0.5).abs() < 1e-6);
+        assert!((ctx.impulsivity + 1.0).abs() < 1e-6);
+    }
+}
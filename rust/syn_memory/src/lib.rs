@@ -2,12 +2,16 @@
 //!
 //! Records player choices, event outcomes, and emotional impacts.
 //! Memories are used by the Event Director to trigger echos and narrative chains.
+//! Journals can be searched by text, tags, participants and intensity
+//! ([`MemoryQuery`]), with salience-ranked, paginated results.
 //!
 //! ## Cold Storage Integration
 //!
 //! When the `storage` feature is enabled (default), journals can be archived
 //! to cold storage via HybridStorage, enabling long-term memory persistence
-//! for dormant NPCs.
+//! for dormant NPCs. Archived journals carry [`JOURNAL_ARCHIVE_SCHEMA_VERSION`]
+//! and are upgraded on load; whole groups of NPCs can be archived and
+//! restored in one storage call.
 
 use serde::{Deserialize, Serialize};
 use std::collections::HashMap;
@@ -15,12 +19,43 @@ use syn_core::npc_behavior::BehaviorKind;
 pub use syn_core::relationships::RelationshipDelta;
 pub use syn_core::{NpcId, SimTick, StatDelta};
 
+pub mod intensity;
+pub use intensity::{MemoryIntensityContext, MemoryIntensityCurves, TraitCurve};
+
+pub mod search;
+pub use search::{MemoryHit, MemoryQuery, MemorySearchPage};
+
 #[cfg(feature = "storage")]
 use syn_storage::HybridStorage;
 
 #[cfg(feature = "storage")]
 use syn_storage::storage_error::StorageError;
 
+#[cfg(feature = "storage")]
+use syn_storage::ArchivedJournal;
+
+/// Schema version of archived journal JSON. Bump it when the [`Journal`]
+/// layout changes in a way serde defaults can't absorb, and add the matching
+/// upgrade step to `upgrade_archived_journal`.
+pub const JOURNAL_ARCHIVE_SCHEMA_VERSION: u32 = 1;
+
+/// Deserialize an archived journal, upgrading older schema versions.
+#[cfg(feature = "storage")]
+fn upgrade_archived_journal(archived: &ArchivedJournal) -> Result<Journal, StorageError> {
+    if archived.schema_version > JOURNAL_ARCHIVE_SCHEMA_VERSION {
+        return Err(StorageError::UnsupportedSchemaVersion {
+            found: archived.schema_version,
+            supported: JOURNAL_ARCHIVE_SCHEMA_VERSION,
+        });
+    }
+    // Version 1 is the current layout. Upgrade steps rewrite the JSON value
+    // from one version to the next before the final deserialize.
+    let value: serde_json::Value = serde_json::from_str(&archived.json)
+        .map_err(|e| StorageError::Unknown(format!("JSON deserialization failed: {}", e)))?;
+    serde_json::from_value(value)
+        .map_err(|e| StorageError::Unknown(format!("JSON deserialization failed: {}", e)))
+}
+
 /// A single memory entry recording an event and its impact.
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct MemoryEntry {
@@ -159,12 +194,16 @@ impl Journal {
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct MemorySystem {
     pub journals: HashMap<NpcId, Journal>,
+    /// Curves used to recalibrate intensity from personality.
+    #[serde(default)]
+    pub intensity_curves: MemoryIntensityCurves,
 }
 
 impl MemorySystem {
     pub fn new() -> Self {
         MemorySystem {
             journals: HashMap::new(),
+            intensity_curves: MemoryIntensityCurves::default(),
         }
     }
 
@@ -184,11 +223,39 @@ impl MemorySystem {
                 .map_err(|e| StorageError::Unknown(format!("JSON serialization failed: {}", e)))?;
             
             // Store in cold tier using DuckDB journal archive
-            storage.archive_journal(npc_id.0, &json)?;
+            storage.archive_journal(npc_id.0, JOURNAL_ARCHIVE_SCHEMA_VERSION, &json)?;
         }
         Ok(())
     }
 
+    /// Archive the journals of many NPCs in one storage call (requires
+    /// `storage` feature).
+    ///
+    /// Meant for demoting a whole group of NPCs at once. NPCs without a
+    /// journal are skipped. Returns how many journals were archived.
+    #[cfg(feature = "storage")]
+    pub fn archive_journals(
+        &self,
+        npc_ids: &[NpcId],
+        storage: &HybridStorage,
+    ) -> Result<usize, StorageError> {
+        let mut archived = Vec::with_capacity(npc_ids.len());
+        for npc_id in npc_ids {
+            if let Some(journal) = self.journals.get(npc_id) {
+                let json = serde_json::to_string(journal).map_err(|e| {
+                    StorageError::Unknown(format!("JSON serialization failed: {}", e))
+                })?;
+                archived.push(ArchivedJournal {
+                    npc_id: npc_id.0,
+                    schema_version: JOURNAL_ARCHIVE_SCHEMA_VERSION,
+                    json,
+                });
+            }
+        }
+        storage.archive_journals(&archived)?;
+        Ok(archived.len())
+    }
+
     /// Load an archived journal from cold storage (requires `storage` feature).
     ///
     /// Retrieves a serialized journal from the cold tier and deserializes it
@@ -199,9 +266,8 @@ impl MemorySystem {
         npc_id: NpcId,
         storage: &HybridStorage,
     ) -> Result<Option<Journal>, StorageError> {
-        if let Some(json_str) = storage.load_archived_journal(npc_id.0)? {
-            let journal: Journal = serde_json::from_str(&json_str)
-                .map_err(|e| StorageError::Unknown(format!("JSON deserialization failed: {}", e)))?;
+        if let Some(archived) = storage.load_archived_journal(npc_id.0)? {
+            let journal = upgrade_archived_journal(&archived)?;
             self.journals.insert(npc_id, journal.clone());
             Ok(Some(journal))
         } else {
@@ -209,6 +275,28 @@ impl MemorySystem {
         }
     }
 
+    /// Restore the archived journals of many NPCs in one storage call
+    /// (requires `storage` feature).
+    ///
+    /// NPCs without an archive are skipped. Returns the NPCs whose journals
+    /// were restored, in the order given.
+    #[cfg(feature = "storage")]
+    pub fn restore_archived_journals(
+        &mut self,
+        npc_ids: &[NpcId],
+        storage: &HybridStorage,
+    ) -> Result<Vec<NpcId>, StorageError> {
+        let ids: Vec<u64> = npc_ids.iter().map(|id| id.0).collect();
+        let mut restored = Vec::new();
+        for archived in storage.load_archived_journals(&ids)? {
+            let journal = upgrade_archived_journal(&archived)?;
+            let npc_id = NpcId(archived.npc_id);
+            self.journals.insert(npc_id, journal);
+            restored.push(npc_id);
+        }
+        Ok(restored)
+    }
+
     /// Prune old memories from a journal, keeping only recent ones.
     ///
     /// Archives the full journal before pruning if storage is provided.
@@ -266,15 +354,26 @@ impl MemorySystem {
     }
 
     /// Record a memory for an NPC.
-    pub fn record_memory(&mut self, entry: MemoryEntry) {
+    ///
+    /// When `context` is provided, the entry's emotional intensity is scaled
+    /// by the holder's personality using [`MemorySystem::intensity_curves`].
+    pub fn record_memory(
+        &mut self,
+        mut entry: MemoryEntry,
+        context: Option<&MemoryIntensityContext>,
+    ) {
+        if let Some(ctx) = context {
+            entry.emotional_intensity =
+                self.intensity_curves.adjust(entry.emotional_intensity, ctx);
+        }
         let journal = self.get_or_create_journal(entry.npc_id);
         journal.record(entry);
     }
 
-    /// Query memories across all NPCs by event_id.
+    /// Query memories across all NPCs by event_id, ordered by NPC id.
     pub fn memories_by_event(&self, event_id: &str) -> Vec<(&NpcId, &MemoryEntry)> {
-        self.journals
-            .iter()
+        syn_core::sorted_entries(&self.journals)
+            .into_iter()
             .flat_map(|(npc_id, journal)| {
                 journal
                     .entries
@@ -371,7 +470,7 @@ pub fn add_npc_behavior_memory(
     let mut entry = MemoryEntry::new(id, "npc_behavior".to_string(), NpcId(npc_id), tick, 0.0);
     entry.tags = tags;
     entry.participants = vec![npc_id, player_id];
-    memory.record_memory(entry);
+    memory.record_memory(entry, None);
 }
 
 /// Expanded helper: record a behavior memory with explicit tags.
@@ -393,7 +492,7 @@ pub fn add_npc_behavior_memory_with_tags(
     );
     entry.tags = tags;
     entry.participants = vec![npc_id, player_id];
-    memory.record_memory(entry);
+    memory.record_memory(entry, None);
 }
 
 #[cfg(test)]
@@ -487,10 +586,31 @@ mod tests {
             0.8,
         );
 
-        memory_sys.record_memory(entry);
+        memory_sys.record_memory(entry, None);
         assert!(memory_sys.get_journal(NpcId(1)).is_some());
     }
 
+    #[test]
+    fn test_record_memory_with_intensity_context() {
+        let mut memory_sys = MemorySystem::new();
+        let volatile = MemoryIntensityContext {
+            volatility: 1.0,
+            empathy: 1.0,
+            impulsivity: 0.0,
+        };
+        let entry = MemoryEntry::new(
+            "mem_002".to_string(),
+            "event_2".to_string(),
+            NpcId(2),
+            SimTick(100),
+            -0.9,
+        );
+
+        memory_sys.record_memory(entry, Some(&volatile));
+        let journal = memory_sys.get_journal(NpcId(2)).unwrap();
+        assert_eq!(journal.entries[0].emotional_intensity, -1.0);
+    }
+
     #[test]
     fn test_prune_old_memories_no_archive() {
         let mut memory_sys = MemorySystem::new();
@@ -505,7 +625,7 @@ mod tests {
                 SimTick(day * 24),
                 0.5,
             );
-            memory_sys.record_memory(entry);
+            memory_sys.record_memory(entry, None);
         }
 
         let journal = memory_sys.get_journal(npc_id).unwrap();
@@ -0,0 +1,172 @@
+//! Journal search.
+//!
+//! A [`MemoryQuery`] combines filters (tags, participants, intensity range)
+//! with an optional free-text term. Memories carry no prose of their own, so
+//! the text is matched against the event id and tags, case-insensitively and
+//! with `_`/`.` read as spaces (`"first date"` finds `romance.first_date`).
+//!
+//! Hits are ranked by salience — the magnitude of the memory's emotional
+//! intensity — weighted by how well the text matched, then by recency.
+//! Results are paginated with `offset`/`limit`.
+
+use serde::{Deserialize, Serialize};
+
+use crate::{Journal, MemoryEntry, MemorySystem, NpcId};
+
+/// Floor on salience so neutral memories still rank (by recency).
+const MIN_SALIENCE: f32 = 0.05;
+
+/// Relevance multiplier when the text equals one of the memory's tags.
+const TAG_EXACT_WEIGHT: f32 = 2.0;
+/// Relevance multiplier when the text appears in the event id.
+const EVENT_ID_WEIGHT: f32 = 1.5;
+/// Relevance multiplier when the text appears inside a tag.
+const TAG_PARTIAL_WEIGHT: f32 = 1.0;
+
+/// Search filters and pagination. Empty filters match everything.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct MemoryQuery {
+    /// Free-text term matched against event ids and tags.
+    pub text: Option<String>,
+    /// Tags the memory must all carry (case-insensitive).
+    pub tags: Vec<String>,
+    /// Participants the memory must all involve.
+    pub participants: Vec<u64>,
+    /// Lowest emotional intensity to include (signed, -1.0..=1.0).
+    pub min_intensity: Option<f32>,
+    /// Highest emotional intensity to include (signed, -1.0..=1.0).
+    pub max_intensity: Option<f32>,
+    /// Ranked hits to skip.
+    pub offset: usize,
+    /// Maximum hits to return.
+    pub limit: usize,
+}
+
+impl Default for MemoryQuery {
+    fn default() -> Self {
+        MemoryQuery {
+            text: None,
+            tags: Vec::new(),
+            participants: Vec::new(),
+            min_intensity: None,
+            max_intensity: None,
+            offset: 0,
+            limit: 20,
+        }
+    }
+}
+
+/// A ranked search hit.
+#[derive(Debug, Clone)]
+pub struct MemoryHit<'a> {
+    pub entry: &'a MemoryEntry,
+    /// Salience times text relevance; higher ranks first.
+    pub score: f32,
+}
+
+/// One page of ranked hits.
+#[derive(Debug, Clone)]
+pub struct MemorySearchPage<'a> {
+    pub hits: Vec<MemoryHit<'a>>,
+    /// Hits across all pages.
+    pub total: usize,
+}
+
+fn normalize(text: &str) -> String {
+    text.to_lowercase().replace(['_', '.'], " ")
+}
+
+impl MemoryQuery {
+    fn passes_filters(&self, entry: &MemoryEntry) -> bool {
+        let intensity = entry.emotional_intensity;
+        self.min_intensity.is_none_or(|min| intensity >= min)
+            && self.max_intensity.is_none_or(|max| intensity <= max)
+            && self
+                .tags
+                .iter()
+                .all(|tag| entry.tags.iter().any(|t| t.eq_ignore_ascii_case(tag)))
+            && self
+                .participants
+                .iter()
+                .all(|id| entry.participants.contains(id))
+    }
+
+    /// Text relevance multiplier, or `None` if the text does not match.
+    fn relevance(&self, entry: &MemoryEntry) -> Option<f32> {
+        let Some(text) = self.text.as_deref().map(normalize) else {
+            return Some(1.0);
+        };
+        let text = text.trim();
+        if text.is_empty() {
+            return Some(1.0);
+        }
+        let tags: Vec<String> = entry.tags.iter().map(|t| normalize(t)).collect();
+        if tags.iter().any(|t| t == text) {
+            Some(TAG_EXACT_WEIGHT)
+        } else if normalize(&entry.event_id).contains(text) {
+            Some(EVENT_ID_WEIGHT)
+        } else if tags.iter().any(|t| t.contains(text)) {
+            Some(TAG_PARTIAL_WEIGHT)
+        } else {
+            None
+        }
+    }
+
+    /// Score a memory, or `None` if it does not match.
+    pub fn score(&self, entry: &MemoryEntry) -> Option<f32> {
+        if !self.passes_filters(entry) {
+            return None;
+        }
+        let salience = entry.emotional_intensity.abs().max(MIN_SALIENCE);
+        self.relevance(entry).map(|relevance| salience * relevance)
+    }
+
+    /// Rank and paginate matching entries.
+    pub fn run<'a>(
+        &self,
+        entries: impl IntoIterator<Item = &'a MemoryEntry>,
+    ) -> MemorySearchPage<'a> {
+        let mut hits: Vec<MemoryHit<'a>> = entries
+            .into_iter()
+            .filter_map(|entry| self.score(entry).map(|score| MemoryHit { entry, score }))
+            .collect();
+        hits.sort_by(|a, b| {
+            b.score
+                .total_cmp(&a.score)
+                .then(b.entry.sim_tick.0.cmp(&a.entry.sim_tick.0))
+                .then_with(|| a.entry.id.cmp(&b.entry.id))
+                .then(a.entry.npc_id.0.cmp(&b.entry.npc_id.0))
+        });
+        let total = hits.len();
+        let hits = hits
+            .into_iter()
+            .skip(self.offset)
+            .take(self.limit)
+            .collect();
+        MemorySearchPage { hits, total }
+    }
+}
+
+impl Journal {
+    /// Search this journal (see [`MemoryQuery`]).
+    pub fn search(&self, query: &MemoryQuery) -> MemorySearchPage<'_> {
+        query.run(&self.entries)
+    }
+}
+
+impl MemorySystem {
+    /// Search one NPC's journal, or every journal when `npc_id` is `None`.
+    pub fn search(&self, npc_id: Option<NpcId>, query: &MemoryQuery) -> MemorySearchPage<'_> {
+        match npc_id {
+            Some(id) => self
+                .get_journal(id)
+                .map(|journal| journal.search(query))
+                .unwrap_or(MemorySearchPage {
+                    hits: Vec::new(),
+                    total: 0,
+                }),
+            None => query.run(self.journals.values().flat_map(|j| j.entries.iter())),
+        }
+    }
+}
@@ -3,7 +3,8 @@
 #[cfg(feature = "storage")]
 mod with_storage {
     use syn_core::{NpcId, SimTick};
-    use syn_memory::{MemoryEntry, MemorySystem};
+    use syn_memory::{MemoryEntry, MemorySystem, JOURNAL_ARCHIVE_SCHEMA_VERSION};
+    use syn_storage::storage_error::StorageError;
     use syn_storage::HybridStorage;
     use tempfile::TempDir;
 
@@ -32,7 +33,7 @@ mod with_storage {
                 SimTick(i * 10),
                 0.5,
             );
-            memory_sys.record_memory(entry);
+            memory_sys.record_memory(entry, None);
         }
 
         let original_journal = memory_sys.get_journal(npc_id).unwrap().clone();
@@ -111,7 +112,7 @@ mod with_storage {
                 SimTick(day * 24),
                 0.5,
             );
-            memory_sys.record_memory(entry);
+            memory_sys.record_memory(entry, None);
         }
 
         assert_eq!(memory_sys.get_journal(npc_id).unwrap().entries.len(), 10);
@@ -141,4 +142,77 @@ mod with_storage {
         
         assert_eq!(archived.entries.len(), 10); // Full journal before pruning
     }
+
+    #[test]
+    fn test_batch_archive_and_restore() {
+        let temp_dir = TempDir::new().unwrap();
+        let hot_path = temp_dir.path().join("test.redb");
+        let cold_path = temp_dir.path().join("test.duckdb");
+
+        let storage = HybridStorage::new(
+            hot_path.to_str().unwrap(),
+            cold_path.to_str().unwrap(),
+        )
+        .expect("Failed to create storage");
+
+        let mut memory_sys = MemorySystem::new();
+        let district: Vec<NpcId> = (1..=600).map(NpcId).collect();
+        for npc_id in &district {
+            let entry = MemoryEntry::new(
+                format!("mem_{}", npc_id.0),
+                "event_block_party".to_string(),
+                *npc_id,
+                SimTick(npc_id.0),
+                0.3,
+            );
+            memory_sys.record_memory(entry, None);
+        }
+
+        // NPC 9999 has no journal and is skipped.
+        let mut ids = district.clone();
+        ids.push(NpcId(9999));
+        let archived = memory_sys
+            .archive_journals(&ids, &storage)
+            .expect("Failed to archive journals");
+        assert_eq!(archived, 600);
+
+        let mut new_sys = MemorySystem::new();
+        let restored = new_sys
+            .restore_archived_journals(&ids, &storage)
+            .expect("Failed to restore journals");
+        assert_eq!(restored, district);
+        for npc_id in &district {
+            let journal = new_sys.get_journal(*npc_id).expect("journal restored");
+            assert_eq!(journal.entries[0].id, format!("mem_{}", npc_id.0));
+        }
+    }
+
+    #[test]
+    fn test_newer_schema_is_rejected() {
+        let temp_dir = TempDir::new().unwrap();
+        let hot_path = temp_dir.path().join("test.redb");
+        let cold_path = temp_dir.path().join("test.duckdb");
+
+        let storage = HybridStorage::new(
+            hot_path.to_str().unwrap(),
+            cold_path.to_str().unwrap(),
+        )
+        .expect("Failed to create storage");
+
+        storage
+            .archive_journal(7, JOURNAL_ARCHIVE_SCHEMA_VERSION + 1, "{}")
+            .expect("Failed to archive journal");
+
+        let mut memory_sys = MemorySystem::new();
+        let err = memory_sys
+            .load_archived_journal(NpcId(7), &storage)
+            .expect_err("newer schema should not load");
+        assert!(matches!(
+            err,
+            StorageError::UnsupportedSchemaVersion { found, supported }
+                if found == JOURNAL_ARCHIVE_SCHEMA_VERSION + 1
+                    && supported == JOURNAL_ARCHIVE_SCHEMA_VERSION
+        ));
+        assert!(memory_sys.get_journal(NpcId(7)).is_none());
+    }
 }
@@ -0,0 +1,118 @@
+use syn_memory::{MemoryEntry, MemoryQuery, MemorySystem, NpcId, SimTick};
+
+fn memory(id: &str, event_id: &str, tick: u64, intensity: f32, tags: Vec<&str>) -> MemoryEntry {
+    MemoryEntry::new(
+        id.to_string(),
+        event_id.to_string(),
+        NpcId(1),
+        SimTick(tick),
+        intensity,
+    )
+    .with_tags(tags)
+}
+
+fn journal() -> MemorySystem {
+    let mut system = MemorySystem::new();
+    let mut date = memory(
+        "m1",
+        "romance.first_date",
+        10,
+        0.6,
+        vec!["romance", "dating"],
+    );
+    date.participants = vec![1, 7];
+    let mut breakup = memory(
+        "m2",
+        "romance.breakup",
+        50,
+        -0.9,
+        vec!["romance", "heartbreak"],
+    );
+    breakup.participants = vec![1, 7];
+    let mut promotion = memory("m3", "career.promotion", 30, 0.4, vec!["career"]);
+    promotion.participants = vec![1, 9];
+    let chores = memory("m4", "home.chores", 40, 0.0, vec![]);
+    for entry in [date, breakup, promotion, chores] {
+        system.record_memory(entry, None);
+    }
+    system
+}
+
+fn ids(system: &MemorySystem, query: &MemoryQuery) -> Vec<String> {
+    system
+        .search(Some(NpcId(1)), query)
+        .hits
+        .iter()
+        .map(|hit| hit.entry.id.clone())
+        .collect()
+}
+
+#[test]
+fn empty_query_ranks_everything_by_salience_then_recency() {
+    let system = journal();
+    assert_eq!(
+        ids(&system, &MemoryQuery::default()),
+        vec!["m2", "m1", "m3", "m4"]
+    );
+}
+
+#[test]
+fn text_matches_event_ids_and_tags_with_relevance_weighting() {
+    let system = journal();
+    let query = MemoryQuery {
+        text: Some("First Date".to_string()),
+        ..Default::default()
+    };
+    assert_eq!(ids(&system, &query), vec!["m1"]);
+
+    // An exact tag match outranks an equally salient event-id match.
+    let query = MemoryQuery {
+        text: Some("career".to_string()),
+        ..Default::default()
+    };
+    let page = system.search(Some(NpcId(1)), &query);
+    assert_eq!(page.total, 1);
+    assert!((page.hits[0].score - 0.8).abs() < 1e-6);
+
+    let query = MemoryQuery {
+        text: Some("heart".to_string()),
+        ..Default::default()
+    };
+    assert_eq!(ids(&system, &query), vec!["m2"]);
+}
+
+#[test]
+fn filters_combine_tags_participants_and_intensity() {
+    let system = journal();
+    let query = MemoryQuery {
+        tags: vec!["ROMANCE".to_string()],
+        participants: vec![7],
+        min_intensity: Some(0.0),
+        ..Default::default()
+    };
+    assert_eq!(ids(&system, &query), vec!["m1"]);
+
+    let query = MemoryQuery {
+        participants: vec![9],
+        max_intensity: Some(0.0),
+        ..Default::default()
+    };
+    assert!(ids(&system, &query).is_empty());
+}
+
+#[test]
+fn pagination_reports_the_full_total() {
+    let system = journal();
+    let query = MemoryQuery {
+        offset: 1,
+        limit: 2,
+        ..Default::default()
+    };
+    let page = system.search(Some(NpcId(1)), &query);
+    assert_eq!(page.total, 4);
+    let ids: Vec<&str> = page.hits.iter().map(|h| h.entry.id.as_str()).collect();
+    assert_eq!(ids, vec!["m1", "m3"]);
+
+    assert_eq!(system.search(Some(NpcId(2)), &query).total, 0);
+    assert_eq!(system.search(None, &query).total, 4);
+}
@@ -2,9 +2,13 @@
 //!
 //! Provides efficient lookups and filters for NPCs, relationships, and events.
 //! Used by syn_sim and syn_director to gather data for decisions.
+//! [`RelationshipNetwork`] exports the player's social graph as JSON or DOT.
+
+pub mod network;
+pub use network::{NetworkEdge, NetworkNode, RelationshipNetwork};
 
 #[allow(unused_imports)]
-use syn_core::{AbstractNpc, NpcId, Relationship, Traits, WorldState};
+use syn_core::{AbstractNpc, HouseholdRole, NpcId, Relationship, Traits, WorldState};
 
 /// Query builder for finding NPCs by various criteria.
 #[derive(Default)]
@@ -172,6 +176,27 @@ impl ClusterQuery {
             .map(|(id, _)| *id)
             .collect()
     }
+
+    /// Find everyone an NPC (or the player) currently lives with.
+    ///
+    /// Used to cast household-based storylets (roommate friction, family
+    /// dinners). Optionally restricted to one household role.
+    pub fn find_housemates(
+        world: &WorldState,
+        npc_id: NpcId,
+        role: Option<HouseholdRole>,
+    ) -> Vec<NpcId> {
+        let Some(household) = world.households.household_of(npc_id) else {
+            return Vec::new();
+        };
+        household
+            .members
+            .iter()
+            .filter(|m| m.npc_id != npc_id)
+            .filter(|m| role.map(|r| m.role == r).unwrap_or(true))
+            .map(|m| m.npc_id)
+            .collect()
+    }
 }
 
 #[cfg(test)]
@@ -266,4 +291,30 @@ mod tests {
         let in_district = ClusterQuery::find_in_district(&world, "Downtown");
         assert_eq!(in_district.len(), 2);
     }
+
+    #[test]
+    fn test_find_housemates() {
+        let mut world = WorldState::new(WorldSeed(42), NpcId(1));
+        let player = world.player_id;
+        world
+            .households
+            .move_in(player, NpcId(2), HouseholdRole::Partner, syn_core::SimTick(1), None, None);
+        world
+            .households
+            .move_in(player, NpcId(3), HouseholdRole::Roommate, syn_core::SimTick(2), None, None);
+
+        assert_eq!(
+            ClusterQuery::find_housemates(&world, player, None),
+            vec![NpcId(2), NpcId(3)]
+        );
+        assert_eq!(
+            ClusterQuery::find_housemates(&world, player, Some(HouseholdRole::Roommate)),
+            vec![NpcId(3)]
+        );
+        assert_eq!(
+            ClusterQuery::find_housemates(&world, NpcId(3), None),
+            vec![player, NpcId(2)]
+        );
+        assert!(ClusterQuery::find_housemates(&world, NpcId(9), None).is_empty());
+    }
 }
@@ -0,0 +1,265 @@
+//! Relationship network export.
+//!
+//! Builds the player's social graph — the player, everyone the player has a
+//! relationship with in either direction, and the relationships among them —
+//! and renders it as JSON (for the in-game relationship map) or GraphViz DOT
+//! (for debug visualization). Nodes and edges are sorted by id so exports are
+//! stable across runs.
+
+use std::collections::BTreeSet;
+use std::fmt::Write as _;
+
+use serde::{Deserialize, Serialize};
+use syn_core::relationship_model::RelationshipVector;
+use syn_core::{NpcId, Relationship, WorldState};
+
+/// A person in the network.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct NetworkNode {
+    /// NPC id (the player's id for the player node).
+    pub id: u64,
+    /// Display name, falling back to the NPC's job or id.
+    pub label: String,
+    /// Whether this node is the player.
+    pub is_player: bool,
+    /// How the player sees this person ("Friend", "Rival", ...); empty for the player.
+    pub role: String,
+    /// Player→NPC affection band; empty for the player.
+    pub affection_band: String,
+    /// Player→NPC trust band; empty for the player.
+    pub trust_band: String,
+    /// Player→NPC attraction band; empty for the player.
+    pub attraction_band: String,
+    /// Player→NPC resentment band; empty for the player.
+    pub resentment_band: String,
+}
+
+/// A directed relationship between two nodes.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct NetworkEdge {
+    /// Actor id.
+    pub from: u64,
+    /// Target id.
+    pub to: u64,
+    /// Role label of this direction ("Friend", "Rival", ...).
+    pub role: String,
+    /// Familiarity axis (-10..10).
+    pub familiarity: f32,
+    /// Emotional intensity (0..1, see `Relationship::heat`).
+    pub heat: f32,
+    /// Drawing weight (0..1): the mean of normalized familiarity and heat.
+    pub weight: f32,
+}
+
+/// The player's social graph.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct RelationshipNetwork {
+    /// Nodes sorted by id, player included.
+    pub nodes: Vec<NetworkNode>,
+    /// Edges sorted by (from, to).
+    pub edges: Vec<NetworkEdge>,
+}
+
+fn vector_of(rel: &Relationship) -> RelationshipVector {
+    RelationshipVector {
+        affection: rel.affection,
+        trust: rel.trust,
+        attraction: rel.attraction,
+        familiarity: rel.familiarity,
+        resentment: rel.resentment,
+    }
+}
+
+fn node_label(world: &WorldState, id: NpcId) -> String {
+    if let Some(proto) = world.npc_prototypes.get(&id) {
+        if !proto.display_name.is_empty() {
+            return proto.display_name.clone();
+        }
+    }
+    match world.npcs.get(&id) {
+        Some(npc) if !npc.job.is_empty() => format!("{} #{}", npc.job, id.0),
+        _ => format!("NPC #{}", id.0),
+    }
+}
+
+/// Escape a string for a double-quoted DOT identifier.
+fn dot_escape(s: &str) -> String {
+    s.replace('\\', "\\\\").replace('"', "\\\"")
+}
+
+impl RelationshipNetwork {
+    /// Build the player's network from the world's relationships.
+    pub fn for_player(world: &WorldState) -> Self {
+        let player = world.player_id;
+
+        let mut members: BTreeSet<NpcId> = BTreeSet::new();
+        members.insert(player);
+        for &(from, to) in world.relationships.keys() {
+            if from == player {
+                members.insert(to);
+            } else if to == player {
+                members.insert(from);
+            }
+        }
+
+        let nodes = members
+            .iter()
+            .map(|&id| {
+                if id == player {
+                    return NetworkNode {
+                        id: id.0,
+                        label: "You".to_string(),
+                        is_player: true,
+                        role: String::new(),
+                        affection_band: String::new(),
+                        trust_band: String::new(),
+                        attraction_band: String::new(),
+                        resentment_band: String::new(),
+                    };
+                }
+                let view = vector_of(&world.get_relationship(player, id));
+                NetworkNode {
+                    id: id.0,
+                    label: node_label(world, id),
+                    is_player: false,
+                    role: view.role().to_string(),
+                    affection_band: view.affection_band().to_string(),
+                    trust_band: view.trust_band().to_string(),
+                    attraction_band: view.attraction_band().to_string(),
+                    resentment_band: view.resentment_band().to_string(),
+                }
+            })
+            .collect();
+
+        let mut edges: Vec<NetworkEdge> = world
+            .relationships
+            .iter()
+            .filter(|((from, to), _)| from != to && members.contains(from) && members.contains(to))
+            .map(|(&(from, to), rel)| {
+                let familiarity = (rel.familiarity.clamp(0.0, 10.0)) / 10.0;
+                let heat = rel.heat();
+                NetworkEdge {
+                    from: from.0,
+                    to: to.0,
+                    role: vector_of(rel).role().to_string(),
+                    familiarity: rel.familiarity,
+                    heat,
+                    weight: ((familiarity + heat.min(1.0)) / 2.0).clamp(0.0, 1.0),
+                }
+            })
+            .collect();
+        edges.sort_by_key(|e| (e.from, e.to));
+
+        RelationshipNetwork { nodes, edges }
+    }
+
+    /// Render as pretty-printed JSON.
+    pub fn to_json(&self) -> String {
+        serde_json::to_string_pretty(self).expect("relationship network serializes")
+    }
+
+    /// Render as a GraphViz DOT digraph.
+    ///
+    /// Node labels carry the role, edge pen width follows the edge weight.
+    pub fn to_dot(&self) -> String {
+        let mut out = String::from("digraph relationships {\n");
+        out.push_str("  node [shape=box, style=rounded];\n");
+        for node in &self.nodes {
+            let label = if node.is_player || node.role.is_empty() {
+                dot_escape(&node.label)
+            } else {
+                format!("{}\\n{}", dot_escape(&node.label), dot_escape(&node.role))
+            };
+            let style = if node.is_player {
+                ", style=\"rounded,bold\""
+            } else {
+                ""
+            };
+            let _ = writeln!(out, "  n{} [label=\"{}\"{}];", node.id, label, style);
+        }
+        for edge in &self.edges {
+            let _ = writeln!(
+                out,
+                "  n{} -> n{} [label=\"{}\", penwidth={:.2}];",
+                edge.from,
+                edge.to,
+                dot_escape(&edge.role),
+                1.0 + edge.weight * 4.0
+            );
+        }
+        out.push_str("}\n");
+        out
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use syn_core::WorldSeed;
+
+    fn world() -> WorldState {
+        let mut world = WorldState::new(WorldSeed(42), NpcId(1));
+        world.set_relationship(
+            NpcId(1),
+            NpcId(2),
+            Relationship {
+                affection: 6.0,
+                trust: 5.0,
+                familiarity: 8.0,
+                ..Default::default()
+            },
+        );
+        world.set_relationship(
+            NpcId(3),
+            NpcId(1),
+            Relationship {
+                resentment: 7.0,
+                ..Default::default()
+            },
+        );
+        // Between two people the player knows: included.
+        world.set_relationship(
+            NpcId(2),
+            NpcId(3),
+            Relationship {
+                familiarity: 4.0,
+                ..Default::default()
+            },
+        );
+        // Between strangers to the player: left out.
+        world.set_relationship(NpcId(8), NpcId(9), Relationship::default());
+        world
+    }
+
+    #[test]
+    fn test_network_covers_player_circle() {
+        let network = RelationshipNetwork::for_player(&world());
+
+        let ids: Vec<u64> = network.nodes.iter().map(|n| n.id).collect();
+        assert_eq!(ids, vec![1, 2, 3]);
+        assert!(network.nodes[0].is_player);
+        assert_eq!(network.nodes[1].role, "Friend");
+
+        let pairs: Vec<(u64, u64)> = network.edges.iter().map(|e| (e.from, e.to)).collect();
+        assert_eq!(pairs, vec![(1, 2), (2, 3), (3, 1)]);
+        let close = &network.edges[0];
+        assert!(close.weight > network.edges[1].weight);
+    }
+
+    #[test]
+    fn test_exports_are_stable_and_parseable() {
+        let world = world();
+        let network = RelationshipNetwork::for_player(&world);
+
+        let json = network.to_json();
+        let parsed: RelationshipNetwork = serde_json::from_str(&json).unwrap();
+        assert_eq!(parsed, network);
+        assert_eq!(json, RelationshipNetwork::for_player(&world).to_json());
+
+        let dot = network.to_dot();
+        assert!(dot.starts_with("digraph relationships {"));
+        assert!(dot.contains("n1 [label=\"You\", style=\"rounded,bold\"];"));
+        assert!(dot.contains("n1 -> n2 [label=\"Friend\""));
+        assert!(!dot.contains("n8"));
+    }
+}
@@ -0,0 +1,80 @@
+//! Benchmarks for the per-tick relationship drift pass.
+//!
+//! Run with: `cargo bench -p syn_sim --bench relationship_drift`
+//!
+//! `active` worlds have every pair still drifting; `settled` worlds have 90%
+//! of pairs already at equilibrium (neutral, fully familiar), which is what a
+//! long-running save converges to.
+
+#![allow(missing_docs)]
+
+use criterion::{black_box, criterion_group, criterion_main, BatchSize, BenchmarkId, Criterion};
+
+use syn_core::{NpcId, Relationship, WorldSeed, WorldState};
+use syn_sim::relationship_drift::{RelationshipDriftConfig, RelationshipDriftSystem};
+
+fn drift_system() -> RelationshipDriftSystem {
+    RelationshipDriftSystem::new(RelationshipDriftConfig {
+        affection_decay_per_tick: 0.05,
+        trust_decay_per_tick: 0.03,
+        resentment_decay_per_tick: 0.02,
+        familiarity_growth_per_tick: 0.01,
+    })
+}
+
+/// World with `pairs` relationships, one in `settled_every` still drifting.
+fn world_with_pairs(pairs: u64, settled_every: u64) -> WorldState {
+    let mut world = WorldState::new(WorldSeed(42), NpcId(1));
+    // Confirm role changes immediately so settled pairs don't sit pending.
+    world.relationship_milestones.tuning.confirm_ticks = 0;
+    let drift = drift_system();
+    for i in 0..pairs {
+        let key = (NpcId(i / 500 + 1), NpcId(i % 500 + 1000));
+        let rel = if settled_every > 1 && i % settled_every != 0 {
+            Relationship {
+                familiarity: 10.0,
+                ..Default::default()
+            }
+        } else {
+            let v = (i % 17) as f32 - 8.0;
+            Relationship {
+                affection: v,
+                trust: -v * 0.5,
+                resentment: (i % 5) as f32,
+                familiarity: (i % 9) as f32,
+                ..Default::default()
+            }
+        };
+        world.relationships.insert(key, rel);
+    }
+    // Let pressure and milestone trackers see every pair once.
+    drift.tick(&mut world);
+    world
+}
+
+fn bench_drift_tick(c: &mut Criterion) {
+    let mut group = c.benchmark_group("relationship_drift");
+    group.sample_size(10);
+    let drift = drift_system();
+
+    for pairs in [10_000u64, 100_000] {
+        for (label, settled_every) in [("active", 1u64), ("settled", 10)] {
+            let world = world_with_pairs(pairs, settled_every);
+            group.bench_with_input(BenchmarkId::new(label, pairs), &pairs, |b, _| {
+                b.iter_batched(
+                    || world.clone(),
+                    |mut world| {
+                        drift.tick(&mut world);
+                        black_box(world)
+                    },
+                    BatchSize::LargeInput,
+                )
+            });
+        }
+    }
+
+    group.finish();
+}
+
+criterion_group!(benches, bench_drift_tick);
+criterion_main!(benches);
@@ -11,23 +11,30 @@
 //! - [`tick_simulation`]: Main simulation tick function
 //! - [`tick_simulation_n`]: Advance multiple ticks
 //! - [`advance_simulation_ticks`]: Helper for N-tick advances
+//! - [`WorldStats`]: Population statistics kept up to date by the tick loop
 //!
 //! The legacy `Simulator` struct and related types are deprecated and will be removed.
 
 mod npc_registry;
+pub mod mood_contagion;
 pub mod relationship_drift;
 pub mod post_life;
 pub mod systems;
+pub mod world_stats;
+pub use mood_contagion::{tick_mood_contagion, MoodContagionConfig};
 pub use npc_registry::NpcRegistry;
 pub use systems::{
     update_npc_tiers_for_tick, update_npcs_for_tick, update_relationships_for_npc,
     update_stats_for_npc, NpcUpdateConfig, TierUpdateConfig,
 };
+pub use world_stats::{DistrictEmployment, WorldStats, WorldStatsTracker, AGE_COHORTS};
 
 use std::collections::HashMap;
 use std::fs;
 use std::path::Path;
 
+use serde::{Deserialize, Serialize};
+
 use syn_core::life_stage::LifeStageConfig;
 use syn_core::narrative_heat::{compute_heat_delta, NarrativeHeatConfig, NarrativeHeatInputs};
 use syn_core::npc::NpcPrototype;
@@ -40,13 +47,14 @@ use syn_core::npc_behavior::{
 };
 use syn_core::relationship_model::RelationshipVector as CoreRelationshipVector;
 use syn_core::{
-    AbstractNpc, DeterministicRng, NpcId, RelationshipDelta, StatKind, Stats, WorldState,
+    AbstractNpc, DeterministicRng, NpcId, Persistence, RelationshipDelta, StatKind, Stats,
+    SynError, WorldSeed, WorldState,
 };
 use syn_core::apply_stat_deltas;
 use syn_core::time::{GameTime, TickContext};
 use syn_memory::MemorySystem;
 use syn_storage::models::AbstractNpc as StorageNpc;
-use syn_storage::{HybridStorage};
+use syn_storage::{HybridStorage, WriteBehindStorage};
 use syn_storage::storage_error::StorageError;
 
 /// Simulation engine: advances world state by one tick.
@@ -68,7 +76,7 @@ pub struct Simulator {
     since = "0.1.0",
     note = "Use `NpcTier` (Tier0/Tier1/Tier2) instead; this legacy enum will be removed."
 )]
-#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
 pub enum LodTier {
     High,
     Medium,
@@ -82,7 +90,7 @@ pub enum LodTier {
     since = "0.1.0",
     note = "Use `NpcTier` instead; this legacy enum will be removed."
 )]
-#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
 pub enum NpcLod {
     Tier2Active,
     Tier1Neighborhood,
@@ -113,6 +121,8 @@ pub struct WorldSimState {
     npc_tiers: HashMap<NpcId, NpcTier>,
     /// Tracks the last tick each NPC was updated.
     last_update_tick: HashMap<NpcId, syn_core::SimTick>,
+    /// Running population statistics.
+    stats: WorldStatsTracker,
 }
 
 impl WorldSimState {
@@ -162,10 +172,41 @@ impl WorldSimState {
     pub fn npc_count(&self) -> usize {
         self.npc_tiers.len()
     }
+
+    /// Bring population statistics up to date after a tick.
+    ///
+    /// Re-reads NPCs updated at the world's current tick; with `full` set,
+    /// rebuilds every aggregate instead.
+    pub fn refresh_world_stats(&mut self, world: &WorldState, full: bool) {
+        if full {
+            self.stats.rebuild(world);
+            return;
+        }
+        self.stats.reconcile_membership(world);
+        for (id, tick) in &self.last_update_tick {
+            if *tick == world.current_tick {
+                self.stats.refresh_npc(world, *id);
+            }
+        }
+    }
+
+    /// Current population statistics.
+    ///
+    /// NPCs added or removed since the last tick are counted; other changes
+    /// made outside the tick loop show up after the affected NPC's next
+    /// update or the daily rebuild.
+    pub fn world_stats(&mut self, world: &WorldState) -> WorldStats {
+        self.stats.reconcile_membership(world);
+        let mut tier_counts = [0u32; 3];
+        for id in world.npcs.keys().filter(|id| **id != world.player_id) {
+            tier_counts[self.npc_tier(*id) as usize] += 1;
+        }
+        self.stats.stats(world, tier_counts)
+    }
 }
 
 /// Minimal simulated NPC container used by the simulator.
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct SimulatedNpc {
     pub id: NpcId,
     pub abstract_npc: AbstractNpc,
@@ -304,6 +345,11 @@ impl Simulator {
     /// Assign LOD tiers based on proximity/relevance to player.
     pub fn update_lod_tiers(&mut self, world: &WorldState, player_id: NpcId) {
         for (npc_id, simulated_npc) in &mut self.active_npcs {
+            // Major characters always run at full fidelity
+            if syn_core::is_major_character(world, *npc_id) {
+                simulated_npc.lod_tier = LodTier::High;
+                continue;
+            }
             // Simple LOD: player's close relations are High, others Medium/Low
             if let Some(rel) = world.relationships.get(&(player_id, *npc_id)) {
                 if rel.affection > 3.0 || rel.trust > 3.0 {
@@ -414,7 +460,9 @@ impl Simulator {
 }
 
 /// Wrap SimulatedNpc with ID + LOD + last_tick for registry use.
-#[derive(Debug)]
+///
+/// Serializable so the registry can be saved with the world.
+#[derive(Debug, Serialize, Deserialize)]
 pub struct NpcInstance {
     pub id: NpcId,
     pub lod: NpcLod,
@@ -926,7 +974,7 @@ pub fn build_action_instance_from_behavior_and_schedule(
 }
 
 /// New canonical LOD tiers for world ticking.
-#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
 pub enum NpcLodTier {
     /// Full AI, behavior, relationships, storylets.
     Tier1Active,
@@ -957,13 +1005,22 @@ pub struct SimState {
     pub npc_registry: crate::npc_registry::NpcRegistry,
     /// Dormant Tier3 population.
     pub population: PopulationStore,
-    /// Unified hot/cold storage backend.
-    pub storage: HybridStorage,
+    /// Unified hot/cold storage backend. Writes are queued and reach disk
+    /// at tick boundaries (see [`tick_world`]) or when the game is saved.
+    pub storage: WriteBehindStorage,
 }
 
 impl SimState {
+    /// Create a SimState with its storage in the shared `data` directory.
+    ///
+    /// Test builds (and the `test-utils` feature) use a fresh temp directory
+    /// instead, so test runs never write into the source tree.
     pub fn new() -> Self {
-        let storage = init_default_storage().expect("failed to initialize hybrid storage");
+        #[cfg(any(test, feature = "test-utils"))]
+        let storage = init_temp_storage();
+        #[cfg(not(any(test, feature = "test-utils")))]
+        let storage = init_default_storage();
+        let storage = WriteBehindStorage::new(storage.expect("failed to initialize hybrid storage"));
         Self {
             npc_registry: crate::npc_registry::NpcRegistry::default(),
             population: PopulationStore::default(),
@@ -971,11 +1028,23 @@ impl SimState {
         }
     }
 
+    /// Create a SimState with its storage under `data_dir`, e.g. one
+    /// profile's storage directory.
+    pub fn new_in(data_dir: impl AsRef<Path>) -> Result<Self, StorageError> {
+        let storage = WriteBehindStorage::new(init_storage_in(data_dir.as_ref())?);
+        Ok(Self {
+            npc_registry: crate::npc_registry::NpcRegistry::default(),
+            population: PopulationStore::default(),
+            storage,
+        })
+    }
+
     /// Create a SimState with temporary storage for testing.
     /// Uses unique paths based on thread ID and timestamp to avoid conflicts.
     #[cfg(any(test, feature = "test-utils"))]
     pub fn new_for_test() -> Self {
         let storage = init_temp_storage().expect("failed to initialize temp storage");
+        let storage = WriteBehindStorage::new(storage);
         Self {
             npc_registry: crate::npc_registry::NpcRegistry::default(),
             population: PopulationStore::default(),
@@ -983,11 +1052,35 @@ impl SimState {
         }
     }
 
-    pub fn save_active_npc(&self, npc: &StorageNpc) -> Result<(), StorageError> {
+    /// Save the world together with the live NPC instances.
+    ///
+    /// Behavior snapshots, busy timers and current activities are kept, so
+    /// NPCs carry on with what they were doing after [`SimState::load_game`].
+    /// Queued storage writes are flushed first.
+    pub fn save_game(&mut self, db: &mut Persistence, world: &WorldState) -> Result<(), SynError> {
+        self.storage
+            .flush()
+            .map_err(|e| SynError::PersistenceError(e.to_string()))?;
+        db.save_world(world)
+            .map_err(|e| SynError::PersistenceError(e.to_string()))?;
+        self.npc_registry.save(db, world.seed)
+    }
+
+    /// Load a world saved with [`SimState::save_game`] and restore its live
+    /// NPC instances. Saves without instances load with an empty registry.
+    pub fn load_game(&mut self, db: &mut Persistence, seed: WorldSeed) -> Result<WorldState, SynError> {
+        let world = db
+            .load_world(seed)
+            .map_err(|e| SynError::PersistenceError(e.to_string()))?;
+        self.npc_registry = crate::npc_registry::NpcRegistry::load(db, seed)?;
+        Ok(world)
+    }
+
+    pub fn save_active_npc(&mut self, npc: &StorageNpc) -> Result<(), StorageError> {
         self.storage.save_active(npc)
     }
 
-    pub fn save_dormant_npc(&self, npc: &StorageNpc) -> Result<(), StorageError> {
+    pub fn save_dormant_npc(&mut self, npc: &StorageNpc) -> Result<(), StorageError> {
         self.storage.save_dormant(npc)
     }
 
@@ -1040,8 +1133,13 @@ impl SimState {
 /// Atomic counter for unique storage instance IDs within a process
 static STORAGE_INSTANCE_COUNTER: std::sync::atomic::AtomicU32 = std::sync::atomic::AtomicU32::new(0);
 
+#[cfg(not(any(test, feature = "test-utils")))]
 fn init_default_storage() -> Result<HybridStorage, StorageError> {
-    let data_dir = Path::new("data");
+    init_storage_in(Path::new("data"))
+}
+
+/// Hot/cold storage under `data_dir`, with a hot database per SimState.
+fn init_storage_in(data_dir: &Path) -> Result<HybridStorage, StorageError> {
     let _ = fs::create_dir_all(data_dir);
     
     // Use PID + instance counter for unique database per SimState
@@ -1261,6 +1359,14 @@ pub fn tick_world(world: &mut WorldState, sim: &mut SimState, ticks: u32) {
 
         // 5) LOD transitions
         tick_lod_transitions(world, sim);
+
+        // 6) Write queued storage writes at the tick boundary. A failed
+        // batch stays queued for the next tick and is counted in the
+        // storage metrics, so the tick carries on.
+        let _ = sim.storage.end_tick(world.current_tick.0);
+
+        // 7) Snap float accumulators to the grid in quantized mode
+        syn_core::det_math::quantize_accumulators(world);
     }
 }
 
@@ -1271,6 +1377,8 @@ pub struct SimulationTickConfig {
     pub tier_config: TierUpdateConfig,
     /// Configuration for per-tier NPC update frequencies.
     pub npc_update_config: NpcUpdateConfig,
+    /// Configuration for mood contagion between closely bonded characters.
+    pub mood_contagion: MoodContagionConfig,
 }
 
 impl Default for SimulationTickConfig {
@@ -1278,6 +1386,7 @@ impl Default for SimulationTickConfig {
         Self {
             tier_config: TierUpdateConfig::default(),
             npc_update_config: NpcUpdateConfig::default(),
+            mood_contagion: MoodContagionConfig::default(),
         }
     }
 }
@@ -1299,7 +1408,9 @@ pub struct SimulationTickResult {
 /// 1. Advance world time
 /// 2. Tier reassignment (promotion/demotion of NPCs)
 /// 3. Per-tier NPC updates (stats, relationships)
-/// 4. [Director step would go here - caller can invoke separately]
+/// 4. Mood contagion between close/devoted pairs (mid-frequency ticks)
+/// 5. Population statistics refresh for NPCs updated this tick
+/// 6. [Director step would go here - caller can invoke separately]
 ///
 /// The director step is intentionally left out of this function to maintain
 /// separation of concerns. Callers should invoke the director after this
@@ -1327,6 +1438,17 @@ pub fn tick_simulation(
     // 2. Per-tier NPC updates with separate RNG stream
     let mut rng_updates = DeterministicRng::with_domain(world_seed, current_tick.0, "npc_updates");
     systems::update_npcs_for_tick(world, sim_state, &config.npc_update_config, &mut rng_updates);
+
+    // 3. Mood contagion (deterministic: relationships are visited in id order)
+    if is_mid_frequency_tick(&world.game_time) {
+        tick_mood_contagion(world, &config.mood_contagion);
+    }
+
+    // 4. Population statistics (full rebuild once a day)
+    sim_state.refresh_world_stats(world, is_low_frequency_tick(&world.game_time));
+
+    // 5. Snap float accumulators to the grid in quantized mode
+    syn_core::det_math::quantize_accumulators(world);
     
     // Return result - caller should invoke director with updated state
     SimulationTickResult {
@@ -0,0 +1,170 @@
+//! Emotional contagion between closely bonded characters.
+//!
+//! Each mid-frequency tick, characters in a strong affection band (Close or
+//! Devoted) toward someone absorb a fraction of that person's recent mood
+//! change. "Recent" means since the previous contagion pass, tracked through
+//! the baselines in [`syn_core::NpcMoods`].
+//!
+//! Transfers are summed per receiver and clamped, then applied after every
+//! baseline has been advanced. That means a received shift counts as the
+//! receiver's own change on the next pass, so a crisis ripples outward through
+//! a circle of friends and fades with each hop.
+
+use std::collections::BTreeMap;
+use syn_core::relationship_model::{AffectionBand, RelationshipVector};
+use syn_core::{mood_of, shift_mood, NpcId, WorldState};
+
+/// Tuning for the contagion pass.
+#[derive(Debug, Clone)]
+pub struct MoodContagionConfig {
+    /// Disable to keep moods isolated per character.
+    pub enabled: bool,
+    /// Share of a Close partner's mood change passed on.
+    pub close_fraction: f32,
+    /// Share of a Devoted partner's mood change passed on.
+    pub devoted_fraction: f32,
+    /// Largest total shift a character can receive in one pass.
+    pub max_transfer: f32,
+    /// Mood changes smaller than this are not contagious.
+    pub min_delta: f32,
+}
+
+impl Default for MoodContagionConfig {
+    fn default() -> Self {
+        Self {
+            enabled: true,
+            close_fraction: 0.15,
+            devoted_fraction: 0.3,
+            max_transfer: 1.5,
+            min_delta: 0.25,
+        }
+    }
+}
+
+impl MoodContagionConfig {
+    /// Fraction shared for an affection band, or `None` if the band is too weak.
+    pub fn fraction_for(&self, band: AffectionBand) -> Option<f32> {
+        match band {
+            AffectionBand::Close => Some(self.close_fraction),
+            AffectionBand::Devoted => Some(self.devoted_fraction),
+            AffectionBand::Stranger | AffectionBand::Acquaintance | AffectionBand::Friendly => None,
+        }
+    }
+}
+
+/// Run one contagion pass, returning the mood shift applied per receiver.
+///
+/// Relationships are visited in `(from, to)` order, where `from` is the one
+/// who feels the bond and therefore absorbs `to`'s mood change.
+pub fn tick_mood_contagion(
+    world: &mut WorldState,
+    config: &MoodContagionConfig,
+) -> BTreeMap<NpcId, f32> {
+    let mut transfers: BTreeMap<NpcId, f32> = BTreeMap::new();
+    if !config.enabled {
+        return transfers;
+    }
+
+    let mut bonds: Vec<(NpcId, NpcId, f32)> = world
+        .relationships
+        .iter()
+        .filter(|((from, to), _)| from != to)
+        .filter_map(|(&(from, to), rel)| {
+            let band = RelationshipVector {
+                affection: rel.affection,
+                trust: rel.trust,
+                attraction: rel.attraction,
+                familiarity: rel.familiarity,
+                resentment: rel.resentment,
+            }
+            .affection_band();
+            config
+                .fraction_for(band)
+                .map(|fraction| (from, to, fraction))
+        })
+        .collect();
+    bonds.sort_by_key(|&(from, to, _)| (from, to));
+
+    for &(receiver, source, fraction) in &bonds {
+        let change = mood_of(world, source) - world.npc_moods.baseline(source);
+        if change.abs() < config.min_delta {
+            continue;
+        }
+        *transfers.entry(receiver).or_insert(0.0) += change * fraction;
+    }
+
+    // Advance baselines before applying transfers so received shifts are
+    // passed on (attenuated) during the next pass.
+    let mut observed: Vec<NpcId> = world.npc_moods.iter().map(|(id, _)| id).collect();
+    observed.push(world.player_id);
+    observed.extend(bonds.iter().map(|&(_, source, _)| source));
+    observed.sort();
+    observed.dedup();
+    for id in observed {
+        let mood = mood_of(world, id);
+        world.npc_moods.set_baseline(id, mood);
+    }
+
+    transfers.retain(|_, shift| *shift != 0.0);
+    for (id, shift) in transfers.iter_mut() {
+        *shift = shift.clamp(-config.max_transfer, config.max_transfer);
+        shift_mood(world, *id, *shift);
+    }
+    transfers
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use syn_core::{Relationship, WorldSeed};
+
+    fn bond(world: &mut WorldState, from: u64, to: u64, affection: f32) {
+        world.set_relationship(
+            NpcId(from),
+            NpcId(to),
+            Relationship {
+                affection,
+                ..Default::default()
+            },
+        );
+    }
+
+    #[test]
+    fn crisis_spreads_to_close_circle_only() {
+        let mut world = WorldState::new(WorldSeed(7), NpcId(1));
+        bond(&mut world, 3, 2, 6.0); // Close
+        bond(&mut world, 4, 2, 9.0); // Devoted
+        bond(&mut world, 5, 2, 2.0); // Friendly: unaffected
+        world.npc_moods.set(NpcId(2), -8.0);
+
+        let transfers = tick_mood_contagion(&mut world, &MoodContagionConfig::default());
+
+        assert!((world.npc_moods.get(NpcId(3)) + 1.2).abs() < 1e-4);
+        assert!(
+            (world.npc_moods.get(NpcId(4)) + 1.5).abs() < 1e-4,
+            "clamped"
+        );
+        assert_eq!(world.npc_moods.get(NpcId(5)), 0.0);
+        assert_eq!(transfers.len(), 2);
+
+        // The source's change is consumed; the receivers' shifts cascade next.
+        bond(&mut world, 6, 3, 6.0);
+        tick_mood_contagion(&mut world, &MoodContagionConfig::default());
+        assert!(world.npc_moods.get(NpcId(6)) < 0.0);
+        assert!((world.npc_moods.get(NpcId(3)) + 1.2).abs() < 1e-4);
+    }
+
+    #[test]
+    fn disabled_config_keeps_moods_isolated() {
+        let mut world = WorldState::new(WorldSeed(7), NpcId(1));
+        bond(&mut world, 3, 2, 9.0);
+        world.npc_moods.set(NpcId(2), -8.0);
+        let config = MoodContagionConfig {
+            enabled: false,
+            ..Default::default()
+        };
+
+        assert!(tick_mood_contagion(&mut world, &config).is_empty());
+        assert_eq!(world.npc_moods.get(NpcId(3)), 0.0);
+    }
+}
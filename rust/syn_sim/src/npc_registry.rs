@@ -1,7 +1,7 @@
 use std::collections::HashMap;
 
 use syn_core::WorldState;
-use syn_core::NpcId;
+use syn_core::{NpcId, NpcInstanceRecord, Persistence, SynError, WorldSeed};
 use crate::{NpcInstance, NpcLod, NpcLodTier, instantiate_simulated_npc_from_prototype};
 
 #[derive(Debug, Default)]
@@ -73,6 +73,44 @@ impl NpcRegistry {
         self.ensure_npc_instance(world, id, NpcLod::Tier2Active, tick);
     }
 
+    /// Serialize every instance for the save file, ordered by NPC id.
+    pub fn to_records(&self) -> Result<Vec<NpcInstanceRecord>, SynError> {
+        let mut ids: Vec<NpcId> = self.instances.keys().copied().collect();
+        ids.sort_unstable();
+        ids.into_iter()
+            .map(|id| {
+                let json_data = serde_json::to_string(&self.instances[&id])
+                    .map_err(|e| SynError::PersistenceError(e.to_string()))?;
+                Ok(NpcInstanceRecord { npc_id: id.0, json_data })
+            })
+            .collect()
+    }
+
+    /// Rebuild a registry from records written by [`NpcRegistry::to_records`].
+    pub fn from_records(records: &[NpcInstanceRecord]) -> Result<Self, SynError> {
+        let mut registry = NpcRegistry::default();
+        for record in records {
+            let instance: NpcInstance = serde_json::from_str(&record.json_data)
+                .map_err(|e| SynError::PersistenceError(e.to_string()))?;
+            registry.instances.insert(instance.id, instance);
+        }
+        Ok(registry)
+    }
+
+    /// Save every instance alongside the world with this seed.
+    pub fn save(&self, db: &mut Persistence, seed: WorldSeed) -> Result<(), SynError> {
+        db.save_npc_instance_records(seed, &self.to_records()?)
+            .map_err(|e| SynError::PersistenceError(e.to_string()))
+    }
+
+    /// Load the instances saved for this seed (empty if none were saved).
+    pub fn load(db: &mut Persistence, seed: WorldSeed) -> Result<Self, SynError> {
+        let records = db
+            .load_npc_instance_records(seed)
+            .map_err(|e| SynError::PersistenceError(e.to_string()))?;
+        Self::from_records(&records)
+    }
+
     pub fn background_npc(&mut self, id: NpcId) {
         if let Some(inst) = self.instances.get_mut(&id) {
             inst.lod = NpcLod::Tier1Neighborhood;
@@ -1,5 +1,8 @@
-use syn_core::relationship_model::RelationshipVector;
-use syn_core::WorldState;
+use std::collections::HashSet;
+
+use syn_core::relationship_model::{RelationshipRole, RelationshipVector};
+use syn_core::relationship_pressure::RelationshipBandSnapshot;
+use syn_core::{NpcId, Relationship, WorldEvent, WorldState};
 
 #[derive(Debug, Clone)]
 pub struct RelationshipDriftConfig {
@@ -20,30 +23,56 @@ impl RelationshipDriftSystem {
     }
 
     pub fn tick(&self, world: &mut WorldState) {
-        for ((actor_id, target_id), rel) in world.relationships.iter_mut() {
-            rel.affection = drift_toward_zero(rel.affection, self.config.affection_decay_per_tick);
-            rel.trust = drift_toward_zero(rel.trust, self.config.trust_decay_per_tick);
-            rel.resentment =
-                drift_toward_zero(rel.resentment, self.config.resentment_decay_per_tick);
-            rel.familiarity = clamp_axis(rel.familiarity + self.config.familiarity_growth_per_tick);
-
-            let snapshot = RelationshipVector {
-                affection: rel.affection,
-                trust: rel.trust,
-                attraction: rel.attraction,
-                familiarity: rel.familiarity,
-                resentment: rel.resentment,
+        // Bonds with major characters fade more slowly.
+        let majors = syn_core::major_character_ids(world);
+        let major_factor = world.importance.config.decay_factor;
+
+        // The drift math is per-pair and order-independent, so it runs over
+        // columns in hash order; only the tracker pass below needs sorting.
+        let mut batch = DriftBatch::with_capacity(world.relationships.len());
+        for (&(actor_id, target_id), rel) in &world.relationships {
+            let factor = if majors.contains(&actor_id) || majors.contains(&target_id) {
+                major_factor
+            } else {
+                1.0
             };
+            batch.push((actor_id, target_id), rel, factor);
+        }
+        let changed = batch.apply(&self.config);
+
+        // Unmodified map, so `iter_mut` visits pairs in the same order as `iter`.
+        for (index, rel) in world.relationships.values_mut().enumerate() {
+            batch.write_back(index, rel);
+        }
+
+        // Pairs sitting at equilibrium whose trackers already match them would
+        // only re-record the same bands and role, so they are skipped.
+        let pending: HashSet<(u64, u64)> = world
+            .relationship_milestones
+            .pending
+            .iter()
+            .map(|p| (p.actor_id, p.target_id))
+            .collect();
+        let mut touched: Vec<usize> = (0..batch.len())
+            .filter(|&index| changed[index] || !trackers_in_sync(world, &pending, &batch, index))
+            .collect();
+
+        // Pair order decides pressure/milestone queue order, so walk pairs sorted.
+        touched.sort_unstable_by_key(|&index| batch.keys[index]);
+        let tick = world.current_tick.0;
+        for index in touched {
+            let (actor_id, target_id) = batch.keys[index];
+            let snapshot = batch.vector(index);
 
             world.relationship_pressure.update_for_pair(
                 actor_id.0,
                 target_id.0,
                 &snapshot,
                 Some("drift".to_string()),
-                Some(world.current_tick.0),
+                Some(tick),
             );
 
-            world
+            let milestone = world
                 .relationship_milestones
                 .evaluate_and_record_milestones_for_pair(
                     actor_id.0,
@@ -51,23 +80,144 @@ impl RelationshipDriftSystem {
                     &snapshot,
                     &[],
                     Some("drift".to_string()),
-                    Some(world.current_tick.0),
+                    Some(tick),
                 );
+            if let Some(milestone) = &milestone {
+                syn_core::publish_world_event(world, WorldEvent::from(milestone));
+            }
         }
     }
 }
 
-fn drift_toward_zero(value: f32, amount: f32) -> f32 {
-    if amount <= 0.0 {
-        return value;
+/// Struct-of-arrays copy of the relationship map for one drift pass.
+///
+/// Each axis is a contiguous column so the drift loops compile to straight
+/// vector arithmetic instead of hopping between hash map entries.
+#[derive(Debug)]
+struct DriftBatch {
+    keys: Vec<(NpcId, NpcId)>,
+    factor: Vec<f32>,
+    affection: Vec<f32>,
+    trust: Vec<f32>,
+    attraction: Vec<f32>,
+    familiarity: Vec<f32>,
+    resentment: Vec<f32>,
+}
+
+impl DriftBatch {
+    fn with_capacity(capacity: usize) -> Self {
+        Self {
+            keys: Vec::with_capacity(capacity),
+            factor: Vec::with_capacity(capacity),
+            affection: Vec::with_capacity(capacity),
+            trust: Vec::with_capacity(capacity),
+            attraction: Vec::with_capacity(capacity),
+            familiarity: Vec::with_capacity(capacity),
+            resentment: Vec::with_capacity(capacity),
+        }
+    }
+
+    fn len(&self) -> usize {
+        self.keys.len()
     }
-    if value > 0.0 {
-        (value - amount).max(0.0)
-    } else if value < 0.0 {
-        (value + amount).min(0.0)
-    } else {
-        0.0
+
+    fn push(&mut self, key: (NpcId, NpcId), rel: &Relationship, factor: f32) {
+        self.keys.push(key);
+        self.factor.push(factor);
+        self.affection.push(rel.affection);
+        self.trust.push(rel.trust);
+        self.attraction.push(rel.attraction);
+        self.familiarity.push(rel.familiarity);
+        self.resentment.push(rel.resentment);
     }
+
+    /// Drift every column in place; returns which pairs moved.
+    fn apply(&mut self, config: &RelationshipDriftConfig) -> Vec<bool> {
+        let mut changed = vec![false; self.len()];
+        drift_column(
+            &mut self.affection,
+            &self.factor,
+            config.affection_decay_per_tick,
+            &mut changed,
+        );
+        drift_column(
+            &mut self.trust,
+            &self.factor,
+            config.trust_decay_per_tick,
+            &mut changed,
+        );
+        drift_column(
+            &mut self.resentment,
+            &self.factor,
+            config.resentment_decay_per_tick,
+            &mut changed,
+        );
+        let growth = config.familiarity_growth_per_tick;
+        for (value, moved) in self.familiarity.iter_mut().zip(changed.iter_mut()) {
+            let next = clamp_axis(*value + growth);
+            *moved |= next.to_bits() != value.to_bits();
+            *value = next;
+        }
+        changed
+    }
+
+    fn write_back(&self, index: usize, rel: &mut Relationship) {
+        rel.affection = self.affection[index];
+        rel.trust = self.trust[index];
+        rel.familiarity = self.familiarity[index];
+        rel.resentment = self.resentment[index];
+    }
+
+    fn vector(&self, index: usize) -> RelationshipVector {
+        RelationshipVector {
+            affection: self.affection[index],
+            trust: self.trust[index],
+            attraction: self.attraction[index],
+            familiarity: self.familiarity[index],
+            resentment: self.resentment[index],
+        }
+    }
+}
+
+fn drift_column(values: &mut [f32], factors: &[f32], rate: f32, changed: &mut [bool]) {
+    for ((value, factor), moved) in values.iter_mut().zip(factors).zip(changed.iter_mut()) {
+        let next = drift_toward_zero(*value, rate * factor);
+        *moved |= next.to_bits() != value.to_bits();
+        *value = next;
+    }
+}
+
+/// Whether the pressure and milestone trackers already hold this pair's
+/// current bands and role, so re-evaluating it could not queue anything.
+fn trackers_in_sync(
+    world: &WorldState,
+    pending: &HashSet<(u64, u64)>,
+    batch: &DriftBatch,
+    index: usize,
+) -> bool {
+    let (actor_id, target_id) = batch.keys[index];
+    let key = (actor_id.0, target_id.0);
+    if pending.contains(&key) {
+        return false;
+    }
+    let snapshot = batch.vector(index);
+    let role = world
+        .relationship_milestones
+        .last_role
+        .get(&key)
+        .copied()
+        .unwrap_or(RelationshipRole::Stranger);
+    role == snapshot.role()
+        && world.relationship_pressure.last_bands.get(&key)
+            == Some(&RelationshipBandSnapshot::from_vector(&snapshot))
+}
+
+/// Move `value` toward zero by `amount` without crossing it.
+///
+/// Written as a clamp rather than sign branches so column loops vectorize.
+fn drift_toward_zero(value: f32, amount: f32) -> f32 {
+    let amount = amount.max(0.0);
+    value - value.clamp(-amount, amount)
 }
 
 fn clamp_axis(value: f32) -> f32 {
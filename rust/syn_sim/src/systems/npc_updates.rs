@@ -5,7 +5,9 @@
 //! - Tier1: Updated at configurable intervals (batched).
 //! - Tier2: Updated at longer intervals (coarse).
 
-use syn_core::{DeterministicRng, NpcId, SimTick, StatKind, WorldState};
+use syn_core::relationship_model::RelationshipVector;
+use syn_core::relationship_pressure::RelationshipBandSnapshot;
+use syn_core::{DeterministicRng, NpcId, Relationship, SimTick, StatKind, WorldState};
 
 use crate::{NpcTier, WorldSimState};
 
@@ -70,17 +72,38 @@ pub fn update_relationships_for_npc(
 
     // Apply drift to each relationship
     for key in rel_keys {
+        // Bonds with major characters fade more slowly
+        let factor = syn_core::relationship_decay_factor(world, key.0, key.1);
         if let Some(rel) = world.relationships.get_mut(&key) {
+            let before = band_snapshot(rel);
             // Small drift toward neutral for affection/trust/resentment
-            rel.affection = drift_toward_zero(rel.affection, 0.01);
-            rel.trust = drift_toward_zero(rel.trust, 0.005);
-            rel.resentment = drift_toward_zero(rel.resentment, 0.008);
+            rel.affection = drift_toward_zero(rel.affection, 0.01 * factor);
+            rel.trust = drift_toward_zero(rel.trust, 0.005 * factor);
+            rel.resentment = drift_toward_zero(rel.resentment, 0.008 * factor);
             // Familiarity grows very slowly
             rel.familiarity = (rel.familiarity + 0.001).min(10.0);
+            // Drift is reported to incremental snapshots only once it moves a band
+            if band_snapshot(rel) != before {
+                let tick = world.current_tick.0;
+                world
+                    .relationship_pressure
+                    .stamp_change(key.0 .0, key.1 .0, tick);
+            }
         }
     }
 }
 
+/// Band labels of a relationship, for telling whether drift moved a band.
+fn band_snapshot(rel: &Relationship) -> RelationshipBandSnapshot {
+    RelationshipBandSnapshot::from_vector(&RelationshipVector {
+        affection: rel.affection,
+        trust: rel.trust,
+        attraction: rel.attraction,
+        familiarity: rel.familiarity,
+        resentment: rel.resentment,
+    })
+}
+
 /// Drift a value toward zero by a small amount.
 fn drift_toward_zero(value: f32, amount: f32) -> f32 {
     if value > 0.0 {
@@ -2,7 +2,8 @@
 //!
 //! This module handles the deterministic assignment of NPCs to fidelity tiers
 //! (Tier0, Tier1, Tier2) based on relationship importance, proximity to player,
-//! and active pressure/milestone involvement.
+//! and active pressure/milestone involvement. Major characters are pinned to
+//! Tier0.
 
 use std::cmp::Ordering;
 
@@ -174,7 +175,8 @@ fn collect_npc_scores(
                 proximity_bonus,
                 has_active_events,
                 recency_score,
-                force_tier0: false, // No pinning mechanism yet
+                // Major characters are never culled to the background
+                force_tier0: syn_core::is_major_character(world, npc_id),
             }
         })
         .collect()
@@ -417,6 +419,24 @@ mod tests {
         assert_eq!(sim_state.npc_tier(NpcId(5)), NpcTier::Tier2);
     }
 
+    #[test]
+    fn test_major_characters_pinned_to_tier0() {
+        let mut world = make_test_world();
+        syn_core::mark_major_character(&mut world, NpcId(5), syn_core::ImportanceSource::Authored);
+        let mut sim_state = WorldSimState::new();
+        let config = TierUpdateConfig {
+            max_tier0_npcs: 2,
+            max_tier1_npcs: 2,
+            ..Default::default()
+        };
+        let mut rng = DeterministicRng::new(42);
+
+        update_npc_tiers_for_tick(&world, &mut sim_state, &config, &mut rng);
+
+        // NPC 5 has the lowest importance but is never culled
+        assert_eq!(sim_state.npc_tier(NpcId(5)), NpcTier::Tier0);
+    }
+
     #[test]
     fn test_tier_assignment_is_deterministic() {
         let world = make_test_world();
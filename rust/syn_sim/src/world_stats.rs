@@ -0,0 +1,341 @@
+//! Population-level statistics for the city overview and balancing dashboards.
+//!
+//! [`WorldStatsTracker`] keeps running aggregates (age cohorts, employment per
+//! district, warmth toward the player) keyed by each NPC's last contribution,
+//! so a tick only re-reads the NPCs the tier system actually updated. Membership
+//! changes are reconciled whenever the population size differs, and the whole
+//! tracker is rebuilt once per in-game day to absorb changes made outside the
+//! tick loop (storylet outcomes, player choices).
+
+use std::collections::{BTreeMap, HashMap};
+
+use syn_core::{AgeCohort, NpcId, SimTick, WorldState};
+
+/// Age cohorts in display order.
+pub const AGE_COHORTS: [AgeCohort; 9] = [
+    AgeCohort::Infant,
+    AgeCohort::Child,
+    AgeCohort::Teen,
+    AgeCohort::YoungAdult,
+    AgeCohort::EarlyCareer,
+    AgeCohort::MidCareer,
+    AgeCohort::LateCareer,
+    AgeCohort::PreRetirement,
+    AgeCohort::Retired,
+];
+
+/// Job labels that do not count as employment.
+const NON_EMPLOYED_JOBS: [&str; 5] = ["", "none", "unemployed", "student", "retired"];
+
+fn is_employed(job: &str) -> bool {
+    let job = job.trim();
+    !NON_EMPLOYED_JOBS
+        .iter()
+        .any(|label| job.eq_ignore_ascii_case(label))
+}
+
+/// Employment counts for one district.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct DistrictEmployment {
+    /// NPCs living in the district.
+    pub population: u32,
+    /// Of those, NPCs with a job.
+    pub employed: u32,
+}
+
+impl DistrictEmployment {
+    /// Share of residents with a job (0.0 for an empty district).
+    pub fn employment_rate(&self) -> f32 {
+        if self.population == 0 {
+            0.0
+        } else {
+            self.employed as f32 / self.population as f32
+        }
+    }
+}
+
+/// Aggregate world statistics at one point in time.
+#[derive(Debug, Clone, PartialEq)]
+pub struct WorldStats {
+    /// Tick the statistics were read at.
+    pub tick: SimTick,
+    /// Instantiated NPCs, excluding the player.
+    pub population: u32,
+    /// NPC count per age cohort, in [`AGE_COHORTS`] order.
+    pub age_distribution: Vec<(AgeCohort, u32)>,
+    /// Employment per district, sorted by district name.
+    pub employment_by_district: BTreeMap<String, DistrictEmployment>,
+    /// Mean NPC→player warmth ((affection + trust) / 2, -10..10) over NPCs
+    /// that have a relationship with the player; `None` if none do.
+    pub average_warmth_to_player: Option<f32>,
+    /// NPCs in each fidelity tier (Tier0, Tier1, Tier2).
+    pub tier_counts: [u32; 3],
+    /// Known NPC prototypes that are not instantiated in the world.
+    pub dormant_population: u32,
+}
+
+/// One NPC's share of the running aggregates.
+#[derive(Debug, Clone, PartialEq)]
+struct NpcContribution {
+    cohort: AgeCohort,
+    district: String,
+    employed: bool,
+    warmth: Option<f32>,
+}
+
+impl NpcContribution {
+    fn read(world: &WorldState, id: NpcId) -> Option<Self> {
+        if id == world.player_id {
+            return None;
+        }
+        let npc = world.npcs.get(&id)?;
+        let warmth = world
+            .relationships
+            .get(&(id, world.player_id))
+            .map(|rel| (rel.affection + rel.trust) / 2.0);
+        Some(Self {
+            cohort: AgeCohort::from_age(npc.age),
+            district: npc.district.clone(),
+            employed: is_employed(&npc.job),
+            warmth,
+        })
+    }
+}
+
+/// Incrementally maintained population aggregates.
+#[derive(Debug, Default)]
+pub struct WorldStatsTracker {
+    contributions: HashMap<NpcId, NpcContribution>,
+    age_counts: HashMap<AgeCohort, u32>,
+    employment: BTreeMap<String, DistrictEmployment>,
+    warmth_sum: f64,
+    warmth_count: u32,
+}
+
+impl WorldStatsTracker {
+    /// Create an empty tracker.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Number of NPCs currently counted.
+    pub fn tracked_count(&self) -> usize {
+        self.contributions.len()
+    }
+
+    /// Re-read one NPC, replacing its previous contribution.
+    ///
+    /// NPCs no longer in the world (and the player) are dropped.
+    pub fn refresh_npc(&mut self, world: &WorldState, id: NpcId) {
+        let next = NpcContribution::read(world, id);
+        if self.contributions.get(&id) == next.as_ref() {
+            return;
+        }
+        if let Some(previous) = self.contributions.remove(&id) {
+            self.subtract(&previous);
+        }
+        if let Some(next) = next {
+            self.add(&next);
+            self.contributions.insert(id, next);
+        }
+    }
+
+    /// Add NPCs that joined the world and drop NPCs that left it.
+    ///
+    /// Cheap when nothing changed: the key scan only runs when the counted
+    /// population differs from the world's.
+    pub fn reconcile_membership(&mut self, world: &WorldState) {
+        let expected = world.npcs.len() - usize::from(world.npcs.contains_key(&world.player_id));
+        if expected == self.contributions.len() {
+            return;
+        }
+        let departed: Vec<NpcId> = self
+            .contributions
+            .keys()
+            .filter(|id| !world.npcs.contains_key(id))
+            .copied()
+            .collect();
+        for id in departed {
+            self.refresh_npc(world, id);
+        }
+        let joined: Vec<NpcId> = world
+            .npcs
+            .keys()
+            .filter(|id| **id != world.player_id && !self.contributions.contains_key(id))
+            .copied()
+            .collect();
+        for id in joined {
+            self.refresh_npc(world, id);
+        }
+    }
+
+    /// Rebuild every aggregate from scratch.
+    pub fn rebuild(&mut self, world: &WorldState) {
+        *self = Self::default();
+        for id in world.npcs.keys() {
+            self.refresh_npc(world, *id);
+        }
+    }
+
+    /// Snapshot the aggregates. Tier counts and dormant size come from the caller.
+    pub fn stats(&self, world: &WorldState, tier_counts: [u32; 3]) -> WorldStats {
+        let dormant_population = world
+            .npc_prototypes
+            .keys()
+            .filter(|id| !world.npcs.contains_key(id))
+            .count() as u32;
+
+        WorldStats {
+            tick: world.current_tick,
+            population: self.contributions.len() as u32,
+            age_distribution: AGE_COHORTS
+                .iter()
+                .map(|cohort| (*cohort, self.age_counts.get(cohort).copied().unwrap_or(0)))
+                .collect(),
+            employment_by_district: self.employment.clone(),
+            average_warmth_to_player: (self.warmth_count > 0)
+                .then(|| (self.warmth_sum / self.warmth_count as f64) as f32),
+            tier_counts,
+            dormant_population,
+        }
+    }
+
+    fn add(&mut self, c: &NpcContribution) {
+        *self.age_counts.entry(c.cohort).or_insert(0) += 1;
+        let district = self.employment.entry(c.district.clone()).or_default();
+        district.population += 1;
+        district.employed += u32::from(c.employed);
+        if let Some(warmth) = c.warmth {
+            self.warmth_sum += warmth as f64;
+            self.warmth_count += 1;
+        }
+    }
+
+    fn subtract(&mut self, c: &NpcContribution) {
+        if let Some(count) = self.age_counts.get_mut(&c.cohort) {
+            *count -= 1;
+            if *count == 0 {
+                self.age_counts.remove(&c.cohort);
+            }
+        }
+        if let Some(district) = self.employment.get_mut(&c.district) {
+            district.population -= 1;
+            district.employed -= u32::from(c.employed);
+            if district.population == 0 {
+                self.employment.remove(&c.district);
+            }
+        }
+        if let Some(warmth) = c.warmth {
+            self.warmth_sum -= warmth as f64;
+            self.warmth_count -= 1;
+            if self.warmth_count == 0 {
+                // Drop accumulated float error once nothing is counted.
+                self.warmth_sum = 0.0;
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use syn_core::{AbstractNpc, AttachmentStyle, Relationship, Traits, WorldSeed};
+
+    fn npc(id: u64, age: u32, job: &str, district: &str) -> AbstractNpc {
+        AbstractNpc {
+            id: NpcId(id),
+            age,
+            job: job.to_string(),
+            district: district.to_string(),
+            household_id: id,
+            traits: Traits::default(),
+            seed: id,
+            attachment_style: AttachmentStyle::Secure,
+        }
+    }
+
+    fn world() -> WorldState {
+        let mut world = WorldState::new(WorldSeed(11), NpcId(1));
+        world
+            .npcs
+            .insert(NpcId(1), npc(1, 20, "Student", "Downtown"));
+        world
+            .npcs
+            .insert(NpcId(2), npc(2, 30, "Barista", "Downtown"));
+        world.npcs.insert(NpcId(3), npc(3, 70, "Retired", "Harbor"));
+        world.npcs.insert(NpcId(4), npc(4, 15, "", "Downtown"));
+        world
+    }
+
+    #[test]
+    fn test_aggregates_exclude_player() {
+        let world = world();
+        let mut tracker = WorldStatsTracker::new();
+        tracker.rebuild(&world);
+        let stats = tracker.stats(&world, [0, 0, 3]);
+
+        assert_eq!(stats.population, 3);
+        let count = |cohort| {
+            stats
+                .age_distribution
+                .iter()
+                .find(|(c, _)| *c == cohort)
+                .map(|(_, n)| *n)
+                .unwrap()
+        };
+        assert_eq!(count(AgeCohort::EarlyCareer), 1);
+        assert_eq!(count(AgeCohort::Retired), 1);
+        assert_eq!(count(AgeCohort::Teen), 1);
+        assert_eq!(count(AgeCohort::YoungAdult), 0);
+
+        let downtown = stats.employment_by_district["Downtown"];
+        assert_eq!(downtown.population, 2);
+        assert_eq!(downtown.employed, 1);
+        assert_eq!(stats.employment_by_district["Harbor"].employed, 0);
+        assert_eq!(stats.average_warmth_to_player, None);
+    }
+
+    #[test]
+    fn test_refresh_updates_warmth_incrementally() {
+        let mut world = world();
+        let mut tracker = WorldStatsTracker::new();
+        tracker.rebuild(&world);
+
+        world.relationships.insert(
+            (NpcId(2), NpcId(1)),
+            Relationship {
+                affection: 6.0,
+                trust: 2.0,
+                ..Default::default()
+            },
+        );
+        tracker.refresh_npc(&world, NpcId(2));
+        assert_eq!(
+            tracker.stats(&world, [0; 3]).average_warmth_to_player,
+            Some(4.0)
+        );
+
+        world.npcs.remove(&NpcId(2));
+        tracker.reconcile_membership(&world);
+        let stats = tracker.stats(&world, [0; 3]);
+        assert_eq!(stats.population, 2);
+        assert_eq!(stats.average_warmth_to_player, None);
+        assert_eq!(stats.employment_by_district["Downtown"].population, 1);
+    }
+
+    #[test]
+    fn test_incremental_matches_rebuild() {
+        let mut world = world();
+        let mut tracker = WorldStatsTracker::new();
+        tracker.rebuild(&world);
+
+        world.npcs.insert(NpcId(5), npc(5, 40, "Nurse", "Harbor"));
+        world.npcs.get_mut(&NpcId(3)).unwrap().district = "Downtown".to_string();
+        tracker.reconcile_membership(&world);
+        tracker.refresh_npc(&world, NpcId(3));
+
+        let mut fresh = WorldStatsTracker::new();
+        fresh.rebuild(&world);
+        assert_eq!(tracker.stats(&world, [0; 3]), fresh.stats(&world, [0; 3]));
+    }
+}
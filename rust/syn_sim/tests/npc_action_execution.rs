@@ -33,6 +33,9 @@ fn run_action_applies_effects_and_busy() {
         },
         active_stages: vec![LifeStage::Teen, LifeStage::Adult],
         schedule: Default::default(),
+        home_district: None,
+        tag_affinities: Default::default(),
+        major: false,
     };
     world.npc_prototypes.insert(npc_id, proto);
 
@@ -33,6 +33,9 @@ fn evaluate_behavior_sets_snapshot_for_active_npc() {
         },
         active_stages: vec![LifeStage::Teen, LifeStage::Adult],
         schedule: Default::default(),
+        home_district: None,
+        tag_affinities: Default::default(),
+        major: false,
     };
     world.npc_prototypes.insert(npc_id, proto.clone());
 
@@ -1,6 +1,6 @@
-use syn_core::npc::{NpcPrototype, NpcRoleTag, PersonalityVector};
-use syn_core::{LifeStage, NpcId, Stats, WorldSeed, WorldState};
-use syn_sim::{instantiate_simulated_npc_from_prototype, NpcLod, NpcRegistry};
+use syn_core::npc::{NpcActivityKind, NpcPrototype, NpcRoleTag, PersonalityVector};
+use syn_core::{LifeStage, NpcId, Persistence, Stats, WorldSeed, WorldState};
+use syn_sim::{instantiate_simulated_npc_from_prototype, NpcLod, NpcRegistry, SimState};
 
 fn make_world_with_proto(id: NpcId) -> WorldState {
     let mut world = WorldState::new(WorldSeed(99), NpcId(1));
@@ -19,6 +19,9 @@ fn make_world_with_proto(id: NpcId) -> WorldState {
         base_stats: Stats::default(),
         active_stages: vec![LifeStage::Teen, LifeStage::Adult],
         schedule: Default::default(),
+        home_district: None,
+        tag_affinities: Default::default(),
+        major: false,
     };
     world.npc_prototypes.insert(id, proto);
     world
@@ -38,3 +41,36 @@ fn test_registry_ensure_instance_and_lod() {
     // SimulatedNpc should have baseline stats initialized
     assert_eq!(inst.sim.stats.get(syn_core::StatKind::Mood), 0.0);
 }
+
+#[test]
+fn test_registry_survives_save_and_load() {
+    let npc_id = NpcId(123);
+    let world = make_world_with_proto(npc_id);
+    let mut sim = SimState::new();
+    sim.npc_registry.ensure_npc_instance(&world, npc_id, NpcLod::Tier2Active, 0);
+    {
+        let inst = sim.npc_registry.get_mut(npc_id).unwrap();
+        inst.busy_until_tick = 40;
+        inst.last_tick = 12;
+        inst.current_activity = NpcActivityKind::Work;
+        inst.sim.stats.set(syn_core::StatKind::Mood, 4.5);
+    }
+
+    let db_path = std::env::temp_dir().join(format!("syn_registry_{}.db", std::process::id()));
+    let db_path = db_path.to_str().unwrap();
+    let _ = std::fs::remove_file(db_path);
+    let mut db = Persistence::new(db_path).unwrap();
+    sim.save_game(&mut db, &world).unwrap();
+
+    let mut restored = SimState::new();
+    let loaded = restored.load_game(&mut db, world.seed).unwrap();
+    assert_eq!(loaded.seed, world.seed);
+    let inst = restored.npc_registry.get(npc_id).expect("instance restored");
+    assert_eq!(inst.busy_until_tick, 40);
+    assert_eq!(inst.last_tick, 12);
+    assert_eq!(inst.current_activity, NpcActivityKind::Work);
+    assert_eq!(inst.sim.stats.get(syn_core::StatKind::Mood), 4.5);
+    assert!(matches!(inst.lod, NpcLod::Tier2Active));
+
+    let _ = std::fs::remove_file(db_path);
+}
@@ -40,6 +40,31 @@ fn relationship_drift_moves_values_toward_zero_and_increases_familiarity() {
     assert!(rel.familiarity > 0.0);
 }
 
+#[test]
+fn bonds_with_major_characters_drift_more_slowly() {
+    let mut world = WorldState::new(WorldSeed(1), NpcId(1));
+    let warm = syn_core::Relationship {
+        affection: 5.0,
+        ..Default::default()
+    };
+    world.relationships.insert((NpcId(1), NpcId(2)), warm);
+    world.relationships.insert((NpcId(1), NpcId(3)), warm);
+    syn_core::mark_major_character(&mut world, NpcId(2), syn_core::ImportanceSource::Emergent);
+
+    let system = RelationshipDriftSystem::new(RelationshipDriftConfig {
+        affection_decay_per_tick: 1.0,
+        trust_decay_per_tick: 0.0,
+        resentment_decay_per_tick: 0.0,
+        familiarity_growth_per_tick: 0.0,
+    });
+    system.tick(&mut world);
+
+    let major = world.relationships[&(NpcId(1), NpcId(2))].affection;
+    let other = world.relationships[&(NpcId(1), NpcId(3))].affection;
+    assert!((major - 4.5).abs() < 1e-5, "{}", major);
+    assert!((other - 4.0).abs() < 1e-5, "{}", other);
+}
+
 #[test]
 fn social_utility_increases_with_affection_and_trust() {
     let low = RelationshipVector {
@@ -129,3 +154,77 @@ fn drift_crossing_band_produces_pressure_event() {
         "Expected a relationship pressure event when affection crosses a band due to drift"
     );
 }
+
+#[test]
+fn relationship_drift_events_ignore_insertion_order() {
+    let pairs: Vec<(u64, u64)> = (1..6u64)
+        .flat_map(|a| (1..6u64).filter(move |b| *b != a).map(move |b| (a, b)))
+        .collect();
+    let run = |order: &[(u64, u64)]| {
+        let mut world = WorldState::new(WorldSeed(1), NpcId(1));
+        for &(a, b) in order {
+            let mut rel = syn_core::Relationship {
+                affection: 5.5,
+                trust: 5.5,
+                resentment: 5.5,
+                ..Default::default()
+            };
+            rel.state = rel.compute_next_state();
+            world.relationships.insert((NpcId(a), NpcId(b)), rel);
+        }
+        let system = RelationshipDriftSystem::new(RelationshipDriftConfig {
+            affection_decay_per_tick: 1.0,
+            trust_decay_per_tick: 1.0,
+            resentment_decay_per_tick: 1.0,
+            familiarity_growth_per_tick: 0.0,
+        });
+        system.tick(&mut world);
+        (
+            format!("{:?}", world.relationship_pressure.queue),
+            format!("{:?}", world.relationship_milestones.queue),
+        )
+    };
+
+    let reversed: Vec<(u64, u64)> = pairs.iter().rev().copied().collect();
+    let forward = run(&pairs);
+    assert!(!forward.0.is_empty());
+    assert_eq!(forward, run(&reversed));
+}
+
+#[test]
+fn settled_pairs_are_skipped_once_trackers_have_seen_them() {
+    let mut world = WorldState::new(WorldSeed(3), NpcId(1));
+    let settled = (NpcId(1), NpcId(2));
+    let drifting = (NpcId(1), NpcId(3));
+    world.relationships.insert(
+        settled,
+        syn_core::Relationship {
+            familiarity: 10.0,
+            ..Default::default()
+        },
+    );
+    world.relationships.insert(
+        drifting,
+        syn_core::Relationship {
+            affection: 4.0,
+            ..Default::default()
+        },
+    );
+    world.relationship_milestones.tuning.confirm_ticks = 0;
+
+    let system = RelationshipDriftSystem::new(RelationshipDriftConfig {
+        affection_decay_per_tick: 0.5,
+        trust_decay_per_tick: 0.5,
+        resentment_decay_per_tick: 0.5,
+        familiarity_growth_per_tick: 0.1,
+    });
+    system.tick(&mut world);
+    assert!(world.relationship_pressure.last_bands.contains_key(&(1, 2)));
+
+    world.relationship_pressure.changed_pairs.clear();
+    system.tick(&mut world);
+
+    assert_eq!(world.relationship_pressure.changed_pairs, vec![(1, 3)]);
+    assert!((world.relationships[&settled].familiarity - 10.0).abs() < 1e-5);
+    assert!((world.relationships[&drifting].affection - 3.0).abs() < 1e-5);
+}
@@ -8,6 +8,7 @@ use syn_core::{LifeStage, NpcId, Stats, WorldSeed, WorldState};
 
 #[allow(deprecated)]
 use syn_sim::{tick_world, NpcLodTier, NpcLod, SimState};
+use syn_storage::models::AbstractNpc;
 
 fn make_world_with_proto(id: NpcId) -> WorldState {
     let mut world = WorldState::new(WorldSeed(1234), NpcId(1));
@@ -26,6 +27,9 @@ fn make_world_with_proto(id: NpcId) -> WorldState {
         base_stats: Stats::default(),
         active_stages: vec![LifeStage::Teen, LifeStage::Adult],
         schedule: Default::default(),
+        home_district: None,
+        tag_affinities: Default::default(),
+        major: false,
     };
     world.npc_prototypes.insert(id, proto);
     world
@@ -56,6 +60,9 @@ fn tick_world_advances_time_and_ticks_tiers() {
         base_stats: Stats::default(),
         active_stages: vec![LifeStage::Teen, LifeStage::Adult],
         schedule: Default::default(),
+        home_district: None,
+        tag_affinities: Default::default(),
+        major: false,
     };
     world.npc_prototypes.insert(NpcId(3), proto2);
     sim.npc_registry
@@ -82,3 +89,36 @@ fn tick_world_advances_time_and_ticks_tiers() {
     assert_eq!(n2.last_tick, world.game_time.tick_index);
     assert!(n3.last_tick <= world.game_time.tick_index);
 }
+
+#[test]
+#[allow(deprecated)]
+fn failed_storage_flushes_are_counted_and_retried_next_tick() {
+    let dir = tempfile::tempdir().unwrap();
+    let mut world = WorldState::new(WorldSeed(1234), NpcId(1));
+    let mut sim = SimState::new_in(dir.path()).unwrap();
+    let npc = AbstractNpc {
+        id: 7,
+        age: 30,
+        district: 2,
+        wealth: 10,
+        health: 80.0,
+        seed: 49,
+    };
+    sim.storage.save_active(&npc).unwrap();
+
+    // Every attempt at the first flush (tick 6) fails
+    let attempts = sim.storage.config().max_attempts;
+    sim.storage.storage().fail_next_batches(attempts);
+    tick_world(&mut world, &mut sim, 6);
+    assert_eq!(sim.storage.pending(), 1);
+    assert_eq!(sim.storage.metrics().failed_flushes, 1);
+    assert_eq!(sim.storage.metrics().flushes, 0);
+    assert!(sim.storage.storage().load_active(7).unwrap().is_none());
+
+    // The batch is still queued and goes out at the next tick boundary
+    tick_world(&mut world, &mut sim, 1);
+    assert_eq!(sim.storage.pending(), 0);
+    assert_eq!(sim.storage.metrics().flushes, 1);
+    let stored = sim.storage.storage().load_active(7).unwrap();
+    assert_eq!(stored.map(|npc| npc.wealth), Some(npc.wealth));
+}
@@ -227,6 +227,7 @@ fn test_tier0_npcs_updated_more_frequently() {
             tier1_update_interval: 3,
             tier2_update_interval: 6,
         },
+        ..Default::default()
     };
 
     // Run 12 ticks
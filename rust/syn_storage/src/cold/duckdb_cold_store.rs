@@ -1,10 +1,69 @@
 //! DuckDB-based cold storage for dormant NPCs.
+//!
+//! Archived journals are stored zstd-compressed alongside the journal schema
+//! version they were written with. Databases from before compression keep
+//! their raw JSON rows in `journal_archive_legacy`; each row is recompressed
+//! into the current table the first time it is loaded.
 
-use duckdb::Connection;
+use duckdb::{params_from_iter, Connection, OptionalExt};
 
 use crate::models::AbstractNpc;
 use crate::storage_error::StorageError;
 
+/// zstd level used for archived journals.
+const JOURNAL_COMPRESSION_LEVEL: i32 = 3;
+
+/// Schema version assumed for journals archived before versioning.
+pub const LEGACY_JOURNAL_SCHEMA_VERSION: u32 = 1;
+
+/// Most NPC ids bound in a single batch load query.
+const BATCH_LOAD_CHUNK: usize = 512;
+
+/// An archived journal: its serialized JSON and the schema version it was
+/// written with, so the caller can migrate older layouts on load.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ArchivedJournal {
+    /// NPC the journal belongs to.
+    pub npc_id: u64,
+    /// Journal schema version at archive time.
+    pub schema_version: u32,
+    /// Journal JSON.
+    pub json: String,
+}
+
+/// Create the journal archive table, moving a pre-compression table aside.
+fn migrate_journal_archive(conn: &Connection) -> Result<(), StorageError> {
+    let legacy_columns: i64 = conn.query_row(
+        "SELECT count(*) FROM information_schema.columns
+         WHERE table_name = 'journal_archive' AND column_name = 'journal_json'",
+        [],
+        |row| row.get(0),
+    )?;
+    if legacy_columns > 0 {
+        conn.execute_batch("ALTER TABLE journal_archive RENAME TO journal_archive_legacy")?;
+    }
+    conn.execute(
+        "CREATE TABLE IF NOT EXISTS journal_archive (
+            npc_id BIGINT PRIMARY KEY,
+            schema_version INTEGER NOT NULL,
+            journal_zstd BLOB NOT NULL,
+            archived_at TIMESTAMP DEFAULT CURRENT_TIMESTAMP
+        )",
+        [],
+    )?;
+    Ok(())
+}
+
+fn compress_journal(json: &str) -> Result<Vec<u8>, StorageError> {
+    zstd::encode_all(json.as_bytes(), JOURNAL_COMPRESSION_LEVEL).map_err(StorageError::Compression)
+}
+
+fn decompress_journal(bytes: &[u8]) -> Result<String, StorageError> {
+    let raw = zstd::decode_all(bytes).map_err(StorageError::Compression)?;
+    String::from_utf8(raw)
+        .map_err(|e| StorageError::Unknown(format!("archived journal is not UTF-8: {}", e)))
+}
+
 /// Cold storage using DuckDB for dormant NPC data.
 ///
 /// Stores dormant (Tier 3) NPCs in a columnar database optimized
@@ -28,15 +87,7 @@ impl DuckDbColdStore {
             )",
             [],
         )?;
-        // Create journal archive table
-        conn.execute(
-            "CREATE TABLE IF NOT EXISTS journal_archive (
-                npc_id BIGINT PRIMARY KEY,
-                journal_json TEXT NOT NULL,
-                archived_at TIMESTAMP DEFAULT CURRENT_TIMESTAMP
-            )",
-            [],
-        )?;
+        migrate_journal_archive(&conn)?;
         Ok(Self { conn })
     }
 
@@ -57,6 +108,29 @@ impl DuckDbColdStore {
         Ok(())
     }
 
+    /// Insert or update many dormant NPCs in one transaction.
+    pub fn insert_dormants(&self, npcs: &[AbstractNpc]) -> Result<(), StorageError> {
+        let tx = self.conn.unchecked_transaction()?;
+        {
+            let mut stmt = tx.prepare(
+                "INSERT OR REPLACE INTO npc_dormant (id, age, district, wealth, health, seed)
+                 VALUES (?, ?, ?, ?, ?, ?)",
+            )?;
+            for npc in npcs {
+                stmt.execute(duckdb::params![
+                    npc.id as i64,
+                    npc.age as i32,
+                    npc.district as i32,
+                    npc.wealth,
+                    npc.health as f64,
+                    npc.seed as i64
+                ])?;
+            }
+        }
+        tx.commit()?;
+        Ok(())
+    }
+
     /// Load a dormant NPC by ID.
     pub fn load_dormant(&self, id: u64) -> Result<Option<AbstractNpc>, StorageError> {
         let mut stmt = self.conn.prepare(
@@ -78,27 +152,154 @@ impl DuckDbColdStore {
         }
     }
 
-    /// Archive a journal (JSON string) for an NPC.
-    pub fn archive_journal(&self, npc_id: u64, journal_json: &str) -> Result<(), StorageError> {
+    /// Archive a journal (JSON string) for an NPC, replacing any earlier one.
+    pub fn archive_journal(
+        &self,
+        npc_id: u64,
+        schema_version: u32,
+        journal_json: &str,
+    ) -> Result<(), StorageError> {
         self.conn.execute(
-            "INSERT OR REPLACE INTO journal_archive (npc_id, journal_json)
-             VALUES (?, ?)",
-            duckdb::params![npc_id as i64, journal_json],
+            "INSERT OR REPLACE INTO journal_archive (npc_id, schema_version, journal_zstd)
+             VALUES (?, ?, ?)",
+            duckdb::params![
+                npc_id as i64,
+                schema_version,
+                compress_journal(journal_json)?
+            ],
         )?;
         Ok(())
     }
 
+    /// Archive many journals in one transaction.
+    pub fn archive_journals(&self, journals: &[ArchivedJournal]) -> Result<(), StorageError> {
+        let tx = self.conn.unchecked_transaction()?;
+        {
+            let mut stmt = tx.prepare(
+                "INSERT OR REPLACE INTO journal_archive (npc_id, schema_version, journal_zstd)
+                 VALUES (?, ?, ?)",
+            )?;
+            for journal in journals {
+                stmt.execute(duckdb::params![
+                    journal.npc_id as i64,
+                    journal.schema_version,
+                    compress_journal(&journal.json)?
+                ])?;
+            }
+        }
+        tx.commit()?;
+        Ok(())
+    }
+
     /// Load an archived journal for an NPC.
-    pub fn load_archived_journal(&self, npc_id: u64) -> Result<Option<String>, StorageError> {
-        let mut stmt = self.conn.prepare(
-            "SELECT journal_json FROM journal_archive WHERE npc_id = ?",
-        )?;
-        let mut rows = stmt.query([npc_id as i64])?;
-        if let Some(row) = rows.next()? {
-            let json: String = row.get(0)?;
-            Ok(Some(json))
-        } else {
-            Ok(None)
+    pub fn load_archived_journal(
+        &self,
+        npc_id: u64,
+    ) -> Result<Option<ArchivedJournal>, StorageError> {
+        let row: Option<(u32, Vec<u8>)> = self
+            .conn
+            .query_row(
+                "SELECT schema_version, journal_zstd FROM journal_archive WHERE npc_id = ?",
+                [npc_id as i64],
+                |row| Ok((row.get(0)?, row.get(1)?)),
+            )
+            .optional()?;
+        match row {
+            Some((schema_version, bytes)) => Ok(Some(ArchivedJournal {
+                npc_id,
+                schema_version,
+                json: decompress_journal(&bytes)?,
+            })),
+            None => self.migrate_legacy_journal(npc_id),
+        }
+    }
+
+    /// Load the archived journals of many NPCs. NPCs without an archive are
+    /// skipped; results follow the order of `npc_ids`.
+    pub fn load_archived_journals(
+        &self,
+        npc_ids: &[u64],
+    ) -> Result<Vec<ArchivedJournal>, StorageError> {
+        let mut found = std::collections::HashMap::with_capacity(npc_ids.len());
+        for chunk in npc_ids.chunks(BATCH_LOAD_CHUNK) {
+            let placeholders = vec!["?"; chunk.len()].join(", ");
+            let mut stmt = self.conn.prepare(&format!(
+                "SELECT npc_id, schema_version, journal_zstd FROM journal_archive
+                 WHERE npc_id IN ({})",
+                placeholders
+            ))?;
+            let mut rows = stmt.query(params_from_iter(chunk.iter().map(|id| *id as i64)))?;
+            while let Some(row) = rows.next()? {
+                let npc_id: i64 = row.get(0)?;
+                let schema_version: u32 = row.get(1)?;
+                let bytes: Vec<u8> = row.get(2)?;
+                found.insert(
+                    npc_id as u64,
+                    ArchivedJournal {
+                        npc_id: npc_id as u64,
+                        schema_version,
+                        json: decompress_journal(&bytes)?,
+                    },
+                );
+            }
+        }
+
+        let mut journals = Vec::with_capacity(found.len());
+        for &npc_id in npc_ids {
+            match found.remove(&npc_id) {
+                Some(journal) => journals.push(journal),
+                None => journals.extend(self.migrate_legacy_journal(npc_id)?),
+            }
         }
+        Ok(journals)
+    }
+
+    /// Move an NPC's pre-compression journal into the current table.
+    fn migrate_legacy_journal(&self, npc_id: u64) -> Result<Option<ArchivedJournal>, StorageError> {
+        if !self.has_legacy_journals()? {
+            return Ok(None);
+        }
+        let json: Option<String> = self
+            .conn
+            .query_row(
+                "SELECT journal_json FROM journal_archive_legacy WHERE npc_id = ?",
+                [npc_id as i64],
+                |row| row.get(0),
+            )
+            .optional()?;
+        let Some(json) = json else {
+            return Ok(None);
+        };
+        let journal = ArchivedJournal {
+            npc_id,
+            schema_version: LEGACY_JOURNAL_SCHEMA_VERSION,
+            json,
+        };
+        let tx = self.conn.unchecked_transaction()?;
+        tx.execute(
+            "INSERT OR REPLACE INTO journal_archive (npc_id, schema_version, journal_zstd)
+             VALUES (?, ?, ?)",
+            duckdb::params![
+                npc_id as i64,
+                journal.schema_version,
+                compress_journal(&journal.json)?
+            ],
+        )?;
+        tx.execute(
+            "DELETE FROM journal_archive_legacy WHERE npc_id = ?",
+            [npc_id as i64],
+        )?;
+        tx.commit()?;
+        Ok(Some(journal))
+    }
+
+    fn has_legacy_journals(&self) -> Result<bool, StorageError> {
+        let tables: i64 = self.conn.query_row(
+            "SELECT count(*) FROM information_schema.tables
+             WHERE table_name = 'journal_archive_legacy'",
+            [],
+            |row| row.get(0),
+        )?;
+        Ok(tables > 0)
     }
 }
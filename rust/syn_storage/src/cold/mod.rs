@@ -6,4 +6,4 @@
 /// DuckDB-based cold storage implementation.
 pub mod duckdb_cold_store;
 
-pub use duckdb_cold_store::DuckDbColdStore;
+pub use duckdb_cold_store::{ArchivedJournal, DuckDbColdStore, LEGACY_JOURNAL_SCHEMA_VERSION};
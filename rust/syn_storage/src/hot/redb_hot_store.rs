@@ -36,10 +36,31 @@ impl RedbHotStore {
         Ok(())
     }
 
+    /// Store many active NPCs in one write transaction.
+    pub fn put_active_npcs(&self, npcs: &[AbstractNpc]) -> Result<(), StorageError> {
+        let txn = self.db.begin_write().map_err(redb::Error::from)?;
+        {
+            let mut table = txn.open_table(NPC_TABLE).map_err(redb::Error::from)?;
+            for npc in npcs {
+                let npc_bytes = bincode::serialize(npc)?;
+                table
+                    .insert(npc.id, npc_bytes.as_slice())
+                    .map_err(redb::Error::from)?;
+            }
+        }
+        txn.commit().map_err(redb::Error::from)?;
+        Ok(())
+    }
+
     /// Retrieve an active NPC by ID.
     pub fn get_active_npc(&self, id: u64) -> Result<Option<AbstractNpc>, StorageError> {
         let txn = self.db.begin_read().map_err(redb::Error::from)?;
-        let table = txn.open_table(NPC_TABLE).map_err(redb::Error::from)?;
+        let table = match txn.open_table(NPC_TABLE) {
+            Ok(table) => table,
+            // The table is created by the first write
+            Err(redb::TableError::TableDoesNotExist(_)) => return Ok(None),
+            Err(err) => return Err(redb::Error::from(err).into()),
+        };
         let npc_opt = if let Some(value) = table.get(id).map_err(redb::Error::from)? {
             let bytes = value.value();
             let npc: AbstractNpc = bincode::deserialize(bytes)?;
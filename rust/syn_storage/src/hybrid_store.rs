@@ -1,6 +1,6 @@
 //! Hybrid storage combining hot and cold tiers for NPC data.
 
-use crate::cold::DuckDbColdStore;
+use crate::cold::{ArchivedJournal, DuckDbColdStore};
 use crate::hot::RedbHotStore;
 use crate::models::AbstractNpc;
 use crate::storage_error::StorageError;
@@ -12,6 +12,9 @@ use crate::storage_error::StorageError;
 pub struct HybridStorage {
     hot: RedbHotStore,
     cold: DuckDbColdStore,
+    /// Batch writes left to fail (see [`HybridStorage::fail_next_batches`]).
+    #[cfg(feature = "test-utils")]
+    failing_batches: std::sync::atomic::AtomicU32,
 }
 
 impl std::fmt::Debug for HybridStorage {
@@ -29,7 +32,38 @@ impl HybridStorage {
     pub fn new(hot_path: &str, cold_path: &str) -> Result<Self, StorageError> {
         let hot = RedbHotStore::new(hot_path)?;
         let cold = DuckDbColdStore::new(cold_path)?;
-        Ok(Self { hot, cold })
+        Ok(Self {
+            hot,
+            cold,
+            #[cfg(feature = "test-utils")]
+            failing_batches: std::sync::atomic::AtomicU32::new(0),
+        })
+    }
+
+    /// Make the next `count` batch writes fail without touching disk, to test
+    /// how callers recover.
+    #[cfg(feature = "test-utils")]
+    pub fn fail_next_batches(&self, count: u32) {
+        self.failing_batches
+            .store(count, std::sync::atomic::Ordering::SeqCst);
+    }
+
+    /// Use up one failure set by [`Self::fail_next_batches`], if any are left.
+    #[cfg(feature = "test-utils")]
+    fn injected_failure(&self) -> Result<(), StorageError> {
+        use std::sync::atomic::Ordering;
+        match self
+            .failing_batches
+            .fetch_update(Ordering::SeqCst, Ordering::SeqCst, |left| left.checked_sub(1))
+        {
+            Ok(_) => Err(StorageError::Unknown("injected batch write failure".to_string())),
+            Err(_) => Ok(()),
+        }
+    }
+
+    #[cfg(not(feature = "test-utils"))]
+    fn injected_failure(&self) -> Result<(), StorageError> {
+        Ok(())
     }
 
     /// Save an NPC to hot (active) storage.
@@ -47,6 +81,18 @@ impl HybridStorage {
         self.cold.insert_dormant(npc)
     }
 
+    /// Save many NPCs to hot storage in a single transaction.
+    pub fn save_active_batch(&self, npcs: &[AbstractNpc]) -> Result<(), StorageError> {
+        self.injected_failure()?;
+        self.hot.put_active_npcs(npcs)
+    }
+
+    /// Save many NPCs to cold storage in a single transaction.
+    pub fn save_dormant_batch(&self, npcs: &[AbstractNpc]) -> Result<(), StorageError> {
+        self.injected_failure()?;
+        self.cold.insert_dormants(npcs)
+    }
+
     /// Load an NPC from cold (dormant) storage.
     pub fn load_dormant(&self, id: u64) -> Result<Option<AbstractNpc>, StorageError> {
         self.cold.load_dormant(id)
@@ -68,13 +114,37 @@ impl HybridStorage {
         Ok(())
     }
 
-    /// Archive a journal (JSON string) to cold storage.
-    pub fn archive_journal(&self, npc_id: u64, journal_json: &str) -> Result<(), StorageError> {
-        self.cold.archive_journal(npc_id, journal_json)
+    /// Archive a journal (JSON string) to cold storage, compressed, tagged
+    /// with the journal schema version it was written with.
+    pub fn archive_journal(
+        &self,
+        npc_id: u64,
+        schema_version: u32,
+        journal_json: &str,
+    ) -> Result<(), StorageError> {
+        self.cold
+            .archive_journal(npc_id, schema_version, journal_json)
+    }
+
+    /// Archive many journals to cold storage in a single transaction.
+    pub fn archive_journals(&self, journals: &[ArchivedJournal]) -> Result<(), StorageError> {
+        self.injected_failure()?;
+        self.cold.archive_journals(journals)
     }
 
     /// Load an archived journal from cold storage.
-    pub fn load_archived_journal(&self, npc_id: u64) -> Result<Option<String>, StorageError> {
+    pub fn load_archived_journal(
+        &self,
+        npc_id: u64,
+    ) -> Result<Option<ArchivedJournal>, StorageError> {
         self.cold.load_archived_journal(npc_id)
     }
+
+    /// Load the archived journals of many NPCs from cold storage.
+    pub fn load_archived_journals(
+        &self,
+        npc_ids: &[u64],
+    ) -> Result<Vec<ArchivedJournal>, StorageError> {
+        self.cold.load_archived_journals(npc_ids)
+    }
 }
@@ -6,6 +6,8 @@
 //!
 //! The [`HybridStorage`] struct provides a unified API for both tiers,
 //! with promote/demote operations for LOD transitions.
+//! [`WriteBehindStorage`] wraps it with a write-behind queue, so writes made
+//! during a tick reach disk in batches at tick boundaries.
 
 /// Hot storage module (redb-based).
 pub mod hot;
@@ -17,5 +19,9 @@ pub mod models;
 pub mod hybrid_store;
 /// Unified error type for storage operations.
 pub mod storage_error;
+/// Write-behind batching for writes made during ticks.
+pub mod write_behind;
 
+pub use cold::{ArchivedJournal, LEGACY_JOURNAL_SCHEMA_VERSION};
 pub use hybrid_store::HybridStorage;
+pub use write_behind::{WriteBehindConfig, WriteBehindMetrics, WriteBehindStorage};
@@ -14,6 +14,25 @@ pub enum StorageError {
     /// Error during serialization/deserialization.
     #[error("Serialization error: {0}")]
     Serialization(#[from] bincode::Error),
+    /// Error compressing or decompressing an archived journal.
+    #[error("Compression error: {0}")]
+    Compression(#[source] std::io::Error),
+    /// An archived record was written with a newer schema than this build reads.
+    #[error("Unsupported schema version {found} (supported up to {supported})")]
+    UnsupportedSchemaVersion {
+        /// Version stored with the record.
+        found: u32,
+        /// Newest version this build can read.
+        supported: u32,
+    },
+    /// The write-behind queue is full and flushing it to disk failed.
+    #[error("Write queue full ({capacity} pending writes) and flush failed: {source}")]
+    WriteQueueFull {
+        /// Most writes the queue holds.
+        capacity: usize,
+        /// Why the forced flush failed.
+        source: Box<StorageError>,
+    },
     /// Catch-all for other storage errors.
     #[error("Unknown storage error: {0}")]
     Unknown(String),
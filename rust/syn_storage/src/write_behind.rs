@@ -0,0 +1,365 @@
+//! Write-behind batching for writes made during simulation ticks.
+//!
+//! Demotions, NPC saves and journal archives used to hit disk one call at a
+//! time mid-tick. [`WriteBehindStorage`] queues them in memory instead and
+//! writes each tier in a single transaction when a tick ends (every
+//! `flush_interval_ticks`, or sooner once `flush_threshold` writes are
+//! pending). Saves call [`WriteBehindStorage::flush`] to write everything now.
+//!
+//! Queued writes are keyed by NPC, so a later write to the same NPC replaces
+//! the earlier one. Reads check the queue before disk, so callers always see
+//! their own writes.
+//!
+//! A failed batch is retried up to `max_attempts` times. If it still fails,
+//! it stays queued, the failure is counted in [`WriteBehindMetrics`] and the
+//! error is returned; [`WriteBehindStorage::end_tick`] tries again at the
+//! next tick boundary. The queue
+//! holds at most `capacity` writes. When it is full, the next write forces a
+//! flush, and if that fails too the write is rejected with
+//! [`StorageError::WriteQueueFull`].
+
+use std::collections::BTreeMap;
+
+use crate::cold::ArchivedJournal;
+use crate::hybrid_store::HybridStorage;
+use crate::models::AbstractNpc;
+use crate::storage_error::StorageError;
+
+/// Flush cadence, bounds and retries for [`WriteBehindStorage`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct WriteBehindConfig {
+    /// Ticks between flushes at tick boundaries.
+    pub flush_interval_ticks: u64,
+    /// Pending writes that trigger a flush at the next tick boundary, even
+    /// before the interval is up.
+    pub flush_threshold: usize,
+    /// Most pending writes held before a write forces a flush.
+    pub capacity: usize,
+    /// Attempts per batch before a flush gives up until the next one.
+    pub max_attempts: u32,
+}
+
+impl Default for WriteBehindConfig {
+    fn default() -> Self {
+        Self {
+            flush_interval_ticks: 6,
+            flush_threshold: 256,
+            capacity: 4096,
+            max_attempts: 3,
+        }
+    }
+}
+
+/// Counters describing how the write-behind queue has behaved.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct WriteBehindMetrics {
+    /// Writes queued.
+    pub enqueued: u64,
+    /// Writes that replaced a pending write to the same NPC.
+    pub coalesced: u64,
+    /// Writes that reached disk.
+    pub written: u64,
+    /// Flushes that wrote every pending batch.
+    pub flushes: u64,
+    /// Flushes that left a batch queued after running out of attempts.
+    pub failed_flushes: u64,
+    /// Batch attempts repeated after an error.
+    pub retries: u64,
+    /// Flushes forced by a full queue.
+    pub forced_flushes: u64,
+    /// Writes rejected because the queue was full and could not be flushed.
+    pub rejected: u64,
+    /// Most writes pending at once.
+    pub max_pending: usize,
+}
+
+/// [`HybridStorage`] with a write-behind queue in front of it.
+pub struct WriteBehindStorage {
+    storage: HybridStorage,
+    config: WriteBehindConfig,
+    active: BTreeMap<u64, AbstractNpc>,
+    dormant: BTreeMap<u64, AbstractNpc>,
+    journals: BTreeMap<u64, ArchivedJournal>,
+    last_flush_tick: u64,
+    metrics: WriteBehindMetrics,
+}
+
+impl std::fmt::Debug for WriteBehindStorage {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("WriteBehindStorage")
+            .field("config", &self.config)
+            .field("pending", &self.pending())
+            .field("metrics", &self.metrics)
+            .finish()
+    }
+}
+
+impl WriteBehindStorage {
+    /// Wrap `storage` with the default configuration.
+    pub fn new(storage: HybridStorage) -> Self {
+        Self::with_config(storage, WriteBehindConfig::default())
+    }
+
+    /// Wrap `storage` with the given configuration.
+    pub fn with_config(storage: HybridStorage, config: WriteBehindConfig) -> Self {
+        Self {
+            storage,
+            config,
+            active: BTreeMap::new(),
+            dormant: BTreeMap::new(),
+            journals: BTreeMap::new(),
+            last_flush_tick: 0,
+            metrics: WriteBehindMetrics::default(),
+        }
+    }
+
+    /// The underlying storage. Writes made through it bypass the queue.
+    pub fn storage(&self) -> &HybridStorage {
+        &self.storage
+    }
+
+    /// Current configuration.
+    pub fn config(&self) -> &WriteBehindConfig {
+        &self.config
+    }
+
+    /// Queue counters so far.
+    pub fn metrics(&self) -> &WriteBehindMetrics {
+        &self.metrics
+    }
+
+    /// Writes waiting to be flushed.
+    pub fn pending(&self) -> usize {
+        self.active.len() + self.dormant.len() + self.journals.len()
+    }
+
+    /// Queue an NPC for hot (active) storage.
+    pub fn save_active(&mut self, npc: &AbstractNpc) -> Result<(), StorageError> {
+        self.make_room()?;
+        let replaced = self.active.insert(npc.id, npc.clone()).is_some();
+        self.record_enqueue(replaced);
+        Ok(())
+    }
+
+    /// Queue an NPC for cold (dormant) storage.
+    pub fn save_dormant(&mut self, npc: &AbstractNpc) -> Result<(), StorageError> {
+        self.make_room()?;
+        let replaced = self.dormant.insert(npc.id, npc.clone()).is_some();
+        self.record_enqueue(replaced);
+        Ok(())
+    }
+
+    /// Queue a journal archive, replacing any pending archive for the NPC.
+    pub fn archive_journal(
+        &mut self,
+        npc_id: u64,
+        schema_version: u32,
+        journal_json: &str,
+    ) -> Result<(), StorageError> {
+        self.archive_journals(&[ArchivedJournal {
+            npc_id,
+            schema_version,
+            json: journal_json.to_string(),
+        }])
+    }
+
+    /// Queue many journal archives.
+    pub fn archive_journals(&mut self, journals: &[ArchivedJournal]) -> Result<(), StorageError> {
+        for journal in journals {
+            self.make_room()?;
+            let replaced = self.journals.insert(journal.npc_id, journal.clone()).is_some();
+            self.record_enqueue(replaced);
+        }
+        Ok(())
+    }
+
+    /// Load an NPC from hot storage, including pending writes.
+    pub fn load_active(&self, id: u64) -> Result<Option<AbstractNpc>, StorageError> {
+        match self.active.get(&id) {
+            Some(npc) => Ok(Some(npc.clone())),
+            None => self.storage.load_active(id),
+        }
+    }
+
+    /// Load an NPC from cold storage, including pending writes.
+    pub fn load_dormant(&self, id: u64) -> Result<Option<AbstractNpc>, StorageError> {
+        match self.dormant.get(&id) {
+            Some(npc) => Ok(Some(npc.clone())),
+            None => self.storage.load_dormant(id),
+        }
+    }
+
+    /// Load an archived journal, including pending archives.
+    pub fn load_archived_journal(
+        &self,
+        npc_id: u64,
+    ) -> Result<Option<ArchivedJournal>, StorageError> {
+        match self.journals.get(&npc_id) {
+            Some(journal) => Ok(Some(journal.clone())),
+            None => self.storage.load_archived_journal(npc_id),
+        }
+    }
+
+    /// Load the archived journals of many NPCs, including pending archives.
+    /// NPCs without an archive are skipped; results follow the order of
+    /// `npc_ids`.
+    pub fn load_archived_journals(
+        &self,
+        npc_ids: &[u64],
+    ) -> Result<Vec<ArchivedJournal>, StorageError> {
+        let stored: Vec<u64> = npc_ids
+            .iter()
+            .copied()
+            .filter(|id| !self.journals.contains_key(id))
+            .collect();
+        let mut from_disk = self.storage.load_archived_journals(&stored)?.into_iter().peekable();
+        let mut journals = Vec::with_capacity(npc_ids.len());
+        for id in npc_ids {
+            if let Some(journal) = self.journals.get(id) {
+                journals.push(journal.clone());
+            } else if from_disk.peek().is_some_and(|j| j.npc_id == *id) {
+                journals.extend(from_disk.next());
+            }
+        }
+        Ok(journals)
+    }
+
+    /// Promote an NPC from cold to hot storage (dormant → active).
+    pub fn promote(&mut self, id: u64) -> Result<(), StorageError> {
+        if let Some(npc) = self.load_dormant(id)? {
+            self.save_active(&npc)?;
+        }
+        Ok(())
+    }
+
+    /// Demote an NPC from hot to cold storage (active → dormant).
+    pub fn demote(&mut self, id: u64) -> Result<(), StorageError> {
+        if let Some(npc) = self.load_active(id)? {
+            self.save_dormant(&npc)?;
+        }
+        Ok(())
+    }
+
+    /// Mark the end of `tick`, flushing if the interval is up or the
+    /// threshold is reached. Returns how many writes reached disk.
+    ///
+    /// After a failed flush the next tick is due again, so the batches left
+    /// queued are retried then rather than a whole interval later.
+    pub fn end_tick(&mut self, tick: u64) -> Result<usize, StorageError> {
+        let due = tick.saturating_sub(self.last_flush_tick) >= self.config.flush_interval_ticks;
+        if !due && self.pending() < self.config.flush_threshold {
+            return Ok(0);
+        }
+        let written = self.flush()?;
+        self.last_flush_tick = tick;
+        Ok(written)
+    }
+
+    /// Write every pending write to disk now, one transaction per tier.
+    /// Returns how many writes reached disk.
+    ///
+    /// Batches that still fail after `max_attempts` stay queued; the first
+    /// such error is returned once the other batches have been tried.
+    pub fn flush(&mut self) -> Result<usize, StorageError> {
+        if self.pending() == 0 {
+            return Ok(0);
+        }
+        let mut written = 0;
+        let mut first_error = None;
+
+        let active = std::mem::take(&mut self.active);
+        let npcs: Vec<AbstractNpc> = active.values().cloned().collect();
+        match self.write_batch(&npcs, |s, batch| s.save_active_batch(batch)) {
+            Ok(()) => written += npcs.len(),
+            Err(err) => {
+                self.active = active;
+                first_error.get_or_insert(err);
+            }
+        }
+
+        let dormant = std::mem::take(&mut self.dormant);
+        let npcs: Vec<AbstractNpc> = dormant.values().cloned().collect();
+        match self.write_batch(&npcs, |s, batch| s.save_dormant_batch(batch)) {
+            Ok(()) => written += npcs.len(),
+            Err(err) => {
+                self.dormant = dormant;
+                first_error.get_or_insert(err);
+            }
+        }
+
+        let journals = std::mem::take(&mut self.journals);
+        let archived: Vec<ArchivedJournal> = journals.values().cloned().collect();
+        match self.write_batch(&archived, |s, batch| s.archive_journals(batch)) {
+            Ok(()) => written += archived.len(),
+            Err(err) => {
+                self.journals = journals;
+                first_error.get_or_insert(err);
+            }
+        }
+
+        self.metrics.written += written as u64;
+        match first_error {
+            Some(err) => {
+                self.metrics.failed_flushes += 1;
+                Err(err)
+            }
+            None => {
+                self.metrics.flushes += 1;
+                Ok(written)
+            }
+        }
+    }
+
+    /// Write one non-empty batch, retrying up to `max_attempts` times.
+    fn write_batch<T>(
+        &mut self,
+        batch: &[T],
+        write: impl Fn(&HybridStorage, &[T]) -> Result<(), StorageError>,
+    ) -> Result<(), StorageError> {
+        if batch.is_empty() {
+            return Ok(());
+        }
+        let mut attempt = 1;
+        loop {
+            match write(&self.storage, batch) {
+                Ok(()) => return Ok(()),
+                Err(err) if attempt >= self.config.max_attempts => return Err(err),
+                Err(_) => {
+                    attempt += 1;
+                    self.metrics.retries += 1;
+                }
+            }
+        }
+    }
+
+    /// Flush first if the queue is full.
+    fn make_room(&mut self) -> Result<(), StorageError> {
+        if self.pending() < self.config.capacity {
+            return Ok(());
+        }
+        self.metrics.forced_flushes += 1;
+        if let Err(err) = self.flush() {
+            self.metrics.rejected += 1;
+            return Err(StorageError::WriteQueueFull {
+                capacity: self.config.capacity,
+                source: Box::new(err),
+            });
+        }
+        Ok(())
+    }
+
+    fn record_enqueue(&mut self, replaced: bool) {
+        self.metrics.enqueued += 1;
+        if replaced {
+            self.metrics.coalesced += 1;
+        }
+        self.metrics.max_pending = self.metrics.max_pending.max(self.pending());
+    }
+}
+
+impl Drop for WriteBehindStorage {
+    /// Best-effort flush so queued writes are not lost on shutdown.
+    fn drop(&mut self) {
+        let _ = self.flush();
+    }
+}
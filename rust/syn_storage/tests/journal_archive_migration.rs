@@ -0,0 +1,54 @@
+//! Pre-compression journal archives are migrated when they are loaded.
+
+use duckdb::Connection;
+use syn_storage::{HybridStorage, LEGACY_JOURNAL_SCHEMA_VERSION};
+use tempfile::TempDir;
+
+#[test]
+fn legacy_journals_are_recompressed_on_load() {
+    let temp_dir = TempDir::new().unwrap();
+    let hot_path = temp_dir.path().join("test.redb");
+    let cold_path = temp_dir.path().join("test.duckdb");
+    let cold = cold_path.to_str().unwrap();
+
+    // The archive table as it was before compression.
+    {
+        let conn = Connection::open(cold).unwrap();
+        conn.execute_batch(
+            "CREATE TABLE journal_archive (
+                npc_id BIGINT PRIMARY KEY,
+                journal_json TEXT NOT NULL,
+                archived_at TIMESTAMP DEFAULT CURRENT_TIMESTAMP
+            );
+            INSERT INTO journal_archive (npc_id, journal_json)
+            VALUES (1, '{\"legacy\":1}'), (2, '{\"legacy\":2}');",
+        )
+        .unwrap();
+    }
+
+    let storage = HybridStorage::new(hot_path.to_str().unwrap(), cold).unwrap();
+    let one = storage.load_archived_journal(1).unwrap().unwrap();
+    assert_eq!(one.schema_version, LEGACY_JOURNAL_SCHEMA_VERSION);
+    assert_eq!(one.json, "{\"legacy\":1}");
+
+    storage.archive_journal(3, 2, "{\"current\":3}").unwrap();
+    let batch = storage.load_archived_journals(&[3, 2, 1, 4]).unwrap();
+    let ids: Vec<u64> = batch.iter().map(|j| j.npc_id).collect();
+    assert_eq!(ids, vec![3, 2, 1]);
+    assert_eq!(batch[0].schema_version, 2);
+    assert_eq!(batch[1].json, "{\"legacy\":2}");
+    drop(storage);
+
+    // Migrated rows left the legacy table and are stored compressed.
+    let conn = Connection::open(cold).unwrap();
+    let legacy: i64 = conn
+        .query_row("SELECT count(*) FROM journal_archive_legacy", [], |row| {
+            row.get(0)
+        })
+        .unwrap();
+    assert_eq!(legacy, 0);
+    let current: i64 = conn
+        .query_row("SELECT count(*) FROM journal_archive", [], |row| row.get(0))
+        .unwrap();
+    assert_eq!(current, 3);
+}
@@ -0,0 +1,100 @@
+//! Writes made during ticks are queued and reach disk in batches.
+
+use syn_storage::models::AbstractNpc;
+use syn_storage::{HybridStorage, WriteBehindConfig, WriteBehindStorage};
+use tempfile::TempDir;
+
+fn npc(id: u64, wealth: i32) -> AbstractNpc {
+    AbstractNpc {
+        id,
+        age: 30,
+        district: 2,
+        wealth,
+        health: 80.0,
+        seed: id * 7,
+    }
+}
+
+fn open(dir: &TempDir) -> HybridStorage {
+    HybridStorage::new(
+        dir.path().join("hot.redb").to_str().unwrap(),
+        dir.path().join("cold.duckdb").to_str().unwrap(),
+    )
+    .unwrap()
+}
+
+#[test]
+fn writes_wait_for_the_tick_boundary_and_coalesce() {
+    let dir = TempDir::new().unwrap();
+    let config = WriteBehindConfig {
+        flush_interval_ticks: 4,
+        ..Default::default()
+    };
+    let mut storage = WriteBehindStorage::with_config(open(&dir), config);
+
+    storage.save_active(&npc(1, 10)).unwrap();
+    storage.save_active(&npc(1, 25)).unwrap();
+    storage.demote(1).unwrap();
+    storage.archive_journal(1, 2, "{\"entries\":[]}").unwrap();
+
+    // Readers see their own writes before anything reaches disk
+    assert_eq!(storage.pending(), 3);
+    assert_eq!(storage.load_dormant(1).unwrap().unwrap().wealth, 25);
+    assert!(storage.storage().load_active(1).unwrap().is_none());
+    assert_eq!(storage.load_archived_journals(&[2, 1]).unwrap().len(), 1);
+
+    assert_eq!(storage.end_tick(3).unwrap(), 0);
+    assert_eq!(storage.end_tick(4).unwrap(), 3);
+    assert_eq!(storage.pending(), 0);
+    assert_eq!(storage.storage().load_active(1).unwrap().unwrap().wealth, 25);
+    assert_eq!(storage.storage().load_dormant(1).unwrap().unwrap().wealth, 25);
+    let journal = storage.storage().load_archived_journal(1).unwrap().unwrap();
+    assert_eq!(journal.schema_version, 2);
+
+    let metrics = *storage.metrics();
+    assert_eq!(metrics.enqueued, 4);
+    assert_eq!(metrics.coalesced, 1);
+    assert_eq!(metrics.written, 3);
+    assert_eq!(metrics.flushes, 1);
+    assert_eq!(metrics.max_pending, 3);
+}
+
+#[test]
+fn thresholds_and_saves_flush_early() {
+    let dir = TempDir::new().unwrap();
+    let config = WriteBehindConfig {
+        flush_interval_ticks: 1_000,
+        flush_threshold: 3,
+        capacity: 5,
+        ..Default::default()
+    };
+    let mut storage = WriteBehindStorage::with_config(open(&dir), config);
+
+    for id in 1..=3 {
+        storage.save_dormant(&npc(id, 1)).unwrap();
+    }
+    // Past the threshold, the next tick boundary flushes
+    assert_eq!(storage.end_tick(1).unwrap(), 3);
+
+    // A full queue flushes before taking another write
+    for id in 10..=15 {
+        storage.save_active(&npc(id, 1)).unwrap();
+    }
+    assert_eq!(storage.metrics().forced_flushes, 1);
+    assert_eq!(storage.pending(), 1);
+
+    // Explicit flush for saves
+    assert_eq!(storage.flush().unwrap(), 1);
+    assert!(storage.storage().load_active(15).unwrap().is_some());
+}
+
+#[test]
+fn queued_writes_survive_a_drop() {
+    let dir = TempDir::new().unwrap();
+    {
+        let mut storage = WriteBehindStorage::new(open(&dir));
+        storage.save_dormant(&npc(9, 42)).unwrap();
+    }
+    let reopened = open(&dir);
+    assert_eq!(reopened.load_dormant(9).unwrap().unwrap().wealth, 42);
+}
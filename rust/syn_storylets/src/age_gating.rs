@@ -0,0 +1,134 @@
+//! Age-appropriate content gating.
+//!
+//! Life stage prerequisites are opt-in per storylet and easy to forget, so a
+//! storylet tagged for mature content could otherwise fire for a child. This
+//! module maps tags and domains to the minimum life stage they suit. The
+//! director enforces the map on every storylet regardless of its authored
+//! prerequisites, and the validator warns when a storylet is authored for a
+//! stage the map rules out.
+//!
+//! Matching is case-insensitive. Tags are looked up in both tables, so
+//! storylets without a domain are still gated by domain-named tags.
+
+use crate::{LifeStage, StoryDomain, Tag};
+
+/// Tags and the earliest life stage they are appropriate for.
+pub const TAG_MINIMUM_STAGES: &[(&str, LifeStage)] = &[
+    ("adult", LifeStage::YoungAdult),
+    ("explicit", LifeStage::YoungAdult),
+    ("nsfw", LifeStage::YoungAdult),
+    ("sexual", LifeStage::YoungAdult),
+    ("intimacy", LifeStage::YoungAdult),
+    ("gore", LifeStage::YoungAdult),
+    ("gambling", LifeStage::YoungAdult),
+    ("alcohol", LifeStage::Teen),
+    ("drugs", LifeStage::Teen),
+    ("violence", LifeStage::Teen),
+    ("dating", LifeStage::Teen),
+];
+
+/// Domains and the earliest life stage they are appropriate for.
+pub const DOMAIN_MINIMUM_STAGES: &[(StoryDomain, LifeStage)] = &[
+    (StoryDomain::Romance, LifeStage::Teen),
+    (StoryDomain::Addiction, LifeStage::Teen),
+    (StoryDomain::Underworld, LifeStage::Teen),
+];
+
+fn tag_minimum(tag: &str) -> Option<LifeStage> {
+    let from_tags = TAG_MINIMUM_STAGES
+        .iter()
+        .filter(|(name, _)| name.eq_ignore_ascii_case(tag))
+        .map(|(_, stage)| *stage);
+    let from_domains = DOMAIN_MINIMUM_STAGES
+        .iter()
+        .filter(|(domain, _)| domain.as_str().eq_ignore_ascii_case(tag))
+        .map(|(_, stage)| *stage);
+    from_tags.chain(from_domains).max()
+}
+
+/// Earliest life stage content with this domain and these tags may reach,
+/// or `None` if nothing in it is age-restricted.
+pub fn minimum_life_stage<'a>(
+    domain: Option<StoryDomain>,
+    tags: impl IntoIterator<Item = &'a str>,
+) -> Option<LifeStage> {
+    let from_domain = domain.and_then(|domain| {
+        DOMAIN_MINIMUM_STAGES
+            .iter()
+            .find(|(d, _)| *d == domain)
+            .map(|(_, stage)| *stage)
+    });
+    tags.into_iter()
+        .filter_map(tag_minimum)
+        .chain(from_domain)
+        .max()
+}
+
+/// Whether content with this domain and these tags may fire at `stage`.
+pub fn age_appropriate<'a>(
+    stage: LifeStage,
+    domain: Option<StoryDomain>,
+    tags: impl IntoIterator<Item = &'a str>,
+) -> bool {
+    minimum_life_stage(domain, tags).is_none_or(|minimum| stage >= minimum)
+}
+
+/// Authored stages (`life_stage` plus any prerequisite stages) that fall
+/// below the minimum for the storylet's domain and tags, with that minimum.
+pub fn conflicting_stages(
+    domain: StoryDomain,
+    tags: &[Tag],
+    life_stage: LifeStage,
+    prerequisite_stages: Option<&[LifeStage]>,
+) -> Vec<(LifeStage, LifeStage)> {
+    let Some(minimum) = minimum_life_stage(Some(domain), tags.iter().map(|t| t.0.as_str())) else {
+        return Vec::new();
+    };
+    let mut conflicts: Vec<(LifeStage, LifeStage)> = std::iter::once(life_stage)
+        .chain(prerequisite_stages.unwrap_or_default().iter().copied())
+        .filter(|stage| *stage < minimum)
+        .map(|stage| (stage, minimum))
+        .collect();
+    conflicts.sort();
+    conflicts.dedup();
+    conflicts
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn minimum_is_the_strictest_matching_entry() {
+        assert_eq!(minimum_life_stage(None, ["friendship"]), None);
+        assert_eq!(
+            minimum_life_stage(Some(StoryDomain::Romance), ["Sexual"]),
+            Some(LifeStage::YoungAdult)
+        );
+        // Domain-named tags gate storylets that carry no domain.
+        assert_eq!(minimum_life_stage(None, ["romance"]), Some(LifeStage::Teen));
+    }
+
+    #[test]
+    fn stages_below_the_minimum_are_refused() {
+        assert!(!age_appropriate(LifeStage::Child, None, ["alcohol"]));
+        assert!(age_appropriate(LifeStage::Teen, None, ["alcohol"]));
+        assert!(age_appropriate(LifeStage::Digital, None, ["explicit"]));
+        assert!(age_appropriate(
+            LifeStage::Child,
+            Some(StoryDomain::Family),
+            []
+        ));
+    }
+
+    #[test]
+    fn conflicts_list_each_offending_authored_stage_once() {
+        let tags = vec![Tag::new("romance")];
+        let prereq = [LifeStage::Child, LifeStage::Teen, LifeStage::Child];
+        assert_eq!(
+            conflicting_stages(StoryDomain::Romance, &tags, LifeStage::Child, Some(&prereq)),
+            vec![(LifeStage::Child, LifeStage::Teen)]
+        );
+        assert!(conflicting_stages(StoryDomain::Romance, &tags, LifeStage::Teen, None).is_empty());
+    }
+}
@@ -1,16 +1,20 @@
-// Storylet Compiler CLI: compiles JSON storylets into an indexed binary library.
+// Storylet Compiler CLI: compiles JSON storylets into an indexed binary library,
+// or with --check, reports broken cross-references between them.
 
 use clap::Parser;
 use std::path::PathBuf;
 use syn_storylets::compiler::StoryletCompiler;
-use syn_storylets::validation::default_storylet_validator;
+use syn_storylets::errors::StoryletCompileError;
+use syn_storylets::validation::{default_storylet_validator, library_warnings};
 
 #[derive(Parser, Debug)]
 #[command(
     name = "storyletc",
     about = "Compiles JSON storylets into a binary library for SYN",
     long_about = "Recursively loads all .json storylet definitions from INPUT directory, \
-                 validates them, builds indexed structures, and writes a compiled binary to OUTPUT"
+                 validates them, builds indexed structures, and writes a compiled binary to OUTPUT. \
+                 With --check, reports broken cross-references (missing follow-ups and dependencies, \
+                 undeclared roles, lone tags) with file and line instead of compiling"
 )]
 struct Args {
     /// Input directory containing JSON storylet files
@@ -18,8 +22,13 @@ struct Args {
     input: PathBuf,
 
     /// Output path for compiled binary library
-    #[arg(long, short)]
-    output: PathBuf,
+    #[arg(long, short, required_unless_present = "check")]
+    output: Option<PathBuf>,
+
+    /// Check cross-references between storylets and report every broken one
+    /// instead of compiling
+    #[arg(long, default_value_t = false)]
+    check: bool,
 
     /// Print detailed error information
     #[arg(long, default_value_t = false)]
@@ -33,17 +42,26 @@ struct Args {
 fn main() {
     let args = Args::parse();
 
+    // Create compiler with default validator
+    let validator = default_storylet_validator();
+    let compiler = StoryletCompiler::new(validator);
+
+    if args.check {
+        check(&compiler, &args);
+        return;
+    }
+    let Some(output) = args.output.as_deref() else {
+        eprintln!("✗ --output is required unless --check is given");
+        std::process::exit(2);
+    };
+
     if args.verbose {
         println!("SYN Storylet Compiler");
         println!("Input directory:  {}", args.input.display());
-        println!("Output library:   {}", args.output.display());
+        println!("Output library:   {}", output.display());
         println!();
     }
 
-    // Create compiler with default validator
-    let validator = default_storylet_validator();
-    let compiler = StoryletCompiler::new(validator);
-
     // Compile storylets
     if args.verbose {
         println!("Scanning for JSON storylets...");
@@ -60,16 +78,22 @@ fn main() {
                 println!("Writing binary library...");
             }
 
+            for (id, warnings) in library_warnings(&library) {
+                for warning in warnings {
+                    eprintln!("⚠ {}: {}", id.0, warning);
+                }
+            }
+
             // Write library to file
-            match library.write_to_file(&args.output) {
+            match library.write_to_file(output) {
                 Ok(()) => {
                     if args.verbose {
-                        let file_size = std::fs::metadata(&args.output)
+                        let file_size = std::fs::metadata(output)
                             .map(|m| m.len())
                             .unwrap_or(0);
-                        println!("✓ Successfully wrote {} bytes to {}", file_size, args.output.display());
+                        println!("✓ Successfully wrote {} bytes to {}", file_size, output.display());
                     } else {
-                        println!("✓ Compilation successful: {}", args.output.display());
+                        println!("✓ Compilation successful: {}", output.display());
                     }
                 }
                 Err(err) => {
@@ -80,19 +104,55 @@ fn main() {
         }
         Err(errors) => {
             eprintln!("✗ Compilation failed with {} error(s):\n", errors.len());
+            print_errors(&errors, args.pretty_errors);
+            std::process::exit(1);
+        }
+    }
+}
 
-            if args.pretty_errors {
-                for (i, err) in errors.iter().enumerate() {
-                    eprintln!("[Error {}]", i + 1);
-                    eprintln!("{}\n", err);
-                }
-            } else {
-                for err in &errors {
-                    eprintln!("- {}", err);
-                }
-            }
+/// `--check`: report every cross-reference issue, exiting non-zero if any
+/// reference is broken. Lone tags are only warnings.
+fn check(compiler: &StoryletCompiler, args: &Args) {
+    if args.verbose {
+        println!("Checking storylets in {}", args.input.display());
+        println!();
+    }
 
+    let issues = match compiler.check_dir(&args.input) {
+        Ok(issues) => issues,
+        Err(errors) => {
+            eprintln!("✗ Failed to load storylets with {} error(s):\n", errors.len());
+            print_errors(&errors, args.pretty_errors);
             std::process::exit(1);
         }
+    };
+
+    for issue in &issues {
+        let marker = if issue.issue.kind.is_broken() { "✗" } else { "⚠" };
+        eprintln!("{} {}", marker, issue);
+        if let Some(text) = &issue.line_text {
+            eprintln!("    | {}", text);
+        }
+    }
+
+    let broken = issues.iter().filter(|i| i.issue.kind.is_broken()).count();
+    let warnings = issues.len() - broken;
+    if broken > 0 {
+        eprintln!("\n✗ {} broken reference(s), {} warning(s)", broken, warnings);
+        std::process::exit(1);
+    }
+    println!("✓ No broken references ({} warning(s))", warnings);
+}
+
+fn print_errors(errors: &[StoryletCompileError], pretty: bool) {
+    if pretty {
+        for (i, err) in errors.iter().enumerate() {
+            eprintln!("[Error {}]", i + 1);
+            eprintln!("{}\n", err);
+        }
+    } else {
+        for err in errors {
+            eprintln!("- {}", err);
+        }
     }
 }
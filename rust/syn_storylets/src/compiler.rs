@@ -1,12 +1,35 @@
 //! Offline storylet compiler: loads JSON files, validates, and builds indexes.
 
 use crate::library::{CompiledStorylet, ResolvedFollowUp, StoryletKey, StoryletLibrary};
-use crate::validation::{StoryletValidator, validate_storylets};
+use crate::validation::{LibraryIssue, StoryletValidator, validate_library, validate_storylets};
 use crate::{StoryletDef, StoryletId};
 use crate::errors::StoryletCompileError;
+use crate::fixtures::StoryletTestFixture;
 use std::collections::HashMap;
 use std::path::{Path, PathBuf};
 
+/// A [`LibraryIssue`] located in the JSON file it was authored in.
+#[derive(Debug, Clone, PartialEq)]
+pub struct SourceIssue {
+    /// The finding.
+    pub issue: LibraryIssue,
+    /// File defining the storylet.
+    pub path: PathBuf,
+    /// 1-based line of the offending reference, when it could be found.
+    pub line: Option<usize>,
+    /// Text of that line, trimmed.
+    pub line_text: Option<String>,
+}
+
+impl std::fmt::Display for SourceIssue {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self.line {
+            Some(line) => write!(f, "{}:{}: {}", self.path.display(), line, self.issue),
+            None => write!(f, "{}: {}", self.path.display(), self.issue),
+        }
+    }
+}
+
 /// Configuration and execution of storylet compilation.
 #[derive(Debug)]
 pub struct StoryletCompiler {
@@ -22,6 +45,8 @@ impl StoryletCompiler {
     /// Compile all `.json` storylet files from a directory into a library.
     ///
     /// Returns a fully indexed `StoryletLibrary` ready for serialization or runtime use.
+    /// Files are keyed in path order, so the same directory always compiles to the
+    /// same keys.
     /// Reports all errors at once: duplicates, validation failures, missing follow-ups.
     pub fn compile_from_dir<P: AsRef<Path>>(
         &self,
@@ -29,8 +54,10 @@ impl StoryletCompiler {
     ) -> Result<StoryletLibrary, Vec<StoryletCompileError>> {
         let dir = dir.as_ref();
 
-        // Step 1: Load all JSON files
-        let loaded_storylets = self.load_json_files(dir)?;
+        // Step 1: Load all JSON files, in path order so keys don't depend on
+        // the filesystem's directory iteration order
+        let mut loaded_storylets = self.load_json_files(dir)?;
+        loaded_storylets.sort_by(|a, b| a.0.cmp(&b.0));
         if loaded_storylets.is_empty() {
             return Err(vec![StoryletCompileError::NoStorylets {
                 dir: dir.to_path_buf(),
@@ -82,6 +109,64 @@ impl StoryletCompiler {
         self.build_library(&loaded_storylets)
     }
 
+    /// Check the cross-references of a directory of storylets without compiling it.
+    ///
+    /// Runs [`validate_library`] over every storylet and locates each finding
+    /// in its source file. Files that fail to load are reported as errors, as
+    /// in [`Self::compile_from_dir`].
+    pub fn check_dir<P: AsRef<Path>>(
+        &self,
+        dir: P,
+    ) -> Result<Vec<SourceIssue>, Vec<StoryletCompileError>> {
+        let mut loaded_storylets = self.load_json_files(dir.as_ref())?;
+        loaded_storylets.sort_by(|a, b| a.0.cmp(&b.0));
+        let storylets: Vec<_> = loaded_storylets.iter().map(|(_, def)| def.clone()).collect();
+
+        let mut sources: HashMap<PathBuf, String> = HashMap::new();
+        let issues = validate_library(&storylets)
+            .into_iter()
+            .map(|issue| {
+                let path = loaded_storylets
+                    .iter()
+                    .find(|(_, def)| def.id == issue.storylet_id)
+                    .map(|(p, _)| p.clone())
+                    .unwrap_or_else(|| PathBuf::from("unknown"));
+                let source = sources
+                    .entry(path.clone())
+                    .or_insert_with(|| std::fs::read_to_string(&path).unwrap_or_default());
+                let (line, line_text) = match locate_issue(source, &issue) {
+                    Some((line, text)) => (Some(line), Some(text)),
+                    None => (None, None),
+                };
+                SourceIssue {
+                    issue,
+                    path,
+                    line,
+                    line_text,
+                }
+            })
+            .collect();
+
+        Ok(issues)
+    }
+
+    /// Collect the embedded `test_fixture` blocks from a directory of storylets.
+    ///
+    /// Returns `(storylet id, source path, fixture)` for every storylet that
+    /// carries one, sorted by ID. Storylets without a fixture are skipped.
+    pub fn load_test_fixtures<P: AsRef<Path>>(
+        &self,
+        dir: P,
+    ) -> Result<Vec<(StoryletId, PathBuf, StoryletTestFixture)>, Vec<StoryletCompileError>> {
+        let mut fixtures: Vec<_> = self
+            .load_json_files(dir.as_ref())?
+            .into_iter()
+            .filter_map(|(path, def)| def.test_fixture.map(|fixture| (def.id, path, fixture)))
+            .collect();
+        fixtures.sort_by(|a, b| a.0 .0.cmp(&b.0 .0));
+        Ok(fixtures)
+    }
+
     /// Load all `.json` files from a directory.
     fn load_json_files(
         &self,
@@ -258,6 +343,43 @@ impl StoryletCompiler {
     }
 }
 
+/// Find the line an issue's reference was authored on: the first line
+/// quoting it, preferring one that also names the offending field.
+fn locate_issue(source: &str, issue: &LibraryIssue) -> Option<(usize, String)> {
+    let reference = issue.kind.reference();
+    if reference.is_empty() {
+        return None;
+    }
+    let field = issue
+        .location
+        .rsplit('.')
+        .next()
+        .and_then(|last| last.split('[').next())
+        .unwrap_or_default();
+    let quoted_field = format!("\"{}\"", field);
+    let quoted = format!("\"{}\"", reference);
+
+    // Comma-separated role lists only contain the role unquoted.
+    let mut candidates: Vec<(usize, &str)> = source
+        .lines()
+        .enumerate()
+        .filter(|(_, line)| line.contains(&quoted))
+        .collect();
+    if candidates.is_empty() {
+        candidates = source
+            .lines()
+            .enumerate()
+            .filter(|(_, line)| line.contains(reference))
+            .collect();
+    }
+
+    candidates
+        .iter()
+        .find(|(_, line)| line.contains(&quoted_field))
+        .or_else(|| candidates.first())
+        .map(|(i, line)| (i + 1, line.trim().to_string()))
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
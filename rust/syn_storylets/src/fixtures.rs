@@ -0,0 +1,239 @@
+//! Per-storylet test fixtures and golden outcome summaries.
+//!
+//! Authors can embed a `test_fixture` block in a storylet's JSON describing a
+//! minimal world, whether the storylet should be eligible in it, and a golden
+//! summary of what the storylet does when it resolves:
+//!
+//! ```json
+//! "test_fixture": {
+//!   "description": "a close friend with good news",
+//!   "world": {
+//!     "stats": { "mood": 4.0 },
+//!     "flags": ["met_sam"],
+//!     "relationships": [
+//!       { "from_role": "protagonist", "to_role": "friend", "axes": { "trust": 6.0 } }
+//!     ]
+//!   },
+//!   "expect_eligible": true,
+//!   "expected_outcome": {
+//!     "stat_deltas": { "mood": 2.0 },
+//!     "flags_set": ["celebrated_with_sam"]
+//!   }
+//! }
+//! ```
+//!
+//! The types here are pure data; the director's fixture harness builds a world
+//! from [`FixtureWorld`], runs eligibility, and compares the storylet's
+//! [`OutcomeSummary`] against the golden one, so content regressions surface in
+//! CI without a hand-written Rust test per storylet.
+
+use std::collections::BTreeMap;
+
+use serde::{Deserialize, Serialize};
+
+use crate::{LifeStage, Outcome, StoryletId};
+
+/// Tolerance used when comparing summed stat deltas.
+pub const OUTCOME_DELTA_EPSILON: f32 = 1e-4;
+
+/// An embedded test case for one storylet.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct StoryletTestFixture {
+    /// What the fixture exercises, for failure reports.
+    #[serde(default)]
+    pub description: Option<String>,
+    /// The world to evaluate the storylet in.
+    #[serde(default)]
+    pub world: FixtureWorld,
+    /// Whether the storylet should be eligible in that world.
+    pub expect_eligible: bool,
+    /// Golden summary of the storylet's outcome, if pinned.
+    #[serde(default)]
+    pub expected_outcome: Option<OutcomeSummary>,
+}
+
+/// Minimal world setup for a fixture.
+///
+/// Anything left out keeps the defaults of a fresh world. Role names in
+/// `relationships` other than "protagonist"/"player" are cast to fixture NPCs.
+#[derive(Debug, Clone, Default, Serialize, Deserialize, PartialEq)]
+#[serde(default)]
+pub struct FixtureWorld {
+    /// Player life stage; defaults to the storylet's own life stage.
+    pub life_stage: Option<LifeStage>,
+    /// Player stats by content name ("mood", "wealth", ...).
+    pub stats: BTreeMap<String, f32>,
+    /// Player traits by name ("impulsivity", "empathy", ...).
+    pub traits: BTreeMap<String, f32>,
+    /// World flags that are set.
+    pub flags: Vec<String>,
+    /// Tags of memories the player holds.
+    pub memory_tags: Vec<String>,
+    /// Storylets that have already fired once.
+    pub fired_storylets: Vec<StoryletId>,
+    /// District state entries (e.g. "crime_level").
+    pub district_state: BTreeMap<String, String>,
+    /// Relationships between roles.
+    pub relationships: Vec<FixtureRelationship>,
+}
+
+/// A relationship between two fixture roles.
+#[derive(Debug, Clone, Default, Serialize, Deserialize, PartialEq)]
+#[serde(default)]
+pub struct FixtureRelationship {
+    /// Role holding the feelings.
+    pub from_role: String,
+    /// Role the feelings are about.
+    pub to_role: String,
+    /// Axis values ("affection", "trust", ...); unset axes stay at zero.
+    pub axes: BTreeMap<String, f32>,
+}
+
+/// Order-independent summary of what an [`Outcome`] does.
+///
+/// Stat deltas are summed per stat; flags, memory tags and follow-ups are
+/// sorted and deduplicated so reordering authored entries is not a change.
+#[derive(Debug, Clone, Default, Serialize, Deserialize, PartialEq)]
+#[serde(default)]
+pub struct OutcomeSummary {
+    /// Net stat deltas by stat name.
+    pub stat_deltas: BTreeMap<String, f32>,
+    /// Flags the outcome sets.
+    pub flags_set: Vec<String>,
+    /// Flags the outcome clears.
+    pub flags_cleared: Vec<String>,
+    /// Tags across all memories the outcome creates.
+    pub memory_tags: Vec<String>,
+    /// Follow-up storylet IDs.
+    pub follow_ups: Vec<String>,
+}
+
+impl OutcomeSummary {
+    /// Summarize an authored outcome.
+    pub fn from_outcome(outcome: &Outcome) -> Self {
+        let mut summary = OutcomeSummary::default();
+        for delta in outcome.stat_deltas.iter().flatten() {
+            *summary.stat_deltas.entry(delta.stat.clone()).or_insert(0.0) += delta.delta;
+        }
+        for op in outcome.flag_operations.iter().flatten() {
+            if op.set {
+                summary.flags_set.push(op.flag.clone());
+            } else {
+                summary.flags_cleared.push(op.flag.clone());
+            }
+        }
+        for memory in outcome.memory_entries.iter().flatten() {
+            summary.memory_tags.extend(memory.tags.iter().cloned());
+        }
+        for follow_up in outcome.follow_ups.iter().flatten() {
+            summary.follow_ups.push(follow_up.storylet_id.clone());
+        }
+        for list in [
+            &mut summary.flags_set,
+            &mut summary.flags_cleared,
+            &mut summary.memory_tags,
+            &mut summary.follow_ups,
+        ] {
+            list.sort();
+            list.dedup();
+        }
+        summary
+    }
+
+    /// Describe every way `actual` differs from this golden summary.
+    ///
+    /// Returns an empty list when they match.
+    pub fn mismatches(&self, actual: &OutcomeSummary) -> Vec<String> {
+        let mut mismatches = Vec::new();
+
+        let stats: std::collections::BTreeSet<&String> = self
+            .stat_deltas
+            .keys()
+            .chain(actual.stat_deltas.keys())
+            .collect();
+        for stat in stats {
+            let expected = self.stat_deltas.get(stat).copied().unwrap_or(0.0);
+            let got = actual.stat_deltas.get(stat).copied().unwrap_or(0.0);
+            if (expected - got).abs() > OUTCOME_DELTA_EPSILON {
+                mismatches.push(format!(
+                    "stat '{}': expected delta {}, got {}",
+                    stat, expected, got
+                ));
+            }
+        }
+
+        for (label, expected, got) in [
+            ("flags_set", &self.flags_set, &actual.flags_set),
+            ("flags_cleared", &self.flags_cleared, &actual.flags_cleared),
+            ("memory_tags", &self.memory_tags, &actual.memory_tags),
+            ("follow_ups", &self.follow_ups, &actual.follow_ups),
+        ] {
+            let mut expected = expected.clone();
+            expected.sort();
+            expected.dedup();
+            if &expected != got {
+                mismatches.push(format!("{}: expected {:?}, got {:?}", label, expected, got));
+            }
+        }
+
+        mismatches
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{FlagOperation, StatDelta};
+
+    fn outcome() -> Outcome {
+        Outcome {
+            stat_deltas: Some(vec![
+                StatDelta {
+                    stat: "mood".to_string(),
+                    delta: 1.5,
+                },
+                StatDelta {
+                    stat: "mood".to_string(),
+                    delta: 0.5,
+                },
+            ]),
+            flag_operations: Some(vec![
+                FlagOperation {
+                    flag: "b".to_string(),
+                    set: true,
+                },
+                FlagOperation {
+                    flag: "a".to_string(),
+                    set: true,
+                },
+            ]),
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn summary_sums_deltas_and_ignores_order() {
+        let summary = OutcomeSummary::from_outcome(&outcome());
+        assert!((summary.stat_deltas["mood"] - 2.0).abs() < OUTCOME_DELTA_EPSILON);
+        assert_eq!(summary.flags_set, vec!["a".to_string(), "b".to_string()]);
+
+        let golden: OutcomeSummary =
+            serde_json::from_str(r#"{ "stat_deltas": { "mood": 2.0 }, "flags_set": ["b", "a"] }"#)
+                .unwrap();
+        assert!(golden.mismatches(&summary).is_empty());
+    }
+
+    #[test]
+    fn mismatches_name_each_difference() {
+        let summary = OutcomeSummary::from_outcome(&outcome());
+        let golden: OutcomeSummary = serde_json::from_str(
+            r#"{ "stat_deltas": { "mood": 3.0, "wealth": 1.0 }, "flags_set": ["a"] }"#,
+        )
+        .unwrap();
+        let mismatches = golden.mismatches(&summary);
+        assert_eq!(mismatches.len(), 3);
+        assert!(mismatches[0].contains("mood"));
+        assert!(mismatches[1].contains("wealth"));
+        assert!(mismatches[2].starts_with("flags_set"));
+    }
+}
@@ -33,6 +33,19 @@
 //!
 //! The `validation` module provides a configurable validator for `StoryletDef` instances.
 //! See [`validation::default_storylet_validator`] for sensible defaults or build custom validators.
+//! [`validation::validate_library`] checks the references between storylets of a whole
+//! library: missing follow-ups and dependencies, undeclared roles, and lone tags.
+//!
+//! ## Age Gating
+//!
+//! The `age_gating` module maps mature tags and domains to the earliest life stage they
+//! suit. The director enforces it on every storylet; the validator warns about conflicts.
+//!
+//! ## Test Fixtures
+//!
+//! The `fixtures` module defines the optional `test_fixture` block a storylet can
+//! embed: a world setup, expected eligibility, and a golden outcome summary. The
+//! director's fixture harness runs them all as a test suite.
 //!
 //! ## Compilation
 //!
@@ -42,15 +55,18 @@
 //! # Example: Compiling Storylets
 //! ```text
 //! $ ./target/release/storyletc --input ./storylets --output ./storylets.bin
+//! $ ./target/release/storyletc --input ./storylets --check
 //! ```
 
 use serde::{Deserialize, Serialize};
 
+pub mod age_gating;
 pub mod validation;
 pub mod library;
 pub mod compiler;
 pub mod binary;
 pub mod errors;
+pub mod fixtures;
 
 #[cfg(feature = "mmap")]
 pub mod mapped;
@@ -113,13 +129,57 @@ pub enum StoryDomain {
     District,
     /// Digital/virtual world events, AI interactions, net culture.
     Digital,
+    /// Black markets, crews, and favors owed; unlocked by underworld exposure.
+    Underworld,
+}
+
+impl StoryDomain {
+    /// Every domain, in declaration order.
+    pub const ALL: [StoryDomain; 11] = [
+        StoryDomain::Romance,
+        StoryDomain::Conflict,
+        StoryDomain::Career,
+        StoryDomain::Trauma,
+        StoryDomain::Addiction,
+        StoryDomain::Family,
+        StoryDomain::Friendship,
+        StoryDomain::SliceOfLife,
+        StoryDomain::District,
+        StoryDomain::Digital,
+        StoryDomain::Underworld,
+    ];
+
+    /// The domain a tag names, if any (`"Romance"`, `"slice_of_life"`, ...).
+    pub fn from_tag(tag: &str) -> Option<StoryDomain> {
+        Self::ALL
+            .into_iter()
+            .find(|domain| domain.as_str().eq_ignore_ascii_case(tag))
+    }
+
+    /// Snake-case name, matching the serialized form (e.g. `"slice_of_life"`).
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            StoryDomain::Romance => "romance",
+            StoryDomain::Conflict => "conflict",
+            StoryDomain::Career => "career",
+            StoryDomain::Trauma => "trauma",
+            StoryDomain::Addiction => "addiction",
+            StoryDomain::Family => "family",
+            StoryDomain::Friendship => "friendship",
+            StoryDomain::SliceOfLife => "slice_of_life",
+            StoryDomain::District => "district",
+            StoryDomain::Digital => "digital",
+            StoryDomain::Underworld => "underworld",
+        }
+    }
 }
 
 /// Character life stage, used for age-gating and demographic filtering.
 ///
 /// These stages determine which storylets are eligible for a character at any given time.
 /// For example, a "first crush" storylet should only fire for teens/young adults, not children.
-#[derive(Debug, Clone, Copy, Eq, PartialEq, Hash, Serialize, Deserialize)]
+/// Stages order chronologically (`Child < Teen < ... < Digital`).
+#[derive(Debug, Clone, Copy, Eq, PartialEq, PartialOrd, Ord, Hash, Serialize, Deserialize)]
 #[serde(rename_all = "snake_case")]
 pub enum LifeStage {
     /// Childhood (0–12 years).
@@ -265,6 +325,9 @@ pub struct GlobalFlags {
 /// - "manager": an authority figure
 ///
 /// Each role can have optional constraints (e.g., minimum relationship affection for a "love_interest" role).
+///
+/// Roles are cast to distinct actors: nobody plays both "target" and "rival"
+/// unless one of the two roles lists the other in `allow_same_as`.
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
 pub struct RoleSlot {
     /// Role name.
@@ -273,6 +336,9 @@ pub struct RoleSlot {
     pub required: bool,
     /// Optional stat or trait constraints for the actor filling this role.
     pub constraints: Option<String>,
+    /// Roles that may be filled by the same actor as this one.
+    #[serde(default)]
+    pub allow_same_as: Vec<String>,
 }
 
 /// The complete set of prerequisites that must be satisfied for a storylet to be eligible.
@@ -295,6 +361,12 @@ pub struct Prerequisites {
     pub world_state_prerequisites: Option<WorldStatePrerequisites>,
     /// Global flag checks.
     pub global_flags: Option<GlobalFlags>,
+    /// Hard dependencies: storylets that must all have fired before this one
+    /// is eligible (e.g. a second date after the first).
+    pub depends_on: Option<Vec<StoryletId>>,
+    /// Soft dependencies: storylets that boost this one's score once any of
+    /// them has fired.
+    pub prefers_after: Option<Vec<StoryletId>>,
 }
 
 /// Cooldown timers to prevent storylets from firing too frequently.
@@ -434,6 +506,30 @@ pub struct Outcome {
     pub memory_entries: Option<Vec<MemoryEntry>>,
     /// Follow-up storylets to schedule.
     pub follow_ups: Option<Vec<FollowUpStorylet>>,
+    /// Choices offered to the player, each with its own outcome.
+    #[serde(default)]
+    pub choices: Option<Vec<OutcomeChoice>>,
+}
+
+/// A choice offered to the player when the storylet fires.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct OutcomeChoice {
+    /// Choice identifier, unique within the storylet.
+    pub id: String,
+    /// Text shown for the choice.
+    #[serde(default)]
+    pub label: String,
+    /// What taking the choice leads to.
+    #[serde(default)]
+    pub outcome: ChoiceOutcome,
+}
+
+/// The outcome of an [`OutcomeChoice`].
+#[derive(Debug, Clone, Default, Serialize, Deserialize, PartialEq)]
+pub struct ChoiceOutcome {
+    /// Storylet queued next when the choice is taken.
+    #[serde(default)]
+    pub next_storylet: Option<String>,
 }
 
 /// A complete storylet definition.
@@ -479,6 +575,9 @@ pub struct StoryletDef {
     pub cooldowns: Cooldowns,
     /// Outcomes that occur when this storylet is selected.
     pub outcomes: Outcome,
+    /// Optional embedded test case (see [`fixtures`]).
+    #[serde(default)]
+    pub test_fixture: Option<fixtures::StoryletTestFixture>,
 }
 
 impl StoryletDef {
@@ -498,6 +597,7 @@ impl StoryletDef {
             triggers: vec![TriggerKind::TimeTick],
             cooldowns: Cooldowns::default(),
             outcomes: Outcome::default(),
+            test_fixture: None,
         }
     }
 }
@@ -527,11 +627,13 @@ mod tests {
                 name: "protagonist".to_string(),
                 required: true,
                 constraints: None,
+                allow_same_as: vec![],
             },
             RoleSlot {
                 name: "love_interest".to_string(),
                 required: true,
                 constraints: Some("affection > 3".to_string()),
+                allow_same_as: vec![],
             },
         ];
 
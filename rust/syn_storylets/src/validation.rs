@@ -4,8 +4,13 @@
 //! checking against game design constraints and allowed vocabularies.
 //! It supports both compiled-time (offline) and runtime validation paths.
 
-use crate::{Cooldowns, MemoryEntry, Outcome, Prerequisites, StoryletDef, StoryletId};
-use std::collections::HashSet;
+use crate::age_gating::conflicting_stages;
+use crate::library::StoryletLibrary;
+use crate::{
+    Cooldowns, LifeStage, MemoryEntry, Outcome, Prerequisites, StoryDomain, StoryletDef,
+    StoryletId, Tag,
+};
+use std::collections::{HashMap, HashSet};
 
 /// Validation error types for storylet definitions.
 ///
@@ -71,6 +76,23 @@ pub enum StoryletValidationError {
     UnknownGlobalFlag { flag: String },
     /// A memory entry contains invalid roles or other issues.
     InvalidMemoryEntry { reason: String },
+    /// A `depends_on`/`prefers_after` storylet ID has invalid format.
+    InvalidDependencyId {
+        id: String,
+        reason: String,
+    },
+    /// A `depends_on`/`prefers_after` entry names a storylet that is not in
+    /// the batch being validated.
+    UnknownDependency { id: String },
+    /// Hard dependencies form a cycle, so none of these storylets can ever
+    /// fire. The first and last IDs are the same.
+    DependencyCycle { cycle: Vec<String> },
+    /// An outcome delta is larger than the authoring maximum (likely a typo).
+    DeltaExceedsAuthoringMax {
+        field: String,
+        delta: f32,
+        max: f32,
+    },
 }
 
 impl std::fmt::Display for StoryletValidationError {
@@ -147,12 +169,80 @@ impl std::fmt::Display for StoryletValidationError {
             Self::InvalidMemoryEntry { reason } => {
                 write!(f, "Invalid memory entry: {}", reason)
             }
+            Self::InvalidDependencyId { id, reason } => {
+                write!(f, "Invalid dependency storylet ID '{}': {}", id, reason)
+            }
+            Self::UnknownDependency { id } => {
+                write!(f, "Dependency '{}' does not name a known storylet", id)
+            }
+            Self::DependencyCycle { cycle } => {
+                write!(f, "Hard dependency cycle: {}", cycle.join(" -> "))
+            }
+            Self::DeltaExceedsAuthoringMax { field, delta, max } => {
+                write!(
+                    f,
+                    "Outcome {} delta {} exceeds the authoring maximum of {}",
+                    field, delta, max
+                )
+            }
         }
     }
 }
 
 impl std::error::Error for StoryletValidationError {}
 
+/// Non-fatal findings about a storylet definition.
+///
+/// Warnings never fail compilation; they flag content an author should
+/// double-check.
+#[derive(Debug, Clone, PartialEq)]
+pub enum StoryletValidationWarning {
+    /// An authored life stage is below the age-gating minimum for the
+    /// storylet's domain and tags, so the director will never fire it there.
+    AgeGateConflict {
+        stage: LifeStage,
+        minimum: LifeStage,
+    },
+}
+
+impl std::fmt::Display for StoryletValidationWarning {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::AgeGateConflict { stage, minimum } => write!(
+                f,
+                "Authored for {:?} but its domain/tags are age-gated to {:?} and up",
+                stage, minimum
+            ),
+        }
+    }
+}
+
+/// Largest outcome deltas content may author.
+///
+/// These are hard validation limits meant to catch typos like `delta: 200`;
+/// the runtime also clamps deltas to its own, tighter per-event and per-day
+/// caps. Stat deltas are further limited to the width of the stat's
+/// configured range, when it has one.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct AuthoringDeltaLimits {
+    /// Largest stat (or karma) delta.
+    pub stat: f32,
+    /// Largest relationship axis delta (axes run -10..=10).
+    pub relationship: f32,
+    /// Largest NPC mood delta.
+    pub mood: f32,
+}
+
+impl Default for AuthoringDeltaLimits {
+    fn default() -> Self {
+        Self {
+            stat: 100.0,
+            relationship: 10.0,
+            mood: 10.0,
+        }
+    }
+}
+
 /// Configuration for validating storylet definitions.
 ///
 /// The validator is initialized with allowed vocabularies (stat names, trait names, etc.)
@@ -178,6 +268,8 @@ pub struct StoryletValidator {
     allowed_memory_tags: HashSet<String>,
     /// Expected range for stat values (used in threshold validation).
     stat_ranges: std::collections::HashMap<String, (f32, f32)>,
+    /// Largest outcome deltas content may author.
+    delta_limits: AuthoringDeltaLimits,
 }
 
 impl StoryletValidator {
@@ -191,6 +283,7 @@ impl StoryletValidator {
             allowed_flags: HashSet::new(),
             allowed_memory_tags: HashSet::new(),
             stat_ranges: std::collections::HashMap::new(),
+            delta_limits: AuthoringDeltaLimits::default(),
         }
     }
 
@@ -246,6 +339,12 @@ impl StoryletValidator {
         self
     }
 
+    /// Set the largest outcome deltas content may author.
+    pub fn with_delta_limits(mut self, limits: AuthoringDeltaLimits) -> Self {
+        self.delta_limits = limits;
+        self
+    }
+
     /// Add an allowed global flag.
     pub fn with_flag(mut self, flag: impl Into<String>) -> Self {
         self.allowed_flags.insert(flag.into());
@@ -274,6 +373,16 @@ impl StoryletValidator {
         self
     }
 
+    /// Non-fatal findings for a storylet definition (see [`StoryletValidationWarning`]).
+    pub fn warnings(&self, s: &StoryletDef) -> Vec<StoryletValidationWarning> {
+        age_gate_warnings(
+            s.domain,
+            &s.tags,
+            s.life_stage,
+            s.prerequisites.life_stages.as_deref(),
+        )
+    }
+
     /// Validate a storylet definition.
     ///
     /// Returns `Ok(())` if the storylet is valid.
@@ -533,6 +642,12 @@ impl StoryletValidator {
             }
         }
 
+        // Validate dependency ID formats
+        let dependencies = prereqs.depends_on.iter().flatten();
+        for id in dependencies.chain(prereqs.prefers_after.iter().flatten()) {
+            errors.extend(self.validate_dependency_id(&id.0));
+        }
+
         errors
     }
 
@@ -587,6 +702,9 @@ impl StoryletValidator {
     fn validate_outcomes(&self, outcomes: &Outcome) -> Vec<StoryletValidationError> {
         let mut errors = Vec::new();
 
+        // Validate delta magnitudes against the authoring maximums
+        errors.extend(self.validate_outcome_deltas(outcomes));
+
         // Validate follow-up IDs
         if let Some(follow_ups) = &outcomes.follow_ups {
             for fu in follow_ups {
@@ -615,6 +733,37 @@ impl StoryletValidator {
         errors
     }
 
+    /// Reject outcome deltas above the authoring maximums.
+    fn validate_outcome_deltas(&self, outcomes: &Outcome) -> Vec<StoryletValidationError> {
+        let limits = self.delta_limits;
+        let mut errors = Vec::new();
+        let mut check = |field: String, delta: f32, max: f32| {
+            if !delta.is_finite() || delta.abs() > max {
+                errors.push(StoryletValidationError::DeltaExceedsAuthoringMax { field, delta, max });
+            }
+        };
+
+        for (i, d) in outcomes.stat_deltas.iter().flatten().enumerate() {
+            let max = match self.stat_ranges.get(&d.stat.to_lowercase()) {
+                Some((min, max)) => limits.stat.min(max - min),
+                None => limits.stat,
+            };
+            check(format!("stat_deltas[{}] ({})", i, d.stat), d.delta, max);
+        }
+        for (i, d) in outcomes.relationship_deltas.iter().flatten().enumerate() {
+            check(
+                format!("relationship_deltas[{}] ({})", i, d.axis),
+                d.delta,
+                limits.relationship,
+            );
+        }
+        for (i, d) in outcomes.mood_deltas.iter().flatten().enumerate() {
+            check(format!("mood_deltas[{}] ({})", i, d.role), d.delta, limits.mood);
+        }
+
+        errors
+    }
+
     /// Validate follow-up storylet ID format.
     fn validate_followup_id(&self, id: &str) -> Vec<StoryletValidationError> {
         self.validate_id(id)
@@ -642,6 +791,25 @@ impl StoryletValidator {
             .collect()
     }
 
+    /// Validate a dependency storylet ID format.
+    fn validate_dependency_id(&self, id: &str) -> Vec<StoryletValidationError> {
+        self.validate_id(id)
+            .into_iter()
+            .map(|err| match err {
+                StoryletValidationError::MissingId => {
+                    StoryletValidationError::InvalidDependencyId {
+                        id: id.to_string(),
+                        reason: "missing or empty".to_string(),
+                    }
+                }
+                StoryletValidationError::InvalidIdFormat { id, reason } => {
+                    StoryletValidationError::InvalidDependencyId { id, reason }
+                }
+                other => other,
+            })
+            .collect()
+    }
+
     /// Validate a memory entry.
     fn validate_memory_entry(&self, mem: &MemoryEntry) -> Vec<StoryletValidationError> {
         let mut errors = Vec::new();
@@ -737,6 +905,10 @@ pub fn default_storylet_validator() -> StoryletValidator {
 
 /// Convenience function to validate multiple storylets at once.
 ///
+/// Besides validating each storylet, this checks `depends_on` and
+/// `prefers_after` across the batch: every entry must name a storylet in
+/// `storylets`, and hard dependencies must not form a cycle.
+///
 /// Returns `Ok(())` if all storylets are valid.
 /// Returns `Err(vec)` containing tuples of (storylet_id, errors) for any invalid storylets.
 /// This is useful for batch validation during import or compilation phases.
@@ -768,9 +940,17 @@ pub fn validate_storylets(
     storylets: &[StoryletDef],
 ) -> Result<(), Vec<(StoryletId, Vec<StoryletValidationError>)>> {
     let mut failures = Vec::new();
+    let dependency_errors = dependency_errors(storylets);
 
     for storylet in storylets {
-        if let Err(errors) = validator.validate_storylet(storylet) {
+        let mut errors = validator.validate_storylet(storylet).err().unwrap_or_default();
+        errors.extend(
+            dependency_errors
+                .iter()
+                .filter(|(id, _)| id == &storylet.id)
+                .map(|(_, err)| err.clone()),
+        );
+        if !errors.is_empty() {
             failures.push((storylet.id.clone(), errors));
         }
     }
@@ -782,6 +962,345 @@ pub fn validate_storylets(
     }
 }
 
+/// DFS state of a storylet while searching for dependency cycles.
+#[derive(Clone, Copy, PartialEq)]
+enum Visit {
+    Unvisited,
+    InProgress,
+    Done,
+}
+
+/// Cross-storylet dependency errors: unknown `depends_on`/`prefers_after`
+/// targets, and cycles among hard dependencies (reported once each, on the
+/// storylet where the cycle closes).
+fn dependency_errors(storylets: &[StoryletDef]) -> Vec<(StoryletId, StoryletValidationError)> {
+    let index: HashMap<&str, usize> = storylets
+        .iter()
+        .enumerate()
+        .map(|(i, s)| (s.id.0.as_str(), i))
+        .collect();
+    let mut errors = Vec::new();
+
+    for storylet in storylets {
+        let prereqs = &storylet.prerequisites;
+        let dependencies = prereqs.depends_on.iter().flatten();
+        for dep in dependencies.chain(prereqs.prefers_after.iter().flatten()) {
+            if !index.contains_key(dep.0.as_str()) {
+                errors.push((
+                    storylet.id.clone(),
+                    StoryletValidationError::UnknownDependency { id: dep.0.clone() },
+                ));
+            }
+        }
+    }
+
+    let mut visits = vec![Visit::Unvisited; storylets.len()];
+    let mut path = Vec::new();
+    for start in 0..storylets.len() {
+        if visits[start] == Visit::Unvisited {
+            find_dependency_cycles(start, storylets, &index, &mut visits, &mut path, &mut errors);
+        }
+    }
+
+    errors
+}
+
+fn find_dependency_cycles(
+    at: usize,
+    storylets: &[StoryletDef],
+    index: &HashMap<&str, usize>,
+    visits: &mut [Visit],
+    path: &mut Vec<usize>,
+    errors: &mut Vec<(StoryletId, StoryletValidationError)>,
+) {
+    visits[at] = Visit::InProgress;
+    path.push(at);
+
+    for dep in storylets[at].prerequisites.depends_on.iter().flatten() {
+        let Some(&next) = index.get(dep.0.as_str()) else {
+            continue;
+        };
+        match visits[next] {
+            Visit::Unvisited => {
+                find_dependency_cycles(next, storylets, index, visits, path, errors);
+            }
+            Visit::InProgress => {
+                let start = path.iter().position(|&i| i == next).unwrap_or(0);
+                let cycle = path[start..]
+                    .iter()
+                    .chain(std::iter::once(&next))
+                    .map(|&i| storylets[i].id.0.clone())
+                    .collect();
+                errors.push((
+                    storylets[at].id.clone(),
+                    StoryletValidationError::DependencyCycle { cycle },
+                ));
+            }
+            Visit::Done => {}
+        }
+    }
+
+    path.pop();
+    visits[at] = Visit::Done;
+}
+
+/// Role names that always refer to the player and need no declaring.
+const PLAYER_ROLE_NAMES: [&str; 2] = ["protagonist", "player"];
+
+/// What is wrong with a cross-reference found by [`validate_library`].
+#[derive(Debug, Clone, PartialEq)]
+pub enum LibraryIssueKind {
+    /// A follow-up names a storylet that is not in the library.
+    MissingFollowUp { id: String },
+    /// A choice's `next_storylet` names a storylet that is not in the
+    /// library.
+    MissingNextStorylet { id: String, choice_id: String },
+    /// A `depends_on`/`prefers_after` entry names a storylet that is not in
+    /// the library.
+    MissingDependency { id: String },
+    /// A prerequisite, outcome or role slot names a role the storylet does
+    /// not declare.
+    UndeclaredRole { role: String },
+    /// A tag no other storylet in the library carries. Tags group storylets
+    /// for selection, so a lone tag is usually a typo of a shared one.
+    LoneTag { tag: String },
+}
+
+impl LibraryIssueKind {
+    /// The name that failed to resolve, as authored.
+    pub fn reference(&self) -> &str {
+        match self {
+            Self::MissingFollowUp { id }
+            | Self::MissingNextStorylet { id, .. }
+            | Self::MissingDependency { id } => id,
+            Self::UndeclaredRole { role } => role,
+            Self::LoneTag { tag } => tag,
+        }
+    }
+
+    /// Whether this is a broken reference rather than merely suspicious
+    /// content (a lone tag).
+    pub fn is_broken(&self) -> bool {
+        !matches!(self, Self::LoneTag { .. })
+    }
+}
+
+impl std::fmt::Display for LibraryIssueKind {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::MissingFollowUp { id } => {
+                write!(f, "follow-up '{}' does not name a storylet in the library", id)
+            }
+            Self::MissingNextStorylet { id, choice_id } => write!(
+                f,
+                "choice '{}' leads to '{}', which is not a storylet in the library",
+                choice_id, id
+            ),
+            Self::MissingDependency { id } => {
+                write!(f, "dependency '{}' does not name a storylet in the library", id)
+            }
+            Self::UndeclaredRole { role } => {
+                write!(f, "role '{}' is not declared in the storylet's roles", role)
+            }
+            Self::LoneTag { tag } => write!(f, "tag '{}' appears on no other storylet", tag),
+        }
+    }
+}
+
+/// A cross-reference finding, with the storylet and field it was found in.
+#[derive(Debug, Clone, PartialEq)]
+pub struct LibraryIssue {
+    /// Storylet the reference was authored in.
+    pub storylet_id: StoryletId,
+    /// Path of the offending field, e.g. `outcomes.follow_ups[0].storylet_id`.
+    pub location: String,
+    /// What is wrong with it.
+    pub kind: LibraryIssueKind,
+}
+
+impl std::fmt::Display for LibraryIssue {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "storylet '{}' at {}: {}",
+            self.storylet_id.0, self.location, self.kind
+        )
+    }
+}
+
+/// Check the references between storylets of a whole library.
+///
+/// [`StoryletValidator::validate_storylet`] looks at one definition at a time;
+/// this reports what only the full set can show: follow-ups, choices'
+/// `next_storylet` and dependencies naming storylets that don't exist, roles used in prerequisites, outcomes
+/// or `allow_same_as` without being declared, and tags carried by a single
+/// storylet. Every finding is returned, in storylet order.
+pub fn validate_library(storylets: &[StoryletDef]) -> Vec<LibraryIssue> {
+    let ids: HashSet<&str> = storylets.iter().map(|s| s.id.0.as_str()).collect();
+    let mut tag_counts: HashMap<&str, usize> = HashMap::new();
+    for storylet in storylets {
+        let tags: HashSet<&str> = storylet.tags.iter().map(|t| t.0.as_str()).collect();
+        for tag in tags {
+            *tag_counts.entry(tag).or_default() += 1;
+        }
+    }
+
+    let mut issues = Vec::new();
+    for storylet in storylets {
+        let mut report = |location: String, kind: LibraryIssueKind| {
+            issues.push(LibraryIssue {
+                storylet_id: storylet.id.clone(),
+                location,
+                kind,
+            });
+        };
+
+        let prereqs = &storylet.prerequisites;
+        let dependencies = [
+            ("depends_on", &prereqs.depends_on),
+            ("prefers_after", &prereqs.prefers_after),
+        ];
+        for (field, deps) in dependencies {
+            for (i, dep) in deps.iter().flatten().enumerate() {
+                if !ids.contains(dep.0.as_str()) {
+                    report(
+                        format!("prerequisites.{}[{}]", field, i),
+                        LibraryIssueKind::MissingDependency { id: dep.0.clone() },
+                    );
+                }
+            }
+        }
+
+        for (i, fu) in storylet.outcomes.follow_ups.iter().flatten().enumerate() {
+            if !ids.contains(fu.storylet_id.as_str()) {
+                report(
+                    format!("outcomes.follow_ups[{}].storylet_id", i),
+                    LibraryIssueKind::MissingFollowUp {
+                        id: fu.storylet_id.clone(),
+                    },
+                );
+            }
+        }
+
+        for (i, choice) in storylet.outcomes.choices.iter().flatten().enumerate() {
+            let Some(next) = &choice.outcome.next_storylet else {
+                continue;
+            };
+            if !ids.contains(next.as_str()) {
+                report(
+                    format!("outcomes.choices[{}].outcome.next_storylet", i),
+                    LibraryIssueKind::MissingNextStorylet {
+                        id: next.clone(),
+                        choice_id: choice.id.clone(),
+                    },
+                );
+            }
+        }
+
+        for (location, role) in role_references(storylet) {
+            if !role_is_declared(storylet, role) {
+                report(
+                    location,
+                    LibraryIssueKind::UndeclaredRole {
+                        role: role.to_string(),
+                    },
+                );
+            }
+        }
+
+        for (i, tag) in storylet.tags.iter().enumerate() {
+            if !tag.0.is_empty() && tag_counts.get(tag.0.as_str()) == Some(&1) {
+                report(
+                    format!("tags[{}]", i),
+                    LibraryIssueKind::LoneTag { tag: tag.0.clone() },
+                );
+            }
+        }
+    }
+
+    issues
+}
+
+/// Every role name a storylet refers to, with the path of the field.
+fn role_references(storylet: &StoryletDef) -> Vec<(String, &str)> {
+    let mut refs = Vec::new();
+
+    for (i, role) in storylet.roles.iter().enumerate() {
+        for (j, other) in role.allow_same_as.iter().enumerate() {
+            refs.push((format!("roles[{}].allow_same_as[{}]", i, j), other.as_str()));
+        }
+    }
+
+    let prereqs = storylet.prerequisites.relationship_prerequisites.iter().flatten();
+    for (i, p) in prereqs.enumerate() {
+        let location = format!("prerequisites.relationship_prerequisites[{}]", i);
+        refs.push((format!("{}.from_role", location), p.from_role.as_str()));
+        refs.push((format!("{}.to_role", location), p.to_role.as_str()));
+    }
+
+    let outcomes = &storylet.outcomes;
+    for (i, d) in outcomes.relationship_deltas.iter().flatten().enumerate() {
+        let location = format!("outcomes.relationship_deltas[{}]", i);
+        refs.push((format!("{}.from_role", location), d.from_role.as_str()));
+        refs.push((format!("{}.to_role", location), d.to_role.as_str()));
+    }
+    for (i, d) in outcomes.mood_deltas.iter().flatten().enumerate() {
+        refs.push((format!("outcomes.mood_deltas[{}].role", i), d.role.as_str()));
+    }
+    for (i, c) in outcomes.trait_changes.iter().flatten().enumerate() {
+        refs.push((format!("outcomes.trait_changes[{}].role", i), c.role.as_str()));
+    }
+    for (i, m) in outcomes.memory_entries.iter().flatten().enumerate() {
+        for role in m.roles.split(',').map(str::trim).filter(|r| !r.is_empty()) {
+            refs.push((format!("outcomes.memory_entries[{}].roles", i), role));
+        }
+    }
+
+    refs
+}
+
+/// Whether `name` resolves the way the director resolves outcome roles: the
+/// player, a declared slot, or a legacy numeric NPC id.
+fn role_is_declared(storylet: &StoryletDef, name: &str) -> bool {
+    let name = name.trim();
+    PLAYER_ROLE_NAMES
+        .iter()
+        .any(|p| p.eq_ignore_ascii_case(name))
+        || storylet.roles.iter().any(|r| r.name == name)
+        || name.parse::<u64>().is_ok()
+}
+
+/// Non-fatal findings for every storylet in a compiled library, by id.
+pub fn library_warnings(
+    library: &StoryletLibrary,
+) -> Vec<(StoryletId, Vec<StoryletValidationWarning>)> {
+    library
+        .storylets
+        .iter()
+        .filter_map(|s| {
+            let warnings = age_gate_warnings(
+                s.domain,
+                &s.tags,
+                s.life_stage,
+                s.prerequisites.life_stages.as_deref(),
+            );
+            (!warnings.is_empty()).then(|| (s.id.clone(), warnings))
+        })
+        .collect()
+}
+
+fn age_gate_warnings(
+    domain: StoryDomain,
+    tags: &[Tag],
+    life_stage: LifeStage,
+    prerequisite_stages: Option<&[LifeStage]>,
+) -> Vec<StoryletValidationWarning> {
+    conflicting_stages(domain, tags, life_stage, prerequisite_stages)
+        .into_iter()
+        .map(|(stage, minimum)| StoryletValidationWarning::AgeGateConflict { stage, minimum })
+        .collect()
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -846,6 +1365,26 @@ mod tests {
         assert!(result.is_err(), "Negative weight should fail validation");
     }
 
+    #[test]
+    fn test_age_gate_conflict_is_a_warning() {
+        let validator = default_storylet_validator();
+        let mut storylet =
+            StoryletDef::new(StoryletId::new("test"), "Test".to_string(), StoryDomain::Romance, LifeStage::Child);
+        storylet.tags = vec![Tag::new("alcohol")];
+
+        assert!(validator.validate_storylet(&storylet).is_ok());
+        assert_eq!(
+            validator.warnings(&storylet),
+            vec![StoryletValidationWarning::AgeGateConflict {
+                stage: LifeStage::Child,
+                minimum: LifeStage::Teen,
+            }]
+        );
+
+        storylet.life_stage = LifeStage::Teen;
+        assert!(validator.warnings(&storylet).is_empty());
+    }
+
     #[test]
     fn test_weight_zero_invalid() {
         let validator = default_storylet_validator();
@@ -1241,4 +1780,212 @@ mod tests {
             );
         }
     }
+
+    fn storylet_with_deps(id: &str, depends_on: &[&str], prefers_after: &[&str]) -> StoryletDef {
+        let mut storylet = StoryletDef::new(
+            StoryletId::new(id),
+            id.to_string(),
+            StoryDomain::Romance,
+            LifeStage::Adult,
+        );
+        storylet.prerequisites.depends_on =
+            Some(depends_on.iter().map(|d| StoryletId::new(*d)).collect());
+        storylet.prerequisites.prefers_after =
+            Some(prefers_after.iter().map(|d| StoryletId::new(*d)).collect());
+        storylet
+    }
+
+    #[test]
+    fn test_typo_sized_outcome_deltas_are_errors() {
+        let validator = default_storylet_validator();
+        let mut storylet =
+            StoryletDef::new(StoryletId::new("test"), "Test".to_string(), StoryDomain::Romance, LifeStage::Adult);
+        storylet.outcomes.stat_deltas = Some(vec![
+            crate::StatDelta { stat: "wealth".to_string(), delta: 50.0 },
+            crate::StatDelta { stat: "health".to_string(), delta: -200.0 },
+            // Mood runs -10..10, so its limit is the width of that range.
+            crate::StatDelta { stat: "mood".to_string(), delta: 25.0 },
+        ]);
+        storylet.outcomes.relationship_deltas = Some(vec![crate::RelationshipDelta {
+            from_role: "protagonist".to_string(),
+            to_role: "friend".to_string(),
+            axis: "trust".to_string(),
+            delta: -10.0,
+        }]);
+
+        let errors = validator.validate_storylet(&storylet).unwrap_err();
+        let fields: Vec<&str> = errors
+            .iter()
+            .filter_map(|e| match e {
+                StoryletValidationError::DeltaExceedsAuthoringMax { field, .. } => Some(field.as_str()),
+                _ => None,
+            })
+            .collect();
+        assert_eq!(fields, vec!["stat_deltas[1] (health)", "stat_deltas[2] (mood)"]);
+
+        let strict = default_storylet_validator().with_delta_limits(AuthoringDeltaLimits {
+            relationship: 5.0,
+            ..Default::default()
+        });
+        let errors = strict.validate_storylet(&storylet).unwrap_err();
+        assert!(errors.iter().any(|e| matches!(
+            e,
+            StoryletValidationError::DeltaExceedsAuthoringMax { field, .. } if field == "relationship_deltas[0] (trust)"
+        )));
+    }
+
+    #[test]
+    fn test_unknown_dependencies_are_errors() {
+        let validator = default_storylet_validator();
+        let storylets = vec![
+            storylet_with_deps("romance.first_date", &[], &[]),
+            storylet_with_deps("romance.second_date", &["romance.first_date"], &["romance.picnic"]),
+        ];
+
+        let failures = validate_storylets(&validator, &storylets).unwrap_err();
+        assert_eq!(failures.len(), 1);
+        assert_eq!(failures[0].0.0, "romance.second_date");
+        assert_eq!(
+            failures[0].1,
+            vec![StoryletValidationError::UnknownDependency {
+                id: "romance.picnic".to_string()
+            }]
+        );
+    }
+
+    #[test]
+    fn test_hard_dependency_cycles_are_errors() {
+        let validator = default_storylet_validator();
+        let storylets = vec![
+            storylet_with_deps("romance.a", &["romance.c"], &[]),
+            storylet_with_deps("romance.b", &["romance.a"], &[]),
+            storylet_with_deps("romance.c", &["romance.b"], &[]),
+            // Soft dependencies may point both ways.
+            storylet_with_deps("romance.d", &[], &["romance.e"]),
+            storylet_with_deps("romance.e", &[], &["romance.d"]),
+        ];
+
+        let failures = validate_storylets(&validator, &storylets).unwrap_err();
+        assert_eq!(failures.len(), 1);
+        assert_eq!(
+            failures[0].1,
+            vec![StoryletValidationError::DependencyCycle {
+                cycle: vec![
+                    "romance.a".to_string(),
+                    "romance.c".to_string(),
+                    "romance.b".to_string(),
+                    "romance.a".to_string(),
+                ]
+            }]
+        );
+    }
+
+    #[test]
+    fn test_library_cross_references() {
+        let mut date = storylet_with_deps("romance.first_date", &[], &["romance.picnic"]);
+        date.tags = vec![Tag::new("romance"), Tag::new("romanse")];
+        date.roles = vec![crate::RoleSlot {
+            name: "date".to_string(),
+            required: true,
+            constraints: None,
+            allow_same_as: vec!["chaperone".to_string()],
+        }];
+        date.outcomes.follow_ups = Some(vec![
+            FollowUpStorylet {
+                storylet_id: "romance.second_date".to_string(),
+                delay_ticks: 24,
+                conditional_on_flag: None,
+            },
+            FollowUpStorylet {
+                storylet_id: "romance.third_date".to_string(),
+                delay_ticks: 48,
+                conditional_on_flag: None,
+            },
+        ]);
+        date.outcomes.mood_deltas = Some(vec![
+            crate::MoodDelta { role: "Player".to_string(), delta: 2.0 },
+            crate::MoodDelta { role: "date".to_string(), delta: 2.0 },
+        ]);
+        date.outcomes.memory_entries = Some(vec![MemoryEntry {
+            roles: "protagonist, date, ex".to_string(),
+            tags: vec!["romance".to_string()],
+            intensity: 5,
+            description: None,
+        }]);
+        let mut second = storylet_with_deps("romance.second_date", &["romance.first_date"], &[]);
+        second.tags = vec![Tag::new("romance")];
+
+        let issues = validate_library(&[date, second]);
+        let found: Vec<(&str, &str, String)> = issues
+            .iter()
+            .map(|i| (i.storylet_id.0.as_str(), i.location.as_str(), i.kind.to_string()))
+            .collect();
+        assert_eq!(
+            found,
+            vec![
+                (
+                    "romance.first_date",
+                    "prerequisites.prefers_after[0]",
+                    "dependency 'romance.picnic' does not name a storylet in the library".to_string()
+                ),
+                (
+                    "romance.first_date",
+                    "outcomes.follow_ups[1].storylet_id",
+                    "follow-up 'romance.third_date' does not name a storylet in the library".to_string()
+                ),
+                (
+                    "romance.first_date",
+                    "roles[0].allow_same_as[0]",
+                    "role 'chaperone' is not declared in the storylet's roles".to_string()
+                ),
+                (
+                    "romance.first_date",
+                    "outcomes.memory_entries[0].roles",
+                    "role 'ex' is not declared in the storylet's roles".to_string()
+                ),
+                (
+                    "romance.first_date",
+                    "tags[1]",
+                    "tag 'romanse' appears on no other storylet".to_string()
+                ),
+            ]
+        );
+        assert!(issues[0].kind.is_broken());
+        assert!(!issues[4].kind.is_broken());
+    }
+
+    #[test]
+    fn test_library_choices_must_lead_to_known_storylets() {
+        let mut date = storylet_with_deps("romance.first_date", &[], &[]);
+        let choice = |id: &str, next: Option<&str>| crate::OutcomeChoice {
+            id: id.to_string(),
+            label: String::new(),
+            outcome: crate::ChoiceOutcome {
+                next_storylet: next.map(str::to_string),
+            },
+        };
+        date.outcomes.choices = Some(vec![
+            choice("kiss", Some("romance.second_date")),
+            choice("leave", None),
+            choice("linger", Some("romance.nightcap")),
+        ]);
+        let second = storylet_with_deps("romance.second_date", &[], &[]);
+
+        let issues = validate_library(&[date, second]);
+        assert_eq!(issues.len(), 1);
+        assert_eq!(issues[0].storylet_id.0, "romance.first_date");
+        assert_eq!(issues[0].location, "outcomes.choices[2].outcome.next_storylet");
+        assert_eq!(
+            issues[0].kind,
+            LibraryIssueKind::MissingNextStorylet {
+                id: "romance.nightcap".to_string(),
+                choice_id: "linger".to_string(),
+            }
+        );
+        assert!(issues[0].kind.is_broken());
+        assert_eq!(
+            issues[0].kind.to_string(),
+            "choice 'linger' leads to 'romance.nightcap', which is not a storylet in the library"
+        );
+    }
 }
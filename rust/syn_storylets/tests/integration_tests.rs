@@ -23,6 +23,7 @@ fn create_test_storylet(id: &str, name: &str, domain: StoryDomain) -> StoryletDe
         triggers: vec![],
         cooldowns: Cooldowns::default(),
         outcomes: Outcome::default(),
+        test_fixture: None,
     }
 }
 
@@ -289,3 +290,41 @@ fn test_library_lookup_methods() {
         "Should find by life stage"
     );
 }
+
+#[test]
+fn test_check_dir_locates_broken_references() {
+    let temp_dir = TempDir::new().unwrap();
+    let dir_path = temp_dir.path();
+
+    let mut s1 = create_test_storylet("test.romance.first_date", "First Date", StoryDomain::Romance);
+    s1.outcomes.follow_ups = Some(vec![syn_storylets::FollowUpStorylet {
+        storylet_id: "test.romance.second_date".to_string(),
+        delay_ticks: 24,
+        conditional_on_flag: None,
+    }]);
+    let json1 = serde_json::to_string_pretty(&s1).unwrap();
+    fs::write(dir_path.join("romance.json"), &json1).unwrap();
+
+    let s2 = create_test_storylet("test.career.promotion", "Promotion", StoryDomain::Career);
+    let json2 = serde_json::to_string_pretty(&s2).unwrap();
+    fs::write(dir_path.join("career.json"), json2).unwrap();
+
+    let compiler = StoryletCompiler::new(default_storylet_validator());
+    let issues = compiler.check_dir(dir_path).expect("Storylets should load");
+
+    assert_eq!(issues.len(), 1);
+    let issue = &issues[0];
+    assert_eq!(issue.issue.storylet_id.0, "test.romance.first_date");
+    assert_eq!(issue.issue.location, "outcomes.follow_ups[0].storylet_id");
+    assert!(issue.issue.kind.is_broken());
+    assert_eq!(issue.path, dir_path.join("romance.json"));
+    let expected_line = json1
+        .lines()
+        .position(|l| l.contains("\"test.romance.second_date\""))
+        .map(|i| i + 1);
+    assert_eq!(issue.line, expected_line);
+    assert_eq!(
+        issue.line_text.as_deref(),
+        Some("\"storylet_id\": \"test.romance.second_date\",")
+    );
+}